@@ -22,3 +22,10 @@ pub trait Command: Send + Sync {
 /// A type-erased result for the command executor.
 pub type CommandResult = Box<dyn Any + Send>;
 
+/// How a command executor runs a batch of commands sharing one unit of work.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CommandExecutionOptions {
+    /// Wrap each command in a savepoint, so a failing command is undone on
+    /// its own while the commands around it still commit.
+    pub savepoint_per_command: bool,
+}
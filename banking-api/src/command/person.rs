@@ -4,6 +4,7 @@ use crate::error::BankingError;
 use async_trait::async_trait;
 use serde::Deserialize;
 use std::sync::Arc;
+use uuid::Uuid;
 use crate::service::{
     CountryService, CountrySubdivisionService, EntityReferenceService,
     LocalityService, LocationService, MessagingService, PersonService,
@@ -68,6 +69,94 @@ impl Command for PopulateGeoDataCommand {
     }
 }
 
+// #############################################################################
+// # Command: Import Geo Data
+// #############################################################################
+
+/// A locality row of a GeoNames extract, keyed on its code
+#[derive(Debug, Clone, Deserialize)]
+pub struct GeoLocalityRecord {
+    pub code: String,
+    pub name_l1: String,
+    #[serde(default)]
+    pub name_l2: Option<String>,
+    #[serde(default)]
+    pub name_l3: Option<String>,
+}
+
+/// An ISO 3166-2 subdivision row with the localities that belong to it
+#[derive(Debug, Clone, Deserialize)]
+pub struct GeoSubdivisionRecord {
+    /// ISO 3166-1 alpha-2 code of a country that must already exist
+    pub country_iso2: String,
+    /// ISO 3166-2 code, e.g. "CM-CE"
+    pub code: String,
+    pub name_l1: String,
+    #[serde(default)]
+    pub name_l2: Option<String>,
+    #[serde(default)]
+    pub name_l3: Option<String>,
+    #[serde(default)]
+    pub localities: Vec<GeoLocalityRecord>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct GeoDataset {
+    pub subdivisions: Vec<GeoSubdivisionRecord>,
+}
+
+/// A dataset row left out of the import
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeoImportRejection {
+    pub code: String,
+    pub reason: String,
+}
+
+/// Outcome of an `ImportGeoDataCommand`, counting subdivisions and localities together.
+/// Rows already stored with the same names count as skipped without a rejection.
+#[derive(Debug, Clone, Default)]
+pub struct GeoImportReport {
+    pub created: usize,
+    pub updated: usize,
+    pub skipped: usize,
+    pub rejected: Vec<GeoImportRejection>,
+}
+
+impl GeoImportReport {
+    pub fn reject(&mut self, code: &str, reason: impl Into<String>) {
+        self.skipped += 1;
+        self.rejected.push(GeoImportRejection {
+            code: code.to_string(),
+            reason: reason.into(),
+        });
+    }
+}
+
+pub const DEFAULT_GEO_IMPORT_CHUNK_SIZE: usize = 200;
+
+/// Command to upsert country subdivisions and localities from an ISO 3166-2 /
+/// GeoNames dataset, matching existing rows on their codes.
+///
+/// Subdivisions are written in chunks of `chunk_size`, each chunk with its
+/// localities inside its own savepoint, so a bad row only costs its chunk a
+/// row-by-row retry. It needs the unit of work for those savepoints and is
+/// therefore run by the command executor rather than through `Command`.
+pub struct ImportGeoDataCommand {
+    pub dataset: GeoDataset,
+    pub chunk_size: usize,
+    pub audit_log_id: Uuid,
+}
+
+impl ImportGeoDataCommand {
+    pub fn new(dataset: GeoDataset, audit_log_id: Uuid) -> Self {
+        Self {
+            dataset,
+            chunk_size: DEFAULT_GEO_IMPORT_CHUNK_SIZE,
+            audit_log_id,
+        }
+    }
+}
+
 // #############################################################################
 // # Command: Add Person Of Interest
 // #############################################################################
@@ -100,6 +189,7 @@ impl Command for AddPersonOfInterestCommand {
 pub enum PersonCommand {
     AddPersonOfInterest(Box<AddPersonOfInterestCommand>),
     PopulateGeoData(PopulateGeoDataCommand),
+    ImportGeoData(ImportGeoDataCommand),
     // Add other commands here
 }
 
@@ -111,4 +201,13 @@ pub trait PersonCommandExecutor: Send + Sync {
         &self,
         command: PersonCommand,
     ) -> Result<CommandResult, BankingError>;
+
+    /// Executes the commands in order within a single unit of work.
+    /// By default the first failure rolls the whole batch back and is
+    /// returned. With `savepoint_per_command` a failing command is rolled
+    /// back to its savepoint and its error takes the place of its result.
+    async fn execute_all(
+        &self,
+        commands: Vec<PersonCommand>,
+    ) -> Result<Vec<Result<CommandResult, BankingError>>, BankingError>;
 }
\ No newline at end of file
@@ -6,6 +6,8 @@ use std::str::FromStr;
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::domain::CurrencyCode;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Account {
     pub id: Uuid,
@@ -13,9 +15,11 @@ pub struct Account {
     pub account_type: AccountType,
     pub account_status: AccountStatus,
     pub signing_condition: SigningCondition,
-    pub currency: HeaplessString<3>,
+    pub currency: CurrencyCode,
     pub open_date: NaiveDate,
     pub domicile_agency_branch_id: Uuid,
+    /// Bank-facing number: the compact IBAN where we issue IBANs, the BBAN otherwise
+    pub account_number: HeaplessString<34>,
     
     // Balance fields
     pub current_balance: Decimal,
@@ -92,6 +96,13 @@ pub struct Account {
     pub updated_by_person_id: Uuid,
 }
 
+/// Account with the codes of the tags attached to it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaggedAccountView {
+    pub account: Account,
+    pub tags: Vec<HeaplessString<50>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum AccountType { 
     Savings, 
@@ -223,6 +234,35 @@ impl Default for AccountStatusChangeRecord {
     }
 }
 
+
+/// What caused a change to an account's balances
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BalanceChangeSource {
+    Transaction,
+    InterestPosting,
+    FeeCharge,
+    LoanRepayment,
+    Reversal,
+    ManualAdjustment,
+}
+
+/// Balances before and after one balance update
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountBalanceChangeRecord {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub old_current_balance: Decimal,
+    pub new_current_balance: Decimal,
+    pub old_available_balance: Decimal,
+    pub new_available_balance: Decimal,
+    pub change_source: BalanceChangeSource,
+    /// References Transaction.id when the change comes from a posting
+    pub transaction_id: Option<Uuid>,
+    pub changed_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub changed_by_person_id: Uuid,
+}
+
 impl Account {
     /// Set product id
     pub fn set_product_id(&mut self, product_id: Uuid) {
@@ -245,6 +285,108 @@ impl Account {
     }
 }
 
+/// Provisioning category derived from the number of consecutive days an account
+/// has closed the day with a negative balance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ProvisioningBucket {
+    /// Account is in credit (zero overdrawn days)
+    Current,
+    Bucket1,
+    Bucket2,
+    Bucket3,
+    /// Beyond the last configured threshold
+    Bucket4,
+}
+
+/// Inclusive upper bounds, in consecutive overdrawn days, of the first three
+/// provisioning buckets. Defaults to 1-30, 31-60, 61-90 and 90+.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProvisioningThresholds {
+    pub bucket1_max_days: i32,
+    pub bucket2_max_days: i32,
+    pub bucket3_max_days: i32,
+}
+
+impl Default for ProvisioningThresholds {
+    fn default() -> Self {
+        Self {
+            bucket1_max_days: 30,
+            bucket2_max_days: 60,
+            bucket3_max_days: 90,
+        }
+    }
+}
+
+impl ProvisioningThresholds {
+    /// Classify an account by its consecutive overdrawn days
+    pub fn classify(&self, consecutive_overdrawn_days: i32) -> ProvisioningBucket {
+        match consecutive_overdrawn_days {
+            d if d <= 0 => ProvisioningBucket::Current,
+            d if d <= self.bucket1_max_days => ProvisioningBucket::Bucket1,
+            d if d <= self.bucket2_max_days => ProvisioningBucket::Bucket2,
+            d if d <= self.bucket3_max_days => ProvisioningBucket::Bucket3,
+            _ => ProvisioningBucket::Bucket4,
+        }
+    }
+}
+
+/// End-of-day balance snapshot carrying the overdrawn-day counter used for provisioning
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountBalanceSnapshot {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub snapshot_date: NaiveDate,
+    pub closing_balance: Decimal,
+    pub consecutive_overdrawn_days: i32,
+    pub provisioning_bucket: ProvisioningBucket,
+    /// Bucket of the previous snapshot, if any
+    pub previous_provisioning_bucket: Option<ProvisioningBucket>,
+    /// Set when the bucket differs from the previous snapshot; read by the risk dashboard
+    pub bucket_transition: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AccountBalanceSnapshot {
+    /// Build today's snapshot from the closing balance and the latest earlier snapshot.
+    /// The counter grows by the days elapsed since that snapshot while the balance stays
+    /// negative, so weekends and skipped runs still count, and resets once back in credit.
+    pub fn next(
+        account_id: Uuid,
+        snapshot_date: NaiveDate,
+        closing_balance: Decimal,
+        previous: Option<&AccountBalanceSnapshot>,
+        thresholds: &ProvisioningThresholds,
+    ) -> Self {
+        let consecutive_overdrawn_days = if closing_balance < Decimal::ZERO {
+            match previous {
+                Some(p) if p.closing_balance < Decimal::ZERO => {
+                    p.consecutive_overdrawn_days + (snapshot_date - p.snapshot_date).num_days() as i32
+                }
+                _ => 1,
+            }
+        } else {
+            0
+        };
+        let provisioning_bucket = thresholds.classify(consecutive_overdrawn_days);
+        let previous_provisioning_bucket = previous.map(|p| p.provisioning_bucket);
+        let bucket_transition = previous_provisioning_bucket
+            .unwrap_or(ProvisioningBucket::Current)
+            != provisioning_bucket;
+
+        Self {
+            id: Uuid::new_v4(),
+            account_id,
+            snapshot_date,
+            closing_balance,
+            consecutive_overdrawn_days,
+            provisioning_bucket,
+            previous_provisioning_bucket,
+            bucket_transition,
+            created_at: Utc::now(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -271,9 +413,10 @@ mod tests {
             account_type: AccountType::Savings,
             account_status: AccountStatus::Active,
             signing_condition: SigningCondition::None,
-            currency: HeaplessString::try_from("USD").unwrap(),
+            currency: CurrencyCode::try_from("USD").unwrap(),
             open_date: chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
             domicile_agency_branch_id: uuid::Uuid::new_v4(),
+            account_number: HeaplessString::try_from("10005000010000000004262").unwrap(),
             current_balance: rust_decimal::Decimal::new(10000, 2),
             available_balance: rust_decimal::Decimal::new(10000, 2),
             accrued_interest: rust_decimal::Decimal::ZERO,
@@ -383,6 +526,14 @@ mod tests {
         assert!(mem::size_of_val(&enum_status) <= 8); // Typically 1-8 bytes for enums
     }
 
+    #[test]
+    fn test_signing_condition_needs_a_matching_owner_count() {
+        assert!(SigningCondition::None.fits_owner_count(1));
+        assert!(!SigningCondition::None.fits_owner_count(2));
+        assert!(!SigningCondition::AnyOwner.fits_owner_count(1));
+        assert!(SigningCondition::AllOwners.fits_owner_count(3));
+    }
+
     #[test]
     fn test_disbursement_instruction_management() {
         let mut account = Account {
@@ -391,9 +542,10 @@ mod tests {
             account_type: AccountType::Loan,
             account_status: AccountStatus::Active,
             signing_condition: SigningCondition::None,
-            currency: HeaplessString::try_from("USD").unwrap(),
+            currency: CurrencyCode::try_from("USD").unwrap(),
             open_date: chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
             domicile_agency_branch_id: uuid::Uuid::new_v4(),
+            account_number: HeaplessString::try_from("10005000010000000004262").unwrap(),
             current_balance: rust_decimal::Decimal::ZERO,
             available_balance: rust_decimal::Decimal::ZERO,
             accrued_interest: rust_decimal::Decimal::ZERO,
@@ -471,6 +623,104 @@ mod tests {
         assert!(!account.has_disbursement_instruction());
         assert_eq!(account.get_last_disbursement_instruction(), None);
     }
+
+    fn snapshot_after(days: &[i64]) -> AccountBalanceSnapshot {
+        let thresholds = ProvisioningThresholds::default();
+        let account_id = Uuid::new_v4();
+        let start = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let mut previous: Option<AccountBalanceSnapshot> = None;
+        for (i, balance) in days.iter().enumerate() {
+            let date = start + chrono::Duration::days(i as i64);
+            let snapshot = AccountBalanceSnapshot::next(
+                account_id,
+                date,
+                Decimal::new(*balance, 0),
+                previous.as_ref(),
+                &thresholds,
+            );
+            previous = Some(snapshot);
+        }
+        previous.unwrap()
+    }
+
+    #[test]
+    fn test_overdrawn_counter_increments_and_resets() {
+        let snapshot = snapshot_after(&[-10, -20, -5]);
+        assert_eq!(snapshot.consecutive_overdrawn_days, 3);
+
+        // Back in credit resets the counter
+        let snapshot = snapshot_after(&[-10, -20, 0]);
+        assert_eq!(snapshot.consecutive_overdrawn_days, 0);
+        assert_eq!(snapshot.provisioning_bucket, ProvisioningBucket::Current);
+
+        // Overdrawn again after a reset starts from one
+        let snapshot = snapshot_after(&[-10, 15, -1]);
+        assert_eq!(snapshot.consecutive_overdrawn_days, 1);
+    }
+
+    #[test]
+    fn test_overdrawn_counter_counts_days_between_snapshots() {
+        let thresholds = ProvisioningThresholds::default();
+        let account_id = Uuid::new_v4();
+        let friday = chrono::NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+        let friday_snapshot =
+            AccountBalanceSnapshot::next(account_id, friday, Decimal::new(-10, 0), None, &thresholds);
+        assert_eq!(friday_snapshot.consecutive_overdrawn_days, 1);
+
+        // No run over the weekend: Monday still counts Saturday and Sunday
+        let monday = friday + chrono::Duration::days(3);
+        let monday_snapshot =
+            AccountBalanceSnapshot::next(account_id, monday, Decimal::new(-10, 0), Some(&friday_snapshot), &thresholds);
+        assert_eq!(monday_snapshot.consecutive_overdrawn_days, 4);
+
+        // Overdrawn again after a gap in credit starts from one
+        let credit_snapshot =
+            AccountBalanceSnapshot::next(account_id, monday + chrono::Duration::days(1), Decimal::ONE, Some(&monday_snapshot), &thresholds);
+        let snapshot = AccountBalanceSnapshot::next(
+            account_id,
+            monday + chrono::Duration::days(5),
+            Decimal::new(-1, 0),
+            Some(&credit_snapshot),
+            &thresholds,
+        );
+        assert_eq!(snapshot.consecutive_overdrawn_days, 1);
+    }
+
+    #[test]
+    fn test_provisioning_bucket_boundaries() {
+        let thresholds = ProvisioningThresholds::default();
+        assert_eq!(thresholds.classify(0), ProvisioningBucket::Current);
+        assert_eq!(thresholds.classify(1), ProvisioningBucket::Bucket1);
+        assert_eq!(thresholds.classify(30), ProvisioningBucket::Bucket1);
+        assert_eq!(thresholds.classify(31), ProvisioningBucket::Bucket2);
+        assert_eq!(thresholds.classify(60), ProvisioningBucket::Bucket2);
+        assert_eq!(thresholds.classify(61), ProvisioningBucket::Bucket3);
+        assert_eq!(thresholds.classify(90), ProvisioningBucket::Bucket3);
+        assert_eq!(thresholds.classify(91), ProvisioningBucket::Bucket4);
+    }
+
+    #[test]
+    fn test_bucket_transition_flag() {
+        // Entering overdraft moves Current -> Bucket1
+        let snapshot = snapshot_after(&[-1]);
+        assert!(snapshot.bucket_transition);
+
+        // Day 30 stays in Bucket1, day 31 crosses into Bucket2
+        let snapshot = snapshot_after(&[-1; 30]);
+        assert_eq!(snapshot.provisioning_bucket, ProvisioningBucket::Bucket1);
+        assert!(!snapshot.bucket_transition);
+        let snapshot = snapshot_after(&[-1; 31]);
+        assert_eq!(snapshot.provisioning_bucket, ProvisioningBucket::Bucket2);
+        assert_eq!(snapshot.previous_provisioning_bucket, Some(ProvisioningBucket::Bucket1));
+        assert!(snapshot.bucket_transition);
+
+        // Recovery is a transition back to Current
+        let mut days = vec![-1; 31];
+        days.push(100);
+        let snapshot = snapshot_after(&days);
+        assert_eq!(snapshot.provisioning_bucket, ProvisioningBucket::Current);
+        assert!(snapshot.bucket_transition);
+    }
 }
 
 // Account Relations Structs and Enums (moved from account_relations.rs)
@@ -613,6 +863,27 @@ impl std::fmt::Display for AccountType {
     }
 }
 
+impl AccountStatus {
+    /// Statuses an account may move to from this one
+    pub fn allowed_transitions(self) -> &'static [AccountStatus] {
+        use AccountStatus::*;
+
+        match self {
+            PendingApproval => &[Active, Closed],
+            Active => &[Dormant, Frozen, PendingClosure, Closed],
+            Dormant => &[Active, PendingReactivation, Closed],
+            Frozen => &[Active, Closed], // Only compliance can unfreeze
+            PendingReactivation => &[Active, Dormant],
+            PendingClosure => &[Closed, Active], // Can revert closure
+            Closed => &[], // Closed is terminal
+        }
+    }
+
+    pub fn can_transition_to(self, new: AccountStatus) -> bool {
+        self.allowed_transitions().contains(&new)
+    }
+}
+
 impl std::fmt::Display for AccountStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -627,6 +898,17 @@ impl std::fmt::Display for AccountStatus {
     }
 }
 
+impl SigningCondition {
+    /// Owners the account must have for this condition to make sense: a
+    /// sole owner signs alone, any-owner and all-owners need a joint account
+    pub fn fits_owner_count(&self, owners: usize) -> bool {
+        match self {
+            SigningCondition::None => owners == 1,
+            SigningCondition::AnyOwner | SigningCondition::AllOwners => owners >= 2,
+        }
+    }
+}
+
 impl std::fmt::Display for SigningCondition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -120,6 +120,14 @@ pub struct PlaceHoldRequest {
     pub source_reference: Option<HeaplessString<100>>,
 }
 
+impl HoldType {
+    /// Court-ordered holds attach to the funds whatever the balance, so
+    /// they may take the available balance below zero
+    pub fn may_overdraw(&self) -> bool {
+        matches!(self, HoldType::JudicialLien)
+    }
+}
+
 impl std::fmt::Display for HoldType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -0,0 +1,267 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Longest account number we store: the ISO 13616 maximum IBAN length
+pub const MAX_ACCOUNT_NUMBER_LENGTH: usize = 34;
+
+/// IBAN lengths of the countries where we issue IBANs, from the SWIFT IBAN registry
+const IBAN_LENGTHS: &[(&str, usize)] = &[
+    ("AT", 20),
+    ("BE", 16),
+    ("BF", 28),
+    ("BJ", 28),
+    ("CF", 27),
+    ("CG", 27),
+    ("CH", 21),
+    ("CI", 28),
+    ("CM", 27),
+    ("DE", 22),
+    ("ES", 24),
+    ("FR", 27),
+    ("GA", 27),
+    ("GB", 22),
+    ("GQ", 27),
+    ("IT", 27),
+    ("LU", 20),
+    ("MA", 28),
+    ("ML", 28),
+    ("NE", 28),
+    ("NL", 18),
+    ("PT", 25),
+    ("SN", 28),
+    ("TD", 27),
+    ("TG", 28),
+];
+
+/// How account numbers of a branch are built, optionally narrowed to one product:
+/// bank code, branch code, zero-padded serial and two national check digits,
+/// wrapped in an IBAN where the bank issues them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountNumberScheme {
+    pub id: Uuid,
+    /// References AgencyBranch.id; serials are sequenced per branch across its schemes
+    pub branch_id: Uuid,
+    /// References Product.id; None for the branch default
+    pub product_id: Option<Uuid>,
+    pub bank_code: HeaplessString<12>,
+    pub branch_code: HeaplessString<10>,
+    /// Digits of the serial, which is zero-padded to this length
+    pub serial_length: u8,
+    /// ISO 3166 country code of the IBAN; None to issue the BBAN alone
+    pub iban_country_code: Option<HeaplessString<2>>,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub updated_by_person_id: Uuid,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AccountNumberFormatError {
+    #[error("Invalid country code: {0}")]
+    InvalidCountryCode(String),
+    #[error("IBANs are not issued for country {0}")]
+    UnsupportedCountry(String),
+    #[error("Account numbers may only contain letters and digits: {0}")]
+    InvalidCharacters(String),
+    #[error("IBAN for {country_code} must be {expected} characters, got {actual}")]
+    InvalidLength {
+        country_code: String,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("Serial {serial} does not fit in {serial_length} digits")]
+    SerialOverflow { serial: i64, serial_length: u8 },
+}
+
+impl AccountNumberScheme {
+    /// Builds the account number of the given serial. The compact IBAN is
+    /// returned when the scheme has an IBAN country, the BBAN otherwise.
+    pub fn compose(&self, serial: i64) -> Result<HeaplessString<34>, AccountNumberFormatError> {
+        let width = self.serial_length as usize;
+        let serial_digits = format!("{serial:0width$}");
+        if serial < 0 || serial_digits.len() > width {
+            return Err(AccountNumberFormatError::SerialOverflow {
+                serial,
+                serial_length: self.serial_length,
+            });
+        }
+
+        let body = format!("{}{}{}", self.bank_code, self.branch_code, serial_digits).to_ascii_uppercase();
+        let check_digits = bban_check_digits(&body)?;
+        let bban = format!("{body}{check_digits:02}");
+
+        match &self.iban_country_code {
+            Some(country_code) => format_iban(country_code, &bban),
+            None => HeaplessString::try_from(bban.as_str()).map_err(|_| AccountNumberFormatError::InvalidLength {
+                country_code: String::new(),
+                expected: MAX_ACCOUNT_NUMBER_LENGTH,
+                actual: bban.len(),
+            }),
+        }
+    }
+}
+
+/// Length of the IBANs of a country, `None` where we do not issue IBANs
+pub fn iban_length(country_code: &str) -> Option<usize> {
+    IBAN_LENGTHS
+        .iter()
+        .find(|(code, _)| code.eq_ignore_ascii_case(country_code))
+        .map(|(_, length)| *length)
+}
+
+/// ISO 7064 MOD 97-10 remainder of an alphanumeric string, letters counting as 10 to 35
+fn mod97(value: &str) -> Result<u32, AccountNumberFormatError> {
+    value.chars().try_fold(0u32, |acc, c| match c.to_digit(36) {
+        Some(digit) if digit >= 10 => Ok((acc * 100 + digit) % 97),
+        Some(digit) => Ok((acc * 10 + digit) % 97),
+        None => Err(AccountNumberFormatError::InvalidCharacters(value.to_string())),
+    })
+}
+
+fn validate_country_code(country_code: &str) -> Result<String, AccountNumberFormatError> {
+    if country_code.len() != 2 || !country_code.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(AccountNumberFormatError::InvalidCountryCode(country_code.to_string()));
+    }
+    Ok(country_code.to_ascii_uppercase())
+}
+
+/// National check digits closing a BBAN: 98 minus the MOD 97-10 remainder of the body followed by 00
+pub fn bban_check_digits(body: &str) -> Result<u8, AccountNumberFormatError> {
+    Ok((98 - mod97(&format!("{body}00"))?) as u8)
+}
+
+/// ISO 13616 check digits of the IBAN of a BBAN
+pub fn iban_check_digits(country_code: &str, bban: &str) -> Result<u8, AccountNumberFormatError> {
+    let country = validate_country_code(country_code)?;
+    if bban.is_empty() || !bban.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(AccountNumberFormatError::InvalidCharacters(bban.to_string()));
+    }
+    Ok((98 - mod97(&format!("{}{country}00", bban.to_ascii_uppercase()))?) as u8)
+}
+
+/// Compact IBAN of a BBAN, checked against the IBAN length of the country
+pub fn format_iban(country_code: &str, bban: &str) -> Result<HeaplessString<34>, AccountNumberFormatError> {
+    let country = validate_country_code(country_code)?;
+    let expected = iban_length(&country).ok_or_else(|| AccountNumberFormatError::UnsupportedCountry(country.clone()))?;
+    let check_digits = iban_check_digits(&country, bban)?;
+
+    let iban = format!("{country}{check_digits:02}{}", bban.to_ascii_uppercase());
+    if iban.len() != expected {
+        return Err(AccountNumberFormatError::InvalidLength {
+            country_code: country,
+            expected,
+            actual: iban.len(),
+        });
+    }
+    HeaplessString::try_from(iban.as_str()).map_err(|_| AccountNumberFormatError::InvalidLength {
+        country_code: country,
+        expected,
+        actual: iban.len(),
+    })
+}
+
+/// Print form of a compact IBAN: blocks of four characters separated by spaces
+pub fn group_iban(iban: &str) -> String {
+    iban.as_bytes()
+        .chunks(4)
+        .map(|chunk| std::str::from_utf8(chunk).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Whether an IBAN, compact or grouped with spaces, has the length of its
+/// country and check digits giving a remainder of 1
+pub fn is_valid_iban(iban: &str) -> bool {
+    let compact: String = iban.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_ascii_uppercase();
+    if compact.len() < 4 || !compact.is_ascii() {
+        return false;
+    }
+    if iban_length(&compact[..2]) != Some(compact.len()) {
+        return false;
+    }
+    let rearranged = format!("{}{}", &compact[4..], &compact[..4]);
+    mod97(&rearranged) == Ok(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scheme(iban_country_code: Option<&str>) -> AccountNumberScheme {
+        AccountNumberScheme {
+            id: Uuid::new_v4(),
+            branch_id: Uuid::new_v4(),
+            product_id: None,
+            bank_code: HeaplessString::try_from("10005").unwrap(),
+            branch_code: HeaplessString::try_from("00001").unwrap(),
+            serial_length: 11,
+            iban_country_code: iban_country_code.map(|code| HeaplessString::try_from(code).unwrap()),
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn test_iban_check_digits_match_registry_examples() {
+        // Examples from the SWIFT IBAN registry and ISO 13616
+        assert_eq!(iban_check_digits("GB", "WEST12345698765432"), Ok(82));
+        assert_eq!(iban_check_digits("DE", "370400440532013000"), Ok(89));
+        assert_eq!(iban_check_digits("FR", "20041010050500013M02606"), Ok(14));
+        assert_eq!(iban_check_digits("BE", "539007547034"), Ok(68));
+        assert_eq!(iban_check_digits("NL", "ABNA0417164300"), Ok(91));
+
+        assert_eq!(
+            format_iban("de", "370400440532013000").unwrap().as_str(),
+            "DE89370400440532013000"
+        );
+        assert!(is_valid_iban("GB82 WEST 1234 5698 7654 32"));
+        assert!(is_valid_iban("FR1420041010050500013M02606"));
+        assert!(!is_valid_iban("GB83WEST12345698765432"));
+        assert!(!is_valid_iban("DE8937040044053201300"));
+        assert_eq!(group_iban("GB82WEST12345698765432"), "GB82 WEST 1234 5698 7654 32");
+    }
+
+    #[test]
+    fn test_format_iban_checks_country_and_length() {
+        assert_eq!(
+            format_iban("DE", "37040044053201300"),
+            Err(AccountNumberFormatError::InvalidLength {
+                country_code: "DE".to_string(),
+                expected: 22,
+                actual: 21,
+            })
+        );
+        assert_eq!(
+            format_iban("US", "123456789"),
+            Err(AccountNumberFormatError::UnsupportedCountry("US".to_string()))
+        );
+        assert!(matches!(format_iban("D1", "370400440532013000"), Err(AccountNumberFormatError::InvalidCountryCode(_))));
+        assert!(matches!(format_iban("DE", "3704-0044"), Err(AccountNumberFormatError::InvalidCharacters(_))));
+    }
+
+    #[test]
+    fn test_compose_builds_bban_and_iban() {
+        let bban = scheme(None).compose(42).unwrap();
+        assert_eq!(bban.len(), 23);
+        assert!(bban.as_str().starts_with("100050000100000000042"));
+        assert_eq!(mod97(bban.as_str()), Ok(1));
+
+        let iban = scheme(Some("CM")).compose(42).unwrap();
+        assert_eq!(iban.len(), 27);
+        assert_eq!(&iban.as_str()[4..], bban.as_str());
+        assert!(is_valid_iban(iban.as_str()));
+
+        // A French IBAN is 27 characters long, one more than this scheme gives
+        let mut french = scheme(Some("FR"));
+        french.serial_length = 10;
+        assert!(matches!(french.compose(42), Err(AccountNumberFormatError::InvalidLength { expected: 27, .. })));
+
+        assert_eq!(
+            scheme(None).compose(100_000_000_000),
+            Err(AccountNumberFormatError::SerialOverflow { serial: 100_000_000_000, serial_length: 11 })
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Commission terms for collection agents of one program or one territory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentCommissionScheme {
+    pub id: Uuid,
+    pub code: HeaplessString<50>,
+    /// References CollectionProgram.id; takes precedence over a territory scheme
+    pub collection_program_id: Option<Uuid>,
+    /// References CollectionAgent.assigned_territory_id
+    pub territory_id: Option<Uuid>,
+    /// Fraction of the processed amount paid as commission, e.g. 0.02
+    pub commission_rate: Decimal,
+    /// Collection rate the agent must exceed to earn the bonus
+    pub target_collection_rate: Option<Decimal>,
+    pub bonus_amount: Decimal,
+    /// Deducted for each CashDiscrepancy alert raised on the agent in the period
+    pub deduction_per_discrepancy: Decimal,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub updated_by_person_id: Uuid,
+}
+
+impl AgentCommissionScheme {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.code.is_empty() {
+            return Err("Scheme code is required".to_string());
+        }
+        if self.collection_program_id.is_some() == self.territory_id.is_some() {
+            return Err("Scheme must apply to either a program or a territory".to_string());
+        }
+        if self.commission_rate < Decimal::ZERO || self.commission_rate > Decimal::ONE {
+            return Err("Commission rate must be between 0 and 1".to_string());
+        }
+        if self.target_collection_rate.is_some_and(|t| t <= Decimal::ZERO || t > Decimal::ONE) {
+            return Err("Target collection rate must be between 0 and 1".to_string());
+        }
+        if self.bonus_amount < Decimal::ZERO || self.deduction_per_discrepancy < Decimal::ZERO {
+            return Err("Bonus and deduction cannot be negative".to_string());
+        }
+        if self.bonus_amount > Decimal::ZERO && self.target_collection_rate.is_none() {
+            return Err("Bonus requires a target collection rate".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn commission_on(&self, processed_amount: Decimal) -> Decimal {
+        (processed_amount * self.commission_rate).round_dp(2)
+    }
+
+    /// The bonus is only earned strictly above the target
+    pub fn earns_bonus(&self, collection_rate: Decimal) -> bool {
+        self.bonus_amount > Decimal::ZERO
+            && self.target_collection_rate.is_some_and(|target| collection_rate > target)
+    }
+
+    pub fn deduction_for(&self, discrepancy_count: u32) -> Decimal {
+        self.deduction_per_discrepancy * Decimal::from(discrepancy_count)
+    }
+}
+
+/// Calendar month commissions are calculated for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommissionPeriod {
+    pub year: i32,
+    pub month: u32,
+}
+
+impl CommissionPeriod {
+    pub fn containing(date: NaiveDate) -> Self {
+        Self { year: date.year(), month: date.month() }
+    }
+
+    /// First and last day of the month
+    pub fn bounds(&self) -> Result<(NaiveDate, NaiveDate), String> {
+        let start = NaiveDate::from_ymd_opt(self.year, self.month, 1)
+            .ok_or_else(|| format!("Invalid commission period {}-{}", self.year, self.month))?;
+        let next = match self.month {
+            12 => NaiveDate::from_ymd_opt(self.year + 1, 1, 1),
+            month => NaiveDate::from_ymd_opt(self.year, month + 1, 1),
+        }
+        .ok_or_else(|| format!("Invalid commission period {}-{}", self.year, self.month))?;
+        Ok((start, next.pred_opt().unwrap_or(start)))
+    }
+}
+
+/// An agent's collections of one program in a period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentCollectionTotals {
+    pub collection_agent_id: Uuid,
+    pub territory_id: Uuid,
+    pub collection_program_id: Uuid,
+    pub processed_count: i64,
+    pub processed_amount: Decimal,
+    /// All collections of the period except reversed ones
+    pub attempted_count: i64,
+}
+
+impl AgentCollectionTotals {
+    pub fn collection_rate(&self) -> Decimal {
+        if self.attempted_count == 0 {
+            return Decimal::ZERO;
+        }
+        Decimal::from(self.processed_count) / Decimal::from(self.attempted_count)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CommissionStatementStatus {
+    /// May be recalculated with force
+    Draft,
+    /// Paid out or billed; never recalculated
+    Invoiced,
+}
+
+/// Monthly commission statement of a collection agent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentCommissionStatement {
+    pub id: Uuid,
+    /// References CollectionAgent.id
+    pub collection_agent_id: Uuid,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub collected_amount: Decimal,
+    pub commission_amount: Decimal,
+    pub bonus_amount: Decimal,
+    pub deduction_amount: Decimal,
+    /// Negative when deductions exceed earnings
+    pub net_amount: Decimal,
+    pub discrepancy_count: u32,
+    pub status: CommissionStatementStatus,
+    pub calculated_at: DateTime<Utc>,
+    pub invoiced_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CommissionLineType {
+    Commission,
+    PerformanceBonus,
+    DiscrepancyDeduction,
+}
+
+/// One component of a commission statement; amounts are positive, deductions included
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentCommissionLineItem {
+    pub id: Uuid,
+    pub statement_id: Uuid,
+    pub line_type: CommissionLineType,
+    pub scheme_id: Uuid,
+    /// None for agent level lines such as discrepancy deductions
+    pub collection_program_id: Option<Uuid>,
+    pub description: HeaplessString<255>,
+    /// Amount the line was calculated on, e.g. the processed amount
+    pub basis_amount: Decimal,
+    pub amount: Decimal,
+}
+
+/// Statement with its line items, as shown in the agent app
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentCommissionStatementDetail {
+    pub statement: AgentCommissionStatement,
+    pub line_items: Vec<AgentCommissionLineItem>,
+}
+
+/// Result of a commission calculation run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentCommissionRun {
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub statements: Vec<AgentCommissionStatement>,
+    /// Draft statements replaced by a forced recalculation
+    pub replaced_statements: usize,
+    /// Agents with processed collections in programs no active scheme covers
+    pub agents_without_scheme: Vec<Uuid>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scheme() -> AgentCommissionScheme {
+        AgentCommissionScheme {
+            id: Uuid::new_v4(),
+            code: HeaplessString::try_from("DAILY_SAVINGS").unwrap(),
+            collection_program_id: Some(Uuid::new_v4()),
+            territory_id: None,
+            commission_rate: Decimal::new(2, 2),
+            target_collection_rate: Some(Decimal::new(9, 1)),
+            bonus_amount: Decimal::from(5000),
+            deduction_per_discrepancy: Decimal::from(1000),
+            is_active: true,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn test_scheme_scope_and_bonus_validation() {
+        assert!(scheme().validate().is_ok());
+
+        let mut both_scopes = scheme();
+        both_scopes.territory_id = Some(Uuid::new_v4());
+        assert!(both_scopes.validate().is_err());
+
+        let mut bonus_without_target = scheme();
+        bonus_without_target.target_collection_rate = None;
+        assert!(bonus_without_target.validate().is_err());
+    }
+
+    #[test]
+    fn test_commission_period_bounds() {
+        let (start, end) = CommissionPeriod { year: 2024, month: 2 }.bounds().unwrap();
+        assert_eq!(start, NaiveDate::from_ymd_opt(2024, 2, 1).unwrap());
+        assert_eq!(end, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+
+        let (_, end) = CommissionPeriod { year: 2024, month: 12 }.bounds().unwrap();
+        assert_eq!(end, NaiveDate::from_ymd_opt(2024, 12, 31).unwrap());
+        assert!(CommissionPeriod { year: 2024, month: 13 }.bounds().is_err());
+    }
+}
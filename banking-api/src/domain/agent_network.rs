@@ -45,6 +45,8 @@ pub struct AgencyBranch {
     // Operational details
     pub operating_hours_id: Uuid,
     pub holiday_plan_id: Uuid,
+    /// Holiday calendar used for business-day rolls on accounts domiciled here; `None` means the default calendar
+    pub calendar_id: Option<HeaplessString<10>>,
     pub temporary_closure_id: Option<Uuid>,
     
     // Contact information - individual messaging fields (up to 5 entries)
@@ -546,6 +548,7 @@ impl AgencyBranch {
             landmark_description: None,
             operating_hours_id: default_operating_hours_id,
             holiday_plan_id: Uuid::nil(), // Default to nil UUID
+            calendar_id: None,
             temporary_closure_id: None,
             messaging1_id: None,
             messaging1_type: None,
@@ -574,6 +577,30 @@ impl AgencyBranch {
     }
 }
 
+/// Accounts domiciled at one branch of a rollup
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BranchRollupNode {
+    pub agency_branch_id: Uuid,
+    pub parent_agency_branch_id: Option<Uuid>,
+    /// Levels below the branch the rollup was requested for
+    pub depth: i32,
+    pub account_count: i64,
+    pub total_balance: Decimal,
+    /// Totals of the branch and every branch below it
+    pub subtree_account_count: i64,
+    pub subtree_balance: Decimal,
+}
+
+/// Account counts and balances of a branch and its whole subtree
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BranchRollup {
+    pub agency_branch_id: Uuid,
+    pub account_count: i64,
+    pub total_balance: Decimal,
+    /// Every branch of the subtree, the requested branch first
+    pub nodes: Vec<BranchRollupNode>,
+}
+
 // Helper function for calculating distance between GPS coordinates
 pub fn calculate_distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
     // Haversine formula for calculating distance between two points on Earth
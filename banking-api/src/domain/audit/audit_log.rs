@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -14,3 +15,35 @@ pub struct AuditLog {
     pub updated_at: DateTime<Utc>,
     pub updated_by_person_id: Uuid,
 }
+
+/// Kinds of records whose history can be looked up in the audit log
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditEntityType {
+    Person,
+    Location,
+    EntityReference,
+}
+
+/// An audit log entry with the name of the person who wrote it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLogEntry {
+    pub audit_log: AuditLog,
+    /// None when the actor is not a known person
+    pub actor_display_name: Option<HeaplessString<100>>,
+}
+
+/// An audit log entry with its place in the hash chain, hashes hex-encoded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditChainEntry {
+    pub audit_log: AuditLog,
+    pub sequence_number: i64,
+    pub prev_hash: String,
+    pub record_hash: String,
+}
+
+/// A run of consecutive chain entries whose links were verified before export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditChainSegment {
+    pub entries: Vec<AuditChainEntry>,
+    pub verified_at: DateTime<Utc>,
+}
@@ -0,0 +1,111 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::{BankingError, BankingResult};
+
+/// Permission needed to post with a value date before the business date
+pub const BACK_DATE_POSTING_PERMISSION: &str = "BackDatePosting";
+
+/// Person posting a transaction with the permissions granted to them
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostingActor {
+    /// References Person.person_id
+    pub person_id: Uuid,
+    pub permissions: Vec<HeaplessString<50>>,
+}
+
+impl PostingActor {
+    pub fn has_permission(&self, permission: &str) -> bool {
+        self.permissions.iter().any(|p| p.as_str() == permission)
+    }
+
+    /// Days the value date lies before the business date when the actor may
+    /// post it. Postings older than `max_back_date_days` are rejected whatever
+    /// the permissions.
+    pub fn authorize_back_dating(
+        &self,
+        value_date: NaiveDate,
+        business_date: NaiveDate,
+        max_back_date_days: i64,
+    ) -> BankingResult<i64> {
+        let days_back = (business_date - value_date).num_days();
+        if days_back <= 0 {
+            return Err(BankingError::ValidationError {
+                field: "value_date".to_string(),
+                message: format!("Value date {value_date} is not before business date {business_date}"),
+            });
+        }
+        if days_back > max_back_date_days {
+            return Err(BankingError::BackDatedPostingTooOld {
+                value_date,
+                business_date,
+                max_back_date_days,
+            });
+        }
+        if !self.has_permission(BACK_DATE_POSTING_PERMISSION) {
+            return Err(BankingError::UnauthorizedOperation(format!(
+                "Posting with value date {value_date} requires permission {BACK_DATE_POSTING_PERMISSION}"
+            )));
+        }
+        Ok(days_back)
+    }
+}
+
+/// Entry of the back-dated postings register reviewed by the monthly audit
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackDatedPosting {
+    pub id: Uuid,
+    pub transaction_id: Uuid,
+    /// Account whose balance and accrued interest changed
+    pub account_id: Uuid,
+    pub value_date: NaiveDate,
+    pub business_date: NaiveDate,
+    pub days_back: i64,
+    /// Interest added to (or, if negative, taken off) the account's accrued
+    /// interest for the days between the value date and the business date
+    pub accrual_delta: Decimal,
+    /// References Person.person_id
+    pub posted_by_person_id: Uuid,
+    pub posted_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn actor(permissions: &[&str]) -> PostingActor {
+        PostingActor {
+            person_id: Uuid::new_v4(),
+            permissions: permissions.iter().map(|p| HeaplessString::try_from(*p).unwrap()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_back_dating_needs_permission_within_max_age() {
+        let business_date = NaiveDate::from_ymd_opt(2024, 6, 14).unwrap();
+        let value_date = NaiveDate::from_ymd_opt(2024, 6, 10).unwrap();
+
+        let teller = actor(&["PostTransaction"]);
+        assert!(matches!(
+            teller.authorize_back_dating(value_date, business_date, 30),
+            Err(BankingError::UnauthorizedOperation(_))
+        ));
+
+        let supervisor = actor(&["PostTransaction", BACK_DATE_POSTING_PERMISSION]);
+        assert_eq!(supervisor.authorize_back_dating(value_date, business_date, 30).unwrap(), 4);
+        assert_eq!(supervisor.authorize_back_dating(value_date, business_date, 4).unwrap(), 4);
+
+        // Too old even for a supervisor
+        assert!(matches!(
+            supervisor.authorize_back_dating(value_date, business_date, 3),
+            Err(BankingError::BackDatedPostingTooOld { max_back_date_days: 3, .. })
+        ));
+        assert!(matches!(
+            supervisor.authorize_back_dating(business_date, business_date, 30),
+            Err(BankingError::ValidationError { .. })
+        ));
+    }
+}
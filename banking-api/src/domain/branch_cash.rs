@@ -0,0 +1,329 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Insurance-driven limit on the cash a branch may hold
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BranchCashCeiling {
+    pub id: Uuid,
+    /// References AgencyBranch.id
+    pub agency_branch_id: Uuid,
+    pub ceiling_amount: Decimal,
+    /// Branch receiving the excess cash; None ships it to the central vault
+    pub excess_destination_branch_id: Option<Uuid>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub updated_by_person_id: Uuid,
+}
+
+impl BranchCashCeiling {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.ceiling_amount <= Decimal::ZERO {
+            return Err("Cash ceiling must be positive".to_string());
+        }
+        if self.excess_destination_branch_id == Some(self.agency_branch_id) {
+            return Err("Excess cash cannot be shipped to the branch itself".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Cash held by a branch: its terminal and teller positions plus the vault
+/// adjustments posted to it. Cash in transit belongs to neither branch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BranchCashPosition {
+    pub agency_branch_id: Uuid,
+    pub terminal_cash: Decimal,
+    pub vault_adjustments: Decimal,
+}
+
+impl BranchCashPosition {
+    pub fn total(&self) -> Decimal {
+        self.terminal_cash + self.vault_adjustments
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CashTransferStatus {
+    Requested,
+    InTransit,
+    Received,
+    Cancelled,
+}
+
+impl CashTransferStatus {
+    pub fn is_open(&self) -> bool {
+        matches!(self, CashTransferStatus::Requested | CashTransferStatus::InTransit)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CashTransferOrigin {
+    /// Raised by the EOD ceiling check
+    CeilingBreach,
+    Manual,
+}
+
+/// Shipment of vault cash from a branch to another branch or the central vault.
+/// Dispatch and receipt each need two different persons; the receiving
+/// persons cannot be the ones who dispatched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashTransferRequest {
+    pub id: Uuid,
+    /// References AgencyBranch.id
+    pub from_agency_branch_id: Uuid,
+    /// References AgencyBranch.id; None for the central vault
+    pub to_agency_branch_id: Option<Uuid>,
+    pub amount: Decimal,
+    pub status: CashTransferStatus,
+    pub origin: CashTransferOrigin,
+    /// References Person.person_id; None when raised by the EOD ceiling check
+    pub requested_by_person_id: Option<Uuid>,
+    pub requested_at: DateTime<Utc>,
+    pub dispatch_authorized_by_person_id: Option<Uuid>,
+    pub dispatch_confirmed_by_person_id: Option<Uuid>,
+    pub dispatched_at: Option<DateTime<Utc>>,
+    pub receipt_authorized_by_person_id: Option<Uuid>,
+    pub receipt_confirmed_by_person_id: Option<Uuid>,
+    pub received_at: Option<DateTime<Utc>>,
+    pub cancelled_by_person_id: Option<Uuid>,
+    pub cancellation_reason: Option<HeaplessString<200>>,
+    pub last_updated_at: DateTime<Utc>,
+}
+
+impl CashTransferRequest {
+    pub fn new(
+        from_agency_branch_id: Uuid,
+        to_agency_branch_id: Option<Uuid>,
+        amount: Decimal,
+        origin: CashTransferOrigin,
+        requested_by_person_id: Option<Uuid>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            from_agency_branch_id,
+            to_agency_branch_id,
+            amount,
+            status: CashTransferStatus::Requested,
+            origin,
+            requested_by_person_id,
+            requested_at: now,
+            dispatch_authorized_by_person_id: None,
+            dispatch_confirmed_by_person_id: None,
+            dispatched_at: None,
+            receipt_authorized_by_person_id: None,
+            receipt_confirmed_by_person_id: None,
+            received_at: None,
+            cancelled_by_person_id: None,
+            cancellation_reason: None,
+            last_updated_at: now,
+        }
+    }
+
+    /// Record a dispatch authorization. Returns true once the second
+    /// authorization puts the cash in transit.
+    pub fn authorize_dispatch(&mut self, person_id: Uuid, now: DateTime<Utc>) -> Result<bool, String> {
+        if self.status != CashTransferStatus::Requested {
+            return Err(format!("Transfer cannot be dispatched from status {:?}", self.status));
+        }
+        match self.dispatch_authorized_by_person_id {
+            None => {
+                self.dispatch_authorized_by_person_id = Some(person_id);
+                self.last_updated_at = now;
+                Ok(false)
+            }
+            Some(first) if first == person_id => {
+                Err("Dispatch must be confirmed by a second person".to_string())
+            }
+            Some(_) => {
+                self.dispatch_confirmed_by_person_id = Some(person_id);
+                self.dispatched_at = Some(now);
+                self.status = CashTransferStatus::InTransit;
+                self.last_updated_at = now;
+                Ok(true)
+            }
+        }
+    }
+
+    /// Record a receipt confirmation. Returns true once the second
+    /// confirmation completes the transfer.
+    pub fn confirm_receipt(&mut self, person_id: Uuid, now: DateTime<Utc>) -> Result<bool, String> {
+        if self.status != CashTransferStatus::InTransit {
+            return Err(format!("Transfer cannot be received from status {:?}", self.status));
+        }
+        if self.dispatch_authorized_by_person_id == Some(person_id)
+            || self.dispatch_confirmed_by_person_id == Some(person_id)
+        {
+            return Err("Receipt must be confirmed by persons other than the dispatchers".to_string());
+        }
+        match self.receipt_authorized_by_person_id {
+            None => {
+                self.receipt_authorized_by_person_id = Some(person_id);
+                self.last_updated_at = now;
+                Ok(false)
+            }
+            Some(first) if first == person_id => {
+                Err("Receipt must be confirmed by a second person".to_string())
+            }
+            Some(_) => {
+                self.receipt_confirmed_by_person_id = Some(person_id);
+                self.received_at = Some(now);
+                self.status = CashTransferStatus::Received;
+                self.last_updated_at = now;
+                Ok(true)
+            }
+        }
+    }
+
+    /// Only transfers not yet dispatched can be cancelled
+    pub fn cancel(&mut self, person_id: Uuid, reason: HeaplessString<200>, now: DateTime<Utc>) -> Result<(), String> {
+        if self.status != CashTransferStatus::Requested {
+            return Err(format!("Transfer cannot be cancelled from status {:?}", self.status));
+        }
+        self.status = CashTransferStatus::Cancelled;
+        self.cancelled_by_person_id = Some(person_id);
+        self.cancellation_reason = Some(reason);
+        self.last_updated_at = now;
+        Ok(())
+    }
+
+    /// Days in the current status: since dispatch when in transit, since the request otherwise
+    pub fn age_days(&self, as_of: NaiveDate) -> i64 {
+        let since = match (self.status, self.dispatched_at) {
+            (CashTransferStatus::InTransit, Some(dispatched_at)) => dispatched_at,
+            _ => self.requested_at,
+        };
+        (as_of - since.date_naive()).num_days().max(0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VaultAdjustmentType {
+    /// Cash leaving the sending branch; negative amount
+    TransferDispatch,
+    /// Cash arriving at the receiving branch or central vault; positive amount
+    TransferReceipt,
+}
+
+/// Vault cash movement posted to a branch position by a cash transfer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VaultAdjustment {
+    pub id: Uuid,
+    /// References AgencyBranch.id; None for the central vault
+    pub agency_branch_id: Option<Uuid>,
+    /// References CashTransferRequest.id
+    pub cash_transfer_request_id: Uuid,
+    pub adjustment_type: VaultAdjustmentType,
+    pub amount: Decimal,
+    pub posted_at: DateTime<Utc>,
+    /// References Person.person_id; the second authorizer of the step
+    pub posted_by_person_id: Uuid,
+}
+
+/// A branch holding more cash than its ceiling at the EOD check
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BranchCashCeilingBreach {
+    pub agency_branch_id: Uuid,
+    pub position: Decimal,
+    pub ceiling_amount: Decimal,
+    /// Transfers already requested from the branch and not yet dispatched
+    pub pending_transfer_amount: Decimal,
+    /// None when pending transfers already cover the excess
+    pub transfer_request_id: Option<Uuid>,
+}
+
+impl BranchCashCeilingBreach {
+    pub fn excess(&self) -> Decimal {
+        self.position - self.ceiling_amount
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BranchCashCeilingReport {
+    pub processing_date: NaiveDate,
+    pub branches_checked: usize,
+    pub breaches: Vec<BranchCashCeilingBreach>,
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum CashTransferAgingBucket {
+    UpToOneDay,
+    TwoToThreeDays,
+    FourToSevenDays,
+    OverSevenDays,
+}
+
+impl CashTransferAgingBucket {
+    pub fn for_age(age_days: i64) -> Self {
+        match age_days {
+            ..=1 => CashTransferAgingBucket::UpToOneDay,
+            2..=3 => CashTransferAgingBucket::TwoToThreeDays,
+            4..=7 => CashTransferAgingBucket::FourToSevenDays,
+            _ => CashTransferAgingBucket::OverSevenDays,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenCashTransfer {
+    pub transfer: CashTransferRequest,
+    pub age_days: i64,
+    pub aging_bucket: CashTransferAgingBucket,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashTransferAgingTotal {
+    pub status: CashTransferStatus,
+    pub aging_bucket: CashTransferAgingBucket,
+    pub transfer_count: usize,
+    pub amount: Decimal,
+}
+
+/// Requested and in-transit transfers, oldest first, with totals per status and age
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashTransferAgingReport {
+    pub as_of: NaiveDate,
+    pub transfers: Vec<OpenCashTransfer>,
+    pub totals: Vec<CashTransferAgingTotal>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dispatch_and_receipt_need_distinct_persons() {
+        let now = Utc::now();
+        let mut transfer = CashTransferRequest::new(
+            Uuid::new_v4(), None, Decimal::from(1_000_000), CashTransferOrigin::Manual, Some(Uuid::new_v4()), now,
+        );
+        let teller = Uuid::new_v4();
+        let manager = Uuid::new_v4();
+
+        assert!(!transfer.authorize_dispatch(teller, now).unwrap());
+        assert!(transfer.authorize_dispatch(teller, now).is_err());
+        assert!(transfer.authorize_dispatch(manager, now).unwrap());
+        assert_eq!(transfer.status, CashTransferStatus::InTransit);
+
+        assert!(transfer.confirm_receipt(manager, now).is_err());
+        let vault_officer = Uuid::new_v4();
+        assert!(!transfer.confirm_receipt(vault_officer, now).unwrap());
+        assert!(transfer.confirm_receipt(Uuid::new_v4(), now).unwrap());
+        assert_eq!(transfer.status, CashTransferStatus::Received);
+        assert!(transfer.cancel(teller, HeaplessString::new(), now).is_err());
+    }
+
+    #[test]
+    fn test_aging_buckets() {
+        assert_eq!(CashTransferAgingBucket::for_age(0), CashTransferAgingBucket::UpToOneDay);
+        assert_eq!(CashTransferAgingBucket::for_age(3), CashTransferAgingBucket::TwoToThreeDays);
+        assert_eq!(CashTransferAgingBucket::for_age(7), CashTransferAgingBucket::FourToSevenDays);
+        assert_eq!(CashTransferAgingBucket::for_age(8), CashTransferAgingBucket::OverSevenDays);
+    }
+}
@@ -0,0 +1,141 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{AccountType, FeeCategory};
+
+/// Part an account plays in a bundle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BundleComponentRole {
+    Current,
+    Savings,
+    MobileWallet,
+}
+
+/// Account opened as part of a bundle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleComponent {
+    pub role: BundleComponentRole,
+    /// References Product.id
+    pub product_id: Uuid,
+    pub account_type: AccountType,
+    /// Closing the account of a required component dissolves the bundle
+    pub required: bool,
+}
+
+/// Bundle pricing: a discount on one fee category of a component, granted
+/// while the account of another component stays Active
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleFeeAdjustment {
+    pub role: BundleComponentRole,
+    pub fee_category: FeeCategory,
+    /// 100 waives the fee
+    pub discount_percentage: Decimal,
+    pub while_active_role: BundleComponentRole,
+}
+
+/// Package of products opened together with shared pricing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductBundle {
+    pub id: Uuid,
+    pub bundle_code: HeaplessString<20>,
+    pub name: HeaplessString<100>,
+    /// Opened in this order
+    pub components: Vec<BundleComponent>,
+    pub fee_adjustments: Vec<BundleFeeAdjustment>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub updated_by_person_id: Uuid,
+}
+
+impl ProductBundle {
+    pub fn component(&self, role: BundleComponentRole) -> Option<&BundleComponent> {
+        self.components.iter().find(|c| c.role == role)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BundleStatus {
+    /// Component accounts are being opened
+    Opening,
+    Active,
+    /// A component account closed and the bundle pricing was withdrawn
+    Dissolved,
+    /// The opening failed and was compensated
+    Cancelled,
+}
+
+/// Bundle held by a customer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountBundle {
+    pub id: Uuid,
+    /// References ProductBundle.id
+    pub product_bundle_id: Uuid,
+    pub bundle_code: HeaplessString<20>,
+    pub customer_id: Uuid,
+    pub status: BundleStatus,
+    /// References Orchestration.id of the opening
+    pub orchestration_id: Uuid,
+    pub opened_at: DateTime<Utc>,
+    pub dissolved_at: Option<DateTime<Utc>>,
+    pub dissolution_reason: Option<HeaplessString<255>>,
+}
+
+/// Link between a customer bundle and one of its accounts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleAccount {
+    pub id: Uuid,
+    /// References AccountBundle.id
+    pub account_bundle_id: Uuid,
+    pub account_id: Uuid,
+    pub role: BundleComponentRole,
+    pub required: bool,
+    pub linked_at: DateTime<Utc>,
+    /// Set when the account closes
+    pub unlinked_at: Option<DateTime<Utc>>,
+}
+
+/// Bundle price read by the fee engine: the discount applies to the account's
+/// fees of the category while the dependency account is Active
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundlePricingMarker {
+    pub id: Uuid,
+    /// References AccountBundle.id
+    pub account_bundle_id: Uuid,
+    pub account_id: Uuid,
+    pub fee_category: FeeCategory,
+    pub discount_percentage: Decimal,
+    pub depends_on_account_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    /// Set when the bundle is re-priced; the account is charged standard fees again
+    pub removed_at: Option<DateTime<Utc>>,
+}
+
+impl BundlePricingMarker {
+    /// Fee after the discount, rounded to cents
+    pub fn apply(&self, fee_amount: Decimal) -> Decimal {
+        (fee_amount * (Decimal::ONE_HUNDRED - self.discount_percentage) / Decimal::ONE_HUNDRED).round_dp(2)
+    }
+}
+
+/// Customer bundle with its accounts and pricing markers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleView {
+    pub bundle: AccountBundle,
+    pub accounts: Vec<BundleAccount>,
+    pub pricing_markers: Vec<BundlePricingMarker>,
+}
+
+/// Effect of closing an account linked to a bundle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleClosureOutcome {
+    pub account_bundle_id: Uuid,
+    pub closed_account_id: Uuid,
+    pub dissolved: bool,
+    /// Remaining accounts whose bundle pricing was withdrawn
+    pub repriced_account_ids: Vec<Uuid>,
+}
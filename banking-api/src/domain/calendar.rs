@@ -1,4 +1,4 @@
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use heapless::String as HeaplessString;
@@ -14,6 +14,64 @@ pub enum Weekday {
     Sunday,
 }
 
+/// Calendar used when an account's branch does not name one of its own.
+/// Single-calendar callers keep working by passing this id.
+pub const DEFAULT_CALENDAR: &str = "DEFAULT";
+
+/// Weekend days of a calendar as a bitmask, Monday in bit 0 through Sunday in bit 6.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct WeekendMask(pub u8);
+
+impl WeekendMask {
+    pub const SATURDAY_SUNDAY: WeekendMask = WeekendMask(0b0110_0000);
+    pub const FRIDAY_SATURDAY: WeekendMask = WeekendMask(0b0011_0000);
+
+    pub fn from_weekdays(weekdays: &[chrono::Weekday]) -> Self {
+        WeekendMask(weekdays.iter().fold(0, |mask, day| mask | Self::bit(*day)))
+    }
+
+    pub fn contains(&self, weekday: chrono::Weekday) -> bool {
+        self.0 & Self::bit(weekday) != 0
+    }
+
+    pub fn is_weekend(&self, date: NaiveDate) -> bool {
+        self.contains(date.weekday())
+    }
+
+    /// Weekend days in Monday-first order.
+    pub fn weekdays(&self) -> Vec<chrono::Weekday> {
+        let mut day = chrono::Weekday::Mon;
+        let mut weekdays = Vec::new();
+        for _ in 0..7 {
+            if self.contains(day) {
+                weekdays.push(day);
+            }
+            day = day.succ();
+        }
+        weekdays
+    }
+
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.0 & 0b0111_1111 == 0b0111_1111 {
+            return Err("A calendar needs at least one business day per week");
+        }
+        if self.0 & !0b0111_1111 != 0 {
+            return Err("Weekend mask only has seven weekday bits");
+        }
+        Ok(())
+    }
+
+    fn bit(weekday: chrono::Weekday) -> u8 {
+        1 << weekday.num_days_from_monday()
+    }
+}
+
+impl Default for WeekendMask {
+    fn default() -> Self {
+        Self::SATURDAY_SUNDAY
+    }
+}
+
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WeekendDays {
@@ -38,6 +96,7 @@ pub struct WeekendDays {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BankHoliday {
     pub id: Uuid,
+    /// Calendar the holiday belongs to: a country (`CM`), a subdivision (`CM-NW`) or `DEFAULT`
     pub jurisdiction: HeaplessString<10>,
     pub holiday_date: NaiveDate,
     pub holiday_name: HeaplessString<50>,
@@ -337,4 +396,26 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), "Holiday date is required");
     }
+
+    #[test]
+    fn test_friday_saturday_weekend_mask() {
+        let mask = WeekendMask::from_weekdays(&[chrono::Weekday::Fri, chrono::Weekday::Sat]);
+        assert_eq!(mask, WeekendMask::FRIDAY_SATURDAY);
+
+        // 2024-03-15 is a Friday, 2024-03-17 a Sunday
+        let friday = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+        let sunday = NaiveDate::from_ymd_opt(2024, 3, 17).unwrap();
+        assert!(mask.is_weekend(friday));
+        assert!(!mask.is_weekend(sunday));
+        assert!(!WeekendMask::default().is_weekend(friday));
+        assert!(WeekendMask::default().is_weekend(sunday));
+        assert_eq!(mask.weekdays(), vec![chrono::Weekday::Fri, chrono::Weekday::Sat]);
+    }
+
+    #[test]
+    fn test_weekend_mask_validation() {
+        assert!(WeekendMask::SATURDAY_SUNDAY.validate().is_ok());
+        assert!(WeekendMask(0b0111_1111).validate().is_err());
+        assert!(WeekendMask(0b1000_0000).validate().is_err());
+    }
 }
\ No newline at end of file
@@ -6,6 +6,7 @@ use uuid::Uuid;
 use validator::Validate;
 
 use crate::domain::customer::{KycStatus, RiskRating};
+use crate::domain::product::DayCountConvention;
 use crate::domain::transaction::TransactionType;
 
 /// CASA (Current & Savings Account) specialized functionality
@@ -90,6 +91,120 @@ pub enum CompoundingFrequency {
     Quarterly,
 }
 
+
+/// Where a balance sits against the account's overdraft limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OverdraftPosition {
+    None,
+    /// Overdrawn within the limit
+    Authorized,
+    /// Overdrawn beyond the limit, or without one
+    Unauthorized,
+}
+
+/// Overdraft position of an account and how much of its limit is used
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountOverdraftStatus {
+    pub account_id: Uuid,
+    pub position: OverdraftPosition,
+    pub current_balance: Decimal,
+    pub overdraft_limit: Decimal,
+    /// Overdrawn amount up to the limit
+    pub authorized_amount: Decimal,
+    /// Overdrawn amount beyond the limit
+    pub unauthorized_amount: Decimal,
+    /// Overdrawn amount as a fraction of the limit; None without a limit
+    pub utilization: Option<Decimal>,
+}
+
+impl AccountOverdraftStatus {
+    pub fn of(account_id: Uuid, current_balance: Decimal, overdraft_limit: Option<Decimal>) -> Self {
+        let overdraft_limit = overdraft_limit.unwrap_or(Decimal::ZERO).max(Decimal::ZERO);
+        let overdrawn = (-current_balance).max(Decimal::ZERO);
+        let authorized_amount = overdrawn.min(overdraft_limit);
+        let unauthorized_amount = overdrawn - authorized_amount;
+        let position = if unauthorized_amount > Decimal::ZERO {
+            OverdraftPosition::Unauthorized
+        } else if authorized_amount > Decimal::ZERO {
+            OverdraftPosition::Authorized
+        } else {
+            OverdraftPosition::None
+        };
+
+        Self {
+            account_id,
+            position,
+            current_balance,
+            overdraft_limit,
+            authorized_amount,
+            unauthorized_amount,
+            utilization: (overdraft_limit > Decimal::ZERO).then(|| overdrawn / overdraft_limit),
+        }
+    }
+}
+
+/// Daily debit interest on an overdrawn balance, the part within the limit and
+/// the part beyond it accrued separately at their own rates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverdraftInterestAccrual {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub accrual_date: NaiveDate,
+    pub position: OverdraftPosition,
+    pub authorized_amount: Decimal,
+    pub authorized_rate: Decimal,
+    pub authorized_interest: Decimal,
+    pub unauthorized_amount: Decimal,
+    pub unauthorized_rate: Decimal,
+    pub unauthorized_interest: Decimal,
+    /// Days in a row, this one included, the account closed beyond its limit
+    pub consecutive_unauthorized_days: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl OverdraftInterestAccrual {
+    /// Accrual for `accrual_date` from the day's overdraft status and the
+    /// previous day's accrual. Rates are annual fractions; interest is unrounded.
+    pub fn next(
+        status: &AccountOverdraftStatus,
+        accrual_date: NaiveDate,
+        authorized_rate: Decimal,
+        unauthorized_rate: Decimal,
+        convention: DayCountConvention,
+        previous: Option<&OverdraftInterestAccrual>,
+    ) -> Self {
+        let consecutive_unauthorized_days = match status.position {
+            OverdraftPosition::Unauthorized => previous.map_or(0, |p| p.consecutive_unauthorized_days) + 1,
+            _ => 0,
+        };
+
+        Self {
+            id: Uuid::new_v4(),
+            account_id: status.account_id,
+            accrual_date,
+            position: status.position,
+            authorized_amount: status.authorized_amount,
+            authorized_rate,
+            authorized_interest: convention.daily_accrual(status.authorized_amount, authorized_rate, accrual_date),
+            unauthorized_amount: status.unauthorized_amount,
+            unauthorized_rate,
+            unauthorized_interest: convention.daily_accrual(status.unauthorized_amount, unauthorized_rate, accrual_date),
+            consecutive_unauthorized_days,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn total_interest(&self) -> Decimal {
+        self.authorized_interest + self.unauthorized_interest
+    }
+
+    /// True on the day the unauthorized streak reaches `alert_after_days`, so a
+    /// streak raises one alert however long it lasts
+    pub fn unauthorized_alert_due(&self, alert_after_days: i32) -> bool {
+        self.consecutive_unauthorized_days == alert_after_days
+    }
+}
+
 /// CASA account summary for comprehensive reporting
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CasaAccountSummary {
@@ -285,4 +400,72 @@ pub struct CreateOverdraftFacilityRequest {
     pub expiry_date: Option<NaiveDate>,
     pub security_required: bool,
     pub security_details: Option<HeaplessString<200>>,
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn march(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, day).unwrap()
+    }
+
+    #[test]
+    fn test_overdraft_status_positions() {
+        let account_id = Uuid::new_v4();
+        let limit = Some(Decimal::from(1_000));
+
+        let none = AccountOverdraftStatus::of(account_id, Decimal::from(50), limit);
+        assert_eq!(none.position, OverdraftPosition::None);
+        assert_eq!(none.utilization, Some(Decimal::ZERO));
+
+        let authorized = AccountOverdraftStatus::of(account_id, Decimal::from(-250), limit);
+        assert_eq!(authorized.position, OverdraftPosition::Authorized);
+        assert_eq!(authorized.utilization, Some(Decimal::new(25, 2)));
+
+        let unauthorized = AccountOverdraftStatus::of(account_id, Decimal::from(-1_200), limit);
+        assert_eq!(unauthorized.position, OverdraftPosition::Unauthorized);
+        assert_eq!(unauthorized.authorized_amount, Decimal::from(1_000));
+        assert_eq!(unauthorized.unauthorized_amount, Decimal::from(200));
+        assert_eq!(unauthorized.utilization, Some(Decimal::new(12, 1)));
+
+        // Without a limit any overdrawn amount is unauthorized
+        let no_limit = AccountOverdraftStatus::of(account_id, Decimal::from(-10), None);
+        assert_eq!(no_limit.position, OverdraftPosition::Unauthorized);
+        assert_eq!(no_limit.utilization, None);
+    }
+
+    #[test]
+    fn test_week_crossing_the_limit_accrues_two_buckets() {
+        let account_id = Uuid::new_v4();
+        let limit = Some(Decimal::from(1_000));
+        // 18.25% is 0.50 a day per 1,000.00 and 36.5% is 1.00
+        let (authorized_rate, unauthorized_rate) = (Decimal::new(1825, 4), Decimal::new(365, 3));
+        let closing_balances = [-400, -700, -900, -1_300, -1_500, -1_200, -800];
+
+        let mut accruals: Vec<OverdraftInterestAccrual> = Vec::new();
+        for (day, balance) in (1..).zip(closing_balances) {
+            let status = AccountOverdraftStatus::of(account_id, Decimal::from(balance), limit);
+            let accrual = OverdraftInterestAccrual::next(
+                &status,
+                march(day),
+                authorized_rate,
+                unauthorized_rate,
+                DayCountConvention::Actual365Fixed,
+                accruals.last(),
+            );
+            accruals.push(accrual);
+        }
+
+        let authorized: Decimal = accruals.iter().map(|a| a.authorized_interest).sum();
+        let unauthorized: Decimal = accruals.iter().map(|a| a.unauthorized_interest).sum();
+        assert_eq!(authorized, Decimal::new(290, 2));
+        assert_eq!(unauthorized, Decimal::new(100, 2));
+        assert_eq!(accruals[4].authorized_interest, Decimal::new(50, 2));
+        assert_eq!(accruals[4].unauthorized_interest, Decimal::new(50, 2));
+
+        let streak: Vec<i32> = accruals.iter().map(|a| a.consecutive_unauthorized_days).collect();
+        assert_eq!(streak, vec![0, 0, 0, 1, 2, 3, 0]);
+        let alerts: Vec<NaiveDate> = accruals.iter().filter(|a| a.unauthorized_alert_due(3)).map(|a| a.accrual_date).collect();
+        assert_eq!(alerts, vec![march(6)]);
+    }
+}
@@ -1,10 +1,13 @@
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Utc};
 use heapless::String as HeaplessString;
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::domain::CurrencyCode;
+use crate::error::{BankingError, BankingResult, LimitType};
+
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 pub struct Channel {
     pub id: Uuid,
@@ -31,6 +34,86 @@ pub enum ChannelStatus {
     Suspended,
 }
 
+/// Caps on what a customer may debit through one channel in one currency.
+/// Caps left unset do not apply; reaching a cap exactly is allowed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChannelLimit {
+    pub id: Uuid,
+    /// Channel code, as carried by transactions
+    pub channel_id: HeaplessString<50>,
+    pub currency: HeaplessString<3>,
+    pub per_transaction_max: Option<Decimal>,
+    pub daily_max: Option<Decimal>,
+    pub monthly_max: Option<Decimal>,
+    pub max_count_per_day: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ChannelLimit {
+    /// Refuses a debit of `amount` that would take the customer past a cap,
+    /// naming the first cap breached
+    pub fn check(&self, amount: Decimal, usage: &ChannelUsage) -> BankingResult<()> {
+        let exceeded = |limit: Decimal, attempted: Decimal, limit_type: LimitType| {
+            Err(BankingError::TransactionLimitExceeded { limit, attempted, limit_type })
+        };
+        if let Some(max) = self.per_transaction_max {
+            if amount > max {
+                return exceeded(max, amount, LimitType::PerTransaction);
+            }
+        }
+        if let Some(max) = self.max_count_per_day {
+            let attempted = usage.daily_count + 1;
+            if attempted > i64::from(max) {
+                return exceeded(Decimal::from(max), Decimal::from(attempted), LimitType::DailyCount);
+            }
+        }
+        if let Some(max) = self.daily_max {
+            let attempted = usage.daily_amount + amount;
+            if attempted > max {
+                return exceeded(max, attempted, LimitType::Daily);
+            }
+        }
+        if let Some(max) = self.monthly_max {
+            let attempted = usage.monthly_amount + amount;
+            if attempted > max {
+                return exceeded(max, attempted, LimitType::Monthly);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Customer's debits through a channel before the transaction being checked
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelUsage {
+    pub daily_amount: Decimal,
+    pub daily_count: i64,
+    pub monthly_amount: Decimal,
+}
+
+/// UTC calendar day and month to date around an instant. The day is the
+/// half-open range from `day_start` to `day_end`; the month runs from
+/// `month_start` to the same end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelUsageWindow {
+    pub month_start: DateTime<Utc>,
+    pub day_start: DateTime<Utc>,
+    pub day_end: DateTime<Utc>,
+}
+
+impl ChannelUsageWindow {
+    pub fn containing(at: DateTime<Utc>) -> Self {
+        let day = at.date_naive();
+        let day_start = day.and_time(NaiveTime::MIN).and_utc();
+        Self {
+            month_start: day.with_day(1).unwrap_or(day).and_time(NaiveTime::MIN).and_utc(),
+            day_start,
+            day_end: day_start + Duration::days(1),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChannelFee {
     pub id: Uuid,
@@ -64,21 +147,71 @@ pub struct ReconciliationReport {
     pub created_at: DateTime<Utc>,
 }
 
+/// A saved reconciliation report with the discrepancies found
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelReconciliationResult {
+    pub report: ReconciliationReport,
+    pub discrepancies: Vec<Discrepancy>,
+}
+
+/// A line of our side of the reconciliation that disagrees with the
+/// external settlement file. `expected_amount` is ours and `actual_amount`
+/// theirs in major units; a missing side counts as zero.
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 pub struct Discrepancy {
     pub id: Uuid,
     pub report_id: Uuid,
-    pub transaction_id: Uuid,
+    pub discrepancy_type: DiscrepancyType,
+    /// None when the external entry has no channel transaction
+    pub transaction_id: Option<Uuid>,
+    /// None when the channel transaction is missing from the external file
+    pub external_reference: Option<HeaplessString<100>>,
     pub description: HeaplessString<200>,
     pub expected_amount: Decimal,
     pub actual_amount: Decimal,
     pub difference: Decimal,
     pub resolved: bool,
     pub resolution_notes: Option<HeaplessString<500>>,
+    /// References Person.person_id
+    pub resolved_by: Option<Uuid>,
+    pub resolved_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiscrepancyType {
+    /// In the external file but not among our channel transactions
+    MissingOurs,
+    /// A channel transaction the external file does not list
+    MissingTheirs,
+    AmountMismatch,
+    /// The external file lists a reference more than once
+    Duplicate,
+}
+
+/// One line of a channel's external settlement file
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalSettlementEntry {
+    /// Matched against the transaction reference number, then its external reference
+    pub reference: HeaplessString<100>,
+    /// Major or minor units of the transaction currency, depending on the file
+    pub amount: Decimal,
+}
+
+impl ExternalSettlementEntry {
+    /// The entry amount in major units: whichever reading, as given or as minor
+    /// units, comes closer to the amount we hold
+    pub fn amount_in_major_units(&self, expected: Decimal, minor_units: u32) -> Decimal {
+        let from_minor_units = self.amount / Decimal::from(10u64.pow(minor_units));
+        if (from_minor_units - expected).abs() < (self.amount - expected).abs() {
+            from_minor_units
+        } else {
+            self.amount
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ReconciliationStatus {
     InProgress,
     Completed,
@@ -115,7 +248,7 @@ pub struct FeeItem {
     pub fee_type: ChannelFeeType,
     pub calculation_method: ChannelFeeCalculationMethod,
     pub fee_amount: Option<Decimal>, // For fixed fees
-    pub fee_percentage: Option<Decimal>, // For percentage-based fees
+    pub fee_percentage: Option<Decimal>, // Fraction of the amount, for percentage-based fees
     pub minimum_fee: Option<Decimal>,
     pub maximum_fee: Option<Decimal>,
     pub tier01_channel_fee_tier_id: Option<Uuid>,
@@ -170,7 +303,7 @@ pub enum ChannelFeeType {
 }
 
 /// Channel fee tier for tiered pricing structures
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChannelFeeTier {
     pub id: Uuid,
     pub fee_item_id: Uuid,
@@ -181,4 +314,323 @@ pub struct ChannelFeeTier {
     pub fee_percentage: Option<Decimal>,
     pub tier_order: i32,
     pub created_at: DateTime<Utc>,
-}
\ No newline at end of file
+}
+
+impl FeeSchedule {
+    /// Active schedules apply from their effective date until, but not on,
+    /// their expiry date
+    pub fn is_in_force(&self, on: NaiveDate) -> bool {
+        self.is_active && self.effective_date <= on && self.expiry_date.is_none_or(|expiry| on < expiry)
+    }
+}
+
+impl FeeItem {
+    /// Prices the item from its fields and, for a tiered item, the tiers given
+    pub fn calculation(&self, mut tiers: Vec<ChannelFeeTier>) -> BankingResult<FeeCalculation> {
+        let missing = |field: &str| BankingError::ValidationError {
+            field: field.to_string(),
+            message: format!("{:?} fee {} needs a {field}", self.calculation_method, self.fee_code),
+        };
+        if let (Some(min), Some(max)) = (self.minimum_fee, self.maximum_fee) {
+            if min > max {
+                return Err(BankingError::ValidationError {
+                    field: "minimum_fee".to_string(),
+                    message: format!("Fee {} has a minimum of {min} above its maximum of {max}", self.fee_code),
+                });
+            }
+        }
+        if !tiers.is_empty() && !matches!(self.calculation_method, ChannelFeeCalculationMethod::Tiered) {
+            return Err(BankingError::ValidationError {
+                field: "tiers".to_string(),
+                message: format!("{:?} fee {} cannot have tiers", self.calculation_method, self.fee_code),
+            });
+        }
+
+        match self.calculation_method {
+            ChannelFeeCalculationMethod::Fixed => Ok(FeeCalculation::Flat {
+                amount: self.fee_amount.ok_or_else(|| missing("fee_amount"))?,
+            }),
+            ChannelFeeCalculationMethod::Percentage => Ok(FeeCalculation::Percentage {
+                rate: self.fee_percentage.ok_or_else(|| missing("fee_percentage"))?,
+                min: self.minimum_fee,
+                max: self.maximum_fee,
+            }),
+            ChannelFeeCalculationMethod::Tiered => {
+                tiers.sort_by_key(|tier| tier.tier_order);
+                FeeCalculation::validate_tiers(&tiers)?;
+                Ok(FeeCalculation::Tiered { tiers, min: self.minimum_fee, max: self.maximum_fee })
+            }
+            ChannelFeeCalculationMethod::BalanceBased
+            | ChannelFeeCalculationMethod::RuleBased
+            | ChannelFeeCalculationMethod::Hybrid => Err(BankingError::ValidationError {
+                field: "calculation_method".to_string(),
+                message: format!("{:?} fee {} cannot be calculated", self.calculation_method, self.fee_code),
+            }),
+        }
+    }
+
+    /// Transaction codes or types (Credit, Debit) the item is restricted to;
+    /// an item naming none applies to every transaction
+    pub fn applies_to(&self, transaction_code: &str, transaction_type: &str) -> bool {
+        let restrictions: Vec<&str> = [
+            &self.applies_to_transaction_type_01, &self.applies_to_transaction_type_02,
+            &self.applies_to_transaction_type_03, &self.applies_to_transaction_type_04,
+            &self.applies_to_transaction_type_05, &self.applies_to_transaction_type_06,
+            &self.applies_to_transaction_type_07, &self.applies_to_transaction_type_08,
+            &self.applies_to_transaction_type_09, &self.applies_to_transaction_type_10,
+            &self.applies_to_transaction_type_11,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|restriction| restriction.as_str())
+        .collect();
+        restrictions.is_empty() || restrictions.iter().any(|r| *r == transaction_code || *r == transaction_type)
+    }
+}
+
+/// How a fee item prices an amount. Rates are fractions of the amount; the
+/// minimum and maximum bound the fee before it is rounded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FeeCalculation {
+    /// The same fee whatever the amount
+    Flat { amount: Decimal },
+    /// A share of the amount
+    Percentage { rate: Decimal, min: Option<Decimal>, max: Option<Decimal> },
+    /// The whole amount is priced by the tier it falls in: a tier takes
+    /// amounts above its minimum up to and including its maximum, the first
+    /// one from zero. Tiers are in tier order.
+    Tiered { tiers: Vec<ChannelFeeTier>, min: Option<Decimal>, max: Option<Decimal> },
+}
+
+impl FeeCalculation {
+    /// Fee on `amount`, rounded half to even to the minor units of the currency
+    pub fn calculate(&self, amount: Decimal, currency: &CurrencyCode) -> BankingResult<Decimal> {
+        let (fee, min, max) = match self {
+            FeeCalculation::Flat { amount: fee } => (*fee, None, None),
+            FeeCalculation::Percentage { rate, min, max } => (amount * rate, *min, *max),
+            FeeCalculation::Tiered { tiers, min, max } => {
+                let tier = tiers
+                    .iter()
+                    .find(|tier| tier.max_amount.is_none_or(|upper| amount <= upper))
+                    .ok_or_else(|| BankingError::ValidationError {
+                        field: "amount".to_string(),
+                        message: format!("No fee tier covers {amount}"),
+                    })?;
+                let fee = tier.fee_amount.unwrap_or_default() + amount * tier.fee_percentage.unwrap_or_default();
+                (fee, *min, *max)
+            }
+        };
+        let fee = min.map_or(fee, |min| fee.max(min));
+        let fee = max.map_or(fee, |max| fee.min(max));
+        Ok(fee.round_dp_with_strategy(currency.minor_units(), RoundingStrategy::MidpointNearestEven))
+    }
+
+    /// Tiers, in tier order, must start at zero and follow on from one another
+    /// without gap or overlap; only the last may be open-ended. Each tier
+    /// charges an amount, a percentage or both.
+    pub fn validate_tiers(tiers: &[ChannelFeeTier]) -> BankingResult<()> {
+        let invalid = |message: String| Err(BankingError::ValidationError { field: "tiers".to_string(), message });
+        let Some(first) = tiers.first() else {
+            return invalid("A tiered fee needs at least one tier".to_string());
+        };
+        if first.min_amount != Decimal::ZERO {
+            return invalid(format!("First tier {} starts at {} instead of zero", first.tier_name, first.min_amount));
+        }
+        for tier in tiers {
+            if tier.max_amount.is_some_and(|max| max <= tier.min_amount) {
+                return invalid(format!("Tier {} ends at or below where it starts", tier.tier_name));
+            }
+            if tier.fee_amount.is_none() && tier.fee_percentage.is_none() {
+                return invalid(format!("Tier {} charges neither an amount nor a percentage", tier.tier_name));
+            }
+        }
+        for pair in tiers.windows(2) {
+            let (previous, next) = (&pair[0], &pair[1]);
+            match previous.max_amount {
+                None => return invalid(format!("Open-ended tier {} is followed by {}", previous.tier_name, next.tier_name)),
+                Some(max) if next.min_amount < max => {
+                    return invalid(format!("Tier {} overlaps tier {} below {max}", next.tier_name, previous.tier_name));
+                }
+                Some(max) if next.min_amount > max => {
+                    return invalid(format!("Tiers {} and {} leave a gap from {max} to {}", previous.tier_name, next.tier_name, next.min_amount));
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    /// Mobile: 500k per transaction, 2M a day, 5M a month, 10 transactions a day
+    fn mobile_limit() -> ChannelLimit {
+        ChannelLimit {
+            id: Uuid::new_v4(),
+            channel_id: HeaplessString::try_from("MOBILE").unwrap(),
+            currency: HeaplessString::try_from("XAF").unwrap(),
+            per_transaction_max: Some(Decimal::from(500_000)),
+            daily_max: Some(Decimal::from(2_000_000)),
+            monthly_max: Some(Decimal::from(5_000_000)),
+            max_count_per_day: Some(10),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn limit_type(result: BankingResult<()>) -> Option<LimitType> {
+        match result {
+            Err(BankingError::TransactionLimitExceeded { limit_type, .. }) => Some(limit_type),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_limits_allow_exact_boundary_and_refuse_beyond() {
+        let limit = mobile_limit();
+        let usage = ChannelUsage {
+            daily_amount: Decimal::from(1_500_000),
+            daily_count: 9,
+            monthly_amount: Decimal::from(4_000_000),
+        };
+        assert!(limit.check(Decimal::from(500_000), &usage).is_ok());
+        assert!(matches!(
+            limit_type(limit.check(Decimal::new(50_000_001, 2), &usage)),
+            Some(LimitType::PerTransaction)
+        ));
+
+        let tenth_today = ChannelUsage { daily_amount: Decimal::from(1_999_999), ..usage };
+        assert!(limit.check(Decimal::ONE, &tenth_today).is_ok());
+        assert!(matches!(limit_type(limit.check(Decimal::TWO, &tenth_today)), Some(LimitType::Daily)));
+
+        let eleventh_today = ChannelUsage { daily_count: 10, ..usage };
+        assert!(matches!(
+            limit.check(Decimal::ONE, &eleventh_today),
+            Err(BankingError::TransactionLimitExceeded { limit_type: LimitType::DailyCount, limit, attempted })
+                if limit == Decimal::from(10) && attempted == Decimal::from(11)
+        ));
+
+        let month_end = ChannelUsage { daily_amount: Decimal::ZERO, daily_count: 0, monthly_amount: Decimal::from(4_600_000) };
+        assert!(limit.check(Decimal::from(400_000), &month_end).is_ok());
+        assert!(matches!(
+            limit_type(limit.check(Decimal::from(400_001), &month_end)),
+            Some(LimitType::Monthly)
+        ));
+    }
+
+    #[test]
+    fn test_usage_window_crosses_midnight_into_new_day_and_month() {
+        let before_midnight = ChannelUsageWindow::containing(Utc.with_ymd_and_hms(2024, 3, 31, 23, 59, 59).unwrap());
+        let after_midnight = ChannelUsageWindow::containing(Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap());
+
+        assert_eq!(before_midnight.day_start, Utc.with_ymd_and_hms(2024, 3, 31, 0, 0, 0).unwrap());
+        assert_eq!(before_midnight.day_end, after_midnight.day_start);
+        assert_eq!(before_midnight.month_start, Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap());
+        assert_eq!(after_midnight.month_start, after_midnight.day_start);
+        assert_eq!(after_midnight.day_end, Utc.with_ymd_and_hms(2024, 4, 2, 0, 0, 0).unwrap());
+    }
+
+    fn tier(order: i32, min_amount: i64, max_amount: Option<i64>, fee_percentage: &str) -> ChannelFeeTier {
+        ChannelFeeTier {
+            id: Uuid::new_v4(),
+            fee_item_id: Uuid::nil(),
+            tier_name: HeaplessString::try_from(format!("T{order}").as_str()).unwrap(),
+            min_amount: Decimal::from(min_amount),
+            max_amount: max_amount.map(Decimal::from),
+            fee_amount: None,
+            fee_percentage: Some(fee_percentage.parse().unwrap()),
+            tier_order: order,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// 1% up to 100k, 0.5% above, never below 100 nor above 5000
+    fn tiered_fee() -> FeeCalculation {
+        FeeCalculation::Tiered {
+            tiers: vec![tier(1, 0, Some(100_000), "0.01"), tier(2, 100_000, None, "0.005")],
+            min: Some(Decimal::from(100)),
+            max: Some(Decimal::from(5_000)),
+        }
+    }
+
+    fn currency(code: &str) -> CurrencyCode {
+        code.parse().unwrap()
+    }
+
+    #[test]
+    fn test_tiered_fee_golden_amounts_at_each_edge() {
+        let fee = tiered_fee();
+        let usd = currency("USD");
+        let golden = [
+            ("0", "100"),
+            ("9999.99", "100"),
+            ("10000", "100"),
+            ("10000.01", "100"),
+            ("10001", "100.01"),
+            ("100000", "1000"),
+            ("100000.01", "500"),
+            ("200000", "1000"),
+            ("999998", "4999.99"),
+            ("1000000", "5000"),
+            ("1000000.01", "5000"),
+            ("25000000", "5000"),
+        ];
+        for (amount, expected) in golden {
+            let amount: Decimal = amount.parse().unwrap();
+            assert_eq!(fee.calculate(amount, &usd).unwrap(), expected.parse::<Decimal>().unwrap(), "fee on {amount}");
+        }
+    }
+
+    #[test]
+    fn test_fees_round_half_to_even_in_minor_units() {
+        let xaf = currency("XAF");
+        // 0.5% of 100,100 and 100,300 land on half a franc
+        assert_eq!(tiered_fee().calculate(Decimal::from(100_100), &xaf).unwrap(), Decimal::from(500));
+        assert_eq!(tiered_fee().calculate(Decimal::from(100_300), &xaf).unwrap(), Decimal::from(502));
+
+        let percentage = FeeCalculation::Percentage { rate: "0.0125".parse().unwrap(), min: None, max: None };
+        assert_eq!(percentage.calculate("10.20".parse().unwrap(), &currency("USD")).unwrap(), "0.13".parse::<Decimal>().unwrap());
+        assert_eq!(percentage.calculate(Decimal::from(1_000), &currency("KWD")).unwrap(), "12.500".parse::<Decimal>().unwrap());
+
+        let flat = FeeCalculation::Flat { amount: Decimal::from(250) };
+        assert_eq!(flat.calculate(Decimal::from(5_000_000), &xaf).unwrap(), Decimal::from(250));
+    }
+
+    #[test]
+    fn test_tiers_must_be_contiguous_and_non_overlapping() {
+        let message = |tiers: &[ChannelFeeTier]| match FeeCalculation::validate_tiers(tiers) {
+            Err(BankingError::ValidationError { message, .. }) => message,
+            other => panic!("Expected invalid tiers, got {other:?}"),
+        };
+        assert!(FeeCalculation::validate_tiers(&[tier(1, 0, Some(100_000), "0.01"), tier(2, 100_000, None, "0.005")]).is_ok());
+
+        assert!(message(&[]).contains("at least one tier"));
+        assert!(message(&[tier(1, 10, None, "0.01")]).contains("instead of zero"));
+        assert!(message(&[tier(1, 0, Some(100_000), "0.01"), tier(2, 90_000, None, "0.005")]).contains("overlaps"));
+        assert!(message(&[tier(1, 0, Some(100_000), "0.01"), tier(2, 110_000, None, "0.005")]).contains("gap"));
+        assert!(message(&[tier(1, 0, None, "0.01"), tier(2, 100_000, None, "0.005")]).contains("Open-ended"));
+        assert!(message(&[tier(1, 0, Some(0), "0.01")]).contains("at or below"));
+        let free = ChannelFeeTier { fee_percentage: None, ..tier(1, 0, None, "0") };
+        assert!(message(&[free]).contains("neither"));
+    }
+
+    #[test]
+    fn test_settlement_amount_read_as_minor_units_when_closer() {
+        let entry = |amount: i64| ExternalSettlementEntry {
+            reference: HeaplessString::try_from("REF-1").unwrap(),
+            amount: Decimal::from(amount),
+        };
+        let expected = Decimal::new(150_000, 2);
+
+        assert_eq!(entry(1_500).amount_in_major_units(expected, 2), expected);
+        assert_eq!(entry(150_000).amount_in_major_units(expected, 2), expected);
+        assert_eq!(entry(140_000).amount_in_major_units(expected, 2), Decimal::from(1_400));
+        assert_eq!(entry(1_600).amount_in_major_units(expected, 2), Decimal::from(1_600));
+        // Currencies without a minor unit read the amount as given
+        assert_eq!(entry(25_000).amount_in_major_units(Decimal::from(25_000), 0), Decimal::from(25_000));
+    }
+
+}
@@ -0,0 +1,192 @@
+use chrono::{DateTime, Duration, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::Severity;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChannelSecurityEventType {
+    FailedAuth,
+    DeviceChange,
+    SimSwapSuspected,
+    PasswordReset,
+}
+
+/// Security-relevant event observed by a channel, e.g. a failed PIN attempt
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelSecurityEvent {
+    pub id: Uuid,
+    /// References Channel.id
+    pub channel_id: Uuid,
+    /// None when the channel could not identify the customer
+    pub customer_id: Option<Uuid>,
+    pub event_type: ChannelSecurityEventType,
+    pub severity: Severity,
+    /// Channel specific details such as device fingerprint or terminal id
+    pub context: Option<serde_json::Value>,
+    pub occurred_at: DateTime<Utc>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Velocity rule over security events of one customer, e.g. 5 FailedAuth in 10 minutes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecurityVelocityRule {
+    pub code: HeaplessString<50>,
+    pub event_type: ChannelSecurityEventType,
+    pub threshold: u32,
+    pub window_minutes: u32,
+    /// Raise a VelocityCheck compliance alert of this severity
+    pub alert_severity: Option<Severity>,
+    /// Restrict the customer on the event's channel for this long
+    pub restriction_minutes: Option<u32>,
+    pub is_active: bool,
+}
+
+impl SecurityVelocityRule {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.code.is_empty() {
+            return Err("Rule code is required".to_string());
+        }
+        if self.threshold == 0 || self.window_minutes == 0 {
+            return Err("Threshold and window must be positive".to_string());
+        }
+        if self.alert_severity.is_none() && self.restriction_minutes.is_none() {
+            return Err("Rule must raise an alert or restrict the channel".to_string());
+        }
+        if self.restriction_minutes == Some(0) {
+            return Err("Restriction duration must be positive".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn window_start(&self, occurred_at: DateTime<Utc>) -> DateTime<Utc> {
+        occurred_at - Duration::minutes(self.window_minutes as i64)
+    }
+
+    /// Fires once per burst: when an event brings the count within the window
+    /// to the threshold, not again for each further event of the burst
+    pub fn fires_on(&self, events_in_window: u64) -> bool {
+        self.is_active && events_in_window == self.threshold as u64
+    }
+}
+
+/// Temporary block of one channel for a customer, e.g. no mobile banking
+/// after repeated failed logins
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelRestriction {
+    pub id: Uuid,
+    pub customer_id: Uuid,
+    /// References Channel.id
+    pub channel_id: Uuid,
+    pub reason: HeaplessString<255>,
+    /// Code of the velocity rule that imposed the restriction
+    pub rule_code: Option<HeaplessString<50>>,
+    pub starts_at: DateTime<Utc>,
+    /// The restriction lapses on its own at this time; None holds it until lifted
+    pub expires_at: Option<DateTime<Utc>>,
+    pub lifted_at: Option<DateTime<Utc>>,
+    /// References Person.person_id
+    pub lifted_by_person_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ChannelRestriction {
+    pub fn is_active_at(&self, at: DateTime<Utc>) -> bool {
+        self.lifted_at.is_none()
+            && self.starts_at <= at
+            && self.expires_at.is_none_or(|expires_at| at < expires_at)
+    }
+}
+
+/// How long security events are kept
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SecurityEventRetention {
+    pub retention_days: u32,
+}
+
+impl SecurityEventRetention {
+    /// Events must at least cover the longest velocity window
+    pub const MIN_RETENTION_DAYS: u32 = 30;
+
+    pub fn cutoff(&self, as_of: DateTime<Utc>) -> DateTime<Utc> {
+        as_of - Duration::days(self.retention_days.max(Self::MIN_RETENTION_DAYS) as i64)
+    }
+}
+
+impl Default for SecurityEventRetention {
+    fn default() -> Self {
+        Self { retention_days: 365 }
+    }
+}
+
+/// Result of ingesting a security event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecurityEventOutcome {
+    pub event: ChannelSecurityEvent,
+    pub fired_rule_codes: Vec<HeaplessString<50>>,
+    pub compliance_alert_ids: Vec<Uuid>,
+    pub restrictions: Vec<ChannelRestriction>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failed_auth_rule() -> SecurityVelocityRule {
+        SecurityVelocityRule {
+            code: HeaplessString::try_from("FAILED_AUTH_BURST").unwrap(),
+            event_type: ChannelSecurityEventType::FailedAuth,
+            threshold: 5,
+            window_minutes: 10,
+            alert_severity: Some(Severity::High),
+            restriction_minutes: Some(30),
+            is_active: true,
+        }
+    }
+
+    #[test]
+    fn test_rule_fires_once_per_burst() {
+        let rule = failed_auth_rule();
+        assert!(!rule.fires_on(4));
+        assert!(rule.fires_on(5));
+        assert!(!rule.fires_on(6));
+
+        let mut inactive = failed_auth_rule();
+        inactive.is_active = false;
+        assert!(!inactive.fires_on(5));
+    }
+
+    #[test]
+    fn test_rule_requires_an_action() {
+        assert!(failed_auth_rule().validate().is_ok());
+
+        let mut no_action = failed_auth_rule();
+        no_action.alert_severity = None;
+        no_action.restriction_minutes = None;
+        assert!(no_action.validate().is_err());
+    }
+
+    #[test]
+    fn test_restriction_expires_on_its_own() {
+        let now = Utc::now();
+        let mut restriction = ChannelRestriction {
+            id: Uuid::new_v4(),
+            customer_id: Uuid::new_v4(),
+            channel_id: Uuid::new_v4(),
+            reason: HeaplessString::try_from("Repeated failed logins").unwrap(),
+            rule_code: None,
+            starts_at: now,
+            expires_at: Some(now + Duration::minutes(30)),
+            lifted_at: None,
+            lifted_by_person_id: None,
+            created_at: now,
+        };
+
+        assert!(restriction.is_active_at(now + Duration::minutes(29)));
+        assert!(!restriction.is_active_at(now + Duration::minutes(30)));
+
+        restriction.lifted_at = Some(now);
+        assert!(!restriction.is_active_at(now + Duration::minutes(1)));
+    }
+}
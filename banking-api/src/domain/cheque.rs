@@ -0,0 +1,253 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::BankingError;
+
+/// A book of consecutively numbered cheque leaves issued on a current account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChequeBook {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub first_serial_number: i64,
+    pub last_serial_number: i64,
+    pub status: ChequeBookStatus,
+    pub requested_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub requested_by_person_id: Uuid,
+    pub issued_at: Option<DateTime<Utc>>,
+}
+
+impl ChequeBook {
+    pub fn leaf_count(&self) -> i64 {
+        self.last_serial_number - self.first_serial_number + 1
+    }
+
+    pub fn contains(&self, serial_number: i64) -> bool {
+        (self.first_serial_number..=self.last_serial_number).contains(&serial_number)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChequeBookStatus {
+    Requested,
+    Issued,
+    Cancelled,
+}
+
+/// A single cheque leaf and its clearing lifecycle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cheque {
+    pub id: Uuid,
+    pub cheque_book_id: Uuid,
+    pub account_id: Uuid,
+    pub serial_number: i64,
+    pub status: ChequeStatus,
+    pub amount: Option<Decimal>,
+    pub presented_at: Option<DateTime<Utc>>,
+    pub paid_at: Option<DateTime<Utc>>,
+    /// References Transaction.id of the posted debit
+    pub transaction_id: Option<Uuid>,
+    pub return_reason: Option<ChequeReturnReason>,
+    pub stop_reason: Option<HeaplessString<200>>,
+    pub stopped_at: Option<DateTime<Utc>>,
+    /// References Person.person_id
+    pub stopped_by_person_id: Option<Uuid>,
+    pub last_updated_at: DateTime<Utc>,
+}
+
+impl Cheque {
+    pub fn new_leaf(cheque_book_id: Uuid, account_id: Uuid, serial_number: i64, now: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            cheque_book_id,
+            account_id,
+            serial_number,
+            status: ChequeStatus::Unused,
+            amount: None,
+            presented_at: None,
+            paid_at: None,
+            transaction_id: None,
+            return_reason: None,
+            stop_reason: None,
+            stopped_at: None,
+            stopped_by_person_id: None,
+            last_updated_at: now,
+        }
+    }
+
+    /// Accept the cheque for payment. A bounced cheque may be presented again.
+    pub fn present(&mut self, amount: Decimal, now: DateTime<Utc>) -> Result<(), ChequeReturnReason> {
+        match self.status {
+            ChequeStatus::Stopped => return Err(ChequeReturnReason::PaymentStopped),
+            ChequeStatus::Presented | ChequeStatus::Paid => return Err(ChequeReturnReason::DuplicatePresentment),
+            ChequeStatus::Unused | ChequeStatus::Bounced => {}
+        }
+        self.status = ChequeStatus::Presented;
+        self.amount = Some(amount);
+        self.presented_at = Some(now);
+        self.return_reason = None;
+        self.last_updated_at = now;
+        Ok(())
+    }
+
+    pub fn mark_paid(&mut self, transaction_id: Uuid, now: DateTime<Utc>) {
+        self.status = ChequeStatus::Paid;
+        self.transaction_id = Some(transaction_id);
+        self.paid_at = Some(now);
+        self.last_updated_at = now;
+    }
+
+    pub fn mark_bounced(&mut self, reason: ChequeReturnReason, now: DateTime<Utc>) {
+        self.status = ChequeStatus::Bounced;
+        self.return_reason = Some(reason);
+        self.last_updated_at = now;
+    }
+
+    /// Place a stop-payment instruction. Only cheques not yet paid can be stopped.
+    pub fn stop(&mut self, reason: HeaplessString<200>, stopped_by_person_id: Uuid, now: DateTime<Utc>) -> Result<(), ChequeReturnReason> {
+        match self.status {
+            ChequeStatus::Presented | ChequeStatus::Paid => return Err(ChequeReturnReason::DuplicatePresentment),
+            ChequeStatus::Stopped => return Err(ChequeReturnReason::PaymentStopped),
+            ChequeStatus::Unused | ChequeStatus::Bounced => {}
+        }
+        self.status = ChequeStatus::Stopped;
+        self.stop_reason = Some(reason);
+        self.stopped_at = Some(now);
+        self.stopped_by_person_id = Some(stopped_by_person_id);
+        self.last_updated_at = now;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChequeStatus {
+    Unused,
+    Presented,
+    Paid,
+    Bounced,
+    Stopped,
+}
+
+/// Reason a presented cheque is returned unpaid, with its clearing code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChequeReturnReason {
+    InsufficientFunds,
+    PaymentStopped,
+    DuplicatePresentment,
+    UnknownCheque,
+    AccountNotOperational,
+}
+
+impl ChequeReturnReason {
+    /// Code reported to the clearing interface
+    pub fn code(&self) -> &'static str {
+        match self {
+            ChequeReturnReason::InsufficientFunds => "CHQ01",
+            ChequeReturnReason::PaymentStopped => "CHQ02",
+            ChequeReturnReason::DuplicatePresentment => "CHQ03",
+            ChequeReturnReason::UnknownCheque => "CHQ04",
+            ChequeReturnReason::AccountNotOperational => "CHQ05",
+        }
+    }
+
+    pub fn into_error(self, account_id: Uuid, serial_number: i64) -> BankingError {
+        match self {
+            ChequeReturnReason::PaymentStopped => BankingError::ChequeStopped { account_id, serial_number },
+            ChequeReturnReason::DuplicatePresentment => BankingError::DuplicateChequePresentment { account_id, serial_number },
+            ChequeReturnReason::UnknownCheque => BankingError::ChequeNotFound { account_id, serial_number },
+            ChequeReturnReason::InsufficientFunds | ChequeReturnReason::AccountNotOperational => {
+                BankingError::ChequeReturned { account_id, serial_number, reason: self }
+            }
+        }
+    }
+
+    /// Recover the return reason from an error raised while presenting a cheque
+    pub fn from_error(error: &BankingError) -> Option<Self> {
+        match error {
+            BankingError::ChequeStopped { .. } => Some(ChequeReturnReason::PaymentStopped),
+            BankingError::DuplicateChequePresentment { .. } => Some(ChequeReturnReason::DuplicatePresentment),
+            BankingError::ChequeNotFound { .. } => Some(ChequeReturnReason::UnknownCheque),
+            BankingError::ChequeReturned { reason, .. } => Some(*reason),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf() -> Cheque {
+        Cheque::new_leaf(Uuid::new_v4(), Uuid::new_v4(), 1001, Utc::now())
+    }
+
+    #[test]
+    fn test_lifecycle_with_bounce_and_representment() {
+        let mut cheque = leaf();
+        let now = Utc::now();
+
+        cheque.present(Decimal::from(500), now).unwrap();
+        assert_eq!(cheque.status, ChequeStatus::Presented);
+
+        // Bounced for insufficient funds, then presented again and paid
+        cheque.mark_bounced(ChequeReturnReason::InsufficientFunds, now);
+        assert_eq!(cheque.status, ChequeStatus::Bounced);
+        assert_eq!(cheque.return_reason.unwrap().code(), "CHQ01");
+
+        cheque.present(Decimal::from(500), now).unwrap();
+        assert!(cheque.return_reason.is_none());
+        let transaction_id = Uuid::new_v4();
+        cheque.mark_paid(transaction_id, now);
+        assert_eq!(cheque.status, ChequeStatus::Paid);
+        assert_eq!(cheque.transaction_id, Some(transaction_id));
+
+        assert_eq!(cheque.present(Decimal::from(500), now), Err(ChequeReturnReason::DuplicatePresentment));
+    }
+
+    #[test]
+    fn test_stopped_cheque_cannot_be_presented() {
+        let mut cheque = leaf();
+        let now = Utc::now();
+        cheque.stop(HeaplessString::try_from("Lost").unwrap(), Uuid::new_v4(), now).unwrap();
+
+        assert_eq!(cheque.present(Decimal::from(100), now), Err(ChequeReturnReason::PaymentStopped));
+        assert_eq!(cheque.status, ChequeStatus::Stopped);
+    }
+
+    #[test]
+    fn test_paid_cheque_cannot_be_stopped() {
+        let mut cheque = leaf();
+        let now = Utc::now();
+        cheque.present(Decimal::from(100), now).unwrap();
+        cheque.mark_paid(Uuid::new_v4(), now);
+
+        assert!(cheque.stop(HeaplessString::try_from("Late").unwrap(), Uuid::new_v4(), now).is_err());
+    }
+
+    #[test]
+    fn test_rejections_map_to_distinct_errors_and_codes() {
+        let account_id = Uuid::new_v4();
+        let reasons = [
+            ChequeReturnReason::InsufficientFunds,
+            ChequeReturnReason::PaymentStopped,
+            ChequeReturnReason::DuplicatePresentment,
+            ChequeReturnReason::UnknownCheque,
+            ChequeReturnReason::AccountNotOperational,
+        ];
+
+        let codes: std::collections::HashSet<&str> = reasons.iter().map(|r| r.code()).collect();
+        assert_eq!(codes.len(), reasons.len());
+
+        for reason in reasons {
+            let error = reason.into_error(account_id, 42);
+            assert_eq!(ChequeReturnReason::from_error(&error), Some(reason));
+        }
+        assert!(matches!(
+            ChequeReturnReason::PaymentStopped.into_error(account_id, 42),
+            BankingError::ChequeStopped { serial_number: 42, .. }
+        ));
+    }
+}
@@ -224,7 +224,11 @@ pub struct CollateralValuation {
     pub valuation_date: NaiveDate,
     pub valuation_method: ValuationMethod,
     pub market_value: Decimal,
+    /// Currency of the values; that of the collateral
+    pub currency: HeaplessString<3>,
     pub forced_sale_value: Option<Decimal>,
+    /// References Person.person_id of the valuer
+    pub valuer_person_id: Uuid,
     pub appraiser_name: HeaplessString<255>,
     pub appraiser_license: Option<HeaplessString<100>>,
     pub valuation_report_reference: HeaplessString<100>,
@@ -5,6 +5,8 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::error::{BankingError, BankingResult};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KycResult {
     pub customer_id: Uuid,
@@ -174,6 +176,8 @@ pub enum ComplianceAlertType {
     SuspiciousPattern,
     GeographicAnomaly,
     CrossBorderTransaction,
+    SanctionsMatch,
+    UnauthorizedOverdraft,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -221,13 +225,104 @@ pub struct SarData {
     pub supporting_transaction_id_17: Option<Uuid>,
     pub supporting_transaction_id_18: Option<Uuid>,
     pub supporting_transaction_id_19: Option<Uuid>,
+    /// Investigator's account of the suspicious activity
+    pub narrative: HeaplessString<1000>,
+    /// Customer name when the SAR was drafted
+    pub customer_full_name: HeaplessString<100>,
+    /// References ComplianceAlert.id; alerts covered by this SAR
+    pub alert_ids: Vec<Uuid>,
+    /// References Account.id; accounts the covered alerts were raised on
+    pub account_ids: Vec<Uuid>,
     pub generated_at: DateTime<Utc>,
     pub status: SarStatus,
+    /// References Person.person_id
+    pub created_by_person_id: Uuid,
+    /// References Person.person_id
+    pub submitted_by_person_id: Option<Uuid>,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub last_updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl SarData {
+    /// Only drafts may change; from submission on the SAR is the record of what was reported
+    pub fn ensure_editable(&self) -> BankingResult<()> {
+        match self.status {
+            SarStatus::Draft => Ok(()),
+            status => Err(BankingError::SarLocked { sar_id: self.id, status }),
+        }
+    }
+
+    /// Adds an alert to the SAR with the transaction and account it was raised on
+    pub fn cover_alert(&mut self, alert_id: Uuid, account_id: Option<Uuid>, transaction_id: Option<Uuid>) -> BankingResult<()> {
+        self.ensure_editable()?;
+        if !self.alert_ids.contains(&alert_id) {
+            self.alert_ids.push(alert_id);
+        }
+        if let Some(account_id) = account_id.filter(|id| !self.account_ids.contains(id)) {
+            self.account_ids.push(account_id);
+        }
+        if let Some(transaction_id) = transaction_id {
+            self.add_supporting_transaction(transaction_id)?;
+        }
+        Ok(())
+    }
+
+    pub fn supporting_transaction_ids(&self) -> Vec<Uuid> {
+        [
+            self.supporting_transaction_id_01, self.supporting_transaction_id_02, self.supporting_transaction_id_03,
+            self.supporting_transaction_id_04, self.supporting_transaction_id_05, self.supporting_transaction_id_06,
+            self.supporting_transaction_id_07, self.supporting_transaction_id_08, self.supporting_transaction_id_09,
+            self.supporting_transaction_id_10, self.supporting_transaction_id_11, self.supporting_transaction_id_12,
+            self.supporting_transaction_id_13, self.supporting_transaction_id_14, self.supporting_transaction_id_15,
+            self.supporting_transaction_id_16, self.supporting_transaction_id_17, self.supporting_transaction_id_18,
+            self.supporting_transaction_id_19,
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    fn add_supporting_transaction(&mut self, transaction_id: Uuid) -> BankingResult<()> {
+        let slots = [
+            &mut self.supporting_transaction_id_01, &mut self.supporting_transaction_id_02, &mut self.supporting_transaction_id_03,
+            &mut self.supporting_transaction_id_04, &mut self.supporting_transaction_id_05, &mut self.supporting_transaction_id_06,
+            &mut self.supporting_transaction_id_07, &mut self.supporting_transaction_id_08, &mut self.supporting_transaction_id_09,
+            &mut self.supporting_transaction_id_10, &mut self.supporting_transaction_id_11, &mut self.supporting_transaction_id_12,
+            &mut self.supporting_transaction_id_13, &mut self.supporting_transaction_id_14, &mut self.supporting_transaction_id_15,
+            &mut self.supporting_transaction_id_16, &mut self.supporting_transaction_id_17, &mut self.supporting_transaction_id_18,
+            &mut self.supporting_transaction_id_19,
+        ];
+        if slots.iter().any(|slot| **slot == Some(transaction_id)) {
+            return Ok(());
+        }
+        match slots.into_iter().find(|slot| slot.is_none()) {
+            Some(slot) => {
+                *slot = Some(transaction_id);
+                Ok(())
+            }
+            None => Err(BankingError::ValidationError {
+                field: "supporting_transactions".to_string(),
+                message: format!("SAR {} already lists 19 supporting transactions", self.id),
+            }),
+        }
+    }
+
+    /// Locks the draft for filing by the MLRO
+    pub fn submit(&mut self, submitted_by_person_id: Uuid, submitted_at: DateTime<Utc>) -> BankingResult<()> {
+        self.ensure_editable()?;
+        self.status = SarStatus::Submitted;
+        self.submitted_by_person_id = Some(submitted_by_person_id);
+        self.submitted_at = Some(submitted_at);
+        self.last_updated_at = submitted_at;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SarStatus {
     Draft,
+    /// Submitted to the MLRO for filing; no longer editable
+    Submitted,
     Filed,
     Acknowledged,
     UnderReview,
@@ -267,6 +362,8 @@ pub struct MonitoringRules {
     pub large_cash_threshold: Decimal,
     pub suspicious_pattern_detection: bool,
     pub cross_border_transaction_monitoring: bool,
+    /// Velocity rules over channel security events
+    pub security_velocity_rules: Vec<super::channel_security::SecurityVelocityRule>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
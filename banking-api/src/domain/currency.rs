@@ -0,0 +1,158 @@
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::BankingError;
+
+/// Active ISO 4217 alphabetic codes, sorted for binary search
+const ISO_4217_CODES: [&str; 155] = [
+    "AED", "AFN", "ALL", "AMD", "ANG", "AOA", "ARS", "AUD", "AWG", "AZN",
+    "BAM", "BBD", "BDT", "BGN", "BHD", "BIF", "BMD", "BND", "BOB", "BRL",
+    "BSD", "BTN", "BWP", "BYN", "BZD", "CAD", "CDF", "CHF", "CLP", "CNY",
+    "COP", "CRC", "CUP", "CVE", "CZK", "DJF", "DKK", "DOP", "DZD", "EGP",
+    "ERN", "ETB", "EUR", "FJD", "FKP", "GBP", "GEL", "GHS", "GIP", "GMD",
+    "GNF", "GTQ", "GYD", "HKD", "HNL", "HTG", "HUF", "IDR", "ILS", "INR",
+    "IQD", "IRR", "ISK", "JMD", "JOD", "JPY", "KES", "KGS", "KHR", "KMF",
+    "KPW", "KRW", "KWD", "KYD", "KZT", "LAK", "LBP", "LKR", "LRD", "LSL",
+    "LYD", "MAD", "MDL", "MGA", "MKD", "MMK", "MNT", "MOP", "MRU", "MUR",
+    "MVR", "MWK", "MXN", "MYR", "MZN", "NAD", "NGN", "NIO", "NOK", "NPR",
+    "NZD", "OMR", "PAB", "PEN", "PGK", "PHP", "PKR", "PLN", "PYG", "QAR",
+    "RON", "RSD", "RUB", "RWF", "SAR", "SBD", "SCR", "SDG", "SEK", "SGD",
+    "SHP", "SLE", "SOS", "SRD", "SSP", "STN", "SYP", "SZL", "THB", "TJS",
+    "TMT", "TND", "TOP", "TRY", "TTD", "TWD", "TZS", "UAH", "UGX", "USD",
+    "UYU", "UZS", "VES", "VND", "VUV", "WST", "XAF", "XCD", "XCG", "XOF",
+    "XPF", "YER", "ZAR", "ZMW", "ZWG",
+];
+
+
+/// Codes without a minor unit, sorted for binary search
+const ZERO_DECIMAL_CODES: [&str; 16] = [
+    "BIF", "CLP", "DJF", "GNF", "ISK", "JPY", "KMF", "KRW", "PYG", "RWF",
+    "UGX", "VND", "VUV", "XAF", "XOF", "XPF",
+];
+
+/// Codes whose minor unit is a thousandth, sorted for binary search
+const THREE_DECIMAL_CODES: [&str; 7] = ["BHD", "IQD", "JOD", "KWD", "LYD", "OMR", "TND"];
+
+/// ISO 4217 alphabetic currency code, validated on construction
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CurrencyCode(HeaplessString<3>);
+
+impl CurrencyCode {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Decimal places of the currency's minor unit
+    pub fn minor_units(&self) -> u32 {
+        if ZERO_DECIMAL_CODES.binary_search(&self.as_str()).is_ok() {
+            0
+        } else if THREE_DECIMAL_CODES.binary_search(&self.as_str()).is_ok() {
+            3
+        } else {
+            2
+        }
+    }
+}
+
+impl FromStr for CurrencyCode {
+    type Err = BankingError;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        if ISO_4217_CODES.binary_search(&code).is_err() {
+            return Err(BankingError::InvalidCurrencyCode(code.to_string()));
+        }
+        HeaplessString::try_from(code)
+            .map(Self)
+            .map_err(|_| BankingError::InvalidCurrencyCode(code.to_string()))
+    }
+}
+
+impl TryFrom<&str> for CurrencyCode {
+    type Error = BankingError;
+
+    fn try_from(code: &str) -> Result<Self, Self::Error> {
+        code.parse()
+    }
+}
+
+impl TryFrom<String> for CurrencyCode {
+    type Error = BankingError;
+
+    fn try_from(code: String) -> Result<Self, Self::Error> {
+        code.parse()
+    }
+}
+
+impl TryFrom<HeaplessString<3>> for CurrencyCode {
+    type Error = BankingError;
+
+    fn try_from(code: HeaplessString<3>) -> Result<Self, Self::Error> {
+        code.as_str().parse()
+    }
+}
+
+impl From<CurrencyCode> for HeaplessString<3> {
+    fn from(code: CurrencyCode) -> Self {
+        code.0
+    }
+}
+
+impl From<CurrencyCode> for String {
+    fn from(code: CurrencyCode) -> Self {
+        code.0.to_string()
+    }
+}
+
+impl fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_are_sorted_for_binary_search() {
+        assert!(ISO_4217_CODES.windows(2).all(|pair| pair[0] < pair[1]));
+        for codes in [&ZERO_DECIMAL_CODES[..], &THREE_DECIMAL_CODES[..]] {
+            assert!(codes.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(codes.iter().all(|code| ISO_4217_CODES.binary_search(code).is_ok()));
+        }
+    }
+
+    #[test]
+    fn test_minor_units() {
+        for (code, minor_units) in [("XAF", 0), ("JPY", 0), ("EUR", 2), ("NGN", 2), ("KWD", 3), ("TND", 3)] {
+            assert_eq!(CurrencyCode::try_from(code).unwrap().minor_units(), minor_units);
+        }
+    }
+
+    #[test]
+    fn test_known_codes_parse() {
+        for code in ["XAF", "XOF", "EUR", "USD", "NGN"] {
+            assert_eq!(CurrencyCode::try_from(code).unwrap().as_str(), code);
+        }
+    }
+
+    #[test]
+    fn test_invalid_codes_are_rejected() {
+        for code in ["", "EU", "EURO", "eur", "ABC", "XXX"] {
+            assert!(matches!(
+                CurrencyCode::try_from(code),
+                Err(BankingError::InvalidCurrencyCode(rejected)) if rejected == code
+            ));
+        }
+    }
+
+    #[test]
+    fn test_deserialize_validates_code() {
+        let code: CurrencyCode = serde_json::from_str("\"XOF\"").unwrap();
+        assert_eq!(code.to_string(), "XOF");
+        assert!(serde_json::from_str::<CurrencyCode>("\"XYZ\"").is_err());
+    }
+}
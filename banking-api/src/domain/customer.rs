@@ -16,6 +16,10 @@ pub struct Customer {
     pub last_updated_at: DateTime<Utc>,
     /// References Person.person_id
     pub updated_by_person_id: Uuid,
+    /// ISO 639-3 code of the language used for customer communications, e.g. "eng"
+    pub preferred_language_code: Option<[u8; 3]>,
+    /// References Customer.id of the surviving record; set when this one is merged into it
+    pub duplicate_of_customer_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -77,11 +81,18 @@ impl std::fmt::Display for CustomerStatus {
             CustomerStatus::PendingVerification => write!(f, "PendingVerification"),
             CustomerStatus::Deceased => write!(f, "Deceased"),
             CustomerStatus::Dissolved => write!(f, "Dissolved"),
+            CustomerStatus::Merged => write!(f, "Merged"),
             CustomerStatus::Blacklisted => write!(f, "Blacklisted"),
         }
     }
 }
 
+impl KycStatus {
+    pub fn allows_account_opening(&self) -> bool {
+        matches!(self, KycStatus::Approved | KycStatus::Complete)
+    }
+}
+
 impl std::fmt::Display for KycStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -161,6 +172,7 @@ impl std::str::FromStr for CustomerStatus {
             "PendingVerification" => Ok(CustomerStatus::PendingVerification),
             "Deceased" => Ok(CustomerStatus::Deceased),
             "Dissolved" => Ok(CustomerStatus::Dissolved),
+            "Merged" => Ok(CustomerStatus::Merged),
             "Blacklisted" => Ok(CustomerStatus::Blacklisted),
             _ => Err(format!("Invalid CustomerStatus: {s}")),
         }
@@ -213,7 +225,18 @@ pub enum CustomerStatus {
     PendingVerification, 
     Deceased,
     Dissolved,
-    Blacklisted
+    Blacklisted,
+    /// Folded into another customer record, see Customer.duplicate_of_customer_id
+    Merged,
+}
+
+/// Why a duplicate customer cannot be merged yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CustomerMergeBlocker {
+    /// An account owned by the duplicate has an active hold
+    ActiveHolds,
+    /// An account owned by the duplicate has an in-progress or pending workflow
+    PendingWorkflows,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -222,11 +245,31 @@ pub struct CustomerPortfolio {
     pub total_accounts: i64,
     pub total_balance: rust_decimal::Decimal,
     pub total_loan_outstanding: Option<rust_decimal::Decimal>,
+    /// Contingent liability from active loan guarantees, capped per guarantee
+    pub total_guarantee_exposure: Option<rust_decimal::Decimal>,
     pub last_activity_date: Option<DateTime<Utc>>,
     pub risk_score: Option<rust_decimal::Decimal>,
     pub kyc_status: KycStatus,
     pub sanctions_checked: bool,
     pub last_screening_date: Option<DateTime<Utc>>,
+    /// Codes of the tags attached to the customer
+    pub tags: Vec<HeaplessString<50>>,
+    /// Most recent channel security events, newest first
+    pub recent_security_events: Vec<crate::domain::ChannelSecurityEvent>,
+    /// Staff notes on the customer and the accounts they own; compliance-only
+    /// notes are not counted
+    pub interaction_note_count: i64,
+}
+
+/// Customer search; all set fields must match
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerSearchCriteria {
+    pub customer_type: Option<CustomerType>,
+    pub status: Option<CustomerStatus>,
+    pub risk_rating: Option<RiskRating>,
+    pub tags: Option<crate::domain::TagFilter>,
+    pub offset: i64,
+    pub limit: i64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -306,6 +349,7 @@ pub struct CustomerBuilder {
     risk_rating: RiskRating,
     status: CustomerStatus,
     updated_by_person_id: Uuid,
+    preferred_language_code: Option<[u8; 3]>,
 }
 
 impl CustomerBuilder {
@@ -319,6 +363,7 @@ impl CustomerBuilder {
             risk_rating: RiskRating::Low,
             status: CustomerStatus::Active,
             updated_by_person_id: Uuid::nil(),
+            preferred_language_code: None,
         }
     }
     
@@ -348,6 +393,11 @@ impl CustomerBuilder {
         self
     }
     
+    pub fn preferred_language(mut self, language_code: [u8; 3]) -> Self {
+        self.preferred_language_code = Some(language_code);
+        self
+    }
+    
     pub fn build(self) -> Result<Customer, &'static str> {
         let full_name_heap = HeaplessString::try_from(self.full_name.as_str()).map_err(|_| "Full name too long")?;
         let id_number_heap = HeaplessString::try_from(self.id_number.as_str()).map_err(|_| "ID number too long")?;
@@ -366,6 +416,8 @@ impl CustomerBuilder {
             created_at: now,
             last_updated_at: now,
             updated_by_person_id: self.updated_by_person_id,
+            preferred_language_code: self.preferred_language_code,
+            duplicate_of_customer_id: None,
         })
     }
 }
@@ -1,11 +1,13 @@
-use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
 use heapless::String as HeaplessString;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use uuid::Uuid;
 
+use crate::error::{BankingError, BankingResult};
 use super::collateral::AlertSeverity;
+use super::person::Location;
 
 
 // ======== Collection Agent Models ========
@@ -94,6 +96,32 @@ pub struct CoverageArea {
     pub transport_mode: TransportMode,
 }
 
+impl CoverageArea {
+    /// Smallest box holding every boundary point, as (min_lat, min_long, max_lat, max_long).
+    /// None when the area has no complete boundary point.
+    pub fn bounding_box(&self) -> Option<(Decimal, Decimal, Decimal, Decimal)> {
+        let points = [
+            (self.boundary_coordinates_lat_1, self.boundary_coordinates_long_1),
+            (self.boundary_coordinates_lat_2, self.boundary_coordinates_long_2),
+            (self.boundary_coordinates_lat_3, self.boundary_coordinates_long_3),
+            (self.boundary_coordinates_lat_4, self.boundary_coordinates_long_4),
+            (self.boundary_coordinates_lat_5, self.boundary_coordinates_long_5),
+        ];
+        points
+            .into_iter()
+            .filter_map(|(lat, long)| Some((lat?, long?)))
+            .fold(None, |bounds, (lat, long)| match bounds {
+                None => Some((lat, long, lat, long)),
+                Some((min_lat, min_long, max_lat, max_long)) => Some((
+                    min_lat.min(lat),
+                    min_long.min(long),
+                    max_lat.max(lat),
+                    max_long.max(long),
+                )),
+            })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum AreaType {
     Urban,
@@ -248,7 +276,10 @@ pub struct PerformanceAlert {
     pub acknowledged: bool,
     pub resolution_required: bool,
     pub acknowledged_at: Option<DateTime<Utc>>,
+    pub acknowledged_by_person_id: Option<Uuid>,
     pub resolved_at: Option<DateTime<Utc>>,
+    pub resolved_by_person_id: Option<Uuid>,
+    pub resolution_notes: Option<HeaplessString<500>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -387,6 +418,61 @@ pub struct CollectionSecurityFeatures {
     pub last_security_scan: Option<DateTime<Utc>>,
 }
 
+/// Condition an agent's device fails, barring it from recording collections
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+pub enum DeviceComplianceError {
+    #[error("device has never synced")]
+    NeverSynced,
+    #[error("device last synced at {last_sync}, more than {max_sync_age_hours} hours ago")]
+    SyncStale {
+        last_sync: DateTime<Utc>,
+        max_sync_age_hours: i64,
+    },
+    #[error("device encryption is disabled")]
+    EncryptionDisabled,
+    #[error("device certificate is not installed")]
+    CertificateMissing,
+}
+
+impl DeviceInformation {
+    /// Every condition the device fails at `now`, sync age first
+    pub fn compliance_failures(
+        &self,
+        security_features: &CollectionSecurityFeatures,
+        now: DateTime<Utc>,
+        max_sync_age: Duration,
+    ) -> Vec<DeviceComplianceError> {
+        let mut failures = Vec::new();
+        match self.last_sync {
+            None => failures.push(DeviceComplianceError::NeverSynced),
+            Some(last_sync) if now - last_sync > max_sync_age => failures.push(DeviceComplianceError::SyncStale {
+                last_sync,
+                max_sync_age_hours: max_sync_age.num_hours(),
+            }),
+            Some(_) => {}
+        }
+        if !security_features.encryption_enabled {
+            failures.push(DeviceComplianceError::EncryptionDisabled);
+        }
+        if !security_features.certificate_installed {
+            failures.push(DeviceComplianceError::CertificateMissing);
+        }
+        failures
+    }
+
+    /// Apply a heartbeat from the mobile backend; the device counts as synced at `at`
+    pub fn record_heartbeat(
+        &mut self,
+        battery_level: Option<f32>,
+        connectivity_status: ConnectivityStatus,
+        at: DateTime<Utc>,
+    ) {
+        self.last_sync = Some(at);
+        self.battery_level = battery_level;
+        self.connectivity_status = connectivity_status;
+    }
+}
+
 // ======== Collection Program Models ========
 
 /// Daily Collection Program entity representing a structured savings program
@@ -409,12 +495,87 @@ pub struct CollectionProgram {
     pub graduation_criteria_id: Uuid,
     pub fee_structure_id: Uuid,
     pub interest_rate: Option<Decimal>,
+    /// Maximum distance between agent and customer location; `None` disables geo verification
+    pub geo_verification_radius_meters: Option<i32>,
+    /// Geo mismatches per agent within seven days that raise a compliance alert
+    pub geo_mismatch_weekly_threshold: Option<i32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub created_by_person_id: Uuid,
     pub reason_id: Option<Uuid>,
 }
 
+impl CollectionProgram {
+    /// Check the agent's reported position against the customer's collection
+    /// location. Returns `None` when the program does not verify locations.
+    pub fn verify_collection_location(
+        &self,
+        record: &CollectionRecord,
+        customer_location: Option<&Location>,
+    ) -> Option<GeoVerification> {
+        let radius_meters = self.geo_verification_radius_meters?;
+
+        let distance_meters = match (record.agent_latitude, record.agent_longitude, customer_location) {
+            (Some(latitude), Some(longitude), Some(location)) => location.distance_meters_to(latitude, longitude),
+            _ => None,
+        };
+
+        Some(match distance_meters {
+            None => GeoVerification { status: GeoVerificationStatus::MissingCoordinates, distance_meters: None },
+            Some(distance) if distance <= f64::from(radius_meters) => {
+                GeoVerification { status: GeoVerificationStatus::Verified, distance_meters: Some(distance) }
+            }
+            Some(distance) => GeoVerification { status: GeoVerificationStatus::GeoMismatch, distance_meters: Some(distance) },
+        })
+    }
+
+    /// True when an agent's mismatch count for the week reaches the threshold.
+    /// Only the crossing raises an alert, further mismatches that week do not.
+    pub fn geo_mismatch_escalates(&self, weekly_mismatches: i64) -> bool {
+        self.geo_mismatch_weekly_threshold
+            .is_some_and(|threshold| threshold > 0 && weekly_mismatches == i64::from(threshold))
+    }
+}
+
+/// Outcome of checking where a collection was recorded
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GeoVerification {
+    pub status: GeoVerificationStatus,
+    pub distance_meters: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum GeoVerificationStatus {
+    Verified,
+    /// Recorded outside the program's radius
+    GeoMismatch,
+    /// Agent or customer coordinates were not available
+    MissingCoordinates,
+}
+
+impl fmt::Display for GeoVerificationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeoVerificationStatus::Verified => write!(f, "Verified"),
+            GeoVerificationStatus::GeoMismatch => write!(f, "GeoMismatch"),
+            GeoVerificationStatus::MissingCoordinates => write!(f, "MissingCoordinates"),
+        }
+    }
+}
+
+impl std::str::FromStr for GeoVerificationStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Verified" => Ok(GeoVerificationStatus::Verified),
+            "GeoMismatch" => Ok(GeoVerificationStatus::GeoMismatch),
+            "MissingCoordinates" => Ok(GeoVerificationStatus::MissingCoordinates),
+            _ => Err(format!("Invalid GeoVerificationStatus: {s}")),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum CollectionProgramType {
     FixedAmount,
@@ -772,11 +933,28 @@ pub struct CollectionRecord {
     pub status: CollectionRecordStatus,
     pub notes: Option<HeaplessString<500>>,
     pub collection_verification_id: Option<Uuid>,
+    /// Agent GPS position supplied by the mobile app
+    pub agent_latitude: Option<Decimal>,
+    pub agent_longitude: Option<Decimal>,
+    pub geo_verification_status: Option<GeoVerificationStatus>,
+    pub geo_distance_meters: Option<f64>,
     pub created_at: DateTime<Utc>,
     pub processed_at: Option<DateTime<Utc>>,
     pub reason_id: Option<Uuid>,
 }
 
+impl CollectionRecord {
+    /// Record the geo check. Unverified records are accepted but held for
+    /// supervisor review.
+    pub fn apply_geo_verification(&mut self, verification: GeoVerification) {
+        self.geo_verification_status = Some(verification.status);
+        self.geo_distance_meters = verification.distance_meters;
+        if verification.status != GeoVerificationStatus::Verified {
+            self.status = CollectionRecordStatus::UnderReview;
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum CollectionMethod {
     Cash,
@@ -994,6 +1172,107 @@ pub struct ReconciliationData {
     pub adjustment_required: bool,
 }
 
+impl AgentPerformanceMetrics {
+    /// Clear the slot holding `alert_id` so another alert can be attached.
+    /// Returns false when the alert is not linked to these metrics.
+    pub fn release_alert_slot(&mut self, alert_id: Uuid) -> bool {
+        let slots = [
+            &mut self.performance_alert_1_id,
+            &mut self.performance_alert_2_id,
+            &mut self.performance_alert_3_id,
+            &mut self.performance_alert_4_id,
+            &mut self.performance_alert_5_id,
+        ];
+        for slot in slots {
+            if *slot == Some(alert_id) {
+                *slot = None;
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl PerformanceAlert {
+    /// Open until resolved; acknowledged alerts are still outstanding
+    pub fn is_open(&self) -> bool {
+        self.resolved_at.is_none()
+    }
+
+    /// Record that `person_id` has seen the alert. Acknowledging again keeps
+    /// the first acknowledgement.
+    pub fn acknowledge(&mut self, person_id: Uuid, at: DateTime<Utc>) -> BankingResult<()> {
+        if !self.is_open() {
+            return Err(BankingError::PerformanceAlertAlreadyResolved(self.id));
+        }
+        if !self.acknowledged {
+            self.acknowledged = true;
+            self.acknowledged_at = Some(at);
+            self.acknowledged_by_person_id = Some(person_id);
+        }
+        Ok(())
+    }
+
+    /// Close the alert. Alerts that require resolution must be acknowledged first.
+    pub fn resolve(
+        &mut self,
+        person_id: Uuid,
+        resolution_notes: Option<HeaplessString<500>>,
+        at: DateTime<Utc>,
+    ) -> BankingResult<()> {
+        if !self.is_open() {
+            return Err(BankingError::PerformanceAlertAlreadyResolved(self.id));
+        }
+        if self.resolution_required && !self.acknowledged {
+            return Err(BankingError::PerformanceAlertNotAcknowledged(self.id));
+        }
+        self.resolved_at = Some(at);
+        self.resolved_by_person_id = Some(person_id);
+        self.resolution_notes = resolution_notes;
+        Ok(())
+    }
+}
+
+impl ReconciliationData {
+    /// Reconcile cash counted for a batch against its processed collections.
+    /// Reversed, failed and held collections are not expected in the count.
+    pub fn for_batch(
+        collection_batch_id: Uuid,
+        records: &[CollectionRecord],
+        counted_cash: Decimal,
+        reconciled_by_person_id: Uuid,
+        reconciliation_timestamp: DateTime<Utc>,
+    ) -> Self {
+        let expected_amount: Decimal = records
+            .iter()
+            .filter(|record| record.status == CollectionRecordStatus::Processed)
+            .map(|record| record.amount)
+            .sum();
+        let variance = counted_cash - expected_amount;
+        Self {
+            id: Uuid::new_v4(),
+            collection_batch_id,
+            expected_amount,
+            actual_amount: counted_cash,
+            variance,
+            variance_reason: None,
+            reconciled_by_person_id,
+            reconciliation_timestamp,
+            // A tolerated variance is still booked as an adjustment
+            adjustment_required: !variance.is_zero(),
+        }
+    }
+
+    /// Completed when the variance, surplus or shortfall, is within the tolerance
+    pub fn batch_status(&self, variance_tolerance: Decimal) -> BatchStatus {
+        if self.variance.abs() <= variance_tolerance {
+            BatchStatus::Completed
+        } else {
+            BatchStatus::RequiresReconciliation
+        }
+    }
+}
+
 // ======== Builder Patterns ========
 
 /// Builder for CollectionAgent
@@ -1105,6 +1384,8 @@ pub struct CollectionProgramBuilder {
     graduation_criteria_id: Option<Uuid>,
     fee_structure_id: Option<Uuid>,
     interest_rate: Option<Decimal>,
+    geo_verification_radius_meters: Option<i32>,
+    geo_mismatch_weekly_threshold: Option<i32>,
     created_by_person_id: Uuid,
     reason_id: Option<Uuid>,
 }
@@ -1128,6 +1409,8 @@ impl CollectionProgram {
             graduation_criteria_id: None,
             fee_structure_id: None,
             interest_rate: None,
+            geo_verification_radius_meters: None,
+            geo_mismatch_weekly_threshold: None,
             created_by_person_id,
             reason_id: None,
         }
@@ -1203,6 +1486,12 @@ impl CollectionProgramBuilder {
         self
     }
 
+    pub fn geo_verification(mut self, radius_meters: i32, weekly_mismatch_threshold: i32) -> Self {
+        self.geo_verification_radius_meters = Some(radius_meters);
+        self.geo_mismatch_weekly_threshold = Some(weekly_mismatch_threshold);
+        self
+    }
+
     pub fn reason_id(mut self, reason_id: Uuid) -> Self {
         self.reason_id = Some(reason_id);
         self
@@ -1228,6 +1517,8 @@ impl CollectionProgramBuilder {
             graduation_criteria_id: self.graduation_criteria_id.ok_or("Graduation criteria ID is required")?,
             fee_structure_id: self.fee_structure_id.ok_or("Fee structure ID is required")?,
             interest_rate: self.interest_rate,
+            geo_verification_radius_meters: self.geo_verification_radius_meters,
+            geo_mismatch_weekly_threshold: self.geo_mismatch_weekly_threshold,
             created_at: now,
             updated_at: now,
             created_by_person_id: self.created_by_person_id,
@@ -1365,6 +1656,8 @@ pub struct CollectionRecordBuilder {
     status: CollectionRecordStatus,
     notes: Option<HeaplessString<500>>,
     collection_verification_id: Option<Uuid>,
+    agent_latitude: Option<Decimal>,
+    agent_longitude: Option<Decimal>,
     reason_id: Option<Uuid>,
 }
 
@@ -1392,6 +1685,8 @@ impl CollectionRecord {
             status: CollectionRecordStatus::Pending,
             notes: None,
             collection_verification_id: None,
+            agent_latitude: None,
+            agent_longitude: None,
             reason_id: None,
         }
     }
@@ -1451,6 +1746,12 @@ impl CollectionRecordBuilder {
         self
     }
 
+    pub fn agent_coordinates(mut self, latitude: Decimal, longitude: Decimal) -> Self {
+        self.agent_latitude = Some(latitude);
+        self.agent_longitude = Some(longitude);
+        self
+    }
+
     pub fn reason_id(mut self, reason_id: Uuid) -> Self {
         self.reason_id = Some(reason_id);
         self
@@ -1475,9 +1776,379 @@ impl CollectionRecordBuilder {
             status: self.status,
             notes: self.notes,
             collection_verification_id: self.collection_verification_id,
+            agent_latitude: self.agent_latitude,
+            agent_longitude: self.agent_longitude,
+            geo_verification_status: None,
+            geo_distance_meters: None,
             created_at: now,
             processed_at: None,
             reason_id: self.reason_id,
         })
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::person::{haversine_distance_meters, LocationType};
+    use std::str::FromStr;
+
+    const CUSTOMER_LAT: &str = "3.8480";
+    const CUSTOMER_LON: &str = "11.5021";
+
+    fn program(radius_meters: i32, weekly_threshold: i32) -> CollectionProgram {
+        CollectionProgram::builder(Uuid::new_v4(), CollectionProgramType::FixedAmount, Uuid::new_v4())
+            .name("Daily savings").unwrap()
+            .start_date(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+            .collection_frequency(CollectionFrequency::Daily)
+            .amounts(Decimal::from(1), Decimal::from(1000))
+            .program_duration_days(365)
+            .graduation_criteria_id(Uuid::new_v4())
+            .fee_structure_id(Uuid::new_v4())
+            .geo_verification(radius_meters, weekly_threshold)
+            .build()
+            .unwrap()
+    }
+
+    fn customer_location() -> Location {
+        Location {
+            id: Uuid::new_v4(),
+            street_line1: HeaplessString::try_from("Marché Central").unwrap(),
+            street_line2: None,
+            street_line3: None,
+            street_line4: None,
+            locality_id: Uuid::new_v4(),
+            postal_code: None,
+            latitude: Some(Decimal::from_str(CUSTOMER_LAT).unwrap()),
+            longitude: Some(Decimal::from_str(CUSTOMER_LON).unwrap()),
+            accuracy_meters: None,
+            location_type: LocationType::Business,
+        }
+    }
+
+    fn record_at(latitude: Option<&str>, longitude: Option<&str>) -> CollectionRecord {
+        let mut builder = CollectionRecord::builder(Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4())
+            .amount(Decimal::from(500))
+            .currency("XAF").unwrap()
+            .collection_method(CollectionMethod::Cash)
+            .receipt_number("R-0001").unwrap();
+        if let (Some(lat), Some(lon)) = (latitude, longitude) {
+            builder = builder.agent_coordinates(Decimal::from_str(lat).unwrap(), Decimal::from_str(lon).unwrap());
+        }
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn test_geo_verification_at_radius_boundary() {
+        // 0.0009 degrees of latitude north of the customer, roughly 100 m
+        let agent_lat = "3.8489";
+        let distance = haversine_distance_meters(3.848, 11.5021, 3.8489, 11.5021);
+        let location = customer_location();
+
+        // Radius just covering the distance: accepted as is
+        let inside = program(distance.ceil() as i32, 3);
+        let mut record = record_at(Some(agent_lat), Some(CUSTOMER_LON));
+        let verification = inside.verify_collection_location(&record, Some(&location)).unwrap();
+        assert_eq!(verification.status, GeoVerificationStatus::Verified);
+        record.apply_geo_verification(verification);
+        assert_eq!(record.status, CollectionRecordStatus::Pending);
+
+        // Radius one meter short: accepted but held for review
+        let outside = program(distance.floor() as i32, 3);
+        let mut record = record_at(Some(agent_lat), Some(CUSTOMER_LON));
+        let verification = outside.verify_collection_location(&record, Some(&location)).unwrap();
+        assert_eq!(verification.status, GeoVerificationStatus::GeoMismatch);
+        record.apply_geo_verification(verification);
+        assert_eq!(record.status, CollectionRecordStatus::UnderReview);
+        assert_eq!(record.geo_verification_status, Some(GeoVerificationStatus::GeoMismatch));
+    }
+
+    #[test]
+    fn test_missing_coordinates_are_flagged_separately() {
+        let program = program(100, 3);
+        let location = customer_location();
+
+        let mut record = record_at(None, None);
+        let verification = program.verify_collection_location(&record, Some(&location)).unwrap();
+        assert_eq!(verification.status, GeoVerificationStatus::MissingCoordinates);
+        record.apply_geo_verification(verification);
+        assert_eq!(record.status, CollectionRecordStatus::UnderReview);
+
+        // A customer location without coordinates cannot be verified either
+        let mut unmapped = customer_location();
+        unmapped.latitude = None;
+        let record = record_at(Some(CUSTOMER_LAT), Some(CUSTOMER_LON));
+        assert_eq!(
+            program.verify_collection_location(&record, Some(&unmapped)).unwrap().status,
+            GeoVerificationStatus::MissingCoordinates
+        );
+    }
+
+    #[test]
+    fn test_geo_verification_disabled_without_radius() {
+        let mut program = program(100, 3);
+        program.geo_verification_radius_meters = None;
+        let record = record_at(None, None);
+        assert!(program.verify_collection_location(&record, None).is_none());
+    }
+
+    #[test]
+    fn test_repeat_offender_escalates_once_per_week() {
+        let program = program(100, 3);
+        assert!(!program.geo_mismatch_escalates(1));
+        assert!(!program.geo_mismatch_escalates(2));
+        assert!(program.geo_mismatch_escalates(3));
+        assert!(!program.geo_mismatch_escalates(4));
+
+        let mut no_threshold = program.clone();
+        no_threshold.geo_mismatch_weekly_threshold = None;
+        assert!(!no_threshold.geo_mismatch_escalates(3));
+    }
+
+    fn batch_records() -> Vec<CollectionRecord> {
+        [
+            (500, CollectionRecordStatus::Processed),
+            (1500, CollectionRecordStatus::Processed),
+            (700, CollectionRecordStatus::Reversed),
+            (300, CollectionRecordStatus::Failed),
+        ]
+        .into_iter()
+        .map(|(amount, status)| {
+            let mut record = record_at(None, None);
+            record.amount = Decimal::from(amount);
+            record.status = status;
+            record
+        })
+        .collect()
+    }
+
+    fn reconcile(counted_cash: i64) -> ReconciliationData {
+        ReconciliationData::for_batch(Uuid::new_v4(), &batch_records(), Decimal::from(counted_cash), Uuid::new_v4(), Utc::now())
+    }
+
+    #[test]
+    fn test_batch_reconciles_with_zero_variance() {
+        let reconciliation = reconcile(2000);
+        assert_eq!(reconciliation.expected_amount, Decimal::from(2000));
+        assert_eq!(reconciliation.variance, Decimal::ZERO);
+        assert!(!reconciliation.adjustment_required);
+        assert_eq!(reconciliation.batch_status(Decimal::ZERO), BatchStatus::Completed);
+    }
+
+    #[test]
+    fn test_batch_variance_within_tolerance_completes() {
+        let shortfall = reconcile(1950);
+        assert_eq!(shortfall.variance, Decimal::from(-50));
+        assert!(shortfall.adjustment_required);
+        assert_eq!(shortfall.batch_status(Decimal::from(50)), BatchStatus::Completed);
+
+        let surplus = reconcile(2050);
+        assert_eq!(surplus.batch_status(Decimal::from(50)), BatchStatus::Completed);
+    }
+
+    #[test]
+    fn test_batch_variance_over_tolerance_requires_reconciliation() {
+        let shortfall = reconcile(1949);
+        assert_eq!(shortfall.batch_status(Decimal::from(50)), BatchStatus::RequiresReconciliation);
+        assert_eq!(shortfall.batch_status(Decimal::ZERO), BatchStatus::RequiresReconciliation);
+
+        // Cash for the reversed collection was handed back, counting it is a surplus
+        let reversed_counted = reconcile(2700);
+        assert_eq!(reversed_counted.variance, Decimal::from(700));
+        assert_eq!(reversed_counted.batch_status(Decimal::from(50)), BatchStatus::RequiresReconciliation);
+    }
+
+    fn alert(resolution_required: bool) -> PerformanceAlert {
+        PerformanceAlert {
+            id: Uuid::new_v4(),
+            agent_performance_metrics_id: Uuid::new_v4(),
+            alert_type: CollectionAlertType::CashDiscrepancy,
+            severity: AlertSeverity::High,
+            message: HeaplessString::try_from("Cash short by 700").unwrap(),
+            created_at: Utc::now(),
+            acknowledged: false,
+            resolution_required,
+            acknowledged_at: None,
+            acknowledged_by_person_id: None,
+            resolved_at: None,
+            resolved_by_person_id: None,
+            resolution_notes: None,
+        }
+    }
+
+    #[test]
+    fn test_alert_requiring_resolution_is_acknowledged_before_resolving() {
+        let mut alert = alert(true);
+        let supervisor = Uuid::new_v4();
+
+        let err = alert.resolve(supervisor, None, Utc::now()).unwrap_err();
+        assert!(matches!(err, BankingError::PerformanceAlertNotAcknowledged(id) if id == alert.id));
+        assert!(alert.is_open());
+
+        alert.acknowledge(supervisor, Utc::now()).unwrap();
+        let acknowledged_at = alert.acknowledged_at;
+        alert.acknowledge(Uuid::new_v4(), Utc::now()).unwrap();
+        assert_eq!(alert.acknowledged_at, acknowledged_at);
+        assert_eq!(alert.acknowledged_by_person_id, Some(supervisor));
+
+        let notes = HeaplessString::try_from("Recounted with agent").unwrap();
+        alert.resolve(supervisor, Some(notes.clone()), Utc::now()).unwrap();
+        assert!(!alert.is_open());
+        assert_eq!(alert.resolved_by_person_id, Some(supervisor));
+        assert_eq!(alert.resolution_notes, Some(notes));
+    }
+
+    #[test]
+    fn test_informational_alert_resolves_without_acknowledgement() {
+        let mut alert = alert(false);
+
+        alert.resolve(Uuid::new_v4(), None, Utc::now()).unwrap();
+
+        assert!(!alert.is_open());
+        assert!(!alert.acknowledged);
+    }
+
+    #[test]
+    fn test_resolved_alert_is_final() {
+        let mut alert = alert(false);
+        alert.resolve(Uuid::new_v4(), None, Utc::now()).unwrap();
+
+        assert!(matches!(
+            alert.acknowledge(Uuid::new_v4(), Utc::now()),
+            Err(BankingError::PerformanceAlertAlreadyResolved(_))
+        ));
+        assert!(matches!(
+            alert.resolve(Uuid::new_v4(), None, Utc::now()),
+            Err(BankingError::PerformanceAlertAlreadyResolved(_))
+        ));
+    }
+
+    #[test]
+    fn test_release_alert_slot_frees_only_matching_slot() {
+        let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut metrics = AgentPerformanceMetrics {
+            id: Uuid::new_v4(),
+            collection_rate: Decimal::ZERO,
+            customer_satisfaction_score: Decimal::ZERO,
+            punctuality_score: Decimal::ZERO,
+            cash_handling_accuracy: Decimal::ZERO,
+            compliance_score: Decimal::ZERO,
+            total_collections: 0,
+            total_amount_collected: Decimal::ZERO,
+            average_collection_time_minutes: 0,
+            customer_retention_rate: Decimal::ZERO,
+            route_efficiency: Decimal::ZERO,
+            monthly_targets_id: Uuid::new_v4(),
+            performance_alert_1_id: Some(first),
+            performance_alert_2_id: Some(second),
+            performance_alert_3_id: None,
+            performance_alert_4_id: None,
+            performance_alert_5_id: None,
+        };
+
+        assert!(metrics.release_alert_slot(second));
+        assert_eq!(metrics.performance_alert_1_id, Some(first));
+        assert_eq!(metrics.performance_alert_2_id, None);
+        assert!(!metrics.release_alert_slot(second));
+    }
+    #[test]
+    fn test_coverage_area_bounding_box_skips_incomplete_points() {
+        let dec = |value: &str| Some(Decimal::from_str(value).unwrap());
+        let mut area = CoverageArea {
+            id: Uuid::new_v4(),
+            area_name: HeaplessString::try_from("Mokolo").unwrap(),
+            area_type: AreaType::Urban,
+            boundary_coordinates_long_1: dec("11.49"),
+            boundary_coordinates_lat_1: dec("3.87"),
+            boundary_coordinates_long_2: dec("11.51"),
+            boundary_coordinates_lat_2: dec("3.86"),
+            boundary_coordinates_long_3: dec("11.50"),
+            boundary_coordinates_lat_3: dec("3.89"),
+            boundary_coordinates_long_4: dec("12.00"),
+            boundary_coordinates_lat_4: None,
+            boundary_coordinates_long_5: None,
+            boundary_coordinates_lat_5: None,
+            customer_density: CustomerDensity::High,
+            transport_mode: TransportMode::Walking,
+        };
+
+        assert_eq!(
+            area.bounding_box(),
+            Some((dec("3.86").unwrap(), dec("11.49").unwrap(), dec("3.89").unwrap(), dec("11.51").unwrap()))
+        );
+
+        area.boundary_coordinates_lat_1 = None;
+        area.boundary_coordinates_lat_2 = None;
+        area.boundary_coordinates_lat_3 = None;
+        assert_eq!(area.bounding_box(), None);
+    }
+
+    fn device(last_sync: Option<DateTime<Utc>>) -> DeviceInformation {
+        DeviceInformation {
+            id: Uuid::new_v4(),
+            external_id: HeaplessString::try_from("IMEI-356938035643809").unwrap(),
+            device_type: DeviceType::Smartphone,
+            model: HeaplessString::try_from("Galaxy A14").unwrap(),
+            os_version: HeaplessString::try_from("Android 14").unwrap(),
+            app_version: HeaplessString::try_from("2.3.1").unwrap(),
+            last_sync,
+            battery_level: Some(0.8),
+            connectivity_status: ConnectivityStatus::Online,
+            security_features_id: Uuid::new_v4(),
+        }
+    }
+
+    fn security_features(encryption_enabled: bool, certificate_installed: bool) -> CollectionSecurityFeatures {
+        CollectionSecurityFeatures {
+            id: Uuid::new_v4(),
+            biometric_enabled: true,
+            pin_protection: true,
+            encryption_enabled,
+            remote_wipe_enabled: true,
+            certificate_installed,
+            last_security_scan: None,
+        }
+    }
+
+    fn now() -> DateTime<Utc> {
+        NaiveDate::from_ymd_opt(2024, 6, 10).unwrap().and_hms_opt(9, 0, 0).unwrap().and_utc()
+    }
+
+    #[test]
+    fn test_stale_sync_fails_device_compliance() {
+        let last_sync = now() - Duration::hours(73);
+
+        let failures = device(Some(last_sync)).compliance_failures(&security_features(true, true), now(), Duration::hours(72));
+
+        assert_eq!(failures, vec![DeviceComplianceError::SyncStale { last_sync, max_sync_age_hours: 72 }]);
+        assert_eq!(
+            device(None).compliance_failures(&security_features(true, true), now(), Duration::hours(72)),
+            vec![DeviceComplianceError::NeverSynced]
+        );
+    }
+
+    #[test]
+    fn test_compliant_device_passes_and_missing_security_features_are_named() {
+        let recent = device(Some(now() - Duration::hours(2)));
+
+        assert!(recent.compliance_failures(&security_features(true, true), now(), Duration::hours(72)).is_empty());
+        assert_eq!(
+            recent.compliance_failures(&security_features(false, false), now(), Duration::hours(72)),
+            vec![DeviceComplianceError::EncryptionDisabled, DeviceComplianceError::CertificateMissing]
+        );
+    }
+
+    #[test]
+    fn test_heartbeat_refreshes_sync_and_unblocks_device() {
+        let mut stale = device(Some(now() - Duration::days(5)));
+        let features = security_features(true, true);
+        assert!(!stale.compliance_failures(&features, now(), Duration::hours(72)).is_empty());
+
+        stale.record_heartbeat(Some(0.35), ConnectivityStatus::LimitedConnectivity, now());
+
+        assert_eq!(stale.last_sync, Some(now()));
+        assert_eq!(stale.battery_level, Some(0.35));
+        assert_eq!(stale.connectivity_status, ConnectivityStatus::LimitedConnectivity);
+        assert!(stale.compliance_failures(&features, now(), Duration::hours(72)).is_empty());
+    }
+}
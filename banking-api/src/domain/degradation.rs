@@ -0,0 +1,129 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Dependencies of the posting path. A critical dependency failing fails the
+/// posting; a non-critical one is skipped and recorded on the transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PostingDependency {
+    BalanceMath,
+    PostingPolicy,
+    MandateCheck,
+    Enrichment,
+    Categorization,
+    IntradayProjection,
+    Notification,
+}
+
+impl PostingDependency {
+    pub fn is_critical(self) -> bool {
+        matches!(
+            self,
+            PostingDependency::BalanceMath | PostingDependency::PostingPolicy | PostingDependency::MandateCheck
+        )
+    }
+
+    /// Flag set on a transaction posted without this dependency; none for critical ones
+    pub fn degraded_flag(self) -> DegradedFlags {
+        match self {
+            PostingDependency::Enrichment => DegradedFlags::ENRICHMENT,
+            PostingDependency::Categorization => DegradedFlags::CATEGORIZATION,
+            PostingDependency::IntradayProjection => DegradedFlags::INTRADAY_PROJECTION,
+            PostingDependency::Notification => DegradedFlags::NOTIFICATION,
+            PostingDependency::BalanceMath | PostingDependency::PostingPolicy | PostingDependency::MandateCheck => {
+                DegradedFlags::NONE
+            }
+        }
+    }
+}
+
+/// Bitset of the non-critical steps skipped when a transaction was posted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DegradedFlags(u32);
+
+impl DegradedFlags {
+    pub const NONE: DegradedFlags = DegradedFlags(0);
+    pub const ENRICHMENT: DegradedFlags = DegradedFlags(1);
+    pub const CATEGORIZATION: DegradedFlags = DegradedFlags(1 << 1);
+    pub const INTRADAY_PROJECTION: DegradedFlags = DegradedFlags(1 << 2);
+    pub const NOTIFICATION: DegradedFlags = DegradedFlags(1 << 3);
+    /// Steps the catch-up job runs again; projections and notifications are
+    /// only useful at posting time
+    pub const BACKFILLABLE: DegradedFlags = DegradedFlags(1 | 1 << 1);
+    const ALL: u32 = 0b1111;
+
+    /// Unknown bits are dropped
+    pub fn from_bits(bits: u32) -> Self {
+        DegradedFlags(bits & Self::ALL)
+    }
+
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Whether every flag of `other` is set
+    pub fn contains(self, other: DegradedFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether any flag of `other` is set
+    pub fn intersects(self, other: DegradedFlags) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    pub fn insert(&mut self, other: DegradedFlags) {
+        self.0 |= other.0;
+    }
+
+    pub fn remove(&mut self, other: DegradedFlags) {
+        self.0 &= !other.0;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CircuitBreakerState {
+    /// Calls go through
+    Closed,
+    /// Calls are skipped until the open period ends
+    Open,
+    /// One probe call decides whether to close or reopen
+    HalfOpen,
+}
+
+/// Counters of one circuit breaker since start-up
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CircuitBreakerMetrics {
+    pub dependency: PostingDependency,
+    pub state: CircuitBreakerState,
+    pub consecutive_failures: u32,
+    pub calls: u64,
+    pub failures: u64,
+    /// Calls skipped while open or while a probe was in flight
+    pub short_circuited: u64,
+    pub times_opened: u64,
+    pub last_state_change: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_degraded_flags_bitset() {
+        let mut flags = DegradedFlags::NONE;
+        flags.insert(PostingDependency::Enrichment.degraded_flag());
+        flags.insert(PostingDependency::Notification.degraded_flag());
+        flags.insert(PostingDependency::BalanceMath.degraded_flag());
+        assert_eq!(flags.bits(), 0b1001);
+        assert!(flags.intersects(DegradedFlags::BACKFILLABLE));
+        assert!(!flags.contains(DegradedFlags::BACKFILLABLE));
+
+        flags.remove(DegradedFlags::ENRICHMENT);
+        assert!(!flags.intersects(DegradedFlags::BACKFILLABLE));
+        assert_eq!(DegradedFlags::from_bits(0xFF).bits(), 0b1111);
+    }
+}
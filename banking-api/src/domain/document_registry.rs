@@ -0,0 +1,150 @@
+use blake3::Hash;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::DocumentReference;
+
+/// Blake3 hash documents are registered and verified by
+pub type ContentHash = Hash;
+
+pub fn hash_content(content: &[u8]) -> ContentHash {
+    blake3::hash(content)
+}
+
+/// Uploaded document content, registered once per content hash
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisteredDocument {
+    pub id: Uuid,
+    /// Blake3 hash of the content; unique in the registry
+    pub content_hash: Hash,
+    pub document_type: HeaplessString<50>,
+    pub document_path: Option<Hash>,
+    /// None when registered from a reference without its content
+    pub size_bytes: Option<u64>,
+    pub registered_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub registered_by_person_id: Uuid,
+}
+
+impl RegisteredDocument {
+    pub fn reference(&self) -> DocumentReference {
+        DocumentReference {
+            document_id: self.id,
+            content_hash: self.content_hash,
+            document_type: self.document_type.clone(),
+            document_path: self.document_path,
+        }
+    }
+}
+
+/// Kind of entity a registered document is linked to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DocumentLinkKind {
+    Workflow,
+    Customer,
+}
+
+/// A workflow or customer referencing a registered document
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentLink {
+    pub id: Uuid,
+    pub document_id: Uuid,
+    pub entity_kind: DocumentLinkKind,
+    pub entity_id: Uuid,
+    pub linked_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub linked_by_person_id: Uuid,
+}
+
+/// Entities referencing a document; a referenced document must not be purged
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentLinkage {
+    pub document_id: Uuid,
+    pub workflow_ids: Vec<Uuid>,
+    pub customer_ids: Vec<Uuid>,
+}
+
+impl DocumentLinkage {
+    pub fn from_links(document_id: Uuid, links: &[DocumentLink]) -> Self {
+        let ids_of = |kind: DocumentLinkKind| {
+            links
+                .iter()
+                .filter(|link| link.entity_kind == kind)
+                .map(|link| link.entity_id)
+                .collect()
+        };
+        Self {
+            document_id,
+            workflow_ids: ids_of(DocumentLinkKind::Workflow),
+            customer_ids: ids_of(DocumentLinkKind::Customer),
+        }
+    }
+
+    pub fn workflow_count(&self) -> usize {
+        self.workflow_ids.len()
+    }
+
+    pub fn customer_count(&self) -> usize {
+        self.customer_ids.len()
+    }
+
+    pub fn is_referenced(&self) -> bool {
+        !self.workflow_ids.is_empty() || !self.customer_ids.is_empty()
+    }
+}
+
+/// Result of registering an upload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentRegistration {
+    pub document: RegisteredDocument,
+    /// True when identical content was already registered and its id is returned
+    pub is_duplicate: bool,
+}
+
+/// Comparison of the hash a reviewer computed with the hash registered at upload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentIntegrityCheck {
+    pub document_id: Uuid,
+    pub registered_hash: Hash,
+    pub supplied_hash: Hash,
+}
+
+impl DocumentIntegrityCheck {
+    /// False when the content changed between upload and review
+    pub fn is_intact(&self) -> bool {
+        self.registered_hash == self.supplied_hash
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(entity_kind: DocumentLinkKind) -> DocumentLink {
+        DocumentLink {
+            id: Uuid::new_v4(),
+            document_id: Uuid::nil(),
+            entity_kind,
+            entity_id: Uuid::new_v4(),
+            linked_at: Utc::now(),
+            linked_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn test_linkage_counts_by_entity_kind() {
+        let links = vec![
+            link(DocumentLinkKind::Workflow),
+            link(DocumentLinkKind::Workflow),
+            link(DocumentLinkKind::Customer),
+        ];
+
+        let linkage = DocumentLinkage::from_links(Uuid::nil(), &links);
+        assert_eq!(linkage.workflow_count(), 2);
+        assert_eq!(linkage.customer_count(), 1);
+        assert!(linkage.is_referenced());
+        assert!(!DocumentLinkage::from_links(Uuid::nil(), &[]).is_referenced());
+    }
+}
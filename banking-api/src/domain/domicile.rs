@@ -0,0 +1,65 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Move of an account to another domicile branch. The account belongs to
+/// `to_agency_branch_id` from `effective_date` on; earlier dates stay with
+/// `from_agency_branch_id`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountDomicileChange {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    /// References AgencyBranch.id
+    pub from_agency_branch_id: Uuid,
+    /// References AgencyBranch.id
+    pub to_agency_branch_id: Uuid,
+    pub effective_date: NaiveDate,
+    /// References ReasonAndPurpose.id
+    pub reason_id: Uuid,
+    /// References Person.person_id
+    pub approved_by_person_id: Uuid,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Branch an account was domiciled at on `date`. Reports for past dates use
+/// this instead of the account's current branch: the first change taking
+/// effect after `date` still has the branch of that day as its origin.
+pub fn domicile_branch_on(current_agency_branch_id: Uuid, changes: &[AccountDomicileChange], date: NaiveDate) -> Uuid {
+    changes
+        .iter()
+        .filter(|c| c.effective_date > date)
+        .min_by_key(|c| (c.effective_date, c.recorded_at))
+        .map(|c| c.from_agency_branch_id)
+        .unwrap_or(current_agency_branch_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(from: Uuid, to: Uuid, effective_date: NaiveDate) -> AccountDomicileChange {
+        AccountDomicileChange {
+            id: Uuid::new_v4(),
+            account_id: Uuid::new_v4(),
+            from_agency_branch_id: from,
+            to_agency_branch_id: to,
+            effective_date,
+            reason_id: Uuid::new_v4(),
+            approved_by_person_id: Uuid::new_v4(),
+            recorded_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_attribution_straddles_effective_date() {
+        let (douala, yaounde, bafoussam) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let date = |d| NaiveDate::from_ymd_opt(2024, 3, d).unwrap();
+        let changes = vec![change(yaounde, bafoussam, date(20)), change(douala, yaounde, date(10))];
+
+        assert_eq!(domicile_branch_on(bafoussam, &changes, date(9)), douala);
+        assert_eq!(domicile_branch_on(bafoussam, &changes, date(10)), yaounde);
+        assert_eq!(domicile_branch_on(bafoussam, &changes, date(19)), yaounde);
+        assert_eq!(domicile_branch_on(bafoussam, &changes, date(20)), bafoussam);
+        assert_eq!(domicile_branch_on(douala, &[], date(1)), douala);
+    }
+}
@@ -0,0 +1,103 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EodRunStatus {
+    Running,
+    /// Stopped on a failing step; `resume` continues from its checkpoint
+    Failed,
+    Completed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EodStepStatus {
+    Pending,
+    Running,
+    Failed,
+    Completed,
+}
+
+/// One end-of-day run per business date, with the progress of each step
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EodRun {
+    pub id: Uuid,
+    pub run_date: NaiveDate,
+    pub status: EodRunStatus,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Times the run was started again after completing
+    pub forced_reruns: i32,
+    /// In execution order
+    pub steps: Vec<EodRunStep>,
+}
+
+impl EodRun {
+    pub fn start(run_date: NaiveDate, now: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            run_date,
+            status: EodRunStatus::Running,
+            started_at: now,
+            finished_at: None,
+            forced_reruns: 0,
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn step(&self, step_name: &str) -> Option<&EodRunStep> {
+        self.steps.iter().find(|s| s.step_name.as_str() == step_name)
+    }
+}
+
+/// Progress of one step of an end-of-day run. Chunked steps save their
+/// cursor after every chunk, so a failed step continues after the last
+/// record it checkpointed instead of starting over.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EodRunStep {
+    pub id: Uuid,
+    pub eod_run_id: Uuid,
+    pub run_date: NaiveDate,
+    pub step_name: HeaplessString<50>,
+    pub step_order: i32,
+    pub status: EodStepStatus,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub records_processed: i64,
+    pub records_failed: i64,
+    /// Last record of the last checkpointed chunk
+    pub checkpoint_cursor: Option<Uuid>,
+    /// Error that stopped the step, cleared when it is resumed
+    pub error: Option<HeaplessString<500>>,
+}
+
+impl EodRunStep {
+    pub fn pending(run: &EodRun, step_name: HeaplessString<50>, step_order: i32) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            eod_run_id: run.id,
+            run_date: run.run_date,
+            step_name,
+            step_order,
+            status: EodStepStatus::Pending,
+            started_at: None,
+            finished_at: None,
+            records_processed: 0,
+            records_failed: 0,
+            checkpoint_cursor: None,
+            error: None,
+        }
+    }
+
+    /// Back to pending with no progress, for a forced rerun
+    pub fn reset(&mut self) {
+        self.status = EodStepStatus::Pending;
+        self.started_at = None;
+        self.finished_at = None;
+        self.records_processed = 0;
+        self.records_failed = 0;
+        self.checkpoint_cursor = None;
+        self.error = None;
+    }
+}
@@ -0,0 +1,89 @@
+use chrono::{DateTime, Duration, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{BankingError, BankingResult};
+
+/// Rate converting one unit of `from_currency` into `to_currency`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeRate {
+    pub from_currency: HeaplessString<3>,
+    pub to_currency: HeaplessString<3>,
+    pub rate: Decimal,
+    /// Applies from this time until a later rate for the pair takes over
+    pub effective_at: DateTime<Utc>,
+    /// Publisher of the rate, e.g. the central bank fixing or a market data feed
+    pub source: HeaplessString<50>,
+}
+
+/// How old the rate used for a foreign-currency posting may be
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExchangeRatePolicy {
+    pub max_age: Duration,
+    /// Refuse postings at a stale rate instead of posting them with a warning
+    pub reject_stale: bool,
+}
+
+impl ExchangeRate {
+    /// `amount` of `from_currency` in `to_currency`, unrounded
+    pub fn convert(&self, amount: Decimal) -> Decimal {
+        amount * self.rate
+    }
+
+    /// Effective for longer than the policy allows at `at`
+    pub fn is_stale(&self, policy: &ExchangeRatePolicy, at: DateTime<Utc>) -> bool {
+        at - self.effective_at > policy.max_age
+    }
+
+    /// Refuse a stale rate when the policy rejects them; a stale rate the
+    /// policy tolerates passes and is for the caller to report
+    pub fn ensure_usable(&self, policy: &ExchangeRatePolicy, at: DateTime<Utc>) -> BankingResult<()> {
+        if policy.reject_stale && self.is_stale(policy, at) {
+            return Err(BankingError::StaleExchangeRate {
+                base_currency: self.from_currency.to_string(),
+                quote_currency: self.to_currency.to_string(),
+                effective_at: self.effective_at,
+                max_age_hours: policy.max_age.num_hours(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn usd_to_xaf(effective_at: DateTime<Utc>) -> ExchangeRate {
+        ExchangeRate {
+            from_currency: HeaplessString::try_from("USD").unwrap(),
+            to_currency: HeaplessString::try_from("XAF").unwrap(),
+            rate: Decimal::new(60525, 2),
+            effective_at,
+            source: HeaplessString::try_from("BEAC").unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_stale_rate_is_rejected_only_when_policy_says_so() {
+        let effective_at = Utc.with_ymd_and_hms(2024, 6, 14, 8, 0, 0).unwrap();
+        let rate = usd_to_xaf(effective_at);
+        let warn = ExchangeRatePolicy { max_age: Duration::hours(24), reject_stale: false };
+        let reject = ExchangeRatePolicy { reject_stale: true, ..warn };
+
+        let fresh_at = effective_at + Duration::hours(24);
+        assert!(!rate.is_stale(&reject, fresh_at));
+        assert!(rate.ensure_usable(&reject, fresh_at).is_ok());
+
+        let stale_at = fresh_at + Duration::minutes(1);
+        assert!(rate.is_stale(&warn, stale_at));
+        assert!(rate.ensure_usable(&warn, stale_at).is_ok());
+        assert!(matches!(
+            rate.ensure_usable(&reject, stale_at),
+            Err(BankingError::StaleExchangeRate { base_currency, quote_currency, max_age_hours: 24, .. })
+                if base_currency == "USD" && quote_currency == "XAF"
+        ));
+    }
+}
@@ -33,6 +33,10 @@ pub struct FeeApplication {
     pub waived_reason_id: Option<Uuid>,
     /// References Person.person_id
     pub applied_by: Uuid,
+    /// References Transaction.id of the compensating credit posted on reversal
+    pub reversal_transaction_id: Option<Uuid>,
+    /// References AuditLog.id of the last waiver or reversal
+    pub audit_log_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -103,12 +107,21 @@ pub enum FeeTriggerEvent {
     MonthlyMaintenance,
     QuarterlyMaintenance,
     AnnualMaintenance,
+    /// Monthly cycle of a dormant account past its product's inactivity threshold
+    AccountInactivity,
     
     // Card-based triggers
     CardIssuance,
     CardReplacement,
     CardActivation,
     
+    // Cheque-based triggers
+    ChequeBookIssuance,
+    StopPayment,
+
+    // Loan-based triggers
+    LoanEarlyRepayment,
+    
     // Other triggers
     Manual,
     Regulatory,
@@ -385,9 +398,13 @@ impl std::fmt::Display for FeeTriggerEvent {
             FeeTriggerEvent::MonthlyMaintenance => write!(f, "MonthlyMaintenance"),
             FeeTriggerEvent::QuarterlyMaintenance => write!(f, "QuarterlyMaintenance"),
             FeeTriggerEvent::AnnualMaintenance => write!(f, "AnnualMaintenance"),
+            FeeTriggerEvent::AccountInactivity => write!(f, "AccountInactivity"),
             FeeTriggerEvent::CardIssuance => write!(f, "CardIssuance"),
             FeeTriggerEvent::CardReplacement => write!(f, "CardReplacement"),
             FeeTriggerEvent::CardActivation => write!(f, "CardActivation"),
+            FeeTriggerEvent::ChequeBookIssuance => write!(f, "ChequeBookIssuance"),
+            FeeTriggerEvent::StopPayment => write!(f, "StopPayment"),
+            FeeTriggerEvent::LoanEarlyRepayment => write!(f, "LoanEarlyRepayment"),
             FeeTriggerEvent::Manual => write!(f, "Manual"),
             FeeTriggerEvent::Regulatory => write!(f, "Regulatory"),
         }
@@ -414,9 +431,13 @@ impl std::str::FromStr for FeeTriggerEvent {
             "MonthlyMaintenance" => Ok(FeeTriggerEvent::MonthlyMaintenance),
             "QuarterlyMaintenance" => Ok(FeeTriggerEvent::QuarterlyMaintenance),
             "AnnualMaintenance" => Ok(FeeTriggerEvent::AnnualMaintenance),
+            "AccountInactivity" => Ok(FeeTriggerEvent::AccountInactivity),
             "CardIssuance" => Ok(FeeTriggerEvent::CardIssuance),
             "CardReplacement" => Ok(FeeTriggerEvent::CardReplacement),
             "CardActivation" => Ok(FeeTriggerEvent::CardActivation),
+            "ChequeBookIssuance" => Ok(FeeTriggerEvent::ChequeBookIssuance),
+            "StopPayment" => Ok(FeeTriggerEvent::StopPayment),
+            "LoanEarlyRepayment" => Ok(FeeTriggerEvent::LoanEarlyRepayment),
             "Manual" => Ok(FeeTriggerEvent::Manual),
             "Regulatory" => Ok(FeeTriggerEvent::Regulatory),
             _ => Err(format!("Invalid FeeTriggerEvent: {s}")),
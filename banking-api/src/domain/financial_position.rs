@@ -0,0 +1,228 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::ExchangeRate;
+
+/// Aggregated position of a customer's owned accounts in one currency
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CurrencyPosition {
+    pub currency: HeaplessString<3>,
+    /// Open accounts owned by the customer in this currency
+    pub account_count: i64,
+    /// Positive balances of deposit accounts
+    pub total_deposits: Decimal,
+    /// Outstanding principal of loan accounts
+    pub loan_exposure: Decimal,
+    /// Exposure on loans the customer guarantees, capped at each guarantee amount
+    pub guarantees_given: Decimal,
+    /// Active holds on the owned accounts
+    pub holds: Decimal,
+    /// Part of `holds` placed by court order
+    pub legal_holds: Decimal,
+    pub overdraft_used: Decimal,
+    pub overdraft_limit: Decimal,
+}
+
+impl CurrencyPosition {
+    pub fn empty(currency: HeaplessString<3>) -> Self {
+        Self {
+            currency,
+            account_count: 0,
+            total_deposits: Decimal::ZERO,
+            loan_exposure: Decimal::ZERO,
+            guarantees_given: Decimal::ZERO,
+            holds: Decimal::ZERO,
+            legal_holds: Decimal::ZERO,
+            overdraft_used: Decimal::ZERO,
+            overdraft_limit: Decimal::ZERO,
+        }
+    }
+
+    fn converted(&self, rate: Decimal) -> Self {
+        let convert = |amount: Decimal| (amount * rate).round_dp(2);
+        Self {
+            currency: self.currency.clone(),
+            account_count: self.account_count,
+            total_deposits: convert(self.total_deposits),
+            loan_exposure: convert(self.loan_exposure),
+            guarantees_given: convert(self.guarantees_given),
+            holds: convert(self.holds),
+            legal_holds: convert(self.legal_holds),
+            overdraft_used: convert(self.overdraft_used),
+            overdraft_limit: convert(self.overdraft_limit),
+        }
+    }
+}
+
+/// Position totals in the base currency
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FinancialPositionTotals {
+    pub total_deposits: Decimal,
+    pub loan_exposure: Decimal,
+    pub guarantees_given: Decimal,
+    pub holds: Decimal,
+    pub legal_holds: Decimal,
+    pub overdraft_used: Decimal,
+    pub overdraft_limit: Decimal,
+}
+
+impl FinancialPositionTotals {
+    /// Share of the overdraft limit in use, as a percentage; `None` without a limit
+    pub fn overdraft_utilization(&self) -> Option<Decimal> {
+        if self.overdraft_limit.is_zero() {
+            return None;
+        }
+        Some((self.overdraft_used * Decimal::ONE_HUNDRED / self.overdraft_limit).round_dp(2))
+    }
+
+    /// Deposits not blocked by holds
+    pub fn free_deposits(&self) -> Decimal {
+        (self.total_deposits - self.holds).max(Decimal::ZERO)
+    }
+}
+
+/// A customer's consolidated assets and liabilities for credit decisions.
+/// Totals only include currencies with a rate into the base currency; the
+/// others are listed in `unconverted_currencies` and kept in the detail.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinancialPositionView {
+    pub customer_id: Uuid,
+    pub base_currency: HeaplessString<3>,
+    pub as_of: NaiveDate,
+    /// Per-currency detail in the account currency
+    pub currencies: Vec<CurrencyPosition>,
+    pub rates_used: Vec<ExchangeRate>,
+    pub totals: FinancialPositionTotals,
+    pub unconverted_currencies: Vec<HeaplessString<3>>,
+    /// Some guarantee given has no cap, so the guarantee total understates the liability
+    pub has_unlimited_guarantee: bool,
+    pub generated_at: DateTime<Utc>,
+}
+
+impl FinancialPositionView {
+    /// `rates` holds the latest rate into the base currency per currency
+    pub fn build(
+        customer_id: Uuid,
+        base_currency: HeaplessString<3>,
+        as_of: NaiveDate,
+        currencies: Vec<CurrencyPosition>,
+        rates: Vec<ExchangeRate>,
+        has_unlimited_guarantee: bool,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let mut totals = FinancialPositionTotals {
+            total_deposits: Decimal::ZERO,
+            loan_exposure: Decimal::ZERO,
+            guarantees_given: Decimal::ZERO,
+            holds: Decimal::ZERO,
+            legal_holds: Decimal::ZERO,
+            overdraft_used: Decimal::ZERO,
+            overdraft_limit: Decimal::ZERO,
+        };
+        let mut rates_used = Vec::new();
+        let mut unconverted_currencies = Vec::new();
+
+        for position in &currencies {
+            let rate = if position.currency == base_currency {
+                Decimal::ONE
+            } else if let Some(rate) = rates
+                .iter()
+                .find(|r| r.from_currency == position.currency && r.to_currency == base_currency)
+            {
+                rates_used.push(rate.clone());
+                rate.rate
+            } else {
+                unconverted_currencies.push(position.currency.clone());
+                continue;
+            };
+
+            let converted = position.converted(rate);
+            totals.total_deposits += converted.total_deposits;
+            totals.loan_exposure += converted.loan_exposure;
+            totals.guarantees_given += converted.guarantees_given;
+            totals.holds += converted.holds;
+            totals.legal_holds += converted.legal_holds;
+            totals.overdraft_used += converted.overdraft_used;
+            totals.overdraft_limit += converted.overdraft_limit;
+        }
+
+        Self {
+            customer_id,
+            base_currency,
+            as_of,
+            currencies,
+            rates_used,
+            totals,
+            unconverted_currencies,
+            has_unlimited_guarantee,
+            generated_at: now,
+        }
+    }
+
+    pub fn is_fully_converted(&self) -> bool {
+        self.unconverted_currencies.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn currency(code: &str) -> HeaplessString<3> {
+        HeaplessString::try_from(code).unwrap()
+    }
+
+    #[test]
+    fn test_totals_convert_with_rates_and_skip_unconvertible_currencies() {
+        let as_of = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+        let xaf = CurrencyPosition {
+            total_deposits: Decimal::from(600_000),
+            overdraft_used: Decimal::from(60_000),
+            overdraft_limit: Decimal::from(120_000),
+            ..CurrencyPosition::empty(currency("XAF"))
+        };
+        let eur = CurrencyPosition {
+            total_deposits: Decimal::from(500),
+            loan_exposure: Decimal::from(2_000),
+            holds: Decimal::from(100),
+            legal_holds: Decimal::from(100),
+            ..CurrencyPosition::empty(currency("EUR"))
+        };
+        let ngn = CurrencyPosition {
+            total_deposits: Decimal::from(1_000_000),
+            ..CurrencyPosition::empty(currency("NGN"))
+        };
+        let rates = vec![ExchangeRate {
+            from_currency: currency("XAF"),
+            to_currency: currency("EUR"),
+            rate: Decimal::new(15, 4),
+            effective_at: Utc.with_ymd_and_hms(2024, 6, 30, 9, 0, 0).unwrap(),
+            source: HeaplessString::try_from("BEAC").unwrap(),
+        }];
+
+        let view = FinancialPositionView::build(
+            Uuid::new_v4(),
+            currency("EUR"),
+            as_of,
+            vec![xaf, eur, ngn.clone()],
+            rates.clone(),
+            false,
+            Utc::now(),
+        );
+
+        assert_eq!(view.rates_used, rates);
+        assert_eq!(view.totals.total_deposits, Decimal::from(1_400));
+        assert_eq!(view.totals.loan_exposure, Decimal::from(2_000));
+        assert_eq!(view.totals.legal_holds, Decimal::from(100));
+        assert_eq!(view.totals.overdraft_utilization(), Some(Decimal::new(5000, 2)));
+        assert_eq!(view.totals.free_deposits(), Decimal::from(1_300));
+        assert_eq!(view.unconverted_currencies, vec![currency("NGN")]);
+        assert!(!view.is_fully_converted());
+        // The unconverted currency stays in the detail
+        assert!(view.currencies.contains(&ngn));
+    }
+}
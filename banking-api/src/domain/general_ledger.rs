@@ -0,0 +1,187 @@
+use chrono::NaiveDate;
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+use crate::domain::{CurrencyCode, TransactionType};
+use crate::error::{BankingError, BankingResult};
+
+/// GL suffix of accounts without their own
+pub const DEFAULT_GL_CODE_SUFFIX: &str = "001";
+
+/// GL code of a customer account: its product's customer account code
+/// followed by the account's suffix
+pub fn customer_gl_code(customer_account_code: &str, gl_code_suffix: Option<&str>) -> String {
+    format!("{}{}", customer_account_code, gl_code_suffix.unwrap_or(DEFAULT_GL_CODE_SUFFIX))
+}
+
+/// Customer postings of a run date on accounts sharing product, GL code,
+/// branch and currency, made through one channel in one direction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GlPostingTotals {
+    pub product_id: Uuid,
+    pub branch_id: Uuid,
+    pub currency: CurrencyCode,
+    pub customer_gl_code: HeaplessString<50>,
+    /// Clearing GL code of the channel, which carries the opposite leg
+    pub contra_gl_code: HeaplessString<50>,
+    /// Direction on the customer account
+    pub transaction_type: TransactionType,
+    pub amount: Decimal,
+    pub transaction_count: i64,
+}
+
+/// Debits and credits booked to one GL code on a run date
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GlSummaryLine {
+    pub run_date: NaiveDate,
+    pub gl_code: HeaplessString<50>,
+    pub product_id: Uuid,
+    pub branch_id: Uuid,
+    pub currency: CurrencyCode,
+    pub debit_total: Decimal,
+    pub credit_total: Decimal,
+    pub transaction_count: i64,
+}
+
+/// End-of-day GL summary, built leg by leg so every posting lands on both
+/// its customer GL code and its channel's clearing GL code
+#[derive(Debug, Clone)]
+pub struct GlSummary {
+    run_date: NaiveDate,
+    lines: BTreeMap<(HeaplessString<50>, Uuid, Uuid, CurrencyCode), GlSummaryLine>,
+}
+
+impl GlSummary {
+    pub fn new(run_date: NaiveDate) -> Self {
+        Self { run_date, lines: BTreeMap::new() }
+    }
+
+    pub fn add(&mut self, totals: &GlPostingTotals) {
+        let customer_debit = totals.transaction_type == TransactionType::Debit;
+        self.book(totals, &totals.customer_gl_code, customer_debit);
+        self.book(totals, &totals.contra_gl_code, !customer_debit);
+    }
+
+    fn book(&mut self, totals: &GlPostingTotals, gl_code: &HeaplessString<50>, debit: bool) {
+        let run_date = self.run_date;
+        let line = self.lines
+            .entry((gl_code.clone(), totals.product_id, totals.branch_id, totals.currency.clone()))
+            .or_insert_with(|| GlSummaryLine {
+                run_date,
+                gl_code: gl_code.clone(),
+                product_id: totals.product_id,
+                branch_id: totals.branch_id,
+                currency: totals.currency.clone(),
+                debit_total: Decimal::ZERO,
+                credit_total: Decimal::ZERO,
+                transaction_count: 0,
+            });
+        if debit {
+            line.debit_total += totals.amount;
+        } else {
+            line.credit_total += totals.amount;
+        }
+        line.transaction_count += totals.transaction_count;
+    }
+
+    /// The summary lines, ordered by GL code, once they balance
+    pub fn into_lines(self) -> BankingResult<Vec<GlSummaryLine>> {
+        let lines: Vec<_> = self.lines.into_values().collect();
+        ensure_gl_balanced(self.run_date, &lines)?;
+        Ok(lines)
+    }
+}
+
+/// Fails with the imbalance of the first currency whose debits and credits differ
+pub fn ensure_gl_balanced(run_date: NaiveDate, lines: &[GlSummaryLine]) -> BankingResult<()> {
+    let mut totals: BTreeMap<&CurrencyCode, (Decimal, Decimal)> = BTreeMap::new();
+    for line in lines {
+        let entry = totals.entry(&line.currency).or_default();
+        entry.0 += line.debit_total;
+        entry.1 += line.credit_total;
+    }
+    match totals.into_iter().find(|(_, (debit_total, credit_total))| debit_total != credit_total) {
+        Some((currency, (debit_total, credit_total))) => Err(BankingError::GlSummaryImbalance {
+            run_date,
+            currency: currency.to_string(),
+            debit_total,
+            credit_total,
+            imbalance: debit_total - credit_total,
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn code(gl_code: &str) -> HeaplessString<50> {
+        HeaplessString::try_from(gl_code).unwrap()
+    }
+
+    fn totals(branch_id: Uuid, channel_gl: &str, transaction_type: TransactionType, amount: Decimal, count: i64) -> GlPostingTotals {
+        GlPostingTotals {
+            product_id: Uuid::from_u128(1),
+            branch_id,
+            currency: CurrencyCode::try_from("XAF").unwrap(),
+            customer_gl_code: code(&customer_gl_code("2100", None)),
+            contra_gl_code: code(channel_gl),
+            transaction_type,
+            amount,
+            transaction_count: count,
+        }
+    }
+
+    #[test]
+    fn test_summary_across_two_branches_balances() {
+        let douala = Uuid::from_u128(10);
+        let yaounde = Uuid::from_u128(20);
+        let run_date = NaiveDate::from_ymd_opt(2024, 6, 14).unwrap();
+        let mut summary = GlSummary::new(run_date);
+        // Two cash deposits and a withdrawal in Douala, a mobile transfer out in Yaoundé
+        summary.add(&totals(douala, "1010", TransactionType::Credit, Decimal::from(150000), 2));
+        summary.add(&totals(douala, "1010", TransactionType::Debit, Decimal::from(40000), 1));
+        summary.add(&totals(yaounde, "1920", TransactionType::Debit, Decimal::from(25000), 1));
+
+        let lines = summary.into_lines().unwrap();
+        assert_eq!(lines.len(), 4);
+        let line = |gl_code: &str, branch_id: Uuid| {
+            lines.iter().find(|line| line.gl_code == code(gl_code) && line.branch_id == branch_id).unwrap()
+        };
+
+        let douala_customers = line("2100001", douala);
+        assert_eq!((douala_customers.debit_total, douala_customers.credit_total), (Decimal::from(40000), Decimal::from(150000)));
+        assert_eq!(douala_customers.transaction_count, 3);
+        let douala_cash = line("1010", douala);
+        assert_eq!((douala_cash.debit_total, douala_cash.credit_total), (Decimal::from(150000), Decimal::from(40000)));
+        let yaounde_clearing = line("1920", yaounde);
+        assert_eq!((yaounde_clearing.debit_total, yaounde_clearing.credit_total), (Decimal::ZERO, Decimal::from(25000)));
+        assert_eq!(line("2100001", yaounde).debit_total, Decimal::from(25000));
+
+        let debits: Decimal = lines.iter().map(|line| line.debit_total).sum();
+        let credits: Decimal = lines.iter().map(|line| line.credit_total).sum();
+        assert_eq!(debits, Decimal::from(215000));
+        assert_eq!(debits, credits);
+    }
+
+    #[test]
+    fn test_imbalance_is_reported_with_its_amount() {
+        let run_date = NaiveDate::from_ymd_opt(2024, 6, 14).unwrap();
+        let mut summary = GlSummary::new(run_date);
+        summary.add(&totals(Uuid::from_u128(10), "1010", TransactionType::Credit, Decimal::from(1000), 1));
+        let mut lines = summary.into_lines().unwrap();
+        lines[0].debit_total += Decimal::new(50, 2);
+
+        match ensure_gl_balanced(run_date, &lines) {
+            Err(BankingError::GlSummaryImbalance { currency, imbalance, .. }) => {
+                assert_eq!(currency, "XAF");
+                assert_eq!(imbalance, Decimal::new(50, 2));
+            }
+            other => panic!("Expected GlSummaryImbalance, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A third party guaranteeing repayment of a loan account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoanGuarantor {
+    pub id: Uuid,
+    pub loan_account_id: Uuid,
+    pub guarantor_type: GuarantorType,
+    /// References Customer.id or Person.person_id depending on `guarantor_type`
+    pub guarantor_id: Uuid,
+    /// Maximum amount guaranteed; `None` for an unlimited guarantee
+    pub guarantee_amount: Option<Decimal>,
+    /// Reference of the signed guarantee agreement
+    pub document_reference: Option<HeaplessString<100>>,
+    pub status: GuarantorStatus,
+    pub released_at: Option<DateTime<Utc>>,
+    /// References Person.person_id
+    pub released_by_person_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub updated_by_person_id: Uuid,
+}
+
+impl LoanGuarantor {
+    pub fn is_unlimited(&self) -> bool {
+        self.guarantee_amount.is_none()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GuarantorType {
+    /// An onboarded customer, subject to KYC
+    Customer,
+    /// A person known to the bank but not onboarded as a customer
+    Person,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GuarantorStatus {
+    Active,
+    Released,
+}
+
+/// Exposure of a guarantor on a single guaranteed loan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuaranteedLoanExposure {
+    pub loan_account_id: Uuid,
+    pub outstanding_principal: Decimal,
+    pub guarantee_amount: Option<Decimal>,
+    /// Outstanding principal capped at the guarantee amount
+    pub exposure: Decimal,
+}
+
+impl GuaranteedLoanExposure {
+    pub fn new(loan_account_id: Uuid, outstanding_principal: Decimal, guarantee_amount: Option<Decimal>) -> Self {
+        let outstanding = outstanding_principal.max(Decimal::ZERO);
+        let exposure = match guarantee_amount {
+            Some(cap) => outstanding.min(cap),
+            None => outstanding,
+        };
+        Self {
+            loan_account_id,
+            outstanding_principal,
+            guarantee_amount,
+            exposure,
+        }
+    }
+}
+
+/// Aggregate contingent liability of a guarantor, used in credit decisions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuarantorExposure {
+    pub guarantor_id: Uuid,
+    pub loans: Vec<GuaranteedLoanExposure>,
+    pub total_exposure: Decimal,
+    /// True if any active guarantee is unlimited
+    pub has_unlimited_guarantee: bool,
+}
+
+impl GuarantorExposure {
+    pub fn aggregate(guarantor_id: Uuid, loans: Vec<GuaranteedLoanExposure>) -> Self {
+        let total_exposure = loans.iter().map(|loan| loan.exposure).sum();
+        let has_unlimited_guarantee = loans.iter().any(|loan| loan.guarantee_amount.is_none());
+        Self {
+            guarantor_id,
+            loans,
+            total_exposure,
+            has_unlimited_guarantee,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exposure_with_mixed_capped_and_unlimited_guarantees() {
+        let guarantor_id = Uuid::new_v4();
+        let loans = vec![
+            // Cap below outstanding: exposure is the cap
+            GuaranteedLoanExposure::new(Uuid::new_v4(), Decimal::from(10_000), Some(Decimal::from(4_000))),
+            // Cap above outstanding: exposure is the outstanding principal
+            GuaranteedLoanExposure::new(Uuid::new_v4(), Decimal::from(2_500), Some(Decimal::from(5_000))),
+            // Unlimited: full outstanding principal
+            GuaranteedLoanExposure::new(Uuid::new_v4(), Decimal::from(7_000), None),
+            // Fully repaid loan carries no exposure
+            GuaranteedLoanExposure::new(Uuid::new_v4(), Decimal::ZERO, None),
+        ];
+
+        let exposure = GuarantorExposure::aggregate(guarantor_id, loans);
+
+        assert_eq!(exposure.loans[0].exposure, Decimal::from(4_000));
+        assert_eq!(exposure.loans[1].exposure, Decimal::from(2_500));
+        assert_eq!(exposure.loans[2].exposure, Decimal::from(7_000));
+        assert_eq!(exposure.loans[3].exposure, Decimal::ZERO);
+        assert_eq!(exposure.total_exposure, Decimal::from(13_500));
+        assert!(exposure.has_unlimited_guarantee);
+    }
+
+    #[test]
+    fn test_exposure_without_guarantees() {
+        let exposure = GuarantorExposure::aggregate(Uuid::new_v4(), vec![]);
+        assert_eq!(exposure.total_exposure, Decimal::ZERO);
+        assert!(!exposure.has_unlimited_guarantee);
+    }
+
+    #[test]
+    fn test_capped_only_exposure() {
+        let exposure = GuarantorExposure::aggregate(Uuid::new_v4(), vec![
+            GuaranteedLoanExposure::new(Uuid::new_v4(), Decimal::from(1_000), Some(Decimal::from(600))),
+            GuaranteedLoanExposure::new(Uuid::new_v4(), Decimal::from(300), Some(Decimal::from(600))),
+        ]);
+        assert_eq!(exposure.total_exposure, Decimal::from(900));
+        assert!(!exposure.has_unlimited_guarantee);
+    }
+}
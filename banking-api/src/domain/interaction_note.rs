@@ -0,0 +1,147 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Kind of entity a note is written on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NoteEntityKind {
+    Customer,
+    Account,
+}
+
+/// Who may read a note
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NoteVisibility {
+    /// Staff of the note's branch, head office and compliance
+    BranchOnly,
+    /// Every member of staff
+    BankWide,
+    /// Compliance staff only; hidden even from the author's branch
+    ComplianceOnly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NoteCategory {
+    BranchVisit,
+    PhoneCall,
+    Complaint,
+    ServiceRequest,
+    Compliance,
+    General,
+}
+
+/// Scope a member of staff reads notes in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NoteReaderScope {
+    /// Branch staff, references AgencyBranch.id
+    Branch(Uuid),
+    /// Head office staff outside compliance
+    HeadOffice,
+    Compliance,
+}
+
+/// Staff note or interaction record on a customer or account. Notes are
+/// immutable: a correction is a new note referencing the one it corrects.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InteractionNote {
+    pub id: Uuid,
+    pub entity_kind: NoteEntityKind,
+    pub entity_id: Uuid,
+    /// References Person.person_id
+    pub author_person_id: Uuid,
+    /// Branch the note was written at, references AgencyBranch.id.
+    /// Restricts the readers of a BranchOnly note.
+    pub agency_branch_id: Option<Uuid>,
+    pub visibility: NoteVisibility,
+    pub category: NoteCategory,
+    pub body: HeaplessString<1000>,
+    /// References InteractionNote.id of the note this one corrects
+    pub corrects_note_id: Option<Uuid>,
+    pub follow_up_date: Option<NaiveDate>,
+    /// Set when the author closes the follow-up; the only change a note takes
+    pub follow_up_completed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl InteractionNote {
+    pub fn is_visible_to(&self, scope: NoteReaderScope) -> bool {
+        match (self.visibility, scope) {
+            (_, NoteReaderScope::Compliance) => true,
+            (NoteVisibility::ComplianceOnly, _) => false,
+            (NoteVisibility::BankWide, _) => true,
+            (NoteVisibility::BranchOnly, NoteReaderScope::HeadOffice) => true,
+            (NoteVisibility::BranchOnly, NoteReaderScope::Branch(branch_id)) => self.agency_branch_id == Some(branch_id),
+        }
+    }
+
+    /// Follow-up set, not completed and due on or before `date`
+    pub fn is_follow_up_due(&self, date: NaiveDate) -> bool {
+        self.follow_up_completed_at.is_none() && self.follow_up_date.is_some_and(|due| due <= date)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InteractionNoteRequest {
+    pub entity_kind: NoteEntityKind,
+    pub entity_id: Uuid,
+    /// Required for a BranchOnly note
+    pub agency_branch_id: Option<Uuid>,
+    pub visibility: NoteVisibility,
+    pub category: NoteCategory,
+    pub body: HeaplessString<1000>,
+    pub corrects_note_id: Option<Uuid>,
+    pub follow_up_date: Option<NaiveDate>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note(visibility: NoteVisibility, agency_branch_id: Option<Uuid>) -> InteractionNote {
+        InteractionNote {
+            id: Uuid::new_v4(),
+            entity_kind: NoteEntityKind::Account,
+            entity_id: Uuid::new_v4(),
+            author_person_id: Uuid::new_v4(),
+            agency_branch_id,
+            visibility,
+            category: NoteCategory::BranchVisit,
+            body: HeaplessString::try_from("Customer asked about overdraft limits").unwrap(),
+            corrects_note_id: None,
+            follow_up_date: None,
+            follow_up_completed_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_visibility_per_reader_scope() {
+        let (douala, yaounde) = (Uuid::new_v4(), Uuid::new_v4());
+        let branch_only = note(NoteVisibility::BranchOnly, Some(douala));
+        let bank_wide = note(NoteVisibility::BankWide, Some(yaounde));
+        let compliance_only = note(NoteVisibility::ComplianceOnly, Some(douala));
+
+        assert!(branch_only.is_visible_to(NoteReaderScope::Branch(douala)));
+        assert!(!branch_only.is_visible_to(NoteReaderScope::Branch(yaounde)));
+        assert!(branch_only.is_visible_to(NoteReaderScope::HeadOffice));
+        assert!(bank_wide.is_visible_to(NoteReaderScope::Branch(douala)));
+        assert!(!compliance_only.is_visible_to(NoteReaderScope::Branch(douala)));
+        assert!(!compliance_only.is_visible_to(NoteReaderScope::HeadOffice));
+        assert!(compliance_only.is_visible_to(NoteReaderScope::Compliance));
+    }
+
+    #[test]
+    fn test_follow_up_due_until_completed() {
+        let date = |d| NaiveDate::from_ymd_opt(2024, 5, d).unwrap();
+        let mut follow_up = note(NoteVisibility::BankWide, None);
+        assert!(!follow_up.is_follow_up_due(date(10)));
+
+        follow_up.follow_up_date = Some(date(10));
+        assert!(!follow_up.is_follow_up_due(date(9)));
+        assert!(follow_up.is_follow_up_due(date(10)));
+        assert!(follow_up.is_follow_up_due(date(11)));
+        follow_up.follow_up_completed_at = Some(Utc::now());
+        assert!(!follow_up.is_follow_up_due(date(11)));
+    }
+}
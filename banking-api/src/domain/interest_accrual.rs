@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// Tuning for the chunked accrual run
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct AccrualOptions {
+    /// Chunks processed concurrently
+    pub parallelism: usize,
+    /// Accounts per chunk, taken in account id order
+    pub chunk_size: i64,
+    /// Attempts per chunk before it is reported as failed
+    pub max_chunk_attempts: u32,
+}
+
+impl Default for AccrualOptions {
+    fn default() -> Self {
+        Self {
+            parallelism: 4,
+            chunk_size: 1000,
+            max_chunk_attempts: 3,
+        }
+    }
+}
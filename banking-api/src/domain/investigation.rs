@@ -0,0 +1,92 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Kind of entry on an account timeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimelineCategory {
+    WorkflowCreated,
+    WorkflowStep,
+    WorkflowCompleted,
+    StatusChange,
+    HoldPlaced,
+    HoldReleased,
+    RestrictionPlaced,
+    RestrictionLifted,
+    Transaction,
+    /// One day of transactions on an account above the summary threshold
+    TransactionSummary,
+    NotificationSent,
+}
+
+impl TimelineCategory {
+    /// Stored name; also the tie-break between entries of the same instant
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimelineCategory::WorkflowCreated => "WorkflowCreated",
+            TimelineCategory::WorkflowStep => "WorkflowStep",
+            TimelineCategory::WorkflowCompleted => "WorkflowCompleted",
+            TimelineCategory::StatusChange => "StatusChange",
+            TimelineCategory::HoldPlaced => "HoldPlaced",
+            TimelineCategory::HoldReleased => "HoldReleased",
+            TimelineCategory::RestrictionPlaced => "RestrictionPlaced",
+            TimelineCategory::RestrictionLifted => "RestrictionLifted",
+            TimelineCategory::Transaction => "Transaction",
+            TimelineCategory::TransactionSummary => "TransactionSummary",
+            TimelineCategory::NotificationSent => "NotificationSent",
+        }
+    }
+}
+
+/// One normalized entry of an account timeline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimelineEvent {
+    pub occurred_at: DateTime<Utc>,
+    pub category: TimelineCategory,
+    /// References Person.person_id; None for system and customer events
+    pub actor_person_id: Option<Uuid>,
+    pub summary: HeaplessString<500>,
+    /// Id of the workflow, status change, hold, restriction, transaction or notification
+    pub reference_id: Uuid,
+}
+
+impl TimelineEvent {
+    /// Chronological, then by category name and reference id so that equal
+    /// timestamps always come out in the same order
+    pub fn sort_key(&self) -> (DateTime<Utc>, &'static str, Uuid) {
+        (self.occurred_at, self.category.name(), self.reference_id)
+    }
+}
+
+/// Case file export of an account timeline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountTimeline {
+    pub account_id: Uuid,
+    pub from_date: NaiveDate,
+    /// Inclusive
+    pub to_date: NaiveDate,
+    pub generated_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub generated_by_person_id: Uuid,
+    pub case_reference: HeaplessString<100>,
+    pub transaction_count: i64,
+    /// Transactions are listed as one TransactionSummary per day
+    pub transactions_summarized: bool,
+    pub events: Vec<TimelineEvent>,
+}
+
+/// Read audit entry: who looked at an account timeline, for which case
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineAccessRecord {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub from_date: NaiveDate,
+    pub to_date: NaiveDate,
+    /// References Person.person_id
+    pub accessed_by_person_id: Uuid,
+    pub case_reference: HeaplessString<100>,
+    pub events_returned: i64,
+    pub exported: bool,
+    pub accessed_at: DateTime<Utc>,
+}
@@ -0,0 +1,169 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::Transaction;
+
+/// Permission needed to activate or deactivate a kill switch
+pub const KILL_SWITCH_PERMISSION: &str = "KillSwitchControl";
+
+/// What a kill switch halts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KillSwitchScope {
+    /// Every posting
+    Global,
+    /// Postings with the transaction code in `scope_value`
+    TransactionKind,
+    /// Postings from the channel in `scope_value`
+    Channel,
+    /// Postings on accounts of the product whose id is in `scope_value`
+    Product,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KillSwitchState {
+    Active,
+    Deactivated,
+    /// Reached its `auto_expire_at`
+    Expired,
+}
+
+/// Operational halt of postings, switched on during incidents without a redeploy
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KillSwitch {
+    pub id: Uuid,
+    pub scope: KillSwitchScope,
+    /// Transaction code, channel id or product id; None for a global switch
+    pub scope_value: Option<HeaplessString<50>>,
+    pub state: KillSwitchState,
+    /// Shown to channels rejecting a halted posting
+    pub reason: HeaplessString<255>,
+    /// References Person.person_id
+    pub activated_by_person_id: Uuid,
+    pub activated_at: DateTime<Utc>,
+    pub auto_expire_at: Option<DateTime<Utc>>,
+    /// References Person.person_id; None when the switch expired
+    pub deactivated_by_person_id: Option<Uuid>,
+    /// When the switch stopped halting postings, by hand or by expiry
+    pub deactivated_at: Option<DateTime<Utc>>,
+}
+
+impl KillSwitch {
+    /// Active and not past its expiry. An expired switch stops halting
+    /// postings at once, before the expiry is recorded.
+    pub fn is_in_force(&self, now: DateTime<Utc>) -> bool {
+        self.state == KillSwitchState::Active && self.auto_expire_at.is_none_or(|expiry| now < expiry)
+    }
+
+    /// Whether the switch applies to the transaction. `product_id` is the
+    /// product of the transaction's account; only product switches use it.
+    pub fn halts(&self, transaction: &Transaction, product_id: Option<Uuid>) -> bool {
+        let value = self.scope_value.as_ref().map(|value| value.as_str());
+        match self.scope {
+            KillSwitchScope::Global => true,
+            KillSwitchScope::TransactionKind => value == Some(transaction.transaction_code.as_str()),
+            KillSwitchScope::Channel => value == Some(transaction.channel_id.as_str()),
+            KillSwitchScope::Product => product_id.is_some_and(|id| value == Some(id.to_string().as_str())),
+        }
+    }
+
+    /// Seconds the switch was in force, up to `now` while it still is
+    pub fn duration_seconds(&self, now: DateTime<Utc>) -> i64 {
+        let end = match self.state {
+            KillSwitchState::Active => now,
+            KillSwitchState::Deactivated | KillSwitchState::Expired => self.deactivated_at.unwrap_or(now),
+        };
+        (end - self.activated_at).num_seconds().max(0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KillSwitchRequest {
+    pub scope: KillSwitchScope,
+    /// Required for every scope but Global
+    pub scope_value: Option<HeaplessString<50>>,
+    pub reason: HeaplessString<255>,
+    pub auto_expire_at: Option<DateTime<Utc>>,
+}
+
+/// Past or current activation, for the incident audit
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KillSwitchActivationRecord {
+    pub switch: KillSwitch,
+    pub duration_seconds: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, NaiveDate};
+    use rust_decimal::Decimal;
+    use crate::domain::{CurrencyCode, DegradedFlags, TransactionStatus, TransactionType};
+
+    fn kill_switch(scope: KillSwitchScope, scope_value: Option<&str>) -> KillSwitch {
+        KillSwitch {
+            id: Uuid::new_v4(),
+            scope,
+            scope_value: scope_value.map(|value| HeaplessString::try_from(value).unwrap()),
+            state: KillSwitchState::Active,
+            reason: HeaplessString::try_from("Incident").unwrap(),
+            activated_by_person_id: Uuid::new_v4(),
+            activated_at: Utc::now(),
+            auto_expire_at: None,
+            deactivated_by_person_id: None,
+            deactivated_at: None,
+        }
+    }
+
+    fn transfer() -> Transaction {
+        Transaction {
+            id: Uuid::new_v4(),
+            account_id: Uuid::new_v4(),
+            transaction_code: HeaplessString::try_from("EXT_TRF").unwrap(),
+            transaction_type: TransactionType::Debit,
+            amount: Decimal::from(100),
+            currency: CurrencyCode::try_from("XAF").unwrap(),
+            description: HeaplessString::try_from("External transfer").unwrap(),
+            channel_id: HeaplessString::try_from("MOBILE").unwrap(),
+            terminal_id: None,
+            agent_person_id: None,
+            transaction_date: Utc::now(),
+            value_date: NaiveDate::from_ymd_opt(2024, 6, 14).unwrap(),
+            status: TransactionStatus::Pending,
+            reference_number: HeaplessString::try_from("REF-1").unwrap(),
+            external_reference: None,
+            gl_code: HeaplessString::try_from("2100").unwrap(),
+            requires_approval: false,
+            approval_status: None,
+            risk_score: None,
+            degraded_flags: DegradedFlags::NONE,
+            idempotency_key: None,
+            reversal_of_transaction_id: None,
+            fx_conversion: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_scope_matching_and_expiry() {
+        let transfer = transfer();
+        let product_id = Uuid::new_v4();
+        let product = product_id.to_string();
+
+        assert!(kill_switch(KillSwitchScope::Global, None).halts(&transfer, None));
+        assert!(kill_switch(KillSwitchScope::TransactionKind, Some("EXT_TRF")).halts(&transfer, None));
+        assert!(!kill_switch(KillSwitchScope::TransactionKind, Some("DEP")).halts(&transfer, None));
+        assert!(!kill_switch(KillSwitchScope::Channel, Some("BRANCH")).halts(&transfer, None));
+        assert!(kill_switch(KillSwitchScope::Product, Some(&product)).halts(&transfer, Some(product_id)));
+        assert!(!kill_switch(KillSwitchScope::Product, Some(&product)).halts(&transfer, Some(Uuid::new_v4())));
+
+        let now = Utc::now();
+        let mut expiring = kill_switch(KillSwitchScope::Global, None);
+        expiring.auto_expire_at = Some(now + Duration::minutes(5));
+        assert!(expiring.is_in_force(now));
+        assert!(!expiring.is_in_force(now + Duration::minutes(5)));
+        expiring.state = KillSwitchState::Deactivated;
+        assert!(!expiring.is_in_force(now));
+    }
+}
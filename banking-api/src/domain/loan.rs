@@ -1,4 +1,4 @@
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
 use heapless::String as HeaplessString;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -25,6 +25,100 @@ pub struct AmortizationSchedule {
     pub last_updated_at: DateTime<Utc>,
 }
 
+impl AmortizationSchedule {
+    /// Compute the installment plan for a request. Shared by schedule
+    /// generation on disbursement and pre-sale loan simulation so both
+    /// always produce the same figures.
+    pub fn generate(request: &GenerateAmortizationScheduleRequest) -> Self {
+        // Generate amortization entries based on payment frequency and method
+        let mut schedule_entries = Vec::new();
+        let mut current_principal = request.principal_amount;
+        let monthly_rate = request.annual_interest_rate / Decimal::from(12) / Decimal::from(100);
+        
+        // Calculate monthly payment for equal installments method
+        let monthly_payment = if monthly_rate > Decimal::ZERO {
+            // Use manual power calculation since Decimal doesn't have powi
+            let mut rate_factor = Decimal::ONE;
+            for _ in 0..request.term_months {
+                rate_factor *= Decimal::ONE + monthly_rate;
+            }
+            (current_principal * monthly_rate * rate_factor) / (rate_factor - Decimal::ONE)
+        } else {
+            current_principal / Decimal::from(request.term_months)
+        };
+
+        let mut cumulative_principal = Decimal::ZERO;
+        let mut cumulative_interest = Decimal::ZERO;
+        let mut current_date = request.first_payment_date;
+
+        for i in 1..=request.term_months {
+            let interest_component = current_principal * monthly_rate;
+            let principal_component = monthly_payment - interest_component;
+            current_principal -= principal_component;
+            cumulative_principal += principal_component;
+            cumulative_interest += interest_component;
+
+            let entry = AmortizationEntry {
+                id: Uuid::new_v4(),
+                schedule_id: Uuid::new_v4(), // Will be set when schedule is created
+                installment_number: i,
+                due_date: current_date,
+                opening_principal_balance: current_principal + principal_component,
+                installment_amount: monthly_payment,
+                principal_component,
+                interest_component,
+                closing_principal_balance: current_principal,
+                cumulative_principal_paid: cumulative_principal,
+                cumulative_interest_paid: cumulative_interest,
+                payment_status: InstallmentStatus::Scheduled,
+                paid_date: None,
+                paid_amount: None,
+                days_overdue: None,
+            };
+
+            schedule_entries.push(entry);
+            
+            // Add one month to current date
+            current_date = current_date.with_day(1)
+                .unwrap()
+                .checked_add_months(chrono::Months::new(1))
+                .unwrap()
+                .with_day(request.first_payment_date.day().min(28))
+                .unwrap_or(current_date);
+        }
+
+        let schedule_id = Uuid::new_v4();
+        
+        // Update all entries with the correct schedule_id
+        for entry in &mut schedule_entries {
+            entry.schedule_id = schedule_id;
+        }
+
+        let maturity_date = schedule_entries.last()
+            .map(|e| e.due_date)
+            .unwrap_or(request.first_payment_date);
+
+        let total_interest = cumulative_interest;
+        let total_payments = request.principal_amount + total_interest;
+
+        AmortizationSchedule {
+            id: schedule_id,
+            loan_account_id: request.loan_account_id,
+            original_principal: request.principal_amount,
+            interest_rate: request.annual_interest_rate,
+            term_months: request.term_months,
+            installment_amount: monthly_payment,
+            first_payment_date: request.first_payment_date,
+            maturity_date,
+            total_interest,
+            total_payments,
+            schedule_entries,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+        }
+    }
+}
+
 /// Individual installment in the amortization schedule
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AmortizationEntry {
@@ -55,6 +149,71 @@ pub enum InstallmentStatus {
     WriteOff,
 }
 
+/// Installment a loan has due next and the penalty rate charged once it is overdue
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoanInstallmentDue {
+    pub loan_account_id: Uuid,
+    pub due_date: NaiveDate,
+    pub installment_amount: Decimal,
+    /// Annual rate as a fraction, charged on the installment amount
+    pub penalty_rate: Decimal,
+}
+
+/// End-of-day assessment of a loan's next installment carrying the
+/// days-past-due counter used for provisioning and compliance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoanPenaltyAccrual {
+    pub id: Uuid,
+    pub loan_account_id: Uuid,
+    pub assessment_date: NaiveDate,
+    /// Due date of the installment assessed
+    pub due_date: NaiveDate,
+    pub days_past_due: i32,
+    pub overdue_amount: Decimal,
+    pub penalty_rate: Decimal,
+    /// Overdue days this assessment charges, zero within the grace period
+    pub penalty_days: i32,
+    /// Penalty interest charged by this assessment, unrounded until the EOD step
+    /// rounds it to the loan currency
+    pub penalty_amount: Decimal,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LoanPenaltyAccrual {
+    /// Assess the installment as of `assessment_date` given the previous assessment.
+    /// Nothing is charged while the installment is within `grace_days` of its due
+    /// date. Past the grace period every overdue day not charged before is charged,
+    /// so a late payment pays penalty from the due date. A paid installment moves
+    /// the due date, which resets the counter without touching earlier assessments.
+    pub fn assess(
+        installment: &LoanInstallmentDue,
+        assessment_date: NaiveDate,
+        grace_days: i32,
+        previous: Option<&LoanPenaltyAccrual>,
+    ) -> Self {
+        let days_past_due = (assessment_date - installment.due_date).num_days().max(0) as i32;
+        let charged_days = previous
+            .filter(|p| p.due_date == installment.due_date && p.days_past_due > grace_days)
+            .map_or(0, |p| p.days_past_due);
+        let penalty_days = if days_past_due > grace_days { days_past_due - charged_days } else { 0 };
+        let overdue_amount = if days_past_due > 0 { installment.installment_amount } else { Decimal::ZERO };
+
+        Self {
+            id: Uuid::new_v4(),
+            loan_account_id: installment.loan_account_id,
+            assessment_date,
+            due_date: installment.due_date,
+            days_past_due,
+            overdue_amount,
+            penalty_rate: installment.penalty_rate,
+            penalty_days,
+            penalty_amount: overdue_amount * installment.penalty_rate * Decimal::from(penalty_days)
+                / Decimal::from(365),
+            created_at: Utc::now(),
+        }
+    }
+}
+
 /// Loan delinquency tracking and management
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoanDelinquency {
@@ -405,4 +564,76 @@ pub struct CreateCollectionActionRequest {
     pub due_date: Option<NaiveDate>,
     pub assigned_to: Uuid, // References Person.person_id
     pub created_by_person_id: Uuid, // References Person.person_id
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRACE_DAYS: i32 = 3;
+
+    fn march(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, day).unwrap()
+    }
+
+    /// Daily assessments from 16 to 25 March of an installment due on 15 March and
+    /// paid on `paid_on`, which moves the next due date to 15 April.
+    /// 36.5% a year on 1,000.00 is 1.00 a day.
+    fn assessments(paid_on: NaiveDate) -> Vec<LoanPenaltyAccrual> {
+        let loan_account_id = Uuid::new_v4();
+        let mut assessments: Vec<LoanPenaltyAccrual> = Vec::new();
+        for day in 16..=25 {
+            let due_date = if march(day) >= paid_on { NaiveDate::from_ymd_opt(2024, 4, 15).unwrap() } else { march(15) };
+            let installment = LoanInstallmentDue {
+                loan_account_id,
+                due_date,
+                installment_amount: Decimal::from(1_000),
+                penalty_rate: Decimal::new(365, 3),
+            };
+            let assessment = LoanPenaltyAccrual::assess(&installment, march(day), GRACE_DAYS, assessments.last());
+            assessments.push(assessment);
+        }
+        assessments
+    }
+
+    fn total_penalty(assessments: &[LoanPenaltyAccrual]) -> Decimal {
+        assessments.iter().map(|a| a.penalty_amount).sum()
+    }
+
+    #[test]
+    fn test_payment_on_last_grace_day_incurs_no_penalty() {
+        let assessments = assessments(march(18));
+
+        assert_eq!(assessments[0].days_past_due, 1);
+        assert_eq!(assessments[1].days_past_due, 2);
+        assert_eq!(total_penalty(&assessments), Decimal::ZERO);
+        // Paying resets the counter from the day of payment
+        assert!(assessments[2..].iter().all(|a| a.days_past_due == 0 && a.overdue_amount == Decimal::ZERO));
+    }
+
+    #[test]
+    fn test_payment_five_days_late_keeps_penalty_from_due_date() {
+        let assessments = assessments(march(20));
+
+        // Grace days charge nothing until the first day past grace charges all four days
+        assert!(assessments[..3].iter().all(|a| a.penalty_amount == Decimal::ZERO));
+        let first_past_grace = &assessments[3];
+        assert_eq!(first_past_grace.assessment_date, march(19));
+        assert_eq!(first_past_grace.days_past_due, 4);
+        assert_eq!(first_past_grace.penalty_days, 4);
+        assert_eq!(first_past_grace.penalty_amount, Decimal::from(4));
+
+        // Catching up stops further penalties; the assessed one stays
+        assert_eq!(assessments[4].days_past_due, 0);
+        assert!(assessments[4..].iter().all(|a| a.penalty_days == 0));
+        assert_eq!(total_penalty(&assessments), Decimal::from(4));
+    }
+
+    #[test]
+    fn test_each_day_past_grace_is_charged_once() {
+        let assessments = assessments(march(26));
+
+        assert_eq!(assessments.last().unwrap().days_past_due, 10);
+        assert!(assessments[4..].iter().all(|a| a.penalty_days == 1));
+        assert_eq!(total_penalty(&assessments), Decimal::from(10));
+    }
+}
@@ -1,11 +1,13 @@
 pub mod customer;
 pub mod account;
+pub mod account_number;
 pub mod account_hold;
 pub mod agent_network;
 pub mod audit;
 pub mod transaction;
 pub mod calendar;
 pub mod workflow;
+pub mod workflow_escalation;
 pub mod compliance;
 pub mod channel;
 pub mod fee;
@@ -18,15 +20,51 @@ pub mod collateral;
 pub mod daily_collection;
 pub mod product;
 pub mod common;
+pub mod currency;
+pub mod exchange_rate;
+pub mod welcome_pack;
+pub mod segment;
+pub mod guarantor;
+pub mod quote;
+pub mod cheque;
+pub mod statement;
+pub mod orchestration;
+pub mod tag;
+pub mod channel_security;
+pub mod agent_commission;
+pub mod document_registry;
+pub mod branch_cash;
+pub mod notification;
+pub mod investigation;
+pub mod bundle;
+pub mod financial_position;
+pub mod promotion;
+pub mod savings_goal;
+pub mod payee;
+pub mod back_dating;
+pub mod degradation;
+pub mod domicile;
+pub mod kill_switch;
+pub mod interaction_note;
+pub mod verification;
+pub mod warehouse;
+pub mod standing_order;
+pub mod general_ledger;
+pub mod risk_rating;
+pub mod transaction_approval;
+pub mod eod_run;
+pub mod interest_accrual;
 
 pub use audit::*;
 pub use customer::*;
 pub use account::*;
+pub use account_number::*;
 pub use account_hold::*;
 pub use agent_network::*;
 pub use transaction::*;
 pub use calendar::*;
 pub use workflow::*;
+pub use workflow_escalation::*;
 pub use compliance::*;
 pub use channel::*;
 
@@ -40,4 +78,38 @@ pub use person::*;
 pub use collateral::*;
 pub use product::*;
 pub use common::*;
-pub use daily_collection::*;
\ No newline at end of file
+pub use currency::*;
+pub use exchange_rate::*;
+pub use daily_collection::*;
+pub use welcome_pack::*;
+pub use segment::*;
+pub use guarantor::*;
+pub use quote::*;
+pub use cheque::*;
+pub use statement::*;
+pub use orchestration::*;
+pub use tag::*;
+pub use channel_security::*;
+pub use agent_commission::*;
+pub use document_registry::*;
+pub use branch_cash::*;
+pub use notification::*;
+pub use investigation::*;
+pub use bundle::*;
+pub use financial_position::*;
+pub use promotion::*;
+pub use savings_goal::*;
+pub use payee::*;
+pub use back_dating::*;
+pub use degradation::*;
+pub use domicile::*;
+pub use kill_switch::*;
+pub use interaction_note::*;
+pub use verification::*;
+pub use warehouse::*;
+pub use standing_order::*;
+pub use general_ledger::*;
+pub use risk_rating::*;
+pub use transaction_approval::*;
+pub use eod_run::*;
+pub use interest_accrual::*;
\ No newline at end of file
@@ -0,0 +1,358 @@
+use std::collections::{BTreeMap, HashMap};
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{hash_content, ContentHash, DocumentNotificationStatus};
+
+/// Default days an idempotency key is kept after its business date. A rerun
+/// of an EOD step older than the configured retention may notify again.
+pub const NOTIFICATION_KEY_RETENTION_DAYS: i64 = 90;
+
+/// Customer message templates queued by EOD steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NotificationTemplate {
+    DormancyNotice,
+    StatementReady,
+    MandateExpiryReminder,
+    CollectionReminder,
+    SavingsGoalsReduced,
+    InactivityFeeWarning,
+    WorkflowEscalation,
+}
+
+impl NotificationTemplate {
+    pub fn code(&self) -> &'static str {
+        match self {
+            NotificationTemplate::DormancyNotice => "DORMANCY_NOTICE",
+            NotificationTemplate::StatementReady => "STATEMENT_READY",
+            NotificationTemplate::MandateExpiryReminder => "MANDATE_EXPIRY_REMINDER",
+            NotificationTemplate::CollectionReminder => "COLLECTION_REMINDER",
+            NotificationTemplate::SavingsGoalsReduced => "SAVINGS_GOALS_REDUCED",
+            NotificationTemplate::InactivityFeeWarning => "INACTIVITY_FEE_WARNING",
+            NotificationTemplate::WorkflowEscalation => "WORKFLOW_ESCALATION",
+        }
+    }
+
+    pub fn body(&self) -> &'static str {
+        match self {
+            NotificationTemplate::DormancyNotice =>
+                "Your account ending {account_suffix} has been classified dormant after a period of inactivity. Visit a branch to reactivate it.",
+            NotificationTemplate::StatementReady =>
+                "Your statement {statement_reference} is ready.",
+            NotificationTemplate::MandateExpiryReminder =>
+                "The mandate on your account ending {account_suffix} expires on {expiry_date}.",
+            NotificationTemplate::CollectionReminder =>
+                "Your contribution of {amount} is due on {due_date}.",
+            NotificationTemplate::SavingsGoalsReduced =>
+                "A withdrawal from your account ending {account_suffix} reduced your savings goals by {amount}.",
+            NotificationTemplate::InactivityFeeWarning =>
+                "Your account ending {account_suffix} has been inactive for a long time. An inactivity fee will be charged from {fee_date} unless you use it before then.",
+            NotificationTemplate::WorkflowEscalation =>
+                "Workflow {workflow_id} ({workflow_type}) on account ending {account_suffix} timed out and is escalated to you at level {level}. Please act by {action_due_at}.",
+        }
+    }
+
+    /// Substitute `{name}` placeholders; every placeholder needs a value.
+    /// Context values without a placeholder only distinguish the message.
+    pub fn render(&self, context: &BTreeMap<String, String>) -> Result<String, String> {
+        substitute_placeholders(self.body(), context)
+            .map_err(|placeholder| format!("Missing value for {placeholder} in template {}", self.code()))
+    }
+}
+
+/// Replace each `{name}` with its value; on failure returns the first
+/// placeholder left without one
+fn substitute_placeholders<'a>(
+    text: &str,
+    values: impl IntoIterator<Item = (&'a String, &'a String)>,
+) -> Result<String, String> {
+    let mut rendered = text.to_string();
+    for (name, value) in values {
+        rendered = rendered.replace(&format!("{{{name}}}"), value);
+    }
+    let Some(start) = rendered.find('{') else {
+        return Ok(rendered);
+    };
+    let end = rendered[start..].find('}').map_or(rendered.len(), |offset| start + offset + 1);
+    Err(rendered[start..end].to_string())
+}
+
+/// Channel a message template is worded for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MessageChannel {
+    Sms,
+    Email,
+    InApp,
+}
+
+/// Stored wording of a customer message in one language. One template is
+/// kept per code and language; subject and body hold `{name}` placeholders.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageTemplate {
+    pub id: Uuid,
+    pub template_code: HeaplessString<50>,
+    pub language_code: [u8; 3],
+    pub channel: MessageChannel,
+    pub subject: Option<HeaplessString<100>>,
+    pub body: HeaplessString<500>,
+    /// Required by regulation: sent even on channels the customer opted out of,
+    /// though still held back during their quiet hours
+    pub regulatory: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl MessageTemplate {
+    /// The template in the first preferred language available, else the one in
+    /// the bank's default language
+    pub fn select<'a>(
+        templates: &'a [MessageTemplate],
+        language_preferences: &[[u8; 3]],
+        default_language: [u8; 3],
+    ) -> Option<&'a MessageTemplate> {
+        language_preferences
+            .iter()
+            .chain(std::iter::once(&default_language))
+            .find_map(|language| templates.iter().find(|template| template.language_code == *language))
+    }
+
+    /// Fill subject and body; every placeholder needs a value
+    pub fn render(&self, params: &HashMap<String, String>) -> Result<RenderedMessage, String> {
+        let missing = |placeholder: String| {
+            format!(
+                "Missing value for {placeholder} in template {} ({})",
+                self.template_code,
+                String::from_utf8_lossy(&self.language_code)
+            )
+        };
+        let subject = self.subject
+            .as_ref()
+            .map(|subject| substitute_placeholders(subject.as_str(), params))
+            .transpose()
+            .map_err(missing)?;
+        let body = substitute_placeholders(self.body.as_str(), params).map_err(missing)?;
+        Ok(RenderedMessage {
+            template_code: self.template_code.clone(),
+            language_code: self.language_code,
+            channel: self.channel,
+            subject,
+            body,
+            regulatory: self.regulatory,
+        })
+    }
+}
+
+/// Unbounded template content as submitted; checked against the stored
+/// bounds when the template is saved
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageTemplateRequest {
+    pub template_code: String,
+    pub language_code: [u8; 3],
+    pub channel: MessageChannel,
+    pub subject: Option<String>,
+    pub body: String,
+    #[serde(default)]
+    pub regulatory: bool,
+}
+
+/// Message text produced from a template, in the language actually used
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenderedMessage {
+    pub template_code: HeaplessString<50>,
+    pub language_code: [u8; 3],
+    pub channel: MessageChannel,
+    pub subject: Option<String>,
+    pub body: String,
+    pub regulatory: bool,
+}
+
+/// A template to deliver to a person on each of `channels`, subject to the
+/// person's contact preferences
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageSendRequest {
+    pub template_code: String,
+    /// References Person.person_id
+    pub person_id: Uuid,
+    pub channels: Vec<MessageChannel>,
+    pub params: HashMap<String, String>,
+    pub requested_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DispatchStatus {
+    /// Deliver at `send_at`, the time requested
+    Send,
+    /// Requested during quiet hours; deliver at `send_at`, when they end
+    Deferred,
+    /// The person opted out of the channel
+    Suppressed,
+}
+
+/// What `render_and_send` decided for one channel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelDispatch {
+    pub channel: MessageChannel,
+    pub status: DispatchStatus,
+    /// None when suppressed
+    pub send_at: Option<DateTime<Utc>>,
+    /// None when suppressed
+    pub message: Option<RenderedMessage>,
+}
+
+/// Natural key of a customer message: the same template, recipient,
+/// business date and context never queue a second message
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationIdempotencyKey {
+    pub template_code: HeaplessString<50>,
+    pub customer_id: Uuid,
+    pub business_date: NaiveDate,
+    /// Hash of the sorted `name=value` context lines
+    pub context_hash: ContentHash,
+}
+
+impl NotificationIdempotencyKey {
+    pub fn new(
+        template: NotificationTemplate,
+        customer_id: Uuid,
+        business_date: NaiveDate,
+        context: &BTreeMap<String, String>,
+    ) -> Self {
+        let canonical: String = context.iter().map(|(name, value)| format!("{name}={value}\n")).collect();
+        Self {
+            template_code: HeaplessString::try_from(template.code()).unwrap_or_default(),
+            customer_id,
+            business_date,
+            context_hash: hash_content(canonical.as_bytes()),
+        }
+    }
+
+    /// Stored form, e.g. "DORMANCY_NOTICE:<customer id>:2024-06-30:<context hash>"
+    pub fn as_key(&self) -> HeaplessString<200> {
+        let mut key = HeaplessString::new();
+        let _ = key.push_str(&format!(
+            "{}:{}:{}:{}",
+            self.template_code,
+            self.customer_id,
+            self.business_date,
+            self.context_hash.to_hex()
+        ));
+        key
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationRequest {
+    pub template: NotificationTemplate,
+    /// References Customer.id
+    pub customer_id: Uuid,
+    pub business_date: NaiveDate,
+    /// Placeholder values; also identify the message within the day
+    pub context: BTreeMap<String, String>,
+}
+
+impl NotificationRequest {
+    pub fn idempotency_key(&self) -> NotificationIdempotencyKey {
+        NotificationIdempotencyKey::new(self.template, self.customer_id, self.business_date, &self.context)
+    }
+}
+
+/// Rendered customer message awaiting delivery
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedNotification {
+    pub id: Uuid,
+    pub idempotency_key: HeaplessString<200>,
+    pub template_code: HeaplessString<50>,
+    /// References Customer.id
+    pub customer_id: Uuid,
+    pub business_date: NaiveDate,
+    pub body: HeaplessString<500>,
+    pub status: DocumentNotificationStatus,
+    pub queued_at: DateTime<Utc>,
+    pub sent_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationQueueOutcome {
+    pub notification: QueuedNotification,
+    /// The key was already queued; `notification` is the original message
+    pub was_duplicate: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuppressedNotificationSummary {
+    pub template_code: HeaplessString<50>,
+    pub notifications_queued: i64,
+    pub duplicates_suppressed: i64,
+}
+
+/// Duplicates suppressed per template for a business date; non-zero counts mean an EOD step was rerun
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationDuplicateReport {
+    pub business_date: NaiveDate,
+    pub by_template: Vec<SuppressedNotificationSummary>,
+}
+
+impl NotificationDuplicateReport {
+    pub fn total_suppressed(&self) -> i64 {
+        self.by_template.iter().map(|summary| summary.duplicates_suppressed).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect()
+    }
+
+    #[test]
+    fn test_render_requires_every_placeholder() {
+        let template = NotificationTemplate::CollectionReminder;
+        assert_eq!(
+            template.render(&context(&[("amount", "5000"), ("due_date", "2024-07-01")])).unwrap(),
+            "Your contribution of 5000 is due on 2024-07-01."
+        );
+        assert!(template.render(&context(&[("amount", "5000")])).unwrap_err().contains("{due_date}"));
+    }
+
+    #[test]
+    fn test_key_depends_on_context_not_insertion_order() {
+        let customer_id = Uuid::new_v4();
+        let date = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+        let template = NotificationTemplate::MandateExpiryReminder;
+        let first = NotificationIdempotencyKey::new(template, customer_id, date, &context(&[("a", "1"), ("b", "2")]));
+        let same = NotificationIdempotencyKey::new(template, customer_id, date, &context(&[("b", "2"), ("a", "1")]));
+        let other = NotificationIdempotencyKey::new(template, customer_id, date, &context(&[("a", "1"), ("b", "3")]));
+
+        assert_eq!(first.as_key(), same.as_key());
+        assert_ne!(first.as_key(), other.as_key());
+        assert!(first.as_key().starts_with("MANDATE_EXPIRY_REMINDER:"));
+    }
+
+    fn template(language_code: [u8; 3], body: &str) -> MessageTemplate {
+        MessageTemplate {
+            id: Uuid::new_v4(),
+            template_code: HeaplessString::try_from("COLLECTION_REMINDER").unwrap(),
+            language_code,
+            channel: MessageChannel::Sms,
+            subject: None,
+            body: HeaplessString::try_from(body).unwrap(),
+            regulatory: false,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_select_follows_preferences_then_default_language() {
+        let templates = [template(*b"eng", "Due {due_date}"), template(*b"fra", "Échéance {due_date}")];
+
+        let chosen = |preferences: &[[u8; 3]]| MessageTemplate::select(&templates, preferences, *b"eng").map(|t| t.language_code);
+        assert_eq!(chosen(&[*b"fra", *b"eng"]), Some(*b"fra"));
+        assert_eq!(chosen(&[*b"swa", *b"fra"]), Some(*b"fra"));
+        assert_eq!(chosen(&[*b"swa"]), Some(*b"eng"));
+        assert_eq!(chosen(&[]), Some(*b"eng"));
+        assert!(MessageTemplate::select(&templates[1..], &[*b"swa"], *b"eng").is_none());
+    }
+}
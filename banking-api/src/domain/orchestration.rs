@@ -0,0 +1,207 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{AccountStatus, MandateStatus};
+
+/// Multi-step operation whose completed steps are undone on failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrchestrationType {
+    CustomerOffboarding,
+    ProductRepricing,
+    BundleOpening,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrchestrationStatus {
+    Running,
+    Completed,
+    /// A step failed and compensations are being executed
+    Compensating,
+    /// All completed steps were undone
+    Compensated,
+    /// At least one compensation failed; needs an operator
+    CompensationFailed,
+    /// Closed by an operator after manual correction
+    ManuallyResolved,
+}
+
+impl OrchestrationStatus {
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            OrchestrationStatus::Completed | OrchestrationStatus::Compensated | OrchestrationStatus::ManuallyResolved
+        )
+    }
+}
+
+/// Entry of the orchestration log. Each step is registered before it takes
+/// effect: compensations write back absolute values, so undoing a step that
+/// only partly ran is harmless.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Orchestration {
+    pub id: Uuid,
+    pub orchestration_type: OrchestrationType,
+    /// Customer, product, ... the orchestration acts on
+    pub subject_id: Uuid,
+    pub status: OrchestrationStatus,
+    pub failure_reason: Option<HeaplessString<255>>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    /// References Person.person_id
+    pub initiated_by_person_id: Uuid,
+    /// References Person.person_id
+    pub resolved_by_person_id: Option<Uuid>,
+    pub resolution_note: Option<HeaplessString<255>>,
+}
+
+/// Previous status of an account, restored on compensation
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AccountStatusSnapshot {
+    pub account_id: Uuid,
+    pub status: AccountStatus,
+}
+
+/// Previous status of a mandate, restored on compensation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MandateStatusSnapshot {
+    pub mandate_id: Uuid,
+    pub status: MandateStatus,
+}
+
+/// Previous contractual rate of a loan account, restored on compensation
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LoanRateSnapshot {
+    pub account_id: Uuid,
+    pub loan_interest_rate: Option<Decimal>,
+}
+
+/// Undo action of a completed step. Every action writes back absolute values,
+/// so running it more than once has the same effect as running it once.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "action")]
+pub enum CompensationAction {
+    RestoreAccountStatuses { accounts: Vec<AccountStatusSnapshot> },
+    RestoreMandateStatuses { mandates: Vec<MandateStatusSnapshot> },
+    RestoreProductOverdraftRate { product_id: Uuid, overdraft_interest_rate: Option<Decimal> },
+    RestoreLoanInterestRates { accounts: Vec<LoanRateSnapshot> },
+    /// Closes an account opened by the orchestration; nothing to do if it was never created
+    CloseOpenedAccount { account_id: Uuid },
+    /// Cancels a customer bundle and removes its pricing markers
+    CancelBundle { account_bundle_id: Uuid },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrchestrationStepStatus {
+    Completed,
+    Compensated,
+    CompensationFailed,
+}
+
+/// A completed step registered with its compensation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrchestrationStep {
+    pub id: Uuid,
+    /// References Orchestration.id
+    pub orchestration_id: Uuid,
+    /// 1-based execution order
+    pub sequence: i32,
+    pub step_name: HeaplessString<100>,
+    /// `None` for steps with nothing to undo
+    pub compensation: Option<CompensationAction>,
+    pub status: OrchestrationStepStatus,
+    pub completed_at: DateTime<Utc>,
+    pub compensated_at: Option<DateTime<Utc>>,
+    pub last_error: Option<HeaplessString<255>>,
+}
+
+impl OrchestrationStep {
+    /// Steps still to be compensated, in the order compensations run: last
+    /// completed step first
+    pub fn pending_compensations(steps: &[OrchestrationStep]) -> Vec<&OrchestrationStep> {
+        let mut pending: Vec<&OrchestrationStep> = steps
+            .iter()
+            .filter(|s| s.compensation.is_some() && s.status != OrchestrationStepStatus::Compensated)
+            .collect();
+        pending.sort_by_key(|s| std::cmp::Reverse(s.sequence));
+        pending
+    }
+}
+
+/// Orchestration awaiting completion, compensation or manual resolution
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncompleteOrchestration {
+    pub orchestration: Orchestration,
+    /// In the order they would run
+    pub pending_compensations: Vec<OrchestrationStep>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(sequence: i32, status: OrchestrationStepStatus, compensation: Option<CompensationAction>) -> OrchestrationStep {
+        OrchestrationStep {
+            id: Uuid::new_v4(),
+            orchestration_id: Uuid::nil(),
+            sequence,
+            step_name: HeaplessString::try_from(format!("step_{sequence}").as_str()).unwrap(),
+            compensation,
+            status,
+            completed_at: Utc::now(),
+            compensated_at: None,
+            last_error: None,
+        }
+    }
+
+    fn restore(account_id: Uuid) -> Option<CompensationAction> {
+        Some(CompensationAction::RestoreAccountStatuses {
+            accounts: vec![AccountStatusSnapshot { account_id, status: AccountStatus::Active }],
+        })
+    }
+
+    #[test]
+    fn test_pending_compensations_run_in_reverse_order() {
+        let steps = vec![
+            step(1, OrchestrationStepStatus::Completed, restore(Uuid::new_v4())),
+            step(2, OrchestrationStepStatus::Completed, None),
+            step(3, OrchestrationStepStatus::Compensated, restore(Uuid::new_v4())),
+            step(4, OrchestrationStepStatus::CompensationFailed, restore(Uuid::new_v4())),
+        ];
+
+        let sequences: Vec<i32> = OrchestrationStep::pending_compensations(&steps).iter().map(|s| s.sequence).collect();
+        assert_eq!(sequences, vec![4, 1]);
+    }
+
+    #[test]
+    fn test_compensation_action_round_trips_as_tagged_json() {
+        let product_id = Uuid::new_v4();
+        let action = CompensationAction::RestoreProductOverdraftRate {
+            product_id,
+            overdraft_interest_rate: Some(Decimal::new(18, 2)),
+        };
+
+        let json = serde_json::to_value(&action).unwrap();
+        assert_eq!(json["action"], "RestoreProductOverdraftRate");
+
+        match serde_json::from_value(json).unwrap() {
+            CompensationAction::RestoreProductOverdraftRate { product_id: id, overdraft_interest_rate } => {
+                assert_eq!(id, product_id);
+                assert_eq!(overdraft_interest_rate, Some(Decimal::new(18, 2)));
+            }
+            other => panic!("Unexpected action {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_terminal_statuses() {
+        assert!(OrchestrationStatus::Completed.is_terminal());
+        assert!(OrchestrationStatus::Compensated.is_terminal());
+        assert!(OrchestrationStatus::ManuallyResolved.is_terminal());
+        assert!(!OrchestrationStatus::Running.is_terminal());
+        assert!(!OrchestrationStatus::Compensating.is_terminal());
+        assert!(!OrchestrationStatus::CompensationFailed.is_terminal());
+    }
+}
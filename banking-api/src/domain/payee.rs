@@ -0,0 +1,173 @@
+use chrono::{DateTime, Duration, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::{BankingError, BankingResult, LimitType};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PayeeVerificationStatus {
+    Unverified,
+    PendingVerification,
+    Verified,
+    Blocked,
+}
+
+/// How a new payee is confirmed before unrestricted use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PayeeVerificationMethod {
+    /// A small random amount is sent to the external account and the
+    /// customer confirms the amount received
+    PennyTest,
+    /// A bank officer confirms the beneficiary details
+    ManualConfirmation,
+}
+
+/// Evidence submitted to verify a payee
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PayeeConfirmation {
+    PennyTestAmount(Decimal),
+    Manual {
+        /// References Person.person_id
+        confirmed_by_person_id: Uuid,
+    },
+}
+
+/// External beneficiary saved by a customer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Payee {
+    pub id: Uuid,
+    /// References Customer.id
+    pub customer_id: Uuid,
+    pub beneficiary_name: HeaplessString<100>,
+    pub external_bank_code: HeaplessString<20>,
+    pub external_account_number: HeaplessString<34>,
+    pub verification_status: PayeeVerificationStatus,
+    pub verification_method: Option<PayeeVerificationMethod>,
+    /// Amount sent by the penny test, never shown to the customer
+    pub penny_test_amount: Option<Decimal>,
+    pub verified_at: Option<DateTime<Utc>>,
+    /// References Person.person_id
+    pub verified_by_person_id: Option<Uuid>,
+    pub first_used_at: Option<DateTime<Utc>>,
+    /// Start of the cooling-off period
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    pub updated_by_person_id: Uuid,
+}
+
+/// Limits on transfers to payees, from configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PayeeTransferPolicy {
+    /// Largest transfer to a payee that is not verified yet
+    pub unverified_transfer_limit: Decimal,
+    pub cooling_off_hours: i64,
+    /// Largest transfer to a payee added within the cooling-off period
+    pub cooling_off_transfer_limit: Decimal,
+}
+
+impl Payee {
+    pub fn cooling_off_ends_at(&self, policy: &PayeeTransferPolicy) -> DateTime<Utc> {
+        self.created_at + Duration::hours(policy.cooling_off_hours)
+    }
+
+    /// Transfers to blocked payees always fail; unverified payees and payees
+    /// still cooling off only take transfers up to their reduced limits
+    pub fn check_transfer(&self, amount: Decimal, now: DateTime<Utc>, policy: &PayeeTransferPolicy) -> BankingResult<()> {
+        match self.verification_status {
+            PayeeVerificationStatus::Blocked => {
+                return Err(BankingError::PayeeBlocked { payee_id: self.id });
+            }
+            PayeeVerificationStatus::Unverified | PayeeVerificationStatus::PendingVerification
+                if amount > policy.unverified_transfer_limit =>
+            {
+                return Err(BankingError::PayeeNotVerified {
+                    payee_id: self.id,
+                    status: self.verification_status,
+                    limit: policy.unverified_transfer_limit,
+                    attempted: amount,
+                });
+            }
+            _ => {}
+        }
+        if now < self.cooling_off_ends_at(policy) && amount > policy.cooling_off_transfer_limit {
+            return Err(BankingError::TransactionLimitExceeded {
+                limit: policy.cooling_off_transfer_limit,
+                attempted: amount,
+                limit_type: LimitType::PayeeCoolingOff,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> PayeeTransferPolicy {
+        PayeeTransferPolicy {
+            unverified_transfer_limit: Decimal::from(100),
+            cooling_off_hours: 24,
+            cooling_off_transfer_limit: Decimal::from(500),
+        }
+    }
+
+    fn payee(status: PayeeVerificationStatus, created_at: DateTime<Utc>) -> Payee {
+        Payee {
+            id: Uuid::new_v4(),
+            customer_id: Uuid::new_v4(),
+            beneficiary_name: HeaplessString::try_from("Jane Supplier").unwrap(),
+            external_bank_code: HeaplessString::try_from("ECOCCMCX").unwrap(),
+            external_account_number: HeaplessString::try_from("CM2110005000011234567890").unwrap(),
+            verification_status: status,
+            verification_method: Some(PayeeVerificationMethod::PennyTest),
+            penny_test_amount: Some(Decimal::new(37, 2)),
+            verified_at: None,
+            verified_by_person_id: None,
+            first_used_at: None,
+            created_at,
+            last_updated_at: created_at,
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn test_verification_gate() {
+        let added = Utc::now() - Duration::days(3);
+        let now = Utc::now();
+
+        let pending = payee(PayeeVerificationStatus::PendingVerification, added);
+        assert!(pending.check_transfer(Decimal::from(100), now, &policy()).is_ok());
+        assert!(matches!(
+            pending.check_transfer(Decimal::new(10001, 2), now, &policy()),
+            Err(BankingError::PayeeNotVerified { status: PayeeVerificationStatus::PendingVerification, .. })
+        ));
+
+        let verified = payee(PayeeVerificationStatus::Verified, added);
+        assert!(verified.check_transfer(Decimal::from(5_000), now, &policy()).is_ok());
+
+        let blocked = payee(PayeeVerificationStatus::Blocked, added);
+        assert!(matches!(
+            blocked.check_transfer(Decimal::ONE, now, &policy()),
+            Err(BankingError::PayeeBlocked { .. })
+        ));
+    }
+
+    #[test]
+    fn test_cooling_off_reduces_limit_until_period_ends() {
+        let added = Utc::now();
+        let verified = payee(PayeeVerificationStatus::Verified, added);
+
+        let during = added + Duration::hours(23);
+        assert!(verified.check_transfer(Decimal::from(500), during, &policy()).is_ok());
+        assert!(matches!(
+            verified.check_transfer(Decimal::from(501), during, &policy()),
+            Err(BankingError::TransactionLimitExceeded { limit_type: LimitType::PayeeCoolingOff, .. })
+        ));
+
+        let after = added + Duration::hours(24);
+        assert!(verified.check_transfer(Decimal::from(501), after, &policy()).is_ok());
+    }
+}
@@ -0,0 +1,102 @@
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::MessageChannel;
+
+/// # Documentation
+/// - How a person wants to be reached on one messaging channel
+/// - A channel without a preference is allowed at any time
+/// # Nature
+/// - Mutable: one record per person and channel, replaced when the person changes their mind
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContactPreference {
+    /// # Nature
+    /// - primary index
+    pub id: Uuid,
+
+    /// # Documentation
+    /// - References Person.person_id
+    /// # Trait method
+    /// - find_contact_preferences_by_person_id
+    pub person_id: Uuid,
+
+    pub channel: MessageChannel,
+
+    /// # Documentation
+    /// - Do-not-contact when false; only regulatory messages are still sent
+    pub allowed: bool,
+
+    /// # Documentation
+    /// - Start of the daily window, in UTC, during which nothing is sent on the channel
+    /// - The window may wrap midnight, e.g. 21:00 to 07:00
+    pub quiet_hours_start: Option<NaiveTime>,
+    pub quiet_hours_end: Option<NaiveTime>,
+
+    /// # Documentation
+    /// - ISO 639-2 code tried first when choosing the wording of a template
+    pub preferred_language: Option<[u8; 3]>,
+}
+
+impl ContactPreference {
+    /// Whether `time` falls in the quiet hours; the end of the window is no longer quiet
+    pub fn is_quiet_at(&self, time: NaiveTime) -> bool {
+        match (self.quiet_hours_start, self.quiet_hours_end) {
+            (Some(start), Some(end)) if start < end => time >= start && time < end,
+            (Some(start), Some(end)) if start > end => time >= start || time < end,
+            _ => false,
+        }
+    }
+
+    /// `at` itself when outside the quiet hours, else the end of the current window
+    pub fn next_allowed_at(&self, at: DateTime<Utc>) -> DateTime<Utc> {
+        let (Some(end), true) = (self.quiet_hours_end, self.is_quiet_at(at.time())) else {
+            return at;
+        };
+        let mut date = at.date_naive();
+        if end <= at.time() {
+            date += Duration::days(1);
+        }
+        date.and_time(end).and_utc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn quiet(start: (u32, u32), end: (u32, u32)) -> ContactPreference {
+        ContactPreference {
+            id: Uuid::new_v4(),
+            person_id: Uuid::new_v4(),
+            channel: MessageChannel::Sms,
+            allowed: true,
+            quiet_hours_start: NaiveTime::from_hms_opt(start.0, start.1, 0),
+            quiet_hours_end: NaiveTime::from_hms_opt(end.0, end.1, 0),
+            preferred_language: None,
+        }
+    }
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        NaiveDate::from_ymd_opt(2024, 6, day).unwrap().and_hms_opt(hour, minute, 0).unwrap().and_utc()
+    }
+
+    #[test]
+    fn test_next_allowed_at_ends_window_wrapping_midnight() {
+        let preference = quiet((21, 0), (7, 0));
+
+        assert_eq!(preference.next_allowed_at(at(10, 20, 59)), at(10, 20, 59));
+        assert_eq!(preference.next_allowed_at(at(10, 22, 30)), at(11, 7, 0));
+        assert_eq!(preference.next_allowed_at(at(11, 3, 0)), at(11, 7, 0));
+        assert_eq!(preference.next_allowed_at(at(11, 7, 0)), at(11, 7, 0));
+    }
+
+    #[test]
+    fn test_next_allowed_at_ends_same_day_window() {
+        let preference = quiet((12, 0), (14, 0));
+
+        assert_eq!(preference.next_allowed_at(at(10, 12, 15)), at(10, 14, 0));
+        assert_eq!(preference.next_allowed_at(at(10, 15, 0)), at(10, 15, 0));
+        assert!(!quiet((9, 0), (9, 0)).is_quiet_at(NaiveTime::from_hms_opt(9, 0, 0).unwrap()));
+    }
+}
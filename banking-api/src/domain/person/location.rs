@@ -1,4 +1,5 @@
 use heapless::{String as HeaplessString};
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -44,6 +45,28 @@ pub struct Location {
     pub location_type: LocationType,
 }
 
+impl Location {
+    /// Great-circle distance in meters to the given coordinates, if this
+    /// location has coordinates
+    pub fn distance_meters_to(&self, latitude: Decimal, longitude: Decimal) -> Option<f64> {
+        let (lat, lon) = (self.latitude?.to_f64()?, self.longitude?.to_f64()?);
+        Some(haversine_distance_meters(lat, lon, latitude.to_f64()?, longitude.to_f64()?))
+    }
+}
+
+/// Mean Earth radius used for distance calculations
+pub const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+/// Haversine great-circle distance in meters between two points in decimal degrees
+pub fn haversine_distance_meters(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+    let delta_phi = (lat2 - lat1).to_radians();
+    let delta_lambda = (lon2 - lon1).to_radians();
+
+    let a = (delta_phi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (delta_lambda / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_METERS * a.sqrt().asin()
+}
+
 /// Type of location for categorization
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LocationType {
@@ -61,4 +84,17 @@ pub enum LocationType {
     Community,
     /// Other location types
     Other,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_haversine_distance() {
+        assert_eq!(haversine_distance_meters(3.848, 11.502, 3.848, 11.502), 0.0);
+        // One degree of latitude is about 111.2 km
+        let one_degree = haversine_distance_meters(0.0, 0.0, 1.0, 0.0);
+        assert!((one_degree - 111_195.0).abs() < 10.0);
+    }
+}
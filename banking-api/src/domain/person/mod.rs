@@ -7,6 +7,7 @@ pub mod entity_reference;
 #[allow(clippy::module_inception)]
 pub mod person;
 pub mod common_enums;
+pub mod contact_preference;
 
 pub use country::*;
 pub use country_subdivision::*;
@@ -15,4 +16,5 @@ pub use location::*;
 pub use messaging::*;
 pub use entity_reference::*;
 pub use person::*;
-pub use common_enums::*;
\ No newline at end of file
+pub use common_enums::*;
+pub use contact_preference::*;
\ No newline at end of file
@@ -56,4 +56,39 @@ pub struct Person {
     /// # Documentation
     /// Reference to another Person if this is a duplicate
     pub duplicate_of_person_id: Option<Uuid>,
-}
\ No newline at end of file
+}
+
+/// # Documentation
+/// - What made a person look like a duplicate of another
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DuplicateMatchReason {
+    /// Same external identifier
+    ExternalIdentifier,
+    /// Same display name once case, punctuation and spacing are ignored
+    NormalizedName,
+    /// At least one shared messaging value (phone, email, ...)
+    Messaging,
+}
+
+/// # Service Trait
+/// - FQN: banking-api/src/service/person/person_service.rs/PersonService
+/// # Documentation
+/// - A scored candidate duplicate of a person, for review before linking
+/// # Nature
+/// - Immutable, computed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateCandidate {
+    /// # Documentation
+    /// Person the search was run for
+    pub person_id: Uuid,
+
+    /// # Documentation
+    /// Person that may be the same individual
+    pub candidate_person_id: Uuid,
+
+    /// # Documentation
+    /// Sum of the weights of the matched reasons; higher is more likely a duplicate
+    pub score: u32,
+
+    pub match_reasons: Vec<DuplicateMatchReason>,
+}
@@ -1,4 +1,4 @@
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -27,6 +27,11 @@ impl Product {
     pub fn builder(id: Uuid, name_l1: &str, name_l2: &str, name_l3: &str, product_type: ProductType, updated_by: Uuid) -> Result<ProductBuilder, &'static str> {
         ProductBuilder::new(id, name_l1, name_l2, name_l3, product_type, updated_by)
     }
+
+    /// Active and within its validity period on `on`
+    pub fn is_open_for_new_accounts(&self, on: NaiveDate) -> bool {
+        self.is_active && self.valid_from <= on && self.valid_to.is_none_or(|valid_to| on <= valid_to)
+    }
 }
 
 /// The type of banking product.
@@ -54,6 +59,56 @@ pub enum ProductAccrualFrequency {
     None,
 }
 
+
+/// How the days of an accrual period and the days of the year are counted
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DayCountConvention {
+    /// Actual days over a fixed 365-day year, leap years included
+    #[default]
+    Actual365Fixed,
+    /// Actual days over a 360-day year
+    Actual360,
+    /// Actual days over the days of the year they fall in, 366 in a leap year
+    ActualActual,
+    /// Every month counted as 30 days over a 360-day year (bond basis)
+    Thirty360,
+}
+
+impl DayCountConvention {
+    /// Days counted from `from` up to, not including, `to`
+    pub fn day_count(self, from: NaiveDate, to: NaiveDate) -> i64 {
+        match self {
+            DayCountConvention::Thirty360 => {
+                let from_day = from.day().min(30) as i64;
+                let to_day = match to.day() as i64 {
+                    31 if from_day == 30 => 30,
+                    day => day,
+                };
+                (to.year() - from.year()) as i64 * 360
+                    + (to.month() as i64 - from.month() as i64) * 30
+                    + (to_day - from_day)
+            }
+            _ => (to - from).num_days(),
+        }
+    }
+
+    /// Days of the year the annual rate is divided by for interest on `date`
+    pub fn year_basis(self, date: NaiveDate) -> i64 {
+        match self {
+            DayCountConvention::Actual365Fixed => 365,
+            DayCountConvention::Actual360 | DayCountConvention::Thirty360 => 360,
+            DayCountConvention::ActualActual => if date.leap_year() { 366 } else { 365 },
+        }
+    }
+
+    /// Unrounded interest on `principal` for `date`: under 30/360 the last day
+    /// of a month short of 30 days also counts the days it is short
+    pub fn daily_accrual(self, principal: Decimal, annual_rate: Decimal, date: NaiveDate) -> Decimal {
+        let days = date.succ_opt().map(|next| self.day_count(date, next)).unwrap_or(1);
+        principal * annual_rate * Decimal::from(days) / Decimal::from(self.year_basis(date))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProductRules {
     pub minimum_balance: Decimal,
@@ -73,7 +128,91 @@ pub struct ProductRules {
     pub default_overdraft_limit: Option<Decimal>,
     pub per_transaction_limit: Option<Decimal>,
     pub overdraft_interest_rate: Option<Decimal>,
+    /// Debit rate on the part of an overdrawn balance beyond the overdraft limit
+    pub unauthorized_overdraft_interest_rate: Option<Decimal>,
     pub accrual_frequency: ProductAccrualFrequency,
+    /// Day count daily accrual divides the annual rate by
+    #[serde(default)]
+    pub day_count_convention: DayCountConvention,
+    /// Loans of this product must keep at least one active guarantor
+    pub guarantor_required: bool,
+    /// Balance of a current account above which the custody fee accrues
+    pub custody_fee_threshold: Option<Decimal>,
+    /// Annual custody fee rate as a negative fraction, charged on the part of
+    /// the balance above the threshold only
+    pub custody_fee_rate: Option<Decimal>,
+    /// Whole months without activity after which a dormant account is charged
+    /// the inactivity fee, and again each month it stays inactive
+    pub inactivity_fee_threshold_months: Option<i32>,
+    /// Channel fee item pricing the inactivity fee
+    pub inactivity_fee_item_id: Option<Uuid>,
+    /// Months before the first inactivity fee the owners are warned of it
+    pub inactivity_fee_warning_months: Option<i32>,
+    /// Currencies accounts of the product may be opened in; empty for any currency
+    #[serde(default)]
+    pub supported_currencies: Vec<HeaplessString<3>>,
+}
+
+/// Where a dormant account stands against the inactivity fee of its product
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InactivityFeeStage {
+    /// Within the warning lead time; the warning period began on `warned_from`
+    /// and the fee is first charged on `fee_date`
+    Warning { warned_from: NaiveDate, fee_date: NaiveDate },
+    /// Past the threshold; the fee is charged once in the monthly cycle
+    /// starting on `cycle_start`
+    Due { cycle_start: NaiveDate },
+}
+
+/// Part of an inactivity fee the balance covers. The fee never takes the
+/// balance below zero, so an empty or overdrawn account is charged nothing.
+pub fn inactivity_fee_charge(fee: Decimal, balance: Decimal) -> Decimal {
+    fee.min(balance).max(Decimal::ZERO)
+}
+
+/// Whole months from `from` to `to`, counted on the day of month of `from`
+fn whole_months_between(from: NaiveDate, to: NaiveDate) -> Option<u32> {
+    let months = (to.year() - from.year()) * 12 + to.month() as i32 - from.month() as i32;
+    let months = u32::try_from(months).ok()?;
+    if from.checked_add_months(Months::new(months))? > to {
+        months.checked_sub(1)
+    } else {
+        Some(months)
+    }
+}
+
+impl ProductRules {
+    pub fn supports_currency(&self, currency: &str) -> bool {
+        self.supported_currencies.is_empty() || self.supported_currencies.iter().any(|c| c.as_str() == currency)
+    }
+
+    /// Part of a balance the custody fee accrues on, with the (negative) rate.
+    /// None when the product has no custody fee or the balance is not above
+    /// the threshold.
+    pub fn custody_fee_basis(&self, balance: Decimal) -> Option<(Decimal, Decimal)> {
+        let threshold = self.custody_fee_threshold?;
+        let rate = self.custody_fee_rate?;
+        (balance > threshold && rate < Decimal::ZERO).then(|| (balance - threshold, rate))
+    }
+
+    /// Stage of the inactivity fee for an account last active on
+    /// `last_activity_date`. None when the product charges no inactivity fee
+    /// or the account is not yet within the warning lead time.
+    pub fn inactivity_fee_stage(&self, last_activity_date: NaiveDate, run_date: NaiveDate) -> Option<InactivityFeeStage> {
+        self.inactivity_fee_item_id?;
+        let threshold = u32::try_from(self.inactivity_fee_threshold_months?).ok()?;
+        let lead = u32::try_from(self.inactivity_fee_warning_months.unwrap_or(0)).ok()?;
+        let months_inactive = whole_months_between(last_activity_date, run_date)?;
+        let after = |months: u32| last_activity_date.checked_add_months(Months::new(months));
+
+        if months_inactive >= threshold {
+            Some(InactivityFeeStage::Due { cycle_start: after(months_inactive)? })
+        } else if months_inactive + lead >= threshold {
+            Some(InactivityFeeStage::Warning { warned_from: after(threshold - lead)?, fee_date: after(threshold)? })
+        } else {
+            None
+        }
+    }
 }
 
 
@@ -166,6 +305,32 @@ impl ProductBuilder {
     }
 
     pub fn build(self) -> Result<Product, &'static str> {
+        if let Some(rules) = &self.rules {
+            if rules.custody_fee_rate.is_some_and(|rate| rate > Decimal::ZERO) {
+                return Err("`custody_fee_rate` must not be positive");
+            }
+            if rules.custody_fee_rate.is_some() != rules.custody_fee_threshold.is_some() {
+                return Err("`custody_fee_rate` and `custody_fee_threshold` go together");
+            }
+            if rules.inactivity_fee_item_id.is_some() != rules.inactivity_fee_threshold_months.is_some() {
+                return Err("`inactivity_fee_item_id` and `inactivity_fee_threshold_months` go together");
+            }
+            if rules.inactivity_fee_threshold_months.is_some_and(|months| months < 1) {
+                return Err("`inactivity_fee_threshold_months` must be at least one");
+            }
+            if matches!(
+                (rules.inactivity_fee_warning_months, rules.inactivity_fee_threshold_months),
+                (Some(warning), Some(threshold)) if warning < 0 || warning >= threshold
+            ) {
+                return Err("`inactivity_fee_warning_months` must be below `inactivity_fee_threshold_months`");
+            }
+            if matches!(
+                (rules.unauthorized_overdraft_interest_rate, rules.overdraft_interest_rate),
+                (Some(unauthorized), Some(authorized)) if unauthorized < authorized
+            ) {
+                return Err("`unauthorized_overdraft_interest_rate` must not be below `overdraft_interest_rate`");
+            }
+        }
         let now = Utc::now();
         Ok(Product {
             id: self.id,
@@ -183,4 +348,95 @@ impl ProductBuilder {
             updated_by_person_id: self.updated_by_person_id,
         })
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inactivity_rules(threshold_months: i32, warning_months: i32) -> ProductRules {
+        ProductRules {
+            minimum_balance: Decimal::ZERO,
+            maximum_balance: None,
+            daily_transaction_limit: None,
+            monthly_transaction_limit: None,
+            overdraft_allowed: false,
+            overdraft_limit: None,
+            interest_calculation_method: HeaplessString::try_from("Daily").unwrap(),
+            interest_posting_frequency: PostingFrequency::Monthly,
+            dormancy_threshold_days: 180,
+            minimum_opening_balance: Decimal::ZERO,
+            closure_fee: Decimal::ZERO,
+            maintenance_fee: None,
+            maintenance_fee_frequency: None,
+            default_dormancy_days: None,
+            default_overdraft_limit: None,
+            per_transaction_limit: None,
+            overdraft_interest_rate: None,
+            unauthorized_overdraft_interest_rate: None,
+            accrual_frequency: ProductAccrualFrequency::Daily,
+            day_count_convention: DayCountConvention::Actual365Fixed,
+            guarantor_required: false,
+            custody_fee_threshold: None,
+            custody_fee_rate: None,
+            inactivity_fee_threshold_months: Some(threshold_months),
+            inactivity_fee_item_id: Some(Uuid::new_v4()),
+            inactivity_fee_warning_months: Some(warning_months),
+            supported_currencies: Vec::new(),
+        }
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_one_month_before_the_threshold_only_warns() {
+        let rules = inactivity_rules(12, 1);
+        let last_activity = date(2024, 1, 31);
+
+        assert_eq!(rules.inactivity_fee_stage(last_activity, date(2024, 12, 30)), None);
+        let warning = InactivityFeeStage::Warning { warned_from: date(2024, 12, 31), fee_date: date(2025, 1, 31) };
+        assert_eq!(rules.inactivity_fee_stage(last_activity, date(2024, 12, 31)), Some(warning));
+        assert_eq!(rules.inactivity_fee_stage(last_activity, date(2025, 1, 30)), Some(warning));
+
+        assert_eq!(
+            rules.inactivity_fee_stage(last_activity, date(2025, 1, 31)),
+            Some(InactivityFeeStage::Due { cycle_start: date(2025, 1, 31) })
+        );
+        // Each later month is a cycle of its own, a month-end anniversary
+        // falling on the last day of shorter months
+        assert_eq!(
+            rules.inactivity_fee_stage(last_activity, date(2025, 3, 15)),
+            Some(InactivityFeeStage::Due { cycle_start: date(2025, 2, 28) })
+        );
+    }
+
+    #[test]
+    fn test_products_without_inactivity_fee_or_warning_lead() {
+        let mut rules = inactivity_rules(6, 0);
+        let last_activity = date(2024, 3, 10);
+        assert_eq!(rules.inactivity_fee_stage(last_activity, date(2024, 9, 9)), None);
+        assert!(matches!(rules.inactivity_fee_stage(last_activity, date(2024, 9, 10)), Some(InactivityFeeStage::Due { .. })));
+
+        rules.inactivity_fee_item_id = None;
+        assert_eq!(rules.inactivity_fee_stage(last_activity, date(2024, 9, 10)), None);
+    }
+
+    #[test]
+    fn test_empty_supported_currencies_accepts_any_currency() {
+        let mut rules = inactivity_rules(6, 1);
+        assert!(rules.supports_currency("XAF"));
+
+        rules.supported_currencies = vec![HeaplessString::try_from("XAF").unwrap(), HeaplessString::try_from("EUR").unwrap()];
+        assert!(rules.supports_currency("EUR"));
+        assert!(!rules.supports_currency("USD"));
+    }
+
+    #[test]
+    fn test_inactivity_fee_charges_only_what_the_balance_covers() {
+        assert_eq!(inactivity_fee_charge(Decimal::from(2_500), Decimal::from(10_000)), Decimal::from(2_500));
+        assert_eq!(inactivity_fee_charge(Decimal::from(2_500), Decimal::from(1_200)), Decimal::from(1_200));
+        assert_eq!(inactivity_fee_charge(Decimal::from(2_500), Decimal::ZERO), Decimal::ZERO);
+        assert_eq!(inactivity_fee_charge(Decimal::from(2_500), Decimal::from(-300)), Decimal::ZERO);
+    }
+}
@@ -0,0 +1,164 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// How a promotion changes the product rate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PromotionRate {
+    /// Added to the product rate
+    Bonus(Decimal),
+    /// Replaces the product rate
+    Override(Decimal),
+}
+
+/// Time-boxed interest rate promotion on a product
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProductPromotion {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub promotion_code: HeaplessString<50>,
+    pub name: HeaplessString<100>,
+    pub rate: PromotionRate,
+    /// Eligible accounts were opened within this range, bounds inclusive
+    pub opened_from: Option<NaiveDate>,
+    pub opened_to: Option<NaiveDate>,
+    /// Eligible accounts have an owner currently in this segment
+    pub segment_code: Option<HeaplessString<50>>,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    /// Total bonus interest the promotion may pay
+    pub budget_cap: Option<Decimal>,
+    /// Bonus interest accrued under the promotion so far
+    pub bonus_interest_attributed: Decimal,
+    pub budget_exhausted_at: Option<DateTime<Utc>>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    pub updated_by_person_id: Uuid,
+}
+
+impl ProductPromotion {
+    /// The promotion ends by itself after `end_date`, no expiry run is needed
+    pub fn is_in_effect(&self, date: NaiveDate) -> bool {
+        self.is_active
+            && self.budget_exhausted_at.is_none()
+            && self.start_date <= date
+            && date <= self.end_date
+    }
+
+    pub fn is_eligible(&self, open_date: NaiveDate, segment_codes: &[HeaplessString<50>]) -> bool {
+        self.opened_from.is_none_or(|from| open_date >= from)
+            && self.opened_to.is_none_or(|to| open_date <= to)
+            && self.segment_code.as_ref().is_none_or(|code| segment_codes.contains(code))
+    }
+
+    /// Budget left, `None` when the promotion has no cap
+    pub fn remaining_budget(&self) -> Option<Decimal> {
+        self.budget_cap
+            .map(|cap| (cap - self.bonus_interest_attributed).max(Decimal::ZERO))
+    }
+
+    pub fn apply(&self, product_rate: Decimal) -> Decimal {
+        match self.rate {
+            PromotionRate::Bonus(bonus) => product_rate + bonus,
+            PromotionRate::Override(rate) => rate,
+        }
+    }
+}
+
+/// Where an account's interest rate comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InterestRateSource {
+    Product,
+    Promotion(Uuid),
+    Account,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResolvedInterestRate {
+    pub rate: Decimal,
+    pub source: InterestRateSource,
+}
+
+impl ResolvedInterestRate {
+    /// Stacking order: an account-level rate beats a promotion, which beats the product rate
+    pub fn resolve(
+        product_rate: Decimal,
+        promotion: Option<&ProductPromotion>,
+        account_rate: Option<Decimal>,
+    ) -> Self {
+        match (account_rate, promotion) {
+            (Some(rate), _) => Self { rate, source: InterestRateSource::Account },
+            (None, Some(promotion)) => Self {
+                rate: promotion.apply(product_rate),
+                source: InterestRateSource::Promotion(promotion.id),
+            },
+            (None, None) => Self { rate: product_rate, source: InterestRateSource::Product },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn promotion(rate: PromotionRate) -> ProductPromotion {
+        ProductPromotion {
+            id: Uuid::new_v4(),
+            product_id: Uuid::new_v4(),
+            promotion_code: HeaplessString::try_from("Q1-SAVER").unwrap(),
+            name: HeaplessString::try_from("Q1 savings boost").unwrap(),
+            rate,
+            opened_from: NaiveDate::from_ymd_opt(2024, 1, 1),
+            opened_to: NaiveDate::from_ymd_opt(2024, 3, 31),
+            segment_code: None,
+            start_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+            budget_cap: None,
+            bonus_interest_attributed: Decimal::ZERO,
+            budget_exhausted_at: None,
+            is_active: true,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn test_eligibility_by_opening_date_and_segment() {
+        let mut promotion = promotion(PromotionRate::Bonus(Decimal::new(1, 2)));
+        let new_account = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+        let old_account = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+
+        assert!(promotion.is_eligible(new_account, &[]));
+        assert!(!promotion.is_eligible(old_account, &[]));
+
+        let youth = HeaplessString::try_from("YOUTH").unwrap();
+        promotion.segment_code = Some(youth.clone());
+        assert!(!promotion.is_eligible(new_account, &[]));
+        assert!(promotion.is_eligible(new_account, &[youth]));
+
+        assert!(promotion.is_in_effect(NaiveDate::from_ymd_opt(2024, 3, 31).unwrap()));
+        assert!(!promotion.is_in_effect(NaiveDate::from_ymd_opt(2024, 4, 1).unwrap()));
+    }
+
+    #[test]
+    fn test_promotion_beats_product_and_loses_to_account_rate() {
+        let product_rate = Decimal::new(2, 2);
+        let bonus = promotion(PromotionRate::Bonus(Decimal::new(1, 2)));
+        let fixed = promotion(PromotionRate::Override(Decimal::new(5, 2)));
+
+        let resolved = ResolvedInterestRate::resolve(product_rate, None, None);
+        assert_eq!(resolved, ResolvedInterestRate { rate: product_rate, source: InterestRateSource::Product });
+
+        let resolved = ResolvedInterestRate::resolve(product_rate, Some(&bonus), None);
+        assert_eq!(resolved.rate, Decimal::new(3, 2));
+        assert_eq!(resolved.source, InterestRateSource::Promotion(bonus.id));
+        assert_eq!(ResolvedInterestRate::resolve(product_rate, Some(&fixed), None).rate, Decimal::new(5, 2));
+
+        let resolved = ResolvedInterestRate::resolve(product_rate, Some(&fixed), Some(Decimal::new(4, 2)));
+        assert_eq!(resolved, ResolvedInterestRate { rate: Decimal::new(4, 2), source: InterestRateSource::Account });
+    }
+}
@@ -0,0 +1,568 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{
+    AmortizationMethod, AmortizationSchedule, FeeCalculationMethod, FeeTriggerEvent,
+    GenerateAmortizationScheduleRequest, InterestRateTier, PaymentFrequency, ProductFeeSchedule,
+    ProductRules,
+};
+
+/// How often a simulated fee is charged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SimulatedFeeFrequency {
+    /// Charged once at opening
+    Upfront,
+    Monthly,
+    Quarterly,
+    Annual,
+}
+
+/// A product fee as it would apply to a simulated account
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulatedFee {
+    pub fee_code: HeaplessString<12>,
+    pub description: HeaplessString<200>,
+    pub frequency: SimulatedFeeFrequency,
+    pub amount: Decimal,
+}
+
+impl SimulatedFee {
+    /// Whether the fee is charged in the given 1-based month
+    pub fn is_due_in_month(&self, month: u32) -> bool {
+        match self.frequency {
+            SimulatedFeeFrequency::Upfront => month == 1,
+            SimulatedFeeFrequency::Monthly => true,
+            SimulatedFeeFrequency::Quarterly => month.is_multiple_of(3),
+            SimulatedFeeFrequency::Annual => month.is_multiple_of(12),
+        }
+    }
+
+    /// Derive the simulated fees of a product. Opening fees may be fixed or a
+    /// percentage of `base_amount`; periodic maintenance fees must be fixed.
+    /// The maintenance fee from the product rules is used only when the fee
+    /// schedule defines no maintenance fee of its own.
+    pub fn from_product(rules: &ProductRules, schedule: &ProductFeeSchedule, base_amount: Decimal) -> Vec<SimulatedFee> {
+        let mut fees = Vec::new();
+        for fee in schedule.fees.iter().filter(|fee| fee.active) {
+            let frequency = match fee.trigger_event {
+                FeeTriggerEvent::AccountOpening => SimulatedFeeFrequency::Upfront,
+                FeeTriggerEvent::MonthlyMaintenance => SimulatedFeeFrequency::Monthly,
+                FeeTriggerEvent::QuarterlyMaintenance => SimulatedFeeFrequency::Quarterly,
+                FeeTriggerEvent::AnnualMaintenance => SimulatedFeeFrequency::Annual,
+                _ => continue,
+            };
+            let amount = match (&fee.calculation_method, frequency) {
+                (FeeCalculationMethod::Fixed, _) => fee.fixed_amount,
+                (FeeCalculationMethod::Percentage, SimulatedFeeFrequency::Upfront) => {
+                    fee.percentage_rate.map(|rate| base_amount * rate)
+                }
+                _ => None,
+            };
+            let Some(mut amount) = amount else { continue };
+            if let Some(min) = fee.minimum_amount {
+                amount = amount.max(min);
+            }
+            if let Some(max) = fee.maximum_amount {
+                amount = amount.min(max);
+            }
+            fees.push(SimulatedFee {
+                fee_code: fee.fee_code.clone(),
+                description: fee.description.clone(),
+                frequency,
+                amount,
+            });
+        }
+
+        let has_maintenance = fees.iter().any(|fee| fee.frequency != SimulatedFeeFrequency::Upfront);
+        if let Some(maintenance_fee) = rules.maintenance_fee.filter(|fee| *fee > Decimal::ZERO) {
+            if !has_maintenance {
+                let frequency = match rules.maintenance_fee_frequency.as_ref().map(|f| f.as_str()) {
+                    Some("Quarterly") => SimulatedFeeFrequency::Quarterly,
+                    Some("Annual") | Some("Annually") => SimulatedFeeFrequency::Annual,
+                    _ => SimulatedFeeFrequency::Monthly,
+                };
+                fees.push(SimulatedFee {
+                    fee_code: HeaplessString::try_from("MAINT").unwrap(),
+                    description: HeaplessString::try_from("Account maintenance fee").unwrap(),
+                    frequency,
+                    amount: maintenance_fee,
+                });
+            }
+        }
+        fees
+    }
+
+    fn total_for_month(fees: &[SimulatedFee], month: u32) -> Decimal {
+        fees.iter().filter(|fee| fee.is_due_in_month(month)).map(|fee| fee.amount).sum()
+    }
+}
+
+/// One month of a savings projection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavingsProjectionEntry {
+    pub month: u32,
+    pub opening_balance: Decimal,
+    pub deposit: Decimal,
+    /// Annual rate of the tier the balance fell in
+    pub applied_rate: Decimal,
+    pub gross_interest: Decimal,
+    pub withholding_tax: Decimal,
+    pub fees: Decimal,
+    pub closing_balance: Decimal,
+}
+
+/// Projected balance growth of a savings product for a regular deposit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavingsSimulation {
+    pub product_id: Uuid,
+    pub monthly_deposit: Decimal,
+    pub months: u32,
+    pub withholding_tax_rate: Decimal,
+    pub fees: Vec<SimulatedFee>,
+    pub entries: Vec<SavingsProjectionEntry>,
+    pub total_deposits: Decimal,
+    pub total_gross_interest: Decimal,
+    pub total_withholding_tax: Decimal,
+    pub total_fees: Decimal,
+    pub final_balance: Decimal,
+}
+
+impl SavingsSimulation {
+    /// Project month by month: deposit at the start of the month, interest on
+    /// the resulting balance at the rate of its tier (annual rates as
+    /// fractions, like interest accrual), withholding tax on the interest,
+    /// then fees. `default_rate` applies when no tier matches.
+    pub fn project(
+        product_id: Uuid,
+        monthly_deposit: Decimal,
+        months: u32,
+        tiers: &[InterestRateTier],
+        default_rate: Decimal,
+        fees: Vec<SimulatedFee>,
+        withholding_tax_rate: Decimal,
+    ) -> Self {
+        let mut entries = Vec::with_capacity(months as usize);
+        let mut balance = Decimal::ZERO;
+
+        for month in 1..=months {
+            let opening_balance = balance;
+            balance += monthly_deposit;
+
+            let applied_rate = Self::tier_rate(tiers, balance).unwrap_or(default_rate);
+            let gross_interest = (balance * applied_rate / Decimal::from(12)).round_dp(2);
+            let withholding_tax = (gross_interest * withholding_tax_rate).round_dp(2);
+            let month_fees = SimulatedFee::total_for_month(&fees, month);
+            balance += gross_interest - withholding_tax - month_fees;
+
+            entries.push(SavingsProjectionEntry {
+                month,
+                opening_balance,
+                deposit: monthly_deposit,
+                applied_rate,
+                gross_interest,
+                withholding_tax,
+                fees: month_fees,
+                closing_balance: balance,
+            });
+        }
+
+        Self {
+            product_id,
+            monthly_deposit,
+            months,
+            withholding_tax_rate,
+            total_deposits: monthly_deposit * Decimal::from(months),
+            total_gross_interest: entries.iter().map(|e| e.gross_interest).sum(),
+            total_withholding_tax: entries.iter().map(|e| e.withholding_tax).sum(),
+            total_fees: entries.iter().map(|e| e.fees).sum(),
+            final_balance: balance,
+            fees,
+            entries,
+        }
+    }
+
+    fn tier_rate(tiers: &[InterestRateTier], balance: Decimal) -> Option<Decimal> {
+        tiers
+            .iter()
+            .filter(|tier| balance >= tier.minimum_balance && tier.maximum_balance.is_none_or(|max| balance <= max))
+            .max_by_key(|tier| tier.minimum_balance)
+            .map(|tier| tier.interest_rate)
+    }
+}
+
+/// Pre-sale loan simulation with the same schedule real disbursement produces
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoanSimulation {
+    pub product_id: Uuid,
+    pub principal: Decimal,
+    pub term_months: u32,
+    /// Annual rate in percent, as used by amortization schedule generation
+    pub annual_interest_rate: Decimal,
+    pub first_payment_date: NaiveDate,
+    pub schedule: AmortizationSchedule,
+    pub total_interest: Decimal,
+    pub fees: Vec<SimulatedFee>,
+    pub total_fees: Decimal,
+    /// APR-style annual rate in percent, including fees
+    pub effective_annual_rate: Decimal,
+}
+
+impl LoanSimulation {
+    pub fn simulate(
+        product_id: Uuid,
+        principal: Decimal,
+        term_months: u32,
+        annual_interest_rate: Decimal,
+        first_payment_date: NaiveDate,
+        fees: Vec<SimulatedFee>,
+    ) -> Self {
+        let request = Self::request(Uuid::nil(), principal, term_months, annual_interest_rate, first_payment_date);
+        let schedule = AmortizationSchedule::generate(&request);
+
+        let upfront_fees: Decimal = fees
+            .iter()
+            .filter(|f| f.frequency == SimulatedFeeFrequency::Upfront)
+            .map(|f| f.amount)
+            .sum();
+        let periodic_fees: Vec<SimulatedFee> = fees
+            .iter()
+            .filter(|f| f.frequency != SimulatedFeeFrequency::Upfront)
+            .cloned()
+            .collect();
+        let payments: Vec<Decimal> = schedule
+            .schedule_entries
+            .iter()
+            .map(|entry| entry.installment_amount + SimulatedFee::total_for_month(&periodic_fees, entry.installment_number))
+            .collect();
+        let total_fees = upfront_fees + payments.iter().sum::<Decimal>()
+            - schedule.schedule_entries.iter().map(|e| e.installment_amount).sum::<Decimal>();
+        let effective_annual_rate = Self::effective_rate(principal - upfront_fees, &payments);
+
+        Self {
+            product_id,
+            principal,
+            term_months,
+            annual_interest_rate,
+            first_payment_date,
+            total_interest: schedule.total_interest,
+            schedule,
+            fees,
+            total_fees,
+            effective_annual_rate,
+        }
+    }
+
+    /// The schedule request to use when the quoted loan is disbursed, so the
+    /// real schedule matches the simulated one.
+    pub fn schedule_request(&self, loan_account_id: Uuid) -> GenerateAmortizationScheduleRequest {
+        Self::request(loan_account_id, self.principal, self.term_months, self.annual_interest_rate, self.first_payment_date)
+    }
+
+    fn request(
+        loan_account_id: Uuid,
+        principal: Decimal,
+        term_months: u32,
+        annual_interest_rate: Decimal,
+        first_payment_date: NaiveDate,
+    ) -> GenerateAmortizationScheduleRequest {
+        GenerateAmortizationScheduleRequest {
+            loan_account_id,
+            principal_amount: principal,
+            annual_interest_rate,
+            term_months,
+            first_payment_date,
+            payment_frequency: PaymentFrequency::Monthly,
+            calculation_method: AmortizationMethod::EqualInstallments,
+        }
+    }
+
+    /// Monthly internal rate of return of the net amount received against
+    /// the payments, by bisection, annualized as 12 x monthly in percent.
+    fn effective_rate(net_received: Decimal, payments: &[Decimal]) -> Decimal {
+        if net_received <= Decimal::ZERO || payments.is_empty() {
+            return Decimal::ZERO;
+        }
+        let present_value = |rate: Decimal| {
+            let mut discount = Decimal::ONE;
+            let mut total = Decimal::ZERO;
+            for payment in payments {
+                discount /= Decimal::ONE + rate;
+                total += payment * discount;
+            }
+            total
+        };
+
+        let mut low = Decimal::ZERO;
+        let mut high = Decimal::ONE;
+        if present_value(low) <= net_received {
+            return Decimal::ZERO;
+        }
+        for _ in 0..60 {
+            let mid = (low + high) / Decimal::from(2);
+            if present_value(mid) > net_received {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        ((low + high) / Decimal::from(2) * Decimal::from(12) * Decimal::from(100)).round_dp(4)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuoteType {
+    Savings,
+    Loan,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuoteStatus {
+    Active,
+    Converted,
+    Expired,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum QuoteSimulation {
+    Savings(SavingsSimulation),
+    Loan(LoanSimulation),
+}
+
+impl QuoteSimulation {
+    pub fn quote_type(&self) -> QuoteType {
+        match self {
+            QuoteSimulation::Savings(_) => QuoteType::Savings,
+            QuoteSimulation::Loan(_) => QuoteType::Loan,
+        }
+    }
+
+    pub fn product_id(&self) -> Uuid {
+        match self {
+            QuoteSimulation::Savings(simulation) => simulation.product_id,
+            QuoteSimulation::Loan(simulation) => simulation.product_id,
+        }
+    }
+}
+
+/// A persisted simulation that can be converted into an account opening request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Quote {
+    pub id: Uuid,
+    pub quote_type: QuoteType,
+    pub product_id: Uuid,
+    /// Prospects may not be customers yet
+    pub customer_id: Option<Uuid>,
+    pub simulation: QuoteSimulation,
+    pub status: QuoteStatus,
+    pub expires_at: DateTime<Utc>,
+    pub converted_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub created_by_person_id: Uuid,
+}
+
+impl Quote {
+    pub fn is_convertible(&self, now: DateTime<Utc>) -> bool {
+        self.status == QuoteStatus::Active && now < self.expires_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{DayCountConvention, PostingFrequency, ProductAccrualFrequency};
+    use std::str::FromStr;
+
+    fn fee(code: &str, frequency: SimulatedFeeFrequency, amount: Decimal) -> SimulatedFee {
+        SimulatedFee {
+            fee_code: HeaplessString::try_from(code).unwrap(),
+            description: HeaplessString::try_from(code).unwrap(),
+            frequency,
+            amount,
+        }
+    }
+
+    fn rules(maintenance_fee: Option<Decimal>) -> ProductRules {
+        ProductRules {
+            minimum_balance: Decimal::ZERO,
+            maximum_balance: None,
+            daily_transaction_limit: None,
+            monthly_transaction_limit: None,
+            overdraft_allowed: false,
+            overdraft_limit: None,
+            interest_calculation_method: HeaplessString::try_from("Daily").unwrap(),
+            interest_posting_frequency: PostingFrequency::Monthly,
+            dormancy_threshold_days: 365,
+            minimum_opening_balance: Decimal::ZERO,
+            closure_fee: Decimal::ZERO,
+            maintenance_fee,
+            maintenance_fee_frequency: None,
+            default_dormancy_days: None,
+            default_overdraft_limit: None,
+            per_transaction_limit: None,
+            overdraft_interest_rate: None,
+            unauthorized_overdraft_interest_rate: None,
+            accrual_frequency: ProductAccrualFrequency::Daily,
+            day_count_convention: DayCountConvention::Actual365Fixed,
+            guarantor_required: false,
+            custody_fee_threshold: None,
+            custody_fee_rate: None,
+            inactivity_fee_threshold_months: None,
+            inactivity_fee_item_id: None,
+            inactivity_fee_warning_months: None,
+            supported_currencies: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_savings_projection_with_tiers_tax_and_fees() {
+        let tiers = vec![
+            InterestRateTier {
+                minimum_balance: Decimal::ZERO,
+                maximum_balance: Some(Decimal::from(1_000)),
+                interest_rate: Decimal::from_str("0.012").unwrap(),
+                tier_name: HeaplessString::try_from("Base").unwrap(),
+            },
+            InterestRateTier {
+                minimum_balance: Decimal::from_str("1000.01").unwrap(),
+                maximum_balance: None,
+                interest_rate: Decimal::from_str("0.024").unwrap(),
+                tier_name: HeaplessString::try_from("Premium").unwrap(),
+            },
+        ];
+        let simulation = SavingsSimulation::project(
+            Uuid::new_v4(),
+            Decimal::from(500),
+            3,
+            &tiers,
+            Decimal::ZERO,
+            vec![fee("MAINT", SimulatedFeeFrequency::Monthly, Decimal::ONE)],
+            Decimal::from_str("0.10").unwrap(),
+        );
+
+        // Month 1: 500 at 1.2% -> 0.50 interest, 0.05 tax, 1.00 fee
+        assert_eq!(simulation.entries[0].gross_interest, Decimal::from_str("0.50").unwrap());
+        assert_eq!(simulation.entries[0].withholding_tax, Decimal::from_str("0.05").unwrap());
+        assert_eq!(simulation.entries[0].closing_balance, Decimal::from_str("499.45").unwrap());
+        // Month 3 crosses into the premium tier
+        assert_eq!(simulation.entries[2].applied_rate, Decimal::from_str("0.024").unwrap());
+        assert_eq!(simulation.total_deposits, Decimal::from(1_500));
+        assert_eq!(simulation.total_fees, Decimal::from(3));
+        assert_eq!(
+            simulation.final_balance,
+            simulation.total_deposits + simulation.total_gross_interest
+                - simulation.total_withholding_tax - simulation.total_fees
+        );
+    }
+
+    #[test]
+    fn test_maintenance_fee_from_rules_when_schedule_has_none() {
+        let schedule = ProductFeeSchedule {
+            product_id: Uuid::new_v4(),
+            fees: vec![],
+            effective_from: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            effective_to: None,
+        };
+        let fees = SimulatedFee::from_product(&rules(Some(Decimal::from(2))), &schedule, Decimal::from(1_000));
+        assert_eq!(fees.len(), 1);
+        assert_eq!(fees[0].frequency, SimulatedFeeFrequency::Monthly);
+        assert!(SimulatedFee::from_product(&rules(None), &schedule, Decimal::from(1_000)).is_empty());
+    }
+
+    #[test]
+    fn test_loan_simulation_totals_and_effective_rate() {
+        let first_payment = NaiveDate::from_ymd_opt(2024, 2, 15).unwrap();
+        let without_fees = LoanSimulation::simulate(Uuid::new_v4(), Decimal::from(12_000), 12, Decimal::from(12), first_payment, vec![]);
+        assert_eq!(without_fees.schedule.schedule_entries.len(), 12);
+        assert_eq!(without_fees.total_interest, without_fees.schedule.total_interest);
+        assert_eq!(without_fees.total_fees, Decimal::ZERO);
+        // Without fees the effective rate equals the nominal rate
+        assert!((without_fees.effective_annual_rate - Decimal::from(12)).abs() < Decimal::from_str("0.01").unwrap());
+
+        let with_fees = LoanSimulation::simulate(
+            Uuid::new_v4(),
+            Decimal::from(12_000),
+            12,
+            Decimal::from(12),
+            first_payment,
+            vec![
+                fee("ORIG", SimulatedFeeFrequency::Upfront, Decimal::from(120)),
+                fee("SVC", SimulatedFeeFrequency::Monthly, Decimal::from(5)),
+            ],
+        );
+        assert_eq!(with_fees.total_fees, Decimal::from(180));
+        assert!(with_fees.effective_annual_rate > without_fees.effective_annual_rate);
+    }
+
+    #[test]
+    fn test_converted_quote_schedule_matches_simulation() {
+        let simulation = LoanSimulation::simulate(
+            Uuid::new_v4(),
+            Decimal::from(25_000),
+            36,
+            Decimal::from_str("9.5").unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+            vec![],
+        );
+        let quote = Quote {
+            id: Uuid::new_v4(),
+            quote_type: QuoteType::Loan,
+            product_id: simulation.product_id,
+            customer_id: None,
+            simulation: QuoteSimulation::Loan(simulation.clone()),
+            status: QuoteStatus::Active,
+            expires_at: Utc::now() + chrono::Duration::days(30),
+            converted_at: None,
+            created_at: Utc::now(),
+            created_by_person_id: Uuid::new_v4(),
+        };
+        assert!(quote.is_convertible(Utc::now()));
+
+        // The quote survives persistence as JSON
+        let stored: Quote = serde_json::from_str(&serde_json::to_string(&quote).unwrap()).unwrap();
+        let QuoteSimulation::Loan(converted) = stored.simulation else {
+            panic!("expected a loan quote");
+        };
+
+        let loan_account_id = Uuid::new_v4();
+        let real = AmortizationSchedule::generate(&converted.schedule_request(loan_account_id));
+
+        assert_eq!(real.loan_account_id, loan_account_id);
+        assert_eq!(real.installment_amount, simulation.schedule.installment_amount);
+        assert_eq!(real.total_interest, simulation.total_interest);
+        assert_eq!(real.maturity_date, simulation.schedule.maturity_date);
+        assert_eq!(real.schedule_entries.len(), simulation.schedule.schedule_entries.len());
+        for (real, simulated) in real.schedule_entries.iter().zip(&simulation.schedule.schedule_entries) {
+            assert_eq!(real.due_date, simulated.due_date);
+            assert_eq!(real.principal_component, simulated.principal_component);
+            assert_eq!(real.interest_component, simulated.interest_component);
+            assert_eq!(real.closing_principal_balance, simulated.closing_principal_balance);
+        }
+    }
+
+    #[test]
+    fn test_expired_or_converted_quote_is_not_convertible() {
+        let simulation = SavingsSimulation::project(Uuid::new_v4(), Decimal::from(100), 1, &[], Decimal::ZERO, vec![], Decimal::ZERO);
+        let mut quote = Quote {
+            id: Uuid::new_v4(),
+            quote_type: QuoteType::Savings,
+            product_id: simulation.product_id,
+            customer_id: None,
+            simulation: QuoteSimulation::Savings(simulation),
+            status: QuoteStatus::Active,
+            expires_at: Utc::now() - chrono::Duration::seconds(1),
+            converted_at: None,
+            created_at: Utc::now(),
+            created_by_person_id: Uuid::new_v4(),
+        };
+        assert!(!quote.is_convertible(Utc::now()));
+
+        quote.expires_at = Utc::now() + chrono::Duration::days(1);
+        quote.status = QuoteStatus::Converted;
+        assert!(!quote.is_convertible(Utc::now()));
+    }
+}
@@ -164,6 +164,63 @@ pub struct ReasonReference {
     pub referenced_by: HeaplessString<100>,
 }
 
+/// Reason required by an operation. Unlike `Option<Uuid>` it cannot be left
+/// out; the service still checks it against the operation before use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ReasonId(pub Uuid);
+
+impl ReasonId {
+    pub fn as_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<Uuid> for ReasonId {
+    fn from(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl From<ReasonId> for Uuid {
+    fn from(id: ReasonId) -> Self {
+        id.0
+    }
+}
+
+impl std::fmt::Display for ReasonId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Operations that regulation requires to carry a reason
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReasonedOperation {
+    AccountStatusChange,
+    CustomerStatusChange,
+    HoldPlacement,
+    HoldRelease,
+    AccountRestriction,
+    FeeWaiver,
+    TransactionReversal,
+    LegalHold,
+    LoanRestructure,
+    WorkflowRejection,
+    AccountDomicileTransfer,
+    CustomerMerge,
+    SuspiciousActivityReport,
+}
+
+/// Why a reason was refused for an operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReasonMismatchIssue {
+    NotFound,
+    Inactive,
+    CategoryNotAllowed(ReasonCategory),
+    ContextNotAllowed(ReasonContext),
+}
+
 impl ReasonAndPurpose {
     /// Get content in specified language, fallback to primary if not available
     pub fn get_content(&self, language_code: &[u8; 3]) -> Option<&str> {
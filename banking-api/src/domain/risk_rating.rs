@@ -0,0 +1,229 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::RiskRating;
+
+/// What a customer's risk rating is scored from, gathered over the
+/// velocity window of the scoring rules
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomerRiskFactors {
+    pub customer_id: Uuid,
+    /// ISO 3166-1 alpha-2 code of the country of the customer's address, if known
+    pub country_iso2: Option<HeaplessString<2>>,
+    /// Accounts the customer holds that are not closed
+    pub account_ids: Vec<Uuid>,
+    /// Products of those accounts
+    pub product_ids: Vec<Uuid>,
+    /// Transactions on those accounts within the velocity window
+    pub transaction_count: i64,
+    /// Compliance alerts ever raised on the customer
+    pub alert_count: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RiskFactor {
+    CountryRisk,
+    ProductMix,
+    TransactionVelocity,
+    PriorAlerts,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RiskFactorScore {
+    pub factor: RiskFactor,
+    pub score: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CountryRiskScore {
+    pub iso2: String,
+    pub score: Decimal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProductRiskScore {
+    pub product_id: Uuid,
+    pub score: Decimal,
+}
+
+/// Score of customers with at least `min_transactions` in the velocity window
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VelocityBand {
+    pub min_transactions: i64,
+    pub score: Decimal,
+}
+
+/// Points each risk factor contributes and the totals at which a customer
+/// is rated Medium and High
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RiskScoringRules {
+    pub country_scores: Vec<CountryRiskScore>,
+    /// Score of unlisted countries and of customers without a known address
+    pub default_country_score: Decimal,
+    /// The riskiest product held sets the product mix score; unlisted products score nothing
+    pub product_scores: Vec<ProductRiskScore>,
+    /// Days of transactions counted towards velocity
+    pub velocity_window_days: i64,
+    /// The highest band reached sets the velocity score
+    pub velocity_bands: Vec<VelocityBand>,
+    pub score_per_alert: Decimal,
+    pub max_alert_score: Decimal,
+    pub medium_threshold: Decimal,
+    pub high_threshold: Decimal,
+}
+
+impl Default for RiskScoringRules {
+    fn default() -> Self {
+        Self {
+            country_scores: Vec::new(),
+            default_country_score: Decimal::from(10),
+            product_scores: Vec::new(),
+            velocity_window_days: 90,
+            velocity_bands: vec![
+                VelocityBand { min_transactions: 150, score: Decimal::from(15) },
+                VelocityBand { min_transactions: 500, score: Decimal::from(30) },
+            ],
+            score_per_alert: Decimal::from(10),
+            max_alert_score: Decimal::from(30),
+            medium_threshold: Decimal::from(30),
+            high_threshold: Decimal::from(60),
+        }
+    }
+}
+
+impl RiskScoringRules {
+    pub fn assess(&self, factors: &CustomerRiskFactors, assessed_at: DateTime<Utc>) -> CustomerRiskAssessment {
+        let country = factors
+            .country_iso2
+            .as_ref()
+            .and_then(|iso2| self.country_scores.iter().find(|country| country.iso2 == iso2.as_str()))
+            .map_or(self.default_country_score, |country| country.score);
+        let product_mix = self
+            .product_scores
+            .iter()
+            .filter(|product| factors.product_ids.contains(&product.product_id))
+            .map(|product| product.score)
+            .max()
+            .unwrap_or(Decimal::ZERO);
+        let velocity = self
+            .velocity_bands
+            .iter()
+            .filter(|band| factors.transaction_count >= band.min_transactions)
+            .map(|band| band.score)
+            .max()
+            .unwrap_or(Decimal::ZERO);
+        let alerts = (self.score_per_alert * Decimal::from(factors.alert_count)).min(self.max_alert_score);
+
+        let factor_scores = vec![
+            RiskFactorScore { factor: RiskFactor::CountryRisk, score: country },
+            RiskFactorScore { factor: RiskFactor::ProductMix, score: product_mix },
+            RiskFactorScore { factor: RiskFactor::TransactionVelocity, score: velocity },
+            RiskFactorScore { factor: RiskFactor::PriorAlerts, score: alerts },
+        ];
+        let score = factor_scores.iter().map(|factor| factor.score).sum();
+        CustomerRiskAssessment {
+            customer_id: factors.customer_id,
+            score,
+            rating: self.rating(score),
+            factor_scores,
+            assessed_at,
+        }
+    }
+
+    /// Rating of a total score; scoring never blacklists
+    pub fn rating(&self, score: Decimal) -> RiskRating {
+        if score >= self.high_threshold {
+            RiskRating::High
+        } else if score >= self.medium_threshold {
+            RiskRating::Medium
+        } else {
+            RiskRating::Low
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomerRiskAssessment {
+    pub customer_id: Uuid,
+    pub score: Decimal,
+    pub rating: RiskRating,
+    pub factor_scores: Vec<RiskFactorScore>,
+    pub assessed_at: DateTime<Utc>,
+}
+
+/// Outcome of recalculating one customer's risk rating
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RiskRatingRecalculation {
+    pub assessment: CustomerRiskAssessment,
+    /// Rating before the recalculation
+    pub previous_rating: RiskRating,
+    /// Enhanced due diligence workflows opened because the rating became High
+    pub edd_workflow_ids: Vec<Uuid>,
+}
+
+impl RiskRatingRecalculation {
+    /// Whether the customer's rating moves to the assessed one. Blacklisted
+    /// customers stay blacklisted whatever they score.
+    pub fn rating_changed(&self) -> bool {
+        self.previous_rating != RiskRating::Blacklisted && self.previous_rating != self.assessment.rating
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(risky_product: Uuid) -> RiskScoringRules {
+        RiskScoringRules {
+            country_scores: vec![CountryRiskScore { iso2: "XX".to_string(), score: Decimal::from(40) }],
+            product_scores: vec![ProductRiskScore { product_id: risky_product, score: Decimal::from(20) }],
+            ..RiskScoringRules::default()
+        }
+    }
+
+    fn factors(country_iso2: Option<&str>, product_ids: Vec<Uuid>, transaction_count: i64, alert_count: i64) -> CustomerRiskFactors {
+        CustomerRiskFactors {
+            customer_id: Uuid::new_v4(),
+            country_iso2: country_iso2.map(|iso2| HeaplessString::try_from(iso2).unwrap()),
+            account_ids: Vec::new(),
+            product_ids,
+            transaction_count,
+            alert_count,
+        }
+    }
+
+    #[test]
+    fn test_factor_scores_add_up_to_rating() {
+        let risky_product = Uuid::new_v4();
+        let rules = rules(risky_product);
+
+        let quiet = rules.assess(&factors(Some("CM"), vec![Uuid::new_v4()], 20, 0), Utc::now());
+        assert_eq!(quiet.score, Decimal::from(10));
+        assert_eq!(quiet.rating, RiskRating::Low);
+
+        let busy = rules.assess(&factors(Some("CM"), vec![risky_product], 200, 0), Utc::now());
+        assert_eq!(busy.score, Decimal::from(45));
+        assert_eq!(busy.rating, RiskRating::Medium);
+
+        let exposed = rules.assess(&factors(Some("XX"), vec![risky_product], 600, 5), Utc::now());
+        let score_of = |factor| exposed.factor_scores.iter().find(|s| s.factor == factor).unwrap().score;
+        assert_eq!(score_of(RiskFactor::CountryRisk), Decimal::from(40));
+        assert_eq!(score_of(RiskFactor::TransactionVelocity), Decimal::from(30));
+        // Five alerts, capped
+        assert_eq!(score_of(RiskFactor::PriorAlerts), Decimal::from(30));
+        assert_eq!(exposed.score, Decimal::from(120));
+        assert_eq!(exposed.rating, RiskRating::High);
+    }
+
+    #[test]
+    fn test_unknown_country_scores_default() {
+        let rules = rules(Uuid::new_v4());
+        let assessment = rules.assess(&factors(None, Vec::new(), 0, 0), Utc::now());
+        assert_eq!(assessment.factor_scores[0].score, rules.default_country_score);
+        assert_eq!(rules.rating(rules.high_threshold - Decimal::ONE), RiskRating::Medium);
+    }
+}
@@ -0,0 +1,176 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SavingsGoalStatus {
+    Active,
+    Closed,
+}
+
+/// Named pocket of a CASA account. Only the allocation is virtual: the money
+/// stays in the account and interest is paid at account level.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavingsGoal {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub name: HeaplessString<100>,
+    pub target_amount: Decimal,
+    pub target_date: Option<NaiveDate>,
+    /// Part of the account's available balance set aside for the goal
+    pub allocated_balance: Decimal,
+    pub status: SavingsGoalStatus,
+    pub created_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub last_updated_at: DateTime<Utc>,
+    pub updated_by_person_id: Uuid,
+}
+
+impl SavingsGoal {
+    pub fn progress(&self, as_of: NaiveDate) -> SavingsGoalProgress {
+        let percent_complete = if self.target_amount > Decimal::ZERO {
+            (self.allocated_balance / self.target_amount * Decimal::ONE_HUNDRED)
+                .min(Decimal::ONE_HUNDRED)
+                .round_dp(2)
+        } else {
+            Decimal::ZERO
+        };
+        SavingsGoalProgress {
+            goal_id: self.id,
+            name: self.name.clone(),
+            target_amount: self.target_amount,
+            allocated_balance: self.allocated_balance,
+            remaining_amount: (self.target_amount - self.allocated_balance).max(Decimal::ZERO),
+            percent_complete,
+            target_date: self.target_date,
+            days_remaining: self.target_date.map(|date| (date - as_of).num_days().max(0)),
+        }
+    }
+}
+
+/// Amount taken off a goal to restore the allocation invariant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavingsGoalDeallocation {
+    pub goal_id: Uuid,
+    pub amount: Decimal,
+}
+
+/// Deallocations bringing the active goals back within `available_balance`.
+/// Each goal gives up its share of the excess in proportion to its
+/// allocation, rounded to cents; the rounding remainder is taken from the
+/// largest goal so the goals end exactly at the available balance.
+pub fn proportional_deallocation(goals: &[SavingsGoal], available_balance: Decimal) -> Vec<SavingsGoalDeallocation> {
+    let active: Vec<&SavingsGoal> = goals
+        .iter()
+        .filter(|g| g.status == SavingsGoalStatus::Active && g.allocated_balance > Decimal::ZERO)
+        .collect();
+    let allocated: Decimal = active.iter().map(|g| g.allocated_balance).sum();
+    let excess = (allocated - available_balance.max(Decimal::ZERO)).min(allocated);
+    if excess <= Decimal::ZERO {
+        return Vec::new();
+    }
+
+    let mut deallocations: Vec<SavingsGoalDeallocation> = active
+        .iter()
+        .map(|g| SavingsGoalDeallocation {
+            goal_id: g.id,
+            amount: (excess * g.allocated_balance / allocated).round_dp(2).min(g.allocated_balance),
+        })
+        .collect();
+
+    let remainder = excess - deallocations.iter().map(|d| d.amount).sum::<Decimal>();
+    if remainder != Decimal::ZERO {
+        if let Some((largest, goal)) = deallocations
+            .iter_mut()
+            .zip(&active)
+            .max_by_key(|(_, g)| g.allocated_balance)
+        {
+            largest.amount = (largest.amount + remainder).min(goal.allocated_balance);
+        }
+    }
+    deallocations.retain(|d| d.amount > Decimal::ZERO);
+    deallocations
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavingsGoalProgress {
+    pub goal_id: Uuid,
+    pub name: HeaplessString<100>,
+    pub target_amount: Decimal,
+    pub allocated_balance: Decimal,
+    pub remaining_amount: Decimal,
+    /// Capped at 100
+    pub percent_complete: Decimal,
+    pub target_date: Option<NaiveDate>,
+    /// Zero once the target date has passed
+    pub days_remaining: Option<i64>,
+}
+
+/// Goals of an account as shown in the app
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavingsGoalsView {
+    pub account_id: Uuid,
+    pub available_balance: Decimal,
+    /// Available balance not allocated to any goal
+    pub unallocated_balance: Decimal,
+    pub goals: Vec<SavingsGoalProgress>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn goal(allocated_balance: Decimal) -> SavingsGoal {
+        SavingsGoal {
+            id: Uuid::new_v4(),
+            account_id: Uuid::new_v4(),
+            name: HeaplessString::try_from("School fees").unwrap(),
+            target_amount: Decimal::from(1000),
+            target_date: NaiveDate::from_ymd_opt(2024, 9, 1),
+            allocated_balance,
+            status: SavingsGoalStatus::Active,
+            created_at: Utc::now(),
+            closed_at: None,
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn test_proportional_deallocation_restores_invariant() {
+        let goals = vec![goal(Decimal::from(600)), goal(Decimal::from(300)), goal(Decimal::from(100))];
+        assert!(proportional_deallocation(&goals, Decimal::from(1000)).is_empty());
+
+        // 1000 allocated, 700 available: each goal gives up 30%
+        let deallocations = proportional_deallocation(&goals, Decimal::from(700));
+        let amounts: Vec<Decimal> = deallocations.iter().map(|d| d.amount).collect();
+        assert_eq!(amounts, vec![Decimal::from(180), Decimal::from(90), Decimal::from(30)]);
+
+        // Thirds do not round to cents; the largest goal absorbs the remainder
+        let goals = vec![goal(Decimal::from(100)), goal(Decimal::from(100)), goal(Decimal::from(100))];
+        let deallocations = proportional_deallocation(&goals, Decimal::from(200));
+        assert_eq!(deallocations.iter().map(|d| d.amount).sum::<Decimal>(), Decimal::from(100));
+        assert_eq!(deallocations.iter().filter(|d| d.amount == Decimal::new(3334, 2)).count(), 1);
+
+        // A negative balance empties every goal
+        let deallocations = proportional_deallocation(&goals, Decimal::from(-50));
+        assert_eq!(deallocations.iter().map(|d| d.amount).sum::<Decimal>(), Decimal::from(300));
+    }
+
+    #[test]
+    fn test_progress_caps_percent_and_days() {
+        let mut school = goal(Decimal::from(250));
+        let progress = school.progress(NaiveDate::from_ymd_opt(2024, 8, 22).unwrap());
+        assert_eq!(progress.percent_complete, Decimal::from(25));
+        assert_eq!(progress.remaining_amount, Decimal::from(750));
+        assert_eq!(progress.days_remaining, Some(10));
+
+        school.allocated_balance = Decimal::from(1200);
+        let progress = school.progress(NaiveDate::from_ymd_opt(2024, 10, 1).unwrap());
+        assert_eq!(progress.percent_complete, Decimal::from(100));
+        assert_eq!(progress.remaining_amount, Decimal::ZERO);
+        assert_eq!(progress.days_remaining, Some(0));
+    }
+}
@@ -0,0 +1,169 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{AccountStatus, AccountType, CustomerStatus, CustomerType, RiskRating};
+
+/// A dynamic customer segment, re-evaluated nightly during EOD.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Segment {
+    pub id: Uuid,
+    pub code: HeaplessString<50>,
+    pub name: HeaplessString<100>,
+    pub description: Option<HeaplessString<255>>,
+    /// All criteria must hold for a customer to be a member
+    pub criteria: Vec<SegmentCriterion>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub updated_by_person_id: Uuid,
+}
+
+/// Comparison applied to a numeric aggregate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SegmentComparison {
+    Equal,
+    NotEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+}
+
+/// A single filter over customer attributes or aggregates of the customer's
+/// non-closed accounts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum SegmentCriterion {
+    CustomerTypeIs { customer_type: CustomerType },
+    CustomerStatusIn { statuses: Vec<CustomerStatus> },
+    RiskRatingIn { ratings: Vec<RiskRating> },
+    /// Sum of current balances across the customer's accounts
+    TotalBalance { operator: SegmentComparison, amount: Decimal },
+    /// Whether the customer holds (or does not hold) an account of the type
+    HoldsAccountType { account_type: AccountType, held: bool },
+    /// Whether the customer holds (or does not hold) an account of the product
+    HoldsProduct { product_id: Uuid, held: bool },
+    /// Whether any of the customer's accounts is in one of the statuses
+    HasAccountInStatus { statuses: Vec<AccountStatus> },
+    /// Days between the evaluation date and the most recent account activity
+    DaysSinceLastActivity { operator: SegmentComparison, days: i32 },
+    /// Whether the customer carries (or does not carry) the tag
+    HasTag { tag_code: HeaplessString<50>, tagged: bool },
+    /// Whether any of the customer's accounts carries (or none carries) the tag
+    HoldsTaggedAccount { tag_code: HeaplessString<50>, held: bool },
+}
+
+impl Segment {
+    /// Validate segment definition
+    pub fn validate(&self) -> Result<(), String> {
+        if self.code.is_empty() {
+            return Err("Segment code is required".to_string());
+        }
+        if self.criteria.is_empty() {
+            return Err("Segment must have at least one criterion".to_string());
+        }
+        for criterion in &self.criteria {
+            match criterion {
+                SegmentCriterion::CustomerStatusIn { statuses } if statuses.is_empty() => {
+                    return Err("CustomerStatusIn requires at least one status".to_string());
+                }
+                SegmentCriterion::RiskRatingIn { ratings } if ratings.is_empty() => {
+                    return Err("RiskRatingIn requires at least one rating".to_string());
+                }
+                SegmentCriterion::HasAccountInStatus { statuses } if statuses.is_empty() => {
+                    return Err("HasAccountInStatus requires at least one status".to_string());
+                }
+                SegmentCriterion::DaysSinceLastActivity { days, .. } if *days < 0 => {
+                    return Err("DaysSinceLastActivity requires a non-negative day count".to_string());
+                }
+                SegmentCriterion::HasTag { tag_code, .. } | SegmentCriterion::HoldsTaggedAccount { tag_code, .. }
+                    if tag_code.is_empty() =>
+                {
+                    return Err("Tag criteria require a tag code".to_string());
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A customer's membership of a segment. An open membership has no
+/// `exited_at`; re-entering after an exit opens a new row.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SegmentMembership {
+    pub id: Uuid,
+    pub segment_id: Uuid,
+    pub customer_id: Uuid,
+    pub entered_at: DateTime<Utc>,
+    pub exited_at: Option<DateTime<Utc>>,
+}
+
+/// Customers who entered or left a segment, used for campaign targeting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SegmentMembershipDelta {
+    pub segment_id: Uuid,
+    pub since: DateTime<Utc>,
+    pub entered_customer_ids: Vec<Uuid>,
+    pub exited_customer_ids: Vec<Uuid>,
+}
+
+/// Result of a nightly segment evaluation run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SegmentEvaluationReport {
+    pub evaluated_at: DateTime<Utc>,
+    pub segments_evaluated: u32,
+    pub total_entered: u32,
+    pub total_exited: u32,
+    pub errors: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(criteria: Vec<SegmentCriterion>) -> Segment {
+        Segment {
+            id: Uuid::new_v4(),
+            code: HeaplessString::try_from("ACTIVE_SAVERS").unwrap(),
+            name: HeaplessString::try_from("Active savers").unwrap(),
+            description: None,
+            criteria,
+            is_active: true,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn test_segment_validation() {
+        let valid = segment(vec![
+            SegmentCriterion::TotalBalance { operator: SegmentComparison::GreaterThan, amount: Decimal::from(1000) },
+            SegmentCriterion::HoldsAccountType { account_type: AccountType::Loan, held: false },
+        ]);
+        assert!(valid.validate().is_ok());
+
+        assert!(segment(vec![]).validate().is_err());
+        assert!(segment(vec![SegmentCriterion::RiskRatingIn { ratings: vec![] }]).validate().is_err());
+        assert!(segment(vec![SegmentCriterion::DaysSinceLastActivity { operator: SegmentComparison::GreaterThan, days: -1 }]).validate().is_err());
+        assert!(segment(vec![SegmentCriterion::HasTag { tag_code: HeaplessString::new(), tagged: true }]).validate().is_err());
+    }
+
+    #[test]
+    fn test_criteria_round_trip_as_tagged_json() {
+        let criteria = vec![
+            SegmentCriterion::HasAccountInStatus { statuses: vec![AccountStatus::Active] },
+            SegmentCriterion::DaysSinceLastActivity { operator: SegmentComparison::GreaterThanOrEqual, days: 300 },
+        ];
+        let json = serde_json::to_string(&criteria).unwrap();
+        assert!(json.contains("\"type\":\"DaysSinceLastActivity\""));
+
+        let parsed: Vec<SegmentCriterion> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, criteria);
+    }
+}
@@ -0,0 +1,205 @@
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Channel of the postings made when a standing order runs. Unlike system
+/// postings they go through the funds check.
+pub const STANDING_ORDER_CHANNEL_ID: &str = "STANDING_ORDER";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StandingOrderFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Quarterly,
+    Yearly,
+}
+
+impl StandingOrderFrequency {
+    /// Scheduled date following `due_date`. Month-based periods count from
+    /// `start_date`, so an order started on the 31st comes back to the 31st
+    /// after a shorter month instead of drifting to the 28th.
+    pub fn next_due_date(self, start_date: NaiveDate, due_date: NaiveDate) -> Option<NaiveDate> {
+        let months_per_period = match self {
+            StandingOrderFrequency::Daily => return due_date.checked_add_signed(Duration::days(1)),
+            StandingOrderFrequency::Weekly => return due_date.checked_add_signed(Duration::days(7)),
+            StandingOrderFrequency::Monthly => 1,
+            StandingOrderFrequency::Quarterly => 3,
+            StandingOrderFrequency::Yearly => 12,
+        };
+        let elapsed_months = (due_date.year() - start_date.year()) * 12 + due_date.month() as i32
+            - start_date.month() as i32;
+        let periods = elapsed_months / months_per_period + 1;
+        start_date.checked_add_months(Months::new(u32::try_from(periods * months_per_period).ok()?))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StandingOrderAmount {
+    Fixed(Decimal),
+    /// Moves whatever the source account holds above the threshold
+    SweepAboveThreshold(Decimal),
+}
+
+impl StandingOrderAmount {
+    /// Amount to transfer out of an account with `available_balance`. A sweep
+    /// with nothing above its threshold transfers nothing.
+    pub fn transfer_amount(&self, available_balance: Decimal) -> Option<Decimal> {
+        match *self {
+            StandingOrderAmount::Fixed(amount) => Some(amount),
+            StandingOrderAmount::SweepAboveThreshold(threshold) => {
+                Some(available_balance - threshold).filter(|excess| *excess > Decimal::ZERO)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StandingOrderStatus {
+    Active,
+    Suspended,
+    Completed,
+    Cancelled,
+}
+
+/// What happens to an occurrence the source account cannot fund
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StandingOrderFailurePolicy {
+    /// Drop the occurrence and wait for the next one
+    Skip,
+    /// Try again each business day until the next occurrence is due
+    RetryNextDay,
+    /// Drop the occurrence; suspend the order after this many failures in a row
+    SuspendAfter(i32),
+}
+
+/// Recurring transfer out of a source account, run by end-of-day processing
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StandingOrder {
+    pub id: Uuid,
+    pub source_account_id: Uuid,
+    /// Internal account credited; None when the beneficiary is external
+    pub beneficiary_account_id: Option<Uuid>,
+    /// Beneficiary reference carried on the debit, required for external beneficiaries
+    pub external_reference: Option<HeaplessString<100>>,
+    pub amount: StandingOrderAmount,
+    pub frequency: StandingOrderFrequency,
+    pub start_date: NaiveDate,
+    /// Last date an occurrence may be scheduled on
+    pub end_date: Option<NaiveDate>,
+    /// Scheduled date of the pending occurrence, before business-day adjustment
+    pub next_due_date: NaiveDate,
+    /// Business day the pending occurrence runs on
+    pub next_run_date: NaiveDate,
+    pub status: StandingOrderStatus,
+    pub failure_policy: StandingOrderFailurePolicy,
+    /// Occurrences in a row the source account could not fund
+    pub consecutive_failures: i32,
+    pub last_executed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    pub updated_by_person_id: Uuid,
+}
+
+impl StandingOrder {
+    pub fn is_due(&self, run_date: NaiveDate) -> bool {
+        self.status == StandingOrderStatus::Active && self.next_run_date <= run_date
+    }
+
+    pub fn record_execution(&mut self, executed_at: DateTime<Utc>) {
+        self.consecutive_failures = 0;
+        self.last_executed_at = Some(executed_at);
+    }
+
+    /// Counts an unfunded occurrence and suspends the order once its policy's
+    /// limit is reached. Returns whether the occurrence is tried again.
+    pub fn record_insufficient_funds(&mut self) -> bool {
+        self.consecutive_failures += 1;
+        match self.failure_policy {
+            StandingOrderFailurePolicy::Skip => false,
+            StandingOrderFailurePolicy::RetryNextDay => true,
+            StandingOrderFailurePolicy::SuspendAfter(max_failures) => {
+                if self.consecutive_failures >= max_failures {
+                    self.status = StandingOrderStatus::Suspended;
+                }
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn order(amount: StandingOrderAmount, failure_policy: StandingOrderFailurePolicy) -> StandingOrder {
+        StandingOrder {
+            id: Uuid::new_v4(),
+            source_account_id: Uuid::new_v4(),
+            beneficiary_account_id: Some(Uuid::new_v4()),
+            external_reference: None,
+            amount,
+            frequency: StandingOrderFrequency::Monthly,
+            start_date: date(2024, 1, 31),
+            end_date: None,
+            next_due_date: date(2024, 1, 31),
+            next_run_date: date(2024, 1, 31),
+            status: StandingOrderStatus::Active,
+            failure_policy,
+            consecutive_failures: 0,
+            last_executed_at: None,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn test_sweep_fires_only_above_threshold() {
+        let sweep = StandingOrderAmount::SweepAboveThreshold(Decimal::from(1000));
+        assert_eq!(sweep.transfer_amount(Decimal::from(800)), None);
+        assert_eq!(sweep.transfer_amount(Decimal::from(1000)), None);
+        assert_eq!(sweep.transfer_amount(Decimal::new(125050, 2)), Some(Decimal::new(25050, 2)));
+
+        let fixed = StandingOrderAmount::Fixed(Decimal::from(200));
+        assert_eq!(fixed.transfer_amount(Decimal::from(50)), Some(Decimal::from(200)));
+    }
+
+    #[test]
+    fn test_month_based_schedule_stays_anchored_to_start_date() {
+        let start = date(2024, 1, 31);
+        let monthly = StandingOrderFrequency::Monthly;
+        assert_eq!(monthly.next_due_date(start, start), Some(date(2024, 2, 29)));
+        assert_eq!(monthly.next_due_date(start, date(2024, 2, 29)), Some(date(2024, 3, 31)));
+        assert_eq!(monthly.next_due_date(start, date(2024, 3, 31)), Some(date(2024, 4, 30)));
+
+        let quarterly = StandingOrderFrequency::Quarterly;
+        assert_eq!(quarterly.next_due_date(start, date(2024, 4, 30)), Some(date(2024, 7, 31)));
+        assert_eq!(StandingOrderFrequency::Weekly.next_due_date(start, start), Some(date(2024, 2, 7)));
+    }
+
+    #[test]
+    fn test_insufficient_funds_follows_failure_policy() {
+        let fixed = StandingOrderAmount::Fixed(Decimal::from(200));
+
+        let mut retried = order(fixed, StandingOrderFailurePolicy::RetryNextDay);
+        assert!(retried.record_insufficient_funds());
+        assert_eq!(retried.status, StandingOrderStatus::Active);
+
+        let mut suspended = order(fixed, StandingOrderFailurePolicy::SuspendAfter(2));
+        assert!(!suspended.record_insufficient_funds());
+        assert_eq!(suspended.status, StandingOrderStatus::Active);
+        suspended.record_execution(Utc::now());
+        assert!(!suspended.record_insufficient_funds());
+        assert!(!suspended.record_insufficient_funds());
+        assert_eq!(suspended.consecutive_failures, 2);
+        assert_eq!(suspended.status, StandingOrderStatus::Suspended);
+        assert!(!suspended.is_due(date(2024, 2, 29)));
+    }
+}
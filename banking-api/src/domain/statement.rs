@@ -0,0 +1,713 @@
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use uuid::Uuid;
+
+use crate::domain::{hash_content, ContentHash, DocumentNotificationStatus};
+use crate::error::BankingError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StatementDeliveryMethod {
+    Paper,
+    Electronic,
+    Both,
+}
+
+impl StatementDeliveryMethod {
+    pub fn is_electronic(&self) -> bool {
+        matches!(self, StatementDeliveryMethod::Electronic | StatementDeliveryMethod::Both)
+    }
+
+    pub fn is_printed(&self) -> bool {
+        matches!(self, StatementDeliveryMethod::Paper | StatementDeliveryMethod::Both)
+    }
+}
+
+/// How cycle statements of an account are delivered. Accounts without a
+/// recorded preference receive paper statements.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementDeliveryPreference {
+    pub account_id: Uuid,
+    pub delivery_method: StatementDeliveryMethod,
+    /// References Messaging.id of the verified contact e-statements are sent to
+    pub contact_messaging_id: Option<Uuid>,
+    pub consent_given_at: DateTime<Utc>,
+    /// Channel the customer gave consent through, e.g. "BRANCH" or "MOBILE_APP"
+    pub consent_channel: HeaplessString<50>,
+    pub last_updated_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub updated_by_person_id: Uuid,
+}
+
+/// A customer's contact channel and whether it has been verified for
+/// electronic correspondence.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerContact {
+    pub id: Uuid,
+    pub customer_id: Uuid,
+    /// References Messaging.id
+    pub messaging_id: Uuid,
+    pub verified_at: Option<DateTime<Utc>>,
+    /// References Person.person_id
+    pub verified_by_person_id: Option<Uuid>,
+}
+
+impl CustomerContact {
+    pub fn is_verified(&self) -> bool {
+        self.verified_at.is_some()
+    }
+}
+
+/// Customer request to change the statement delivery of an account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementPreferenceChange {
+    pub account_id: Uuid,
+    pub customer_id: Uuid,
+    pub delivery_method: StatementDeliveryMethod,
+    /// References Messaging.id; required for Electronic and Both
+    pub contact_messaging_id: Option<Uuid>,
+    pub consent_channel: HeaplessString<50>,
+    /// Reference of the captured consent, e.g. a signed form or online session id
+    pub consent_reference: HeaplessString<100>,
+    /// References Person.person_id
+    pub requested_by_person_id: Uuid,
+}
+
+/// Immutable evidence of a statement delivery consent, kept for regulators
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementConsentRecord {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub customer_id: Uuid,
+    pub previous_method: StatementDeliveryMethod,
+    pub new_method: StatementDeliveryMethod,
+    /// References Messaging.id
+    pub contact_messaging_id: Option<Uuid>,
+    /// When the contact was verified, as seen at the time of consent
+    pub contact_verified_at: Option<DateTime<Utc>>,
+    pub consent_channel: HeaplessString<50>,
+    pub consent_reference: HeaplessString<100>,
+    pub consent_given_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub recorded_by_person_id: Uuid,
+}
+
+impl StatementDeliveryPreference {
+    /// Apply a preference change and capture its consent evidence. Electronic
+    /// delivery requires a verified contact of the requesting customer.
+    pub fn apply_change(
+        current: Option<&StatementDeliveryPreference>,
+        change: StatementPreferenceChange,
+        contact: Option<&CustomerContact>,
+        now: DateTime<Utc>,
+    ) -> Result<(StatementDeliveryPreference, StatementConsentRecord), BankingError> {
+        let contact = if change.delivery_method.is_electronic() {
+            let contact = contact
+                .filter(|c| Some(c.messaging_id) == change.contact_messaging_id && c.customer_id == change.customer_id)
+                .ok_or_else(|| BankingError::ValidationError {
+                    field: "contact_messaging_id".to_string(),
+                    message: "Electronic statements require a contact channel of the customer".to_string(),
+                })?;
+            if !contact.is_verified() {
+                return Err(BankingError::ValidationError {
+                    field: "contact_messaging_id".to_string(),
+                    message: "Electronic statements require a verified contact channel".to_string(),
+                });
+            }
+            Some(contact)
+        } else {
+            None
+        };
+
+        let preference = StatementDeliveryPreference {
+            account_id: change.account_id,
+            delivery_method: change.delivery_method,
+            contact_messaging_id: contact.map(|c| c.messaging_id),
+            consent_given_at: now,
+            consent_channel: change.consent_channel.clone(),
+            last_updated_at: now,
+            updated_by_person_id: change.requested_by_person_id,
+        };
+        let consent = StatementConsentRecord {
+            id: Uuid::new_v4(),
+            account_id: change.account_id,
+            customer_id: change.customer_id,
+            previous_method: current.map(|p| p.delivery_method).unwrap_or(StatementDeliveryMethod::Paper),
+            new_method: change.delivery_method,
+            contact_messaging_id: contact.map(|c| c.messaging_id),
+            contact_verified_at: contact.and_then(|c| c.verified_at),
+            consent_channel: change.consent_channel,
+            consent_reference: change.consent_reference,
+            consent_given_at: now,
+            recorded_by_person_id: change.requested_by_person_id,
+        };
+        Ok((preference, consent))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StatementNotificationType {
+    PreferenceChanged,
+    StatementReady,
+}
+
+/// Outbound message about statement delivery
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementNotification {
+    pub id: Uuid,
+    pub notification_type: StatementNotificationType,
+    pub account_id: Uuid,
+    pub customer_id: Uuid,
+    /// References Messaging.id; `None` leaves the choice of channel to the sender
+    pub contact_messaging_id: Option<Uuid>,
+    /// Set for StatementReady
+    pub statement_reference: Option<HeaplessString<50>>,
+    pub status: DocumentNotificationStatus,
+    pub queued_at: DateTime<Utc>,
+    pub sent_at: Option<DateTime<Utc>>,
+}
+
+impl StatementNotification {
+    pub fn queued(
+        notification_type: StatementNotificationType,
+        account_id: Uuid,
+        customer_id: Uuid,
+        contact_messaging_id: Option<Uuid>,
+        statement_reference: Option<HeaplessString<50>>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            notification_type,
+            account_id,
+            customer_id,
+            contact_messaging_id,
+            statement_reference,
+            status: DocumentNotificationStatus::Queued,
+            queued_at: now,
+            sent_at: None,
+        }
+    }
+}
+
+/// Statements are cut on the last day of each calendar month
+pub fn is_statement_cycle_end(date: NaiveDate) -> bool {
+    date.succ_opt().is_some_and(|next| next.day() == 1)
+}
+
+/// Reference of an account's statement for a cycle, e.g. "STM-20240630-1A2B3C4D5E6F"
+pub fn statement_reference(account_id: Uuid, cycle_date: NaiveDate) -> HeaplessString<50> {
+    let simple = account_id.simple().to_string().to_uppercase();
+    let mut reference = HeaplessString::new();
+    let _ = reference.push_str(&format!("STM-{}-", cycle_date.format("%Y%m%d")));
+    let _ = reference.push_str(&simple[..12]);
+    reference
+}
+
+/// Account due a cycle statement, with its delivery routing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementRecipient {
+    pub account_id: Uuid,
+    /// Primary account holder
+    pub customer_id: Uuid,
+    pub delivery_method: StatementDeliveryMethod,
+    /// References Messaging.id
+    pub contact_messaging_id: Option<Uuid>,
+}
+
+/// Print and dispatch extract of one statement cycle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementPrintBatch {
+    pub id: Uuid,
+    pub cycle_date: NaiveDate,
+    pub entry_count: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementPrintEntry {
+    pub id: Uuid,
+    /// References StatementPrintBatch.id
+    pub batch_id: Uuid,
+    pub account_id: Uuid,
+    pub customer_id: Uuid,
+    pub statement_reference: HeaplessString<50>,
+    pub delivery_method: StatementDeliveryMethod,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementPrintExtract {
+    pub batch: StatementPrintBatch,
+    pub entries: Vec<StatementPrintEntry>,
+}
+
+/// Routing of one cycle's statements to the print extract and e-statement
+/// notifications
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementDispatchPlan {
+    pub extract: StatementPrintExtract,
+    pub notifications: Vec<StatementNotification>,
+}
+
+impl StatementDispatchPlan {
+    /// Electronic recipients without a contact fall back to paper so that
+    /// no statement goes undelivered.
+    pub fn build(cycle_date: NaiveDate, recipients: &[StatementRecipient], now: DateTime<Utc>) -> Self {
+        let batch_id = Uuid::new_v4();
+        let mut entries = Vec::new();
+        let mut notifications = Vec::new();
+
+        for recipient in recipients {
+            let reference = statement_reference(recipient.account_id, cycle_date);
+            let electronic = recipient.delivery_method.is_electronic() && recipient.contact_messaging_id.is_some();
+
+            if electronic {
+                notifications.push(StatementNotification::queued(
+                    StatementNotificationType::StatementReady,
+                    recipient.account_id,
+                    recipient.customer_id,
+                    recipient.contact_messaging_id,
+                    Some(reference.clone()),
+                    now,
+                ));
+            }
+            if recipient.delivery_method.is_printed() || !electronic {
+                entries.push(StatementPrintEntry {
+                    id: Uuid::new_v4(),
+                    batch_id,
+                    account_id: recipient.account_id,
+                    customer_id: recipient.customer_id,
+                    statement_reference: reference,
+                    delivery_method: recipient.delivery_method,
+                });
+            }
+        }
+
+        Self {
+            extract: StatementPrintExtract {
+                batch: StatementPrintBatch {
+                    id: batch_id,
+                    cycle_date,
+                    entry_count: entries.len() as i64,
+                    created_at: now,
+                },
+                entries,
+            },
+            notifications,
+        }
+    }
+}
+
+/// Outcome of the cycle-statement EOD step
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementCycleReport {
+    pub cycle_date: NaiveDate,
+    pub accounts_processed: i64,
+    pub notifications_queued: i64,
+    pub print_entries: i64,
+    pub print_batch_id: Option<Uuid>,
+    /// The cycle had already been dispatched by an earlier run
+    pub already_dispatched: bool,
+}
+
+/// Account still receiving paper statements, for migration campaigns
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaperStatementAccount {
+    pub account_id: Uuid,
+    pub customer_id: Uuid,
+    pub delivery_method: StatementDeliveryMethod,
+    /// The customer already has a verified contact and can switch without a new verification
+    pub has_verified_contact: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaperStatementReport {
+    pub generated_at: DateTime<Utc>,
+    pub accounts: Vec<PaperStatementAccount>,
+    pub paper_only_count: i64,
+    pub paper_and_electronic_count: i64,
+}
+
+/// First day of the period a cycle statement covers
+pub fn statement_period_start(cycle_date: NaiveDate) -> NaiveDate {
+    cycle_date.with_day(1).unwrap_or(cycle_date)
+}
+
+/// Posted transaction of the statement period; credits are positive, debits negative
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementEntry {
+    pub transaction_id: Uuid,
+    pub value_date: NaiveDate,
+    pub description: HeaplessString<200>,
+    pub amount: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatementLine {
+    pub transaction_id: Uuid,
+    pub value_date: NaiveDate,
+    pub description: HeaplessString<200>,
+    /// Credits are positive, debits negative
+    pub amount: Decimal,
+    pub running_balance: Decimal,
+}
+
+impl StatementLine {
+    /// Debit column, zero for credits
+    pub fn debit(&self) -> Decimal {
+        if self.amount.is_sign_negative() { -self.amount } else { Decimal::ZERO }
+    }
+
+    /// Credit column, zero for debits
+    pub fn credit(&self) -> Decimal {
+        if self.amount.is_sign_negative() { Decimal::ZERO } else { self.amount }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatementTotals {
+    pub opening_balance: Decimal,
+    pub total_credits: Decimal,
+    /// Sum of the debit amounts, as a positive number
+    pub total_debits: Decimal,
+    pub closing_balance: Decimal,
+}
+
+/// Interest accrued and fees charged on the account over the statement period
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatementChargeSummary {
+    pub interest_accrued: Decimal,
+    /// Applied fees, net of waivers and reversals
+    pub fees_charged: Decimal,
+    pub fee_count: i64,
+}
+
+/// Persisted statement snapshot. Reprints serve the stored lines and totals,
+/// never a recomputation, and are checked against the content hash taken at
+/// generation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountStatement {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    /// Position in the account's sequence of stored statements, from 1
+    pub statement_number: i32,
+    pub statement_reference: HeaplessString<50>,
+    pub period_start: NaiveDate,
+    /// Cycle date of a cycle statement
+    pub period_end: NaiveDate,
+    pub lines: Vec<StatementLine>,
+    pub totals: StatementTotals,
+    /// Informational; not part of the hashed content
+    pub charges: StatementChargeSummary,
+    /// Blake3 hash of the canonical content
+    pub content_hash: ContentHash,
+    /// Earlier statement of the same account and period this one replaces
+    pub supersedes_statement_id: Option<Uuid>,
+    pub generated_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub generated_by_person_id: Uuid,
+}
+
+impl AccountStatement {
+    /// Statement of the period with running balances from the opening
+    /// balance. It is numbered 1 and supersedes nothing until the caller sets
+    /// those fields, which the content hash does not cover.
+    pub fn build(
+        account_id: Uuid,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        opening_balance: Decimal,
+        entries: Vec<StatementEntry>,
+        generated_by_person_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let mut balance = opening_balance;
+        let mut total_credits = Decimal::ZERO;
+        let mut total_debits = Decimal::ZERO;
+        let lines: Vec<StatementLine> = entries
+            .into_iter()
+            .map(|entry| {
+                balance += entry.amount;
+                if entry.amount.is_sign_negative() {
+                    total_debits -= entry.amount;
+                } else {
+                    total_credits += entry.amount;
+                }
+                StatementLine {
+                    transaction_id: entry.transaction_id,
+                    value_date: entry.value_date,
+                    description: entry.description,
+                    amount: entry.amount,
+                    running_balance: balance,
+                }
+            })
+            .collect();
+
+        let mut statement = Self {
+            id: Uuid::new_v4(),
+            account_id,
+            statement_number: 1,
+            statement_reference: statement_reference(account_id, period_end),
+            period_start,
+            period_end,
+            lines,
+            totals: StatementTotals {
+                opening_balance,
+                total_credits,
+                total_debits,
+                closing_balance: balance,
+            },
+            charges: StatementChargeSummary::default(),
+            content_hash: hash_content(&[]),
+            supersedes_statement_id: None,
+            generated_at: now,
+            generated_by_person_id,
+        };
+        statement.content_hash = statement.compute_hash();
+        statement
+    }
+
+    /// One record per line with length-prefixed descriptions and normalized
+    /// amounts, so the hash does not depend on storage scale or field content.
+    pub fn canonical_content(&self) -> String {
+        let mut content = String::new();
+        let _ = writeln!(
+            content,
+            "statement|{}|{}|{}|{}",
+            self.account_id, self.statement_reference, self.period_start, self.period_end
+        );
+        for (number, line) in self.lines.iter().enumerate() {
+            let _ = writeln!(
+                content,
+                "line|{}|{}|{}|{}:{}|{}|{}",
+                number + 1,
+                line.transaction_id,
+                line.value_date,
+                line.description.len(),
+                line.description,
+                line.amount.normalize(),
+                line.running_balance.normalize()
+            );
+        }
+        let _ = writeln!(
+            content,
+            "totals|{}|{}|{}|{}|{}",
+            self.lines.len(),
+            self.totals.opening_balance.normalize(),
+            self.totals.total_credits.normalize(),
+            self.totals.total_debits.normalize(),
+            self.totals.closing_balance.normalize()
+        );
+        content
+    }
+
+    pub fn compute_hash(&self) -> ContentHash {
+        hash_content(self.canonical_content().as_bytes())
+    }
+
+    /// False when the stored lines or totals no longer match the hash
+    pub fn is_intact(&self) -> bool {
+        self.compute_hash() == self.content_hash
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StatementIntegrityCheck {
+    Reprint,
+    ArchiveVerification,
+}
+
+/// Raised for operations when a stored statement no longer matches its hash
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementIntegrityAlert {
+    pub id: Uuid,
+    pub statement_id: Uuid,
+    pub account_id: Uuid,
+    pub stored_hash: ContentHash,
+    pub computed_hash: ContentHash,
+    pub detected_during: StatementIntegrityCheck,
+    pub detected_at: DateTime<Utc>,
+}
+
+/// Outcome of checking every archived statement of a cycle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementArchiveVerification {
+    pub period_end: NaiveDate,
+    /// Superseded statements are checked too
+    pub statements_checked: i64,
+    pub intact_count: i64,
+    pub tampered_statement_ids: Vec<Uuid>,
+    pub verified_at: DateTime<Utc>,
+}
+
+impl StatementArchiveVerification {
+    pub fn is_clean(&self) -> bool {
+        self.tampered_statement_ids.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(customer_id: Uuid, method: StatementDeliveryMethod, contact_messaging_id: Option<Uuid>) -> StatementPreferenceChange {
+        StatementPreferenceChange {
+            account_id: Uuid::new_v4(),
+            customer_id,
+            delivery_method: method,
+            contact_messaging_id,
+            consent_channel: HeaplessString::try_from("MOBILE_APP").unwrap(),
+            consent_reference: HeaplessString::try_from("SESSION-7F3A").unwrap(),
+            requested_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    fn contact(customer_id: Uuid, verified_at: Option<DateTime<Utc>>) -> CustomerContact {
+        CustomerContact {
+            id: Uuid::new_v4(),
+            customer_id,
+            messaging_id: Uuid::new_v4(),
+            verified_at,
+            verified_by_person_id: None,
+        }
+    }
+
+    #[test]
+    fn test_consent_evidence_is_captured() {
+        let customer_id = Uuid::new_v4();
+        let verified_at = Utc::now() - chrono::Duration::days(3);
+        let email = contact(customer_id, Some(verified_at));
+        let request = change(customer_id, StatementDeliveryMethod::Electronic, Some(email.messaging_id));
+        let now = Utc::now();
+
+        let (preference, consent) = StatementDeliveryPreference::apply_change(None, request.clone(), Some(&email), now).unwrap();
+
+        assert_eq!(preference.delivery_method, StatementDeliveryMethod::Electronic);
+        assert_eq!(preference.contact_messaging_id, Some(email.messaging_id));
+        assert_eq!(preference.consent_given_at, now);
+
+        assert_eq!(consent.previous_method, StatementDeliveryMethod::Paper);
+        assert_eq!(consent.new_method, StatementDeliveryMethod::Electronic);
+        assert_eq!(consent.customer_id, customer_id);
+        assert_eq!(consent.contact_messaging_id, Some(email.messaging_id));
+        assert_eq!(consent.contact_verified_at, Some(verified_at));
+        assert_eq!(consent.consent_channel.as_str(), "MOBILE_APP");
+        assert_eq!(consent.consent_reference.as_str(), "SESSION-7F3A");
+        assert_eq!(consent.recorded_by_person_id, request.requested_by_person_id);
+
+        // Reverting to paper records the previous method and drops the contact
+        let back = change(customer_id, StatementDeliveryMethod::Paper, None);
+        let (preference, consent) = StatementDeliveryPreference::apply_change(Some(&preference), back, None, now).unwrap();
+        assert_eq!(consent.previous_method, StatementDeliveryMethod::Electronic);
+        assert!(consent.contact_messaging_id.is_none());
+        assert!(preference.contact_messaging_id.is_none());
+    }
+
+    #[test]
+    fn test_electronic_requires_verified_contact_of_customer() {
+        let customer_id = Uuid::new_v4();
+        let now = Utc::now();
+
+        let unverified = contact(customer_id, None);
+        let request = change(customer_id, StatementDeliveryMethod::Both, Some(unverified.messaging_id));
+        assert!(StatementDeliveryPreference::apply_change(None, request, Some(&unverified), now).is_err());
+
+        let request = change(customer_id, StatementDeliveryMethod::Electronic, None);
+        assert!(StatementDeliveryPreference::apply_change(None, request, None, now).is_err());
+
+        // A verified contact of someone else does not qualify
+        let other = contact(Uuid::new_v4(), Some(now));
+        let request = change(customer_id, StatementDeliveryMethod::Electronic, Some(other.messaging_id));
+        assert!(StatementDeliveryPreference::apply_change(None, request, Some(&other), now).is_err());
+    }
+
+    #[test]
+    fn test_print_extract_contents() {
+        let cycle_date = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+        let recipient = |delivery_method, contact_messaging_id| StatementRecipient {
+            account_id: Uuid::new_v4(),
+            customer_id: Uuid::new_v4(),
+            delivery_method,
+            contact_messaging_id,
+        };
+        let paper = recipient(StatementDeliveryMethod::Paper, None);
+        let electronic = recipient(StatementDeliveryMethod::Electronic, Some(Uuid::new_v4()));
+        let both = recipient(StatementDeliveryMethod::Both, Some(Uuid::new_v4()));
+        let electronic_without_contact = recipient(StatementDeliveryMethod::Electronic, None);
+        let recipients = vec![paper.clone(), electronic.clone(), both.clone(), electronic_without_contact.clone()];
+
+        let plan = StatementDispatchPlan::build(cycle_date, &recipients, Utc::now());
+
+        let printed: Vec<Uuid> = plan.extract.entries.iter().map(|e| e.account_id).collect();
+        assert_eq!(printed, vec![paper.account_id, both.account_id, electronic_without_contact.account_id]);
+        assert_eq!(plan.extract.batch.entry_count, 3);
+        assert_eq!(plan.extract.batch.cycle_date, cycle_date);
+        assert!(plan.extract.entries.iter().all(|e| e.batch_id == plan.extract.batch.id));
+        assert_eq!(
+            plan.extract.entries[0].statement_reference,
+            statement_reference(paper.account_id, cycle_date)
+        );
+
+        let notified: Vec<Uuid> = plan.notifications.iter().map(|n| n.account_id).collect();
+        assert_eq!(notified, vec![electronic.account_id, both.account_id]);
+        let ready = &plan.notifications[0];
+        assert_eq!(ready.notification_type, StatementNotificationType::StatementReady);
+        assert_eq!(ready.contact_messaging_id, electronic.contact_messaging_id);
+        assert_eq!(ready.statement_reference, Some(statement_reference(electronic.account_id, cycle_date)));
+    }
+
+    #[test]
+    fn test_cycle_end_and_reference() {
+        assert!(is_statement_cycle_end(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()));
+        assert!(is_statement_cycle_end(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()));
+        assert!(!is_statement_cycle_end(NaiveDate::from_ymd_opt(2024, 6, 29).unwrap()));
+
+        let account_id = Uuid::parse_str("1a2b3c4d-5e6f-7081-92a3-b4c5d6e7f809").unwrap();
+        let reference = statement_reference(account_id, NaiveDate::from_ymd_opt(2024, 6, 30).unwrap());
+        assert_eq!(reference.as_str(), "STM-20240630-1A2B3C4D5E6F");
+    }
+
+    #[test]
+    fn test_statement_hash_covers_lines_but_not_storage_scale() {
+        let cycle_date = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+        let entry = |day, amount: Decimal| StatementEntry {
+            transaction_id: Uuid::new_v4(),
+            value_date: NaiveDate::from_ymd_opt(2024, 6, day).unwrap(),
+            description: HeaplessString::try_from("Transfer").unwrap(),
+            amount,
+        };
+        let entries = vec![entry(3, Decimal::new(25000, 2)), entry(12, Decimal::new(-4050, 2))];
+        let statement = AccountStatement::build(
+            Uuid::new_v4(),
+            statement_period_start(cycle_date),
+            cycle_date,
+            Decimal::from(100),
+            entries,
+            Uuid::new_v4(),
+            Utc::now(),
+        );
+
+        assert_eq!(statement.period_start, NaiveDate::from_ymd_opt(2024, 6, 1).unwrap());
+        assert_eq!(statement.lines[1].running_balance, Decimal::new(30950, 2));
+        assert_eq!((statement.lines[0].debit(), statement.lines[0].credit()), (Decimal::ZERO, Decimal::new(25000, 2)));
+        assert_eq!((statement.lines[1].debit(), statement.lines[1].credit()), (Decimal::new(4050, 2), Decimal::ZERO));
+        assert_eq!(statement.totals.total_debits, Decimal::new(4050, 2));
+        assert_eq!(statement.totals.closing_balance, Decimal::new(30950, 2));
+        assert!(statement.is_intact());
+
+        // Numeric columns may come back with a different scale
+        let mut reloaded = statement.clone();
+        reloaded.lines[0].amount = Decimal::new(250000, 3);
+        assert!(reloaded.is_intact());
+
+        let mut tampered = statement.clone();
+        tampered.lines[1].amount = Decimal::new(-405, 1) + Decimal::ONE;
+        assert!(!tampered.is_intact());
+
+        let mut tampered = statement;
+        tampered.lines[0].description = HeaplessString::try_from("Transfer|").unwrap();
+        assert!(!tampered.is_intact());
+    }
+}
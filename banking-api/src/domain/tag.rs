@@ -0,0 +1,227 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{AccountStatus, CustomerSearchCriteria};
+use crate::error::{BankingError, BankingResult};
+
+/// Kind of entity a tag can be attached to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TaggableEntityKind {
+    Customer,
+    Account,
+}
+
+/// Controlled label from the tag registry, e.g. "VIP" or "LegacyMigrationBatch7"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tag {
+    pub id: Uuid,
+    pub code: HeaplessString<50>,
+    pub description: HeaplessString<255>,
+    pub applicable_entity_kinds: Vec<TaggableEntityKind>,
+    /// Protected tags can only be assigned or removed by holders of this permission
+    pub required_permission: Option<HeaplessString<50>>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub updated_by_person_id: Uuid,
+}
+
+impl Tag {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.code.is_empty() {
+            return Err("Tag code is required".to_string());
+        }
+        if !self.code.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err("Tag code may only contain letters, digits and underscores".to_string());
+        }
+        if self.applicable_entity_kinds.is_empty() {
+            return Err("Tag must apply to at least one entity kind".to_string());
+        }
+        if self.required_permission.as_ref().is_some_and(|p| p.is_empty()) {
+            return Err("Required permission cannot be empty".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn is_protected(&self) -> bool {
+        self.required_permission.is_some()
+    }
+
+    /// Check that the tag can be put on (or taken off) an entity of the kind by the actor
+    pub fn authorize(&self, entity_kind: TaggableEntityKind, actor: &TagActor) -> BankingResult<()> {
+        if !self.is_active {
+            return Err(BankingError::ValidationError {
+                field: "tag_code".to_string(),
+                message: format!("Tag {} is inactive", self.code),
+            });
+        }
+        if !self.applicable_entity_kinds.contains(&entity_kind) {
+            return Err(BankingError::ValidationError {
+                field: "entity_kind".to_string(),
+                message: format!("Tag {} does not apply to {entity_kind:?}", self.code),
+            });
+        }
+        if let Some(permission) = &self.required_permission {
+            if !actor.has_permission(permission) {
+                return Err(BankingError::UnauthorizedOperation(format!(
+                    "Tag {} requires permission {permission}",
+                    self.code
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Person performing a tag operation with the permissions granted to them
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagActor {
+    /// References Person.person_id
+    pub person_id: Uuid,
+    pub permissions: Vec<HeaplessString<50>>,
+}
+
+impl TagActor {
+    pub fn has_permission(&self, permission: &str) -> bool {
+        self.permissions.iter().any(|p| p.as_str() == permission)
+    }
+}
+
+/// A tag attached to a customer or an account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityTag {
+    pub id: Uuid,
+    pub tag_id: Uuid,
+    pub entity_kind: TaggableEntityKind,
+    pub entity_id: Uuid,
+    pub assigned_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub assigned_by_person_id: Uuid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TagMatchMode {
+    /// At least one of the tags
+    Any,
+    /// Every tag
+    All,
+}
+
+/// Tag filter used by the search criteria
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TagFilter {
+    pub tag_codes: Vec<HeaplessString<50>>,
+    pub match_mode: TagMatchMode,
+}
+
+impl TagFilter {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.tag_codes.is_empty() {
+            return Err("Tag filter requires at least one tag code".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Accounts selected for bulk tagging; all set fields must match
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AccountTagSelection {
+    pub product_id: Option<Uuid>,
+    pub account_status: Option<AccountStatus>,
+    pub opened_from: Option<NaiveDate>,
+    pub opened_to: Option<NaiveDate>,
+    /// E.g. all accounts already tagged with a migration batch
+    pub tags: Option<TagFilter>,
+}
+
+/// Entities a tag is assigned to in bulk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum BulkTagTarget {
+    Accounts(AccountTagSelection),
+    Customers(CustomerSearchCriteria),
+}
+
+impl BulkTagTarget {
+    pub fn entity_kind(&self) -> TaggableEntityKind {
+        match self {
+            BulkTagTarget::Accounts(_) => TaggableEntityKind::Account,
+            BulkTagTarget::Customers(_) => TaggableEntityKind::Customer,
+        }
+    }
+}
+
+/// Result of a bulk tag assignment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkTagReport {
+    pub tag_code: HeaplessString<50>,
+    pub entity_kind: TaggableEntityKind,
+    pub matched: u64,
+    pub newly_tagged: u64,
+    /// Matched entities that already carried the tag
+    pub already_tagged: u64,
+    pub chunks_processed: u32,
+    pub errors: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(required_permission: Option<&str>) -> Tag {
+        Tag {
+            id: Uuid::new_v4(),
+            code: HeaplessString::try_from("StaffAccount").unwrap(),
+            description: HeaplessString::try_from("Account held by a member of staff").unwrap(),
+            applicable_entity_kinds: vec![TaggableEntityKind::Account],
+            required_permission: required_permission.map(|p| HeaplessString::try_from(p).unwrap()),
+            is_active: true,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    fn actor(permissions: &[&str]) -> TagActor {
+        TagActor {
+            person_id: Uuid::new_v4(),
+            permissions: permissions.iter().map(|p| HeaplessString::try_from(*p).unwrap()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_protected_tag_requires_permission() {
+        let staff = tag(Some("TAG_STAFF_ACCOUNT"));
+
+        assert!(matches!(
+            staff.authorize(TaggableEntityKind::Account, &actor(&[])),
+            Err(BankingError::UnauthorizedOperation(_))
+        ));
+        assert!(staff.authorize(TaggableEntityKind::Account, &actor(&["TAG_STAFF_ACCOUNT"])).is_ok());
+        assert!(tag(None).authorize(TaggableEntityKind::Account, &actor(&[])).is_ok());
+    }
+
+    #[test]
+    fn test_tag_must_apply_to_entity_kind_and_be_active() {
+        let mut account_tag = tag(None);
+        assert!(account_tag.authorize(TaggableEntityKind::Customer, &actor(&[])).is_err());
+
+        account_tag.is_active = false;
+        assert!(account_tag.authorize(TaggableEntityKind::Account, &actor(&[])).is_err());
+    }
+
+    #[test]
+    fn test_tag_validation() {
+        assert!(tag(None).validate().is_ok());
+
+        let mut invalid = tag(None);
+        invalid.code = HeaplessString::try_from("Staff Account").unwrap();
+        assert!(invalid.validate().is_err());
+
+        let mut no_kinds = tag(None);
+        no_kinds.applicable_entity_kinds.clear();
+        assert!(no_kinds.validate().is_err());
+    }
+}
@@ -1,10 +1,13 @@
 use blake3::Hash;
 use chrono::{DateTime, NaiveDate, Utc};
 use heapless::String as HeaplessString;
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::domain::{CurrencyCode, ExchangeRate, ExchangeRatePolicy};
+use crate::error::{BankingError, BankingResult};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Transaction {
     pub id: Uuid,
@@ -12,7 +15,7 @@ pub struct Transaction {
     pub transaction_code: HeaplessString<8>,
     pub transaction_type: TransactionType,
     pub amount: Decimal,
-    pub currency: HeaplessString<3>,
+    pub currency: CurrencyCode,
     pub description: HeaplessString<200>,
     pub channel_id: HeaplessString<50>,
     pub terminal_id: Option<Uuid>,
@@ -27,9 +30,177 @@ pub struct Transaction {
     pub requires_approval: bool,
     pub approval_status: Option<TransactionApprovalStatus>,
     pub risk_score: Option<Decimal>,
+    /// Non-critical steps skipped when the transaction was posted
+    pub degraded_flags: crate::domain::DegradedFlags,
+    /// Client-chosen key, unique per channel; a retry with the same key returns the original transaction
+    pub idempotency_key: Option<HeaplessString<64>>,
+    /// References Transaction.id of the transaction this one reverses
+    pub reversal_of_transaction_id: Option<Uuid>,
+    /// Set when the transaction was submitted in another currency than the
+    /// account's; `amount` and `currency` then hold the booked conversion
+    pub fx_conversion: Option<FxConversion>,
     pub created_at: DateTime<Utc>,
 }
 
+/// What a foreign-currency transaction was submitted as, and the rate it was booked at
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FxConversion {
+    pub original_amount: Decimal,
+    pub original_currency: CurrencyCode,
+    /// Units of the account currency per unit of `original_currency`
+    pub exchange_rate: Decimal,
+}
+
+/// Channel of postings the bank makes itself, such as interest capitalization
+pub const SYSTEM_CHANNEL_ID: &str = "SYSTEM";
+
+impl Transaction {
+    /// System postings are not customer instructions: customer-level debit
+    /// restrictions and the funds check do not stop them
+    pub fn is_system_posting(&self) -> bool {
+        self.channel_id.as_str() == SYSTEM_CHANNEL_ID
+    }
+
+    /// Postings are booked in the account's currency; a foreign-currency
+    /// transaction must go through `convert_to` first
+    pub fn ensure_currency(&self, account_currency: &CurrencyCode) -> BankingResult<()> {
+        if &self.currency != account_currency {
+            return Err(BankingError::CurrencyMismatch {
+                account_id: self.account_id,
+                account_currency: account_currency.to_string(),
+                transaction_currency: self.currency.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Book a transaction submitted in another currency in `account_currency`.
+    /// `rate` is the rate from the transaction currency into the account
+    /// currency effective at the transaction date; its age there is checked
+    /// against `policy`. The booked amount is rounded to the account currency's
+    /// minor unit. A transaction already in the account currency is left as it is.
+    pub fn convert_to(
+        &mut self,
+        account_currency: &CurrencyCode,
+        rate: Option<&ExchangeRate>,
+        policy: &ExchangeRatePolicy,
+    ) -> BankingResult<()> {
+        if &self.currency == account_currency {
+            return Ok(());
+        }
+        let rate = rate
+            .filter(|rate| {
+                rate.from_currency.as_str() == self.currency.as_str()
+                    && rate.to_currency.as_str() == account_currency.as_str()
+            })
+            .ok_or_else(|| BankingError::ExchangeRateNotFound {
+                base_currency: self.currency.to_string(),
+                quote_currency: account_currency.to_string(),
+                as_of: self.transaction_date,
+            })?;
+        rate.ensure_usable(policy, self.transaction_date)?;
+        let booked_amount = rate
+            .convert(self.amount)
+            .round_dp_with_strategy(account_currency.minor_units(), RoundingStrategy::MidpointNearestEven);
+        if booked_amount <= Decimal::ZERO {
+            return Err(BankingError::InvalidTransactionAmount(format!(
+                "{} {} is less than one {} minor unit",
+                self.amount, self.currency, account_currency
+            )));
+        }
+        self.fx_conversion = Some(FxConversion {
+            original_amount: self.amount,
+            original_currency: self.currency.clone(),
+            exchange_rate: rate.rate,
+        });
+        self.amount = booked_amount;
+        self.currency = account_currency.clone();
+        Ok(())
+    }
+
+    /// Amount and currency the client submitted, before any conversion
+    pub fn requested_amount(&self) -> (Decimal, &CurrencyCode) {
+        match &self.fx_conversion {
+            Some(conversion) => (conversion.original_amount, &conversion.original_currency),
+            None => (self.amount, &self.currency),
+        }
+    }
+
+    /// Hash of what the client asked for, compared when an idempotency key is replayed.
+    /// Fields the pipeline assigns (id, dates, status, reference) are left out.
+    pub fn payload_hash(&self) -> Hash {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(self.account_id.as_bytes());
+        hasher.update(self.transaction_code.as_bytes());
+        hasher.update(self.transaction_type.to_string().as_bytes());
+        let (amount, currency) = self.requested_amount();
+        hasher.update(amount.normalize().to_string().as_bytes());
+        hasher.update(currency.as_str().as_bytes());
+        hasher.update(self.description.as_bytes());
+        hasher.update(self.channel_id.as_bytes());
+        hasher.update(self.value_date.to_string().as_bytes());
+        if let Some(external_reference) = &self.external_reference {
+            hasher.update(external_reference.as_bytes());
+        }
+        hasher.finalize()
+    }
+
+    /// A retry under the same idempotency key must carry the same payload as `original`
+    pub fn ensure_replay_of(&self, original: &Transaction) -> BankingResult<()> {
+        if self.payload_hash() != original.payload_hash() {
+            return Err(BankingError::IdempotencyKeyConflict {
+                channel_id: self.channel_id.to_string(),
+                idempotency_key: self.idempotency_key.as_deref().unwrap_or_default().to_string(),
+                transaction_id: original.id,
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks that the transaction may be reversed on `business_date`: it must be
+    /// posted, not reversed yet and not itself a reversal. Postings of the business
+    /// date may always be reversed, older ones up to `window_days` back.
+    pub fn check_reversible(&self, business_date: NaiveDate, window_days: i64) -> Result<(), ReversalRefusal> {
+        if self.reversal_of_transaction_id.is_some() {
+            return Err(ReversalRefusal::IsReversal);
+        }
+        match self.status {
+            TransactionStatus::Posted => {}
+            TransactionStatus::Reversed => return Err(ReversalRefusal::AlreadyReversed),
+            _ => return Err(ReversalRefusal::NotPosted),
+        }
+        if (business_date - self.transaction_date.date_naive()).num_days() > window_days {
+            return Err(ReversalRefusal::OutsideWindow);
+        }
+        Ok(())
+    }
+}
+
+/// Why a transaction cannot be reversed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReversalRefusal {
+    /// Pending, failed and unapproved transactions moved no balance
+    NotPosted,
+    AlreadyReversed,
+    /// A contra transaction is corrected by a new posting, never reversed
+    IsReversal,
+    /// Posted before the business date and beyond the reversal window
+    OutsideWindow,
+}
+
+/// Transaction search; all set fields must match
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionSearchCriteria {
+    pub account_id: Option<Uuid>,
+    pub from_date: Option<NaiveDate>,
+    pub to_date: Option<NaiveDate>,
+    pub status: Option<TransactionStatus>,
+    /// Tags of the account the transaction was posted to
+    pub account_tags: Option<crate::domain::TagFilter>,
+    pub offset: i64,
+    pub limit: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum TransactionType { 
     Credit, 
@@ -471,9 +642,9 @@ impl TransactionValidationResult {
     pub fn merge(&mut self, other: &TransactionValidationResult) {
         self.is_valid = self.is_valid && other.is_valid;
 
-        if other.validation_error_01_field.is_some() {
+        if let Some(field) = &other.validation_error_01_field {
             self.add_check(
-                other.validation_error_01_field.as_ref().unwrap(),
+                field,
                 false,
                 other
                     .validation_error_01_message
@@ -486,9 +657,9 @@ impl TransactionValidationResult {
                     .map(|s| s.to_string()),
             );
         }
-        if other.validation_error_02_field.is_some() {
+        if let Some(field) = &other.validation_error_02_field {
             self.add_check(
-                other.validation_error_02_field.as_ref().unwrap(),
+                field,
                 false,
                 other
                     .validation_error_02_message
@@ -501,9 +672,9 @@ impl TransactionValidationResult {
                     .map(|s| s.to_string()),
             );
         }
-        if other.validation_error_03_field.is_some() {
+        if let Some(field) = &other.validation_error_03_field {
             self.add_check(
-                other.validation_error_03_field.as_ref().unwrap(),
+                field,
                 false,
                 other
                     .validation_error_03_message
@@ -726,6 +897,7 @@ impl Transaction {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
     use std::mem;
 
     #[test]
@@ -797,4 +969,189 @@ mod tests {
         assert_eq!(transaction_status, TransactionStatus::Pending);
         assert_eq!(approval_status, TransactionApprovalStatus::Approved);
     }
+
+    fn deposit(currency: &str) -> Transaction {
+        Transaction {
+            id: Uuid::new_v4(),
+            account_id: Uuid::new_v4(),
+            transaction_code: HeaplessString::try_from("DEP").unwrap(),
+            transaction_type: TransactionType::Credit,
+            amount: Decimal::from(100),
+            currency: CurrencyCode::try_from(currency).unwrap(),
+            description: HeaplessString::try_from("Cash deposit").unwrap(),
+            channel_id: HeaplessString::try_from("BRANCH").unwrap(),
+            terminal_id: None,
+            agent_person_id: None,
+            transaction_date: Utc::now(),
+            value_date: NaiveDate::from_ymd_opt(2024, 6, 14).unwrap(),
+            status: TransactionStatus::Pending,
+            reference_number: HeaplessString::try_from("REF-1").unwrap(),
+            external_reference: None,
+            gl_code: HeaplessString::try_from("2100").unwrap(),
+            requires_approval: false,
+            approval_status: None,
+            risk_score: None,
+            degraded_flags: crate::domain::DegradedFlags::NONE,
+            idempotency_key: None,
+            reversal_of_transaction_id: None,
+            fx_conversion: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn rate(from: &str, to: &str, rate: Decimal) -> ExchangeRate {
+        ExchangeRate {
+            from_currency: HeaplessString::try_from(from).unwrap(),
+            to_currency: HeaplessString::try_from(to).unwrap(),
+            rate,
+            effective_at: Utc::now() - chrono::Duration::hours(1),
+            source: HeaplessString::try_from("BEAC").unwrap(),
+        }
+    }
+
+    const RATE_POLICY: ExchangeRatePolicy = ExchangeRatePolicy { max_age: chrono::Duration::hours(24), reject_stale: true };
+
+    #[test]
+    fn test_usd_posting_on_xaf_account_is_booked_at_rate() {
+        let xaf = CurrencyCode::try_from("XAF").unwrap();
+        let mut transaction = deposit("USD");
+        transaction.amount = Decimal::new(15050, 2);
+
+        let usd_to_xaf = rate("USD", "XAF", Decimal::new(60525, 2));
+        transaction.convert_to(&xaf, Some(&usd_to_xaf), &RATE_POLICY).unwrap();
+
+        // 150.50 * 605.25 = 91090.125, rounded to whole francs
+        assert_eq!(transaction.amount, Decimal::from(91090));
+        assert_eq!(transaction.currency, xaf);
+        assert!(transaction.ensure_currency(&xaf).is_ok());
+        assert_eq!(
+            transaction.fx_conversion,
+            Some(FxConversion {
+                original_amount: Decimal::new(15050, 2),
+                original_currency: CurrencyCode::try_from("USD").unwrap(),
+                exchange_rate: Decimal::new(60525, 2),
+            })
+        );
+        let (amount, currency) = transaction.requested_amount();
+        assert_eq!((amount, currency.as_str()), (Decimal::new(15050, 2), "USD"));
+    }
+
+    #[test]
+    fn test_posting_without_rate_for_the_pair_fails() {
+        let xaf = CurrencyCode::try_from("XAF").unwrap();
+        let mut transaction = deposit("USD");
+        let eur_to_xaf = rate("EUR", "XAF", Decimal::new(655957, 3));
+
+        for available in [None, Some(&eur_to_xaf)] {
+            let err = transaction.convert_to(&xaf, available, &RATE_POLICY).unwrap_err();
+            assert!(matches!(
+                err,
+                BankingError::ExchangeRateNotFound { base_currency, quote_currency, as_of }
+                    if base_currency == "USD" && quote_currency == "XAF" && as_of == transaction.transaction_date
+            ));
+        }
+        assert_eq!(transaction.amount, Decimal::from(100));
+        assert!(transaction.fx_conversion.is_none());
+    }
+
+    #[test]
+    fn test_stale_rate_is_refused_when_policy_rejects_stale_rates() {
+        let xaf = CurrencyCode::try_from("XAF").unwrap();
+        let usd_to_xaf = rate("USD", "XAF", Decimal::new(60525, 2));
+        let mut transaction = deposit("USD");
+        transaction.transaction_date = usd_to_xaf.effective_at + chrono::Duration::hours(25);
+
+        assert!(matches!(
+            transaction.clone().convert_to(&xaf, Some(&usd_to_xaf), &RATE_POLICY),
+            Err(BankingError::StaleExchangeRate { .. })
+        ));
+
+        let warn_only = ExchangeRatePolicy { reject_stale: false, ..RATE_POLICY };
+        transaction.convert_to(&xaf, Some(&usd_to_xaf), &warn_only).unwrap();
+        assert_eq!(transaction.amount, Decimal::from(60525));
+    }
+
+    #[test]
+    fn test_retry_of_converted_transaction_is_a_replay() {
+        let xaf = CurrencyCode::try_from("XAF").unwrap();
+        let retry = deposit("USD");
+        let mut original = retry.clone();
+        let usd_to_xaf = rate("USD", "XAF", Decimal::new(60525, 2));
+        original.convert_to(&xaf, Some(&usd_to_xaf), &RATE_POLICY).unwrap();
+
+        assert!(retry.ensure_replay_of(&original).is_ok());
+    }
+
+    #[test]
+    fn test_posting_in_account_currency_is_accepted() {
+        let eur = CurrencyCode::try_from("EUR").unwrap();
+        assert!(deposit("EUR").ensure_currency(&eur).is_ok());
+    }
+
+    #[test]
+    fn test_posting_in_other_currency_is_rejected_with_both_codes() {
+        let transaction = deposit("XOF");
+        let eur = CurrencyCode::try_from("EUR").unwrap();
+
+        let err = transaction.ensure_currency(&eur).unwrap_err();
+
+        assert!(matches!(
+            &err,
+            BankingError::CurrencyMismatch { account_id, account_currency, transaction_currency }
+                if *account_id == transaction.account_id && account_currency == "EUR" && transaction_currency == "XOF"
+        ));
+        assert!(err.to_string().contains("XOF") && err.to_string().contains("EUR"));
+    }
+
+    #[test]
+    fn test_only_posted_originals_within_window_are_reversible() {
+        let mut posted = deposit("EUR");
+        posted.status = TransactionStatus::Posted;
+        posted.transaction_date = Utc.with_ymd_and_hms(2024, 6, 14, 16, 30, 0).unwrap();
+        let posting_date = NaiveDate::from_ymd_opt(2024, 6, 14).unwrap();
+
+        assert_eq!(posted.check_reversible(posting_date, 0), Ok(()));
+        assert_eq!(
+            posted.check_reversible(posting_date.succ_opt().unwrap(), 0),
+            Err(ReversalRefusal::OutsideWindow)
+        );
+        assert_eq!(posted.check_reversible(NaiveDate::from_ymd_opt(2024, 6, 17).unwrap(), 3), Ok(()));
+
+        let mut reversed = posted.clone();
+        reversed.status = TransactionStatus::Reversed;
+        assert_eq!(reversed.check_reversible(posting_date, 0), Err(ReversalRefusal::AlreadyReversed));
+
+        let mut contra = posted.clone();
+        contra.reversal_of_transaction_id = Some(posted.id);
+        assert_eq!(contra.check_reversible(posting_date, 0), Err(ReversalRefusal::IsReversal));
+
+        assert_eq!(deposit("EUR").check_reversible(posting_date, 0), Err(ReversalRefusal::NotPosted));
+    }
+
+    #[test]
+    fn test_retry_with_same_payload_is_a_replay() {
+        let original = deposit("EUR");
+        let mut retry = original.clone();
+        retry.id = Uuid::new_v4();
+        retry.transaction_date = Utc::now();
+        retry.reference_number = HeaplessString::try_from("REF-2").unwrap();
+        retry.amount = Decimal::new(10000, 2);
+
+        assert!(retry.ensure_replay_of(&original).is_ok());
+    }
+
+    #[test]
+    fn test_retry_with_different_amount_conflicts() {
+        let mut original = deposit("EUR");
+        original.idempotency_key = Some(HeaplessString::try_from("mobile-7f3a").unwrap());
+        let mut retry = original.clone();
+        retry.id = Uuid::new_v4();
+        retry.amount = Decimal::from(150);
+
+        assert!(matches!(
+            retry.ensure_replay_of(&original),
+            Err(BankingError::IdempotencyKeyConflict { channel_id, idempotency_key, transaction_id })
+                if channel_id == "BRANCH" && idempotency_key == "mobile-7f3a" && transaction_id == original.id
+        ));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,222 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{
+    AccountMandate, HoldPriority, HoldType, PlaceHoldRequest, Transaction, TransactionType,
+};
+
+/// Why a person's decision on a transaction awaiting approval was refused
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ApprovalRefusal {
+    /// Whoever initiated the transaction cannot also approve it
+    Initiator,
+    /// The account mandate names its approvers and this person is not one of them
+    NotMandated,
+    AlreadyApproved,
+    /// The approval workflow timed out
+    Expired,
+}
+
+/// Sign-offs a transaction awaiting approval needs before it posts. The
+/// strictest active mandate of the account sets them; without one, a single
+/// person other than the initiator suffices.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApprovalRequirement {
+    /// References Person.person_id
+    pub initiated_by: Uuid,
+    /// Persons who may decide; empty when anyone but the initiator may
+    pub approvers: Vec<Uuid>,
+    pub required_signers_count: u8,
+    pub timeout_at: DateTime<Utc>,
+    /// Persons who approved so far
+    pub approved_by: Vec<Uuid>,
+}
+
+impl ApprovalRequirement {
+    pub fn from_mandates(initiated_by: Uuid, mandates: &[AccountMandate], timeout_at: DateTime<Utc>) -> Self {
+        let strictest = mandates.iter().max_by_key(|mandate| mandate.required_signers_count);
+        Self {
+            initiated_by,
+            approvers: strictest.map(mandated_approvers).unwrap_or_default(),
+            required_signers_count: strictest.map_or(1, |mandate| mandate.required_signers_count.max(1)),
+            timeout_at,
+            approved_by: Vec::new(),
+        }
+    }
+
+    pub fn is_expired(&self, at: DateTime<Utc>) -> bool {
+        at >= self.timeout_at
+    }
+
+    pub fn is_satisfied(&self) -> bool {
+        self.approved_by.len() >= usize::from(self.required_signers_count)
+    }
+
+    /// Checks that `person_id` may approve or reject the transaction at `at`
+    pub fn check_decider(&self, person_id: Uuid, at: DateTime<Utc>) -> Result<(), ApprovalRefusal> {
+        if self.is_expired(at) {
+            return Err(ApprovalRefusal::Expired);
+        }
+        if person_id == self.initiated_by {
+            return Err(ApprovalRefusal::Initiator);
+        }
+        if !self.approvers.is_empty() && !self.approvers.contains(&person_id) {
+            return Err(ApprovalRefusal::NotMandated);
+        }
+        if self.approved_by.contains(&person_id) {
+            return Err(ApprovalRefusal::AlreadyApproved);
+        }
+        Ok(())
+    }
+
+    /// Records the approval of `approver` at `at`; returns whether the
+    /// transaction now has every sign-off it needs
+    pub fn approve(&mut self, approver: Uuid, at: DateTime<Utc>) -> Result<bool, ApprovalRefusal> {
+        self.check_decider(approver, at)?;
+        self.approved_by.push(approver);
+        Ok(self.is_satisfied())
+    }
+
+    /// Hold over the funds of a customer debit while it awaits approval. It
+    /// expires with the workflow, so a transaction nobody decides on leaves
+    /// the funds unencumbered.
+    pub fn hold_request(&self, transaction: &Transaction, reason_id: Uuid) -> Option<PlaceHoldRequest> {
+        if transaction.transaction_type != TransactionType::Debit || transaction.is_system_posting() {
+            return None;
+        }
+        Some(PlaceHoldRequest {
+            account_id: transaction.account_id,
+            hold_type: HoldType::PendingAuthorization,
+            amount: transaction.amount,
+            reason_id,
+            additional_details: None,
+            placed_by_person_id: self.initiated_by,
+            expires_at: Some(self.timeout_at),
+            priority: HoldPriority::Standard,
+            source_reference: HeaplessString::try_from(transaction.id.to_string().as_str()).ok(),
+        })
+    }
+}
+
+fn mandated_approvers(mandate: &AccountMandate) -> Vec<Uuid> {
+    [
+        mandate.approver01_person_id,
+        mandate.approver02_person_id,
+        mandate.approver03_person_id,
+        mandate.approver04_person_id,
+        mandate.approver05_person_id,
+        mandate.approver06_person_id,
+        mandate.approver07_person_id,
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, NaiveDate};
+    use rust_decimal::Decimal;
+    use crate::domain::{CurrencyCode, DegradedFlags, MandateStatus, PermissionType, TransactionStatus};
+
+    fn mandate(approvers: &[Uuid], required_signers_count: u8) -> AccountMandate {
+        let approver = |index: usize| approvers.get(index).copied();
+        AccountMandate {
+            id: Uuid::new_v4(),
+            account_id: Uuid::new_v4(),
+            grantee_customer_id: Uuid::new_v4(),
+            permission_type: PermissionType::JointApproval,
+            transaction_limit: None,
+            approver01_person_id: approver(0),
+            approver02_person_id: approver(1),
+            approver03_person_id: approver(2),
+            approver04_person_id: None,
+            approver05_person_id: None,
+            approver06_person_id: None,
+            approver07_person_id: None,
+            required_signers_count,
+            conditional_mandate_id: None,
+            status: MandateStatus::Active,
+            start_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            end_date: None,
+        }
+    }
+
+    fn transfer(amount: Decimal) -> Transaction {
+        Transaction {
+            id: Uuid::new_v4(),
+            account_id: Uuid::new_v4(),
+            transaction_code: HeaplessString::try_from("EXT_TRF").unwrap(),
+            transaction_type: TransactionType::Debit,
+            amount,
+            currency: CurrencyCode::try_from("XAF").unwrap(),
+            description: HeaplessString::try_from("External transfer").unwrap(),
+            channel_id: HeaplessString::try_from("BRANCH").unwrap(),
+            terminal_id: None,
+            agent_person_id: Some(Uuid::new_v4()),
+            transaction_date: Utc::now(),
+            value_date: NaiveDate::from_ymd_opt(2024, 6, 14).unwrap(),
+            status: TransactionStatus::AwaitingApproval,
+            reference_number: HeaplessString::try_from("REF-1").unwrap(),
+            external_reference: None,
+            gl_code: HeaplessString::try_from("2100").unwrap(),
+            requires_approval: true,
+            approval_status: None,
+            risk_score: None,
+            degraded_flags: DegradedFlags::NONE,
+            idempotency_key: None,
+            reversal_of_transaction_id: None,
+            fx_conversion: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_initiator_cannot_approve() {
+        let initiator = Uuid::new_v4();
+        let mut requirement = ApprovalRequirement::from_mandates(initiator, &[], Utc::now() + Duration::hours(24));
+        assert_eq!(requirement.required_signers_count, 1);
+        assert_eq!(requirement.approve(initiator, Utc::now()), Err(ApprovalRefusal::Initiator));
+        assert!(requirement.approved_by.is_empty());
+
+        assert_eq!(requirement.approve(Uuid::new_v4(), Utc::now()), Ok(true));
+    }
+
+    #[test]
+    fn test_two_of_three_signers() {
+        let initiator = Uuid::new_v4();
+        let signers = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+        let mandates = [mandate(&signers[..1], 1), mandate(&signers, 2)];
+        let mut requirement = ApprovalRequirement::from_mandates(initiator, &mandates, Utc::now() + Duration::hours(24));
+        assert_eq!(requirement.required_signers_count, 2);
+
+        assert_eq!(requirement.approve(Uuid::new_v4(), Utc::now()), Err(ApprovalRefusal::NotMandated));
+        assert_eq!(requirement.approve(signers[2], Utc::now()), Ok(false));
+        assert_eq!(requirement.approve(signers[2], Utc::now()), Err(ApprovalRefusal::AlreadyApproved));
+        assert_eq!(requirement.approve(signers[0], Utc::now()), Ok(true));
+        assert!(requirement.is_satisfied());
+    }
+
+    #[test]
+    fn test_expiry_refuses_approval_and_releases_the_hold() {
+        let initiated_at = Utc::now();
+        let timeout_at = initiated_at + Duration::hours(24);
+        let mut requirement = ApprovalRequirement::from_mandates(Uuid::new_v4(), &[], timeout_at);
+
+        let mut debit = transfer(Decimal::from(2_500_000));
+        let hold = requirement.hold_request(&debit, Uuid::new_v4()).expect("Debits are held");
+        assert_eq!(hold.hold_type, HoldType::PendingAuthorization);
+        assert_eq!(hold.amount, debit.amount);
+        // Released automatically by the hold expiry when the workflow times out
+        assert_eq!(hold.expires_at, Some(timeout_at));
+
+        assert_eq!(requirement.approve(Uuid::new_v4(), timeout_at), Err(ApprovalRefusal::Expired));
+        assert!(!requirement.is_satisfied());
+
+        debit.transaction_type = TransactionType::Credit;
+        assert!(requirement.hold_request(&debit, Uuid::new_v4()).is_none());
+    }
+}
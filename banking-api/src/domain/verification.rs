@@ -0,0 +1,128 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::PostingActor;
+
+/// Permission a teller needs to verify a customer in person
+pub const BRANCH_VERIFICATION_PERMISSION: &str = "BranchIdentityVerification";
+
+/// High-risk operation a verification is obtained for; a verification only
+/// unlocks the kind it was obtained for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum VerificationOperationKind {
+    ContactDetailChange,
+    LimitIncrease,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChallengeStatus {
+    Pending,
+    Verified,
+    /// Used up by the operation it was verified for
+    Consumed,
+    /// Too many wrong codes
+    Failed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VerificationMethod {
+    /// Code sent to the customer's registered channel
+    Otp,
+    /// Customer verified in person by a teller
+    BranchAssertion,
+}
+
+/// Short-lived step-up verification of a customer for one operation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VerificationChallenge {
+    pub id: Uuid,
+    pub customer_id: Uuid,
+    pub operation_kind: VerificationOperationKind,
+    /// Channel the challenge was raised on, references Channel.id
+    pub channel_id: Uuid,
+    pub code: HeaplessString<10>,
+    pub status: ChallengeStatus,
+    pub failed_attempts: u32,
+    pub last_failed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub verified_method: Option<VerificationMethod>,
+    /// Teller who asserted the verification, references Person.person_id
+    pub verified_by_person_id: Option<Uuid>,
+    pub verified_at: Option<DateTime<Utc>>,
+    pub consumed_at: Option<DateTime<Utc>>,
+}
+
+impl VerificationChallenge {
+    /// Whether a proof may still be checked against the challenge
+    pub fn check_verifiable(&self, now: DateTime<Utc>) -> Result<(), ChallengeRejection> {
+        match self.status {
+            ChallengeStatus::Verified | ChallengeStatus::Consumed => Err(ChallengeRejection::AlreadyUsed),
+            ChallengeStatus::Failed => Err(ChallengeRejection::AttemptsExhausted),
+            ChallengeStatus::Pending if now >= self.expires_at => Err(ChallengeRejection::Expired),
+            ChallengeStatus::Pending => Ok(()),
+        }
+    }
+}
+
+/// What the caller of `initiate_challenge` holds on to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChallengeHandle {
+    pub challenge_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+    /// False when the code could not be delivered; the challenge can still
+    /// be verified in branch
+    pub code_sent: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum VerificationProof {
+    Code(HeaplessString<10>),
+    /// Teller attesting they verified the customer in person
+    BranchAssertion(PostingActor),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChallengeRejection {
+    Expired,
+    /// Verified or consumed already; challenges are single-use
+    AlreadyUsed,
+    WrongCode { attempts_left: u32 },
+    AttemptsExhausted,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_only_pending_unexpired_challenges_are_verifiable() {
+        let now = Utc::now();
+        let mut challenge = VerificationChallenge {
+            id: Uuid::new_v4(),
+            customer_id: Uuid::new_v4(),
+            operation_kind: VerificationOperationKind::ContactDetailChange,
+            channel_id: Uuid::new_v4(),
+            code: HeaplessString::try_from("123456").unwrap(),
+            status: ChallengeStatus::Pending,
+            failed_attempts: 0,
+            last_failed_at: None,
+            created_at: now,
+            expires_at: now + Duration::minutes(5),
+            verified_method: None,
+            verified_by_person_id: None,
+            verified_at: None,
+            consumed_at: None,
+        };
+        assert_eq!(challenge.check_verifiable(now), Ok(()));
+        assert_eq!(challenge.check_verifiable(now + Duration::minutes(5)), Err(ChallengeRejection::Expired));
+
+        challenge.status = ChallengeStatus::Consumed;
+        assert_eq!(challenge.check_verifiable(now), Err(ChallengeRejection::AlreadyUsed));
+        challenge.status = ChallengeStatus::Failed;
+        assert_eq!(challenge.check_verifiable(now), Err(ChallengeRejection::AttemptsExhausted));
+    }
+}
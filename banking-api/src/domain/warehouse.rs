@@ -0,0 +1,144 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Version of the exported record layout. Bump it whenever the field set of
+/// an entity changes; `test_schema_version_tracks_exported_fields` fails
+/// until the version and its fingerprint are updated together.
+pub const WAREHOUSE_SCHEMA_VERSION: u32 = 1;
+
+/// Table exported to the data warehouse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WarehouseEntity {
+    Account,
+    Transaction,
+    Customer,
+    Workflow,
+}
+
+impl WarehouseEntity {
+    pub const ALL: [WarehouseEntity; 4] = [
+        WarehouseEntity::Account,
+        WarehouseEntity::Transaction,
+        WarehouseEntity::Customer,
+        WarehouseEntity::Workflow,
+    ];
+
+    /// Columns written for each row. Customer names and identity numbers
+    /// stay out of the warehouse.
+    pub fn exported_fields(self) -> &'static [&'static str] {
+        match self {
+            WarehouseEntity::Account => &[
+                "id", "product_id", "account_type", "account_status", "currency", "open_date",
+                "domicile_agency_branch_id", "current_balance", "available_balance", "accrued_interest",
+                "overdraft_limit", "outstanding_principal", "close_date", "last_activity_date",
+                "created_at", "last_updated_at",
+            ],
+            WarehouseEntity::Transaction => &[
+                "id", "account_id", "transaction_code", "transaction_type", "amount", "currency",
+                "channel_id", "terminal_id", "agent_person_id", "transaction_date", "value_date",
+                "status", "gl_code", "requires_approval", "approval_status", "degraded_flags",
+                "created_at", "updated_at",
+            ],
+            WarehouseEntity::Customer => &[
+                "id", "customer_type", "risk_rating", "status", "preferred_language_code",
+                "created_at", "last_updated_at",
+            ],
+            WarehouseEntity::Workflow => &[
+                "id", "account_id", "workflow_type", "current_step", "status", "initiated_by",
+                "initiated_at", "completed_at", "timeout_at", "created_at", "last_updated_at",
+            ],
+        }
+    }
+}
+
+/// FNV-1a over the entities and their exported fields, stable across builds
+pub fn warehouse_schema_fingerprint() -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for entity in WarehouseEntity::ALL {
+        let fields = entity.exported_fields().join(",");
+        for byte in format!("{entity:?}:{fields};").bytes() {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WarehouseExportMode {
+    /// Rows changed and deleted after the previous watermark
+    Incremental,
+    /// Every current row, for initial loads; restarts the watermark chain
+    FullSnapshot,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WarehouseRecordOp {
+    Upsert,
+    Delete,
+}
+
+/// One line of an entity's newline-delimited JSON output
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WarehouseRecord {
+    pub op: WarehouseRecordOp,
+    pub entity_id: Uuid,
+    /// Change-tracking timestamp of the row, or deletion time
+    pub changed_at: DateTime<Utc>,
+    /// Exported fields of the row; None for deletions
+    pub row: Option<serde_json::Value>,
+}
+
+/// Row and watermark statistics of one entity in an export
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EntityDeltaManifest {
+    pub entity: WarehouseEntity,
+    pub upserted_rows: u64,
+    pub deleted_rows: u64,
+    pub min_watermark: Option<DateTime<Utc>>,
+    pub max_watermark: Option<DateTime<Utc>>,
+}
+
+/// Describes one export run. Runs cover `(from_watermark, to_watermark]`,
+/// each starting where the previous one ended.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeltaManifest {
+    pub run_id: Uuid,
+    pub mode: WarehouseExportMode,
+    pub schema_version: u32,
+    /// None for a full snapshot
+    pub from_watermark: Option<DateTime<Utc>>,
+    pub to_watermark: DateTime<Utc>,
+    pub generated_at: DateTime<Utc>,
+    pub entities: Vec<EntityDeltaManifest>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fingerprint of the field sets exported under WAREHOUSE_SCHEMA_VERSION
+    const SCHEMA_FINGERPRINTS: &[(u32, u64)] = &[(1, 0xd64b_5005_11c6_322c)];
+
+    #[test]
+    fn test_schema_version_tracks_exported_fields() {
+        let (version, fingerprint) = *SCHEMA_FINGERPRINTS.last().unwrap();
+        assert_eq!(
+            (version, fingerprint),
+            (WAREHOUSE_SCHEMA_VERSION, warehouse_schema_fingerprint()),
+            "Exported fields changed: bump WAREHOUSE_SCHEMA_VERSION and record the new fingerprint"
+        );
+        // Every version has its own field set
+        for pair in SCHEMA_FINGERPRINTS.windows(2) {
+            assert!(pair[0].0 < pair[1].0 && pair[0].1 != pair[1].1);
+        }
+    }
+
+    #[test]
+    fn test_every_entity_exports_its_id() {
+        for entity in WarehouseEntity::ALL {
+            assert_eq!(entity.exported_fields().first(), Some(&"id"), "{entity:?}");
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{AccountStatus, MessagingType};
+
+/// Localized summary of a newly activated account, sent to the customer once
+/// on first activation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WelcomePack {
+    pub account_id: Uuid,
+    pub customer_id: Uuid,
+    pub language_code: [u8; 3],
+
+    // Account details
+    pub account_number: HeaplessString<34>,
+    /// IBAN-style grouped number, present only when the institution issues IBANs
+    pub formatted_account_number: Option<HeaplessString<42>>,
+    pub currency: HeaplessString<3>,
+    pub open_date: chrono::NaiveDate,
+
+    // Product summary
+    pub product_id: Uuid,
+    pub product_name: HeaplessString<100>,
+    pub product_description: HeaplessString<255>,
+    pub minimum_balance: Decimal,
+    pub overdraft_allowed: bool,
+    pub overdraft_limit: Option<Decimal>,
+    pub maintenance_fee: Option<Decimal>,
+    pub closure_fee: Decimal,
+
+    // Branch details
+    pub branch_id: Uuid,
+    pub branch_name: HeaplessString<100>,
+    pub branch_code: HeaplessString<8>,
+    /// References Messaging.id of the branch's primary contact method
+    pub branch_contact_messaging_id: Option<Uuid>,
+    pub branch_contact_messaging_type: Option<MessagingType>,
+
+    pub generated_at: DateTime<Utc>,
+}
+
+impl WelcomePack {
+    /// A welcome pack is sent only when an account leaves PendingApproval for
+    /// Active. Re-activation from Dormant or Frozen does not qualify.
+    pub fn is_triggered_by(previous: AccountStatus, new: AccountStatus) -> bool {
+        previous == AccountStatus::PendingApproval && new == AccountStatus::Active
+    }
+}
+
+/// Kind of document produced by the system for a customer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GeneratedDocumentType {
+    WelcomePack,
+}
+
+/// A rendered document stored for later retrieval. The `id` doubles as the
+/// retrieval id handed to the customer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedDocument {
+    pub id: Uuid,
+    pub document_type: GeneratedDocumentType,
+    pub account_id: Uuid,
+    pub customer_id: Uuid,
+    pub language_code: [u8; 3],
+    /// Rendered document payload (JSON)
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl GeneratedDocument {
+    /// Short reference quoted in customer notifications, e.g. "DOC-1A2B3C4D5E6F"
+    pub fn retrieval_reference(&self) -> HeaplessString<50> {
+        let simple = self.id.simple().to_string().to_uppercase();
+        let mut reference = HeaplessString::new();
+        let _ = reference.push_str("DOC-");
+        let _ = reference.push_str(&simple[..12]);
+        reference
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DocumentNotificationStatus {
+    Queued,
+    Sent,
+    Failed,
+}
+
+/// Outbound message telling a customer that a document is ready
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentNotification {
+    pub id: Uuid,
+    /// References GeneratedDocument.id
+    pub document_id: Uuid,
+    pub customer_id: Uuid,
+    pub retrieval_reference: HeaplessString<50>,
+    pub language_code: [u8; 3],
+    pub status: DocumentNotificationStatus,
+    pub queued_at: DateTime<Utc>,
+    pub sent_at: Option<DateTime<Utc>>,
+}
+
+/// Localization slot holding a translated value (`name_l1`, `name_l2`, `name_l3`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LocalizationSlot {
+    L1,
+    L2,
+    L3,
+}
+
+impl LocalizationSlot {
+    /// Picks the value for this slot, falling back to the primary language when
+    /// the translation is empty.
+    pub fn pick<'a>(&self, l1: &'a str, l2: &'a str, l3: &'a str) -> &'a str {
+        let value = match self {
+            LocalizationSlot::L1 => l1,
+            LocalizationSlot::L2 => l2,
+            LocalizationSlot::L3 => l3,
+        };
+        if value.is_empty() { l1 } else { value }
+    }
+}
+
+/// Languages configured for the l1/l2/l3 slots of the institution's data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SupportedLanguages {
+    pub l1_language_code: [u8; 3],
+    pub l2_language_code: Option<[u8; 3]>,
+    pub l3_language_code: Option<[u8; 3]>,
+}
+
+impl Default for SupportedLanguages {
+    fn default() -> Self {
+        Self {
+            l1_language_code: *b"eng",
+            l2_language_code: Some(*b"fra"),
+            l3_language_code: None,
+        }
+    }
+}
+
+impl SupportedLanguages {
+    /// Resolves a preferred language to a slot and the language actually used,
+    /// falling back to the primary language if it is missing or unsupported.
+    pub fn select(&self, preferred: Option<[u8; 3]>) -> (LocalizationSlot, [u8; 3]) {
+        match preferred {
+            Some(code) if self.l2_language_code == Some(code) => (LocalizationSlot::L2, code),
+            Some(code) if self.l3_language_code == Some(code) => (LocalizationSlot::L3, code),
+            _ => (LocalizationSlot::L1, self.l1_language_code),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trigger_fires_only_on_first_activation() {
+        assert!(WelcomePack::is_triggered_by(AccountStatus::PendingApproval, AccountStatus::Active));
+
+        assert!(!WelcomePack::is_triggered_by(AccountStatus::Dormant, AccountStatus::Active));
+        assert!(!WelcomePack::is_triggered_by(AccountStatus::PendingReactivation, AccountStatus::Active));
+        assert!(!WelcomePack::is_triggered_by(AccountStatus::Frozen, AccountStatus::Active));
+        assert!(!WelcomePack::is_triggered_by(AccountStatus::PendingClosure, AccountStatus::Active));
+        assert!(!WelcomePack::is_triggered_by(AccountStatus::PendingApproval, AccountStatus::Closed));
+    }
+
+    #[test]
+    fn test_localization_selects_preferred_language() {
+        let languages = SupportedLanguages {
+            l1_language_code: *b"eng",
+            l2_language_code: Some(*b"fra"),
+            l3_language_code: Some(*b"swa"),
+        };
+
+        assert_eq!(languages.select(Some(*b"fra")), (LocalizationSlot::L2, *b"fra"));
+        assert_eq!(languages.select(Some(*b"swa")), (LocalizationSlot::L3, *b"swa"));
+        assert_eq!(languages.select(Some(*b"eng")), (LocalizationSlot::L1, *b"eng"));
+    }
+
+    #[test]
+    fn test_localization_falls_back_to_primary_language() {
+        let languages = SupportedLanguages {
+            l1_language_code: *b"eng",
+            l2_language_code: Some(*b"fra"),
+            l3_language_code: None,
+        };
+
+        assert_eq!(languages.select(None), (LocalizationSlot::L1, *b"eng"));
+        assert_eq!(languages.select(Some(*b"deu")), (LocalizationSlot::L1, *b"eng"));
+
+        assert_eq!(LocalizationSlot::L2.pick("Savings", "Épargne", ""), "Épargne");
+        assert_eq!(LocalizationSlot::L3.pick("Savings", "Épargne", ""), "Savings");
+    }
+
+    #[test]
+    fn test_retrieval_reference() {
+        let document = GeneratedDocument {
+            id: Uuid::parse_str("1a2b3c4d-5e6f-7081-92a3-b4c5d6e7f809").unwrap(),
+            document_type: GeneratedDocumentType::WelcomePack,
+            account_id: Uuid::new_v4(),
+            customer_id: Uuid::new_v4(),
+            language_code: *b"eng",
+            content: String::new(),
+            created_at: Utc::now(),
+        };
+
+        assert_eq!(document.retrieval_reference().as_str(), "DOC-1A2B3C4D5E6F");
+    }
+}
@@ -5,6 +5,9 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::{CurrencyCode, SigningCondition};
+use crate::error::{BankingError, BankingResult};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccountWorkflow {
     pub id: Uuid,
@@ -28,6 +31,7 @@ pub enum WorkflowType {
     AccountReactivation,
     ComplianceVerification,
     MultiPartyApproval,
+    DormancyAssessment,
 }
 
 impl std::fmt::Display for WorkflowType {
@@ -38,6 +42,7 @@ impl std::fmt::Display for WorkflowType {
             WorkflowType::AccountReactivation => write!(f, "AccountReactivation"),
             WorkflowType::ComplianceVerification => write!(f, "ComplianceVerification"),
             WorkflowType::MultiPartyApproval => write!(f, "MultiPartyApproval"),
+            WorkflowType::DormancyAssessment => write!(f, "DormancyAssessment"),
         }
     }
 }
@@ -76,11 +81,17 @@ pub struct WorkflowStepRecord {
 pub struct AccountOpeningRequest {
     pub customer_id: Uuid,
     pub product_id: Uuid,
+    pub currency: CurrencyCode,
+    pub signing_condition: SigningCondition,
+    /// References Customer.id of the owners other than `customer_id`
+    pub joint_owner_ids: Vec<Uuid>,
     pub initial_deposit: Option<Decimal>,
     pub channel: HeaplessString<50>,
     /// References Person.person_id
     pub initiated_by: Uuid,
     pub supporting_documents: Vec<DocumentReference>,
+    /// References Quote.id when the opening converts a pre-sale quote
+    pub quote_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -111,6 +122,41 @@ pub struct FinalSettlement {
     pub requires_disbursement: bool,
 }
 
+impl FinalSettlement {
+    /// Settle a closing account: the balance and interest accrued to the
+    /// settlement date, less fees not yet posted and the product closure fee.
+    /// An account with active holds is not settled until they are released.
+    pub fn settle(
+        account_id: Uuid,
+        current_balance: Decimal,
+        accrued_interest: Decimal,
+        pending_fees: Decimal,
+        closure_fees: Decimal,
+        active_hold_ids: &[Uuid],
+    ) -> BankingResult<Self> {
+        if !active_hold_ids.is_empty() {
+            return Err(BankingError::SettlementBlockedByHolds {
+                account_id,
+                hold_ids: active_hold_ids.to_vec(),
+            });
+        }
+        let final_amount = current_balance + accrued_interest - pending_fees - closure_fees;
+        Ok(Self {
+            current_balance,
+            accrued_interest,
+            pending_fees,
+            closure_fees,
+            final_amount,
+            requires_disbursement: final_amount > Decimal::ZERO,
+        })
+    }
+
+    /// The customer owes the bank; the amount is recovered, not disbursed
+    pub fn is_receivable(&self) -> bool {
+        self.final_amount < Decimal::ZERO
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DormancyAssessment {
     pub is_eligible: bool,
@@ -122,18 +168,23 @@ pub struct DormancyAssessment {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DocumentReference {
-    pub document_id: Hash,
+    /// References RegisteredDocument.id once attached; identical content
+    /// resolves to the id it was first registered under
+    pub document_id: Uuid,
+    /// Blake3 hash of the document content
+    pub content_hash: Hash,
     pub document_type: HeaplessString<50>,
     pub document_path: Option<Hash>,
 }
 
 impl DocumentReference {
-    /// Create new document reference with hash-based ID
+    /// Create new document reference with content hash
     pub fn new(document_type: &str, content: &[u8]) -> Result<Self, &'static str> {
         let doc_type = HeaplessString::try_from(document_type)
             .map_err(|_| "Document type exceeds maximum length of 50 characters")?;
         Ok(Self {
-            document_id: blake3::hash(content),
+            document_id: Uuid::new_v4(),
+            content_hash: blake3::hash(content),
             document_type: doc_type,
             document_path: None,
         })
@@ -144,19 +195,68 @@ impl DocumentReference {
         let doc_type = HeaplessString::try_from(document_type)
             .map_err(|_| "Document type exceeds maximum length of 50 characters")?;
         Ok(Self {
-            document_id: blake3::hash(content),
+            document_id: Uuid::new_v4(),
+            content_hash: blake3::hash(content),
             document_type: doc_type,
             document_path: Some(blake3::hash(path_content)),
         })
     }
     
-    /// Get document ID as hex string for display/logging
-    pub fn document_id_hex(&self) -> String {
-        self.document_id.to_hex().to_string()
+    /// Get content hash as hex string for display/logging
+    pub fn content_hash_hex(&self) -> String {
+        self.content_hash.to_hex().to_string()
     }
     
     /// Get document path as hex string if available
     pub fn document_path_hex(&self) -> Option<String> {
         self.document_path.map(|hash| hash.to_hex().to_string())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settlement_adds_accrued_interest_and_deducts_fees() {
+        let settlement = FinalSettlement::settle(
+            Uuid::new_v4(),
+            Decimal::from(150_000),
+            Decimal::new(41_096, 2),
+            Decimal::from(1_500),
+            Decimal::from(2_000),
+            &[],
+        )
+        .unwrap();
+        assert_eq!(settlement.final_amount, Decimal::new(14_691_096, 2));
+        assert!(settlement.requires_disbursement);
+        assert!(!settlement.is_receivable());
+    }
+
+    #[test]
+    fn test_active_holds_block_the_settlement() {
+        let account_id = Uuid::new_v4();
+        let hold_ids = vec![Uuid::new_v4(), Uuid::new_v4()];
+        let result = FinalSettlement::settle(account_id, Decimal::from(150_000), Decimal::ZERO, Decimal::ZERO, Decimal::ZERO, &hold_ids);
+        assert!(matches!(
+            result,
+            Err(BankingError::SettlementBlockedByHolds { account_id: id, hold_ids: ids }) if id == account_id && ids == hold_ids
+        ));
+    }
+
+    #[test]
+    fn test_fees_above_the_balance_leave_a_receivable() {
+        let settlement = FinalSettlement::settle(
+            Uuid::new_v4(),
+            Decimal::from(1_000),
+            Decimal::ZERO,
+            Decimal::from(2_500),
+            Decimal::from(2_000),
+            &[],
+        )
+        .unwrap();
+        assert_eq!(settlement.final_amount, Decimal::from(-3_500));
+        assert!(!settlement.requires_disbursement);
+        assert!(settlement.is_receivable());
+    }
+}
@@ -0,0 +1,246 @@
+use chrono::{DateTime, Duration, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::{BankingError, BankingResult};
+
+/// Escalation of a timed-out workflow to a supervisor of its initiator. Level 1
+/// goes to the initiator's direct supervisor, each further level one step up
+/// the reporting line.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowEscalation {
+    pub id: Uuid,
+    /// References AccountWorkflow.id
+    pub workflow_id: Uuid,
+    /// References Person.person_id
+    pub escalated_to_person_id: Uuid,
+    pub level: u8,
+    pub status: WorkflowEscalationStatus,
+    pub created_at: DateTime<Utc>,
+    /// Still open at this time, the escalation goes one level further up
+    pub action_due_at: DateTime<Utc>,
+    pub acknowledged_at: Option<DateTime<Utc>>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    /// References Person.person_id
+    pub resolved_by_person_id: Option<Uuid>,
+    pub resolution_notes: Option<HeaplessString<500>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorkflowEscalationStatus {
+    /// Waiting for the supervisor to act
+    Open,
+    /// Taken on by the supervisor; no longer escalates
+    Acknowledged,
+    Resolved,
+    /// Not actioned in time and handed to the next level
+    Escalated,
+}
+
+/// How long a supervisor has to act and how far up the reporting line a
+/// workflow may be escalated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkflowEscalationPolicy {
+    pub action_period: Duration,
+    pub max_depth: u8,
+}
+
+impl WorkflowEscalation {
+    /// Level-1 escalation of a workflow that timed out at `at`. `reporting_line`
+    /// lists the initiator's supervisors, nearest first; None when it is empty.
+    pub fn open(
+        workflow_id: Uuid,
+        reporting_line: &[Uuid],
+        policy: &WorkflowEscalationPolicy,
+        at: DateTime<Utc>,
+    ) -> Option<Self> {
+        Self::at_level(workflow_id, 1, reporting_line, policy, at)
+    }
+
+    fn at_level(
+        workflow_id: Uuid,
+        level: u8,
+        reporting_line: &[Uuid],
+        policy: &WorkflowEscalationPolicy,
+        at: DateTime<Utc>,
+    ) -> Option<Self> {
+        if level == 0 || level > policy.max_depth {
+            return None;
+        }
+        let escalated_to_person_id = *reporting_line.get(usize::from(level) - 1)?;
+        Some(Self {
+            id: Uuid::new_v4(),
+            workflow_id,
+            escalated_to_person_id,
+            level,
+            status: WorkflowEscalationStatus::Open,
+            created_at: at,
+            action_due_at: at + policy.action_period,
+            acknowledged_at: None,
+            resolved_at: None,
+            resolved_by_person_id: None,
+            resolution_notes: None,
+        })
+    }
+
+    /// In the supervisor's queue: neither resolved nor handed further up
+    pub fn is_open(&self) -> bool {
+        matches!(self.status, WorkflowEscalationStatus::Open | WorkflowEscalationStatus::Acknowledged)
+    }
+
+    /// Not acknowledged or resolved within the action period
+    pub fn is_overdue(&self, at: DateTime<Utc>) -> bool {
+        self.status == WorkflowEscalationStatus::Open && at >= self.action_due_at
+    }
+
+    /// Hand an overdue escalation to the next supervisor in `reporting_line`
+    /// (the same line the escalation was opened from). Returns the next-level
+    /// escalation and marks this one escalated; None while the escalation is
+    /// not overdue, or once the maximum depth or the top of the line is reached.
+    pub fn escalate(
+        &mut self,
+        reporting_line: &[Uuid],
+        policy: &WorkflowEscalationPolicy,
+        at: DateTime<Utc>,
+    ) -> Option<Self> {
+        if !self.is_overdue(at) {
+            return None;
+        }
+        let next = Self::at_level(self.workflow_id, self.level.checked_add(1)?, reporting_line, policy, at)?;
+        self.status = WorkflowEscalationStatus::Escalated;
+        Some(next)
+    }
+
+    /// The assigned supervisor takes the escalation on, which stops it from
+    /// escalating further. Acknowledging again keeps the first acknowledgement.
+    pub fn acknowledge(&mut self, person_id: Uuid, at: DateTime<Utc>) -> BankingResult<()> {
+        self.ensure_actionable_by(person_id)?;
+        if self.status == WorkflowEscalationStatus::Open {
+            self.status = WorkflowEscalationStatus::Acknowledged;
+            self.acknowledged_at = Some(at);
+        }
+        Ok(())
+    }
+
+    /// Close the escalation; open escalations may be resolved without being acknowledged
+    pub fn resolve(
+        &mut self,
+        person_id: Uuid,
+        resolution_notes: Option<HeaplessString<500>>,
+        at: DateTime<Utc>,
+    ) -> BankingResult<()> {
+        self.ensure_actionable_by(person_id)?;
+        self.status = WorkflowEscalationStatus::Resolved;
+        self.resolved_at = Some(at);
+        self.resolved_by_person_id = Some(person_id);
+        self.resolution_notes = resolution_notes;
+        Ok(())
+    }
+
+    fn ensure_actionable_by(&self, person_id: Uuid) -> BankingResult<()> {
+        if !self.is_open() {
+            return Err(BankingError::WorkflowEscalationClosed {
+                escalation_id: self.id,
+                status: self.status,
+            });
+        }
+        if person_id != self.escalated_to_person_id {
+            return Err(BankingError::WorkflowEscalationNotAssigned {
+                escalation_id: self.id,
+                person_id,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(max_depth: u8) -> WorkflowEscalationPolicy {
+        WorkflowEscalationPolicy { action_period: Duration::hours(24), max_depth }
+    }
+
+    #[test]
+    fn test_timeout_opens_level_one_escalation_to_direct_supervisor() {
+        let line = [Uuid::new_v4(), Uuid::new_v4()];
+        let timed_out_at = Utc::now();
+
+        let escalation = WorkflowEscalation::open(Uuid::new_v4(), &line, &policy(3), timed_out_at).unwrap();
+
+        assert_eq!(escalation.level, 1);
+        assert_eq!(escalation.escalated_to_person_id, line[0]);
+        assert_eq!(escalation.status, WorkflowEscalationStatus::Open);
+        assert_eq!(escalation.action_due_at, timed_out_at + Duration::hours(24));
+        assert!(WorkflowEscalation::open(Uuid::new_v4(), &[], &policy(3), timed_out_at).is_none());
+    }
+
+    #[test]
+    fn test_inaction_escalates_to_next_level_up_to_max_depth() {
+        let line = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+        let policy = policy(2);
+        let opened_at = Utc::now();
+        let mut first = WorkflowEscalation::open(Uuid::new_v4(), &line, &policy, opened_at).unwrap();
+
+        assert!(first.escalate(&line, &policy, opened_at + Duration::hours(23)).is_none());
+        assert_eq!(first.status, WorkflowEscalationStatus::Open);
+
+        let overdue_at = opened_at + Duration::hours(24);
+        let mut second = first.escalate(&line, &policy, overdue_at).unwrap();
+        assert_eq!(first.status, WorkflowEscalationStatus::Escalated);
+        assert!(!first.is_open());
+        assert_eq!(second.level, 2);
+        assert_eq!(second.workflow_id, first.workflow_id);
+        assert_eq!(second.escalated_to_person_id, line[1]);
+
+        // The reporting line goes further, the policy does not
+        assert!(second.escalate(&line, &policy, overdue_at + Duration::hours(24)).is_none());
+        assert_eq!(second.status, WorkflowEscalationStatus::Open);
+    }
+
+    #[test]
+    fn test_resolution_and_acknowledgement_stop_escalation() {
+        let line = [Uuid::new_v4(), Uuid::new_v4()];
+        let policy = policy(3);
+        let opened_at = Utc::now();
+        let overdue_at = opened_at + Duration::hours(48);
+
+        let mut resolved = WorkflowEscalation::open(Uuid::new_v4(), &line, &policy, opened_at).unwrap();
+        let notes = HeaplessString::try_from("Documents collected from customer").unwrap();
+        resolved.resolve(line[0], Some(notes.clone()), opened_at).unwrap();
+        assert!(resolved.escalate(&line, &policy, overdue_at).is_none());
+        assert_eq!(resolved.status, WorkflowEscalationStatus::Resolved);
+        assert_eq!(resolved.resolved_by_person_id, Some(line[0]));
+        assert_eq!(resolved.resolution_notes, Some(notes));
+
+        let mut acknowledged = WorkflowEscalation::open(Uuid::new_v4(), &line, &policy, opened_at).unwrap();
+        acknowledged.acknowledge(line[0], opened_at).unwrap();
+        assert!(acknowledged.escalate(&line, &policy, overdue_at).is_none());
+        assert!(acknowledged.is_open());
+    }
+
+    #[test]
+    fn test_only_assigned_supervisor_acts_on_open_escalation() {
+        let line = [Uuid::new_v4()];
+        let mut escalation = WorkflowEscalation::open(Uuid::new_v4(), &line, &policy(1), Utc::now()).unwrap();
+        let stranger = Uuid::new_v4();
+
+        assert!(matches!(
+            escalation.acknowledge(stranger, Utc::now()),
+            Err(BankingError::WorkflowEscalationNotAssigned { person_id, .. }) if person_id == stranger
+        ));
+
+        escalation.acknowledge(line[0], Utc::now()).unwrap();
+        let acknowledged_at = escalation.acknowledged_at;
+        escalation.acknowledge(line[0], Utc::now()).unwrap();
+        assert_eq!(escalation.acknowledged_at, acknowledged_at);
+
+        escalation.resolve(line[0], None, Utc::now()).unwrap();
+        assert!(matches!(
+            escalation.resolve(line[0], None, Utc::now()),
+            Err(BankingError::WorkflowEscalationClosed { status: WorkflowEscalationStatus::Resolved, .. })
+        ));
+    }
+}
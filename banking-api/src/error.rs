@@ -1,6 +1,8 @@
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -54,6 +56,128 @@ pub enum BankingError {
     #[error("Account {account_id} is not in a transactional state")]
     AccountNotTransactional { account_id: Uuid },
 
+    #[error("Account {account_id} cannot be settled until its active holds {hold_ids:?} are released")]
+    SettlementBlockedByHolds {
+        account_id: Uuid,
+        hold_ids: Vec<Uuid>,
+    },
+
+    #[error("Account number {0} is already in use")]
+    DuplicateAccountNumber(String),
+
+    #[error("No account number scheme for product {product_id} at branch {branch_id}")]
+    AccountNumberSchemeNotFound {
+        branch_id: Uuid,
+        product_id: Uuid,
+    },
+
+    // Cheque-related errors
+    #[error("Cheque {serial_number} not found on account {account_id}")]
+    ChequeNotFound {
+        account_id: Uuid,
+        serial_number: i64,
+    },
+
+    #[error("Payment of cheque {serial_number} on account {account_id} has been stopped")]
+    ChequeStopped {
+        account_id: Uuid,
+        serial_number: i64,
+    },
+
+    #[error("Cheque {serial_number} on account {account_id} has already been presented")]
+    DuplicateChequePresentment {
+        account_id: Uuid,
+        serial_number: i64,
+    },
+
+    #[error("Cheque {serial_number} on account {account_id} returned: {reason:?}")]
+    ChequeReturned {
+        account_id: Uuid,
+        serial_number: i64,
+        reason: crate::domain::ChequeReturnReason,
+    },
+
+    // Statement-related errors
+    #[error("Statement {statement_id} failed integrity verification: stored hash {stored_hash}, computed {computed_hash}")]
+    StatementIntegrityViolation {
+        statement_id: Uuid,
+        stored_hash: String,
+        computed_hash: String,
+    },
+
+    #[error("Statement of account {account_id} to {period_end} closes at {closing_balance} but the ledger balance is {ledger_balance}")]
+    StatementOutOfBalance {
+        account_id: Uuid,
+        period_end: NaiveDate,
+        closing_balance: Decimal,
+        ledger_balance: Decimal,
+    },
+
+    #[error("Agent network hierarchy contains a cycle through {node_id}")]
+    HierarchyCycle { node_id: Uuid },
+
+    #[error("GL summary for {run_date} does not balance in {currency}: debits {debit_total}, credits {credit_total}, imbalance {imbalance}")]
+    GlSummaryImbalance {
+        run_date: NaiveDate,
+        currency: String,
+        debit_total: Decimal,
+        credit_total: Decimal,
+        imbalance: Decimal,
+    },
+
+    // Reason-related errors
+    #[error("Reason {reason_id} cannot be used for {operation:?}: {issue:?}")]
+    ReasonMismatch {
+        reason_id: Uuid,
+        operation: crate::domain::ReasonedOperation,
+        issue: crate::domain::ReasonMismatchIssue,
+    },
+
+    // Payee-related errors
+    #[error("Payee {payee_id} is blocked")]
+    PayeeBlocked { payee_id: Uuid },
+
+    #[error("Payee {payee_id} is {status:?}: transfers are limited to {limit}, attempted {attempted}")]
+    PayeeNotVerified {
+        payee_id: Uuid,
+        status: crate::domain::PayeeVerificationStatus,
+        limit: Decimal,
+        attempted: Decimal,
+    },
+
+    // Collection-related errors
+    #[error("Collection batch {batch_id} has {pending_records} pending records; process or fail them before reconciling")]
+    CollectionBatchHasPendingRecords {
+        batch_id: Uuid,
+        pending_records: usize,
+    },
+
+    #[error("Performance alert {0} must be acknowledged before it can be resolved")]
+    PerformanceAlertNotAcknowledged(Uuid),
+
+    #[error("Performance alert {0} is already resolved")]
+    PerformanceAlertAlreadyResolved(Uuid),
+
+    #[error("Device {device_id} of collection agent {agent_id} may not record collections: {reason}")]
+    DeviceNonCompliant {
+        agent_id: Uuid,
+        device_id: Uuid,
+        reason: crate::domain::DeviceComplianceError,
+    },
+
+    // Workflow-related errors
+    #[error("Workflow escalation {escalation_id} is {status:?} and can no longer be actioned")]
+    WorkflowEscalationClosed {
+        escalation_id: Uuid,
+        status: crate::domain::WorkflowEscalationStatus,
+    },
+
+    #[error("Workflow escalation {escalation_id} is not assigned to person {person_id}")]
+    WorkflowEscalationNotAssigned {
+        escalation_id: Uuid,
+        person_id: Uuid,
+    },
+
     // Customer-related errors
     #[error("Customer not found: {0}")]
     CustomerNotFound(Uuid),
@@ -70,6 +194,13 @@ pub enum BankingError {
         blacklist_reason: String,
     },
 
+    #[error("Customer {duplicate_id} cannot be merged into {survivor_id}: {blocker:?}")]
+    CustomerMergeBlocked {
+        survivor_id: Uuid,
+        duplicate_id: Uuid,
+        blocker: crate::domain::CustomerMergeBlocker,
+    },
+
     // Transaction-related errors
     #[error("Transaction limit exceeded: attempted {attempted}, limit {limit} for {limit_type:?}")]
     TransactionLimitExceeded {
@@ -84,12 +215,95 @@ pub enum BankingError {
         provided_signatories: Vec<Uuid>,
     },
 
+    #[error("Value date {value_date} is more than {max_back_date_days} days before business date {business_date}")]
+    BackDatedPostingTooOld {
+        value_date: NaiveDate,
+        business_date: NaiveDate,
+        max_back_date_days: i64,
+    },
+
+    #[error("Posting halted by kill switch {switch_id} ({scope:?} {scope_value:?}): {reason}")]
+    KillSwitchActive {
+        switch_id: Uuid,
+        scope: crate::domain::KillSwitchScope,
+        scope_value: Option<String>,
+        reason: String,
+    },
+
+    #[error("Transaction currency {transaction_currency} does not match currency {account_currency} of account {account_id}")]
+    CurrencyMismatch {
+        account_id: Uuid,
+        account_currency: String,
+        transaction_currency: String,
+    },
+
+    #[error("No {base_currency}/{quote_currency} exchange rate effective at {as_of}")]
+    ExchangeRateNotFound {
+        base_currency: String,
+        quote_currency: String,
+        as_of: DateTime<Utc>,
+    },
+
+    #[error("{base_currency}/{quote_currency} exchange rate effective since {effective_at} is older than {max_age_hours} hours")]
+    StaleExchangeRate {
+        base_currency: String,
+        quote_currency: String,
+        effective_at: DateTime<Utc>,
+        max_age_hours: i64,
+    },
+
+    #[error("Idempotency key {idempotency_key} on channel {channel_id} was already used for a different transaction {transaction_id}")]
+    IdempotencyKeyConflict {
+        channel_id: String,
+        idempotency_key: String,
+        transaction_id: Uuid,
+    },
+
+    #[error("Transaction {transaction_id} cannot be reversed: {refusal:?}")]
+    TransactionNotReversible {
+        transaction_id: Uuid,
+        refusal: crate::domain::ReversalRefusal,
+    },
+
+    // Step-up verification errors
+    #[error("{operation_kind:?} for customer {customer_id} requires a recently verified challenge")]
+    VerificationRequired {
+        customer_id: Uuid,
+        operation_kind: crate::domain::VerificationOperationKind,
+    },
+
+    #[error("Verification challenge {challenge_id} rejected: {rejection:?}")]
+    ChallengeRejected {
+        challenge_id: Uuid,
+        rejection: crate::domain::ChallengeRejection,
+    },
+
+    #[error("{operation_kind:?} verification for customer {customer_id} locked out until {until}")]
+    VerificationLockedOut {
+        customer_id: Uuid,
+        operation_kind: crate::domain::VerificationOperationKind,
+        until: DateTime<Utc>,
+    },
+
     #[error("Approval required for transaction {transaction_id}: required approvers {required_approvers:?}")]
     ApprovalRequired {
         transaction_id: Uuid,
         required_approvers: Vec<Uuid>,
     },
 
+    #[error("Decision of {person_id} on transaction {transaction_id} refused: {refusal:?}")]
+    ApprovalRefused {
+        transaction_id: Uuid,
+        person_id: Uuid,
+        refusal: crate::domain::ApprovalRefusal,
+    },
+
+    #[error("Fee application {fee_application_id} was applied by {person_id}, who cannot also waive or reverse it")]
+    FeeSelfApproval {
+        fee_application_id: Uuid,
+        person_id: Uuid,
+    },
+
     // Compliance-related errors
     #[error("Compliance violation: {violation_type} for customer {customer_id:?}")]
     ComplianceViolation {
@@ -109,6 +323,12 @@ pub enum BankingError {
         match_details: String,
     },
 
+    #[error("SAR {sar_id} is {status:?} and can no longer be edited")]
+    SarLocked {
+        sar_id: Uuid,
+        status: crate::domain::SarStatus,
+    },
+
     // Agent Network Hierarchy Limit Violations
     #[error("Branch limit violation: branch {limit_type} limit ({branch_limit}) exceeds network limit ({network_limit})")]
     BranchLimitExceedsNetwork {
@@ -145,6 +365,12 @@ pub enum BankingError {
         validation_errors: Vec<String>,
     },
 
+    // Engine configuration
+    #[error("Banking configuration validation failed: {validation_errors:?}")]
+    ConfigValidationFailed {
+        validation_errors: Vec<String>,
+    },
+
     // Product and system errors
     #[error("Invalid product id: {0}")]
     InvalidProductId(Uuid),
@@ -171,6 +397,18 @@ pub enum BankingError {
         retry_possible: bool,
     },
 
+    #[error("{class} query exceeded its statement timeout after {elapsed:?}")]
+    QueryTimeout {
+        class: QueryClass,
+        elapsed: Duration,
+    },
+
+    #[error("{entity_id} was modified after version {expected_version} was read; reload and retry")]
+    StaleVersion {
+        entity_id: Uuid,
+        expected_version: i32,
+    },
+
     #[error("Database constraint violation: {constraint} - {details}")]
     DatabaseConstraintViolation {
         constraint: String,
@@ -190,6 +428,9 @@ pub enum BankingError {
         field: String,
     },
 
+    #[error("Invalid ISO 4217 currency code: {0:?}")]
+    InvalidCurrencyCode(String),
+
     // Transaction errors
     #[error("Invalid transaction amount: {0}")]
     InvalidTransactionAmount(String),
@@ -257,7 +498,34 @@ pub enum LimitType {
     Daily,
     Monthly,
     PerTransaction,
+    /// Number of transactions in a day
+    DailyCount,
     Terminal,
     Branch,
     Network,
-}
\ No newline at end of file
+    /// Reduced limit while a new payee is cooling off
+    PayeeCoolingOff,
+}
+
+/// Classifies a repository call by its expected cost so the executor can apply
+/// the matching statement timeout. Repositories default to `Interactive`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum QueryClass {
+    /// Single-entity lookups and writes on the posting path.
+    #[default]
+    Interactive,
+    /// EOD and bulk maintenance jobs.
+    Batch,
+    /// Reporting, statement and export queries.
+    Analytical,
+}
+
+impl fmt::Display for QueryClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryClass::Interactive => write!(f, "Interactive"),
+            QueryClass::Batch => write!(f, "Batch"),
+            QueryClass::Analytical => write!(f, "Analytical"),
+        }
+    }
+}
@@ -6,7 +6,7 @@ use uuid::Uuid;
 use crate::{
     domain::{
         AccountHold, AccountHoldExpiryJob, AccountHoldReleaseRequest, AccountHoldSummary,
-        HoldPriority, HoldStatus, HoldType, PlaceHoldRequest,
+        HoldPriority, HoldStatus, HoldType, PlaceHoldRequest, ReasonId,
     },
     BankingResult,
 };
@@ -33,7 +33,7 @@ pub trait AccountHoldService: Send + Sync {
     async fn cancel_hold(
         &self,
         hold_id: Uuid,
-        cancellation_reason_id: Uuid,
+        cancellation_reason_id: ReasonId,
         cancelled_by_person_id: Uuid,
     ) -> BankingResult<AccountHold>;
     async fn get_hold_amounts_by_priority(
@@ -70,6 +70,9 @@ pub trait AccountHoldService: Send + Sync {
         processing_date: NaiveDate,
         hold_types: Option<Vec<HoldType>>,
     ) -> BankingResult<AccountHoldExpiryJob>;
+    /// End-of-day sweep expiring holds due by `as_of` and restoring the
+    /// available balance they reserved
+    async fn expire_holds(&self, as_of: DateTime<Utc>) -> BankingResult<AccountHoldExpiryJob>;
     async fn process_automatic_releases(
         &self,
         processing_date: NaiveDate,
@@ -79,14 +82,14 @@ pub trait AccountHoldService: Send + Sync {
         account_ids: Vec<Uuid>,
         hold_type: HoldType,
         amount_per_account: Decimal,
-        reason_id: Uuid,
+        reason_id: ReasonId,
         placed_by_person_id: Uuid,
         expires_at: Option<DateTime<Utc>>,
     ) -> BankingResult<Vec<AccountHold>>;
     async fn bulk_release_holds(
         &self,
         hold_ids: Vec<Uuid>,
-        release_reason_id: Uuid,
+        release_reason_id: ReasonId,
         released_by_person_id: Uuid,
     ) -> BankingResult<Vec<AccountHold>>;
     async fn override_holds_for_transaction(
@@ -95,7 +98,7 @@ pub trait AccountHoldService: Send + Sync {
         transaction_amount: Decimal,
         override_priority: HoldPriority,
         authorized_by_person_id: Uuid,
-        override_reason_id: Uuid,
+        override_reason_id: ReasonId,
     ) -> BankingResult<Vec<AccountHold>>;
     async fn reorder_hold_priorities(
         &self,
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use heapless::String as HeaplessString;
+use uuid::Uuid;
+
+use crate::{
+    domain::{Account, AccountNumberScheme},
+    error::BankingResult,
+};
+
+/// Bank-facing account numbers, built from the scheme of the branch and product
+/// around a serial sequenced per branch
+#[async_trait]
+pub trait AccountNumberService: Send + Sync {
+    /// Save a branch default (no product) or product scheme, replacing the one
+    /// of the same scope. Fails with ValidationError when the scheme cannot
+    /// produce a valid number, e.g. an IBAN of the wrong length for its country.
+    async fn configure_scheme(&self, scheme: AccountNumberScheme) -> BankingResult<AccountNumberScheme>;
+
+    /// Scheme used for the product at the branch: its own, else the branch default
+    async fn find_applicable_scheme(&self, branch_id: Uuid, product_id: Uuid) -> BankingResult<Option<AccountNumberScheme>>;
+
+    /// Take the next serial of the branch and build the number from the applicable
+    /// scheme. Fails with AccountNumberSchemeNotFound when there is none.
+    async fn generate_account_number(&self, branch_id: Uuid, product_id: Uuid) -> BankingResult<HeaplessString<34>>;
+
+    /// Account with the given number, compact or grouped with spaces, in any case
+    async fn find_account_by_number(&self, account_number: &str) -> BankingResult<Option<Account>>;
+}
@@ -6,7 +6,8 @@ use uuid::Uuid;
 use crate::{
     BankingResult,
     domain::{
-        Account, AccountStatus, AccountBalanceCalculation, AccountHoldSummary,
+        Account, AccountStatus, AccountBalanceCalculation, AccountHoldSummary, TaggedAccountView, ReasonId,
+        AccountDomicileChange, AccountBalanceChangeRecord,
     },
 };
 
@@ -17,16 +18,30 @@ pub trait AccountService: Send + Sync {
     
     /// Find account by ID
     async fn find_account_by_id(&self, account_id: Uuid) -> BankingResult<Option<Account>>;
+
+    /// Account with its tags
+    async fn get_account_view(&self, account_id: Uuid) -> BankingResult<TaggedAccountView>;
     
     /// Status updates with immediate enforcement
     /// @param authorized_by_person_id - References Person.person_id
     async fn update_account_status(&self, account_id: Uuid, status: AccountStatus, authorized_by_person_id: Uuid) -> BankingResult<()>;
+
+    /// Move many accounts to `status`, validating each against its current status.
+    /// Unknown accounts and disallowed transitions fail on their own without stopping the batch;
+    /// results are paired with the ids in input order
+    async fn bulk_update_account_status(
+        &self,
+        account_ids: &[Uuid],
+        status: AccountStatus,
+        reason_id: ReasonId,
+        changed_by_person_id: Uuid,
+    ) -> BankingResult<Vec<(Uuid, BankingResult<()>)>>;
     
     /// Balance operations with product rule integration
     async fn calculate_balance(&self, account_id: Uuid) -> BankingResult<Decimal>;
     async fn calculate_available_balance(&self, account_id: Uuid) -> BankingResult<Decimal>;
     /// Apply hold with reason ID validation
-    async fn apply_hold(&self, account_id: Uuid, amount: Decimal, reason_id: Uuid, additional_details: Option<&str>) -> BankingResult<()>;
+    async fn apply_hold(&self, account_id: Uuid, amount: Decimal, reason_id: ReasonId, additional_details: Option<&str>) -> BankingResult<()>;
     
     /// Legacy method - deprecated, use apply_hold with reason_id instead
     #[deprecated(note = "Use apply_hold with reason_id instead")]
@@ -48,6 +63,14 @@ pub trait AccountService: Send + Sync {
     /// @param updated_by_person_id - References Person.person_id
     async fn update_balance(&self, account_id: Uuid, new_balance: Decimal, updated_by_person_id: Uuid) -> BankingResult<()>;
 
+    /// Balance changes made between `from` and `to` inclusive, oldest first
+    async fn get_balance_history(
+        &self,
+        account_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> BankingResult<Vec<AccountBalanceChangeRecord>>;
+
     /// Reset accrued interest to zero
     async fn reset_accrued_interest(&self, account_id: Uuid) -> BankingResult<()>;
 
@@ -64,6 +87,19 @@ pub trait AccountService: Send + Sync {
     /// Update last activity date
     async fn update_last_activity_date(&self, account_id: Uuid, activity_date: chrono::NaiveDate) -> BankingResult<()>;
 
+    /// Move an account to another branch of the same agent network. Branch
+    /// reports for dates before `effective_date` keep the old branch.
+    async fn transfer_account_domicile(
+        &self,
+        account_id: Uuid,
+        new_branch_id: Uuid,
+        effective_date: NaiveDate,
+        reason_id: ReasonId,
+        approved_by_person_id: Uuid,
+    ) -> BankingResult<AccountDomicileChange>;
+
+    async fn get_domicile_history(&self, account_id: Uuid) -> BankingResult<Vec<AccountDomicileChange>>;
+
     
     // ============================================================================
     // BALANCE CALCULATION ENGINE (enhanced)
@@ -127,7 +163,7 @@ pub trait AccountService: Send + Sync {
         account_ids: Vec<Uuid>,
         hold_type: crate::domain::HoldType,
         amount_per_account: Decimal,
-        reason_id: Uuid, // References ReasonAndPurpose.id
+        reason_id: ReasonId, // References ReasonAndPurpose.id
         placed_by_person_id: Uuid, // References Person.person_id
         expires_at: Option<DateTime<Utc>>,
     ) -> BankingResult<Vec<crate::domain::AccountHold>>;
@@ -136,7 +172,7 @@ pub trait AccountService: Send + Sync {
     async fn bulk_release_holds(
         &self,
         hold_ids: Vec<Uuid>,
-        release_reason_id: Uuid, // References ReasonAndPurpose.id
+        release_reason_id: ReasonId, // References ReasonAndPurpose.id
         released_by_person_id: Uuid, // References Person.person_id
     ) -> BankingResult<Vec<crate::domain::AccountHold>>;
     
@@ -151,7 +187,7 @@ pub trait AccountService: Send + Sync {
         transaction_amount: Decimal,
         override_priority: crate::domain::HoldPriority,
         authorized_by_person_id: Uuid, // References Person.person_id
-        override_reason_id: Uuid, // References ReasonAndPurpose.id
+        override_reason_id: ReasonId, // References ReasonAndPurpose.id
     ) -> BankingResult<Vec<crate::domain::AccountHold>>;
     
     /// Reorder hold priorities (e.g., judicial lien takes precedence)
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{
+        AgentCommissionRun, AgentCommissionScheme, AgentCommissionStatement, AgentCommissionStatementDetail,
+        CommissionPeriod,
+    },
+};
+
+/// Commission of collection agents on their monthly collection volumes
+#[async_trait]
+pub trait AgentCommissionService: Send + Sync {
+    async fn create_scheme(&self, scheme: AgentCommissionScheme) -> BankingResult<AgentCommissionScheme>;
+    async fn update_scheme(&self, scheme: AgentCommissionScheme) -> BankingResult<AgentCommissionScheme>;
+    async fn find_scheme_by_id(&self, scheme_id: Uuid) -> BankingResult<Option<AgentCommissionScheme>>;
+    async fn find_active_schemes(&self) -> BankingResult<Vec<AgentCommissionScheme>>;
+
+    /// Calculate the statements of every agent with processed collections in the period.
+    /// A calculated period is only recalculated with `force`, and never once invoiced.
+    async fn calculate_agent_commissions(&self, period: CommissionPeriod, force: bool) -> BankingResult<AgentCommissionRun>;
+
+    /// Statements of an agent with their line items, most recent period first
+    async fn find_agent_commission_statements(&self, collection_agent_id: Uuid, limit: i64) -> BankingResult<Vec<AgentCommissionStatementDetail>>;
+
+    /// Lock a statement against recalculation once it has been paid or billed
+    async fn mark_statement_invoiced(&self, statement_id: Uuid) -> BankingResult<AgentCommissionStatement>;
+}
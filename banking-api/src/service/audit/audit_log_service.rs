@@ -1,5 +1,6 @@
-use crate::domain::audit::AuditLog;
+use crate::domain::audit::{AuditChainSegment, AuditEntityType, AuditLog, AuditLogEntry};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use uuid::Uuid;
@@ -8,6 +9,14 @@ use uuid::Uuid;
 pub enum AuditLogServiceError {
     #[error("Repository error: {0}")]
     RepositoryError(String),
+    #[error("Audit log not found: {0}")]
+    NotFound(Uuid),
+    #[error("Audit chain broken at entry {audit_log_id} (sequence {sequence_number}): {reason}")]
+    BrokenChain {
+        audit_log_id: Uuid,
+        sequence_number: i64,
+        reason: String,
+    },
 }
 
 pub type AuditLogServiceResult<T> = Result<T, AuditLogServiceError>;
@@ -19,4 +28,28 @@ pub trait AuditLogService: Send + Sync {
         updated_by_person_id: Uuid,
     ) -> AuditLogServiceResult<AuditLog>;
     async fn find_audit_log_by_id(&self, id: Uuid) -> AuditLogServiceResult<Option<AuditLog>>;
+    /// Changes to the entity within `[from, to)`, newest first. Pages start at 1
+    /// and hold at most 500 entries.
+    async fn find_entity_history(
+        &self,
+        entity_type: AuditEntityType,
+        entity_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        page: i32,
+        page_size: i32,
+    ) -> AuditLogServiceResult<Vec<AuditLogEntry>>;
+    /// Changes made by the person within `[from, to)`, newest first. Pages start
+    /// at 1 and hold at most 500 entries.
+    async fn find_actor_history(
+        &self,
+        person_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        page: i32,
+        page_size: i32,
+    ) -> AuditLogServiceResult<Vec<AuditLogEntry>>;
+    /// The chain from `from_id` to `to_id`, both included, for handing to
+    /// auditors. Fails with BrokenChain unless every link verifies.
+    async fn export_verified_segment(&self, from_id: Uuid, to_id: Uuid) -> AuditLogServiceResult<AuditChainSegment>;
 }
\ No newline at end of file
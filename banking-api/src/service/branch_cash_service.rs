@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{
+        BranchCashCeiling, BranchCashCeilingReport, BranchCashPosition, CashTransferAgingReport,
+        CashTransferRequest, VaultAdjustment,
+    },
+};
+
+/// Branch vault cash against insurance ceilings, and the cash shipments
+/// moving excess cash between branches and the central vault
+#[async_trait]
+pub trait BranchCashService: Send + Sync {
+    /// Create or replace the ceiling of the branch
+    async fn set_cash_ceiling(&self, ceiling: BranchCashCeiling) -> BankingResult<BranchCashCeiling>;
+    async fn find_cash_ceiling(&self, agency_branch_id: Uuid) -> BankingResult<Option<BranchCashCeiling>>;
+
+    async fn get_branch_cash_position(&self, agency_branch_id: Uuid) -> BankingResult<BranchCashPosition>;
+
+    /// EOD check: flag branches above their ceiling and request a transfer of
+    /// the excess not already covered by a requested transfer
+    async fn check_cash_ceilings(&self, processing_date: NaiveDate) -> BankingResult<BranchCashCeilingReport>;
+
+    /// Request a transfer to another branch, or to the central vault when
+    /// `to_agency_branch_id` is None
+    async fn request_cash_transfer(
+        &self,
+        from_agency_branch_id: Uuid,
+        to_agency_branch_id: Option<Uuid>,
+        amount: Decimal,
+        requested_by_person_id: Uuid,
+    ) -> BankingResult<CashTransferRequest>;
+
+    /// First call records the authorization; a second, different person
+    /// dispatches the cash and posts the sending branch's vault adjustment
+    async fn authorize_dispatch(&self, transfer_id: Uuid, person_id: Uuid) -> BankingResult<CashTransferRequest>;
+
+    /// First call records the confirmation; a second, different person
+    /// completes the transfer and posts the receiving vault adjustment
+    async fn confirm_receipt(&self, transfer_id: Uuid, person_id: Uuid) -> BankingResult<CashTransferRequest>;
+
+    async fn cancel_cash_transfer(&self, transfer_id: Uuid, person_id: Uuid, reason: &str) -> BankingResult<CashTransferRequest>;
+
+    async fn find_cash_transfer_by_id(&self, transfer_id: Uuid) -> BankingResult<Option<CashTransferRequest>>;
+    async fn find_vault_adjustments(&self, transfer_id: Uuid) -> BankingResult<Vec<VaultAdjustment>>;
+
+    /// Requested and in-transit transfers with their age
+    async fn get_open_transfer_aging(&self, as_of: NaiveDate) -> BankingResult<CashTransferAgingReport>;
+}
@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{BundleClosureOutcome, BundleView, ProductBundle},
+};
+
+/// Product bundles: linked accounts opened together with shared pricing
+#[async_trait]
+pub trait BundleService: Send + Sync {
+    async fn create_bundle(&self, bundle: ProductBundle) -> BankingResult<ProductBundle>;
+    async fn find_bundle_by_code(&self, bundle_code: &str) -> BankingResult<Option<ProductBundle>>;
+
+    /// Open the component accounts in configuration order as one compensated
+    /// orchestration: a failing opening closes the accounts already opened and
+    /// cancels the bundle
+    async fn open_bundle(
+        &self,
+        customer_id: Uuid,
+        bundle_code: &str,
+        currency: &str,
+        domicile_agency_branch_id: Uuid,
+        opened_by_person_id: Uuid,
+    ) -> BankingResult<BundleView>;
+
+    /// Withdraw the bundle pricing that depends on a closed account, and
+    /// dissolve the bundle when a required component or all but one account closed.
+    /// Returns None when the account is not linked to an active bundle.
+    async fn handle_account_closure(
+        &self,
+        account_id: Uuid,
+        closed_by_person_id: Uuid,
+    ) -> BankingResult<Option<BundleClosureOutcome>>;
+
+    /// Newest first
+    async fn find_bundles_by_customer(&self, customer_id: Uuid) -> BankingResult<Vec<BundleView>>;
+    async fn find_bundle_view(&self, account_bundle_id: Uuid) -> BankingResult<BundleView>;
+}
@@ -11,6 +11,7 @@ use crate::{
         CasaAccountSummary, OverdraftProcessingJob, OverdraftLimitAdjustment,
         CasaTransactionValidation, InterestPostingRecord, InterestType,
         CompoundingFrequency, ReviewFrequency, CreateOverdraftFacilityRequest,
+        AccountOverdraftStatus, OverdraftInterestAccrual,
         transaction::TransactionType
     },
 };
@@ -59,6 +60,13 @@ pub trait CasaService: Send + Sync {
         &self,
         account_id: Uuid,
     ) -> BankingResult<Option<OverdraftFacility>>;
+
+    
+    /// Whether the account is overdrawn within or beyond its limit, and the share of the limit used
+    async fn get_overdraft_status(
+        &self,
+        account_id: Uuid,
+    ) -> BankingResult<AccountOverdraftStatus>;
     
     /// Request overdraft limit adjustment
     async fn request_overdraft_limit_adjustment(
@@ -121,6 +129,17 @@ pub trait CasaService: Send + Sync {
         account_id: Uuid,
         calculation_date: NaiveDate,
     ) -> BankingResult<OverdraftInterestCalculation>;
+
+    
+    /// Accrue the day's debit interest, the parts within and beyond the limit at
+    /// the product's authorized and unauthorized rates, and raise a compliance
+    /// alert once the account has been beyond its limit for the configured days.
+    /// None when the account is not overdrawn and was not the day before.
+    async fn accrue_overdraft_interest(
+        &self,
+        account_id: Uuid,
+        accrual_date: NaiveDate,
+    ) -> BankingResult<Option<OverdraftInterestAccrual>>;
     
     /// Get overdraft utilization history for interest calculation
     async fn get_overdraft_utilization_history(
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::{
+    domain::{ChannelReconciliationResult, Discrepancy, ExternalSettlementEntry},
+    error::BankingResult,
+};
+
+/// Settlement reconciliation of a channel's transactions against the file
+/// sent by the external network
+#[async_trait]
+pub trait ChannelReconciliationService: Send + Sync {
+    /// Match the external entries against the channel's posted and pending
+    /// transactions valued on the settlement date, by reference then amount,
+    /// and save the report with its discrepancies. A report with discrepancies
+    /// requires manual review; one without is completed.
+    async fn reconcile_channel(
+        &self,
+        channel_id: Uuid,
+        settlement_date: NaiveDate,
+        external_entries: Vec<ExternalSettlementEntry>,
+    ) -> BankingResult<ChannelReconciliationResult>;
+
+    /// Discrepancies still open across all reports of the channel, oldest first
+    async fn get_unresolved_discrepancies(&self, channel_id: Uuid) -> BankingResult<Vec<Discrepancy>>;
+
+    /// Close a discrepancy with the resolution given; the report completes
+    /// when its last discrepancy is resolved
+    async fn resolve_discrepancy(&self, discrepancy_id: Uuid, resolution: &str, resolved_by: Uuid) -> BankingResult<Discrepancy>;
+}
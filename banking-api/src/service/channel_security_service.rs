@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{ChannelRestriction, ChannelSecurityEvent, SecurityEventOutcome},
+};
+
+/// Ingestion of channel security events and the restrictions they trigger
+#[async_trait]
+pub trait ChannelSecurityService: Send + Sync {
+    /// Record an event and apply the security velocity rules of the monitoring rules
+    async fn record_security_event(&self, event: ChannelSecurityEvent) -> BankingResult<SecurityEventOutcome>;
+
+    /// Most recent events of a customer, newest first
+    async fn find_recent_events_for_customer(&self, customer_id: Uuid, limit: i64) -> BankingResult<Vec<ChannelSecurityEvent>>;
+
+    /// Manually restrict a channel; without expiry the restriction holds until lifted
+    async fn restrict_channel(
+        &self,
+        customer_id: Uuid,
+        channel_id: Uuid,
+        reason: HeaplessString<255>,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> BankingResult<ChannelRestriction>;
+    async fn lift_restriction(&self, restriction_id: Uuid, lifted_by_person_id: Uuid) -> BankingResult<ChannelRestriction>;
+
+    /// Restrictions in force at the given time; expired ones are ignored
+    async fn find_active_restrictions(&self, customer_id: Uuid, as_of: DateTime<Utc>) -> BankingResult<Vec<ChannelRestriction>>;
+    async fn is_channel_restricted(&self, customer_id: Uuid, channel_id: Uuid, as_of: DateTime<Utc>) -> BankingResult<bool>;
+
+    /// Delete events older than the retention period; returns the number deleted
+    async fn purge_expired_events(&self, as_of: DateTime<Utc>) -> BankingResult<u64>;
+}
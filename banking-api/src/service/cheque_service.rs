@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{Cheque, ChequeBook},
+};
+
+/// Service for cheque books and the clearing lifecycle of individual cheques.
+#[async_trait]
+pub trait ChequeService: Send + Sync {
+    /// Issue a cheque book on an active current account. Serial numbers are
+    /// allocated as one consecutive range.
+    async fn request_cheque_book(&self, account_id: Uuid, leaves: u32, requested_by_person_id: Uuid) -> BankingResult<ChequeBook>;
+
+    /// Record a cheque presented for payment and post the debit.
+    ///
+    /// Stopped, duplicate and unknown cheques are rejected with
+    /// `ChequeStopped`, `DuplicateChequePresentment` and `ChequeNotFound`.
+    /// A cheque without sufficient available funds is returned as `Bounced`
+    /// with its return reason set.
+    async fn record_cheque_presented(
+        &self,
+        account_id: Uuid,
+        serial_number: i64,
+        amount: Decimal,
+        channel_id: HeaplessString<50>,
+    ) -> BankingResult<Cheque>;
+
+    /// Place a stop-payment instruction and charge the product's stop fee
+    async fn stop_cheque(
+        &self,
+        account_id: Uuid,
+        serial_number: i64,
+        reason: HeaplessString<200>,
+        stopped_by_person_id: Uuid,
+    ) -> BankingResult<Cheque>;
+
+    /// Issued cheques not yet presented
+    async fn get_outstanding_cheques(&self, account_id: Uuid) -> BankingResult<Vec<Cheque>>;
+}
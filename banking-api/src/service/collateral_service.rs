@@ -7,7 +7,7 @@ use crate::domain::{
     Collateral, CollateralAlert, CollateralEnforcement, CollateralPledge, CollateralPortfolioSummary,
     CollateralValuation, ConcentrationAnalysis, RiskDistribution, ValuationStatusSummary,
     ComplianceSummary, CovenantCompliance, AlertSeverity, EnforcementMethod, CollateralType, 
-    CollateralRiskRating,
+    CollateralRiskRating, ExchangeRate,
 };
 
 /// Service for managing collateral assets including pledges, valuations, monitoring, and enforcement
@@ -56,6 +56,10 @@ pub trait CollateralService: Send + Sync {
     /// Find collaterals with overdue valuations
     async fn get_overdue_valuations(&self, reference_date: NaiveDate) -> Result<Vec<Collateral>, String>;
     
+    /// Record a valuation in the collateral's currency; unless an earlier-dated
+    /// one, it becomes the collateral's current market value
+    async fn record_valuation(&self, valuation: CollateralValuation) -> Result<CollateralValuation, String>;
+    
     /// Update collateral market value based on latest valuation
     async fn update_market_value(&self, collateral_id: Uuid, new_value: Decimal, valuation_date: NaiveDate, updated_by_person_id: Uuid) -> Result<(), String>;
 
@@ -93,6 +97,13 @@ pub trait CollateralService: Send + Sync {
     /// Update covenant compliance status for a pledge
     async fn update_covenant_compliance(&self, pledge_id: Uuid, compliance: CovenantCompliance) -> Result<(), String>;
     
+    /// End-of-day check of loan-to-value: outstanding principal of each collateralized
+    /// loan over the latest valuations of its pledged collaterals. Loans above
+    /// `max_ltv_percentage` get an LTV breach alert on each collateral without one
+    /// still open. Valuations in another currency than the loan are converted with
+    /// `rates`; loans lacking a rate are skipped. Returns the alerts raised.
+    async fn detect_ltv_breaches(&self, max_ltv_percentage: Decimal, rates: &[ExchangeRate]) -> Result<Vec<CollateralAlert>, String>;
+    
     /// Calculate available collateral value for additional pledging
     async fn calculate_available_value(&self, collateral_id: Uuid) -> Result<Decimal, String>;
 
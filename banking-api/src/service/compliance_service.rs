@@ -5,7 +5,8 @@ use uuid::Uuid;
 use crate::{
     domain::{
         Customer, Transaction, KycResult, ScreeningResult, MonitoringResult, 
-        SarData, UboVerificationResult, VerificationStatus
+        SarData, SarStatus, UboVerificationResult, VerificationStatus, RiskRatingRecalculation,
+        compliance::{SanctionsMatch, ScreeningType},
     },
     error::BankingResult,
 };
@@ -28,16 +29,44 @@ pub trait ComplianceService: Send + Sync {
     #[deprecated(note = "Use generate_sar_data with reason_id instead")]
     async fn generate_sar_data_legacy(&self, customer_id: Uuid, reason: String) -> BankingResult<SarData>;
     
+    /// Draft a SAR covering the alert, snapshotting its customer, account and
+    /// transaction; the alert is escalated
+    async fn create_sar_from_alert(&self, alert_id: Uuid, narrative: HeaplessString<1000>, created_by: Uuid) -> BankingResult<SarData>;
+
+    /// Add another alert of the same customer to a draft SAR and escalate it
+    async fn add_alert_to_sar(&self, sar_id: Uuid, alert_id: Uuid, updated_by: Uuid) -> BankingResult<SarData>;
+
+    async fn update_sar_narrative(&self, sar_id: Uuid, narrative: HeaplessString<1000>) -> BankingResult<SarData>;
+
+    /// Submit a draft SAR for filing; it can no longer be edited
+    async fn submit_sar(&self, sar_id: Uuid, submitted_by: Uuid) -> BankingResult<SarData>;
+
+    /// SARs generated between the dates inclusive, optionally in one status, newest first
+    async fn list_sars(&self, status: Option<SarStatus>, from_date: chrono::NaiveDate, to_date: chrono::NaiveDate) -> BankingResult<Vec<SarData>>;
+
     /// Ultimate Beneficial Owner verification
     async fn verify_ubo_chain(&self, corporate_customer_id: Uuid) -> BankingResult<UboVerificationResult>;
     async fn update_ubo_status(&self, ubo_link_id: Uuid, status: VerificationStatus) -> BankingResult<()>;
 
+    /// Walk the active ownership links above a corporate customer: check each
+    /// entity's owners hold at most 100%, detect ownership cycles and multiply
+    /// shares along each path to find the natural persons owning the customer.
+    /// The outcome is recorded as the customer's UBO verification result.
+    async fn validate_ubo_structure(&self, corporate_customer_id: Uuid) -> BankingResult<UboStructureReport>;
+
     /// Batch compliance screening for efficiency
     async fn batch_screen_customers(&self, customer_ids: Vec<Uuid>) -> BankingResult<Vec<ScreeningResult>>;
 
+    /// Screen customers in bulk against one screening type, skipping those
+    /// screened for it within the configured freshness window
+    async fn screen_customers_batch(&self, customer_ids: &[Uuid], screening_type: ScreeningType) -> BankingResult<BatchScreeningSummary>;
+
     /// Get compliance alerts for review
     async fn get_pending_compliance_alerts(&self) -> BankingResult<Vec<crate::domain::ComplianceAlert>>;
 
+    /// Raise a compliance alert detected outside transaction monitoring
+    async fn raise_alert(&self, alert: crate::domain::ComplianceAlert) -> BankingResult<crate::domain::ComplianceAlert>;
+
     /// Update compliance alert status
     async fn update_alert_status(&self, alert_id: Uuid, status: crate::domain::AlertStatus, updated_by_person_id: Uuid) -> BankingResult<()>;
 
@@ -53,6 +82,13 @@ pub trait ComplianceService: Send + Sync {
     /// Update customer risk profile
     async fn update_risk_profile(&self, customer_id: Uuid, risk_factors: Vec<HeaplessString<100>>) -> BankingResult<()>;
 
+    /// Score the customer from the configured risk factors, record the score and
+    /// update the customer's rating. Becoming High opens enhanced due diligence.
+    async fn recalculate_risk_rating(&self, customer_id: Uuid) -> BankingResult<RiskRatingRecalculation>;
+
+    /// Recalculate every active customer not scored within `review_interval_days`
+    async fn recalculate_all_due(&self, review_interval_days: i64) -> BankingResult<RiskRecalculationSummary>;
+
     /// Get transaction monitoring rules
     async fn get_monitoring_rules(&self) -> BankingResult<crate::domain::MonitoringRules>;
 
@@ -60,6 +96,70 @@ pub trait ComplianceService: Send + Sync {
     async fn update_monitoring_rules(&self, rules: crate::domain::MonitoringRules) -> BankingResult<()>;
 }
 
+/// Matches a customer against the lists behind a screening type
+#[async_trait]
+pub trait SanctionsMatcher: Send + Sync {
+    /// Candidate list entries for the customer; empty when screening is clear
+    async fn find_matches(&self, customer: &Customer, screening_type: ScreeningType) -> BankingResult<Vec<SanctionsMatch>>;
+}
+
+/// Outcome counts of a batch screening run
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BatchScreeningSummary {
+    pub screened: i64,
+    /// Customers screened within the freshness window, left alone
+    pub skipped: i64,
+    /// Screened customers with at least one match
+    pub hits: i64,
+    pub alerts_created: i64,
+}
+
+/// Outcome counts of a risk rating recalculation run
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RiskRecalculationSummary {
+    pub recalculated: i64,
+    pub rating_changes: i64,
+    pub edd_workflows_opened: i64,
+    /// Customers whose recalculation failed; the run carries on without them
+    pub failed: i64,
+}
+
+/// Ownership of a corporate customer as found by UBO structure validation
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct UboStructureReport {
+    pub corporate_customer_id: Uuid,
+    /// Natural persons with a share in the customer, largest first
+    pub owners: Vec<UboOwnership>,
+    pub over_allocated_entities: Vec<UboOverAllocation>,
+    /// Customer ids around each ownership cycle, starting and ending with the same entity
+    pub ownership_cycles: Vec<Vec<Uuid>>,
+    /// No over-allocated entity and no cycle
+    pub is_valid: bool,
+    pub validated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Share of a natural person in the validated customer, in percent
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct UboOwnership {
+    pub beneficiary_customer_id: Uuid,
+    /// Held in the validated customer itself
+    pub direct_percentage: rust_decimal::Decimal,
+    /// Held through intermediate entities, the product of the shares along each path
+    pub indirect_percentage: rust_decimal::Decimal,
+    pub total_percentage: rust_decimal::Decimal,
+    /// Total at or above the configured reporting threshold
+    pub is_reportable: bool,
+    /// Links through which the person holds their shares
+    pub ubo_link_ids: Vec<Uuid>,
+}
+
+/// Entity whose owners together hold more than 100%
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct UboOverAllocation {
+    pub entity_customer_id: Uuid,
+    pub total_percentage: rust_decimal::Decimal,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ComplianceReport {
     pub report_id: Uuid,
@@ -3,9 +3,11 @@ use uuid::Uuid;
 
 use crate::{
     domain::{
-        Customer, CustomerAudit, CustomerDocument, CustomerPortfolio, CustomerStatus, RiskRating,
+        Customer, CustomerAudit, CustomerDocument, CustomerPortfolio, CustomerSearchCriteria, CustomerStatus,
+        ReasonId, RiskRating,
     },
     error::BankingResult,
+    views::customer_views::CustomerPortfolioView,
 };
 
 #[async_trait]
@@ -23,8 +25,12 @@ pub trait CustomerService: Send + Sync {
     async fn update_risk_rating(&self, customer_id: Uuid, risk_rating: RiskRating, authorized_by: Uuid) -> BankingResult<()>;
     
     /// Status changes with cascade effects and reason ID validation
-    async fn update_customer_status(&self, customer_id: Uuid, status: CustomerStatus, reason_id: Uuid, additional_details: Option<&str>) -> BankingResult<()>;
+    async fn update_customer_status(&self, customer_id: Uuid, status: CustomerStatus, reason_id: ReasonId, additional_details: Option<&str>) -> BankingResult<()>;
     
+    /// Fold a duplicate record into the surviving customer; the duplicate is kept, marked Merged
+    /// @param merged_by - References Person.person_id
+    async fn merge_customers(&self, survivor_id: Uuid, duplicate_id: Uuid, reason_id: ReasonId, merged_by: Uuid) -> BankingResult<()>;
+
     /// Legacy method - deprecated, use update_customer_status with reason_id instead
     #[deprecated(note = "Use update_customer_status with reason_id instead")]
     async fn update_customer_status_legacy(&self, customer_id: Uuid, status: CustomerStatus, reason: String) -> BankingResult<()>;
@@ -32,6 +38,13 @@ pub trait CustomerService: Send + Sync {
     /// 360-degree customer view
     async fn get_customer_portfolio(&self, customer_id: Uuid) -> BankingResult<CustomerPortfolio>;
 
+    /// Customer 360 view with accounts, active holds, open workflows, recent alerts and entity references.
+    /// Fails with CustomerNotFound when the customer does not exist
+    async fn get_customer_360(&self, customer_id: Uuid) -> BankingResult<CustomerPortfolioView>;
+
+    /// Find customers matching the criteria, ordered by name
+    async fn search_customers(&self, criteria: CustomerSearchCriteria) -> BankingResult<Vec<Customer>>;
+
     /// Find customers by identity document
     async fn find_customer_by_identity(&self, id_type: crate::domain::IdentityType, id_number: &str) -> BankingResult<Option<Customer>>;
 
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
 use rust_decimal::Decimal;
 use uuid::Uuid;
 
@@ -8,7 +9,9 @@ use crate::{
     domain::{
         CollectionProgram, ProgramStatus, CustomerCollectionProfile, CollectionStatus,
         CollectionRecord, CollectionRecordStatus, CollectionAgent, AgentStatus,
-        CollectionBatch, CollectionMethod
+        CollectionBatch, CollectionMethod, ReconciliationData, CollectionVerification,
+        BiometricData, PhotoEvidence, WitnessInformation, PerformanceAlert,
+        ConnectivityStatus, DeviceComplianceError, DeviceInformation,
     },
 };
 
@@ -89,9 +92,18 @@ pub trait DailyCollectionService: Send + Sync {
     
     // ======== Collection Operations ========
     
-    /// Record a single collection
+    /// Record a single collection. When the program verifies locations, collections
+    /// recorded outside the customer's geo-fence are accepted but held for review.
+    /// Fails with DeviceNonCompliant when the agent's device has not synced within
+    /// the configured window or lacks encryption or its certificate.
     async fn record_collection(&self, collection: CollectionRecord) -> BankingResult<CollectionRecord>;
     
+    /// Record collections captured offline by the mobile app, applying the same device and geo checks
+    async fn sync_collections(&self, collections: Vec<CollectionRecord>) -> BankingResult<Vec<CollectionRecord>>;
+    
+    /// Find collections held for supervisor review after a failed geo check
+    async fn find_geo_review_queue(&self) -> BankingResult<Vec<CollectionRecord>>;
+    
     /// Process collection batch
     async fn process_collection_batch(&self, batch: CollectionBatch) -> BankingResult<CollectionBatch>;
     
@@ -112,6 +124,20 @@ pub trait DailyCollectionService: Send + Sync {
     /// Find collections by customer
     async fn find_collections_by_customer(&self, customer_id: Uuid) -> BankingResult<Vec<CollectionRecord>>;
     
+    /// Find an agent's or customer's collections over a date range, newest
+    /// first, with their verification evidence. `page` starts at 1.
+    #[allow(clippy::too_many_arguments)]
+    async fn find_collection_records(
+        &self,
+        agent_id: Option<Uuid>,
+        customer_id: Option<Uuid>,
+        from: NaiveDate,
+        to: NaiveDate,
+        status: Option<CollectionRecordStatus>,
+        page: i32,
+        page_size: i32,
+    ) -> BankingResult<Vec<CollectionRecordDetail>>;
+    
     /// Find collections by status
     async fn find_collections_by_status(&self, status: CollectionRecordStatus) -> BankingResult<Vec<CollectionRecord>>;
     
@@ -140,6 +166,18 @@ pub trait DailyCollectionService: Send + Sync {
         actual_amount: Decimal,
         reconciled_by_person_id: Uuid,
     ) -> BankingResult<Vec<CollectionRecord>>;
+
+    /// Reconcile cash counted for a batch against its processed collections.
+    /// The batch completes when the variance is within `variance_tolerance`,
+    /// otherwise it requires reconciliation. Fails while any collection in the
+    /// batch is still pending.
+    async fn reconcile_batch(
+        &self,
+        batch_id: Uuid,
+        counted_cash: Decimal,
+        variance_tolerance: Decimal,
+        reconciled_by_person_id: Uuid,
+    ) -> BankingResult<ReconciliationData>;
     
     /// Get collection statistics for period
     async fn get_collection_statistics(
@@ -199,6 +237,36 @@ pub trait DailyCollectionService: Send + Sync {
     /// Find agents by territory
     async fn find_agents_by_territory(&self, territory_id: Uuid) -> BankingResult<Vec<CollectionAgent>>;
     
+    // ======== Device Compliance ========
+    
+    /// Refresh the device's last sync to now, with the battery and connectivity the mobile backend reports
+    async fn update_device_heartbeat(
+        &self,
+        device_id: Uuid,
+        battery_level: Option<f32>,
+        connectivity_status: ConnectivityStatus,
+    ) -> BankingResult<DeviceInformation>;
+    
+    /// Devices of agents who may not record collections, with every condition each fails
+    async fn find_non_compliant_devices(&self) -> BankingResult<Vec<NonCompliantDevice>>;
+    
+    // ======== Performance Alerts ========
+    
+    /// Acknowledge an open alert on behalf of a supervisor
+    async fn acknowledge_alert(&self, alert_id: Uuid, person_id: Uuid) -> BankingResult<PerformanceAlert>;
+    
+    /// Resolve an open alert; alerts requiring resolution must be acknowledged first.
+    /// Resolving frees the alert's slot on the agent's performance metrics.
+    async fn resolve_alert(
+        &self,
+        alert_id: Uuid,
+        person_id: Uuid,
+        resolution_notes: Option<HeaplessString<500>>,
+    ) -> BankingResult<PerformanceAlert>;
+    
+    /// Find the agent's unresolved alerts, oldest first
+    async fn find_open_alerts_by_agent(&self, agent_id: Uuid) -> BankingResult<Vec<PerformanceAlert>>;
+    
     // ======== Route Optimization and Scheduling ========
     
     /// Generate optimal collection routes for agent
@@ -276,6 +344,14 @@ pub struct AgentPerformanceUpdate {
     pub compliance_score: Option<Decimal>,
 }
 
+/// An agent's device failing the compliance checks for recording collections
+#[derive(Debug, Clone)]
+pub struct NonCompliantDevice {
+    pub agent_id: Uuid,
+    pub device: DeviceInformation,
+    pub failures: Vec<DeviceComplianceError>,
+}
+
 /// Agent performance report
 #[derive(Debug, Clone)]
 pub struct AgentPerformanceReport {
@@ -317,6 +393,16 @@ pub struct RouteWaypoint {
     pub special_instructions: Option<String>,
 }
 
+/// Collection record with the verification evidence captured with it
+#[derive(Debug, Clone)]
+pub struct CollectionRecordDetail {
+    pub record: CollectionRecord,
+    pub verification: Option<CollectionVerification>,
+    pub biometric_data: Option<BiometricData>,
+    pub photo_evidence: Option<PhotoEvidence>,
+    pub witness_information: Option<WitnessInformation>,
+}
+
 /// Scheduled collection for agent planning
 #[derive(Debug, Clone)]
 pub struct ScheduledCollection {
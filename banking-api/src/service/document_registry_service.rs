@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{
+        ContentHash, DocumentIntegrityCheck, DocumentLinkKind, DocumentLinkage, DocumentReference,
+        DocumentRegistration, RegisteredDocument,
+    },
+};
+
+/// Registry of uploaded documents keyed by content hash, with the workflows
+/// and customers referencing each document
+#[async_trait]
+pub trait DocumentRegistryService: Send + Sync {
+    /// Register uploaded content. Content already registered returns the
+    /// existing document instead of creating a duplicate.
+    async fn register_document(
+        &self,
+        document_type: &str,
+        content: &[u8],
+        registered_by_person_id: Uuid,
+    ) -> BankingResult<DocumentRegistration>;
+
+    /// Resolve references by content hash, registering unknown content, and
+    /// link the documents to the entity. Returns the references with the
+    /// registry's document ids.
+    async fn attach_documents(
+        &self,
+        entity_kind: DocumentLinkKind,
+        entity_id: Uuid,
+        references: Vec<DocumentReference>,
+        linked_by_person_id: Uuid,
+    ) -> BankingResult<Vec<DocumentReference>>;
+
+    async fn find_document_by_id(&self, document_id: Uuid) -> BankingResult<Option<RegisteredDocument>>;
+    async fn find_linked_documents(&self, entity_kind: DocumentLinkKind, entity_id: Uuid) -> BankingResult<Vec<RegisteredDocument>>;
+
+    /// Compare a hash computed at review with the hash registered at upload
+    async fn verify_document_integrity(&self, document_id: Uuid, supplied_hash: ContentHash) -> BankingResult<DocumentIntegrityCheck>;
+
+    async fn get_document_linkage(&self, document_id: Uuid) -> BankingResult<DocumentLinkage>;
+
+    /// Remove a document no workflow or customer references any more
+    async fn purge_document(&self, document_id: Uuid) -> BankingResult<()>;
+}
@@ -4,6 +4,7 @@ use std::collections::HashMap;
 use uuid::Uuid;
 
 use crate::{
+    domain::{AccountHoldExpiryJob, BranchCashCeilingReport, EodRun, GlSummaryLine, NotificationDuplicateReport, ProvisioningBucket, SegmentEvaluationReport, StatementCycleReport},
     error::BankingResult,
     service::{AccrualReport, CapitalizationReport}
 };
@@ -14,15 +15,35 @@ pub trait EodService: Send + Sync {
     async fn process_daily_interest_accrual(&self) -> BankingResult<EodReport>;
     async fn post_periodic_interest(&self, processing_date: NaiveDate) -> BankingResult<EodReport>;
     
+    /// Overdraft debit interest per CASA account, within and beyond the overdraft limit
+    async fn process_overdraft_interest(&self, processing_date: NaiveDate) -> BankingResult<EodReport>;
+    
+    /// Standing orders due by the run date, posted through the transaction service
+    async fn execute_standing_orders(&self, run_date: NaiveDate) -> BankingResult<EodReport>;
+    
     /// Fee application with product-specific schedules
     async fn apply_periodic_fees(&self, processing_date: NaiveDate) -> BankingResult<EodReport>;
     
     /// Loan management with grace period calculations
     async fn update_delinquent_loans(&self, processing_date: NaiveDate) -> BankingResult<EodReport>;
     
+    /// Days-past-due counters and penalty interest on overdue loan installments past their grace period
+    async fn assess_overdue_penalties(&self, run_date: NaiveDate) -> BankingResult<EodReport>;
+    
+    /// Overdrawn-day tracking for CASA accounts; loans are excluded as they use schedule arrears
+    async fn track_overdrawn_days(&self, processing_date: NaiveDate) -> BankingResult<EodReport>;
+    
+    /// Provisioning exposure per bucket, product and branch from the snapshots of the given date
+    async fn get_provisioning_report(&self, as_of_date: NaiveDate) -> BankingResult<ProvisioningReport>;
+    
     /// Regulatory reporting
     async fn generate_regulatory_reports(&self, processing_date: NaiveDate) -> BankingResult<Vec<RegulatoryReport>>;
     
+    /// Debits and credits of the day's postings per GL code, product, branch and
+    /// currency, saved in place of any earlier summary of the run date. Fails
+    /// with the imbalance when debits and credits differ.
+    async fn generate_gl_summary(&self, run_date: NaiveDate) -> BankingResult<Vec<GlSummaryLine>>;
+    
     /// System maintenance
     async fn reset_daily_counters(&self) -> BankingResult<()>;
     async fn archive_completed_workflows(&self) -> BankingResult<()>;
@@ -33,9 +54,24 @@ pub trait EodService: Send + Sync {
     /// Complete EOD processing workflow
     async fn run_eod_processing(&self, processing_date: NaiveDate) -> BankingResult<EodProcessingResult>;
 
+    /// Resumable EOD run, checkpointing account-paged steps after every page.
+    /// A completed run of the date is returned as it is unless `force` is set;
+    /// an unfinished one is resumed.
+    async fn run_eod(&self, run_date: NaiveDate, force: bool) -> BankingResult<EodRun>;
+
+    /// Continue a failed run, skipping completed steps and restarting a
+    /// partially finished one after its last checkpoint
+    async fn resume(&self, run_date: NaiveDate) -> BankingResult<EodRun>;
+
     /// Dormancy management from enhancements
     async fn process_dormancy_candidates(&self, processing_date: NaiveDate) -> BankingResult<DormancyReport>;
 
+    /// Inactivity fees of dormant accounts past their product's threshold, at
+    /// most once per account and monthly cycle, and warnings to the owners of
+    /// those within the warning lead time. Accounts under a judicial lien are
+    /// not charged.
+    async fn assess_inactivity_fees(&self, run_date: NaiveDate) -> BankingResult<InactivityFeeReport>;
+
     /// Account maintenance from enhancements
     async fn process_pending_closures(&self, processing_date: NaiveDate) -> BankingResult<MaintenanceReport>;
 
@@ -99,16 +135,62 @@ pub struct EodProcessingResult {
     pub processing_date: NaiveDate,
     pub started_at: chrono::DateTime<chrono::Utc>,
     pub completed_at: chrono::DateTime<chrono::Utc>,
+    /// Holds expired by the end of the day, their amounts back in available balance
+    pub hold_expiry: AccountHoldExpiryJob,
+    /// Standing orders run, with unfunded occurrences as warnings
+    pub standing_orders: EodReport,
     pub interest_accrual: AccrualReport,
     pub interest_capitalization: CapitalizationReport,
+    /// CASA accounts overdrawn, with those long enough beyond their limit as warnings
+    pub overdraft_interest: EodReport,
     pub fee_processing: EodReport,
     pub loan_updates: EodReport,
+    /// Loans past due with the penalty interest charged for the day
+    pub overdue_penalties: EodReport,
+    pub overdrawn_tracking: EodReport,
     pub dormancy_processing: DormancyReport,
+    /// Inactivity fees charged and warnings queued for dormant accounts
+    pub inactivity_fees: InactivityFeeReport,
     pub maintenance_processing: MaintenanceReport,
     pub regulatory_reports: Vec<RegulatoryReport>,
+    pub segment_evaluation: SegmentEvaluationReport,
+    /// Present on statement cycle end dates only
+    pub statement_cycle: Option<StatementCycleReport>,
+    /// Branches above their vault cash ceiling and the transfers requested for the excess
+    pub cash_ceiling_check: BranchCashCeilingReport,
+    /// Balanced GL summary of the day's postings
+    pub gl_summary: Vec<GlSummaryLine>,
+    /// Customer notices not queued again because the step had already run for the date
+    pub notification_duplicates: NotificationDuplicateReport,
     pub overall_status: EodReportStatus,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ProvisioningReport {
+    pub as_of_date: NaiveDate,
+    pub exposures: Vec<ProvisioningExposure>,
+    /// Accounts whose bucket changed on `as_of_date`
+    pub bucket_transitions: Vec<ProvisioningBucketTransition>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ProvisioningExposure {
+    pub bucket: ProvisioningBucket,
+    pub product_id: Uuid,
+    pub domicile_agency_branch_id: Uuid,
+    pub account_count: i32,
+    /// Sum of the absolute negative closing balances
+    pub exposure: rust_decimal::Decimal,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ProvisioningBucketTransition {
+    pub account_id: Uuid,
+    pub from_bucket: ProvisioningBucket,
+    pub to_bucket: ProvisioningBucket,
+    pub consecutive_overdrawn_days: i32,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct DormancyReport {
     pub processing_date: NaiveDate,
@@ -116,6 +198,24 @@ pub struct DormancyReport {
     pub accounts_marked_dormant: i32,
     pub accounts_by_product: HashMap<String, i32>,
     pub notifications_generated: i32,
+    /// Notices already queued by an earlier run of the step for this date
+    pub notifications_suppressed: i32,
+    pub errors_encountered: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct InactivityFeeReport {
+    pub processing_date: NaiveDate,
+    pub accounts_evaluated: i32,
+    pub fees_charged: i32,
+    pub amount_charged: rust_decimal::Decimal,
+    /// Accounts past the threshold but already charged in the cycle or with nothing to charge
+    pub fees_not_charged: i32,
+    /// Accounts past the threshold left alone because of a judicial lien
+    pub skipped_for_legal_hold: i32,
+    pub warnings_generated: i32,
+    /// Warnings already queued for the warning period by an earlier run
+    pub warnings_suppressed: i32,
     pub errors_encountered: Vec<String>,
 }
 
@@ -8,7 +8,7 @@ use crate::{
     domain::{
         FeeApplication, FeeApplicationStatus, FeeTriggerEvent,
         FeeProcessingJob, FeeJobType, ProductFeeSchedule, ProductFee,
-        FeeWaiver, FeeCategory,
+        FeeWaiver, FeeCategory, FeeItem, ChannelFeeTier, CurrencyCode, ReasonId,
         fee::FeeType,
     },
 };
@@ -93,6 +93,18 @@ pub trait FeeService: Send + Sync {
         fee_categories: Vec<FeeCategory>,
     ) -> BankingResult<Vec<FeeApplication>>;
     
+    /// Charge the inactivity fee of a dormant account for the monthly cycle
+    /// starting on `cycle_start`, priced by the channel fee item. Only what
+    /// the balance covers is charged. Returns None when the account was
+    /// already charged in the cycle or its balance covers nothing.
+    async fn apply_inactivity_fee(
+        &self,
+        account_id: Uuid,
+        fee_item_id: Uuid,
+        cycle_start: NaiveDate,
+        run_date: NaiveDate,
+    ) -> BankingResult<Option<FeeApplication>>;
+    
     // ============================================================================
     // FEE WAIVER MANAGEMENT
     // ============================================================================
@@ -114,6 +126,16 @@ pub trait FeeService: Send + Sync {
         notes: Option<String>,
     ) -> BankingResult<FeeWaiver>;
     
+    /// Waive a pending fee application before it is posted. The approver
+    /// cannot be the person who applied the fee; waiving a waived fee
+    /// returns it unchanged.
+    async fn waive_fee(
+        &self,
+        fee_application_id: Uuid,
+        reason_id: ReasonId,
+        approved_by: Uuid,
+    ) -> BankingResult<FeeApplication>;
+    
     /// Automatically waive fees based on business rules
     async fn apply_automatic_waivers(
         &self,
@@ -164,6 +186,24 @@ pub trait FeeService: Send + Sync {
         base_amount: Decimal,
     ) -> BankingResult<Decimal>;
     
+    /// Calculate the fee a channel fee item charges on an amount, rounded to
+    /// the minor units of the currency. Tiered items are priced from their
+    /// saved tiers.
+    async fn calculate_fee(
+        &self,
+        fee_item: &FeeItem,
+        amount: Decimal,
+        currency: &CurrencyCode,
+    ) -> BankingResult<Decimal>;
+    
+    /// Save a channel fee item with its tiers; the tiers of a tiered item
+    /// must be contiguous and non-overlapping
+    async fn save_fee_item(
+        &self,
+        fee_item: FeeItem,
+        tiers: Vec<ChannelFeeTier>,
+    ) -> BankingResult<FeeItem>;
+    
     /// Check fee conditions and eligibility
     async fn check_fee_conditions(
         &self,
@@ -220,6 +260,16 @@ pub trait FeeService: Send + Sync {
         reversed_by: String,
     ) -> BankingResult<FeeApplication>;
     
+    /// Reverse an applied fee by posting a compensating credit linked to the
+    /// original fee transaction. The person reversing cannot be the one who
+    /// applied the fee; reversing twice returns the existing reversal.
+    async fn reverse_fee(
+        &self,
+        fee_application_id: Uuid,
+        reason_id: ReasonId,
+        performed_by: Uuid,
+    ) -> BankingResult<FeeApplication>;
+    
     /// Bulk reverse fees for an account (e.g., account closure)
     async fn bulk_reverse_account_fees(
         &self,
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::FinancialPositionView,
+};
+
+/// Service consolidating a customer's assets and liabilities for credit decisions.
+#[async_trait]
+pub trait FinancialPositionService: Send + Sync {
+    /// Deposits, loan exposure, guarantees given, holds and overdraft use over
+    /// all accounts the customer owns, per currency and converted into
+    /// `base_currency` with the latest rates as of today
+    async fn get_customer_financial_position(&self, customer_id: Uuid, base_currency: &str) -> BankingResult<FinancialPositionView>;
+}
@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{GuarantorExposure, LoanGuarantor},
+};
+
+/// Service for managing loan guarantors and their exposure.
+#[async_trait]
+pub trait GuarantorService: Send + Sync {
+    /// Attach a guarantor to a loan account. Customer guarantors must not be
+    /// borrowers on the loan and must pass KYC.
+    async fn add_guarantor(&self, guarantor: LoanGuarantor) -> BankingResult<LoanGuarantor>;
+
+    /// Release a guarantor. Blocked if the loan's product requires a guarantor
+    /// and this is the last active one.
+    async fn release_guarantor(&self, guarantor_link_id: Uuid, released_by_person_id: Uuid) -> BankingResult<LoanGuarantor>;
+
+    /// All guarantors of a loan, released ones included
+    async fn find_guarantors_for_loan(&self, loan_account_id: Uuid) -> BankingResult<Vec<LoanGuarantor>>;
+
+    /// Outstanding principal of all loans guaranteed by the customer or person,
+    /// capped at each guarantee amount
+    async fn get_guarantor_exposure(&self, guarantor_id: Uuid) -> BankingResult<GuarantorExposure>;
+}
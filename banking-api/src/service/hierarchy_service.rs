@@ -3,7 +3,7 @@ use rust_decimal::Decimal;
 use uuid::Uuid;
 
 use crate::{
-    domain::{TransactionValidationResult, TerminalLimits, AgentNetwork, AgencyBranch, AgentTerminal},
+    domain::{TransactionValidationResult, TerminalLimits, AgentNetwork, AgencyBranch, AgentTerminal, BranchRollup},
     error::BankingResult,
 };
 
@@ -62,6 +62,14 @@ pub trait HierarchyService: Send + Sync {
 
     /// Get network hierarchy (network -> branches -> terminals)
     async fn get_network_hierarchy(&self, network_id: Uuid) -> BankingResult<NetworkHierarchy>;
+
+    /// Account counts and balances of a branch and every branch below it
+    async fn get_branch_rollup(&self, branch_id: Uuid) -> BankingResult<BranchRollup>;
+
+    /// Supervisors of a person, nearest first: the managers of the branch the
+    /// person's terminal belongs to and of each branch above it. A branch
+    /// manager's line starts at the parent branch.
+    async fn get_reporting_line(&self, person_id: Uuid) -> BankingResult<Vec<Uuid>>;
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
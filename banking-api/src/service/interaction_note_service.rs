@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{InteractionNote, InteractionNoteRequest, NoteEntityKind, NoteReaderScope},
+};
+
+/// Staff notes and interaction log on customers and accounts. Reads only
+/// return the notes the reader's scope may see.
+#[async_trait]
+pub trait InteractionNoteService: Send + Sync {
+    /// Record a note, or a correction when `corrects_note_id` is set
+    async fn add_note(&self, request: InteractionNoteRequest, author_person_id: Uuid, scope: NoteReaderScope) -> BankingResult<InteractionNote>;
+
+    /// Notes on the entity visible in `scope`, newest first
+    async fn get_notes(&self, entity_kind: NoteEntityKind, entity_id: Uuid, scope: NoteReaderScope) -> BankingResult<Vec<InteractionNote>>;
+
+    /// Open follow-ups of the author, earliest due first
+    async fn pending_follow_ups(&self, author_person_id: Uuid) -> BankingResult<Vec<InteractionNote>>;
+
+    /// Close a follow-up; only its author may
+    async fn complete_follow_up(&self, note_id: Uuid, person_id: Uuid) -> BankingResult<InteractionNote>;
+
+    /// Morning sweep: remind each author of their open follow-ups due on or
+    /// before `date`. Returns the notes reminded of.
+    async fn notify_due_follow_ups(&self, date: NaiveDate) -> BankingResult<Vec<InteractionNote>>;
+}
+
+/// Delivers messages to a member of staff, e.g. through the staff inbox
+#[async_trait]
+pub trait StaffNotifier: Send + Sync {
+    async fn notify_staff(&self, person_id: Uuid, subject: &str, body: &str) -> BankingResult<()>;
+}
@@ -1,8 +1,10 @@
 use async_trait::async_trait;
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
 use rust_decimal::Decimal;
 use uuid::Uuid;
 
+use crate::domain::AccrualOptions;
 use crate::error::BankingResult;
 
 #[async_trait]
@@ -20,8 +22,18 @@ pub trait InterestService: Send + Sync {
     async fn calculate_accrued_interest(&self, account_id: Uuid, from_date: NaiveDate, to_date: NaiveDate) -> BankingResult<Decimal>;
     async fn should_post_interest(&self, account_id: Uuid, date: NaiveDate) -> BankingResult<bool>;
 
-    /// Daily interest accrual for EOD processing
-    async fn accrue_daily_interest(&self, processing_date: NaiveDate) -> BankingResult<AccrualReport>;
+    /// Interest the account accrues on `date` under its product's day count convention,
+    /// rounded half to even to the minor units of the account currency
+    async fn calculate_daily_accrual(&self, account_id: Uuid, date: NaiveDate) -> BankingResult<Decimal>;
+
+    /// Move the interest accrued over the period ending `period_end` into the balance
+    /// and reset the accrual, in one transaction. Returns the amount posted; a
+    /// negative amount is a custody fee debited.
+    async fn post_accrued_interest(&self, account_id: Uuid, period_end: NaiveDate) -> BankingResult<Decimal>;
+
+    /// Daily interest accrual for EOD processing. Accounts are processed in chunks,
+    /// concurrently and each in its own transaction; an account is accrued at most once per date.
+    async fn accrue_daily_interest(&self, processing_date: NaiveDate, options: AccrualOptions) -> BankingResult<AccrualReport>;
 
     /// Interest capitalization for eligible accounts
     async fn capitalize_interest(&self, processing_date: NaiveDate) -> BankingResult<CapitalizationReport>;
@@ -34,23 +46,71 @@ pub trait InterestService: Send + Sync {
 
     /// Check if account should accrue interest on given date
     async fn should_accrue_interest(&self, account_id: Uuid, date: NaiveDate) -> BankingResult<bool>;
+
+    /// Interest figures for customer display: year to date and projected interest
+    /// for deposits, paid and remaining interest for loans
+    async fn get_interest_summary(&self, account_id: Uuid) -> BankingResult<InterestSummaryView>;
+
+    /// Adjust accrued interest for a balance change already posted with a value date
+    /// of `from_date`: the accruals of `from_date` up to, not including, `to_date` ran
+    /// without it. Returns the delta added to the accrued interest.
+    async fn recalculate_back_dated_accrual(
+        &self,
+        account_id: Uuid,
+        balance_change: Decimal,
+        from_date: NaiveDate,
+        to_date: NaiveDate,
+    ) -> BankingResult<Decimal>;
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct AccrualReport {
     pub processing_date: NaiveDate,
+    /// Accounts accrued by this run
     pub accounts_processed: i64,
+    /// Accounts skipped because they were already accrued for the date
+    pub accounts_already_accrued: i64,
+    /// Accounts in chunks that failed on every attempt
+    pub accounts_failed: i64,
     pub total_interest_accrued: Decimal,
     pub account_accruals: Vec<AccountAccrual>,
+    /// Per-chunk results in account id order
+    pub chunks: Vec<AccrualChunkSummary>,
+    pub started_at: DateTime<Utc>,
+    pub completed_at: DateTime<Utc>,
+    pub wall_clock_ms: i64,
+    /// Promotions whose budget cap ran out during the run
+    pub exhausted_promotion_ids: Vec<Uuid>,
     pub errors: Vec<String>,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AccrualChunkSummary {
+    pub chunk_index: i64,
+    pub first_account_id: Uuid,
+    pub last_account_id: Uuid,
+    pub accounts_processed: i64,
+    pub accounts_already_accrued: i64,
+    pub total_interest_accrued: Decimal,
+    pub attempts: u32,
+    /// Time spent on the chunk across all attempts
+    pub duration_ms: i64,
+    /// Last error when every attempt failed
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct AccountAccrual {
     pub account_id: Uuid,
+    /// Negative for a custody fee
     pub daily_interest: Decimal,
     pub interest_rate: Decimal,
+    /// Balance the rate applies to; for a custody fee only the part above the threshold
     pub principal_balance: Decimal,
+    /// Promotion the rate came from, if any
+    pub promotion_id: Option<Uuid>,
+    /// Part of `daily_interest` paid by the promotion over the product rate
+    pub bonus_interest: Decimal,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -71,6 +131,93 @@ pub struct CapitalizationResult {
     pub new_balance: Decimal,
 }
 
+/// What a deposit account accrues, labelled apart on statements and summaries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum DepositAccrualKind {
+    CreditInterest,
+    /// Negative interest charged on a balance above the product threshold
+    CustodyFee,
+}
+
+impl DepositAccrualKind {
+    pub fn of(amount: Decimal) -> Self {
+        if amount < Decimal::ZERO {
+            DepositAccrualKind::CustodyFee
+        } else {
+            DepositAccrualKind::CreditInterest
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DepositAccrualKind::CreditInterest => "Credit interest",
+            DepositAccrualKind::CustodyFee => "Custody fee",
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum InterestSummaryView {
+    Deposit(DepositInterestSummary),
+    Loan(LoanInterestSummary),
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DepositInterestSummary {
+    pub account_id: Uuid,
+    pub currency: HeaplessString<3>,
+    pub as_of: NaiveDate,
+    /// Interest posted to the balance since 1 January
+    pub capitalized_year_to_date: Decimal,
+    /// Custody fees debited since 1 January, as a positive amount
+    pub custody_fees_year_to_date: Decimal,
+    /// Accrued interest not yet posted to the balance; negative for an accrued custody fee
+    pub accrued_not_capitalized: Decimal,
+    /// Whether the account currently accrues credit interest or a custody fee
+    pub accrual_kind: DepositAccrualKind,
+    /// Interest expected to accrue from the day after as_of until 31 December
+    pub projected_accrual_to_year_end: Decimal,
+    /// Capitalized, accrued and projected interest of the year, net of custody fees
+    pub projected_year_total: Decimal,
+    /// Always true; the projection only holds under its assumptions
+    pub is_estimate: bool,
+    pub assumptions: InterestProjectionAssumptions,
+}
+
+/// What a year end projection takes for granted
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct InterestProjectionAssumptions {
+    /// Annual rate applied by daily accrual today, as a fraction
+    pub annual_interest_rate: Decimal,
+    /// Balance interest accrues on, assumed unchanged until projection_to
+    pub principal_balance: Decimal,
+    /// Days the annual rate is divided by, the same as daily accrual
+    pub day_count_basis: u32,
+    pub projection_from: NaiveDate,
+    pub projection_to: NaiveDate,
+    /// Days in the projection interest accrues on under the product's accrual frequency
+    pub accrual_days: u32,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LoanInterestSummary {
+    pub account_id: Uuid,
+    pub currency: HeaplessString<3>,
+    pub as_of: NaiveDate,
+    pub annual_interest_rate: Decimal,
+    pub outstanding_principal: Decimal,
+    /// Scheduled interest of the installments the repaid principal covers
+    pub interest_paid_to_date: Decimal,
+    /// Accrued interest not yet due
+    pub accrued_not_due: Decimal,
+    /// Interest still payable if the loan is repaid on schedule until maturity
+    pub remaining_interest_to_maturity: Decimal,
+    pub remaining_installments: u32,
+    pub maturity_date: Option<NaiveDate>,
+    /// Always true; remaining interest assumes repayment on schedule at the current rate
+    pub is_estimate: bool,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ServiceInterestRateTier {
     pub minimum_balance: Decimal,
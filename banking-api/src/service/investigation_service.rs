@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{TimelineAccessRecord, TimelineEvent},
+};
+
+/// Account history for investigators. Every timeline read is recorded in the
+/// read audit with the person and the case it was read for.
+#[async_trait]
+pub trait InvestigationService: Send + Sync {
+    /// Workflows, status changes, holds, restrictions, transactions and sent
+    /// notifications of the account between the dates (inclusive), oldest first
+    async fn build_account_timeline(
+        &self,
+        account_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+        accessed_by_person_id: Uuid,
+        case_reference: &str,
+    ) -> BankingResult<Vec<TimelineEvent>>;
+
+    /// The same timeline as an AccountTimeline JSON document for the case file
+    async fn export_account_timeline(
+        &self,
+        account_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+        accessed_by_person_id: Uuid,
+        case_reference: &str,
+    ) -> BankingResult<String>;
+
+    async fn find_timeline_access(&self, account_id: Uuid) -> BankingResult<Vec<TimelineAccessRecord>>;
+}
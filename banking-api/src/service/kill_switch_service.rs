@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{KillSwitch, KillSwitchActivationRecord, KillSwitchRequest, PostingActor, Transaction},
+};
+
+/// Operational halts of postings by transaction kind, channel, product or
+/// globally. Activating and deactivating need the KillSwitchControl
+/// permission and are always reported to the ops distribution list.
+#[async_trait]
+pub trait KillSwitchService: Send + Sync {
+    async fn activate_switch(&self, request: KillSwitchRequest, actor: &PostingActor) -> BankingResult<KillSwitch>;
+
+    async fn deactivate_switch(&self, switch_id: Uuid, actor: &PostingActor) -> BankingResult<KillSwitch>;
+
+    /// Gate of the posting path, run before any other processing. Fails with
+    /// KillSwitchActive naming the first switch in force that halts the posting.
+    async fn check_posting(&self, transaction: &Transaction) -> BankingResult<()>;
+
+    /// Record the expiry of active switches past their `auto_expire_at`
+    async fn expire_switches(&self, now: DateTime<Utc>) -> BankingResult<Vec<KillSwitch>>;
+
+    /// Activations from `from` to `to` with how long each was in force
+    async fn activation_history(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> BankingResult<Vec<KillSwitchActivationRecord>>;
+}
+
+/// Delivers operational alerts, e.g. by email to a distribution list
+#[async_trait]
+pub trait OpsAlertSender: Send + Sync {
+    async fn send_ops_alert(&self, recipients: &[String], subject: &str, body: &str) -> BankingResult<()>;
+}
@@ -6,7 +6,7 @@ use crate::{
     domain::{
         AccountWorkflow, AccountOpeningRequest, ClosureRequest, 
         FinalSettlement, DormancyAssessment, AccountStatus, 
-        AccountStatusChangeRecord, KycResult
+        AccountStatusChangeRecord, ContentHash, KycResult, ReasonId
     },
     error::BankingResult,
 };
@@ -17,6 +17,9 @@ pub trait AccountLifecycleService: Send + Sync {
     /// Account origination workflow
     async fn initiate_account_opening(&self, request: AccountOpeningRequest) -> BankingResult<AccountWorkflow>;
     async fn complete_kyc_verification(&self, account_id: Uuid, verification_result: KycResult) -> BankingResult<()>;
+    /// Document verification step: each document attached to the workflow needs the hash
+    /// computed at review, which must match the hash registered at upload
+    async fn complete_document_verification(&self, workflow_id: Uuid, reviewed_hashes: Vec<(Uuid, ContentHash)>, verified_by: Uuid) -> BankingResult<()>;
     async fn activate_account(&self, account_id: Uuid, authorized_by: Uuid) -> BankingResult<()>;
     
     /// Dormancy management (automated)
@@ -34,7 +37,7 @@ pub trait AccountLifecycleService: Send + Sync {
     async fn finalize_closure(&self, account_id: Uuid) -> BankingResult<()>;
     
     /// Status management with reason ID validation
-    async fn update_account_status(&self, account_id: Uuid, new_status: AccountStatus, reason_id: Uuid, additional_context: Option<&str>, authorized_by: Uuid) -> BankingResult<()>;
+    async fn update_account_status(&self, account_id: Uuid, new_status: AccountStatus, reason_id: ReasonId, additional_context: Option<&str>, authorized_by: Uuid) -> BankingResult<()>;
     
     /// Legacy method - deprecated, use update_account_status with reason_id instead
     #[deprecated(note = "Use update_account_status with reason_id instead")]
@@ -49,7 +52,7 @@ pub trait AccountLifecycleService: Send + Sync {
     /// Workflow step progression
     async fn advance_workflow_step(&self, workflow_id: Uuid, completed_by: Uuid, notes: Option<HeaplessString<500>>) -> BankingResult<()>;
     /// Reject workflow with reason ID validation
-    async fn reject_workflow(&self, workflow_id: Uuid, reason_id: Uuid, additional_details: Option<&str>, rejected_by: Uuid) -> BankingResult<()>;
+    async fn reject_workflow(&self, workflow_id: Uuid, reason_id: ReasonId, additional_details: Option<&str>, rejected_by: Uuid) -> BankingResult<()>;
     
     /// Legacy method - deprecated, use reject_workflow with reason_id instead
     #[deprecated(note = "Use reject_workflow with reason_id instead")]
@@ -61,7 +64,8 @@ pub trait AccountLifecycleService: Send + Sync {
     async fn find_accounts_eligible_for_dormancy(&self, threshold_days: i32) -> BankingResult<Vec<Uuid>>;
     
     /// Batch processing for EOD
-    async fn batch_process_dormancy(&self, processing_date: chrono::NaiveDate) -> BankingResult<crate::service::DormancyReport>;
+    /// Opens a DormancyAssessment workflow for each dormancy candidate, optionally for one product only
+    async fn batch_process_dormancy(&self, processing_date: chrono::NaiveDate, product_id: Option<Uuid>) -> BankingResult<crate::service::DormancyReport>;
     async fn batch_process_closures(&self, processing_date: chrono::NaiveDate) -> BankingResult<crate::service::MaintenanceReport>;
     
     /// Compliance integration
@@ -362,6 +362,26 @@ pub trait LoanService: Send + Sync {
         include_penalties: bool,
     ) -> BankingResult<EarlySettlementCalculation>;
     
+    /// Amount that settles a loan in full on `as_of_date`: outstanding principal,
+    /// interest accrued since the last installment and early repayment fees
+    async fn calculate_payoff_quote(
+        &self,
+        loan_account_id: Uuid,
+        as_of_date: NaiveDate,
+    ) -> BankingResult<PayoffQuote>;
+    
+    /// Apply an early repayment, allocated to interest, then fees, then principal.
+    /// A partial prepayment shortens the term or reduces the installment as
+    /// `prepayment_type` chooses; repaying the whole balance closes the account.
+    async fn apply_early_repayment(
+        &self,
+        loan_account_id: Uuid,
+        amount: Decimal,
+        value_date: NaiveDate,
+        prepayment_type: PrepaymentType,
+        processed_by: Uuid, // References Person.person_id
+    ) -> BankingResult<LoanPayment>;
+    
     /// Process loan write-off
     async fn process_loan_write_off(
         &self,
@@ -567,6 +587,17 @@ pub struct EarlySettlementCalculation {
     pub savings_to_customer: Decimal,
 }
 
+/// Amount needed to repay a loan in full on a given date
+#[derive(Debug, Clone)]
+pub struct PayoffQuote {
+    pub loan_account_id: Uuid,
+    pub as_of_date: NaiveDate,
+    pub outstanding_principal: Decimal,
+    pub accrued_interest: Decimal,
+    pub early_termination_fee: Decimal,
+    pub total_payoff_amount: Decimal,
+}
+
 /// Loan write-off record
 #[derive(Debug, Clone)]
 pub struct LoanWriteOff {
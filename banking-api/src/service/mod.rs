@@ -1,5 +1,6 @@
 // pub mod customer_service;
 // pub mod account_service;
+// pub mod account_number_service;
 // pub mod account_hold_service;
 // pub mod transaction_service;
 // pub mod interest_service;
@@ -7,6 +8,7 @@
 // pub mod hierarchy_service;
 // pub mod compliance_service;
 // pub mod channel_service;
+// pub mod channel_reconciliation_service;
 // pub mod eod_service;
 // pub mod lifecycle_service;
 // pub mod fee_service;
@@ -17,18 +19,43 @@
 // pub mod collateral_service;
 // pub mod daily_collection_service;
 // pub mod product_service;
+// pub mod welcome_pack_service;
+// pub mod segment_service;
+// pub mod guarantor_service;
+// pub mod simulation_service;
+// pub mod cheque_service;
+// pub mod statement_service;
+// pub mod orchestration_service;
+// pub mod tag_service;
+// pub mod channel_security_service;
+// pub mod agent_commission_service;
+// pub mod document_registry_service;
+// pub mod branch_cash_service;
+// pub mod notification_service;
+// pub mod investigation_service;
+// pub mod bundle_service;
+// pub mod financial_position_service;
+// pub mod savings_goal_service;
+// pub mod payee_service;
+// pub mod kill_switch_service;
+// pub mod interaction_note_service;
+// pub mod verification_service;
+// pub mod warehouse_export_service;
+// pub mod workflow_escalation_service;
 pub mod audit;
 pub mod person;
 
 
 // pub use customer_service::*;
 // pub use account_service::*;
+// pub use account_number_service::*;
 // pub use transaction_service::*;
 // pub use interest_service::*;
 // pub use calendar_service::*;
 // pub use hierarchy_service::*;
 // pub use compliance_service::*;
 // pub use channel_service::*;
+// pub use channel_reconciliation_service::*;
 // pub use eod_service::*;
 // pub use lifecycle_service::*;
 // pub use fee_service::*;
@@ -39,5 +66,28 @@ pub mod person;
 // pub use collateral_service::*;
 // pub use product_service::*;
 // pub use daily_collection_service::*;
+// pub use welcome_pack_service::*;
+// pub use segment_service::*;
+// pub use guarantor_service::*;
+// pub use simulation_service::*;
+// pub use cheque_service::*;
+// pub use statement_service::*;
+// pub use orchestration_service::*;
+// pub use tag_service::*;
+// pub use channel_security_service::*;
+// pub use agent_commission_service::*;
+// pub use document_registry_service::*;
+// pub use branch_cash_service::*;
+// pub use notification_service::*;
+// pub use investigation_service::*;
+// pub use bundle_service::*;
+// pub use financial_position_service::*;
+// pub use savings_goal_service::*;
+// pub use payee_service::*;
+// pub use kill_switch_service::*;
+// pub use interaction_note_service::*;
+// pub use verification_service::*;
+// pub use warehouse_export_service::*;
+// pub use workflow_escalation_service::*;
 pub use audit::*;
 pub use person::*;
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{
+        ChannelDispatch, MessageSendRequest, MessageTemplate, MessageTemplateRequest,
+        NotificationDuplicateReport, NotificationQueueOutcome, NotificationRequest, QueuedNotification,
+        RenderedMessage,
+    },
+};
+
+/// Customer message queue keyed by template, recipient, business date and
+/// context, so that rerunning an EOD step never messages a customer twice
+#[async_trait]
+pub trait NotificationService: Send + Sync {
+    /// Render the template and queue the message. When the request's key was
+    /// already queued, returns the original message without queueing another.
+    async fn render_and_queue(&self, request: NotificationRequest) -> BankingResult<NotificationQueueOutcome>;
+
+    /// Save the wording of a template in one language, replacing any earlier
+    /// wording in that language. Fails when a field exceeds its stored length.
+    async fn save_message_template(&self, request: MessageTemplateRequest) -> BankingResult<MessageTemplate>;
+
+    /// Render the template in the first of `language_preferences` it exists in,
+    /// else in the bank's default language. Fails when no such template exists
+    /// or a placeholder has no value in `params`.
+    async fn render_message(
+        &self,
+        template_code: &str,
+        language_preferences: &[[u8; 3]],
+        params: &HashMap<String, String>,
+    ) -> BankingResult<RenderedMessage>;
+
+    /// Render the template for each requested channel in the language the
+    /// person prefers there, and decide when it goes out: suppressed on
+    /// channels they opted out of unless the template is regulatory, and
+    /// deferred to the end of their quiet hours. A channel without a stored
+    /// preference is sent at the requested time.
+    async fn render_and_send(&self, request: MessageSendRequest) -> BankingResult<Vec<ChannelDispatch>>;
+
+    /// Rendered from the stored template in the customer's preferred language
+    async fn queue_dormancy_notice(
+        &self,
+        customer_id: Uuid,
+        account_id: Uuid,
+        business_date: NaiveDate,
+    ) -> BankingResult<NotificationQueueOutcome>;
+
+    async fn queue_statement_ready(
+        &self,
+        customer_id: Uuid,
+        statement_reference: &HeaplessString<50>,
+        business_date: NaiveDate,
+    ) -> BankingResult<NotificationQueueOutcome>;
+
+    async fn queue_mandate_expiry_reminder(
+        &self,
+        customer_id: Uuid,
+        account_id: Uuid,
+        expiry_date: NaiveDate,
+        business_date: NaiveDate,
+    ) -> BankingResult<NotificationQueueOutcome>;
+
+    /// Rendered from the stored template in the customer's preferred language
+    async fn queue_collection_reminder(
+        &self,
+        customer_id: Uuid,
+        amount: Decimal,
+        due_date: NaiveDate,
+        business_date: NaiveDate,
+    ) -> BankingResult<NotificationQueueOutcome>;
+
+    async fn queue_savings_goals_reduced(
+        &self,
+        customer_id: Uuid,
+        account_id: Uuid,
+        amount: Decimal,
+        business_date: NaiveDate,
+    ) -> BankingResult<NotificationQueueOutcome>;
+
+    /// Rendered from the stored template in the customer's preferred language.
+    /// Queued for the date the warning period began, so the daily step warns
+    /// each owner once.
+    async fn queue_inactivity_fee_warning(
+        &self,
+        customer_id: Uuid,
+        account_id: Uuid,
+        fee_date: NaiveDate,
+        warned_from: NaiveDate,
+    ) -> BankingResult<NotificationQueueOutcome>;
+
+    async fn find_notifications_by_customer(&self, customer_id: Uuid, business_date: NaiveDate) -> BankingResult<Vec<QueuedNotification>>;
+
+    /// Duplicates suppressed for the business date, per template
+    async fn get_duplicate_report(&self, business_date: NaiveDate) -> BankingResult<NotificationDuplicateReport>;
+
+    /// Drop idempotency keys past the configured retention; returns the number removed
+    async fn purge_expired_keys(&self, as_of: NaiveDate) -> BankingResult<u64>;
+}
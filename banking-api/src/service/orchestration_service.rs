@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use heapless::String as HeaplessString;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{CompensationAction, IncompleteOrchestration, Orchestration, OrchestrationStep, OrchestrationType},
+};
+
+/// Orchestration log and compensation runner for multi-step operations.
+#[async_trait]
+pub trait OrchestrationService: Send + Sync {
+    async fn start_orchestration(
+        &self,
+        orchestration_type: OrchestrationType,
+        subject_id: Uuid,
+        initiated_by_person_id: Uuid,
+    ) -> BankingResult<Orchestration>;
+
+    /// Register a completed step together with the action that undoes it
+    async fn record_step(
+        &self,
+        orchestration_id: Uuid,
+        step_name: &str,
+        compensation: Option<CompensationAction>,
+    ) -> BankingResult<OrchestrationStep>;
+
+    async fn complete_orchestration(&self, orchestration_id: Uuid) -> BankingResult<Orchestration>;
+
+    /// Run the compensations of all completed steps in reverse order. Ends in
+    /// `Compensated`, or `CompensationFailed` if any compensation failed.
+    async fn fail_orchestration(&self, orchestration_id: Uuid, failure_reason: &str) -> BankingResult<Orchestration>;
+
+    /// Operator view: orchestrations not in a terminal state with their pending compensations
+    async fn find_incomplete_orchestrations(&self) -> BankingResult<Vec<IncompleteOrchestration>>;
+
+    /// Operator action: run the outstanding compensations again
+    async fn retry_compensation(&self, orchestration_id: Uuid) -> BankingResult<Orchestration>;
+
+    /// Operator action: close an orchestration whose effects were corrected by hand
+    async fn resolve_manually(
+        &self,
+        orchestration_id: Uuid,
+        resolved_by_person_id: Uuid,
+        resolution_note: HeaplessString<255>,
+    ) -> BankingResult<Orchestration>;
+}
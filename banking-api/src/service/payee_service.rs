@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{Payee, PayeeConfirmation},
+};
+
+/// External beneficiaries saved by customers. Every external transfer must
+/// be authorized against its payee before it is sent.
+#[async_trait]
+pub trait PayeeService: Send + Sync {
+    /// Save the payee and start its verification with the configured method
+    async fn add_payee(&self, payee: Payee) -> BankingResult<Payee>;
+
+    /// Complete a pending verification; a wrong penny test amount is refused
+    async fn verify_payee(&self, payee_id: Uuid, confirmation: PayeeConfirmation) -> BankingResult<Payee>;
+
+    async fn block_payee(&self, payee_id: Uuid, blocked_by_person_id: Uuid) -> BankingResult<Payee>;
+
+    async fn find_payees_by_customer(&self, customer_id: Uuid) -> BankingResult<Vec<Payee>>;
+
+    /// Gate of the external-transfer path: fails for blocked payees, for
+    /// unverified payees above the unverified limit and for new payees above
+    /// the cooling-off limit. Records the first use of the payee.
+    async fn authorize_external_transfer(&self, customer_id: Uuid, payee_id: Uuid, amount: Decimal) -> BankingResult<Payee>;
+}
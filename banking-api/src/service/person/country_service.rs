@@ -13,6 +13,8 @@ pub enum CountryServiceError {
     DuplicateCountryISO2(String),
     #[error("Invalid country ISO2: {0}")]
     InvalidCountryISO2(String),
+    #[error("Invalid country name prefix: {0}")]
+    InvalidNamePrefix(String),
     #[error("Repository error: {0}")]
     RepositoryError(String),
 }
@@ -27,4 +29,11 @@ pub trait CountryService: Send + Sync {
         iso2: HeaplessString<2>,
     ) -> Result<Option<Country>, CountryServiceError>;
     async fn get_all_countries(&self) -> Result<Vec<Country>, CountryServiceError>;
+    /// Type-ahead search by name in any language, ignoring case and accents.
+    /// The prefix is trimmed and must have at least two characters.
+    async fn search_countries(
+        &self,
+        prefix: &str,
+        limit: i32,
+    ) -> Result<Vec<Country>, CountryServiceError>;
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 use crate::domain::person::Location;
 use crate::domain::AuditLog;
 use async_trait::async_trait;
+use rust_decimal::Decimal;
 use std::error::Error;
 use std::fmt;
 use uuid::Uuid;
@@ -98,4 +99,21 @@ pub trait LocationService: Send + Sync {
         &self,
         locality_id: Uuid,
     ) -> LocationServiceResult<Vec<Location>>;
+    /// Locations inside the box, paged from 1
+    async fn find_locations_within_bounding_box(
+        &self,
+        min_latitude: Decimal,
+        min_longitude: Decimal,
+        max_latitude: Decimal,
+        max_longitude: Decimal,
+        page: i32,
+        page_size: i32,
+    ) -> LocationServiceResult<Vec<Location>>;
+    /// Up to `limit` locations closest to the point
+    async fn find_nearest_locations(
+        &self,
+        latitude: Decimal,
+        longitude: Decimal,
+        limit: i32,
+    ) -> LocationServiceResult<Vec<Location>>;
 }
\ No newline at end of file
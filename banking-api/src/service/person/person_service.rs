@@ -1,6 +1,7 @@
-use crate::domain::person::Person;
+use crate::domain::person::{DuplicateCandidate, Person};
 use crate::domain::AuditLog;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use heapless::String as HeaplessString;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -24,6 +25,8 @@ pub enum PersonServiceError {
     LocationNotFound(Uuid),
     #[error("Invalid locations found for {0} persons")]
     InvalidLocations(usize),
+    #[error("Person not found: {0}")]
+    PersonNotFound(Uuid),
     #[error("Persons not found: {0:?}")]
     ManyPersonsNotFound(Vec<Uuid>),
     #[error("Referenced person for duplicate not found: {0}")]
@@ -65,4 +68,15 @@ pub trait PersonService: Send + Sync {
         &self,
         external_identifier: HeaplessString<50>,
     ) -> PersonServiceResult<Vec<Person>>;
+    /// Candidate duplicates of a person, highest score first
+    async fn detect_duplicates(&self, person_id: Uuid) -> PersonServiceResult<Vec<DuplicateCandidate>>;
+    /// Mark `duplicate_id` as a duplicate of `canonical_id` after review
+    async fn link_duplicate(
+        &self,
+        duplicate_id: Uuid,
+        canonical_id: Uuid,
+        reviewed_by: Uuid,
+    ) -> PersonServiceResult<Person>;
+    /// Candidate duplicates of every person created since the timestamp, each pair reported once
+    async fn scan_for_duplicates(&self, since: DateTime<Utc>) -> PersonServiceResult<Vec<DuplicateCandidate>>;
 }
\ No newline at end of file
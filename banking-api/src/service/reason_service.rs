@@ -1,5 +1,5 @@
 use crate::error::BankingResult;
-use crate::domain::{AccountStatus, TransactionStatus, SarData, LoanRestructuring, PaymentReversal, FeeWaiver};
+use crate::domain::{AccountStatus, TransactionStatus, SarData, LoanRestructuring, PaymentReversal, FeeWaiver, ReasonId};
 use uuid::Uuid;
 
 // Note: ReasonAndPurpose types will be imported when the banking-db dependency is properly configured
@@ -22,7 +22,7 @@ pub trait EnhancedAccountService: Send + Sync {
     async fn close_account_with_reason(
         &self,
         account_id: Uuid,
-        reason_id: Uuid,
+        reason_id: ReasonId,
         additional_details: Option<&str>,
         closed_by: Uuid, // References Person.person_id
     ) -> BankingResult<()>;
@@ -32,7 +32,7 @@ pub trait EnhancedAccountService: Send + Sync {
         &self,
         account_id: Uuid,
         amount: rust_decimal::Decimal,
-        reason_id: Uuid,
+        reason_id: ReasonId,
         additional_details: Option<&str>,
         placed_by: Uuid, // References Person.person_id
     ) -> BankingResult<Uuid>;
@@ -42,7 +42,7 @@ pub trait EnhancedAccountService: Send + Sync {
         &self,
         account_id: Uuid,
         new_status: AccountStatus,
-        reason_id: Uuid,
+        reason_id: ReasonId,
         additional_context: Option<&str>,
         updated_by_person_id: Uuid, // References Person.person_id
     ) -> BankingResult<()>;
@@ -55,7 +55,7 @@ pub trait EnhancedTransactionService: Send + Sync {
     async fn reverse_transaction_with_reason(
         &self,
         transaction_id: Uuid,
-        reason_id: Uuid,
+        reason_id: ReasonId,
         additional_details: Option<&str>,
         reversed_by: Uuid, // References Person.person_id
     ) -> BankingResult<()>;
@@ -65,7 +65,7 @@ pub trait EnhancedTransactionService: Send + Sync {
         &self,
         transaction_id: Uuid,
         status: TransactionStatus,
-        reason_id: Uuid,
+        reason_id: ReasonId,
         additional_context: Option<&str>,
         updated_by_person_id: Uuid, // References Person.person_id
     ) -> BankingResult<()>;
@@ -91,7 +91,7 @@ pub trait EnhancedLoanService: Send + Sync {
     async fn restructure_loan_with_reason(
         &self,
         loan_account_id: Uuid,
-        restructuring_reason_id: Uuid,
+        restructuring_reason_id: ReasonId,
         additional_details: Option<&str>,
         requested_by: Uuid, // References Person.person_id
     ) -> BankingResult<LoanRestructuring>;
@@ -100,7 +100,7 @@ pub trait EnhancedLoanService: Send + Sync {
     async fn reverse_payment_with_reason(
         &self,
         payment_id: Uuid,
-        reversal_reason_id: Uuid,
+        reversal_reason_id: ReasonId,
         additional_details: Option<&str>,
         reversed_by: Uuid, // References Person.person_id
     ) -> BankingResult<PaymentReversal>;
@@ -113,7 +113,7 @@ pub trait EnhancedFeeService: Send + Sync {
     async fn request_fee_waiver_with_reason(
         &self,
         fee_application_id: Uuid,
-        reason_id: Uuid,
+        reason_id: ReasonId,
         additional_details: Option<&str>,
         requested_by: Uuid, // References Person.person_id
     ) -> BankingResult<FeeWaiver>;
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{SavingsGoal, SavingsGoalDeallocation, SavingsGoalsView},
+};
+
+/// Savings goals partitioning the available balance of a CASA account.
+/// The sum of the allocations of active goals never exceeds the account's
+/// available balance.
+#[async_trait]
+pub trait SavingsGoalService: Send + Sync {
+    /// Goals start with nothing allocated
+    async fn create_goal(&self, goal: SavingsGoal) -> BankingResult<SavingsGoal>;
+
+    /// The goal's allocation returns to the unallocated pool
+    async fn close_goal(&self, goal_id: Uuid, closed_by_person_id: Uuid) -> BankingResult<SavingsGoal>;
+
+    /// Move unallocated balance into the goal; refused when the account's
+    /// goals would exceed its available balance
+    async fn allocate_to_goal(&self, goal_id: Uuid, amount: Decimal, updated_by_person_id: Uuid) -> BankingResult<SavingsGoal>;
+
+    /// Move part of the goal back into the unallocated pool
+    async fn deallocate_from_goal(&self, goal_id: Uuid, amount: Decimal, updated_by_person_id: Uuid) -> BankingResult<SavingsGoal>;
+
+    /// Called after a withdrawal. When the goals exceed the new available
+    /// balance they are reduced proportionally and the owners notified.
+    async fn rebalance_after_withdrawal(&self, account_id: Uuid, business_date: NaiveDate) -> BankingResult<Vec<SavingsGoalDeallocation>>;
+
+    async fn find_goals_by_account(&self, account_id: Uuid) -> BankingResult<Vec<SavingsGoal>>;
+
+    /// Progress of the active goals for the app
+    async fn get_goals_view(&self, account_id: Uuid, as_of: NaiveDate) -> BankingResult<SavingsGoalsView>;
+}
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{Segment, SegmentEvaluationReport, SegmentMembership, SegmentMembershipDelta},
+};
+
+/// Service for defining customer segments and querying their membership.
+#[async_trait]
+pub trait SegmentService: Send + Sync {
+    /// Segment definition management
+    async fn create_segment(&self, segment: Segment) -> BankingResult<Segment>;
+    async fn update_segment(&self, segment: Segment) -> BankingResult<Segment>;
+    async fn find_segment_by_id(&self, segment_id: Uuid) -> BankingResult<Option<Segment>>;
+    async fn find_segment_by_code(&self, code: &str) -> BankingResult<Option<Segment>>;
+    async fn find_active_segments(&self) -> BankingResult<Vec<Segment>>;
+    async fn deactivate_segment(&self, segment_id: Uuid, updated_by_person_id: Uuid) -> BankingResult<()>;
+    async fn delete_segment(&self, segment_id: Uuid) -> BankingResult<()>;
+
+    /// Re-evaluate every active segment against the data as of `processing_date`
+    async fn evaluate_segments(&self, processing_date: NaiveDate) -> BankingResult<SegmentEvaluationReport>;
+
+    /// Customers who entered or left the segment after `since`, for campaign targeting
+    async fn get_segment_delta(&self, segment_id: Uuid, since: DateTime<Utc>) -> BankingResult<SegmentMembershipDelta>;
+
+    /// Current members of a segment
+    async fn get_segment_members(&self, segment_id: Uuid) -> BankingResult<Vec<SegmentMembership>>;
+
+    /// Active segments the customer currently belongs to
+    async fn get_segments_for_customer(&self, customer_id: Uuid) -> BankingResult<Vec<Segment>>;
+}
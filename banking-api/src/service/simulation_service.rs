@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{AccountOpeningRequest, CurrencyCode, LoanSimulation, Quote, QuoteSimulation, SavingsSimulation},
+};
+
+/// Pre-sale simulations of savings and loan products, computed from the
+/// product catalogue with the same engines used on live accounts.
+#[async_trait]
+pub trait SimulationService: Send + Sync {
+    /// Month-by-month projection of regular deposits into a savings product
+    async fn simulate_savings(&self, product_id: Uuid, monthly_deposit: Decimal, months: u32) -> BankingResult<SavingsSimulation>;
+
+    /// Amortization schedule, total interest, fees and effective rate of a loan
+    async fn simulate_loan(&self, product_id: Uuid, principal: Decimal, term_months: u32) -> BankingResult<LoanSimulation>;
+
+    /// Persist a simulation as a quote valid for the configured number of days
+    async fn save_quote(&self, simulation: QuoteSimulation, customer_id: Option<Uuid>, created_by_person_id: Uuid) -> BankingResult<Quote>;
+
+    async fn find_quote_by_id(&self, quote_id: Uuid) -> BankingResult<Option<Quote>>;
+
+    /// Convert an active, unexpired quote into an account opening request
+    /// carrying the quote reference for a sole-owner account in `currency`.
+    /// The quote is marked converted.
+    async fn convert_quote(
+        &self,
+        quote_id: Uuid,
+        customer_id: Uuid,
+        currency: CurrencyCode,
+        channel: HeaplessString<50>,
+        initiated_by: Uuid,
+    ) -> BankingResult<AccountOpeningRequest>;
+
+    /// Mark active quotes past their expiry as expired
+    async fn expire_quotes(&self, as_of: DateTime<Utc>) -> BankingResult<u64>;
+}
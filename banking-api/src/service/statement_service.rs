@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{
+        AccountStatement, PaperStatementReport, StatementArchiveVerification, StatementConsentRecord,
+        StatementCycleReport, StatementDeliveryPreference, StatementPreferenceChange, StatementPrintExtract,
+    },
+};
+
+/// Service for statement delivery preferences and cycle statement dispatch.
+#[async_trait]
+pub trait StatementService: Send + Sync {
+    /// Change how an account receives statements, record the consent evidence
+    /// and notify the customer. Switching to Electronic or Both without a
+    /// verified contact channel is rejected.
+    async fn change_delivery_preference(&self, change: StatementPreferenceChange) -> BankingResult<StatementDeliveryPreference>;
+
+    /// Current preference, `None` if the account is on the default paper delivery
+    async fn get_delivery_preference(&self, account_id: Uuid) -> BankingResult<Option<StatementDeliveryPreference>>;
+
+    /// Consent history of an account, oldest first
+    async fn get_consent_history(&self, account_id: Uuid) -> BankingResult<Vec<StatementConsentRecord>>;
+
+    /// EOD step: notify e-statement accounts and add paper accounts to the
+    /// cycle's print extract. A cycle is dispatched at most once.
+    async fn dispatch_cycle_statements(&self, cycle_date: NaiveDate) -> BankingResult<StatementCycleReport>;
+
+    /// Print and dispatch extract of a cycle
+    async fn find_print_extract(&self, cycle_date: NaiveDate) -> BankingResult<Option<StatementPrintExtract>>;
+
+    /// Accounts still receiving paper statements
+    async fn get_paper_statement_report(&self) -> BankingResult<PaperStatementReport>;
+
+    /// Snapshot the account's statement for a cycle. An existing statement is
+    /// returned unless `force` is set or transactions were since backdated
+    /// into the cycle, in which case a new statement superseding it is stored
+    /// and the original is kept.
+    async fn generate_statement(
+        &self,
+        account_id: Uuid,
+        cycle_date: NaiveDate,
+        force: bool,
+        generated_by_person_id: Uuid,
+    ) -> BankingResult<AccountStatement>;
+
+    /// Snapshot the account's statement from `from` to `to` inclusive, with
+    /// the period's interest and fee summaries. Stored statements are reused
+    /// and superseded as for cycle statements. Fails with
+    /// `StatementOutOfBalance` if the closing balance does not match the
+    /// ledger balance at the end of the period.
+    async fn generate_period_statement(
+        &self,
+        account_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+        generated_by_person_id: Uuid,
+    ) -> BankingResult<AccountStatement>;
+
+    /// Serve a stored statement after checking it against its content hash.
+    /// A mismatch raises an integrity alert and returns
+    /// `StatementIntegrityViolation`.
+    async fn reprint_statement(&self, statement_id: Uuid) -> BankingResult<AccountStatement>;
+
+    /// Check every stored statement of a cycle, superseded ones included,
+    /// raising an integrity alert for each mismatch
+    async fn verify_statement_archive(&self, period_end: NaiveDate) -> BankingResult<StatementArchiveVerification>;
+}
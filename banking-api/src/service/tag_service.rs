@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{BulkTagReport, BulkTagTarget, EntityTag, Tag, TagActor, TaggableEntityKind},
+};
+
+/// Tag registry and tag assignment on customers and accounts
+#[async_trait]
+pub trait TagService: Send + Sync {
+    /// Tag registry management
+    async fn create_tag(&self, tag: Tag) -> BankingResult<Tag>;
+    async fn update_tag(&self, tag: Tag) -> BankingResult<Tag>;
+    async fn find_tag_by_code(&self, code: &str) -> BankingResult<Option<Tag>>;
+    async fn find_active_tags(&self) -> BankingResult<Vec<Tag>>;
+    async fn deactivate_tag(&self, tag_id: Uuid, updated_by_person_id: Uuid) -> BankingResult<()>;
+
+    /// Attach a tag; protected tags require the tag's permission. Assigning a
+    /// tag the entity already carries returns the existing assignment.
+    async fn assign_tag(
+        &self,
+        tag_code: &str,
+        entity_kind: TaggableEntityKind,
+        entity_id: Uuid,
+        actor: &TagActor,
+    ) -> BankingResult<EntityTag>;
+
+    /// Detach a tag; protected tags require the tag's permission
+    async fn remove_tag(
+        &self,
+        tag_code: &str,
+        entity_kind: TaggableEntityKind,
+        entity_id: Uuid,
+        actor: &TagActor,
+    ) -> BankingResult<()>;
+
+    async fn find_tags_for_entity(&self, entity_kind: TaggableEntityKind, entity_id: Uuid) -> BankingResult<Vec<Tag>>;
+
+    /// Attach a tag to every entity matching the target, in chunks
+    async fn bulk_assign_tag(&self, tag_code: &str, target: BulkTagTarget, actor: &TagActor) -> BankingResult<BulkTagReport>;
+}
@@ -5,21 +5,46 @@ use uuid::Uuid;
 use crate::{
     domain::{
         Transaction, TransactionType, TransactionValidationResult, TransactionApprovalWorkflow,
-        PermittedOperation, TransactionRequest, TransactionResult, FinalSettlement
+        PermittedOperation, TransactionRequest, TransactionResult, FinalSettlement, TransactionSearchCriteria,
+        ReasonId, PostingActor, BackDatedPosting, PostingDependency, CircuitBreakerMetrics,
     },
     error::BankingResult,
 };
 
 #[async_trait]
 pub trait TransactionService: Send + Sync {
-    /// Process a transaction through the full pipeline
+    /// Process a transaction through the full pipeline. Value dates before the
+    /// transaction date are refused; those go through post_back_dated_transaction.
     async fn process_transaction(&self, transaction: Transaction) -> BankingResult<Transaction>;
+
+    /// Post a transaction valued before the business date. Needs the BackDatePosting
+    /// permission and a value date within the configured maximum age; the accrued
+    /// interest of the back-dated window is recalculated and the posting registered.
+    async fn post_back_dated_transaction(
+        &self,
+        transaction: Transaction,
+        actor: &PostingActor,
+        business_date: NaiveDate,
+    ) -> BankingResult<BackDatedPosting>;
+
+    /// Back-dated postings register for business dates from `from` to `to` inclusive
+    async fn find_back_dated_postings(&self, from: NaiveDate, to: NaiveDate) -> BankingResult<Vec<BackDatedPosting>>;
+
+    /// Catch-up job: run the skipped enrichment and categorization of up to
+    /// `limit` degraded transactions again and clear the flags of the steps that succeed
+    async fn backfill_degraded_transactions(&self, limit: i64) -> BankingResult<DegradedBackfillReport>;
+
+    /// State and counters of the circuit breakers guarding non-critical posting steps
+    async fn get_circuit_breaker_metrics(&self) -> BankingResult<Vec<CircuitBreakerMetrics>>;
     
     /// Validate transaction limits
     async fn validate_transaction_limits(&self, transaction: &Transaction) -> BankingResult<TransactionValidationResult>;
     
-    /// Reverse a posted transaction with reason ID validation
-    async fn reverse_transaction(&self, transaction_id: Uuid, reason_id: Uuid, additional_details: Option<&str>) -> BankingResult<()>;
+    /// Reverse a posted transaction in full: a contra transaction linked through
+    /// reversal_of_transaction_id moves the balance back and the original becomes
+    /// Reversed. Refused with `BankingError::TransactionNotReversible` for reversals,
+    /// transactions already reversed and postings older than the reversal window.
+    async fn reverse_transaction(&self, transaction_id: Uuid, reason_id: ReasonId, requested_by: Uuid) -> BankingResult<Transaction>;
     
     /// Legacy method - deprecated, use reverse_transaction with reason_id instead
     #[deprecated(note = "Use reverse_transaction with reason_id instead")]
@@ -27,10 +52,21 @@ pub trait TransactionService: Send + Sync {
     
     /// Find transactions for an account within a date range
     async fn find_transactions_by_account(&self, account_id: Uuid, from: NaiveDate, to: NaiveDate) -> BankingResult<Vec<Transaction>>;
+
+    /// Find transactions matching the criteria, most recent first
+    async fn search_transactions(&self, criteria: TransactionSearchCriteria) -> BankingResult<Vec<Transaction>>;
     
-    /// Multi-party authorization workflow
+    /// Multi-party authorization workflow. Opening it for a transaction
+    /// awaiting approval holds the funds of a customer debit until the workflow
+    /// times out.
     async fn initiate_approval_workflow(&self, transaction: Transaction) -> BankingResult<TransactionApprovalWorkflow>;
-    async fn approve_transaction(&self, transaction_id: Uuid, approver_person_id: Uuid) -> BankingResult<()>;
+
+    /// Record an approval; the transaction posts once the signers required by
+    /// the account mandate have approved. The initiator cannot approve.
+    async fn approve_transaction(&self, transaction_id: Uuid, approver_person_id: Uuid) -> BankingResult<Transaction>;
+
+    /// Reject a transaction awaiting approval and release the hold on its funds
+    async fn reject_transaction(&self, transaction_id: Uuid, approver_person_id: Uuid, reason_id: ReasonId) -> BankingResult<Transaction>;
 
     /// Status-aware transaction validation (from enhancements)
     async fn validate_account_transactional_status(&self, account_id: Uuid, transaction_type: TransactionType) -> BankingResult<TransactionValidationResult>;
@@ -41,7 +77,7 @@ pub trait TransactionService: Send + Sync {
     /// Final settlement operations
     async fn process_closure_transaction(&self, account_id: Uuid, settlement: FinalSettlement) -> BankingResult<Transaction>;
     /// Reverse pending transactions with reason ID validation
-    async fn reverse_pending_transactions(&self, account_id: Uuid, reason_id: Uuid, additional_details: Option<&str>) -> BankingResult<Vec<Transaction>>;
+    async fn reverse_pending_transactions(&self, account_id: Uuid, reason_id: ReasonId, additional_details: Option<&str>) -> BankingResult<Vec<Transaction>>;
     
     /// Legacy method - deprecated, use reverse_pending_transactions with reason_id instead
     #[deprecated(note = "Use reverse_pending_transactions with reason_id instead")]
@@ -63,6 +99,24 @@ pub trait TransactionService: Send + Sync {
     async fn update_transaction_status(&self, transaction_id: Uuid, status: crate::domain::TransactionStatus, reason: String) -> BankingResult<()>;
 }
 
+/// Step run after the balance is posted. Critical steps fail the posting;
+/// non-critical ones run behind a circuit breaker and are skipped on failure.
+#[async_trait]
+pub trait PostingStep: Send + Sync {
+    fn dependency(&self) -> PostingDependency;
+    async fn run(&self, transaction: &Transaction) -> BankingResult<()>;
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct DegradedBackfillReport {
+    pub transactions_examined: i64,
+    pub steps_backfilled: i64,
+    /// Transactions left with no backfillable step outstanding
+    pub transactions_completed: i64,
+    /// Transactions still flagged, usually because a breaker is still open
+    pub transactions_still_degraded: i64,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct TransactionAuditEntry {
     pub audit_id: Uuid,
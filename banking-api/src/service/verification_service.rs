@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use heapless::String as HeaplessString;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{ChallengeHandle, VerificationChallenge, VerificationOperationKind, VerificationProof},
+};
+
+/// Step-up verification for high-risk service operations such as changing
+/// contact details or raising limits by phone. Wrong codes count towards a
+/// lockout per customer and operation kind and are recorded as channel
+/// security events.
+#[async_trait]
+pub trait VerificationService: Send + Sync {
+    /// Create a short-lived challenge and send its code to the customer's
+    /// registered channel. Refused while the customer is locked out.
+    async fn initiate_challenge(
+        &self,
+        customer_id: Uuid,
+        operation_kind: VerificationOperationKind,
+        channel_id: Uuid,
+    ) -> BankingResult<ChallengeHandle>;
+
+    /// Check a code, or record a teller's in-person assertion
+    async fn verify_challenge(&self, handle: &ChallengeHandle, proof: VerificationProof) -> BankingResult<VerificationChallenge>;
+
+    /// Guard of high-risk operations: use up the customer's most recent
+    /// verified challenge for the operation kind. Fails with
+    /// VerificationRequired when there is none still valid.
+    async fn consume_verification(&self, customer_id: Uuid, operation_kind: VerificationOperationKind) -> BankingResult<VerificationChallenge>;
+}
+
+/// Delivers verification codes through the messaging layer
+#[async_trait]
+pub trait VerificationCodeSender: Send + Sync {
+    async fn send_verification_code(
+        &self,
+        customer_id: Uuid,
+        operation_kind: VerificationOperationKind,
+        code: &HeaplessString<10>,
+    ) -> BankingResult<()>;
+}
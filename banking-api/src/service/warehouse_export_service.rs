@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::{
+    error::BankingResult,
+    domain::{DeltaManifest, WarehouseEntity},
+};
+
+/// Incremental export of the key tables to the bank's data warehouse. Runs
+/// are chained by watermark so that consecutive runs neither skip nor repeat
+/// a change.
+#[async_trait]
+pub trait WarehouseExportService: Send + Sync {
+    /// Rows changed and deleted after `since_watermark`, which must be the
+    /// watermark the previous run ended at
+    async fn export_warehouse_delta(
+        &self,
+        since_watermark: DateTime<Utc>,
+        writer: &dyn WarehouseDeltaWriter,
+    ) -> BankingResult<DeltaManifest>;
+
+    /// Every current row, for the initial load or a reload of the warehouse
+    async fn export_full_snapshot(&self, writer: &dyn WarehouseDeltaWriter) -> BankingResult<DeltaManifest>;
+
+    /// Watermark the next incremental run starts from; None before the first snapshot
+    async fn last_watermark(&self) -> BankingResult<Option<DateTime<Utc>>>;
+}
+
+/// Destination of an export, e.g. one NDJSON file per entity in a drop folder
+#[async_trait]
+pub trait WarehouseDeltaWriter: Send + Sync {
+    /// Append newline-terminated JSON records of the entity
+    async fn write_lines(&self, entity: WarehouseEntity, lines: &str) -> BankingResult<()>;
+
+    /// Called once all records of the run are written
+    async fn write_manifest(&self, manifest: &DeltaManifest) -> BankingResult<()>;
+}
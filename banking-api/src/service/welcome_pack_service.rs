@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::{
+    error::BankingResult,
+    domain::{AccountStatus, GeneratedDocument},
+};
+
+/// Service for generating and retrieving account welcome packs.
+#[async_trait]
+pub trait WelcomePackService: Send + Sync {
+    /// Render, store and notify the welcome pack for an account that moved from
+    /// `previous_status` to Active. Returns `None` when the transition does not
+    /// qualify or a pack was already issued for the account.
+    async fn issue_on_activation(&self, account_id: Uuid, previous_status: AccountStatus) -> BankingResult<Option<GeneratedDocument>>;
+
+    /// Retrieve a generated document by its retrieval id.
+    async fn find_document_by_id(&self, document_id: Uuid) -> BankingResult<Option<GeneratedDocument>>;
+}
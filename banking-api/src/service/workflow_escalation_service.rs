@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use uuid::Uuid;
+
+use crate::{
+    domain::WorkflowEscalation,
+    error::BankingResult,
+};
+
+/// Escalates timed-out workflows up the initiator's reporting line until a
+/// supervisor acts on them
+#[async_trait]
+pub trait WorkflowEscalationService: Send + Sync {
+    /// Time out the workflows expired at `reference_time` and open a level-1
+    /// escalation for each, then hand escalations not actioned within the
+    /// action period to the next level. Every new escalation notifies its supervisor.
+    async fn process_escalations(&self, reference_time: DateTime<Utc>) -> BankingResult<EscalationRunSummary>;
+
+    /// Open and acknowledged escalations assigned to the supervisor, oldest first
+    async fn find_open_escalations(&self, person_id: Uuid) -> BankingResult<Vec<WorkflowEscalation>>;
+
+    /// Every escalation of the workflow, lowest level first
+    async fn find_escalations_by_workflow(&self, workflow_id: Uuid) -> BankingResult<Vec<WorkflowEscalation>>;
+
+    async fn acknowledge_escalation(&self, escalation_id: Uuid, person_id: Uuid) -> BankingResult<WorkflowEscalation>;
+
+    async fn resolve_escalation(
+        &self,
+        escalation_id: Uuid,
+        person_id: Uuid,
+        resolution_notes: Option<HeaplessString<500>>,
+    ) -> BankingResult<WorkflowEscalation>;
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct EscalationRunSummary {
+    pub workflows_timed_out: i64,
+    /// Escalations opened by this run, level 1 and above
+    pub escalations_opened: Vec<WorkflowEscalation>,
+    /// Timed-out workflows whose initiator has no supervisor to escalate to
+    pub workflows_without_supervisor: Vec<Uuid>,
+}
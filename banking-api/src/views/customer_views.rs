@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    Account, AccountHold, AccountOwnership, AccountWorkflow, ComplianceAlert, Customer, EntityReference, RiskRating,
+};
+
+/// Customer 360 view: everything a relationship manager needs about one
+/// customer, assembled in a single call. Sections with nothing to show are
+/// empty, never absent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerPortfolioView {
+    pub customer: Customer,
+    pub risk_rating: RiskRating,
+    /// Accounts the customer owns, in ownership order
+    pub accounts: Vec<CustomerAccountView>,
+    /// Active holds across all the customer's accounts, newest first
+    pub active_holds: Vec<AccountHold>,
+    /// In-progress and pending-action workflows across the accounts, newest first
+    pub open_workflows: Vec<AccountWorkflow>,
+    /// Latest compliance alerts raised on the customer, newest first
+    pub recent_alerts: Vec<ComplianceAlert>,
+    /// Entity references whose external identifier is the customer id
+    pub entity_references: Vec<EntityReference>,
+    pub assembled_at: DateTime<Utc>,
+}
+
+/// One account of the customer with its balances and the customer's ownership of it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerAccountView {
+    pub account: Account,
+    pub ownership: AccountOwnership,
+}
@@ -1 +1,2 @@
-pub mod branch_views;
\ No newline at end of file
+pub mod branch_views;
+pub mod customer_views;
\ No newline at end of file
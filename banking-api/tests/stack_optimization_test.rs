@@ -1,4 +1,4 @@
-use banking_api::domain::{Account, AccountType, AccountStatus, SigningCondition, Transaction, TransactionType, TransactionStatus, Customer, CustomerType, IdentityType, RiskRating, CustomerStatus};
+use banking_api::domain::{Account, AccountType, CurrencyCode, AccountStatus, SigningCondition, Transaction, TransactionType, TransactionStatus, DegradedFlags, Customer, CustomerType, IdentityType, RiskRating, CustomerStatus};
 use banking_api::domain::compliance::{KycCheck, CheckResult};
 use banking_api::domain::workflow::DocumentReference;
 use banking_api::domain::transaction::{TransactionAudit, TransactionAuditAction};
@@ -21,9 +21,10 @@ mod stack_optimization_tests {
             account_type: AccountType::Savings,
             account_status: AccountStatus::Active,
             signing_condition: SigningCondition::None,
-            currency: HeaplessString::try_from("USD").unwrap(),
+            currency: CurrencyCode::try_from("USD").unwrap(),
             open_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
             domicile_agency_branch_id: Uuid::new_v4(),
+            account_number: HeaplessString::try_from("10005000010000000004262").unwrap(),
             current_balance: Decimal::new(150000, 2), // $1500.00
             available_balance: Decimal::new(150000, 2),
             accrued_interest: Decimal::new(1250, 2), // $12.50
@@ -109,7 +110,7 @@ mod stack_optimization_tests {
             transaction_code: HeaplessString::try_from("DEBIT1").unwrap(),
             transaction_type: TransactionType::Debit,
             amount: Decimal::new(25000, 2), // $250.00
-            currency: HeaplessString::try_from("USD").unwrap(),
+            currency: CurrencyCode::try_from("USD").unwrap(),
             description: HeaplessString::try_from("Atm withdrawal at Main Branch").unwrap(),
             channel_id: HeaplessString::try_from("Atm").unwrap(),
             terminal_id: Some(Uuid::new_v4()),
@@ -123,6 +124,10 @@ mod stack_optimization_tests {
             requires_approval: false,
             approval_status: None,
             risk_score: Some(Decimal::new(15, 2)), // 0.15
+            degraded_flags: DegradedFlags::NONE,
+            idempotency_key: None,
+            reversal_of_transaction_id: None,
+            fx_conversion: None,
             created_at: Utc::now(),
         };
 
@@ -211,7 +216,7 @@ mod stack_optimization_tests {
         assert_eq!(doc_deserialized.document_type, "passport");
         // Hash should be deterministic for same content
         let expected_hash = blake3::hash(b"passport_scan_data_content_for_hashing");
-        assert_eq!(doc_deserialized.document_id, expected_hash);
+        assert_eq!(doc_deserialized.content_hash, expected_hash);
 
         // Test TransactionAudit with Blake3 hash
         let audit = TransactionAudit::new(
@@ -41,8 +41,8 @@ fn demonstrate_batch_usage() {
     println!("   Instead of:");
     println!("   for id in ids_to_delete {{ repo.delete(id).await?; }}");
     println!("   Use:");
-    println!("   repo.delete_batch(&ids_to_delete).await?;");
-    println!("   Benefits: Single DELETE WHERE id = ANY($1)\n");
+    println!("   repo.delete_batch(&ids_to_delete, audit_log_id).await?;");
+    println!("   Benefits: Single UPDATE WHERE id = ANY($1) that deactivates the rows, keeping their history\n");
     
     // Example 5: Batch Validation
     println!("5. Batch Validation - Pre-validating constraints");
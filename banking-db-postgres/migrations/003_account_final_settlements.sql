@@ -0,0 +1,20 @@
+-- Create ENUM types
+CREATE TYPE disbursement_method AS ENUM ('Transfer', 'CashWithdrawal', 'Check', 'HoldFunds', 'OverdraftFacility', 'StagedRelease');
+CREATE TYPE settlement_status AS ENUM ('Pending', 'Disbursed', 'Confirmed');
+
+-- Main table for model AccountFinalSettlementModel; one settlement per closed account
+CREATE TABLE account_final_settlements (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL UNIQUE,
+    settlement_date DATE NOT NULL,
+    current_balance DECIMAL(15, 2) NOT NULL,
+    accrued_interest DECIMAL(15, 2) NOT NULL,
+    closure_fees DECIMAL(15, 2) NOT NULL,
+    final_amount DECIMAL(15, 2) NOT NULL,
+    disbursement_method disbursement_method NOT NULL,
+    disbursement_reference VARCHAR(100),
+    status settlement_status NOT NULL DEFAULT 'Pending',
+    processed_by_person_id UUID NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,36 @@
+-- Create ENUM types
+CREATE TYPE screening_type AS ENUM ('Sanctions', 'PoliticallyExposed', 'AdverseMedia', 'Watchlist');
+
+-- Main table for model SanctionsScreeningModel
+CREATE TABLE sanctions_screenings (
+    id UUID PRIMARY KEY,
+    customer_id UUID NOT NULL,
+    screening_type screening_type NOT NULL,
+    screening_date TIMESTAMP WITH TIME ZONE NOT NULL,
+    screening_result VARCHAR(50) NOT NULL,
+    match_details VARCHAR(500),
+    risk_score DECIMAL(5, 2),
+    screening_provider VARCHAR(50) NOT NULL,
+    status VARCHAR(50) NOT NULL,
+    reviewed_by VARCHAR(100),
+    review_notes VARCHAR(500),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    last_updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- Batch screening looks up the latest screening per customer and type
+CREATE INDEX idx_sanctions_screenings_customer_type_date
+    ON sanctions_screenings (customer_id, screening_type, screening_date);
+
+-- Matches behind a screening hit, model SanctionsMatchModel
+CREATE TABLE sanctions_matches (
+    id UUID PRIMARY KEY,
+    screening_id UUID NOT NULL,
+    matched_name VARCHAR(100) NOT NULL,
+    confidence_score DECIMAL(5, 2) NOT NULL,
+    details VARCHAR(500),
+    list_source VARCHAR(50) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_sanctions_matches_screening ON sanctions_matches (screening_id);
@@ -0,0 +1,14 @@
+-- Main table for model LoanPenaltyAccrualModel; one assessment per loan and EOD date
+CREATE TABLE loan_penalty_accruals (
+    id UUID PRIMARY KEY,
+    loan_account_id UUID NOT NULL,
+    assessment_date DATE NOT NULL,
+    due_date DATE NOT NULL,
+    days_past_due INTEGER NOT NULL,
+    overdue_amount DECIMAL(15, 2) NOT NULL,
+    penalty_rate DECIMAL(7, 6) NOT NULL,
+    penalty_days INTEGER NOT NULL,
+    penalty_amount DECIMAL(15, 2) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (loan_account_id, assessment_date)
+);
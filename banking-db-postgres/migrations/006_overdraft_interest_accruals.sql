@@ -0,0 +1,19 @@
+-- Create ENUM types
+CREATE TYPE overdraft_position AS ENUM ('None', 'Authorized', 'Unauthorized');
+
+-- Main table for model OverdraftInterestAccrual; one accrual per account and EOD date
+CREATE TABLE overdraft_interest_accruals (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL,
+    accrual_date DATE NOT NULL,
+    position overdraft_position NOT NULL,
+    authorized_amount DECIMAL(15, 2) NOT NULL,
+    authorized_rate DECIMAL(7, 6) NOT NULL,
+    authorized_interest DECIMAL(20, 10) NOT NULL,
+    unauthorized_amount DECIMAL(15, 2) NOT NULL,
+    unauthorized_rate DECIMAL(7, 6) NOT NULL,
+    unauthorized_interest DECIMAL(20, 10) NOT NULL,
+    consecutive_unauthorized_days INTEGER NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (account_id, accrual_date)
+);
@@ -0,0 +1,35 @@
+-- Create ENUM types
+CREATE TYPE standing_order_amount_type AS ENUM ('Fixed', 'SweepAboveThreshold');
+CREATE TYPE standing_order_frequency AS ENUM ('Daily', 'Weekly', 'Monthly', 'Quarterly', 'Yearly');
+CREATE TYPE standing_order_status AS ENUM ('Active', 'Suspended', 'Completed', 'Cancelled');
+CREATE TYPE standing_order_failure_policy AS ENUM ('Skip', 'RetryNextDay', 'SuspendAfter');
+
+-- Main table for model StandingOrderModel
+CREATE TABLE standing_orders (
+    id UUID PRIMARY KEY,
+    source_account_id UUID NOT NULL,
+    beneficiary_account_id UUID,
+    external_reference VARCHAR(100),
+    amount_type standing_order_amount_type NOT NULL,
+    amount DECIMAL(15, 2) NOT NULL,
+    frequency standing_order_frequency NOT NULL,
+    start_date DATE NOT NULL,
+    end_date DATE,
+    next_due_date DATE NOT NULL,
+    next_run_date DATE NOT NULL,
+    status standing_order_status NOT NULL,
+    failure_policy standing_order_failure_policy NOT NULL,
+    max_consecutive_failures INTEGER,
+    consecutive_failures INTEGER NOT NULL DEFAULT 0,
+    last_executed_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    last_updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_by_person_id UUID NOT NULL,
+    CHECK (beneficiary_account_id IS NOT NULL OR external_reference IS NOT NULL),
+    CHECK ((failure_policy = 'SuspendAfter') = (max_consecutive_failures IS NOT NULL))
+);
+
+CREATE INDEX idx_standing_orders_source_account ON standing_orders (source_account_id);
+
+-- End of day picks up the active orders due on or before the run date
+CREATE INDEX idx_standing_orders_due ON standing_orders (next_run_date) WHERE status = 'Active';
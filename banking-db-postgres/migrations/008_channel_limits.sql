@@ -0,0 +1,14 @@
+-- Main table for model ChannelLimitModel
+CREATE TABLE channel_limits (
+    id UUID PRIMARY KEY,
+    channel_id VARCHAR(50) NOT NULL,
+    currency VARCHAR(3) NOT NULL,
+    per_transaction_max DECIMAL(15, 2),
+    daily_max DECIMAL(15, 2),
+    monthly_max DECIMAL(15, 2),
+    max_count_per_day INTEGER,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (channel_id, currency)
+);
+
@@ -0,0 +1,13 @@
+-- End-of-day GL summary, model GlSummaryLineModel
+CREATE TABLE gl_summary (
+    run_date DATE NOT NULL,
+    gl_code VARCHAR(50) NOT NULL,
+    product_id UUID NOT NULL,
+    branch_id UUID NOT NULL,
+    currency VARCHAR(3) NOT NULL,
+    debit_total DECIMAL(15, 2) NOT NULL DEFAULT 0,
+    credit_total DECIMAL(15, 2) NOT NULL DEFAULT 0,
+    transaction_count BIGINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (run_date, gl_code, product_id, branch_id, currency)
+);
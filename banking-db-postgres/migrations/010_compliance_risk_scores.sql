@@ -0,0 +1,20 @@
+-- Customer risk rating history, model ComplianceRiskScoreModel. Every
+-- recalculation adds a row; the latest one is the customer's current score.
+CREATE TABLE compliance_risk_scores (
+    id UUID PRIMARY KEY,
+    customer_id UUID NOT NULL,
+    risk_score DECIMAL(7, 2) NOT NULL,
+    risk_category VARCHAR(20) NOT NULL,
+    calculation_method VARCHAR(50) NOT NULL,
+    factors_considered VARCHAR(1000) NOT NULL,
+    calculated_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    calculated_by VARCHAR(100) NOT NULL,
+    valid_until DATE,
+    notes VARCHAR(500),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    last_updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- Latest score per customer, for rating lookups and the stale review batch
+CREATE INDEX idx_compliance_risk_scores_customer_calculated
+    ON compliance_risk_scores (customer_id, calculated_at);
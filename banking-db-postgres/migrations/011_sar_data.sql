@@ -0,0 +1,30 @@
+-- Create ENUM types
+CREATE TYPE sar_status AS ENUM ('Draft', 'Submitted', 'Filed', 'Acknowledged', 'UnderReview', 'Closed');
+
+-- Suspicious activity reports, model SarDataModel. A SAR is editable while
+-- Draft; submission records who locked it and when.
+CREATE TABLE sar_data (
+    id UUID PRIMARY KEY,
+    customer_id UUID NOT NULL,
+    reason_id UUID NOT NULL,
+    additional_details VARCHAR(500),
+    -- supporting_transaction_id_01 .. _19 of the model, in order
+    supporting_transaction_ids UUID[] NOT NULL DEFAULT '{}',
+    narrative VARCHAR(1000) NOT NULL,
+    customer_full_name VARCHAR(100) NOT NULL,
+    alert_ids UUID[] NOT NULL DEFAULT '{}',
+    account_ids UUID[] NOT NULL DEFAULT '{}',
+    generated_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    status sar_status NOT NULL DEFAULT 'Draft',
+    created_by_person_id UUID NOT NULL,
+    submitted_by_person_id UUID,
+    submitted_at TIMESTAMP WITH TIME ZONE,
+    last_updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CHECK (cardinality(supporting_transaction_ids) <= 19),
+    CHECK (status = 'Draft' OR submitted_at IS NOT NULL)
+);
+
+CREATE INDEX idx_sar_data_customer ON sar_data (customer_id);
+
+-- MLRO dashboard lists SARs by status over a period
+CREATE INDEX idx_sar_data_status_generated ON sar_data (status, generated_at);
@@ -0,0 +1,69 @@
+-- Create ENUM types
+CREATE TYPE channel_fee_type AS ENUM (
+    'TransactionFee', 'MaintenanceFee', 'ServiceFee', 'PenaltyFee',
+    'ProcessingFee', 'ComplianceFee', 'InterchangeFee', 'NetworkFee'
+);
+CREATE TYPE channel_fee_calculation_method AS ENUM (
+    'Fixed', 'Percentage', 'Tiered', 'BalanceBased', 'RuleBased', 'Hybrid'
+);
+
+-- Main table for model FeeScheduleModel
+CREATE TABLE channel_fee_schedules (
+    id UUID PRIMARY KEY,
+    schedule_name VARCHAR(100) NOT NULL,
+    channel_id UUID,
+    effective_date DATE NOT NULL,
+    expiry_date DATE,
+    currency VARCHAR(3) NOT NULL,
+    fee01_fee_item_id UUID,
+    fee02_fee_item_id UUID,
+    fee03_fee_item_id UUID,
+    fee04_fee_item_id UUID,
+    fee05_fee_item_id UUID,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CHECK (expiry_date IS NULL OR expiry_date > effective_date)
+);
+
+-- Main table for model FeeItemModel. Percentages are fractions of the
+-- amount; the tier slots of the model are read back from channel_fee_tiers.
+CREATE TABLE channel_fee_items (
+    id UUID PRIMARY KEY,
+    schedule_id UUID NOT NULL REFERENCES channel_fee_schedules(id),
+    fee_code VARCHAR(20) NOT NULL,
+    fee_name VARCHAR(100) NOT NULL,
+    fee_type channel_fee_type NOT NULL,
+    calculation_method channel_fee_calculation_method NOT NULL,
+    fee_amount DECIMAL(15, 2),
+    fee_percentage DECIMAL(9, 6),
+    minimum_fee DECIMAL(15, 2),
+    maximum_fee DECIMAL(15, 2),
+    -- applies_to_transaction_type_01 .. _11 of the model, in order
+    applies_to_transaction_types VARCHAR(20)[] NOT NULL DEFAULT '{}',
+    is_waivable BOOLEAN NOT NULL DEFAULT TRUE,
+    requires_approval_for_waiver BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (schedule_id, fee_code),
+    CHECK (cardinality(applies_to_transaction_types) <= 11),
+    CHECK (minimum_fee IS NULL OR maximum_fee IS NULL OR minimum_fee <= maximum_fee),
+    CHECK (calculation_method <> 'Fixed' OR fee_amount IS NOT NULL),
+    CHECK (calculation_method <> 'Percentage' OR fee_percentage IS NOT NULL)
+);
+
+-- Main table for model FeeTierModel. Contiguity of the tiers of an item is
+-- checked when the item is saved.
+CREATE TABLE channel_fee_tiers (
+    id UUID PRIMARY KEY,
+    fee_item_id UUID NOT NULL REFERENCES channel_fee_items(id) ON DELETE CASCADE,
+    tier_name VARCHAR(50) NOT NULL,
+    min_amount DECIMAL(15, 2) NOT NULL,
+    max_amount DECIMAL(15, 2),
+    fee_amount DECIMAL(15, 2),
+    fee_percentage DECIMAL(9, 6),
+    tier_order INTEGER NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (fee_item_id, tier_order),
+    CHECK (max_amount IS NULL OR max_amount > min_amount),
+    CHECK (fee_amount IS NOT NULL OR fee_percentage IS NOT NULL)
+);
@@ -0,0 +1,30 @@
+-- Create ENUM types
+CREATE TYPE eod_run_status AS ENUM ('Running', 'Failed', 'Completed');
+CREATE TYPE eod_step_status AS ENUM ('Pending', 'Running', 'Failed', 'Completed');
+
+-- End-of-day runs, model EodRunModel
+CREATE TABLE eod_runs (
+    id UUID PRIMARY KEY,
+    run_date DATE NOT NULL UNIQUE,
+    status eod_run_status NOT NULL,
+    started_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    finished_at TIMESTAMP WITH TIME ZONE,
+    forced_reruns INTEGER NOT NULL DEFAULT 0
+);
+
+-- Step progress and checkpoints, model EodRunStepModel
+CREATE TABLE eod_run_steps (
+    id UUID PRIMARY KEY,
+    eod_run_id UUID NOT NULL REFERENCES eod_runs(id),
+    run_date DATE NOT NULL,
+    step_name VARCHAR(50) NOT NULL,
+    step_order INTEGER NOT NULL,
+    status eod_step_status NOT NULL,
+    started_at TIMESTAMP WITH TIME ZONE,
+    finished_at TIMESTAMP WITH TIME ZONE,
+    records_processed BIGINT NOT NULL DEFAULT 0,
+    records_failed BIGINT NOT NULL DEFAULT 0,
+    checkpoint_cursor UUID,
+    error VARCHAR(500),
+    UNIQUE (eod_run_id, step_name)
+);
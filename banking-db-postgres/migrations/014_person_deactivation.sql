@@ -0,0 +1,18 @@
+-- Soft deletion for the person module. delete_batch stamps these columns instead
+-- of removing the row, so compliance can still read what a record looked like.
+-- deactivated_by holds the AuditLog.id of the deactivating change.
+ALTER TABLE person
+    ADD COLUMN deactivated_at TIMESTAMP WITH TIME ZONE,
+    ADD COLUMN deactivated_by UUID;
+
+ALTER TABLE location
+    ADD COLUMN deactivated_at TIMESTAMP WITH TIME ZONE,
+    ADD COLUMN deactivated_by UUID;
+
+ALTER TABLE locality
+    ADD COLUMN deactivated_at TIMESTAMP WITH TIME ZONE,
+    ADD COLUMN deactivated_by UUID;
+
+ALTER TABLE entity_reference
+    ADD COLUMN deactivated_at TIMESTAMP WITH TIME ZONE,
+    ADD COLUMN deactivated_by UUID;
@@ -0,0 +1,3 @@
+-- Proximity searches compare coordinates numerically; only active, geocoded locations are searched
+CREATE INDEX idx_location_coordinates ON location (latitude, longitude)
+    WHERE deactivated_at IS NULL AND latitude IS NOT NULL AND longitude IS NOT NULL;
@@ -0,0 +1,21 @@
+-- Create ENUM types
+CREATE TYPE message_channel AS ENUM ('Sms', 'Email', 'InApp');
+
+-- Customer message wording per language, model MessageTemplateModel
+CREATE TABLE message_templates (
+    id UUID PRIMARY KEY,
+    template_code VARCHAR(50) NOT NULL,
+    language_code CHAR(3) NOT NULL,
+    channel message_channel NOT NULL,
+    subject VARCHAR(100),
+    body VARCHAR(500) NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (template_code, language_code)
+);
+
+-- Default-language wording of the notices queued by the dormancy and collection steps
+INSERT INTO message_templates (id, template_code, language_code, channel, subject, body) VALUES
+    ('6f1c2a4e-8d3b-4c5a-9e7f-0a1b2c3d4e01', 'DORMANCY_NOTICE', 'eng', 'Sms', NULL,
+     'Your account ending {account_suffix} has been classified dormant after a period of inactivity. Visit a branch to reactivate it.'),
+    ('6f1c2a4e-8d3b-4c5a-9e7f-0a1b2c3d4e02', 'COLLECTION_REMINDER', 'eng', 'Sms', NULL,
+     'Your contribution of {amount} is due on {due_date}.');
@@ -0,0 +1,39 @@
+-- Create ENUM types
+CREATE TYPE reconciliation_status AS ENUM ('InProgress', 'Completed', 'Failed', 'RequiresManualReview');
+CREATE TYPE reconciliation_discrepancy_type AS ENUM ('MissingOurs', 'MissingTheirs', 'AmountMismatch', 'Duplicate');
+
+-- Channel settlement reconciliations, model ChannelReconciliationReportModel
+CREATE TABLE channel_reconciliation_reports (
+    id UUID PRIMARY KEY,
+    channel_id UUID NOT NULL,
+    reconciliation_date DATE NOT NULL,
+    total_transactions BIGINT NOT NULL,
+    total_amount DECIMAL(15, 2) NOT NULL,
+    status reconciliation_status NOT NULL,
+    generated_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    completed_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_channel_reconciliation_reports_channel ON channel_reconciliation_reports (channel_id, reconciliation_date);
+
+-- Lines that disagree with the external settlement file, model ReconciliationDiscrepancyModel
+CREATE TABLE reconciliation_discrepancies (
+    id UUID PRIMARY KEY,
+    report_id UUID NOT NULL REFERENCES channel_reconciliation_reports(id),
+    discrepancy_type reconciliation_discrepancy_type NOT NULL,
+    transaction_id UUID,
+    external_reference VARCHAR(100),
+    description VARCHAR(200) NOT NULL,
+    expected_amount DECIMAL(15, 2) NOT NULL,
+    actual_amount DECIMAL(15, 2) NOT NULL,
+    difference DECIMAL(15, 2) NOT NULL,
+    resolved BOOLEAN NOT NULL DEFAULT FALSE,
+    resolution_notes VARCHAR(500),
+    resolved_by UUID,
+    resolved_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CHECK (transaction_id IS NOT NULL OR external_reference IS NOT NULL)
+);
+
+CREATE INDEX idx_reconciliation_discrepancies_unresolved ON reconciliation_discrepancies (report_id) WHERE NOT resolved;
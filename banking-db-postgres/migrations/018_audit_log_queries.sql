@@ -0,0 +1,3 @@
+-- Investigators page through one person's changes, newest first, within a time range.
+-- Entity history needs no new index: each audit table's primary key leads with the entity id.
+CREATE INDEX idx_audit_log_actor_updated_at ON audit_log (updated_by_person_id, updated_at DESC, id DESC);
@@ -0,0 +1,39 @@
+-- Tamper evidence for the audit log. Entries form a chain in sequence_number order:
+-- record_hash = sha256(prev_hash || id || updated_at || updated_by_person_id || sequence_number),
+-- with updated_at in epoch microseconds and both integers as big-endian int8.
+-- The first entry's prev_hash is 32 zero bytes.
+ALTER TABLE audit_log
+    ADD COLUMN sequence_number BIGINT,
+    ADD COLUMN prev_hash BYTEA,
+    ADD COLUMN record_hash BYTEA;
+
+-- Chain the entries written before this migration in the order they were written
+DO $$
+DECLARE
+    entry RECORD;
+    prev BYTEA := decode(repeat('00', 32), 'hex');
+    seq BIGINT := 0;
+BEGIN
+    FOR entry IN SELECT id, updated_at, updated_by_person_id FROM audit_log ORDER BY updated_at, id LOOP
+        seq := seq + 1;
+        UPDATE audit_log
+        SET sequence_number = seq,
+            prev_hash = prev,
+            record_hash = sha256(
+                prev
+                || uuid_send(entry.id)
+                || int8send((extract(epoch FROM entry.updated_at) * 1000000)::BIGINT)
+                || uuid_send(entry.updated_by_person_id)
+                || int8send(seq))
+        WHERE id = entry.id
+        RETURNING record_hash INTO prev;
+    END LOOP;
+END $$;
+
+ALTER TABLE audit_log
+    ALTER COLUMN sequence_number SET NOT NULL,
+    ALTER COLUMN prev_hash SET NOT NULL,
+    ALTER COLUMN record_hash SET NOT NULL;
+
+-- Two entries claiming the same position would fork the chain
+CREATE UNIQUE INDEX idx_audit_log_sequence_number ON audit_log (sequence_number);
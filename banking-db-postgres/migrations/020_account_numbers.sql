@@ -0,0 +1,35 @@
+-- How account numbers are built per branch, optionally per product, model AccountNumberSchemeModel
+CREATE TABLE account_number_schemes (
+    id UUID PRIMARY KEY,
+    branch_id UUID NOT NULL,
+    product_id UUID,
+    bank_code VARCHAR(12) NOT NULL,
+    branch_code VARCHAR(10) NOT NULL,
+    serial_length SMALLINT NOT NULL CHECK (serial_length BETWEEN 1 AND 18),
+    iban_country_code CHAR(2),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    last_updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_by_person_id UUID NOT NULL
+);
+
+-- One branch default (NULL product) and one scheme per product at each branch
+CREATE UNIQUE INDEX idx_account_number_schemes_scope
+    ON account_number_schemes (branch_id, COALESCE(product_id, '00000000-0000-0000-0000-000000000000'::UUID));
+
+-- Last serial handed out per branch. Advanced with a single upsert, so concurrent
+-- openings at a branch queue on the row lock and never share a serial.
+CREATE TABLE account_number_sequences (
+    branch_id UUID PRIMARY KEY,
+    last_serial BIGINT NOT NULL CHECK (last_serial > 0)
+);
+
+-- Accounts opened before account numbers existed keep their id, without dashes, as number
+DO $$
+BEGIN
+    IF to_regclass('accounts') IS NOT NULL THEN
+        ALTER TABLE accounts ADD COLUMN IF NOT EXISTS account_number VARCHAR(34);
+        UPDATE accounts SET account_number = upper(replace(id::text, '-', '')) WHERE account_number IS NULL;
+        ALTER TABLE accounts ALTER COLUMN account_number SET NOT NULL;
+        CREATE UNIQUE INDEX IF NOT EXISTS idx_accounts_account_number ON accounts (account_number);
+    END IF;
+END $$;
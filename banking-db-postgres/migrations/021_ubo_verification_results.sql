@@ -0,0 +1,13 @@
+-- Latest UBO structure validation of each corporate customer, model UboVerificationResultModel.
+-- Revalidating a customer replaces its row.
+CREATE TABLE ubo_verification_results (
+    corporate_customer_id UUID PRIMARY KEY,
+    -- ubo_chain_link_id_01 .. _05 of the model, in order
+    ubo_chain_link_ids UUID[] NOT NULL DEFAULT '{}',
+    verification_complete BOOLEAN NOT NULL,
+    -- requires_update_01 .. _05 of the model, in order
+    requires_update VARCHAR(100)[] NOT NULL DEFAULT '{}',
+    verified_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CHECK (cardinality(ubo_chain_link_ids) <= 5),
+    CHECK (cardinality(requires_update) <= 5)
+);
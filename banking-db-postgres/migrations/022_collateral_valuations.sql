@@ -0,0 +1,54 @@
+-- Create ENUM types
+CREATE TYPE valuation_method AS ENUM (
+    'MarketComparison', 'IncomeApproach', 'CostApproach', 'ExpertAppraisal', 'AuctionValue', 'BookValue', 'MarkToMarket'
+);
+CREATE TYPE collateral_alert_type AS ENUM (
+    'ValuationDue', 'ValuationOverdue', 'InsuranceExpiring', 'InsuranceExpired', 'PerfectionExpiring',
+    'PerfectionExpired', 'LtvBreach', 'CovenantBreach', 'MaintenanceRequired', 'DocumentationMissing',
+    'EnvironmentalRisk', 'MarketValueDecline'
+);
+CREATE TYPE alert_severity AS ENUM ('Low', 'Medium', 'High', 'Critical');
+CREATE TYPE collateral_alert_status AS ENUM ('Open', 'InProgress', 'Resolved', 'Dismissed', 'Escalated');
+
+-- Valuation history of each collateral, model CollateralValuationModel. The latest
+-- one is also copied onto the collateral as its current market value.
+CREATE TABLE collateral_valuations (
+    id UUID PRIMARY KEY,
+    collateral_id UUID NOT NULL,
+    valuation_date DATE NOT NULL,
+    valuation_method valuation_method NOT NULL,
+    market_value DECIMAL(15,2) NOT NULL CHECK (market_value >= 0),
+    currency CHAR(3) NOT NULL,
+    forced_sale_value DECIMAL(15,2),
+    valuer_person_id UUID NOT NULL,
+    appraiser_name VARCHAR(255) NOT NULL,
+    appraiser_license VARCHAR(100),
+    valuation_report_reference VARCHAR(100) NOT NULL,
+    validity_period_months INTEGER NOT NULL,
+    next_valuation_due DATE NOT NULL,
+    valuation_notes VARCHAR(1000),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    created_by_person_id UUID NOT NULL
+);
+
+-- Latest valuation of a collateral
+CREATE INDEX idx_collateral_valuations_collateral_date
+    ON collateral_valuations (collateral_id, valuation_date DESC, created_at DESC);
+
+-- Alerts raised on collaterals, model CollateralAlertModel
+CREATE TABLE collateral_alerts (
+    id UUID PRIMARY KEY,
+    collateral_id UUID NOT NULL,
+    alert_type collateral_alert_type NOT NULL,
+    severity alert_severity NOT NULL,
+    message VARCHAR(500) NOT NULL,
+    trigger_date TIMESTAMP WITH TIME ZONE NOT NULL,
+    due_date TIMESTAMP WITH TIME ZONE,
+    status collateral_alert_status NOT NULL DEFAULT 'Open',
+    assigned_to_person_id UUID,
+    resolution_notes VARCHAR(1000),
+    resolved_at TIMESTAMP WITH TIME ZONE,
+    resolved_by_person_id UUID
+);
+
+CREATE INDEX idx_collateral_alerts_collateral ON collateral_alerts (collateral_id, trigger_date DESC);
@@ -0,0 +1,10 @@
+-- Contra transactions point at the transaction they reverse, model TransactionModel.
+-- The unique index lets a transaction be reversed at most once.
+DO $$
+BEGIN
+    IF to_regclass('transactions') IS NOT NULL THEN
+        ALTER TABLE transactions ADD COLUMN IF NOT EXISTS reversal_of_transaction_id UUID REFERENCES transactions(id);
+        CREATE UNIQUE INDEX IF NOT EXISTS idx_transactions_reversal_of
+            ON transactions (reversal_of_transaction_id) WHERE reversal_of_transaction_id IS NOT NULL;
+    END IF;
+END $$;
@@ -0,0 +1,5 @@
+-- Default-language wording of the warning queued by the inactivity fee step
+INSERT INTO message_templates (id, template_code, language_code, channel, subject, body) VALUES
+    ('6f1c2a4e-8d3b-4c5a-9e7f-0a1b2c3d4e03', 'INACTIVITY_FEE_WARNING', 'eng', 'Sms', NULL,
+     'Your account ending {account_suffix} has been inactive for a long time. An inactivity fee will be charged from {fee_date} unless you use it before then.')
+ON CONFLICT (template_code, language_code) DO NOTHING;
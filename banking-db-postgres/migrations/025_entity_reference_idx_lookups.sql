@@ -0,0 +1,10 @@
+-- Lazily loaded entity reference caches answer secondary lookups from the
+-- index table, so it needs the external id hash and indexes on both keys.
+-- Rows written before this column existed cannot be read back by the
+-- repository at all, so the temporary default never reaches a cache.
+ALTER TABLE entity_reference_idx ADD COLUMN reference_external_id_hash BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE entity_reference_idx ALTER COLUMN reference_external_id_hash DROP DEFAULT;
+
+CREATE INDEX idx_entity_reference_idx_person_id ON entity_reference_idx (person_id);
+CREATE INDEX idx_entity_reference_idx_reference_external_id_hash
+    ON entity_reference_idx (reference_external_id_hash);
@@ -0,0 +1,17 @@
+-- Per-channel contact preferences of a person, model ContactPreferenceModel
+CREATE TABLE contact_preference (
+    id UUID PRIMARY KEY,
+    person_id UUID NOT NULL REFERENCES person(id),
+    channel message_channel NOT NULL,
+    allowed BOOLEAN NOT NULL DEFAULT TRUE,
+    quiet_hours_start TIME,
+    quiet_hours_end TIME,
+    preferred_language CHAR(3),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (person_id, channel),
+    CONSTRAINT ck_contact_preference_quiet_hours
+        CHECK ((quiet_hours_start IS NULL) = (quiet_hours_end IS NULL))
+);
+
+-- Regulatory templates are sent even to people who opted out of a channel
+ALTER TABLE message_templates ADD COLUMN regulatory BOOLEAN NOT NULL DEFAULT FALSE;
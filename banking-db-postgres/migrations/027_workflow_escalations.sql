@@ -0,0 +1,31 @@
+-- Create ENUM types
+CREATE TYPE workflow_escalation_status AS ENUM ('Open', 'Acknowledged', 'Resolved', 'Escalated');
+
+-- Supervisor escalations of timed-out workflows, model WorkflowEscalationModel
+CREATE TABLE workflow_escalations (
+    id UUID PRIMARY KEY,
+    workflow_id UUID NOT NULL,
+    escalated_to_person_id UUID NOT NULL,
+    level SMALLINT NOT NULL CHECK (level > 0),
+    status workflow_escalation_status NOT NULL DEFAULT 'Open',
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    action_due_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    acknowledged_at TIMESTAMP WITH TIME ZONE,
+    resolved_at TIMESTAMP WITH TIME ZONE,
+    resolved_by_person_id UUID,
+    resolution_notes VARCHAR(500),
+    last_updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (workflow_id, level)
+);
+
+-- Supervisor queues and the overdue scan
+CREATE INDEX idx_workflow_escalations_person_open ON workflow_escalations (escalated_to_person_id, created_at)
+    WHERE status IN ('Open', 'Acknowledged');
+CREATE INDEX idx_workflow_escalations_due ON workflow_escalations (action_due_at)
+    WHERE status = 'Open';
+
+-- Default-language wording of the notice sent to the supervisor of each escalation level
+INSERT INTO message_templates (id, template_code, language_code, channel, subject, body) VALUES
+    ('6f1c2a4e-8d3b-4c5a-9e7f-0a1b2c3d4e04', 'WORKFLOW_ESCALATION', 'eng', 'Email', 'Workflow escalated to you',
+     'Workflow {workflow_id} ({workflow_type}) on account ending {account_suffix} timed out and is escalated to you at level {level}. Please act by {action_due_at}.')
+ON CONFLICT (template_code, language_code) DO NOTHING;
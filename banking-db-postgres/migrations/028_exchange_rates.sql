@@ -0,0 +1,28 @@
+-- Published exchange rates, model ExchangeRateModel. A rate applies from
+-- effective_at until the next rate of the pair.
+CREATE TABLE IF NOT EXISTS exchange_rates (
+    from_currency VARCHAR(3) NOT NULL,
+    to_currency VARCHAR(3) NOT NULL,
+    rate DECIMAL(20, 10) NOT NULL CHECK (rate > 0),
+    effective_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    source VARCHAR(50) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (from_currency, to_currency, effective_at),
+    CHECK (from_currency <> to_currency)
+);
+
+-- Foreign-currency transactions keep what was submitted next to the booked
+-- amount, model TransactionModel
+DO $$
+BEGIN
+    IF to_regclass('transactions') IS NOT NULL THEN
+        ALTER TABLE transactions ADD COLUMN IF NOT EXISTS original_amount DECIMAL(15, 3);
+        ALTER TABLE transactions ADD COLUMN IF NOT EXISTS original_currency VARCHAR(3);
+        ALTER TABLE transactions ADD COLUMN IF NOT EXISTS exchange_rate DECIMAL(20, 10);
+        ALTER TABLE transactions DROP CONSTRAINT IF EXISTS ck_transactions_fx_conversion;
+        ALTER TABLE transactions ADD CONSTRAINT ck_transactions_fx_conversion CHECK (
+            (original_amount IS NULL AND original_currency IS NULL AND exchange_rate IS NULL)
+            OR (original_amount IS NOT NULL AND original_currency IS NOT NULL AND exchange_rate IS NOT NULL)
+        );
+    END IF;
+END $$;
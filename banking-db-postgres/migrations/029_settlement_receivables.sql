@@ -0,0 +1,6 @@
+-- A final settlement below zero is owed by the customer and recovered
+-- instead of disbursed
+ALTER TYPE settlement_status ADD VALUE IF NOT EXISTS 'Receivable' AFTER 'Pending';
+
+-- Fees not yet posted when the account was settled, model AccountFinalSettlementModel
+ALTER TABLE account_final_settlements ADD COLUMN IF NOT EXISTS pending_fees DECIMAL(15, 2) NOT NULL DEFAULT 0;
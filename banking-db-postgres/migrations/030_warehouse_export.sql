@@ -0,0 +1,37 @@
+-- Create ENUM types
+CREATE TYPE warehouse_entity AS ENUM ('Account', 'Transaction', 'Customer', 'Workflow');
+CREATE TYPE warehouse_export_mode AS ENUM ('Incremental', 'FullSnapshot');
+
+-- Deleted rows of exported tables, model WarehouseTombstoneModel
+CREATE TABLE warehouse_tombstones (
+    id UUID PRIMARY KEY,
+    entity warehouse_entity NOT NULL,
+    entity_id UUID NOT NULL,
+    deleted_at TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+-- Tombstones are read per entity in (deleted_at, id) keyset order
+CREATE INDEX idx_warehouse_tombstones_entity_deleted_at ON warehouse_tombstones (entity, deleted_at, id);
+
+-- Completed export runs, model WarehouseExportRunModel; the latest holds the watermark
+CREATE TABLE warehouse_export_runs (
+    id UUID PRIMARY KEY,
+    mode warehouse_export_mode NOT NULL,
+    schema_version INTEGER NOT NULL,
+    from_watermark TIMESTAMP WITH TIME ZONE,
+    to_watermark TIMESTAMP WITH TIME ZONE NOT NULL,
+    total_rows BIGINT NOT NULL,
+    completed_at TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE INDEX idx_warehouse_export_runs_completed_at ON warehouse_export_runs (completed_at DESC);
+
+-- Change capture reads transactions by COALESCE(updated_at, created_at); posted
+-- transactions only get updated_at once changed after posting
+DO $$
+BEGIN
+    IF to_regclass('transactions') IS NOT NULL THEN
+        ALTER TABLE transactions ADD COLUMN IF NOT EXISTS updated_at TIMESTAMP WITH TIME ZONE;
+        CREATE INDEX IF NOT EXISTS idx_transactions_changed_at ON transactions ((COALESCE(updated_at, created_at)), id);
+    END IF;
+END $$;
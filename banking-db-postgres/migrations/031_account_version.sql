@@ -0,0 +1,8 @@
+-- Optimistic locking for account updates, model AccountModel. An update only
+-- writes the row still at the version it read, and bumps it.
+DO $$
+BEGIN
+    IF to_regclass('accounts') IS NOT NULL THEN
+        ALTER TABLE accounts ADD COLUMN IF NOT EXISTS version INTEGER NOT NULL DEFAULT 0;
+    END IF;
+END $$;
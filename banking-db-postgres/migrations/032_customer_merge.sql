@@ -0,0 +1,13 @@
+-- A merged duplicate keeps its row, marked Merged and pointing at the surviving
+-- customer, model CustomerModel
+DO $$
+BEGIN
+    IF to_regtype('customer_status') IS NOT NULL THEN
+        ALTER TYPE customer_status ADD VALUE IF NOT EXISTS 'Merged';
+    END IF;
+    IF to_regclass('customers') IS NOT NULL THEN
+        ALTER TABLE customers ADD COLUMN IF NOT EXISTS duplicate_of_customer_id UUID REFERENCES customers(id);
+        CREATE INDEX IF NOT EXISTS idx_customers_duplicate_of
+            ON customers (duplicate_of_customer_id) WHERE duplicate_of_customer_id IS NOT NULL;
+    END IF;
+END $$;
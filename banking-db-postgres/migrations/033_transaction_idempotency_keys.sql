@@ -0,0 +1,11 @@
+-- Client idempotency keys, unique per channel, model TransactionModel. Inserts
+-- rely on the unique index through ON CONFLICT (channel_id, idempotency_key);
+-- postings without a key leave it NULL and never conflict.
+DO $$
+BEGIN
+    IF to_regclass('transactions') IS NOT NULL THEN
+        ALTER TABLE transactions ADD COLUMN IF NOT EXISTS idempotency_key VARCHAR(64);
+        CREATE UNIQUE INDEX IF NOT EXISTS idx_transactions_channel_idempotency_key
+            ON transactions (channel_id, idempotency_key);
+    END IF;
+END $$;
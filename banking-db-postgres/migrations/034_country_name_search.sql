@@ -0,0 +1,49 @@
+-- Country names lower-cased with their accents folded, written by the repository
+-- with fold_country_name. Type-ahead search matches prefixes against these
+-- instead of folding in SQL, which is byte-wise on a SQL_ASCII database.
+ALTER TABLE country
+    ADD COLUMN name_l1_search VARCHAR(100),
+    ADD COLUMN name_l2_search VARCHAR(100),
+    ADD COLUMN name_l3_search VARCHAR(100);
+
+-- Fold existing rows the same way, upper-case letters included. replace()
+-- compares whole substrings, so it works whatever the server encoding.
+DO $$
+DECLARE
+    fold RECORD;
+BEGIN
+    UPDATE country SET
+        name_l1_search = lower(name_l1),
+        name_l2_search = lower(name_l2),
+        name_l3_search = lower(name_l3);
+
+    FOR fold IN
+        SELECT * FROM (VALUES
+            ('à', 'a'), ('á', 'a'), ('â', 'a'), ('ã', 'a'), ('ä', 'a'), ('å', 'a'),
+            ('À', 'a'), ('Á', 'a'), ('Â', 'a'), ('Ã', 'a'), ('Ä', 'a'), ('Å', 'a'),
+            ('ç', 'c'), ('Ç', 'c'),
+            ('è', 'e'), ('é', 'e'), ('ê', 'e'), ('ë', 'e'),
+            ('È', 'e'), ('É', 'e'), ('Ê', 'e'), ('Ë', 'e'),
+            ('ì', 'i'), ('í', 'i'), ('î', 'i'), ('ï', 'i'),
+            ('Ì', 'i'), ('Í', 'i'), ('Î', 'i'), ('Ï', 'i'),
+            ('ñ', 'n'), ('Ñ', 'n'),
+            ('ò', 'o'), ('ó', 'o'), ('ô', 'o'), ('õ', 'o'), ('ö', 'o'), ('ø', 'o'),
+            ('Ò', 'o'), ('Ó', 'o'), ('Ô', 'o'), ('Õ', 'o'), ('Ö', 'o'), ('Ø', 'o'),
+            ('ù', 'u'), ('ú', 'u'), ('û', 'u'), ('ü', 'u'),
+            ('Ù', 'u'), ('Ú', 'u'), ('Û', 'u'), ('Ü', 'u'),
+            ('ý', 'y'), ('ÿ', 'y'), ('Ý', 'y'), ('Ÿ', 'y')
+        ) AS folds(accented, base)
+    LOOP
+        UPDATE country SET
+            name_l1_search = replace(name_l1_search, fold.accented, fold.base),
+            name_l2_search = replace(name_l2_search, fold.accented, fold.base),
+            name_l3_search = replace(name_l3_search, fold.accented, fold.base);
+    END LOOP;
+END $$;
+
+ALTER TABLE country ALTER COLUMN name_l1_search SET NOT NULL;
+
+-- Anchored LIKE patterns can use these under any collation
+CREATE INDEX idx_country_name_l1_search ON country (name_l1_search text_pattern_ops);
+CREATE INDEX idx_country_name_l2_search ON country (name_l2_search text_pattern_ops);
+CREATE INDEX idx_country_name_l3_search ON country (name_l3_search text_pattern_ops);
@@ -0,0 +1,19 @@
+-- Create ENUM types
+CREATE TYPE provisioning_bucket AS ENUM ('Current', 'Bucket1', 'Bucket2', 'Bucket3', 'Bucket4');
+
+-- End-of-day balances with the overdrawn-day counter, model AccountBalanceSnapshotModel
+CREATE TABLE account_balance_snapshots (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL,
+    snapshot_date DATE NOT NULL,
+    closing_balance DECIMAL(15, 2) NOT NULL,
+    consecutive_overdrawn_days INTEGER NOT NULL CHECK (consecutive_overdrawn_days >= 0),
+    provisioning_bucket provisioning_bucket NOT NULL,
+    previous_provisioning_bucket provisioning_bucket,
+    bucket_transition BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (account_id, snapshot_date)
+);
+
+-- Provisioning reports read every account's snapshot for one date
+CREATE INDEX idx_account_balance_snapshots_date ON account_balance_snapshots (snapshot_date);
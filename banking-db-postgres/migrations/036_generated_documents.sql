@@ -0,0 +1,31 @@
+-- Create ENUM types
+CREATE TYPE generated_document_type AS ENUM ('WelcomePack');
+CREATE TYPE document_notification_status AS ENUM ('Queued', 'Sent', 'Failed');
+
+-- Documents rendered for a customer, model GeneratedDocumentModel
+CREATE TABLE generated_documents (
+    id UUID PRIMARY KEY,
+    document_type generated_document_type NOT NULL,
+    account_id UUID NOT NULL,
+    customer_id UUID NOT NULL,
+    language_code CHAR(3) NOT NULL,
+    content TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_generated_documents_account ON generated_documents (account_id, document_type, created_at);
+
+-- Notices telling a customer where to retrieve a document, model DocumentNotificationModel
+CREATE TABLE document_notifications (
+    id UUID PRIMARY KEY,
+    document_id UUID NOT NULL REFERENCES generated_documents(id),
+    customer_id UUID NOT NULL,
+    retrieval_reference VARCHAR(50) NOT NULL,
+    language_code CHAR(3) NOT NULL,
+    status document_notification_status NOT NULL DEFAULT 'Queued',
+    queued_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    sent_at TIMESTAMP WITH TIME ZONE
+);
+
+-- The sender drains queued notices oldest first
+CREATE INDEX idx_document_notifications_queued ON document_notifications (queued_at) WHERE status = 'Queued';
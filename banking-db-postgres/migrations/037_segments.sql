@@ -0,0 +1,30 @@
+-- Customer segments defined by stored criteria, model SegmentModel
+CREATE TABLE segments (
+    id UUID PRIMARY KEY,
+    code VARCHAR(50) NOT NULL UNIQUE,
+    name VARCHAR(100) NOT NULL,
+    description VARCHAR(255),
+    criteria JSONB NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    last_updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_by_person_id UUID NOT NULL
+);
+
+-- Periods a customer spent in a segment, model SegmentMembershipModel; an open
+-- membership has no exited_at
+CREATE TABLE segment_memberships (
+    id UUID PRIMARY KEY,
+    segment_id UUID NOT NULL REFERENCES segments(id),
+    customer_id UUID NOT NULL,
+    entered_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    exited_at TIMESTAMP WITH TIME ZONE
+);
+
+-- At most one open membership per customer and segment
+CREATE UNIQUE INDEX idx_segment_memberships_open ON segment_memberships (segment_id, customer_id)
+    WHERE exited_at IS NULL;
+CREATE INDEX idx_segment_memberships_customer ON segment_memberships (customer_id) WHERE exited_at IS NULL;
+-- Change feeds read entries and exits after a point in time
+CREATE INDEX idx_segment_memberships_entered_at ON segment_memberships (segment_id, entered_at);
+CREATE INDEX idx_segment_memberships_exited_at ON segment_memberships (segment_id, exited_at);
@@ -0,0 +1,24 @@
+-- Create ENUM types
+CREATE TYPE guarantor_type AS ENUM ('Customer', 'Person');
+CREATE TYPE guarantor_status AS ENUM ('Active', 'Released');
+
+-- Customers or persons standing guarantee for a loan, model LoanGuarantorModel
+CREATE TABLE loan_guarantors (
+    id UUID PRIMARY KEY,
+    loan_account_id UUID NOT NULL,
+    guarantor_type guarantor_type NOT NULL,
+    guarantor_id UUID NOT NULL,
+    guarantee_amount DECIMAL(15, 2),
+    document_reference VARCHAR(100),
+    status guarantor_status NOT NULL DEFAULT 'Active',
+    released_at TIMESTAMP WITH TIME ZONE,
+    released_by_person_id UUID,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    last_updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_by_person_id UUID NOT NULL
+);
+
+CREATE INDEX idx_loan_guarantors_loan ON loan_guarantors (loan_account_id, created_at);
+
+-- Exposure of a guarantor across the loans they still guarantee
+CREATE INDEX idx_loan_guarantors_guarantor ON loan_guarantors (guarantor_id) WHERE status = 'Active';
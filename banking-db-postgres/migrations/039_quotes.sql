@@ -0,0 +1,22 @@
+-- Create ENUM types
+CREATE TYPE quote_type AS ENUM ('Savings', 'Loan');
+CREATE TYPE quote_status AS ENUM ('Active', 'Converted', 'Expired');
+
+-- Product simulations offered to a prospect or customer, model QuoteModel
+CREATE TABLE quotes (
+    id UUID PRIMARY KEY,
+    quote_type quote_type NOT NULL,
+    product_id UUID NOT NULL,
+    customer_id UUID,
+    simulation JSONB NOT NULL,
+    status quote_status NOT NULL DEFAULT 'Active',
+    expires_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    converted_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    created_by_person_id UUID NOT NULL
+);
+
+CREATE INDEX idx_quotes_customer ON quotes (customer_id, created_at DESC);
+
+-- The expiry sweep only looks at active quotes
+CREATE INDEX idx_quotes_active_expires_at ON quotes (expires_at) WHERE status = 'Active';
@@ -0,0 +1,49 @@
+-- Create ENUM types
+CREATE TYPE cheque_book_status AS ENUM ('Requested', 'Issued', 'Cancelled');
+CREATE TYPE cheque_status AS ENUM ('Unused', 'Presented', 'Paid', 'Bounced', 'Stopped');
+CREATE TYPE cheque_return_reason AS ENUM (
+    'InsufficientFunds', 'PaymentStopped', 'DuplicatePresentment', 'UnknownCheque', 'AccountNotOperational'
+);
+
+-- Named counters advanced with a single upsert, e.g. 'cheque_serial:<account id>'
+-- for the next cheque serial of an account
+CREATE TABLE sequence_counters (
+    sequence_name VARCHAR(100) PRIMARY KEY,
+    next_value BIGINT NOT NULL CHECK (next_value > 0)
+);
+
+-- Cheque books requested for an account, model ChequeBookModel
+CREATE TABLE cheque_books (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL,
+    first_serial_number BIGINT NOT NULL,
+    last_serial_number BIGINT NOT NULL,
+    status cheque_book_status NOT NULL DEFAULT 'Requested',
+    requested_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    requested_by_person_id UUID NOT NULL,
+    issued_at TIMESTAMP WITH TIME ZONE,
+    CHECK (last_serial_number >= first_serial_number)
+);
+
+CREATE INDEX idx_cheque_books_account ON cheque_books (account_id, first_serial_number);
+
+-- One row per leaf of a cheque book, model ChequeModel
+CREATE TABLE cheques (
+    id UUID PRIMARY KEY,
+    cheque_book_id UUID NOT NULL REFERENCES cheque_books(id),
+    account_id UUID NOT NULL,
+    serial_number BIGINT NOT NULL,
+    status cheque_status NOT NULL DEFAULT 'Unused',
+    amount DECIMAL(15, 2),
+    presented_at TIMESTAMP WITH TIME ZONE,
+    paid_at TIMESTAMP WITH TIME ZONE,
+    transaction_id UUID,
+    return_reason cheque_return_reason,
+    stop_reason VARCHAR(200),
+    stopped_at TIMESTAMP WITH TIME ZONE,
+    stopped_by_person_id UUID,
+    last_updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (account_id, serial_number)
+);
+
+CREATE INDEX idx_cheques_book ON cheques (cheque_book_id);
@@ -0,0 +1,11 @@
+-- Daily interest accrued on an account, model AccountInterestAccrualModel. The
+-- (account_id, accrual_date) key makes a retried accrual chunk a no-op.
+CREATE TABLE account_interest_accruals (
+    account_id UUID NOT NULL,
+    accrual_date DATE NOT NULL,
+    daily_interest DECIMAL(20, 10) NOT NULL,
+    interest_rate DECIMAL(7, 6) NOT NULL,
+    principal_balance DECIMAL(15, 2) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (account_id, accrual_date)
+);
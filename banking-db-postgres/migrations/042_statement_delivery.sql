@@ -0,0 +1,78 @@
+-- Create ENUM types
+CREATE TYPE statement_delivery_method AS ENUM ('Paper', 'Electronic', 'Both');
+CREATE TYPE statement_notification_type AS ENUM ('PreferenceChanged', 'StatementReady');
+
+-- How each account receives its statements, model StatementDeliveryPreferenceModel;
+-- accounts without a row get paper statements
+CREATE TABLE statement_delivery_preferences (
+    account_id UUID PRIMARY KEY,
+    delivery_method statement_delivery_method NOT NULL,
+    contact_messaging_id UUID,
+    consent_given_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    consent_channel VARCHAR(50) NOT NULL,
+    last_updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_by_person_id UUID NOT NULL
+);
+
+-- Messaging contacts of a customer and when they were verified, model CustomerContactModel
+CREATE TABLE customer_contacts (
+    id UUID PRIMARY KEY,
+    customer_id UUID NOT NULL,
+    messaging_id UUID NOT NULL,
+    verified_at TIMESTAMP WITH TIME ZONE,
+    verified_by_person_id UUID,
+    UNIQUE (customer_id, messaging_id)
+);
+
+-- Evidence of every delivery preference change, model StatementConsentRecordModel
+CREATE TABLE statement_consent_records (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL,
+    customer_id UUID NOT NULL,
+    previous_method statement_delivery_method NOT NULL,
+    new_method statement_delivery_method NOT NULL,
+    contact_messaging_id UUID,
+    contact_verified_at TIMESTAMP WITH TIME ZONE,
+    consent_channel VARCHAR(50) NOT NULL,
+    consent_reference VARCHAR(100) NOT NULL,
+    consent_given_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    recorded_by_person_id UUID NOT NULL
+);
+
+CREATE INDEX idx_statement_consent_records_account ON statement_consent_records (account_id, consent_given_at);
+
+-- Statement notices queued for a customer, model StatementNotificationModel
+CREATE TABLE statement_notifications (
+    id UUID PRIMARY KEY,
+    notification_type statement_notification_type NOT NULL,
+    account_id UUID NOT NULL,
+    customer_id UUID NOT NULL,
+    contact_messaging_id UUID,
+    statement_reference VARCHAR(50),
+    status document_notification_status NOT NULL DEFAULT 'Queued',
+    queued_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    sent_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_statement_notifications_queued ON statement_notifications (queued_at) WHERE status = 'Queued';
+
+-- One print extract per statement cycle, model StatementPrintBatchModel; the unique
+-- cycle date makes a second dispatch of the same cycle a no-op
+CREATE TABLE statement_print_batches (
+    id UUID PRIMARY KEY,
+    cycle_date DATE NOT NULL UNIQUE,
+    entry_count BIGINT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- Statements to print in a cycle, model StatementPrintEntryModel
+CREATE TABLE statement_print_entries (
+    id UUID PRIMARY KEY,
+    batch_id UUID NOT NULL REFERENCES statement_print_batches(id),
+    account_id UUID NOT NULL,
+    customer_id UUID NOT NULL,
+    statement_reference VARCHAR(50) NOT NULL,
+    delivery_method statement_delivery_method NOT NULL
+);
+
+CREATE INDEX idx_statement_print_entries_batch ON statement_print_entries (batch_id, account_id);
@@ -0,0 +1,38 @@
+-- Create ENUM types
+CREATE TYPE orchestration_type AS ENUM ('CustomerOffboarding', 'ProductRepricing');
+CREATE TYPE orchestration_status AS ENUM (
+    'Running', 'Completed', 'Compensating', 'Compensated', 'CompensationFailed', 'ManuallyResolved'
+);
+CREATE TYPE orchestration_step_status AS ENUM ('Completed', 'Compensated', 'CompensationFailed');
+
+-- Multi-step operations and their outcome, model OrchestrationModel
+CREATE TABLE orchestration_log (
+    id UUID PRIMARY KEY,
+    orchestration_type orchestration_type NOT NULL,
+    subject_id UUID NOT NULL,
+    status orchestration_status NOT NULL DEFAULT 'Running',
+    failure_reason VARCHAR(255),
+    started_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    finished_at TIMESTAMP WITH TIME ZONE,
+    initiated_by_person_id UUID NOT NULL,
+    resolved_by_person_id UUID,
+    resolution_note VARCHAR(255)
+);
+
+-- Recovery after a restart resumes the orchestrations that did not finish
+CREATE INDEX idx_orchestration_log_incomplete ON orchestration_log (started_at)
+    WHERE status NOT IN ('Completed', 'Compensated', 'ManuallyResolved');
+
+-- Completed steps with the compensation that undoes them, model OrchestrationStepModel
+CREATE TABLE orchestration_steps (
+    id UUID PRIMARY KEY,
+    orchestration_id UUID NOT NULL REFERENCES orchestration_log(id),
+    sequence INTEGER NOT NULL,
+    step_name VARCHAR(100) NOT NULL,
+    compensation JSONB,
+    status orchestration_step_status NOT NULL DEFAULT 'Completed',
+    completed_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    compensated_at TIMESTAMP WITH TIME ZONE,
+    last_error VARCHAR(255),
+    UNIQUE (orchestration_id, sequence)
+);
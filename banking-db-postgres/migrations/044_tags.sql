@@ -0,0 +1,30 @@
+-- Create ENUM types
+CREATE TYPE taggable_entity_kind AS ENUM ('Customer', 'Account');
+
+-- Labels that can be put on customers and accounts, model TagModel
+CREATE TABLE tags (
+    id UUID PRIMARY KEY,
+    code VARCHAR(50) NOT NULL UNIQUE,
+    description VARCHAR(255) NOT NULL,
+    applicable_entity_kinds taggable_entity_kind[] NOT NULL,
+    required_permission VARCHAR(50),
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    last_updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_by_person_id UUID NOT NULL
+);
+
+-- Tags put on a customer or an account, model EntityTagModel; assigning a tag
+-- twice is a no-op
+CREATE TABLE entity_tags (
+    id UUID PRIMARY KEY,
+    tag_id UUID NOT NULL REFERENCES tags(id),
+    entity_kind taggable_entity_kind NOT NULL,
+    entity_id UUID NOT NULL,
+    assigned_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    assigned_by_person_id UUID NOT NULL,
+    UNIQUE (tag_id, entity_kind, entity_id)
+);
+
+-- Tag filters look up the tags of one entity
+CREATE INDEX idx_entity_tags_entity ON entity_tags (entity_kind, entity_id);
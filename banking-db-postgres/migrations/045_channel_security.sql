@@ -0,0 +1,44 @@
+-- Create ENUM types
+CREATE TYPE channel_security_event_type AS ENUM ('FailedAuth', 'DeviceChange', 'SimSwapSuspected', 'PasswordReset');
+
+-- Compliance severity, shared with compliance alerts where that schema exists
+DO $$
+BEGIN
+    IF to_regtype('severity') IS NULL THEN
+        CREATE TYPE severity AS ENUM ('Low', 'Medium', 'High', 'Critical');
+    END IF;
+END $$;
+
+-- Security signals reported by channels, model ChannelSecurityEventModel
+CREATE TABLE channel_security_events (
+    id UUID PRIMARY KEY,
+    channel_id UUID NOT NULL,
+    customer_id UUID,
+    event_type channel_security_event_type NOT NULL,
+    severity severity NOT NULL,
+    context JSONB,
+    occurred_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    recorded_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- Rules count a customer's events of one type in a time window
+CREATE INDEX idx_channel_security_events_customer ON channel_security_events (customer_id, event_type, occurred_at);
+-- Retention purges events by age
+CREATE INDEX idx_channel_security_events_occurred_at ON channel_security_events (occurred_at);
+
+-- Channels a customer is barred from, model ChannelRestrictionModel
+CREATE TABLE channel_restrictions (
+    id UUID PRIMARY KEY,
+    customer_id UUID NOT NULL,
+    channel_id UUID NOT NULL,
+    reason VARCHAR(255) NOT NULL,
+    rule_code VARCHAR(50),
+    starts_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    expires_at TIMESTAMP WITH TIME ZONE,
+    lifted_at TIMESTAMP WITH TIME ZONE,
+    lifted_by_person_id UUID,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_channel_restrictions_customer_active ON channel_restrictions (customer_id, starts_at)
+    WHERE lifted_at IS NULL;
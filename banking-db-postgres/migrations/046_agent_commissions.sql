@@ -0,0 +1,56 @@
+-- Create ENUM types
+CREATE TYPE commission_statement_status AS ENUM ('Draft', 'Invoiced');
+CREATE TYPE commission_line_type AS ENUM ('Commission', 'PerformanceBonus', 'DiscrepancyDeduction');
+
+-- Commission terms for collection agents, optionally per program or territory,
+-- model AgentCommissionSchemeModel
+CREATE TABLE agent_commission_schemes (
+    id UUID PRIMARY KEY,
+    code VARCHAR(50) NOT NULL UNIQUE,
+    collection_program_id UUID,
+    territory_id UUID,
+    commission_rate DECIMAL(7, 6) NOT NULL,
+    target_collection_rate DECIMAL(7, 6),
+    bonus_amount DECIMAL(15, 2) NOT NULL DEFAULT 0,
+    deduction_per_discrepancy DECIMAL(15, 2) NOT NULL DEFAULT 0,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    last_updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_by_person_id UUID NOT NULL
+);
+
+-- Commission owed to an agent for a period, model AgentCommissionStatementModel.
+-- Draft statements of a period are recalculated; invoiced ones are final.
+CREATE TABLE agent_commission_statements (
+    id UUID PRIMARY KEY,
+    collection_agent_id UUID NOT NULL,
+    period_start DATE NOT NULL,
+    period_end DATE NOT NULL,
+    collected_amount DECIMAL(15, 2) NOT NULL,
+    commission_amount DECIMAL(15, 2) NOT NULL,
+    bonus_amount DECIMAL(15, 2) NOT NULL,
+    deduction_amount DECIMAL(15, 2) NOT NULL,
+    net_amount DECIMAL(15, 2) NOT NULL,
+    discrepancy_count INTEGER NOT NULL DEFAULT 0,
+    status commission_statement_status NOT NULL DEFAULT 'Draft',
+    calculated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    invoiced_at TIMESTAMP WITH TIME ZONE,
+    UNIQUE (collection_agent_id, period_start),
+    CHECK (period_end >= period_start)
+);
+
+CREATE INDEX idx_agent_commission_statements_period ON agent_commission_statements (period_start, status);
+
+-- Breakdown of a commission statement, model AgentCommissionLineItemModel
+CREATE TABLE agent_commission_line_items (
+    id UUID PRIMARY KEY,
+    statement_id UUID NOT NULL REFERENCES agent_commission_statements(id),
+    line_type commission_line_type NOT NULL,
+    scheme_id UUID NOT NULL REFERENCES agent_commission_schemes(id),
+    collection_program_id UUID,
+    description VARCHAR(255) NOT NULL,
+    basis_amount DECIMAL(15, 2) NOT NULL,
+    amount DECIMAL(15, 2) NOT NULL
+);
+
+CREATE INDEX idx_agent_commission_line_items_statement ON agent_commission_line_items (statement_id);
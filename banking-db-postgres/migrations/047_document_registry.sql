@@ -0,0 +1,27 @@
+-- Create ENUM types
+CREATE TYPE document_link_kind AS ENUM ('Workflow', 'Customer');
+
+-- Uploaded documents, one row per content hash, model RegisteredDocumentModel
+CREATE TABLE registered_documents (
+    id UUID PRIMARY KEY,
+    content_hash BYTEA NOT NULL UNIQUE CHECK (length(content_hash) = 32),
+    document_type VARCHAR(50) NOT NULL,
+    document_path BYTEA CHECK (length(document_path) = 32),
+    size_bytes BIGINT,
+    registered_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    registered_by_person_id UUID NOT NULL
+);
+
+-- Workflows and customers referencing a registered document, model DocumentLinkModel.
+-- A document is only removed once nothing links to it.
+CREATE TABLE document_links (
+    id UUID PRIMARY KEY,
+    document_id UUID NOT NULL REFERENCES registered_documents(id),
+    entity_kind document_link_kind NOT NULL,
+    entity_id UUID NOT NULL,
+    linked_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    linked_by_person_id UUID NOT NULL,
+    UNIQUE (document_id, entity_kind, entity_id)
+);
+
+CREATE INDEX idx_document_links_entity ON document_links (entity_kind, entity_id);
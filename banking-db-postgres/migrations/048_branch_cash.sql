@@ -0,0 +1,55 @@
+-- Create ENUM types
+CREATE TYPE cash_transfer_status AS ENUM ('Requested', 'InTransit', 'Received', 'Cancelled');
+CREATE TYPE cash_transfer_origin AS ENUM ('CeilingBreach', 'Manual');
+CREATE TYPE vault_adjustment_type AS ENUM ('TransferDispatch', 'TransferReceipt');
+
+-- Cash a branch may hold before the excess is sent on, model BranchCashCeilingModel
+CREATE TABLE branch_cash_ceilings (
+    id UUID PRIMARY KEY,
+    agency_branch_id UUID NOT NULL UNIQUE,
+    ceiling_amount DECIMAL(15, 2) NOT NULL CHECK (ceiling_amount >= 0),
+    excess_destination_branch_id UUID,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    last_updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_by_person_id UUID NOT NULL
+);
+
+-- Cash moved between branches under dual control, model CashTransferRequestModel.
+-- last_updated_at doubles as the optimistic lock of each transition.
+CREATE TABLE cash_transfer_requests (
+    id UUID PRIMARY KEY,
+    from_agency_branch_id UUID NOT NULL,
+    to_agency_branch_id UUID,
+    amount DECIMAL(15, 2) NOT NULL CHECK (amount > 0),
+    status cash_transfer_status NOT NULL DEFAULT 'Requested',
+    origin cash_transfer_origin NOT NULL,
+    requested_by_person_id UUID,
+    requested_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    dispatch_authorized_by_person_id UUID,
+    dispatch_confirmed_by_person_id UUID,
+    dispatched_at TIMESTAMP WITH TIME ZONE,
+    receipt_authorized_by_person_id UUID,
+    receipt_confirmed_by_person_id UUID,
+    received_at TIMESTAMP WITH TIME ZONE,
+    cancelled_by_person_id UUID,
+    cancellation_reason VARCHAR(200),
+    last_updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_cash_transfer_requests_open ON cash_transfer_requests (requested_at)
+    WHERE status IN ('Requested', 'InTransit');
+
+-- Vault movements posted by dispatch and receipt of a transfer, model VaultAdjustmentModel
+CREATE TABLE vault_adjustments (
+    id UUID PRIMARY KEY,
+    agency_branch_id UUID,
+    cash_transfer_request_id UUID NOT NULL REFERENCES cash_transfer_requests(id),
+    adjustment_type vault_adjustment_type NOT NULL,
+    amount DECIMAL(15, 2) NOT NULL,
+    posted_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    posted_by_person_id UUID NOT NULL
+);
+
+CREATE INDEX idx_vault_adjustments_branch ON vault_adjustments (agency_branch_id);
+CREATE INDEX idx_vault_adjustments_transfer ON vault_adjustments (cash_transfer_request_id, posted_at);
@@ -0,0 +1,29 @@
+-- Idempotency keys of templated notifications, one per business event. A rerun
+-- with the same key gets the original notification back and counts a duplicate.
+-- Rows are purged by business date once reruns can no longer happen.
+CREATE TABLE notification_idempotency_keys (
+    idempotency_key VARCHAR(200) PRIMARY KEY,
+    notification_id UUID NOT NULL,
+    template_code VARCHAR(50) NOT NULL,
+    business_date DATE NOT NULL,
+    duplicates_suppressed INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    last_duplicate_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_notification_idempotency_keys_business_date ON notification_idempotency_keys (business_date, template_code);
+
+-- Rendered templated notifications awaiting delivery, model QueuedNotificationModel
+CREATE TABLE queued_notifications (
+    id UUID PRIMARY KEY,
+    idempotency_key VARCHAR(200) NOT NULL,
+    template_code VARCHAR(50) NOT NULL,
+    customer_id UUID NOT NULL,
+    business_date DATE NOT NULL,
+    body VARCHAR(500) NOT NULL,
+    status document_notification_status NOT NULL DEFAULT 'Queued',
+    queued_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    sent_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_queued_notifications_customer ON queued_notifications (customer_id, business_date, queued_at);
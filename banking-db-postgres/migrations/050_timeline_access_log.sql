@@ -0,0 +1,14 @@
+-- Reads of an account's investigation timeline, model TimelineAccessRecordModel
+CREATE TABLE timeline_access_log (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL,
+    from_date DATE NOT NULL,
+    to_date DATE NOT NULL,
+    accessed_by_person_id UUID NOT NULL,
+    case_reference VARCHAR(100) NOT NULL,
+    events_returned BIGINT NOT NULL,
+    exported BOOLEAN NOT NULL DEFAULT FALSE,
+    accessed_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_timeline_access_log_account ON timeline_access_log (account_id, accessed_at DESC);
@@ -0,0 +1,66 @@
+-- Create ENUM types
+CREATE TYPE bundle_status AS ENUM ('Opening', 'Active', 'Dissolved', 'Cancelled');
+CREATE TYPE bundle_component_role AS ENUM ('Current', 'Savings', 'MobileWallet');
+
+-- Bundles are opened as a compensated orchestration
+ALTER TYPE orchestration_type ADD VALUE IF NOT EXISTS 'BundleOpening';
+
+-- Packages of products opened together, model ProductBundleModel; components and
+-- fee adjustments are stored as JSON
+CREATE TABLE product_bundles (
+    id UUID PRIMARY KEY,
+    bundle_code VARCHAR(20) NOT NULL UNIQUE,
+    name VARCHAR(100) NOT NULL,
+    components JSONB NOT NULL,
+    fee_adjustments JSONB NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    last_updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_by_person_id UUID NOT NULL
+);
+
+-- A bundle opened for a customer, model AccountBundleModel
+CREATE TABLE account_bundles (
+    id UUID PRIMARY KEY,
+    product_bundle_id UUID NOT NULL REFERENCES product_bundles(id),
+    bundle_code VARCHAR(20) NOT NULL,
+    customer_id UUID NOT NULL,
+    status bundle_status NOT NULL DEFAULT 'Opening',
+    orchestration_id UUID NOT NULL,
+    opened_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    dissolved_at TIMESTAMP WITH TIME ZONE,
+    dissolution_reason VARCHAR(255)
+);
+
+CREATE INDEX idx_account_bundles_customer ON account_bundles (customer_id, opened_at DESC);
+
+-- Accounts making up an opened bundle, model BundleAccountModel
+CREATE TABLE bundle_accounts (
+    id UUID PRIMARY KEY,
+    account_bundle_id UUID NOT NULL REFERENCES account_bundles(id),
+    account_id UUID NOT NULL,
+    role bundle_component_role NOT NULL,
+    required BOOLEAN NOT NULL,
+    linked_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    unlinked_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_bundle_accounts_bundle ON bundle_accounts (account_bundle_id);
+-- Closing an account looks up the bundle it is still linked to
+CREATE INDEX idx_bundle_accounts_account ON bundle_accounts (account_id) WHERE unlinked_at IS NULL;
+
+-- Fee discounts the fee engine applies while a bundle stays complete, model
+-- BundlePricingMarkerModel
+CREATE TABLE bundle_pricing_markers (
+    id UUID PRIMARY KEY,
+    account_bundle_id UUID NOT NULL REFERENCES account_bundles(id),
+    account_id UUID NOT NULL,
+    fee_category VARCHAR(20) NOT NULL,
+    discount_percentage DECIMAL(5, 2) NOT NULL CHECK (discount_percentage BETWEEN 0 AND 100),
+    depends_on_account_id UUID NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    removed_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_bundle_pricing_markers_bundle ON bundle_pricing_markers (account_bundle_id);
+CREATE INDEX idx_bundle_pricing_markers_account ON bundle_pricing_markers (account_id) WHERE removed_at IS NULL;
@@ -0,0 +1,53 @@
+-- Create ENUM types
+CREATE TYPE statement_integrity_check AS ENUM ('Reprint', 'ArchiveVerification');
+
+-- Stored statement snapshots, model AccountStatementModel. A regenerated statement
+-- supersedes the earlier one instead of overwriting it.
+CREATE TABLE account_statements (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL,
+    statement_reference VARCHAR(50) NOT NULL,
+    period_start DATE NOT NULL,
+    period_end DATE NOT NULL,
+    opening_balance DECIMAL(15, 2) NOT NULL,
+    total_credits DECIMAL(15, 2) NOT NULL,
+    total_debits DECIMAL(15, 2) NOT NULL,
+    closing_balance DECIMAL(15, 2) NOT NULL,
+    content_hash BYTEA NOT NULL CHECK (length(content_hash) = 32),
+    supersedes_statement_id UUID REFERENCES account_statements(id),
+    generated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    generated_by_person_id UUID NOT NULL,
+    CHECK (period_end >= period_start)
+);
+
+CREATE INDEX idx_account_statements_account_period ON account_statements (account_id, period_end);
+CREATE INDEX idx_account_statements_period_end ON account_statements (period_end);
+
+-- A statement is superseded at most once
+CREATE UNIQUE INDEX idx_account_statements_supersedes ON account_statements (supersedes_statement_id)
+    WHERE supersedes_statement_id IS NOT NULL;
+
+-- Lines of a stored statement, model StatementLineModel
+CREATE TABLE statement_lines (
+    statement_id UUID NOT NULL REFERENCES account_statements(id),
+    line_number INTEGER NOT NULL,
+    transaction_id UUID NOT NULL,
+    value_date DATE NOT NULL,
+    description VARCHAR(200) NOT NULL,
+    amount DECIMAL(15, 2) NOT NULL,
+    running_balance DECIMAL(15, 2) NOT NULL,
+    PRIMARY KEY (statement_id, line_number)
+);
+
+-- Stored statements whose content no longer matches their hash, model StatementIntegrityAlertModel
+CREATE TABLE statement_integrity_alerts (
+    id UUID PRIMARY KEY,
+    statement_id UUID NOT NULL REFERENCES account_statements(id),
+    account_id UUID NOT NULL,
+    stored_hash BYTEA NOT NULL,
+    computed_hash BYTEA NOT NULL,
+    detected_during statement_integrity_check NOT NULL,
+    detected_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_statement_integrity_alerts_statement ON statement_integrity_alerts (statement_id);
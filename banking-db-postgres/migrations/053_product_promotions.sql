@@ -0,0 +1,39 @@
+-- Create ENUM types
+CREATE TYPE promotion_rate_kind AS ENUM ('Bonus', 'Override');
+
+-- Time-boxed interest promotions on a product, model ProductPromotionModel. The
+-- bonus paid out is summed from the accruals that carry the promotion.
+CREATE TABLE product_promotions (
+    id UUID PRIMARY KEY,
+    product_id UUID NOT NULL,
+    promotion_code VARCHAR(50) NOT NULL UNIQUE,
+    name VARCHAR(100) NOT NULL,
+    rate_kind promotion_rate_kind NOT NULL,
+    rate_value DECIMAL(7, 6) NOT NULL,
+    opened_from DATE,
+    opened_to DATE,
+    segment_code VARCHAR(50),
+    start_date DATE NOT NULL,
+    end_date DATE NOT NULL,
+    budget_cap DECIMAL(15, 2),
+    budget_exhausted_at TIMESTAMP WITH TIME ZONE,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    last_updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_by_person_id UUID NOT NULL,
+    CHECK (end_date >= start_date)
+);
+
+CREATE INDEX idx_product_promotions_product ON product_promotions (product_id, start_date DESC);
+
+-- Accrual resolves the promotions running on the accrual date
+CREATE INDEX idx_product_promotions_running ON product_promotions (start_date, end_date)
+    WHERE is_active AND budget_exhausted_at IS NULL;
+
+-- Promotion applied to each daily accrual and the bonus it added
+ALTER TABLE account_interest_accruals
+    ADD COLUMN promotion_id UUID REFERENCES product_promotions(id),
+    ADD COLUMN bonus_interest DECIMAL(20, 10) NOT NULL DEFAULT 0;
+
+CREATE INDEX idx_account_interest_accruals_promotion ON account_interest_accruals (promotion_id)
+    WHERE promotion_id IS NOT NULL;
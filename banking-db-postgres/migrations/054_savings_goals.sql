@@ -0,0 +1,19 @@
+-- Create ENUM types
+CREATE TYPE savings_goal_status AS ENUM ('Active', 'Closed');
+
+-- Named pots earmarking part of a savings account balance, model SavingsGoalModel
+CREATE TABLE savings_goals (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    target_amount DECIMAL(15, 2) NOT NULL CHECK (target_amount > 0),
+    target_date DATE,
+    allocated_balance DECIMAL(15, 2) NOT NULL DEFAULT 0 CHECK (allocated_balance >= 0),
+    status savings_goal_status NOT NULL DEFAULT 'Active',
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    closed_at TIMESTAMP WITH TIME ZONE,
+    last_updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_by_person_id UUID NOT NULL
+);
+
+CREATE INDEX idx_savings_goals_account ON savings_goals (account_id, created_at);
@@ -0,0 +1,22 @@
+-- Create ENUM types
+CREATE TYPE payee_verification_status AS ENUM ('Unverified', 'PendingVerification', 'Verified', 'Blocked');
+CREATE TYPE payee_verification_method AS ENUM ('PennyTest', 'ManualConfirmation');
+
+-- External accounts a customer saved as transfer beneficiaries, model PayeeModel
+CREATE TABLE payees (
+    id UUID PRIMARY KEY,
+    customer_id UUID NOT NULL,
+    beneficiary_name VARCHAR(100) NOT NULL,
+    external_bank_code VARCHAR(20) NOT NULL,
+    external_account_number VARCHAR(34) NOT NULL,
+    verification_status payee_verification_status NOT NULL DEFAULT 'Unverified',
+    verification_method payee_verification_method,
+    penny_test_amount DECIMAL(15, 2),
+    verified_at TIMESTAMP WITH TIME ZONE,
+    verified_by_person_id UUID,
+    first_used_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    last_updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_by_person_id UUID NOT NULL,
+    UNIQUE (customer_id, external_bank_code, external_account_number)
+);
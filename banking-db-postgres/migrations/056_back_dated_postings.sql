@@ -0,0 +1,17 @@
+-- Postings made with a value date before the business date, model BackDatedPostingModel;
+-- read by the back-dating report and the interest recalculation
+CREATE TABLE back_dated_postings (
+    id UUID PRIMARY KEY,
+    transaction_id UUID NOT NULL UNIQUE,
+    account_id UUID NOT NULL,
+    value_date DATE NOT NULL,
+    business_date DATE NOT NULL,
+    days_back BIGINT NOT NULL CHECK (days_back > 0),
+    accrual_delta DECIMAL(20, 10) NOT NULL,
+    posted_by_person_id UUID NOT NULL,
+    posted_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CHECK (value_date < business_date)
+);
+
+CREATE INDEX idx_back_dated_postings_business_date ON back_dated_postings (business_date, posted_at);
+CREATE INDEX idx_back_dated_postings_account ON back_dated_postings (account_id, posted_at);
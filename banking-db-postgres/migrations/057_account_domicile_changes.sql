@@ -0,0 +1,16 @@
+-- Transfers of an account's domicile between agency branches, model AccountDomicileChangeModel
+CREATE TABLE account_domicile_changes (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL,
+    from_agency_branch_id UUID NOT NULL,
+    to_agency_branch_id UUID NOT NULL,
+    effective_date DATE NOT NULL,
+    reason_id UUID NOT NULL,
+    approved_by_person_id UUID NOT NULL,
+    recorded_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- Domicile history of an account, oldest first
+CREATE INDEX idx_account_domicile_changes_account ON account_domicile_changes (account_id, effective_date, recorded_at);
+-- Changes taking effect after a date, for branch-level reporting
+CREATE INDEX idx_account_domicile_changes_effective_date ON account_domicile_changes (effective_date);
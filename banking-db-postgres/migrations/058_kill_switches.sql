@@ -0,0 +1,22 @@
+-- Create ENUM types
+CREATE TYPE kill_switch_scope AS ENUM ('Global', 'TransactionKind', 'Channel', 'Product');
+CREATE TYPE kill_switch_state AS ENUM ('Active', 'Deactivated', 'Expired');
+
+-- Operational kill switches checked before every posting, model KillSwitchModel
+CREATE TABLE kill_switches (
+    id UUID PRIMARY KEY,
+    scope kill_switch_scope NOT NULL,
+    scope_value VARCHAR(50),
+    state kill_switch_state NOT NULL DEFAULT 'Active',
+    reason VARCHAR(255) NOT NULL,
+    activated_by_person_id UUID NOT NULL,
+    activated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    auto_expire_at TIMESTAMP WITH TIME ZONE,
+    deactivated_by_person_id UUID,
+    deactivated_at TIMESTAMP WITH TIME ZONE
+);
+
+-- Active switches, loaded into the posting-path cache
+CREATE INDEX idx_kill_switches_active ON kill_switches (activated_at) WHERE state = 'Active';
+-- Activation history for the operations report
+CREATE INDEX idx_kill_switches_activated_at ON kill_switches (activated_at);
@@ -0,0 +1,27 @@
+-- Create ENUM types
+CREATE TYPE note_entity_kind AS ENUM ('Customer', 'Account');
+CREATE TYPE note_visibility AS ENUM ('BranchOnly', 'BankWide', 'ComplianceOnly');
+CREATE TYPE note_category AS ENUM ('BranchVisit', 'PhoneCall', 'Complaint', 'ServiceRequest', 'Compliance', 'General');
+
+-- Notes taken on customer and account interactions, model InteractionNoteModel;
+-- notes are never edited, a correction is a new note pointing at the one it corrects
+CREATE TABLE interaction_notes (
+    id UUID PRIMARY KEY,
+    entity_kind note_entity_kind NOT NULL,
+    entity_id UUID NOT NULL,
+    author_person_id UUID NOT NULL,
+    agency_branch_id UUID,
+    visibility note_visibility NOT NULL,
+    category note_category NOT NULL,
+    body VARCHAR(1000) NOT NULL,
+    corrects_note_id UUID REFERENCES interaction_notes(id),
+    follow_up_date DATE,
+    follow_up_completed_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- Notes of a customer or account, newest first
+CREATE INDEX idx_interaction_notes_entity ON interaction_notes (entity_kind, entity_id, created_at DESC);
+-- Open follow-ups, per author and for the daily due list
+CREATE INDEX idx_interaction_notes_open_follow_ups ON interaction_notes (follow_up_date, author_person_id)
+    WHERE follow_up_date IS NOT NULL AND follow_up_completed_at IS NULL;
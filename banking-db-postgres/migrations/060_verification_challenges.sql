@@ -0,0 +1,25 @@
+-- Create ENUM types
+CREATE TYPE verification_operation_kind AS ENUM ('ContactDetailChange', 'LimitIncrease');
+CREATE TYPE challenge_status AS ENUM ('Pending', 'Verified', 'Consumed', 'Failed');
+CREATE TYPE verification_method AS ENUM ('Otp', 'BranchAssertion');
+
+-- Step-up verification challenges for sensitive operations, model VerificationChallengeModel
+CREATE TABLE verification_challenges (
+    id UUID PRIMARY KEY,
+    customer_id UUID NOT NULL,
+    operation_kind verification_operation_kind NOT NULL,
+    channel_id UUID NOT NULL,
+    code VARCHAR(10) NOT NULL,
+    status challenge_status NOT NULL DEFAULT 'Pending',
+    failed_attempts INTEGER NOT NULL DEFAULT 0,
+    last_failed_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    verified_method verification_method,
+    verified_by_person_id UUID,
+    verified_at TIMESTAMP WITH TIME ZONE,
+    consumed_at TIMESTAMP WITH TIME ZONE
+);
+
+-- Recent challenges of a customer for an operation, for rate limiting
+CREATE INDEX idx_verification_challenges_customer ON verification_challenges (customer_id, operation_kind, created_at);
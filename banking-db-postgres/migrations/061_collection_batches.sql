@@ -0,0 +1,29 @@
+-- Create ENUM types
+CREATE TYPE batch_status AS ENUM ('Pending', 'Processing', 'Completed', 'Failed', 'PartiallyProcessed', 'RequiresReconciliation');
+
+-- Daily batches of an agent's collections reconciled against counted cash, model CollectionBatchModel
+CREATE TABLE collection_batches (
+    id UUID PRIMARY KEY,
+    collection_agent_id UUID NOT NULL,
+    collection_date DATE NOT NULL,
+    total_collections INTEGER NOT NULL DEFAULT 0,
+    total_amount DECIMAL(15, 2) NOT NULL DEFAULT 0,
+    currency VARCHAR(3) NOT NULL,
+    status batch_status NOT NULL DEFAULT 'Pending',
+    collection_records UUID[] NOT NULL DEFAULT '{}',
+
+    -- Reconciliation data (flattened)
+    reconciliation_expected_amount DECIMAL(15, 2),
+    reconciliation_actual_amount DECIMAL(15, 2),
+    reconciliation_variance DECIMAL(15, 2),
+    reconciliation_variance_reason VARCHAR(500),
+    reconciled_by_person_id UUID,
+    reconciliation_timestamp TIMESTAMP WITH TIME ZONE,
+    reconciliation_adjustment_required BOOLEAN,
+
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    processed_at TIMESTAMP WITH TIME ZONE
+);
+
+-- Batches of an agent for a collection day
+CREATE INDEX idx_collection_batches_agent_date ON collection_batches (collection_agent_id, collection_date);
@@ -0,0 +1,44 @@
+-- Create ENUM types
+CREATE TYPE balance_change_source AS ENUM ('Transaction', 'InterestPosting', 'FeeCharge', 'LoanRepayment', 'Reversal', 'ManualAdjustment');
+
+-- Account lifecycle status, shared with accounts where that schema exists
+DO $$
+BEGIN
+    IF to_regtype('account_status') IS NULL THEN
+        CREATE TYPE account_status AS ENUM ('PendingApproval', 'Active', 'Dormant', 'Frozen', 'PendingClosure', 'Closed', 'PendingReactivation');
+    END IF;
+END $$;
+
+-- Balance history written alongside every balance update, model AccountBalanceChangeRecordModel
+CREATE TABLE account_balance_change_records (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL,
+    old_current_balance DECIMAL(15, 2) NOT NULL,
+    new_current_balance DECIMAL(15, 2) NOT NULL,
+    old_available_balance DECIMAL(15, 2) NOT NULL,
+    new_available_balance DECIMAL(15, 2) NOT NULL,
+    change_source balance_change_source NOT NULL,
+    transaction_id UUID,
+    changed_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    changed_by_person_id UUID NOT NULL
+);
+
+-- Balance history of an account over a period
+CREATE INDEX idx_account_balance_change_records_account ON account_balance_change_records (account_id, changed_at);
+
+-- Status history written alongside every status update, model AccountStatusChangeRecordModel
+CREATE TABLE account_status_change_records (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL,
+    old_status account_status,
+    new_status account_status NOT NULL,
+    reason_id UUID NOT NULL,
+    additional_context VARCHAR(200),
+    changed_by_person_id UUID NOT NULL,
+    changed_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    system_triggered BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- Status history of an account, newest first
+CREATE INDEX idx_account_status_change_records_account ON account_status_change_records (account_id, changed_at DESC);
@@ -0,0 +1,10 @@
+-- Sequential statement numbers and charge summaries of stored statements, model AccountStatementModel.
+-- A regenerated statement keeps the number of the statement it supersedes.
+ALTER TABLE account_statements
+    ADD COLUMN statement_number INTEGER NOT NULL DEFAULT 1,
+    ADD COLUMN interest_accrued DECIMAL(20, 10) NOT NULL DEFAULT 0,
+    ADD COLUMN fees_charged DECIMAL(15, 2) NOT NULL DEFAULT 0,
+    ADD COLUMN fee_count BIGINT NOT NULL DEFAULT 0;
+
+-- Latest statement number of an account
+CREATE INDEX idx_account_statements_account_number ON account_statements (account_id, statement_number);
@@ -0,0 +1,354 @@
+use async_trait::async_trait;
+use banking_db::DatabaseExecutor;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::pool::PoolConnection;
+use sqlx::{PgPool, Postgres};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+
+use crate::postgres_repositories::PostgresRepositories;
+
+/// Tables the core cannot serve requests without
+pub const CRITICAL_TABLES: [&str; 3] = ["accounts", "transactions", "account_workflows"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum HealthStatus {
+    Healthy,
+    /// Every check passed but the pool had no connection to spare
+    Degraded,
+    Down,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthCheckResult {
+    pub name: String,
+    pub status: HealthStatus,
+    pub latency_ms: u64,
+    pub detail: Option<String>,
+}
+
+/// Pool occupancy sampled before the health check takes its own connection
+#[derive(Debug, Clone, Serialize)]
+pub struct PoolStatistics {
+    pub size: u32,
+    pub idle: u32,
+    pub in_use: u32,
+    pub max_connections: u32,
+    /// Health check acquires that found no idle connection and had to wait.
+    /// sqlx does not expose the pool's own waiter count.
+    pub wait_count: u64,
+}
+
+impl PoolStatistics {
+    fn sample(pool: &PgPool, wait_count: u64) -> Self {
+        let size = pool.size();
+        let idle = pool.num_idle() as u32;
+        Self {
+            size,
+            idle,
+            in_use: size.saturating_sub(idle),
+            max_connections: pool.options().get_max_connections(),
+            wait_count,
+        }
+    }
+
+    pub fn is_saturated(&self) -> bool {
+        self.idle == 0 && self.size >= self.max_connections
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    pub checked_at: DateTime<Utc>,
+    pub pool: PoolStatistics,
+    pub checks: Vec<HealthCheckResult>,
+}
+
+impl HealthReport {
+    /// Liveness: the database answers, even if slowly
+    pub fn is_live(&self) -> bool {
+        self.status != HealthStatus::Down
+    }
+
+    /// Readiness: the core can take more load
+    pub fn is_ready(&self) -> bool {
+        self.status == HealthStatus::Healthy
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HealthCheckOptions {
+    /// Bound on each check, including waiting for a pooled connection
+    pub timeout: Duration,
+    pub critical_tables: Vec<String>,
+}
+
+impl Default for HealthCheckOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(2),
+            critical_tables: CRITICAL_TABLES.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+}
+
+/// Receives the reports produced by [`PostgresRepositories::spawn_health_monitor`]
+#[async_trait]
+pub trait HealthReportRecorder: Send + Sync {
+    async fn record(&self, report: &HealthReport);
+}
+
+fn check_result(
+    name: &str,
+    started: Instant,
+    outcome: Result<(), String>,
+) -> HealthCheckResult {
+    let (status, detail) = match outcome {
+        Ok(()) => (HealthStatus::Healthy, None),
+        Err(detail) => (HealthStatus::Down, Some(detail)),
+    };
+    HealthCheckResult {
+        name: name.to_string(),
+        status,
+        latency_ms: started.elapsed().as_millis() as u64,
+        detail,
+    }
+}
+
+async fn probe(
+    conn: &mut PoolConnection<Postgres>,
+    sql: &str,
+    timeout: Duration,
+) -> Result<(), String> {
+    match tokio::time::timeout(timeout, sqlx::query(sql).execute(&mut **conn)).await {
+        Ok(Ok(_)) => Ok(()),
+        Ok(Err(err)) => Err(err.to_string()),
+        Err(_) => Err(format!("timed out after {}ms", timeout.as_millis())),
+    }
+}
+
+impl PostgresRepositories {
+    /// Runs the checks with [`HealthCheckOptions::default`]
+    pub async fn health_check(&self) -> HealthReport {
+        self.health_check_with(&HealthCheckOptions::default()).await
+    }
+
+    /// Acquires a connection, runs `SELECT 1` and reads each critical table,
+    /// every step bounded by `options.timeout`. Tables are only probed once a
+    /// connection was obtained; otherwise they are reported down unchecked.
+    pub async fn health_check_with(&self, options: &HealthCheckOptions) -> HealthReport {
+        let checked_at = Utc::now();
+        let mut pool = PoolStatistics::sample(&self.pool, 0);
+        if pool.idle == 0 {
+            self.health_waits.fetch_add(1, Ordering::Relaxed);
+        }
+        pool.wait_count = self.health_waits.load(Ordering::Relaxed);
+
+        let mut checks = Vec::with_capacity(options.critical_tables.len() + 1);
+        let started = Instant::now();
+        let acquired =
+            tokio::time::timeout(options.timeout, DatabaseExecutor::acquire(self.pool.as_ref()))
+                .await;
+        let mut conn = match acquired {
+            Ok(Ok(conn)) => Some(conn),
+            Ok(Err(err)) => {
+                checks.push(check_result("connectivity", started, Err(err.to_string())));
+                None
+            }
+            Err(_) => {
+                checks.push(check_result(
+                    "connectivity",
+                    started,
+                    Err(format!(
+                        "no connection within {}ms",
+                        options.timeout.as_millis()
+                    )),
+                ));
+                None
+            }
+        };
+
+        if let Some(conn) = conn.as_mut() {
+            let started = Instant::now();
+            let outcome = probe(conn, "SELECT 1", options.timeout).await;
+            checks.push(check_result("connectivity", started, outcome));
+        }
+
+        for table in &options.critical_tables {
+            let name = format!("table:{table}");
+            let started = Instant::now();
+            let outcome = match conn.as_mut() {
+                Some(conn) => {
+                    let sql = format!("SELECT 1 FROM \"{}\" LIMIT 1", table.replace('"', "\"\""));
+                    probe(conn, &sql, options.timeout).await
+                }
+                None => Err("not checked: no connection".to_string()),
+            };
+            checks.push(check_result(&name, started, outcome));
+        }
+
+        let status = if checks.iter().any(|c| c.status == HealthStatus::Down) {
+            HealthStatus::Down
+        } else if pool.is_saturated() {
+            HealthStatus::Degraded
+        } else {
+            HealthStatus::Healthy
+        };
+
+        HealthReport {
+            status,
+            checked_at,
+            pool,
+            checks,
+        }
+    }
+
+    /// Runs a health check every `interval` and hands each report to
+    /// `recorder`, until the returned handle is aborted.
+    pub fn spawn_health_monitor(
+        &self,
+        interval: Duration,
+        options: HealthCheckOptions,
+        recorder: Arc<dyn HealthReportRecorder>,
+    ) -> JoinHandle<()> {
+        let repositories = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                ticker.tick().await;
+                let report = repositories.health_check_with(&options).await;
+                recorder.record(&report).await;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::setup_test_schema;
+    use parking_lot::Mutex;
+
+    fn existing_tables() -> HealthCheckOptions {
+        HealthCheckOptions {
+            timeout: Duration::from_millis(500),
+            critical_tables: vec!["person".to_string(), "audit_log".to_string()],
+        }
+    }
+
+    #[derive(Default)]
+    struct CollectingRecorder {
+        reports: Mutex<Vec<HealthReport>>,
+    }
+
+    #[async_trait]
+    impl HealthReportRecorder for CollectingRecorder {
+        async fn record(&self, report: &HealthReport) {
+            self.reports.lock().push(report.clone());
+        }
+    }
+
+    #[test]
+    fn test_default_options_cover_the_critical_tables() {
+        let options = HealthCheckOptions::default();
+        assert_eq!(
+            options.critical_tables,
+            vec!["accounts", "transactions", "account_workflows"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_health_check_reports_healthy() {
+        let schema = setup_test_schema().await.unwrap();
+        let report = schema.repositories().health_check_with(&existing_tables()).await;
+
+        assert_eq!(report.status, HealthStatus::Healthy);
+        assert!(report.is_live() && report.is_ready());
+        let names: Vec<&str> = report.checks.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["connectivity", "table:person", "table:audit_log"]);
+        assert!(report.checks.iter().all(|c| c.status == HealthStatus::Healthy));
+        assert_eq!(report.pool.max_connections, 5);
+    }
+
+    #[tokio::test]
+    async fn test_health_check_reports_unreachable_table() {
+        let schema = setup_test_schema().await.unwrap();
+        let mut options = existing_tables();
+        options.critical_tables.push("no_such_table".to_string());
+        let report = schema.repositories().health_check_with(&options).await;
+
+        assert_eq!(report.status, HealthStatus::Down);
+        let missing = report.checks.last().unwrap();
+        assert_eq!(missing.name, "table:no_such_table");
+        assert_eq!(missing.status, HealthStatus::Down);
+        assert!(missing.detail.as_deref().unwrap().contains("no_such_table"));
+    }
+
+    #[tokio::test]
+    async fn test_health_check_times_out_on_exhausted_pool() {
+        let schema = setup_test_schema().await.unwrap();
+        let pool = schema.connect_pool(1).await.unwrap();
+        let repositories = PostgresRepositories::new(pool.clone());
+        let _held = pool.acquire().await.unwrap();
+
+        let report = repositories.health_check_with(&existing_tables()).await;
+
+        assert_eq!(report.status, HealthStatus::Down);
+        assert!(!report.is_live());
+        assert!(report.pool.is_saturated());
+        assert_eq!(report.pool.in_use, 1);
+        assert_eq!(report.pool.wait_count, 1);
+        assert!(report.checks[0]
+            .detail
+            .as_deref()
+            .unwrap()
+            .starts_with("no connection within"));
+        assert!(report.checks[1..]
+            .iter()
+            .all(|c| c.status == HealthStatus::Down));
+    }
+
+    #[tokio::test]
+    async fn test_health_check_reports_degraded_when_pool_saturated() {
+        let schema = setup_test_schema().await.unwrap();
+        let pool = schema.connect_pool(1).await.unwrap();
+        let repositories = PostgresRepositories::new(pool.clone());
+        let held = pool.acquire().await.unwrap();
+        let release = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            drop(held);
+        });
+
+        let mut options = existing_tables();
+        options.timeout = Duration::from_secs(5);
+        let report = repositories.health_check_with(&options).await;
+        release.await.unwrap();
+
+        assert_eq!(report.status, HealthStatus::Degraded);
+        assert!(report.is_live() && !report.is_ready());
+        assert!(report.checks.iter().all(|c| c.status == HealthStatus::Healthy));
+    }
+
+    #[tokio::test]
+    async fn test_health_monitor_records_reports() {
+        let schema = setup_test_schema().await.unwrap();
+        let recorder = Arc::new(CollectingRecorder::default());
+        let handle = schema.repositories().spawn_health_monitor(
+            Duration::from_millis(20),
+            existing_tables(),
+            recorder.clone(),
+        );
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        handle.abort();
+
+        let reports = recorder.reports.lock();
+        assert!(reports.len() >= 2);
+        assert!(reports.iter().all(|r| r.status == HealthStatus::Healthy));
+    }
+}
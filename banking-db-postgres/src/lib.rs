@@ -1,3 +1,4 @@
+pub mod health;
 pub mod postgres_repositories;
 pub mod repository;
 pub mod utils;
@@ -11,5 +12,5 @@ pub use repository::person::locality_repository::LocalityRepositoryImpl;
 pub use repository::person::location_repository::LocationRepositoryImpl;
 pub use repository::person::person_repository::PersonRepositoryImpl;
 pub use repository::unit_of_work_impl;
-#[cfg(test)]
+#[cfg(any(test, feature = "test-utils"))]
 pub mod test_helper;
\ No newline at end of file
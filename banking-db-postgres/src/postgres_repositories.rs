@@ -1,10 +1,11 @@
 use banking_db::models::person::{
-    CountryIdxModelCache, CountrySubdivisionIdxModelCache, EntityReferenceIdxModelCache,
-    LocalityIdxModelCache, LocationIdxModelCache, PersonIdxModelCache,
+    CountryIdxModelCache, CountrySubdivisionIdxModelCache, LocalityIdxModelCache, LocationIdxModelCache, PersonIdxModelCache,
 };
+use banking_db::repository::{InstrumentedRepository, RepositoryMetrics, RepositoryMetricsSnapshot};
 use banking_logic::services::repositories::Repositories;
 use parking_lot::RwLock;
 use sqlx::{PgPool, Postgres};
+use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 
 use crate::repository::{
@@ -18,15 +19,44 @@ use crate::repository::{
         location_repository::LocationRepositoryImpl,
         person_repository::PersonRepositoryImpl,
     },
+    unit_of_work_impl::PersonCachePolicies,
 };
 
+#[derive(Clone)]
 pub struct PostgresRepositories {
-    pool: Arc<PgPool>,
+    pub(crate) pool: Arc<PgPool>,
+    /// Health check acquires that found the pool without an idle connection
+    pub(crate) health_waits: Arc<AtomicU64>,
+    metrics: Option<Arc<dyn RepositoryMetrics>>,
+    cache_policies: PersonCachePolicies,
 }
 
 impl PostgresRepositories {
     pub fn new(pool: Arc<PgPool>) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            health_waits: Arc::new(AtomicU64::new(0)),
+            metrics: None,
+            cache_policies: PersonCachePolicies::default(),
+        }
+    }
+
+    /// Applies `policies` to the Idx caches of the repositories created afterwards
+    pub fn with_cache_policies(mut self, policies: PersonCachePolicies) -> Self {
+        self.cache_policies = policies;
+        self
+    }
+
+    /// Reports every call of the repositories created afterwards to `metrics`.
+    /// Without it the repositories are returned unwrapped.
+    pub fn with_metrics(mut self, metrics: Arc<dyn RepositoryMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Aggregates of the installed metrics, if they are kept in-process
+    pub fn metrics_snapshot(&self) -> Option<RepositoryMetricsSnapshot> {
+        self.metrics.as_ref().and_then(|metrics| metrics.metrics_snapshot())
     }
 
     pub async fn create_person_service_repositories(&self) -> Repositories<Postgres> {
@@ -35,10 +65,15 @@ impl PostgresRepositories {
         let country_idx_models = CountryRepositoryImpl::load_all_country_idx(&executor)
             .await
             .expect("Failed to load country index");
-        let country_idx_cache = Arc::new(RwLock::new(
-            CountryIdxModelCache::new(country_idx_models)
-                .expect("Failed to create country index cache"),
-        ));
+        let mut country_idx_cache = CountryIdxModelCache::new(country_idx_models)
+            .expect("Failed to create country index cache");
+        for country in CountryRepositoryImpl::load_all_countries(&executor)
+            .await
+            .expect("Failed to load countries")
+        {
+            country_idx_cache.add_names(country);
+        }
+        let country_idx_cache = Arc::new(RwLock::new(country_idx_cache));
 
         let country_subdivision_idx_models =
             CountrySubdivisionRepositoryImpl::load_all_country_subdivision_idx(&executor)
@@ -105,13 +140,13 @@ impl PostgresRepositories {
             person_idx_cache,
         ));
 
-        let entity_reference_idx_models =
-            EntityReferenceRepositoryImpl::load_all_entity_reference_idx(&executor)
-                .await
-                .expect("Failed to load entity reference index");
         let entity_reference_idx_cache = Arc::new(RwLock::new(
-            EntityReferenceIdxModelCache::new(entity_reference_idx_models)
-                .expect("Failed to create entity reference index cache"),
+            EntityReferenceRepositoryImpl::load_entity_reference_idx_cache(
+                &executor,
+                self.cache_policies.entity_reference,
+            )
+            .await
+            .expect("Failed to load entity reference index"),
         ));
 
         let entity_reference_repository = Arc::new(EntityReferenceRepositoryImpl::new(
@@ -119,7 +154,7 @@ impl PostgresRepositories {
             person_repository.clone(),
             entity_reference_idx_cache,
         ));
-        Repositories {
+        let repositories = Repositories {
             person_repository,
             audit_log_repository: Arc::new(AuditLogRepositoryImpl::new(executor.clone())),
             country_repository,
@@ -127,6 +162,141 @@ impl PostgresRepositories {
             locality_repository,
             location_repository,
             entity_reference_repository,
+        };
+        match &self.metrics {
+            Some(metrics) => instrument(repositories, metrics),
+            None => repositories,
+        }
+    }
+}
+
+fn instrument(
+    repositories: Repositories<Postgres>,
+    metrics: &Arc<dyn RepositoryMetrics>,
+) -> Repositories<Postgres> {
+    Repositories {
+        person_repository: Arc::new(InstrumentedRepository::new(
+            "person",
+            repositories.person_repository,
+            metrics.clone(),
+        )),
+        audit_log_repository: Arc::new(InstrumentedRepository::new(
+            "audit_log",
+            repositories.audit_log_repository,
+            metrics.clone(),
+        )),
+        country_repository: Arc::new(InstrumentedRepository::new(
+            "country",
+            repositories.country_repository,
+            metrics.clone(),
+        )),
+        country_subdivision_repository: Arc::new(InstrumentedRepository::new(
+            "country_subdivision",
+            repositories.country_subdivision_repository,
+            metrics.clone(),
+        )),
+        locality_repository: Arc::new(InstrumentedRepository::new(
+            "locality",
+            repositories.locality_repository,
+            metrics.clone(),
+        )),
+        location_repository: Arc::new(InstrumentedRepository::new(
+            "location",
+            repositories.location_repository,
+            metrics.clone(),
+        )),
+        entity_reference_repository: Arc::new(InstrumentedRepository::new(
+            "entity_reference",
+            repositories.entity_reference_repository,
+            metrics.clone(),
+        )),
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::person::test_helpers::create_test_country_model;
+    use crate::repository::unit_of_work_impl::PostgresUnitOfWork;
+    use crate::test_helper::setup_test_schema;
+    use banking_db::repository::{
+        CountryRepository, InMemoryRepositoryMetrics, NoopRepositoryMetrics, PersonRepos,
+        RepositoryMetricsSnapshot, UnitOfWork, UnitOfWorkSession,
+    };
+
+    async fn save_and_find_twice(countries: &dyn CountryRepository<Postgres>) {
+        let country = create_test_country_model("MX", "Mexico");
+        countries.save(country.clone()).await.unwrap();
+        assert!(countries.find_by_id(country.id).await.unwrap().is_some());
+        assert_eq!(countries.find_by_iso2("MX", 1, 10).await.unwrap().len(), 1);
+    }
+
+    fn assert_counted(snapshot: &RepositoryMetricsSnapshot) {
+        assert_eq!(snapshot.queries.len(), 3);
+        for method in ["save", "find_by_id", "find_by_iso2"] {
+            let stats = snapshot.get("country", method).unwrap();
+            assert_eq!((stats.calls, stats.errors, stats.rows), (1, 0, 1), "{method}");
+            assert_eq!(stats.latency_histogram.iter().sum::<u64>(), 1);
         }
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_metrics_count_calls_on_pool_repositories() {
+        let schema = setup_test_schema().await.unwrap();
+        let repositories = schema
+            .repositories()
+            .with_metrics(Arc::new(InMemoryRepositoryMetrics::new()));
+        let repos = repositories.create_person_service_repositories().await;
+
+        save_and_find_twice(repos.country_repository.as_ref()).await;
+
+        assert_counted(&repositories.metrics_snapshot().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_metrics_count_calls_inside_transaction() {
+        let schema = setup_test_schema().await.unwrap();
+        let executor = Executor::Tx(Arc::new(tokio::sync::Mutex::new(
+            schema.pool().begin().await.unwrap(),
+        )));
+        let cache = Arc::new(RwLock::new(CountryIdxModelCache::new(Vec::new()).unwrap()));
+        let metrics = Arc::new(InMemoryRepositoryMetrics::new());
+        let countries = InstrumentedRepository::new(
+            "country",
+            Arc::new(CountryRepositoryImpl::new(executor.clone(), cache)),
+            metrics.clone(),
+        );
+
+        save_and_find_twice(&countries).await;
+        executor.rollback().await.unwrap();
+
+        assert_counted(&metrics.metrics_snapshot().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_metrics_count_calls_in_unit_of_work_session() {
+        let schema = setup_test_schema().await.unwrap();
+        let metrics = Arc::new(InMemoryRepositoryMetrics::new());
+        let uow = PostgresUnitOfWork::new(schema.pool())
+            .await
+            .with_metrics(metrics.clone());
+        let session = uow.begin().await.unwrap();
+
+        save_and_find_twice(session.person_repos().countries()).await;
+        session.rollback().await.unwrap();
+
+        assert_counted(&metrics.metrics_snapshot().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_noop_metrics_keep_no_snapshot() {
+        let schema = setup_test_schema().await.unwrap();
+        let repositories = schema
+            .repositories()
+            .with_metrics(Arc::new(NoopRepositoryMetrics));
+        let repos = repositories.create_person_service_repositories().await;
+
+        save_and_find_twice(repos.country_repository.as_ref()).await;
+
+        assert!(repositories.metrics_snapshot().is_none());
+    }
+}
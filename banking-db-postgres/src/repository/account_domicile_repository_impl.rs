@@ -0,0 +1,129 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::AccountDomicileChangeModel;
+use banking_db::repository::AccountDomicileRepository;
+use chrono::NaiveDate;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of AccountDomicileRepository
+pub struct AccountDomicileRepositoryImpl {
+    pool: PgPool,
+}
+
+impl AccountDomicileRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+impl TryFromRow<PgRow> for AccountDomicileChangeModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(AccountDomicileChangeModel {
+            id: row.get("id"),
+            account_id: row.get("account_id"),
+            from_agency_branch_id: row.get("from_agency_branch_id"),
+            to_agency_branch_id: row.get("to_agency_branch_id"),
+            effective_date: row.get("effective_date"),
+            reason_id: row.get("reason_id"),
+            approved_by_person_id: row.get("approved_by_person_id"),
+            recorded_at: row.get("recorded_at"),
+        })
+    }
+}
+
+const DOMICILE_CHANGE_COLUMNS: &str = r#"
+    id, account_id, from_agency_branch_id, to_agency_branch_id, effective_date,
+    reason_id, approved_by_person_id, recorded_at
+"#;
+
+#[async_trait]
+impl AccountDomicileRepository for AccountDomicileRepositoryImpl {
+    async fn transfer_domicile(&self, change: AccountDomicileChangeModel) -> BankingResult<AccountDomicileChangeModel> {
+        let mut tx = self.pool.begin().await?;
+
+        // Only move the account if it is still at the branch the change was validated against
+        let moved = sqlx::query(
+            r#"
+            UPDATE accounts
+            SET domicile_agency_branch_id = $2,
+                updated_by_person_id = $3,
+                last_updated_at = NOW()
+            WHERE id = $1 AND domicile_agency_branch_id = $4
+            "#,
+        )
+        .bind(change.account_id)
+        .bind(change.to_agency_branch_id)
+        .bind(change.approved_by_person_id)
+        .bind(change.from_agency_branch_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to move account domicile: {e}")))?;
+        if moved.rows_affected() == 0 {
+            return Err(BankingError::Internal(format!(
+                "Account {} is no longer domiciled at branch {}",
+                change.account_id, change.from_agency_branch_id
+            )));
+        }
+
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO account_domicile_changes (
+                id, account_id, from_agency_branch_id, to_agency_branch_id, effective_date,
+                reason_id, approved_by_person_id, recorded_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING {DOMICILE_CHANGE_COLUMNS}
+            "#
+        ))
+        .bind(change.id)
+        .bind(change.account_id)
+        .bind(change.from_agency_branch_id)
+        .bind(change.to_agency_branch_id)
+        .bind(change.effective_date)
+        .bind(change.reason_id)
+        .bind(change.approved_by_person_id)
+        .bind(change.recorded_at)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to record domicile change: {e}")))?;
+
+        tx.commit().await?;
+        AccountDomicileChangeModel::try_from_row(&row)
+    }
+
+    async fn find_domicile_history(&self, account_id: Uuid) -> BankingResult<Vec<AccountDomicileChangeModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {DOMICILE_CHANGE_COLUMNS} FROM account_domicile_changes WHERE account_id = $1 ORDER BY effective_date, recorded_at"
+        ))
+        .bind(account_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find domicile history: {e}")))?;
+
+        rows.iter().map(AccountDomicileChangeModel::try_from_row).collect()
+    }
+
+    async fn find_domicile_changes_effective_after(&self, date: NaiveDate) -> BankingResult<Vec<AccountDomicileChangeModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {DOMICILE_CHANGE_COLUMNS}
+            FROM account_domicile_changes
+            WHERE effective_date > $1
+            ORDER BY account_id, effective_date, recorded_at
+            "#
+        ))
+        .bind(date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find domicile changes: {e}")))?;
+
+        rows.iter().map(AccountDomicileChangeModel::try_from_row).collect()
+    }
+}
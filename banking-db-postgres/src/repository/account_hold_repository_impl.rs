@@ -11,7 +11,7 @@ use banking_db::models::{
 use banking_db::repository::account_hold_repository::AccountHoldRepository;
 use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
-use sqlx::{PgPool};
+use sqlx::{PgPool, Postgres, Transaction};
 use uuid::Uuid;
 
 use banking_db::models::{HoldPriority, HoldStatus, HoldType};
@@ -56,23 +56,21 @@ impl AccountHoldRepositoryImpl {
     }
 }
 
-#[async_trait]
-impl AccountHoldRepository for AccountHoldRepositoryImpl {
-    async fn create_hold(&self, hold: AccountHoldModel) -> BankingResult<AccountHoldModel> {
-        let result = sqlx::query(
-            r#"
-            INSERT INTO account_holds (
-                id, account_id, amount, hold_type, reason_id, additional_details,
-                placed_by_person_id, placed_at, expires_at, status, released_at, released_by_person_id,
-                priority, source_reference, automatic_release
-            )
-            VALUES ($1, $2, $3, $4::hold_type, $5, $6, $7, $8, $9, $10::hold_status, $11, $12, $13::hold_priority, $14, $15)
-            RETURNING id, account_id, amount, hold_type::text as hold_type, reason_id,
-                     additional_details, placed_by_person_id, placed_at, expires_at, status::text as status,
-                     released_at, released_by_person_id, priority::text as priority, source_reference, automatic_release,
-                     created_at, updated_at
-            "#,
-        )
+const INSERT_HOLD: &str = r#"
+    INSERT INTO account_holds (
+        id, account_id, amount, hold_type, reason_id, additional_details,
+        placed_by_person_id, placed_at, expires_at, status, released_at, released_by_person_id,
+        priority, source_reference, automatic_release
+    )
+    VALUES ($1, $2, $3, $4::hold_type, $5, $6, $7, $8, $9, $10::hold_status, $11, $12, $13::hold_priority, $14, $15)
+    RETURNING id, account_id, amount, hold_type::text as hold_type, reason_id,
+             additional_details, placed_by_person_id, placed_at, expires_at, status::text as status,
+             released_at, released_by_person_id, priority::text as priority, source_reference, automatic_release,
+             created_at, updated_at
+"#;
+
+fn insert_hold_query(hold: &AccountHoldModel) -> sqlx::query::Query<'_, Postgres, sqlx::postgres::PgArguments> {
+    sqlx::query(INSERT_HOLD)
         .bind(hold.id)
         .bind(hold.account_id)
         .bind(hold.amount)
@@ -88,8 +86,41 @@ impl AccountHoldRepository for AccountHoldRepositoryImpl {
         .bind(hold.priority)
         .bind(hold.source_reference.as_deref())
         .bind(hold.automatic_release)
-        .fetch_one(&self.pool)
-        .await?;
+}
+
+/// Recompute available balance as current balance less active holds, and
+/// point each account at its largest active hold. The version bump keeps a
+/// full update built from an earlier read from undoing the change.
+async fn refresh_hold_balances(tx: &mut Transaction<'_, Postgres>, account_ids: &[Uuid]) -> BankingResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE accounts
+        SET available_balance = current_balance - COALESCE((
+                SELECT SUM(h.amount) FROM account_holds h
+                WHERE h.account_id = accounts.id AND h.status = 'Active'
+            ), 0),
+            most_significant_account_hold_id = (
+                SELECT h.id FROM account_holds h
+                WHERE h.account_id = accounts.id AND h.status = 'Active'
+                ORDER BY h.amount DESC, h.placed_at ASC
+                LIMIT 1
+            ),
+            last_updated_at = NOW(),
+            version = version + 1
+        WHERE id = ANY($1)
+        "#,
+    )
+    .bind(account_ids)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl AccountHoldRepository for AccountHoldRepositoryImpl {
+    async fn create_hold(&self, hold: AccountHoldModel) -> BankingResult<AccountHoldModel> {
+        let result = insert_hold_query(&hold).fetch_one(&self.pool).await?;
 
         Ok(AccountHoldModel::try_from_row(&result)?)
     }
@@ -141,41 +172,119 @@ impl AccountHoldRepository for AccountHoldRepositoryImpl {
         Ok(holds)
     }
 
+    async fn find_active_holds_by_accounts(&self, account_ids: &[Uuid]) -> BankingResult<Vec<AccountHoldModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, account_id, amount, hold_type::text as hold_type, reason_id,
+                   additional_details, placed_by_person_id, placed_at, expires_at, status::text as status,
+                   released_at, released_by_person_id, priority::text as priority, source_reference, automatic_release,
+                   created_at, updated_at
+            FROM account_holds
+            WHERE account_id = ANY($1) AND status = 'Active'
+              AND (expires_at IS NULL OR expires_at > NOW())
+            ORDER BY placed_at DESC, id
+            "#,
+        )
+        .bind(account_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(AccountHoldModel::try_from_row).collect()
+    }
+
+    async fn place_hold(&self, hold: AccountHoldModel, allow_overdraw: bool) -> BankingResult<AccountHoldModel> {
+        let mut tx = self.pool.begin().await?;
+
+        // The account lock serialises hold placement against the same balance
+        let current_balance: Decimal = sqlx::query_scalar(
+            "SELECT current_balance FROM accounts WHERE id = $1 FOR UPDATE",
+        )
+        .bind(hold.account_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(BankingError::AccountNotFound(hold.account_id))?;
+        let held: Decimal = sqlx::query_scalar(
+            "SELECT COALESCE(SUM(amount), 0) FROM account_holds WHERE account_id = $1 AND status = 'Active'",
+        )
+        .bind(hold.account_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let available = current_balance - held;
+        if hold.amount > available && !allow_overdraw {
+            return Err(BankingError::InsufficientFunds {
+                account_id: hold.account_id,
+                requested: hold.amount,
+                available,
+            });
+        }
+
+        let row = insert_hold_query(&hold).fetch_one(&mut *tx).await?;
+        refresh_hold_balances(&mut tx, &[hold.account_id]).await?;
+        tx.commit().await?;
+
+        AccountHoldModel::try_from_row(&row)
+    }
+
     async fn release_hold(&self, hold_id: Uuid, released_by_person_id: Uuid) -> BankingResult<()> {
-        sqlx::query(
+        let mut tx = self.pool.begin().await?;
+
+        let account_id: Uuid = sqlx::query_scalar(
             r#"
             UPDATE account_holds
             SET status = 'Released',
                 released_at = NOW(),
-                released_by_person_id = $2
-            WHERE id = $1
+                released_by_person_id = $2,
+                updated_at = NOW()
+            WHERE id = $1 AND status = 'Active'
+            RETURNING account_id
             "#,
         )
         .bind(hold_id)
         .bind(released_by_person_id)
-        .execute(&self.pool)
-        .await?;
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| BankingError::NotFound(format!("Active hold {hold_id}")))?;
+        refresh_hold_balances(&mut tx, &[account_id]).await?;
+        tx.commit().await?;
 
         Ok(())
     }
 
-    async fn release_expired_holds(&self, reference_date: DateTime<Utc>) -> BankingResult<i64> {
-        let result = sqlx::query(
+    async fn release_expired_holds(&self, reference_date: DateTime<Utc>) -> BankingResult<Vec<AccountHoldModel>> {
+        let mut tx = self.pool.begin().await?;
+
+        let rows = sqlx::query(
             r#"
             UPDATE account_holds
             SET status = 'Expired',
-                released_at = NOW()
+                released_at = NOW(),
+                updated_at = NOW()
             WHERE status = 'Active'
               AND expires_at IS NOT NULL
               AND expires_at <= $1
               AND automatic_release = true
+            RETURNING id, account_id, amount, hold_type::text as hold_type, reason_id,
+                     additional_details, placed_by_person_id, placed_at, expires_at, status::text as status,
+                     released_at, released_by_person_id, priority::text as priority, source_reference, automatic_release,
+                     created_at, updated_at
             "#,
         )
         .bind(reference_date)
-        .execute(&self.pool)
+        .fetch_all(&mut *tx)
         .await?;
 
-        Ok(result.rows_affected() as i64)
+        let mut holds = Vec::with_capacity(rows.len());
+        for row in rows {
+            holds.push(AccountHoldModel::try_from_row(&row)?);
+        }
+        let mut account_ids: Vec<Uuid> = holds.iter().map(|hold| hold.account_id).collect();
+        account_ids.sort();
+        account_ids.dedup();
+        refresh_hold_balances(&mut tx, &account_ids).await?;
+        tx.commit().await?;
+
+        Ok(holds)
     }
 
     // Additional Hold Methods - Migrated from HoldRepositoryImpl
@@ -0,0 +1,131 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::AccountNumberSchemeModel;
+use banking_db::repository::AccountNumberRepository;
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of AccountNumberRepository
+pub struct AccountNumberRepositoryImpl {
+    pool: PgPool,
+}
+
+impl AccountNumberRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+fn heapless<const N: usize>(value: String, field: &str) -> BankingResult<HeaplessString<N>> {
+    HeaplessString::try_from(value.as_str()).map_err(|_| BankingError::ValidationError {
+        field: field.to_string(),
+        message: format!("{field} too long"),
+    })
+}
+
+impl TryFromRow<PgRow> for AccountNumberSchemeModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(AccountNumberSchemeModel {
+            id: row.get("id"),
+            branch_id: row.get("branch_id"),
+            product_id: row.get("product_id"),
+            bank_code: heapless(row.get("bank_code"), "bank_code")?,
+            branch_code: heapless(row.get("branch_code"), "branch_code")?,
+            serial_length: row.get("serial_length"),
+            iban_country_code: row
+                .get::<Option<String>, _>("iban_country_code")
+                .map(|value| heapless(value, "iban_country_code"))
+                .transpose()?,
+            created_at: row.get("created_at"),
+            last_updated_at: row.get("last_updated_at"),
+            updated_by_person_id: row.get("updated_by_person_id"),
+        })
+    }
+}
+
+const SCHEME_COLUMNS: &str = r#"
+    id, branch_id, product_id, bank_code, branch_code, serial_length, iban_country_code,
+    created_at, last_updated_at, updated_by_person_id
+"#;
+
+#[async_trait]
+impl AccountNumberRepository for AccountNumberRepositoryImpl {
+    async fn save_scheme(&self, scheme: AccountNumberSchemeModel) -> BankingResult<AccountNumberSchemeModel> {
+        // The id and created_at of a replaced scheme are kept
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO account_number_schemes (
+                id, branch_id, product_id, bank_code, branch_code, serial_length, iban_country_code,
+                created_at, last_updated_at, updated_by_person_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (branch_id, COALESCE(product_id, '00000000-0000-0000-0000-000000000000'::UUID))
+            DO UPDATE SET
+                bank_code = EXCLUDED.bank_code,
+                branch_code = EXCLUDED.branch_code,
+                serial_length = EXCLUDED.serial_length,
+                iban_country_code = EXCLUDED.iban_country_code,
+                last_updated_at = EXCLUDED.last_updated_at,
+                updated_by_person_id = EXCLUDED.updated_by_person_id
+            RETURNING {SCHEME_COLUMNS}
+            "#
+        ))
+        .bind(scheme.id)
+        .bind(scheme.branch_id)
+        .bind(scheme.product_id)
+        .bind(scheme.bank_code.as_str())
+        .bind(scheme.branch_code.as_str())
+        .bind(scheme.serial_length)
+        .bind(scheme.iban_country_code.as_ref().map(|code| code.as_str()))
+        .bind(scheme.created_at)
+        .bind(scheme.last_updated_at)
+        .bind(scheme.updated_by_person_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to save account number scheme: {e}")))?;
+
+        AccountNumberSchemeModel::try_from_row(&row)
+    }
+
+    async fn find_scheme(&self, branch_id: Uuid, product_id: Option<Uuid>) -> BankingResult<Option<AccountNumberSchemeModel>> {
+        let row = sqlx::query(&format!(
+            r#"
+            SELECT {SCHEME_COLUMNS}
+            FROM account_number_schemes
+            WHERE branch_id = $1 AND product_id IS NOT DISTINCT FROM $2
+            "#
+        ))
+        .bind(branch_id)
+        .bind(product_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find account number scheme: {e}")))?;
+
+        row.as_ref().map(AccountNumberSchemeModel::try_from_row).transpose()
+    }
+
+    async fn next_serial(&self, branch_id: Uuid) -> BankingResult<i64> {
+        // A single statement: the row lock taken by the upsert serialises concurrent callers
+        let serial: i64 = sqlx::query_scalar(
+            r#"
+            INSERT INTO account_number_sequences (branch_id, last_serial)
+            VALUES ($1, 1)
+            ON CONFLICT (branch_id) DO UPDATE SET last_serial = account_number_sequences.last_serial + 1
+            RETURNING last_serial
+            "#,
+        )
+        .bind(branch_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to advance account number sequence: {e}")))?;
+
+        Ok(serial)
+    }
+}
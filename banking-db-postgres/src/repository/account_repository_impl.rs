@@ -3,18 +3,32 @@ use banking_api::{BankingError, BankingResult};
 use banking_db::models::{
     AccountFinalSettlementModel, AccountMandateModel, AccountModel, AccountOwnershipModel,
     AccountRelationshipModel, AccountStatusChangeRecordModel, ReasonAndPurpose as ReasonAndPurposeModel,
+    AccountBalanceSnapshotModel, AccountInterestAccrualModel, AccountBalanceChangeRecordModel, DbBalanceChangeSource,
+    DbSettlementStatus, LoanPenaltyAccrualModel,
 };
+use banking_db::models::casa::OverdraftInterestAccrual as OverdraftInterestAccrualModel;
+use banking_db::models::{AccountIdxModel, AccountIdxModelCache};
 use banking_db::repository::{AccountRepository, ReasonAndPurposeRepository};
 use banking_db::{DbAccountType, DbMandateStatus, DbPermissionType};
-use chrono::{NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
-use sqlx::{PgPool, Row, postgres::PgRow};
+use sqlx::{PgPool, Postgres, Row, Transaction, postgres::PgRow};
 use uuid::Uuid;
 use banking_api::domain::{ReasonCategory, ReasonContext};
 use crate::repository::reason_and_purpose_repository_impl::ReasonAndPurposeRepositoryImpl;
 use heapless::String as HeaplessString;
+use parking_lot::RwLock;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hasher;
 use std::str::FromStr;
+use std::sync::Arc;
+use twox_hash::XxHash64;
 
+/// Ids bound as one array per query; larger inputs are split into chunks
+const MAX_IDS_PER_QUERY: usize = 10_000;
+
+/// Accounts whose status is changed in one transaction by bulk_update_status
+const STATUS_CHANGE_CHUNK_SIZE: usize = 200;
 
 trait TryFromRow<R> {
     fn try_from_row(row: &R) -> BankingResult<Self>
@@ -25,6 +39,7 @@ trait TryFromRow<R> {
 pub struct AccountRepositoryImpl {
     pub pool: PgPool,
     reason_repo: Box<dyn ReasonAndPurposeRepository>,
+    account_idx_cache: Arc<RwLock<AccountIdxModelCache>>,
 }
 
 impl AccountRepositoryImpl {
@@ -32,8 +47,132 @@ impl AccountRepositoryImpl {
         Self {
             pool: pool.clone(),
             reason_repo: Box::new(ReasonAndPurposeRepositoryImpl::new(pool)),
+            account_idx_cache: Arc::new(RwLock::new(AccountIdxModelCache::default())),
         }
     }
+
+    fn account_number_hash(account_number: &str) -> i64 {
+        let mut hasher = XxHash64::with_seed(0);
+        hasher.write(account_number.as_bytes());
+        hasher.finish() as i64
+    }
+
+    fn idx_model(account: &AccountModel) -> AccountIdxModel {
+        AccountIdxModel {
+            account_id: account.id,
+            account_number_hash: Self::account_number_hash(account.account_number.as_str()),
+        }
+    }
+
+    /// Applies one chunk of a bulk status change, returning the previous status of each account found
+    async fn update_status_chunk(
+        &self,
+        account_ids: &[Uuid],
+        status: &str,
+        reason_id: Uuid,
+        changed_by_person_id: Uuid,
+    ) -> BankingResult<HashMap<Uuid, String>> {
+        let mut tx = self.pool.begin().await?;
+
+        let previous: Vec<(Uuid, String)> = sqlx::query_as(
+            "SELECT id, account_status::text FROM accounts WHERE id = ANY($1) FOR UPDATE",
+        )
+        .bind(account_ids)
+        .fetch_all(&mut *tx)
+        .await?;
+        let (ids, old_statuses): (Vec<Uuid>, Vec<String>) = previous.iter().cloned().unzip();
+
+        sqlx::query(
+            r#"
+            UPDATE accounts
+            SET account_status = $2::account_status,
+                status_changed_by_person_id = $3,
+                status_change_reason_id = $4,
+                status_change_timestamp = NOW(),
+                last_updated_at = NOW()
+            WHERE id = ANY($1)
+            "#,
+        )
+        .bind(&ids)
+        .bind(status)
+        .bind(changed_by_person_id)
+        .bind(reason_id)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO account_status_change_records (
+                id, account_id, old_status, new_status, reason_id,
+                additional_context, changed_by_person_id, changed_at, system_triggered
+            )
+            SELECT gen_random_uuid(), changed.id, changed.old_status::account_status, $3::account_status, $4,
+                   NULL, $5, NOW(), false
+            FROM unnest($1::uuid[], $2::text[]) AS changed(id, old_status)
+            "#,
+        )
+        .bind(&ids)
+        .bind(&old_statuses)
+        .bind(status)
+        .bind(reason_id)
+        .bind(changed_by_person_id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(previous.into_iter().collect())
+    }
+
+    /// Sets the balances of an account locked by `tx` from `old` to `new`, each a
+    /// (current, available) pair, and records the change in the balance history
+    async fn set_balances(
+        tx: &mut Transaction<'_, Postgres>,
+        account_id: Uuid,
+        (old_current_balance, old_available_balance): (Decimal, Decimal),
+        (current_balance, available_balance): (Decimal, Decimal),
+        change_source: DbBalanceChangeSource,
+        transaction_id: Option<Uuid>,
+        changed_by_person_id: Uuid,
+    ) -> BankingResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE accounts 
+            SET current_balance = $2,
+                available_balance = $3,
+                last_updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(account_id)
+        .bind(current_balance)
+        .bind(available_balance)
+        .execute(&mut **tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO account_balance_change_records (
+                id, account_id, old_current_balance, new_current_balance,
+                old_available_balance, new_available_balance, change_source,
+                transaction_id, changed_at, changed_by_person_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7::balance_change_source, $8, NOW(), $9)
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(account_id)
+        .bind(old_current_balance)
+        .bind(current_balance)
+        .bind(old_available_balance)
+        .bind(available_balance)
+        .bind(change_source)
+        .bind(transaction_id)
+        .bind(changed_by_person_id)
+        .execute(&mut **tx)
+        .await?;
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -57,17 +196,17 @@ impl AccountRepository for AccountRepositoryImpl {
                 access16_account_mandate_id, access17_account_mandate_id, interest01_ultimate_beneficiary_id,
                 interest02_ultimate_beneficiary_id, interest03_ultimate_beneficiary_id, interest04_ultimate_beneficiary_id,
                 interest05_ultimate_beneficiary_id, interest06_ultimate_beneficiary_id, interest07_ultimate_beneficiary_id,
-                updated_by_person_id
+                updated_by_person_id, account_number
             )
             VALUES (
                 $1, $2, $3::account_type, $4::account_status, $5::signing_condition, $6, $7, $8, $9, $10, $11, $12, $13, $14,
                 $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29, $30,
                 $31, $32, $33, $34, $35, $36, $37, $38, $39, $40, $41, $42, $43, $44, $45, $46,
-                $47, $48, $49, $50, $51, $52, $53, $54, $55, $56
+                $47, $48, $49, $50, $51, $52, $53, $54, $55, $56, $57
             )
             RETURNING id, product_id, account_type::text as account_type,
                      account_status::text as account_status, signing_condition::text as signing_condition,
-                     currency, open_date, domicile_agency_branch_id, gl_code_suffix, current_balance, available_balance,
+                     currency, open_date, domicile_agency_branch_id, account_number, gl_code_suffix, current_balance, available_balance,
                      accrued_interest, overdraft_limit, original_principal, outstanding_principal,
                      loan_interest_rate, loan_term_months, disbursement_date, maturity_date,
                      installment_amount, next_due_date, penalty_rate, collateral_id, loan_purpose_id,
@@ -81,7 +220,7 @@ impl AccountRepository for AccountRepositoryImpl {
                      access16_account_mandate_id, access17_account_mandate_id, interest01_ultimate_beneficiary_id,
                      interest02_ultimate_beneficiary_id, interest03_ultimate_beneficiary_id, interest04_ultimate_beneficiary_id,
                      interest05_ultimate_beneficiary_id, interest06_ultimate_beneficiary_id, interest07_ultimate_beneficiary_id,
-                     created_at, last_updated_at, updated_by_person_id
+                     created_at, last_updated_at, updated_by_person_id, version
             "#,
         )
         .bind(account.id)
@@ -140,11 +279,20 @@ impl AccountRepository for AccountRepositoryImpl {
         .bind(account.interest06_ultimate_beneficiary_id)
         .bind(account.interest07_ultimate_beneficiary_id)
         .bind(account.updated_by_person_id)
+        .bind(account.account_number.as_str())
         .fetch_one(&self.pool)
-        .await?;
+        .await
+        .map_err(|err| match &err {
+            sqlx::Error::Database(db_err) if db_err.constraint() == Some("idx_accounts_account_number") => {
+                BankingError::DuplicateAccountNumber(account.account_number.to_string())
+            }
+            _ => BankingError::from(err),
+        })?;
 
         // Convert result back to AccountModel
-        AccountModel::try_from_row(&result)
+        let created = AccountModel::try_from_row(&result)?;
+        self.account_idx_cache.write().add(Self::idx_model(&created));
+        Ok(created)
     }
 
     async fn update(&self, account: AccountModel) -> BankingResult<AccountModel> {
@@ -169,11 +317,12 @@ impl AccountRepository for AccountRepositoryImpl {
                 access15_account_mandate_id = $46, access16_account_mandate_id = $47, access17_account_mandate_id = $48,
                 interest01_ultimate_beneficiary_id = $49, interest02_ultimate_beneficiary_id = $50, interest03_ultimate_beneficiary_id = $51,
                 interest04_ultimate_beneficiary_id = $52, interest05_ultimate_beneficiary_id = $53, interest06_ultimate_beneficiary_id = $54,
-                interest07_ultimate_beneficiary_id = $55, last_updated_at = NOW(), updated_by_person_id = $56
-            WHERE id = $1
+                interest07_ultimate_beneficiary_id = $55, last_updated_at = NOW(), updated_by_person_id = $56,
+                version = version + 1
+            WHERE id = $1 AND version = $57
             RETURNING id, product_id, account_type::text as account_type,
                      account_status::text as account_status, signing_condition::text as signing_condition,
-                     currency, open_date, domicile_agency_branch_id, gl_code_suffix, current_balance, available_balance,
+                     currency, open_date, domicile_agency_branch_id, account_number, gl_code_suffix, current_balance, available_balance,
                      accrued_interest, overdraft_limit, original_principal, outstanding_principal,
                      loan_interest_rate, loan_term_months, disbursement_date, maturity_date,
                      installment_amount, next_due_date, penalty_rate, collateral_id, loan_purpose_id,
@@ -187,7 +336,7 @@ impl AccountRepository for AccountRepositoryImpl {
                      access16_account_mandate_id, access17_account_mandate_id, interest01_ultimate_beneficiary_id,
                      interest02_ultimate_beneficiary_id, interest03_ultimate_beneficiary_id, interest04_ultimate_beneficiary_id,
                      interest05_ultimate_beneficiary_id, interest06_ultimate_beneficiary_id, interest07_ultimate_beneficiary_id,
-                     created_at, last_updated_at, updated_by_person_id
+                     created_at, last_updated_at, updated_by_person_id, version
             "#,
         )
         .bind(account.id)
@@ -246,10 +395,19 @@ impl AccountRepository for AccountRepositoryImpl {
         .bind(account.interest06_ultimate_beneficiary_id)
         .bind(account.interest07_ultimate_beneficiary_id)
         .bind(account.updated_by_person_id)
-        .fetch_one(&self.pool)
+        .bind(account.version)
+        .fetch_optional(&self.pool)
         .await?;
 
-        AccountModel::try_from_row(&result)
+        match result {
+            Some(row) => AccountModel::try_from_row(&row),
+            // Nothing matched the id and version: either another writer got there first or the account is gone
+            None if self.exists(account.id).await? => Err(BankingError::StaleVersion {
+                entity_id: account.id,
+                expected_version: account.version,
+            }),
+            None => Err(BankingError::AccountNotFound(account.id)),
+        }
     }
 
     async fn find_by_id(&self, account_id: Uuid) -> BankingResult<Option<AccountModel>> {
@@ -257,7 +415,7 @@ impl AccountRepository for AccountRepositoryImpl {
             r#"
             SELECT id, product_id, account_type::text as account_type,
                    account_status::text as account_status, signing_condition::text as signing_condition,
-                   currency, open_date, domicile_agency_branch_id, gl_code_suffix, current_balance, available_balance,
+                   currency, open_date, domicile_agency_branch_id, account_number, gl_code_suffix, current_balance, available_balance,
                    accrued_interest, overdraft_limit, original_principal, outstanding_principal,
                    loan_interest_rate, loan_term_months, disbursement_date, maturity_date,
                    installment_amount, next_due_date, penalty_rate, collateral_id, loan_purpose_id,
@@ -272,7 +430,7 @@ impl AccountRepository for AccountRepositoryImpl {
                    access16_account_mandate_id, access17_account_mandate_id, interest01_ultimate_beneficiary_id,
                    interest02_ultimate_beneficiary_id, interest03_ultimate_beneficiary_id, interest04_ultimate_beneficiary_id,
                    interest05_ultimate_beneficiary_id, interest06_ultimate_beneficiary_id, interest07_ultimate_beneficiary_id,
-                   created_at, last_updated_at, updated_by_person_id
+                   created_at, last_updated_at, updated_by_person_id, version
             FROM accounts WHERE id = $1
             "#,
         )
@@ -286,12 +444,61 @@ impl AccountRepository for AccountRepositoryImpl {
         }
     }
 
+    async fn find_by_account_number(&self, account_number: &str) -> BankingResult<Option<AccountModel>> {
+        let account_number_hash = Self::account_number_hash(account_number);
+        let cached_id = self.account_idx_cache.read().get_by_account_number_hash(&account_number_hash);
+        if let Some(account_id) = cached_id {
+            // The hash only narrows the search; a colliding number falls through to the query
+            if let Some(account) = self.find_by_id(account_id).await? {
+                if account.account_number.as_str() == account_number {
+                    return Ok(Some(account));
+                }
+            }
+        }
+
+        let result = sqlx::query(
+            r#"
+            SELECT id, product_id, account_type::text as account_type,
+                   account_status::text as account_status, signing_condition::text as signing_condition,
+                   currency, open_date, domicile_agency_branch_id, account_number, gl_code_suffix, current_balance, available_balance,
+                   accrued_interest, overdraft_limit, original_principal, outstanding_principal,
+                   loan_interest_rate, loan_term_months, disbursement_date, maturity_date,
+                   installment_amount, next_due_date, penalty_rate, collateral_id, loan_purpose_id,
+                   close_date, last_activity_date, dormancy_threshold_days, reactivation_required,
+                   pending_closure_reason_id, last_disbursement_instruction_id, status_changed_by_person_id,
+                   status_change_reason_id, status_change_timestamp,
+                   most_significant_account_hold_id, account_ownership_id,
+                   access01_account_relationship_id, access02_account_relationship_id, access03_account_relationship_id,
+                   access04_account_relationship_id, access05_account_relationship_id, access06_account_relationship_id,
+                   access07_account_relationship_id, access11_account_mandate_id, access12_account_mandate_id,
+                   access13_account_mandate_id, access14_account_mandate_id, access15_account_mandate_id,
+                   access16_account_mandate_id, access17_account_mandate_id, interest01_ultimate_beneficiary_id,
+                   interest02_ultimate_beneficiary_id, interest03_ultimate_beneficiary_id, interest04_ultimate_beneficiary_id,
+                   interest05_ultimate_beneficiary_id, interest06_ultimate_beneficiary_id, interest07_ultimate_beneficiary_id,
+                   created_at, last_updated_at, updated_by_person_id, version
+            FROM accounts WHERE account_number = $1
+            "#,
+        )
+        .bind(account_number)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match result {
+            Some(row) => {
+                let account = AccountModel::try_from_row(&row)?;
+                self.account_idx_cache.write().add(Self::idx_model(&account));
+                Ok(Some(account))
+            }
+            None => Ok(None),
+        }
+    }
+
     async fn find_by_customer_id(&self, customer_id: Uuid) -> BankingResult<Vec<AccountModel>> {
         let rows = sqlx::query(
             r#"
             SELECT a.id, a.product_id, a.account_type::text as account_type,
                    a.account_status::text as account_status, a.signing_condition::text as signing_condition,
-                   a.currency, a.open_date, a.domicile_agency_branch_id, a.gl_code_suffix, a.current_balance, a.available_balance,
+                   a.currency, a.open_date, a.domicile_agency_branch_id, a.account_number, a.gl_code_suffix, a.current_balance, a.available_balance,
                    a.accrued_interest, a.overdraft_limit, a.original_principal, a.outstanding_principal,
                    a.loan_interest_rate, a.loan_term_months, a.disbursement_date, a.maturity_date,
                    a.installment_amount, a.next_due_date, a.penalty_rate, a.collateral_id, a.loan_purpose_id,
@@ -329,7 +536,7 @@ impl AccountRepository for AccountRepositoryImpl {
             r#"
             SELECT id, product_id, account_type::text as account_type,
                    account_status::text as account_status, signing_condition::text as signing_condition,
-                   currency, open_date, domicile_agency_branch_id, gl_code_suffix, current_balance, available_balance,
+                   currency, open_date, domicile_agency_branch_id, account_number, gl_code_suffix, current_balance, available_balance,
                    accrued_interest, overdraft_limit, original_principal, outstanding_principal,
                    loan_interest_rate, loan_term_months, disbursement_date, maturity_date,
                    installment_amount, next_due_date, penalty_rate, collateral_id, loan_purpose_id,
@@ -344,7 +551,7 @@ impl AccountRepository for AccountRepositoryImpl {
                    access16_account_mandate_id, access17_account_mandate_id, interest01_ultimate_beneficiary_id,
                    interest02_ultimate_beneficiary_id, interest03_ultimate_beneficiary_id, interest04_ultimate_beneficiary_id,
                    interest05_ultimate_beneficiary_id, interest06_ultimate_beneficiary_id, interest07_ultimate_beneficiary_id,
-                   created_at, last_updated_at, updated_by_person_id
+                   created_at, last_updated_at, updated_by_person_id, version
             FROM accounts WHERE product_id = $1
             ORDER BY created_at DESC
             "#,
@@ -365,7 +572,7 @@ impl AccountRepository for AccountRepositoryImpl {
             r#"
             SELECT id, product_id, account_type::text as account_type,
                    account_status::text as account_status, signing_condition::text as signing_condition,
-                   currency, open_date, domicile_agency_branch_id, gl_code_suffix, current_balance, available_balance,
+                   currency, open_date, domicile_agency_branch_id, account_number, gl_code_suffix, current_balance, available_balance,
                    accrued_interest, overdraft_limit, original_principal, outstanding_principal,
                    loan_interest_rate, loan_term_months, disbursement_date, maturity_date,
                    installment_amount, next_due_date, penalty_rate, collateral_id, loan_purpose_id,
@@ -380,7 +587,7 @@ impl AccountRepository for AccountRepositoryImpl {
                    access16_account_mandate_id, access17_account_mandate_id, interest01_ultimate_beneficiary_id,
                    interest02_ultimate_beneficiary_id, interest03_ultimate_beneficiary_id, interest04_ultimate_beneficiary_id,
                    interest05_ultimate_beneficiary_id, interest06_ultimate_beneficiary_id, interest07_ultimate_beneficiary_id,
-                   created_at, last_updated_at, updated_by_person_id
+                   created_at, last_updated_at, updated_by_person_id, version
             FROM accounts WHERE account_status::text = $1
             ORDER BY created_at DESC
             "#,
@@ -401,7 +608,7 @@ impl AccountRepository for AccountRepositoryImpl {
             r#"
             SELECT id, product_id, account_type::text as account_type,
                    account_status::text as account_status, signing_condition::text as signing_condition,
-                   currency, open_date, domicile_agency_branch_id, gl_code_suffix, current_balance, available_balance,
+                   currency, open_date, domicile_agency_branch_id, account_number, gl_code_suffix, current_balance, available_balance,
                    accrued_interest, overdraft_limit, original_principal, outstanding_principal,
                    loan_interest_rate, loan_term_months, disbursement_date, maturity_date,
                    installment_amount, next_due_date, penalty_rate, collateral_id, loan_purpose_id,
@@ -416,7 +623,7 @@ impl AccountRepository for AccountRepositoryImpl {
                    access16_account_mandate_id, access17_account_mandate_id, interest01_ultimate_beneficiary_id,
                    interest02_ultimate_beneficiary_id, interest03_ultimate_beneficiary_id, interest04_ultimate_beneficiary_id,
                    interest05_ultimate_beneficiary_id, interest06_ultimate_beneficiary_id, interest07_ultimate_beneficiary_id,
-                   created_at, last_updated_at, updated_by_person_id
+                   created_at, last_updated_at, updated_by_person_id, version
             FROM accounts WHERE account_type::text = $1
             ORDER BY created_at DESC
             "#,
@@ -432,12 +639,12 @@ impl AccountRepository for AccountRepositoryImpl {
         Ok(accounts)
     }
 
-    async fn find_dormancy_candidates(&self, reference_date: NaiveDate, threshold_days: i32) -> BankingResult<Vec<AccountModel>> {
+    async fn find_dormancy_candidates(&self, reference_date: NaiveDate, threshold_days: i32, product_id: Option<Uuid>) -> BankingResult<Vec<AccountModel>> {
         let rows = sqlx::query(
             r#"
             SELECT id, product_id, account_type::text as account_type,
                    account_status::text as account_status, signing_condition::text as signing_condition,
-                   currency, open_date, domicile_agency_branch_id, gl_code_suffix, current_balance, available_balance,
+                   currency, open_date, domicile_agency_branch_id, account_number, gl_code_suffix, current_balance, available_balance,
                    accrued_interest, overdraft_limit, original_principal, outstanding_principal,
                    loan_interest_rate, loan_term_months, disbursement_date, maturity_date,
                    installment_amount, next_due_date, penalty_rate, collateral_id, loan_purpose_id,
@@ -452,16 +659,18 @@ impl AccountRepository for AccountRepositoryImpl {
                    access16_account_mandate_id, access17_account_mandate_id, interest01_ultimate_beneficiary_id,
                    interest02_ultimate_beneficiary_id, interest03_ultimate_beneficiary_id, interest04_ultimate_beneficiary_id,
                    interest05_ultimate_beneficiary_id, interest06_ultimate_beneficiary_id, interest07_ultimate_beneficiary_id,
-                   created_at, last_updated_at, updated_by_person_id
+                   created_at, last_updated_at, updated_by_person_id, version
             FROM accounts 
             WHERE account_status = 'Active'
               AND last_activity_date IS NOT NULL
-              AND $1 - last_activity_date >= $2
+              AND $1 - last_activity_date >= COALESCE(dormancy_threshold_days, $2)
+              AND ($3::uuid IS NULL OR product_id = $3)
             ORDER BY last_activity_date ASC
             "#,
         )
         .bind(reference_date)
         .bind(threshold_days)
+        .bind(product_id)
         .fetch_all(&self.pool)
         .await?;
 
@@ -477,7 +686,7 @@ impl AccountRepository for AccountRepositoryImpl {
             r#"
             SELECT id, product_id, account_type::text as account_type,
                    account_status::text as account_status, signing_condition::text as signing_condition,
-                   currency, open_date, domicile_agency_branch_id, gl_code_suffix, current_balance, available_balance,
+                   currency, open_date, domicile_agency_branch_id, account_number, gl_code_suffix, current_balance, available_balance,
                    accrued_interest, overdraft_limit, original_principal, outstanding_principal,
                    loan_interest_rate, loan_term_months, disbursement_date, maturity_date,
                    installment_amount, next_due_date, penalty_rate, collateral_id, loan_purpose_id,
@@ -492,7 +701,7 @@ impl AccountRepository for AccountRepositoryImpl {
                    access16_account_mandate_id, access17_account_mandate_id, interest01_ultimate_beneficiary_id,
                    interest02_ultimate_beneficiary_id, interest03_ultimate_beneficiary_id, interest04_ultimate_beneficiary_id,
                    interest05_ultimate_beneficiary_id, interest06_ultimate_beneficiary_id, interest07_ultimate_beneficiary_id,
-                   created_at, last_updated_at, updated_by_person_id
+                   created_at, last_updated_at, updated_by_person_id, version
             FROM accounts 
             WHERE account_status = 'PendingClosure'
             ORDER BY created_at DESC
@@ -513,7 +722,7 @@ impl AccountRepository for AccountRepositoryImpl {
             r#"
             SELECT id, product_id, account_type::text as account_type,
                    account_status::text as account_status, signing_condition::text as signing_condition,
-                   currency, open_date, domicile_agency_branch_id, gl_code_suffix, current_balance, available_balance,
+                   currency, open_date, domicile_agency_branch_id, account_number, gl_code_suffix, current_balance, available_balance,
                    accrued_interest, overdraft_limit, original_principal, outstanding_principal,
                    loan_interest_rate, loan_term_months, disbursement_date, maturity_date,
                    installment_amount, next_due_date, penalty_rate, collateral_id, loan_purpose_id,
@@ -528,7 +737,7 @@ impl AccountRepository for AccountRepositoryImpl {
                    access16_account_mandate_id, access17_account_mandate_id, interest01_ultimate_beneficiary_id,
                    interest02_ultimate_beneficiary_id, interest03_ultimate_beneficiary_id, interest04_ultimate_beneficiary_id,
                    interest05_ultimate_beneficiary_id, interest06_ultimate_beneficiary_id, interest07_ultimate_beneficiary_id,
-                   created_at, last_updated_at, updated_by_person_id
+                   created_at, last_updated_at, updated_by_person_id, version
             FROM accounts 
             WHERE account_type = 'Savings' 
                OR (account_type = 'Loan' AND loan_interest_rate > 0)
@@ -545,22 +754,129 @@ impl AccountRepository for AccountRepositoryImpl {
         Ok(accounts)
     }
 
-    async fn update_status(&self, account_id: Uuid, status: &str, reason: &str, changed_by_person_id: Uuid) -> BankingResult<()> {
+    async fn find_interest_bearing_accounts_after(&self, after_account_id: Option<Uuid>, limit: i64) -> BankingResult<Vec<AccountModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, product_id, account_type::text as account_type,
+                   account_status::text as account_status, signing_condition::text as signing_condition,
+                   currency, open_date, domicile_agency_branch_id, account_number, gl_code_suffix, current_balance, available_balance,
+                   accrued_interest, overdraft_limit, original_principal, outstanding_principal,
+                   loan_interest_rate, loan_term_months, disbursement_date, maturity_date,
+                   installment_amount, next_due_date, penalty_rate, collateral_id, loan_purpose_id,
+                   close_date, last_activity_date, dormancy_threshold_days, reactivation_required,
+                   pending_closure_reason_id, last_disbursement_instruction_id, status_changed_by_person_id,
+                   status_change_reason_id, status_change_timestamp,
+                   most_significant_account_hold_id, account_ownership_id,
+                   access01_account_relationship_id, access02_account_relationship_id, access03_account_relationship_id,
+                   access04_account_relationship_id, access05_account_relationship_id, access06_account_relationship_id,
+                   access07_account_relationship_id, access11_account_mandate_id, access12_account_mandate_id,
+                   access13_account_mandate_id, access14_account_mandate_id, access15_account_mandate_id,
+                   access16_account_mandate_id, access17_account_mandate_id, interest01_ultimate_beneficiary_id,
+                   interest02_ultimate_beneficiary_id, interest03_ultimate_beneficiary_id, interest04_ultimate_beneficiary_id,
+                   interest05_ultimate_beneficiary_id, interest06_ultimate_beneficiary_id, interest07_ultimate_beneficiary_id,
+                   created_at, last_updated_at, updated_by_person_id, version
+            FROM accounts
+            WHERE (account_type = 'Savings'
+                   OR (account_type = 'Loan' AND loan_interest_rate > 0)
+                   OR (account_type = 'Current' AND current_balance > 0))
+              AND ($1::uuid IS NULL OR id > $1)
+            ORDER BY id
+            LIMIT $2
+            "#
+        )
+        .bind(after_account_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut accounts = Vec::new();
+        for row in rows {
+            accounts.push(AccountModel::try_from_row(&row)?);
+        }
+        Ok(accounts)
+    }
+
+    async fn update_status(&self, account_id: Uuid, status: &str, reason_id: Uuid, changed_by_person_id: Uuid) -> BankingResult<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let old_status: Option<String> = sqlx::query_scalar(
+            "SELECT account_status::text FROM accounts WHERE id = $1 FOR UPDATE",
+        )
+        .bind(account_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let old_status = old_status.ok_or(BankingError::AccountNotFound(account_id))?;
+
         sqlx::query(
             r#"
             UPDATE accounts
             SET account_status = $2::account_status,
                 status_changed_by_person_id = $3,
-                status_change_timestamp = NOW()
+                status_change_reason_id = $4,
+                status_change_timestamp = NOW(),
+                last_updated_at = NOW()
             WHERE id = $1
             "#,
         )
         .bind(account_id)
         .bind(status)
         .bind(changed_by_person_id)
-        .execute(&self.pool)
+        .bind(reason_id)
+        .execute(&mut *tx)
+        .await?;
+
+        // Add status change to history
+        sqlx::query(
+            r#"
+            INSERT INTO account_status_change_records (
+                id, account_id, old_status, new_status, reason_id,
+                additional_context, changed_by_person_id, changed_at, system_triggered
+            )
+            VALUES (gen_random_uuid(), $1, $2::account_status, $3::account_status, $4, NULL, $5, NOW(), false)
+            "#,
+        )
+        .bind(account_id)
+        .bind(old_status)
+        .bind(status)
+        .bind(reason_id)
+        .bind(changed_by_person_id)
+        .execute(&mut *tx)
         .await?;
-        
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn bulk_update_status(
+        &self,
+        account_ids: &[Uuid],
+        status: &str,
+        reason_id: Uuid,
+        changed_by_person_id: Uuid,
+    ) -> Vec<(Uuid, BankingResult<()>)> {
+        let mut results = Vec::with_capacity(account_ids.len());
+        for chunk in account_ids.chunks(STATUS_CHANGE_CHUNK_SIZE) {
+            match self.update_status_chunk(chunk, status, reason_id, changed_by_person_id).await {
+                Ok(previous) => results.extend(chunk.iter().map(|id| {
+                    let result = if previous.contains_key(id) {
+                        Ok(())
+                    } else {
+                        Err(BankingError::AccountNotFound(*id))
+                    };
+                    (*id, result)
+                })),
+                Err(e) => {
+                    let message = e.to_string();
+                    results.extend(chunk.iter().map(|id| {
+                        (*id, Err(BankingError::Internal(format!("Status change rolled back: {message}"))))
+                    }));
+                }
+            }
+        }
+        results
+    }
+
+    async fn update_status_legacy(&self, account_id: Uuid, status: &str, reason: &str, changed_by_person_id: Uuid) -> BankingResult<()> {
         let reason_model = ReasonAndPurposeModel {
             id: Uuid::new_v4(),
             code: HeaplessString::from_str(reason)
@@ -586,81 +902,197 @@ impl AccountRepository for AccountRepositoryImpl {
         };
         let created_reason = self.reason_repo.create(reason_model).await?;
 
-        // Add status change to history
-        sqlx::query(
+        self.update_status(account_id, status, created_reason.id, changed_by_person_id).await
+    }
+
+    async fn update_balance(
+        &self,
+        account_id: Uuid,
+        current_balance: Decimal,
+        available_balance: Decimal,
+        change_source: DbBalanceChangeSource,
+        transaction_id: Option<Uuid>,
+        changed_by_person_id: Uuid,
+    ) -> BankingResult<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let (old_current_balance, old_available_balance): (Decimal, Decimal) = sqlx::query_as(
+            "SELECT current_balance, available_balance FROM accounts WHERE id = $1 FOR UPDATE",
+        )
+        .bind(account_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(BankingError::AccountNotFound(account_id))?;
+
+        Self::set_balances(
+            &mut tx,
+            account_id,
+            (old_current_balance, old_available_balance),
+            (current_balance, available_balance),
+            change_source,
+            transaction_id,
+            changed_by_person_id,
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn get_balance_history(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> BankingResult<Vec<AccountBalanceChangeRecordModel>> {
+        let rows = sqlx::query(
             r#"
-            INSERT INTO account_status_change_records (
-                id, account_id, old_status, new_status, reason_id,
-                additional_context, changed_by_person_id, changed_at, system_triggered
-            )
-            VALUES (gen_random_uuid(), $1,
-                    (SELECT account_status FROM accounts WHERE id = $1),
-                    $2::account_status, $3, $4, $5, NOW(), false)
+            SELECT id, account_id, old_current_balance, new_current_balance,
+                   old_available_balance, new_available_balance,
+                   change_source::text as change_source,
+                   transaction_id, changed_at, changed_by_person_id
+            FROM account_balance_change_records
+            WHERE account_id = $1 AND changed_at BETWEEN $2 AND $3
+            ORDER BY changed_at ASC
             "#,
         )
         .bind(account_id)
-        .bind(status)
-        .bind(created_reason.id)
-        .bind(reason)
-        .bind(changed_by_person_id)
-        .execute(&self.pool)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
         .await?;
 
-        Ok(())
+        let mut history = Vec::new();
+        for row in rows {
+            history.push(AccountBalanceChangeRecordModel::try_from_row(&row)?);
+        }
+        Ok(history)
     }
 
-    async fn update_balance(&self, account_id: Uuid, current_balance: Decimal, available_balance: Decimal) -> BankingResult<()> {
+    async fn update_accrued_interest(&self, account_id: Uuid, accrued_interest: Decimal) -> BankingResult<()> {
         sqlx::query(
             r#"
             UPDATE accounts 
-            SET current_balance = $2,
-                available_balance = $3,
+            SET accrued_interest = $2,
                 last_updated_at = NOW()
             WHERE id = $1
             "#,
         )
         .bind(account_id)
-        .bind(current_balance)
-        .bind(available_balance)
+        .bind(accrued_interest)
         .execute(&self.pool)
         .await?;
 
         Ok(())
     }
 
-    async fn update_accrued_interest(&self, account_id: Uuid, accrued_interest: Decimal) -> BankingResult<()> {
+    async fn reset_accrued_interest(&self, account_id: Uuid) -> BankingResult<()> {
         sqlx::query(
             r#"
             UPDATE accounts 
-            SET accrued_interest = $2,
+            SET accrued_interest = 0.00,
                 last_updated_at = NOW()
             WHERE id = $1
             "#,
         )
         .bind(account_id)
-        .bind(accrued_interest)
         .execute(&self.pool)
         .await?;
 
         Ok(())
     }
 
-    async fn reset_accrued_interest(&self, account_id: Uuid) -> BankingResult<()> {
+    async fn post_accrued_interest(
+        &self,
+        account_id: Uuid,
+        amount: Decimal,
+        change_source: DbBalanceChangeSource,
+        transaction_id: Option<Uuid>,
+        changed_by_person_id: Uuid,
+    ) -> BankingResult<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let (current_balance, available_balance, accrued_interest): (Decimal, Decimal, Decimal) = sqlx::query_as(
+            "SELECT current_balance, available_balance, accrued_interest FROM accounts WHERE id = $1 FOR UPDATE",
+        )
+        .bind(account_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(BankingError::AccountNotFound(account_id))?;
+
+        Self::set_balances(
+            &mut tx,
+            account_id,
+            (current_balance, available_balance),
+            (current_balance + amount, available_balance + amount),
+            change_source,
+            transaction_id,
+            changed_by_person_id,
+        )
+        .await?;
+
+        // Zero once everything accrued is posted; a rounding remainder stays accrued
         sqlx::query(
             r#"
             UPDATE accounts 
-            SET accrued_interest = 0.00,
+            SET accrued_interest = $2,
                 last_updated_at = NOW()
             WHERE id = $1
             "#,
         )
         .bind(account_id)
-        .execute(&self.pool)
+        .bind(accrued_interest - amount)
+        .execute(&mut *tx)
         .await?;
 
+        tx.commit().await?;
         Ok(())
     }
 
+    async fn apply_interest_accruals(&self, accruals: Vec<AccountInterestAccrualModel>) -> BankingResult<Vec<Uuid>> {
+        let mut tx = self.pool.begin().await?;
+
+        // The (account_id, accrual_date) key is the idempotency marker: a retried chunk
+        // only gets back the accounts that were not accrued yet
+        let accrued: Vec<(Uuid, Decimal)> = sqlx::query_as(
+            r#"
+            INSERT INTO account_interest_accruals (
+                account_id, accrual_date, daily_interest, interest_rate, principal_balance,
+                promotion_id, bonus_interest, created_at
+            )
+            SELECT * FROM UNNEST(
+                $1::uuid[], $2::date[], $3::numeric[], $4::numeric[], $5::numeric[],
+                $6::uuid[], $7::numeric[], $8::timestamptz[]
+            )
+            ON CONFLICT (account_id, accrual_date) DO NOTHING
+            RETURNING account_id, daily_interest
+            "#,
+        )
+        .bind(accruals.iter().map(|a| a.account_id).collect::<Vec<_>>())
+        .bind(accruals.iter().map(|a| a.accrual_date).collect::<Vec<_>>())
+        .bind(accruals.iter().map(|a| a.daily_interest).collect::<Vec<_>>())
+        .bind(accruals.iter().map(|a| a.interest_rate).collect::<Vec<_>>())
+        .bind(accruals.iter().map(|a| a.principal_balance).collect::<Vec<_>>())
+        .bind(accruals.iter().map(|a| a.promotion_id).collect::<Vec<_>>())
+        .bind(accruals.iter().map(|a| a.bonus_interest).collect::<Vec<_>>())
+        .bind(accruals.iter().map(|a| a.created_at).collect::<Vec<_>>())
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let (account_ids, amounts): (Vec<Uuid>, Vec<Decimal>) = accrued.into_iter().unzip();
+        sqlx::query(
+            r#"
+            UPDATE accounts
+            SET accrued_interest = accounts.accrued_interest + accrual.daily_interest,
+                last_updated_at = NOW()
+            FROM UNNEST($1::uuid[], $2::numeric[]) AS accrual(account_id, daily_interest)
+            WHERE accounts.id = accrual.account_id
+            "#,
+        )
+        .bind(&account_ids)
+        .bind(&amounts)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(account_ids)
+    }
+
     async fn update_last_activity_date(&self, account_id: Uuid, activity_date: NaiveDate) -> BankingResult<()> {
         sqlx::query(
             r#"
@@ -992,24 +1424,85 @@ impl AccountRepository for AccountRepositoryImpl {
     }
 
     async fn create_final_settlement(&self, settlement: AccountFinalSettlementModel) -> BankingResult<AccountFinalSettlementModel> {
-        // This is a conceptual operation. The AccountFinalSettlementModel is not directly stored.
-        // We would typically calculate this on the fly.
-        // For the purpose of this repository, we will return the input.
-        Ok(settlement)
+        let result = sqlx::query(
+            r#"
+            INSERT INTO account_final_settlements (
+                id, account_id, settlement_date, current_balance, accrued_interest, pending_fees, closure_fees,
+                final_amount, disbursement_method, disbursement_reference, status, processed_by_person_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9::disbursement_method, $10, $11::settlement_status, $12)
+            RETURNING id, account_id, settlement_date, current_balance, accrued_interest, pending_fees, closure_fees,
+                      final_amount, disbursement_method::text as disbursement_method, disbursement_reference,
+                      status::text as status, processed_by_person_id, created_at
+            "#,
+        )
+        .bind(settlement.id)
+        .bind(settlement.account_id)
+        .bind(settlement.settlement_date)
+        .bind(settlement.current_balance)
+        .bind(settlement.accrued_interest)
+        .bind(settlement.pending_fees)
+        .bind(settlement.closure_fees)
+        .bind(settlement.final_amount)
+        .bind(settlement.disbursement_method)
+        .bind(settlement.disbursement_reference.as_ref().map(|s| s.as_str()))
+        .bind(settlement.status)
+        .bind(settlement.processed_by_person_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        AccountFinalSettlementModel::try_from_row(&result)
     }
-    
-    #[allow(unused_variables)]
+
     async fn find_settlement_by_account(&self, account_id: Uuid) -> BankingResult<Option<AccountFinalSettlementModel>> {
-        // This is a conceptual operation. The AccountFinalSettlementModel is not directly stored.
-        // We would typically calculate this on the fly.
-        // Returning None as there is no table to query from.
-        Ok(None)
+        let row = sqlx::query(
+            r#"
+            SELECT id, account_id, settlement_date, current_balance, accrued_interest, pending_fees, closure_fees,
+                   final_amount, disbursement_method::text as disbursement_method, disbursement_reference,
+                   status::text as status, processed_by_person_id, created_at
+            FROM account_final_settlements
+            WHERE account_id = $1
+            "#,
+        )
+        .bind(account_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|row| AccountFinalSettlementModel::try_from_row(&row)).transpose()
     }
 
-    async fn update_settlement_status(&self, _settlement_id: Uuid, _status: &str) -> BankingResult<()> {
-        // The final_settlements table does not have a status field.
-        // This might need a schema change or a different logic.
-        // For now, we do nothing.
+    async fn update_settlement_status(&self, settlement_id: Uuid, status: &str) -> BankingResult<()> {
+        let status = DbSettlementStatus::from_str(status).map_err(|_| BankingError::ValidationError {
+            field: "settlement_status".to_string(),
+            message: format!("Invalid settlement status: {status}"),
+        })?;
+        let mut tx = self.pool.begin().await?;
+
+        let current: String = sqlx::query_scalar(
+            "SELECT status::text FROM account_final_settlements WHERE id = $1 FOR UPDATE",
+        )
+        .bind(settlement_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| BankingError::NotFound(format!("Final settlement {settlement_id} not found")))?;
+        let current = DbSettlementStatus::from_str(&current)
+            .map_err(|_| BankingError::Internal(format!("Failed to parse settlement status {current}")))?;
+        if !current.can_advance_to(status) {
+            return Err(BankingError::ValidationError {
+                field: "settlement_status".to_string(),
+                message: format!("Settlement {settlement_id} cannot move from {current} to {status}"),
+            });
+        }
+
+        sqlx::query(
+            "UPDATE account_final_settlements SET status = $2::settlement_status, updated_at = NOW() WHERE id = $1",
+        )
+        .bind(settlement_id)
+        .bind(status)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
         Ok(())
     }
 
@@ -1063,6 +1556,210 @@ impl AccountRepository for AccountRepositoryImpl {
         AccountStatusChangeRecordModel::try_from_row(&result)
     }
 
+    async fn save_balance_snapshot(&self, snapshot: AccountBalanceSnapshotModel) -> BankingResult<AccountBalanceSnapshotModel> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO account_balance_snapshots (
+                id, account_id, snapshot_date, closing_balance, consecutive_overdrawn_days,
+                provisioning_bucket, previous_provisioning_bucket, bucket_transition
+            )
+            VALUES ($1, $2, $3, $4, $5, $6::provisioning_bucket, $7::provisioning_bucket, $8)
+            ON CONFLICT (account_id, snapshot_date) DO UPDATE SET
+                closing_balance = EXCLUDED.closing_balance,
+                consecutive_overdrawn_days = EXCLUDED.consecutive_overdrawn_days,
+                provisioning_bucket = EXCLUDED.provisioning_bucket,
+                previous_provisioning_bucket = EXCLUDED.previous_provisioning_bucket,
+                bucket_transition = EXCLUDED.bucket_transition
+            RETURNING id, account_id, snapshot_date, closing_balance, consecutive_overdrawn_days,
+                      provisioning_bucket::text as provisioning_bucket,
+                      previous_provisioning_bucket::text as previous_provisioning_bucket,
+                      bucket_transition, created_at
+            "#,
+        )
+        .bind(snapshot.id)
+        .bind(snapshot.account_id)
+        .bind(snapshot.snapshot_date)
+        .bind(snapshot.closing_balance)
+        .bind(snapshot.consecutive_overdrawn_days)
+        .bind(snapshot.provisioning_bucket)
+        .bind(snapshot.previous_provisioning_bucket)
+        .bind(snapshot.bucket_transition)
+        .fetch_one(&self.pool)
+        .await?;
+
+        AccountBalanceSnapshotModel::try_from_row(&result)
+    }
+
+    async fn find_latest_balance_snapshot_before(&self, account_id: Uuid, before_date: NaiveDate) -> BankingResult<Option<AccountBalanceSnapshotModel>> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, account_id, snapshot_date, closing_balance, consecutive_overdrawn_days,
+                   provisioning_bucket::text as provisioning_bucket,
+                   previous_provisioning_bucket::text as previous_provisioning_bucket,
+                   bucket_transition, created_at
+            FROM account_balance_snapshots
+            WHERE account_id = $1 AND snapshot_date < $2
+            ORDER BY snapshot_date DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(account_id)
+        .bind(before_date)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(row) => Ok(Some(AccountBalanceSnapshotModel::try_from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_balance_snapshots_by_date(&self, snapshot_date: NaiveDate) -> BankingResult<Vec<AccountBalanceSnapshotModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, account_id, snapshot_date, closing_balance, consecutive_overdrawn_days,
+                   provisioning_bucket::text as provisioning_bucket,
+                   previous_provisioning_bucket::text as previous_provisioning_bucket,
+                   bucket_transition, created_at
+            FROM account_balance_snapshots
+            WHERE snapshot_date = $1
+            "#,
+        )
+        .bind(snapshot_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut snapshots = Vec::new();
+        for row in rows {
+            snapshots.push(AccountBalanceSnapshotModel::try_from_row(&row)?);
+        }
+        Ok(snapshots)
+    }
+
+
+    async fn save_loan_penalty_accrual(&self, accrual: LoanPenaltyAccrualModel) -> BankingResult<LoanPenaltyAccrualModel> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO loan_penalty_accruals (
+                id, loan_account_id, assessment_date, due_date, days_past_due,
+                overdue_amount, penalty_rate, penalty_days, penalty_amount
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (loan_account_id, assessment_date) DO UPDATE SET
+                due_date = EXCLUDED.due_date,
+                days_past_due = EXCLUDED.days_past_due,
+                overdue_amount = EXCLUDED.overdue_amount,
+                penalty_rate = EXCLUDED.penalty_rate,
+                penalty_days = EXCLUDED.penalty_days,
+                penalty_amount = EXCLUDED.penalty_amount
+            RETURNING id, loan_account_id, assessment_date, due_date, days_past_due,
+                      overdue_amount, penalty_rate, penalty_days, penalty_amount, created_at
+            "#,
+        )
+        .bind(accrual.id)
+        .bind(accrual.loan_account_id)
+        .bind(accrual.assessment_date)
+        .bind(accrual.due_date)
+        .bind(accrual.days_past_due)
+        .bind(accrual.overdue_amount)
+        .bind(accrual.penalty_rate)
+        .bind(accrual.penalty_days)
+        .bind(accrual.penalty_amount)
+        .fetch_one(&self.pool)
+        .await?;
+
+        LoanPenaltyAccrualModel::try_from_row(&result)
+    }
+
+    async fn find_latest_loan_penalty_accrual_before(&self, loan_account_id: Uuid, before_date: NaiveDate) -> BankingResult<Option<LoanPenaltyAccrualModel>> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, loan_account_id, assessment_date, due_date, days_past_due,
+                   overdue_amount, penalty_rate, penalty_days, penalty_amount, created_at
+            FROM loan_penalty_accruals
+            WHERE loan_account_id = $1 AND assessment_date < $2
+            ORDER BY assessment_date DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(loan_account_id)
+        .bind(before_date)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(row) => Ok(Some(LoanPenaltyAccrualModel::try_from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+
+    async fn save_overdraft_interest_accrual(&self, accrual: OverdraftInterestAccrualModel) -> BankingResult<OverdraftInterestAccrualModel> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO overdraft_interest_accruals (
+                id, account_id, accrual_date, position,
+                authorized_amount, authorized_rate, authorized_interest,
+                unauthorized_amount, unauthorized_rate, unauthorized_interest,
+                consecutive_unauthorized_days
+            )
+            VALUES ($1, $2, $3, $4::overdraft_position, $5, $6, $7, $8, $9, $10, $11)
+            ON CONFLICT (account_id, accrual_date) DO UPDATE SET
+                position = EXCLUDED.position,
+                authorized_amount = EXCLUDED.authorized_amount,
+                authorized_rate = EXCLUDED.authorized_rate,
+                authorized_interest = EXCLUDED.authorized_interest,
+                unauthorized_amount = EXCLUDED.unauthorized_amount,
+                unauthorized_rate = EXCLUDED.unauthorized_rate,
+                unauthorized_interest = EXCLUDED.unauthorized_interest,
+                consecutive_unauthorized_days = EXCLUDED.consecutive_unauthorized_days
+            RETURNING id, account_id, accrual_date, position::text as position,
+                      authorized_amount, authorized_rate, authorized_interest,
+                      unauthorized_amount, unauthorized_rate, unauthorized_interest,
+                      consecutive_unauthorized_days, created_at
+            "#,
+        )
+        .bind(accrual.id)
+        .bind(accrual.account_id)
+        .bind(accrual.accrual_date)
+        .bind(accrual.position)
+        .bind(accrual.authorized_amount)
+        .bind(accrual.authorized_rate)
+        .bind(accrual.authorized_interest)
+        .bind(accrual.unauthorized_amount)
+        .bind(accrual.unauthorized_rate)
+        .bind(accrual.unauthorized_interest)
+        .bind(accrual.consecutive_unauthorized_days)
+        .fetch_one(&self.pool)
+        .await?;
+
+        OverdraftInterestAccrualModel::try_from_row(&result)
+    }
+
+    async fn find_latest_overdraft_interest_accrual_before(&self, account_id: Uuid, before_date: NaiveDate) -> BankingResult<Option<OverdraftInterestAccrualModel>> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, account_id, accrual_date, position::text as position,
+                   authorized_amount, authorized_rate, authorized_interest,
+                   unauthorized_amount, unauthorized_rate, unauthorized_interest,
+                   consecutive_unauthorized_days, created_at
+            FROM overdraft_interest_accruals
+            WHERE account_id = $1 AND accrual_date < $2
+            ORDER BY accrual_date DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(account_id)
+        .bind(before_date)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(row) => Ok(Some(OverdraftInterestAccrualModel::try_from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
     async fn exists(&self, account_id: Uuid) -> BankingResult<bool> {
         let result: (bool,) = sqlx::query_as("SELECT EXISTS(SELECT 1 FROM accounts WHERE id = $1)")
             .bind(account_id)
@@ -1071,6 +1768,58 @@ impl AccountRepository for AccountRepositoryImpl {
         Ok(result.0)
     }
 
+    async fn find_by_ids(&self, account_ids: &[Uuid]) -> BankingResult<Vec<AccountModel>> {
+        // Dedupe so an id repeated across chunks is not returned twice
+        let mut seen = HashSet::with_capacity(account_ids.len());
+        let unique_ids: Vec<Uuid> = account_ids.iter().copied().filter(|id| seen.insert(*id)).collect();
+        let mut accounts = Vec::with_capacity(unique_ids.len());
+        for chunk in unique_ids.chunks(MAX_IDS_PER_QUERY) {
+            let rows = sqlx::query(
+                r#"
+                SELECT id, product_id, account_type::text as account_type,
+                       account_status::text as account_status, signing_condition::text as signing_condition,
+                       currency, open_date, domicile_agency_branch_id, account_number, gl_code_suffix, current_balance, available_balance,
+                       accrued_interest, overdraft_limit, original_principal, outstanding_principal,
+                       loan_interest_rate, loan_term_months, disbursement_date, maturity_date,
+                       installment_amount, next_due_date, penalty_rate, collateral_id, loan_purpose_id,
+                       close_date, last_activity_date, dormancy_threshold_days, reactivation_required,
+                       pending_closure_reason_id, last_disbursement_instruction_id, status_changed_by_person_id,
+                       status_change_reason_id, status_change_timestamp,
+                       most_significant_account_hold_id, account_ownership_id,
+                       access01_account_relationship_id, access02_account_relationship_id, access03_account_relationship_id,
+                       access04_account_relationship_id, access05_account_relationship_id, access06_account_relationship_id,
+                       access07_account_relationship_id, access11_account_mandate_id, access12_account_mandate_id,
+                       access13_account_mandate_id, access14_account_mandate_id, access15_account_mandate_id,
+                       access16_account_mandate_id, access17_account_mandate_id, interest01_ultimate_beneficiary_id,
+                       interest02_ultimate_beneficiary_id, interest03_ultimate_beneficiary_id, interest04_ultimate_beneficiary_id,
+                       interest05_ultimate_beneficiary_id, interest06_ultimate_beneficiary_id, interest07_ultimate_beneficiary_id,
+                       created_at, last_updated_at, updated_by_person_id, version
+                FROM accounts WHERE id = ANY($1)
+                "#,
+            )
+            .bind(chunk)
+            .fetch_all(&self.pool)
+            .await?;
+
+            for row in rows {
+                accounts.push(AccountModel::try_from_row(&row)?);
+            }
+        }
+        Ok(accounts)
+    }
+
+    async fn exist_by_ids(&self, account_ids: &[Uuid]) -> BankingResult<Vec<(Uuid, bool)>> {
+        let mut existing = HashSet::with_capacity(account_ids.len());
+        for chunk in account_ids.chunks(MAX_IDS_PER_QUERY) {
+            let found: Vec<(Uuid,)> = sqlx::query_as("SELECT id FROM accounts WHERE id = ANY($1)")
+                .bind(chunk)
+                .fetch_all(&self.pool)
+                .await?;
+            existing.extend(found.into_iter().map(|(id,)| id));
+        }
+        Ok(account_ids.iter().map(|id| (*id, existing.contains(id))).collect())
+    }
+
     async fn count_by_customer(&self, customer_id: Uuid) -> BankingResult<i64> {
         let result: (i64,) = sqlx::query_as(
             "SELECT COUNT(*) FROM account_ownership WHERE customer_id = $1",
@@ -1094,7 +1843,7 @@ impl AccountRepository for AccountRepositoryImpl {
             r#"
             SELECT id, product_id, account_type::text as account_type,
                    account_status::text as account_status, signing_condition::text as signing_condition,
-                   currency, open_date, domicile_agency_branch_id, gl_code_suffix, current_balance, available_balance,
+                   currency, open_date, domicile_agency_branch_id, account_number, gl_code_suffix, current_balance, available_balance,
                    accrued_interest, overdraft_limit, original_principal, outstanding_principal,
                    loan_interest_rate, loan_term_months, disbursement_date, maturity_date,
                    installment_amount, next_due_date, penalty_rate, collateral_id, loan_purpose_id,
@@ -1109,7 +1858,7 @@ impl AccountRepository for AccountRepositoryImpl {
                    access16_account_mandate_id, access17_account_mandate_id, interest01_ultimate_beneficiary_id,
                    interest02_ultimate_beneficiary_id, interest03_ultimate_beneficiary_id, interest04_ultimate_beneficiary_id,
                    interest05_ultimate_beneficiary_id, interest06_ultimate_beneficiary_id, interest07_ultimate_beneficiary_id,
-                   created_at, last_updated_at, updated_by_person_id
+                   created_at, last_updated_at, updated_by_person_id, version
             FROM accounts
             ORDER BY created_at DESC
             OFFSET $1 LIMIT $2
@@ -1127,6 +1876,45 @@ impl AccountRepository for AccountRepositoryImpl {
         Ok(accounts)
     }
 
+    async fn list_after(&self, after_account_id: Option<Uuid>, limit: i64) -> BankingResult<Vec<AccountModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, product_id, account_type::text as account_type,
+                   account_status::text as account_status, signing_condition::text as signing_condition,
+                   currency, open_date, domicile_agency_branch_id, account_number, gl_code_suffix, current_balance, available_balance,
+                   accrued_interest, overdraft_limit, original_principal, outstanding_principal,
+                   loan_interest_rate, loan_term_months, disbursement_date, maturity_date,
+                   installment_amount, next_due_date, penalty_rate, collateral_id, loan_purpose_id,
+                   close_date, last_activity_date, dormancy_threshold_days, reactivation_required,
+                   pending_closure_reason_id, last_disbursement_instruction_id, status_changed_by_person_id,
+                   status_change_reason_id, status_change_timestamp,
+                   most_significant_account_hold_id, account_ownership_id,
+                   access01_account_relationship_id, access02_account_relationship_id, access03_account_relationship_id,
+                   access04_account_relationship_id, access05_account_relationship_id, access06_account_relationship_id,
+                   access07_account_relationship_id, access11_account_mandate_id, access12_account_mandate_id,
+                   access13_account_mandate_id, access14_account_mandate_id, access15_account_mandate_id,
+                   access16_account_mandate_id, access17_account_mandate_id, interest01_ultimate_beneficiary_id,
+                   interest02_ultimate_beneficiary_id, interest03_ultimate_beneficiary_id, interest04_ultimate_beneficiary_id,
+                   interest05_ultimate_beneficiary_id, interest06_ultimate_beneficiary_id, interest07_ultimate_beneficiary_id,
+                   created_at, last_updated_at, updated_by_person_id, version
+            FROM accounts
+            WHERE $1::uuid IS NULL OR id > $1
+            ORDER BY id
+            LIMIT $2
+            "#
+        )
+        .bind(after_account_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut accounts = Vec::new();
+        for row in rows {
+            accounts.push(AccountModel::try_from_row(&row)?);
+        }
+        Ok(accounts)
+    }
+
     async fn count(&self) -> BankingResult<i64> {
         let result: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM accounts")
             .fetch_one(&self.pool)
@@ -1143,16 +1931,95 @@ impl TryFromRow<PgRow> for AccountFinalSettlementModel {
             settlement_date: row.get("settlement_date"),
             current_balance: row.get("current_balance"),
             accrued_interest: row.get("accrued_interest"),
+            pending_fees: row.get("pending_fees"),
             closure_fees: row.get("closure_fees"),
             final_amount: row.get("final_amount"),
             disbursement_method: row.get::<String, _>("disbursement_method").parse().map_err(|_| BankingError::Internal("Failed to parse disbursement_method".into()))?,
             disbursement_reference: row.get::<Option<String>, _>("disbursement_reference").map(|s| s.parse().unwrap()),
+            status: row.get::<String, _>("status").parse().map_err(|_| BankingError::Internal("Failed to parse settlement status".into()))?,
             processed_by_person_id: row.get("processed_by_person_id"),
             created_at: row.get("created_at"),
         })
     }
 }
 
+impl TryFromRow<PgRow> for AccountBalanceSnapshotModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        let previous_bucket_str: Option<String> = row.get("previous_provisioning_bucket");
+        let previous_provisioning_bucket = previous_bucket_str
+            .map(|s| s.parse().map_err(|_| BankingError::Internal("Failed to parse previous_provisioning_bucket".into())))
+            .transpose()?;
+
+        Ok(AccountBalanceSnapshotModel {
+            id: row.get("id"),
+            account_id: row.get("account_id"),
+            snapshot_date: row.get("snapshot_date"),
+            closing_balance: row.get("closing_balance"),
+            consecutive_overdrawn_days: row.get("consecutive_overdrawn_days"),
+            provisioning_bucket: row.get::<String, _>("provisioning_bucket").parse().map_err(|_| BankingError::Internal("Failed to parse provisioning_bucket".into()))?,
+            previous_provisioning_bucket,
+            bucket_transition: row.get("bucket_transition"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
+
+
+impl TryFromRow<PgRow> for LoanPenaltyAccrualModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(LoanPenaltyAccrualModel {
+            id: row.get("id"),
+            loan_account_id: row.get("loan_account_id"),
+            assessment_date: row.get("assessment_date"),
+            due_date: row.get("due_date"),
+            days_past_due: row.get("days_past_due"),
+            overdue_amount: row.get("overdue_amount"),
+            penalty_rate: row.get("penalty_rate"),
+            penalty_days: row.get("penalty_days"),
+            penalty_amount: row.get("penalty_amount"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
+
+
+impl TryFromRow<PgRow> for OverdraftInterestAccrualModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(OverdraftInterestAccrualModel {
+            id: row.get("id"),
+            account_id: row.get("account_id"),
+            accrual_date: row.get("accrual_date"),
+            position: row.get::<String, _>("position").parse().map_err(|_| BankingError::Internal("Failed to parse position".into()))?,
+            authorized_amount: row.get("authorized_amount"),
+            authorized_rate: row.get("authorized_rate"),
+            authorized_interest: row.get("authorized_interest"),
+            unauthorized_amount: row.get("unauthorized_amount"),
+            unauthorized_rate: row.get("unauthorized_rate"),
+            unauthorized_interest: row.get("unauthorized_interest"),
+            consecutive_unauthorized_days: row.get("consecutive_unauthorized_days"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
+
+
+impl TryFromRow<PgRow> for AccountBalanceChangeRecordModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(AccountBalanceChangeRecordModel {
+            id: row.get("id"),
+            account_id: row.get("account_id"),
+            old_current_balance: row.get("old_current_balance"),
+            new_current_balance: row.get("new_current_balance"),
+            old_available_balance: row.get("old_available_balance"),
+            new_available_balance: row.get("new_available_balance"),
+            change_source: row.get::<String, _>("change_source").parse().map_err(|_| BankingError::Internal("Failed to parse change_source".into()))?,
+            transaction_id: row.get("transaction_id"),
+            changed_at: row.get("changed_at"),
+            changed_by_person_id: row.get("changed_by_person_id"),
+        })
+    }
+}
+
 impl TryFromRow<PgRow> for AccountStatusChangeRecordModel {
     fn try_from_row(row: &PgRow) -> BankingResult<Self> {
         let old_status_str: Option<String> = row.get("old_status");
@@ -1183,9 +2050,11 @@ impl TryFromRow<PgRow> for AccountModel {
             account_type: row.get::<String, _>("account_type").parse().map_err(|_| BankingError::Internal("Failed to parse account_type".into()))?,
             account_status: row.get::<String, _>("account_status").parse().map_err(|_| BankingError::Internal("Failed to parse account_status".into()))?,
             signing_condition: row.get::<String, _>("signing_condition").parse().map_err(|_| BankingError::Internal("Failed to parse signing_condition".into()))?,
-            currency: row.get::<String, _>("currency").parse().unwrap(),
+            currency: row.get::<String, _>("currency").parse().map_err(|_| BankingError::Internal("Failed to parse currency".into()))?,
             open_date: row.get("open_date"),
             domicile_agency_branch_id: row.get("domicile_agency_branch_id"),
+            account_number: HeaplessString::try_from(row.get::<&str, _>("account_number"))
+                .map_err(|_| BankingError::Internal("Account number too long".into()))?,
             current_balance: row.get("current_balance"),
             available_balance: row.get("available_balance"),
             accrued_interest: row.get("accrued_interest"),
@@ -1236,6 +2105,7 @@ impl TryFromRow<PgRow> for AccountModel {
             created_at: row.get("created_at"),
             last_updated_at: row.get("last_updated_at"),
             updated_by_person_id: row.get("updated_by_person_id"),
+            version: row.get("version"),
             gl_code_suffix: row.get::<Option<String>, _>("gl_code_suffix").map(|s| s.parse().unwrap()),
         })
     }
@@ -0,0 +1,435 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{
+    AgentCollectionTotalsModel, AgentCommissionLineItemModel, AgentCommissionSchemeModel,
+    AgentCommissionStatementModel, DbCommissionLineType, DbCommissionStatementStatus,
+};
+use banking_db::repository::AgentCommissionRepository;
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// PostgreSQL implementation of AgentCommissionRepository
+pub struct AgentCommissionRepositoryImpl {
+    pool: PgPool,
+}
+
+impl AgentCommissionRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+fn heapless<const N: usize>(value: String, field: &str) -> BankingResult<HeaplessString<N>> {
+    HeaplessString::try_from(value.as_str()).map_err(|_| BankingError::ValidationError {
+        field: field.to_string(),
+        message: format!("{field} too long"),
+    })
+}
+
+impl TryFromRow<PgRow> for AgentCommissionSchemeModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(AgentCommissionSchemeModel {
+            id: row.get("id"),
+            code: heapless(row.get("code"), "code")?,
+            collection_program_id: row.get("collection_program_id"),
+            territory_id: row.get("territory_id"),
+            commission_rate: row.get("commission_rate"),
+            target_collection_rate: row.get("target_collection_rate"),
+            bonus_amount: row.get("bonus_amount"),
+            deduction_per_discrepancy: row.get("deduction_per_discrepancy"),
+            is_active: row.get("is_active"),
+            created_at: row.get("created_at"),
+            last_updated_at: row.get("last_updated_at"),
+            updated_by_person_id: row.get("updated_by_person_id"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for AgentCommissionStatementModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(AgentCommissionStatementModel {
+            id: row.get("id"),
+            collection_agent_id: row.get("collection_agent_id"),
+            period_start: row.get("period_start"),
+            period_end: row.get("period_end"),
+            collected_amount: row.get("collected_amount"),
+            commission_amount: row.get("commission_amount"),
+            bonus_amount: row.get("bonus_amount"),
+            deduction_amount: row.get("deduction_amount"),
+            net_amount: row.get("net_amount"),
+            discrepancy_count: row.get("discrepancy_count"),
+            status: row.get::<String, _>("status")
+                .parse::<DbCommissionStatementStatus>()
+                .map_err(|_| BankingError::Internal("Invalid commission statement status".to_string()))?,
+            calculated_at: row.get("calculated_at"),
+            invoiced_at: row.get("invoiced_at"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for AgentCommissionLineItemModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(AgentCommissionLineItemModel {
+            id: row.get("id"),
+            statement_id: row.get("statement_id"),
+            line_type: row.get::<String, _>("line_type")
+                .parse::<DbCommissionLineType>()
+                .map_err(|_| BankingError::Internal("Invalid commission line type".to_string()))?,
+            scheme_id: row.get("scheme_id"),
+            collection_program_id: row.get("collection_program_id"),
+            description: heapless(row.get("description"), "description")?,
+            basis_amount: row.get("basis_amount"),
+            amount: row.get("amount"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for AgentCollectionTotalsModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(AgentCollectionTotalsModel {
+            collection_agent_id: row.get("collection_agent_id"),
+            territory_id: row.get("territory_id"),
+            collection_program_id: row.get("collection_program_id"),
+            processed_count: row.get("processed_count"),
+            processed_amount: row.get("processed_amount"),
+            attempted_count: row.get("attempted_count"),
+        })
+    }
+}
+
+const SCHEME_COLUMNS: &str = r#"
+    id, code, collection_program_id, territory_id, commission_rate, target_collection_rate,
+    bonus_amount, deduction_per_discrepancy, is_active, created_at, last_updated_at, updated_by_person_id
+"#;
+
+const STATEMENT_COLUMNS: &str = r#"
+    id, collection_agent_id, period_start, period_end, collected_amount, commission_amount,
+    bonus_amount, deduction_amount, net_amount, discrepancy_count, status::text as status,
+    calculated_at, invoiced_at
+"#;
+
+const LINE_ITEM_COLUMNS: &str = r#"
+    id, statement_id, line_type::text as line_type, scheme_id, collection_program_id, description,
+    basis_amount, amount
+"#;
+
+#[async_trait]
+impl AgentCommissionRepository for AgentCommissionRepositoryImpl {
+    async fn create_scheme(&self, scheme: AgentCommissionSchemeModel) -> BankingResult<AgentCommissionSchemeModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO agent_commission_schemes (
+                id, code, collection_program_id, territory_id, commission_rate, target_collection_rate,
+                bonus_amount, deduction_per_discrepancy, is_active, created_at, last_updated_at, updated_by_person_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            RETURNING {SCHEME_COLUMNS}
+            "#
+        ))
+        .bind(scheme.id)
+        .bind(scheme.code.as_str())
+        .bind(scheme.collection_program_id)
+        .bind(scheme.territory_id)
+        .bind(scheme.commission_rate)
+        .bind(scheme.target_collection_rate)
+        .bind(scheme.bonus_amount)
+        .bind(scheme.deduction_per_discrepancy)
+        .bind(scheme.is_active)
+        .bind(scheme.created_at)
+        .bind(scheme.last_updated_at)
+        .bind(scheme.updated_by_person_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create commission scheme: {e}")))?;
+
+        AgentCommissionSchemeModel::try_from_row(&row)
+    }
+
+    async fn update_scheme(&self, scheme: AgentCommissionSchemeModel) -> BankingResult<AgentCommissionSchemeModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE agent_commission_schemes
+            SET code = $2, collection_program_id = $3, territory_id = $4, commission_rate = $5,
+                target_collection_rate = $6, bonus_amount = $7, deduction_per_discrepancy = $8,
+                is_active = $9, last_updated_at = $10, updated_by_person_id = $11
+            WHERE id = $1
+            RETURNING {SCHEME_COLUMNS}
+            "#
+        ))
+        .bind(scheme.id)
+        .bind(scheme.code.as_str())
+        .bind(scheme.collection_program_id)
+        .bind(scheme.territory_id)
+        .bind(scheme.commission_rate)
+        .bind(scheme.target_collection_rate)
+        .bind(scheme.bonus_amount)
+        .bind(scheme.deduction_per_discrepancy)
+        .bind(scheme.is_active)
+        .bind(scheme.last_updated_at)
+        .bind(scheme.updated_by_person_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update commission scheme: {e}")))?
+        .ok_or_else(|| BankingError::NotFound(format!("Commission scheme {} not found", scheme.id)))?;
+
+        AgentCommissionSchemeModel::try_from_row(&row)
+    }
+
+    async fn find_scheme_by_id(&self, scheme_id: Uuid) -> BankingResult<Option<AgentCommissionSchemeModel>> {
+        let row = sqlx::query(&format!("SELECT {SCHEME_COLUMNS} FROM agent_commission_schemes WHERE id = $1"))
+            .bind(scheme_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find commission scheme: {e}")))?;
+
+        row.as_ref().map(AgentCommissionSchemeModel::try_from_row).transpose()
+    }
+
+    async fn find_active_schemes(&self) -> BankingResult<Vec<AgentCommissionSchemeModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {SCHEME_COLUMNS} FROM agent_commission_schemes WHERE is_active ORDER BY code"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find commission schemes: {e}")))?;
+
+        rows.iter().map(AgentCommissionSchemeModel::try_from_row).collect()
+    }
+
+    async fn aggregate_agent_collections(&self, from: NaiveDate, to: NaiveDate) -> BankingResult<Vec<AgentCollectionTotalsModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT cr.collection_agent_id, ca.assigned_territory_id as territory_id, cr.collection_program_id,
+                   COUNT(*) FILTER (WHERE cr.status = 'Processed') as processed_count,
+                   COALESCE(SUM(cr.amount) FILTER (WHERE cr.status = 'Processed'), 0) as processed_amount,
+                   COUNT(*) FILTER (WHERE cr.status <> 'Reversed') as attempted_count
+            FROM collection_records cr
+            JOIN collection_agents ca ON ca.id = cr.collection_agent_id
+            WHERE cr.collection_date BETWEEN $1 AND $2
+            GROUP BY cr.collection_agent_id, ca.assigned_territory_id, cr.collection_program_id
+            HAVING COUNT(*) FILTER (WHERE cr.status = 'Processed') > 0
+            ORDER BY cr.collection_agent_id, cr.collection_program_id
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to aggregate agent collections: {e}")))?;
+
+        rows.iter().map(AgentCollectionTotalsModel::try_from_row).collect()
+    }
+
+    async fn count_cash_discrepancy_alerts(&self, from: NaiveDate, to: NaiveDate) -> BankingResult<HashMap<Uuid, i64>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT ca.id as collection_agent_id, COUNT(*) as discrepancy_count
+            FROM performance_alerts pa
+            JOIN collection_agents ca ON ca.agent_performance_metrics_id = pa.agent_performance_metrics_id
+            WHERE pa.alert_type = 'CashDiscrepancy' AND pa.created_at::date BETWEEN $1 AND $2
+            GROUP BY ca.id
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to count cash discrepancy alerts: {e}")))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| (row.get("collection_agent_id"), row.get("discrepancy_count")))
+            .collect())
+    }
+
+    async fn find_statements_for_period(&self, period_start: NaiveDate) -> BankingResult<Vec<AgentCommissionStatementModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {STATEMENT_COLUMNS} FROM agent_commission_statements WHERE period_start = $1 ORDER BY collection_agent_id"
+        ))
+        .bind(period_start)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find commission statements: {e}")))?;
+
+        rows.iter().map(AgentCommissionStatementModel::try_from_row).collect()
+    }
+
+    async fn replace_period_statements(
+        &self,
+        period_start: NaiveDate,
+        statements: Vec<AgentCommissionStatementModel>,
+        line_items: Vec<AgentCommissionLineItemModel>,
+    ) -> BankingResult<()> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| BankingError::Internal(format!("Failed to start transaction: {e}")))?;
+
+        // Lock the period so a concurrent invoicing cannot slip in between check and delete
+        let invoiced: Option<Uuid> = sqlx::query_scalar(
+            r#"
+            SELECT id FROM agent_commission_statements
+            WHERE period_start = $1 AND status = 'Invoiced'
+            LIMIT 1
+            FOR UPDATE
+            "#,
+        )
+        .bind(period_start)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to check invoiced commission statements: {e}")))?;
+        if invoiced.is_some() {
+            return Err(BankingError::ValidationError {
+                field: "period".to_string(),
+                message: format!("Commissions for the period starting {period_start} are invoiced"),
+            });
+        }
+
+        sqlx::query(
+            r#"
+            DELETE FROM agent_commission_line_items
+            WHERE statement_id IN (
+                SELECT id FROM agent_commission_statements WHERE period_start = $1 AND status = 'Draft'
+            )
+            "#,
+        )
+        .bind(period_start)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to delete commission line items: {e}")))?;
+        sqlx::query("DELETE FROM agent_commission_statements WHERE period_start = $1 AND status = 'Draft'")
+            .bind(period_start)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to delete commission statements: {e}")))?;
+
+        for statement in statements {
+            sqlx::query(
+                r#"
+                INSERT INTO agent_commission_statements (
+                    id, collection_agent_id, period_start, period_end, collected_amount, commission_amount,
+                    bonus_amount, deduction_amount, net_amount, discrepancy_count, status, calculated_at, invoiced_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11::commission_statement_status, $12, $13)
+                "#,
+            )
+            .bind(statement.id)
+            .bind(statement.collection_agent_id)
+            .bind(statement.period_start)
+            .bind(statement.period_end)
+            .bind(statement.collected_amount)
+            .bind(statement.commission_amount)
+            .bind(statement.bonus_amount)
+            .bind(statement.deduction_amount)
+            .bind(statement.net_amount)
+            .bind(statement.discrepancy_count)
+            .bind(statement.status)
+            .bind(statement.calculated_at)
+            .bind(statement.invoiced_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to create commission statement: {e}")))?;
+        }
+
+        for line_item in line_items {
+            sqlx::query(
+                r#"
+                INSERT INTO agent_commission_line_items (
+                    id, statement_id, line_type, scheme_id, collection_program_id, description, basis_amount, amount
+                )
+                VALUES ($1, $2, $3::commission_line_type, $4, $5, $6, $7, $8)
+                "#,
+            )
+            .bind(line_item.id)
+            .bind(line_item.statement_id)
+            .bind(line_item.line_type)
+            .bind(line_item.scheme_id)
+            .bind(line_item.collection_program_id)
+            .bind(line_item.description.as_str())
+            .bind(line_item.basis_amount)
+            .bind(line_item.amount)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to create commission line item: {e}")))?;
+        }
+
+        tx.commit().await
+            .map_err(|e| BankingError::Internal(format!("Failed to commit commission statements: {e}")))
+    }
+
+    async fn find_statement_by_id(&self, statement_id: Uuid) -> BankingResult<Option<AgentCommissionStatementModel>> {
+        let row = sqlx::query(&format!("SELECT {STATEMENT_COLUMNS} FROM agent_commission_statements WHERE id = $1"))
+            .bind(statement_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find commission statement: {e}")))?;
+
+        row.as_ref().map(AgentCommissionStatementModel::try_from_row).transpose()
+    }
+
+    async fn find_statements_by_agent(&self, collection_agent_id: Uuid, limit: i64) -> BankingResult<Vec<AgentCommissionStatementModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {STATEMENT_COLUMNS} FROM agent_commission_statements
+            WHERE collection_agent_id = $1
+            ORDER BY period_start DESC
+            LIMIT $2
+            "#
+        ))
+        .bind(collection_agent_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find agent commission statements: {e}")))?;
+
+        rows.iter().map(AgentCommissionStatementModel::try_from_row).collect()
+    }
+
+    async fn find_line_items(&self, statement_id: Uuid) -> BankingResult<Vec<AgentCommissionLineItemModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {LINE_ITEM_COLUMNS} FROM agent_commission_line_items
+            WHERE statement_id = $1
+            ORDER BY line_type, collection_program_id
+            "#
+        ))
+        .bind(statement_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find commission line items: {e}")))?;
+
+        rows.iter().map(AgentCommissionLineItemModel::try_from_row).collect()
+    }
+
+    async fn update_statement_status(
+        &self,
+        statement_id: Uuid,
+        status: DbCommissionStatementStatus,
+        invoiced_at: Option<DateTime<Utc>>,
+    ) -> BankingResult<AgentCommissionStatementModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE agent_commission_statements
+            SET status = $2::commission_statement_status, invoiced_at = $3
+            WHERE id = $1
+            RETURNING {STATEMENT_COLUMNS}
+            "#
+        ))
+        .bind(statement_id)
+        .bind(status)
+        .bind(invoiced_at)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update commission statement: {e}")))?
+        .ok_or_else(|| BankingError::NotFound(format!("Commission statement {statement_id} not found")))?;
+
+        AgentCommissionStatementModel::try_from_row(&row)
+    }
+}
@@ -1,11 +1,12 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::{PgPool, Row, postgres::PgRow};
 use uuid::Uuid;
 use rust_decimal::Decimal;
 use chrono::{DateTime, Utc, NaiveDate};
 
-use banking_api::BankingResult;
+use banking_api::{BankingError, BankingResult};
 use banking_db::models::{AgentNetworkModel, AgencyBranchModel, AgentTerminalModel, CashLimitCheckModel};
+use banking_db::models::agent_network::{BranchRollupModel, HierarchyNodeModel, HierarchyNodeType};
 // Remove unused simple models import
 use banking_db::repository::{AgentNetworkRepository, TerminalLimits, BranchLimits, NetworkLimits, 
     LimitValidationResult, NetworkPerformanceReport, BranchPerformanceReport, TerminalPerformanceReport,
@@ -121,6 +122,27 @@ impl AgentNetworkRepositoryImpl {
     }
 }
 
+/// Traversal rows carry `is_cycle`, set on the branch that closes a loop; the
+/// recursion stops there instead of running forever
+fn hierarchy_nodes_from_rows(rows: Vec<PgRow>) -> BankingResult<Vec<HierarchyNodeModel>> {
+    rows.iter()
+        .map(|row| {
+            let node_id: Uuid = row.get("node_id");
+            if row.get::<bool, _>("is_cycle") {
+                return Err(BankingError::HierarchyCycle { node_id });
+            }
+            Ok(HierarchyNodeModel {
+                node_id,
+                node_type: row.get::<String, _>("node_type")
+                    .parse::<HierarchyNodeType>()
+                    .map_err(BankingError::Internal)?,
+                parent_id: row.get("parent_id"),
+                depth: row.get("depth"),
+            })
+        })
+        .collect()
+}
+
 #[async_trait]
 impl AgentNetworkRepository for AgentNetworkRepositoryImpl {
     /// Agent Network Operations
@@ -210,6 +232,135 @@ impl AgentNetworkRepository for AgentNetworkRepositoryImpl {
         Ok(vec![])
     }
 
+    /// Hierarchy Traversal
+    async fn find_descendants(&self, node_id: Uuid, max_depth: i32) -> BankingResult<Vec<HierarchyNodeModel>> {
+        // Root branches hang off the network, the others off their parent branch
+        let rows = sqlx::query(
+            r#"
+            WITH RECURSIVE tree AS (
+                SELECT $1::uuid AS node_id, NULL::uuid AS parent_id, 0 AS depth,
+                       ARRAY[$1::uuid] AS path, false AS is_cycle
+              UNION ALL
+                SELECT b.id, COALESCE(b.parent_agency_branch_id, b.agent_network_id), t.depth + 1,
+                       t.path || b.id, b.id = ANY(t.path)
+                FROM agent_branches b
+                JOIN tree t ON b.parent_agency_branch_id = t.node_id
+                    OR (b.parent_agency_branch_id IS NULL AND b.agent_network_id = t.node_id)
+                WHERE NOT t.is_cycle AND t.depth < $2
+            )
+            SELECT node_id, 'Branch' AS node_type, parent_id, depth, is_cycle
+            FROM tree
+            WHERE depth > 0
+            UNION ALL
+            SELECT a.id, 'Terminal', a.agency_branch_id, t.depth + 1, false
+            FROM agent_terminals a
+            JOIN tree t ON a.agency_branch_id = t.node_id
+            WHERE NOT t.is_cycle AND t.depth < $2
+            ORDER BY depth, node_id
+            "#,
+        )
+        .bind(node_id)
+        .bind(max_depth)
+        .fetch_all(&self.pool)
+        .await?;
+
+        hierarchy_nodes_from_rows(rows)
+    }
+
+    async fn find_ancestors(&self, node_id: Uuid) -> BankingResult<Vec<HierarchyNodeModel>> {
+        // A branch starts the walk at depth 0, a terminal at its branch
+        let rows = sqlx::query(
+            r#"
+            WITH RECURSIVE chain AS (
+                SELECT b.id AS node_id, b.parent_agency_branch_id, b.agent_network_id,
+                       CASE WHEN b.id = $1 THEN 0 ELSE 1 END AS depth,
+                       ARRAY[b.id] AS path, false AS is_cycle
+                FROM agent_branches b
+                WHERE b.id = $1
+                   OR b.id = (SELECT agency_branch_id FROM agent_terminals WHERE id = $1)
+              UNION ALL
+                SELECT b.id, b.parent_agency_branch_id, b.agent_network_id, c.depth + 1,
+                       c.path || b.id, b.id = ANY(c.path)
+                FROM agent_branches b
+                JOIN chain c ON b.id = c.parent_agency_branch_id
+                WHERE NOT c.is_cycle
+            )
+            SELECT node_id, 'Branch' AS node_type,
+                   COALESCE(parent_agency_branch_id, agent_network_id) AS parent_id, depth, is_cycle
+            FROM chain
+            WHERE depth > 0
+            UNION ALL
+            SELECT n.id, 'Network', NULL, c.depth + 1, false
+            FROM chain c
+            JOIN agent_networks n ON n.id = c.agent_network_id
+            WHERE c.parent_agency_branch_id IS NULL AND NOT c.is_cycle
+            ORDER BY depth
+            "#,
+        )
+        .bind(node_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        hierarchy_nodes_from_rows(rows)
+    }
+
+    async fn find_branch_rollup(&self, agency_branch_id: Uuid) -> BankingResult<Vec<BranchRollupModel>> {
+        // Each branch's path lists the branches above it, so a subtree total sums
+        // the branches whose path goes through it
+        let rows = sqlx::query(
+            r#"
+            WITH RECURSIVE subtree AS (
+                SELECT id, parent_agency_branch_id, 0 AS depth, ARRAY[id] AS path, false AS is_cycle
+                FROM agent_branches
+                WHERE id = $1
+              UNION ALL
+                SELECT b.id, b.parent_agency_branch_id, s.depth + 1, s.path || b.id, b.id = ANY(s.path)
+                FROM agent_branches b
+                JOIN subtree s ON b.parent_agency_branch_id = s.id
+                WHERE NOT s.is_cycle
+            ),
+            own AS (
+                SELECT s.id, s.parent_agency_branch_id, s.depth, s.path, s.is_cycle,
+                       COUNT(a.id) AS account_count,
+                       COALESCE(SUM(a.current_balance), 0) AS total_balance
+                FROM subtree s
+                LEFT JOIN accounts a ON a.domicile_agency_branch_id = s.id
+                    AND a.account_status::text <> 'Closed'
+                GROUP BY s.id, s.parent_agency_branch_id, s.depth, s.path, s.is_cycle
+            )
+            SELECT o.id AS agency_branch_id, o.parent_agency_branch_id, o.depth, o.is_cycle,
+                   o.account_count, o.total_balance,
+                   (SELECT SUM(d.account_count) FROM own d WHERE NOT d.is_cycle AND o.id = ANY(d.path))::bigint
+                       AS subtree_account_count,
+                   (SELECT SUM(d.total_balance) FROM own d WHERE NOT d.is_cycle AND o.id = ANY(d.path))
+                       AS subtree_balance
+            FROM own o
+            ORDER BY o.depth, o.id
+            "#,
+        )
+        .bind(agency_branch_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter()
+            .map(|row| {
+                let branch_id: Uuid = row.get("agency_branch_id");
+                if row.get::<bool, _>("is_cycle") {
+                    return Err(BankingError::HierarchyCycle { node_id: branch_id });
+                }
+                Ok(BranchRollupModel {
+                    agency_branch_id: branch_id,
+                    parent_agency_branch_id: row.get("parent_agency_branch_id"),
+                    depth: row.get("depth"),
+                    account_count: row.get("account_count"),
+                    total_balance: row.get("total_balance"),
+                    subtree_account_count: row.get("subtree_account_count"),
+                    subtree_balance: row.get("subtree_balance"),
+                })
+            })
+            .collect()
+    }
+
     /// Hierarchical Limit Validation - The core validation logic
     async fn validate_hierarchical_limits(&self, terminal_id: Uuid, amount: Decimal) -> BankingResult<LimitValidationResult> {
         // Get terminal with branch and network info in one query
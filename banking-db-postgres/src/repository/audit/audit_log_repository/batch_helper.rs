@@ -1,47 +1,52 @@
-use crate::repository::executor::Executor;
-use chrono::{DateTime, Utc};
-use std::error::Error;
-use uuid::Uuid;
+use banking_db::models::audit::{AuditLogModel, AUDIT_CHAIN_GENESIS_HASH};
+use chrono::DateTime;
+use sqlx::{PgConnection, Row};
 
-type AuditLogTuple = (Uuid, DateTime<Utc>, Uuid);
+/// Link the entry to the current tail of the chain and insert it. Must run in
+/// a transaction: the advisory lock stops concurrent writers from reading the
+/// same tail and forking the chain, and is held until that transaction ends.
+/// The lock is keyed by schema, as each schema has a chain of its own.
+pub async fn append_to_chain(
+    conn: &mut PgConnection,
+    audit_log: &AuditLogModel,
+) -> Result<AuditLogModel, sqlx::Error> {
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended('audit_log_chain.' || current_schema(), 0))")
+        .execute(&mut *conn)
+        .await?;
+    let tail = sqlx::query(
+        r#"
+        SELECT sequence_number, record_hash FROM audit_log
+        ORDER BY sequence_number DESC
+        LIMIT 1
+        "#,
+    )
+    .fetch_optional(&mut *conn)
+    .await?;
 
-pub async fn execute_audit_log_insert(
-    executor: &Executor,
-    values: Vec<AuditLogTuple>,
-) -> Result<(), Box<dyn Error + Send + Sync>> {
-    let (ids, updated_ats, updated_by_person_ids) =
-        values
-            .into_iter()
-            .fold((Vec::new(), Vec::new(), Vec::new()), |mut acc, val| {
-                acc.0.push(val.0);
-                acc.1.push(val.1);
-                acc.2.push(val.2);
-                acc
-            });
+    let mut chained = audit_log.clone();
+    // Hash what PostgreSQL will store, so verification reads back the same bytes
+    chained.updated_at = DateTime::from_timestamp_micros(audit_log.updated_at.timestamp_micros())
+        .unwrap_or(audit_log.updated_at);
+    (chained.sequence_number, chained.prev_hash) = match tail {
+        Some(row) => (row.get::<i64, _>("sequence_number") + 1, row.get("record_hash")),
+        None => (1, AUDIT_CHAIN_GENESIS_HASH.to_vec()),
+    };
+    chained.record_hash = chained.compute_record_hash();
 
-    let query = r#"
-        INSERT INTO audit_log (id, updated_at, updated_by_person_id)
-        SELECT * FROM UNNEST($1::uuid[], $2::timestamptz[], $3::uuid[])
-    "#;
+    sqlx::query(
+        r#"
+        INSERT INTO audit_log (id, updated_at, updated_by_person_id, sequence_number, prev_hash, record_hash)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        "#,
+    )
+    .bind(chained.id)
+    .bind(chained.updated_at)
+    .bind(chained.updated_by_person_id)
+    .bind(chained.sequence_number)
+    .bind(&chained.prev_hash)
+    .bind(&chained.record_hash)
+    .execute(&mut *conn)
+    .await?;
 
-    match executor {
-        Executor::Pool(pool) => {
-            sqlx::query(query)
-                .bind(ids)
-                .bind(updated_ats)
-                .bind(updated_by_person_ids)
-                .execute(&**pool)
-                .await?;
-        }
-        Executor::Tx(tx) => {
-            let mut tx = tx.lock().await;
-            sqlx::query(query)
-                .bind(ids)
-                .bind(updated_ats)
-                .bind(updated_by_person_ids)
-                .execute(&mut **tx)
-                .await?;
-        }
-    }
-    Ok(())
-}
\ No newline at end of file
+    Ok(chained)
+}
@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use banking_db::{
     models::audit::AuditLogModel,
     repository::{
+        audit_repository::AuditLogRepositoryError,
         batch_repository::BatchRepository,
     },
 };
@@ -33,12 +34,13 @@ impl BatchRepository<Postgres, AuditLogModel> for AuditLogRepositoryImpl {
         _items: Vec<AuditLogModel>,
         _audit_log_id: Uuid,
     ) -> Result<Vec<AuditLogModel>, Box<dyn Error + Send + Sync>> {
-        unimplemented!("Audit logs are immutable and cannot be updated in batch.")
+        Err(Box::new(AuditLogRepositoryError::Immutable("updated")))
     }
 
     async fn delete_batch(
         &self,
         ids: &[Uuid],
+        _audit_log_id: Uuid,
     ) -> Result<usize, Box<dyn Error + Send + Sync>> {
         super::delete_batch::delete_batch(&self.executor, ids).await
     }
@@ -8,28 +8,21 @@ pub async fn create(
     executor: &Executor,
     audit_log: &AuditLogModel,
 ) -> AuditLogResult<AuditLogModel> {
-    let query = sqlx::query(
-        r#"
-        INSERT INTO audit_log (id, updated_at, updated_by_person_id)
-        VALUES ($1, $2, $3)
-        "#,
-    )
-    .bind(audit_log.id)
-    .bind(audit_log.updated_at)
-    .bind(audit_log.updated_by_person_id);
-
-    // Match on the executor type to run the query
-    match executor {
+    // Outside a unit of work the append gets a transaction of its own
+    let created = match executor {
         Executor::Pool(pool) => {
-            query.execute(&**pool).await?;
+            let mut tx = pool.begin().await?;
+            let created = super::batch_helper::append_to_chain(&mut tx, audit_log).await?;
+            tx.commit().await?;
+            created
         }
         Executor::Tx(tx) => {
             let mut tx = tx.lock().await;
-            query.execute(&mut **tx).await?;
+            super::batch_helper::append_to_chain(&mut tx, audit_log).await?
         }
     };
 
-    Ok(audit_log.clone())
+    Ok(created)
 }
 
 #[cfg(test)]
@@ -55,11 +48,7 @@ mod tests {
     }
 
     fn new_test_audit_log() -> AuditLogModel {
-        AuditLogModel {
-            id: Uuid::new_v4(),
-            updated_at: Utc::now(),
-            updated_by_person_id: Uuid::new_v4(),
-        }
+        AuditLogModel::new(Uuid::new_v4(), Utc::now(), Uuid::new_v4())
     }
 
     #[tokio::test]
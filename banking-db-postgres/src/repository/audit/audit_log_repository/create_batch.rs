@@ -10,14 +10,25 @@ pub async fn create_batch(
         return Ok(Vec::new());
     }
 
-    let tuples = items
-        .iter()
-        .map(|item| (item.id, item.updated_at, item.updated_by_person_id))
-        .collect();
-
-    super::batch_helper::execute_audit_log_insert(executor, tuples).await?;
+    // Entries are chained in the order given
+    let mut created = Vec::with_capacity(items.len());
+    match executor {
+        Executor::Pool(pool) => {
+            let mut tx = pool.begin().await?;
+            for item in &items {
+                created.push(super::batch_helper::append_to_chain(&mut tx, item).await?);
+            }
+            tx.commit().await?;
+        }
+        Executor::Tx(tx) => {
+            let mut tx = tx.lock().await;
+            for item in &items {
+                created.push(super::batch_helper::append_to_chain(&mut tx, item).await?);
+            }
+        }
+    }
 
-    Ok(items)
+    Ok(created)
 }
 
 #[cfg(test)]
@@ -43,11 +54,7 @@ mod tests {
     }
 
     fn new_test_audit_log() -> AuditLogModel {
-        AuditLogModel {
-            id: Uuid::new_v4(),
-            updated_at: Utc::now(),
-            updated_by_person_id: Uuid::new_v4(),
-        }
+        AuditLogModel::new(Uuid::new_v4(), Utc::now(), Uuid::new_v4())
     }
 
     #[tokio::test]
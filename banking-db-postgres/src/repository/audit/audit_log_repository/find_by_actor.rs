@@ -0,0 +1,95 @@
+use banking_db::{
+    models::audit::AuditLogModel,
+    repository::audit_repository::{AuditLogResult, MAX_AUDIT_LOG_PAGE_SIZE},
+};
+use crate::repository::executor::Executor;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+pub async fn find_by_actor(
+    executor: &Executor,
+    person_id: Uuid,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    page: i32,
+    page_size: i32,
+) -> AuditLogResult<Vec<AuditLogModel>> {
+    let page_size = page_size.clamp(1, MAX_AUDIT_LOG_PAGE_SIZE);
+    let query = sqlx::query(
+        r#"
+        SELECT *
+        FROM audit_log
+        WHERE updated_by_person_id = $1
+          AND updated_at >= $2
+          AND updated_at < $3
+        ORDER BY updated_at DESC, id DESC
+        LIMIT $4 OFFSET $5
+        "#,
+    )
+    .bind(person_id)
+    .bind(from)
+    .bind(to)
+    .bind(i64::from(page_size))
+    .bind(i64::from(page.max(1) - 1) * i64::from(page_size));
+
+    let rows = match executor {
+        Executor::Pool(pool) => query.fetch_all(&**pool).await?,
+        Executor::Tx(tx) => {
+            let mut tx = tx.lock().await;
+            query.fetch_all(&mut **tx).await?
+        }
+    };
+
+    Ok(rows.iter().map(AuditLogModel::from_row).collect::<Result<_, _>>()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use banking_db::models::audit::AuditLogModel;
+    use banking_db::repository::{AuditLogRepository, UnitOfWorkSession, MAX_AUDIT_LOG_PAGE_SIZE};
+    use chrono::{Duration, SubsecRound, Utc};
+    use uuid::Uuid;
+    use crate::test_helper::setup_test_context;
+
+    #[tokio::test]
+    async fn test_find_by_actor_filters_orders_and_caps_pages() {
+        let ctx = setup_test_context().await.unwrap();
+        let audit_logs = ctx.session.audit_logs();
+        // Whole seconds, so the stored timestamps compare equal to the computed ones
+        let start = (Utc::now() - Duration::days(30)).trunc_subsecs(0);
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+
+        // Bob writes five entries in between; Alice one more than a page can hold
+        let entries = MAX_AUDIT_LOG_PAGE_SIZE as i64 + 6;
+        for minute in 0..entries {
+            let actor = if minute % 100 == 50 { bob } else { alice };
+            audit_logs
+                .create(&AuditLogModel::new(Uuid::new_v4(), start + Duration::minutes(minute), actor))
+                .await
+                .unwrap();
+        }
+        let end = start + Duration::minutes(entries);
+
+        let bobs = audit_logs.find_by_actor(bob, start, end, 1, 50).await.unwrap();
+        assert_eq!(bobs.len(), 5);
+        assert!(bobs.iter().all(|a| a.updated_by_person_id == bob));
+        assert!(bobs.windows(2).all(|pair| pair[0].updated_at > pair[1].updated_at));
+        assert_eq!(bobs[0].updated_at, start + Duration::minutes(450));
+
+        // An oversized page is cut down to the cap; the rest is on the next page
+        let alices = audit_logs.find_by_actor(alice, start, end, 1, 10_000).await.unwrap();
+        assert_eq!(alices.len(), MAX_AUDIT_LOG_PAGE_SIZE as usize);
+        assert!(alices.iter().all(|a| a.updated_by_person_id == alice));
+        assert_eq!(alices[0].updated_at, start + Duration::minutes(entries - 1));
+        let rest = audit_logs.find_by_actor(alice, start, end, 2, 10_000).await.unwrap();
+        assert_eq!(rest.len(), 1);
+
+        // Only the requested window is searched
+        let window = audit_logs
+            .find_by_actor(alice, start + Duration::minutes(10), start + Duration::minutes(20), 1, 50)
+            .await
+            .unwrap();
+        assert_eq!(window.len(), 10);
+    }
+}
@@ -0,0 +1,122 @@
+use banking_db::{
+    models::audit::{AuditEntityType, AuditLogModel},
+    repository::audit_repository::{AuditLogResult, MAX_AUDIT_LOG_PAGE_SIZE},
+};
+use crate::repository::executor::Executor;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Audit table and entity key column holding the versions of each entity type
+fn audit_table(entity_type: AuditEntityType) -> (&'static str, &'static str) {
+    match entity_type {
+        AuditEntityType::Person => ("person_audit", "person_id"),
+        AuditEntityType::Location => ("location_audit", "location_id"),
+        AuditEntityType::EntityReference => ("entity_reference_audit", "entity_reference_id"),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn find_by_entity(
+    executor: &Executor,
+    entity_type: AuditEntityType,
+    entity_id: Uuid,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    page: i32,
+    page_size: i32,
+) -> AuditLogResult<Vec<AuditLogModel>> {
+    let page_size = page_size.clamp(1, MAX_AUDIT_LOG_PAGE_SIZE);
+    let (table, key_column) = audit_table(entity_type);
+    // The audit table's primary key leads with the entity id
+    let sql = format!(
+        r#"
+        SELECT a.*
+        FROM audit_log a
+        WHERE a.id IN (SELECT e.audit_log_id FROM {table} e WHERE e.{key_column} = $1)
+          AND a.updated_at >= $2
+          AND a.updated_at < $3
+        ORDER BY a.updated_at DESC, a.id DESC
+        LIMIT $4 OFFSET $5
+        "#
+    );
+    let query = sqlx::query(&sql)
+        .bind(entity_id)
+        .bind(from)
+        .bind(to)
+        .bind(i64::from(page_size))
+        .bind(i64::from(page.max(1) - 1) * i64::from(page_size));
+
+    let rows = match executor {
+        Executor::Pool(pool) => query.fetch_all(&**pool).await?,
+        Executor::Tx(tx) => {
+            let mut tx = tx.lock().await;
+            query.fetch_all(&mut **tx).await?
+        }
+    };
+
+    Ok(rows.iter().map(AuditLogModel::from_row).collect::<Result<_, _>>()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use banking_db::models::audit::{AuditEntityType, AuditLogModel};
+    use banking_db::repository::{AuditLogRepository, PersonRepository, PersonRepos, UnitOfWorkSession};
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+    use crate::repository::person::test_helpers::create_test_person_model;
+    use crate::test_helper::setup_test_context;
+
+    #[tokio::test]
+    async fn test_find_by_entity() {
+        let ctx = setup_test_context().await.unwrap();
+        let persons = ctx.person_repos().persons();
+        let audit_logs = ctx.session.audit_logs();
+        let start = Utc::now() - Duration::days(7);
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+
+        // Alice creates both persons, Bob changes the first one twice, a day apart
+        let mut audits = Vec::new();
+        for (days, actor) in [(0, alice), (1, bob), (2, bob)] {
+            let audit = AuditLogModel::new(Uuid::new_v4(), start + Duration::days(days), actor);
+            audits.push(audit_logs.create(&audit).await.unwrap());
+        }
+        let mut first = create_test_person_model("First Audited");
+        let second = create_test_person_model("Second Audited");
+        persons.save(first.clone(), audits[0].id).await.unwrap();
+        persons.save(second.clone(), audits[0].id).await.unwrap();
+        first.department = Some(heapless::String::try_from("Audit").unwrap());
+        persons.save(first.clone(), audits[1].id).await.unwrap();
+        first.department = Some(heapless::String::try_from("Risk").unwrap());
+        persons.save(first.clone(), audits[2].id).await.unwrap();
+
+        let end = Utc::now();
+        let history = audit_logs
+            .find_by_entity(AuditEntityType::Person, first.id, start, end, 1, 50)
+            .await
+            .unwrap();
+        let ids: Vec<Uuid> = history.iter().map(|a| a.id).collect();
+        assert_eq!(ids, vec![audits[2].id, audits[1].id, audits[0].id]);
+
+        let history = audit_logs
+            .find_by_entity(AuditEntityType::Person, second.id, start, end, 1, 50)
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].updated_by_person_id, alice);
+
+        // The upper bound is exclusive and pages follow the newest-first order
+        let history = audit_logs
+            .find_by_entity(AuditEntityType::Person, first.id, start, audits[2].updated_at, 2, 1)
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].id, audits[0].id);
+
+        assert!(audit_logs
+            .find_by_entity(AuditEntityType::Location, first.id, start, end, 1, 50)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -3,7 +3,7 @@ use banking_db::{
     repository::audit_repository::AuditLogResult,
 };
 use crate::repository::executor::Executor;
-use sqlx::Row;
+use sqlx::FromRow;
 use uuid::Uuid;
 
 pub async fn find_by_id(
@@ -26,11 +26,7 @@ pub async fn find_by_id(
     };
 
     match row {
-        Some(row) => Ok(Some(AuditLogModel {
-            id: row.get("id"),
-            updated_at: row.get("updated_at"),
-            updated_by_person_id: row.get("updated_by_person_id"),
-        })),
+        Some(row) => Ok(Some(AuditLogModel::from_row(&row)?)),
         None => Ok(None),
     }
 }
@@ -59,11 +55,7 @@ mod tests {
     }
 
     fn new_test_audit_log() -> AuditLogModel {
-        AuditLogModel {
-            id: Uuid::new_v4(),
-            updated_at: Utc::now(),
-            updated_by_person_id: Uuid::new_v4(),
-        }
+        AuditLogModel::new(Uuid::new_v4(), Utc::now(), Uuid::new_v4())
     }
 
     #[tokio::test]
@@ -0,0 +1,60 @@
+use banking_db::{
+    models::audit::AuditLogModel,
+    repository::audit_repository::{AuditLogRepositoryError, AuditLogResult},
+};
+use crate::repository::executor::Executor;
+use sqlx::postgres::{PgArguments, PgRow};
+use sqlx::{FromRow, Postgres, Row};
+use uuid::Uuid;
+
+pub(crate) async fn fetch_all(
+    executor: &Executor,
+    query: sqlx::query::Query<'_, Postgres, PgArguments>,
+) -> Result<Vec<PgRow>, sqlx::Error> {
+    match executor {
+        Executor::Pool(pool) => query.fetch_all(&**pool).await,
+        Executor::Tx(tx) => {
+            let mut tx = tx.lock().await;
+            query.fetch_all(&mut **tx).await
+        }
+    }
+}
+
+/// Entries between the two ids in chain order, whichever of them comes first
+pub async fn find_chain_segment(
+    executor: &Executor,
+    from_id: Uuid,
+    to_id: Uuid,
+) -> AuditLogResult<Vec<AuditLogModel>> {
+    let bounds = fetch_all(
+        executor,
+        sqlx::query("SELECT id, sequence_number FROM audit_log WHERE id = $1 OR id = $2")
+            .bind(from_id)
+            .bind(to_id),
+    )
+    .await?;
+    let sequence_of = |id: Uuid| {
+        bounds
+            .iter()
+            .find(|row| row.get::<Uuid, _>("id") == id)
+            .map(|row| row.get::<i64, _>("sequence_number"))
+            .ok_or(AuditLogRepositoryError::NotFound(id))
+    };
+    let (from_sequence, to_sequence) = (sequence_of(from_id)?, sequence_of(to_id)?);
+
+    let rows = fetch_all(
+        executor,
+        sqlx::query(
+            r#"
+            SELECT * FROM audit_log
+            WHERE sequence_number BETWEEN $1 AND $2
+            ORDER BY sequence_number
+            "#,
+        )
+        .bind(from_sequence.min(to_sequence))
+        .bind(from_sequence.max(to_sequence)),
+    )
+    .await?;
+
+    Ok(rows.iter().map(AuditLogModel::from_row).collect::<Result<_, _>>()?)
+}
@@ -62,11 +62,7 @@ mod tests {
     }
 
     fn new_test_audit_log() -> AuditLogModel {
-        AuditLogModel {
-            id: Uuid::new_v4(),
-            updated_at: Utc::now(),
-            updated_by_person_id: Uuid::new_v4(),
-        }
+        AuditLogModel::new(Uuid::new_v4(), Utc::now(), Uuid::new_v4())
     }
 
     #[tokio::test]
@@ -3,7 +3,11 @@ pub mod batch_helper;
 pub mod create;
 pub mod create_batch;
 pub mod delete_batch;
+pub mod find_chain_segment;
+pub mod find_by_actor;
+pub mod find_by_entity;
 pub mod find_by_id;
 pub mod load_batch;
 pub mod repo_impl;
+pub mod verify_chain;
 pub use repo_impl::*;
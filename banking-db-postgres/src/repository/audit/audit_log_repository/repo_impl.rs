@@ -1,8 +1,9 @@
 use async_trait::async_trait;
 use banking_db::{
-    models::audit::AuditLogModel,
+    models::audit::{AuditChainVerification, AuditEntityType, AuditLogModel},
     repository::audit_repository::{AuditLogRepository, AuditLogResult},
 };
+use chrono::{DateTime, Utc};
 use sqlx::{Postgres};
 use uuid::Uuid;
 
@@ -30,4 +31,35 @@ impl AuditLogRepository<Postgres> for AuditLogRepositoryImpl {
     async fn find_by_id(&self, id: Uuid) -> AuditLogResult<Option<AuditLogModel>> {
         super::find_by_id::find_by_id(&self.executor, id).await
     }
-}
\ No newline at end of file
+
+    async fn find_by_entity(
+        &self,
+        entity_type: AuditEntityType,
+        entity_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        page: i32,
+        page_size: i32,
+    ) -> AuditLogResult<Vec<AuditLogModel>> {
+        super::find_by_entity::find_by_entity(&self.executor, entity_type, entity_id, from, to, page, page_size).await
+    }
+
+    async fn find_by_actor(
+        &self,
+        person_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        page: i32,
+        page_size: i32,
+    ) -> AuditLogResult<Vec<AuditLogModel>> {
+        super::find_by_actor::find_by_actor(&self.executor, person_id, from, to, page, page_size).await
+    }
+
+    async fn find_chain_segment(&self, from_id: Uuid, to_id: Uuid) -> AuditLogResult<Vec<AuditLogModel>> {
+        super::find_chain_segment::find_chain_segment(&self.executor, from_id, to_id).await
+    }
+
+    async fn verify_chain(&self, from_id: Uuid, to_id: Uuid) -> AuditLogResult<AuditChainVerification> {
+        super::verify_chain::verify_chain(&self.executor, from_id, to_id).await
+    }
+}
@@ -0,0 +1,100 @@
+use banking_db::{
+    models::audit::{
+        AuditChainVerification, BrokenAuditLink, BrokenAuditLinkReason, AUDIT_CHAIN_GENESIS_HASH,
+    },
+    repository::audit_repository::AuditLogResult,
+};
+use crate::repository::executor::Executor;
+use sqlx::Row;
+use uuid::Uuid;
+
+use super::find_chain_segment::{fetch_all, find_chain_segment};
+
+pub async fn verify_chain(
+    executor: &Executor,
+    from_id: Uuid,
+    to_id: Uuid,
+) -> AuditLogResult<AuditChainVerification> {
+    let entries = find_chain_segment(executor, from_id, to_id).await?;
+    let Some(first) = entries.first() else {
+        return Ok(AuditChainVerification { entries_checked: 0, first_broken_link: None });
+    };
+
+    // The first entry must also link to the one before it
+    let predecessor_hash = if first.sequence_number == 1 {
+        AUDIT_CHAIN_GENESIS_HASH.to_vec()
+    } else {
+        let rows = fetch_all(
+            executor,
+            sqlx::query("SELECT record_hash FROM audit_log WHERE sequence_number = $1")
+                .bind(first.sequence_number - 1),
+        )
+        .await?;
+        match rows.first() {
+            Some(row) => row.get("record_hash"),
+            None => {
+                return Ok(AuditChainVerification {
+                    entries_checked: 1,
+                    first_broken_link: Some(BrokenAuditLink {
+                        audit_log_id: first.id,
+                        sequence_number: first.sequence_number,
+                        reason: BrokenAuditLinkReason::MissingEntry,
+                    }),
+                });
+            }
+        }
+    };
+
+    Ok(AuditChainVerification::walk(&predecessor_hash, &entries))
+}
+
+#[cfg(test)]
+mod tests {
+    use banking_db::models::audit::{AuditLogModel, BrokenAuditLinkReason};
+    use banking_db::repository::AuditLogRepository;
+    use chrono::Utc;
+    use uuid::Uuid;
+    use crate::repository::audit::audit_log_repository::AuditLogRepositoryImpl;
+    use crate::repository::executor::Executor;
+    use crate::test_helper::setup_test_schema;
+
+    #[tokio::test]
+    async fn test_verify_chain_finds_the_tampered_entry() {
+        let schema = setup_test_schema().await.unwrap();
+        let audit_logs = AuditLogRepositoryImpl::new(Executor::Pool(schema.pool()));
+
+        let mut entries = Vec::new();
+        for _ in 0..10 {
+            let audit = AuditLogModel::new(Uuid::new_v4(), Utc::now(), Uuid::new_v4());
+            entries.push(audit_logs.create(&audit).await.unwrap());
+        }
+        assert!(entries.windows(2).all(|pair| pair[1].prev_hash == pair[0].record_hash));
+        let (first, last) = (entries[0].id, entries[9].id);
+
+        let verification = audit_logs.verify_chain(first, last).await.unwrap();
+        assert_eq!(verification.entries_checked, 10);
+        assert!(verification.first_broken_link.is_none());
+
+        // Flip one bit of the seventh entry's actor id behind the repository's back
+        let tampered = &entries[6];
+        let mut forged_actor = *tampered.updated_by_person_id.as_bytes();
+        forged_actor[0] ^= 0x01;
+        sqlx::query("UPDATE audit_log SET updated_by_person_id = $2 WHERE id = $1")
+            .bind(tampered.id)
+            .bind(Uuid::from_bytes(forged_actor))
+            .execute(&*schema.pool())
+            .await
+            .unwrap();
+
+        let verification = audit_logs.verify_chain(first, last).await.unwrap();
+        let broken = verification.first_broken_link.unwrap();
+        assert_eq!(broken.audit_log_id, tampered.id);
+        assert_eq!(broken.sequence_number, tampered.sequence_number);
+        assert_eq!(broken.reason, BrokenAuditLinkReason::RecordHashMismatch);
+        assert_eq!(verification.entries_checked, 7);
+
+        // Entries before the tampered one still verify on their own
+        let verification = audit_logs.verify_chain(first, entries[5].id).await.unwrap();
+        assert!(verification.first_broken_link.is_none());
+    }
+}
@@ -0,0 +1,105 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::BackDatedPostingModel;
+use banking_db::repository::BackDatedPostingRepository;
+use chrono::NaiveDate;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of BackDatedPostingRepository
+pub struct BackDatedPostingRepositoryImpl {
+    pool: PgPool,
+}
+
+impl BackDatedPostingRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+impl TryFromRow<PgRow> for BackDatedPostingModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(BackDatedPostingModel {
+            id: row.get("id"),
+            transaction_id: row.get("transaction_id"),
+            account_id: row.get("account_id"),
+            value_date: row.get("value_date"),
+            business_date: row.get("business_date"),
+            days_back: row.get("days_back"),
+            accrual_delta: row.get("accrual_delta"),
+            posted_by_person_id: row.get("posted_by_person_id"),
+            posted_at: row.get("posted_at"),
+        })
+    }
+}
+
+const BACK_DATED_POSTING_COLUMNS: &str = r#"
+    id, transaction_id, account_id, value_date, business_date, days_back,
+    accrual_delta, posted_by_person_id, posted_at
+"#;
+
+#[async_trait]
+impl BackDatedPostingRepository for BackDatedPostingRepositoryImpl {
+    async fn create_back_dated_posting(&self, posting: BackDatedPostingModel) -> BankingResult<BackDatedPostingModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO back_dated_postings (
+                id, transaction_id, account_id, value_date, business_date, days_back,
+                accrual_delta, posted_by_person_id, posted_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING {BACK_DATED_POSTING_COLUMNS}
+            "#
+        ))
+        .bind(posting.id)
+        .bind(posting.transaction_id)
+        .bind(posting.account_id)
+        .bind(posting.value_date)
+        .bind(posting.business_date)
+        .bind(posting.days_back)
+        .bind(posting.accrual_delta)
+        .bind(posting.posted_by_person_id)
+        .bind(posting.posted_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create back-dated posting: {e}")))?;
+
+        BackDatedPostingModel::try_from_row(&row)
+    }
+
+    async fn find_back_dated_postings(&self, from_date: NaiveDate, to_date: NaiveDate) -> BankingResult<Vec<BackDatedPostingModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {BACK_DATED_POSTING_COLUMNS}
+            FROM back_dated_postings
+            WHERE business_date BETWEEN $1 AND $2
+            ORDER BY business_date, posted_at
+            "#
+        ))
+        .bind(from_date)
+        .bind(to_date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find back-dated postings: {e}")))?;
+
+        rows.iter().map(BackDatedPostingModel::try_from_row).collect()
+    }
+
+    async fn find_back_dated_postings_by_account(&self, account_id: Uuid) -> BankingResult<Vec<BackDatedPostingModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {BACK_DATED_POSTING_COLUMNS} FROM back_dated_postings WHERE account_id = $1 ORDER BY posted_at"
+        ))
+        .bind(account_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find back-dated postings by account: {e}")))?;
+
+        rows.iter().map(BackDatedPostingModel::try_from_row).collect()
+    }
+}
@@ -0,0 +1,325 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{
+    BranchCashCeilingModel, BranchCashPositionModel, CashTransferRequestModel, DbCashTransferOrigin,
+    DbCashTransferStatus, DbVaultAdjustmentType, VaultAdjustmentModel,
+};
+use banking_db::repository::BranchCashRepository;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of BranchCashRepository
+pub struct BranchCashRepositoryImpl {
+    pool: PgPool,
+}
+
+impl BranchCashRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+impl TryFromRow<PgRow> for BranchCashCeilingModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(BranchCashCeilingModel {
+            id: row.get("id"),
+            agency_branch_id: row.get("agency_branch_id"),
+            ceiling_amount: row.get("ceiling_amount"),
+            excess_destination_branch_id: row.get("excess_destination_branch_id"),
+            is_active: row.get("is_active"),
+            created_at: row.get("created_at"),
+            last_updated_at: row.get("last_updated_at"),
+            updated_by_person_id: row.get("updated_by_person_id"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for CashTransferRequestModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(CashTransferRequestModel {
+            id: row.get("id"),
+            from_agency_branch_id: row.get("from_agency_branch_id"),
+            to_agency_branch_id: row.get("to_agency_branch_id"),
+            amount: row.get("amount"),
+            status: row.get::<String, _>("status")
+                .parse::<DbCashTransferStatus>()
+                .map_err(|_| BankingError::Internal("Invalid cash transfer status".to_string()))?,
+            origin: row.get::<String, _>("origin")
+                .parse::<DbCashTransferOrigin>()
+                .map_err(|_| BankingError::Internal("Invalid cash transfer origin".to_string()))?,
+            requested_by_person_id: row.get("requested_by_person_id"),
+            requested_at: row.get("requested_at"),
+            dispatch_authorized_by_person_id: row.get("dispatch_authorized_by_person_id"),
+            dispatch_confirmed_by_person_id: row.get("dispatch_confirmed_by_person_id"),
+            dispatched_at: row.get("dispatched_at"),
+            receipt_authorized_by_person_id: row.get("receipt_authorized_by_person_id"),
+            receipt_confirmed_by_person_id: row.get("receipt_confirmed_by_person_id"),
+            received_at: row.get("received_at"),
+            cancelled_by_person_id: row.get("cancelled_by_person_id"),
+            cancellation_reason: row
+                .get::<Option<String>, _>("cancellation_reason")
+                .map(|reason| HeaplessString::try_from(reason.as_str()))
+                .transpose()
+                .map_err(|_| BankingError::ValidationError {
+                    field: "cancellation_reason".to_string(),
+                    message: "Cancellation reason too long".to_string(),
+                })?,
+            last_updated_at: row.get("last_updated_at"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for VaultAdjustmentModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(VaultAdjustmentModel {
+            id: row.get("id"),
+            agency_branch_id: row.get("agency_branch_id"),
+            cash_transfer_request_id: row.get("cash_transfer_request_id"),
+            adjustment_type: row.get::<String, _>("adjustment_type")
+                .parse::<DbVaultAdjustmentType>()
+                .map_err(|_| BankingError::Internal("Invalid vault adjustment type".to_string()))?,
+            amount: row.get("amount"),
+            posted_at: row.get("posted_at"),
+            posted_by_person_id: row.get("posted_by_person_id"),
+        })
+    }
+}
+
+const CEILING_COLUMNS: &str = r#"
+    id, agency_branch_id, ceiling_amount, excess_destination_branch_id, is_active,
+    created_at, last_updated_at, updated_by_person_id
+"#;
+
+const TRANSFER_COLUMNS: &str = r#"
+    id, from_agency_branch_id, to_agency_branch_id, amount, status::text as status, origin::text as origin,
+    requested_by_person_id, requested_at, dispatch_authorized_by_person_id, dispatch_confirmed_by_person_id,
+    dispatched_at, receipt_authorized_by_person_id, receipt_confirmed_by_person_id, received_at,
+    cancelled_by_person_id, cancellation_reason, last_updated_at
+"#;
+
+const ADJUSTMENT_COLUMNS: &str = r#"
+    id, agency_branch_id, cash_transfer_request_id, adjustment_type::text as adjustment_type,
+    amount, posted_at, posted_by_person_id
+"#;
+
+#[async_trait]
+impl BranchCashRepository for BranchCashRepositoryImpl {
+    async fn upsert_ceiling(&self, ceiling: BranchCashCeilingModel) -> BankingResult<BranchCashCeilingModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO branch_cash_ceilings (
+                id, agency_branch_id, ceiling_amount, excess_destination_branch_id, is_active,
+                created_at, last_updated_at, updated_by_person_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (agency_branch_id) DO UPDATE SET
+                ceiling_amount = EXCLUDED.ceiling_amount,
+                excess_destination_branch_id = EXCLUDED.excess_destination_branch_id,
+                is_active = EXCLUDED.is_active,
+                last_updated_at = EXCLUDED.last_updated_at,
+                updated_by_person_id = EXCLUDED.updated_by_person_id
+            RETURNING {CEILING_COLUMNS}
+            "#
+        ))
+        .bind(ceiling.id)
+        .bind(ceiling.agency_branch_id)
+        .bind(ceiling.ceiling_amount)
+        .bind(ceiling.excess_destination_branch_id)
+        .bind(ceiling.is_active)
+        .bind(ceiling.created_at)
+        .bind(ceiling.last_updated_at)
+        .bind(ceiling.updated_by_person_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to store branch cash ceiling: {e}")))?;
+
+        BranchCashCeilingModel::try_from_row(&row)
+    }
+
+    async fn find_ceiling_by_branch(&self, agency_branch_id: Uuid) -> BankingResult<Option<BranchCashCeilingModel>> {
+        let row = sqlx::query(&format!("SELECT {CEILING_COLUMNS} FROM branch_cash_ceilings WHERE agency_branch_id = $1"))
+            .bind(agency_branch_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find branch cash ceiling: {e}")))?;
+
+        row.as_ref().map(BranchCashCeilingModel::try_from_row).transpose()
+    }
+
+    async fn find_active_ceilings(&self) -> BankingResult<Vec<BranchCashCeilingModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {CEILING_COLUMNS} FROM branch_cash_ceilings WHERE is_active = TRUE ORDER BY agency_branch_id"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find branch cash ceilings: {e}")))?;
+
+        rows.iter().map(BranchCashCeilingModel::try_from_row).collect()
+    }
+
+    async fn get_branch_cash_position(&self, agency_branch_id: Uuid) -> BankingResult<BranchCashPositionModel> {
+        let row = sqlx::query(
+            r#"
+            SELECT
+                (SELECT COALESCE(SUM(current_cash_balance), 0) FROM agent_terminals
+                 WHERE agency_branch_id = $1) as terminal_cash,
+                (SELECT COALESCE(SUM(amount), 0) FROM vault_adjustments
+                 WHERE agency_branch_id = $1) as vault_adjustments
+            "#,
+        )
+        .bind(agency_branch_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to get branch cash position: {e}")))?;
+
+        Ok(BranchCashPositionModel {
+            agency_branch_id,
+            terminal_cash: row.get("terminal_cash"),
+            vault_adjustments: row.get("vault_adjustments"),
+        })
+    }
+
+    async fn create_transfer(&self, transfer: CashTransferRequestModel) -> BankingResult<CashTransferRequestModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO cash_transfer_requests (
+                id, from_agency_branch_id, to_agency_branch_id, amount, status, origin,
+                requested_by_person_id, requested_at, last_updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5::cash_transfer_status, $6::cash_transfer_origin, $7, $8, $9)
+            RETURNING {TRANSFER_COLUMNS}
+            "#
+        ))
+        .bind(transfer.id)
+        .bind(transfer.from_agency_branch_id)
+        .bind(transfer.to_agency_branch_id)
+        .bind(transfer.amount)
+        .bind(transfer.status)
+        .bind(transfer.origin)
+        .bind(transfer.requested_by_person_id)
+        .bind(transfer.requested_at)
+        .bind(transfer.last_updated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create cash transfer: {e}")))?;
+
+        CashTransferRequestModel::try_from_row(&row)
+    }
+
+    async fn find_transfer_by_id(&self, transfer_id: Uuid) -> BankingResult<Option<CashTransferRequestModel>> {
+        let row = sqlx::query(&format!("SELECT {TRANSFER_COLUMNS} FROM cash_transfer_requests WHERE id = $1"))
+            .bind(transfer_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find cash transfer: {e}")))?;
+
+        row.as_ref().map(CashTransferRequestModel::try_from_row).transpose()
+    }
+
+    async fn find_open_transfers(&self) -> BankingResult<Vec<CashTransferRequestModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {TRANSFER_COLUMNS} FROM cash_transfer_requests
+            WHERE status IN ('Requested', 'InTransit')
+            ORDER BY requested_at
+            "#
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find open cash transfers: {e}")))?;
+
+        rows.iter().map(CashTransferRequestModel::try_from_row).collect()
+    }
+
+    async fn update_transfer(
+        &self,
+        transfer: CashTransferRequestModel,
+        expected_last_updated_at: DateTime<Utc>,
+        adjustment: Option<VaultAdjustmentModel>,
+    ) -> BankingResult<CashTransferRequestModel> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| BankingError::Internal(format!("Failed to start transaction: {e}")))?;
+
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE cash_transfer_requests SET
+                status = $2::cash_transfer_status,
+                dispatch_authorized_by_person_id = $3,
+                dispatch_confirmed_by_person_id = $4,
+                dispatched_at = $5,
+                receipt_authorized_by_person_id = $6,
+                receipt_confirmed_by_person_id = $7,
+                received_at = $8,
+                cancelled_by_person_id = $9,
+                cancellation_reason = $10,
+                last_updated_at = $11
+            WHERE id = $1 AND last_updated_at = $12
+            RETURNING {TRANSFER_COLUMNS}
+            "#
+        ))
+        .bind(transfer.id)
+        .bind(transfer.status)
+        .bind(transfer.dispatch_authorized_by_person_id)
+        .bind(transfer.dispatch_confirmed_by_person_id)
+        .bind(transfer.dispatched_at)
+        .bind(transfer.receipt_authorized_by_person_id)
+        .bind(transfer.receipt_confirmed_by_person_id)
+        .bind(transfer.received_at)
+        .bind(transfer.cancelled_by_person_id)
+        .bind(transfer.cancellation_reason.as_ref().map(|reason| reason.as_str()))
+        .bind(transfer.last_updated_at)
+        .bind(expected_last_updated_at)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update cash transfer: {e}")))?
+        .ok_or_else(|| BankingError::ValidationError {
+            field: "transfer_id".to_string(),
+            message: format!("Cash transfer {} was modified concurrently", transfer.id),
+        })?;
+
+        if let Some(adjustment) = adjustment {
+            sqlx::query(
+                r#"
+                INSERT INTO vault_adjustments (
+                    id, agency_branch_id, cash_transfer_request_id, adjustment_type, amount, posted_at, posted_by_person_id
+                )
+                VALUES ($1, $2, $3, $4::vault_adjustment_type, $5, $6, $7)
+                "#,
+            )
+            .bind(adjustment.id)
+            .bind(adjustment.agency_branch_id)
+            .bind(adjustment.cash_transfer_request_id)
+            .bind(adjustment.adjustment_type)
+            .bind(adjustment.amount)
+            .bind(adjustment.posted_at)
+            .bind(adjustment.posted_by_person_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to post vault adjustment: {e}")))?;
+        }
+
+        tx.commit().await
+            .map_err(|e| BankingError::Internal(format!("Failed to commit cash transfer: {e}")))?;
+        CashTransferRequestModel::try_from_row(&row)
+    }
+
+    async fn find_adjustments_by_transfer(&self, transfer_id: Uuid) -> BankingResult<Vec<VaultAdjustmentModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {ADJUSTMENT_COLUMNS} FROM vault_adjustments WHERE cash_transfer_request_id = $1 ORDER BY posted_at"
+        ))
+        .bind(transfer_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find vault adjustments: {e}")))?;
+
+        rows.iter().map(VaultAdjustmentModel::try_from_row).collect()
+    }
+}
@@ -0,0 +1,370 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{
+    AccountBundleModel, BundleAccountModel, BundlePricingMarkerModel, DbBundleComponentRole, DbBundleStatus,
+    FeeCategory, ProductBundleModel,
+};
+use banking_db::repository::BundleRepository;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of BundleRepository
+pub struct BundleRepositoryImpl {
+    pool: PgPool,
+}
+
+impl BundleRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+fn heapless<const N: usize>(value: String, field: &str) -> BankingResult<HeaplessString<N>> {
+    HeaplessString::try_from(value.as_str()).map_err(|_| BankingError::ValidationError {
+        field: field.to_string(),
+        message: format!("{field} too long"),
+    })
+}
+
+fn fee_category_from_str(value: &str) -> BankingResult<FeeCategory> {
+    match value {
+        "Transaction" => Ok(FeeCategory::Transaction),
+        "Maintenance" => Ok(FeeCategory::Maintenance),
+        "Service" => Ok(FeeCategory::Service),
+        "Penalty" => Ok(FeeCategory::Penalty),
+        "Card" => Ok(FeeCategory::Card),
+        "Loan" => Ok(FeeCategory::Loan),
+        "Regulatory" => Ok(FeeCategory::Regulatory),
+        _ => Err(BankingError::Internal(format!("Invalid fee category: {value}"))),
+    }
+}
+
+impl TryFromRow<PgRow> for ProductBundleModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(ProductBundleModel {
+            id: row.get("id"),
+            bundle_code: heapless(row.get("bundle_code"), "bundle_code")?,
+            name: heapless(row.get("name"), "name")?,
+            components: row.get("components"),
+            fee_adjustments: row.get("fee_adjustments"),
+            is_active: row.get("is_active"),
+            created_at: row.get("created_at"),
+            last_updated_at: row.get("last_updated_at"),
+            updated_by_person_id: row.get("updated_by_person_id"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for AccountBundleModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(AccountBundleModel {
+            id: row.get("id"),
+            product_bundle_id: row.get("product_bundle_id"),
+            bundle_code: heapless(row.get("bundle_code"), "bundle_code")?,
+            customer_id: row.get("customer_id"),
+            status: row.get::<String, _>("status")
+                .parse::<DbBundleStatus>()
+                .map_err(|_| BankingError::Internal("Invalid bundle status".to_string()))?,
+            orchestration_id: row.get("orchestration_id"),
+            opened_at: row.get("opened_at"),
+            dissolved_at: row.get("dissolved_at"),
+            dissolution_reason: row
+                .get::<Option<String>, _>("dissolution_reason")
+                .map(|reason| heapless(reason, "dissolution_reason"))
+                .transpose()?,
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for BundleAccountModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(BundleAccountModel {
+            id: row.get("id"),
+            account_bundle_id: row.get("account_bundle_id"),
+            account_id: row.get("account_id"),
+            role: row.get::<String, _>("role")
+                .parse::<DbBundleComponentRole>()
+                .map_err(|_| BankingError::Internal("Invalid bundle component role".to_string()))?,
+            required: row.get("required"),
+            linked_at: row.get("linked_at"),
+            unlinked_at: row.get("unlinked_at"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for BundlePricingMarkerModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(BundlePricingMarkerModel {
+            id: row.get("id"),
+            account_bundle_id: row.get("account_bundle_id"),
+            account_id: row.get("account_id"),
+            fee_category: fee_category_from_str(row.get("fee_category"))?,
+            discount_percentage: row.get("discount_percentage"),
+            depends_on_account_id: row.get("depends_on_account_id"),
+            created_at: row.get("created_at"),
+            removed_at: row.get("removed_at"),
+        })
+    }
+}
+
+const PRODUCT_BUNDLE_COLUMNS: &str = r#"
+    id, bundle_code, name, components::text as components, fee_adjustments::text as fee_adjustments,
+    is_active, created_at, last_updated_at, updated_by_person_id
+"#;
+
+const ACCOUNT_BUNDLE_COLUMNS: &str = r#"
+    id, product_bundle_id, bundle_code, customer_id, status::text as status, orchestration_id,
+    opened_at, dissolved_at, dissolution_reason
+"#;
+
+const BUNDLE_ACCOUNT_COLUMNS: &str = r#"
+    id, account_bundle_id, account_id, role::text as role, required, linked_at, unlinked_at
+"#;
+
+const MARKER_COLUMNS: &str = r#"
+    id, account_bundle_id, account_id, fee_category, discount_percentage, depends_on_account_id,
+    created_at, removed_at
+"#;
+
+#[async_trait]
+impl BundleRepository for BundleRepositoryImpl {
+    async fn create_product_bundle(&self, bundle: ProductBundleModel) -> BankingResult<ProductBundleModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO product_bundles (
+                id, bundle_code, name, components, fee_adjustments, is_active,
+                created_at, last_updated_at, updated_by_person_id
+            )
+            VALUES ($1, $2, $3, $4::jsonb, $5::jsonb, $6, $7, $8, $9)
+            RETURNING {PRODUCT_BUNDLE_COLUMNS}
+            "#
+        ))
+        .bind(bundle.id)
+        .bind(bundle.bundle_code.as_str())
+        .bind(bundle.name.as_str())
+        .bind(&bundle.components)
+        .bind(&bundle.fee_adjustments)
+        .bind(bundle.is_active)
+        .bind(bundle.created_at)
+        .bind(bundle.last_updated_at)
+        .bind(bundle.updated_by_person_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create product bundle: {e}")))?;
+
+        ProductBundleModel::try_from_row(&row)
+    }
+
+    async fn find_product_bundle_by_code(&self, bundle_code: &str) -> BankingResult<Option<ProductBundleModel>> {
+        let row = sqlx::query(&format!("SELECT {PRODUCT_BUNDLE_COLUMNS} FROM product_bundles WHERE bundle_code = $1"))
+            .bind(bundle_code)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find product bundle: {e}")))?;
+
+        row.as_ref().map(ProductBundleModel::try_from_row).transpose()
+    }
+
+    async fn create_account_bundle(&self, bundle: AccountBundleModel) -> BankingResult<AccountBundleModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO account_bundles (
+                id, product_bundle_id, bundle_code, customer_id, status, orchestration_id,
+                opened_at, dissolved_at, dissolution_reason
+            )
+            VALUES ($1, $2, $3, $4, $5::bundle_status, $6, $7, $8, $9)
+            RETURNING {ACCOUNT_BUNDLE_COLUMNS}
+            "#
+        ))
+        .bind(bundle.id)
+        .bind(bundle.product_bundle_id)
+        .bind(bundle.bundle_code.as_str())
+        .bind(bundle.customer_id)
+        .bind(bundle.status)
+        .bind(bundle.orchestration_id)
+        .bind(bundle.opened_at)
+        .bind(bundle.dissolved_at)
+        .bind(bundle.dissolution_reason.as_ref().map(|s| s.as_str()))
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create account bundle: {e}")))?;
+
+        AccountBundleModel::try_from_row(&row)
+    }
+
+    async fn update_account_bundle(&self, bundle: AccountBundleModel) -> BankingResult<AccountBundleModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE account_bundles
+            SET status = $2::bundle_status, dissolved_at = $3, dissolution_reason = $4
+            WHERE id = $1
+            RETURNING {ACCOUNT_BUNDLE_COLUMNS}
+            "#
+        ))
+        .bind(bundle.id)
+        .bind(bundle.status)
+        .bind(bundle.dissolved_at)
+        .bind(bundle.dissolution_reason.as_ref().map(|s| s.as_str()))
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update account bundle: {e}")))?;
+
+        AccountBundleModel::try_from_row(&row)
+    }
+
+    async fn find_account_bundle_by_id(&self, account_bundle_id: Uuid) -> BankingResult<Option<AccountBundleModel>> {
+        let row = sqlx::query(&format!("SELECT {ACCOUNT_BUNDLE_COLUMNS} FROM account_bundles WHERE id = $1"))
+            .bind(account_bundle_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find account bundle: {e}")))?;
+
+        row.as_ref().map(AccountBundleModel::try_from_row).transpose()
+    }
+
+    async fn find_account_bundles_by_customer(&self, customer_id: Uuid) -> BankingResult<Vec<AccountBundleModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {ACCOUNT_BUNDLE_COLUMNS} FROM account_bundles WHERE customer_id = $1 ORDER BY opened_at DESC"
+        ))
+        .bind(customer_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find account bundles: {e}")))?;
+
+        rows.iter().map(AccountBundleModel::try_from_row).collect()
+    }
+
+    async fn create_bundle_account(&self, link: BundleAccountModel) -> BankingResult<BundleAccountModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO bundle_accounts (id, account_bundle_id, account_id, role, required, linked_at, unlinked_at)
+            VALUES ($1, $2, $3, $4::bundle_component_role, $5, $6, $7)
+            RETURNING {BUNDLE_ACCOUNT_COLUMNS}
+            "#
+        ))
+        .bind(link.id)
+        .bind(link.account_bundle_id)
+        .bind(link.account_id)
+        .bind(link.role)
+        .bind(link.required)
+        .bind(link.linked_at)
+        .bind(link.unlinked_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to link bundle account: {e}")))?;
+
+        BundleAccountModel::try_from_row(&row)
+    }
+
+    async fn find_bundle_accounts(&self, account_bundle_id: Uuid) -> BankingResult<Vec<BundleAccountModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {BUNDLE_ACCOUNT_COLUMNS} FROM bundle_accounts WHERE account_bundle_id = $1 ORDER BY linked_at, id"
+        ))
+        .bind(account_bundle_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find bundle accounts: {e}")))?;
+
+        rows.iter().map(BundleAccountModel::try_from_row).collect()
+    }
+
+    async fn find_linked_bundle_account(&self, account_id: Uuid) -> BankingResult<Option<BundleAccountModel>> {
+        let row = sqlx::query(&format!(
+            r#"
+            SELECT {BUNDLE_ACCOUNT_COLUMNS} FROM bundle_accounts
+            WHERE account_id = $1 AND unlinked_at IS NULL
+              AND account_bundle_id IN (SELECT id FROM account_bundles WHERE status = 'Active')
+            "#
+        ))
+        .bind(account_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find bundle account: {e}")))?;
+
+        row.as_ref().map(BundleAccountModel::try_from_row).transpose()
+    }
+
+    async fn unlink_bundle_account(&self, link_id: Uuid, unlinked_at: DateTime<Utc>) -> BankingResult<()> {
+        sqlx::query("UPDATE bundle_accounts SET unlinked_at = $2 WHERE id = $1 AND unlinked_at IS NULL")
+            .bind(link_id)
+            .bind(unlinked_at)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to unlink bundle account: {e}")))?;
+
+        Ok(())
+    }
+
+    async fn create_pricing_marker(&self, marker: BundlePricingMarkerModel) -> BankingResult<BundlePricingMarkerModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO bundle_pricing_markers (
+                id, account_bundle_id, account_id, fee_category, discount_percentage, depends_on_account_id,
+                created_at, removed_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING {MARKER_COLUMNS}
+            "#
+        ))
+        .bind(marker.id)
+        .bind(marker.account_bundle_id)
+        .bind(marker.account_id)
+        .bind(format!("{:?}", marker.fee_category))
+        .bind(marker.discount_percentage)
+        .bind(marker.depends_on_account_id)
+        .bind(marker.created_at)
+        .bind(marker.removed_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create bundle pricing marker: {e}")))?;
+
+        BundlePricingMarkerModel::try_from_row(&row)
+    }
+
+    async fn find_pricing_markers_by_bundle(&self, account_bundle_id: Uuid) -> BankingResult<Vec<BundlePricingMarkerModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {MARKER_COLUMNS} FROM bundle_pricing_markers WHERE account_bundle_id = $1 ORDER BY created_at, id"
+        ))
+        .bind(account_bundle_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find bundle pricing markers: {e}")))?;
+
+        rows.iter().map(BundlePricingMarkerModel::try_from_row).collect()
+    }
+
+    async fn find_active_markers_by_account(&self, account_id: Uuid) -> BankingResult<Vec<BundlePricingMarkerModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {MARKER_COLUMNS} FROM bundle_pricing_markers
+            WHERE account_id = $1 AND removed_at IS NULL
+            ORDER BY created_at, id
+            "#
+        ))
+        .bind(account_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find bundle pricing markers: {e}")))?;
+
+        rows.iter().map(BundlePricingMarkerModel::try_from_row).collect()
+    }
+
+    async fn remove_pricing_markers(&self, marker_ids: &[Uuid], removed_at: DateTime<Utc>) -> BankingResult<()> {
+        sqlx::query("UPDATE bundle_pricing_markers SET removed_at = $2 WHERE id = ANY($1) AND removed_at IS NULL")
+            .bind(marker_ids)
+            .bind(removed_at)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to remove bundle pricing markers: {e}")))?;
+
+        Ok(())
+    }
+}
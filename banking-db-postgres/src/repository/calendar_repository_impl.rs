@@ -4,7 +4,7 @@ use uuid::Uuid;
 use chrono::{NaiveDate, Weekday};
 use heapless::String as HeaplessString;
 
-use banking_api::BankingResult;
+use banking_api::{domain::WeekendMask, BankingResult};
 use banking_db::models::{BankHolidayModel, HolidayType};
 use banking_db::repository::{CalendarRepository, ValidationResult, ImportResult, CalendarSummaryReport};
 
@@ -21,7 +21,7 @@ impl CalendarRepositoryImpl {
 #[async_trait]
 impl CalendarRepository for CalendarRepositoryImpl {
     /// Get weekend configuration for jurisdiction
-    async fn get_weekend_days(&self, jurisdiction: &str) -> BankingResult<Vec<Weekday>> {
+    async fn get_weekend_days(&self, jurisdiction: &str) -> BankingResult<Option<WeekendMask>> {
         let config = sqlx::query!(
             r#"
             SELECT weekend_days
@@ -53,17 +53,18 @@ impl CalendarRepository for CalendarRepositoryImpl {
                     _ => None,
                 })
                 .collect();
-            Ok(weekend_days)
+            Ok(Some(WeekendMask::from_weekdays(&weekend_days)))
         } else {
-            // Default to Saturday and Sunday
-            Ok(vec![Weekday::Sat, Weekday::Sun])
+            // The service falls back to the default calendar
+            Ok(None)
         }
     }
 
     /// Set weekend configuration
-    async fn set_weekend_days(&self, jurisdiction: &str, weekend_days: Vec<Weekday>) -> BankingResult<()> {
+    async fn set_weekend_days(&self, jurisdiction: &str, weekend_days: WeekendMask) -> BankingResult<()> {
         // Convert Weekday enum to integers
         let day_numbers: Vec<i32> = weekend_days
+            .weekdays()
             .iter()
             .map(|day| match day {
                 Weekday::Mon => 1,
@@ -400,9 +401,8 @@ impl CalendarRepository for CalendarRepositoryImpl {
         Ok(vec![])
     }
 
-    async fn add_jurisdiction(&self, _jurisdiction: &str, _weekend_days: Vec<Weekday>) -> BankingResult<()> {
-        // TODO: Implement jurisdiction addition
-        Ok(())
+    async fn add_jurisdiction(&self, jurisdiction: &str, weekend_days: WeekendMask) -> BankingResult<()> {
+        self.set_weekend_days(jurisdiction, weekend_days).await
     }
 
     async fn remove_jurisdiction(&self, _jurisdiction: &str) -> BankingResult<()> {
@@ -1,8 +1,13 @@
 use async_trait::async_trait;
 use banking_api::{BankingResult, BankingError};
-use banking_db::models::channel::{ChannelModel, ChannelStatus};
+use banking_db::models::channel::{
+    ChannelLimitModel, ChannelModel, ChannelReconciliationReportModel, ChannelStatus, ChannelUsageModel,
+    DiscrepancyType, FeeItemModel, FeeScheduleModel, FeeTierModel, ReconciliationDiscrepancyModel,
+    ReconciliationStatus,
+};
 use banking_db::repository::{ChannelRepository, ChannelStats};
 use banking_db::ChannelType;
+use chrono::{DateTime, Utc};
 use sqlx::{PgPool, Row};
 use uuid::Uuid;
 use heapless::String as HeaplessString;
@@ -100,6 +105,244 @@ impl TryFromRow<sqlx::postgres::PgRow> for ChannelStats {
     }
 }
 
+impl TryFromRow<sqlx::postgres::PgRow> for ChannelLimitModel {
+    fn try_from_row(row: &sqlx::postgres::PgRow) -> BankingResult<Self> {
+        Ok(ChannelLimitModel {
+            id: row.get("id"),
+            channel_id: HeaplessString::try_from(
+                row.get::<String, _>("channel_id").as_str()
+            ).map_err(|_| BankingError::ValidationError {
+                field: "channel_id".to_string(),
+                message: "Channel code too long".to_string(),
+            })?,
+            currency: HeaplessString::try_from(
+                row.get::<String, _>("currency").as_str()
+            ).map_err(|_| BankingError::ValidationError {
+                field: "currency".to_string(),
+                message: "Currency code too long".to_string(),
+            })?,
+            per_transaction_max: row.get("per_transaction_max"),
+            daily_max: row.get("daily_max"),
+            monthly_max: row.get("monthly_max"),
+            max_count_per_day: row.get("max_count_per_day"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        })
+    }
+}
+
+const CHANNEL_LIMIT_COLUMNS: &str = r#"
+    id, channel_id, currency, per_transaction_max, daily_max, monthly_max, max_count_per_day, created_at, updated_at
+"#;
+
+impl TryFromRow<sqlx::postgres::PgRow> for FeeScheduleModel {
+    fn try_from_row(row: &sqlx::postgres::PgRow) -> BankingResult<Self> {
+        let too_long = |column: &str| BankingError::ValidationError {
+            field: column.to_string(),
+            message: format!("{column} field too long"),
+        };
+        Ok(FeeScheduleModel {
+            id: row.get("id"),
+            schedule_name: HeaplessString::try_from(row.get::<String, _>("schedule_name").as_str())
+                .map_err(|_| too_long("schedule_name"))?,
+            channel_id: row.get("channel_id"),
+            effective_date: row.get("effective_date"),
+            expiry_date: row.get("expiry_date"),
+            currency: HeaplessString::try_from(row.get::<String, _>("currency").as_str())
+                .map_err(|_| too_long("currency"))?,
+            fee01_fee_item_id: row.get("fee01_fee_item_id"),
+            fee02_fee_item_id: row.get("fee02_fee_item_id"),
+            fee03_fee_item_id: row.get("fee03_fee_item_id"),
+            fee04_fee_item_id: row.get("fee04_fee_item_id"),
+            fee05_fee_item_id: row.get("fee05_fee_item_id"),
+            is_active: row.get("is_active"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        })
+    }
+}
+
+impl TryFromRow<sqlx::postgres::PgRow> for FeeItemModel {
+    fn try_from_row(row: &sqlx::postgres::PgRow) -> BankingResult<Self> {
+        let too_long = |column: &str| BankingError::ValidationError {
+            field: column.to_string(),
+            message: format!("{column} field too long"),
+        };
+        let tier_ids: Vec<Uuid> = row.get("tier_ids");
+        if tier_ids.len() > 11 {
+            return Err(BankingError::ValidationError {
+                field: "tier_ids".to_string(),
+                message: format!("Fee item has {} tiers, at most 11 fit", tier_ids.len()),
+            });
+        }
+        let mut tiers = tier_ids.into_iter();
+        let mut next_tier = || tiers.next();
+        let mut transaction_types = row
+            .get::<Vec<String>, _>("applies_to_transaction_types")
+            .into_iter()
+            .map(|transaction_type| {
+                HeaplessString::try_from(transaction_type.as_str()).map_err(|_| too_long("applies_to_transaction_types"))
+            })
+            .collect::<BankingResult<Vec<_>>>()?
+            .into_iter();
+        let mut next_type = || transaction_types.next();
+        Ok(FeeItemModel {
+            id: row.get("id"),
+            schedule_id: row.get("schedule_id"),
+            fee_code: HeaplessString::try_from(row.get::<String, _>("fee_code").as_str())
+                .map_err(|_| too_long("fee_code"))?,
+            fee_name: HeaplessString::try_from(row.get::<String, _>("fee_name").as_str())
+                .map_err(|_| too_long("fee_name"))?,
+            fee_type: row.get("fee_type"),
+            calculation_method: row.get("calculation_method"),
+            fee_amount: row.get("fee_amount"),
+            fee_percentage: row.get("fee_percentage"),
+            minimum_fee: row.get("minimum_fee"),
+            maximum_fee: row.get("maximum_fee"),
+            tier01_channel_fee_tier_id: next_tier(),
+            tier02_channel_fee_tier_id: next_tier(),
+            tier03_channel_fee_tier_id: next_tier(),
+            tier04_channel_fee_tier_id: next_tier(),
+            tier05_channel_fee_tier_id: next_tier(),
+            tier06_channel_fee_tier_id: next_tier(),
+            tier07_channel_fee_tier_id: next_tier(),
+            tier08_channel_fee_tier_id: next_tier(),
+            tier09_channel_fee_tier_id: next_tier(),
+            tier10_channel_fee_tier_id: next_tier(),
+            tier11_channel_fee_tier_id: next_tier(),
+            applies_to_transaction_type_01: next_type(),
+            applies_to_transaction_type_02: next_type(),
+            applies_to_transaction_type_03: next_type(),
+            applies_to_transaction_type_04: next_type(),
+            applies_to_transaction_type_05: next_type(),
+            applies_to_transaction_type_06: next_type(),
+            applies_to_transaction_type_07: next_type(),
+            applies_to_transaction_type_08: next_type(),
+            applies_to_transaction_type_09: next_type(),
+            applies_to_transaction_type_10: next_type(),
+            applies_to_transaction_type_11: next_type(),
+            is_waivable: row.get("is_waivable"),
+            requires_approval_for_waiver: row.get("requires_approval_for_waiver"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
+
+impl TryFromRow<sqlx::postgres::PgRow> for FeeTierModel {
+    fn try_from_row(row: &sqlx::postgres::PgRow) -> BankingResult<Self> {
+        Ok(FeeTierModel {
+            id: row.get("id"),
+            fee_item_id: row.get("fee_item_id"),
+            tier_name: HeaplessString::try_from(row.get::<String, _>("tier_name").as_str())
+                .map_err(|_| BankingError::ValidationError {
+                    field: "tier_name".to_string(),
+                    message: "tier_name field too long".to_string(),
+                })?,
+            min_amount: row.get("min_amount"),
+            max_amount: row.get("max_amount"),
+            fee_amount: row.get("fee_amount"),
+            fee_percentage: row.get("fee_percentage"),
+            tier_order: row.get("tier_order"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
+
+impl TryFromRow<sqlx::postgres::PgRow> for ChannelReconciliationReportModel {
+    fn try_from_row(row: &sqlx::postgres::PgRow) -> BankingResult<Self> {
+        Ok(ChannelReconciliationReportModel {
+            id: row.get("id"),
+            channel_id: row.get("channel_id"),
+            reconciliation_date: row.get("reconciliation_date"),
+            total_transactions: row.get("total_transactions"),
+            total_amount: row.get("total_amount"),
+            status: row.get::<String, _>("status")
+                .parse::<ReconciliationStatus>()
+                .map_err(BankingError::Internal)?,
+            generated_at: row.get("generated_at"),
+            completed_at: row.get("completed_at"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
+
+impl TryFromRow<sqlx::postgres::PgRow> for ReconciliationDiscrepancyModel {
+    fn try_from_row(row: &sqlx::postgres::PgRow) -> BankingResult<Self> {
+        let too_long = |column: &str| BankingError::ValidationError {
+            field: column.to_string(),
+            message: format!("{column} field too long"),
+        };
+        Ok(ReconciliationDiscrepancyModel {
+            id: row.get("id"),
+            report_id: row.get("report_id"),
+            discrepancy_type: row.get::<String, _>("discrepancy_type")
+                .parse::<DiscrepancyType>()
+                .map_err(BankingError::Internal)?,
+            transaction_id: row.get("transaction_id"),
+            external_reference: row.get::<Option<String>, _>("external_reference")
+                .map(|reference| HeaplessString::try_from(reference.as_str()).map_err(|_| too_long("external_reference")))
+                .transpose()?,
+            description: HeaplessString::try_from(row.get::<String, _>("description").as_str())
+                .map_err(|_| too_long("description"))?,
+            expected_amount: row.get("expected_amount"),
+            actual_amount: row.get("actual_amount"),
+            difference: row.get("difference"),
+            resolved: row.get("resolved"),
+            resolution_notes: row.get::<Option<String>, _>("resolution_notes")
+                .map(|notes| HeaplessString::try_from(notes.as_str()).map_err(|_| too_long("resolution_notes")))
+                .transpose()?,
+            resolved_by: row.get("resolved_by"),
+            resolved_at: row.get("resolved_at"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
+
+/// Applies-to slots of a fee item, in order
+fn applies_to_transaction_types(item: &FeeItemModel) -> Vec<String> {
+    [
+        &item.applies_to_transaction_type_01, &item.applies_to_transaction_type_02,
+        &item.applies_to_transaction_type_03, &item.applies_to_transaction_type_04,
+        &item.applies_to_transaction_type_05, &item.applies_to_transaction_type_06,
+        &item.applies_to_transaction_type_07, &item.applies_to_transaction_type_08,
+        &item.applies_to_transaction_type_09, &item.applies_to_transaction_type_10,
+        &item.applies_to_transaction_type_11,
+    ]
+    .into_iter()
+    .flatten()
+    .map(|transaction_type| transaction_type.to_string())
+    .collect()
+}
+
+const FEE_SCHEDULE_COLUMNS: &str = r#"
+    id, schedule_name, channel_id, effective_date, expiry_date, currency,
+    fee01_fee_item_id, fee02_fee_item_id, fee03_fee_item_id, fee04_fee_item_id, fee05_fee_item_id,
+    is_active, created_at, updated_at
+"#;
+
+// Tier slots are the item's tiers in tier order
+const FEE_ITEM_COLUMNS: &str = r#"
+    i.id, i.schedule_id, i.fee_code, i.fee_name, i.fee_type, i.calculation_method, i.fee_amount, i.fee_percentage,
+    i.minimum_fee, i.maximum_fee, i.applies_to_transaction_types, i.is_waivable, i.requires_approval_for_waiver,
+    i.created_at,
+    ARRAY(SELECT t.id FROM channel_fee_tiers t WHERE t.fee_item_id = i.id ORDER BY t.tier_order) AS tier_ids
+"#;
+
+const FEE_TIER_COLUMNS: &str = r#"
+    id, fee_item_id, tier_name, min_amount, max_amount, fee_amount, fee_percentage, tier_order, created_at
+"#;
+
+const RECONCILIATION_REPORT_COLUMNS: &str = r#"
+    id, channel_id, reconciliation_date, total_transactions, total_amount, status::text as status,
+    generated_at, completed_at, created_at
+"#;
+
+const DISCREPANCY_COLUMNS: &str = r#"
+    d.id, d.report_id, d.discrepancy_type::text as discrepancy_type, d.transaction_id, d.external_reference,
+    d.description, d.expected_amount, d.actual_amount, d.difference, d.resolved, d.resolution_notes,
+    d.resolved_by, d.resolved_at, d.created_at
+"#;
+
 #[async_trait]
 impl ChannelRepository for ChannelRepositoryImpl {
     async fn create(&self, channel: ChannelModel) -> BankingResult<ChannelModel> {
@@ -322,4 +565,412 @@ impl ChannelRepository for ChannelRepositoryImpl {
         
         Ok(count)
     }
-}
\ No newline at end of file
+
+    async fn upsert_limit(&self, limit: ChannelLimitModel) -> BankingResult<ChannelLimitModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO channel_limits (
+                id, channel_id, currency, per_transaction_max, daily_max, monthly_max, max_count_per_day,
+                created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (channel_id, currency) DO UPDATE
+            SET per_transaction_max = EXCLUDED.per_transaction_max, daily_max = EXCLUDED.daily_max,
+                monthly_max = EXCLUDED.monthly_max, max_count_per_day = EXCLUDED.max_count_per_day,
+                updated_at = EXCLUDED.updated_at
+            RETURNING {CHANNEL_LIMIT_COLUMNS}
+            "#
+        ))
+        .bind(limit.id)
+        .bind(limit.channel_id.as_str())
+        .bind(limit.currency.as_str())
+        .bind(limit.per_transaction_max)
+        .bind(limit.daily_max)
+        .bind(limit.monthly_max)
+        .bind(limit.max_count_per_day)
+        .bind(limit.created_at)
+        .bind(limit.updated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to save channel limit: {e}")))?;
+
+        ChannelLimitModel::try_from_row(&row)
+    }
+
+    async fn find_effective_limit(&self, channel_id: &str, currency: &str) -> BankingResult<Option<ChannelLimitModel>> {
+        let row = sqlx::query(&format!(
+            "SELECT {CHANNEL_LIMIT_COLUMNS} FROM channel_limits WHERE channel_id = $1 AND currency = $2"
+        ))
+        .bind(channel_id)
+        .bind(currency)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find channel limit: {e}")))?;
+
+        row.as_ref().map(ChannelLimitModel::try_from_row).transpose()
+    }
+
+    async fn get_owner_channel_usage(
+        &self,
+        account_id: Uuid,
+        channel_id: &str,
+        currency: &str,
+        month_start: DateTime<Utc>,
+        day_start: DateTime<Utc>,
+        day_end: DateTime<Utc>,
+    ) -> BankingResult<ChannelUsageModel> {
+        // The account itself is listed directly so usage counts before any ownership is recorded
+        let row = sqlx::query(
+            r#"
+            SELECT
+                COALESCE(SUM(t.amount) FILTER (WHERE t.transaction_date >= $5), 0) AS daily_amount,
+                COUNT(*) FILTER (WHERE t.transaction_date >= $5) AS daily_count,
+                COALESCE(SUM(t.amount), 0) AS monthly_amount
+            FROM transactions t
+            WHERE t.channel_id = $2
+              AND t.currency = $3
+              AND t.transaction_type::text = 'Debit'
+              AND t.status::text IN ('Posted', 'AwaitingApproval')
+              AND t.transaction_date >= $4
+              AND t.transaction_date < $6
+              AND t.account_id IN (
+                  SELECT $1::uuid
+                  UNION
+                  SELECT held.account_id
+                  FROM account_ownership owner
+                  JOIN account_ownership held ON held.customer_id = owner.customer_id
+                  WHERE owner.account_id = $1
+              )
+            "#
+        )
+        .bind(account_id)
+        .bind(channel_id)
+        .bind(currency)
+        .bind(month_start)
+        .bind(day_start)
+        .bind(day_end)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to aggregate channel usage: {e}")))?;
+
+        Ok(ChannelUsageModel {
+            daily_amount: row.get("daily_amount"),
+            daily_count: row.get("daily_count"),
+            monthly_amount: row.get("monthly_amount"),
+        })
+    }
+
+    async fn save_fee_schedule(&self, schedule: FeeScheduleModel) -> BankingResult<FeeScheduleModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO channel_fee_schedules (
+                id, schedule_name, channel_id, effective_date, expiry_date, currency,
+                fee01_fee_item_id, fee02_fee_item_id, fee03_fee_item_id, fee04_fee_item_id, fee05_fee_item_id,
+                is_active, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            ON CONFLICT (id) DO UPDATE
+            SET schedule_name = EXCLUDED.schedule_name, channel_id = EXCLUDED.channel_id,
+                effective_date = EXCLUDED.effective_date, expiry_date = EXCLUDED.expiry_date,
+                currency = EXCLUDED.currency, fee01_fee_item_id = EXCLUDED.fee01_fee_item_id,
+                fee02_fee_item_id = EXCLUDED.fee02_fee_item_id, fee03_fee_item_id = EXCLUDED.fee03_fee_item_id,
+                fee04_fee_item_id = EXCLUDED.fee04_fee_item_id, fee05_fee_item_id = EXCLUDED.fee05_fee_item_id,
+                is_active = EXCLUDED.is_active, updated_at = EXCLUDED.updated_at
+            RETURNING {FEE_SCHEDULE_COLUMNS}
+            "#
+        ))
+        .bind(schedule.id)
+        .bind(schedule.schedule_name.as_str())
+        .bind(schedule.channel_id)
+        .bind(schedule.effective_date)
+        .bind(schedule.expiry_date)
+        .bind(schedule.currency.as_str())
+        .bind(schedule.fee01_fee_item_id)
+        .bind(schedule.fee02_fee_item_id)
+        .bind(schedule.fee03_fee_item_id)
+        .bind(schedule.fee04_fee_item_id)
+        .bind(schedule.fee05_fee_item_id)
+        .bind(schedule.is_active)
+        .bind(schedule.created_at)
+        .bind(schedule.updated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to save fee schedule: {e}")))?;
+
+        FeeScheduleModel::try_from_row(&row)
+    }
+
+    async fn find_fee_schedule_by_id(&self, schedule_id: Uuid) -> BankingResult<Option<FeeScheduleModel>> {
+        let row = sqlx::query(&format!("SELECT {FEE_SCHEDULE_COLUMNS} FROM channel_fee_schedules WHERE id = $1"))
+            .bind(schedule_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find fee schedule: {e}")))?;
+
+        row.as_ref().map(FeeScheduleModel::try_from_row).transpose()
+    }
+
+    async fn save_fee_item(&self, item: FeeItemModel, tiers: Vec<FeeTierModel>) -> BankingResult<FeeItemModel> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| BankingError::Internal(format!("Failed to begin transaction: {e}")))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO channel_fee_items (
+                id, schedule_id, fee_code, fee_name, fee_type, calculation_method, fee_amount, fee_percentage,
+                minimum_fee, maximum_fee, applies_to_transaction_types, is_waivable, requires_approval_for_waiver,
+                created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            ON CONFLICT (id) DO UPDATE
+            SET schedule_id = EXCLUDED.schedule_id, fee_code = EXCLUDED.fee_code, fee_name = EXCLUDED.fee_name,
+                fee_type = EXCLUDED.fee_type, calculation_method = EXCLUDED.calculation_method,
+                fee_amount = EXCLUDED.fee_amount, fee_percentage = EXCLUDED.fee_percentage,
+                minimum_fee = EXCLUDED.minimum_fee, maximum_fee = EXCLUDED.maximum_fee,
+                applies_to_transaction_types = EXCLUDED.applies_to_transaction_types,
+                is_waivable = EXCLUDED.is_waivable,
+                requires_approval_for_waiver = EXCLUDED.requires_approval_for_waiver
+            "#,
+        )
+        .bind(item.id)
+        .bind(item.schedule_id)
+        .bind(item.fee_code.as_str())
+        .bind(item.fee_name.as_str())
+        .bind(&item.fee_type)
+        .bind(&item.calculation_method)
+        .bind(item.fee_amount)
+        .bind(item.fee_percentage)
+        .bind(item.minimum_fee)
+        .bind(item.maximum_fee)
+        .bind(applies_to_transaction_types(&item))
+        .bind(item.is_waivable)
+        .bind(item.requires_approval_for_waiver)
+        .bind(item.created_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to save fee item: {e}")))?;
+
+        sqlx::query("DELETE FROM channel_fee_tiers WHERE fee_item_id = $1")
+            .bind(item.id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to replace fee tiers: {e}")))?;
+
+        for tier in &tiers {
+            sqlx::query(
+                r#"
+                INSERT INTO channel_fee_tiers (
+                    id, fee_item_id, tier_name, min_amount, max_amount, fee_amount, fee_percentage, tier_order,
+                    created_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                "#,
+            )
+            .bind(tier.id)
+            .bind(item.id)
+            .bind(tier.tier_name.as_str())
+            .bind(tier.min_amount)
+            .bind(tier.max_amount)
+            .bind(tier.fee_amount)
+            .bind(tier.fee_percentage)
+            .bind(tier.tier_order)
+            .bind(tier.created_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to save fee tier: {e}")))?;
+        }
+
+        let row = sqlx::query(&format!("SELECT {FEE_ITEM_COLUMNS} FROM channel_fee_items i WHERE i.id = $1"))
+            .bind(item.id)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to save fee item: {e}")))?;
+        let saved = FeeItemModel::try_from_row(&row)?;
+
+        tx.commit().await
+            .map_err(|e| BankingError::Internal(format!("Failed to commit transaction: {e}")))?;
+        Ok(saved)
+    }
+
+    async fn find_fee_item_by_id(&self, fee_item_id: Uuid) -> BankingResult<Option<FeeItemModel>> {
+        let row = sqlx::query(&format!("SELECT {FEE_ITEM_COLUMNS} FROM channel_fee_items i WHERE i.id = $1"))
+            .bind(fee_item_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find fee item: {e}")))?;
+
+        row.as_ref().map(FeeItemModel::try_from_row).transpose()
+    }
+
+    async fn find_fee_items_by_schedule(&self, schedule_id: Uuid) -> BankingResult<Vec<FeeItemModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {FEE_ITEM_COLUMNS} FROM channel_fee_items i WHERE i.schedule_id = $1 ORDER BY i.fee_code"
+        ))
+        .bind(schedule_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find fee items: {e}")))?;
+
+        rows.iter().map(FeeItemModel::try_from_row).collect()
+    }
+
+    async fn find_fee_tiers(&self, fee_item_id: Uuid) -> BankingResult<Vec<FeeTierModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {FEE_TIER_COLUMNS} FROM channel_fee_tiers WHERE fee_item_id = $1 ORDER BY tier_order"
+        ))
+        .bind(fee_item_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find fee tiers: {e}")))?;
+
+        rows.iter().map(FeeTierModel::try_from_row).collect()
+    }
+
+    async fn create_reconciliation_report(
+        &self,
+        report: ChannelReconciliationReportModel,
+        discrepancies: Vec<ReconciliationDiscrepancyModel>,
+    ) -> BankingResult<ChannelReconciliationReportModel> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| BankingError::Internal(format!("Failed to begin transaction: {e}")))?;
+
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO channel_reconciliation_reports (
+                id, channel_id, reconciliation_date, total_transactions, total_amount, status,
+                generated_at, completed_at, created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6::reconciliation_status, $7, $8, $9)
+            RETURNING {RECONCILIATION_REPORT_COLUMNS}
+            "#
+        ))
+        .bind(report.id)
+        .bind(report.channel_id)
+        .bind(report.reconciliation_date)
+        .bind(report.total_transactions)
+        .bind(report.total_amount)
+        .bind(report.status.to_string())
+        .bind(report.generated_at)
+        .bind(report.completed_at)
+        .bind(report.created_at)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to save reconciliation report: {e}")))?;
+        let saved = ChannelReconciliationReportModel::try_from_row(&row)?;
+
+        for discrepancy in &discrepancies {
+            sqlx::query(
+                r#"
+                INSERT INTO reconciliation_discrepancies (
+                    id, report_id, discrepancy_type, transaction_id, external_reference, description,
+                    expected_amount, actual_amount, difference, resolved, resolution_notes, resolved_by,
+                    resolved_at, created_at
+                )
+                VALUES ($1, $2, $3::reconciliation_discrepancy_type, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+                "#,
+            )
+            .bind(discrepancy.id)
+            .bind(saved.id)
+            .bind(discrepancy.discrepancy_type.to_string())
+            .bind(discrepancy.transaction_id)
+            .bind(discrepancy.external_reference.as_ref().map(|r| r.as_str()))
+            .bind(discrepancy.description.as_str())
+            .bind(discrepancy.expected_amount)
+            .bind(discrepancy.actual_amount)
+            .bind(discrepancy.difference)
+            .bind(discrepancy.resolved)
+            .bind(discrepancy.resolution_notes.as_ref().map(|n| n.as_str()))
+            .bind(discrepancy.resolved_by)
+            .bind(discrepancy.resolved_at)
+            .bind(discrepancy.created_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to save reconciliation discrepancy: {e}")))?;
+        }
+
+        tx.commit().await
+            .map_err(|e| BankingError::Internal(format!("Failed to commit transaction: {e}")))?;
+        Ok(saved)
+    }
+
+    async fn find_reconciliation_report_by_id(&self, report_id: Uuid) -> BankingResult<Option<ChannelReconciliationReportModel>> {
+        let row = sqlx::query(&format!(
+            "SELECT {RECONCILIATION_REPORT_COLUMNS} FROM channel_reconciliation_reports WHERE id = $1"
+        ))
+        .bind(report_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find reconciliation report: {e}")))?;
+
+        row.as_ref().map(ChannelReconciliationReportModel::try_from_row).transpose()
+    }
+
+    async fn find_unresolved_discrepancies(&self, channel_id: Uuid) -> BankingResult<Vec<ReconciliationDiscrepancyModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {DISCREPANCY_COLUMNS}
+            FROM reconciliation_discrepancies d
+            JOIN channel_reconciliation_reports r ON r.id = d.report_id
+            WHERE r.channel_id = $1 AND NOT d.resolved
+            ORDER BY r.reconciliation_date, d.created_at, d.id
+            "#
+        ))
+        .bind(channel_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find unresolved discrepancies: {e}")))?;
+
+        rows.iter().map(ReconciliationDiscrepancyModel::try_from_row).collect()
+    }
+
+    async fn resolve_discrepancy(
+        &self,
+        discrepancy_id: Uuid,
+        resolution_notes: &str,
+        resolved_by: Uuid,
+        resolved_at: DateTime<Utc>,
+    ) -> BankingResult<Option<ReconciliationDiscrepancyModel>> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| BankingError::Internal(format!("Failed to begin transaction: {e}")))?;
+
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE reconciliation_discrepancies d
+            SET resolved = TRUE, resolution_notes = $2, resolved_by = $3, resolved_at = $4
+            WHERE d.id = $1 AND NOT d.resolved
+            RETURNING {DISCREPANCY_COLUMNS}
+            "#
+        ))
+        .bind(discrepancy_id)
+        .bind(resolution_notes)
+        .bind(resolved_by)
+        .bind(resolved_at)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to resolve discrepancy: {e}")))?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let resolved = ReconciliationDiscrepancyModel::try_from_row(&row)?;
+
+        sqlx::query(
+            r#"
+            UPDATE channel_reconciliation_reports
+            SET status = 'Completed', completed_at = $2
+            WHERE id = $1
+              AND NOT EXISTS (
+                  SELECT 1 FROM reconciliation_discrepancies WHERE report_id = $1 AND NOT resolved
+              )
+            "#,
+        )
+        .bind(resolved.report_id)
+        .bind(resolved_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to complete reconciliation report: {e}")))?;
+
+        tx.commit().await
+            .map_err(|e| BankingError::Internal(format!("Failed to commit transaction: {e}")))?;
+        Ok(Some(resolved))
+    }
+}
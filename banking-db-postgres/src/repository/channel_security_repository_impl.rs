@@ -0,0 +1,242 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{
+    ChannelRestrictionModel, ChannelSecurityEventModel, DbChannelSecurityEventType, Severity,
+};
+use banking_db::repository::ChannelSecurityRepository;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of ChannelSecurityRepository
+pub struct ChannelSecurityRepositoryImpl {
+    pool: PgPool,
+}
+
+impl ChannelSecurityRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+fn heapless<const N: usize>(value: Option<String>, field: &str) -> BankingResult<Option<HeaplessString<N>>> {
+    value
+        .map(|v| {
+            HeaplessString::try_from(v.as_str()).map_err(|_| BankingError::ValidationError {
+                field: field.to_string(),
+                message: format!("{field} too long"),
+            })
+        })
+        .transpose()
+}
+
+impl TryFromRow<PgRow> for ChannelSecurityEventModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(ChannelSecurityEventModel {
+            id: row.get("id"),
+            channel_id: row.get("channel_id"),
+            customer_id: row.get("customer_id"),
+            event_type: row.get::<String, _>("event_type")
+                .parse::<DbChannelSecurityEventType>()
+                .map_err(|_| BankingError::Internal("Invalid channel security event type".to_string()))?,
+            severity: row.get::<String, _>("severity")
+                .parse::<Severity>()
+                .map_err(|_| BankingError::Internal("Invalid severity".to_string()))?,
+            context: row.get("context"),
+            occurred_at: row.get("occurred_at"),
+            recorded_at: row.get("recorded_at"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for ChannelRestrictionModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(ChannelRestrictionModel {
+            id: row.get("id"),
+            customer_id: row.get("customer_id"),
+            channel_id: row.get("channel_id"),
+            reason: heapless(Some(row.get("reason")), "reason")?.unwrap_or_default(),
+            rule_code: heapless(row.get("rule_code"), "rule_code")?,
+            starts_at: row.get("starts_at"),
+            expires_at: row.get("expires_at"),
+            lifted_at: row.get("lifted_at"),
+            lifted_by_person_id: row.get("lifted_by_person_id"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
+
+const EVENT_COLUMNS: &str = r#"
+    id, channel_id, customer_id, event_type::text as event_type, severity::text as severity,
+    context::text as context, occurred_at, recorded_at
+"#;
+
+const RESTRICTION_COLUMNS: &str = r#"
+    id, customer_id, channel_id, reason, rule_code, starts_at, expires_at, lifted_at,
+    lifted_by_person_id, created_at
+"#;
+
+#[async_trait]
+impl ChannelSecurityRepository for ChannelSecurityRepositoryImpl {
+    async fn create_event(&self, event: ChannelSecurityEventModel) -> BankingResult<ChannelSecurityEventModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO channel_security_events (
+                id, channel_id, customer_id, event_type, severity, context, occurred_at, recorded_at
+            )
+            VALUES ($1, $2, $3, $4::channel_security_event_type, $5::severity, $6::jsonb, $7, $8)
+            RETURNING {EVENT_COLUMNS}
+            "#
+        ))
+        .bind(event.id)
+        .bind(event.channel_id)
+        .bind(event.customer_id)
+        .bind(event.event_type)
+        .bind(event.severity)
+        .bind(event.context.as_deref())
+        .bind(event.occurred_at)
+        .bind(event.recorded_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create channel security event: {e}")))?;
+
+        ChannelSecurityEventModel::try_from_row(&row)
+    }
+
+    async fn count_customer_events(
+        &self,
+        customer_id: Uuid,
+        event_type: DbChannelSecurityEventType,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> BankingResult<u64> {
+        let count: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM channel_security_events
+            WHERE customer_id = $1 AND event_type = $2::channel_security_event_type
+              AND occurred_at > $3 AND occurred_at <= $4
+            "#,
+        )
+        .bind(customer_id)
+        .bind(event_type)
+        .bind(from)
+        .bind(to)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to count channel security events: {e}")))?;
+
+        Ok(count as u64)
+    }
+
+    async fn find_recent_events_for_customer(&self, customer_id: Uuid, limit: i64) -> BankingResult<Vec<ChannelSecurityEventModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {EVENT_COLUMNS} FROM channel_security_events
+            WHERE customer_id = $1
+            ORDER BY occurred_at DESC, id
+            LIMIT $2
+            "#
+        ))
+        .bind(customer_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find channel security events: {e}")))?;
+
+        rows.iter().map(ChannelSecurityEventModel::try_from_row).collect()
+    }
+
+    async fn delete_events_before(&self, cutoff: DateTime<Utc>) -> BankingResult<u64> {
+        let result = sqlx::query("DELETE FROM channel_security_events WHERE occurred_at < $1")
+            .bind(cutoff)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to purge channel security events: {e}")))?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn create_restriction(&self, restriction: ChannelRestrictionModel) -> BankingResult<ChannelRestrictionModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO channel_restrictions (
+                id, customer_id, channel_id, reason, rule_code, starts_at, expires_at, lifted_at,
+                lifted_by_person_id, created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING {RESTRICTION_COLUMNS}
+            "#
+        ))
+        .bind(restriction.id)
+        .bind(restriction.customer_id)
+        .bind(restriction.channel_id)
+        .bind(restriction.reason.as_str())
+        .bind(restriction.rule_code.as_ref().map(|c| c.as_str()))
+        .bind(restriction.starts_at)
+        .bind(restriction.expires_at)
+        .bind(restriction.lifted_at)
+        .bind(restriction.lifted_by_person_id)
+        .bind(restriction.created_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create channel restriction: {e}")))?;
+
+        ChannelRestrictionModel::try_from_row(&row)
+    }
+
+    async fn find_restriction_by_id(&self, restriction_id: Uuid) -> BankingResult<Option<ChannelRestrictionModel>> {
+        let row = sqlx::query(&format!("SELECT {RESTRICTION_COLUMNS} FROM channel_restrictions WHERE id = $1"))
+            .bind(restriction_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find channel restriction: {e}")))?;
+
+        row.as_ref().map(ChannelRestrictionModel::try_from_row).transpose()
+    }
+
+    async fn update_restriction(&self, restriction: ChannelRestrictionModel) -> BankingResult<ChannelRestrictionModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE channel_restrictions
+            SET expires_at = $2, lifted_at = $3, lifted_by_person_id = $4
+            WHERE id = $1
+            RETURNING {RESTRICTION_COLUMNS}
+            "#
+        ))
+        .bind(restriction.id)
+        .bind(restriction.expires_at)
+        .bind(restriction.lifted_at)
+        .bind(restriction.lifted_by_person_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update channel restriction: {e}")))?
+        .ok_or_else(|| BankingError::NotFound(format!("Channel restriction {} not found", restriction.id)))?;
+
+        ChannelRestrictionModel::try_from_row(&row)
+    }
+
+    async fn find_active_restrictions(&self, customer_id: Uuid, as_of: DateTime<Utc>) -> BankingResult<Vec<ChannelRestrictionModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {RESTRICTION_COLUMNS} FROM channel_restrictions
+            WHERE customer_id = $1 AND lifted_at IS NULL AND starts_at <= $2
+              AND (expires_at IS NULL OR expires_at > $2)
+            ORDER BY starts_at
+            "#
+        ))
+        .bind(customer_id)
+        .bind(as_of)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find active channel restrictions: {e}")))?;
+
+        rows.iter().map(ChannelRestrictionModel::try_from_row).collect()
+    }
+}
@@ -0,0 +1,277 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{
+    ChequeBookModel, ChequeModel, DbChequeBookStatus, DbChequeReturnReason, DbChequeStatus,
+};
+use banking_db::repository::ChequeRepository;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of ChequeRepository
+pub struct ChequeRepositoryImpl {
+    pool: PgPool,
+}
+
+impl ChequeRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+impl TryFromRow<PgRow> for ChequeBookModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(ChequeBookModel {
+            id: row.get("id"),
+            account_id: row.get("account_id"),
+            first_serial_number: row.get("first_serial_number"),
+            last_serial_number: row.get("last_serial_number"),
+            status: row.get::<String, _>("status").parse::<DbChequeBookStatus>()
+                .map_err(|_| BankingError::Internal("Invalid cheque book status".to_string()))?,
+            requested_at: row.get("requested_at"),
+            requested_by_person_id: row.get("requested_by_person_id"),
+            issued_at: row.get("issued_at"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for ChequeModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(ChequeModel {
+            id: row.get("id"),
+            cheque_book_id: row.get("cheque_book_id"),
+            account_id: row.get("account_id"),
+            serial_number: row.get("serial_number"),
+            status: row.get::<String, _>("status").parse::<DbChequeStatus>()
+                .map_err(|_| BankingError::Internal("Invalid cheque status".to_string()))?,
+            amount: row.get("amount"),
+            presented_at: row.get("presented_at"),
+            paid_at: row.get("paid_at"),
+            transaction_id: row.get("transaction_id"),
+            return_reason: row.get::<Option<String>, _>("return_reason")
+                .map(|s| s.parse::<DbChequeReturnReason>())
+                .transpose()
+                .map_err(|_| BankingError::Internal("Invalid cheque return reason".to_string()))?,
+            stop_reason: row.get::<Option<String>, _>("stop_reason")
+                .map(|s| HeaplessString::try_from(s.as_str()))
+                .transpose()
+                .map_err(|_| BankingError::ValidationError {
+                    field: "stop_reason".to_string(),
+                    message: "Stop reason too long".to_string(),
+                })?,
+            stopped_at: row.get("stopped_at"),
+            stopped_by_person_id: row.get("stopped_by_person_id"),
+            last_updated_at: row.get("last_updated_at"),
+        })
+    }
+}
+
+const CHEQUE_COLUMNS: &str = r#"
+    id, cheque_book_id, account_id, serial_number, status::text as status, amount,
+    presented_at, paid_at, transaction_id, return_reason::text as return_reason,
+    stop_reason, stopped_at, stopped_by_person_id, last_updated_at
+"#;
+
+const CHEQUE_BOOK_COLUMNS: &str = r#"
+    id, account_id, first_serial_number, last_serial_number, status::text as status,
+    requested_at, requested_by_person_id, issued_at
+"#;
+
+#[async_trait]
+impl ChequeRepository for ChequeRepositoryImpl {
+    async fn reserve_serial_range(&self, account_id: Uuid, count: i64) -> BankingResult<i64> {
+        // The upsert row lock serialises concurrent reservations for the same account
+        let first: i64 = sqlx::query_scalar(
+            r#"
+            INSERT INTO sequence_counters (sequence_name, next_value)
+            VALUES ($1, 1 + $2)
+            ON CONFLICT (sequence_name)
+            DO UPDATE SET next_value = sequence_counters.next_value + $2
+            RETURNING next_value - $2
+            "#,
+        )
+        .bind(format!("cheque_serial:{account_id}"))
+        .bind(count)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to reserve cheque serial range: {e}")))?;
+
+        Ok(first)
+    }
+
+    async fn create_cheque_book(&self, book: ChequeBookModel, cheques: Vec<ChequeModel>) -> BankingResult<ChequeBookModel> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| BankingError::Internal(format!("Failed to begin transaction: {e}")))?;
+
+        let result = sqlx::query(&format!(
+            r#"
+            INSERT INTO cheque_books (
+                id, account_id, first_serial_number, last_serial_number, status,
+                requested_at, requested_by_person_id, issued_at
+            )
+            VALUES ($1, $2, $3, $4, $5::cheque_book_status, $6, $7, $8)
+            RETURNING {CHEQUE_BOOK_COLUMNS}
+            "#
+        ))
+        .bind(book.id)
+        .bind(book.account_id)
+        .bind(book.first_serial_number)
+        .bind(book.last_serial_number)
+        .bind(book.status)
+        .bind(book.requested_at)
+        .bind(book.requested_by_person_id)
+        .bind(book.issued_at)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create cheque book: {e}")))?;
+
+        let ids: Vec<Uuid> = cheques.iter().map(|c| c.id).collect();
+        let serials: Vec<i64> = cheques.iter().map(|c| c.serial_number).collect();
+        sqlx::query(
+            r#"
+            INSERT INTO cheques (id, cheque_book_id, account_id, serial_number, status, last_updated_at)
+            SELECT leaf.id, $2, $3, leaf.serial_number, 'Unused'::cheque_status, $4
+            FROM UNNEST($1::uuid[], $5::bigint[]) AS leaf(id, serial_number)
+            "#,
+        )
+        .bind(&ids)
+        .bind(book.id)
+        .bind(book.account_id)
+        .bind(book.requested_at)
+        .bind(&serials)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create cheque leaves: {e}")))?;
+
+        tx.commit().await
+            .map_err(|e| BankingError::Internal(format!("Failed to commit cheque book: {e}")))?;
+
+        ChequeBookModel::try_from_row(&result)
+    }
+
+    async fn find_cheque_book_by_id(&self, book_id: Uuid) -> BankingResult<Option<ChequeBookModel>> {
+        let result = sqlx::query(&format!("SELECT {CHEQUE_BOOK_COLUMNS} FROM cheque_books WHERE id = $1"))
+            .bind(book_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find cheque book: {e}")))?;
+
+        match result {
+            Some(row) => Ok(Some(ChequeBookModel::try_from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_cheque_books_by_account(&self, account_id: Uuid) -> BankingResult<Vec<ChequeBookModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {CHEQUE_BOOK_COLUMNS} FROM cheque_books WHERE account_id = $1 ORDER BY first_serial_number"
+        ))
+        .bind(account_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find cheque books: {e}")))?;
+
+        let mut books = Vec::new();
+        for row in rows {
+            books.push(ChequeBookModel::try_from_row(&row)?);
+        }
+        Ok(books)
+    }
+
+    async fn find_cheque(&self, account_id: Uuid, serial_number: i64) -> BankingResult<Option<ChequeModel>> {
+        let result = sqlx::query(&format!(
+            "SELECT {CHEQUE_COLUMNS} FROM cheques WHERE account_id = $1 AND serial_number = $2"
+        ))
+        .bind(account_id)
+        .bind(serial_number)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find cheque: {e}")))?;
+
+        match result {
+            Some(row) => Ok(Some(ChequeModel::try_from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn update_cheque(&self, cheque: ChequeModel) -> BankingResult<ChequeModel> {
+        let result = sqlx::query(&format!(
+            r#"
+            UPDATE cheques
+            SET status = $2::cheque_status, amount = $3, presented_at = $4, paid_at = $5,
+                transaction_id = $6, return_reason = $7::cheque_return_reason, stop_reason = $8,
+                stopped_at = $9, stopped_by_person_id = $10, last_updated_at = $11
+            WHERE id = $1
+            RETURNING {CHEQUE_COLUMNS}
+            "#
+        ))
+        .bind(cheque.id)
+        .bind(cheque.status)
+        .bind(cheque.amount)
+        .bind(cheque.presented_at)
+        .bind(cheque.paid_at)
+        .bind(cheque.transaction_id)
+        .bind(cheque.return_reason)
+        .bind(cheque.stop_reason.as_ref().map(|s| s.as_str()))
+        .bind(cheque.stopped_at)
+        .bind(cheque.stopped_by_person_id)
+        .bind(cheque.last_updated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update cheque: {e}")))?;
+
+        ChequeModel::try_from_row(&result)
+    }
+
+    async fn mark_presented(&self, cheque_id: Uuid, amount: Decimal, presented_at: DateTime<Utc>) -> BankingResult<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE cheques
+            SET status = 'Presented'::cheque_status, amount = $2, presented_at = $3,
+                return_reason = NULL, last_updated_at = $3
+            WHERE id = $1 AND status IN ('Unused'::cheque_status, 'Bounced'::cheque_status)
+            "#,
+        )
+        .bind(cheque_id)
+        .bind(amount)
+        .bind(presented_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to mark cheque presented: {e}")))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn find_outstanding_cheques(&self, account_id: Uuid) -> BankingResult<Vec<ChequeModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {CHEQUE_COLUMNS}
+            FROM cheques
+            WHERE account_id = $1
+              AND status = 'Unused'::cheque_status
+              AND cheque_book_id IN (
+                  SELECT id FROM cheque_books WHERE status = 'Issued'::cheque_book_status
+              )
+            ORDER BY serial_number
+            "#
+        ))
+        .bind(account_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find outstanding cheques: {e}")))?;
+
+        let mut cheques = Vec::new();
+        for row in rows {
+            cheques.push(ChequeModel::try_from_row(&row)?);
+        }
+        Ok(cheques)
+    }
+}
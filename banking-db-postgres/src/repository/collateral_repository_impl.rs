@@ -1,5 +1,8 @@
 use async_trait::async_trait;
-use banking_db::models::{CollateralAlertType, CollateralEnforcementModel, CollateralModel, CollateralStatus};
+use banking_db::models::{
+    CollateralAlertModel, CollateralAlertType, CollateralEnforcementModel, CollateralizedLoanModel, CollateralModel,
+    CollateralStatus, CollateralValuationModel,
+};
 use banking_db::repository::CollateralRepository;
 use sqlx::{PgPool, Row};
 use uuid::Uuid;
@@ -99,6 +102,48 @@ impl TryFromRow<sqlx::postgres::PgRow> for CollateralEnforcementModel {
     }
 }
 
+impl TryFromRow<sqlx::postgres::PgRow> for CollateralValuationModel {
+    fn try_from_row(row: &sqlx::postgres::PgRow) -> Result<Self, String> {
+        Ok(CollateralValuationModel {
+            id: row.get("id"),
+            collateral_id: row.get("collateral_id"),
+            valuation_date: row.get("valuation_date"),
+            valuation_method: row.get("valuation_method"),
+            market_value: row.get("market_value"),
+            currency: HeaplessString::try_from(row.get::<String, _>("currency").as_str()).map_err(|_| "Currency too long".to_string())?,
+            forced_sale_value: row.get("forced_sale_value"),
+            valuer_person_id: row.get("valuer_person_id"),
+            appraiser_name: HeaplessString::try_from(row.get::<String, _>("appraiser_name").as_str()).map_err(|_| "Appraiser name too long".to_string())?,
+            appraiser_license: row.get::<Option<String>, _>("appraiser_license").and_then(|s| HeaplessString::try_from(s.as_str()).ok()),
+            valuation_report_reference: HeaplessString::try_from(row.get::<String, _>("valuation_report_reference").as_str()).map_err(|_| "Valuation report reference too long".to_string())?,
+            validity_period_months: row.get("validity_period_months"),
+            next_valuation_due: row.get("next_valuation_due"),
+            valuation_notes: row.get::<Option<String>, _>("valuation_notes").and_then(|s| HeaplessString::try_from(s.as_str()).ok()),
+            created_at: row.get("created_at"),
+            created_by_person_id: row.get("created_by_person_id"),
+        })
+    }
+}
+
+impl TryFromRow<sqlx::postgres::PgRow> for CollateralAlertModel {
+    fn try_from_row(row: &sqlx::postgres::PgRow) -> Result<Self, String> {
+        Ok(CollateralAlertModel {
+            id: row.get("id"),
+            collateral_id: row.get("collateral_id"),
+            alert_type: row.get("alert_type"),
+            severity: row.get("severity"),
+            message: HeaplessString::try_from(row.get::<String, _>("message").as_str()).map_err(|_| "Message too long".to_string())?,
+            trigger_date: row.get("trigger_date"),
+            due_date: row.get("due_date"),
+            status: row.get("status"),
+            assigned_to_person_id: row.get("assigned_to_person_id"),
+            resolution_notes: row.get::<Option<String>, _>("resolution_notes").and_then(|s| HeaplessString::try_from(s.as_str()).ok()),
+            resolved_at: row.get("resolved_at"),
+            resolved_by_person_id: row.get("resolved_by_person_id"),
+        })
+    }
+}
+
 #[async_trait]
 impl CollateralRepository for CollateralRepositoryImpl {
     async fn save_collateral(&self, collateral: &CollateralModel) -> Result<(), String> {
@@ -557,20 +602,123 @@ impl CollateralRepository for CollateralRepositoryImpl {
         Ok(collaterals)
     }
 
-    async fn save_valuation(&self, _collateral_id: Uuid, _valuation_data: String) -> Result<(), String> {
+    async fn save_valuation(&self, valuation: &CollateralValuationModel) -> Result<(), String> {
+        sqlx::query(
+            r#"
+            INSERT INTO collateral_valuations (
+                id, collateral_id, valuation_date, valuation_method, market_value, currency,
+                forced_sale_value, valuer_person_id, appraiser_name, appraiser_license,
+                valuation_report_reference, validity_period_months, next_valuation_due,
+                valuation_notes, created_at, created_by_person_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+            ON CONFLICT (id) DO UPDATE SET
+                valuation_date = EXCLUDED.valuation_date,
+                valuation_method = EXCLUDED.valuation_method,
+                market_value = EXCLUDED.market_value,
+                currency = EXCLUDED.currency,
+                forced_sale_value = EXCLUDED.forced_sale_value,
+                valuer_person_id = EXCLUDED.valuer_person_id,
+                appraiser_name = EXCLUDED.appraiser_name,
+                appraiser_license = EXCLUDED.appraiser_license,
+                valuation_report_reference = EXCLUDED.valuation_report_reference,
+                validity_period_months = EXCLUDED.validity_period_months,
+                next_valuation_due = EXCLUDED.next_valuation_due,
+                valuation_notes = EXCLUDED.valuation_notes
+            "#
+        )
+        .bind(valuation.id)
+        .bind(valuation.collateral_id)
+        .bind(valuation.valuation_date)
+        .bind(valuation.valuation_method)
+        .bind(valuation.market_value)
+        .bind(valuation.currency.as_str())
+        .bind(valuation.forced_sale_value)
+        .bind(valuation.valuer_person_id)
+        .bind(valuation.appraiser_name.as_str())
+        .bind(valuation.appraiser_license.as_ref().map(|s| s.as_str()))
+        .bind(valuation.valuation_report_reference.as_str())
+        .bind(valuation.validity_period_months)
+        .bind(valuation.next_valuation_due)
+        .bind(valuation.valuation_notes.as_ref().map(|s| s.as_str()))
+        .bind(valuation.created_at)
+        .bind(valuation.created_by_person_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to save valuation: {e}"))?;
+
         Ok(())
     }
 
-    async fn find_valuations_by_collateral(&self, _collateral_id: Uuid) -> Result<Vec<String>, String> {
-        Ok(Vec::new())
+    async fn find_valuation_by_id(&self, valuation_id: Uuid) -> Result<Option<CollateralValuationModel>, String> {
+        let result = sqlx::query("SELECT * FROM collateral_valuations WHERE id = $1")
+            .bind(valuation_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to find valuation: {e}"))?;
+
+        match result {
+            Some(row) => Ok(Some(CollateralValuationModel::try_from_row(&row)?)),
+            None => Ok(None),
+        }
     }
 
-    async fn find_latest_valuation(&self, _collateral_id: Uuid) -> Result<Option<String>, String> {
-        Ok(None)
+    async fn find_valuations_by_collateral(&self, collateral_id: Uuid) -> Result<Vec<CollateralValuationModel>, String> {
+        let results = sqlx::query(
+            "SELECT * FROM collateral_valuations WHERE collateral_id = $1 ORDER BY valuation_date DESC, created_at DESC"
+        )
+        .bind(collateral_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to find valuations by collateral: {e}"))?;
+
+        let mut valuations = Vec::new();
+        for row in results {
+            valuations.push(CollateralValuationModel::try_from_row(&row)?);
+        }
+        Ok(valuations)
     }
 
-    async fn find_valuations_by_date_range(&self, _from_date: NaiveDate, _to_date: NaiveDate) -> Result<Vec<String>, String> {
-        Ok(Vec::new())
+    async fn find_latest_valuation(&self, collateral_id: Uuid) -> Result<Option<CollateralValuationModel>, String> {
+        let result = sqlx::query(
+            "SELECT * FROM collateral_valuations WHERE collateral_id = $1 ORDER BY valuation_date DESC, created_at DESC LIMIT 1"
+        )
+        .bind(collateral_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to find latest valuation: {e}"))?;
+
+        match result {
+            Some(row) => Ok(Some(CollateralValuationModel::try_from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn delete_valuation(&self, valuation_id: Uuid) -> Result<(), String> {
+        sqlx::query("DELETE FROM collateral_valuations WHERE id = $1")
+            .bind(valuation_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to delete valuation: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn find_valuations_by_date_range(&self, from_date: NaiveDate, to_date: NaiveDate) -> Result<Vec<CollateralValuationModel>, String> {
+        let results = sqlx::query(
+            "SELECT * FROM collateral_valuations WHERE valuation_date BETWEEN $1 AND $2 ORDER BY valuation_date ASC"
+        )
+        .bind(from_date)
+        .bind(to_date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to find valuations by date range: {e}"))?;
+
+        let mut valuations = Vec::new();
+        for row in results {
+            valuations.push(CollateralValuationModel::try_from_row(&row)?);
+        }
+        Ok(valuations)
     }
 
     async fn save_pledge(&self, _collateral_id: Uuid, _pledge_data: String) -> Result<(), String> {
@@ -597,6 +745,36 @@ impl CollateralRepository for CollateralRepositoryImpl {
         Ok(())
     }
 
+    async fn find_collateralized_loans(&self) -> Result<Vec<CollateralizedLoanModel>, String> {
+        let results = sqlx::query(
+            r#"
+            SELECT p.loan_account_id, p.collateral_id, a.currency,
+                   COALESCE(a.outstanding_principal, 0) AS outstanding_principal
+            FROM collateral_pledges p
+            JOIN accounts a ON a.id = p.loan_account_id
+            WHERE p.status IN ('Active'::pledge_status, 'PartiallyReleased'::pledge_status)
+              AND a.account_status <> 'Closed'::account_status
+            ORDER BY p.loan_account_id, p.pledge_date
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to find collateralized loans: {e}"))?;
+
+        results
+            .iter()
+            .map(|row| {
+                Ok(CollateralizedLoanModel {
+                    loan_account_id: row.get("loan_account_id"),
+                    collateral_id: row.get("collateral_id"),
+                    currency: HeaplessString::try_from(row.get::<String, _>("currency").as_str())
+                        .map_err(|_| "Invalid loan currency".to_string())?,
+                    outstanding_principal: row.get("outstanding_principal"),
+                })
+            })
+            .collect()
+    }
+
     async fn update_pledged_amount(&self, _pledge_id: Uuid, _new_amount: Decimal, _updated_by_person_id: Uuid) -> Result<(), String> {
         Ok(())
     }
@@ -605,7 +783,38 @@ impl CollateralRepository for CollateralRepositoryImpl {
         Ok(Vec::new())
     }
 
-    async fn save_alert(&self, _collateral_id: Uuid, _alert_data: String) -> Result<(), String> {
+    async fn save_alert(&self, alert: &CollateralAlertModel) -> Result<(), String> {
+        sqlx::query(
+            r#"
+            INSERT INTO collateral_alerts (
+                id, collateral_id, alert_type, severity, message, trigger_date, due_date, status,
+                assigned_to_person_id, resolution_notes, resolved_at, resolved_by_person_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            ON CONFLICT (id) DO UPDATE SET
+                status = EXCLUDED.status,
+                assigned_to_person_id = EXCLUDED.assigned_to_person_id,
+                resolution_notes = EXCLUDED.resolution_notes,
+                resolved_at = EXCLUDED.resolved_at,
+                resolved_by_person_id = EXCLUDED.resolved_by_person_id
+            "#
+        )
+        .bind(alert.id)
+        .bind(alert.collateral_id)
+        .bind(alert.alert_type)
+        .bind(alert.severity)
+        .bind(alert.message.as_str())
+        .bind(alert.trigger_date)
+        .bind(alert.due_date)
+        .bind(alert.status)
+        .bind(alert.assigned_to_person_id)
+        .bind(alert.resolution_notes.as_ref().map(|s| s.as_str()))
+        .bind(alert.resolved_at)
+        .bind(alert.resolved_by_person_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to save alert: {e}"))?;
+
         Ok(())
     }
 
@@ -613,8 +822,18 @@ impl CollateralRepository for CollateralRepositoryImpl {
         Ok(None)
     }
 
-    async fn find_alerts_by_collateral(&self, _collateral_id: Uuid) -> Result<Vec<String>, String> {
-        Ok(Vec::new())
+    async fn find_alerts_by_collateral(&self, collateral_id: Uuid) -> Result<Vec<CollateralAlertModel>, String> {
+        let results = sqlx::query("SELECT * FROM collateral_alerts WHERE collateral_id = $1 ORDER BY trigger_date DESC")
+            .bind(collateral_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to find alerts by collateral: {e}"))?;
+
+        let mut alerts = Vec::new();
+        for row in results {
+            alerts.push(CollateralAlertModel::try_from_row(&row)?);
+        }
+        Ok(alerts)
     }
 
     async fn find_active_alerts(&self) -> Result<Vec<String>, String> {
@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use banking_api::{BankingResult, BankingError};
-use banking_db::models::{SanctionsScreeningModel, ComplianceAlertModel, ExtendedComplianceAlertModel, ComplianceRiskScoreModel, ComplianceResultModel, SarDataModel};
+use banking_db::models::{SanctionsScreeningModel, SanctionsMatchModel, ScreeningType, ComplianceAlertModel, ExtendedComplianceAlertModel, ComplianceRiskScoreModel, ComplianceResultModel, CustomerRiskFactorsModel, SarDataModel, SarStatus, UboVerificationResultModel};
 use banking_db::models::account::UltimateBeneficiaryModel;
 use banking_db::repository::compliance_repository::{
     ComplianceRepository, TransactionMonitoringResult, TransactionMonitoringRecord, 
@@ -9,7 +9,7 @@ use banking_db::repository::compliance_repository::{
 use banking_db::AlertType;
 use sqlx::{PgPool, Row};
 use uuid::Uuid;
-use chrono::{NaiveDate};
+use chrono::{DateTime, NaiveDate, Utc};
 use heapless::String as HeaplessString;
 
 pub struct ComplianceRepositoryImpl {
@@ -74,12 +74,177 @@ impl TryFromRow<sqlx::postgres::PgRow> for ExtendedComplianceAlertModel {
     }
 }
 
+impl TryFromRow<sqlx::postgres::PgRow> for ComplianceRiskScoreModel {
+    fn try_from_row(row: &sqlx::postgres::PgRow) -> BankingResult<Self> {
+        let too_long = |column: &str| BankingError::ValidationError {
+            field: column.to_string(),
+            message: format!("{column} field too long"),
+        };
+        Ok(ComplianceRiskScoreModel {
+            id: row.get("id"),
+            customer_id: row.get("customer_id"),
+            risk_score: row.get("risk_score"),
+            risk_category: HeaplessString::try_from(row.get::<String, _>("risk_category").as_str())
+                .map_err(|_| too_long("risk_category"))?,
+            calculation_method: HeaplessString::try_from(row.get::<String, _>("calculation_method").as_str())
+                .map_err(|_| too_long("calculation_method"))?,
+            factors_considered: HeaplessString::try_from(row.get::<String, _>("factors_considered").as_str())
+                .map_err(|_| too_long("factors_considered"))?,
+            calculated_at: row.get("calculated_at"),
+            calculated_by: HeaplessString::try_from(row.get::<String, _>("calculated_by").as_str())
+                .map_err(|_| too_long("calculated_by"))?,
+            valid_until: row.get("valid_until"),
+            notes: row
+                .get::<Option<String>, _>("notes")
+                .map(|notes| HeaplessString::try_from(notes.as_str()).map_err(|_| too_long("notes")))
+                .transpose()?,
+            created_at: row.get("created_at"),
+            last_updated_at: row.get("last_updated_at"),
+        })
+    }
+}
+
+impl TryFromRow<sqlx::postgres::PgRow> for SarDataModel {
+    fn try_from_row(row: &sqlx::postgres::PgRow) -> BankingResult<Self> {
+        let too_long = |column: &str| BankingError::ValidationError {
+            field: column.to_string(),
+            message: format!("{column} field too long"),
+        };
+        let mut supporting = row.get::<Vec<Uuid>, _>("supporting_transaction_ids").into_iter();
+        let mut next = || supporting.next();
+        Ok(SarDataModel {
+            id: row.get("id"),
+            customer_id: row.get("customer_id"),
+            reason_id: row.get("reason_id"),
+            additional_details: row
+                .get::<Option<String>, _>("additional_details")
+                .map(|details| HeaplessString::try_from(details.as_str()).map_err(|_| too_long("additional_details")))
+                .transpose()?,
+            supporting_transaction_id_01: next(),
+            supporting_transaction_id_02: next(),
+            supporting_transaction_id_03: next(),
+            supporting_transaction_id_04: next(),
+            supporting_transaction_id_05: next(),
+            supporting_transaction_id_06: next(),
+            supporting_transaction_id_07: next(),
+            supporting_transaction_id_08: next(),
+            supporting_transaction_id_09: next(),
+            supporting_transaction_id_10: next(),
+            supporting_transaction_id_11: next(),
+            supporting_transaction_id_12: next(),
+            supporting_transaction_id_13: next(),
+            supporting_transaction_id_14: next(),
+            supporting_transaction_id_15: next(),
+            supporting_transaction_id_16: next(),
+            supporting_transaction_id_17: next(),
+            supporting_transaction_id_18: next(),
+            supporting_transaction_id_19: next(),
+            narrative: HeaplessString::try_from(row.get::<String, _>("narrative").as_str())
+                .map_err(|_| too_long("narrative"))?,
+            customer_full_name: HeaplessString::try_from(row.get::<String, _>("customer_full_name").as_str())
+                .map_err(|_| too_long("customer_full_name"))?,
+            alert_ids: row.get("alert_ids"),
+            account_ids: row.get("account_ids"),
+            generated_at: row.get("generated_at"),
+            status: row.get("status"),
+            created_by_person_id: row.get("created_by_person_id"),
+            submitted_by_person_id: row.get("submitted_by_person_id"),
+            submitted_at: row.get("submitted_at"),
+            last_updated_at: row.get("last_updated_at"),
+        })
+    }
+}
+
+/// The numbered supporting transaction fields of a SAR, stored as one array
+fn supporting_transaction_ids(sar: &SarDataModel) -> Vec<Uuid> {
+    [
+        sar.supporting_transaction_id_01, sar.supporting_transaction_id_02, sar.supporting_transaction_id_03,
+        sar.supporting_transaction_id_04, sar.supporting_transaction_id_05, sar.supporting_transaction_id_06,
+        sar.supporting_transaction_id_07, sar.supporting_transaction_id_08, sar.supporting_transaction_id_09,
+        sar.supporting_transaction_id_10, sar.supporting_transaction_id_11, sar.supporting_transaction_id_12,
+        sar.supporting_transaction_id_13, sar.supporting_transaction_id_14, sar.supporting_transaction_id_15,
+        sar.supporting_transaction_id_16, sar.supporting_transaction_id_17, sar.supporting_transaction_id_18,
+        sar.supporting_transaction_id_19,
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+impl TryFromRow<sqlx::postgres::PgRow> for UboVerificationResultModel {
+    fn try_from_row(row: &sqlx::postgres::PgRow) -> BankingResult<Self> {
+        let mut link_ids = row.get::<Vec<Uuid>, _>("ubo_chain_link_ids").into_iter();
+        let mut next_link = || link_ids.next();
+        let mut updates = row
+            .get::<Vec<String>, _>("requires_update")
+            .into_iter()
+            .map(|update| {
+                HeaplessString::try_from(update.as_str()).map_err(|_| BankingError::ValidationError {
+                    field: "requires_update".to_string(),
+                    message: "requires_update entry too long".to_string(),
+                })
+            })
+            .collect::<BankingResult<Vec<_>>>()?
+            .into_iter();
+        let mut next_update = || updates.next();
+        Ok(UboVerificationResultModel {
+            corporate_customer_id: row.get("corporate_customer_id"),
+            ubo_chain_link_id_01: next_link(),
+            ubo_chain_link_id_02: next_link(),
+            ubo_chain_link_id_03: next_link(),
+            ubo_chain_link_id_04: next_link(),
+            ubo_chain_link_id_05: next_link(),
+            verification_complete: row.get("verification_complete"),
+            requires_update_01: next_update(),
+            requires_update_02: next_update(),
+            requires_update_03: next_update(),
+            requires_update_04: next_update(),
+            requires_update_05: next_update(),
+        })
+    }
+}
+
+const SAR_COLUMNS: &str = r#"
+    id, customer_id, reason_id, additional_details, supporting_transaction_ids, narrative,
+    customer_full_name, alert_ids, account_ids, generated_at, status, created_by_person_id,
+    submitted_by_person_id, submitted_at, last_updated_at
+"#;
+
+const RISK_SCORE_COLUMNS: &str = r#"
+    id, customer_id, risk_score, risk_category, calculation_method, factors_considered,
+    calculated_at, calculated_by, valid_until, notes, created_at, last_updated_at
+"#;
+
 #[async_trait]
 impl ComplianceRepository for ComplianceRepositoryImpl {
 
     /// Sanctions Screening Operations
     async fn create_sanctions_screening(&self, screening: SanctionsScreeningModel) -> BankingResult<SanctionsScreeningModel> {
-        // Return a placeholder for now - full implementation would require complex screening logic
+        sqlx::query(
+            r#"
+            INSERT INTO sanctions_screenings (
+                id, customer_id, screening_type, screening_date, screening_result, match_details,
+                risk_score, screening_provider, status, reviewed_by, review_notes, created_at, last_updated_at
+            )
+            VALUES ($1, $2, $3::screening_type, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            "#
+        )
+        .bind(screening.id)
+        .bind(screening.customer_id)
+        .bind(screening.screening_type)
+        .bind(screening.screening_date)
+        .bind(screening.screening_result.as_str())
+        .bind(screening.match_details.as_ref().map(|s| s.as_str()))
+        .bind(screening.risk_score)
+        .bind(screening.screening_provider.as_str())
+        .bind(screening.status.as_str())
+        .bind(screening.reviewed_by.as_ref().map(|s| s.as_str()))
+        .bind(screening.review_notes.as_ref().map(|s| s.as_str()))
+        .bind(screening.created_at)
+        .bind(screening.last_updated_at)
+        .execute(&self.pool)
+        .await?;
+
         Ok(screening)
     }
 
@@ -112,6 +277,52 @@ impl ComplianceRepository for ComplianceRepositoryImpl {
         Ok(Vec::new())
     }
 
+    async fn create_sanctions_matches(&self, screening_id: Uuid, matches: Vec<SanctionsMatchModel>) -> BankingResult<()> {
+        let ids: Vec<Uuid> = matches.iter().map(|_| Uuid::new_v4()).collect();
+        let names: Vec<String> = matches.iter().map(|m| m.matched_name.to_string()).collect();
+        let scores: Vec<rust_decimal::Decimal> = matches.iter().map(|m| m.confidence_score).collect();
+        let details: Vec<Option<String>> = matches.iter().map(|m| m.details.as_ref().map(|d| d.to_string())).collect();
+        let sources: Vec<String> = matches.iter().map(|m| m.list_source.to_string()).collect();
+
+        sqlx::query(
+            r#"
+            INSERT INTO sanctions_matches (id, screening_id, matched_name, confidence_score, details, list_source)
+            SELECT id, $2, matched_name, confidence_score, details, list_source
+            FROM UNNEST($1::uuid[], $3::varchar[], $4::decimal[], $5::varchar[], $6::varchar[])
+                AS m(id, matched_name, confidence_score, details, list_source)
+            "#
+        )
+        .bind(&ids)
+        .bind(screening_id)
+        .bind(&names)
+        .bind(&scores)
+        .bind(&details)
+        .bind(&sources)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_customers_screened_since(&self, customer_ids: &[Uuid], screening_type: ScreeningType, since: DateTime<Utc>) -> BankingResult<Vec<Uuid>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT DISTINCT customer_id
+            FROM sanctions_screenings
+            WHERE customer_id = ANY($1)
+              AND screening_type = $2::screening_type
+              AND screening_date >= $3
+            "#
+        )
+        .bind(customer_ids)
+        .bind(screening_type)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(|row| row.get("customer_id")).collect())
+    }
+
     /// Compliance Alert Operations
     async fn create_alert(&self, alert: ComplianceAlertModel) -> BankingResult<ComplianceAlertModel> {
         let extended_alert = alert.alert_data;
@@ -367,17 +578,95 @@ impl ComplianceRepository for ComplianceRepositoryImpl {
         Ok(())
     }
 
-    /// Risk Score Operations - Simplified implementations
+    async fn save_ubo_verification_result(&self, result: UboVerificationResultModel) -> BankingResult<UboVerificationResultModel> {
+        let link_ids: Vec<Uuid> = [
+            result.ubo_chain_link_id_01, result.ubo_chain_link_id_02, result.ubo_chain_link_id_03,
+            result.ubo_chain_link_id_04, result.ubo_chain_link_id_05,
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        let updates: Vec<&str> = [
+            &result.requires_update_01, &result.requires_update_02, &result.requires_update_03,
+            &result.requires_update_04, &result.requires_update_05,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|update| update.as_str())
+        .collect();
+
+        let row = sqlx::query(
+            r#"
+            INSERT INTO ubo_verification_results (
+                corporate_customer_id, ubo_chain_link_ids, verification_complete, requires_update, verified_at
+            )
+            VALUES ($1, $2, $3, $4, NOW())
+            ON CONFLICT (corporate_customer_id) DO UPDATE SET
+                ubo_chain_link_ids = EXCLUDED.ubo_chain_link_ids,
+                verification_complete = EXCLUDED.verification_complete,
+                requires_update = EXCLUDED.requires_update,
+                verified_at = EXCLUDED.verified_at
+            RETURNING corporate_customer_id, ubo_chain_link_ids, verification_complete, requires_update
+            "#
+        )
+        .bind(result.corporate_customer_id)
+        .bind(&link_ids)
+        .bind(result.verification_complete)
+        .bind(&updates)
+        .fetch_one(&self.pool)
+        .await?;
+
+        UboVerificationResultModel::try_from_row(&row)
+    }
+
+    /// Risk Score Operations
     async fn create_risk_score(&self, risk_score: ComplianceRiskScoreModel) -> BankingResult<ComplianceRiskScoreModel> {
-        Ok(risk_score)
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO compliance_risk_scores (
+                id, customer_id, risk_score, risk_category, calculation_method, factors_considered,
+                calculated_at, calculated_by, valid_until, notes, created_at, last_updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            RETURNING {RISK_SCORE_COLUMNS}
+            "#
+        ))
+        .bind(risk_score.id)
+        .bind(risk_score.customer_id)
+        .bind(risk_score.risk_score)
+        .bind(risk_score.risk_category.as_str())
+        .bind(risk_score.calculation_method.as_str())
+        .bind(risk_score.factors_considered.as_str())
+        .bind(risk_score.calculated_at)
+        .bind(risk_score.calculated_by.as_str())
+        .bind(risk_score.valid_until)
+        .bind(risk_score.notes.as_ref().map(|s| s.as_str()))
+        .bind(risk_score.created_at)
+        .bind(risk_score.last_updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        ComplianceRiskScoreModel::try_from_row(&row)
     }
 
     async fn update_risk_score(&self, risk_score: ComplianceRiskScoreModel) -> BankingResult<ComplianceRiskScoreModel> {
         Ok(risk_score)
     }
 
-    async fn find_risk_score_by_customer(&self, _customer_id: Uuid) -> BankingResult<Option<ComplianceRiskScoreModel>> {
-        Ok(None)
+    async fn find_risk_score_by_customer(&self, customer_id: Uuid) -> BankingResult<Option<ComplianceRiskScoreModel>> {
+        let row = sqlx::query(&format!(
+            r#"
+            SELECT {RISK_SCORE_COLUMNS} FROM compliance_risk_scores
+            WHERE customer_id = $1
+            ORDER BY calculated_at DESC, created_at DESC
+            LIMIT 1
+            "#
+        ))
+        .bind(customer_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(ComplianceRiskScoreModel::try_from_row).transpose()
     }
 
     async fn find_high_risk_customers(&self, _threshold_score: f64) -> BankingResult<Vec<ComplianceRiskScoreModel>> {
@@ -388,6 +677,102 @@ impl ComplianceRepository for ComplianceRepositoryImpl {
         Ok(Vec::new())
     }
 
+    async fn find_risk_score_history(&self, customer_id: Uuid) -> BankingResult<Vec<ComplianceRiskScoreModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {RISK_SCORE_COLUMNS} FROM compliance_risk_scores
+            WHERE customer_id = $1
+            ORDER BY calculated_at DESC, created_at DESC
+            "#
+        ))
+        .bind(customer_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(ComplianceRiskScoreModel::try_from_row).collect()
+    }
+
+    async fn find_risk_factors(&self, customer_id: Uuid, transactions_since: DateTime<Utc>) -> BankingResult<CustomerRiskFactorsModel> {
+        // The customer's person is the one referencing it in the Customer role;
+        // its address leads to the country through locality and subdivision.
+        // Deactivated person records are skipped so risk reflects current data
+        let row = sqlx::query(
+            r#"
+            WITH held AS (
+                SELECT a.id, a.product_id
+                FROM account_ownership o
+                JOIN accounts a ON a.id = o.account_id
+                WHERE o.customer_id = $1 AND a.account_status::text <> 'Closed'
+            )
+            SELECT
+                (
+                    SELECT c.iso2
+                    FROM entity_reference er
+                    JOIN person p ON p.id = er.person_id
+                    JOIN location l ON l.id = p.location_id
+                    JOIN locality lo ON lo.id = l.locality_id
+                    JOIN country_subdivision cs ON cs.id = lo.country_subdivision_id
+                    JOIN country c ON c.id = cs.country_id
+                    WHERE er.entity_role = 'Customer'::person_entity_type
+                      AND er.reference_external_id = $1::text
+                      AND er.deactivated_at IS NULL
+                      AND p.deactivated_at IS NULL
+                      AND l.deactivated_at IS NULL
+                      AND lo.deactivated_at IS NULL
+                    LIMIT 1
+                ) AS country_iso2,
+                COALESCE((SELECT array_agg(id ORDER BY id) FROM held), '{}') AS account_ids,
+                COALESCE((SELECT array_agg(DISTINCT product_id) FROM held), '{}') AS product_ids,
+                (
+                    SELECT COUNT(*) FROM transactions t
+                    WHERE t.account_id IN (SELECT id FROM held)
+                      AND t.status::text IN ('Posted', 'Reversed')
+                      AND t.transaction_date >= $2
+                ) AS transaction_count,
+                (SELECT COUNT(*) FROM compliance_alerts WHERE customer_id = $1) AS alert_count
+            "#
+        )
+        .bind(customer_id)
+        .bind(transactions_since)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(CustomerRiskFactorsModel {
+            customer_id,
+            country_iso2: row
+                .get::<Option<String>, _>("country_iso2")
+                .map(|iso2| HeaplessString::try_from(iso2.as_str()))
+                .transpose()
+                .map_err(|_| BankingError::Internal("Country code too long".to_string()))?,
+            account_ids: row.get("account_ids"),
+            product_ids: row.get("product_ids"),
+            transaction_count: row.get("transaction_count"),
+            alert_count: row.get("alert_count"),
+        })
+    }
+
+    async fn find_customers_due_for_risk_review(&self, calculated_before: DateTime<Utc>) -> BankingResult<Vec<Uuid>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT c.id
+            FROM customers c
+            LEFT JOIN LATERAL (
+                SELECT MAX(calculated_at) AS calculated_at
+                FROM compliance_risk_scores
+                WHERE customer_id = c.id
+            ) latest ON TRUE
+            WHERE c.status::text = 'Active'
+              AND (latest.calculated_at IS NULL OR latest.calculated_at < $1)
+            ORDER BY latest.calculated_at NULLS FIRST, c.id
+            "#
+        )
+        .bind(calculated_before)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(|row| row.get("id")).collect())
+    }
+
     /// Compliance Result Operations - Simplified implementations
     async fn create_compliance_result(&self, result: ComplianceResultModel) -> BankingResult<ComplianceResultModel> {
         Ok(result)
@@ -409,29 +794,154 @@ impl ComplianceRepository for ComplianceRepositoryImpl {
         Ok(Vec::new())
     }
 
-    /// SAR Operations - Simplified implementations
+    /// SAR Operations
     async fn create_sar_data(&self, sar: SarDataModel) -> BankingResult<SarDataModel> {
-        Ok(sar)
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO sar_data (
+                id, customer_id, reason_id, additional_details, supporting_transaction_ids, narrative,
+                customer_full_name, alert_ids, account_ids, generated_at, status, created_by_person_id,
+                submitted_by_person_id, submitted_at, last_updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11::sar_status, $12, $13, $14, $15)
+            RETURNING {SAR_COLUMNS}
+            "#
+        ))
+        .bind(sar.id)
+        .bind(sar.customer_id)
+        .bind(sar.reason_id)
+        .bind(sar.additional_details.as_ref().map(|s| s.as_str()))
+        .bind(supporting_transaction_ids(&sar))
+        .bind(sar.narrative.as_str())
+        .bind(sar.customer_full_name.as_str())
+        .bind(&sar.alert_ids)
+        .bind(&sar.account_ids)
+        .bind(sar.generated_at)
+        .bind(sar.status)
+        .bind(sar.created_by_person_id)
+        .bind(sar.submitted_by_person_id)
+        .bind(sar.submitted_at)
+        .bind(sar.last_updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        SarDataModel::try_from_row(&row)
     }
 
-    async fn find_sar_by_id(&self, _sar_id: Uuid) -> BankingResult<Option<SarDataModel>> {
-        Ok(None)
+    async fn find_sar_by_id(&self, sar_id: Uuid) -> BankingResult<Option<SarDataModel>> {
+        let row = sqlx::query(&format!("SELECT {SAR_COLUMNS} FROM sar_data WHERE id = $1"))
+            .bind(sar_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.as_ref().map(SarDataModel::try_from_row).transpose()
     }
 
-    async fn find_sar_by_customer(&self, _customer_id: Uuid) -> BankingResult<Vec<SarDataModel>> {
-        Ok(Vec::new())
+    async fn find_sar_by_customer(&self, customer_id: Uuid) -> BankingResult<Vec<SarDataModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {SAR_COLUMNS} FROM sar_data WHERE customer_id = $1 ORDER BY generated_at DESC"
+        ))
+        .bind(customer_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(SarDataModel::try_from_row).collect()
     }
 
-    async fn find_sar_by_status(&self, _status: &str) -> BankingResult<Vec<SarDataModel>> {
-        Ok(Vec::new())
+    async fn find_sar_by_status(&self, status: &str) -> BankingResult<Vec<SarDataModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {SAR_COLUMNS} FROM sar_data WHERE status::text = $1 ORDER BY generated_at DESC"
+        ))
+        .bind(status)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(SarDataModel::try_from_row).collect()
     }
 
-    async fn update_sar_status(&self, _sar_id: Uuid, _status: &str, _updated_by_person_id: &str) -> BankingResult<()> {
+    async fn update_sar_status(&self, sar_id: Uuid, status: &str, _updated_by_person_id: &str) -> BankingResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE sar_data SET
+                status = $2::sar_status,
+                last_updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1
+            "#
+        )
+        .bind(sar_id)
+        .bind(status)
+        .execute(&self.pool)
+        .await?;
+
         Ok(())
     }
 
     async fn find_pending_sar_filings(&self) -> BankingResult<Vec<SarDataModel>> {
-        Ok(Vec::new())
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {SAR_COLUMNS} FROM sar_data
+            WHERE status::text IN ('Submitted', 'UnderReview')
+            ORDER BY submitted_at
+            "#
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(SarDataModel::try_from_row).collect()
+    }
+
+    async fn update_draft_sar(&self, sar: SarDataModel) -> BankingResult<SarDataModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE sar_data SET
+                additional_details = $2,
+                supporting_transaction_ids = $3,
+                narrative = $4,
+                alert_ids = $5,
+                account_ids = $6,
+                status = $7::sar_status,
+                submitted_by_person_id = $8,
+                submitted_at = $9,
+                last_updated_at = $10
+            WHERE id = $1 AND status::text = 'Draft'
+            RETURNING {SAR_COLUMNS}
+            "#
+        ))
+        .bind(sar.id)
+        .bind(sar.additional_details.as_ref().map(|s| s.as_str()))
+        .bind(supporting_transaction_ids(&sar))
+        .bind(sar.narrative.as_str())
+        .bind(&sar.alert_ids)
+        .bind(&sar.account_ids)
+        .bind(sar.status)
+        .bind(sar.submitted_by_person_id)
+        .bind(sar.submitted_at)
+        .bind(sar.last_updated_at)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(row) => SarDataModel::try_from_row(&row),
+            None => Err(BankingError::NotFound(format!("Draft SAR {} not found", sar.id))),
+        }
+    }
+
+    async fn find_sars(&self, status: Option<SarStatus>, from: DateTime<Utc>, to: DateTime<Utc>) -> BankingResult<Vec<SarDataModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {SAR_COLUMNS} FROM sar_data
+            WHERE ($1::sar_status IS NULL OR status = $1::sar_status)
+              AND generated_at >= $2 AND generated_at < $3
+            ORDER BY generated_at DESC
+            "#
+        ))
+        .bind(status)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(SarDataModel::try_from_row).collect()
     }
 
     /// Transaction Monitoring Operations - Simplified implementations
@@ -1,14 +1,32 @@
 use async_trait::async_trait;
-use banking_api::{BankingResult, BankingError};
+use banking_api::{domain::CustomerMergeBlocker, BankingResult, BankingError};
 use banking_db::models::{
-    CustomerModel, CustomerPortfolioModel, CustomerDocumentModel, CustomerAuditModel
+    CustomerModel, CustomerPortfolioModel, CustomerDocumentModel, CustomerAuditModel, CustomerSearchCriteriaModel,
+    DbTaggableEntityKind,
 };
-use banking_db::repository::CustomerRepository;
+use banking_db::repository::{ChannelSecurityRepository, CustomerRepository};
 use banking_db::{CustomerStatus, IdentityType, RiskRating};
 use sqlx::{PgPool, Row, postgres::PgRow};
 use uuid::Uuid;
 use heapless::String as HeaplessString;
 
+use crate::repository::channel_security_repository_impl::ChannelSecurityRepositoryImpl;
+use crate::repository::tag_filter_sql::{required_matches, tag_codes, tag_filter_sql};
+
+/// Security events shown in the customer portfolio
+const RECENT_SECURITY_EVENTS_LIMIT: i64 = 10;
+
+/// Customer-keyed columns a merge moves from the duplicate to the survivor,
+/// besides account ownership which needs joint holdings folded first
+const MERGED_CUSTOMER_REFERENCES: &[(&str, &str)] = &[
+    ("account_mandates", "grantee_customer_id"),
+    ("compliance_alerts", "customer_id"),
+    ("kyc_results", "customer_id"),
+    ("sanctions_screening", "customer_id"),
+    ("ultimate_beneficial_owners", "corporate_customer_id"),
+    ("ultimate_beneficial_owners", "beneficiary_customer_id"),
+];
+
 /// PostgreSQL implementation of CustomerRepository
 pub struct CustomerRepositoryImpl {
     pool: PgPool,
@@ -44,6 +62,20 @@ impl TryFromRow<PgRow> for CustomerModel {
             created_at: row.get("created_at"),
             last_updated_at: row.get("last_updated_at"),
             updated_by_person_id: row.get("updated_by_person_id"),
+            preferred_language_code: row.get::<Option<String>, _>("preferred_language_code")
+                .map(|s| {
+                    let bytes = s.as_bytes();
+                    if bytes.len() == 3 {
+                        Ok([bytes[0], bytes[1], bytes[2]])
+                    } else {
+                        Err(BankingError::ValidationError {
+                            field: "preferred_language_code".to_string(),
+                            message: "Language code must be 3 characters".to_string(),
+                        })
+                    }
+                })
+                .transpose()?,
+            duplicate_of_customer_id: row.get("duplicate_of_customer_id"),
         })
     }
 }
@@ -129,15 +161,17 @@ impl CustomerRepository for CustomerRepositoryImpl {
             r#"
             INSERT INTO customers (
                 id, customer_type, full_name, id_type, id_number,
-                risk_rating, status, created_at, last_updated_at, updated_by_person_id 
+                risk_rating, status, created_at, last_updated_at, updated_by_person_id,
+                preferred_language_code
             )
             VALUES (
                 $1, $2::customer_type, $3, $4::identity_type, $5,
-                $6::risk_rating, $7::customer_status, $8, $9, $10
+                $6::risk_rating, $7::customer_status, $8, $9, $10, $11
             )
             RETURNING id, customer_type::customer_type as customer_type, full_name,
                      id_type::identity_type as id_type, id_number, risk_rating::risk_rating as risk_rating,
-                     status::customer_status as status, created_at, last_updated_at, updated_by_person_id 
+                     status::customer_status as status, created_at, last_updated_at, updated_by_person_id,
+                   preferred_language_code, duplicate_of_customer_id
             "#
         )
         .bind(customer.id)
@@ -150,6 +184,7 @@ impl CustomerRepository for CustomerRepositoryImpl {
         .bind(customer.created_at)
         .bind(customer.last_updated_at)
         .bind(customer.updated_by_person_id)
+        .bind(customer.preferred_language_code.as_ref().map(|c| String::from_utf8_lossy(c).to_string()))
         .fetch_one(&self.pool)
         .await
         .map_err(|e| BankingError::Internal(format!("Failed to create customer: {e}")))?
@@ -164,11 +199,12 @@ impl CustomerRepository for CustomerRepositoryImpl {
             UPDATE customers 
             SET customer_type = $2::customer_type, full_name = $3, id_type = $4::identity_type,
                 id_number = $5, risk_rating = $6::risk_rating, status = $7::customer_status,
-                last_updated_at = $8, updated_by_person_id = $9
+                last_updated_at = $8, updated_by_person_id = $9, preferred_language_code = $10
             WHERE id = $1
             RETURNING id, customer_type::customer_type as customer_type, full_name,
                      id_type::identity_type as id_type, id_number, risk_rating::risk_rating as risk_rating,
-                     status::customer_status as status, created_at, last_updated_at, updated_by_person_id 
+                     status::customer_status as status, created_at, last_updated_at, updated_by_person_id,
+                   preferred_language_code, duplicate_of_customer_id
             "#
         )
         .bind(customer.id)
@@ -180,6 +216,7 @@ impl CustomerRepository for CustomerRepositoryImpl {
         .bind(customer.status)
         .bind(customer.last_updated_at)
         .bind(customer.updated_by_person_id)
+        .bind(customer.preferred_language_code.as_ref().map(|c| String::from_utf8_lossy(c).to_string()))
         .fetch_one(&self.pool)
         .await
         .map_err(|e| BankingError::Internal(format!("Failed to update customer: {e}")))?
@@ -193,7 +230,8 @@ impl CustomerRepository for CustomerRepositoryImpl {
             r#"
             SELECT id, customer_type::customer_type as customer_type, full_name,
                    id_type::identity_type as id_type, id_number, risk_rating::risk_rating as risk_rating,
-                   status::customer_status as status, created_at, last_updated_at, updated_by_person_id 
+                   status::customer_status as status, created_at, last_updated_at, updated_by_person_id,
+                   preferred_language_code, duplicate_of_customer_id
             FROM customers 
             WHERE id = $1
             "#
@@ -210,12 +248,32 @@ impl CustomerRepository for CustomerRepositoryImpl {
         }
     }
 
+    async fn find_by_ids(&self, customer_ids: &[Uuid]) -> BankingResult<Vec<CustomerModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, customer_type::customer_type as customer_type, full_name,
+                   id_type::identity_type as id_type, id_number, risk_rating::risk_rating as risk_rating,
+                   status::customer_status as status, created_at, last_updated_at, updated_by_person_id,
+                   preferred_language_code, duplicate_of_customer_id
+            FROM customers 
+            WHERE id = ANY($1)
+            "#
+        )
+        .bind(customer_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find customers by ID: {e}")))?;
+
+        rows.iter().map(CustomerModel::try_from_row).collect()
+    }
+
     async fn find_by_identity(&self, id_type: IdentityType, id_number: &str) -> BankingResult<Option<CustomerModel>> {
         let result = sqlx::query(
             r#"
             SELECT id, customer_type::customer_type as customer_type, full_name,
                    id_type::identity_type as id_type, id_number, risk_rating::risk_rating as risk_rating,
-                   status::customer_status as status, created_at, last_updated_at, updated_by_person_id 
+                   status::customer_status as status, created_at, last_updated_at, updated_by_person_id,
+                   preferred_language_code, duplicate_of_customer_id
             FROM customers 
             WHERE id_type = $1::identity_type AND id_number = $2
             "#
@@ -238,7 +296,8 @@ impl CustomerRepository for CustomerRepositoryImpl {
             r#"
             SELECT id, customer_type::customer_type as customer_type, full_name,
                    id_type::identity_type as id_type, id_number, risk_rating::risk_rating as risk_rating,
-                   status::customer_status as status, created_at, last_updated_at, updated_by_person_id 
+                   status::customer_status as status, created_at, last_updated_at, updated_by_person_id,
+                   preferred_language_code, duplicate_of_customer_id
             FROM customers 
             WHERE risk_rating = $1::risk_rating
             ORDER BY full_name
@@ -262,7 +321,8 @@ impl CustomerRepository for CustomerRepositoryImpl {
             r#"
             SELECT id, customer_type::customer_type as customer_type, full_name,
                    id_type::identity_type as id_type, id_number, risk_rating::risk_rating as risk_rating,
-                   status::customer_status as status, created_at, last_updated_at, updated_by_person_id 
+                   status::customer_status as status, created_at, last_updated_at, updated_by_person_id,
+                   preferred_language_code, duplicate_of_customer_id
             FROM customers 
             WHERE status = 'PendingVerification' OR risk_rating = 'High' OR risk_rating = 'Blacklisted'
                OR last_updated_at < NOW() - INTERVAL '1 year'
@@ -289,11 +349,35 @@ impl CustomerRepository for CustomerRepositoryImpl {
                 0::bigint as total_accounts,
                 0::decimal as total_balance,
                 0::decimal as total_loan_outstanding,
+                (
+                    SELECT SUM(LEAST(GREATEST(COALESCE(a.outstanding_principal, 0), 0),
+                                     COALESCE(g.guarantee_amount, GREATEST(COALESCE(a.outstanding_principal, 0), 0))))
+                    FROM loan_guarantors g
+                    JOIN accounts a ON a.id = g.loan_account_id
+                    WHERE g.guarantor_type = 'Customer'::guarantor_type
+                      AND g.guarantor_id = $1
+                      AND g.status = 'Active'::guarantor_status
+                      AND a.account_status <> 'Closed'::account_status
+                ) as total_guarantee_exposure,
                 NULL::timestamp as last_activity_date,
                 NULL::decimal as risk_score,
                 'NotStarted'::kyc_status as kyc_status,
                 false as sanctions_checked,
-                NULL::timestamp as last_screening_date
+                NULL::timestamp as last_screening_date,
+                ARRAY(
+                    SELECT t.code FROM entity_tags et
+                    JOIN tags t ON t.id = et.tag_id
+                    WHERE et.entity_kind = 'Customer'::taggable_entity_kind AND et.entity_id = $1
+                    ORDER BY t.code
+                ) as tags,
+                (
+                    SELECT COUNT(*) FROM interaction_notes n
+                    WHERE n.visibility <> 'ComplianceOnly'::note_visibility
+                      AND ((n.entity_kind = 'Customer'::note_entity_kind AND n.entity_id = $1)
+                        OR (n.entity_kind = 'Account'::note_entity_kind AND n.entity_id IN (
+                            SELECT ao.account_id FROM account_ownership ao WHERE ao.customer_id = $1
+                        )))
+                ) as interaction_note_count
             "#
         )
         .bind(customer_id)
@@ -304,22 +388,75 @@ impl CustomerRepository for CustomerRepositoryImpl {
 
         match result {
             Some(row) => {
+                let recent_security_events = ChannelSecurityRepositoryImpl::new(self.pool.clone())
+                    .find_recent_events_for_customer(customer_id, RECENT_SECURITY_EVENTS_LIMIT)
+                    .await?;
+
                 Ok(Some(CustomerPortfolioModel {
                     customer_id: row.get("customer_id"),
                     total_accounts: row.get("total_accounts"),
                     total_balance: row.get("total_balance"),
                     total_loan_outstanding: row.get("total_loan_outstanding"),
+                    total_guarantee_exposure: row.get("total_guarantee_exposure"),
                     last_activity_date: row.get("last_activity_date"),
                     risk_score: row.get("risk_score"),
                     kyc_status: row.get("kyc_status"),
                     sanctions_checked: row.get("sanctions_checked"),
                     last_screening_date: row.get("last_screening_date"),
+                    tags: row.get::<Vec<String>, _>("tags")
+                        .iter()
+                        .map(|code| HeaplessString::try_from(code.as_str())
+                            .map_err(|_| BankingError::Internal(format!("Tag code too long: {code}"))))
+                        .collect::<BankingResult<Vec<_>>>()?,
+                    recent_security_events,
+                    interaction_note_count: row.get("interaction_note_count"),
                 }))
             },
             None => Ok(None),
         }
     }
 
+    async fn search(&self, criteria: CustomerSearchCriteriaModel) -> BankingResult<Vec<CustomerModel>> {
+        let (codes, required) = match &criteria.tags {
+            Some(filter) => (tag_codes(filter), required_matches(filter)),
+            None => (Vec::new(), 0),
+        };
+        let query = format!(
+            r#"
+            SELECT c.id, c.customer_type::customer_type as customer_type, c.full_name,
+                   c.id_type::identity_type as id_type, c.id_number, c.risk_rating::risk_rating as risk_rating,
+                   c.status::customer_status as status, c.created_at, c.last_updated_at, c.updated_by_person_id,
+                   c.preferred_language_code, c.duplicate_of_customer_id
+            FROM customers c
+            WHERE ($1::customer_type IS NULL OR c.customer_type = $1::customer_type)
+              AND ($2::customer_status IS NULL OR c.status = $2::customer_status)
+              AND ($3::risk_rating IS NULL OR c.risk_rating = $3::risk_rating)
+              AND (cardinality($4::text[]) = 0 OR {})
+            ORDER BY c.full_name, c.id
+            LIMIT $6 OFFSET $7
+            "#,
+            tag_filter_sql(DbTaggableEntityKind::Customer, "c.id", "$4", "$5")
+        );
+
+        let rows = sqlx::query(&query)
+            .bind(criteria.customer_type)
+            .bind(criteria.status)
+            .bind(criteria.risk_rating)
+            .bind(&codes)
+            .bind(required)
+            .bind(criteria.limit)
+            .bind(criteria.offset)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to search customers: {e}")))?;
+
+        let mut customers = Vec::new();
+        for row in rows {
+            customers.push(CustomerModel::try_from_row(&row)?);
+        }
+        Ok(customers)
+    }
+
     async fn update_risk_rating(&self, customer_id: Uuid, risk_rating: RiskRating, authorized_by: Uuid) -> BankingResult<()> {
         let mut tx = self.pool.begin().await.map_err(|e| BankingError::Internal(format!("Failed to start transaction: {e}")))?
         ;
@@ -528,6 +665,144 @@ impl CustomerRepository for CustomerRepositoryImpl {
         Ok(audit_entries)
     }
 
+    async fn merge_into(&self, survivor_id: Uuid, duplicate_id: Uuid, reason: &str, merged_by: Uuid) -> BankingResult<()> {
+        let mut tx = self.pool.begin().await.map_err(|e| BankingError::Internal(format!("Failed to start transaction: {e}")))?
+        ;
+
+        // Lock the duplicate so a concurrent merge of the same record waits
+        let duplicate = sqlx::query(
+            "SELECT status::text as status FROM customers WHERE id = $1 FOR UPDATE"
+        )
+        .bind(duplicate_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to lock duplicate customer: {e}")))?
+        .ok_or(BankingError::CustomerNotFound(duplicate_id))?;
+        let old_status: String = duplicate.get("status");
+
+        let blockers = sqlx::query(
+            r#"
+            SELECT
+                EXISTS(
+                    SELECT 1 FROM account_holds h
+                    JOIN account_ownership o ON o.account_id = h.account_id
+                    WHERE o.customer_id = $1 AND h.status = 'Active'
+                ) as has_active_holds,
+                EXISTS(
+                    SELECT 1 FROM account_workflows w
+                    JOIN account_ownership o ON o.account_id = w.account_id
+                    WHERE o.customer_id = $1 AND w.status IN ('InProgress', 'PendingAction')
+                ) as has_pending_workflows
+            "#
+        )
+        .bind(duplicate_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to check merge blockers: {e}")))?
+        ;
+        let blocker = if blockers.get("has_active_holds") {
+            Some(CustomerMergeBlocker::ActiveHolds)
+        } else if blockers.get("has_pending_workflows") {
+            Some(CustomerMergeBlocker::PendingWorkflows)
+        } else {
+            None
+        };
+        if let Some(blocker) = blocker {
+            return Err(BankingError::CustomerMergeBlocked { survivor_id, duplicate_id, blocker });
+        }
+
+        // Accounts held by both: fold the duplicate's share into the survivor's
+        sqlx::query(
+            r#"
+            UPDATE account_ownership s
+            SET ownership_percentage = s.ownership_percentage + COALESCE(d.ownership_percentage, 0)
+            FROM account_ownership d
+            WHERE s.customer_id = $1 AND d.customer_id = $2 AND d.account_id = s.account_id
+            "#
+        )
+        .bind(survivor_id)
+        .bind(duplicate_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to fold joint ownership: {e}")))?
+        ;
+        sqlx::query(
+            r#"
+            DELETE FROM account_ownership d
+            WHERE d.customer_id = $2
+              AND EXISTS (SELECT 1 FROM account_ownership s WHERE s.customer_id = $1 AND s.account_id = d.account_id)
+            "#
+        )
+        .bind(survivor_id)
+        .bind(duplicate_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to fold joint ownership: {e}")))?
+        ;
+        sqlx::query("UPDATE account_ownership SET customer_id = $1 WHERE customer_id = $2")
+            .bind(survivor_id)
+            .bind(duplicate_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to move account ownership: {e}")))?
+            ;
+
+        for (table, column) in MERGED_CUSTOMER_REFERENCES {
+            sqlx::query(&format!("UPDATE {table} SET {column} = $1 WHERE {column} = $2"))
+                .bind(survivor_id)
+                .bind(duplicate_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| BankingError::Internal(format!("Failed to move {table}.{column}: {e}")))?
+                ;
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE customers
+            SET status = 'Merged'::customer_status, duplicate_of_customer_id = $1,
+                last_updated_at = NOW(), updated_by_person_id = $3
+            WHERE id = $2
+            "#
+        )
+        .bind(survivor_id)
+        .bind(duplicate_id)
+        .bind(merged_by)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to mark customer merged: {e}")))?
+        ;
+
+        // Audit both sides: the duplicate's status change and the survivor's absorbed record
+        for (customer_id, field_name, old_value, new_value) in [
+            (duplicate_id, "status", Some(old_status), "Merged".to_string()),
+            (survivor_id, "merged_customer", None, duplicate_id.to_string()),
+        ] {
+            sqlx::query(
+                r#"
+                INSERT INTO customer_audit_trail (
+                    id, customer_id, field_name, old_value, new_value, changed_at, changed_by, reason
+                )
+                VALUES ($1, $2, $3, $4, $5, NOW(), $6, $7)
+                "#
+            )
+            .bind(Uuid::new_v4())
+            .bind(customer_id)
+            .bind(field_name)
+            .bind(old_value)
+            .bind(new_value)
+            .bind(merged_by)
+            .bind(reason)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to add audit trail: {e}")))?
+            ;
+        }
+
+        tx.commit().await.map_err(|e| BankingError::Internal(format!("Failed to commit transaction: {e}")))?;
+        Ok(())
+    }
+
     async fn delete(&self, customer_id: Uuid, deleted_by: Uuid) -> BankingResult<()> {
         let mut tx = self.pool.begin().await.map_err(|e| BankingError::Internal(format!("Failed to start transaction: {e}")))?
         ;
@@ -586,7 +861,8 @@ impl CustomerRepository for CustomerRepositoryImpl {
             r#"
             SELECT id, customer_type::customer_type as customer_type, full_name,
                    id_type::identity_type as id_type, id_number, risk_rating::risk_rating as risk_rating,
-                   status::customer_status as status, created_at, last_updated_at, updated_by_person_id 
+                   status::customer_status as status, created_at, last_updated_at, updated_by_person_id,
+                   preferred_language_code, duplicate_of_customer_id
             FROM customers 
             ORDER BY full_name
             LIMIT $1 OFFSET $2
@@ -1,10 +1,22 @@
 use async_trait::async_trait;
-use banking_db::models::daily_collection::{AgentStatus, CollectionAgentModel};
+use banking_db::models::daily_collection::{
+    AgentDeviceModel, AgentStatus, CollectionAgentModel, CollectionBatchModel, CollectionProgramModel,
+    CollectionRecordModel, CollectionRecordStatus, CollectionSecurityFeaturesModel, CollectionStatus,
+    ConnectivityStatus, CoverageAreaModel, CustomerCollectionProfileModel, DeviceInformationModel, DeviceType,
+    GeoVerificationStatus, PerformanceAlertModel, TerritoryModel,
+};
 use banking_db::repository::daily_collection_repository::DailyCollectionRepository;
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
 use sqlx::PgPool;
 use std::sync::Arc;
 use uuid::Uuid;
 
+/// Bounded copy of a device text column
+fn heapless<const N: usize>(value: String, field: &str) -> Result<HeaplessString<N>, String> {
+    HeaplessString::try_from(value.as_str()).map_err(|_| format!("Device {field} longer than {N} characters: {value}"))
+}
+
 pub struct DailyCollectionRepositoryImpl {
     pool: Arc<PgPool>,
 }
@@ -141,4 +153,654 @@ impl DailyCollectionRepository for DailyCollectionRepositoryImpl {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    async fn get_device_information(&self, device_id: Uuid) -> Result<Option<DeviceInformationModel>, String> {
+        let result = sqlx::query_as!(
+            DeviceInformationModel,
+            r#"
+            SELECT id, external_id, device_type as "device_type: _", model, os_version, app_version,
+                last_sync, battery_level, connectivity_status as "connectivity_status: _", security_features_id
+            FROM device_information
+            WHERE id = $1
+            "#,
+            device_id
+        )
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+
+    async fn get_security_features(&self, security_features_id: Uuid) -> Result<Option<CollectionSecurityFeaturesModel>, String> {
+        let result = sqlx::query_as!(
+            CollectionSecurityFeaturesModel,
+            r#"
+            SELECT id, biometric_enabled, pin_protection, encryption_enabled, remote_wipe_enabled,
+                certificate_installed, last_security_scan
+            FROM collection_security_features
+            WHERE id = $1
+            "#,
+            security_features_id
+        )
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+
+    async fn update_device_heartbeat(
+        &self,
+        device_id: Uuid,
+        battery_level: Option<f32>,
+        connectivity_status: ConnectivityStatus,
+        synced_at: DateTime<Utc>,
+    ) -> Result<Option<DeviceInformationModel>, String> {
+        let result = sqlx::query_as!(
+            DeviceInformationModel,
+            r#"
+            UPDATE device_information
+            SET last_sync = $2, battery_level = $3, connectivity_status = $4
+            WHERE id = $1
+            RETURNING id, external_id, device_type as "device_type: _", model, os_version, app_version,
+                last_sync, battery_level, connectivity_status as "connectivity_status: _", security_features_id
+            "#,
+            device_id,
+            synced_at,
+            battery_level,
+            connectivity_status as _
+        )
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+
+    async fn find_non_compliant_devices(&self, synced_before: DateTime<Utc>) -> Result<Vec<AgentDeviceModel>, String> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                ca.id as agent_id,
+                di.id, di.external_id, di.device_type as "device_type: DeviceType", di.model, di.os_version,
+                di.app_version, di.last_sync, di.battery_level,
+                di.connectivity_status as "connectivity_status: ConnectivityStatus", di.security_features_id,
+                sf.biometric_enabled, sf.pin_protection, sf.encryption_enabled, sf.remote_wipe_enabled,
+                sf.certificate_installed, sf.last_security_scan
+            FROM collection_agents ca
+            JOIN device_information di ON di.id = ca.device_information_id
+            JOIN collection_security_features sf ON sf.id = di.security_features_id
+            WHERE ca.status <> 'Terminated'
+                AND (di.last_sync IS NULL OR di.last_sync < $1 OR NOT sf.encryption_enabled OR NOT sf.certificate_installed)
+            ORDER BY di.last_sync NULLS FIRST, di.id
+            "#,
+            synced_before
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(AgentDeviceModel {
+                    agent_id: row.agent_id,
+                    device: DeviceInformationModel {
+                        id: row.id,
+                        external_id: heapless(row.external_id, "external_id")?,
+                        device_type: row.device_type,
+                        model: heapless(row.model, "model")?,
+                        os_version: heapless(row.os_version, "os_version")?,
+                        app_version: heapless(row.app_version, "app_version")?,
+                        last_sync: row.last_sync,
+                        battery_level: row.battery_level,
+                        connectivity_status: row.connectivity_status,
+                        security_features_id: row.security_features_id,
+                    },
+                    security_features: CollectionSecurityFeaturesModel {
+                        id: row.security_features_id,
+                        biometric_enabled: row.biometric_enabled,
+                        pin_protection: row.pin_protection,
+                        encryption_enabled: row.encryption_enabled,
+                        remote_wipe_enabled: row.remote_wipe_enabled,
+                        certificate_installed: row.certificate_installed,
+                        last_security_scan: row.last_security_scan,
+                    },
+                })
+            })
+            .collect()
+    }
+
+    async fn get_territory(&self, territory_id: Uuid) -> Result<Option<TerritoryModel>, String> {
+        let result = sqlx::query_as!(
+            TerritoryModel,
+            r#"
+            SELECT id, territory_name, coverage_area_id, customer_count, route_optimization_enabled, territory_manager_person_id
+            FROM territories
+            WHERE id = $1
+            "#,
+            territory_id
+        )
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+
+    async fn get_coverage_area(&self, coverage_area_id: Uuid) -> Result<Option<CoverageAreaModel>, String> {
+        let result = sqlx::query_as!(
+            CoverageAreaModel,
+            r#"
+            SELECT
+                id, area_name, area_type as "area_type: _",
+                boundary_coordinates_long_1, boundary_coordinates_lat_1, boundary_coordinates_long_2, boundary_coordinates_lat_2,
+                boundary_coordinates_long_3, boundary_coordinates_lat_3, boundary_coordinates_long_4, boundary_coordinates_lat_4,
+                boundary_coordinates_long_5, boundary_coordinates_lat_5,
+                customer_density as "customer_density: _", transport_mode as "transport_mode: _"
+            FROM coverage_areas
+            WHERE id = $1
+            "#,
+            coverage_area_id
+        )
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+
+    async fn get_collection_program(&self, program_id: Uuid) -> Result<Option<CollectionProgramModel>, String> {
+        let result = sqlx::query_as!(
+            CollectionProgramModel,
+            r#"
+            SELECT
+                id, name, description, program_type as "program_type: _",
+                status as "status: _", start_date, end_date, collection_frequency as "collection_frequency: _",
+                operating_hours_id, minimum_amount, maximum_amount, target_amount,
+                program_duration_days, graduation_minimum_balance, graduation_minimum_collection_rate, graduation_minimum_duration_days,
+                graduation_consecutive_collections_required, graduation_target_achievement_required, graduation_auto_graduation_enabled, fee_setup_fee,
+                fee_collection_fee, fee_maintenance_fee, fee_graduation_fee, fee_early_termination_fee,
+                fee_frequency as "fee_frequency: _", interest_rate, geo_verification_radius_meters, geo_mismatch_weekly_threshold,
+                created_at, updated_at, created_by_person_id, reason_id
+            FROM collection_programs
+            WHERE id = $1
+            "#,
+            program_id
+        )
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+
+    async fn get_customer_collection_profile(
+        &self,
+        customer_id: Uuid,
+        program_id: Uuid,
+    ) -> Result<Option<CustomerCollectionProfileModel>, String> {
+        let result = sqlx::query_as!(
+            CustomerCollectionProfileModel,
+            r#"
+            SELECT
+                id, customer_id, collection_program_id, account_id,
+                enrollment_date, status as "status: _", daily_amount, schedule_frequency as "schedule_frequency: _",
+                schedule_collection_time, schedule_timezone, schedule_holiday_handling as "schedule_holiday_handling: _", assigned_collection_agent_id,
+                collection_location_id, performance_collection_rate, performance_total_collections, performance_total_amount_collected,
+                performance_average_collection_amount, performance_consecutive_collections, performance_missed_collections, performance_last_collection_date,
+                performance_score, performance_reliability_rating as "performance_reliability_rating: _", graduation_current_balance, graduation_target_balance,
+                graduation_days_in_program, graduation_minimum_days_required, graduation_collection_consistency_rate, graduation_minimum_consistency_required,
+                graduation_eligible, graduation_date, graduation_next_review_date, created_at,
+                updated_at, reason_id
+            FROM customer_collection_profiles
+            WHERE customer_id = $1 AND collection_program_id = $2
+            "#,
+            customer_id,
+            program_id
+        )
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+
+
+    async fn find_customer_profiles_by_agent(
+        &self,
+        agent_id: Uuid,
+        status: CollectionStatus,
+    ) -> Result<Vec<CustomerCollectionProfileModel>, String> {
+        let result = sqlx::query_as!(
+            CustomerCollectionProfileModel,
+            r#"
+            SELECT
+                id, customer_id, collection_program_id, account_id,
+                enrollment_date, status as "status: _", daily_amount, schedule_frequency as "schedule_frequency: _",
+                schedule_collection_time, schedule_timezone, schedule_holiday_handling as "schedule_holiday_handling: _", assigned_collection_agent_id,
+                collection_location_id, performance_collection_rate, performance_total_collections, performance_total_amount_collected,
+                performance_average_collection_amount, performance_consecutive_collections, performance_missed_collections, performance_last_collection_date,
+                performance_score, performance_reliability_rating as "performance_reliability_rating: _", graduation_current_balance, graduation_target_balance,
+                graduation_days_in_program, graduation_minimum_days_required, graduation_collection_consistency_rate, graduation_minimum_consistency_required,
+                graduation_eligible, graduation_date, graduation_next_review_date, created_at,
+                updated_at, reason_id
+            FROM customer_collection_profiles
+            WHERE assigned_collection_agent_id = $1 AND status = $2
+            "#,
+            agent_id,
+            status as _
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+
+    async fn create_collection_record(&self, record: CollectionRecordModel) -> Result<CollectionRecordModel, String> {
+        let result = sqlx::query_as!(
+            CollectionRecordModel,
+            r#"
+            INSERT INTO collection_records (
+                id, customer_id, collection_agent_id, collection_program_id, account_id,
+                collection_date, collection_time, amount, currency, collection_method,
+                location_id, receipt_number, status, notes, verification_customer_signature,
+                verification_agent_verification_code, verification_fingerprint_hash, verification_face_recognition_score, verification_biometric_method, verification_confidence_level,
+                verification_customer_photo_hash, verification_receipt_photo_hash, verification_location_photo_hash, verification_photo_timestamp, verification_witness_name,
+                verification_witness_contact, verification_witness_relationship, verification_witness_signature, verification_timestamp, agent_latitude,
+                agent_longitude, geo_verification_status, geo_distance_meters, created_at, processed_at,
+                reason_id
+            )
+            VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10,
+                $11, $12, $13, $14, $15, $16, $17, $18, $19, $20,
+                $21, $22, $23, $24, $25, $26, $27, $28, $29, $30,
+                $31, $32, $33, $34, $35, $36
+            )
+            RETURNING
+                id, customer_id, collection_agent_id, collection_program_id,
+                account_id, collection_date, collection_time, amount,
+                currency, collection_method as "collection_method: _", location_id, receipt_number,
+                status as "status: _", notes, verification_customer_signature, verification_agent_verification_code,
+                verification_fingerprint_hash, verification_face_recognition_score, verification_biometric_method as "verification_biometric_method: _", verification_confidence_level,
+                verification_customer_photo_hash, verification_receipt_photo_hash, verification_location_photo_hash, verification_photo_timestamp,
+                verification_witness_name, verification_witness_contact, verification_witness_relationship, verification_witness_signature,
+                verification_timestamp, agent_latitude, agent_longitude, geo_verification_status as "geo_verification_status: _",
+                geo_distance_meters, created_at, processed_at, reason_id
+            "#,
+            record.id,
+            record.customer_id,
+            record.collection_agent_id,
+            record.collection_program_id,
+            record.account_id,
+            record.collection_date,
+            record.collection_time,
+            record.amount,
+            record.currency as _,
+            record.collection_method as _,
+            record.location_id,
+            record.receipt_number as _,
+            record.status as _,
+            record.notes as _,
+            record.verification_customer_signature as _,
+            record.verification_agent_verification_code as _,
+            record.verification_fingerprint_hash as _,
+            record.verification_face_recognition_score,
+            record.verification_biometric_method as _,
+            record.verification_confidence_level,
+            record.verification_customer_photo_hash as _,
+            record.verification_receipt_photo_hash as _,
+            record.verification_location_photo_hash as _,
+            record.verification_photo_timestamp,
+            record.verification_witness_name as _,
+            record.verification_witness_contact as _,
+            record.verification_witness_relationship as _,
+            record.verification_witness_signature as _,
+            record.verification_timestamp,
+            record.agent_latitude,
+            record.agent_longitude,
+            record.geo_verification_status as _,
+            record.geo_distance_meters,
+            record.created_at,
+            record.processed_at,
+            record.reason_id
+        )
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+
+    async fn find_collection_records(
+        &self,
+        agent_id: Option<Uuid>,
+        customer_id: Option<Uuid>,
+        from: NaiveDate,
+        to: NaiveDate,
+        status: Option<CollectionRecordStatus>,
+        page: i32,
+        page_size: i32,
+    ) -> Result<Vec<CollectionRecordModel>, String> {
+        let offset = i64::from((page - 1).max(0)) * i64::from(page_size);
+        let result = sqlx::query_as!(
+            CollectionRecordModel,
+            r#"
+            SELECT
+                id, customer_id, collection_agent_id, collection_program_id,
+                account_id, collection_date, collection_time, amount,
+                currency, collection_method as "collection_method: _", location_id, receipt_number,
+                status as "status: _", notes, verification_customer_signature, verification_agent_verification_code,
+                verification_fingerprint_hash, verification_face_recognition_score, verification_biometric_method as "verification_biometric_method: _", verification_confidence_level,
+                verification_customer_photo_hash, verification_receipt_photo_hash, verification_location_photo_hash, verification_photo_timestamp,
+                verification_witness_name, verification_witness_contact, verification_witness_relationship, verification_witness_signature,
+                verification_timestamp, agent_latitude, agent_longitude, geo_verification_status as "geo_verification_status: _",
+                geo_distance_meters, created_at, processed_at, reason_id
+            FROM collection_records
+            WHERE ($1::uuid IS NULL OR collection_agent_id = $1)
+              AND ($2::uuid IS NULL OR customer_id = $2)
+              AND collection_date BETWEEN $3 AND $4
+              AND ($5::collection_record_status IS NULL OR status = $5)
+            ORDER BY collection_time DESC, id
+            LIMIT $6 OFFSET $7
+            "#,
+            agent_id,
+            customer_id,
+            from,
+            to,
+            status as _,
+            i64::from(page_size),
+            offset
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+
+    async fn count_agent_collections_by_geo_status(
+        &self,
+        agent_id: Uuid,
+        status: GeoVerificationStatus,
+        since: DateTime<Utc>,
+    ) -> Result<i64, String> {
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM collection_records
+            WHERE collection_agent_id = $1 AND geo_verification_status = $2 AND collection_time >= $3
+            "#,
+            agent_id,
+            status as _,
+            since
+        )
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(count)
+    }
+
+    async fn find_geo_review_queue(&self) -> Result<Vec<CollectionRecordModel>, String> {
+        let result = sqlx::query_as!(
+            CollectionRecordModel,
+            r#"
+            SELECT
+                id, customer_id, collection_agent_id, collection_program_id,
+                account_id, collection_date, collection_time, amount,
+                currency, collection_method as "collection_method: _", location_id, receipt_number,
+                status as "status: _", notes, verification_customer_signature, verification_agent_verification_code,
+                verification_fingerprint_hash, verification_face_recognition_score, verification_biometric_method as "verification_biometric_method: _", verification_confidence_level,
+                verification_customer_photo_hash, verification_receipt_photo_hash, verification_location_photo_hash, verification_photo_timestamp,
+                verification_witness_name, verification_witness_contact, verification_witness_relationship, verification_witness_signature,
+                verification_timestamp, agent_latitude, agent_longitude, geo_verification_status as "geo_verification_status: _",
+                geo_distance_meters, created_at, processed_at, reason_id
+            FROM collection_records
+            WHERE status = 'UnderReview'
+              AND geo_verification_status IN ('GeoMismatch', 'MissingCoordinates')
+            ORDER BY collection_time
+            "#
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+
+    async fn get_collection_batch(&self, batch_id: Uuid) -> Result<Option<CollectionBatchModel>, String> {
+        let result = sqlx::query_as!(
+            CollectionBatchModel,
+            r#"
+            SELECT
+                id, collection_agent_id, collection_date, total_collections,
+                total_amount, currency, status as "status: _", collection_records,
+                reconciliation_expected_amount, reconciliation_actual_amount, reconciliation_variance, reconciliation_variance_reason,
+                reconciled_by_person_id, reconciliation_timestamp, reconciliation_adjustment_required, created_at,
+                processed_at
+            FROM collection_batches
+            WHERE id = $1
+            "#,
+            batch_id
+        )
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+
+    async fn find_collection_records_by_ids(&self, record_ids: &[Uuid]) -> Result<Vec<CollectionRecordModel>, String> {
+        let result = sqlx::query_as!(
+            CollectionRecordModel,
+            r#"
+            SELECT
+                id, customer_id, collection_agent_id, collection_program_id,
+                account_id, collection_date, collection_time, amount,
+                currency, collection_method as "collection_method: _", location_id, receipt_number,
+                status as "status: _", notes, verification_customer_signature, verification_agent_verification_code,
+                verification_fingerprint_hash, verification_face_recognition_score, verification_biometric_method as "verification_biometric_method: _", verification_confidence_level,
+                verification_customer_photo_hash, verification_receipt_photo_hash, verification_location_photo_hash, verification_photo_timestamp,
+                verification_witness_name, verification_witness_contact, verification_witness_relationship, verification_witness_signature,
+                verification_timestamp, agent_latitude, agent_longitude, geo_verification_status as "geo_verification_status: _",
+                geo_distance_meters, created_at, processed_at, reason_id
+            FROM collection_records
+            WHERE id = ANY($1)
+            ORDER BY collection_time
+            "#,
+            record_ids
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+
+    async fn update_batch_reconciliation(&self, batch: CollectionBatchModel) -> Result<CollectionBatchModel, String> {
+        let result = sqlx::query_as!(
+            CollectionBatchModel,
+            r#"
+            UPDATE collection_batches
+            SET
+                status = $2,
+                reconciliation_expected_amount = $3,
+                reconciliation_actual_amount = $4,
+                reconciliation_variance = $5,
+                reconciliation_variance_reason = $6,
+                reconciled_by_person_id = $7,
+                reconciliation_timestamp = $8,
+                reconciliation_adjustment_required = $9,
+                processed_at = $10
+            WHERE id = $1
+            RETURNING
+                id, collection_agent_id, collection_date, total_collections,
+                total_amount, currency, status as "status: _", collection_records,
+                reconciliation_expected_amount, reconciliation_actual_amount, reconciliation_variance, reconciliation_variance_reason,
+                reconciled_by_person_id, reconciliation_timestamp, reconciliation_adjustment_required, created_at,
+                processed_at
+            "#,
+            batch.id,
+            batch.status as _,
+            batch.reconciliation_expected_amount,
+            batch.reconciliation_actual_amount,
+            batch.reconciliation_variance,
+            batch.reconciliation_variance_reason as _,
+            batch.reconciled_by_person_id,
+            batch.reconciliation_timestamp,
+            batch.reconciliation_adjustment_required,
+            batch.processed_at
+        )
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+
+    async fn create_performance_alert(&self, alert: PerformanceAlertModel) -> Result<PerformanceAlertModel, String> {
+        let result = sqlx::query_as!(
+            PerformanceAlertModel,
+            r#"
+            INSERT INTO performance_alerts (
+                id, agent_performance_metrics_id, alert_type, severity, message,
+                acknowledged, resolution_required, created_at, acknowledged_at, acknowledged_by_person_id,
+                resolved_at, resolved_by_person_id, resolution_notes
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            RETURNING
+                id, agent_performance_metrics_id, alert_type as "alert_type: _", severity as "severity: _",
+                message, acknowledged, resolution_required, created_at,
+                acknowledged_at, acknowledged_by_person_id, resolved_at, resolved_by_person_id, resolution_notes
+            "#,
+            alert.id,
+            alert.agent_performance_metrics_id,
+            alert.alert_type as _,
+            alert.severity as _,
+            alert.message as _,
+            alert.acknowledged,
+            alert.resolution_required,
+            alert.created_at,
+            alert.acknowledged_at,
+            alert.acknowledged_by_person_id,
+            alert.resolved_at,
+            alert.resolved_by_person_id,
+            alert.resolution_notes as _
+        )
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+
+    async fn get_performance_alert(&self, alert_id: Uuid) -> Result<Option<PerformanceAlertModel>, String> {
+        let result = sqlx::query_as!(
+            PerformanceAlertModel,
+            r#"
+            SELECT
+                id, agent_performance_metrics_id, alert_type as "alert_type: _", severity as "severity: _",
+                message, acknowledged, resolution_required, created_at,
+                acknowledged_at, acknowledged_by_person_id, resolved_at, resolved_by_person_id, resolution_notes
+            FROM performance_alerts
+            WHERE id = $1
+            "#,
+            alert_id
+        )
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+
+    async fn acknowledge_performance_alert(
+        &self,
+        alert_id: Uuid,
+        person_id: Uuid,
+        acknowledged_at: DateTime<Utc>,
+    ) -> Result<Option<PerformanceAlertModel>, String> {
+        let result = sqlx::query_as!(
+            PerformanceAlertModel,
+            r#"
+            UPDATE performance_alerts
+            SET
+                acknowledged = TRUE,
+                acknowledged_at = COALESCE(acknowledged_at, $3),
+                acknowledged_by_person_id = COALESCE(acknowledged_by_person_id, $2)
+            WHERE id = $1 AND resolved_at IS NULL
+            RETURNING
+                id, agent_performance_metrics_id, alert_type as "alert_type: _", severity as "severity: _",
+                message, acknowledged, resolution_required, created_at,
+                acknowledged_at, acknowledged_by_person_id, resolved_at, resolved_by_person_id, resolution_notes
+            "#,
+            alert_id,
+            person_id,
+            acknowledged_at
+        )
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+
+    async fn resolve_performance_alert(
+        &self,
+        alert_id: Uuid,
+        person_id: Uuid,
+        resolution_notes: Option<HeaplessString<500>>,
+        resolved_at: DateTime<Utc>,
+    ) -> Result<Option<PerformanceAlertModel>, String> {
+        let result = sqlx::query_as!(
+            PerformanceAlertModel,
+            r#"
+            UPDATE performance_alerts
+            SET
+                resolved_at = $3,
+                resolved_by_person_id = $2,
+                resolution_notes = $4
+            WHERE id = $1
+                AND resolved_at IS NULL
+                AND (acknowledged OR NOT resolution_required)
+            RETURNING
+                id, agent_performance_metrics_id, alert_type as "alert_type: _", severity as "severity: _",
+                message, acknowledged, resolution_required, created_at,
+                acknowledged_at, acknowledged_by_person_id, resolved_at, resolved_by_person_id, resolution_notes
+            "#,
+            alert_id,
+            person_id,
+            resolved_at,
+            resolution_notes as _
+        )
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+
+    async fn find_open_alerts_by_agent(&self, agent_id: Uuid) -> Result<Vec<PerformanceAlertModel>, String> {
+        let result = sqlx::query_as!(
+            PerformanceAlertModel,
+            r#"
+            SELECT
+                pa.id, pa.agent_performance_metrics_id, pa.alert_type as "alert_type: _", pa.severity as "severity: _",
+                pa.message, pa.acknowledged, pa.resolution_required, pa.created_at,
+                pa.acknowledged_at, pa.acknowledged_by_person_id, pa.resolved_at, pa.resolved_by_person_id, pa.resolution_notes
+            FROM performance_alerts pa
+            JOIN collection_agents ca ON ca.agent_performance_metrics_id = pa.agent_performance_metrics_id
+            WHERE ca.id = $1 AND pa.resolved_at IS NULL
+            ORDER BY pa.created_at, pa.id
+            "#,
+            agent_id
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,212 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{DbDocumentLinkKind, DocumentLinkModel, RegisteredDocumentModel};
+use banking_db::repository::DocumentRegistryRepository;
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of DocumentRegistryRepository
+pub struct DocumentRegistryRepositoryImpl {
+    pool: PgPool,
+}
+
+impl DocumentRegistryRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+fn hash_bytes(field: &str, bytes: Vec<u8>) -> BankingResult<[u8; 32]> {
+    bytes.try_into().map_err(|_| BankingError::Internal(format!("Invalid {field} length")))
+}
+
+impl TryFromRow<PgRow> for RegisteredDocumentModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(RegisteredDocumentModel {
+            id: row.get("id"),
+            content_hash: hash_bytes("content_hash", row.get("content_hash"))?.into(),
+            document_type: HeaplessString::try_from(
+                row.get::<String, _>("document_type").as_str()
+            ).map_err(|_| BankingError::ValidationError {
+                field: "document_type".to_string(),
+                message: "Document type too long".to_string(),
+            })?,
+            document_path: row
+                .get::<Option<Vec<u8>>, _>("document_path")
+                .map(|bytes| hash_bytes("document_path", bytes))
+                .transpose()?
+                .map(Into::into),
+            size_bytes: row.get("size_bytes"),
+            registered_at: row.get("registered_at"),
+            registered_by_person_id: row.get("registered_by_person_id"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for DocumentLinkModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(DocumentLinkModel {
+            id: row.get("id"),
+            document_id: row.get("document_id"),
+            entity_kind: row.get::<String, _>("entity_kind").parse().map_err(|_| BankingError::Internal("Failed to parse entity_kind".into()))?,
+            entity_id: row.get("entity_id"),
+            linked_at: row.get("linked_at"),
+            linked_by_person_id: row.get("linked_by_person_id"),
+        })
+    }
+}
+
+const DOCUMENT_COLUMNS: &str = r#"
+    id, content_hash, document_type, document_path, size_bytes, registered_at, registered_by_person_id
+"#;
+
+const LINK_COLUMNS: &str = r#"
+    id, document_id, entity_kind::text as entity_kind, entity_id, linked_at, linked_by_person_id
+"#;
+
+#[async_trait]
+impl DocumentRegistryRepository for DocumentRegistryRepositoryImpl {
+    async fn register_document(&self, document: RegisteredDocumentModel) -> BankingResult<RegisteredDocumentModel> {
+        let inserted = sqlx::query(&format!(
+            r#"
+            INSERT INTO registered_documents (
+                id, content_hash, document_type, document_path, size_bytes, registered_at, registered_by_person_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (content_hash) DO NOTHING
+            RETURNING {DOCUMENT_COLUMNS}
+            "#
+        ))
+        .bind(document.id)
+        .bind(document.content_hash.as_bytes().as_slice())
+        .bind(document.document_type.as_str())
+        .bind(document.document_path.as_ref().map(|path| path.as_bytes().to_vec()))
+        .bind(document.size_bytes)
+        .bind(document.registered_at)
+        .bind(document.registered_by_person_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to register document: {e}")))?;
+
+        match inserted {
+            Some(row) => RegisteredDocumentModel::try_from_row(&row),
+            None => self
+                .find_document_by_hash(document.content_hash.as_bytes())
+                .await?
+                .ok_or_else(|| BankingError::Internal("Registered document disappeared".to_string())),
+        }
+    }
+
+    async fn find_document_by_id(&self, document_id: Uuid) -> BankingResult<Option<RegisteredDocumentModel>> {
+        let row = sqlx::query(&format!("SELECT {DOCUMENT_COLUMNS} FROM registered_documents WHERE id = $1"))
+            .bind(document_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find document: {e}")))?;
+
+        row.as_ref().map(RegisteredDocumentModel::try_from_row).transpose()
+    }
+
+    async fn find_document_by_hash(&self, content_hash: &[u8; 32]) -> BankingResult<Option<RegisteredDocumentModel>> {
+        let row = sqlx::query(&format!("SELECT {DOCUMENT_COLUMNS} FROM registered_documents WHERE content_hash = $1"))
+            .bind(content_hash.as_slice())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find document by hash: {e}")))?;
+
+        row.as_ref().map(RegisteredDocumentModel::try_from_row).transpose()
+    }
+
+    async fn link_document(&self, link: DocumentLinkModel) -> BankingResult<DocumentLinkModel> {
+        let inserted = sqlx::query(&format!(
+            r#"
+            INSERT INTO document_links (id, document_id, entity_kind, entity_id, linked_at, linked_by_person_id)
+            VALUES ($1, $2, $3::document_link_kind, $4, $5, $6)
+            ON CONFLICT (document_id, entity_kind, entity_id) DO NOTHING
+            RETURNING {LINK_COLUMNS}
+            "#
+        ))
+        .bind(link.id)
+        .bind(link.document_id)
+        .bind(link.entity_kind)
+        .bind(link.entity_id)
+        .bind(link.linked_at)
+        .bind(link.linked_by_person_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to link document: {e}")))?;
+
+        let row = match inserted {
+            Some(row) => row,
+            None => sqlx::query(&format!(
+                r#"
+                SELECT {LINK_COLUMNS} FROM document_links
+                WHERE document_id = $1 AND entity_kind = $2::document_link_kind AND entity_id = $3
+                "#
+            ))
+            .bind(link.document_id)
+            .bind(link.entity_kind)
+            .bind(link.entity_id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find document link: {e}")))?,
+        };
+
+        DocumentLinkModel::try_from_row(&row)
+    }
+
+    async fn find_links_by_document(&self, document_id: Uuid) -> BankingResult<Vec<DocumentLinkModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {LINK_COLUMNS} FROM document_links WHERE document_id = $1 ORDER BY linked_at"
+        ))
+        .bind(document_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find document links: {e}")))?;
+
+        rows.iter().map(DocumentLinkModel::try_from_row).collect()
+    }
+
+    async fn find_documents_by_link(&self, entity_kind: DbDocumentLinkKind, entity_id: Uuid) -> BankingResult<Vec<RegisteredDocumentModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {DOCUMENT_COLUMNS} FROM registered_documents
+            WHERE id IN (
+                SELECT document_id FROM document_links
+                WHERE entity_kind = $1::document_link_kind AND entity_id = $2
+            )
+            ORDER BY registered_at
+            "#
+        ))
+        .bind(entity_kind)
+        .bind(entity_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find linked documents: {e}")))?;
+
+        rows.iter().map(RegisteredDocumentModel::try_from_row).collect()
+    }
+
+    async fn delete_unlinked_document(&self, document_id: Uuid) -> BankingResult<bool> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM registered_documents
+            WHERE id = $1
+              AND NOT EXISTS (SELECT 1 FROM document_links WHERE document_id = $1)
+            "#,
+        )
+        .bind(document_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to delete document: {e}")))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
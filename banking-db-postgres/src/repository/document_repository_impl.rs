@@ -0,0 +1,212 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{
+    DbDocumentNotificationStatus, DbGeneratedDocumentType, DocumentNotificationModel,
+    GeneratedDocumentModel,
+};
+use banking_db::repository::DocumentRepository;
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of DocumentRepository
+pub struct DocumentRepositoryImpl {
+    pool: PgPool,
+}
+
+impl DocumentRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+fn language_code_from_str(field: &str, value: &str) -> BankingResult<[u8; 3]> {
+    let bytes = value.as_bytes();
+    if bytes.len() == 3 {
+        Ok([bytes[0], bytes[1], bytes[2]])
+    } else {
+        Err(BankingError::ValidationError {
+            field: field.to_string(),
+            message: "Language code must be 3 characters".to_string(),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for GeneratedDocumentModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(GeneratedDocumentModel {
+            id: row.get("id"),
+            document_type: row.get::<String, _>("document_type").parse().map_err(|_| BankingError::Internal("Failed to parse document_type".into()))?,
+            account_id: row.get("account_id"),
+            customer_id: row.get("customer_id"),
+            language_code: language_code_from_str("language_code", &row.get::<String, _>("language_code"))?,
+            content: row.get("content"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for DocumentNotificationModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(DocumentNotificationModel {
+            id: row.get("id"),
+            document_id: row.get("document_id"),
+            customer_id: row.get("customer_id"),
+            retrieval_reference: HeaplessString::try_from(
+                row.get::<String, _>("retrieval_reference").as_str()
+            ).map_err(|_| BankingError::ValidationError {
+                field: "retrieval_reference".to_string(),
+                message: "Retrieval reference too long".to_string(),
+            })?,
+            language_code: language_code_from_str("language_code", &row.get::<String, _>("language_code"))?,
+            status: row.get::<String, _>("status").parse().map_err(|_| BankingError::Internal("Failed to parse notification status".into()))?,
+            queued_at: row.get("queued_at"),
+            sent_at: row.get("sent_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl DocumentRepository for DocumentRepositoryImpl {
+    async fn create_document(&self, document: GeneratedDocumentModel) -> BankingResult<GeneratedDocumentModel> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO generated_documents (
+                id, document_type, account_id, customer_id, language_code, content, created_at
+            )
+            VALUES ($1, $2::generated_document_type, $3, $4, $5, $6, $7)
+            RETURNING id, document_type::text as document_type, account_id, customer_id,
+                      language_code, content, created_at
+            "#,
+        )
+        .bind(document.id)
+        .bind(document.document_type)
+        .bind(document.account_id)
+        .bind(document.customer_id)
+        .bind(String::from_utf8_lossy(&document.language_code).to_string())
+        .bind(&document.content)
+        .bind(document.created_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create generated document: {e}")))?;
+
+        GeneratedDocumentModel::try_from_row(&result)
+    }
+
+    async fn find_document_by_id(&self, document_id: Uuid) -> BankingResult<Option<GeneratedDocumentModel>> {
+        let result = sqlx::query(
+            r#"
+            SELECT id, document_type::text as document_type, account_id, customer_id,
+                   language_code, content, created_at
+            FROM generated_documents
+            WHERE id = $1
+            "#,
+        )
+        .bind(document_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find generated document: {e}")))?;
+
+        match result {
+            Some(row) => Ok(Some(GeneratedDocumentModel::try_from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_documents_by_account(&self, account_id: Uuid, document_type: DbGeneratedDocumentType) -> BankingResult<Vec<GeneratedDocumentModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, document_type::text as document_type, account_id, customer_id,
+                   language_code, content, created_at
+            FROM generated_documents
+            WHERE account_id = $1 AND document_type = $2::generated_document_type
+            ORDER BY created_at
+            "#,
+        )
+        .bind(account_id)
+        .bind(document_type)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find generated documents: {e}")))?;
+
+        let mut documents = Vec::new();
+        for row in rows {
+            documents.push(GeneratedDocumentModel::try_from_row(&row)?);
+        }
+        Ok(documents)
+    }
+
+    async fn queue_notification(&self, notification: DocumentNotificationModel) -> BankingResult<DocumentNotificationModel> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO document_notifications (
+                id, document_id, customer_id, retrieval_reference, language_code,
+                status, queued_at, sent_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6::document_notification_status, $7, $8)
+            RETURNING id, document_id, customer_id, retrieval_reference, language_code,
+                      status::text as status, queued_at, sent_at
+            "#,
+        )
+        .bind(notification.id)
+        .bind(notification.document_id)
+        .bind(notification.customer_id)
+        .bind(notification.retrieval_reference.as_str())
+        .bind(String::from_utf8_lossy(&notification.language_code).to_string())
+        .bind(notification.status)
+        .bind(notification.queued_at)
+        .bind(notification.sent_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to queue document notification: {e}")))?;
+
+        DocumentNotificationModel::try_from_row(&result)
+    }
+
+    async fn find_queued_notifications(&self, limit: i64) -> BankingResult<Vec<DocumentNotificationModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, document_id, customer_id, retrieval_reference, language_code,
+                   status::text as status, queued_at, sent_at
+            FROM document_notifications
+            WHERE status = 'Queued'
+            ORDER BY queued_at
+            LIMIT $1
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find queued notifications: {e}")))?;
+
+        let mut notifications = Vec::new();
+        for row in rows {
+            notifications.push(DocumentNotificationModel::try_from_row(&row)?);
+        }
+        Ok(notifications)
+    }
+
+    async fn update_notification_status(&self, notification_id: Uuid, status: DbDocumentNotificationStatus) -> BankingResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE document_notifications
+            SET status = $2::document_notification_status,
+                sent_at = CASE WHEN $2::document_notification_status = 'Sent' THEN NOW() ELSE sent_at END
+            WHERE id = $1
+            "#,
+        )
+        .bind(notification_id)
+        .bind(status)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update notification status: {e}")))?;
+
+        Ok(())
+    }
+}
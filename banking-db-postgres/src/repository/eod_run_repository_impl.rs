@@ -0,0 +1,183 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{DbEodRunStatus, DbEodStepStatus, EodRunModel, EodRunStepModel};
+use banking_db::repository::EodRunRepository;
+use chrono::NaiveDate;
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of EodRunRepository
+pub struct EodRunRepositoryImpl {
+    pool: PgPool,
+}
+
+impl EodRunRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+impl TryFromRow<PgRow> for EodRunModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(EodRunModel {
+            id: row.get("id"),
+            run_date: row.get("run_date"),
+            status: row.get::<String, _>("status")
+                .parse::<DbEodRunStatus>()
+                .map_err(|_| BankingError::Internal("Invalid end-of-day run status".to_string()))?,
+            started_at: row.get("started_at"),
+            finished_at: row.get("finished_at"),
+            forced_reruns: row.get("forced_reruns"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for EodRunStepModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(EodRunStepModel {
+            id: row.get("id"),
+            eod_run_id: row.get("eod_run_id"),
+            run_date: row.get("run_date"),
+            step_name: HeaplessString::try_from(row.get::<String, _>("step_name").as_str())
+                .map_err(|_| BankingError::ValidationError {
+                    field: "step_name".to_string(),
+                    message: "Step name too long".to_string(),
+                })?,
+            step_order: row.get("step_order"),
+            status: row.get::<String, _>("status")
+                .parse::<DbEodStepStatus>()
+                .map_err(|_| BankingError::Internal("Invalid end-of-day step status".to_string()))?,
+            started_at: row.get("started_at"),
+            finished_at: row.get("finished_at"),
+            records_processed: row.get("records_processed"),
+            records_failed: row.get("records_failed"),
+            checkpoint_cursor: row.get("checkpoint_cursor"),
+            error: row
+                .get::<Option<String>, _>("error")
+                .map(|error| HeaplessString::try_from(error.as_str()))
+                .transpose()
+                .map_err(|_| BankingError::ValidationError {
+                    field: "error".to_string(),
+                    message: "Step error too long".to_string(),
+                })?,
+        })
+    }
+}
+
+const EOD_RUN_COLUMNS: &str = r#"
+    id, run_date, status::text as status, started_at, finished_at, forced_reruns
+"#;
+
+const EOD_RUN_STEP_COLUMNS: &str = r#"
+    id, eod_run_id, run_date, step_name, step_order, status::text as status, started_at, finished_at,
+    records_processed, records_failed, checkpoint_cursor, error
+"#;
+
+#[async_trait]
+impl EodRunRepository for EodRunRepositoryImpl {
+    async fn create_run(&self, run: EodRunModel) -> BankingResult<EodRunModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO eod_runs (id, run_date, status, started_at, finished_at, forced_reruns)
+            VALUES ($1, $2, $3::eod_run_status, $4, $5, $6)
+            RETURNING {EOD_RUN_COLUMNS}
+            "#
+        ))
+        .bind(run.id)
+        .bind(run.run_date)
+        .bind(run.status)
+        .bind(run.started_at)
+        .bind(run.finished_at)
+        .bind(run.forced_reruns)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create end-of-day run: {e}")))?;
+
+        EodRunModel::try_from_row(&row)
+    }
+
+    async fn update_run(&self, run: EodRunModel) -> BankingResult<EodRunModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE eod_runs
+            SET status = $2::eod_run_status, started_at = $3, finished_at = $4, forced_reruns = $5
+            WHERE id = $1
+            RETURNING {EOD_RUN_COLUMNS}
+            "#
+        ))
+        .bind(run.id)
+        .bind(run.status)
+        .bind(run.started_at)
+        .bind(run.finished_at)
+        .bind(run.forced_reruns)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update end-of-day run: {e}")))?;
+
+        EodRunModel::try_from_row(&row)
+    }
+
+    async fn find_run_by_date(&self, run_date: NaiveDate) -> BankingResult<Option<EodRunModel>> {
+        let row = sqlx::query(&format!("SELECT {EOD_RUN_COLUMNS} FROM eod_runs WHERE run_date = $1"))
+            .bind(run_date)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find end-of-day run: {e}")))?;
+
+        row.as_ref().map(EodRunModel::try_from_row).transpose()
+    }
+
+    async fn save_step(&self, step: EodRunStepModel) -> BankingResult<EodRunStepModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO eod_run_steps (
+                id, eod_run_id, run_date, step_name, step_order, status, started_at, finished_at,
+                records_processed, records_failed, checkpoint_cursor, error
+            )
+            VALUES ($1, $2, $3, $4, $5, $6::eod_step_status, $7, $8, $9, $10, $11, $12)
+            ON CONFLICT (eod_run_id, step_name) DO UPDATE SET
+                step_order = EXCLUDED.step_order, status = EXCLUDED.status, started_at = EXCLUDED.started_at,
+                finished_at = EXCLUDED.finished_at, records_processed = EXCLUDED.records_processed,
+                records_failed = EXCLUDED.records_failed, checkpoint_cursor = EXCLUDED.checkpoint_cursor,
+                error = EXCLUDED.error
+            RETURNING {EOD_RUN_STEP_COLUMNS}
+            "#
+        ))
+        .bind(step.id)
+        .bind(step.eod_run_id)
+        .bind(step.run_date)
+        .bind(step.step_name.as_str())
+        .bind(step.step_order)
+        .bind(step.status)
+        .bind(step.started_at)
+        .bind(step.finished_at)
+        .bind(step.records_processed)
+        .bind(step.records_failed)
+        .bind(step.checkpoint_cursor)
+        .bind(step.error.as_ref().map(|e| e.as_str()))
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to save end-of-day step: {e}")))?;
+
+        EodRunStepModel::try_from_row(&row)
+    }
+
+    async fn find_steps(&self, eod_run_id: Uuid) -> BankingResult<Vec<EodRunStepModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {EOD_RUN_STEP_COLUMNS} FROM eod_run_steps WHERE eod_run_id = $1 ORDER BY step_order, step_name"
+        ))
+        .bind(eod_run_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find end-of-day steps: {e}")))?;
+
+        rows.iter().map(EodRunStepModel::try_from_row).collect()
+    }
+}
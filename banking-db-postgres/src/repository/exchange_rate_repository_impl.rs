@@ -0,0 +1,109 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::ExchangeRateModel;
+use banking_db::repository::ExchangeRateRepository;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+
+/// PostgreSQL implementation of ExchangeRateRepository
+pub struct ExchangeRateRepositoryImpl {
+    pool: PgPool,
+}
+
+impl ExchangeRateRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+impl TryFromRow<PgRow> for ExchangeRateModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        let currency = |column: &str| {
+            HeaplessString::try_from(row.get::<String, _>(column).as_str())
+                .map_err(|_| BankingError::Internal(format!("Invalid currency in {column}")))
+        };
+        Ok(ExchangeRateModel {
+            from_currency: currency("from_currency")?,
+            to_currency: currency("to_currency")?,
+            rate: row.get("rate"),
+            effective_at: row.get("effective_at"),
+            source: HeaplessString::try_from(row.get::<String, _>("source").as_str())
+                .map_err(|_| BankingError::ValidationError {
+                    field: "source".to_string(),
+                    message: "Rate source too long".to_string(),
+                })?,
+        })
+    }
+}
+
+const RATE_COLUMNS: &str = "from_currency, to_currency, rate, effective_at, source";
+
+#[async_trait]
+impl ExchangeRateRepository for ExchangeRateRepositoryImpl {
+    async fn save_rate(&self, rate: ExchangeRateModel) -> BankingResult<ExchangeRateModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO exchange_rates (from_currency, to_currency, rate, effective_at, source)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (from_currency, to_currency, effective_at)
+            DO UPDATE SET rate = EXCLUDED.rate, source = EXCLUDED.source
+            RETURNING {RATE_COLUMNS}
+            "#
+        ))
+        .bind(rate.from_currency.as_str())
+        .bind(rate.to_currency.as_str())
+        .bind(rate.rate)
+        .bind(rate.effective_at)
+        .bind(rate.source.as_str())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to save exchange rate: {e}")))?;
+
+        ExchangeRateModel::try_from_row(&row)
+    }
+
+    async fn get_rate(&self, base_currency: &str, quote_currency: &str, as_of: DateTime<Utc>) -> BankingResult<Option<ExchangeRateModel>> {
+        let row = sqlx::query(&format!(
+            r#"
+            SELECT {RATE_COLUMNS} FROM exchange_rates
+            WHERE from_currency = $1 AND to_currency = $2 AND effective_at <= $3
+            ORDER BY effective_at DESC
+            LIMIT 1
+            "#
+        ))
+        .bind(base_currency)
+        .bind(quote_currency)
+        .bind(as_of)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to get exchange rate: {e}")))?;
+
+        row.as_ref().map(ExchangeRateModel::try_from_row).transpose()
+    }
+
+    async fn find_rates_by_pair(&self, base_currency: &str, quote_currency: &str, limit: i64) -> BankingResult<Vec<ExchangeRateModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {RATE_COLUMNS} FROM exchange_rates
+            WHERE from_currency = $1 AND to_currency = $2
+            ORDER BY effective_at DESC
+            LIMIT $3
+            "#
+        ))
+        .bind(base_currency)
+        .bind(quote_currency)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find exchange rates: {e}")))?;
+
+        rows.iter().map(ExchangeRateModel::try_from_row).collect()
+    }
+}
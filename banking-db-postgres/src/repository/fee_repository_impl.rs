@@ -94,6 +94,8 @@ impl TryFromRow<sqlx::postgres::PgRow> for FeeApplicationModel {
             waived_by: row.get("waived_by"),
             waived_reason_id: row.get("waived_reason_id"),
             applied_by: row.get("applied_by"),
+            reversal_transaction_id: row.get("reversal_transaction_id"),
+            audit_log_id: row.get("audit_log_id"),
             created_at: row.get("created_at"),
         })
     }
@@ -200,13 +202,15 @@ impl FeeRepository for FeeRepositoryImpl {
                 id, account_id, transaction_id, fee_type, fee_category,
                 product_id, fee_code, description, amount, currency, calculation_method,
                 calculation_base_amount, fee_rate, trigger_event, status, applied_at,
-                value_date, reversal_deadline, waived, waived_by, waived_reason_id, applied_by
+                value_date, reversal_deadline, waived, waived_by, waived_reason_id, applied_by,
+                reversal_transaction_id, audit_log_id
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24)
             RETURNING id, account_id, transaction_id, fee_type, fee_category,
                      product_id, fee_code, description, amount, currency, calculation_method,
                      calculation_base_amount, fee_rate, trigger_event, status, applied_at,
-                     value_date, reversal_deadline, waived, waived_by, waived_reason_id, applied_by, created_at
+                     value_date, reversal_deadline, waived, waived_by, waived_reason_id, applied_by,
+                     reversal_transaction_id, audit_log_id, created_at
             "#
         )
         .bind(fee_application.id)
@@ -231,6 +235,8 @@ impl FeeRepository for FeeRepositoryImpl {
         .bind(fee_application.waived_by)
         .bind(fee_application.waived_reason_id)
         .bind(fee_application.applied_by)
+        .bind(fee_application.reversal_transaction_id)
+        .bind(fee_application.audit_log_id)
         .fetch_one(&self.pool)
         .await?;
         
@@ -249,12 +255,13 @@ impl FeeRepository for FeeRepositoryImpl {
                 calculation_method = $11, calculation_base_amount = $12, fee_rate = $13,
                 trigger_event = $14, status = $15, applied_at = $16, value_date = $17,
                 reversal_deadline = $18, waived = $19, waived_by = $20, waived_reason_id = $21,
-                applied_by = $22
+                applied_by = $22, reversal_transaction_id = $23, audit_log_id = $24
             WHERE id = $1
             RETURNING id, account_id, transaction_id, fee_type, fee_category,
                      product_id, fee_code, description, amount, currency, calculation_method,
                      calculation_base_amount, fee_rate, trigger_event, status, applied_at,
-                     value_date, reversal_deadline, waived, waived_by, waived_reason_id, applied_by, created_at
+                     value_date, reversal_deadline, waived, waived_by, waived_reason_id, applied_by,
+                     reversal_transaction_id, audit_log_id, created_at
             "#
         )
         .bind(fee_application.id)
@@ -279,6 +286,8 @@ impl FeeRepository for FeeRepositoryImpl {
         .bind(fee_application.waived_by)
         .bind(fee_application.waived_reason_id)
         .bind(fee_application.applied_by)
+        .bind(fee_application.reversal_transaction_id)
+        .bind(fee_application.audit_log_id)
         .fetch_one(&self.pool)
         .await?;
         
@@ -427,13 +436,15 @@ impl FeeRepository for FeeRepositoryImpl {
                     id, account_id, transaction_id, fee_type, fee_category,
                     product_id, fee_code, description, amount, currency, calculation_method,
                     calculation_base_amount, fee_rate, trigger_event, status, applied_at,
-                    value_date, reversal_deadline, waived, waived_by, waived_reason_id, applied_by
+                    value_date, reversal_deadline, waived, waived_by, waived_reason_id, applied_by,
+                    reversal_transaction_id, audit_log_id
                 )
-                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24)
                 RETURNING id, account_id, transaction_id, fee_type, fee_category,
                          product_id, fee_code, description, amount, currency, calculation_method,
                          calculation_base_amount, fee_rate, trigger_event, status, applied_at,
-                         value_date, reversal_deadline, waived, waived_by, waived_reason_id, applied_by, created_at
+                         value_date, reversal_deadline, waived, waived_by, waived_reason_id, applied_by,
+                         reversal_transaction_id, audit_log_id, created_at
                 "#
             )
             .bind(app.id)
@@ -458,6 +469,8 @@ impl FeeRepository for FeeRepositoryImpl {
             .bind(app.waived_by)
             .bind(app.waived_reason_id)
             .bind(app.applied_by)
+            .bind(app.reversal_transaction_id)
+            .bind(app.audit_log_id)
             .fetch_one(&mut *tx)
             .await?;
             
@@ -927,7 +940,8 @@ impl FeeRepository for FeeRepositoryImpl {
             RETURNING id, account_id, transaction_id, fee_type, fee_category,
                      product_id, fee_code, description, amount, currency, calculation_method,
                      calculation_base_amount, fee_rate, trigger_event, status, applied_at,
-                     value_date, reversal_deadline, waived, waived_by, waived_reason_id, applied_by, created_at
+                     value_date, reversal_deadline, waived, waived_by, waived_reason_id, applied_by,
+                     reversal_transaction_id, audit_log_id, created_at
             "#
         )
         .bind(id)
@@ -970,7 +984,8 @@ impl FeeRepository for FeeRepositoryImpl {
                 RETURNING id, account_id, transaction_id, fee_type, fee_category,
                          product_id, fee_code, description, amount, currency, calculation_method,
                          calculation_base_amount, fee_rate, trigger_event, status, applied_at,
-                         value_date, reversal_deadline, waived, waived_by, waived_reason_id, applied_by, created_at
+                         value_date, reversal_deadline, waived, waived_by, waived_reason_id, applied_by,
+                         reversal_transaction_id, audit_log_id, created_at
                 "#
             )
             .bind(fee_id)
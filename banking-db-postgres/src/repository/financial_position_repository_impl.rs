@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult, QueryClass};
+use banking_db::models::{CurrencyPositionModel, ExchangeRateModel};
+use banking_db::repository::FinancialPositionRepository;
+use chrono::NaiveDate;
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::repository::executor::Executor;
+
+/// PostgreSQL implementation of FinancialPositionRepository
+pub struct FinancialPositionRepositoryImpl {
+    pool: PgPool,
+    /// Runs the long scans under the `Analytical` statement timeout
+    executor: Executor,
+}
+
+impl FinancialPositionRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        let executor = Executor::Pool(Arc::new(pool.clone()));
+        Self { pool, executor }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+fn currency(row: &PgRow, column: &str) -> BankingResult<HeaplessString<3>> {
+    HeaplessString::try_from(row.get::<String, _>(column).as_str())
+        .map_err(|_| BankingError::Internal(format!("Invalid currency in {column}")))
+}
+
+impl TryFromRow<PgRow> for CurrencyPositionModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(CurrencyPositionModel {
+            currency: currency(row, "currency")?,
+            account_count: row.get("account_count"),
+            total_deposits: row.get("total_deposits"),
+            loan_exposure: row.get("loan_exposure"),
+            holds: row.get("holds"),
+            legal_holds: row.get("legal_holds"),
+            overdraft_used: row.get("overdraft_used"),
+            overdraft_limit: row.get("overdraft_limit"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for ExchangeRateModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(ExchangeRateModel {
+            from_currency: currency(row, "from_currency")?,
+            to_currency: currency(row, "to_currency")?,
+            rate: row.get("rate"),
+            effective_at: row.get("effective_at"),
+            source: HeaplessString::try_from(row.get::<String, _>("source").as_str())
+                .map_err(|_| BankingError::Internal("Invalid exchange rate source".to_string()))?,
+        })
+    }
+}
+
+#[async_trait]
+impl FinancialPositionRepository for FinancialPositionRepositoryImpl {
+    async fn find_currency_positions(&self, customer_id: Uuid) -> BankingResult<Vec<CurrencyPositionModel>> {
+        let rows = self
+            .executor
+            .run(QueryClass::Analytical, move |conn| {
+                Box::pin(async move {
+                    sqlx::query(
+                        r#"
+                        WITH owned AS (
+                            SELECT DISTINCT a.id, a.currency, a.account_type::text as account_type,
+                                   a.current_balance, a.outstanding_principal, a.overdraft_limit
+                            FROM accounts a
+                            JOIN account_ownership o ON o.account_id = a.id
+                            WHERE o.customer_id = $1
+                              AND a.account_status <> 'Closed'::account_status
+                        ),
+                        holds AS (
+                            SELECT h.account_id,
+                                   SUM(h.amount) as holds,
+                                   SUM(h.amount) FILTER (WHERE h.hold_type::text = 'JudicialLien') as legal_holds
+                            FROM account_holds h
+                            JOIN owned ON owned.id = h.account_id
+                            WHERE h.status = 'Active'
+                              AND (h.expires_at IS NULL OR h.expires_at > NOW())
+                            GROUP BY h.account_id
+                        )
+                        SELECT owned.currency,
+                               COUNT(*) as account_count,
+                               COALESCE(SUM(GREATEST(owned.current_balance, 0)) FILTER (WHERE owned.account_type <> 'Loan'), 0) as total_deposits,
+                               COALESCE(SUM(COALESCE(owned.outstanding_principal, 0)) FILTER (WHERE owned.account_type = 'Loan'), 0) as loan_exposure,
+                               COALESCE(SUM(holds.holds), 0) as holds,
+                               COALESCE(SUM(holds.legal_holds), 0) as legal_holds,
+                               COALESCE(SUM(GREATEST(-owned.current_balance, 0)) FILTER (WHERE owned.account_type <> 'Loan'), 0) as overdraft_used,
+                               COALESCE(SUM(owned.overdraft_limit), 0) as overdraft_limit
+                        FROM owned
+                        LEFT JOIN holds ON holds.account_id = owned.id
+                        GROUP BY owned.currency
+                        ORDER BY owned.currency
+                        "#,
+                    )
+                    .bind(customer_id)
+                    .fetch_all(conn)
+                    .await
+                })
+            })
+            .await?;
+
+        rows.iter().map(CurrencyPositionModel::try_from_row).collect()
+    }
+
+    async fn find_exchange_rates(&self, to_currency: &str, as_of: NaiveDate) -> BankingResult<Vec<ExchangeRateModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT DISTINCT ON (from_currency) from_currency, to_currency, rate, effective_at, source
+            FROM exchange_rates
+            WHERE to_currency = $1 AND effective_at < ($2::date + 1)
+            ORDER BY from_currency, effective_at DESC
+            "#,
+        )
+        .bind(to_currency)
+        .bind(as_of)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find exchange rates: {e}")))?;
+
+        rows.iter().map(ExchangeRateModel::try_from_row).collect()
+    }
+}
@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{GlPostingTotalsModel, GlSummaryLineModel, TransactionType};
+use banking_db::repository::GeneralLedgerRepository;
+use chrono::NaiveDate;
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+
+/// PostgreSQL implementation of GeneralLedgerRepository
+pub struct GeneralLedgerRepositoryImpl {
+    pool: PgPool,
+}
+
+impl GeneralLedgerRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+impl TryFromRow<PgRow> for GlPostingTotalsModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        let transaction_type = match row.get::<String, _>("transaction_type").as_str() {
+            "Credit" => TransactionType::Credit,
+            "Debit" => TransactionType::Debit,
+            _ => return Err(BankingError::Internal("Invalid transaction type".to_string())),
+        };
+        Ok(GlPostingTotalsModel {
+            product_id: row.get("product_id"),
+            gl_code_suffix: row
+                .get::<Option<String>, _>("gl_code_suffix")
+                .map(|suffix| HeaplessString::try_from(suffix.as_str()))
+                .transpose()
+                .map_err(|_| BankingError::Internal("gl_code_suffix too long".to_string()))?,
+            branch_id: row.get("branch_id"),
+            currency: HeaplessString::try_from(row.get::<String, _>("currency").as_str())
+                .map_err(|_| BankingError::Internal("currency too long".to_string()))?,
+            channel_id: HeaplessString::try_from(row.get::<String, _>("channel_id").as_str())
+                .map_err(|_| BankingError::Internal("channel_id too long".to_string()))?,
+            transaction_type,
+            amount_total: row.get("amount_total"),
+            transaction_count: row.get("transaction_count"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for GlSummaryLineModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(GlSummaryLineModel {
+            run_date: row.get("run_date"),
+            gl_code: HeaplessString::try_from(row.get::<String, _>("gl_code").as_str())
+                .map_err(|_| BankingError::Internal("gl_code too long".to_string()))?,
+            product_id: row.get("product_id"),
+            branch_id: row.get("branch_id"),
+            currency: HeaplessString::try_from(row.get::<String, _>("currency").as_str())
+                .map_err(|_| BankingError::Internal("currency too long".to_string()))?,
+            debit_total: row.get("debit_total"),
+            credit_total: row.get("credit_total"),
+            transaction_count: row.get("transaction_count"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
+
+const GL_SUMMARY_COLUMNS: &str = r#"
+    run_date, gl_code, product_id, branch_id, currency, debit_total, credit_total, transaction_count, created_at
+"#;
+
+#[async_trait]
+impl GeneralLedgerRepository for GeneralLedgerRepositoryImpl {
+    async fn find_posting_totals(&self, run_date: NaiveDate) -> BankingResult<Vec<GlPostingTotalsModel>> {
+        // A reversed transaction was posted before its reversal, which is a
+        // posting of its own, so both count towards the day's ledger
+        let rows = sqlx::query(
+            r#"
+            SELECT a.product_id, a.gl_code_suffix, a.domicile_agency_branch_id AS branch_id,
+                   t.currency, t.channel_id, t.transaction_type::text AS transaction_type,
+                   SUM(t.amount) AS amount_total, COUNT(*) AS transaction_count
+            FROM transactions t
+            JOIN accounts a ON a.id = t.account_id
+            WHERE t.transaction_date::date = $1
+              AND t.status::text IN ('Posted', 'Reversed')
+            GROUP BY a.product_id, a.gl_code_suffix, a.domicile_agency_branch_id,
+                     t.currency, t.channel_id, t.transaction_type
+            ORDER BY a.product_id, a.domicile_agency_branch_id, t.currency, t.channel_id, t.transaction_type::text
+            "#,
+        )
+        .bind(run_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(GlPostingTotalsModel::try_from_row).collect()
+    }
+
+    async fn replace_gl_summary(&self, run_date: NaiveDate, lines: Vec<GlSummaryLineModel>) -> BankingResult<Vec<GlSummaryLineModel>> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM gl_summary WHERE run_date = $1")
+            .bind(run_date)
+            .execute(&mut *tx)
+            .await?;
+
+        let mut saved = Vec::with_capacity(lines.len());
+        for line in lines {
+            if line.run_date != run_date {
+                return Err(BankingError::ValidationError {
+                    field: "run_date".to_string(),
+                    message: format!("GL summary line for {} in the summary of {run_date}", line.run_date),
+                });
+            }
+            let row = sqlx::query(&format!(
+                r#"
+                INSERT INTO gl_summary (
+                    run_date, gl_code, product_id, branch_id, currency, debit_total, credit_total,
+                    transaction_count, created_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                RETURNING {GL_SUMMARY_COLUMNS}
+                "#
+            ))
+            .bind(line.run_date)
+            .bind(line.gl_code.as_str())
+            .bind(line.product_id)
+            .bind(line.branch_id)
+            .bind(line.currency.as_str())
+            .bind(line.debit_total)
+            .bind(line.credit_total)
+            .bind(line.transaction_count)
+            .bind(line.created_at)
+            .fetch_one(&mut *tx)
+            .await?;
+            saved.push(GlSummaryLineModel::try_from_row(&row)?);
+        }
+
+        tx.commit().await?;
+        Ok(saved)
+    }
+
+    async fn find_gl_summary(&self, run_date: NaiveDate) -> BankingResult<Vec<GlSummaryLineModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {GL_SUMMARY_COLUMNS} FROM gl_summary WHERE run_date = $1 ORDER BY gl_code, product_id, branch_id, currency"
+        ))
+        .bind(run_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(GlSummaryLineModel::try_from_row).collect()
+    }
+}
@@ -0,0 +1,205 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{
+    DbGuarantorStatus, DbGuarantorType, GuaranteedLoanModel, LoanGuarantorModel,
+};
+use banking_db::repository::GuarantorRepository;
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of GuarantorRepository
+pub struct GuarantorRepositoryImpl {
+    pool: PgPool,
+}
+
+impl GuarantorRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+impl TryFromRow<PgRow> for LoanGuarantorModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(LoanGuarantorModel {
+            id: row.get("id"),
+            loan_account_id: row.get("loan_account_id"),
+            guarantor_type: row.get::<String, _>("guarantor_type").parse::<DbGuarantorType>()
+                .map_err(|_| BankingError::Internal("Invalid guarantor type".to_string()))?,
+            guarantor_id: row.get("guarantor_id"),
+            guarantee_amount: row.get("guarantee_amount"),
+            document_reference: row.get::<Option<String>, _>("document_reference")
+                .map(|s| HeaplessString::try_from(s.as_str()))
+                .transpose()
+                .map_err(|_| BankingError::ValidationError {
+                    field: "document_reference".to_string(),
+                    message: "Document reference too long".to_string(),
+                })?,
+            status: row.get::<String, _>("status").parse::<DbGuarantorStatus>()
+                .map_err(|_| BankingError::Internal("Invalid guarantor status".to_string()))?,
+            released_at: row.get("released_at"),
+            released_by_person_id: row.get("released_by_person_id"),
+            created_at: row.get("created_at"),
+            last_updated_at: row.get("last_updated_at"),
+            updated_by_person_id: row.get("updated_by_person_id"),
+        })
+    }
+}
+
+#[async_trait]
+impl GuarantorRepository for GuarantorRepositoryImpl {
+    async fn create_guarantor(&self, guarantor: LoanGuarantorModel) -> BankingResult<LoanGuarantorModel> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO loan_guarantors (
+                id, loan_account_id, guarantor_type, guarantor_id, guarantee_amount,
+                document_reference, status, released_at, released_by_person_id,
+                created_at, last_updated_at, updated_by_person_id
+            )
+            VALUES ($1, $2, $3::guarantor_type, $4, $5, $6, $7::guarantor_status, $8, $9, $10, $11, $12)
+            RETURNING id, loan_account_id, guarantor_type::text as guarantor_type, guarantor_id,
+                      guarantee_amount, document_reference, status::text as status, released_at,
+                      released_by_person_id, created_at, last_updated_at, updated_by_person_id
+            "#,
+        )
+        .bind(guarantor.id)
+        .bind(guarantor.loan_account_id)
+        .bind(guarantor.guarantor_type)
+        .bind(guarantor.guarantor_id)
+        .bind(guarantor.guarantee_amount)
+        .bind(guarantor.document_reference.as_ref().map(|s| s.as_str()))
+        .bind(guarantor.status)
+        .bind(guarantor.released_at)
+        .bind(guarantor.released_by_person_id)
+        .bind(guarantor.created_at)
+        .bind(guarantor.last_updated_at)
+        .bind(guarantor.updated_by_person_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create loan guarantor: {e}")))?;
+
+        LoanGuarantorModel::try_from_row(&result)
+    }
+
+    async fn update_guarantor(&self, guarantor: LoanGuarantorModel) -> BankingResult<LoanGuarantorModel> {
+        let result = sqlx::query(
+            r#"
+            UPDATE loan_guarantors
+            SET guarantee_amount = $2, document_reference = $3, status = $4::guarantor_status,
+                released_at = $5, released_by_person_id = $6, last_updated_at = $7,
+                updated_by_person_id = $8
+            WHERE id = $1
+            RETURNING id, loan_account_id, guarantor_type::text as guarantor_type, guarantor_id,
+                      guarantee_amount, document_reference, status::text as status, released_at,
+                      released_by_person_id, created_at, last_updated_at, updated_by_person_id
+            "#,
+        )
+        .bind(guarantor.id)
+        .bind(guarantor.guarantee_amount)
+        .bind(guarantor.document_reference.as_ref().map(|s| s.as_str()))
+        .bind(guarantor.status)
+        .bind(guarantor.released_at)
+        .bind(guarantor.released_by_person_id)
+        .bind(guarantor.last_updated_at)
+        .bind(guarantor.updated_by_person_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update loan guarantor: {e}")))?;
+
+        LoanGuarantorModel::try_from_row(&result)
+    }
+
+    async fn find_guarantor_by_id(&self, guarantor_link_id: Uuid) -> BankingResult<Option<LoanGuarantorModel>> {
+        let result = sqlx::query(
+            r#"
+            SELECT id, loan_account_id, guarantor_type::text as guarantor_type, guarantor_id,
+                   guarantee_amount, document_reference, status::text as status, released_at,
+                   released_by_person_id, created_at, last_updated_at, updated_by_person_id
+            FROM loan_guarantors
+            WHERE id = $1
+            "#,
+        )
+        .bind(guarantor_link_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find loan guarantor: {e}")))?;
+
+        match result {
+            Some(row) => Ok(Some(LoanGuarantorModel::try_from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_guarantors_by_loan(&self, loan_account_id: Uuid) -> BankingResult<Vec<LoanGuarantorModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, loan_account_id, guarantor_type::text as guarantor_type, guarantor_id,
+                   guarantee_amount, document_reference, status::text as status, released_at,
+                   released_by_person_id, created_at, last_updated_at, updated_by_person_id
+            FROM loan_guarantors
+            WHERE loan_account_id = $1
+            ORDER BY created_at
+            "#,
+        )
+        .bind(loan_account_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find loan guarantors: {e}")))?;
+
+        let mut guarantors = Vec::new();
+        for row in rows {
+            guarantors.push(LoanGuarantorModel::try_from_row(&row)?);
+        }
+        Ok(guarantors)
+    }
+
+    async fn count_active_guarantors(&self, loan_account_id: Uuid) -> BankingResult<i64> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM loan_guarantors WHERE loan_account_id = $1 AND status = 'Active'::guarantor_status",
+        )
+        .bind(loan_account_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to count loan guarantors: {e}")))?;
+
+        Ok(count)
+    }
+
+    async fn find_guaranteed_loans(&self, guarantor_id: Uuid) -> BankingResult<Vec<GuaranteedLoanModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT g.loan_account_id, a.currency,
+                   COALESCE(a.outstanding_principal, 0) as outstanding_principal,
+                   g.guarantee_amount
+            FROM loan_guarantors g
+            JOIN accounts a ON a.id = g.loan_account_id
+            WHERE g.guarantor_id = $1
+              AND g.status = 'Active'::guarantor_status
+              AND a.account_status <> 'Closed'::account_status
+            ORDER BY g.created_at
+            "#,
+        )
+        .bind(guarantor_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find guaranteed loans: {e}")))?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(GuaranteedLoanModel {
+                    loan_account_id: row.get("loan_account_id"),
+                    currency: HeaplessString::try_from(row.get::<String, _>("currency").as_str())
+                        .map_err(|_| BankingError::Internal("Invalid loan currency".to_string()))?,
+                    outstanding_principal: row.get("outstanding_principal"),
+                    guarantee_amount: row.get("guarantee_amount"),
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,177 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{DbNoteCategory, DbNoteEntityKind, DbNoteVisibility, InteractionNoteModel};
+use banking_db::repository::InteractionNoteRepository;
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of InteractionNoteRepository
+pub struct InteractionNoteRepositoryImpl {
+    pool: PgPool,
+}
+
+impl InteractionNoteRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+impl TryFromRow<PgRow> for InteractionNoteModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(InteractionNoteModel {
+            id: row.get("id"),
+            entity_kind: row.get::<String, _>("entity_kind")
+                .parse::<DbNoteEntityKind>()
+                .map_err(|_| BankingError::Internal("Invalid note entity kind".to_string()))?,
+            entity_id: row.get("entity_id"),
+            author_person_id: row.get("author_person_id"),
+            agency_branch_id: row.get("agency_branch_id"),
+            visibility: row.get::<String, _>("visibility")
+                .parse::<DbNoteVisibility>()
+                .map_err(|_| BankingError::Internal("Invalid note visibility".to_string()))?,
+            category: row.get::<String, _>("category")
+                .parse::<DbNoteCategory>()
+                .map_err(|_| BankingError::Internal("Invalid note category".to_string()))?,
+            body: HeaplessString::try_from(row.get::<String, _>("body").as_str()).map_err(|_| {
+                BankingError::ValidationError {
+                    field: "body".to_string(),
+                    message: "body too long".to_string(),
+                }
+            })?,
+            corrects_note_id: row.get("corrects_note_id"),
+            follow_up_date: row.get("follow_up_date"),
+            follow_up_completed_at: row.get("follow_up_completed_at"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
+
+const NOTE_COLUMNS: &str = r#"
+    id, entity_kind::text as entity_kind, entity_id, author_person_id, agency_branch_id,
+    visibility::text as visibility, category::text as category, body, corrects_note_id,
+    follow_up_date, follow_up_completed_at, created_at
+"#;
+
+#[async_trait]
+impl InteractionNoteRepository for InteractionNoteRepositoryImpl {
+    async fn create_note(&self, note: InteractionNoteModel) -> BankingResult<InteractionNoteModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO interaction_notes (
+                id, entity_kind, entity_id, author_person_id, agency_branch_id, visibility,
+                category, body, corrects_note_id, follow_up_date, follow_up_completed_at, created_at
+            )
+            VALUES ($1, $2::note_entity_kind, $3, $4, $5, $6::note_visibility, $7::note_category, $8, $9, $10, $11, $12)
+            RETURNING {NOTE_COLUMNS}
+            "#
+        ))
+        .bind(note.id)
+        .bind(note.entity_kind)
+        .bind(note.entity_id)
+        .bind(note.author_person_id)
+        .bind(note.agency_branch_id)
+        .bind(note.visibility)
+        .bind(note.category)
+        .bind(note.body.as_str())
+        .bind(note.corrects_note_id)
+        .bind(note.follow_up_date)
+        .bind(note.follow_up_completed_at)
+        .bind(note.created_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create interaction note: {e}")))?;
+
+        InteractionNoteModel::try_from_row(&row)
+    }
+
+    async fn find_note_by_id(&self, note_id: Uuid) -> BankingResult<Option<InteractionNoteModel>> {
+        let row = sqlx::query(&format!("SELECT {NOTE_COLUMNS} FROM interaction_notes WHERE id = $1"))
+            .bind(note_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find interaction note: {e}")))?;
+
+        row.as_ref().map(InteractionNoteModel::try_from_row).transpose()
+    }
+
+    async fn find_notes_by_entity(&self, entity_kind: DbNoteEntityKind, entity_id: Uuid) -> BankingResult<Vec<InteractionNoteModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {NOTE_COLUMNS}
+            FROM interaction_notes
+            WHERE entity_kind = $1::note_entity_kind AND entity_id = $2
+            ORDER BY created_at DESC
+            "#
+        ))
+        .bind(entity_kind)
+        .bind(entity_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find interaction notes: {e}")))?;
+
+        rows.iter().map(InteractionNoteModel::try_from_row).collect()
+    }
+
+    async fn find_pending_follow_ups_by_author(&self, author_person_id: Uuid) -> BankingResult<Vec<InteractionNoteModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {NOTE_COLUMNS}
+            FROM interaction_notes
+            WHERE author_person_id = $1
+              AND follow_up_date IS NOT NULL
+              AND follow_up_completed_at IS NULL
+            ORDER BY follow_up_date, created_at
+            "#
+        ))
+        .bind(author_person_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find pending follow-ups: {e}")))?;
+
+        rows.iter().map(InteractionNoteModel::try_from_row).collect()
+    }
+
+    async fn find_follow_ups_due(&self, date: NaiveDate) -> BankingResult<Vec<InteractionNoteModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {NOTE_COLUMNS}
+            FROM interaction_notes
+            WHERE follow_up_date <= $1
+              AND follow_up_completed_at IS NULL
+            ORDER BY author_person_id, follow_up_date, created_at
+            "#
+        ))
+        .bind(date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find due follow-ups: {e}")))?;
+
+        rows.iter().map(InteractionNoteModel::try_from_row).collect()
+    }
+
+    async fn complete_follow_up(&self, note_id: Uuid, completed_at: DateTime<Utc>) -> BankingResult<InteractionNoteModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE interaction_notes
+            SET follow_up_completed_at = $2
+            WHERE id = $1
+            RETURNING {NOTE_COLUMNS}
+            "#
+        ))
+        .bind(note_id)
+        .bind(completed_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to complete follow-up: {e}")))?;
+
+        InteractionNoteModel::try_from_row(&row)
+    }
+}
@@ -0,0 +1,253 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{
+    DbTimelineCategory, DbTimelineSource, TimelineAccessRecordModel, TimelineCursorModel, TimelineEventModel,
+};
+use banking_db::repository::InvestigationRepository;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of InvestigationRepository
+pub struct InvestigationRepositoryImpl {
+    pool: PgPool,
+}
+
+impl InvestigationRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+fn heapless<const N: usize>(value: String, field: &str) -> BankingResult<HeaplessString<N>> {
+    HeaplessString::try_from(value.as_str()).map_err(|_| BankingError::ValidationError {
+        field: field.to_string(),
+        message: format!("{field} too long"),
+    })
+}
+
+impl TryFromRow<PgRow> for TimelineEventModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(TimelineEventModel {
+            occurred_at: row.get("occurred_at"),
+            category: row.get::<String, _>("category")
+                .parse::<DbTimelineCategory>()
+                .map_err(|_| BankingError::Internal("Invalid timeline category".to_string()))?,
+            actor_person_id: row.get("actor_person_id"),
+            summary: heapless(row.get("summary"), "summary")?,
+            reference_id: row.get("reference_id"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for TimelineAccessRecordModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(TimelineAccessRecordModel {
+            id: row.get("id"),
+            account_id: row.get("account_id"),
+            from_date: row.get("from_date"),
+            to_date: row.get("to_date"),
+            accessed_by_person_id: row.get("accessed_by_person_id"),
+            case_reference: heapless(row.get("case_reference"), "case_reference")?,
+            events_returned: row.get("events_returned"),
+            exported: row.get("exported"),
+            accessed_at: row.get("accessed_at"),
+        })
+    }
+}
+
+const ACCESS_COLUMNS: &str = r#"
+    id, account_id, from_date, to_date, accessed_by_person_id, case_reference,
+    events_returned, exported, accessed_at
+"#;
+
+/// Rows of the source normalized to occurred_at, category, actor_person_id,
+/// summary and reference_id, for account $1 between $2 (inclusive) and $3
+fn source_query(source: DbTimelineSource) -> &'static str {
+    match source {
+        DbTimelineSource::Workflows => r#"
+            SELECT initiated_at AS occurred_at, 'WorkflowCreated'::text AS category, initiated_by AS actor_person_id,
+                   'Workflow ' || workflow_type::text || ' started' AS summary, id AS reference_id
+            FROM account_workflows
+            WHERE account_id = $1 AND initiated_at >= $2 AND initiated_at < $3
+            UNION ALL
+            SELECT completed_at, 'WorkflowCompleted'::text, NULL::uuid,
+                   'Workflow ' || workflow_type::text || ' ended ' || status::text, id
+            FROM account_workflows
+            WHERE account_id = $1 AND completed_at >= $2 AND completed_at < $3
+        "#,
+        DbTimelineSource::WorkflowSteps => r#"
+            SELECT s.completed_at AS occurred_at, 'WorkflowStep'::text AS category, s.completed_by AS actor_person_id,
+                   'Step ' || s.step::text || COALESCE(': ' || s.notes, '') AS summary, s.workflow_id AS reference_id
+            FROM workflow_step_records s
+            JOIN account_workflows w ON w.id = s.workflow_id
+            WHERE w.account_id = $1 AND s.completed_at >= $2 AND s.completed_at < $3
+        "#,
+        DbTimelineSource::StatusChanges => r#"
+            SELECT changed_at AS occurred_at, 'StatusChange'::text AS category, changed_by_person_id AS actor_person_id,
+                   'Status ' || COALESCE(old_status::text, 'none') || ' -> ' || new_status::text
+                       || COALESCE(': ' || additional_context, '') AS summary,
+                   id AS reference_id
+            FROM account_status_change_records
+            WHERE account_id = $1 AND changed_at >= $2 AND changed_at < $3
+        "#,
+        DbTimelineSource::Holds => r#"
+            SELECT placed_at AS occurred_at, 'HoldPlaced'::text AS category, placed_by_person_id AS actor_person_id,
+                   hold_type::text || ' hold of ' || amount::text || COALESCE(': ' || additional_details, '') AS summary,
+                   id AS reference_id
+            FROM account_holds
+            WHERE account_id = $1 AND placed_at >= $2 AND placed_at < $3
+            UNION ALL
+            SELECT released_at, 'HoldReleased'::text, released_by_person_id,
+                   hold_type::text || ' hold of ' || amount::text || ' released', id
+            FROM account_holds
+            WHERE account_id = $1 AND released_at >= $2 AND released_at < $3
+        "#,
+        DbTimelineSource::Restrictions => r#"
+            SELECT r.starts_at AS occurred_at, 'RestrictionPlaced'::text AS category, NULL::uuid AS actor_person_id,
+                   'Channel restricted: ' || r.reason AS summary, r.id AS reference_id
+            FROM channel_restrictions r
+            JOIN account_ownership o ON o.customer_id = r.customer_id
+            WHERE o.account_id = $1 AND r.starts_at >= $2 AND r.starts_at < $3
+            UNION ALL
+            SELECT r.lifted_at, 'RestrictionLifted'::text, r.lifted_by_person_id,
+                   'Channel restriction lifted: ' || r.reason, r.id
+            FROM channel_restrictions r
+            JOIN account_ownership o ON o.customer_id = r.customer_id
+            WHERE o.account_id = $1 AND r.lifted_at >= $2 AND r.lifted_at < $3
+        "#,
+        DbTimelineSource::Transactions => r#"
+            SELECT transaction_date AS occurred_at, 'Transaction'::text AS category, agent_person_id AS actor_person_id,
+                   transaction_type::text || ' ' || amount::text || ' ' || currency || ' ' || reference_number
+                       || ': ' || description AS summary,
+                   id AS reference_id
+            FROM transactions
+            WHERE account_id = $1 AND transaction_date >= $2 AND transaction_date < $3
+        "#,
+        DbTimelineSource::TransactionDays => r#"
+            SELECT MIN(transaction_date) AS occurred_at, 'TransactionSummary'::text AS category, NULL::uuid AS actor_person_id,
+                   COUNT(*) || ' transactions on ' || (transaction_date AT TIME ZONE 'UTC')::date || ': '
+                       || COUNT(*) FILTER (WHERE transaction_type::text = 'Credit') || ' credits totalling '
+                       || COALESCE(SUM(amount) FILTER (WHERE transaction_type::text = 'Credit'), 0) || ', '
+                       || COUNT(*) FILTER (WHERE transaction_type::text = 'Debit') || ' debits totalling '
+                       || COALESCE(SUM(amount) FILTER (WHERE transaction_type::text = 'Debit'), 0) AS summary,
+                   (ARRAY_AGG(id ORDER BY transaction_date, id))[1] AS reference_id
+            FROM transactions
+            WHERE account_id = $1 AND transaction_date >= $2 AND transaction_date < $3
+            GROUP BY (transaction_date AT TIME ZONE 'UTC')::date
+        "#,
+        DbTimelineSource::Notifications => r#"
+            SELECT n.sent_at AS occurred_at, 'NotificationSent'::text AS category, NULL::uuid AS actor_person_id,
+                   n.template_code || ': ' || n.body AS summary, n.id AS reference_id
+            FROM queued_notifications n
+            JOIN account_ownership o ON o.customer_id = n.customer_id
+            WHERE o.account_id = $1 AND n.sent_at >= $2 AND n.sent_at < $3
+        "#,
+    }
+}
+
+#[async_trait]
+impl InvestigationRepository for InvestigationRepositoryImpl {
+    async fn account_exists(&self, account_id: Uuid) -> BankingResult<bool> {
+        sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM accounts WHERE id = $1)")
+            .bind(account_id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to check account: {e}")))
+    }
+
+    async fn find_timeline_page(
+        &self,
+        source: DbTimelineSource,
+        account_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        after: Option<TimelineCursorModel>,
+        limit: i64,
+    ) -> BankingResult<Vec<TimelineEventModel>> {
+        // Category names compare bytewise so the order matches TimelineEvent::sort_key
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT occurred_at, category, actor_person_id, LEFT(summary, 500) AS summary, reference_id
+            FROM ({}) timeline
+            WHERE $4::timestamptz IS NULL
+               OR occurred_at > $4
+               OR (occurred_at = $4 AND (category COLLATE "C" > $5::text
+                   OR (category = $5::text AND reference_id > $6::uuid)))
+            ORDER BY occurred_at, category COLLATE "C", reference_id
+            LIMIT $7
+            "#,
+            source_query(source)
+        ))
+        .bind(account_id)
+        .bind(from)
+        .bind(to)
+        .bind(after.as_ref().map(|cursor| cursor.occurred_at))
+        .bind(after.as_ref().map(|cursor| cursor.category.to_string()))
+        .bind(after.as_ref().map(|cursor| cursor.reference_id))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to read {source:?} timeline: {e}")))?;
+
+        rows.iter().map(TimelineEventModel::try_from_row).collect()
+    }
+
+    async fn count_transactions(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> BankingResult<i64> {
+        sqlx::query_scalar(
+            "SELECT COUNT(*) FROM transactions WHERE account_id = $1 AND transaction_date >= $2 AND transaction_date < $3",
+        )
+        .bind(account_id)
+        .bind(from)
+        .bind(to)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to count transactions: {e}")))
+    }
+
+    async fn record_access(&self, record: TimelineAccessRecordModel) -> BankingResult<TimelineAccessRecordModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO timeline_access_log (
+                id, account_id, from_date, to_date, accessed_by_person_id, case_reference,
+                events_returned, exported, accessed_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING {ACCESS_COLUMNS}
+            "#
+        ))
+        .bind(record.id)
+        .bind(record.account_id)
+        .bind(record.from_date)
+        .bind(record.to_date)
+        .bind(record.accessed_by_person_id)
+        .bind(record.case_reference.as_str())
+        .bind(record.events_returned)
+        .bind(record.exported)
+        .bind(record.accessed_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to record timeline access: {e}")))?;
+
+        TimelineAccessRecordModel::try_from_row(&row)
+    }
+
+    async fn find_access_by_account(&self, account_id: Uuid) -> BankingResult<Vec<TimelineAccessRecordModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {ACCESS_COLUMNS} FROM timeline_access_log WHERE account_id = $1 ORDER BY accessed_at DESC"
+        ))
+        .bind(account_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find timeline access: {e}")))?;
+
+        rows.iter().map(TimelineAccessRecordModel::try_from_row).collect()
+    }
+}
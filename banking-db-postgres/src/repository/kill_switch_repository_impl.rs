@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{DbKillSwitchScope, DbKillSwitchState, KillSwitchModel};
+use banking_db::repository::KillSwitchRepository;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of KillSwitchRepository
+pub struct KillSwitchRepositoryImpl {
+    pool: PgPool,
+}
+
+impl KillSwitchRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+fn heapless<const N: usize>(value: String, field: &str) -> BankingResult<HeaplessString<N>> {
+    HeaplessString::try_from(value.as_str()).map_err(|_| BankingError::ValidationError {
+        field: field.to_string(),
+        message: format!("{field} too long"),
+    })
+}
+
+impl TryFromRow<PgRow> for KillSwitchModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(KillSwitchModel {
+            id: row.get("id"),
+            scope: row.get::<String, _>("scope")
+                .parse::<DbKillSwitchScope>()
+                .map_err(|_| BankingError::Internal("Invalid kill switch scope".to_string()))?,
+            scope_value: row
+                .get::<Option<String>, _>("scope_value")
+                .map(|value| heapless(value, "scope_value"))
+                .transpose()?,
+            state: row.get::<String, _>("state")
+                .parse::<DbKillSwitchState>()
+                .map_err(|_| BankingError::Internal("Invalid kill switch state".to_string()))?,
+            reason: heapless(row.get("reason"), "reason")?,
+            activated_by_person_id: row.get("activated_by_person_id"),
+            activated_at: row.get("activated_at"),
+            auto_expire_at: row.get("auto_expire_at"),
+            deactivated_by_person_id: row.get("deactivated_by_person_id"),
+            deactivated_at: row.get("deactivated_at"),
+        })
+    }
+}
+
+const KILL_SWITCH_COLUMNS: &str = r#"
+    id, scope::text as scope, scope_value, state::text as state, reason,
+    activated_by_person_id, activated_at, auto_expire_at,
+    deactivated_by_person_id, deactivated_at
+"#;
+
+#[async_trait]
+impl KillSwitchRepository for KillSwitchRepositoryImpl {
+    async fn create_kill_switch(&self, kill_switch: KillSwitchModel) -> BankingResult<KillSwitchModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO kill_switches (
+                id, scope, scope_value, state, reason, activated_by_person_id, activated_at,
+                auto_expire_at, deactivated_by_person_id, deactivated_at
+            )
+            VALUES ($1, $2::kill_switch_scope, $3, $4::kill_switch_state, $5, $6, $7, $8, $9, $10)
+            RETURNING {KILL_SWITCH_COLUMNS}
+            "#
+        ))
+        .bind(kill_switch.id)
+        .bind(kill_switch.scope)
+        .bind(kill_switch.scope_value.as_ref().map(|value| value.as_str()))
+        .bind(kill_switch.state)
+        .bind(kill_switch.reason.as_str())
+        .bind(kill_switch.activated_by_person_id)
+        .bind(kill_switch.activated_at)
+        .bind(kill_switch.auto_expire_at)
+        .bind(kill_switch.deactivated_by_person_id)
+        .bind(kill_switch.deactivated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create kill switch: {e}")))?;
+
+        KillSwitchModel::try_from_row(&row)
+    }
+
+    async fn update_kill_switch(&self, kill_switch: KillSwitchModel) -> BankingResult<KillSwitchModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE kill_switches
+            SET state = $2::kill_switch_state, auto_expire_at = $3,
+                deactivated_by_person_id = $4, deactivated_at = $5
+            WHERE id = $1
+            RETURNING {KILL_SWITCH_COLUMNS}
+            "#
+        ))
+        .bind(kill_switch.id)
+        .bind(kill_switch.state)
+        .bind(kill_switch.auto_expire_at)
+        .bind(kill_switch.deactivated_by_person_id)
+        .bind(kill_switch.deactivated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update kill switch: {e}")))?;
+
+        KillSwitchModel::try_from_row(&row)
+    }
+
+    async fn find_kill_switch_by_id(&self, kill_switch_id: Uuid) -> BankingResult<Option<KillSwitchModel>> {
+        let row = sqlx::query(&format!("SELECT {KILL_SWITCH_COLUMNS} FROM kill_switches WHERE id = $1"))
+            .bind(kill_switch_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find kill switch: {e}")))?;
+
+        row.as_ref().map(KillSwitchModel::try_from_row).transpose()
+    }
+
+    async fn find_active_kill_switches(&self) -> BankingResult<Vec<KillSwitchModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {KILL_SWITCH_COLUMNS} FROM kill_switches WHERE state = 'Active' ORDER BY activated_at"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find active kill switches: {e}")))?;
+
+        rows.iter().map(KillSwitchModel::try_from_row).collect()
+    }
+
+    async fn find_kill_switches_activated_between(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> BankingResult<Vec<KillSwitchModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {KILL_SWITCH_COLUMNS}
+            FROM kill_switches
+            WHERE activated_at BETWEEN $1 AND $2
+            ORDER BY activated_at
+            "#
+        ))
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find kill switch activations: {e}")))?;
+
+        rows.iter().map(KillSwitchModel::try_from_row).collect()
+    }
+}
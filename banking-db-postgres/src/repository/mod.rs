@@ -1,4 +1,5 @@
 pub mod executor;
+pub mod savepoint;
 // #[cfg(feature = "customer")]
 // pub mod customer_repository_impl;
 // #[cfg(feature = "agent_network")]
@@ -7,6 +8,8 @@ pub mod executor;
 // pub mod calendar_repository_impl;
 // #[cfg(feature = "account")]
 // pub mod account_repository_impl;
+// #[cfg(feature = "account")]
+// pub mod account_number_repository_impl;
 // #[cfg(feature = "account_hold")]
 // pub mod account_hold_repository_impl;
 // #[cfg(feature = "transaction")]
@@ -20,6 +23,8 @@ pub mod person;
 // pub mod daily_collection_repository_impl;
 // #[cfg(feature = "workflow")]
 // pub mod workflow_repository_impl;
+// #[cfg(feature = "workflow")]
+// pub mod workflow_escalation_repository_impl;
 // #[cfg(feature = "fee")]
 // pub mod fee_repository_impl;
 // #[cfg(feature = "reason_and_purpose")]
@@ -28,5 +33,66 @@ pub mod person;
 // pub mod channel_repository_impl;
 // #[cfg(feature = "product")]
 // pub mod product_repository_impl;
+// #[cfg(feature = "document")]
+// pub mod document_repository_impl;
+// #[cfg(feature = "segment")]
+// pub mod segment_query_builder;
+// pub mod tag_filter_sql;
+// #[cfg(feature = "segment")]
+// pub mod segment_repository_impl;
+// #[cfg(feature = "guarantor")]
+// pub mod guarantor_repository_impl;
+// #[cfg(feature = "quote")]
+// pub mod quote_repository_impl;
+// #[cfg(feature = "cheque")]
+// pub mod cheque_repository_impl;
+// #[cfg(feature = "statement")]
+// pub mod statement_repository_impl;
+// #[cfg(feature = "orchestration")]
+// pub mod orchestration_repository_impl;
+// #[cfg(feature = "tag")]
+// pub mod tag_repository_impl;
+// #[cfg(feature = "channel_security")]
+// pub mod channel_security_repository_impl;
+// #[cfg(feature = "agent_commission")]
+// pub mod agent_commission_repository_impl;
+// #[cfg(feature = "document_registry")]
+// pub mod document_registry_repository_impl;
+// #[cfg(feature = "branch_cash")]
+// pub mod branch_cash_repository_impl;
+// #[cfg(feature = "notification")]
+// pub mod notification_repository_impl;
+// #[cfg(feature = "investigation")]
+// pub mod investigation_repository_impl;
+// #[cfg(feature = "bundle")]
+// pub mod bundle_repository_impl;
+// #[cfg(feature = "financial_position")]
+// pub mod financial_position_repository_impl;
+// #[cfg(feature = "financial_position")]
+// pub mod exchange_rate_repository_impl;
+// #[cfg(feature = "promotion")]
+// pub mod promotion_repository_impl;
+// #[cfg(feature = "savings_goal")]
+// pub mod savings_goal_repository_impl;
+// #[cfg(feature = "payee")]
+// pub mod payee_repository_impl;
+// #[cfg(feature = "back_dated_posting")]
+// pub mod back_dated_posting_repository_impl;
+// #[cfg(feature = "account_domicile")]
+// pub mod account_domicile_repository_impl;
+// #[cfg(feature = "kill_switch")]
+// pub mod kill_switch_repository_impl;
+// #[cfg(feature = "interaction_note")]
+// pub mod interaction_note_repository_impl;
+// #[cfg(feature = "verification")]
+// pub mod verification_repository_impl;
+// #[cfg(feature = "warehouse_export")]
+// pub mod warehouse_export_repository_impl;
+// #[cfg(feature = "standing_order")]
+// pub mod standing_order_repository_impl;
+// #[cfg(feature = "general_ledger")]
+// pub mod general_ledger_repository_impl;
+// #[cfg(feature = "eod_run")]
+// pub mod eod_run_repository_impl;
 pub mod audit;
 pub mod unit_of_work_impl;
\ No newline at end of file
@@ -0,0 +1,251 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{
+    DbDocumentNotificationStatus, DbMessageChannel, MessageTemplateModel, NotificationDuplicateSummaryModel,
+    QueuedNotificationModel,
+};
+use banking_db::repository::NotificationRepository;
+use chrono::NaiveDate;
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of NotificationRepository
+pub struct NotificationRepositoryImpl {
+    pool: PgPool,
+}
+
+impl NotificationRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+fn heapless<const N: usize>(value: String, field: &str) -> BankingResult<HeaplessString<N>> {
+    HeaplessString::try_from(value.as_str()).map_err(|_| BankingError::ValidationError {
+        field: field.to_string(),
+        message: format!("{field} too long"),
+    })
+}
+
+impl TryFromRow<PgRow> for QueuedNotificationModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(QueuedNotificationModel {
+            id: row.get("id"),
+            idempotency_key: heapless(row.get("idempotency_key"), "idempotency_key")?,
+            template_code: heapless(row.get("template_code"), "template_code")?,
+            customer_id: row.get("customer_id"),
+            business_date: row.get("business_date"),
+            body: heapless(row.get("body"), "body")?,
+            status: row.get::<String, _>("status")
+                .parse::<DbDocumentNotificationStatus>()
+                .map_err(|_| BankingError::Internal("Invalid notification status".to_string()))?,
+            queued_at: row.get("queued_at"),
+            sent_at: row.get("sent_at"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for MessageTemplateModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        let language_code: String = row.get("language_code");
+        Ok(MessageTemplateModel {
+            id: row.get("id"),
+            template_code: heapless(row.get("template_code"), "template_code")?,
+            language_code: language_code.as_bytes().try_into().map_err(|_| BankingError::ValidationError {
+                field: "language_code".to_string(),
+                message: "Language code must be 3 characters".to_string(),
+            })?,
+            channel: row.get::<String, _>("channel")
+                .parse::<DbMessageChannel>()
+                .map_err(|_| BankingError::Internal("Invalid message channel".to_string()))?,
+            subject: row.get::<Option<String>, _>("subject")
+                .map(|subject| heapless(subject, "subject"))
+                .transpose()?,
+            body: heapless(row.get("body"), "body")?,
+            regulatory: row.get("regulatory"),
+            updated_at: row.get("updated_at"),
+        })
+    }
+}
+
+const MESSAGE_TEMPLATE_COLUMNS: &str = r#"
+    id, template_code, language_code, channel::text as channel, subject, body, regulatory, updated_at
+"#;
+
+const NOTIFICATION_COLUMNS: &str = r#"
+    id, idempotency_key, template_code, customer_id, business_date, body,
+    status::text as status, queued_at, sent_at
+"#;
+
+#[async_trait]
+impl NotificationRepository for NotificationRepositoryImpl {
+    async fn queue_notification(&self, notification: QueuedNotificationModel) -> BankingResult<QueuedNotificationModel> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| BankingError::Internal(format!("Failed to start transaction: {e}")))?;
+
+        // The key row lock serializes concurrent reruns: the second waits and sees the first's id
+        let keyed_notification_id: Uuid = sqlx::query_scalar(
+            r#"
+            INSERT INTO notification_idempotency_keys (
+                idempotency_key, notification_id, template_code, business_date, duplicates_suppressed
+            )
+            VALUES ($1, $2, $3, $4, 0)
+            ON CONFLICT (idempotency_key) DO UPDATE SET
+                duplicates_suppressed = notification_idempotency_keys.duplicates_suppressed + 1,
+                last_duplicate_at = NOW()
+            RETURNING notification_id
+            "#,
+        )
+        .bind(notification.idempotency_key.as_str())
+        .bind(notification.id)
+        .bind(notification.template_code.as_str())
+        .bind(notification.business_date)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to record notification key: {e}")))?;
+
+        let row = if keyed_notification_id == notification.id {
+            sqlx::query(&format!(
+                r#"
+                INSERT INTO queued_notifications (
+                    id, idempotency_key, template_code, customer_id, business_date, body, status, queued_at, sent_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7::document_notification_status, $8, $9)
+                RETURNING {NOTIFICATION_COLUMNS}
+                "#
+            ))
+            .bind(notification.id)
+            .bind(notification.idempotency_key.as_str())
+            .bind(notification.template_code.as_str())
+            .bind(notification.customer_id)
+            .bind(notification.business_date)
+            .bind(notification.body.as_str())
+            .bind(notification.status)
+            .bind(notification.queued_at)
+            .bind(notification.sent_at)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to queue notification: {e}")))?
+        } else {
+            sqlx::query(&format!("SELECT {NOTIFICATION_COLUMNS} FROM queued_notifications WHERE id = $1"))
+                .bind(keyed_notification_id)
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(|e| BankingError::Internal(format!("Failed to find queued notification: {e}")))?
+        };
+
+        tx.commit().await
+            .map_err(|e| BankingError::Internal(format!("Failed to commit notification: {e}")))?;
+        QueuedNotificationModel::try_from_row(&row)
+    }
+
+    async fn find_notification_by_id(&self, notification_id: Uuid) -> BankingResult<Option<QueuedNotificationModel>> {
+        let row = sqlx::query(&format!("SELECT {NOTIFICATION_COLUMNS} FROM queued_notifications WHERE id = $1"))
+            .bind(notification_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find notification: {e}")))?;
+
+        row.as_ref().map(QueuedNotificationModel::try_from_row).transpose()
+    }
+
+    async fn find_notifications_by_customer(&self, customer_id: Uuid, business_date: NaiveDate) -> BankingResult<Vec<QueuedNotificationModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {NOTIFICATION_COLUMNS} FROM queued_notifications
+            WHERE customer_id = $1 AND business_date = $2
+            ORDER BY queued_at
+            "#
+        ))
+        .bind(customer_id)
+        .bind(business_date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find notifications: {e}")))?;
+
+        rows.iter().map(QueuedNotificationModel::try_from_row).collect()
+    }
+
+    async fn summarize_duplicates(&self, business_date: NaiveDate) -> BankingResult<Vec<NotificationDuplicateSummaryModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT template_code,
+                   COUNT(*) as notifications_queued,
+                   COALESCE(SUM(duplicates_suppressed), 0)::bigint as duplicates_suppressed
+            FROM notification_idempotency_keys
+            WHERE business_date = $1
+            GROUP BY template_code
+            ORDER BY template_code
+            "#,
+        )
+        .bind(business_date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to summarize notification duplicates: {e}")))?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(NotificationDuplicateSummaryModel {
+                    template_code: heapless(row.get("template_code"), "template_code")?,
+                    notifications_queued: row.get("notifications_queued"),
+                    duplicates_suppressed: row.get("duplicates_suppressed"),
+                })
+            })
+            .collect()
+    }
+
+    async fn delete_keys_before(&self, cutoff: NaiveDate) -> BankingResult<u64> {
+        let result = sqlx::query("DELETE FROM notification_idempotency_keys WHERE business_date < $1")
+            .bind(cutoff)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to delete notification keys: {e}")))?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn save_message_template(&self, template: MessageTemplateModel) -> BankingResult<MessageTemplateModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO message_templates (id, template_code, language_code, channel, subject, body, regulatory, updated_at)
+            VALUES ($1, $2, $3, $4::message_channel, $5, $6, $7, $8)
+            ON CONFLICT (template_code, language_code) DO UPDATE SET
+                channel = EXCLUDED.channel, subject = EXCLUDED.subject, body = EXCLUDED.body,
+                regulatory = EXCLUDED.regulatory, updated_at = EXCLUDED.updated_at
+            RETURNING {MESSAGE_TEMPLATE_COLUMNS}
+            "#
+        ))
+        .bind(template.id)
+        .bind(template.template_code.as_str())
+        .bind(String::from_utf8_lossy(&template.language_code).to_string())
+        .bind(template.channel)
+        .bind(template.subject.as_ref().map(|s| s.as_str()))
+        .bind(template.body.as_str())
+        .bind(template.regulatory)
+        .bind(template.updated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to save message template: {e}")))?;
+
+        MessageTemplateModel::try_from_row(&row)
+    }
+
+    async fn find_message_templates(&self, template_code: &str) -> BankingResult<Vec<MessageTemplateModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {MESSAGE_TEMPLATE_COLUMNS} FROM message_templates WHERE template_code = $1 ORDER BY language_code"
+        ))
+        .bind(template_code)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find message templates: {e}")))?;
+
+        rows.iter().map(MessageTemplateModel::try_from_row).collect()
+    }
+}
@@ -0,0 +1,231 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{
+    DbOrchestrationStatus, DbOrchestrationStepStatus, DbOrchestrationType, OrchestrationModel,
+    OrchestrationStepModel,
+};
+use banking_db::repository::OrchestrationRepository;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of OrchestrationRepository
+pub struct OrchestrationRepositoryImpl {
+    pool: PgPool,
+}
+
+impl OrchestrationRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+fn heapless<const N: usize>(value: Option<String>, field: &str) -> BankingResult<Option<HeaplessString<N>>> {
+    value
+        .map(|v| {
+            HeaplessString::try_from(v.as_str()).map_err(|_| BankingError::ValidationError {
+                field: field.to_string(),
+                message: format!("{field} too long"),
+            })
+        })
+        .transpose()
+}
+
+impl TryFromRow<PgRow> for OrchestrationModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(OrchestrationModel {
+            id: row.get("id"),
+            orchestration_type: row.get::<String, _>("orchestration_type")
+                .parse::<DbOrchestrationType>()
+                .map_err(|_| BankingError::Internal("Invalid orchestration type".to_string()))?,
+            subject_id: row.get("subject_id"),
+            status: row.get::<String, _>("status")
+                .parse::<DbOrchestrationStatus>()
+                .map_err(|_| BankingError::Internal("Invalid orchestration status".to_string()))?,
+            failure_reason: heapless(row.get("failure_reason"), "failure_reason")?,
+            started_at: row.get("started_at"),
+            finished_at: row.get("finished_at"),
+            initiated_by_person_id: row.get("initiated_by_person_id"),
+            resolved_by_person_id: row.get("resolved_by_person_id"),
+            resolution_note: heapless(row.get("resolution_note"), "resolution_note")?,
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for OrchestrationStepModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(OrchestrationStepModel {
+            id: row.get("id"),
+            orchestration_id: row.get("orchestration_id"),
+            sequence: row.get("sequence"),
+            step_name: heapless(Some(row.get("step_name")), "step_name")?.unwrap_or_default(),
+            compensation: row.get("compensation"),
+            status: row.get::<String, _>("status")
+                .parse::<DbOrchestrationStepStatus>()
+                .map_err(|_| BankingError::Internal("Invalid orchestration step status".to_string()))?,
+            completed_at: row.get("completed_at"),
+            compensated_at: row.get("compensated_at"),
+            last_error: heapless(row.get("last_error"), "last_error")?,
+        })
+    }
+}
+
+const ORCHESTRATION_COLUMNS: &str = r#"
+    id, orchestration_type::text as orchestration_type, subject_id, status::text as status, failure_reason,
+    started_at, finished_at, initiated_by_person_id, resolved_by_person_id, resolution_note
+"#;
+
+const STEP_COLUMNS: &str = r#"
+    id, orchestration_id, sequence, step_name, compensation::text as compensation, status::text as status,
+    completed_at, compensated_at, last_error
+"#;
+
+#[async_trait]
+impl OrchestrationRepository for OrchestrationRepositoryImpl {
+    async fn create_orchestration(&self, orchestration: OrchestrationModel) -> BankingResult<OrchestrationModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO orchestration_log (
+                id, orchestration_type, subject_id, status, failure_reason, started_at, finished_at,
+                initiated_by_person_id, resolved_by_person_id, resolution_note
+            )
+            VALUES ($1, $2::orchestration_type, $3, $4::orchestration_status, $5, $6, $7, $8, $9, $10)
+            RETURNING {ORCHESTRATION_COLUMNS}
+            "#
+        ))
+        .bind(orchestration.id)
+        .bind(orchestration.orchestration_type)
+        .bind(orchestration.subject_id)
+        .bind(orchestration.status)
+        .bind(orchestration.failure_reason.as_ref().map(|s| s.as_str()))
+        .bind(orchestration.started_at)
+        .bind(orchestration.finished_at)
+        .bind(orchestration.initiated_by_person_id)
+        .bind(orchestration.resolved_by_person_id)
+        .bind(orchestration.resolution_note.as_ref().map(|s| s.as_str()))
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create orchestration: {e}")))?;
+
+        OrchestrationModel::try_from_row(&row)
+    }
+
+    async fn find_orchestration_by_id(&self, orchestration_id: Uuid) -> BankingResult<Option<OrchestrationModel>> {
+        let row = sqlx::query(&format!("SELECT {ORCHESTRATION_COLUMNS} FROM orchestration_log WHERE id = $1"))
+            .bind(orchestration_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find orchestration: {e}")))?;
+
+        row.as_ref().map(OrchestrationModel::try_from_row).transpose()
+    }
+
+    async fn update_orchestration(&self, orchestration: OrchestrationModel) -> BankingResult<OrchestrationModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE orchestration_log
+            SET status = $2::orchestration_status, failure_reason = $3, finished_at = $4,
+                resolved_by_person_id = $5, resolution_note = $6
+            WHERE id = $1
+            RETURNING {ORCHESTRATION_COLUMNS}
+            "#
+        ))
+        .bind(orchestration.id)
+        .bind(orchestration.status)
+        .bind(orchestration.failure_reason.as_ref().map(|s| s.as_str()))
+        .bind(orchestration.finished_at)
+        .bind(orchestration.resolved_by_person_id)
+        .bind(orchestration.resolution_note.as_ref().map(|s| s.as_str()))
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update orchestration: {e}")))?
+        .ok_or_else(|| BankingError::NotFound(format!("Orchestration {} not found", orchestration.id)))?;
+
+        OrchestrationModel::try_from_row(&row)
+    }
+
+    async fn find_incomplete_orchestrations(&self) -> BankingResult<Vec<OrchestrationModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {ORCHESTRATION_COLUMNS} FROM orchestration_log
+            WHERE status NOT IN ('Completed', 'Compensated', 'ManuallyResolved')
+            ORDER BY started_at
+            "#
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find incomplete orchestrations: {e}")))?;
+
+        rows.iter().map(OrchestrationModel::try_from_row).collect()
+    }
+
+    async fn create_step(&self, step: OrchestrationStepModel) -> BankingResult<OrchestrationStepModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO orchestration_steps (
+                id, orchestration_id, sequence, step_name, compensation, status, completed_at, compensated_at, last_error
+            )
+            VALUES ($1, $2, $3, $4, $5::jsonb, $6::orchestration_step_status, $7, $8, $9)
+            RETURNING {STEP_COLUMNS}
+            "#
+        ))
+        .bind(step.id)
+        .bind(step.orchestration_id)
+        .bind(step.sequence)
+        .bind(step.step_name.as_str())
+        .bind(step.compensation.as_deref())
+        .bind(step.status)
+        .bind(step.completed_at)
+        .bind(step.compensated_at)
+        .bind(step.last_error.as_ref().map(|s| s.as_str()))
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create orchestration step: {e}")))?;
+
+        OrchestrationStepModel::try_from_row(&row)
+    }
+
+    async fn find_steps(&self, orchestration_id: Uuid) -> BankingResult<Vec<OrchestrationStepModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {STEP_COLUMNS} FROM orchestration_steps WHERE orchestration_id = $1 ORDER BY sequence"
+        ))
+        .bind(orchestration_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find orchestration steps: {e}")))?;
+
+        rows.iter().map(OrchestrationStepModel::try_from_row).collect()
+    }
+
+    async fn update_step_status(
+        &self,
+        step_id: Uuid,
+        status: DbOrchestrationStepStatus,
+        compensated_at: Option<DateTime<Utc>>,
+        last_error: Option<HeaplessString<255>>,
+    ) -> BankingResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE orchestration_steps
+            SET status = $2::orchestration_step_status, compensated_at = $3, last_error = $4
+            WHERE id = $1
+            "#,
+        )
+        .bind(step_id)
+        .bind(status)
+        .bind(compensated_at)
+        .bind(last_error.as_ref().map(|s| s.as_str()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update orchestration step: {e}")))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{DbPayeeVerificationMethod, DbPayeeVerificationStatus, PayeeModel};
+use banking_db::repository::PayeeRepository;
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of PayeeRepository
+pub struct PayeeRepositoryImpl {
+    pool: PgPool,
+}
+
+impl PayeeRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+fn heapless<const N: usize>(value: String, field: &str) -> BankingResult<HeaplessString<N>> {
+    HeaplessString::try_from(value.as_str()).map_err(|_| BankingError::ValidationError {
+        field: field.to_string(),
+        message: format!("{field} too long"),
+    })
+}
+
+impl TryFromRow<PgRow> for PayeeModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(PayeeModel {
+            id: row.get("id"),
+            customer_id: row.get("customer_id"),
+            beneficiary_name: heapless(row.get("beneficiary_name"), "beneficiary_name")?,
+            external_bank_code: heapless(row.get("external_bank_code"), "external_bank_code")?,
+            external_account_number: heapless(row.get("external_account_number"), "external_account_number")?,
+            verification_status: row.get::<String, _>("verification_status")
+                .parse::<DbPayeeVerificationStatus>()
+                .map_err(|_| BankingError::Internal("Invalid payee verification status".to_string()))?,
+            verification_method: row
+                .get::<Option<String>, _>("verification_method")
+                .map(|method| {
+                    method
+                        .parse::<DbPayeeVerificationMethod>()
+                        .map_err(|_| BankingError::Internal("Invalid payee verification method".to_string()))
+                })
+                .transpose()?,
+            penny_test_amount: row.get("penny_test_amount"),
+            verified_at: row.get("verified_at"),
+            verified_by_person_id: row.get("verified_by_person_id"),
+            first_used_at: row.get("first_used_at"),
+            created_at: row.get("created_at"),
+            last_updated_at: row.get("last_updated_at"),
+            updated_by_person_id: row.get("updated_by_person_id"),
+        })
+    }
+}
+
+const PAYEE_COLUMNS: &str = r#"
+    id, customer_id, beneficiary_name, external_bank_code, external_account_number,
+    verification_status::text as verification_status, verification_method::text as verification_method,
+    penny_test_amount, verified_at, verified_by_person_id, first_used_at,
+    created_at, last_updated_at, updated_by_person_id
+"#;
+
+#[async_trait]
+impl PayeeRepository for PayeeRepositoryImpl {
+    async fn create_payee(&self, payee: PayeeModel) -> BankingResult<PayeeModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO payees (
+                id, customer_id, beneficiary_name, external_bank_code, external_account_number,
+                verification_status, verification_method, penny_test_amount, verified_at,
+                verified_by_person_id, first_used_at, created_at, last_updated_at, updated_by_person_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6::payee_verification_status, $7::payee_verification_method,
+                    $8, $9, $10, $11, $12, $13, $14)
+            RETURNING {PAYEE_COLUMNS}
+            "#
+        ))
+        .bind(payee.id)
+        .bind(payee.customer_id)
+        .bind(payee.beneficiary_name.as_str())
+        .bind(payee.external_bank_code.as_str())
+        .bind(payee.external_account_number.as_str())
+        .bind(payee.verification_status)
+        .bind(payee.verification_method)
+        .bind(payee.penny_test_amount)
+        .bind(payee.verified_at)
+        .bind(payee.verified_by_person_id)
+        .bind(payee.first_used_at)
+        .bind(payee.created_at)
+        .bind(payee.last_updated_at)
+        .bind(payee.updated_by_person_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create payee: {e}")))?;
+
+        PayeeModel::try_from_row(&row)
+    }
+
+    async fn update_payee(&self, payee: PayeeModel) -> BankingResult<PayeeModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE payees
+            SET beneficiary_name = $2, verification_status = $3::payee_verification_status,
+                verification_method = $4::payee_verification_method, penny_test_amount = $5,
+                verified_at = $6, verified_by_person_id = $7, first_used_at = $8,
+                last_updated_at = $9, updated_by_person_id = $10
+            WHERE id = $1
+            RETURNING {PAYEE_COLUMNS}
+            "#
+        ))
+        .bind(payee.id)
+        .bind(payee.beneficiary_name.as_str())
+        .bind(payee.verification_status)
+        .bind(payee.verification_method)
+        .bind(payee.penny_test_amount)
+        .bind(payee.verified_at)
+        .bind(payee.verified_by_person_id)
+        .bind(payee.first_used_at)
+        .bind(payee.last_updated_at)
+        .bind(payee.updated_by_person_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update payee: {e}")))?;
+
+        PayeeModel::try_from_row(&row)
+    }
+
+    async fn find_payee_by_id(&self, payee_id: Uuid) -> BankingResult<Option<PayeeModel>> {
+        let row = sqlx::query(&format!("SELECT {PAYEE_COLUMNS} FROM payees WHERE id = $1"))
+            .bind(payee_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find payee: {e}")))?;
+
+        row.as_ref().map(PayeeModel::try_from_row).transpose()
+    }
+
+    async fn find_payees_by_customer(&self, customer_id: Uuid) -> BankingResult<Vec<PayeeModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {PAYEE_COLUMNS} FROM payees WHERE customer_id = $1 ORDER BY created_at"
+        ))
+        .bind(customer_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find payees by customer: {e}")))?;
+
+        rows.iter().map(PayeeModel::try_from_row).collect()
+    }
+
+    async fn delete_payee(&self, payee_id: Uuid) -> BankingResult<()> {
+        sqlx::query("DELETE FROM payees WHERE id = $1")
+            .bind(payee_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to delete payee: {e}")))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use banking_db::repository::{ContactPreferenceRepositoryError, ContactPreferenceResult};
+use crate::repository::executor::Executor;
+use crate::repository::person::contact_preference_repository::ContactPreferenceRepositoryImpl;
+use uuid::Uuid;
+
+pub async fn delete(repo: &ContactPreferenceRepositoryImpl, id: Uuid) -> ContactPreferenceResult<()> {
+    let query = sqlx::query("DELETE FROM contact_preference WHERE id = $1").bind(id);
+
+    let result = match &repo.executor {
+        Executor::Pool(pool) => query.execute(&**pool).await,
+        Executor::Tx(tx) => {
+            let mut tx = tx.lock().await;
+            query.execute(&mut **tx).await
+        }
+    }
+    .map_err(|e| ContactPreferenceRepositoryError::RepositoryError(e.into()))?;
+
+    if result.rows_affected() == 0 {
+        return Err(ContactPreferenceRepositoryError::ContactPreferenceNotFound(id));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use banking_db::models::person::ContactChannel;
+    use banking_db::repository::{
+        ContactPreferenceRepository, ContactPreferenceRepositoryError, PersonRepository,
+        PersonRepos,
+    };
+    use uuid::Uuid;
+
+    use crate::repository::person::test_helpers::{
+        create_test_contact_preference_model, create_test_person_model,
+    };
+    use crate::test_helper::setup_test_context;
+
+    #[tokio::test]
+    async fn test_delete() {
+        let ctx = setup_test_context().await.unwrap();
+        let person_repo = ctx.person_repos().persons();
+        let repo = ctx.person_repos().contact_preferences();
+
+        let person = create_test_person_model("Jane Doe");
+        person_repo.save(person.clone(), Uuid::new_v4()).await.unwrap();
+        let preference = create_test_contact_preference_model(person.id, ContactChannel::Sms, false);
+        repo.save(preference.clone()).await.unwrap();
+
+        repo.delete(preference.id).await.unwrap();
+        assert!(repo.find_by_id(preference.id).await.unwrap().is_none());
+        assert!(matches!(
+            repo.delete(preference.id).await,
+            Err(ContactPreferenceRepositoryError::ContactPreferenceNotFound(_))
+        ));
+    }
+}
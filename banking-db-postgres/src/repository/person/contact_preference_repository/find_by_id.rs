@@ -0,0 +1,55 @@
+use banking_db::models::person::ContactPreferenceModel;
+use banking_db::repository::{ContactPreferenceRepositoryError, ContactPreferenceResult};
+use crate::repository::executor::Executor;
+use crate::repository::person::contact_preference_repository::ContactPreferenceRepositoryImpl;
+use crate::utils::TryFromRow;
+use uuid::Uuid;
+
+pub async fn find_by_id(
+    repo: &ContactPreferenceRepositoryImpl,
+    id: Uuid,
+) -> ContactPreferenceResult<Option<ContactPreferenceModel>> {
+    let query = sqlx::query("SELECT * FROM contact_preference WHERE id = $1").bind(id);
+
+    let row = match &repo.executor {
+        Executor::Pool(pool) => query.fetch_optional(&**pool).await,
+        Executor::Tx(tx) => {
+            let mut tx = tx.lock().await;
+            query.fetch_optional(&mut **tx).await
+        }
+    }
+    .map_err(|e| ContactPreferenceRepositoryError::RepositoryError(e.into()))?;
+
+    row.map(|row| ContactPreferenceModel::try_from_row(&row))
+        .transpose()
+        .map_err(ContactPreferenceRepositoryError::RepositoryError)
+}
+
+#[cfg(test)]
+mod tests {
+    use banking_db::models::person::ContactChannel;
+    use banking_db::repository::{ContactPreferenceRepository, PersonRepository, PersonRepos};
+    use uuid::Uuid;
+
+    use crate::repository::person::test_helpers::{
+        create_test_contact_preference_model, create_test_person_model,
+    };
+    use crate::test_helper::setup_test_context;
+
+    #[tokio::test]
+    async fn test_find_by_id() {
+        let ctx = setup_test_context().await.unwrap();
+        let person_repo = ctx.person_repos().persons();
+        let repo = ctx.person_repos().contact_preferences();
+
+        let person = create_test_person_model("Jane Doe");
+        person_repo.save(person.clone(), Uuid::new_v4()).await.unwrap();
+        let preference = create_test_contact_preference_model(person.id, ContactChannel::InApp, true);
+        repo.save(preference.clone()).await.unwrap();
+
+        let found = repo.find_by_id(preference.id).await.unwrap().unwrap();
+        assert_eq!(found.person_id, person.id);
+        assert_eq!(found.channel, ContactChannel::InApp);
+        assert!(repo.find_by_id(Uuid::new_v4()).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,68 @@
+use banking_db::models::person::{ContactChannel, ContactPreferenceModel};
+use banking_db::repository::{ContactPreferenceRepositoryError, ContactPreferenceResult};
+use crate::repository::executor::Executor;
+use crate::repository::person::contact_preference_repository::ContactPreferenceRepositoryImpl;
+use crate::utils::TryFromRow;
+use uuid::Uuid;
+
+pub async fn find_by_person_and_channel(
+    repo: &ContactPreferenceRepositoryImpl,
+    person_id: Uuid,
+    channel: ContactChannel,
+) -> ContactPreferenceResult<Option<ContactPreferenceModel>> {
+    let query = sqlx::query(
+        "SELECT * FROM contact_preference WHERE person_id = $1 AND channel = $2",
+    )
+    .bind(person_id)
+    .bind(channel);
+
+    let row = match &repo.executor {
+        Executor::Pool(pool) => query.fetch_optional(&**pool).await,
+        Executor::Tx(tx) => {
+            let mut tx = tx.lock().await;
+            query.fetch_optional(&mut **tx).await
+        }
+    }
+    .map_err(|e| ContactPreferenceRepositoryError::RepositoryError(e.into()))?;
+
+    row.map(|row| ContactPreferenceModel::try_from_row(&row))
+        .transpose()
+        .map_err(ContactPreferenceRepositoryError::RepositoryError)
+}
+
+#[cfg(test)]
+mod tests {
+    use banking_db::models::person::ContactChannel;
+    use banking_db::repository::{ContactPreferenceRepository, PersonRepository, PersonRepos};
+    use uuid::Uuid;
+
+    use crate::repository::person::test_helpers::{
+        create_test_contact_preference_model, create_test_person_model,
+    };
+    use crate::test_helper::setup_test_context;
+
+    #[tokio::test]
+    async fn test_find_by_person_and_channel() {
+        let ctx = setup_test_context().await.unwrap();
+        let person_repo = ctx.person_repos().persons();
+        let repo = ctx.person_repos().contact_preferences();
+
+        let person = create_test_person_model("Jane Doe");
+        person_repo.save(person.clone(), Uuid::new_v4()).await.unwrap();
+        repo.save(create_test_contact_preference_model(person.id, ContactChannel::Email, false))
+            .await
+            .unwrap();
+
+        let email = repo
+            .find_by_person_and_channel(person.id, ContactChannel::Email)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!email.allowed);
+        assert!(repo
+            .find_by_person_and_channel(person.id, ContactChannel::Sms)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,68 @@
+use banking_db::models::person::ContactPreferenceModel;
+use banking_db::repository::{ContactPreferenceRepositoryError, ContactPreferenceResult};
+use crate::repository::executor::Executor;
+use crate::repository::person::contact_preference_repository::ContactPreferenceRepositoryImpl;
+use crate::utils::TryFromRow;
+use uuid::Uuid;
+
+pub async fn find_by_person_id(
+    repo: &ContactPreferenceRepositoryImpl,
+    person_id: Uuid,
+) -> ContactPreferenceResult<Vec<ContactPreferenceModel>> {
+    let query = sqlx::query(
+        "SELECT * FROM contact_preference WHERE person_id = $1 ORDER BY channel",
+    )
+    .bind(person_id);
+
+    let rows = match &repo.executor {
+        Executor::Pool(pool) => query.fetch_all(&**pool).await,
+        Executor::Tx(tx) => {
+            let mut tx = tx.lock().await;
+            query.fetch_all(&mut **tx).await
+        }
+    }
+    .map_err(|e| ContactPreferenceRepositoryError::RepositoryError(e.into()))?;
+
+    rows.iter()
+        .map(ContactPreferenceModel::try_from_row)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(ContactPreferenceRepositoryError::RepositoryError)
+}
+
+#[cfg(test)]
+mod tests {
+    use banking_db::models::person::ContactChannel;
+    use banking_db::repository::{ContactPreferenceRepository, PersonRepository, PersonRepos};
+    use uuid::Uuid;
+
+    use crate::repository::person::test_helpers::{
+        create_test_contact_preference_model, create_test_person_model,
+    };
+    use crate::test_helper::setup_test_context;
+
+    #[tokio::test]
+    async fn test_find_by_person_id() {
+        let ctx = setup_test_context().await.unwrap();
+        let person_repo = ctx.person_repos().persons();
+        let repo = ctx.person_repos().contact_preferences();
+
+        let person = create_test_person_model("Jane Doe");
+        let other = create_test_person_model("John Doe");
+        person_repo.save(person.clone(), Uuid::new_v4()).await.unwrap();
+        person_repo.save(other.clone(), Uuid::new_v4()).await.unwrap();
+
+        repo.save(create_test_contact_preference_model(person.id, ContactChannel::Sms, true))
+            .await
+            .unwrap();
+        repo.save(create_test_contact_preference_model(person.id, ContactChannel::Email, false))
+            .await
+            .unwrap();
+        repo.save(create_test_contact_preference_model(other.id, ContactChannel::Sms, false))
+            .await
+            .unwrap();
+
+        let preferences = repo.find_by_person_id(person.id).await.unwrap();
+        assert_eq!(preferences.len(), 2);
+        assert!(preferences.iter().all(|p| p.person_id == person.id));
+    }
+}
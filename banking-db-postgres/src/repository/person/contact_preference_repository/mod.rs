@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use banking_db::models::person::{ContactChannel, ContactPreferenceModel};
+use banking_db::repository::{
+    ContactPreferenceRepository, ContactPreferenceResult, TransactionAware,
+};
+use crate::repository::executor::Executor;
+use crate::repository::person::person_repository::PersonRepositoryImpl;
+use crate::utils::TryFromRow;
+use sqlx::{postgres::PgRow, Postgres, Row};
+use std::error::Error;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub mod delete;
+pub mod find_by_id;
+pub mod find_by_person_and_channel;
+pub mod find_by_person_id;
+pub mod save;
+
+pub struct ContactPreferenceRepositoryImpl {
+    pub(crate) executor: Executor,
+    pub(crate) person_repository: Arc<PersonRepositoryImpl>,
+}
+
+impl ContactPreferenceRepositoryImpl {
+    pub fn new(executor: Executor, person_repository: Arc<PersonRepositoryImpl>) -> Self {
+        Self {
+            executor,
+            person_repository,
+        }
+    }
+}
+
+/// CHAR(3) language column as the fixed-size code the model carries
+fn get_optional_language_code(
+    row: &PgRow,
+    col_name: &str,
+) -> Result<Option<[u8; 3]>, Box<dyn Error + Send + Sync>> {
+    let code: Option<String> = row.try_get(col_name)?;
+    code.map(|code| {
+        <[u8; 3]>::try_from(code.as_bytes()).map_err(|_| {
+            format!("Value for column '{col_name}' is not a 3-letter language code").into()
+        })
+    })
+    .transpose()
+}
+
+pub(crate) fn language_code_str(code: &Option<[u8; 3]>) -> Option<&str> {
+    code.as_ref().and_then(|code| std::str::from_utf8(code).ok())
+}
+
+impl TryFromRow<PgRow> for ContactPreferenceModel {
+    fn try_from_row(row: &PgRow) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Ok(ContactPreferenceModel {
+            id: row.get("id"),
+            person_id: row.get("person_id"),
+            channel: row.get("channel"),
+            allowed: row.get("allowed"),
+            quiet_hours_start: row.get("quiet_hours_start"),
+            quiet_hours_end: row.get("quiet_hours_end"),
+            preferred_language: get_optional_language_code(row, "preferred_language")?,
+            updated_at: row.get("updated_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl ContactPreferenceRepository<Postgres> for ContactPreferenceRepositoryImpl {
+    async fn save(
+        &self,
+        preference: ContactPreferenceModel,
+    ) -> ContactPreferenceResult<ContactPreferenceModel> {
+        save::save(self, preference).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> ContactPreferenceResult<Option<ContactPreferenceModel>> {
+        find_by_id::find_by_id(self, id).await
+    }
+
+    async fn find_by_person_id(
+        &self,
+        person_id: Uuid,
+    ) -> ContactPreferenceResult<Vec<ContactPreferenceModel>> {
+        find_by_person_id::find_by_person_id(self, person_id).await
+    }
+
+    async fn find_by_person_and_channel(
+        &self,
+        person_id: Uuid,
+        channel: ContactChannel,
+    ) -> ContactPreferenceResult<Option<ContactPreferenceModel>> {
+        find_by_person_and_channel::find_by_person_and_channel(self, person_id, channel).await
+    }
+
+    async fn delete(&self, id: Uuid) -> ContactPreferenceResult<()> {
+        delete::delete(self, id).await
+    }
+}
+
+/// Preferences are read straight from the database, so there is no
+/// transaction-local state to publish or discard.
+#[async_trait]
+impl TransactionAware for ContactPreferenceRepositoryImpl {
+    async fn on_commit(&self) -> BankingResult<()> {
+        Ok(())
+    }
+
+    async fn on_rollback(&self) -> BankingResult<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use banking_db::models::person::ContactPreferenceModel;
+use banking_db::repository::{
+    ContactPreferenceRepositoryError, ContactPreferenceResult, PersonRepository,
+};
+use crate::repository::executor::Executor;
+use crate::repository::person::contact_preference_repository::{
+    language_code_str, ContactPreferenceRepositoryImpl,
+};
+use crate::utils::TryFromRow;
+
+pub async fn save(
+    repo: &ContactPreferenceRepositoryImpl,
+    preference: ContactPreferenceModel,
+) -> ContactPreferenceResult<ContactPreferenceModel> {
+    if preference.quiet_hours_start.is_some() != preference.quiet_hours_end.is_some() {
+        return Err(ContactPreferenceRepositoryError::IncompleteQuietHours(
+            preference.id,
+        ));
+    }
+
+    if !repo
+        .person_repository
+        .exists_by_id(preference.person_id)
+        .await
+        .map_err(|e| ContactPreferenceRepositoryError::RepositoryError(e.into()))?
+    {
+        return Err(ContactPreferenceRepositoryError::PersonNotFound(
+            preference.person_id,
+        ));
+    }
+
+    // The row already stored for the channel keeps its id
+    let query = sqlx::query(
+        r#"
+        INSERT INTO contact_preference (id, person_id, channel, allowed, quiet_hours_start, quiet_hours_end, preferred_language, updated_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        ON CONFLICT (person_id, channel) DO UPDATE SET
+            allowed = EXCLUDED.allowed,
+            quiet_hours_start = EXCLUDED.quiet_hours_start,
+            quiet_hours_end = EXCLUDED.quiet_hours_end,
+            preferred_language = EXCLUDED.preferred_language,
+            updated_at = EXCLUDED.updated_at
+        RETURNING *
+        "#,
+    )
+    .bind(preference.id)
+    .bind(preference.person_id)
+    .bind(preference.channel)
+    .bind(preference.allowed)
+    .bind(preference.quiet_hours_start)
+    .bind(preference.quiet_hours_end)
+    .bind(language_code_str(&preference.preferred_language))
+    .bind(preference.updated_at);
+
+    let row = match &repo.executor {
+        Executor::Pool(pool) => query.fetch_one(&**pool).await,
+        Executor::Tx(tx) => {
+            let mut tx = tx.lock().await;
+            query.fetch_one(&mut **tx).await
+        }
+    }
+    .map_err(|e| ContactPreferenceRepositoryError::RepositoryError(e.into()))?;
+
+    ContactPreferenceModel::try_from_row(&row)
+        .map_err(ContactPreferenceRepositoryError::RepositoryError)
+}
+
+#[cfg(test)]
+mod tests {
+    use banking_db::models::person::ContactChannel;
+    use banking_db::repository::{
+        ContactPreferenceRepository, ContactPreferenceRepositoryError, PersonRepository,
+        PersonRepos,
+    };
+    use chrono::NaiveTime;
+    use uuid::Uuid;
+
+    use crate::repository::person::test_helpers::{
+        create_test_contact_preference_model, create_test_person_model,
+    };
+    use crate::test_helper::setup_test_context;
+
+    #[tokio::test]
+    async fn test_save_replaces_preference_for_channel() {
+        let ctx = setup_test_context().await.unwrap();
+        let person_repo = ctx.person_repos().persons();
+        let repo = ctx.person_repos().contact_preferences();
+
+        let person = create_test_person_model("Jane Doe");
+        person_repo.save(person.clone(), Uuid::new_v4()).await.unwrap();
+
+        let first = create_test_contact_preference_model(person.id, ContactChannel::Sms, true);
+        let saved = repo.save(first.clone()).await.unwrap();
+        assert_eq!(saved.id, first.id);
+        assert!(saved.allowed);
+
+        let mut second = create_test_contact_preference_model(person.id, ContactChannel::Sms, false);
+        second.quiet_hours_start = NaiveTime::from_hms_opt(21, 0, 0);
+        second.quiet_hours_end = NaiveTime::from_hms_opt(7, 0, 0);
+        second.preferred_language = Some(*b"fra");
+        let replaced = repo.save(second).await.unwrap();
+
+        assert_eq!(replaced.id, first.id);
+        assert!(!replaced.allowed);
+        assert_eq!(replaced.quiet_hours_end, NaiveTime::from_hms_opt(7, 0, 0));
+        assert_eq!(replaced.preferred_language, Some(*b"fra"));
+        assert_eq!(repo.find_by_person_id(person.id).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_save_rejects_unknown_person_and_half_quiet_hours() {
+        let ctx = setup_test_context().await.unwrap();
+        let repo = ctx.person_repos().contact_preferences();
+
+        let unknown = create_test_contact_preference_model(Uuid::new_v4(), ContactChannel::Email, true);
+        assert!(matches!(
+            repo.save(unknown).await,
+            Err(ContactPreferenceRepositoryError::PersonNotFound(_))
+        ));
+
+        let mut half = create_test_contact_preference_model(Uuid::new_v4(), ContactChannel::Email, true);
+        half.quiet_hours_start = NaiveTime::from_hms_opt(22, 0, 0);
+        assert!(matches!(
+            repo.save(half).await,
+            Err(ContactPreferenceRepositoryError::IncompleteQuietHours(_))
+        ));
+    }
+}
@@ -1,6 +1,7 @@
 // FILE: banking-db-postgres/src/repository/person/country_repository/batch_helper.rs
 
 use crate::repository::person::country_repository::repo_impl::CountryRepositoryImpl;
+use banking_db::models::person::fold_country_name;
 use std::error::Error;
 use uuid::Uuid;
 
@@ -17,6 +18,22 @@ pub(crate) type CountryIdxTuple = (
     String,
 );
 
+/// Folded names for the search columns, see [`fold_country_name`]
+fn search_names(
+    name_l1s: &[String],
+    name_l2s: &[Option<String>],
+    name_l3s: &[Option<String>],
+) -> (Vec<String>, Vec<Option<String>>, Vec<Option<String>>) {
+    let fold_all = |names: &[Option<String>]| {
+        names.iter().map(|name| name.as_deref().map(fold_country_name)).collect()
+    };
+    (
+        name_l1s.iter().map(|name| fold_country_name(name)).collect(),
+        fold_all(name_l2s),
+        fold_all(name_l3s),
+    )
+}
+
 /// Helper functions for batch operations
 impl CountryRepositoryImpl {
     pub(crate) async fn execute_country_insert(
@@ -24,8 +41,8 @@ impl CountryRepositoryImpl {
         values: Vec<CountryTuple>,
     ) -> Result<(), Box<dyn Error + Send + Sync>> {
         let query = r#"
-            INSERT INTO country (id, iso2, name_l1, name_l2, name_l3)
-            SELECT * FROM UNNEST($1::uuid[], $2::text[], $3::text[], $4::text[], $5::text[])
+            INSERT INTO country (id, iso2, name_l1, name_l2, name_l3, name_l1_search, name_l2_search, name_l3_search)
+            SELECT * FROM UNNEST($1::uuid[], $2::text[], $3::text[], $4::text[], $5::text[], $6::text[], $7::text[], $8::text[])
         "#;
         
         let (ids, iso2s, name_l1s, name_l2s, name_l3s) =
@@ -40,6 +57,7 @@ impl CountryRepositoryImpl {
                     acc
                 },
             );
+        let (search_l1s, search_l2s, search_l3s) = search_names(&name_l1s, &name_l2s, &name_l3s);
 
         match &self.executor {
             crate::repository::executor::Executor::Pool(pool) => {
@@ -49,6 +67,9 @@ impl CountryRepositoryImpl {
                     .bind(&name_l1s)
                     .bind(&name_l2s)
                     .bind(&name_l3s)
+                    .bind(&search_l1s)
+                    .bind(&search_l2s)
+                    .bind(&search_l3s)
                     .execute(&**pool)
                     .await?;
             }
@@ -60,6 +81,9 @@ impl CountryRepositoryImpl {
                     .bind(&name_l1s)
                     .bind(&name_l2s)
                     .bind(&name_l3s)
+                    .bind(&search_l1s)
+                    .bind(&search_l2s)
+                    .bind(&search_l3s)
                     .execute(&mut **tx)
                     .await?;
             }
@@ -115,9 +139,12 @@ impl CountryRepositoryImpl {
                 iso2 = u.iso2,
                 name_l1 = u.name_l1,
                 name_l2 = u.name_l2,
-                name_l3 = u.name_l3
-            FROM (SELECT * FROM UNNEST($1::uuid[], $2::text[], $3::text[], $4::text[], $5::text[]))
-            AS u(id, iso2, name_l1, name_l2, name_l3)
+                name_l3 = u.name_l3,
+                name_l1_search = u.name_l1_search,
+                name_l2_search = u.name_l2_search,
+                name_l3_search = u.name_l3_search
+            FROM (SELECT * FROM UNNEST($1::uuid[], $2::text[], $3::text[], $4::text[], $5::text[], $6::text[], $7::text[], $8::text[]))
+            AS u(id, iso2, name_l1, name_l2, name_l3, name_l1_search, name_l2_search, name_l3_search)
             WHERE country.id = u.id
         "#;
 
@@ -133,6 +160,7 @@ impl CountryRepositoryImpl {
                     acc
                 },
             );
+        let (search_l1s, search_l2s, search_l3s) = search_names(&name_l1s, &name_l2s, &name_l3s);
 
         match &self.executor {
             crate::repository::executor::Executor::Pool(pool) => {
@@ -142,6 +170,9 @@ impl CountryRepositoryImpl {
                     .bind(&name_l1s)
                     .bind(&name_l2s)
                     .bind(&name_l3s)
+                    .bind(&search_l1s)
+                    .bind(&search_l2s)
+                    .bind(&search_l3s)
                     .execute(&**pool)
                     .await?;
             }
@@ -153,6 +184,9 @@ impl CountryRepositoryImpl {
                     .bind(&name_l1s)
                     .bind(&name_l2s)
                     .bind(&name_l3s)
+                    .bind(&search_l1s)
+                    .bind(&search_l2s)
+                    .bind(&search_l3s)
                     .execute(&mut **tx)
                     .await?;
             }
@@ -35,7 +35,11 @@ impl BatchRepository<Postgres, CountryModel> for CountryRepositoryImpl {
         update_batch::update_batch(self, items, audit_log_id).await
     }
 
-    async fn delete_batch(&self, ids: &[Uuid]) -> Result<usize, Box<dyn Error + Send + Sync>> {
+    async fn delete_batch(
+        &self,
+        ids: &[Uuid],
+        _audit_log_id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
         delete_batch::delete_batch(self, ids).await
     }
 }
\ No newline at end of file
@@ -38,6 +38,7 @@ pub(crate) async fn create_batch(
             iso2: item.iso2.clone(),
         };
         cache.add(idx_model);
+        cache.add_names(item.clone());
     }
 
     let mut country_values = Vec::new();
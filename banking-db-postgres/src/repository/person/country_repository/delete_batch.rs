@@ -71,7 +71,7 @@ mod tests {
             .await?;
         let ids: Vec<Uuid> = saved_countries.iter().map(|c| c.id).collect();
 
-        let deleted_count = country_repo.delete_batch(&ids).await?;
+        let deleted_count = country_repo.delete_batch(&ids, Uuid::new_v4()).await?;
         assert_eq!(deleted_count, 5);
 
         for id in ids {
@@ -91,7 +91,7 @@ mod tests {
             ids.push(Uuid::new_v4());
         }
 
-        let deleted_count = country_repo.delete_batch(&ids).await?;
+        let deleted_count = country_repo.delete_batch(&ids, Uuid::new_v4()).await?;
         assert_eq!(deleted_count, 0);
 
         Ok(())
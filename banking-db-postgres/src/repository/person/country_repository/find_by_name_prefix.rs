@@ -0,0 +1,121 @@
+use crate::repository::executor::Executor;
+use crate::repository::person::country_repository::repo_impl::CountryRepositoryImpl;
+use crate::utils::TryFromRow;
+use banking_db::models::person::CountryModel;
+use banking_db::repository::person::country_repository::{
+    normalize_country_name_prefix, CountryRepositoryError, CountryResult,
+};
+
+pub(crate) async fn find_by_name_prefix(
+    repo: &CountryRepositoryImpl,
+    prefix: &str,
+    limit: i32,
+) -> CountryResult<Vec<CountryModel>> {
+    let folded_prefix = normalize_country_name_prefix(prefix)?;
+    let limit = limit.max(0);
+
+    // A full page from the cache needs no round trip
+    let cached = repo
+        .country_idx_cache
+        .read()
+        .await
+        .find_by_name_prefix(&folded_prefix, limit as usize);
+    if cached.len() >= limit as usize {
+        return Ok(cached);
+    }
+
+    // The search columns hold the names folded like the prefix; the anchored
+    // pattern keeps the match index-friendly and the C collation orders like the cache.
+    let pattern = format!(
+        "{}%",
+        folded_prefix
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_")
+    );
+    let query = sqlx::query(
+        r#"
+        SELECT * FROM country
+        WHERE name_l1_search LIKE $1
+           OR name_l2_search LIKE $1
+           OR name_l3_search LIKE $1
+        ORDER BY name_l1_search COLLATE "C", id
+        LIMIT $2
+        "#,
+    )
+    .bind(pattern)
+    .bind(limit as i64);
+
+    let rows = match &repo.executor {
+        Executor::Pool(pool) => query.fetch_all(&**pool).await,
+        Executor::Tx(tx) => {
+            let mut tx = tx.lock().await;
+            query.fetch_all(&mut **tx).await
+        }
+    }
+    .map_err(|e| CountryRepositoryError::RepositoryError(e.into()))?;
+
+    rows.iter()
+        .map(|row| CountryModel::try_from_row(row).map_err(CountryRepositoryError::RepositoryError))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::repository::person::country_repository::test_helpers::setup_test_country;
+    use crate::test_helper::setup_test_context;
+    use banking_db::repository::{CountryRepository, CountryRepositoryError, PersonRepos};
+    use heapless::String as HeaplessString;
+
+    #[tokio::test]
+    async fn test_find_by_name_prefix_ignores_case_and_accents() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let ctx = setup_test_context().await?;
+        let country_repo = ctx.person_repos().countries();
+
+        for (iso2, name_l1, name_l2) in [
+            ("CI", "Côte d'Ivoire", Some("Ivory Coast")),
+            ("CR", "Costa Rica", None),
+            ("CO", "Colombia", Some("Colombie")),
+            ("CM", "Cameroon", Some("Cameroun")),
+        ] {
+            let mut country = setup_test_country().await;
+            country.iso2 = HeaplessString::try_from(iso2).unwrap();
+            country.name_l1 = HeaplessString::try_from(name_l1).unwrap();
+            country.name_l2 = name_l2.map(|name| HeaplessString::try_from(name).unwrap());
+            country_repo.save(country).await?;
+        }
+        let names = |countries: Vec<banking_db::models::person::CountryModel>| {
+            countries.into_iter().map(|c| c.name_l1.to_string()).collect::<Vec<_>>()
+        };
+
+        // Fewer matches than the limit, so the database answers
+        let found = country_repo.find_by_name_prefix("  co ", 10).await?;
+        assert_eq!(names(found), ["Colombia", "Costa Rica", "Côte d'Ivoire"]);
+
+        // A full page is served from the cache, in the same order
+        let found = country_repo.find_by_name_prefix("CO", 2).await?;
+        assert_eq!(names(found), ["Colombia", "Costa Rica"]);
+
+        assert_eq!(names(country_repo.find_by_name_prefix("CÔTE", 10).await?), ["Côte d'Ivoire"]);
+        assert_eq!(names(country_repo.find_by_name_prefix("cote", 1).await?), ["Côte d'Ivoire"]);
+        assert_eq!(names(country_repo.find_by_name_prefix("ivory", 10).await?), ["Côte d'Ivoire"]);
+        assert!(country_repo.find_by_name_prefix("_o", 10).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_find_by_name_prefix_rejects_short_prefix() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let ctx = setup_test_context().await?;
+        let country_repo = ctx.person_repos().countries();
+
+        for prefix in ["", "c", "  c  ", "É"] {
+            assert!(matches!(
+                country_repo.find_by_name_prefix(prefix, 10).await,
+                Err(CountryRepositoryError::InvalidNamePrefix(_))
+            ));
+        }
+
+        Ok(())
+    }
+}
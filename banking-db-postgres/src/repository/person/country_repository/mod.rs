@@ -14,6 +14,7 @@ pub mod exists_by_id;
 pub mod find_by_id;
 pub mod find_by_ids;
 pub mod find_by_iso2;
+pub mod find_by_name_prefix;
 pub mod find_ids_by_iso2;
 pub mod repo_impl;
 pub mod load;
@@ -1,9 +1,12 @@
 use crate::repository::executor::Executor;
+use crate::repository::savepoint::SavepointSnapshots;
 use crate::repository::person::country_repository;
 use crate::utils::{get_heapless_string, get_optional_heapless_string, TryFromRow};
 use async_trait::async_trait;
 use banking_api::BankingResult;
-use banking_db::models::person::{CountryIdxModel, CountryIdxModelCache, CountryModel};
+use banking_db::models::person::{
+    country_name_matches, fold_country_name, CountryIdxModel, CountryIdxModelCache, CountryModel,
+};
 use banking_db::repository::person::country_repository::{CountryRepository, CountryResult};
 use banking_db::repository::TransactionAware;
 use heapless::String as HeaplessString;
@@ -50,6 +53,23 @@ impl CountryRepositoryImpl {
         }
         Ok(idx_models)
     }
+
+    /// Full country rows, loaded at startup so name searches can be served from the cache
+    pub async fn load_all_countries(executor: &Executor) -> Result<Vec<CountryModel>, sqlx::Error> {
+        let query = sqlx::query("SELECT * FROM country");
+        let rows = match executor {
+            Executor::Pool(pool) => query.fetch_all(&**pool).await?,
+            Executor::Tx(tx) => {
+                let mut tx = tx.lock().await;
+                query.fetch_all(&mut **tx).await?
+            }
+        };
+        let mut countries = Vec::with_capacity(rows.len());
+        for row in rows {
+            countries.push(CountryModel::try_from_row(&row).map_err(sqlx::Error::Decode)?);
+        }
+        Ok(countries)
+    }
 }
 
 #[async_trait]
@@ -90,6 +110,14 @@ impl CountryRepository<Postgres> for CountryRepositoryImpl {
     async fn exist_by_ids(&self, ids: &[Uuid]) -> CountryResult<Vec<(Uuid, bool)>> {
         country_repository::exist_by_ids::exist_by_ids(self, ids).await
     }
+
+    async fn find_by_name_prefix(
+        &self,
+        prefix: &str,
+        limit: i32,
+    ) -> CountryResult<Vec<CountryModel>> {
+        country_repository::find_by_name_prefix::find_by_name_prefix(self, prefix, limit).await
+    }
 }
 
 #[async_trait]
@@ -101,6 +129,18 @@ impl TransactionAware for CountryRepositoryImpl {
     async fn on_rollback(&self) -> BankingResult<()> {
         self.country_idx_cache.read().await.on_rollback().await
     }
+
+    async fn on_savepoint(&self, name: &str) -> BankingResult<()> {
+        self.country_idx_cache.read().await.on_savepoint(name).await
+    }
+
+    async fn on_rollback_to(&self, name: &str) -> BankingResult<()> {
+        self.country_idx_cache.read().await.on_rollback_to(name).await
+    }
+
+    async fn on_release(&self, name: &str) -> BankingResult<()> {
+        self.country_idx_cache.read().await.on_release(name).await
+    }
 }
 
 impl TryFromRow<PgRow> for CountryModel {
@@ -124,10 +164,15 @@ impl TryFromRow<PgRow> for CountryIdxModel {
     }
 }
 
+/// Local additions, deletions and names, as snapshotted at a savepoint
+type LocalChanges = (HashMap<Uuid, CountryIdxModel>, HashSet<Uuid>, HashMap<Uuid, CountryModel>);
+
 pub struct TransactionAwareCountryIdxModelCache {
     shared_cache: Arc<ParkingRwLock<CountryIdxModelCache>>,
     local_additions: ParkingRwLock<HashMap<Uuid, CountryIdxModel>>,
     local_deletions: ParkingRwLock<HashSet<Uuid>>,
+    local_names: ParkingRwLock<HashMap<Uuid, CountryModel>>,
+    savepoints: SavepointSnapshots<LocalChanges>,
 }
 
 impl TransactionAwareCountryIdxModelCache {
@@ -136,6 +181,8 @@ impl TransactionAwareCountryIdxModelCache {
             shared_cache,
             local_additions: ParkingRwLock::new(HashMap::new()),
             local_deletions: ParkingRwLock::new(HashSet::new()),
+            local_names: ParkingRwLock::new(HashMap::new()),
+            savepoints: SavepointSnapshots::new(),
         }
     }
 
@@ -149,6 +196,37 @@ impl TransactionAwareCountryIdxModelCache {
         if self.local_additions.write().remove(primary_key).is_none() {
             self.local_deletions.write().insert(*primary_key);
         }
+        self.local_names.write().remove(primary_key);
+    }
+
+    /// Records the names of a country created or renamed in this transaction
+    pub fn add_names(&self, country: CountryModel) {
+        self.local_names.write().insert(country.id, country);
+    }
+
+    /// Countries with a name starting with `folded_prefix`, ordered by folded
+    /// primary name then id, seeing the names changed in this transaction
+    pub fn find_by_name_prefix(&self, folded_prefix: &str, limit: usize) -> Vec<CountryModel> {
+        let local_names = self.local_names.read();
+        let local_deletions = self.local_deletions.read();
+        let mut matches: Vec<CountryModel> = self
+            .shared_cache
+            .read()
+            .find_by_name_prefix(folded_prefix)
+            .into_iter()
+            .filter(|country| {
+                !local_deletions.contains(&country.id) && !local_names.contains_key(&country.id)
+            })
+            .collect();
+        matches.extend(
+            local_names
+                .values()
+                .filter(|country| country_name_matches(country, folded_prefix))
+                .cloned(),
+        );
+        matches.sort_by_cached_key(|country| (fold_country_name(&country.name_l1), country.id));
+        matches.truncate(limit);
+        matches
     }
 
     pub fn contains_primary(&self, primary_key: &Uuid) -> bool {
@@ -203,15 +281,46 @@ impl TransactionAware for TransactionAwareCountryIdxModelCache {
         for primary_key in local_deletions.iter() {
             shared_cache.remove(primary_key);
         }
+        for country in self.local_names.write().drain().map(|(_, country)| country) {
+            shared_cache.add_names(country);
+        }
 
         local_additions.clear();
         local_deletions.clear();
+        self.savepoints.clear();
         Ok(())
     }
 
     async fn on_rollback(&self) -> BankingResult<()> {
         self.local_additions.write().clear();
         self.local_deletions.write().clear();
+        self.local_names.write().clear();
+        self.savepoints.clear();
+        Ok(())
+    }
+
+    async fn on_savepoint(&self, name: &str) -> BankingResult<()> {
+        self.savepoints.push(
+            name,
+            (
+                self.local_additions.read().clone(),
+                self.local_deletions.read().clone(),
+                self.local_names.read().clone(),
+            ),
+        );
+        Ok(())
+    }
+
+    async fn on_rollback_to(&self, name: &str) -> BankingResult<()> {
+        let (additions, deletions, names) = self.savepoints.rollback_to(name);
+        *self.local_additions.write() = additions;
+        *self.local_deletions.write() = deletions;
+        *self.local_names.write() = names;
+        Ok(())
+    }
+
+    async fn on_release(&self, name: &str) -> BankingResult<()> {
+        self.savepoints.release(name);
         Ok(())
     }
 }
\ No newline at end of file
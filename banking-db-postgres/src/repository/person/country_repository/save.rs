@@ -1,6 +1,6 @@
 use crate::repository::executor::Executor;
 use crate::repository::person::country_repository::repo_impl::CountryRepositoryImpl;
-use banking_db::models::person::{CountryIdxModel, CountryModel};
+use banking_db::models::person::{fold_country_name, CountryIdxModel, CountryModel};
 use banking_db::repository::person::country_repository::{CountryRepositoryError, CountryResult};
 
 pub(crate) async fn save(
@@ -19,15 +19,18 @@ pub(crate) async fn save(
 
     let query1 = sqlx::query(
         r#"
-        INSERT INTO country (id, iso2, name_l1, name_l2, name_l3)
-        VALUES ($1, $2, $3, $4, $5)
+        INSERT INTO country (id, iso2, name_l1, name_l2, name_l3, name_l1_search, name_l2_search, name_l3_search)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
         "#,
     )
     .bind(country.id)
     .bind(country.iso2.as_str())
     .bind(country.name_l1.as_str())
     .bind(country.name_l2.as_ref().map(|s| s.as_str()))
-    .bind(country.name_l3.as_ref().map(|s| s.as_str()));
+    .bind(country.name_l3.as_ref().map(|s| s.as_str()))
+    .bind(fold_country_name(&country.name_l1))
+    .bind(country.name_l2.as_deref().map(fold_country_name))
+    .bind(country.name_l3.as_deref().map(fold_country_name));
 
     let query2 = sqlx::query(
         r#"
@@ -68,7 +71,9 @@ pub(crate) async fn save(
         country_id: country.id,
         iso2: country.iso2.clone(),
     };
-    repo.country_idx_cache.read().await.add(new_idx_model);
+    let cache = repo.country_idx_cache.read().await;
+    cache.add(new_idx_model);
+    cache.add_names(country.clone());
 
     Ok(country)
 }
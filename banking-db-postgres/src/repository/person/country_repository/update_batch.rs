@@ -34,7 +34,9 @@ pub(crate) async fn update_batch(
     let mut country_values = Vec::new();
     let mut updated_items = Vec::new();
 
+    let cache = repo.country_idx_cache.read().await;
     for item in items {
+        cache.add_names(item.clone());
         country_values.push((
             item.id,
             item.iso2.to_string(),
@@ -31,7 +31,11 @@ impl BatchRepository<Postgres, CountrySubdivisionModel> for CountrySubdivisionRe
         crate::repository::person::country_subdivision_repository::update_batch::update_batch(self, items, _audit_log_id).await
     }
 
-    async fn delete_batch(&self, ids: &[Uuid]) -> Result<usize, Box<dyn Error + Send + Sync>> {
+    async fn delete_batch(
+        &self,
+        ids: &[Uuid],
+        _audit_log_id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
         crate::repository::person::country_subdivision_repository::delete_batch::delete_batch(self, ids).await
     }
 }
\ No newline at end of file
@@ -84,7 +84,7 @@ mod tests {
             .create_batch(country_subdivisions.clone(), audit_log_id)
             .await?;
 
-        let result = country_subdivision_repo.delete_batch(&ids).await?;
+        let result = country_subdivision_repo.delete_batch(&ids, Uuid::new_v4()).await?;
         assert_eq!(result, 5);
 
         for id in &ids {
@@ -8,6 +8,7 @@ use banking_db::repository::{
     CountrySubdivisionResult, TransactionAware,
 };
 use crate::repository::executor::Executor;
+use crate::repository::savepoint::SavepointSnapshots;
 use crate::repository::person::country_repository::repo_impl::CountryRepositoryImpl;
 use crate::repository::person::locality_repository::LocalityRepositoryImpl;
 use crate::utils::{get_heapless_string, get_optional_heapless_string, TryFromRow};
@@ -136,6 +137,18 @@ impl TransactionAware for CountrySubdivisionRepositoryImpl {
             .on_rollback()
             .await
     }
+
+    async fn on_savepoint(&self, name: &str) -> BankingResult<()> {
+        self.country_subdivision_idx_cache.read().await.on_savepoint(name).await
+    }
+
+    async fn on_rollback_to(&self, name: &str) -> BankingResult<()> {
+        self.country_subdivision_idx_cache.read().await.on_rollback_to(name).await
+    }
+
+    async fn on_release(&self, name: &str) -> BankingResult<()> {
+        self.country_subdivision_idx_cache.read().await.on_release(name).await
+    }
 }
 
 impl TryFromRow<PgRow> for CountrySubdivisionModel {
@@ -165,6 +178,7 @@ pub struct TransactionAwareCountrySubdivisionIdxModelCache {
     shared_cache: Arc<ParkingRwLock<CountrySubdivisionIdxModelCache>>,
     local_additions: ParkingRwLock<HashMap<Uuid, CountrySubdivisionIdxModel>>,
     local_removals: ParkingRwLock<HashSet<Uuid>>,
+    savepoints: SavepointSnapshots<(HashMap<Uuid, CountrySubdivisionIdxModel>, HashSet<Uuid>)>,
 }
 
 impl TransactionAwareCountrySubdivisionIdxModelCache {
@@ -173,6 +187,7 @@ impl TransactionAwareCountrySubdivisionIdxModelCache {
             shared_cache,
             local_additions: ParkingRwLock::new(HashMap::new()),
             local_removals: ParkingRwLock::new(HashSet::new()),
+            savepoints: SavepointSnapshots::new(),
         }
     }
 
@@ -253,12 +268,37 @@ impl TransactionAware for TransactionAwareCountrySubdivisionIdxModelCache {
 
         local_additions.clear();
         self.local_removals.write().clear();
+        self.savepoints.clear();
         Ok(())
     }
 
     async fn on_rollback(&self) -> BankingResult<()> {
         self.local_additions.write().clear();
         self.local_removals.write().clear();
+        self.savepoints.clear();
+        Ok(())
+    }
+
+    async fn on_savepoint(&self, name: &str) -> BankingResult<()> {
+        self.savepoints.push(
+            name,
+            (
+                self.local_additions.read().clone(),
+                self.local_removals.read().clone(),
+            ),
+        );
+        Ok(())
+    }
+
+    async fn on_rollback_to(&self, name: &str) -> BankingResult<()> {
+        let (additions, removals) = self.savepoints.rollback_to(name);
+        *self.local_additions.write() = additions;
+        *self.local_removals.write() = removals;
+        Ok(())
+    }
+
+    async fn on_release(&self, name: &str) -> BankingResult<()> {
+        self.savepoints.release(name);
         Ok(())
     }
 }
\ No newline at end of file
@@ -98,22 +98,24 @@ impl EntityReferenceRepositoryImpl {
 
     pub async fn execute_entity_reference_idx_insert(
         &self,
-        values: Vec<(Uuid, Uuid, i32, i64)>,
+        values: Vec<(Uuid, Uuid, i32, i64, i64)>,
     ) -> Result<(), Box<dyn Error + Send + Sync>> {
-        let (entity_reference_ids, person_ids, versions, hashes) = values.into_iter().fold(
-            (Vec::new(), Vec::new(), Vec::new(), Vec::new()),
-            |mut acc, val| {
-                acc.0.push(val.0);
-                acc.1.push(val.1);
-                acc.2.push(val.2);
-                acc.3.push(val.3);
-                acc
-            },
-        );
+        let (entity_reference_ids, person_ids, versions, hashes, reference_external_id_hashes) =
+            values.into_iter().fold(
+                (Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new()),
+                |mut acc, val| {
+                    acc.0.push(val.0);
+                    acc.1.push(val.1);
+                    acc.2.push(val.2);
+                    acc.3.push(val.3);
+                    acc.4.push(val.4);
+                    acc
+                },
+            );
 
         let query = r#"
-            INSERT INTO entity_reference_idx (entity_reference_id, person_id, version, hash)
-            SELECT * FROM UNNEST($1::uuid[], $2::uuid[], $3::int[], $4::bigint[])
+            INSERT INTO entity_reference_idx (entity_reference_id, person_id, version, hash, reference_external_id_hash)
+            SELECT * FROM UNNEST($1::uuid[], $2::uuid[], $3::int[], $4::bigint[], $5::bigint[])
         "#;
 
         match &self.executor {
@@ -123,6 +125,7 @@ impl EntityReferenceRepositoryImpl {
                     .bind(person_ids)
                     .bind(versions)
                     .bind(hashes)
+                    .bind(reference_external_id_hashes)
                     .execute(&**pool)
                     .await?;
             }
@@ -133,6 +136,7 @@ impl EntityReferenceRepositoryImpl {
                     .bind(person_ids)
                     .bind(versions)
                     .bind(hashes)
+                    .bind(reference_external_id_hashes)
                     .execute(&mut **tx)
                     .await?;
             }
@@ -306,27 +310,30 @@ impl EntityReferenceRepositoryImpl {
 
     pub async fn execute_entity_reference_idx_update(
         &self,
-        values: Vec<(Uuid, Uuid, i32, i64)>,
+        values: Vec<(Uuid, Uuid, i32, i64, i64)>,
     ) -> Result<(), Box<dyn Error + Send + Sync>> {
-        let (entity_reference_ids, person_ids, versions, hashes) = values.into_iter().fold(
-            (Vec::new(), Vec::new(), Vec::new(), Vec::new()),
-            |mut acc, val| {
-                acc.0.push(val.0);
-                acc.1.push(val.1);
-                acc.2.push(val.2);
-                acc.3.push(val.3);
-                acc
-            },
-        );
+        let (entity_reference_ids, person_ids, versions, hashes, reference_external_id_hashes) =
+            values.into_iter().fold(
+                (Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new()),
+                |mut acc, val| {
+                    acc.0.push(val.0);
+                    acc.1.push(val.1);
+                    acc.2.push(val.2);
+                    acc.3.push(val.3);
+                    acc.4.push(val.4);
+                    acc
+                },
+            );
 
         let query = r#"
             UPDATE entity_reference_idx SET
                 person_id = u.person_id,
                 version = u.version,
-                hash = u.hash
+                hash = u.hash,
+                reference_external_id_hash = u.reference_external_id_hash
             FROM (
-                SELECT * FROM UNNEST($1::uuid[], $2::uuid[], $3::int[], $4::bigint[])
-            ) AS u(entity_reference_id, person_id, version, hash)
+                SELECT * FROM UNNEST($1::uuid[], $2::uuid[], $3::int[], $4::bigint[], $5::bigint[])
+            ) AS u(entity_reference_id, person_id, version, hash, reference_external_id_hash)
             WHERE entity_reference_idx.entity_reference_id = u.entity_reference_id
         "#;
 
@@ -337,6 +344,7 @@ impl EntityReferenceRepositoryImpl {
                     .bind(person_ids)
                     .bind(versions)
                     .bind(hashes)
+                    .bind(reference_external_id_hashes)
                     .execute(&**pool)
                     .await?;
             }
@@ -347,6 +355,7 @@ impl EntityReferenceRepositoryImpl {
                     .bind(person_ids)
                     .bind(versions)
                     .bind(hashes)
+                    .bind(reference_external_id_hashes)
                     .execute(&mut **tx)
                     .await?;
             }
@@ -42,9 +42,13 @@ impl BatchRepository<Postgres, EntityReferenceModel> for EntityReferenceReposito
         .await
     }
 
-    async fn delete_batch(&self, ids: &[Uuid]) -> Result<usize, Box<dyn Error + Send + Sync>> {
+    async fn delete_batch(
+        &self,
+        ids: &[Uuid],
+        audit_log_id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
         crate::repository::person::entity_reference_repository::delete_batch::delete_batch(
-            self, ids,
+            self, ids, audit_log_id,
         )
         .await
     }
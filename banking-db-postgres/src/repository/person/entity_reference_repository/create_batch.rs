@@ -69,7 +69,13 @@ pub async fn create_batch(
             item.reference_details_l3.as_ref().map(|s| s.to_string()),
         ));
 
-        entity_reference_idx_values.push((item.id, item.person_id, 0i32, idx_model.hash));
+        entity_reference_idx_values.push((
+            item.id,
+            item.person_id,
+            0i32,
+            idx_model.hash,
+            idx_model.reference_external_id_hash,
+        ));
 
         entity_reference_audit_values.push((
             item.id,
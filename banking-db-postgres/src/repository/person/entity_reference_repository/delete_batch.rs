@@ -8,11 +8,11 @@ use uuid::Uuid;
 pub async fn delete_batch(
     repo: &EntityReferenceRepositoryImpl,
     ids: &[Uuid],
+    audit_log_id: Uuid,
 ) -> Result<usize, Box<dyn Error + Send + Sync>> {
     if ids.is_empty() {
         return Ok(0);
     }
-    let audit_log_id = Uuid::new_v4();
 
     let items_to_delete = repo.load_batch(ids).await?;
     let items_to_delete: Vec<EntityReferenceModel> =
@@ -69,14 +69,14 @@ pub async fn delete_batch(
         }
     };
 
-    let query_main = "DELETE FROM entity_reference WHERE id = ANY($1)";
+    let query_main = "UPDATE entity_reference SET deactivated_at = NOW(), deactivated_by = $2 WHERE id = ANY($1) AND deactivated_at IS NULL";
     let result = match &repo.executor {
         crate::repository::executor::Executor::Pool(pool) => {
-            sqlx::query(query_main).bind(ids).execute(&**pool).await?
+            sqlx::query(query_main).bind(ids).bind(audit_log_id).execute(&**pool).await?
         }
         crate::repository::executor::Executor::Tx(tx) => {
             let mut tx = tx.lock().await;
-            sqlx::query(query_main).bind(ids).execute(&mut **tx).await?
+            sqlx::query(query_main).bind(ids).bind(audit_log_id).execute(&mut **tx).await?
         }
     };
 
@@ -111,7 +111,7 @@ mod tests {
             .create_batch(entity_refs.clone(), Uuid::new_v4())
             .await?;
         let ids: Vec<Uuid> = saved.iter().map(|e| e.id).collect();
-        let deleted_count = entity_ref_repo.delete_batch(&ids).await?;
+        let deleted_count = entity_ref_repo.delete_batch(&ids, Uuid::new_v4()).await?;
         assert_eq!(deleted_count, 4);
         let loaded = entity_ref_repo.load_batch(&ids).await?;
         assert_eq!(loaded.len(), 4);
@@ -1,4 +1,6 @@
-use banking_db::repository::person::entity_reference_repository::EntityReferenceResult;
+use banking_db::repository::person::entity_reference_repository::{
+    EntityReferenceRepositoryError, EntityReferenceResult,
+};
 use crate::repository::person::entity_reference_repository::EntityReferenceRepositoryImpl;
 use uuid::Uuid;
 
@@ -9,10 +11,13 @@ pub async fn exist_by_ids(
     if ids.is_empty() {
         return Ok(Vec::new());
     }
-    let cache = repo.entity_reference_idx_cache.read().await;
+    let found = repo
+        .resolve_idx(ids)
+        .await
+        .map_err(|e| EntityReferenceRepositoryError::RepositoryError(e.into()))?;
     let mut result = Vec::with_capacity(ids.len());
     for &id in ids {
-        result.push((id, cache.get_by_primary(&id).is_some()));
+        result.push((id, found.contains_key(&id)));
     }
     Ok(result)
 }
\ No newline at end of file
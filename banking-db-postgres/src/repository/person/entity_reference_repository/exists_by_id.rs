@@ -1,4 +1,6 @@
-use banking_db::repository::person::entity_reference_repository::EntityReferenceResult;
+use banking_db::repository::person::entity_reference_repository::{
+    EntityReferenceRepositoryError, EntityReferenceResult,
+};
 use crate::repository::person::entity_reference_repository::EntityReferenceRepositoryImpl;
 use uuid::Uuid;
 
@@ -7,11 +9,10 @@ pub async fn exists_by_id(
     id: Uuid,
 ) -> EntityReferenceResult<bool> {
     Ok(repo
-        .entity_reference_idx_cache
-        .read()
+        .resolve_idx(&[id])
         .await
-        .get_by_primary(&id)
-        .is_some())
+        .map_err(|e| EntityReferenceRepositoryError::RepositoryError(e.into()))?
+        .contains_key(&id))
 }
 
 #[cfg(test)]
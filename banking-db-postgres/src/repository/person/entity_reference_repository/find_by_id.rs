@@ -1,5 +1,7 @@
 use banking_db::models::person::EntityReferenceIdxModel;
-use banking_db::repository::person::entity_reference_repository::EntityReferenceResult;
+use banking_db::repository::person::entity_reference_repository::{
+    EntityReferenceRepositoryError, EntityReferenceResult,
+};
 use crate::repository::person::entity_reference_repository::EntityReferenceRepositoryImpl;
 use uuid::Uuid;
 
@@ -8,10 +10,10 @@ pub async fn find_by_id(
     id: Uuid,
 ) -> EntityReferenceResult<Option<EntityReferenceIdxModel>> {
     Ok(repo
-        .entity_reference_idx_cache
-        .read()
+        .resolve_idx(&[id])
         .await
-        .get_by_primary(&id))
+        .map_err(|e| EntityReferenceRepositoryError::RepositoryError(e.into()))?
+        .remove(&id))
 }
 
 #[cfg(test)]
@@ -1,5 +1,7 @@
 use banking_db::models::person::EntityReferenceIdxModel;
-use banking_db::repository::person::entity_reference_repository::EntityReferenceResult;
+use banking_db::repository::person::entity_reference_repository::{
+    EntityReferenceRepositoryError, EntityReferenceResult,
+};
 use crate::repository::person::entity_reference_repository::EntityReferenceRepositoryImpl;
 use uuid::Uuid;
 
@@ -7,10 +9,13 @@ pub async fn find_by_ids(
     repo: &EntityReferenceRepositoryImpl,
     ids: &[Uuid],
 ) -> EntityReferenceResult<Vec<EntityReferenceIdxModel>> {
-    let cache = repo.entity_reference_idx_cache.read().await;
+    let mut found = repo
+        .resolve_idx(ids)
+        .await
+        .map_err(|e| EntityReferenceRepositoryError::RepositoryError(e.into()))?;
     let mut refs = Vec::with_capacity(ids.len());
     for id in ids {
-        if let Some(model) = cache.get_by_primary(id) {
+        if let Some(model) = found.remove(id) {
             refs.push(model);
         }
     }
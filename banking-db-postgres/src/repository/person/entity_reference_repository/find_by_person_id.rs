@@ -1,5 +1,7 @@
 use banking_db::models::person::EntityReferenceIdxModel;
-use banking_db::repository::person::entity_reference_repository::EntityReferenceResult;
+use banking_db::repository::person::entity_reference_repository::{
+    EntityReferenceRepositoryError, EntityReferenceResult,
+};
 use crate::repository::person::entity_reference_repository::EntityReferenceRepositoryImpl;
 use uuid::Uuid;
 
@@ -9,6 +11,19 @@ pub async fn find_by_person_id(
     page: i32,
     page_size: i32,
 ) -> EntityReferenceResult<Vec<EntityReferenceIdxModel>> {
+    if !repo.entity_reference_idx_cache.read().await.is_complete() {
+        // The session's own writes are visible to its reads, so no overlay is needed
+        let start = ((page - 1) * page_size).max(0) as usize;
+        return Ok(repo
+            .load_idx_by_person_id(person_id)
+            .await
+            .map_err(|e| EntityReferenceRepositoryError::RepositoryError(e.into()))?
+            .into_iter()
+            .skip(start)
+            .take(page_size as usize)
+            .collect());
+    }
+
     let cache = repo.entity_reference_idx_cache.read().await;
     if let Some(ids) = cache.get_by_person_id(&person_id) {
         let start = ((page - 1) * page_size) as usize;
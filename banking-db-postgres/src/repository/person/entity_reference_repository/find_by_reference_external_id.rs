@@ -1,5 +1,7 @@
 use banking_db::models::person::EntityReferenceIdxModel;
-use banking_db::repository::person::entity_reference_repository::EntityReferenceResult;
+use banking_db::repository::person::entity_reference_repository::{
+    EntityReferenceRepositoryError, EntityReferenceResult,
+};
 use crate::repository::person::entity_reference_repository::EntityReferenceRepositoryImpl;
 use std::hash::Hasher;
 use twox_hash::XxHash64;
@@ -14,6 +16,18 @@ pub async fn find_by_reference_external_id(
     hasher.write(reference_external_id.as_bytes());
     let hash = hasher.finish() as i64;
 
+    if !repo.entity_reference_idx_cache.read().await.is_complete() {
+        let start = ((page - 1) * page_size).max(0) as usize;
+        return Ok(repo
+            .load_idx_by_reference_external_id_hash(hash)
+            .await
+            .map_err(|e| EntityReferenceRepositoryError::RepositoryError(e.into()))?
+            .into_iter()
+            .skip(start)
+            .take(page_size as usize)
+            .collect());
+    }
+
     let cache = repo.entity_reference_idx_cache.read().await;
     if let Some(ids) = cache.get_by_reference_external_id_hash(&hash) {
         let start = ((page - 1) * page_size) as usize;
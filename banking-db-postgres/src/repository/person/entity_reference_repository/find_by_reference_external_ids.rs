@@ -0,0 +1,152 @@
+use banking_db::models::person::EntityReferenceIdxModel;
+use banking_db::repository::person::entity_reference_repository::{
+    EntityReferenceRepositoryError, EntityReferenceResult,
+};
+use crate::repository::executor::Executor;
+use crate::repository::person::entity_reference_repository::EntityReferenceRepositoryImpl;
+use crate::utils::TryFromRow;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hasher;
+use twox_hash::XxHash64;
+
+pub async fn find_by_reference_external_ids(
+    repo: &EntityReferenceRepositoryImpl,
+    external_ids: &[&str],
+    page: i32,
+    page_size: i32,
+) -> EntityReferenceResult<HashMap<String, Vec<EntityReferenceIdxModel>>> {
+    let mut seen = HashSet::new();
+    let hashed: Vec<(&str, i64)> = external_ids
+        .iter()
+        .filter(|external_id| seen.insert(**external_id))
+        .map(|external_id| {
+            let mut hasher = XxHash64::with_seed(0);
+            hasher.write(external_id.as_bytes());
+            (*external_id, hasher.finish() as i64)
+        })
+        .collect();
+
+    // The transaction-aware cache sees references added in the current transaction
+    let mut matches: HashMap<&str, Vec<EntityReferenceIdxModel>> = HashMap::new();
+    let mut misses: Vec<(&str, i64)> = Vec::new();
+    // A partial cache cannot tell a complete set of matches from a subset
+    let complete = repo.entity_reference_idx_cache.read().await.is_complete();
+    {
+        let cache = repo.entity_reference_idx_cache.read().await;
+        for (external_id, hash) in &hashed {
+            match cache
+                .get_by_reference_external_id_hash(hash)
+                .filter(|_| complete)
+            {
+                Some(ids) => {
+                    let models = ids.iter().filter_map(|id| cache.get_by_primary(id)).collect();
+                    matches.insert(*external_id, models);
+                }
+                None => misses.push((*external_id, *hash)),
+            }
+        }
+    }
+
+    if !misses.is_empty() {
+        let miss_hashes: Vec<i64> = misses.iter().map(|(_, hash)| *hash).collect();
+        let query = r#"SELECT * FROM entity_reference_idx WHERE reference_external_id_hash = ANY($1)"#;
+        let rows = match &repo.executor {
+            Executor::Pool(pool) => sqlx::query(query)
+                .bind(&miss_hashes)
+                .fetch_all(pool.as_ref())
+                .await
+                .map_err(|e| EntityReferenceRepositoryError::RepositoryError(e.into()))?,
+            Executor::Tx(tx) => {
+                let mut tx = tx.lock().await;
+                sqlx::query(query)
+                    .bind(&miss_hashes)
+                    .fetch_all(&mut **tx)
+                    .await
+                    .map_err(|e| EntityReferenceRepositoryError::RepositoryError(e.into()))?
+            }
+        };
+        let cache = repo.entity_reference_idx_cache.read().await;
+        for row in rows {
+            let model = EntityReferenceIdxModel::try_from_row(&row)
+                .map_err(EntityReferenceRepositoryError::RepositoryError)?;
+            if !complete {
+                cache.cache_loaded(model.clone());
+            }
+            for (external_id, hash) in &misses {
+                if *hash == model.reference_external_id_hash {
+                    matches.entry(*external_id).or_default().push(model.clone());
+                }
+            }
+        }
+    }
+
+    let start = ((page - 1) * page_size).max(0) as usize;
+    let mut result = HashMap::with_capacity(matches.len());
+    for (external_id, mut models) in matches {
+        models.sort_by_key(|model| model.entity_reference_id);
+        let paged: Vec<_> = models.into_iter().skip(start).take(page_size as usize).collect();
+        if !paged.is_empty() {
+            result.insert(external_id.to_string(), paged);
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use banking_db::models::person::RelationshipRole;
+    use banking_db::repository::{EntityReferenceRepository, PersonRepository, PersonRepos};
+    use crate::repository::person::test_helpers::{
+        create_test_entity_reference_model, create_test_person_model,
+    };
+    use crate::test_helper::setup_test_context;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_find_by_reference_external_ids() {
+        let ctx = setup_test_context().await.unwrap();
+        let person_repo = ctx.person_repos().persons();
+        let repo = ctx.person_repos().entity_references();
+
+        let new_person = create_test_person_model("John Doe");
+        let audit_log_id = Uuid::new_v4();
+        person_repo
+            .save(new_person.clone(), audit_log_id)
+            .await
+            .unwrap();
+
+        // Saved in the test's transaction, so only visible through local additions
+        let customer_ref = create_test_entity_reference_model(
+            new_person.id,
+            RelationshipRole::Customer,
+            "CUST-12345",
+        );
+        repo.save(customer_ref.clone(), audit_log_id)
+            .await
+            .unwrap();
+        let employee_ref = create_test_entity_reference_model(
+            new_person.id,
+            RelationshipRole::Employee,
+            "EMP-54321",
+        );
+        repo.save(employee_ref.clone(), audit_log_id)
+            .await
+            .unwrap();
+
+        let found = repo
+            .find_by_reference_external_ids(&["CUST-12345", "EMP-54321", "CUST-12345", "UNKNOWN-1"], 1, 10)
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 2);
+        assert_eq!(found["CUST-12345"].len(), 1);
+        assert_eq!(found["CUST-12345"][0].entity_reference_id, customer_ref.id);
+        assert_eq!(found["EMP-54321"][0].entity_reference_id, employee_ref.id);
+        assert!(!found.contains_key("UNKNOWN-1"));
+
+        let second_page = repo
+            .find_by_reference_external_ids(&["CUST-12345"], 2, 10)
+            .await
+            .unwrap();
+        assert!(second_page.is_empty());
+    }
+}
@@ -1,4 +1,6 @@
-use banking_db::repository::person::entity_reference_repository::EntityReferenceResult;
+use banking_db::repository::person::entity_reference_repository::{
+    EntityReferenceRepositoryError, EntityReferenceResult,
+};
 use crate::repository::person::entity_reference_repository::EntityReferenceRepositoryImpl;
 use uuid::Uuid;
 
@@ -6,6 +8,16 @@ pub async fn find_ids_by_person_id(
     repo: &EntityReferenceRepositoryImpl,
     person_id: Uuid,
 ) -> EntityReferenceResult<Vec<Uuid>> {
+    if !repo.entity_reference_idx_cache.read().await.is_complete() {
+        return Ok(repo
+            .load_idx_by_person_id(person_id)
+            .await
+            .map_err(|e| EntityReferenceRepositoryError::RepositoryError(e.into()))?
+            .into_iter()
+            .map(|model| model.entity_reference_id)
+            .collect());
+    }
+
     let cache = repo.entity_reference_idx_cache.read().await;
     if let Some(ids) = cache.get_by_person_id(&person_id) {
         Ok(ids.clone())
@@ -12,7 +12,7 @@ pub async fn load(
 ) -> EntityReferenceResult<EntityReferenceModel> {
     let query = sqlx::query(
         r#"
-        SELECT * FROM entity_reference WHERE id = $1
+        SELECT * FROM entity_reference WHERE id = $1 AND deactivated_at IS NULL
         "#,
     )
     .bind(id);
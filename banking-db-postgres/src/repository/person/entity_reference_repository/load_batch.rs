@@ -11,7 +11,7 @@ pub async fn load_batch(
     if ids.is_empty() {
         return Ok(Vec::new());
     }
-    let query = r#"SELECT * FROM entity_reference WHERE id = ANY($1)"#;
+    let query = r#"SELECT * FROM entity_reference WHERE id = ANY($1) AND deactivated_at IS NULL"#;
     let rows = match &repo.executor {
         crate::repository::executor::Executor::Pool(pool) => {
             sqlx::query(query).bind(ids).fetch_all(&**pool).await?
@@ -3,13 +3,16 @@ use banking_api::BankingResult;
 use crate::utils::{get_heapless_string, get_optional_heapless_string, TryFromRow};
 use banking_db::models::person::{
     EntityReferenceIdxModel, EntityReferenceIdxModelCache,
-    EntityReferenceModel,
+    EntityReferenceModel, IdxCacheLoading, IdxCachePolicy,
 };
 use banking_db::repository::{EntityReferenceRepository, TransactionAware};
 use banking_db::repository::person::entity_reference_repository::EntityReferenceResult;
 use crate::repository::executor::Executor;
+use crate::repository::savepoint::SavepointSnapshots;
 use crate::repository::person::person_repository::PersonRepositoryImpl;
-use sqlx::{postgres::PgRow, Postgres, Row};
+use sqlx::postgres::{PgArguments, PgRow};
+use sqlx::query::QueryAs;
+use sqlx::{Postgres, Row};
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::sync::Arc;
@@ -29,10 +32,14 @@ pub mod find_by_id;
 pub mod find_by_ids;
 pub mod find_by_person_id;
 pub mod find_by_reference_external_id;
+pub mod find_by_reference_external_ids;
 pub mod find_ids_by_person_id;
 pub mod load;
 pub mod save;
 
+/// Rows read per query by [`EntityReferenceRepositoryImpl::warm_up_where`]
+pub const WARM_UP_PAGE_SIZE: i64 = 1_000;
+
 pub struct EntityReferenceRepositoryImpl {
     pub executor: Executor,
     pub entity_reference_idx_cache:
@@ -68,6 +75,169 @@ impl EntityReferenceRepositoryImpl {
             }
         }
     }
+
+    /// Builds the shared cache, reading the whole index only under eager loading
+    pub async fn load_entity_reference_idx_cache(
+        executor: &Executor,
+        policy: IdxCachePolicy,
+    ) -> Result<EntityReferenceIdxModelCache, sqlx::Error> {
+        let items = match policy.loading {
+            IdxCacheLoading::Eager => Self::load_all_entity_reference_idx(executor).await?,
+            IdxCacheLoading::Lazy => Vec::new(),
+        };
+        EntityReferenceIdxModelCache::with_policy(items, policy)
+            .map_err(|e| sqlx::Error::Protocol(e.to_string()))
+    }
+
+    async fn fetch_idx<'q>(
+        &self,
+        query: QueryAs<'q, Postgres, EntityReferenceIdxModel, PgArguments>,
+    ) -> Result<Vec<EntityReferenceIdxModel>, sqlx::Error> {
+        let rows = match &self.executor {
+            Executor::Pool(pool) => query.fetch_all(&**pool).await?,
+            Executor::Tx(tx) => {
+                let mut tx = tx.lock().await;
+                query.fetch_all(&mut **tx).await?
+            }
+        };
+        let cache = self.entity_reference_idx_cache.read().await;
+        for row in &rows {
+            cache.cache_loaded(row.clone());
+        }
+        Ok(rows)
+    }
+
+    /// Looks `ids` up in the transaction-aware cache and reads the misses that
+    /// the cache policy leaves to the database, caching what was found.
+    pub async fn resolve_idx(
+        &self,
+        ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, EntityReferenceIdxModel>, sqlx::Error> {
+        let mut found = HashMap::with_capacity(ids.len());
+        let mut misses = Vec::new();
+        {
+            let cache = self.entity_reference_idx_cache.read().await;
+            for id in ids {
+                match cache.get_by_primary(id) {
+                    Some(model) => {
+                        found.insert(*id, model);
+                    }
+                    None if cache.falls_through(id) => misses.push(*id),
+                    None => {}
+                }
+            }
+        }
+        let rows = match misses.as_slice() {
+            [] => Vec::new(),
+            [id] => {
+                self.fetch_idx(
+                    sqlx::query_as(
+                        "SELECT * FROM entity_reference_idx WHERE entity_reference_id = $1",
+                    )
+                    .bind(*id),
+                )
+                .await?
+            }
+            _ => {
+                self.fetch_idx(
+                    sqlx::query_as(
+                        "SELECT * FROM entity_reference_idx WHERE entity_reference_id = ANY($1)",
+                    )
+                    .bind(&misses),
+                )
+                .await?
+            }
+        };
+        for row in rows {
+            found.insert(row.entity_reference_id, row);
+        }
+        Ok(found)
+    }
+
+    /// Index rows of a person, read from the database.
+    /// Used when the cache does not hold every row.
+    pub async fn load_idx_by_person_id(
+        &self,
+        person_id: Uuid,
+    ) -> Result<Vec<EntityReferenceIdxModel>, sqlx::Error> {
+        self.fetch_idx(
+            sqlx::query_as(
+                "SELECT * FROM entity_reference_idx WHERE person_id = $1 ORDER BY entity_reference_id",
+            )
+            .bind(person_id),
+        )
+        .await
+    }
+
+    /// Index rows sharing an external id hash, read from the database.
+    /// Used when the cache does not hold every row.
+    pub async fn load_idx_by_reference_external_id_hash(
+        &self,
+        hash: i64,
+    ) -> Result<Vec<EntityReferenceIdxModel>, sqlx::Error> {
+        self.fetch_idx(
+            sqlx::query_as(
+                "SELECT * FROM entity_reference_idx WHERE reference_external_id_hash = $1 ORDER BY entity_reference_id",
+            )
+            .bind(hash),
+        )
+        .await
+    }
+
+    /// Preloads the given ids into the shared cache, returning how many exist
+    pub async fn warm_up(&self, ids: &[Uuid]) -> Result<usize, sqlx::Error> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        let rows = self
+            .fetch_idx(
+                sqlx::query_as(
+                    "SELECT * FROM entity_reference_idx WHERE entity_reference_id = ANY($1)",
+                )
+                .bind(ids),
+            )
+            .await?;
+        Ok(rows.len())
+    }
+
+    /// Scans the index in pages of `WARM_UP_PAGE_SIZE` rows and preloads those
+    /// matching `predicate`, returning how many were loaded. Only one page is
+    /// held at a time, so this stays bounded on large tables.
+    pub async fn warm_up_where<F>(&self, predicate: F) -> Result<usize, sqlx::Error>
+    where
+        F: Fn(&EntityReferenceIdxModel) -> bool,
+    {
+        let mut loaded = 0;
+        let mut after = Uuid::nil();
+        loop {
+            let query = sqlx::query_as::<_, EntityReferenceIdxModel>(
+                "SELECT * FROM entity_reference_idx WHERE entity_reference_id > $1 ORDER BY entity_reference_id LIMIT $2",
+            )
+            .bind(after)
+            .bind(WARM_UP_PAGE_SIZE);
+            let page = match &self.executor {
+                Executor::Pool(pool) => query.fetch_all(&**pool).await?,
+                Executor::Tx(tx) => {
+                    let mut tx = tx.lock().await;
+                    query.fetch_all(&mut **tx).await?
+                }
+            };
+            let Some(last) = page.last() else {
+                return Ok(loaded);
+            };
+            after = last.entity_reference_id;
+            let full_page = page.len() as i64 == WARM_UP_PAGE_SIZE;
+
+            let cache = self.entity_reference_idx_cache.read().await;
+            for row in page.into_iter().filter(|row| predicate(row)) {
+                cache.cache_loaded(row);
+                loaded += 1;
+            }
+            if !full_page {
+                return Ok(loaded);
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -123,6 +293,21 @@ impl EntityReferenceRepository<Postgres> for EntityReferenceRepositoryImpl {
         .await
     }
 
+    async fn find_by_reference_external_ids(
+        &self,
+        external_ids: &[&str],
+        page: i32,
+        page_size: i32,
+    ) -> EntityReferenceResult<HashMap<String, Vec<EntityReferenceIdxModel>>> {
+        crate::repository::person::entity_reference_repository::find_by_reference_external_ids::find_by_reference_external_ids(
+            self,
+            external_ids,
+            page,
+            page_size,
+        )
+        .await
+    }
+
     async fn find_by_ids(
         &self,
         ids: &[Uuid],
@@ -173,13 +358,31 @@ impl TransactionAware for EntityReferenceRepositoryImpl {
             .on_rollback()
             .await
     }
+
+    async fn on_savepoint(&self, name: &str) -> BankingResult<()> {
+        self.entity_reference_idx_cache.read().await.on_savepoint(name).await
+    }
+
+    async fn on_rollback_to(&self, name: &str) -> BankingResult<()> {
+        self.entity_reference_idx_cache.read().await.on_rollback_to(name).await
+    }
+
+    async fn on_release(&self, name: &str) -> BankingResult<()> {
+        self.entity_reference_idx_cache.read().await.on_release(name).await
+    }
 }
 
+/// Local additions, updates and deletions, as snapshotted at a savepoint
+type LocalChanges = (HashMap<Uuid, EntityReferenceIdxModel>, HashMap<Uuid, EntityReferenceIdxModel>, HashSet<Uuid>);
+
 pub struct TransactionAwareEntityReferenceIdxModelCache {
     shared_cache: Arc<RwLock<EntityReferenceIdxModelCache>>,
     local_additions: RwLock<HashMap<Uuid, EntityReferenceIdxModel>>,
     local_updates: RwLock<HashMap<Uuid, EntityReferenceIdxModel>>,
     local_deletions: RwLock<HashSet<Uuid>>,
+    /// Ids this session pinned in the shared cache while they are locally dirty
+    pinned: RwLock<HashSet<Uuid>>,
+    savepoints: SavepointSnapshots<LocalChanges>,
 }
 
 impl TransactionAwareEntityReferenceIdxModelCache {
@@ -189,7 +392,57 @@ impl TransactionAwareEntityReferenceIdxModelCache {
             local_additions: RwLock::new(HashMap::new()),
             local_updates: RwLock::new(HashMap::new()),
             local_deletions: RwLock::new(HashSet::new()),
+            pinned: RwLock::new(HashSet::new()),
+            savepoints: SavepointSnapshots::new(),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.shared_cache.read().is_complete()
+    }
+
+    /// Whether a miss on `primary_key` has to be answered by the database
+    pub fn falls_through(&self, primary_key: &Uuid) -> bool {
+        !self.local_deletions.read().contains(primary_key) && !self.is_complete()
+    }
+
+    /// Caches a row read from the database. Rows this session changed are left
+    /// to the local overlays, as the shared cache only holds committed state.
+    pub fn cache_loaded(&self, item: EntityReferenceIdxModel) {
+        let primary_key = item.entity_reference_id;
+        if self.local_additions.read().contains_key(&primary_key)
+            || self.local_updates.read().contains_key(&primary_key)
+            || self.local_deletions.read().contains(&primary_key)
+        {
+            return;
+        }
+        self.shared_cache.write().add(item);
+    }
+
+    fn pin(&self, primary_key: Uuid) {
+        if self.pinned.write().insert(primary_key) {
+            self.shared_cache.write().pin(primary_key);
+        }
+    }
+
+    /// Re-pins exactly the ids that are still locally updated or deleted
+    fn sync_pins(&self) {
+        let dirty: HashSet<Uuid> = self
+            .local_updates
+            .read()
+            .keys()
+            .chain(self.local_deletions.read().iter())
+            .copied()
+            .collect();
+        let mut pinned = self.pinned.write();
+        let mut shared_cache = self.shared_cache.write();
+        for primary_key in dirty.difference(&pinned) {
+            shared_cache.pin(*primary_key);
         }
+        for primary_key in pinned.difference(&dirty) {
+            shared_cache.unpin(primary_key);
+        }
+        *pinned = dirty;
     }
 
     pub fn add(&self, item: EntityReferenceIdxModel) {
@@ -206,11 +459,13 @@ impl TransactionAwareEntityReferenceIdxModelCache {
             return;
         }
         self.local_updates.write().insert(primary_key, item);
+        self.pin(primary_key);
     }
 
     pub fn remove(&self, primary_key: &Uuid) {
         if self.local_additions.write().remove(primary_key).is_none() {
             self.local_deletions.write().insert(*primary_key);
+            self.pin(*primary_key);
         }
         self.local_updates.write().remove(primary_key);
     }
@@ -336,10 +591,14 @@ impl TransactionAware for TransactionAwareEntityReferenceIdxModelCache {
         for primary_key in local_deletions.iter() {
             shared_cache.remove(primary_key);
         }
+        for primary_key in self.pinned.write().drain() {
+            shared_cache.unpin(&primary_key);
+        }
 
         local_additions.clear();
         local_updates.clear();
         local_deletions.clear();
+        self.savepoints.clear();
         Ok(())
     }
 
@@ -347,6 +606,34 @@ impl TransactionAware for TransactionAwareEntityReferenceIdxModelCache {
         self.local_additions.write().clear();
         self.local_updates.write().clear();
         self.local_deletions.write().clear();
+        self.sync_pins();
+        self.savepoints.clear();
+        Ok(())
+    }
+
+    async fn on_savepoint(&self, name: &str) -> BankingResult<()> {
+        self.savepoints.push(
+            name,
+            (
+                self.local_additions.read().clone(),
+                self.local_updates.read().clone(),
+                self.local_deletions.read().clone(),
+            ),
+        );
+        Ok(())
+    }
+
+    async fn on_rollback_to(&self, name: &str) -> BankingResult<()> {
+        let (additions, updates, deletions) = self.savepoints.rollback_to(name);
+        *self.local_additions.write() = additions;
+        *self.local_updates.write() = updates;
+        *self.local_deletions.write() = deletions;
+        self.sync_pins();
+        Ok(())
+    }
+
+    async fn on_release(&self, name: &str) -> BankingResult<()> {
+        self.savepoints.release(name);
         Ok(())
     }
 }
@@ -384,4 +671,178 @@ impl TryFromRow<PgRow> for EntityReferenceIdxModel {
             hash: row.get("hash"),
         })
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::person::test_helpers::{
+        create_test_entity_reference_model, create_test_person_model,
+    };
+    use crate::repository::unit_of_work_impl::{PersonCachePolicies, PostgresUnitOfWork};
+    use crate::test_helper::{setup_shared_uow, TestSchema};
+    use banking_db::models::person::RelationshipRole;
+    use banking_db::repository::{PersonRepos, PersonRepository, UnitOfWork, UnitOfWorkSession};
+
+    fn idx_model() -> EntityReferenceIdxModel {
+        EntityReferenceIdxModel {
+            entity_reference_id: Uuid::new_v4(),
+            person_id: Uuid::new_v4(),
+            reference_external_id_hash: 0,
+            version: 0,
+            hash: 0,
+        }
+    }
+
+    /// Commits a person with one entity reference per external id, then opens
+    /// a unit of work whose entity reference cache starts empty.
+    async fn lazy_uow_with_references(
+        external_ids: &[&str],
+    ) -> (PostgresUnitOfWork, TestSchema, Uuid, Vec<EntityReferenceModel>) {
+        let (uow, schema) = setup_shared_uow().await.unwrap();
+        let audit_log_id = Uuid::new_v4();
+        let person = create_test_person_model("Lazy Loaded");
+        let references: Vec<_> = external_ids
+            .iter()
+            .map(|id| create_test_entity_reference_model(person.id, RelationshipRole::Customer, id))
+            .collect();
+
+        let session = uow.begin().await.unwrap();
+        session
+            .person_repos()
+            .persons()
+            .save(person.clone(), audit_log_id)
+            .await
+            .unwrap();
+        for reference in &references {
+            session
+                .person_repos()
+                .entity_references()
+                .save(reference.clone(), audit_log_id)
+                .await
+                .unwrap();
+        }
+        session.commit().await.unwrap();
+
+        let lazy_uow = PostgresUnitOfWork::with_cache_policies(
+            schema.pool(),
+            PersonCachePolicies {
+                entity_reference: IdxCachePolicy::lazy(None),
+            },
+        )
+        .await;
+        (lazy_uow, schema, person.id, references)
+    }
+
+    #[tokio::test]
+    async fn test_lazy_cache_populates_on_miss() {
+        let (uow, _schema, person_id, references) =
+            lazy_uow_with_references(&["LAZY-001"]).await;
+        let reference = &references[0];
+        let session = uow.begin().await.unwrap();
+        let repo = session.person_repos().entity_references().inner();
+        let shared_cache = repo.entity_reference_idx_cache.read().await.shared_cache.clone();
+        assert!(shared_cache.read().is_empty());
+
+        let found = repo.find_by_id(reference.id).await.unwrap().unwrap();
+        assert_eq!(found.person_id, person_id);
+        assert!(shared_cache.read().contains_primary(&reference.id));
+        assert!(repo.find_by_id(Uuid::new_v4()).await.unwrap().is_none());
+
+        assert_eq!(
+            repo.find_ids_by_person_id(person_id).await.unwrap(),
+            vec![reference.id]
+        );
+        assert_eq!(
+            repo.find_by_reference_external_id("LAZY-001", 1, 10)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_warm_up_preloads_ids_and_matching_rows() {
+        let (uow, _schema, person_id, references) =
+            lazy_uow_with_references(&["WARM-001", "WARM-002"]).await;
+        let session = uow.begin().await.unwrap();
+        let repo = session.person_repos().entity_references().inner();
+        let shared_cache = repo.entity_reference_idx_cache.read().await.shared_cache.clone();
+
+        assert_eq!(repo.warm_up(&[references[0].id]).await.unwrap(), 1);
+        assert_eq!(shared_cache.read().len(), 1);
+
+        let loaded = repo
+            .warm_up_where(|idx| idx.person_id == person_id)
+            .await
+            .unwrap();
+        assert_eq!(loaded, 2);
+        assert!(references
+            .iter()
+            .all(|reference| shared_cache.read().contains_primary(&reference.id)));
+    }
+
+    #[test]
+    fn test_capacity_evicts_least_recently_used() {
+        let mut cache = EntityReferenceIdxModelCache::with_policy(
+            Vec::new(),
+            IdxCachePolicy::lazy(Some(2)),
+        )
+        .unwrap();
+        let (first, second, third) = (idx_model(), idx_model(), idx_model());
+
+        cache.add(first.clone());
+        cache.add(second.clone());
+        cache.get_by_primary(&first.entity_reference_id);
+        cache.add(third.clone());
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.contains_primary(&first.entity_reference_id));
+        assert!(!cache.contains_primary(&second.entity_reference_id));
+        assert!(cache.get_by_person_id(&second.person_id).is_none());
+        assert!(cache.contains_primary(&third.entity_reference_id));
+    }
+
+    #[tokio::test]
+    async fn test_eviction_keeps_entries_with_uncommitted_updates() {
+        let shared_cache = Arc::new(RwLock::new(
+            EntityReferenceIdxModelCache::with_policy(Vec::new(), IdxCachePolicy::lazy(Some(1)))
+                .unwrap(),
+        ));
+        let dirty = idx_model();
+        shared_cache.write().add(dirty.clone());
+        let cache = TransactionAwareEntityReferenceIdxModelCache::new(shared_cache.clone());
+
+        let mut updated = dirty.clone();
+        updated.version = 1;
+        cache.update(updated);
+        let loaded = idx_model();
+        cache.cache_loaded(loaded.clone());
+
+        // Over capacity until the update is committed
+        assert_eq!(shared_cache.read().len(), 2);
+        assert!(shared_cache.read().is_pinned(&dirty.entity_reference_id));
+        assert_eq!(
+            shared_cache
+                .read()
+                .get_by_primary(&dirty.entity_reference_id)
+                .unwrap()
+                .version,
+            0
+        );
+
+        cache.on_commit().await.unwrap();
+
+        let shared_cache = shared_cache.read();
+        assert_eq!(shared_cache.len(), 1);
+        assert!(!shared_cache.is_pinned(&dirty.entity_reference_id));
+        assert_eq!(
+            shared_cache
+                .get_by_primary(&dirty.entity_reference_id)
+                .unwrap()
+                .version,
+            1
+        );
+        assert!(!shared_cache.contains_primary(&loaded.entity_reference_id));
+    }
+}
@@ -37,10 +37,11 @@ pub async fn save(
     ref_hasher.write(entity_ref.reference_external_id.as_bytes());
     let reference_external_id_hash = ref_hasher.finish() as i64;
 
-    let maybe_existing_idx = {
-        let cache_read_guard = repo.entity_reference_idx_cache.read().await;
-        cache_read_guard.get_by_primary(&entity_ref.id)
-    };
+    let maybe_existing_idx = repo
+        .resolve_idx(&[entity_ref.id])
+        .await
+        .map_err(|e| EntityReferenceRepositoryError::RepositoryError(e.into()))?
+        .remove(&entity_ref.id);
 
     if let Some(existing_idx) = maybe_existing_idx {
         // UPDATE
@@ -130,13 +131,17 @@ pub async fn save(
             r#"
                 UPDATE entity_reference_idx SET
                     version = $2,
-                    hash = $3
+                    hash = $3,
+                    person_id = $4,
+                    reference_external_id_hash = $5
                 WHERE entity_reference_id = $1
                 "#,
         )
         .bind(entity_ref.id)
         .bind(new_version)
-        .bind(new_hash);
+        .bind(new_hash)
+        .bind(entity_ref.person_id)
+        .bind(reference_external_id_hash);
 
         match &repo.executor {
             crate::repository::executor::Executor::Pool(pool) => {
@@ -260,14 +265,15 @@ pub async fn save(
 
         let query3 = sqlx::query(
             r#"
-                INSERT INTO entity_reference_idx (entity_reference_id, person_id, version, hash)
-                VALUES ($1, $2, $3, $4)
+                INSERT INTO entity_reference_idx (entity_reference_id, person_id, version, hash, reference_external_id_hash)
+                VALUES ($1, $2, $3, $4, $5)
                 "#,
         )
         .bind(entity_ref.id)
         .bind(entity_ref.person_id)
         .bind(version)
-        .bind(new_hash);
+        .bind(new_hash)
+        .bind(reference_external_id_hash);
 
         match &repo.executor {
             crate::repository::executor::Executor::Pool(pool) => {
@@ -29,8 +29,8 @@ pub async fn update_batch(
         ));
     }
 
+    let mut existing = repo.resolve_idx(&ids).await?;
     let mut to_update = Vec::new();
-    let cache = repo.entity_reference_idx_cache.read().await;
     for item in items {
         let mut hasher = XxHash64::with_seed(0);
         let mut cbor = Vec::new();
@@ -38,7 +38,7 @@ pub async fn update_batch(
         hasher.write(&cbor);
         let new_hash = hasher.finish() as i64;
 
-        if let Some(idx) = cache.get_by_primary(&item.id) {
+        if let Some(idx) = existing.get(&item.id) {
             if idx.hash != new_hash {
                 to_update.push((item, new_hash));
             }
@@ -64,8 +64,9 @@ pub async fn update_batch(
     let mut entity_reference_audit_values = Vec::new();
     let mut saved_items = Vec::new();
 
+    let cache = repo.entity_reference_idx_cache.read().await;
     for (item, new_hash) in to_update {
-        let old_idx = cache.get_by_primary(&item.id).unwrap();
+        let old_idx = existing.remove(&item.id).unwrap();
         let new_version = old_idx.version + 1;
 
         let mut ref_hasher = XxHash64::with_seed(0);
@@ -91,7 +92,13 @@ pub async fn update_batch(
             item.reference_details_l3.as_ref().map(|s| s.to_string()),
         ));
 
-        entity_reference_idx_values.push((item.id, item.person_id, new_version, new_hash));
+        entity_reference_idx_values.push((
+            item.id,
+            item.person_id,
+            new_version,
+            new_hash,
+            reference_external_id_hash,
+        ));
 
         entity_reference_audit_values.push((
             item.id,
@@ -43,7 +43,11 @@ impl BatchRepository<Postgres, LocalityModel> for LocalityRepositoryImpl {
         .await
     }
 
-    async fn delete_batch(&self, ids: &[Uuid]) -> Result<usize, Box<dyn Error + Send + Sync>> {
-        crate::repository::person::locality_repository::delete_batch::delete_batch(self, ids).await
+    async fn delete_batch(
+        &self,
+        ids: &[Uuid],
+        audit_log_id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        crate::repository::person::locality_repository::delete_batch::delete_batch(self, ids, audit_log_id).await
     }
 }
\ No newline at end of file
@@ -8,6 +8,7 @@ use uuid::Uuid;
 pub async fn delete_batch(
     repo: &LocalityRepositoryImpl,
     ids: &[Uuid],
+    audit_log_id: Uuid,
 ) -> Result<usize, Box<dyn Error + Send + Sync>> {
     if ids.is_empty() {
         return Ok(0);
@@ -33,7 +34,7 @@ pub async fn delete_batch(
         cache.remove(id);
     }
 
-    let delete_query = r#"DELETE FROM locality WHERE id = ANY($1)"#;
+    let deactivate_query = r#"UPDATE locality SET deactivated_at = NOW(), deactivated_by = $2 WHERE id = ANY($1) AND deactivated_at IS NULL"#;
     let delete_idx_query = r#"DELETE FROM locality_idx WHERE locality_id = ANY($1)"#;
 
     match &repo.executor {
@@ -42,7 +43,11 @@ pub async fn delete_batch(
                 .bind(ids)
                 .execute(pool.as_ref())
                 .await?;
-            sqlx::query(delete_query).bind(ids).execute(pool.as_ref()).await?;
+            sqlx::query(deactivate_query)
+                .bind(ids)
+                .bind(audit_log_id)
+                .execute(pool.as_ref())
+                .await?;
         }
         Executor::Tx(tx) => {
             let mut tx = tx.lock().await;
@@ -50,7 +55,11 @@ pub async fn delete_batch(
                 .bind(ids)
                 .execute(&mut **tx)
                 .await?;
-            sqlx::query(delete_query).bind(ids).execute(&mut **tx).await?;
+            sqlx::query(deactivate_query)
+                .bind(ids)
+                .bind(audit_log_id)
+                .execute(&mut **tx)
+                .await?;
         }
     }
 
@@ -110,7 +119,7 @@ mod tests {
             .create_batch(localities.clone(), audit_log_id)
             .await?;
 
-        let deleted_count = locality_repo.delete_batch(&ids).await?;
+        let deleted_count = locality_repo.delete_batch(&ids, Uuid::new_v4()).await?;
         assert_eq!(deleted_count, 5);
 
         for id in &ids {
@@ -8,7 +8,7 @@ use uuid::Uuid;
 pub async fn load(repo: &LocalityRepositoryImpl, id: Uuid) -> LocalityResult<LocalityModel> {
     let query = sqlx::query(
         r#"
-        SELECT * FROM locality WHERE id = $1
+        SELECT * FROM locality WHERE id = $1 AND deactivated_at IS NULL
         "#,
     )
     .bind(id);
@@ -12,7 +12,7 @@ pub async fn load_batch(
     if ids.is_empty() {
         return Ok(Vec::new());
     }
-    let query = r#"SELECT * FROM locality WHERE id = ANY($1)"#;
+    let query = r#"SELECT * FROM locality WHERE id = ANY($1) AND deactivated_at IS NULL"#;
     let rows = match &repo.executor {
         Executor::Pool(pool) => sqlx::query(query).bind(ids).fetch_all(pool.as_ref()).await?,
         Executor::Tx(tx) => {
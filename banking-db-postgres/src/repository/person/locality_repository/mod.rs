@@ -9,6 +9,7 @@ pub mod update_batch;
 pub mod delete_batch;
 pub mod save;
 pub mod load;
+pub mod load_including_deactivated;
 pub mod find_by_id;
 pub mod find_by_country_subdivision_id;
 pub mod find_by_code;
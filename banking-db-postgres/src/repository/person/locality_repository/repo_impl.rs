@@ -6,6 +6,7 @@ use banking_db::repository::{
     TransactionAware,
 };
 use crate::repository::executor::Executor;
+use crate::repository::savepoint::SavepointSnapshots;
 use crate::repository::person::country_subdivision_repository::CountrySubdivisionRepositoryImpl;
 use crate::repository::person::location_repository::LocationRepositoryImpl;
 use crate::utils::{get_heapless_string, get_optional_heapless_string, TryFromRow};
@@ -70,6 +71,10 @@ impl LocalityRepository<Postgres> for LocalityRepositoryImpl {
         crate::repository::person::locality_repository::load::load(self, id).await
     }
 
+    async fn load_including_deactivated(&self, id: Uuid) -> LocalityResult<LocalityModel> {
+        crate::repository::person::locality_repository::load_including_deactivated::load_including_deactivated(self, id).await
+    }
+
     async fn find_by_id(&self, id: Uuid) -> LocalityResult<Option<LocalityIdxModel>> {
         crate::repository::person::locality_repository::find_by_id::find_by_id(self, id).await
     }
@@ -120,12 +125,25 @@ impl TransactionAware for LocalityRepositoryImpl {
     async fn on_rollback(&self) -> BankingResult<()> {
         self.locality_idx_cache.read().await.on_rollback().await
     }
+
+    async fn on_savepoint(&self, name: &str) -> BankingResult<()> {
+        self.locality_idx_cache.read().await.on_savepoint(name).await
+    }
+
+    async fn on_rollback_to(&self, name: &str) -> BankingResult<()> {
+        self.locality_idx_cache.read().await.on_rollback_to(name).await
+    }
+
+    async fn on_release(&self, name: &str) -> BankingResult<()> {
+        self.locality_idx_cache.read().await.on_release(name).await
+    }
 }
 
 pub struct TransactionAwareLocalityIdxModelCache {
     shared_cache: Arc<RwLock<LocalityIdxModelCache>>,
     local_additions: RwLock<HashMap<Uuid, LocalityIdxModel>>,
     local_deletions: RwLock<HashSet<Uuid>>,
+    savepoints: SavepointSnapshots<(HashMap<Uuid, LocalityIdxModel>, HashSet<Uuid>)>,
 }
 
 impl TransactionAwareLocalityIdxModelCache {
@@ -134,6 +152,7 @@ impl TransactionAwareLocalityIdxModelCache {
             shared_cache,
             local_additions: RwLock::new(HashMap::new()),
             local_deletions: RwLock::new(HashSet::new()),
+            savepoints: SavepointSnapshots::new(),
         }
     }
 
@@ -227,12 +246,37 @@ impl TransactionAware for TransactionAwareLocalityIdxModelCache {
 
         local_additions.clear();
         local_deletions.clear();
+        self.savepoints.clear();
         Ok(())
     }
 
     async fn on_rollback(&self) -> BankingResult<()> {
         self.local_additions.write().clear();
         self.local_deletions.write().clear();
+        self.savepoints.clear();
+        Ok(())
+    }
+
+    async fn on_savepoint(&self, name: &str) -> BankingResult<()> {
+        self.savepoints.push(
+            name,
+            (
+                self.local_additions.read().clone(),
+                self.local_deletions.read().clone(),
+            ),
+        );
+        Ok(())
+    }
+
+    async fn on_rollback_to(&self, name: &str) -> BankingResult<()> {
+        let (additions, deletions) = self.savepoints.rollback_to(name);
+        *self.local_additions.write() = additions;
+        *self.local_deletions.write() = deletions;
+        Ok(())
+    }
+
+    async fn on_release(&self, name: &str) -> BankingResult<()> {
+        self.savepoints.release(name);
         Ok(())
     }
 }
@@ -45,10 +45,6 @@ pub async fn update_batch(
         let new_code_hash = hasher.finish() as i64;
 
         if let Some(existing_idx) = cache.get_by_primary(&item.id) {
-            if existing_idx.code_hash == new_code_hash {
-                continue;
-            }
-
             locality_values.push((
                 item.id,
                 item.country_subdivision_id,
@@ -58,17 +54,21 @@ pub async fn update_batch(
                 item.name_l3.clone(),
             ));
 
-            locality_idx_values.push((item.id, item.country_subdivision_id, new_code_hash));
+            if existing_idx.code_hash != new_code_hash {
+                locality_idx_values.push((item.id, item.country_subdivision_id, new_code_hash));
 
-            let mut updated_idx = existing_idx.clone();
-            updated_idx.code_hash = new_code_hash;
-            cache.add(updated_idx);
+                let mut updated_idx = existing_idx.clone();
+                updated_idx.code_hash = new_code_hash;
+                cache.add(updated_idx);
+            }
             updated_items.push(item);
         }
     }
 
     if !locality_values.is_empty() {
         execute_locality_update(&repo.executor, locality_values).await?;
+    }
+    if !locality_idx_values.is_empty() {
         execute_locality_idx_update(&repo.executor, locality_idx_values).await?;
     }
 
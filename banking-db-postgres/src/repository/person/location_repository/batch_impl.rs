@@ -41,7 +41,11 @@ impl BatchRepository<Postgres, LocationModel> for LocationRepositoryImpl {
         .await
     }
 
-    async fn delete_batch(&self, ids: &[Uuid]) -> Result<usize, Box<dyn Error + Send + Sync>> {
-        crate::repository::person::location_repository::delete_batch::delete_batch(self, ids).await
+    async fn delete_batch(
+        &self,
+        ids: &[Uuid],
+        audit_log_id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        crate::repository::person::location_repository::delete_batch::delete_batch(self, ids, audit_log_id).await
     }
 }
\ No newline at end of file
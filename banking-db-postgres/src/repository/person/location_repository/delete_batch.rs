@@ -9,6 +9,7 @@ use uuid::Uuid;
 pub async fn delete_batch(
     repo: &LocationRepositoryImpl,
     ids: &[Uuid],
+    audit_log_id: Uuid,
 ) -> Result<usize, Box<dyn Error + Send + Sync>> {
     if ids.is_empty() {
         return Ok(0);
@@ -35,7 +36,6 @@ pub async fn delete_batch(
         cache.remove(id);
     }
 
-    let audit_log_id = Uuid::new_v4();
     let mut location_audit_values = Vec::new();
     for item in &items_to_delete {
         if let Some(idx_model) = repo.get_idx_by_id(item.id).await? {
@@ -73,14 +73,14 @@ pub async fn delete_batch(
         }
     };
 
-    let query_main = "DELETE FROM location WHERE id = ANY($1)";
+    let query_main = "UPDATE location SET deactivated_at = NOW(), deactivated_by = $2 WHERE id = ANY($1) AND deactivated_at IS NULL";
     let result = match &repo.executor {
         crate::repository::executor::Executor::Pool(pool) => {
-            sqlx::query(query_main).bind(ids).execute(&**pool).await?
+            sqlx::query(query_main).bind(ids).bind(audit_log_id).execute(&**pool).await?
         }
         crate::repository::executor::Executor::Tx(tx) => {
             let mut tx = tx.lock().await;
-            sqlx::query(query_main).bind(ids).execute(&mut **tx).await?
+            sqlx::query(query_main).bind(ids).bind(audit_log_id).execute(&mut **tx).await?
         }
     };
 
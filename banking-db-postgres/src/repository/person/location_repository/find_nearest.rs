@@ -0,0 +1,86 @@
+use banking_db::models::person::LocationModel;
+use banking_db::repository::{LocationRepositoryError, LocationResult};
+use banking_api::domain::person::EARTH_RADIUS_METERS;
+use crate::repository::executor::Executor;
+use crate::repository::person::location_repository::find_within_bounding_box::validate_coordinates;
+use crate::repository::person::location_repository::LocationRepositoryImpl;
+use crate::utils::TryFromRow;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+/// Active geocoded locations closest to the point by haversine distance
+pub async fn find_nearest(
+    repo: &LocationRepositoryImpl,
+    latitude: Decimal,
+    longitude: Decimal,
+    limit: i32,
+) -> LocationResult<Vec<LocationModel>> {
+    validate_coordinates(latitude, longitude)?;
+
+    let query = sqlx::query(
+        r#"
+        SELECT * FROM location
+        WHERE deactivated_at IS NULL
+          AND latitude IS NOT NULL
+          AND longitude IS NOT NULL
+        ORDER BY 2 * $3 * asin(sqrt(
+            power(sin(radians(latitude::float8 - $1) / 2), 2)
+            + cos(radians($1)) * cos(radians(latitude::float8))
+              * power(sin(radians(longitude::float8 - $2) / 2), 2)
+        )), id
+        LIMIT $4
+        "#,
+    )
+    .bind(latitude.to_f64().unwrap_or_default())
+    .bind(longitude.to_f64().unwrap_or_default())
+    .bind(EARTH_RADIUS_METERS)
+    .bind(i64::from(limit));
+
+    let rows = match &repo.executor {
+        Executor::Pool(pool) => query
+            .fetch_all(&**pool)
+            .await
+            .map_err(|e| LocationRepositoryError::RepositoryError(e.into()))?,
+        Executor::Tx(tx) => {
+            let mut tx = tx.lock().await;
+            query
+                .fetch_all(&mut **tx)
+                .await
+                .map_err(|e| LocationRepositoryError::RepositoryError(e.into()))?
+        }
+    };
+
+    rows.iter()
+        .map(|row| LocationModel::try_from_row(row).map_err(LocationRepositoryError::RepositoryError))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use banking_db::repository::{LocationRepository, LocationRepositoryError, PersonRepos};
+    use uuid::Uuid;
+
+    use crate::repository::person::location_repository::find_within_bounding_box::tests::{
+        dec, seed_locations,
+    };
+    use crate::test_helper::setup_test_context;
+
+    #[tokio::test]
+    async fn test_find_nearest() {
+        let ctx = setup_test_context().await.unwrap();
+        let locations = seed_locations(&ctx).await;
+        let repo = ctx.person_repos().locations();
+
+        // Closest to the third location, then its neighbours by distance
+        let nearest = repo.find_nearest(dec("3.8710"), dec("11.5000"), 3).await.unwrap();
+        let ids: Vec<Uuid> = nearest.iter().map(|l| l.id).collect();
+        assert_eq!(ids, vec![locations[2].id, locations[1].id, locations[3].id]);
+
+        let everything = repo.find_nearest(dec("3.8710"), dec("11.5000"), 10).await.unwrap();
+        assert_eq!(everything.len(), 5);
+        assert_eq!(everything[4].id, locations[4].id);
+
+        let invalid = repo.find_nearest(dec("90.5"), dec("11.5"), 3).await;
+        assert!(matches!(invalid, Err(LocationRepositoryError::InvalidCoordinates { .. })));
+    }
+}
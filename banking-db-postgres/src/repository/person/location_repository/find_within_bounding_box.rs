@@ -0,0 +1,159 @@
+use banking_db::models::person::LocationModel;
+use banking_db::repository::{LocationRepositoryError, LocationResult};
+use crate::repository::executor::Executor;
+use crate::repository::person::location_repository::LocationRepositoryImpl;
+use crate::utils::TryFromRow;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+/// Latitude must be within ±90 and longitude within ±180 degrees
+pub(crate) fn validate_coordinates(latitude: Decimal, longitude: Decimal) -> LocationResult<()> {
+    if latitude.abs() > Decimal::from(90) || longitude.abs() > Decimal::from(180) {
+        return Err(LocationRepositoryError::InvalidCoordinates {
+            latitude: latitude.to_f64().unwrap_or_default(),
+            longitude: longitude.to_f64().unwrap_or_default(),
+        });
+    }
+    Ok(())
+}
+
+/// Active locations inside the box, corners included. Boxes crossing the
+/// antimeridian are not supported. Pages start at 1.
+pub async fn find_within_bounding_box(
+    repo: &LocationRepositoryImpl,
+    min_latitude: Decimal,
+    min_longitude: Decimal,
+    max_latitude: Decimal,
+    max_longitude: Decimal,
+    page: i32,
+    page_size: i32,
+) -> LocationResult<Vec<LocationModel>> {
+    validate_coordinates(min_latitude, min_longitude)?;
+    validate_coordinates(max_latitude, max_longitude)?;
+    if min_latitude > max_latitude || min_longitude > max_longitude {
+        return Err(LocationRepositoryError::InvalidCoordinates {
+            latitude: min_latitude.to_f64().unwrap_or_default(),
+            longitude: min_longitude.to_f64().unwrap_or_default(),
+        });
+    }
+
+    let query = sqlx::query(
+        r#"
+        SELECT * FROM location
+        WHERE deactivated_at IS NULL
+          AND latitude BETWEEN $1 AND $3
+          AND longitude BETWEEN $2 AND $4
+        ORDER BY latitude, longitude, id
+        LIMIT $5 OFFSET $6
+        "#,
+    )
+    .bind(min_latitude)
+    .bind(min_longitude)
+    .bind(max_latitude)
+    .bind(max_longitude)
+    .bind(i64::from(page_size))
+    .bind(i64::from(page.max(1) - 1) * i64::from(page_size));
+
+    let rows = match &repo.executor {
+        Executor::Pool(pool) => query
+            .fetch_all(&**pool)
+            .await
+            .map_err(|e| LocationRepositoryError::RepositoryError(e.into()))?,
+        Executor::Tx(tx) => {
+            let mut tx = tx.lock().await;
+            query
+                .fetch_all(&mut **tx)
+                .await
+                .map_err(|e| LocationRepositoryError::RepositoryError(e.into()))?
+        }
+    };
+
+    rows.iter()
+        .map(|row| LocationModel::try_from_row(row).map_err(LocationRepositoryError::RepositoryError))
+        .collect()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use banking_db::models::person::LocationModel;
+    use banking_db::repository::{
+        CountryRepository, CountrySubdivisionRepository, LocalityRepository, LocationRepository,
+        LocationRepositoryError, PersonRepos,
+    };
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+
+    use crate::repository::person::test_helpers::{
+        create_test_country_model, create_test_country_subdivision_model,
+        create_test_locality_model, create_test_location_model,
+    };
+    use crate::repository::unit_of_work_impl::PostgresUnitOfWorkSession;
+    use crate::test_helper::{setup_test_context, TestContext};
+
+    pub fn dec(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    /// Five geocoded locations around Yaoundé, west to east, plus one without coordinates
+    pub async fn seed_locations(
+        ctx: &TestContext<PostgresUnitOfWorkSession>,
+    ) -> Vec<LocationModel> {
+        let repo = ctx.person_repos().locations();
+        let unique_iso2 = format!("G{}", &Uuid::new_v4().to_string()[0..1].to_uppercase());
+        let country = create_test_country_model(&unique_iso2, "Test Country");
+        ctx.person_repos().countries().save(country.clone()).await.unwrap();
+        let subdivision = create_test_country_subdivision_model(country.id, "GS1", "Test Subdivision");
+        ctx.person_repos()
+            .country_subdivisions()
+            .save(subdivision.clone())
+            .await
+            .unwrap();
+        let locality = create_test_locality_model(subdivision.id, "GL1", "Test Locality");
+        ctx.person_repos().localities().save(locality.clone()).await.unwrap();
+
+        let coordinates = [
+            ("3.8480", "11.4000"),
+            ("3.8600", "11.4800"),
+            ("3.8700", "11.5020"),
+            ("3.8900", "11.5500"),
+            ("4.0500", "11.7000"),
+        ];
+        let mut locations = Vec::new();
+        for (i, (latitude, longitude)) in coordinates.iter().enumerate() {
+            let mut location =
+                create_test_location_model(locality.id, &format!("Street {i}"), "00237");
+            location.latitude = Some(dec(latitude));
+            location.longitude = Some(dec(longitude));
+            locations.push(repo.save(location, Uuid::new_v4()).await.unwrap());
+        }
+        let ungeocoded = create_test_location_model(locality.id, "No Coordinates", "00237");
+        repo.save(ungeocoded, Uuid::new_v4()).await.unwrap();
+        locations
+    }
+
+    #[tokio::test]
+    async fn test_find_within_bounding_box() {
+        let ctx = setup_test_context().await.unwrap();
+        let locations = seed_locations(&ctx).await;
+        let repo = ctx.person_repos().locations();
+
+        let found = repo
+            .find_within_bounding_box(dec("3.85"), dec("11.45"), dec("3.89"), dec("11.56"), 1, 10)
+            .await
+            .unwrap();
+        let ids: Vec<Uuid> = found.iter().map(|l| l.id).collect();
+        assert_eq!(ids, vec![locations[1].id, locations[2].id, locations[3].id]);
+
+        let second_page = repo
+            .find_within_bounding_box(dec("3.85"), dec("11.45"), dec("3.89"), dec("11.56"), 2, 2)
+            .await
+            .unwrap();
+        assert_eq!(second_page.len(), 1);
+        assert_eq!(second_page[0].id, locations[3].id);
+
+        let invalid = repo
+            .find_within_bounding_box(dec("-91"), dec("11.45"), dec("3.89"), dec("11.56"), 1, 10)
+            .await;
+        assert!(matches!(invalid, Err(LocationRepositoryError::InvalidCoordinates { .. })));
+    }
+}
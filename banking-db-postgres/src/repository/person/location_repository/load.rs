@@ -8,7 +8,7 @@ use uuid::Uuid;
 pub async fn load(repo: &LocationRepositoryImpl, id: Uuid) -> LocationResult<LocationModel> {
     let query = sqlx::query(
         r#"
-        SELECT * FROM location WHERE id = $1
+        SELECT * FROM location WHERE id = $1 AND deactivated_at IS NULL
         "#,
     )
     .bind(id);
@@ -8,9 +8,12 @@ pub mod update_batch;
 pub mod delete_batch;
 pub mod save;
 pub mod load;
+pub mod load_including_deactivated;
 pub mod find_by_id;
 pub mod find_by_ids;
 pub mod find_by_locality_id;
+pub mod find_within_bounding_box;
+pub mod find_nearest;
 pub mod exists_by_id;
 pub mod find_ids_by_locality_id;
 pub mod exist_by_ids;
\ No newline at end of file
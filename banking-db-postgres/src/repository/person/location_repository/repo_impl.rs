@@ -8,8 +8,10 @@ use banking_db::repository::{
     TransactionAware,
 };
 use crate::repository::executor::Executor;
+use crate::repository::savepoint::SavepointSnapshots;
 use crate::repository::person::locality_repository::LocalityRepositoryImpl;
 use crate::utils::{get_heapless_string, get_optional_heapless_string, TryFromRow};
+use rust_decimal::Decimal;
 use sqlx::{postgres::PgRow, Postgres, Row};
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
@@ -76,6 +78,10 @@ impl LocationRepository<Postgres> for LocationRepositoryImpl {
         crate::repository::person::location_repository::load::load(self, id).await
     }
 
+    async fn load_including_deactivated(&self, id: Uuid) -> LocationResult<LocationModel> {
+        crate::repository::person::location_repository::load_including_deactivated::load_including_deactivated(self, id).await
+    }
+
     async fn find_by_id(&self, id: Uuid) -> LocationResult<Option<LocationIdxModel>> {
         crate::repository::person::location_repository::find_by_id::find_by_id(self, id).await
     }
@@ -114,6 +120,36 @@ impl LocationRepository<Postgres> for LocationRepositoryImpl {
     async fn exist_by_ids(&self, ids: &[Uuid]) -> LocationResult<Vec<(Uuid, bool)>> {
         crate::repository::person::location_repository::exist_by_ids::exist_by_ids(self, ids).await
     }
+
+    async fn find_within_bounding_box(
+        &self,
+        min_latitude: Decimal,
+        min_longitude: Decimal,
+        max_latitude: Decimal,
+        max_longitude: Decimal,
+        page: i32,
+        page_size: i32,
+    ) -> LocationResult<Vec<LocationModel>> {
+        crate::repository::person::location_repository::find_within_bounding_box::find_within_bounding_box(
+            self,
+            min_latitude,
+            min_longitude,
+            max_latitude,
+            max_longitude,
+            page,
+            page_size,
+        )
+        .await
+    }
+
+    async fn find_nearest(
+        &self,
+        latitude: Decimal,
+        longitude: Decimal,
+        limit: i32,
+    ) -> LocationResult<Vec<LocationModel>> {
+        crate::repository::person::location_repository::find_nearest::find_nearest(self, latitude, longitude, limit).await
+    }
 }
 
 #[async_trait]
@@ -125,13 +161,29 @@ impl TransactionAware for LocationRepositoryImpl {
     async fn on_rollback(&self) -> BankingResult<()> {
         self.location_idx_cache.read().await.on_rollback().await
     }
+
+    async fn on_savepoint(&self, name: &str) -> BankingResult<()> {
+        self.location_idx_cache.read().await.on_savepoint(name).await
+    }
+
+    async fn on_rollback_to(&self, name: &str) -> BankingResult<()> {
+        self.location_idx_cache.read().await.on_rollback_to(name).await
+    }
+
+    async fn on_release(&self, name: &str) -> BankingResult<()> {
+        self.location_idx_cache.read().await.on_release(name).await
+    }
 }
 
+/// Local additions, updates and deletions, as snapshotted at a savepoint
+type LocalChanges = (HashMap<Uuid, LocationIdxModel>, HashMap<Uuid, LocationIdxModel>, HashSet<Uuid>);
+
 pub struct TransactionAwareLocationIdxModelCache {
     shared_cache: Arc<RwLock<LocationIdxModelCache>>,
     local_additions: RwLock<HashMap<Uuid, LocationIdxModel>>,
     local_updates: RwLock<HashMap<Uuid, LocationIdxModel>>,
     local_deletions: RwLock<HashSet<Uuid>>,
+    savepoints: SavepointSnapshots<LocalChanges>,
 }
 
 impl TransactionAwareLocationIdxModelCache {
@@ -141,6 +193,7 @@ impl TransactionAwareLocationIdxModelCache {
             local_additions: RwLock::new(HashMap::new()),
             local_updates: RwLock::new(HashMap::new()),
             local_deletions: RwLock::new(HashSet::new()),
+            savepoints: SavepointSnapshots::new(),
         }
     }
 
@@ -238,6 +291,7 @@ impl TransactionAware for TransactionAwareLocationIdxModelCache {
         local_additions.clear();
         local_updates.clear();
         local_deletions.clear();
+        self.savepoints.clear();
         Ok(())
     }
 
@@ -245,6 +299,32 @@ impl TransactionAware for TransactionAwareLocationIdxModelCache {
         self.local_additions.write().clear();
         self.local_updates.write().clear();
         self.local_deletions.write().clear();
+        self.savepoints.clear();
+        Ok(())
+    }
+
+    async fn on_savepoint(&self, name: &str) -> BankingResult<()> {
+        self.savepoints.push(
+            name,
+            (
+                self.local_additions.read().clone(),
+                self.local_updates.read().clone(),
+                self.local_deletions.read().clone(),
+            ),
+        );
+        Ok(())
+    }
+
+    async fn on_rollback_to(&self, name: &str) -> BankingResult<()> {
+        let (additions, updates, deletions) = self.savepoints.rollback_to(name);
+        *self.local_additions.write() = additions;
+        *self.local_updates.write() = updates;
+        *self.local_deletions.write() = deletions;
+        Ok(())
+    }
+
+    async fn on_release(&self, name: &str) -> BankingResult<()> {
+        self.savepoints.release(name);
         Ok(())
     }
 }
@@ -233,8 +233,8 @@ pub async fn save(
 #[cfg(test)]
 mod tests {
     use banking_db::repository::{
-        CountryRepository, CountrySubdivisionRepository, LocalityRepository, LocationRepository,
-        PersonRepos,
+        BatchRepository, CountryRepository, CountrySubdivisionRepository, LocalityRepository,
+        LocationRepository, LocationRepositoryError, PersonRepos,
     };
     use uuid::Uuid;
 
@@ -281,4 +281,49 @@ mod tests {
         let saved_location = repo.save(new_location.clone(), audit_log_id).await.unwrap();
         assert_eq!(new_location.id, saved_location.id);
     }
+    #[tokio::test]
+    async fn test_save_location_in_deactivated_locality_fails() {
+        let ctx = setup_test_context().await.unwrap();
+        let country_repo = ctx.person_repos().countries();
+        let country_subdivision_repo = ctx.person_repos().country_subdivisions();
+        let locality_repo = ctx.person_repos().localities();
+        let repo = ctx.person_repos().locations();
+
+        let unique_iso2 = format!("D{}", &Uuid::new_v4().to_string()[0..1].to_uppercase());
+        let country = create_test_country_model(&unique_iso2, "Test Country");
+        country_repo.save(country.clone()).await.unwrap();
+
+        let unique_subdivision_code =
+            format!("DS{}", &Uuid::new_v4().to_string()[0..1].to_uppercase());
+        let country_subdivision = create_test_country_subdivision_model(
+            country.id,
+            &unique_subdivision_code,
+            "Test Subdivision",
+        );
+        country_subdivision_repo
+            .save(country_subdivision.clone())
+            .await
+            .unwrap();
+
+        let unique_locality_code =
+            format!("DL{}", &Uuid::new_v4().to_string()[0..1].to_uppercase());
+        let locality =
+            create_test_locality_model(country_subdivision.id, &unique_locality_code, "Test Locality");
+        locality_repo.save(locality.clone()).await.unwrap();
+        locality_repo
+            .delete_batch(&[locality.id], Uuid::new_v4())
+            .await
+            .unwrap();
+
+        let new_location = create_test_location_model(locality.id, "Test Street", "12345");
+        let result = repo.save(new_location, Uuid::new_v4()).await;
+        assert!(matches!(
+            result,
+            Err(LocationRepositoryError::LocalityNotFound(id)) if id == locality.id
+        ));
+        assert!(locality_repo
+            .load_including_deactivated(locality.id)
+            .await
+            .is_ok());
+    }
 }
\ No newline at end of file
@@ -1,3 +1,4 @@
+pub mod contact_preference_repository;
 pub mod country_repository;
 pub mod country_subdivision_repository;
 pub mod entity_reference_repository;
@@ -44,8 +44,12 @@ impl BatchRepository<Postgres, PersonModel> for PersonRepositoryImpl {
         .await
     }
 
-    async fn delete_batch(&self, ids: &[Uuid]) -> Result<usize, Box<dyn Error + Send + Sync>> {
-        crate::repository::person::person_repository::delete_batch::delete_batch(self, ids).await
+    async fn delete_batch(
+        &self,
+        ids: &[Uuid],
+        audit_log_id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        crate::repository::person::person_repository::delete_batch::delete_batch(self, ids, audit_log_id).await
     }
 }
 
@@ -1,11 +1,8 @@
 use crate::repository::person::person_repository::repo_impl::PersonRepositoryImpl;
 use banking_db::models::person::{PersonIdxModel, PersonModel};
-use banking_db::repository::{
-    BatchOperationStats, LocationRepository, PersonRepository, PersonRepositoryError,
-};
+use banking_db::repository::{LocationRepository, PersonRepository, PersonRepositoryError};
 use std::error::Error;
 use std::hash::Hasher;
-use std::time::Instant;
 use twox_hash::XxHash64;
 use uuid::Uuid;
 
@@ -18,12 +15,7 @@ pub async fn create_batch(
         return Ok(Vec::new());
     }
 
-    let start = Instant::now();
     let mut saved_items = Vec::with_capacity(items.len());
-    let mut stats = BatchOperationStats {
-        total_items: items.len(),
-        ..Default::default()
-    };
 
     // filter ids into a vec
     let ids: Vec<Uuid> = items.iter().map(|p| p.id).collect();
@@ -147,7 +139,6 @@ pub async fn create_batch(
         ));
 
         saved_items.push(person);
-        stats.successful_items += 1;
     }
 
     if !person_values.is_empty() {
@@ -168,7 +159,6 @@ pub async fn create_batch(
         .await?;
     }
 
-    stats.duration_ms = start.elapsed().as_millis() as u64;
     Ok(saved_items)
 }
 #[cfg(test)]
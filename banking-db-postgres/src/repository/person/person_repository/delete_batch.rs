@@ -8,6 +8,7 @@ use uuid::Uuid;
 pub async fn delete_batch(
     repo: &PersonRepositoryImpl,
     ids: &[Uuid],
+    audit_log_id: Uuid,
 ) -> Result<usize, Box<dyn Error + Send + Sync>> {
     if ids.is_empty() {
         return Ok(0);
@@ -62,20 +63,20 @@ pub async fn delete_batch(
             person.location_id,
             person.duplicate_of_person_id,
             person.entity_reference_count,
-            Uuid::new_v4(),
+            audit_log_id,
         ));
     }
-    let delete_query = "DELETE FROM person WHERE id = ANY($1)";
+    let deactivate_query = "UPDATE person SET deactivated_at = NOW(), deactivated_by = $2 WHERE id = ANY($1) AND deactivated_at IS NULL";
     let delete_idx_query = "DELETE FROM person_idx WHERE person_id = ANY($1)";
     match &repo.executor {
         crate::repository::executor::Executor::Pool(pool) => {
             sqlx::query(delete_idx_query).bind(&existing_ids).execute(&**pool).await?;
-            sqlx::query(delete_query).bind(&existing_ids).execute(&**pool).await?;
+            sqlx::query(deactivate_query).bind(&existing_ids).bind(audit_log_id).execute(&**pool).await?;
         }
         crate::repository::executor::Executor::Tx(tx) => {
             let mut tx = tx.lock().await;
             sqlx::query(delete_idx_query).bind(&existing_ids).execute(&mut **tx).await?;
-            sqlx::query(delete_query).bind(&existing_ids).execute(&mut **tx).await?;
+            sqlx::query(deactivate_query).bind(&existing_ids).bind(audit_log_id).execute(&mut **tx).await?;
         }
     }
     crate::repository::person::person_repository::batch_helper::execute_person_audit_insert(
@@ -133,8 +134,9 @@ mod tests {
         // Delete first two persons
         let ids_to_delete: Vec<Uuid> = saved_persons.iter().take(2).map(|p| p.id).collect();
 
+        let delete_audit_log_id = Uuid::new_v4();
         person_repo
-            .delete_batch(&ids_to_delete)
+            .delete_batch(&ids_to_delete, delete_audit_log_id)
             .await?;
 
         // Verify deletions
@@ -147,6 +149,13 @@ mod tests {
         assert!(!exists_results[1].1); // Deleted
         assert!(exists_results[2].1); // Still exists
 
+        // Deactivated persons are gone from the default reads but kept for history
+        let deleted_id = ids_to_delete[0];
+        assert!(person_repo.find_by_id(deleted_id).await?.is_none());
+        assert!(person_repo.load(deleted_id).await.is_err());
+        let deactivated = person_repo.load_including_deactivated(deleted_id).await?;
+        assert_eq!(deactivated.display_name.as_str(), "Delete Test 0");
+
         Ok(())
     }
 }
\ No newline at end of file
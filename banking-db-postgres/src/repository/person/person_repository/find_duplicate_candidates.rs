@@ -0,0 +1,101 @@
+use banking_db::models::person::PersonModel;
+use banking_db::repository::{PersonRepositoryError, PersonResult};
+use crate::repository::person::person_repository::repo_impl::PersonRepositoryImpl;
+use crate::utils::TryFromRow;
+use uuid::Uuid;
+
+pub async fn find_duplicate_candidates(
+    repo: &PersonRepositoryImpl,
+    person_id: Uuid,
+    external_identifier: Option<&str>,
+    normalized_name: &str,
+    messaging_values: &[String],
+) -> PersonResult<Vec<PersonModel>> {
+    // The name normalization mirrors the service: lowercase, punctuation dropped,
+    // whitespace collapsed
+    let query = sqlx::query(
+        r#"
+        SELECT * FROM person
+        WHERE id <> $1
+          AND deactivated_at IS NULL
+          AND (
+            ($2::text IS NOT NULL AND external_identifier = $2)
+            OR btrim(regexp_replace(
+                regexp_replace(lower(display_name), '[^[:alnum:][:space:]]', '', 'g'),
+                '\s+', ' ', 'g'
+            )) = $3
+            OR lower(messaging_info1) = ANY($4)
+            OR lower(messaging_info2) = ANY($4)
+            OR lower(messaging_info3) = ANY($4)
+            OR lower(messaging_info4) = ANY($4)
+            OR lower(messaging_info5) = ANY($4)
+          )
+        "#,
+    )
+    .bind(person_id)
+    .bind(external_identifier)
+    .bind(normalized_name)
+    .bind(messaging_values);
+
+    let rows = match &repo.executor {
+        crate::repository::executor::Executor::Pool(pool) => query.fetch_all(&**pool).await?,
+        crate::repository::executor::Executor::Tx(tx) => {
+            let mut tx = tx.lock().await;
+            query.fetch_all(&mut **tx).await?
+        }
+    };
+
+    rows.iter()
+        .map(|row| PersonModel::try_from_row(row).map_err(PersonRepositoryError::RepositoryError))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use banking_db::repository::{PersonRepository, PersonRepos};
+    use heapless::String as HeaplessString;
+    use uuid::Uuid;
+    use crate::repository::person::test_helpers::create_test_person_model;
+    use crate::test_helper::setup_test_context;
+
+    #[tokio::test]
+    async fn test_find_duplicate_candidates() {
+        let ctx = setup_test_context().await.unwrap();
+        let repo = ctx.person_repos().persons();
+        let audit_log_id = Uuid::new_v4();
+
+        let mut person = create_test_person_model("Amara Okafor");
+        person.messaging_info1 = Some(HeaplessString::try_from("email:amara@example.com").unwrap());
+        repo.save(person.clone(), audit_log_id).await.unwrap();
+
+        let mut same_identifier = create_test_person_model("A. Okafor");
+        same_identifier.external_identifier = person.external_identifier.clone();
+        repo.save(same_identifier.clone(), audit_log_id).await.unwrap();
+
+        let same_name = create_test_person_model("AMARA  okafor.");
+        repo.save(same_name.clone(), audit_log_id).await.unwrap();
+
+        let mut shared_email = create_test_person_model("Someone Else");
+        shared_email.messaging_info3 = Some(HeaplessString::try_from("EMAIL:Amara@Example.com").unwrap());
+        repo.save(shared_email.clone(), audit_log_id).await.unwrap();
+
+        let unrelated = create_test_person_model("Unrelated Person");
+        repo.save(unrelated.clone(), audit_log_id).await.unwrap();
+
+        let candidates = repo
+            .find_duplicate_candidates(
+                person.id,
+                person.external_identifier.as_deref(),
+                "amara okafor",
+                &["email:amara@example.com".to_string()],
+            )
+            .await
+            .unwrap();
+
+        let mut ids: Vec<Uuid> = candidates.iter().map(|p| p.id).collect();
+        ids.sort();
+        let mut expected = vec![same_identifier.id, same_name.id, shared_email.id];
+        expected.sort();
+        assert_eq!(ids, expected);
+    }
+}
@@ -0,0 +1,69 @@
+use banking_db::repository::PersonResult;
+use chrono::{DateTime, Utc};
+use crate::repository::person::person_repository::repo_impl::PersonRepositoryImpl;
+use sqlx::Row;
+use uuid::Uuid;
+
+pub async fn find_ids_created_since(
+    repo: &PersonRepositoryImpl,
+    since: DateTime<Utc>,
+) -> PersonResult<Vec<Uuid>> {
+    // A person is created by its version 0, stamped by the audit log of that change
+    let query = sqlx::query(
+        r#"
+        SELECT pa.person_id
+        FROM person_audit pa
+        JOIN audit_log al ON al.id = pa.audit_log_id
+        JOIN person p ON p.id = pa.person_id
+        WHERE pa.version = 0
+          AND al.updated_at >= $1
+          AND p.deactivated_at IS NULL
+        ORDER BY al.updated_at, pa.person_id
+        "#,
+    )
+    .bind(since);
+
+    let rows = match &repo.executor {
+        crate::repository::executor::Executor::Pool(pool) => query.fetch_all(&**pool).await?,
+        crate::repository::executor::Executor::Tx(tx) => {
+            let mut tx = tx.lock().await;
+            query.fetch_all(&mut **tx).await?
+        }
+    };
+
+    Ok(rows.iter().map(|row| row.get("person_id")).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use banking_db::models::audit::AuditLogModel;
+    use banking_db::repository::{AuditLogRepository, PersonRepository, PersonRepos, UnitOfWorkSession};
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+    use crate::repository::person::test_helpers::create_test_person_model;
+    use crate::test_helper::setup_test_context;
+
+    #[tokio::test]
+    async fn test_find_ids_created_since() {
+        let ctx = setup_test_context().await.unwrap();
+        let repo = ctx.person_repos().persons();
+        let since = Utc::now() - Duration::hours(1);
+
+        let old_audit = AuditLogModel::new(Uuid::new_v4(), since - Duration::days(1), Uuid::new_v4());
+        let new_audit = AuditLogModel::new(Uuid::new_v4(), Utc::now(), Uuid::new_v4());
+        ctx.session.audit_logs().create(&old_audit).await.unwrap();
+        ctx.session.audit_logs().create(&new_audit).await.unwrap();
+
+        let mut old_person = create_test_person_model("Old Person");
+        repo.save(old_person.clone(), old_audit.id).await.unwrap();
+        let new_person = create_test_person_model("New Person");
+        repo.save(new_person.clone(), new_audit.id).await.unwrap();
+
+        // A later change to an old person does not make it new
+        old_person.department = Some(heapless::String::try_from("Sales").unwrap());
+        repo.save(old_person, new_audit.id).await.unwrap();
+
+        let ids = repo.find_ids_created_since(since).await.unwrap();
+        assert_eq!(ids, vec![new_person.id]);
+    }
+}
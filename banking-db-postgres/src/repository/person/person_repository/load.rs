@@ -7,7 +7,7 @@ use uuid::Uuid;
 pub async fn load(repo: &PersonRepositoryImpl, id: Uuid) -> PersonResult<PersonModel> {
     let query = sqlx::query(
         r#"
-        SELECT * FROM person WHERE id = $1
+        SELECT * FROM person WHERE id = $1 AND deactivated_at IS NULL
         "#,
     )
     .bind(id);
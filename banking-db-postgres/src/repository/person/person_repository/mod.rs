@@ -10,6 +10,7 @@ pub mod update_batch;
 pub mod delete_batch;
 pub mod save;
 pub mod load;
+pub mod load_including_deactivated;
 pub mod find_by_id;
 pub mod find_by_ids;
 pub mod exists_by_id;
@@ -17,4 +18,6 @@ pub mod exist_by_ids;
 pub mod get_ids_by_external_identifier;
 pub mod get_by_external_identifier;
 pub mod find_by_duplicate_of_person_id;
-pub mod find_by_organization_person_id;
\ No newline at end of file
+pub mod find_by_organization_person_id;
+pub mod find_duplicate_candidates;
+pub mod find_ids_created_since;
\ No newline at end of file
@@ -2,7 +2,9 @@ use async_trait::async_trait;
 use banking_api::BankingResult;
 use banking_db::models::person::{PersonIdxModel, PersonIdxModelCache, PersonModel};
 use banking_db::repository::{PersonRepository, PersonResult, TransactionAware};
+use chrono::{DateTime, Utc};
 use crate::repository::executor::Executor;
+use crate::repository::savepoint::SavepointSnapshots;
 use crate::repository::person::location_repository::LocationRepositoryImpl;
 use crate::utils::{get_heapless_string, get_optional_heapless_string, TryFromRow};
 use sqlx::{postgres::PgRow, Postgres, Row};
@@ -62,6 +64,10 @@ impl PersonRepository<Postgres> for PersonRepositoryImpl {
         crate::repository::person::person_repository::load::load(self, id).await
     }
 
+    async fn load_including_deactivated(&self, id: Uuid) -> PersonResult<PersonModel> {
+        crate::repository::person::person_repository::load_including_deactivated::load_including_deactivated(self, id).await
+    }
+
     async fn find_by_id(&self, id: Uuid) -> PersonResult<Option<PersonIdxModel>> {
         crate::repository::person::person_repository::find_by_id::find_by_id(self, id).await
     }
@@ -102,6 +108,27 @@ impl PersonRepository<Postgres> for PersonRepositoryImpl {
     ) -> PersonResult<Vec<PersonIdxModel>> {
         crate::repository::person::person_repository::find_by_organization_person_id::find_by_organization_person_id(self, person_id).await
     }
+
+    async fn find_duplicate_candidates(
+        &self,
+        person_id: Uuid,
+        external_identifier: Option<&str>,
+        normalized_name: &str,
+        messaging_values: &[String],
+    ) -> PersonResult<Vec<PersonModel>> {
+        crate::repository::person::person_repository::find_duplicate_candidates::find_duplicate_candidates(
+            self,
+            person_id,
+            external_identifier,
+            normalized_name,
+            messaging_values,
+        )
+        .await
+    }
+
+    async fn find_ids_created_since(&self, since: DateTime<Utc>) -> PersonResult<Vec<Uuid>> {
+        crate::repository::person::person_repository::find_ids_created_since::find_ids_created_since(self, since).await
+    }
 }
 
 #[async_trait]
@@ -115,13 +142,29 @@ impl TransactionAware for PersonRepositoryImpl {
         let cache = self.person_idx_cache.read().await;
         cache.on_rollback().await
     }
+
+    async fn on_savepoint(&self, name: &str) -> BankingResult<()> {
+        self.person_idx_cache.read().await.on_savepoint(name).await
+    }
+
+    async fn on_rollback_to(&self, name: &str) -> BankingResult<()> {
+        self.person_idx_cache.read().await.on_rollback_to(name).await
+    }
+
+    async fn on_release(&self, name: &str) -> BankingResult<()> {
+        self.person_idx_cache.read().await.on_release(name).await
+    }
 }
 
+/// Local additions, updates and deletions, as snapshotted at a savepoint
+type LocalChanges = (HashMap<Uuid, PersonIdxModel>, HashMap<Uuid, PersonIdxModel>, HashSet<Uuid>);
+
 pub struct TransactionAwarePersonIdxModelCache {
     shared_cache: Arc<RwLock<PersonIdxModelCache>>,
     local_additions: RwLock<HashMap<Uuid, PersonIdxModel>>,
     local_updates: RwLock<HashMap<Uuid, PersonIdxModel>>,
     local_deletions: RwLock<HashSet<Uuid>>,
+    savepoints: SavepointSnapshots<LocalChanges>,
 }
 
 impl TransactionAwarePersonIdxModelCache {
@@ -131,6 +174,7 @@ impl TransactionAwarePersonIdxModelCache {
             local_additions: RwLock::new(HashMap::new()),
             local_updates: RwLock::new(HashMap::new()),
             local_deletions: RwLock::new(HashSet::new()),
+            savepoints: SavepointSnapshots::new(),
         }
     }
 
@@ -247,6 +291,7 @@ impl TransactionAware for TransactionAwarePersonIdxModelCache {
         self.local_additions.write().clear();
         self.local_updates.write().clear();
         self.local_deletions.write().clear();
+        self.savepoints.clear();
         Ok(())
     }
 
@@ -254,6 +299,32 @@ impl TransactionAware for TransactionAwarePersonIdxModelCache {
         self.local_additions.write().clear();
         self.local_updates.write().clear();
         self.local_deletions.write().clear();
+        self.savepoints.clear();
+        Ok(())
+    }
+
+    async fn on_savepoint(&self, name: &str) -> BankingResult<()> {
+        self.savepoints.push(
+            name,
+            (
+                self.local_additions.read().clone(),
+                self.local_updates.read().clone(),
+                self.local_deletions.read().clone(),
+            ),
+        );
+        Ok(())
+    }
+
+    async fn on_rollback_to(&self, name: &str) -> BankingResult<()> {
+        let (additions, updates, deletions) = self.savepoints.rollback_to(name);
+        *self.local_additions.write() = additions;
+        *self.local_updates.write() = updates;
+        *self.local_deletions.write() = deletions;
+        Ok(())
+    }
+
+    async fn on_release(&self, name: &str) -> BankingResult<()> {
+        self.savepoints.release(name);
         Ok(())
     }
 }
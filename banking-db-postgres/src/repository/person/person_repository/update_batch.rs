@@ -1,11 +1,8 @@
 use crate::repository::person::person_repository::repo_impl::PersonRepositoryImpl;
 use banking_db::models::person::PersonModel;
-use banking_db::repository::{
-    BatchOperationStats, LocationRepository, PersonRepository, PersonRepositoryError,
-};
+use banking_db::repository::{LocationRepository, PersonRepository, PersonRepositoryError};
 use std::error::Error;
 use std::hash::Hasher;
-use std::time::Instant;
 use twox_hash::XxHash64;
 use uuid::Uuid;
 
@@ -17,12 +14,7 @@ pub async fn update_batch(
     if items.is_empty() {
         return Ok(Vec::new());
     }
-    let start = Instant::now();
     let mut updated_items = Vec::new();
-    let mut stats = BatchOperationStats {
-        total_items: items.len(),
-        ..Default::default()
-    };
     let ids: Vec<Uuid> = items.iter().map(|p| p.id).collect();
     let existing_persons_check = repo.exist_by_ids(&ids).await?;
     let missing_ids: Vec<Uuid> = existing_persons_check
@@ -46,7 +38,6 @@ pub async fn update_batch(
         let new_hash = hasher.finish() as i64;
         if let Some(existing_idx) = cache.get_by_primary(&person.id) {
             if existing_idx.hash == new_hash {
-                stats.skipped_items += 1;
                 continue;
             }
             let new_version = existing_idx.version + 1;
@@ -89,9 +80,6 @@ pub async fn update_batch(
             updated_idx.duplicate_of_person_id = person.duplicate_of_person_id;
             cache.update(updated_idx);
             updated_items.push(person);
-            stats.successful_items += 1;
-        } else {
-            stats.failed_items += 1;
         }
     }
     // location validation
@@ -150,7 +138,6 @@ pub async fn update_batch(
         )
         .await?;
     }
-    stats.duration_ms = start.elapsed().as_millis() as u64;
     Ok(updated_items)
 }
 #[cfg(test)]
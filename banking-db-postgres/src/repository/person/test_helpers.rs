@@ -103,4 +103,22 @@ pub fn create_test_entity_reference_model(
         reference_details_l2: None,
         reference_details_l3: None,
     }
-}
\ No newline at end of file
+}
+use banking_db::models::person::{ContactChannel, ContactPreferenceModel};
+
+pub fn create_test_contact_preference_model(
+    person_id: Uuid,
+    channel: ContactChannel,
+    allowed: bool,
+) -> ContactPreferenceModel {
+    ContactPreferenceModel {
+        id: Uuid::new_v4(),
+        person_id,
+        channel,
+        allowed,
+        quiet_hours_start: None,
+        quiet_hours_end: None,
+        preferred_language: None,
+        updated_at: chrono::Utc::now(),
+    }
+}
@@ -0,0 +1,235 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{AccountSegmentCodeModel, DbPromotionRateKind, ProductPromotionModel};
+use banking_db::repository::PromotionRepository;
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of PromotionRepository
+pub struct PromotionRepositoryImpl {
+    pool: PgPool,
+}
+
+impl PromotionRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+fn heapless<const N: usize>(value: String, field: &str) -> BankingResult<HeaplessString<N>> {
+    HeaplessString::try_from(value.as_str()).map_err(|_| BankingError::ValidationError {
+        field: field.to_string(),
+        message: format!("{field} too long"),
+    })
+}
+
+impl TryFromRow<PgRow> for ProductPromotionModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(ProductPromotionModel {
+            id: row.get("id"),
+            product_id: row.get("product_id"),
+            promotion_code: heapless(row.get("promotion_code"), "promotion_code")?,
+            name: heapless(row.get("name"), "name")?,
+            rate_kind: row.get::<String, _>("rate_kind")
+                .parse::<DbPromotionRateKind>()
+                .map_err(|_| BankingError::Internal("Invalid promotion rate kind".to_string()))?,
+            rate_value: row.get("rate_value"),
+            opened_from: row.get("opened_from"),
+            opened_to: row.get("opened_to"),
+            segment_code: row
+                .get::<Option<String>, _>("segment_code")
+                .map(|code| heapless(code, "segment_code"))
+                .transpose()?,
+            start_date: row.get("start_date"),
+            end_date: row.get("end_date"),
+            budget_cap: row.get("budget_cap"),
+            bonus_interest_attributed: row.get("bonus_interest_attributed"),
+            budget_exhausted_at: row.get("budget_exhausted_at"),
+            is_active: row.get("is_active"),
+            created_at: row.get("created_at"),
+            last_updated_at: row.get("last_updated_at"),
+            updated_by_person_id: row.get("updated_by_person_id"),
+        })
+    }
+}
+
+/// The attributed bonus is summed from the accrual rows, so it only counts
+/// accruals that were applied and never double counts a retried chunk
+const PROMOTION_COLUMNS: &str = r#"
+    id, product_id, promotion_code, name, rate_kind::text as rate_kind, rate_value,
+    opened_from, opened_to, segment_code, start_date, end_date, budget_cap,
+    COALESCE((
+        SELECT SUM(a.bonus_interest) FROM account_interest_accruals a
+        WHERE a.promotion_id = product_promotions.id
+    ), 0) as bonus_interest_attributed,
+    budget_exhausted_at, is_active, created_at, last_updated_at, updated_by_person_id
+"#;
+
+#[async_trait]
+impl PromotionRepository for PromotionRepositoryImpl {
+    async fn create_promotion(&self, promotion: ProductPromotionModel) -> BankingResult<ProductPromotionModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO product_promotions (
+                id, product_id, promotion_code, name, rate_kind, rate_value, opened_from, opened_to,
+                segment_code, start_date, end_date, budget_cap, budget_exhausted_at, is_active,
+                created_at, last_updated_at, updated_by_person_id
+            )
+            VALUES ($1, $2, $3, $4, $5::promotion_rate_kind, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+            RETURNING {PROMOTION_COLUMNS}
+            "#
+        ))
+        .bind(promotion.id)
+        .bind(promotion.product_id)
+        .bind(promotion.promotion_code.as_str())
+        .bind(promotion.name.as_str())
+        .bind(promotion.rate_kind)
+        .bind(promotion.rate_value)
+        .bind(promotion.opened_from)
+        .bind(promotion.opened_to)
+        .bind(promotion.segment_code.as_ref().map(|s| s.as_str()))
+        .bind(promotion.start_date)
+        .bind(promotion.end_date)
+        .bind(promotion.budget_cap)
+        .bind(promotion.budget_exhausted_at)
+        .bind(promotion.is_active)
+        .bind(promotion.created_at)
+        .bind(promotion.last_updated_at)
+        .bind(promotion.updated_by_person_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create promotion: {e}")))?;
+
+        ProductPromotionModel::try_from_row(&row)
+    }
+
+    async fn update_promotion(&self, promotion: ProductPromotionModel) -> BankingResult<ProductPromotionModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE product_promotions
+            SET name = $2, rate_kind = $3::promotion_rate_kind, rate_value = $4, opened_from = $5, opened_to = $6,
+                segment_code = $7, start_date = $8, end_date = $9, budget_cap = $10, is_active = $11,
+                last_updated_at = $12, updated_by_person_id = $13
+            WHERE id = $1
+            RETURNING {PROMOTION_COLUMNS}
+            "#
+        ))
+        .bind(promotion.id)
+        .bind(promotion.name.as_str())
+        .bind(promotion.rate_kind)
+        .bind(promotion.rate_value)
+        .bind(promotion.opened_from)
+        .bind(promotion.opened_to)
+        .bind(promotion.segment_code.as_ref().map(|s| s.as_str()))
+        .bind(promotion.start_date)
+        .bind(promotion.end_date)
+        .bind(promotion.budget_cap)
+        .bind(promotion.is_active)
+        .bind(promotion.last_updated_at)
+        .bind(promotion.updated_by_person_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update promotion: {e}")))?;
+
+        ProductPromotionModel::try_from_row(&row)
+    }
+
+    async fn find_promotion_by_id(&self, promotion_id: Uuid) -> BankingResult<Option<ProductPromotionModel>> {
+        let row = sqlx::query(&format!("SELECT {PROMOTION_COLUMNS} FROM product_promotions WHERE id = $1"))
+            .bind(promotion_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find promotion: {e}")))?;
+
+        row.as_ref().map(ProductPromotionModel::try_from_row).transpose()
+    }
+
+    async fn find_promotions_by_product(&self, product_id: Uuid) -> BankingResult<Vec<ProductPromotionModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {PROMOTION_COLUMNS} FROM product_promotions WHERE product_id = $1 ORDER BY start_date DESC"
+        ))
+        .bind(product_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find promotions by product: {e}")))?;
+
+        rows.iter().map(ProductPromotionModel::try_from_row).collect()
+    }
+
+    async fn delete_promotion(&self, promotion_id: Uuid) -> BankingResult<()> {
+        sqlx::query("DELETE FROM product_promotions WHERE id = $1")
+            .bind(promotion_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to delete promotion: {e}")))?;
+        Ok(())
+    }
+
+    async fn find_promotions_in_effect(&self, date: NaiveDate) -> BankingResult<Vec<ProductPromotionModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {PROMOTION_COLUMNS}
+            FROM product_promotions
+            WHERE is_active = TRUE
+              AND budget_exhausted_at IS NULL
+              AND start_date <= $1 AND end_date >= $1
+            ORDER BY product_id, start_date
+            "#
+        ))
+        .bind(date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find promotions in effect: {e}")))?;
+
+        rows.iter().map(ProductPromotionModel::try_from_row).collect()
+    }
+
+    async fn find_account_segment_codes(&self, account_ids: &[Uuid]) -> BankingResult<Vec<AccountSegmentCodeModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT DISTINCT o.account_id, s.code
+            FROM account_ownership o
+            JOIN segment_memberships m ON m.customer_id = o.customer_id AND m.exited_at IS NULL
+            JOIN segments s ON s.id = m.segment_id AND s.is_active = TRUE
+            WHERE o.account_id = ANY($1)
+            "#,
+        )
+        .bind(account_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find account segment codes: {e}")))?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(AccountSegmentCodeModel {
+                    account_id: row.get("account_id"),
+                    segment_code: heapless(row.get("code"), "segment_code")?,
+                })
+            })
+            .collect()
+    }
+
+    async fn mark_budget_exhausted(&self, promotion_id: Uuid, exhausted_at: DateTime<Utc>) -> BankingResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE product_promotions
+            SET budget_exhausted_at = COALESCE(budget_exhausted_at, $2)
+            WHERE id = $1
+            "#,
+        )
+        .bind(promotion_id)
+        .bind(exhausted_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to mark promotion budget exhausted: {e}")))?;
+        Ok(())
+    }
+}
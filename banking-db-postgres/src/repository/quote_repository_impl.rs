@@ -0,0 +1,155 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{DbQuoteStatus, DbQuoteType, QuoteModel};
+use banking_db::repository::QuoteRepository;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of QuoteRepository
+pub struct QuoteRepositoryImpl {
+    pool: PgPool,
+}
+
+impl QuoteRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+impl TryFromRow<PgRow> for QuoteModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(QuoteModel {
+            id: row.get("id"),
+            quote_type: row.get::<String, _>("quote_type").parse::<DbQuoteType>()
+                .map_err(|_| BankingError::Internal("Invalid quote type".to_string()))?,
+            product_id: row.get("product_id"),
+            customer_id: row.get("customer_id"),
+            simulation: row.get("simulation"),
+            status: row.get::<String, _>("status").parse::<DbQuoteStatus>()
+                .map_err(|_| BankingError::Internal("Invalid quote status".to_string()))?,
+            expires_at: row.get("expires_at"),
+            converted_at: row.get("converted_at"),
+            created_at: row.get("created_at"),
+            created_by_person_id: row.get("created_by_person_id"),
+        })
+    }
+}
+
+#[async_trait]
+impl QuoteRepository for QuoteRepositoryImpl {
+    async fn create_quote(&self, quote: QuoteModel) -> BankingResult<QuoteModel> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO quotes (
+                id, quote_type, product_id, customer_id, simulation, status,
+                expires_at, converted_at, created_at, created_by_person_id
+            )
+            VALUES ($1, $2::quote_type, $3, $4, $5::jsonb, $6::quote_status, $7, $8, $9, $10)
+            RETURNING id, quote_type::text as quote_type, product_id, customer_id,
+                      simulation::text as simulation, status::text as status,
+                      expires_at, converted_at, created_at, created_by_person_id
+            "#,
+        )
+        .bind(quote.id)
+        .bind(quote.quote_type)
+        .bind(quote.product_id)
+        .bind(quote.customer_id)
+        .bind(&quote.simulation)
+        .bind(quote.status)
+        .bind(quote.expires_at)
+        .bind(quote.converted_at)
+        .bind(quote.created_at)
+        .bind(quote.created_by_person_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create quote: {e}")))?;
+
+        QuoteModel::try_from_row(&result)
+    }
+
+    async fn find_quote_by_id(&self, quote_id: Uuid) -> BankingResult<Option<QuoteModel>> {
+        let result = sqlx::query(
+            r#"
+            SELECT id, quote_type::text as quote_type, product_id, customer_id,
+                   simulation::text as simulation, status::text as status,
+                   expires_at, converted_at, created_at, created_by_person_id
+            FROM quotes
+            WHERE id = $1
+            "#,
+        )
+        .bind(quote_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find quote: {e}")))?;
+
+        match result {
+            Some(row) => Ok(Some(QuoteModel::try_from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_quotes_by_customer(&self, customer_id: Uuid) -> BankingResult<Vec<QuoteModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, quote_type::text as quote_type, product_id, customer_id,
+                   simulation::text as simulation, status::text as status,
+                   expires_at, converted_at, created_at, created_by_person_id
+            FROM quotes
+            WHERE customer_id = $1
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(customer_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find quotes for customer: {e}")))?;
+
+        let mut quotes = Vec::new();
+        for row in rows {
+            quotes.push(QuoteModel::try_from_row(&row)?);
+        }
+        Ok(quotes)
+    }
+
+    async fn transition_active_quote(
+        &self,
+        quote_id: Uuid,
+        status: DbQuoteStatus,
+        converted_at: Option<DateTime<Utc>>,
+    ) -> BankingResult<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE quotes
+            SET status = $2::quote_status, converted_at = $3
+            WHERE id = $1 AND status = 'Active'::quote_status
+            "#,
+        )
+        .bind(quote_id)
+        .bind(status)
+        .bind(converted_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update quote status: {e}")))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn expire_quotes(&self, as_of: DateTime<Utc>) -> BankingResult<u64> {
+        let result = sqlx::query(
+            "UPDATE quotes SET status = 'Expired'::quote_status WHERE status = 'Active'::quote_status AND expires_at <= $1",
+        )
+        .bind(as_of)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to expire quotes: {e}")))?;
+
+        Ok(result.rows_affected())
+    }
+}
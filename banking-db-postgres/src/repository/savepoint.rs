@@ -0,0 +1,101 @@
+use parking_lot::RwLock;
+
+/// Snapshots of a transaction-aware cache's local changes, one per open savepoint.
+///
+/// Follows PostgreSQL semantics: savepoint names may be reused and always
+/// refer to the most recent savepoint of that name, rolling back to a
+/// savepoint keeps it open and discards later ones, and releasing a
+/// savepoint discards it together with later ones.
+///
+/// The database has already validated the name by the time a cache is
+/// notified, so an unknown name means the savepoint was created before the
+/// cache joined the transaction: every local change postdates it.
+pub struct SavepointSnapshots<S> {
+    stack: RwLock<Vec<(String, S)>>,
+}
+
+impl<S: Clone + Default> SavepointSnapshots<S> {
+    pub fn new() -> Self {
+        Self {
+            stack: RwLock::new(Vec::new()),
+        }
+    }
+
+    pub fn push(&self, name: &str, snapshot: S) {
+        self.stack.write().push((name.to_string(), snapshot));
+    }
+
+    /// Returns the local changes as of savepoint `name` and discards later snapshots
+    pub fn rollback_to(&self, name: &str) -> S {
+        let mut stack = self.stack.write();
+        match Self::position(&stack, name) {
+            Some(position) => {
+                stack.truncate(position + 1);
+                stack[position].1.clone()
+            }
+            None => {
+                stack.clear();
+                S::default()
+            }
+        }
+    }
+
+    pub fn release(&self, name: &str) {
+        let mut stack = self.stack.write();
+        let position = Self::position(&stack, name).unwrap_or(0);
+        stack.truncate(position);
+    }
+
+    pub fn clear(&self) {
+        self.stack.write().clear();
+    }
+
+    fn position(stack: &[(String, S)], name: &str) -> Option<usize> {
+        stack.iter().rposition(|(savepoint, _)| savepoint == name)
+    }
+}
+
+impl<S: Clone + Default> Default for SavepointSnapshots<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rollback_to_keeps_savepoint_and_drops_later_ones() {
+        let snapshots = SavepointSnapshots::new();
+        snapshots.push("a", 1);
+        snapshots.push("b", 2);
+        snapshots.push("c", 3);
+
+        assert_eq!(snapshots.rollback_to("b"), 2);
+        assert_eq!(snapshots.rollback_to("b"), 2);
+
+        snapshots.release("b");
+        assert_eq!(snapshots.rollback_to("a"), 1);
+    }
+
+    #[test]
+    fn test_reused_name_refers_to_latest_savepoint() {
+        let snapshots = SavepointSnapshots::new();
+        snapshots.push("sp", 1);
+        snapshots.push("sp", 2);
+
+        assert_eq!(snapshots.rollback_to("sp"), 2);
+        snapshots.release("sp");
+        assert_eq!(snapshots.rollback_to("sp"), 1);
+    }
+
+    #[test]
+    fn test_unknown_savepoint_predates_all_snapshots() {
+        let snapshots = SavepointSnapshots::new();
+        snapshots.push("later", 5);
+
+        assert_eq!(snapshots.rollback_to("earlier"), 0);
+        assert_eq!(snapshots.rollback_to("later"), 0);
+    }
+}
@@ -0,0 +1,143 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{DbSavingsGoalStatus, SavingsGoalModel};
+use banking_db::repository::SavingsGoalRepository;
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of SavingsGoalRepository
+pub struct SavingsGoalRepositoryImpl {
+    pool: PgPool,
+}
+
+impl SavingsGoalRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+impl TryFromRow<PgRow> for SavingsGoalModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(SavingsGoalModel {
+            id: row.get("id"),
+            account_id: row.get("account_id"),
+            name: HeaplessString::try_from(row.get::<String, _>("name").as_str()).map_err(|_| {
+                BankingError::ValidationError {
+                    field: "name".to_string(),
+                    message: "Goal name too long".to_string(),
+                }
+            })?,
+            target_amount: row.get("target_amount"),
+            target_date: row.get("target_date"),
+            allocated_balance: row.get("allocated_balance"),
+            status: row.get::<String, _>("status")
+                .parse::<DbSavingsGoalStatus>()
+                .map_err(|_| BankingError::Internal("Invalid savings goal status".to_string()))?,
+            created_at: row.get("created_at"),
+            closed_at: row.get("closed_at"),
+            last_updated_at: row.get("last_updated_at"),
+            updated_by_person_id: row.get("updated_by_person_id"),
+        })
+    }
+}
+
+const SAVINGS_GOAL_COLUMNS: &str = r#"
+    id, account_id, name, target_amount, target_date, allocated_balance, status::text as status,
+    created_at, closed_at, last_updated_at, updated_by_person_id
+"#;
+
+#[async_trait]
+impl SavingsGoalRepository for SavingsGoalRepositoryImpl {
+    async fn create_goal(&self, goal: SavingsGoalModel) -> BankingResult<SavingsGoalModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO savings_goals (
+                id, account_id, name, target_amount, target_date, allocated_balance, status,
+                created_at, closed_at, last_updated_at, updated_by_person_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7::savings_goal_status, $8, $9, $10, $11)
+            RETURNING {SAVINGS_GOAL_COLUMNS}
+            "#
+        ))
+        .bind(goal.id)
+        .bind(goal.account_id)
+        .bind(goal.name.as_str())
+        .bind(goal.target_amount)
+        .bind(goal.target_date)
+        .bind(goal.allocated_balance)
+        .bind(goal.status)
+        .bind(goal.created_at)
+        .bind(goal.closed_at)
+        .bind(goal.last_updated_at)
+        .bind(goal.updated_by_person_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create savings goal: {e}")))?;
+
+        SavingsGoalModel::try_from_row(&row)
+    }
+
+    async fn update_goal(&self, goal: SavingsGoalModel) -> BankingResult<SavingsGoalModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE savings_goals
+            SET name = $2, target_amount = $3, target_date = $4, allocated_balance = $5,
+                status = $6::savings_goal_status, closed_at = $7, last_updated_at = $8, updated_by_person_id = $9
+            WHERE id = $1
+            RETURNING {SAVINGS_GOAL_COLUMNS}
+            "#
+        ))
+        .bind(goal.id)
+        .bind(goal.name.as_str())
+        .bind(goal.target_amount)
+        .bind(goal.target_date)
+        .bind(goal.allocated_balance)
+        .bind(goal.status)
+        .bind(goal.closed_at)
+        .bind(goal.last_updated_at)
+        .bind(goal.updated_by_person_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update savings goal: {e}")))?;
+
+        SavingsGoalModel::try_from_row(&row)
+    }
+
+    async fn find_goal_by_id(&self, goal_id: Uuid) -> BankingResult<Option<SavingsGoalModel>> {
+        let row = sqlx::query(&format!("SELECT {SAVINGS_GOAL_COLUMNS} FROM savings_goals WHERE id = $1"))
+            .bind(goal_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find savings goal: {e}")))?;
+
+        row.as_ref().map(SavingsGoalModel::try_from_row).transpose()
+    }
+
+    async fn find_goals_by_account(&self, account_id: Uuid) -> BankingResult<Vec<SavingsGoalModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {SAVINGS_GOAL_COLUMNS} FROM savings_goals WHERE account_id = $1 ORDER BY created_at, id"
+        ))
+        .bind(account_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find savings goals by account: {e}")))?;
+
+        rows.iter().map(SavingsGoalModel::try_from_row).collect()
+    }
+
+    async fn delete_goal(&self, goal_id: Uuid) -> BankingResult<()> {
+        sqlx::query("DELETE FROM savings_goals WHERE id = $1")
+            .bind(goal_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to delete savings goal: {e}")))?;
+        Ok(())
+    }
+}
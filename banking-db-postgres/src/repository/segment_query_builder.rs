@@ -0,0 +1,356 @@
+//! Translates typed segment criteria into a parameterized membership query.
+//!
+//! Every value supplied by a criterion is bound as a parameter; only fixed
+//! SQL fragments chosen by matching on the criterion are spliced into the
+//! query text, so segment definitions cannot inject SQL.
+
+use banking_db::models::{
+    CustomerStatus, CustomerType, DbAccountStatus, DbAccountType, DbSegmentComparison, DbTaggableEntityKind,
+    RiskRating, SegmentCriterionModel,
+};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use sqlx::postgres::{PgArguments, Postgres};
+use sqlx::query::Query;
+use uuid::Uuid;
+
+use crate::repository::tag_filter_sql::has_tag_sql;
+
+/// Accounts of customer `c` that count towards segment aggregates
+const CUSTOMER_ACCOUNTS: &str = "FROM account_ownership o JOIN accounts a ON a.id = o.account_id \
+     WHERE o.customer_id = c.id AND a.account_status <> 'Closed'";
+
+/// A value bound to a segment query placeholder
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum SegmentParam {
+    Decimal(Decimal),
+    Int(i32),
+    Uuid(Uuid),
+    Text(String),
+    TextArray(Vec<String>),
+    Date(NaiveDate),
+}
+
+/// SQL selecting the ids of matching customers (`customer_id` column), with
+/// its parameters in placeholder order.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SegmentQuery {
+    pub sql: String,
+    pub params: Vec<SegmentParam>,
+}
+
+impl SegmentQuery {
+    /// Bind the query parameters in order
+    pub(crate) fn bind_params<'q>(&'q self, mut query: Query<'q, Postgres, PgArguments>) -> Query<'q, Postgres, PgArguments> {
+        for param in &self.params {
+            query = match param {
+                SegmentParam::Decimal(value) => query.bind(*value),
+                SegmentParam::Int(value) => query.bind(*value),
+                SegmentParam::Uuid(value) => query.bind(*value),
+                SegmentParam::Text(value) => query.bind(value.as_str()),
+                SegmentParam::TextArray(values) => query.bind(values.as_slice()),
+                SegmentParam::Date(value) => query.bind(*value),
+            };
+        }
+        query
+    }
+}
+
+pub(crate) struct SegmentQueryBuilder {
+    params: Vec<SegmentParam>,
+    first_placeholder: usize,
+}
+
+impl SegmentQueryBuilder {
+    /// Start numbering placeholders at `first_placeholder`, leaving the lower
+    /// ones for the statement that embeds the membership query.
+    pub(crate) fn new(first_placeholder: usize) -> Self {
+        Self {
+            params: Vec::new(),
+            first_placeholder,
+        }
+    }
+
+    pub(crate) fn build(mut self, criteria: &[SegmentCriterionModel], reference_date: NaiveDate) -> SegmentQuery {
+        let conditions: Vec<String> = criteria
+            .iter()
+            .map(|criterion| self.condition(criterion, reference_date))
+            .collect();
+        let where_clause = if conditions.is_empty() {
+            "FALSE".to_string()
+        } else {
+            conditions.join(" AND ")
+        };
+
+        SegmentQuery {
+            sql: format!("SELECT c.id AS customer_id FROM customers c WHERE {where_clause}"),
+            params: self.params,
+        }
+    }
+
+    fn push(&mut self, param: SegmentParam) -> String {
+        self.params.push(param);
+        format!("${}", self.first_placeholder + self.params.len() - 1)
+    }
+
+    fn condition(&mut self, criterion: &SegmentCriterionModel, reference_date: NaiveDate) -> String {
+        match criterion {
+            SegmentCriterionModel::CustomerTypeIs { customer_type } => {
+                let p = self.push(SegmentParam::Text(customer_type_str(*customer_type).to_string()));
+                format!("c.customer_type::text = {p}")
+            }
+            SegmentCriterionModel::CustomerStatusIn { statuses } => {
+                let values = statuses.iter().map(|s| customer_status_str(*s).to_string()).collect();
+                let p = self.push(SegmentParam::TextArray(values));
+                format!("c.status::text = ANY({p})")
+            }
+            SegmentCriterionModel::RiskRatingIn { ratings } => {
+                let values = ratings.iter().map(|r| risk_rating_str(*r).to_string()).collect();
+                let p = self.push(SegmentParam::TextArray(values));
+                format!("c.risk_rating::text = ANY({p})")
+            }
+            SegmentCriterionModel::TotalBalance { operator, amount } => {
+                let p = self.push(SegmentParam::Decimal(*amount));
+                format!(
+                    "(SELECT COALESCE(SUM(a.current_balance), 0) {CUSTOMER_ACCOUNTS}) {} {p}",
+                    operator_sql(*operator)
+                )
+            }
+            SegmentCriterionModel::HoldsAccountType { account_type, held } => {
+                let p = self.push(SegmentParam::Text(account_type_str(*account_type).to_string()));
+                format!(
+                    "{}EXISTS (SELECT 1 {CUSTOMER_ACCOUNTS} AND a.account_type::text = {p})",
+                    negation(*held)
+                )
+            }
+            SegmentCriterionModel::HoldsProduct { product_id, held } => {
+                let p = self.push(SegmentParam::Uuid(*product_id));
+                format!(
+                    "{}EXISTS (SELECT 1 {CUSTOMER_ACCOUNTS} AND a.product_id = {p})",
+                    negation(*held)
+                )
+            }
+            SegmentCriterionModel::HasAccountInStatus { statuses } => {
+                // Closed accounts are excluded from the account set, so match
+                // the status directly against all owned accounts.
+                let values = statuses.iter().map(|s| account_status_str(*s).to_string()).collect();
+                let p = self.push(SegmentParam::TextArray(values));
+                format!(
+                    "EXISTS (SELECT 1 FROM account_ownership o JOIN accounts a ON a.id = o.account_id \
+                     WHERE o.customer_id = c.id AND a.account_status::text = ANY({p}))"
+                )
+            }
+            SegmentCriterionModel::DaysSinceLastActivity { operator, days } => {
+                let date = self.push(SegmentParam::Date(reference_date));
+                let p = self.push(SegmentParam::Int(*days));
+                format!(
+                    "({date}::date - (SELECT MAX(COALESCE(a.last_activity_date, a.open_date)) {CUSTOMER_ACCOUNTS})) {} {p}",
+                    operator_sql(*operator)
+                )
+            }
+            SegmentCriterionModel::HasTag { tag_code, tagged } => {
+                let p = self.push(SegmentParam::Text(tag_code.to_string()));
+                format!("{}{}", negation(*tagged), has_tag_sql(DbTaggableEntityKind::Customer, "c.id", &p))
+            }
+            SegmentCriterionModel::HoldsTaggedAccount { tag_code, held } => {
+                let p = self.push(SegmentParam::Text(tag_code.to_string()));
+                format!(
+                    "{}EXISTS (SELECT 1 {CUSTOMER_ACCOUNTS} AND {})",
+                    negation(*held),
+                    has_tag_sql(DbTaggableEntityKind::Account, "a.id", &p)
+                )
+            }
+        }
+    }
+}
+
+fn negation(held: bool) -> &'static str {
+    if held { "" } else { "NOT " }
+}
+
+fn operator_sql(operator: DbSegmentComparison) -> &'static str {
+    match operator {
+        DbSegmentComparison::Equal => "=",
+        DbSegmentComparison::NotEqual => "<>",
+        DbSegmentComparison::GreaterThan => ">",
+        DbSegmentComparison::GreaterThanOrEqual => ">=",
+        DbSegmentComparison::LessThan => "<",
+        DbSegmentComparison::LessThanOrEqual => "<=",
+    }
+}
+
+fn customer_type_str(customer_type: CustomerType) -> &'static str {
+    match customer_type {
+        CustomerType::Individual => "Individual",
+        CustomerType::Corporate => "Corporate",
+    }
+}
+
+fn customer_status_str(status: CustomerStatus) -> &'static str {
+    match status {
+        CustomerStatus::Active => "Active",
+        CustomerStatus::PendingVerification => "PendingVerification",
+        CustomerStatus::Deceased => "Deceased",
+        CustomerStatus::Dissolved => "Dissolved",
+        CustomerStatus::Merged => "Merged",
+        CustomerStatus::Blacklisted => "Blacklisted",
+    }
+}
+
+fn risk_rating_str(rating: RiskRating) -> &'static str {
+    match rating {
+        RiskRating::Low => "Low",
+        RiskRating::Medium => "Medium",
+        RiskRating::High => "High",
+        RiskRating::Blacklisted => "Blacklisted",
+    }
+}
+
+fn account_type_str(account_type: DbAccountType) -> &'static str {
+    match account_type {
+        DbAccountType::Savings => "Savings",
+        DbAccountType::Current => "Current",
+        DbAccountType::Loan => "Loan",
+    }
+}
+
+fn account_status_str(status: DbAccountStatus) -> &'static str {
+    match status {
+        DbAccountStatus::PendingApproval => "PendingApproval",
+        DbAccountStatus::Active => "Active",
+        DbAccountStatus::Dormant => "Dormant",
+        DbAccountStatus::Frozen => "Frozen",
+        DbAccountStatus::PendingClosure => "PendingClosure",
+        DbAccountStatus::Closed => "Closed",
+        DbAccountStatus::PendingReactivation => "PendingReactivation",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reference_date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, 30).unwrap()
+    }
+
+    fn build_one(criterion: SegmentCriterionModel) -> SegmentQuery {
+        SegmentQueryBuilder::new(1).build(&[criterion], reference_date())
+    }
+
+    #[test]
+    fn test_customer_type_is() {
+        let query = build_one(SegmentCriterionModel::CustomerTypeIs { customer_type: CustomerType::Corporate });
+        assert!(query.sql.ends_with("WHERE c.customer_type::text = $1"));
+        assert_eq!(query.params, vec![SegmentParam::Text("Corporate".to_string())]);
+    }
+
+    #[test]
+    fn test_customer_status_in() {
+        let query = build_one(SegmentCriterionModel::CustomerStatusIn {
+            statuses: vec![CustomerStatus::Active, CustomerStatus::PendingVerification],
+        });
+        assert!(query.sql.ends_with("WHERE c.status::text = ANY($1)"));
+        assert_eq!(
+            query.params,
+            vec![SegmentParam::TextArray(vec!["Active".to_string(), "PendingVerification".to_string()])]
+        );
+    }
+
+    #[test]
+    fn test_risk_rating_in() {
+        let query = build_one(SegmentCriterionModel::RiskRatingIn { ratings: vec![RiskRating::High] });
+        assert!(query.sql.ends_with("WHERE c.risk_rating::text = ANY($1)"));
+        assert_eq!(query.params, vec![SegmentParam::TextArray(vec!["High".to_string()])]);
+    }
+
+    #[test]
+    fn test_total_balance() {
+        let query = build_one(SegmentCriterionModel::TotalBalance {
+            operator: DbSegmentComparison::GreaterThanOrEqual,
+            amount: Decimal::new(500000, 2),
+        });
+        assert!(query.sql.contains("SELECT COALESCE(SUM(a.current_balance), 0) FROM account_ownership o"));
+        assert!(query.sql.ends_with(") >= $1"));
+        assert_eq!(query.params, vec![SegmentParam::Decimal(Decimal::new(500000, 2))]);
+    }
+
+    #[test]
+    fn test_holds_account_type() {
+        let held = build_one(SegmentCriterionModel::HoldsAccountType { account_type: DbAccountType::Savings, held: true });
+        assert!(held.sql.contains("WHERE EXISTS (SELECT 1 FROM account_ownership o"));
+        assert!(held.sql.ends_with("AND a.account_type::text = $1)"));
+        assert_eq!(held.params, vec![SegmentParam::Text("Savings".to_string())]);
+
+        let not_held = build_one(SegmentCriterionModel::HoldsAccountType { account_type: DbAccountType::Loan, held: false });
+        assert!(not_held.sql.contains("WHERE NOT EXISTS (SELECT 1 FROM account_ownership o"));
+        assert_eq!(not_held.params, vec![SegmentParam::Text("Loan".to_string())]);
+    }
+
+    #[test]
+    fn test_holds_product() {
+        let product_id = Uuid::new_v4();
+        let query = build_one(SegmentCriterionModel::HoldsProduct { product_id, held: false });
+        assert!(query.sql.contains("WHERE NOT EXISTS (SELECT 1 FROM account_ownership o"));
+        assert!(query.sql.ends_with("AND a.product_id = $1)"));
+        assert_eq!(query.params, vec![SegmentParam::Uuid(product_id)]);
+    }
+
+    #[test]
+    fn test_has_account_in_status() {
+        let query = build_one(SegmentCriterionModel::HasAccountInStatus { statuses: vec![DbAccountStatus::Dormant] });
+        assert!(query.sql.ends_with("AND a.account_status::text = ANY($1))"));
+        assert!(!query.sql.contains("<> 'Closed'"));
+        assert_eq!(query.params, vec![SegmentParam::TextArray(vec!["Dormant".to_string()])]);
+    }
+
+    #[test]
+    fn test_days_since_last_activity() {
+        let query = build_one(SegmentCriterionModel::DaysSinceLastActivity {
+            operator: DbSegmentComparison::GreaterThan,
+            days: 270,
+        });
+        assert!(query.sql.contains("($1::date - (SELECT MAX(COALESCE(a.last_activity_date, a.open_date))"));
+        assert!(query.sql.ends_with(") > $2"));
+        assert_eq!(query.params, vec![SegmentParam::Date(reference_date()), SegmentParam::Int(270)]);
+    }
+
+    #[test]
+    fn test_tag_criteria() {
+        let query = build_one(SegmentCriterionModel::HasTag {
+            tag_code: heapless::String::try_from("VIP").unwrap(),
+            tagged: false,
+        });
+        assert!(query.sql.contains("WHERE NOT EXISTS (SELECT 1 FROM entity_tags et"));
+        assert!(query.sql.ends_with("et.entity_kind = 'Customer' AND et.entity_id = c.id AND t.code = $1)"));
+        assert_eq!(query.params, vec![SegmentParam::Text("VIP".to_string())]);
+
+        let query = build_one(SegmentCriterionModel::HoldsTaggedAccount {
+            tag_code: heapless::String::try_from("LegacyMigrationBatch7").unwrap(),
+            held: true,
+        });
+        assert!(query.sql.contains("a.account_status <> 'Closed' AND EXISTS (SELECT 1 FROM entity_tags et"));
+        assert!(query.sql.ends_with("et.entity_kind = 'Account' AND et.entity_id = a.id AND t.code = $1))"));
+    }
+
+    #[test]
+    fn test_criteria_are_conjoined_and_numbered_from_offset() {
+        let query = SegmentQueryBuilder::new(3).build(
+            &[
+                SegmentCriterionModel::TotalBalance { operator: DbSegmentComparison::GreaterThan, amount: Decimal::from(1000) },
+                SegmentCriterionModel::HoldsAccountType { account_type: DbAccountType::Loan, held: false },
+            ],
+            reference_date(),
+        );
+        assert!(query.sql.contains(") > $3 AND NOT EXISTS"));
+        assert!(query.sql.ends_with("a.account_type::text = $4)"));
+        assert_eq!(query.params.len(), 2);
+    }
+
+    #[test]
+    fn test_empty_criteria_match_nobody() {
+        let query = SegmentQueryBuilder::new(1).build(&[], reference_date());
+        assert!(query.sql.ends_with("WHERE FALSE"));
+        assert!(query.params.is_empty());
+    }
+}
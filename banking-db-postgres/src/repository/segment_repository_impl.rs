@@ -0,0 +1,382 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{
+    SegmentCriterionModel, SegmentMembershipDeltaModel, SegmentMembershipModel, SegmentModel,
+};
+use banking_db::repository::SegmentRepository;
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+use crate::repository::segment_query_builder::SegmentQueryBuilder;
+
+/// PostgreSQL implementation of SegmentRepository
+pub struct SegmentRepositoryImpl {
+    pool: PgPool,
+}
+
+impl SegmentRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+impl TryFromRow<PgRow> for SegmentModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        let criteria: Vec<SegmentCriterionModel> = serde_json::from_value(row.get("criteria"))
+            .map_err(|e| BankingError::Internal(format!("Failed to parse segment criteria: {e}")))?;
+
+        Ok(SegmentModel {
+            id: row.get("id"),
+            code: HeaplessString::try_from(row.get::<String, _>("code").as_str())
+                .map_err(|_| BankingError::ValidationError {
+                    field: "code".to_string(),
+                    message: "Segment code too long".to_string(),
+                })?,
+            name: HeaplessString::try_from(row.get::<String, _>("name").as_str())
+                .map_err(|_| BankingError::ValidationError {
+                    field: "name".to_string(),
+                    message: "Segment name too long".to_string(),
+                })?,
+            description: row.get::<Option<String>, _>("description")
+                .map(|s| HeaplessString::try_from(s.as_str()))
+                .transpose()
+                .map_err(|_| BankingError::ValidationError {
+                    field: "description".to_string(),
+                    message: "Segment description too long".to_string(),
+                })?,
+            criteria,
+            is_active: row.get("is_active"),
+            created_at: row.get("created_at"),
+            last_updated_at: row.get("last_updated_at"),
+            updated_by_person_id: row.get("updated_by_person_id"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for SegmentMembershipModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(SegmentMembershipModel {
+            id: row.get("id"),
+            segment_id: row.get("segment_id"),
+            customer_id: row.get("customer_id"),
+            entered_at: row.get("entered_at"),
+            exited_at: row.get("exited_at"),
+        })
+    }
+}
+
+fn criteria_to_json(criteria: &[SegmentCriterionModel]) -> BankingResult<serde_json::Value> {
+    serde_json::to_value(criteria)
+        .map_err(|e| BankingError::Internal(format!("Failed to serialize segment criteria: {e}")))
+}
+
+#[async_trait]
+impl SegmentRepository for SegmentRepositoryImpl {
+    async fn create_segment(&self, segment: SegmentModel) -> BankingResult<SegmentModel> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO segments (
+                id, code, name, description, criteria, is_active,
+                created_at, last_updated_at, updated_by_person_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING id, code, name, description, criteria, is_active,
+                      created_at, last_updated_at, updated_by_person_id
+            "#,
+        )
+        .bind(segment.id)
+        .bind(segment.code.as_str())
+        .bind(segment.name.as_str())
+        .bind(segment.description.as_ref().map(|s| s.as_str()))
+        .bind(criteria_to_json(&segment.criteria)?)
+        .bind(segment.is_active)
+        .bind(segment.created_at)
+        .bind(segment.last_updated_at)
+        .bind(segment.updated_by_person_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create segment: {e}")))?;
+
+        SegmentModel::try_from_row(&result)
+    }
+
+    async fn update_segment(&self, segment: SegmentModel) -> BankingResult<SegmentModel> {
+        let result = sqlx::query(
+            r#"
+            UPDATE segments
+            SET code = $2, name = $3, description = $4, criteria = $5, is_active = $6,
+                last_updated_at = $7, updated_by_person_id = $8
+            WHERE id = $1
+            RETURNING id, code, name, description, criteria, is_active,
+                      created_at, last_updated_at, updated_by_person_id
+            "#,
+        )
+        .bind(segment.id)
+        .bind(segment.code.as_str())
+        .bind(segment.name.as_str())
+        .bind(segment.description.as_ref().map(|s| s.as_str()))
+        .bind(criteria_to_json(&segment.criteria)?)
+        .bind(segment.is_active)
+        .bind(segment.last_updated_at)
+        .bind(segment.updated_by_person_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update segment: {e}")))?;
+
+        SegmentModel::try_from_row(&result)
+    }
+
+    async fn find_segment_by_id(&self, segment_id: Uuid) -> BankingResult<Option<SegmentModel>> {
+        let result = sqlx::query(
+            r#"
+            SELECT id, code, name, description, criteria, is_active,
+                   created_at, last_updated_at, updated_by_person_id
+            FROM segments
+            WHERE id = $1
+            "#,
+        )
+        .bind(segment_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find segment: {e}")))?;
+
+        match result {
+            Some(row) => Ok(Some(SegmentModel::try_from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_segment_by_code(&self, code: &str) -> BankingResult<Option<SegmentModel>> {
+        let result = sqlx::query(
+            r#"
+            SELECT id, code, name, description, criteria, is_active,
+                   created_at, last_updated_at, updated_by_person_id
+            FROM segments
+            WHERE code = $1
+            "#,
+        )
+        .bind(code)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find segment by code: {e}")))?;
+
+        match result {
+            Some(row) => Ok(Some(SegmentModel::try_from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_active_segments(&self) -> BankingResult<Vec<SegmentModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, code, name, description, criteria, is_active,
+                   created_at, last_updated_at, updated_by_person_id
+            FROM segments
+            WHERE is_active = TRUE
+            ORDER BY code
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find active segments: {e}")))?;
+
+        let mut segments = Vec::new();
+        for row in rows {
+            segments.push(SegmentModel::try_from_row(&row)?);
+        }
+        Ok(segments)
+    }
+
+    async fn deactivate_segment(&self, segment_id: Uuid, updated_by_person_id: Uuid) -> BankingResult<()> {
+        sqlx::query(
+            "UPDATE segments SET is_active = FALSE, last_updated_at = NOW(), updated_by_person_id = $2 WHERE id = $1",
+        )
+        .bind(segment_id)
+        .bind(updated_by_person_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to deactivate segment: {e}")))?;
+
+        Ok(())
+    }
+
+    async fn delete_segment(&self, segment_id: Uuid) -> BankingResult<()> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM segment_memberships WHERE segment_id = $1")
+            .bind(segment_id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("DELETE FROM segments WHERE id = $1")
+            .bind(segment_id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn refresh_membership(
+        &self,
+        segment: &SegmentModel,
+        reference_date: NaiveDate,
+        evaluated_at: DateTime<Utc>,
+    ) -> BankingResult<SegmentMembershipDeltaModel> {
+        // $1 = segment id, $2 = evaluation timestamp, criteria from $3
+        let members = SegmentQueryBuilder::new(3).build(&segment.criteria, reference_date);
+
+        let exit_sql = format!(
+            r#"
+            UPDATE segment_memberships
+            SET exited_at = $2
+            WHERE segment_id = $1 AND exited_at IS NULL
+              AND customer_id NOT IN ({})
+            RETURNING customer_id
+            "#,
+            members.sql
+        );
+        let enter_sql = format!(
+            r#"
+            INSERT INTO segment_memberships (id, segment_id, customer_id, entered_at)
+            SELECT gen_random_uuid(), $1, m.customer_id, $2
+            FROM ({}) m
+            WHERE NOT EXISTS (
+                SELECT 1 FROM segment_memberships s
+                WHERE s.segment_id = $1 AND s.customer_id = m.customer_id AND s.exited_at IS NULL
+            )
+            RETURNING customer_id
+            "#,
+            members.sql
+        );
+
+        let mut tx = self.pool.begin().await?;
+
+        let exited_rows = members
+            .bind_params(sqlx::query(&exit_sql).bind(segment.id).bind(evaluated_at))
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to close segment memberships: {e}")))?;
+        let entered_rows = members
+            .bind_params(sqlx::query(&enter_sql).bind(segment.id).bind(evaluated_at))
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to open segment memberships: {e}")))?;
+
+        tx.commit().await?;
+
+        Ok(SegmentMembershipDeltaModel {
+            segment_id: segment.id,
+            since: evaluated_at,
+            entered_customer_ids: entered_rows.iter().map(|row| row.get("customer_id")).collect(),
+            exited_customer_ids: exited_rows.iter().map(|row| row.get("customer_id")).collect(),
+        })
+    }
+
+    async fn find_current_members(&self, segment_id: Uuid) -> BankingResult<Vec<SegmentMembershipModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, segment_id, customer_id, entered_at, exited_at
+            FROM segment_memberships
+            WHERE segment_id = $1 AND exited_at IS NULL
+            ORDER BY entered_at, customer_id
+            "#,
+        )
+        .bind(segment_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find segment members: {e}")))?;
+
+        let mut memberships = Vec::new();
+        for row in rows {
+            memberships.push(SegmentMembershipModel::try_from_row(&row)?);
+        }
+        Ok(memberships)
+    }
+
+    async fn find_membership_history(&self, segment_id: Uuid, customer_id: Uuid) -> BankingResult<Vec<SegmentMembershipModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, segment_id, customer_id, entered_at, exited_at
+            FROM segment_memberships
+            WHERE segment_id = $1 AND customer_id = $2
+            ORDER BY entered_at
+            "#,
+        )
+        .bind(segment_id)
+        .bind(customer_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find membership history: {e}")))?;
+
+        let mut memberships = Vec::new();
+        for row in rows {
+            memberships.push(SegmentMembershipModel::try_from_row(&row)?);
+        }
+        Ok(memberships)
+    }
+
+    async fn find_membership_changes_since(&self, segment_id: Uuid, since: DateTime<Utc>) -> BankingResult<SegmentMembershipDeltaModel> {
+        let entered_customer_ids: Vec<Uuid> = sqlx::query_scalar(
+            r#"
+            SELECT customer_id FROM segment_memberships
+            WHERE segment_id = $1 AND entered_at > $2
+            ORDER BY customer_id
+            "#,
+        )
+        .bind(segment_id)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find segment entrants: {e}")))?;
+
+        let exited_customer_ids: Vec<Uuid> = sqlx::query_scalar(
+            r#"
+            SELECT customer_id FROM segment_memberships
+            WHERE segment_id = $1 AND exited_at > $2
+            ORDER BY customer_id
+            "#,
+        )
+        .bind(segment_id)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find segment leavers: {e}")))?;
+
+        Ok(SegmentMembershipDeltaModel {
+            segment_id,
+            since,
+            entered_customer_ids,
+            exited_customer_ids,
+        })
+    }
+
+    async fn find_segments_for_customer(&self, customer_id: Uuid) -> BankingResult<Vec<SegmentModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT s.id, s.code, s.name, s.description, s.criteria, s.is_active,
+                   s.created_at, s.last_updated_at, s.updated_by_person_id
+            FROM segments s
+            JOIN segment_memberships m ON m.segment_id = s.id
+            WHERE m.customer_id = $1 AND m.exited_at IS NULL AND s.is_active = TRUE
+            ORDER BY s.code
+            "#,
+        )
+        .bind(customer_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find segments for customer: {e}")))?;
+
+        let mut segments = Vec::new();
+        for row in rows {
+            segments.push(SegmentModel::try_from_row(&row)?);
+        }
+        Ok(segments)
+    }
+}
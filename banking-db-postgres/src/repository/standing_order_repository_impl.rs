@@ -0,0 +1,206 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{
+    DbStandingOrderAmountType, DbStandingOrderFailurePolicy, DbStandingOrderFrequency, DbStandingOrderStatus,
+    StandingOrderModel,
+};
+use banking_db::repository::StandingOrderRepository;
+use chrono::NaiveDate;
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of StandingOrderRepository
+pub struct StandingOrderRepositoryImpl {
+    pool: PgPool,
+}
+
+impl StandingOrderRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+impl TryFromRow<PgRow> for StandingOrderModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(StandingOrderModel {
+            id: row.get("id"),
+            source_account_id: row.get("source_account_id"),
+            beneficiary_account_id: row.get("beneficiary_account_id"),
+            external_reference: row
+                .get::<Option<String>, _>("external_reference")
+                .map(|reference| HeaplessString::try_from(reference.as_str()))
+                .transpose()
+                .map_err(|_| BankingError::ValidationError {
+                    field: "external_reference".to_string(),
+                    message: "External reference too long".to_string(),
+                })?,
+            amount_type: row.get::<String, _>("amount_type")
+                .parse::<DbStandingOrderAmountType>()
+                .map_err(|_| BankingError::Internal("Invalid standing order amount type".to_string()))?,
+            amount: row.get("amount"),
+            frequency: row.get::<String, _>("frequency")
+                .parse::<DbStandingOrderFrequency>()
+                .map_err(|_| BankingError::Internal("Invalid standing order frequency".to_string()))?,
+            start_date: row.get("start_date"),
+            end_date: row.get("end_date"),
+            next_due_date: row.get("next_due_date"),
+            next_run_date: row.get("next_run_date"),
+            status: row.get::<String, _>("status")
+                .parse::<DbStandingOrderStatus>()
+                .map_err(|_| BankingError::Internal("Invalid standing order status".to_string()))?,
+            failure_policy: row.get::<String, _>("failure_policy")
+                .parse::<DbStandingOrderFailurePolicy>()
+                .map_err(|_| BankingError::Internal("Invalid standing order failure policy".to_string()))?,
+            max_consecutive_failures: row.get("max_consecutive_failures"),
+            consecutive_failures: row.get("consecutive_failures"),
+            last_executed_at: row.get("last_executed_at"),
+            created_at: row.get("created_at"),
+            last_updated_at: row.get("last_updated_at"),
+            updated_by_person_id: row.get("updated_by_person_id"),
+        })
+    }
+}
+
+const STANDING_ORDER_COLUMNS: &str = r#"
+    id, source_account_id, beneficiary_account_id, external_reference, amount_type::text as amount_type, amount,
+    frequency::text as frequency, start_date, end_date, next_due_date, next_run_date, status::text as status,
+    failure_policy::text as failure_policy, max_consecutive_failures, consecutive_failures, last_executed_at,
+    created_at, last_updated_at, updated_by_person_id
+"#;
+
+#[async_trait]
+impl StandingOrderRepository for StandingOrderRepositoryImpl {
+    async fn create_standing_order(&self, order: StandingOrderModel) -> BankingResult<StandingOrderModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO standing_orders (
+                id, source_account_id, beneficiary_account_id, external_reference, amount_type, amount,
+                frequency, start_date, end_date, next_due_date, next_run_date, status,
+                failure_policy, max_consecutive_failures, consecutive_failures, last_executed_at,
+                created_at, last_updated_at, updated_by_person_id
+            )
+            VALUES (
+                $1, $2, $3, $4, $5::standing_order_amount_type, $6,
+                $7::standing_order_frequency, $8, $9, $10, $11, $12::standing_order_status,
+                $13::standing_order_failure_policy, $14, $15, $16,
+                $17, $18, $19
+            )
+            RETURNING {STANDING_ORDER_COLUMNS}
+            "#
+        ))
+        .bind(order.id)
+        .bind(order.source_account_id)
+        .bind(order.beneficiary_account_id)
+        .bind(order.external_reference.as_ref().map(|r| r.as_str()))
+        .bind(order.amount_type)
+        .bind(order.amount)
+        .bind(order.frequency)
+        .bind(order.start_date)
+        .bind(order.end_date)
+        .bind(order.next_due_date)
+        .bind(order.next_run_date)
+        .bind(order.status)
+        .bind(order.failure_policy)
+        .bind(order.max_consecutive_failures)
+        .bind(order.consecutive_failures)
+        .bind(order.last_executed_at)
+        .bind(order.created_at)
+        .bind(order.last_updated_at)
+        .bind(order.updated_by_person_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create standing order: {e}")))?;
+
+        StandingOrderModel::try_from_row(&row)
+    }
+
+    async fn update_standing_order(&self, order: StandingOrderModel) -> BankingResult<StandingOrderModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE standing_orders
+            SET beneficiary_account_id = $2, external_reference = $3, amount_type = $4::standing_order_amount_type,
+                amount = $5, frequency = $6::standing_order_frequency, end_date = $7, next_due_date = $8,
+                next_run_date = $9, status = $10::standing_order_status,
+                failure_policy = $11::standing_order_failure_policy, max_consecutive_failures = $12,
+                consecutive_failures = $13, last_executed_at = $14, last_updated_at = $15, updated_by_person_id = $16
+            WHERE id = $1
+            RETURNING {STANDING_ORDER_COLUMNS}
+            "#
+        ))
+        .bind(order.id)
+        .bind(order.beneficiary_account_id)
+        .bind(order.external_reference.as_ref().map(|r| r.as_str()))
+        .bind(order.amount_type)
+        .bind(order.amount)
+        .bind(order.frequency)
+        .bind(order.end_date)
+        .bind(order.next_due_date)
+        .bind(order.next_run_date)
+        .bind(order.status)
+        .bind(order.failure_policy)
+        .bind(order.max_consecutive_failures)
+        .bind(order.consecutive_failures)
+        .bind(order.last_executed_at)
+        .bind(order.last_updated_at)
+        .bind(order.updated_by_person_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update standing order: {e}")))?;
+
+        StandingOrderModel::try_from_row(&row)
+    }
+
+    async fn find_standing_order_by_id(&self, order_id: Uuid) -> BankingResult<Option<StandingOrderModel>> {
+        let row = sqlx::query(&format!("SELECT {STANDING_ORDER_COLUMNS} FROM standing_orders WHERE id = $1"))
+            .bind(order_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find standing order: {e}")))?;
+
+        row.as_ref().map(StandingOrderModel::try_from_row).transpose()
+    }
+
+    async fn find_standing_orders_by_account(&self, source_account_id: Uuid) -> BankingResult<Vec<StandingOrderModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {STANDING_ORDER_COLUMNS} FROM standing_orders WHERE source_account_id = $1 ORDER BY created_at, id"
+        ))
+        .bind(source_account_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find standing orders by account: {e}")))?;
+
+        rows.iter().map(StandingOrderModel::try_from_row).collect()
+    }
+
+    async fn delete_standing_order(&self, order_id: Uuid) -> BankingResult<()> {
+        sqlx::query("DELETE FROM standing_orders WHERE id = $1")
+            .bind(order_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to delete standing order: {e}")))?;
+        Ok(())
+    }
+
+    async fn find_due(&self, run_date: NaiveDate) -> BankingResult<Vec<StandingOrderModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {STANDING_ORDER_COLUMNS} FROM standing_orders
+            WHERE status = 'Active'::standing_order_status AND next_run_date <= $1
+            ORDER BY next_run_date, id
+            "#
+        ))
+        .bind(run_date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find due standing orders: {e}")))?;
+
+        rows.iter().map(StandingOrderModel::try_from_row).collect()
+    }
+}
@@ -0,0 +1,811 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult, QueryClass};
+use banking_db::models::{
+    AccountStatementModel, CustomerContactModel, DbDocumentNotificationStatus, DbStatementDeliveryMethod,
+    DbStatementNotificationType, PaperStatementAccountModel, StatementChargeSummaryModel, StatementConsentRecordModel,
+    StatementDeliveryPreferenceModel, StatementEntryModel, StatementIntegrityAlertModel, StatementLineModel,
+    StatementNotificationModel, StatementPrintBatchModel, StatementPrintEntryModel, StatementRecipientModel,
+};
+use banking_db::repository::StatementRepository;
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use sqlx::{PgPool, Postgres, Row, Transaction, postgres::PgRow};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::repository::executor::Executor;
+
+/// PostgreSQL implementation of StatementRepository
+pub struct StatementRepositoryImpl {
+    pool: PgPool,
+    /// Runs the long scans under the `Analytical` statement timeout
+    executor: Executor,
+}
+
+impl StatementRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        let executor = Executor::Pool(Arc::new(pool.clone()));
+        Self { pool, executor }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+fn delivery_method(row: &PgRow, column: &str) -> BankingResult<DbStatementDeliveryMethod> {
+    row.get::<String, _>(column)
+        .parse::<DbStatementDeliveryMethod>()
+        .map_err(|_| BankingError::Internal("Invalid statement delivery method".to_string()))
+}
+
+fn heapless<const N: usize>(value: String, field: &str) -> BankingResult<HeaplessString<N>> {
+    HeaplessString::try_from(value.as_str()).map_err(|_| BankingError::ValidationError {
+        field: field.to_string(),
+        message: format!("{field} too long"),
+    })
+}
+
+impl TryFromRow<PgRow> for StatementDeliveryPreferenceModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(StatementDeliveryPreferenceModel {
+            account_id: row.get("account_id"),
+            delivery_method: delivery_method(row, "delivery_method")?,
+            contact_messaging_id: row.get("contact_messaging_id"),
+            consent_given_at: row.get("consent_given_at"),
+            consent_channel: heapless(row.get("consent_channel"), "consent_channel")?,
+            last_updated_at: row.get("last_updated_at"),
+            updated_by_person_id: row.get("updated_by_person_id"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for StatementConsentRecordModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(StatementConsentRecordModel {
+            id: row.get("id"),
+            account_id: row.get("account_id"),
+            customer_id: row.get("customer_id"),
+            previous_method: delivery_method(row, "previous_method")?,
+            new_method: delivery_method(row, "new_method")?,
+            contact_messaging_id: row.get("contact_messaging_id"),
+            contact_verified_at: row.get("contact_verified_at"),
+            consent_channel: heapless(row.get("consent_channel"), "consent_channel")?,
+            consent_reference: heapless(row.get("consent_reference"), "consent_reference")?,
+            consent_given_at: row.get("consent_given_at"),
+            recorded_by_person_id: row.get("recorded_by_person_id"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for StatementPrintEntryModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(StatementPrintEntryModel {
+            id: row.get("id"),
+            batch_id: row.get("batch_id"),
+            account_id: row.get("account_id"),
+            customer_id: row.get("customer_id"),
+            statement_reference: heapless(row.get("statement_reference"), "statement_reference")?,
+            delivery_method: delivery_method(row, "delivery_method")?,
+        })
+    }
+}
+
+fn hash_bytes(field: &str, bytes: Vec<u8>) -> BankingResult<[u8; 32]> {
+    bytes.try_into().map_err(|_| BankingError::Internal(format!("Invalid {field} length")))
+}
+
+impl TryFromRow<PgRow> for AccountStatementModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(AccountStatementModel {
+            id: row.get("id"),
+            account_id: row.get("account_id"),
+            statement_number: row.get("statement_number"),
+            statement_reference: heapless(row.get("statement_reference"), "statement_reference")?,
+            period_start: row.get("period_start"),
+            period_end: row.get("period_end"),
+            opening_balance: row.get("opening_balance"),
+            total_credits: row.get("total_credits"),
+            total_debits: row.get("total_debits"),
+            closing_balance: row.get("closing_balance"),
+            interest_accrued: row.get("interest_accrued"),
+            fees_charged: row.get("fees_charged"),
+            fee_count: row.get("fee_count"),
+            content_hash: hash_bytes("content_hash", row.get("content_hash"))?.into(),
+            supersedes_statement_id: row.get("supersedes_statement_id"),
+            generated_at: row.get("generated_at"),
+            generated_by_person_id: row.get("generated_by_person_id"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for StatementLineModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(StatementLineModel {
+            statement_id: row.get("statement_id"),
+            line_number: row.get("line_number"),
+            transaction_id: row.get("transaction_id"),
+            value_date: row.get("value_date"),
+            description: heapless(row.get("description"), "description")?,
+            amount: row.get("amount"),
+            running_balance: row.get("running_balance"),
+        })
+    }
+}
+
+const STATEMENT_COLUMNS: &str = r#"
+    id, account_id, statement_number, statement_reference, period_start, period_end, opening_balance, total_credits,
+    total_debits, closing_balance, interest_accrued, fees_charged, fee_count, content_hash, supersedes_statement_id,
+    generated_at, generated_by_person_id
+"#;
+
+const PREFERENCE_COLUMNS: &str = r#"
+    account_id, delivery_method::text as delivery_method, contact_messaging_id, consent_given_at,
+    consent_channel, last_updated_at, updated_by_person_id
+"#;
+
+/// Primary holder of each account: its earliest registered owner
+const PRIMARY_OWNER_JOIN: &str = r#"
+    JOIN LATERAL (
+        SELECT o.customer_id FROM account_ownership o
+        WHERE o.account_id = a.id
+        ORDER BY o.created_at
+        LIMIT 1
+    ) owner ON TRUE
+"#;
+
+async fn insert_notifications(
+    tx: &mut Transaction<'_, Postgres>,
+    notifications: &[StatementNotificationModel],
+) -> BankingResult<()> {
+    let ids: Vec<Uuid> = notifications.iter().map(|n| n.id).collect();
+    let types: Vec<String> = notifications.iter().map(|n| notification_type_name(n.notification_type).to_string()).collect();
+    let account_ids: Vec<Uuid> = notifications.iter().map(|n| n.account_id).collect();
+    let customer_ids: Vec<Uuid> = notifications.iter().map(|n| n.customer_id).collect();
+    let contact_ids: Vec<Option<Uuid>> = notifications.iter().map(|n| n.contact_messaging_id).collect();
+    let references: Vec<Option<String>> = notifications
+        .iter()
+        .map(|n| n.statement_reference.as_ref().map(|r| r.to_string()))
+        .collect();
+    let statuses: Vec<String> = notifications.iter().map(|n| notification_status_name(n.status).to_string()).collect();
+    let queued_at: Vec<DateTime<Utc>> = notifications.iter().map(|n| n.queued_at).collect();
+
+    sqlx::query(
+        r#"
+        INSERT INTO statement_notifications (
+            id, notification_type, account_id, customer_id, contact_messaging_id,
+            statement_reference, status, queued_at
+        )
+        SELECT n.id, n.notification_type::statement_notification_type, n.account_id, n.customer_id,
+               n.contact_messaging_id, n.statement_reference, n.status::document_notification_status, n.queued_at
+        FROM UNNEST($1::uuid[], $2::text[], $3::uuid[], $4::uuid[], $5::uuid[], $6::text[], $7::text[], $8::timestamptz[])
+            AS n(id, notification_type, account_id, customer_id, contact_messaging_id, statement_reference, status, queued_at)
+        "#,
+    )
+    .bind(&ids)
+    .bind(&types)
+    .bind(&account_ids)
+    .bind(&customer_ids)
+    .bind(&contact_ids)
+    .bind(&references)
+    .bind(&statuses)
+    .bind(&queued_at)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| BankingError::Internal(format!("Failed to queue statement notifications: {e}")))?;
+
+    Ok(())
+}
+
+fn notification_type_name(notification_type: DbStatementNotificationType) -> &'static str {
+    match notification_type {
+        DbStatementNotificationType::PreferenceChanged => "PreferenceChanged",
+        DbStatementNotificationType::StatementReady => "StatementReady",
+    }
+}
+
+fn notification_status_name(status: DbDocumentNotificationStatus) -> &'static str {
+    match status {
+        DbDocumentNotificationStatus::Queued => "Queued",
+        DbDocumentNotificationStatus::Sent => "Sent",
+        DbDocumentNotificationStatus::Failed => "Failed",
+    }
+}
+
+fn delivery_method_name(method: DbStatementDeliveryMethod) -> &'static str {
+    match method {
+        DbStatementDeliveryMethod::Paper => "Paper",
+        DbStatementDeliveryMethod::Electronic => "Electronic",
+        DbStatementDeliveryMethod::Both => "Both",
+    }
+}
+
+#[async_trait]
+impl StatementRepository for StatementRepositoryImpl {
+    async fn find_preference(&self, account_id: Uuid) -> BankingResult<Option<StatementDeliveryPreferenceModel>> {
+        let result = sqlx::query(&format!(
+            "SELECT {PREFERENCE_COLUMNS} FROM statement_delivery_preferences WHERE account_id = $1"
+        ))
+        .bind(account_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find statement preference: {e}")))?;
+
+        match result {
+            Some(row) => Ok(Some(StatementDeliveryPreferenceModel::try_from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn save_preference_change(
+        &self,
+        preference: StatementDeliveryPreferenceModel,
+        consent: StatementConsentRecordModel,
+        notification: StatementNotificationModel,
+    ) -> BankingResult<StatementDeliveryPreferenceModel> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| BankingError::Internal(format!("Failed to begin transaction: {e}")))?;
+
+        let result = sqlx::query(&format!(
+            r#"
+            INSERT INTO statement_delivery_preferences (
+                account_id, delivery_method, contact_messaging_id, consent_given_at,
+                consent_channel, last_updated_at, updated_by_person_id
+            )
+            VALUES ($1, $2::statement_delivery_method, $3, $4, $5, $6, $7)
+            ON CONFLICT (account_id) DO UPDATE SET
+                delivery_method = EXCLUDED.delivery_method,
+                contact_messaging_id = EXCLUDED.contact_messaging_id,
+                consent_given_at = EXCLUDED.consent_given_at,
+                consent_channel = EXCLUDED.consent_channel,
+                last_updated_at = EXCLUDED.last_updated_at,
+                updated_by_person_id = EXCLUDED.updated_by_person_id
+            RETURNING {PREFERENCE_COLUMNS}
+            "#
+        ))
+        .bind(preference.account_id)
+        .bind(preference.delivery_method)
+        .bind(preference.contact_messaging_id)
+        .bind(preference.consent_given_at)
+        .bind(preference.consent_channel.as_str())
+        .bind(preference.last_updated_at)
+        .bind(preference.updated_by_person_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to save statement preference: {e}")))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO statement_consent_records (
+                id, account_id, customer_id, previous_method, new_method, contact_messaging_id,
+                contact_verified_at, consent_channel, consent_reference, consent_given_at, recorded_by_person_id
+            )
+            VALUES ($1, $2, $3, $4::statement_delivery_method, $5::statement_delivery_method, $6, $7, $8, $9, $10, $11)
+            "#,
+        )
+        .bind(consent.id)
+        .bind(consent.account_id)
+        .bind(consent.customer_id)
+        .bind(consent.previous_method)
+        .bind(consent.new_method)
+        .bind(consent.contact_messaging_id)
+        .bind(consent.contact_verified_at)
+        .bind(consent.consent_channel.as_str())
+        .bind(consent.consent_reference.as_str())
+        .bind(consent.consent_given_at)
+        .bind(consent.recorded_by_person_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to record statement consent: {e}")))?;
+
+        insert_notifications(&mut tx, &[notification]).await?;
+
+        tx.commit().await
+            .map_err(|e| BankingError::Internal(format!("Failed to commit statement preference: {e}")))?;
+
+        StatementDeliveryPreferenceModel::try_from_row(&result)
+    }
+
+    async fn find_consent_records(&self, account_id: Uuid) -> BankingResult<Vec<StatementConsentRecordModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, account_id, customer_id, previous_method::text as previous_method,
+                   new_method::text as new_method, contact_messaging_id, contact_verified_at,
+                   consent_channel, consent_reference, consent_given_at, recorded_by_person_id
+            FROM statement_consent_records
+            WHERE account_id = $1
+            ORDER BY consent_given_at
+            "#,
+        )
+        .bind(account_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find statement consent records: {e}")))?;
+
+        let mut records = Vec::new();
+        for row in rows {
+            records.push(StatementConsentRecordModel::try_from_row(&row)?);
+        }
+        Ok(records)
+    }
+
+    async fn find_customer_contact(&self, customer_id: Uuid, messaging_id: Uuid) -> BankingResult<Option<CustomerContactModel>> {
+        let result = sqlx::query(
+            r#"
+            SELECT id, customer_id, messaging_id, verified_at, verified_by_person_id
+            FROM customer_contacts
+            WHERE customer_id = $1 AND messaging_id = $2
+            "#,
+        )
+        .bind(customer_id)
+        .bind(messaging_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find customer contact: {e}")))?;
+
+        Ok(result.map(|row| CustomerContactModel {
+            id: row.get("id"),
+            customer_id: row.get("customer_id"),
+            messaging_id: row.get("messaging_id"),
+            verified_at: row.get("verified_at"),
+            verified_by_person_id: row.get("verified_by_person_id"),
+        }))
+    }
+
+    async fn find_statement_recipients(&self, cycle_date: NaiveDate) -> BankingResult<Vec<StatementRecipientModel>> {
+        let rows = self
+            .executor
+            .run(QueryClass::Analytical, move |conn| {
+                Box::pin(async move {
+                    sqlx::query(&format!(
+                        r#"
+                        SELECT a.id as account_id, owner.customer_id,
+                               COALESCE(p.delivery_method, 'Paper'::statement_delivery_method)::text as delivery_method,
+                               p.contact_messaging_id
+                        FROM accounts a
+                        {PRIMARY_OWNER_JOIN}
+                        LEFT JOIN statement_delivery_preferences p ON p.account_id = a.id
+                        WHERE a.open_date <= $1
+                          AND a.account_status <> 'Closed'::account_status
+                        ORDER BY a.id
+                        "#
+                    ))
+                    .bind(cycle_date)
+                    .fetch_all(conn)
+                    .await
+                })
+            })
+            .await?;
+
+        let mut recipients = Vec::new();
+        for row in rows {
+            recipients.push(StatementRecipientModel {
+                account_id: row.get("account_id"),
+                customer_id: row.get("customer_id"),
+                delivery_method: delivery_method(&row, "delivery_method")?,
+                contact_messaging_id: row.get("contact_messaging_id"),
+            });
+        }
+        Ok(recipients)
+    }
+
+    async fn create_cycle_dispatch(
+        &self,
+        batch: StatementPrintBatchModel,
+        entries: Vec<StatementPrintEntryModel>,
+        notifications: Vec<StatementNotificationModel>,
+    ) -> BankingResult<bool> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| BankingError::Internal(format!("Failed to begin transaction: {e}")))?;
+
+        // The unique cycle date makes a concurrent second dispatch a no-op
+        let inserted = sqlx::query(
+            r#"
+            INSERT INTO statement_print_batches (id, cycle_date, entry_count, created_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (cycle_date) DO NOTHING
+            "#,
+        )
+        .bind(batch.id)
+        .bind(batch.cycle_date)
+        .bind(batch.entry_count)
+        .bind(batch.created_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create statement print batch: {e}")))?;
+        if inserted.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        let ids: Vec<Uuid> = entries.iter().map(|e| e.id).collect();
+        let account_ids: Vec<Uuid> = entries.iter().map(|e| e.account_id).collect();
+        let customer_ids: Vec<Uuid> = entries.iter().map(|e| e.customer_id).collect();
+        let references: Vec<String> = entries.iter().map(|e| e.statement_reference.to_string()).collect();
+        let methods: Vec<String> = entries.iter().map(|e| delivery_method_name(e.delivery_method).to_string()).collect();
+        sqlx::query(
+            r#"
+            INSERT INTO statement_print_entries (id, batch_id, account_id, customer_id, statement_reference, delivery_method)
+            SELECT e.id, $2, e.account_id, e.customer_id, e.statement_reference, e.delivery_method::statement_delivery_method
+            FROM UNNEST($1::uuid[], $3::uuid[], $4::uuid[], $5::text[], $6::text[])
+                AS e(id, account_id, customer_id, statement_reference, delivery_method)
+            "#,
+        )
+        .bind(&ids)
+        .bind(batch.id)
+        .bind(&account_ids)
+        .bind(&customer_ids)
+        .bind(&references)
+        .bind(&methods)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create statement print entries: {e}")))?;
+
+        insert_notifications(&mut tx, &notifications).await?;
+
+        tx.commit().await
+            .map_err(|e| BankingError::Internal(format!("Failed to commit statement dispatch: {e}")))?;
+        Ok(true)
+    }
+
+    async fn find_print_batch(&self, cycle_date: NaiveDate) -> BankingResult<Option<StatementPrintBatchModel>> {
+        let result = sqlx::query(
+            "SELECT id, cycle_date, entry_count, created_at FROM statement_print_batches WHERE cycle_date = $1",
+        )
+        .bind(cycle_date)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find statement print batch: {e}")))?;
+
+        Ok(result.map(|row| StatementPrintBatchModel {
+            id: row.get("id"),
+            cycle_date: row.get("cycle_date"),
+            entry_count: row.get("entry_count"),
+            created_at: row.get("created_at"),
+        }))
+    }
+
+    async fn find_print_entries(&self, batch_id: Uuid) -> BankingResult<Vec<StatementPrintEntryModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, batch_id, account_id, customer_id, statement_reference,
+                   delivery_method::text as delivery_method
+            FROM statement_print_entries
+            WHERE batch_id = $1
+            ORDER BY account_id
+            "#,
+        )
+        .bind(batch_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find statement print entries: {e}")))?;
+
+        let mut entries = Vec::new();
+        for row in rows {
+            entries.push(StatementPrintEntryModel::try_from_row(&row)?);
+        }
+        Ok(entries)
+    }
+
+    async fn find_paper_statement_accounts(&self) -> BankingResult<Vec<PaperStatementAccountModel>> {
+        let rows = self
+            .executor
+            .run(QueryClass::Analytical, move |conn| {
+                Box::pin(async move {
+                    sqlx::query(&format!(
+                        r#"
+                        SELECT a.id as account_id, owner.customer_id,
+                               COALESCE(p.delivery_method, 'Paper'::statement_delivery_method)::text as delivery_method,
+                               EXISTS (
+                                   SELECT 1 FROM customer_contacts c
+                                   WHERE c.customer_id = owner.customer_id AND c.verified_at IS NOT NULL
+                               ) as has_verified_contact
+                        FROM accounts a
+                        {PRIMARY_OWNER_JOIN}
+                        LEFT JOIN statement_delivery_preferences p ON p.account_id = a.id
+                        WHERE a.account_status <> 'Closed'::account_status
+                          AND COALESCE(p.delivery_method, 'Paper'::statement_delivery_method)
+                              IN ('Paper'::statement_delivery_method, 'Both'::statement_delivery_method)
+                        ORDER BY owner.customer_id, a.id
+                        "#
+                    ))
+                    .fetch_all(conn)
+                    .await
+                })
+            })
+            .await?;
+
+        let mut accounts = Vec::new();
+        for row in rows {
+            accounts.push(PaperStatementAccountModel {
+                account_id: row.get("account_id"),
+                customer_id: row.get("customer_id"),
+                delivery_method: delivery_method(&row, "delivery_method")?,
+                has_verified_contact: row.get("has_verified_contact"),
+            });
+        }
+        Ok(accounts)
+    }
+
+    async fn find_opening_balance(&self, account_id: Uuid, period_start: NaiveDate) -> BankingResult<Decimal> {
+        self.executor
+            .run(QueryClass::Analytical, move |conn| {
+                Box::pin(async move {
+                    sqlx::query_scalar(
+                        r#"
+                        SELECT COALESCE(SUM(CASE WHEN transaction_type::text = 'Credit' THEN amount ELSE -amount END), 0)
+                        FROM transactions
+                        WHERE account_id = $1 AND status = 'Posted' AND value_date < $2
+                        "#,
+                    )
+                    .bind(account_id)
+                    .bind(period_start)
+                    .fetch_one(conn)
+                    .await
+                })
+            })
+            .await
+    }
+
+    async fn find_statement_entries(
+        &self,
+        account_id: Uuid,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> BankingResult<Vec<StatementEntryModel>> {
+        let rows = self
+            .executor
+            .run(QueryClass::Analytical, move |conn| {
+                Box::pin(async move {
+                    sqlx::query(
+                        r#"
+                        SELECT id, value_date, description, transaction_date,
+                               CASE WHEN transaction_type::text = 'Credit' THEN amount ELSE -amount END AS amount
+                        FROM transactions
+                        WHERE account_id = $1 AND status = 'Posted' AND value_date BETWEEN $2 AND $3
+                        ORDER BY value_date, transaction_date, id
+                        "#,
+                    )
+                    .bind(account_id)
+                    .bind(period_start)
+                    .bind(period_end)
+                    .fetch_all(conn)
+                    .await
+                })
+            })
+            .await?;
+
+        let mut entries = Vec::new();
+        for row in rows {
+            entries.push(StatementEntryModel {
+                transaction_id: row.get("id"),
+                value_date: row.get("value_date"),
+                description: heapless(row.get("description"), "description")?,
+                amount: row.get("amount"),
+                posted_at: row.get("transaction_date"),
+            });
+        }
+        Ok(entries)
+    }
+
+    async fn find_statement_charges(
+        &self,
+        account_id: Uuid,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> BankingResult<StatementChargeSummaryModel> {
+        let row = self
+            .executor
+            .run(QueryClass::Analytical, move |conn| {
+                Box::pin(async move {
+                    sqlx::query(
+                        r#"
+                        SELECT
+                            (SELECT COALESCE(SUM(daily_interest), 0) FROM account_interest_accruals
+                             WHERE account_id = $1 AND accrual_date BETWEEN $2 AND $3) AS interest_accrued,
+                            COALESCE(SUM(amount), 0) AS fees_charged,
+                            COUNT(*) AS fee_count
+                        FROM fee_applications
+                        WHERE account_id = $1 AND status::text = 'Applied' AND value_date BETWEEN $2 AND $3
+                        "#,
+                    )
+                    .bind(account_id)
+                    .bind(period_start)
+                    .bind(period_end)
+                    .fetch_one(conn)
+                    .await
+                })
+            })
+            .await?;
+
+        Ok(StatementChargeSummaryModel {
+            interest_accrued: row.get("interest_accrued"),
+            fees_charged: row.get("fees_charged"),
+            fee_count: row.get("fee_count"),
+        })
+    }
+
+    async fn find_last_statement_number(&self, account_id: Uuid) -> BankingResult<Option<i32>> {
+        sqlx::query_scalar("SELECT MAX(statement_number) FROM account_statements WHERE account_id = $1")
+            .bind(account_id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find last statement number: {e}")))
+    }
+
+    async fn create_statement(
+        &self,
+        statement: AccountStatementModel,
+        lines: Vec<StatementLineModel>,
+    ) -> BankingResult<AccountStatementModel> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| BankingError::Internal(format!("Failed to begin transaction: {e}")))?;
+
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO account_statements (
+                id, account_id, statement_number, statement_reference, period_start, period_end, opening_balance,
+                total_credits, total_debits, closing_balance, interest_accrued, fees_charged, fee_count, content_hash,
+                supersedes_statement_id, generated_at, generated_by_person_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+            RETURNING {STATEMENT_COLUMNS}
+            "#
+        ))
+        .bind(statement.id)
+        .bind(statement.account_id)
+        .bind(statement.statement_number)
+        .bind(statement.statement_reference.as_str())
+        .bind(statement.period_start)
+        .bind(statement.period_end)
+        .bind(statement.opening_balance)
+        .bind(statement.total_credits)
+        .bind(statement.total_debits)
+        .bind(statement.closing_balance)
+        .bind(statement.interest_accrued)
+        .bind(statement.fees_charged)
+        .bind(statement.fee_count)
+        .bind(statement.content_hash.as_bytes().as_slice())
+        .bind(statement.supersedes_statement_id)
+        .bind(statement.generated_at)
+        .bind(statement.generated_by_person_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create statement: {e}")))?;
+
+        let line_numbers: Vec<i32> = lines.iter().map(|l| l.line_number).collect();
+        let transaction_ids: Vec<Uuid> = lines.iter().map(|l| l.transaction_id).collect();
+        let value_dates: Vec<NaiveDate> = lines.iter().map(|l| l.value_date).collect();
+        let descriptions: Vec<String> = lines.iter().map(|l| l.description.to_string()).collect();
+        let amounts: Vec<Decimal> = lines.iter().map(|l| l.amount).collect();
+        let running_balances: Vec<Decimal> = lines.iter().map(|l| l.running_balance).collect();
+        sqlx::query(
+            r#"
+            INSERT INTO statement_lines (
+                statement_id, line_number, transaction_id, value_date, description, amount, running_balance
+            )
+            SELECT $1, l.line_number, l.transaction_id, l.value_date, l.description, l.amount, l.running_balance
+            FROM UNNEST($2::int4[], $3::uuid[], $4::date[], $5::text[], $6::numeric[], $7::numeric[])
+                AS l(line_number, transaction_id, value_date, description, amount, running_balance)
+            "#,
+        )
+        .bind(statement.id)
+        .bind(&line_numbers)
+        .bind(&transaction_ids)
+        .bind(&value_dates)
+        .bind(&descriptions)
+        .bind(&amounts)
+        .bind(&running_balances)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create statement lines: {e}")))?;
+
+        tx.commit().await
+            .map_err(|e| BankingError::Internal(format!("Failed to commit statement: {e}")))?;
+
+        AccountStatementModel::try_from_row(&row)
+    }
+
+    async fn find_statement_by_id(&self, statement_id: Uuid) -> BankingResult<Option<AccountStatementModel>> {
+        let result = sqlx::query(&format!("SELECT {STATEMENT_COLUMNS} FROM account_statements WHERE id = $1"))
+            .bind(statement_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find statement: {e}")))?;
+
+        match result {
+            Some(row) => Ok(Some(AccountStatementModel::try_from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_statement_lines(&self, statement_id: Uuid) -> BankingResult<Vec<StatementLineModel>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT statement_id, line_number, transaction_id, value_date, description, amount, running_balance
+            FROM statement_lines
+            WHERE statement_id = $1
+            ORDER BY line_number
+            "#,
+        )
+        .bind(statement_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find statement lines: {e}")))?;
+
+        let mut lines = Vec::new();
+        for row in rows {
+            lines.push(StatementLineModel::try_from_row(&row)?);
+        }
+        Ok(lines)
+    }
+
+    async fn find_current_statement(
+        &self,
+        account_id: Uuid,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> BankingResult<Option<AccountStatementModel>> {
+        let result = sqlx::query(&format!(
+            r#"
+            SELECT {STATEMENT_COLUMNS} FROM account_statements s
+            WHERE s.account_id = $1 AND s.period_start = $2 AND s.period_end = $3
+              AND NOT EXISTS (SELECT 1 FROM account_statements n WHERE n.supersedes_statement_id = s.id)
+            ORDER BY s.generated_at DESC
+            LIMIT 1
+            "#
+        ))
+        .bind(account_id)
+        .bind(period_start)
+        .bind(period_end)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find current statement: {e}")))?;
+
+        match result {
+            Some(row) => Ok(Some(AccountStatementModel::try_from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_statements_by_period(&self, period_end: NaiveDate) -> BankingResult<Vec<AccountStatementModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {STATEMENT_COLUMNS} FROM account_statements WHERE period_end = $1 ORDER BY account_id, generated_at"
+        ))
+        .bind(period_end)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find statements: {e}")))?;
+
+        let mut statements = Vec::new();
+        for row in rows {
+            statements.push(AccountStatementModel::try_from_row(&row)?);
+        }
+        Ok(statements)
+    }
+
+    async fn create_integrity_alert(&self, alert: StatementIntegrityAlertModel) -> BankingResult<StatementIntegrityAlertModel> {
+        sqlx::query(
+            r#"
+            INSERT INTO statement_integrity_alerts (
+                id, statement_id, account_id, stored_hash, computed_hash, detected_during, detected_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6::statement_integrity_check, $7)
+            "#,
+        )
+        .bind(alert.id)
+        .bind(alert.statement_id)
+        .bind(alert.account_id)
+        .bind(alert.stored_hash.as_bytes().as_slice())
+        .bind(alert.computed_hash.as_bytes().as_slice())
+        .bind(alert.detected_during)
+        .bind(alert.detected_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to record statement integrity alert: {e}")))?;
+
+        Ok(alert)
+    }
+}
@@ -0,0 +1,85 @@
+//! SQL fragments filtering customers and accounts by their tags.
+//!
+//! Tag codes are always bound as parameters; only the entity kind, chosen by
+//! matching on the enum, is spliced into the query text.
+
+use banking_db::models::{DbTagMatchMode, DbTaggableEntityKind, TagFilterModel};
+
+/// Condition that the entity in `entity_column` carries the tag whose code is
+/// bound to `code_param`
+pub(crate) fn has_tag_sql(entity_kind: DbTaggableEntityKind, entity_column: &str, code_param: &str) -> String {
+    format!(
+        "EXISTS (SELECT 1 FROM entity_tags et JOIN tags t ON t.id = et.tag_id \
+         WHERE et.entity_kind = '{}' AND et.entity_id = {entity_column} AND t.code = {code_param})",
+        entity_kind_str(entity_kind)
+    )
+}
+
+/// Condition that the entity in `entity_column` matches a tag filter, with
+/// `codes_param` bound to `tag_codes(filter)` and `required_param` to
+/// `required_matches(filter)`
+pub(crate) fn tag_filter_sql(
+    entity_kind: DbTaggableEntityKind,
+    entity_column: &str,
+    codes_param: &str,
+    required_param: &str,
+) -> String {
+    format!(
+        "(SELECT COUNT(DISTINCT t.code) FROM entity_tags et JOIN tags t ON t.id = et.tag_id \
+         WHERE et.entity_kind = '{}' AND et.entity_id = {entity_column} AND t.code = ANY({codes_param})) >= {required_param}",
+        entity_kind_str(entity_kind)
+    )
+}
+
+pub(crate) fn tag_codes(filter: &TagFilterModel) -> Vec<String> {
+    let mut codes: Vec<String> = filter.tag_codes.iter().map(|c| c.to_string()).collect();
+    codes.sort();
+    codes.dedup();
+    codes
+}
+
+/// Number of distinct filter tags an entity must carry
+pub(crate) fn required_matches(filter: &TagFilterModel) -> i64 {
+    match filter.match_mode {
+        DbTagMatchMode::Any => 1,
+        DbTagMatchMode::All => tag_codes(filter).len() as i64,
+    }
+}
+
+pub(crate) fn entity_kind_str(entity_kind: DbTaggableEntityKind) -> &'static str {
+    match entity_kind {
+        DbTaggableEntityKind::Customer => "Customer",
+        DbTaggableEntityKind::Account => "Account",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use heapless::String as HeaplessString;
+
+    fn filter(codes: &[&str], match_mode: DbTagMatchMode) -> TagFilterModel {
+        TagFilterModel {
+            tag_codes: codes.iter().map(|c| HeaplessString::try_from(*c).unwrap()).collect(),
+            match_mode,
+        }
+    }
+
+    #[test]
+    fn test_all_mode_requires_every_distinct_code() {
+        let all = filter(&["VIP", "StaffAccount", "VIP"], DbTagMatchMode::All);
+        assert_eq!(tag_codes(&all), vec!["StaffAccount".to_string(), "VIP".to_string()]);
+        assert_eq!(required_matches(&all), 2);
+        assert_eq!(required_matches(&filter(&["VIP", "StaffAccount"], DbTagMatchMode::Any)), 1);
+    }
+
+    #[test]
+    fn test_fragments_bind_codes_as_parameters() {
+        let sql = tag_filter_sql(DbTaggableEntityKind::Account, "a.id", "$2", "$3");
+        assert!(sql.contains("et.entity_kind = 'Account' AND et.entity_id = a.id AND t.code = ANY($2)"));
+        assert!(sql.ends_with(">= $3"));
+
+        let sql = has_tag_sql(DbTaggableEntityKind::Customer, "c.id", "$1");
+        assert!(sql.ends_with("et.entity_kind = 'Customer' AND et.entity_id = c.id AND t.code = $1)"));
+    }
+}
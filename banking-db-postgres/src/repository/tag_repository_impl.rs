@@ -0,0 +1,399 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{
+    AccountTagSelectionModel, BulkTagTargetModel, CustomerSearchCriteriaModel, DbTaggableEntityKind,
+    EntityTagModel, TagModel,
+};
+use banking_db::repository::TagRepository;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+use crate::repository::tag_filter_sql::{required_matches, tag_codes, tag_filter_sql};
+
+/// PostgreSQL implementation of TagRepository
+pub struct TagRepositoryImpl {
+    pool: PgPool,
+}
+
+impl TagRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn find_account_ids(
+        &self,
+        selection: &AccountTagSelectionModel,
+        after_entity_id: Option<Uuid>,
+        limit: i64,
+    ) -> BankingResult<Vec<Uuid>> {
+        let (codes, required) = match &selection.tags {
+            Some(filter) => (tag_codes(filter), required_matches(filter)),
+            None => (Vec::new(), 0),
+        };
+        let query = format!(
+            r#"
+            SELECT a.id FROM accounts a
+            WHERE ($1::uuid IS NULL OR a.product_id = $1)
+              AND ($2::account_status IS NULL OR a.account_status = $2::account_status)
+              AND ($3::date IS NULL OR a.open_date >= $3)
+              AND ($4::date IS NULL OR a.open_date <= $4)
+              AND (cardinality($5::text[]) = 0 OR {})
+              AND ($7::uuid IS NULL OR a.id > $7)
+            ORDER BY a.id
+            LIMIT $8
+            "#,
+            tag_filter_sql(DbTaggableEntityKind::Account, "a.id", "$5", "$6")
+        );
+
+        let rows = sqlx::query(&query)
+            .bind(selection.product_id)
+            .bind(selection.account_status)
+            .bind(selection.opened_from)
+            .bind(selection.opened_to)
+            .bind(&codes)
+            .bind(required)
+            .bind(after_entity_id)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to select accounts for tagging: {e}")))?;
+
+        Ok(rows.iter().map(|row| row.get("id")).collect())
+    }
+
+    /// Keyset paged; the criteria's own offset and limit are ignored
+    async fn find_customer_ids(
+        &self,
+        criteria: &CustomerSearchCriteriaModel,
+        after_entity_id: Option<Uuid>,
+        limit: i64,
+    ) -> BankingResult<Vec<Uuid>> {
+        let (codes, required) = match &criteria.tags {
+            Some(filter) => (tag_codes(filter), required_matches(filter)),
+            None => (Vec::new(), 0),
+        };
+        let query = format!(
+            r#"
+            SELECT c.id FROM customers c
+            WHERE ($1::customer_type IS NULL OR c.customer_type = $1::customer_type)
+              AND ($2::customer_status IS NULL OR c.status = $2::customer_status)
+              AND ($3::risk_rating IS NULL OR c.risk_rating = $3::risk_rating)
+              AND (cardinality($4::text[]) = 0 OR {})
+              AND ($6::uuid IS NULL OR c.id > $6)
+            ORDER BY c.id
+            LIMIT $7
+            "#,
+            tag_filter_sql(DbTaggableEntityKind::Customer, "c.id", "$4", "$5")
+        );
+
+        let rows = sqlx::query(&query)
+            .bind(criteria.customer_type)
+            .bind(criteria.status)
+            .bind(criteria.risk_rating)
+            .bind(&codes)
+            .bind(required)
+            .bind(after_entity_id)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to select customers for tagging: {e}")))?;
+
+        Ok(rows.iter().map(|row| row.get("id")).collect())
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+fn heapless<const N: usize>(value: String, field: &str) -> BankingResult<HeaplessString<N>> {
+    HeaplessString::try_from(value.as_str()).map_err(|_| BankingError::ValidationError {
+        field: field.to_string(),
+        message: format!("{field} too long"),
+    })
+}
+
+fn entity_kind(value: &str) -> BankingResult<DbTaggableEntityKind> {
+    value
+        .parse::<DbTaggableEntityKind>()
+        .map_err(|_| BankingError::Internal(format!("Invalid taggable entity kind: {value}")))
+}
+
+impl TryFromRow<PgRow> for TagModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(TagModel {
+            id: row.get("id"),
+            code: heapless(row.get("code"), "code")?,
+            description: heapless(row.get("description"), "description")?,
+            applicable_entity_kinds: row.get::<Vec<String>, _>("applicable_entity_kinds")
+                .iter()
+                .map(|kind| entity_kind(kind))
+                .collect::<BankingResult<Vec<_>>>()?,
+            required_permission: row.get::<Option<String>, _>("required_permission")
+                .map(|p| heapless(p, "required_permission"))
+                .transpose()?,
+            is_active: row.get("is_active"),
+            created_at: row.get("created_at"),
+            last_updated_at: row.get("last_updated_at"),
+            updated_by_person_id: row.get("updated_by_person_id"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for EntityTagModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(EntityTagModel {
+            id: row.get("id"),
+            tag_id: row.get("tag_id"),
+            entity_kind: entity_kind(&row.get::<String, _>("entity_kind"))?,
+            entity_id: row.get("entity_id"),
+            assigned_at: row.get("assigned_at"),
+            assigned_by_person_id: row.get("assigned_by_person_id"),
+        })
+    }
+}
+
+const TAG_COLUMNS: &str = r#"
+    id, code, description, applicable_entity_kinds::text[] as applicable_entity_kinds, required_permission,
+    is_active, created_at, last_updated_at, updated_by_person_id
+"#;
+
+const ENTITY_TAG_COLUMNS: &str = r#"
+    id, tag_id, entity_kind::text as entity_kind, entity_id, assigned_at, assigned_by_person_id
+"#;
+
+fn entity_kind_names(tag: &TagModel) -> Vec<String> {
+    tag.applicable_entity_kinds
+        .iter()
+        .map(|kind| crate::repository::tag_filter_sql::entity_kind_str(*kind).to_string())
+        .collect()
+}
+
+#[async_trait]
+impl TagRepository for TagRepositoryImpl {
+    async fn create_tag(&self, tag: TagModel) -> BankingResult<TagModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO tags (
+                id, code, description, applicable_entity_kinds, required_permission, is_active,
+                created_at, last_updated_at, updated_by_person_id
+            )
+            VALUES ($1, $2, $3, $4::text[]::taggable_entity_kind[], $5, $6, $7, $8, $9)
+            RETURNING {TAG_COLUMNS}
+            "#
+        ))
+        .bind(tag.id)
+        .bind(tag.code.as_str())
+        .bind(tag.description.as_str())
+        .bind(entity_kind_names(&tag))
+        .bind(tag.required_permission.as_ref().map(|p| p.as_str()))
+        .bind(tag.is_active)
+        .bind(tag.created_at)
+        .bind(tag.last_updated_at)
+        .bind(tag.updated_by_person_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create tag: {e}")))?;
+
+        TagModel::try_from_row(&row)
+    }
+
+    async fn update_tag(&self, tag: TagModel) -> BankingResult<TagModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE tags
+            SET description = $2, applicable_entity_kinds = $3::text[]::taggable_entity_kind[],
+                required_permission = $4, is_active = $5, last_updated_at = $6, updated_by_person_id = $7
+            WHERE id = $1
+            RETURNING {TAG_COLUMNS}
+            "#
+        ))
+        .bind(tag.id)
+        .bind(tag.description.as_str())
+        .bind(entity_kind_names(&tag))
+        .bind(tag.required_permission.as_ref().map(|p| p.as_str()))
+        .bind(tag.is_active)
+        .bind(tag.last_updated_at)
+        .bind(tag.updated_by_person_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update tag: {e}")))?
+        .ok_or_else(|| BankingError::NotFound(format!("Tag {} not found", tag.id)))?;
+
+        TagModel::try_from_row(&row)
+    }
+
+    async fn find_tag_by_id(&self, tag_id: Uuid) -> BankingResult<Option<TagModel>> {
+        let row = sqlx::query(&format!("SELECT {TAG_COLUMNS} FROM tags WHERE id = $1"))
+            .bind(tag_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find tag: {e}")))?;
+
+        row.as_ref().map(TagModel::try_from_row).transpose()
+    }
+
+    async fn find_tag_by_code(&self, code: &str) -> BankingResult<Option<TagModel>> {
+        let row = sqlx::query(&format!("SELECT {TAG_COLUMNS} FROM tags WHERE code = $1"))
+            .bind(code)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find tag by code: {e}")))?;
+
+        row.as_ref().map(TagModel::try_from_row).transpose()
+    }
+
+    async fn find_active_tags(&self) -> BankingResult<Vec<TagModel>> {
+        let rows = sqlx::query(&format!("SELECT {TAG_COLUMNS} FROM tags WHERE is_active ORDER BY code"))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find active tags: {e}")))?;
+
+        rows.iter().map(TagModel::try_from_row).collect()
+    }
+
+    async fn deactivate_tag(&self, tag_id: Uuid, updated_by_person_id: Uuid) -> BankingResult<()> {
+        sqlx::query(
+            "UPDATE tags SET is_active = FALSE, last_updated_at = NOW(), updated_by_person_id = $2 WHERE id = $1",
+        )
+        .bind(tag_id)
+        .bind(updated_by_person_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to deactivate tag: {e}")))?;
+
+        Ok(())
+    }
+
+    async fn entity_exists(&self, entity_kind: DbTaggableEntityKind, entity_id: Uuid) -> BankingResult<bool> {
+        let query = match entity_kind {
+            DbTaggableEntityKind::Customer => "SELECT EXISTS(SELECT 1 FROM customers WHERE id = $1)",
+            DbTaggableEntityKind::Account => "SELECT EXISTS(SELECT 1 FROM accounts WHERE id = $1)",
+        };
+        let exists: bool = sqlx::query_scalar(query)
+            .bind(entity_id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to check tagged entity: {e}")))?;
+
+        Ok(exists)
+    }
+
+    async fn assign_tag(&self, entity_tag: EntityTagModel) -> BankingResult<EntityTagModel> {
+        let inserted = sqlx::query(&format!(
+            r#"
+            INSERT INTO entity_tags (id, tag_id, entity_kind, entity_id, assigned_at, assigned_by_person_id)
+            VALUES ($1, $2, $3::taggable_entity_kind, $4, $5, $6)
+            ON CONFLICT (tag_id, entity_kind, entity_id) DO NOTHING
+            RETURNING {ENTITY_TAG_COLUMNS}
+            "#
+        ))
+        .bind(entity_tag.id)
+        .bind(entity_tag.tag_id)
+        .bind(entity_tag.entity_kind)
+        .bind(entity_tag.entity_id)
+        .bind(entity_tag.assigned_at)
+        .bind(entity_tag.assigned_by_person_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to assign tag: {e}")))?;
+
+        let row = match inserted {
+            Some(row) => row,
+            None => sqlx::query(&format!(
+                r#"
+                SELECT {ENTITY_TAG_COLUMNS} FROM entity_tags
+                WHERE tag_id = $1 AND entity_kind = $2::taggable_entity_kind AND entity_id = $3
+                "#
+            ))
+            .bind(entity_tag.tag_id)
+            .bind(entity_tag.entity_kind)
+            .bind(entity_tag.entity_id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find tag assignment: {e}")))?,
+        };
+
+        EntityTagModel::try_from_row(&row)
+    }
+
+    async fn remove_tag(&self, tag_id: Uuid, entity_kind: DbTaggableEntityKind, entity_id: Uuid) -> BankingResult<bool> {
+        let result = sqlx::query(
+            "DELETE FROM entity_tags WHERE tag_id = $1 AND entity_kind = $2::taggable_entity_kind AND entity_id = $3",
+        )
+        .bind(tag_id)
+        .bind(entity_kind)
+        .bind(entity_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to remove tag: {e}")))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_tags_for_entity(&self, entity_kind: DbTaggableEntityKind, entity_id: Uuid) -> BankingResult<Vec<TagModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {TAG_COLUMNS} FROM tags
+            WHERE id IN (
+                SELECT tag_id FROM entity_tags
+                WHERE entity_kind = $1::taggable_entity_kind AND entity_id = $2
+            )
+            ORDER BY code
+            "#
+        ))
+        .bind(entity_kind)
+        .bind(entity_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find tags for entity: {e}")))?;
+
+        rows.iter().map(TagModel::try_from_row).collect()
+    }
+
+    async fn find_bulk_target_ids(
+        &self,
+        target: &BulkTagTargetModel,
+        after_entity_id: Option<Uuid>,
+        limit: i64,
+    ) -> BankingResult<Vec<Uuid>> {
+        match target {
+            BulkTagTargetModel::Accounts(selection) => self.find_account_ids(selection, after_entity_id, limit).await,
+            BulkTagTargetModel::Customers(criteria) => self.find_customer_ids(criteria, after_entity_id, limit).await,
+        }
+    }
+
+    async fn assign_tag_bulk(
+        &self,
+        tag_id: Uuid,
+        entity_kind: DbTaggableEntityKind,
+        entity_ids: Vec<Uuid>,
+        assigned_by_person_id: Uuid,
+        assigned_at: DateTime<Utc>,
+    ) -> BankingResult<u64> {
+        let ids: Vec<Uuid> = entity_ids.iter().map(|_| Uuid::new_v4()).collect();
+        let result = sqlx::query(
+            r#"
+            INSERT INTO entity_tags (id, tag_id, entity_kind, entity_id, assigned_at, assigned_by_person_id)
+            SELECT id, $1, $2::taggable_entity_kind, entity_id, $5, $6
+            FROM UNNEST($3::uuid[], $4::uuid[]) AS input(id, entity_id)
+            ON CONFLICT (tag_id, entity_kind, entity_id) DO NOTHING
+            "#,
+        )
+        .bind(tag_id)
+        .bind(entity_kind)
+        .bind(&ids)
+        .bind(&entity_ids)
+        .bind(assigned_at)
+        .bind(assigned_by_person_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to assign tags in bulk: {e}")))?;
+
+        Ok(result.rows_affected())
+    }
+}
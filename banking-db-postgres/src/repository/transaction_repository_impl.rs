@@ -1,6 +1,9 @@
 use async_trait::async_trait;
 use banking_api::{BankingResult, BankingError};
-use banking_db::models::{TransactionModel, TransactionStatus, TransactionApprovalStatus};
+use banking_api::domain::ReversalRefusal;
+use banking_db::models::{
+    DbTaggableEntityKind, TransactionModel, TransactionSearchCriteriaModel, TransactionStatus, TransactionApprovalStatus,
+};
 use banking_db::models::workflow::{ApprovalWorkflowModel, WorkflowTransactionApprovalModel, WorkflowStatusModel};
 use banking_db::repository::TransactionRepository;
 use sqlx::{PgPool, Row};
@@ -9,6 +12,8 @@ use rust_decimal::Decimal;
 use chrono::{DateTime, Utc, NaiveDate};
 use heapless::String as HeaplessString;
 
+use crate::repository::tag_filter_sql::{required_matches, tag_codes, tag_filter_sql};
+
 pub struct TransactionRepositoryImpl {
     pool: PgPool,
 }
@@ -17,6 +22,19 @@ impl TransactionRepositoryImpl {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    /// Original transaction behind an insert that conflicted on its idempotency key
+    async fn find_replayed(&self, transaction: &TransactionModel) -> BankingResult<TransactionModel> {
+        let key = transaction.idempotency_key.as_ref().ok_or_else(|| {
+            BankingError::Internal(format!("Transaction {} was not inserted", transaction.id))
+        })?;
+        self.find_by_idempotency_key(transaction.channel_id.as_str(), key.as_str())
+            .await?
+            .ok_or_else(|| BankingError::Internal(format!(
+                "Idempotency key {} on channel {} conflicted but no transaction holds it",
+                key, transaction.channel_id
+            )))
+    }
 }
 
 /// Helper function to parse transaction status string
@@ -135,30 +153,122 @@ fn extract_transaction_from_row(row: &sqlx::postgres::PgRow) -> BankingResult<Tr
             None => None,
         },
         risk_score: row.get("risk_score"),
+        degraded_flags: row.get("degraded_flags"),
+        idempotency_key: match row.get::<Option<String>, _>("idempotency_key") {
+            Some(key) => Some(HeaplessString::try_from(key.as_str()).map_err(|_| {
+                BankingError::ValidationError {
+                    field: "idempotency_key".to_string(),
+                    message: "Idempotency key too long".to_string(),
+                }
+            })?),
+            None => None,
+        },
+        reversal_of_transaction_id: row.get("reversal_of_transaction_id"),
+        original_amount: row.get("original_amount"),
+        original_currency: match row.get::<Option<String>, _>("original_currency") {
+            Some(currency) => Some(HeaplessString::try_from(currency.as_str()).map_err(|_| {
+                BankingError::ValidationError {
+                    field: "original_currency".to_string(),
+                    message: "Currency code too long".to_string(),
+                }
+            })?),
+            None => None,
+        },
+        exchange_rate: row.get("exchange_rate"),
         created_at: row.get("created_at"),
     })
 }
 
+const TRANSACTION_COLUMNS: &str = r#"
+    id, account_id, transaction_code, transaction_type::text as transaction_type,
+    amount, currency, description, channel_id, terminal_id, agent_person_id,
+    transaction_date, value_date, status::text as status, reference_number,
+    external_reference, gl_code, requires_approval, approval_status::text as approval_status,
+    risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+    original_amount, original_currency, exchange_rate, created_at
+"#;
+
+/// Lock the account row so concurrent postings apply their deltas one after
+/// another, and return the balance change of the transaction. A debit beyond
+/// the available balance and overdraft limit fails with InsufficientFunds.
+async fn lock_balance_delta(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    transaction: &TransactionModel,
+) -> BankingResult<Decimal> {
+    let account = sqlx::query(
+        "SELECT available_balance, overdraft_limit FROM accounts WHERE id = $1 FOR UPDATE"
+    )
+    .bind(transaction.account_id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(BankingError::AccountNotFound(transaction.account_id))?;
+
+    match transaction.transaction_type {
+        banking_db::models::TransactionType::Credit => Ok(transaction.amount),
+        banking_db::models::TransactionType::Debit => {
+            let available_balance: Decimal = account.get("available_balance");
+            let overdraft_limit: Option<Decimal> = account.get("overdraft_limit");
+            let available = available_balance + overdraft_limit.unwrap_or(Decimal::ZERO);
+            if transaction.amount > available {
+                return Err(BankingError::InsufficientFunds {
+                    account_id: transaction.account_id,
+                    requested: transaction.amount,
+                    available,
+                });
+            }
+            Ok(-transaction.amount)
+        }
+    }
+}
+
+/// Bumping the version makes a concurrent full-account update retry instead of overwriting the balance
+async fn apply_balance_delta(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    account_id: Uuid,
+    delta: Decimal,
+) -> BankingResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE accounts
+        SET current_balance = current_balance + $2,
+            available_balance = available_balance + $2,
+            last_activity_date = CURRENT_DATE,
+            last_updated_at = NOW(),
+            version = version + 1
+        WHERE id = $1
+        "#
+    )
+    .bind(account_id)
+    .bind(delta)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
 #[async_trait]
 impl TransactionRepository for TransactionRepositoryImpl {
     async fn create(&self, transaction: TransactionModel) -> BankingResult<TransactionModel> {
-        let result = sqlx::query(
+        let inserted = sqlx::query(
             r#"
             INSERT INTO transactions (
                 id, account_id, transaction_code, transaction_type, amount, currency,
                 description, channel_id, terminal_id, agent_person_id, transaction_date, value_date,
                 status, reference_number, external_reference, gl_code, requires_approval,
-                approval_status, risk_score
+                approval_status, risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                original_amount, original_currency, exchange_rate
             )
             VALUES (
                 $1, $2, $3, $4::transaction_type, $5, $6, $7, $8, $9, $10, $11, $12,
-                $13::transaction_status, $14, $15, $16, $17, $18::transaction_approval_status, $19
+                $13::transaction_status, $14, $15, $16, $17, $18::transaction_approval_status, $19, $20, $21, $22,
+                $23, $24, $25
             )
+            ON CONFLICT (channel_id, idempotency_key) DO NOTHING
             RETURNING id, account_id, transaction_code, transaction_type::text as transaction_type,
                      amount, currency, description, channel_id, terminal_id, agent_person_id,
                      transaction_date, value_date, status::text as status, reference_number,
                      external_reference, gl_code, requires_approval, approval_status::text as approval_status,
-                     risk_score, created_at
+                     risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                     original_amount, original_currency, exchange_rate, created_at
             "#
         )
         .bind(transaction.id)
@@ -180,12 +290,123 @@ impl TransactionRepository for TransactionRepositoryImpl {
         .bind(transaction.requires_approval)
         .bind(transaction.approval_status.as_ref().map(|s| s.to_string()))
         .bind(transaction.risk_score)
-        .fetch_one(&self.pool)
+        .bind(transaction.degraded_flags)
+        .bind(transaction.idempotency_key.as_ref().map(|s| s.as_str()))
+        .bind(transaction.reversal_of_transaction_id)
+        .bind(transaction.original_amount)
+        .bind(transaction.original_currency.as_ref().map(|s| s.as_str()))
+        .bind(transaction.exchange_rate)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match inserted {
+            Some(row) => extract_transaction_from_row(&row),
+            None => self.find_replayed(&transaction).await,
+        }
+    }
+
+    async fn post_transaction(&self, transaction: TransactionModel) -> BankingResult<TransactionModel> {
+        let mut tx = self.pool.begin().await?;
+        let delta = lock_balance_delta(&mut tx, &transaction).await?;
+
+        // A replayed idempotency key inserts nothing and leaves the balance alone
+        let inserted = sqlx::query(
+            r#"
+            INSERT INTO transactions (
+                id, account_id, transaction_code, transaction_type, amount, currency,
+                description, channel_id, terminal_id, agent_person_id, transaction_date, value_date,
+                status, reference_number, external_reference, gl_code, requires_approval,
+                approval_status, risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                original_amount, original_currency, exchange_rate
+            )
+            VALUES (
+                $1, $2, $3, $4::transaction_type, $5, $6, $7, $8, $9, $10, $11, $12,
+                $13::transaction_status, $14, $15, $16, $17, $18::transaction_approval_status, $19, $20, $21, $22,
+                $23, $24, $25
+            )
+            ON CONFLICT (channel_id, idempotency_key) DO NOTHING
+            RETURNING id, account_id, transaction_code, transaction_type::text as transaction_type,
+                     amount, currency, description, channel_id, terminal_id, agent_person_id,
+                     transaction_date, value_date, status::text as status, reference_number,
+                     external_reference, gl_code, requires_approval, approval_status::text as approval_status,
+                     risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                     original_amount, original_currency, exchange_rate, created_at
+            "#
+        )
+        .bind(transaction.id)
+        .bind(transaction.account_id)
+        .bind(transaction.transaction_code.as_str())
+        .bind(transaction.transaction_type.to_string())
+        .bind(transaction.amount)
+        .bind(transaction.currency.as_str())
+        .bind(transaction.description.as_str())
+        .bind(transaction.channel_id.as_str())
+        .bind(transaction.terminal_id)
+        .bind(transaction.agent_person_id)
+        .bind(transaction.transaction_date)
+        .bind(transaction.value_date)
+        .bind(transaction.status.to_string())
+        .bind(transaction.reference_number.as_str())
+        .bind(transaction.external_reference.as_ref().map(|s| s.as_str()))
+        .bind(transaction.gl_code.as_str())
+        .bind(transaction.requires_approval)
+        .bind(transaction.approval_status.as_ref().map(|s| s.to_string()))
+        .bind(transaction.risk_score)
+        .bind(transaction.degraded_flags)
+        .bind(transaction.idempotency_key.as_ref().map(|s| s.as_str()))
+        .bind(transaction.reversal_of_transaction_id)
+        .bind(transaction.original_amount)
+        .bind(transaction.original_currency.as_ref().map(|s| s.as_str()))
+        .bind(transaction.exchange_rate)
+        .fetch_optional(&mut *tx)
         .await?;
+        let Some(result) = inserted else {
+            tx.rollback().await?;
+            return self.find_replayed(&transaction).await;
+        };
+
+        apply_balance_delta(&mut tx, transaction.account_id, delta).await?;
+        tx.commit().await?;
 
         extract_transaction_from_row(&result)
     }
 
+    async fn post_approved_transaction(&self, transaction_id: Uuid) -> BankingResult<Option<TransactionModel>> {
+        let mut tx = self.pool.begin().await?;
+
+        let Some(row) = sqlx::query(&format!(
+            "SELECT {TRANSACTION_COLUMNS} FROM transactions WHERE id = $1 AND status = 'AwaitingApproval' FOR UPDATE"
+        ))
+        .bind(transaction_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        else {
+            tx.rollback().await?;
+            return Ok(None);
+        };
+        let transaction = extract_transaction_from_row(&row)?;
+        let delta = lock_balance_delta(&mut tx, &transaction).await?;
+
+        let result = sqlx::query(&format!(
+            r#"
+            UPDATE transactions
+            SET status = 'Posted'::transaction_status,
+                approval_status = 'Approved'::transaction_approval_status,
+                updated_at = NOW()
+            WHERE id = $1
+            RETURNING {TRANSACTION_COLUMNS}
+            "#
+        ))
+        .bind(transaction_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        apply_balance_delta(&mut tx, transaction.account_id, delta).await?;
+        tx.commit().await?;
+
+        extract_transaction_from_row(&result).map(Some)
+    }
+
     async fn update(&self, transaction: TransactionModel) -> BankingResult<TransactionModel> {
         let result = sqlx::query(
             r#"
@@ -195,13 +416,14 @@ impl TransactionRepository for TransactionRepositoryImpl {
                 agent_person_id = $10, transaction_date = $11, value_date = $12,
                 status = $13::transaction_status, reference_number = $14, external_reference = $15,
                 gl_code = $16, requires_approval = $17, approval_status = $18::transaction_approval_status,
-                risk_score = $19
+                risk_score = $19, degraded_flags = $20, updated_at = NOW()
             WHERE id = $1
             RETURNING id, account_id, transaction_code, transaction_type::text as transaction_type,
                      amount, currency, description, channel_id, terminal_id, agent_person_id,
                      transaction_date, value_date, status::text as status, reference_number,
                      external_reference, gl_code, requires_approval, approval_status::text as approval_status,
-                     risk_score, created_at
+                     risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                     original_amount, original_currency, exchange_rate, created_at
             "#
         )
         .bind(transaction.id)
@@ -223,6 +445,7 @@ impl TransactionRepository for TransactionRepositoryImpl {
         .bind(transaction.requires_approval)
         .bind(transaction.approval_status.as_ref().map(|s| s.to_string()))
         .bind(transaction.risk_score)
+        .bind(transaction.degraded_flags)
         .fetch_one(&self.pool)
         .await?;
 
@@ -236,7 +459,8 @@ impl TransactionRepository for TransactionRepositoryImpl {
                    amount, currency, description, channel_id, terminal_id, agent_person_id,
                    transaction_date, value_date, status::text as status, reference_number,
                    external_reference, gl_code, requires_approval, approval_status::text as approval_status,
-                   risk_score, created_at
+                   risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                   original_amount, original_currency, exchange_rate, created_at
             FROM transactions
             WHERE id = $1
             "#
@@ -258,7 +482,8 @@ impl TransactionRepository for TransactionRepositoryImpl {
                    amount, currency, description, channel_id, terminal_id, agent_person_id,
                    transaction_date, value_date, status::text as status, reference_number,
                    external_reference, gl_code, requires_approval, approval_status::text as approval_status,
-                   risk_score, created_at
+                   risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                   original_amount, original_currency, exchange_rate, created_at
             FROM transactions
             WHERE account_id = $1
             "#
@@ -307,7 +532,8 @@ impl TransactionRepository for TransactionRepositoryImpl {
                    amount, currency, description, channel_id, terminal_id, agent_person_id,
                    transaction_date, value_date, status::text as status, reference_number,
                    external_reference, gl_code, requires_approval, approval_status::text as approval_status,
-                   risk_score, created_at
+                   risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                   original_amount, original_currency, exchange_rate, created_at
             FROM transactions
             WHERE account_id = $1 AND value_date >= $2 AND value_date <= $3
             ORDER BY transaction_date DESC
@@ -327,6 +553,51 @@ impl TransactionRepository for TransactionRepositoryImpl {
         Ok(transactions)
     }
 
+    async fn search(&self, criteria: TransactionSearchCriteriaModel) -> BankingResult<Vec<TransactionModel>> {
+        let (codes, required) = match &criteria.account_tags {
+            Some(filter) => (tag_codes(filter), required_matches(filter)),
+            None => (Vec::new(), 0),
+        };
+        let query = format!(
+            r#"
+            SELECT tx.id, tx.account_id, tx.transaction_code, tx.transaction_type::text as transaction_type,
+                   tx.amount, tx.currency, tx.description, tx.channel_id, tx.terminal_id, tx.agent_person_id,
+                   tx.transaction_date, tx.value_date, tx.status::text as status, tx.reference_number,
+                   tx.external_reference, tx.gl_code, tx.requires_approval, tx.approval_status::text as approval_status,
+                   tx.risk_score, tx.degraded_flags, tx.idempotency_key, tx.reversal_of_transaction_id,
+                   tx.original_amount, tx.original_currency, tx.exchange_rate, tx.created_at
+            FROM transactions tx
+            WHERE ($1::uuid IS NULL OR tx.account_id = $1)
+              AND ($2::date IS NULL OR tx.value_date >= $2)
+              AND ($3::date IS NULL OR tx.value_date <= $3)
+              AND ($4::text IS NULL OR tx.status::text = $4)
+              AND (cardinality($5::text[]) = 0 OR {})
+            ORDER BY tx.transaction_date DESC, tx.id
+            LIMIT $7 OFFSET $8
+            "#,
+            tag_filter_sql(DbTaggableEntityKind::Account, "tx.account_id", "$5", "$6")
+        );
+
+        let results = sqlx::query(&query)
+            .bind(criteria.account_id)
+            .bind(criteria.from_date)
+            .bind(criteria.to_date)
+            .bind(criteria.status.map(|s| s.to_string()))
+            .bind(&codes)
+            .bind(required)
+            .bind(criteria.limit)
+            .bind(criteria.offset)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut transactions = Vec::new();
+        for row in results {
+            transactions.push(extract_transaction_from_row(&row)?);
+        }
+
+        Ok(transactions)
+    }
+
     async fn find_by_reference(&self, reference_number: &str) -> BankingResult<Option<TransactionModel>> {
         let result = sqlx::query(
             r#"
@@ -334,7 +605,8 @@ impl TransactionRepository for TransactionRepositoryImpl {
                    amount, currency, description, channel_id, terminal_id, agent_person_id,
                    transaction_date, value_date, status::text as status, reference_number,
                    external_reference, gl_code, requires_approval, approval_status::text as approval_status,
-                   risk_score, created_at
+                   risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                   original_amount, original_currency, exchange_rate, created_at
             FROM transactions
             WHERE reference_number = $1
             "#
@@ -349,6 +621,30 @@ impl TransactionRepository for TransactionRepositoryImpl {
         }
     }
 
+    async fn find_by_idempotency_key(&self, channel_id: &str, idempotency_key: &str) -> BankingResult<Option<TransactionModel>> {
+        let result = sqlx::query(
+            r#"
+            SELECT id, account_id, transaction_code, transaction_type::text as transaction_type,
+                   amount, currency, description, channel_id, terminal_id, agent_person_id,
+                   transaction_date, value_date, status::text as status, reference_number,
+                   external_reference, gl_code, requires_approval, approval_status::text as approval_status,
+                   risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                   original_amount, original_currency, exchange_rate, created_at
+            FROM transactions
+            WHERE channel_id = $1 AND idempotency_key = $2
+            "#
+        )
+        .bind(channel_id)
+        .bind(idempotency_key)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match result {
+            Some(row) => Ok(Some(extract_transaction_from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
     async fn find_by_external_reference(&self, external_reference: &str) -> BankingResult<Vec<TransactionModel>> {
         let results = sqlx::query(
             r#"
@@ -356,7 +652,8 @@ impl TransactionRepository for TransactionRepositoryImpl {
                    amount, currency, description, channel_id, terminal_id, agent_person_id,
                    transaction_date, value_date, status::text as status, reference_number,
                    external_reference, gl_code, requires_approval, approval_status::text as approval_status,
-                   risk_score, created_at
+                   risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                   original_amount, original_currency, exchange_rate, created_at
             FROM transactions
             WHERE external_reference = $1
             ORDER BY transaction_date DESC
@@ -381,7 +678,8 @@ impl TransactionRepository for TransactionRepositoryImpl {
                    amount, currency, description, channel_id, terminal_id, agent_person_id,
                    transaction_date, value_date, status::text as status, reference_number,
                    external_reference, gl_code, requires_approval, approval_status::text as approval_status,
-                   risk_score, created_at
+                   risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                   original_amount, original_currency, exchange_rate, created_at
             FROM transactions
             WHERE status = $1::transaction_status
             ORDER BY transaction_date DESC
@@ -406,7 +704,8 @@ impl TransactionRepository for TransactionRepositoryImpl {
                    amount, currency, description, channel_id, terminal_id, agent_person_id,
                    transaction_date, value_date, status::text as status, reference_number,
                    external_reference, gl_code, requires_approval, approval_status::text as approval_status,
-                   risk_score, created_at
+                   risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                   original_amount, original_currency, exchange_rate, created_at
             FROM transactions
             WHERE requires_approval = true AND (approval_status IS NULL OR approval_status = 'Pending')
             ORDER BY transaction_date ASC
@@ -430,7 +729,8 @@ impl TransactionRepository for TransactionRepositoryImpl {
                    amount, currency, description, channel_id, terminal_id, agent_person_id,
                    transaction_date, value_date, status::text as status, reference_number,
                    external_reference, gl_code, requires_approval, approval_status::text as approval_status,
-                   risk_score, created_at
+                   risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                   original_amount, original_currency, exchange_rate, created_at
             FROM transactions
             WHERE terminal_id = $1
             "#
@@ -479,7 +779,8 @@ impl TransactionRepository for TransactionRepositoryImpl {
                    amount, currency, description, channel_id, terminal_id, agent_person_id,
                    transaction_date, value_date, status::text as status, reference_number,
                    external_reference, gl_code, requires_approval, approval_status::text as approval_status,
-                   risk_score, created_at
+                   risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                   original_amount, original_currency, exchange_rate, created_at
             FROM transactions
             WHERE agent_person_id = $1
             "#
@@ -528,7 +829,8 @@ impl TransactionRepository for TransactionRepositoryImpl {
                    amount, currency, description, channel_id, terminal_id, agent_person_id,
                    transaction_date, value_date, status::text as status, reference_number,
                    external_reference, gl_code, requires_approval, approval_status::text as approval_status,
-                   risk_score, created_at
+                   risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                   original_amount, original_currency, exchange_rate, created_at
             FROM transactions
             WHERE channel_id = $1
             "#
@@ -574,7 +876,7 @@ impl TransactionRepository for TransactionRepositoryImpl {
         sqlx::query(
             r#"
             UPDATE transactions 
-            SET status = $2::transaction_status
+            SET status = $2::transaction_status, updated_at = NOW()
             WHERE id = $1
             "#
         )
@@ -590,7 +892,7 @@ impl TransactionRepository for TransactionRepositoryImpl {
         sqlx::query(
             r#"
             UPDATE transactions 
-            SET approval_status = $2::transaction_approval_status
+            SET approval_status = $2::transaction_approval_status, updated_at = NOW()
             WHERE id = $1
             "#
         )
@@ -609,7 +911,8 @@ impl TransactionRepository for TransactionRepositoryImpl {
                    amount, currency, description, channel_id, terminal_id, agent_person_id,
                    transaction_date, value_date, status::text as status, reference_number,
                    external_reference, gl_code, requires_approval, approval_status::text as approval_status,
-                   risk_score, created_at
+                   risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                   original_amount, original_currency, exchange_rate, created_at
             FROM transactions
             WHERE account_id = $1 
               AND channel_id NOT IN ('System', 'AutoInterest', 'AutoFee')
@@ -681,32 +984,47 @@ impl TransactionRepository for TransactionRepositoryImpl {
     async fn reverse_transaction(&self, original_id: Uuid, reversal_transaction: TransactionModel) -> BankingResult<TransactionModel> {
         let mut tx = self.pool.begin().await?;
 
-        // Update original transaction status to Reversed
-        sqlx::query(
-            "UPDATE transactions SET status = 'Reversed' WHERE id = $1"
+        let original = sqlx::query(
+            "SELECT status::text as status, reversal_of_transaction_id FROM transactions WHERE id = $1 FOR UPDATE"
         )
         .bind(original_id)
-        .execute(&mut *tx)
-        .await?;
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| BankingError::TransactionNotFound(original_id.to_string()))?;
+        let refusal = if original.get::<Option<Uuid>, _>("reversal_of_transaction_id").is_some() {
+            Some(ReversalRefusal::IsReversal)
+        } else {
+            match parse_transaction_status(&original.get::<String, _>("status"))? {
+                TransactionStatus::Posted => None,
+                TransactionStatus::Reversed => Some(ReversalRefusal::AlreadyReversed),
+                _ => Some(ReversalRefusal::NotPosted),
+            }
+        };
+        if let Some(refusal) = refusal {
+            tx.rollback().await?;
+            return Err(BankingError::TransactionNotReversible { transaction_id: original_id, refusal });
+        }
+
+        let delta = lock_balance_delta(&mut tx, &reversal_transaction).await?;
 
-        // Insert reversal transaction
         let result = sqlx::query(
             r#"
             INSERT INTO transactions (
                 id, account_id, transaction_code, transaction_type, amount, currency,
                 description, channel_id, terminal_id, agent_person_id, transaction_date, value_date,
                 status, reference_number, external_reference, gl_code, requires_approval,
-                approval_status, risk_score
+                approval_status, risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id
             )
             VALUES (
                 $1, $2, $3, $4::transaction_type, $5, $6, $7, $8, $9, $10, $11, $12,
-                $13::transaction_status, $14, $15, $16, $17, $18::transaction_approval_status, $19
+                $13::transaction_status, $14, $15, $16, $17, $18::transaction_approval_status, $19, $20, $21, $22
             )
             RETURNING id, account_id, transaction_code, transaction_type::text as transaction_type,
                      amount, currency, description, channel_id, terminal_id, agent_person_id,
                      transaction_date, value_date, status::text as status, reference_number,
                      external_reference, gl_code, requires_approval, approval_status::text as approval_status,
-                     risk_score, created_at
+                     risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                     original_amount, original_currency, exchange_rate, created_at
             "#
         )
         .bind(reversal_transaction.id)
@@ -728,9 +1046,35 @@ impl TransactionRepository for TransactionRepositoryImpl {
         .bind(reversal_transaction.requires_approval)
         .bind(reversal_transaction.approval_status.as_ref().map(|s| s.to_string()))
         .bind(reversal_transaction.risk_score)
+        .bind(reversal_transaction.degraded_flags)
+        .bind(reversal_transaction.idempotency_key.as_ref().map(|s| s.as_str()))
+        .bind(original_id)
         .fetch_one(&mut *tx)
         .await?;
 
+        sqlx::query(
+            "UPDATE transactions SET status = 'Reversed'::transaction_status, updated_at = NOW() WHERE id = $1"
+        )
+        .bind(original_id)
+        .execute(&mut *tx)
+        .await?;
+
+        // Undoing a posting is not account activity: last_activity_date stays where it was
+        sqlx::query(
+            r#"
+            UPDATE accounts
+            SET current_balance = current_balance + $2,
+                available_balance = available_balance + $2,
+                last_updated_at = NOW(),
+                version = version + 1
+            WHERE id = $1
+            "#
+        )
+        .bind(reversal_transaction.account_id)
+        .bind(delta)
+        .execute(&mut *tx)
+        .await?;
+
         tx.commit().await?;
 
         extract_transaction_from_row(&result)
@@ -743,7 +1087,8 @@ impl TransactionRepository for TransactionRepositoryImpl {
                    amount, currency, description, channel_id, terminal_id, agent_person_id,
                    transaction_date, value_date, status::text as status, reference_number,
                    external_reference, gl_code, requires_approval, approval_status::text as approval_status,
-                   risk_score, created_at
+                   risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                   original_amount, original_currency, exchange_rate, created_at
             FROM transactions
             WHERE channel_id = $1 AND value_date = $2 AND status IN ('Posted', 'Pending')
             ORDER BY transaction_date ASC
@@ -848,7 +1193,7 @@ impl TransactionRepository for TransactionRepositoryImpl {
                    aw.timeout_at, aw.created_at, aw.last_updated_at
             FROM account_workflows aw
             JOIN transaction_approvals ta ON aw.id = ta.workflow_id
-            WHERE ta.id = $1
+            WHERE ta.transaction_id = $1
             LIMIT 1
             "#
         )
@@ -1142,7 +1487,8 @@ impl TransactionRepository for TransactionRepositoryImpl {
                    amount, currency, description, channel_id, terminal_id, agent_person_id,
                    transaction_date, value_date, status::text as status, reference_number,
                    external_reference, gl_code, requires_approval, approval_status::text as approval_status,
-                   risk_score, created_at
+                   risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                   original_amount, original_currency, exchange_rate, created_at
             FROM transactions
             ORDER BY transaction_date DESC, id ASC
             LIMIT $1 OFFSET $2
@@ -1168,4 +1514,42 @@ impl TransactionRepository for TransactionRepositoryImpl {
 
         Ok(result.get("transaction_count"))
     }
+
+    async fn find_degraded(&self, flags_mask: i32, limit: i64) -> BankingResult<Vec<TransactionModel>> {
+        let results = sqlx::query(
+            r#"
+            SELECT id, account_id, transaction_code, transaction_type::text as transaction_type,
+                   amount, currency, description, channel_id, terminal_id, agent_person_id,
+                   transaction_date, value_date, status::text as status, reference_number,
+                   external_reference, gl_code, requires_approval, approval_status::text as approval_status,
+                   risk_score, degraded_flags, idempotency_key, reversal_of_transaction_id,
+                   original_amount, original_currency, exchange_rate, created_at
+            FROM transactions
+            WHERE degraded_flags & $1 <> 0
+            ORDER BY created_at ASC, id ASC
+            LIMIT $2
+            "#
+        )
+        .bind(flags_mask)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut transactions = Vec::new();
+        for row in results {
+            transactions.push(extract_transaction_from_row(&row)?);
+        }
+
+        Ok(transactions)
+    }
+
+    async fn update_degraded_flags(&self, transaction_id: Uuid, degraded_flags: i32) -> BankingResult<()> {
+        sqlx::query("UPDATE transactions SET degraded_flags = $2, updated_at = NOW() WHERE id = $1")
+            .bind(transaction_id)
+            .bind(degraded_flags)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -1,11 +1,14 @@
 use async_trait::async_trait;
-use banking_api::BankingResult;
+use banking_api::{BankingError, BankingResult};
 use banking_db::{
     models::person::{
         CountryIdxModelCache, CountrySubdivisionIdxModelCache, EntityReferenceIdxModelCache,
-        LocalityIdxModelCache, LocationIdxModelCache, PersonIdxModelCache,
+        IdxCachePolicy, LocalityIdxModelCache, LocationIdxModelCache, PersonIdxModelCache,
+    },
+    repository::{
+        InstrumentedRepository, NoopRepositoryMetrics, PersonRepos, RepositoryMetrics, TransactionAware,
+        UnitOfWork, UnitOfWorkSession,
     },
-    repository::{PersonRepos, TransactionAware, UnitOfWork, UnitOfWorkSession},
 };
 use once_cell::sync::OnceCell;
 use parking_lot::RwLock;
@@ -14,7 +17,8 @@ use std::sync::Arc;
 
 use crate::repository::{
     audit::audit_log_repository::AuditLogRepositoryImpl,
-    executor::Executor,
+    executor::{query_timeouts, set_local_statement_timeout, Executor},
+    person::contact_preference_repository::ContactPreferenceRepositoryImpl,
     person::country_repository::repo_impl::CountryRepositoryImpl,
     person::country_subdivision_repository::CountrySubdivisionRepositoryImpl,
     person::entity_reference_repository::EntityReferenceRepositoryImpl,
@@ -33,22 +37,39 @@ pub struct PersonCaches {
     pub entity_reference_idx_cache: Arc<RwLock<EntityReferenceIdxModelCache>>,
 }
 
+/// Policies of the person Idx caches that may be bounded or loaded lazily.
+/// The remaining caches are always loaded eagerly, as are these by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PersonCachePolicies {
+    pub entity_reference: IdxCachePolicy,
+}
+
 pub struct PostgresUnitOfWork {
     pool: Arc<PgPool>,
     caches: PersonCaches,
+    metrics: Arc<dyn RepositoryMetrics>,
 }
 
 impl PostgresUnitOfWork {
     pub async fn new(pool: Arc<PgPool>) -> Self {
+        Self::with_cache_policies(pool, PersonCachePolicies::default()).await
+    }
+
+    pub async fn with_cache_policies(pool: Arc<PgPool>, policies: PersonCachePolicies) -> Self {
         let executor = Executor::Pool(pool.clone());
 
         let country_idx_models = CountryRepositoryImpl::load_all_country_idx(&executor)
             .await
             .expect("Failed to load country index");
-        let country_idx_cache = Arc::new(RwLock::new(
-            CountryIdxModelCache::new(country_idx_models)
-                .expect("Failed to create country index cache"),
-        ));
+        let mut country_idx_cache = CountryIdxModelCache::new(country_idx_models)
+            .expect("Failed to create country index cache");
+        for country in CountryRepositoryImpl::load_all_countries(&executor)
+            .await
+            .expect("Failed to load countries")
+        {
+            country_idx_cache.add_names(country);
+        }
+        let country_idx_cache = Arc::new(RwLock::new(country_idx_cache));
 
         let country_subdivision_idx_models =
             CountrySubdivisionRepositoryImpl::load_all_country_subdivision_idx(&executor)
@@ -82,14 +103,13 @@ impl PostgresUnitOfWork {
             PersonIdxModelCache::new(person_idx_models).expect("Failed to create person index cache"),
         ));
 
-
-        let entity_reference_idx_models =
-            EntityReferenceRepositoryImpl::load_all_entity_reference_idx(&executor)
-                .await
-                .expect("Failed to load entity reference index");
         let entity_reference_idx_cache = Arc::new(RwLock::new(
-            EntityReferenceIdxModelCache::new(entity_reference_idx_models)
-                .expect("Failed to create entity reference index cache"),
+            EntityReferenceRepositoryImpl::load_entity_reference_idx_cache(
+                &executor,
+                policies.entity_reference,
+            )
+            .await
+            .expect("Failed to load entity reference index"),
         ));
 
         let caches = PersonCaches {
@@ -101,7 +121,17 @@ impl PostgresUnitOfWork {
             entity_reference_idx_cache,
         };
 
-        Self { pool, caches }
+        Self {
+            pool,
+            caches,
+            metrics: Arc::new(NoopRepositoryMetrics),
+        }
+    }
+
+    /// Reports every repository call of the sessions begun afterwards to `metrics`
+    pub fn with_metrics(mut self, metrics: Arc<dyn RepositoryMetrics>) -> Self {
+        self.metrics = metrics;
+        self
     }
 }
 
@@ -109,124 +139,201 @@ impl PostgresUnitOfWork {
 impl UnitOfWork<Postgres> for PostgresUnitOfWork {
     type Session = PostgresUnitOfWorkSession;
     async fn begin(&self) -> BankingResult<Self::Session> {
-        let tx = self.pool.begin().await?;
-        Ok(PostgresUnitOfWorkSession::new(tx, self.caches.clone()))
+        let mut tx = self.pool.begin().await?;
+        // Sessions default to the Interactive class; Executor::run overrides it per call.
+        set_local_statement_timeout(&mut tx, query_timeouts().interactive).await?;
+        Ok(PostgresUnitOfWorkSession::new(tx, self.caches.clone(), self.metrics.clone()))
+    }
+
+    async fn refresh_geo_idx_caches(&self) -> BankingResult<()> {
+        let executor = Executor::Pool(self.pool.clone());
+
+        let country_subdivision_idx_models =
+            CountrySubdivisionRepositoryImpl::load_all_country_subdivision_idx(&executor).await?;
+        let country_subdivision_idx_cache =
+            CountrySubdivisionIdxModelCache::new(country_subdivision_idx_models)
+                .map_err(|e| BankingError::Internal(e.to_string()))?;
+
+        let locality_idx_models = LocalityRepositoryImpl::load_all_locality_idx(&executor).await?;
+        let locality_idx_cache = LocalityIdxModelCache::new(locality_idx_models)
+            .map_err(|e| BankingError::Internal(e.to_string()))?;
+
+        *self.caches.country_subdivision_idx_cache.write() = country_subdivision_idx_cache;
+        *self.caches.locality_idx_cache.write() = locality_idx_cache;
+        Ok(())
     }
 }
 
 pub struct PostgresPersonRepos {
     executor: Executor,
     caches: PersonCaches,
-    persons: OnceCell<Arc<PersonRepositoryImpl>>,
-    countries: OnceCell<Arc<CountryRepositoryImpl>>,
-    country_subdivisions: OnceCell<Arc<CountrySubdivisionRepositoryImpl>>,
-    localities: OnceCell<Arc<LocalityRepositoryImpl>>,
-    locations: OnceCell<Arc<LocationRepositoryImpl>>,
-    entity_references: OnceCell<Arc<EntityReferenceRepositoryImpl>>,
+    metrics: Arc<dyn RepositoryMetrics>,
+    persons: OnceCell<Arc<InstrumentedRepository<PersonRepositoryImpl>>>,
+    countries: OnceCell<Arc<InstrumentedRepository<CountryRepositoryImpl>>>,
+    country_subdivisions: OnceCell<Arc<InstrumentedRepository<CountrySubdivisionRepositoryImpl>>>,
+    localities: OnceCell<Arc<InstrumentedRepository<LocalityRepositoryImpl>>>,
+    locations: OnceCell<Arc<InstrumentedRepository<LocationRepositoryImpl>>>,
+    entity_references: OnceCell<Arc<InstrumentedRepository<EntityReferenceRepositoryImpl>>>,
+    contact_preferences: OnceCell<Arc<InstrumentedRepository<ContactPreferenceRepositoryImpl>>>,
 }
 
 impl PostgresPersonRepos {
-    fn new(executor: Executor, caches: PersonCaches) -> Self {
+    fn new(executor: Executor, caches: PersonCaches, metrics: Arc<dyn RepositoryMetrics>) -> Self {
         Self {
             executor,
             caches,
+            metrics,
             persons: OnceCell::new(),
             countries: OnceCell::new(),
             country_subdivisions: OnceCell::new(),
             localities: OnceCell::new(),
             locations: OnceCell::new(),
             entity_references: OnceCell::new(),
+            contact_preferences: OnceCell::new(),
         }
     }
+
+    /// Repositories created so far, the only ones holding transaction-local state
+    fn initialized(&self) -> Vec<&dyn TransactionAware> {
+        let mut repos: Vec<&dyn TransactionAware> = Vec::new();
+        if let Some(persons) = self.persons.get() {
+            repos.push(persons.as_ref());
+        }
+        if let Some(countries) = self.countries.get() {
+            repos.push(countries.as_ref());
+        }
+        if let Some(country_subdivisions) = self.country_subdivisions.get() {
+            repos.push(country_subdivisions.as_ref());
+        }
+        if let Some(localities) = self.localities.get() {
+            repos.push(localities.as_ref());
+        }
+        if let Some(locations) = self.locations.get() {
+            repos.push(locations.as_ref());
+        }
+        if let Some(entity_references) = self.entity_references.get() {
+            repos.push(entity_references.as_ref());
+        }
+        if let Some(contact_preferences) = self.contact_preferences.get() {
+            repos.push(contact_preferences.as_ref());
+        }
+        repos
+    }
+
+    /// Wraps a repository of the session so its calls reach the unit of work's metrics
+    fn instrument<R>(&self, name: &'static str, repository: R) -> Arc<InstrumentedRepository<R>> {
+        Arc::new(InstrumentedRepository::new(name, Arc::new(repository), self.metrics.clone()))
+    }
 }
 
 impl PersonRepos<Postgres> for PostgresPersonRepos {
-    type PersonRepo = PersonRepositoryImpl;
-    type CountryRepo = CountryRepositoryImpl;
-    type CountrySubdivisionRepo = CountrySubdivisionRepositoryImpl;
-    type LocalityRepo = LocalityRepositoryImpl;
-    type LocationRepo = LocationRepositoryImpl;
-    type EntityReferenceRepo = EntityReferenceRepositoryImpl;
+    type PersonRepo = InstrumentedRepository<PersonRepositoryImpl>;
+    type CountryRepo = InstrumentedRepository<CountryRepositoryImpl>;
+    type CountrySubdivisionRepo = InstrumentedRepository<CountrySubdivisionRepositoryImpl>;
+    type LocalityRepo = InstrumentedRepository<LocalityRepositoryImpl>;
+    type LocationRepo = InstrumentedRepository<LocationRepositoryImpl>;
+    type EntityReferenceRepo = InstrumentedRepository<EntityReferenceRepositoryImpl>;
+    type ContactPreferenceRepo = InstrumentedRepository<ContactPreferenceRepositoryImpl>;
 
     fn persons(&self) -> &Self::PersonRepo {
         self.persons.get_or_init(|| {
-            Arc::new(PersonRepositoryImpl::new(
-                self.executor.clone(),
-                {
-                    self.locations();
-                    self.locations
-                        .get()
-                        .expect("Location repository not initialized")
-                        .clone()
-                },
-                self.caches.person_idx_cache.clone(),
-            ))
+            self.instrument(
+                "person",
+                PersonRepositoryImpl::new(
+                    self.executor.clone(),
+                    {
+                        self.locations();
+                        self.locations
+                            .get()
+                            .expect("Location repository not initialized")
+                            .inner()
+                            .clone()
+                    },
+                    self.caches.person_idx_cache.clone(),
+                ),
+            )
         })
     }
 
     fn countries(&self) -> &Self::CountryRepo {
         self.countries.get_or_init(|| {
-            Arc::new(CountryRepositoryImpl::new(
-                self.executor.clone(),
-                self.caches.country_idx_cache.clone(),
-            ))
+            self.instrument(
+                "country",
+                CountryRepositoryImpl::new(
+                    self.executor.clone(),
+                    self.caches.country_idx_cache.clone(),
+                ),
+            )
         })
     }
 
     fn country_subdivisions(&self) -> &Self::CountrySubdivisionRepo {
         self.country_subdivisions.get_or_init(|| {
-            Arc::new(CountrySubdivisionRepositoryImpl::new(
-                self.executor.clone(),
-                {
-                    self.countries();
-                    self.countries
-                        .get()
-                        .expect("Country repository not initialized")
-                        .clone()
-                },
-                self.caches.country_subdivision_idx_cache.clone(),
-            ))
+            self.instrument(
+                "country_subdivision",
+                CountrySubdivisionRepositoryImpl::new(
+                    self.executor.clone(),
+                    {
+                        self.countries();
+                        self.countries
+                            .get()
+                            .expect("Country repository not initialized")
+                            .inner()
+                            .clone()
+                    },
+                    self.caches.country_subdivision_idx_cache.clone(),
+                ),
+            )
         })
     }
 
     fn localities(&self) -> &Self::LocalityRepo {
         self.localities.get_or_init(|| {
-            Arc::new(LocalityRepositoryImpl::new(
-                self.executor.clone(),
-                {
-                    self.country_subdivisions();
-                    self.country_subdivisions
-                        .get()
-                        .expect("Country subdivision repository not initialized")
-                        .clone()
-                },
-                self.caches.locality_idx_cache.clone(),
-            ))
+            self.instrument(
+                "locality",
+                LocalityRepositoryImpl::new(
+                    self.executor.clone(),
+                    {
+                        self.country_subdivisions();
+                        self.country_subdivisions
+                            .get()
+                            .expect("Country subdivision repository not initialized")
+                            .inner()
+                            .clone()
+                    },
+                    self.caches.locality_idx_cache.clone(),
+                ),
+            )
         })
     }
 
     fn locations(&self) -> &Self::LocationRepo {
         let location_repo = self.locations.get_or_init(|| {
-            Arc::new(LocationRepositoryImpl::new(
-                self.executor.clone(),
-                {
-                    self.localities();
-                    self.localities
-                        .get()
-                        .expect("Locality repository not initialized")
-                        .clone()
-                },
-                self.caches.location_idx_cache.clone(),
-            ))
+            self.instrument(
+                "location",
+                LocationRepositoryImpl::new(
+                    self.executor.clone(),
+                    {
+                        self.localities();
+                        self.localities
+                            .get()
+                            .expect("Locality repository not initialized")
+                            .inner()
+                            .clone()
+                    },
+                    self.caches.location_idx_cache.clone(),
+                ),
+            )
         });
         let locality_repo = self
             .localities
             .get()
-            .expect("Locality repository not initialized");
+            .expect("Locality repository not initialized")
+            .inner();
         if locality_repo.location_repository.get().is_none() {
             locality_repo
                 .location_repository
-                .set(location_repo.clone())
+                .set(location_repo.inner().clone())
                 .ok();
         }
         location_repo
@@ -235,17 +342,40 @@ impl PersonRepos<Postgres> for PostgresPersonRepos {
 
     fn entity_references(&self) -> &Self::EntityReferenceRepo {
         self.entity_references.get_or_init(|| {
-            Arc::new(EntityReferenceRepositoryImpl::new(
-                self.executor.clone(),
-                {
-                    self.persons();
-                    self.persons
-                        .get()
-                        .expect("Person repository not initialized")
-                        .clone()
-                },
-                self.caches.entity_reference_idx_cache.clone(),
-            ))
+            self.instrument(
+                "entity_reference",
+                EntityReferenceRepositoryImpl::new(
+                    self.executor.clone(),
+                    {
+                        self.persons();
+                        self.persons
+                            .get()
+                            .expect("Person repository not initialized")
+                            .inner()
+                            .clone()
+                    },
+                    self.caches.entity_reference_idx_cache.clone(),
+                ),
+            )
+        })
+    }
+
+    fn contact_preferences(&self) -> &Self::ContactPreferenceRepo {
+        self.contact_preferences.get_or_init(|| {
+            self.instrument(
+                "contact_preference",
+                ContactPreferenceRepositoryImpl::new(
+                    self.executor.clone(),
+                    {
+                        self.persons();
+                        self.persons
+                            .get()
+                            .expect("Person repository not initialized")
+                            .inner()
+                            .clone()
+                    },
+                ),
+            )
         })
     }
 }
@@ -271,6 +401,9 @@ impl TransactionAware for PostgresPersonRepos {
         if let Some(entity_references) = self.entity_references.get() {
             entity_references.on_commit().await?;
         }
+        if let Some(contact_preferences) = self.contact_preferences.get() {
+            contact_preferences.on_commit().await?;
+        }
         Ok(())
     }
 
@@ -293,6 +426,30 @@ impl TransactionAware for PostgresPersonRepos {
         if let Some(entity_references) = self.entity_references.get() {
             entity_references.on_rollback().await?;
         }
+        if let Some(contact_preferences) = self.contact_preferences.get() {
+            contact_preferences.on_rollback().await?;
+        }
+        Ok(())
+    }
+
+    async fn on_savepoint(&self, name: &str) -> BankingResult<()> {
+        for repo in self.initialized() {
+            repo.on_savepoint(name).await?;
+        }
+        Ok(())
+    }
+
+    async fn on_rollback_to(&self, name: &str) -> BankingResult<()> {
+        for repo in self.initialized() {
+            repo.on_rollback_to(name).await?;
+        }
+        Ok(())
+    }
+
+    async fn on_release(&self, name: &str) -> BankingResult<()> {
+        for repo in self.initialized() {
+            repo.on_release(name).await?;
+        }
         Ok(())
     }
 }
@@ -306,19 +463,25 @@ impl TransactionAware for PostgresPersonRepos {
 pub struct PostgresUnitOfWorkSession {
     tx: crate::repository::executor::Executor,
     caches: PersonCaches,
-    audit_logs: OnceCell<Arc<AuditLogRepositoryImpl>>,
+    metrics: Arc<dyn RepositoryMetrics>,
+    audit_logs: OnceCell<Arc<InstrumentedRepository<AuditLogRepositoryImpl>>>,
     person_repos: OnceCell<Arc<PostgresPersonRepos>>,
     observers: Arc<RwLock<Vec<Arc<dyn TransactionAware>>>>,
 }
 
 impl PostgresUnitOfWorkSession {
-    pub fn new(tx: Transaction<'static, Postgres>, caches: PersonCaches) -> Self {
+    pub fn new(
+        tx: Transaction<'static, Postgres>,
+        caches: PersonCaches,
+        metrics: Arc<dyn RepositoryMetrics>,
+    ) -> Self {
         let executor =
             crate::repository::executor::Executor::Tx(Arc::new(tokio::sync::Mutex::new(tx)));
 
         Self {
             tx: executor,
             caches,
+            metrics,
             audit_logs: OnceCell::new(),
             person_repos: OnceCell::new(),
             observers: Arc::new(RwLock::new(Vec::new())),
@@ -328,20 +491,27 @@ impl PostgresUnitOfWorkSession {
 
 #[async_trait]
 impl UnitOfWorkSession<Postgres> for PostgresUnitOfWorkSession {
-    type AuditLogRepo = AuditLogRepositoryImpl;
+    type AuditLogRepo = InstrumentedRepository<AuditLogRepositoryImpl>;
     type PersonRepos = PostgresPersonRepos;
 
     fn audit_logs(&self) -> &Self::AuditLogRepo {
         self.audit_logs.get_or_init(|| {
-            Arc::new(AuditLogRepositoryImpl::new(self.tx.clone()))
+            Arc::new(InstrumentedRepository::new(
+                "audit_log",
+                Arc::new(AuditLogRepositoryImpl::new(self.tx.clone())),
+                self.metrics.clone(),
+            ))
         })
     }
 
     fn person_repos(&self) -> &Self::PersonRepos {
+        let mut created = false;
         let person_repos = self.person_repos.get_or_init(|| {
+            created = true;
             Arc::new(PostgresPersonRepos::new(
                 self.tx.clone(),
                 self.caches.clone(),
+                self.metrics.clone(),
             ))
         });
 
@@ -353,14 +523,19 @@ impl UnitOfWorkSession<Postgres> for PostgresUnitOfWorkSession {
         let cs_repo = person_repos
             .country_subdivisions
             .get()
-            .expect("Country subdivision repository not initialized");
+            .expect("Country subdivision repository not initialized")
+            .inner();
         let l_repo = person_repos
             .localities
             .get()
-            .expect("Locality repository not initialized");
+            .expect("Locality repository not initialized")
+            .inner();
         cs_repo.locality_repository.set(l_repo.clone()).ok();
 
-        self.register_transaction_aware(person_repos.clone());
+        // Registered once, so each savepoint is snapshotted once per cache
+        if created {
+            self.register_transaction_aware(person_repos.clone());
+        }
         person_repos
     }
 
@@ -368,13 +543,35 @@ impl UnitOfWorkSession<Postgres> for PostgresUnitOfWorkSession {
         self.observers.write().push(observer);
     }
 
-    async fn commit(self) -> BankingResult<()> {
-        if let crate::repository::executor::Executor::Tx(tx_arc) = self.tx {
-            let tx = Arc::try_unwrap(tx_arc)
-                .expect("Cannot commit transaction with multiple references")
-                .into_inner();
-            tx.commit().await?;
+    async fn savepoint(&self, name: &str) -> BankingResult<()> {
+        self.tx.savepoint(name).await?;
+        let observers = self.observers.read().clone();
+        for observer in observers.iter() {
+            observer.on_savepoint(name).await?;
+        }
+        Ok(())
+    }
+
+    async fn rollback_to(&self, name: &str) -> BankingResult<()> {
+        self.tx.rollback_to_savepoint(name).await?;
+        let observers = self.observers.read().clone();
+        for observer in observers.iter() {
+            observer.on_rollback_to(name).await?;
+        }
+        Ok(())
+    }
+
+    async fn release(&self, name: &str) -> BankingResult<()> {
+        self.tx.release_savepoint(name).await?;
+        let observers = self.observers.read().clone();
+        for observer in observers.iter() {
+            observer.on_release(name).await?;
         }
+        Ok(())
+    }
+
+    async fn commit(self) -> BankingResult<()> {
+        self.tx.commit().await?;
         let observers = self.observers.read().clone();
         for observer in observers.iter() {
             observer.on_commit().await?;
@@ -383,16 +580,141 @@ impl UnitOfWorkSession<Postgres> for PostgresUnitOfWorkSession {
     }
 
     async fn rollback(self) -> BankingResult<()> {
-        if let crate::repository::executor::Executor::Tx(tx_arc) = self.tx {
-            let tx = Arc::try_unwrap(tx_arc)
-                .expect("Cannot rollback transaction with multiple references")
-                .into_inner();
-            tx.rollback().await?;
-        }
+        self.tx.rollback().await?;
         let observers = self.observers.read().clone();
         for observer in observers.iter() {
             observer.on_rollback().await?;
         }
         Ok(())
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use banking_api::command::person::{
+        GeoDataset, GeoImportReport, GeoLocalityRecord, GeoSubdivisionRecord, ImportGeoDataCommand,
+    };
+    use banking_db::repository::{
+        CountryRepository, CountrySubdivisionRepository, LocalityRepository, PersonRepository,
+        PersonRepos, UnitOfWork, UnitOfWorkSession,
+    };
+    use banking_logic::commands::geo_import::import_geo_data;
+    use crate::repository::person::test_helpers::{
+        create_test_country_model, create_test_country_subdivision_model, create_test_person_model,
+    };
+    use crate::test_helper::setup_shared_uow;
+    use uuid::Uuid;
+
+    fn subdivision(country_iso2: &str, code: &str, name: &str, localities: &[&str]) -> GeoSubdivisionRecord {
+        GeoSubdivisionRecord {
+            country_iso2: country_iso2.to_string(),
+            code: code.to_string(),
+            name_l1: name.to_string(),
+            name_l2: None,
+            name_l3: None,
+            localities: localities
+                .iter()
+                .map(|code| GeoLocalityRecord {
+                    code: code.to_string(),
+                    name_l1: code.to_string(),
+                    name_l2: None,
+                    name_l3: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_import_geo_data_upserts_and_rejects_bad_rows() {
+        let (uow, _schema) = setup_shared_uow().await.unwrap();
+        let country = create_test_country_model("CM", "Cameroon");
+        let centre = create_test_country_subdivision_model(country.id, "CM-CE", "Centre");
+
+        let session = uow.begin().await.unwrap();
+        session.person_repos().countries().save(country.clone()).await.unwrap();
+        session
+            .person_repos()
+            .country_subdivisions()
+            .save(centre.clone())
+            .await
+            .unwrap();
+        session.commit().await.unwrap();
+
+        let dataset = GeoDataset {
+            subdivisions: vec![
+                subdivision("CM", "CM-CE", "Centre Region", &["YAOUNDE"]),
+                subdivision("CM", "CM-LT", "Littoral", &["DOUALA"]),
+                subdivision("CM", "CM-LT", "Littoral Again", &["EDEA"]),
+                subdivision("ZZ", "ZZ-01", "Nowhere", &["NOWHERE"]),
+            ],
+        };
+        let mut command = ImportGeoDataCommand::new(dataset, Uuid::new_v4());
+        command.chunk_size = 1;
+
+        let session = uow.begin().await.unwrap();
+        let report: GeoImportReport = import_geo_data(&session, &command).await.unwrap();
+        session.commit().await.unwrap();
+        uow.refresh_geo_idx_caches().await.unwrap();
+
+        assert_eq!(report.created, 3);
+        assert_eq!(report.updated, 1);
+        assert_eq!(report.skipped, 4);
+        let rejected: Vec<&str> = report.rejected.iter().map(|r| r.code.as_str()).collect();
+        assert_eq!(rejected, vec!["CM-LT", "EDEA", "ZZ-01", "NOWHERE"]);
+
+        let session = uow.begin().await.unwrap();
+        let subdivisions = session.person_repos().country_subdivisions();
+        let updated = subdivisions.load(centre.id).await.unwrap();
+        assert_eq!(updated.name_l1.as_str(), "Centre Region");
+        let littoral = subdivisions.find_by_code(country.id, "CM-LT").await.unwrap().unwrap();
+        let localities = session.person_repos().localities();
+        let douala = localities.find_by_code(country.id, "DOUALA").await.unwrap().unwrap();
+        assert_eq!(douala.country_subdivision_id, littoral.country_subdivision_id);
+        assert!(localities.find_by_code(country.id, "EDEA").await.unwrap().is_none());
+
+        // Importing the same rows again finds nothing to write
+        let report = import_geo_data(&session, &command).await.unwrap();
+        assert_eq!((report.created, report.updated), (0, 0));
+        assert_eq!(report.skipped, 8);
+        session.rollback().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rollback_to_savepoint_discards_later_work() {
+        let (uow, schema) = setup_shared_uow().await.unwrap();
+        let audit_log_id = Uuid::new_v4();
+        let first = create_test_person_model("First Person");
+        let second = create_test_person_model("Second Person");
+        let third = create_test_person_model("Third Person");
+
+        let session = uow.begin().await.unwrap();
+        let persons = session.person_repos().persons();
+        persons.save(first.clone(), audit_log_id).await.unwrap();
+        persons.save(second.clone(), audit_log_id).await.unwrap();
+
+        session.savepoint("before_third").await.unwrap();
+        persons.save(third.clone(), audit_log_id).await.unwrap();
+        assert!(persons.find_by_id(third.id).await.unwrap().is_some());
+
+        session.rollback_to("before_third").await.unwrap();
+        assert!(persons.find_by_id(third.id).await.unwrap().is_none());
+        assert!(persons.find_by_id(second.id).await.unwrap().is_some());
+        session.release("before_third").await.unwrap();
+        session.commit().await.unwrap();
+
+        let persisted: Vec<Uuid> = sqlx::query_scalar("SELECT id FROM person WHERE id = ANY($1)")
+            .bind(vec![first.id, second.id, third.id])
+            .fetch_all(&*schema.pool())
+            .await
+            .unwrap();
+        assert_eq!(persisted.len(), 2);
+        assert!(!persisted.contains(&third.id));
+
+        // The shared cache only received the changes that were committed
+        let session = uow.begin().await.unwrap();
+        let persons = session.person_repos().persons();
+        assert!(persons.find_by_id(first.id).await.unwrap().is_some());
+        assert!(persons.find_by_id(second.id).await.unwrap().is_some());
+        assert!(persons.find_by_id(third.id).await.unwrap().is_none());
+        session.rollback().await.unwrap();
+    }
+}
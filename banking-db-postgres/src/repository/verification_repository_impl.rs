@@ -0,0 +1,200 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{DbChallengeStatus, DbVerificationMethod, DbVerificationOperationKind, VerificationChallengeModel};
+use banking_db::repository::VerificationRepository;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of VerificationRepository
+pub struct VerificationRepositoryImpl {
+    pool: PgPool,
+}
+
+impl VerificationRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+impl TryFromRow<PgRow> for VerificationChallengeModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(VerificationChallengeModel {
+            id: row.get("id"),
+            customer_id: row.get("customer_id"),
+            operation_kind: row.get::<String, _>("operation_kind")
+                .parse::<DbVerificationOperationKind>()
+                .map_err(|_| BankingError::Internal("Invalid verification operation kind".to_string()))?,
+            channel_id: row.get("channel_id"),
+            code: HeaplessString::try_from(row.get::<String, _>("code").as_str())
+                .map_err(|_| BankingError::Internal("Verification code too long".to_string()))?,
+            status: row.get::<String, _>("status")
+                .parse::<DbChallengeStatus>()
+                .map_err(|_| BankingError::Internal("Invalid challenge status".to_string()))?,
+            failed_attempts: row.get("failed_attempts"),
+            last_failed_at: row.get("last_failed_at"),
+            created_at: row.get("created_at"),
+            expires_at: row.get("expires_at"),
+            verified_method: row
+                .get::<Option<String>, _>("verified_method")
+                .map(|method| method
+                    .parse::<DbVerificationMethod>()
+                    .map_err(|_| BankingError::Internal("Invalid verification method".to_string())))
+                .transpose()?,
+            verified_by_person_id: row.get("verified_by_person_id"),
+            verified_at: row.get("verified_at"),
+            consumed_at: row.get("consumed_at"),
+        })
+    }
+}
+
+const CHALLENGE_COLUMNS: &str = r#"
+    id, customer_id, operation_kind::text as operation_kind, channel_id, code,
+    status::text as status, failed_attempts, last_failed_at, created_at, expires_at,
+    verified_method::text as verified_method, verified_by_person_id, verified_at, consumed_at
+"#;
+
+#[async_trait]
+impl VerificationRepository for VerificationRepositoryImpl {
+    async fn create_challenge(&self, challenge: VerificationChallengeModel) -> BankingResult<VerificationChallengeModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO verification_challenges (
+                id, customer_id, operation_kind, channel_id, code, status, failed_attempts,
+                last_failed_at, created_at, expires_at, verified_method, verified_by_person_id,
+                verified_at, consumed_at
+            )
+            VALUES ($1, $2, $3::verification_operation_kind, $4, $5, $6::challenge_status, $7, $8, $9, $10,
+                    $11::verification_method, $12, $13, $14)
+            RETURNING {CHALLENGE_COLUMNS}
+            "#
+        ))
+        .bind(challenge.id)
+        .bind(challenge.customer_id)
+        .bind(challenge.operation_kind)
+        .bind(challenge.channel_id)
+        .bind(challenge.code.as_str())
+        .bind(challenge.status)
+        .bind(challenge.failed_attempts)
+        .bind(challenge.last_failed_at)
+        .bind(challenge.created_at)
+        .bind(challenge.expires_at)
+        .bind(challenge.verified_method)
+        .bind(challenge.verified_by_person_id)
+        .bind(challenge.verified_at)
+        .bind(challenge.consumed_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create verification challenge: {e}")))?;
+
+        VerificationChallengeModel::try_from_row(&row)
+    }
+
+    async fn update_challenge(&self, challenge: VerificationChallengeModel) -> BankingResult<VerificationChallengeModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE verification_challenges
+            SET status = $2::challenge_status, failed_attempts = $3, last_failed_at = $4,
+                verified_method = $5::verification_method, verified_by_person_id = $6,
+                verified_at = $7, consumed_at = $8
+            WHERE id = $1
+            RETURNING {CHALLENGE_COLUMNS}
+            "#
+        ))
+        .bind(challenge.id)
+        .bind(challenge.status)
+        .bind(challenge.failed_attempts)
+        .bind(challenge.last_failed_at)
+        .bind(challenge.verified_method)
+        .bind(challenge.verified_by_person_id)
+        .bind(challenge.verified_at)
+        .bind(challenge.consumed_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to update verification challenge: {e}")))?;
+
+        VerificationChallengeModel::try_from_row(&row)
+    }
+
+    async fn find_challenge_by_id(&self, challenge_id: Uuid) -> BankingResult<Option<VerificationChallengeModel>> {
+        let row = sqlx::query(&format!("SELECT {CHALLENGE_COLUMNS} FROM verification_challenges WHERE id = $1"))
+            .bind(challenge_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find verification challenge: {e}")))?;
+
+        row.as_ref().map(VerificationChallengeModel::try_from_row).transpose()
+    }
+
+    async fn find_challenges_created_since(
+        &self,
+        customer_id: Uuid,
+        operation_kind: DbVerificationOperationKind,
+        since: DateTime<Utc>,
+    ) -> BankingResult<Vec<VerificationChallengeModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {CHALLENGE_COLUMNS}
+            FROM verification_challenges
+            WHERE customer_id = $1 AND operation_kind = $2::verification_operation_kind AND created_at >= $3
+            ORDER BY created_at
+            "#
+        ))
+        .bind(customer_id)
+        .bind(operation_kind)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find verification challenges: {e}")))?;
+
+        rows.iter().map(VerificationChallengeModel::try_from_row).collect()
+    }
+
+    async fn find_latest_verified_challenge(
+        &self,
+        customer_id: Uuid,
+        operation_kind: DbVerificationOperationKind,
+    ) -> BankingResult<Option<VerificationChallengeModel>> {
+        let row = sqlx::query(&format!(
+            r#"
+            SELECT {CHALLENGE_COLUMNS}
+            FROM verification_challenges
+            WHERE customer_id = $1 AND operation_kind = $2::verification_operation_kind AND status = 'Verified'
+            ORDER BY verified_at DESC
+            LIMIT 1
+            "#
+        ))
+        .bind(customer_id)
+        .bind(operation_kind)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find verified challenge: {e}")))?;
+
+        row.as_ref().map(VerificationChallengeModel::try_from_row).transpose()
+    }
+
+    async fn consume_challenge(&self, challenge_id: Uuid, consumed_at: DateTime<Utc>) -> BankingResult<Option<VerificationChallengeModel>> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE verification_challenges
+            SET status = 'Consumed', consumed_at = $2
+            WHERE id = $1 AND status = 'Verified'
+            RETURNING {CHALLENGE_COLUMNS}
+            "#
+        ))
+        .bind(challenge_id)
+        .bind(consumed_at)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to consume verification challenge: {e}")))?;
+
+        row.as_ref().map(VerificationChallengeModel::try_from_row).transpose()
+    }
+}
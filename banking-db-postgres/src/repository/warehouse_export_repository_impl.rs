@@ -0,0 +1,218 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult, QueryClass};
+use banking_db::models::{
+    DbWarehouseEntity, DbWarehouseExportMode, WarehouseChangeModel, WarehouseExportRunModel, WarehouseTombstoneModel,
+};
+use banking_db::repository::WarehouseExportRepository;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row, postgres::PgRow};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::repository::executor::Executor;
+
+/// PostgreSQL implementation of WarehouseExportRepository
+pub struct WarehouseExportRepositoryImpl {
+    pool: PgPool,
+    /// Runs the long scans under the `Analytical` statement timeout
+    executor: Executor,
+}
+
+impl WarehouseExportRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        let executor = Executor::Pool(Arc::new(pool.clone()));
+        Self { pool, executor }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+impl TryFromRow<PgRow> for WarehouseTombstoneModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(WarehouseTombstoneModel {
+            id: row.get("id"),
+            entity: row.get::<String, _>("entity")
+                .parse::<DbWarehouseEntity>()
+                .map_err(|_| BankingError::Internal("Invalid warehouse entity".to_string()))?,
+            entity_id: row.get("entity_id"),
+            deleted_at: row.get("deleted_at"),
+        })
+    }
+}
+
+impl TryFromRow<PgRow> for WarehouseExportRunModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(WarehouseExportRunModel {
+            id: row.get("id"),
+            mode: row.get::<String, _>("mode")
+                .parse::<DbWarehouseExportMode>()
+                .map_err(|_| BankingError::Internal("Invalid warehouse export mode".to_string()))?,
+            schema_version: row.get("schema_version"),
+            from_watermark: row.get("from_watermark"),
+            to_watermark: row.get("to_watermark"),
+            total_rows: row.get("total_rows"),
+            completed_at: row.get("completed_at"),
+        })
+    }
+}
+
+/// Table and change-tracking timestamp of each exported entity. Transactions
+/// only carry `updated_at` once changed after posting.
+fn change_source(entity: DbWarehouseEntity) -> (&'static str, &'static str) {
+    match entity {
+        DbWarehouseEntity::Account => ("accounts", "t.last_updated_at"),
+        DbWarehouseEntity::Transaction => ("transactions", "COALESCE(t.updated_at, t.created_at)"),
+        DbWarehouseEntity::Customer => ("customers", "t.last_updated_at"),
+        DbWarehouseEntity::Workflow => ("account_workflows", "t.last_updated_at"),
+    }
+}
+
+const TOMBSTONE_COLUMNS: &str = "id, entity::text as entity, entity_id, deleted_at";
+
+const EXPORT_RUN_COLUMNS: &str = r#"
+    id, mode::text as mode, schema_version, from_watermark, to_watermark, total_rows, completed_at
+"#;
+
+#[async_trait]
+impl WarehouseExportRepository for WarehouseExportRepositoryImpl {
+    async fn find_changed_rows(
+        &self,
+        entity: DbWarehouseEntity,
+        from: Option<DateTime<Utc>>,
+        to: DateTime<Utc>,
+        after: Option<(DateTime<Utc>, Uuid)>,
+        limit: i64,
+    ) -> BankingResult<Vec<WarehouseChangeModel>> {
+        let (table, changed_at) = change_source(entity);
+        let rows = self
+            .executor
+            .run(QueryClass::Analytical, move |conn| {
+                Box::pin(async move {
+                    sqlx::query(&format!(
+                        r#"
+                        SELECT t.id as entity_id, {changed_at} as changed_at, to_jsonb(t) as row
+                        FROM {table} t
+                        WHERE ($1::timestamptz IS NULL OR {changed_at} > $1)
+                          AND {changed_at} <= $2
+                          AND ($3::timestamptz IS NULL OR ({changed_at}, t.id) > ($3, $4))
+                        ORDER BY {changed_at}, t.id
+                        LIMIT $5
+                        "#
+                    ))
+                    .bind(from)
+                    .bind(to)
+                    .bind(after.map(|(changed_at, _)| changed_at))
+                    .bind(after.map(|(_, id)| id))
+                    .bind(limit)
+                    .fetch_all(conn)
+                    .await
+                })
+            })
+            .await?;
+
+        Ok(rows
+            .iter()
+            .map(|row| WarehouseChangeModel {
+                entity_id: row.get("entity_id"),
+                changed_at: row.get("changed_at"),
+                row: row.get("row"),
+            })
+            .collect())
+    }
+
+    async fn find_tombstones(
+        &self,
+        entity: DbWarehouseEntity,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        after: Option<(DateTime<Utc>, Uuid)>,
+        limit: i64,
+    ) -> BankingResult<Vec<WarehouseTombstoneModel>> {
+        let rows = self
+            .executor
+            .run(QueryClass::Analytical, move |conn| {
+                Box::pin(async move {
+                    sqlx::query(&format!(
+                        r#"
+                        SELECT {TOMBSTONE_COLUMNS}
+                        FROM warehouse_tombstones
+                        WHERE entity = $1::warehouse_entity
+                          AND deleted_at > $2 AND deleted_at <= $3
+                          AND ($4::timestamptz IS NULL OR (deleted_at, id) > ($4, $5))
+                        ORDER BY deleted_at, id
+                        LIMIT $6
+                        "#
+                    ))
+                    .bind(entity)
+                    .bind(from)
+                    .bind(to)
+                    .bind(after.map(|(deleted_at, _)| deleted_at))
+                    .bind(after.map(|(_, id)| id))
+                    .bind(limit)
+                    .fetch_all(conn)
+                    .await
+                })
+            })
+            .await?;
+
+        rows.iter().map(WarehouseTombstoneModel::try_from_row).collect()
+    }
+
+    async fn create_tombstone(&self, tombstone: WarehouseTombstoneModel) -> BankingResult<WarehouseTombstoneModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO warehouse_tombstones (id, entity, entity_id, deleted_at)
+            VALUES ($1, $2::warehouse_entity, $3, $4)
+            RETURNING {TOMBSTONE_COLUMNS}
+            "#
+        ))
+        .bind(tombstone.id)
+        .bind(tombstone.entity)
+        .bind(tombstone.entity_id)
+        .bind(tombstone.deleted_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create warehouse tombstone: {e}")))?;
+
+        WarehouseTombstoneModel::try_from_row(&row)
+    }
+
+    async fn create_export_run(&self, run: WarehouseExportRunModel) -> BankingResult<WarehouseExportRunModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO warehouse_export_runs (
+                id, mode, schema_version, from_watermark, to_watermark, total_rows, completed_at
+            )
+            VALUES ($1, $2::warehouse_export_mode, $3, $4, $5, $6, $7)
+            RETURNING {EXPORT_RUN_COLUMNS}
+            "#
+        ))
+        .bind(run.id)
+        .bind(run.mode)
+        .bind(run.schema_version)
+        .bind(run.from_watermark)
+        .bind(run.to_watermark)
+        .bind(run.total_rows)
+        .bind(run.completed_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to record warehouse export run: {e}")))?;
+
+        WarehouseExportRunModel::try_from_row(&row)
+    }
+
+    async fn find_latest_export_run(&self) -> BankingResult<Option<WarehouseExportRunModel>> {
+        let row = sqlx::query(&format!(
+            "SELECT {EXPORT_RUN_COLUMNS} FROM warehouse_export_runs ORDER BY completed_at DESC LIMIT 1"
+        ))
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find latest warehouse export run: {e}")))?;
+
+        row.as_ref().map(WarehouseExportRunModel::try_from_row).transpose()
+    }
+}
@@ -0,0 +1,212 @@
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::{WorkflowEscalationModel, WorkflowEscalationStatusModel};
+use banking_db::repository::WorkflowEscalationRepository;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use sqlx::{PgPool, Row, postgres::PgRow};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// PostgreSQL implementation of WorkflowEscalationRepository
+pub struct WorkflowEscalationRepositoryImpl {
+    pool: PgPool,
+}
+
+impl WorkflowEscalationRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+trait TryFromRow<R> {
+    fn try_from_row(row: &R) -> BankingResult<Self>
+    where
+        Self: Sized;
+}
+
+impl TryFromRow<PgRow> for WorkflowEscalationModel {
+    fn try_from_row(row: &PgRow) -> BankingResult<Self> {
+        Ok(WorkflowEscalationModel {
+            id: row.get("id"),
+            workflow_id: row.get("workflow_id"),
+            escalated_to_person_id: row.get("escalated_to_person_id"),
+            level: row.get("level"),
+            status: WorkflowEscalationStatusModel::from_str(&row.get::<String, _>("status"))
+                .map_err(|e| BankingError::ValidationError {
+                    field: "status".to_string(),
+                    message: e,
+                })?,
+            created_at: row.get("created_at"),
+            action_due_at: row.get("action_due_at"),
+            acknowledged_at: row.get("acknowledged_at"),
+            resolved_at: row.get("resolved_at"),
+            resolved_by_person_id: row.get("resolved_by_person_id"),
+            resolution_notes: row
+                .get::<Option<String>, _>("resolution_notes")
+                .map(|notes| HeaplessString::try_from(notes.as_str()))
+                .transpose()
+                .map_err(|_| BankingError::ValidationError {
+                    field: "resolution_notes".to_string(),
+                    message: "Resolution notes too long".to_string(),
+                })?,
+            last_updated_at: row.get("last_updated_at"),
+        })
+    }
+}
+
+const ESCALATION_COLUMNS: &str = r#"
+    id, workflow_id, escalated_to_person_id, level, status::text as status, created_at, action_due_at,
+    acknowledged_at, resolved_at, resolved_by_person_id, resolution_notes, last_updated_at
+"#;
+
+#[async_trait]
+impl WorkflowEscalationRepository for WorkflowEscalationRepositoryImpl {
+    async fn create_escalation(&self, escalation: WorkflowEscalationModel) -> BankingResult<WorkflowEscalationModel> {
+        let row = sqlx::query(&format!(
+            r#"
+            INSERT INTO workflow_escalations (
+                id, workflow_id, escalated_to_person_id, level, status, created_at, action_due_at,
+                acknowledged_at, resolved_at, resolved_by_person_id, resolution_notes, last_updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5::workflow_escalation_status, $6, $7, $8, $9, $10, $11, $12)
+            RETURNING {ESCALATION_COLUMNS}
+            "#
+        ))
+        .bind(escalation.id)
+        .bind(escalation.workflow_id)
+        .bind(escalation.escalated_to_person_id)
+        .bind(escalation.level)
+        .bind(escalation.status.to_string())
+        .bind(escalation.created_at)
+        .bind(escalation.action_due_at)
+        .bind(escalation.acknowledged_at)
+        .bind(escalation.resolved_at)
+        .bind(escalation.resolved_by_person_id)
+        .bind(escalation.resolution_notes.as_ref().map(|notes| notes.as_str()))
+        .bind(escalation.last_updated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to create workflow escalation: {e}")))?;
+
+        WorkflowEscalationModel::try_from_row(&row)
+    }
+
+    async fn find_escalation_by_id(&self, escalation_id: Uuid) -> BankingResult<Option<WorkflowEscalationModel>> {
+        let row = sqlx::query(&format!("SELECT {ESCALATION_COLUMNS} FROM workflow_escalations WHERE id = $1"))
+            .bind(escalation_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to find workflow escalation: {e}")))?;
+
+        row.as_ref().map(WorkflowEscalationModel::try_from_row).transpose()
+    }
+
+    async fn find_escalations_by_workflow(&self, workflow_id: Uuid) -> BankingResult<Vec<WorkflowEscalationModel>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {ESCALATION_COLUMNS} FROM workflow_escalations WHERE workflow_id = $1 ORDER BY level"
+        ))
+        .bind(workflow_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find workflow escalations: {e}")))?;
+
+        rows.iter().map(WorkflowEscalationModel::try_from_row).collect()
+    }
+
+    async fn find_open_escalations_by_person(&self, person_id: Uuid) -> BankingResult<Vec<WorkflowEscalationModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {ESCALATION_COLUMNS} FROM workflow_escalations
+            WHERE escalated_to_person_id = $1 AND status IN ('Open', 'Acknowledged')
+            ORDER BY created_at
+            "#
+        ))
+        .bind(person_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find open workflow escalations: {e}")))?;
+
+        rows.iter().map(WorkflowEscalationModel::try_from_row).collect()
+    }
+
+    async fn find_overdue_escalations(&self, reference_time: DateTime<Utc>, max_level: i16) -> BankingResult<Vec<WorkflowEscalationModel>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {ESCALATION_COLUMNS} FROM workflow_escalations
+            WHERE status = 'Open' AND action_due_at <= $1 AND level < $2
+            ORDER BY action_due_at
+            "#
+        ))
+        .bind(reference_time)
+        .bind(max_level)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to find overdue workflow escalations: {e}")))?;
+
+        rows.iter().map(WorkflowEscalationModel::try_from_row).collect()
+    }
+
+    async fn acknowledge_escalation(&self, escalation_id: Uuid, acknowledged_at: DateTime<Utc>) -> BankingResult<Option<WorkflowEscalationModel>> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE workflow_escalations
+            SET status = 'Acknowledged', acknowledged_at = $2, last_updated_at = $2
+            WHERE id = $1 AND status = 'Open'
+            RETURNING {ESCALATION_COLUMNS}
+            "#
+        ))
+        .bind(escalation_id)
+        .bind(acknowledged_at)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to acknowledge workflow escalation: {e}")))?;
+
+        row.as_ref().map(WorkflowEscalationModel::try_from_row).transpose()
+    }
+
+    async fn resolve_escalation(
+        &self,
+        escalation_id: Uuid,
+        resolved_by_person_id: Uuid,
+        resolution_notes: Option<HeaplessString<500>>,
+        resolved_at: DateTime<Utc>,
+    ) -> BankingResult<Option<WorkflowEscalationModel>> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE workflow_escalations
+            SET status = 'Resolved', resolved_by_person_id = $2, resolution_notes = $3,
+                resolved_at = $4, last_updated_at = $4
+            WHERE id = $1 AND status IN ('Open', 'Acknowledged')
+            RETURNING {ESCALATION_COLUMNS}
+            "#
+        ))
+        .bind(escalation_id)
+        .bind(resolved_by_person_id)
+        .bind(resolution_notes.as_ref().map(|notes| notes.as_str()))
+        .bind(resolved_at)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to resolve workflow escalation: {e}")))?;
+
+        row.as_ref().map(WorkflowEscalationModel::try_from_row).transpose()
+    }
+
+    async fn mark_escalated(&self, escalation_id: Uuid, escalated_at: DateTime<Utc>) -> BankingResult<Option<WorkflowEscalationModel>> {
+        let row = sqlx::query(&format!(
+            r#"
+            UPDATE workflow_escalations
+            SET status = 'Escalated', last_updated_at = $2
+            WHERE id = $1 AND status = 'Open'
+            RETURNING {ESCALATION_COLUMNS}
+            "#
+        ))
+        .bind(escalation_id)
+        .bind(escalated_at)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to mark workflow escalation escalated: {e}")))?;
+
+        row.as_ref().map(WorkflowEscalationModel::try_from_row).transpose()
+    }
+}
@@ -1,8 +1,11 @@
 use async_trait::async_trait;
 use banking_api::{BankingResult, BankingError};
 use banking_db::models::{AccountWorkflowModel, WorkflowStepRecordModel, WorkflowTypeModel, WorkflowStepModel, WorkflowStatusModel};
-use banking_db::repository::{WorkflowRepository, WorkflowMetricsReport, WorkflowPerformanceReport, WorkflowBottleneckReport};
-use sqlx::{PgPool, Row};
+use banking_db::repository::{
+    WorkflowRepository, WorkflowFilter, WorkflowPage, WorkflowSearchResult, WorkflowMetricsReport, WorkflowPerformanceReport,
+    WorkflowBottleneckReport, WorkflowTypeMetrics,
+};
+use sqlx::{PgPool, Row, postgres::{PgArguments, PgRow, Postgres}, query::Query};
 use uuid::Uuid;
 use chrono::{DateTime, Utc, NaiveDate};
 use heapless::String as HeaplessString;
@@ -12,10 +15,112 @@ pub struct WorkflowRepositoryImpl {
     pool: PgPool,
 }
 
+/// Hours from initiation to completion
+const COMPLETION_HOURS: &str = "EXTRACT(EPOCH FROM completed_at - initiated_at)::float8 / 3600.0";
+
+/// Initiated within the period bound to $1 and $2
+const IN_PERIOD: &str = "(initiated_at AT TIME ZONE 'UTC')::date BETWEEN $1 AND $2";
+
 impl WorkflowRepositoryImpl {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    /// Workflows matching `filter`, newest first; no limit returns every match
+    async fn select_workflows(&self, filter: &WorkflowFilter, offset: i64, limit: Option<i64>) -> BankingResult<Vec<AccountWorkflowModel>> {
+        let where_clause = WorkflowWhereClause::new(filter);
+        let offset_placeholder = where_clause.params.len() + 1;
+        let sql = format!(
+            r#"
+            SELECT id, account_id, workflow_type::text, current_step, status,
+                   initiated_by, initiated_at, completed_at, next_action_required, timeout_at,
+                   created_at, last_updated_at
+            FROM account_workflows
+            WHERE {}
+            ORDER BY created_at DESC, id
+            OFFSET ${} LIMIT ${}
+            "#,
+            where_clause.sql,
+            offset_placeholder,
+            offset_placeholder + 1
+        );
+        let rows = where_clause
+            .bind_params(sqlx::query(&sql))
+            .bind(offset)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to search workflows: {e}")))?;
+
+        rows.iter().map(workflow_from_row).collect()
+    }
+}
+
+/// A value bound to a workflow filter placeholder
+enum WorkflowFilterParam {
+    Text(String),
+    Uuid(Uuid),
+    Date(NaiveDate),
+}
+
+/// WHERE clause for a workflow filter with its parameters in placeholder
+/// order, numbered from $1. Filter values are always bound, never spliced.
+struct WorkflowWhereClause {
+    sql: String,
+    params: Vec<WorkflowFilterParam>,
+}
+
+impl WorkflowWhereClause {
+    fn new(filter: &WorkflowFilter) -> Self {
+        let mut clause = Self { sql: String::new(), params: Vec::new() };
+        let mut conditions = Vec::new();
+        if let Some(status) = filter.status {
+            let p = clause.push(WorkflowFilterParam::Text(status.to_string()));
+            conditions.push(format!("status = {p}"));
+        }
+        if let Some(workflow_type) = &filter.workflow_type {
+            let p = clause.push(WorkflowFilterParam::Text(workflow_type.to_string()));
+            conditions.push(format!("workflow_type = {p}::workflow_type"));
+        }
+        if let Some(initiated_by) = filter.initiated_by {
+            let p = clause.push(WorkflowFilterParam::Uuid(initiated_by));
+            conditions.push(format!("initiated_by = {p}"));
+        }
+        if let Some(account_id) = filter.account_id {
+            let p = clause.push(WorkflowFilterParam::Uuid(account_id));
+            conditions.push(format!("account_id = {p}"));
+        }
+        if let Some(initiated_from) = filter.initiated_from {
+            let p = clause.push(WorkflowFilterParam::Date(initiated_from));
+            conditions.push(format!("(initiated_at AT TIME ZONE 'UTC')::date >= {p}"));
+        }
+        if let Some(initiated_to) = filter.initiated_to {
+            let p = clause.push(WorkflowFilterParam::Date(initiated_to));
+            conditions.push(format!("(initiated_at AT TIME ZONE 'UTC')::date <= {p}"));
+        }
+        clause.sql = if conditions.is_empty() {
+            "TRUE".to_string()
+        } else {
+            conditions.join(" AND ")
+        };
+        clause
+    }
+
+    fn push(&mut self, param: WorkflowFilterParam) -> String {
+        self.params.push(param);
+        format!("${}", self.params.len())
+    }
+
+    fn bind_params<'q>(&'q self, mut query: Query<'q, Postgres, PgArguments>) -> Query<'q, Postgres, PgArguments> {
+        for param in &self.params {
+            query = match param {
+                WorkflowFilterParam::Text(value) => query.bind(value.as_str()),
+                WorkflowFilterParam::Uuid(value) => query.bind(*value),
+                WorkflowFilterParam::Date(value) => query.bind(*value),
+            };
+        }
+        query
+    }
 }
 
 #[async_trait]
@@ -305,116 +410,49 @@ impl WorkflowRepository for WorkflowRepositoryImpl {
         }
     }
 
-    async fn find_workflows_by_type(&self, workflow_type: &str) -> BankingResult<Vec<AccountWorkflowModel>> {
+    async fn find_open_workflows_by_accounts(&self, account_ids: &[Uuid]) -> BankingResult<Vec<AccountWorkflowModel>> {
         let rows = sqlx::query(
             r#"
-            SELECT id, account_id, workflow_type::text, current_step, status, 
+            SELECT id, account_id, workflow_type::text, current_step, status,
                    initiated_by, initiated_at, completed_at, next_action_required, timeout_at,
                    created_at, last_updated_at
-            FROM account_workflows 
-            WHERE workflow_type = $1::workflow_type
-            ORDER BY created_at DESC
+            FROM account_workflows
+            WHERE account_id = ANY($1) AND status IN ('InProgress', 'PendingAction')
+            ORDER BY created_at DESC, id
             "#
         )
-        .bind(workflow_type)
+        .bind(account_ids)
         .fetch_all(&self.pool)
         .await
-        .map_err(|e| BankingError::Internal(format!("Failed to find workflows by type: {e}"),
-        ))?;
+        .map_err(|e| BankingError::Internal(format!("Failed to find open workflows by accounts: {e}")))?;
 
-        let mut workflows = Vec::new();
-        for row in rows {
-            workflows.push(AccountWorkflowModel {
-                id: row.get("id"),
-                account_id: row.get("account_id"),
-                workflow_type: WorkflowTypeModel::from_str(&row.get::<String, _>("workflow_type"))
-                    .map_err(|e| BankingError::ValidationError {
-                        field: "workflow_type".to_string(),
-                        message: e,
-                    })?,
-                current_step: WorkflowStepModel::from_str(&row.get::<String, _>("current_step"))
-                    .map_err(|e| BankingError::ValidationError {
-                        field: "current_step".to_string(),
-                        message: e,
-                    })?,
-                status: WorkflowStatusModel::from_str(&row.get::<String, _>("status"))
-                    .map_err(|e| BankingError::ValidationError {
-                        field: "status".to_string(),
-                        message: e,
-                    })?,
-                initiated_by: row.get("initiated_by"),
-                initiated_at: row.get("initiated_at"),
-                completed_at: row.get("completed_at"),
-                next_action_required: row.get::<Option<String>, _>("next_action_required")
-                    .map(|s| HeaplessString::try_from(s.as_str()))
-                    .transpose()
-                    .map_err(|_| BankingError::ValidationError {
-                        field: "next_action_required".to_string(),
-                        message: "Next action required too long".to_string(),
-                    })?,
-                timeout_at: row.get("timeout_at"),
-                created_at: row.get("created_at"),
-                last_updated_at: row.get("last_updated_at"),
-            });
-        }
-        Ok(workflows)
+        rows.iter().map(workflow_from_row).collect()
     }
 
-    async fn find_workflows_by_status(&self, status: &str) -> BankingResult<Vec<AccountWorkflowModel>> {
-        let rows = sqlx::query(
-            r#"
-            SELECT id, account_id, workflow_type::text, current_step, status, 
-                   initiated_by, initiated_at, completed_at, next_action_required, timeout_at,
-                   created_at, last_updated_at
-            FROM account_workflows 
-            WHERE status = $1
-            ORDER BY created_at DESC
-            "#
-        )
-        .bind(status)
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| BankingError::Internal(format!("Failed to find workflows by status: {e}"),
-        ))?;
+    async fn find_workflows_by_type(&self, workflow_type: &str) -> BankingResult<Vec<AccountWorkflowModel>> {
+        let filter = WorkflowFilter {
+            workflow_type: Some(WorkflowTypeModel::from_str(workflow_type).map_err(|e| BankingError::ValidationError {
+                field: "workflow_type".to_string(),
+                message: e,
+            })?),
+            ..Default::default()
+        };
+        self.select_workflows(&filter, 0, None).await
+    }
 
-        let mut workflows = Vec::new();
-        for row in rows {
-            workflows.push(AccountWorkflowModel {
-                id: row.get("id"),
-                account_id: row.get("account_id"),
-                workflow_type: WorkflowTypeModel::from_str(&row.get::<String, _>("workflow_type"))
-                    .map_err(|e| BankingError::ValidationError {
-                        field: "workflow_type".to_string(),
-                        message: e,
-                    })?,
-                current_step: WorkflowStepModel::from_str(&row.get::<String, _>("current_step"))
-                    .map_err(|e| BankingError::ValidationError {
-                        field: "current_step".to_string(),
-                        message: e,
-                    })?,
-                status: WorkflowStatusModel::from_str(&row.get::<String, _>("status"))
-                    .map_err(|e| BankingError::ValidationError {
-                        field: "status".to_string(),
-                        message: e,
-                    })?,
-                initiated_by: row.get("initiated_by"),
-                initiated_at: row.get("initiated_at"),
-                completed_at: row.get("completed_at"),
-                next_action_required: row.get::<Option<String>, _>("next_action_required")
-                    .map(|s| HeaplessString::try_from(s.as_str()))
-                    .transpose()
-                    .map_err(|_| BankingError::ValidationError {
-                        field: "next_action_required".to_string(),
-                        message: "Next action required too long".to_string(),
-                    })?,
-                timeout_at: row.get("timeout_at"),
-                created_at: row.get("created_at"),
-                last_updated_at: row.get("last_updated_at"),
-            });
-        }
-        Ok(workflows)
+
+    async fn find_workflows_by_status(&self, status: &str) -> BankingResult<Vec<AccountWorkflowModel>> {
+        let filter = WorkflowFilter {
+            status: Some(WorkflowStatusModel::from_str(status).map_err(|e| BankingError::ValidationError {
+                field: "status".to_string(),
+                message: e,
+            })?),
+            ..Default::default()
+        };
+        self.select_workflows(&filter, 0, None).await
     }
 
+
     async fn find_workflows_by_initiator(&self, initiated_by: &str) -> BankingResult<Vec<AccountWorkflowModel>> {
         let initiated_by_uuid = Uuid::parse_str(initiated_by)
             .map_err(|_| BankingError::ValidationError {
@@ -476,6 +514,33 @@ impl WorkflowRepository for WorkflowRepositoryImpl {
         Ok(workflows)
     }
 
+    async fn search_workflows(&self, filter: &WorkflowFilter, page: WorkflowPage) -> BankingResult<WorkflowSearchResult> {
+        if page.offset < 0 {
+            return Err(BankingError::ValidationError {
+                field: "offset".to_string(),
+                message: "Offset cannot be negative".to_string(),
+            });
+        }
+        if page.limit <= 0 {
+            return Err(BankingError::ValidationError {
+                field: "limit".to_string(),
+                message: "Limit must be positive".to_string(),
+            });
+        }
+
+        let workflows = self.select_workflows(filter, page.offset, Some(page.limit)).await?;
+        let where_clause = WorkflowWhereClause::new(filter);
+        let sql = format!("SELECT COUNT(*) as count FROM account_workflows WHERE {}", where_clause.sql);
+        let total_count: i64 = where_clause
+            .bind_params(sqlx::query(&sql))
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to count workflows: {e}")))?
+            .get("count");
+
+        Ok(WorkflowSearchResult { workflows, total_count })
+    }
+
     /// Workflow Status Management
     async fn update_workflow_status(&self, id: Uuid, status: &str, notes: &str) -> BankingResult<()> {
         sqlx::query(
@@ -514,7 +579,14 @@ impl WorkflowRepository for WorkflowRepositoryImpl {
         Ok(())
     }
 
-    async fn advance_workflow_step(&self, id: Uuid, step: &str, notes: &str) -> BankingResult<()> {
+    async fn advance_workflow_step(
+        &self,
+        id: Uuid,
+        step: &str,
+        completed_by: Uuid,
+        notes: &str,
+        supporting_documents: Vec<HeaplessString<100>>,
+    ) -> BankingResult<()> {
         // First update the workflow current step
         self.update_workflow_step(id, step).await?;
 
@@ -526,17 +598,17 @@ impl WorkflowRepository for WorkflowRepositoryImpl {
                     message: e,
                 })?,
             completed_at: Utc::now(),
-            completed_by: Uuid::nil(), // Would normally be provided by the caller
+            completed_by,
             notes: if notes.is_empty() { None } else { 
                 Some(HeaplessString::try_from(notes).map_err(|_| BankingError::ValidationError {
                     field: "notes".to_string(),
                     message: "Notes too long".to_string(),
                 })?)
             },
-            supporting_documents: Vec::new(),
+            supporting_documents,
         };
 
-        self.add_step_record(step_record).await?;
+        self.add_step_record(id, step_record).await?;
 
         Ok(())
     }
@@ -596,9 +668,32 @@ impl WorkflowRepository for WorkflowRepositoryImpl {
     }
 
     /// Workflow Step Record Operations
-    async fn add_step_record(&self, step_record: WorkflowStepRecordModel) -> BankingResult<WorkflowStepRecordModel> {
-        // Since workflow_step_records requires a workflow_id, we need to modify the signature
-        // For now, we'll use a placeholder implementation
+    async fn add_step_record(&self, workflow_id: Uuid, step_record: WorkflowStepRecordModel) -> BankingResult<WorkflowStepRecordModel> {
+        let supporting_documents: Vec<String> = step_record
+            .supporting_documents
+            .iter()
+            .map(|document| document.to_string())
+            .collect();
+        sqlx::query(
+            r#"
+            INSERT INTO workflow_step_records (
+                id, workflow_id, step, completed_at, completed_by, notes, supporting_documents
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#
+        )
+        .bind(Uuid::new_v4())
+        .bind(workflow_id)
+        .bind(step_record.step.to_string())
+        .bind(step_record.completed_at)
+        .bind(step_record.completed_by)
+        .bind(step_record.notes.as_ref().map(|notes| notes.as_str()))
+        .bind(&supporting_documents)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to add step record: {e}"),
+        ))?;
+
         Ok(step_record)
     }
 
@@ -634,7 +729,7 @@ impl WorkflowRepository for WorkflowRepositoryImpl {
                         field: "notes".to_string(),
                         message: "Notes too long".to_string(),
                     })?,
-                supporting_documents: Vec::new(), // Would need JSON parsing for full implementation
+                supporting_documents: supporting_documents_from_row(&row)?,
             });
         }
         Ok(step_records)
@@ -672,7 +767,7 @@ impl WorkflowRepository for WorkflowRepositoryImpl {
                         field: "notes".to_string(),
                         message: "Notes too long".to_string(),
                     })?,
-                supporting_documents: Vec::new(),
+                supporting_documents: supporting_documents_from_row(&row)?,
             })),
             None => Ok(None),
         }
@@ -1033,49 +1128,177 @@ impl WorkflowRepository for WorkflowRepositoryImpl {
         Ok(workflows.into_iter().filter(|w| w.status == WorkflowStatusModel::PendingAction).collect())
     }
 
-    // Analytics and reporting methods - placeholder implementations
-    async fn get_workflow_metrics(&self, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<WorkflowMetricsReport> {
-        Ok(WorkflowMetricsReport {
-            period_start: _from_date,
-            period_end: _to_date,
+    // Analytics and reporting methods. Periods select workflows by their UTC
+    // initiation date, both bounds included; durations are in hours.
+    async fn get_workflow_metrics(&self, from_date: NaiveDate, to_date: NaiveDate) -> BankingResult<WorkflowMetricsReport> {
+        let rows = sqlx::query(
+            &format!(
+                r#"
+                SELECT workflow_type::text as workflow_type,
+                       COUNT(*) as total_created,
+                       COUNT(*) FILTER (WHERE status = 'Completed') as total_completed,
+                       COUNT(*) FILTER (WHERE status = 'Cancelled') as total_cancelled,
+                       COUNT(*) FILTER (WHERE status IN ('InProgress', 'PendingAction')) as total_in_progress,
+                       SUM({COMPLETION_HOURS}) FILTER (WHERE status = 'Completed') as completion_hours_sum,
+                       AVG({COMPLETION_HOURS}) FILTER (WHERE status = 'Completed') as average_completion_hours
+                FROM account_workflows
+                WHERE {IN_PERIOD}
+                GROUP BY workflow_type
+                ORDER BY workflow_type::text
+                "#
+            )
+        )
+        .bind(from_date)
+        .bind(to_date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to get workflow metrics: {e}")))?;
+
+        let mut report = WorkflowMetricsReport {
+            period_start: from_date,
+            period_end: to_date,
             total_workflows_created: 0,
             total_workflows_completed: 0,
             total_workflows_cancelled: 0,
             total_workflows_in_progress: 0,
             average_completion_time_hours: 0.0,
             workflows_by_type: Vec::new(),
-        })
+        };
+        let mut completion_hours_sum = 0.0;
+        for row in rows {
+            let total_completed: i64 = row.get("total_completed");
+            report.total_workflows_created += row.get::<i64, _>("total_created");
+            report.total_workflows_completed += total_completed;
+            report.total_workflows_cancelled += row.get::<i64, _>("total_cancelled");
+            report.total_workflows_in_progress += row.get::<i64, _>("total_in_progress");
+            completion_hours_sum += row.get::<Option<f64>, _>("completion_hours_sum").unwrap_or(0.0);
+            report.workflows_by_type.push(WorkflowTypeMetrics {
+                workflow_type: row.get("workflow_type"),
+                total_created: row.get("total_created"),
+                total_completed,
+                total_cancelled: row.get("total_cancelled"),
+                average_completion_time_hours: row.get::<Option<f64>, _>("average_completion_hours").unwrap_or(0.0),
+            });
+        }
+        if report.total_workflows_completed > 0 {
+            report.average_completion_time_hours = completion_hours_sum / report.total_workflows_completed as f64;
+        }
+        Ok(report)
     }
 
-    async fn get_workflow_performance(&self, _workflow_type: &str, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<WorkflowPerformanceReport> {
+    async fn get_workflow_performance(&self, workflow_type: &str, from_date: NaiveDate, to_date: NaiveDate) -> BankingResult<WorkflowPerformanceReport> {
+        let row = sqlx::query(
+            &format!(
+                r#"
+                SELECT COUNT(*) as total_workflows,
+                       COUNT(*) FILTER (WHERE status = 'Completed') as completed_workflows,
+                       AVG({COMPLETION_HOURS}) FILTER (WHERE status = 'Completed') as average_hours,
+                       percentile_cont(0.5) WITHIN GROUP (ORDER BY {COMPLETION_HOURS})
+                           FILTER (WHERE status = 'Completed') as median_hours,
+                       MIN({COMPLETION_HOURS}) FILTER (WHERE status = 'Completed') as fastest_hours,
+                       MAX({COMPLETION_HOURS}) FILTER (WHERE status = 'Completed') as slowest_hours
+                FROM account_workflows
+                WHERE {IN_PERIOD} AND workflow_type = $3::workflow_type
+                "#
+            )
+        )
+        .bind(from_date)
+        .bind(to_date)
+        .bind(workflow_type)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to get workflow performance: {e}")))?;
+
+        let total_workflows: i64 = row.get("total_workflows");
+        let completed_workflows: i64 = row.get("completed_workflows");
+        let hours = |column: &str| row.get::<Option<f64>, _>(column).unwrap_or(0.0);
         Ok(WorkflowPerformanceReport {
-            workflow_type: _workflow_type.to_string(),
-            period_start: _from_date,
-            period_end: _to_date,
-            total_workflows: 0,
-            completed_workflows: 0,
-            completion_rate_percentage: 0.0,
-            average_completion_time_hours: 0.0,
-            median_completion_time_hours: 0.0,
-            fastest_completion_hours: 0.0,
-            slowest_completion_hours: 0.0,
+            workflow_type: workflow_type.to_string(),
+            period_start: from_date,
+            period_end: to_date,
+            total_workflows,
+            completed_workflows,
+            completion_rate_percentage: if total_workflows > 0 {
+                completed_workflows as f64 * 100.0 / total_workflows as f64
+            } else {
+                0.0
+            },
+            average_completion_time_hours: hours("average_hours"),
+            median_completion_time_hours: hours("median_hours"),
+            fastest_completion_hours: hours("fastest_hours"),
+            slowest_completion_hours: hours("slowest_hours"),
         })
     }
 
+    /// Steps of in-progress workflows by average dwell time, longest first. A
+    /// workflow entered its current step with its latest step record, or at
+    /// initiation when it has none.
     async fn get_workflow_bottlenecks(&self) -> BankingResult<Vec<WorkflowBottleneckReport>> {
-        Ok(Vec::new())
+        let rows = sqlx::query(
+            r#"
+            WITH dwell AS (
+                SELECT w.current_step, w.workflow_type::text as workflow_type,
+                       EXTRACT(EPOCH FROM NOW() - COALESCE(
+                           (SELECT MAX(r.completed_at) FROM workflow_step_records r WHERE r.workflow_id = w.id),
+                           w.initiated_at
+                       ))::float8 / 3600.0 as hours
+                FROM account_workflows w
+                WHERE w.status IN ('InProgress', 'PendingAction')
+            )
+            SELECT current_step, workflow_type,
+                   AVG(hours) as average_hours,
+                   COUNT(*) as stuck_count,
+                   MAX(hours) as max_hours
+            FROM dwell
+            GROUP BY current_step, workflow_type
+            ORDER BY average_hours DESC, current_step, workflow_type
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to get workflow bottlenecks: {e}")))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| WorkflowBottleneckReport {
+                workflow_step: row.get("current_step"),
+                workflow_type: row.get("workflow_type"),
+                average_time_spent_hours: row.get("average_hours"),
+                workflows_stuck_count: row.get("stuck_count"),
+                max_time_stuck_hours: row.get("max_hours"),
+            })
+            .collect())
     }
 
-    async fn get_average_completion_time(&self, _workflow_type: &str) -> BankingResult<Option<f64>> {
-        Ok(None)
+    async fn get_average_completion_time(&self, workflow_type: &str) -> BankingResult<Option<f64>> {
+        let row = sqlx::query(
+            &format!(
+                r#"
+                SELECT AVG({COMPLETION_HOURS}) as average_hours
+                FROM account_workflows
+                WHERE status = 'Completed' AND workflow_type = $1::workflow_type
+                "#
+            )
+        )
+        .bind(workflow_type)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BankingError::Internal(format!("Failed to get average completion time: {e}")))?;
+
+        Ok(row.get("average_hours"))
     }
 
     // Cleanup and maintenance methods
     async fn cleanup_completed_workflows(&self, retention_days: i32) -> BankingResult<i64> {
         let result = sqlx::query(
             r#"
-            DELETE FROM account_workflows 
-            WHERE status = 'Completed' AND completed_at < NOW() - INTERVAL '1 day' * $1
+            WITH deleted AS (
+                DELETE FROM account_workflows
+                WHERE status = 'Completed' AND completed_at < NOW() - INTERVAL '1 day' * $1
+                RETURNING id
+            )
+            INSERT INTO warehouse_tombstones (id, entity, entity_id, deleted_at)
+            SELECT gen_random_uuid(), 'Workflow'::warehouse_entity, id, NOW() FROM deleted
             "#
         )
         .bind(retention_days)
@@ -1090,8 +1313,13 @@ impl WorkflowRepository for WorkflowRepositoryImpl {
     async fn cleanup_cancelled_workflows(&self, retention_days: i32) -> BankingResult<i64> {
         let result = sqlx::query(
             r#"
-            DELETE FROM account_workflows 
-            WHERE status = 'Cancelled' AND last_updated_at < NOW() - INTERVAL '1 day' * $1
+            WITH deleted AS (
+                DELETE FROM account_workflows
+                WHERE status = 'Cancelled' AND last_updated_at < NOW() - INTERVAL '1 day' * $1
+                RETURNING id
+            )
+            INSERT INTO warehouse_tombstones (id, entity, entity_id, deleted_at)
+            SELECT gen_random_uuid(), 'Workflow'::warehouse_entity, id, NOW() FROM deleted
             "#
         )
         .bind(retention_days)
@@ -1306,4 +1534,53 @@ impl WorkflowRepository for WorkflowRepositoryImpl {
 
         Ok(result.get::<i64, _>("count"))
     }
-}
\ No newline at end of file
+}
+
+/// Supporting documents are stored as a text[] column
+fn supporting_documents_from_row(row: &PgRow) -> BankingResult<Vec<HeaplessString<100>>> {
+    row.get::<Option<Vec<String>>, _>("supporting_documents")
+        .unwrap_or_default()
+        .iter()
+        .map(|document| {
+            HeaplessString::try_from(document.as_str()).map_err(|_| BankingError::ValidationError {
+                field: "supporting_documents".to_string(),
+                message: "Supporting document reference too long".to_string(),
+            })
+        })
+        .collect()
+}
+
+fn workflow_from_row(row: &PgRow) -> BankingResult<AccountWorkflowModel> {
+    Ok(AccountWorkflowModel {
+        id: row.get("id"),
+        account_id: row.get("account_id"),
+        workflow_type: WorkflowTypeModel::from_str(&row.get::<String, _>("workflow_type"))
+            .map_err(|e| BankingError::ValidationError {
+                field: "workflow_type".to_string(),
+                message: e,
+            })?,
+        current_step: WorkflowStepModel::from_str(&row.get::<String, _>("current_step"))
+            .map_err(|e| BankingError::ValidationError {
+                field: "current_step".to_string(),
+                message: e,
+            })?,
+        status: WorkflowStatusModel::from_str(&row.get::<String, _>("status"))
+            .map_err(|e| BankingError::ValidationError {
+                field: "status".to_string(),
+                message: e,
+            })?,
+        initiated_by: row.get("initiated_by"),
+        initiated_at: row.get("initiated_at"),
+        completed_at: row.get("completed_at"),
+        next_action_required: row.get::<Option<String>, _>("next_action_required")
+            .map(|s| HeaplessString::try_from(s.as_str()))
+            .transpose()
+            .map_err(|_| BankingError::ValidationError {
+                field: "next_action_required".to_string(),
+                message: "Next action required too long".to_string(),
+            })?,
+        timeout_at: row.get("timeout_at"),
+        created_at: row.get("created_at"),
+        last_updated_at: row.get("last_updated_at"),
+    })
+}
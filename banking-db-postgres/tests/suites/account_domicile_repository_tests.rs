@@ -0,0 +1,80 @@
+use banking_db::models::AccountDomicileChangeModel;
+use banking_db::repository::AccountDomicileRepository;
+use banking_db_postgres::repository::account_domicile_repository_impl::AccountDomicileRepositoryImpl;
+use chrono::{NaiveDate, Utc};
+use sqlx::PgPool;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+async fn insert_account(pool: &PgPool, branch_id: Uuid) -> Uuid {
+    let person_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO persons (id, person_type, display_name, external_identifier)
+         VALUES ($1, 'System', 'Test User', 'test-user')"
+    )
+    .bind(person_id)
+    .execute(pool)
+    .await
+    .expect("Failed to create test person");
+
+    let account_id = Uuid::new_v4();
+    sqlx::query(
+        r#"INSERT INTO accounts (
+            id, product_id, account_type, account_status, signing_condition,
+            currency, open_date, domicile_agency_branch_id, current_balance, available_balance,
+            accrued_interest, overdraft_limit, updated_by_person_id
+        ) VALUES (
+            $1, $2, 'Savings', 'Active', 'AnyOwner', 'XAF', '2023-05-02',
+            $3, 1000.00, 1000.00, 0.00, NULL, $4
+        )"#
+    )
+    .bind(account_id)
+    .bind(Uuid::new_v4())
+    .bind(branch_id)
+    .bind(person_id)
+    .execute(pool)
+    .await
+    .expect("Failed to create test account");
+    account_id
+}
+
+fn change(account_id: Uuid, from: Uuid, to: Uuid, effective_date: NaiveDate) -> AccountDomicileChangeModel {
+    AccountDomicileChangeModel {
+        id: Uuid::new_v4(),
+        account_id,
+        from_agency_branch_id: from,
+        to_agency_branch_id: to,
+        effective_date,
+        reason_id: Uuid::new_v4(),
+        approved_by_person_id: Uuid::new_v4(),
+        recorded_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_transfer_moves_account_and_keeps_history() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let pool = schema.pg_pool();
+    let repo = AccountDomicileRepositoryImpl::new(pool.clone());
+    let (douala, yaounde) = (Uuid::new_v4(), Uuid::new_v4());
+    let account_id = insert_account(&pool, douala).await;
+    let effective_date = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+
+    repo.transfer_domicile(change(account_id, douala, yaounde, effective_date)).await.unwrap();
+    let branch: Uuid = sqlx::query_scalar("SELECT domicile_agency_branch_id FROM accounts WHERE id = $1")
+        .bind(account_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(branch, yaounde);
+
+    // A second move validated against the old branch is refused and leaves no history row
+    assert!(repo.transfer_domicile(change(account_id, douala, Uuid::new_v4(), effective_date)).await.is_err());
+    assert_eq!(repo.find_domicile_history(account_id).await.unwrap().len(), 1);
+
+    // Reports for the day before the move still see the change; reports from the move on do not
+    let before = repo.find_domicile_changes_effective_after(NaiveDate::from_ymd_opt(2024, 3, 9).unwrap()).await.unwrap();
+    assert_eq!(before.len(), 1);
+    assert_eq!(before[0].from_agency_branch_id, douala);
+    assert!(repo.find_domicile_changes_effective_after(effective_date).await.unwrap().is_empty());
+}
@@ -0,0 +1,88 @@
+use banking_api::domain::AccountNumberScheme;
+use banking_db::models::AccountNumberSchemeModel;
+use banking_db::repository::AccountNumberRepository;
+use banking_db_postgres::repository::account_number_repository_impl::AccountNumberRepositoryImpl;
+use chrono::Utc;
+use heapless::String as HeaplessString;
+use banking_db_postgres::test_helper::setup_test_schema;
+use std::collections::HashSet;
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn scheme(branch_id: Uuid, product_id: Option<Uuid>, bank_code: &str) -> AccountNumberSchemeModel {
+    AccountNumberSchemeModel {
+        id: Uuid::new_v4(),
+        branch_id,
+        product_id,
+        bank_code: HeaplessString::try_from(bank_code).unwrap(),
+        branch_code: HeaplessString::try_from("00001").unwrap(),
+        serial_length: 11,
+        iban_country_code: Some(HeaplessString::try_from("CM").unwrap()),
+        created_at: Utc::now(),
+        last_updated_at: Utc::now(),
+        updated_by_person_id: Uuid::new_v4(),
+    }
+}
+
+#[tokio::test]
+async fn test_saving_a_scheme_replaces_the_one_of_the_same_scope() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = AccountNumberRepositoryImpl::new(schema.pg_pool());
+    let branch_id = Uuid::new_v4();
+    let product_id = Uuid::new_v4();
+
+    let default = repo.save_scheme(scheme(branch_id, None, "10005")).await.unwrap();
+    repo.save_scheme(scheme(branch_id, Some(product_id), "10006")).await.unwrap();
+    let replaced = repo.save_scheme(scheme(branch_id, None, "10007")).await.unwrap();
+
+    assert_eq!(replaced.id, default.id);
+    assert_eq!(replaced.bank_code.as_str(), "10007");
+    assert_eq!(repo.find_scheme(branch_id, None).await.unwrap().unwrap().bank_code.as_str(), "10007");
+    assert_eq!(repo.find_scheme(branch_id, Some(product_id)).await.unwrap().unwrap().bank_code.as_str(), "10006");
+    assert!(repo.find_scheme(branch_id, Some(Uuid::new_v4())).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_concurrent_generation_produces_no_duplicates() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = Arc::new(AccountNumberRepositoryImpl::new(schema.pg_pool()));
+    let branch_id = Uuid::new_v4();
+    let other_branch_id = Uuid::new_v4();
+    let scheme = scheme(branch_id, None, "10005");
+    let domain_scheme = AccountNumberScheme {
+        id: scheme.id,
+        branch_id,
+        product_id: None,
+        bank_code: scheme.bank_code.clone(),
+        branch_code: scheme.branch_code.clone(),
+        serial_length: scheme.serial_length as u8,
+        iban_country_code: scheme.iban_country_code.clone(),
+        created_at: scheme.created_at,
+        last_updated_at: scheme.last_updated_at,
+        updated_by_person_id: scheme.updated_by_person_id,
+    };
+
+    let tasks: Vec<_> = (0..100)
+        .map(|i| {
+            let repo = repo.clone();
+            let branch = if i % 5 == 0 { other_branch_id } else { branch_id };
+            tokio::spawn(async move { (branch, repo.next_serial(branch).await.unwrap()) })
+        })
+        .collect();
+    let mut serials = Vec::new();
+    for task in tasks {
+        serials.push(task.await.unwrap());
+    }
+
+    let branch_serials: HashSet<i64> = serials.iter().filter(|(b, _)| *b == branch_id).map(|(_, s)| *s).collect();
+    assert_eq!(branch_serials, (1..=80).collect());
+    let other_serials: HashSet<i64> = serials.iter().filter(|(b, _)| *b == other_branch_id).map(|(_, s)| *s).collect();
+    assert_eq!(other_serials, (1..=20).collect());
+
+    let numbers: HashSet<String> = branch_serials
+        .iter()
+        .map(|serial| domain_scheme.compose(*serial).unwrap().to_string())
+        .collect();
+    assert_eq!(numbers.len(), 80);
+    assert_eq!(repo.next_serial(branch_id).await.unwrap(), 81);
+}
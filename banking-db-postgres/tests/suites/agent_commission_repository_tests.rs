@@ -0,0 +1,97 @@
+use banking_db::models::{
+    AgentCommissionLineItemModel, AgentCommissionSchemeModel, AgentCommissionStatementModel,
+    DbCommissionLineType, DbCommissionStatementStatus,
+};
+use banking_db::repository::AgentCommissionRepository;
+use banking_db_postgres::repository::agent_commission_repository_impl::AgentCommissionRepositoryImpl;
+use chrono::{NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn scheme() -> AgentCommissionSchemeModel {
+    AgentCommissionSchemeModel {
+        id: Uuid::new_v4(),
+        code: HeaplessString::try_from("DAILY_SAVINGS").unwrap(),
+        collection_program_id: Some(Uuid::new_v4()),
+        territory_id: None,
+        commission_rate: Decimal::new(2, 2),
+        target_collection_rate: Some(Decimal::new(9, 1)),
+        bonus_amount: Decimal::from(5000),
+        deduction_per_discrepancy: Decimal::from(1000),
+        is_active: true,
+        created_at: Utc::now(),
+        last_updated_at: Utc::now(),
+        updated_by_person_id: Uuid::new_v4(),
+    }
+}
+
+fn statement(collection_agent_id: Uuid, period_start: NaiveDate) -> AgentCommissionStatementModel {
+    AgentCommissionStatementModel {
+        id: Uuid::new_v4(),
+        collection_agent_id,
+        period_start,
+        period_end: NaiveDate::from_ymd_opt(2024, 5, 31).unwrap(),
+        collected_amount: Decimal::from(190000),
+        commission_amount: Decimal::from(3800),
+        bonus_amount: Decimal::ZERO,
+        deduction_amount: Decimal::ZERO,
+        net_amount: Decimal::from(3800),
+        discrepancy_count: 0,
+        status: DbCommissionStatementStatus::Draft,
+        calculated_at: Utc::now(),
+        invoiced_at: None,
+    }
+}
+
+#[tokio::test]
+async fn test_commission_scheme_round_trip() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = AgentCommissionRepositoryImpl::new(schema.pg_pool());
+
+    let created = repo.create_scheme(scheme()).await.unwrap();
+    assert_eq!(created.commission_rate, Decimal::new(2, 2));
+
+    let mut deactivated = created.clone();
+    deactivated.is_active = false;
+    repo.update_scheme(deactivated).await.unwrap();
+    assert!(repo.find_active_schemes().await.unwrap().is_empty());
+    assert!(!repo.find_scheme_by_id(created.id).await.unwrap().unwrap().is_active);
+}
+
+#[tokio::test]
+async fn test_period_statements_are_replaced_until_invoiced() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = AgentCommissionRepositoryImpl::new(schema.pg_pool());
+    let scheme = repo.create_scheme(scheme()).await.unwrap();
+    let agent_id = Uuid::new_v4();
+    let period_start = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+
+    let line_item = |statement_id: Uuid| AgentCommissionLineItemModel {
+        id: Uuid::new_v4(),
+        statement_id,
+        line_type: DbCommissionLineType::Commission,
+        scheme_id: scheme.id,
+        collection_program_id: scheme.collection_program_id,
+        description: HeaplessString::try_from("2% of 19 processed collections").unwrap(),
+        basis_amount: Decimal::from(190000),
+        amount: Decimal::from(3800),
+    };
+
+    let first = statement(agent_id, period_start);
+    repo.replace_period_statements(period_start, vec![first.clone()], vec![line_item(first.id)]).await.unwrap();
+    let second = statement(agent_id, period_start);
+    repo.replace_period_statements(period_start, vec![second.clone()], vec![line_item(second.id)]).await.unwrap();
+
+    let stored = repo.find_statements_for_period(period_start).await.unwrap();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].id, second.id);
+    assert!(repo.find_line_items(first.id).await.unwrap().is_empty());
+    assert_eq!(repo.find_line_items(second.id).await.unwrap().len(), 1);
+
+    repo.update_statement_status(second.id, DbCommissionStatementStatus::Invoiced, Some(Utc::now())).await.unwrap();
+    let third = statement(agent_id, period_start);
+    assert!(repo.replace_period_statements(period_start, vec![third], vec![]).await.is_err());
+    assert_eq!(repo.find_statements_by_agent(agent_id, 12).await.unwrap()[0].id, second.id);
+}
@@ -0,0 +1,197 @@
+use banking_api::BankingError;
+use banking_db::models::agent_network::HierarchyNodeType;
+use banking_db::repository::AgentNetworkRepository;
+use banking_db_postgres::repository::agent_network_repository_impl::AgentNetworkRepositoryImpl;
+use rust_decimal_macros::dec;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+/// Network -> regional office -> two branches, a terminal under the first
+struct Hierarchy {
+    network_id: Uuid,
+    regional_id: Uuid,
+    first_branch_id: Uuid,
+    second_branch_id: Uuid,
+    terminal_id: Uuid,
+}
+
+async fn insert_branch(pool: &PgPool, network_id: Uuid, parent_id: Option<Uuid>, code: &str, level: i32) -> Uuid {
+    let id = Uuid::new_v4();
+    sqlx::query(
+        r#"
+        INSERT INTO agent_branches (
+            id, agent_network_id, parent_agency_branch_id, branch_name, branch_code, branch_level,
+            gl_code_prefix, status, daily_transaction_limit, current_daily_volume,
+            max_cash_limit, current_cash_balance, minimum_cash_balance
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, 'AG01', 'Active'::branch_status, 10000000, 0, 5000000, 0, 0)
+        "#,
+    )
+    .bind(id)
+    .bind(network_id)
+    .bind(parent_id)
+    .bind(format!("Branch {code}"))
+    .bind(code)
+    .bind(level)
+    .execute(pool)
+    .await
+    .expect("Failed to create branch");
+    id
+}
+
+async fn insert_account(pool: &PgPool, branch_id: Uuid, balance: Decimal, status: &str) {
+    sqlx::query(
+        r#"
+        INSERT INTO accounts (
+            id, product_id, account_type, account_status, signing_condition,
+            currency, open_date, domicile_agency_branch_id, current_balance, available_balance,
+            accrued_interest, updated_by_person_id
+        )
+        VALUES (
+            $1, $2, 'Savings'::account_type, $3::account_status, 'None'::signing_condition,
+            'XAF', CURRENT_DATE, $4, $5, $5, 0, $6
+        )
+        "#,
+    )
+    .bind(Uuid::new_v4())
+    .bind(Uuid::new_v4())
+    .bind(status)
+    .bind(branch_id)
+    .bind(balance)
+    .bind(Uuid::new_v4())
+    .execute(pool)
+    .await
+    .expect("Failed to create account");
+}
+
+async fn build_hierarchy(pool: &PgPool) -> Hierarchy {
+    let network_id = Uuid::new_v4();
+    sqlx::query(
+        r#"
+        INSERT INTO agent_networks (
+            id, network_name, network_type, status, aggregate_daily_limit, current_daily_volume,
+            settlement_gl_code
+        )
+        VALUES ($1, 'Test network', 'Internal'::network_type, 'Active'::network_status, 50000000, 0, '10100000')
+        "#,
+    )
+    .bind(network_id)
+    .execute(pool)
+    .await
+    .expect("Failed to create network");
+
+    let regional_id = insert_branch(pool, network_id, None, "REG01", 1).await;
+    let first_branch_id = insert_branch(pool, network_id, Some(regional_id), "BR01", 2).await;
+    let second_branch_id = insert_branch(pool, network_id, Some(regional_id), "BR02", 2).await;
+
+    let terminal_id = Uuid::new_v4();
+    sqlx::query(
+        r#"
+        INSERT INTO agent_terminals (
+            id, agency_branch_id, agent_person_id, terminal_type, terminal_name,
+            daily_transaction_limit, current_daily_volume, max_cash_limit, current_cash_balance,
+            minimum_cash_balance, status, last_sync_at
+        )
+        VALUES ($1, $2, $3, 'Pos'::terminal_type, 'Counter 1', 1000000, 0, 500000, 0, 0,
+                'Active'::terminal_status, NOW())
+        "#,
+    )
+    .bind(terminal_id)
+    .bind(first_branch_id)
+    .bind(Uuid::new_v4())
+    .execute(pool)
+    .await
+    .expect("Failed to create terminal");
+
+    Hierarchy { network_id, regional_id, first_branch_id, second_branch_id, terminal_id }
+}
+
+#[tokio::test]
+async fn test_traversal_in_both_directions() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let pool = schema.pg_pool();
+    let repo = AgentNetworkRepositoryImpl::new(pool.clone());
+    let hierarchy = build_hierarchy(&pool).await;
+
+    let descendants = repo.find_descendants(hierarchy.regional_id, 5).await.unwrap();
+    assert_eq!(descendants.len(), 3);
+    let branches: Vec<_> = descendants.iter().filter(|node| node.depth == 1).collect();
+    assert_eq!(branches.len(), 2);
+    assert!(branches.iter().all(|node| node.node_type == HierarchyNodeType::Branch
+        && node.parent_id == Some(hierarchy.regional_id)));
+    let terminal = &descendants[2];
+    assert_eq!((terminal.node_id, terminal.node_type, terminal.depth), (hierarchy.terminal_id, HierarchyNodeType::Terminal, 2));
+    assert_eq!(terminal.parent_id, Some(hierarchy.first_branch_id));
+
+    // The depth limit stops at the regional office
+    let first_level = repo.find_descendants(hierarchy.network_id, 1).await.unwrap();
+    assert_eq!(first_level.len(), 1);
+    assert_eq!(first_level[0].node_id, hierarchy.regional_id);
+    assert_eq!(first_level[0].parent_id, Some(hierarchy.network_id));
+
+    let ancestors = repo.find_ancestors(hierarchy.terminal_id).await.unwrap();
+    let path: Vec<_> = ancestors.iter().map(|node| (node.node_id, node.node_type, node.depth)).collect();
+    assert_eq!(path, vec![
+        (hierarchy.first_branch_id, HierarchyNodeType::Branch, 1),
+        (hierarchy.regional_id, HierarchyNodeType::Branch, 2),
+        (hierarchy.network_id, HierarchyNodeType::Network, 3),
+    ]);
+    assert_eq!(repo.find_ancestors(hierarchy.regional_id).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_branch_rollup_totals() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let pool = schema.pg_pool();
+    let repo = AgentNetworkRepositoryImpl::new(pool.clone());
+    let hierarchy = build_hierarchy(&pool).await;
+    insert_account(&pool, hierarchy.regional_id, dec!(1000.00), "Active").await;
+    insert_account(&pool, hierarchy.first_branch_id, dec!(200.00), "Active").await;
+    insert_account(&pool, hierarchy.first_branch_id, dec!(300.00), "Dormant").await;
+    insert_account(&pool, hierarchy.second_branch_id, dec!(50.00), "Active").await;
+    insert_account(&pool, hierarchy.second_branch_id, dec!(999.00), "Closed").await;
+
+    let rollup = repo.find_branch_rollup(hierarchy.regional_id).await.unwrap();
+    assert_eq!(rollup.len(), 3);
+    let regional = &rollup[0];
+    assert_eq!(regional.agency_branch_id, hierarchy.regional_id);
+    assert_eq!((regional.account_count, regional.total_balance), (1, dec!(1000.00)));
+    assert_eq!((regional.subtree_account_count, regional.subtree_balance), (4, dec!(1550.00)));
+
+    let first = rollup.iter().find(|node| node.agency_branch_id == hierarchy.first_branch_id).unwrap();
+    assert_eq!(first.depth, 1);
+    assert_eq!((first.subtree_account_count, first.subtree_balance), (2, dec!(500.00)));
+    let second = rollup.iter().find(|node| node.agency_branch_id == hierarchy.second_branch_id).unwrap();
+    assert_eq!((second.subtree_account_count, second.subtree_balance), (1, dec!(50.00)));
+
+    assert!(repo.find_branch_rollup(Uuid::new_v4()).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_cycle_is_reported_instead_of_looping() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let pool = schema.pg_pool();
+    let repo = AgentNetworkRepositoryImpl::new(pool.clone());
+    let hierarchy = build_hierarchy(&pool).await;
+    sqlx::query("UPDATE agent_branches SET parent_agency_branch_id = $1 WHERE id = $2")
+        .bind(hierarchy.second_branch_id)
+        .bind(hierarchy.regional_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        repo.find_descendants(hierarchy.regional_id, 10).await,
+        Err(BankingError::HierarchyCycle { .. })
+    ));
+    assert!(matches!(
+        repo.find_ancestors(hierarchy.terminal_id).await,
+        Err(BankingError::HierarchyCycle { .. })
+    ));
+    assert!(matches!(
+        repo.find_branch_rollup(hierarchy.regional_id).await,
+        Err(BankingError::HierarchyCycle { .. })
+    ));
+}
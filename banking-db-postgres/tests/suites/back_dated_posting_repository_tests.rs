@@ -0,0 +1,58 @@
+use banking_db::models::BackDatedPostingModel;
+use banking_db::repository::BackDatedPostingRepository;
+use banking_db_postgres::repository::back_dated_posting_repository_impl::BackDatedPostingRepositoryImpl;
+use chrono::{NaiveDate, Utc};
+use rust_decimal_macros::dec;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn posting(account_id: Uuid, value_date: NaiveDate, business_date: NaiveDate) -> BackDatedPostingModel {
+    BackDatedPostingModel {
+        id: Uuid::new_v4(),
+        transaction_id: Uuid::new_v4(),
+        account_id,
+        value_date,
+        business_date,
+        days_back: (business_date - value_date).num_days(),
+        accrual_delta: dec!(0.3835616438),
+        posted_by_person_id: Uuid::new_v4(),
+        posted_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_register_is_queried_by_business_date() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = BackDatedPostingRepositoryImpl::new(schema.pg_pool());
+    let account_id = Uuid::new_v4();
+
+    let june = posting(
+        account_id,
+        NaiveDate::from_ymd_opt(2024, 6, 10).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 6, 14).unwrap(),
+    );
+    let july = posting(
+        account_id,
+        NaiveDate::from_ymd_opt(2024, 6, 28).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 7, 2).unwrap(),
+    );
+    let created = repo.create_back_dated_posting(june.clone()).await.unwrap();
+    repo.create_back_dated_posting(july).await.unwrap();
+    assert_eq!(created.days_back, 4);
+    assert_eq!(created.accrual_delta, dec!(0.3835616438));
+
+    // The June audit report only sees postings made on June business dates
+    let register = repo
+        .find_back_dated_postings(
+            NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 6, 30).unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(register.len(), 1);
+    assert_eq!(register[0].transaction_id, june.transaction_id);
+    assert_eq!(register[0].posted_by_person_id, june.posted_by_person_id);
+    assert_eq!(register[0].value_date, june.value_date);
+
+    assert_eq!(repo.find_back_dated_postings_by_account(account_id).await.unwrap().len(), 2);
+}
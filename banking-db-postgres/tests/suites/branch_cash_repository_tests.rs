@@ -0,0 +1,91 @@
+use banking_db::models::{
+    BranchCashCeilingModel, CashTransferRequestModel, DbCashTransferOrigin, DbCashTransferStatus,
+    DbVaultAdjustmentType, VaultAdjustmentModel,
+};
+use banking_db::repository::BranchCashRepository;
+use banking_db_postgres::repository::branch_cash_repository_impl::BranchCashRepositoryImpl;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn ceiling(agency_branch_id: Uuid, ceiling_amount: i64) -> BranchCashCeilingModel {
+    BranchCashCeilingModel {
+        id: Uuid::new_v4(),
+        agency_branch_id,
+        ceiling_amount: Decimal::from(ceiling_amount),
+        excess_destination_branch_id: None,
+        is_active: true,
+        created_at: Utc::now(),
+        last_updated_at: Utc::now(),
+        updated_by_person_id: Uuid::new_v4(),
+    }
+}
+
+fn transfer(from_agency_branch_id: Uuid) -> CashTransferRequestModel {
+    CashTransferRequestModel {
+        id: Uuid::new_v4(),
+        from_agency_branch_id,
+        to_agency_branch_id: None,
+        amount: Decimal::from(2_000_000),
+        status: DbCashTransferStatus::Requested,
+        origin: DbCashTransferOrigin::CeilingBreach,
+        requested_by_person_id: None,
+        requested_at: Utc::now(),
+        dispatch_authorized_by_person_id: None,
+        dispatch_confirmed_by_person_id: None,
+        dispatched_at: None,
+        receipt_authorized_by_person_id: None,
+        receipt_confirmed_by_person_id: None,
+        received_at: None,
+        cancelled_by_person_id: None,
+        cancellation_reason: None,
+        last_updated_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_ceiling_upsert_replaces_branch_ceiling() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = BranchCashRepositoryImpl::new(schema.pg_pool());
+    let branch_id = Uuid::new_v4();
+
+    let first = repo.upsert_ceiling(ceiling(branch_id, 5_000_000)).await.unwrap();
+    let replaced = repo.upsert_ceiling(ceiling(branch_id, 8_000_000)).await.unwrap();
+
+    assert_eq!(replaced.id, first.id);
+    assert_eq!(replaced.ceiling_amount, Decimal::from(8_000_000));
+    assert_eq!(repo.find_active_ceilings().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_dispatch_posts_adjustment_and_rejects_stale_update() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = BranchCashRepositoryImpl::new(schema.pg_pool());
+    let branch_id = Uuid::new_v4();
+    let created = repo.create_transfer(transfer(branch_id)).await.unwrap();
+    let stale = created.last_updated_at;
+
+    let mut dispatched = created.clone();
+    dispatched.status = DbCashTransferStatus::InTransit;
+    dispatched.dispatch_authorized_by_person_id = Some(Uuid::new_v4());
+    dispatched.dispatch_confirmed_by_person_id = Some(Uuid::new_v4());
+    dispatched.dispatched_at = Some(Utc::now());
+    dispatched.last_updated_at = Utc::now();
+    let adjustment = VaultAdjustmentModel {
+        id: Uuid::new_v4(),
+        agency_branch_id: Some(branch_id),
+        cash_transfer_request_id: created.id,
+        adjustment_type: DbVaultAdjustmentType::TransferDispatch,
+        amount: -created.amount,
+        posted_at: Utc::now(),
+        posted_by_person_id: Uuid::new_v4(),
+    };
+    repo.update_transfer(dispatched.clone(), stale, Some(adjustment)).await.unwrap();
+
+    assert!(repo.update_transfer(dispatched, stale, None).await.is_err());
+    let position = repo.get_branch_cash_position(branch_id).await.unwrap();
+    assert_eq!(position.vault_adjustments, Decimal::from(-2_000_000));
+    assert_eq!(repo.find_adjustments_by_transfer(created.id).await.unwrap().len(), 1);
+    assert_eq!(repo.find_open_transfers().await.unwrap()[0].status, DbCashTransferStatus::InTransit);
+}
@@ -0,0 +1,107 @@
+use banking_db::models::{
+    AccountBundleModel, BundleAccountModel, BundlePricingMarkerModel, DbBundleComponentRole, DbBundleStatus,
+    FeeCategory, ProductBundleModel,
+};
+use banking_db::repository::BundleRepository;
+use banking_db_postgres::repository::bundle_repository_impl::BundleRepositoryImpl;
+use chrono::{Duration, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal_macros::dec;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn product_bundle(bundle_code: &str) -> ProductBundleModel {
+    ProductBundleModel {
+        id: Uuid::new_v4(),
+        bundle_code: HeaplessString::try_from(bundle_code).unwrap(),
+        name: HeaplessString::try_from("Everyday Bundle").unwrap(),
+        components: format!(
+            r#"[{{"role":"Current","product_id":"{}","account_type":"Current","required":true}}]"#,
+            Uuid::new_v4()
+        ),
+        fee_adjustments: "[]".to_string(),
+        is_active: true,
+        created_at: Utc::now(),
+        last_updated_at: Utc::now(),
+        updated_by_person_id: Uuid::new_v4(),
+    }
+}
+
+fn account_bundle(product_bundle: &ProductBundleModel) -> AccountBundleModel {
+    AccountBundleModel {
+        id: Uuid::new_v4(),
+        product_bundle_id: product_bundle.id,
+        bundle_code: product_bundle.bundle_code.clone(),
+        customer_id: Uuid::new_v4(),
+        status: DbBundleStatus::Active,
+        orchestration_id: Uuid::new_v4(),
+        opened_at: Utc::now(),
+        dissolved_at: None,
+        dissolution_reason: None,
+    }
+}
+
+fn marker(account_bundle_id: Uuid, account_id: Uuid) -> BundlePricingMarkerModel {
+    BundlePricingMarkerModel {
+        id: Uuid::new_v4(),
+        account_bundle_id,
+        account_id,
+        fee_category: FeeCategory::Maintenance,
+        discount_percentage: dec!(50),
+        depends_on_account_id: Uuid::new_v4(),
+        created_at: Utc::now(),
+        removed_at: None,
+    }
+}
+
+#[tokio::test]
+async fn test_linked_account_is_found_only_while_bundle_is_active() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = BundleRepositoryImpl::new(schema.pg_pool());
+
+    let product = repo.create_product_bundle(product_bundle("EVERYDAY")).await.unwrap();
+    let found = repo.find_product_bundle_by_code("EVERYDAY").await.unwrap().unwrap();
+    assert_eq!(found.components, product.components);
+
+    let mut bundle = repo.create_account_bundle(account_bundle(&product)).await.unwrap();
+    let account_id = Uuid::new_v4();
+    repo.create_bundle_account(BundleAccountModel {
+        id: Uuid::new_v4(),
+        account_bundle_id: bundle.id,
+        account_id,
+        role: DbBundleComponentRole::Current,
+        required: true,
+        linked_at: Utc::now(),
+        unlinked_at: None,
+    })
+    .await
+    .unwrap();
+    assert!(repo.find_linked_bundle_account(account_id).await.unwrap().is_some());
+
+    bundle.status = DbBundleStatus::Dissolved;
+    bundle.dissolved_at = Some(Utc::now());
+    repo.update_account_bundle(bundle).await.unwrap();
+    assert!(repo.find_linked_bundle_account(account_id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_removed_markers_keep_first_removal_time() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = BundleRepositoryImpl::new(schema.pg_pool());
+
+    let product = repo.create_product_bundle(product_bundle("SAVER")).await.unwrap();
+    let bundle = repo.create_account_bundle(account_bundle(&product)).await.unwrap();
+    let account_id = Uuid::new_v4();
+    let first = repo.create_pricing_marker(marker(bundle.id, account_id)).await.unwrap();
+    let second = repo.create_pricing_marker(marker(bundle.id, account_id)).await.unwrap();
+
+    let removed_at = Utc::now();
+    repo.remove_pricing_markers(&[first.id], removed_at).await.unwrap();
+    repo.remove_pricing_markers(&[first.id, second.id], removed_at + Duration::hours(1)).await.unwrap();
+
+    assert!(repo.find_active_markers_by_account(account_id).await.unwrap().is_empty());
+    let markers = repo.find_pricing_markers_by_bundle(bundle.id).await.unwrap();
+    let first = markers.iter().find(|m| m.id == first.id).unwrap();
+    assert_eq!(first.removed_at.unwrap().timestamp(), removed_at.timestamp());
+    assert_eq!(first.fee_category, FeeCategory::Maintenance);
+}
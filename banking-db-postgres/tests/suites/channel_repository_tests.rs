@@ -1,7 +1,11 @@
 
 mod channel_repository_tests {
     use banking_api::{BankingResult};
-    use banking_db::models::channel::{ChannelModel, ChannelStatus};
+    use banking_db::models::channel::{
+        ChannelFeeCalculationMethod, ChannelFeeType, ChannelModel, ChannelReconciliationReportModel, ChannelStatus,
+        DiscrepancyType, FeeItemModel, FeeScheduleModel, FeeTierModel, ReconciliationDiscrepancyModel,
+        ReconciliationStatus,
+    };
     use banking_db::repository::ChannelRepository;
     use banking_db::ChannelType;
     use banking_db_postgres::repository::ChannelRepositoryImpl;
@@ -24,6 +28,7 @@ mod channel_repository_tests {
     }
 
     async fn cleanup_database(pool: &PgPool) {
+        let _ = sqlx::query("DELETE FROM reconciliation_discrepancies").execute(pool).await;
         let _ = sqlx::query("DELETE FROM channel_reconciliation_reports").execute(pool).await;
         let _ = sqlx::query("DELETE FROM channel_fees").execute(pool).await;
         let _ = sqlx::query("DELETE FROM channels").execute(pool).await;
@@ -415,4 +420,165 @@ mod channel_repository_tests {
         
         cleanup_database(&repo.get_pool()).await;
     }
+
+    fn fee_tier(fee_item_id: Uuid, order: i32, min_amount: i64, max_amount: Option<i64>, fee_percentage: &str) -> FeeTierModel {
+        FeeTierModel {
+            id: Uuid::new_v4(),
+            fee_item_id,
+            tier_name: HeaplessString::try_from(format!("T{order}").as_str()).unwrap(),
+            min_amount: Decimal::from(min_amount),
+            max_amount: max_amount.map(Decimal::from),
+            fee_amount: None,
+            fee_percentage: Some(Decimal::from_str(fee_percentage).unwrap()),
+            tier_order: order,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fee_item_tiers_are_replaced_and_read_in_order() {
+        let pool = setup_test_db().await.expect("Failed to setup test database");
+        let repo = ChannelRepositoryImpl::new(pool);
+        let now = Utc::now();
+
+        let schedule = repo.save_fee_schedule(FeeScheduleModel {
+            id: Uuid::new_v4(),
+            schedule_name: HeaplessString::try_from("Mobile transfers").unwrap(),
+            channel_id: None,
+            effective_date: now.date_naive(),
+            expiry_date: None,
+            currency: HeaplessString::try_from("XAF").unwrap(),
+            fee01_fee_item_id: None,
+            fee02_fee_item_id: None,
+            fee03_fee_item_id: None,
+            fee04_fee_item_id: None,
+            fee05_fee_item_id: None,
+            is_active: true,
+            created_at: now,
+            updated_at: now,
+        }).await.expect("Failed to save fee schedule");
+
+        let item_id = Uuid::new_v4();
+        let item = FeeItemModel {
+            id: item_id,
+            schedule_id: schedule.id,
+            fee_code: HeaplessString::try_from("MOB_TRF").unwrap(),
+            fee_name: HeaplessString::try_from("Mobile transfer fee").unwrap(),
+            fee_type: ChannelFeeType::TransactionFee,
+            calculation_method: ChannelFeeCalculationMethod::Tiered,
+            fee_amount: None,
+            fee_percentage: None,
+            minimum_fee: Some(Decimal::from(100)),
+            maximum_fee: Some(Decimal::from(5_000)),
+            tier01_channel_fee_tier_id: None,
+            tier02_channel_fee_tier_id: None,
+            tier03_channel_fee_tier_id: None,
+            tier04_channel_fee_tier_id: None,
+            tier05_channel_fee_tier_id: None,
+            tier06_channel_fee_tier_id: None,
+            tier07_channel_fee_tier_id: None,
+            tier08_channel_fee_tier_id: None,
+            tier09_channel_fee_tier_id: None,
+            tier10_channel_fee_tier_id: None,
+            tier11_channel_fee_tier_id: None,
+            applies_to_transaction_type_01: Some(HeaplessString::try_from("Debit").unwrap()),
+            applies_to_transaction_type_02: None,
+            applies_to_transaction_type_03: None,
+            applies_to_transaction_type_04: None,
+            applies_to_transaction_type_05: None,
+            applies_to_transaction_type_06: None,
+            applies_to_transaction_type_07: None,
+            applies_to_transaction_type_08: None,
+            applies_to_transaction_type_09: None,
+            applies_to_transaction_type_10: None,
+            applies_to_transaction_type_11: None,
+            is_waivable: true,
+            requires_approval_for_waiver: false,
+            created_at: now,
+        };
+
+        let upper = fee_tier(item_id, 2, 100_000, None, "0.005");
+        let lower = fee_tier(item_id, 1, 0, Some(100_000), "0.01");
+        let saved = repo.save_fee_item(item.clone(), vec![upper.clone(), lower.clone()]).await.expect("Failed to save fee item");
+        assert_eq!(saved.calculation_method, ChannelFeeCalculationMethod::Tiered);
+        assert_eq!(saved.tier01_channel_fee_tier_id, Some(lower.id));
+        assert_eq!(saved.tier02_channel_fee_tier_id, Some(upper.id));
+        assert_eq!(saved.applies_to_transaction_type_01.as_ref().map(|t| t.as_str()), Some("Debit"));
+
+        // Saving again replaces the tiers
+        let single = fee_tier(item_id, 1, 0, None, "0.0075");
+        repo.save_fee_item(item, vec![single.clone()]).await.expect("Failed to resave fee item");
+        let tiers = repo.find_fee_tiers(item_id).await.expect("Failed to find fee tiers");
+        assert_eq!(tiers.iter().map(|t| t.id).collect::<Vec<_>>(), vec![single.id]);
+
+        let items = repo.find_fee_items_by_schedule(schedule.id).await.expect("Failed to find fee items");
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].tier01_channel_fee_tier_id, Some(single.id));
+        assert_eq!(items[0].tier02_channel_fee_tier_id, None);
+    }
+
+    fn discrepancy(report_id: Uuid, discrepancy_type: DiscrepancyType, reference: &str) -> ReconciliationDiscrepancyModel {
+        ReconciliationDiscrepancyModel {
+            id: Uuid::new_v4(),
+            report_id,
+            discrepancy_type,
+            transaction_id: None,
+            external_reference: Some(HeaplessString::try_from(reference).unwrap()),
+            description: HeaplessString::try_from("Settlement mismatch").unwrap(),
+            expected_amount: Decimal::ZERO,
+            actual_amount: Decimal::from(100),
+            difference: Decimal::from(100),
+            resolved: false,
+            resolution_notes: None,
+            resolved_by: None,
+            resolved_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolving_last_discrepancy_completes_report() {
+        let pool = setup_test_db().await.expect("Failed to setup test database");
+        cleanup_database(&pool).await;
+        let repo = ChannelRepositoryImpl::new(pool);
+        let channel_id = Uuid::new_v4();
+        let now = Utc::now();
+        let report = ChannelReconciliationReportModel {
+            id: Uuid::new_v4(),
+            channel_id,
+            reconciliation_date: now.date_naive(),
+            total_transactions: 3,
+            total_amount: Decimal::from(300),
+            status: ReconciliationStatus::RequiresManualReview,
+            generated_at: now,
+            completed_at: None,
+            created_at: now,
+        };
+        let missing = discrepancy(report.id, DiscrepancyType::MissingOurs, "EXT-1");
+        let duplicate = discrepancy(report.id, DiscrepancyType::Duplicate, "EXT-2");
+
+        repo.create_reconciliation_report(report.clone(), vec![missing.clone(), duplicate.clone()])
+            .await
+            .expect("Failed to save reconciliation report");
+        let unresolved = repo.find_unresolved_discrepancies(channel_id).await.expect("Failed to find discrepancies");
+        assert_eq!(unresolved.len(), 2);
+        assert!(repo.find_unresolved_discrepancies(Uuid::new_v4()).await.unwrap().is_empty());
+
+        let resolver = Uuid::new_v4();
+        let resolved = repo.resolve_discrepancy(missing.id, "Posted late", resolver, now).await.unwrap().unwrap();
+        assert!(resolved.resolved);
+        assert_eq!(resolved.resolved_by, Some(resolver));
+        assert_eq!(resolved.discrepancy_type, DiscrepancyType::MissingOurs);
+        assert!(repo.resolve_discrepancy(missing.id, "Again", resolver, now).await.unwrap().is_none());
+        let pending = repo.find_reconciliation_report_by_id(report.id).await.unwrap().unwrap();
+        assert_eq!(pending.status, ReconciliationStatus::RequiresManualReview);
+
+        repo.resolve_discrepancy(duplicate.id, "Counted once", resolver, now).await.unwrap().unwrap();
+        let completed = repo.find_reconciliation_report_by_id(report.id).await.unwrap().unwrap();
+        assert_eq!(completed.status, ReconciliationStatus::Completed);
+        assert!(completed.completed_at.is_some());
+        assert!(repo.find_unresolved_discrepancies(channel_id).await.unwrap().is_empty());
+
+        cleanup_database(repo.get_pool()).await;
+    }
 }
\ No newline at end of file
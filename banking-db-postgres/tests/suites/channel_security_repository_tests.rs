@@ -0,0 +1,86 @@
+use banking_db::models::{
+    ChannelRestrictionModel, ChannelSecurityEventModel, DbChannelSecurityEventType, Severity,
+};
+use banking_db::repository::ChannelSecurityRepository;
+use banking_db_postgres::repository::channel_security_repository_impl::ChannelSecurityRepositoryImpl;
+use chrono::{DateTime, Duration, Utc};
+use heapless::String as HeaplessString;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn failed_auth(customer_id: Uuid, occurred_at: DateTime<Utc>) -> ChannelSecurityEventModel {
+    ChannelSecurityEventModel {
+        id: Uuid::new_v4(),
+        channel_id: Uuid::new_v4(),
+        customer_id: Some(customer_id),
+        event_type: DbChannelSecurityEventType::FailedAuth,
+        severity: Severity::Low,
+        context: Some(r#"{"reason":"WrongPin"}"#.to_string()),
+        occurred_at,
+        recorded_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_security_events_are_counted_per_window_and_purged() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = ChannelSecurityRepositoryImpl::new(schema.pg_pool());
+    let customer_id = Uuid::new_v4();
+    let now = Utc::now();
+
+    for minutes_ago in [30, 8, 4, 1] {
+        repo.create_event(failed_auth(customer_id, now - Duration::minutes(minutes_ago))).await.unwrap();
+    }
+    let mut device_change = failed_auth(customer_id, now);
+    device_change.event_type = DbChannelSecurityEventType::DeviceChange;
+    repo.create_event(device_change).await.unwrap();
+
+    let count = repo
+        .count_customer_events(customer_id, DbChannelSecurityEventType::FailedAuth, now - Duration::minutes(10), now)
+        .await
+        .unwrap();
+    assert_eq!(count, 3);
+
+    let recent = repo.find_recent_events_for_customer(customer_id, 2).await.unwrap();
+    assert_eq!(recent.len(), 2);
+    assert_eq!(recent[0].event_type, DbChannelSecurityEventType::DeviceChange);
+    let context: serde_json::Value = serde_json::from_str(recent[1].context.as_deref().unwrap()).unwrap();
+    assert_eq!(context["reason"], "WrongPin");
+
+    assert_eq!(repo.delete_events_before(now - Duration::minutes(10)).await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_channel_restriction_expires_and_can_be_lifted() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = ChannelSecurityRepositoryImpl::new(schema.pg_pool());
+    let customer_id = Uuid::new_v4();
+    let now = Utc::now();
+
+    let restriction = repo
+        .create_restriction(ChannelRestrictionModel {
+            id: Uuid::new_v4(),
+            customer_id,
+            channel_id: Uuid::new_v4(),
+            reason: HeaplessString::try_from("Security rule FAILED_AUTH_BURST fired").unwrap(),
+            rule_code: Some(HeaplessString::try_from("FAILED_AUTH_BURST").unwrap()),
+            starts_at: now,
+            expires_at: Some(now + Duration::minutes(30)),
+            lifted_at: None,
+            lifted_by_person_id: None,
+            created_at: now,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(repo.find_active_restrictions(customer_id, now + Duration::minutes(29)).await.unwrap().len(), 1);
+    assert!(repo.find_active_restrictions(customer_id, now + Duration::minutes(30)).await.unwrap().is_empty());
+
+    let mut lifted = restriction.clone();
+    lifted.lifted_at = Some(now + Duration::minutes(1));
+    lifted.lifted_by_person_id = Some(Uuid::new_v4());
+    repo.update_restriction(lifted).await.unwrap();
+    assert!(repo.find_active_restrictions(customer_id, now + Duration::minutes(2)).await.unwrap().is_empty());
+    let found = repo.find_restriction_by_id(restriction.id).await.unwrap().expect("Restriction not found");
+    assert!(found.lifted_at.is_some());
+}
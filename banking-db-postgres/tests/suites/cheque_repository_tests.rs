@@ -0,0 +1,85 @@
+use banking_db::models::{ChequeBookModel, ChequeModel, DbChequeBookStatus, DbChequeStatus};
+use banking_db::repository::ChequeRepository;
+use banking_db_postgres::repository::cheque_repository_impl::ChequeRepositoryImpl;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn test_person_id() -> Uuid {
+    Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap()
+}
+
+fn create_book(account_id: Uuid, first_serial_number: i64, leaves: i64) -> (ChequeBookModel, Vec<ChequeModel>) {
+    let now = Utc::now();
+    let book = ChequeBookModel {
+        id: Uuid::new_v4(),
+        account_id,
+        first_serial_number,
+        last_serial_number: first_serial_number + leaves - 1,
+        status: DbChequeBookStatus::Issued,
+        requested_at: now,
+        requested_by_person_id: test_person_id(),
+        issued_at: Some(now),
+    };
+    let cheques = (book.first_serial_number..=book.last_serial_number)
+        .map(|serial_number| ChequeModel {
+            id: Uuid::new_v4(),
+            cheque_book_id: book.id,
+            account_id,
+            serial_number,
+            status: DbChequeStatus::Unused,
+            amount: None,
+            presented_at: None,
+            paid_at: None,
+            transaction_id: None,
+            return_reason: None,
+            stop_reason: None,
+            stopped_at: None,
+            stopped_by_person_id: None,
+            last_updated_at: now,
+        })
+        .collect();
+    (book, cheques)
+}
+
+#[tokio::test]
+async fn test_serial_ranges_are_consecutive_per_account() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = ChequeRepositoryImpl::new(schema.pg_pool());
+    let account_id = Uuid::new_v4();
+
+    assert_eq!(repo.reserve_serial_range(account_id, 25).await.unwrap(), 1);
+    assert_eq!(repo.reserve_serial_range(account_id, 10).await.unwrap(), 26);
+    // Other accounts have their own sequence
+    assert_eq!(repo.reserve_serial_range(Uuid::new_v4(), 5).await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_presentment_and_outstanding_cheques() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = ChequeRepositoryImpl::new(schema.pg_pool());
+    let account_id = Uuid::new_v4();
+
+    let first = repo.reserve_serial_range(account_id, 3).await.unwrap();
+    let (book, cheques) = create_book(account_id, first, 3);
+    repo.create_cheque_book(book, cheques).await.expect("Failed to create cheque book");
+    assert_eq!(repo.find_outstanding_cheques(account_id).await.unwrap().len(), 3);
+
+    let cheque = repo.find_cheque(account_id, first).await.unwrap().expect("Cheque not found");
+    assert!(repo.mark_presented(cheque.id, Decimal::from(100), Utc::now()).await.unwrap());
+    // A second presentment of the same leaf loses
+    assert!(!repo.mark_presented(cheque.id, Decimal::from(100), Utc::now()).await.unwrap());
+
+    // Bounced cheques may be presented again
+    let mut presented = repo.find_cheque(account_id, first).await.unwrap().unwrap();
+    presented.status = DbChequeStatus::Bounced;
+    repo.update_cheque(presented).await.unwrap();
+    assert!(repo.mark_presented(cheque.id, Decimal::from(100), Utc::now()).await.unwrap());
+
+    let outstanding = repo.find_outstanding_cheques(account_id).await.unwrap();
+    assert_eq!(outstanding.iter().map(|c| c.serial_number).collect::<Vec<_>>(), vec![first + 1, first + 2]);
+
+    // Cheques are scoped to their account
+    assert!(repo.find_cheque(Uuid::new_v4(), first).await.unwrap().is_none());
+}
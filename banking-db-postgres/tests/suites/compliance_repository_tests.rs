@@ -1,7 +1,12 @@
-use banking_db::models::compliance::{ComplianceAlertModel, ExtendedComplianceAlertModel, AlertType, Severity, AlertStatus};
+use banking_db::models::compliance::{
+    ComplianceAlertModel, ComplianceRiskScoreModel, ExtendedComplianceAlertModel, AlertType, Severity, AlertStatus,
+    SarDataModel, SarStatus, UboVerificationResultModel,
+};
 use banking_db::repository::compliance_repository::ComplianceRepository;
 use banking_db_postgres::ComplianceRepositoryImpl;
-use chrono::Utc;
+use chrono::{DateTime, Duration, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -49,4 +54,142 @@ async fn test_create_and_find_alert() {
 
     let found_alert = repo.find_alert_by_id(alert.alert_data.id).await.unwrap().unwrap();
     assert_eq!(alert.alert_data.id, found_alert.alert_data.id);
-}
\ No newline at end of file
+}
+
+fn risk_score(customer_id: Uuid, score: i64, category: &str, calculated_at: DateTime<Utc>) -> ComplianceRiskScoreModel {
+    ComplianceRiskScoreModel {
+        id: Uuid::new_v4(),
+        customer_id,
+        risk_score: Decimal::from(score),
+        risk_category: HeaplessString::try_from(category).unwrap(),
+        calculation_method: HeaplessString::try_from("FactorScoring").unwrap(),
+        factors_considered: HeaplessString::try_from("[]").unwrap(),
+        calculated_at,
+        calculated_by: HeaplessString::try_from("SYSTEM").unwrap(),
+        valid_until: None,
+        notes: None,
+        created_at: calculated_at,
+        last_updated_at: calculated_at,
+    }
+}
+
+#[tokio::test]
+async fn test_risk_scores_keep_history_with_latest_current() {
+    let pool = setup_test_db().await;
+    let repo = ComplianceRepositoryImpl::new(pool);
+    let customer_id = Uuid::new_v4();
+    let now = Utc::now();
+
+    repo.create_risk_score(risk_score(customer_id, 10, "Low", now - Duration::days(200))).await.unwrap();
+    repo.create_risk_score(risk_score(customer_id, 70, "High", now - Duration::days(1))).await.unwrap();
+
+    let latest = repo.find_risk_score_by_customer(customer_id).await.unwrap().unwrap();
+    assert_eq!(latest.risk_category.as_str(), "High");
+    assert_eq!(latest.risk_score, Decimal::from(70));
+
+    let history = repo.find_risk_score_history(customer_id).await.unwrap();
+    let categories: Vec<&str> = history.iter().map(|score| score.risk_category.as_str()).collect();
+    assert_eq!(categories, vec!["High", "Low"]);
+}
+
+fn draft_sar(customer_id: Uuid, transaction_id: Uuid, generated_at: DateTime<Utc>) -> SarDataModel {
+    SarDataModel {
+        id: Uuid::new_v4(),
+        customer_id,
+        reason_id: Uuid::new_v4(),
+        additional_details: None,
+        supporting_transaction_id_01: Some(transaction_id),
+        supporting_transaction_id_02: None,
+        supporting_transaction_id_03: None,
+        supporting_transaction_id_04: None,
+        supporting_transaction_id_05: None,
+        supporting_transaction_id_06: None,
+        supporting_transaction_id_07: None,
+        supporting_transaction_id_08: None,
+        supporting_transaction_id_09: None,
+        supporting_transaction_id_10: None,
+        supporting_transaction_id_11: None,
+        supporting_transaction_id_12: None,
+        supporting_transaction_id_13: None,
+        supporting_transaction_id_14: None,
+        supporting_transaction_id_15: None,
+        supporting_transaction_id_16: None,
+        supporting_transaction_id_17: None,
+        supporting_transaction_id_18: None,
+        supporting_transaction_id_19: None,
+        narrative: HeaplessString::try_from("Structured cash deposits").unwrap(),
+        customer_full_name: HeaplessString::try_from("Test Customer").unwrap(),
+        alert_ids: vec![Uuid::new_v4()],
+        account_ids: vec![Uuid::new_v4()],
+        generated_at,
+        status: SarStatus::Draft,
+        created_by_person_id: Uuid::new_v4(),
+        submitted_by_person_id: None,
+        submitted_at: None,
+        last_updated_at: generated_at,
+    }
+}
+
+#[tokio::test]
+async fn test_submitted_sar_no_longer_updates_as_draft() {
+    let pool = setup_test_db().await;
+    let repo = ComplianceRepositoryImpl::new(pool);
+    let customer_id = Uuid::new_v4();
+    let now = Utc::now();
+    let (transaction_id, second_transaction_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+    let mut sar = repo.create_sar_data(draft_sar(customer_id, transaction_id, now)).await.unwrap();
+    let found = repo.find_sar_by_id(sar.id).await.unwrap().unwrap();
+    assert_eq!(found.supporting_transaction_id_01, Some(transaction_id));
+    assert_eq!(found.supporting_transaction_id_02, None);
+    assert_eq!(found.alert_ids, sar.alert_ids);
+
+    sar.supporting_transaction_id_02 = Some(second_transaction_id);
+    sar.status = SarStatus::Submitted;
+    sar.submitted_by_person_id = Some(Uuid::new_v4());
+    sar.submitted_at = Some(now);
+    let submitted = repo.update_draft_sar(sar.clone()).await.unwrap();
+    assert_eq!(submitted.status, SarStatus::Submitted);
+    assert_eq!(submitted.supporting_transaction_id_02, Some(second_transaction_id));
+
+    sar.narrative = HeaplessString::try_from("Rewritten after submission").unwrap();
+    assert!(repo.update_draft_sar(sar.clone()).await.is_err());
+
+    let listed = repo.find_sars(Some(SarStatus::Submitted), now - Duration::minutes(1), now + Duration::minutes(1)).await.unwrap();
+    assert!(listed.iter().any(|s| s.id == sar.id && s.narrative.as_str() == "Structured cash deposits"));
+    let drafts = repo.find_sars(Some(SarStatus::Draft), now - Duration::minutes(1), now + Duration::minutes(1)).await.unwrap();
+    assert!(drafts.iter().all(|s| s.id != sar.id));
+}
+
+#[tokio::test]
+async fn test_ubo_verification_result_replaces_previous() {
+    let pool = setup_test_db().await;
+    let repo = ComplianceRepositoryImpl::new(pool);
+    let corporate_customer_id = Uuid::new_v4();
+    let link_id = Uuid::new_v4();
+
+    let mut result = UboVerificationResultModel {
+        corporate_customer_id,
+        ubo_chain_link_id_01: Some(link_id),
+        ubo_chain_link_id_02: None,
+        ubo_chain_link_id_03: None,
+        ubo_chain_link_id_04: None,
+        ubo_chain_link_id_05: None,
+        verification_complete: false,
+        requires_update_01: Some(HeaplessString::try_from("Ownership cycle").unwrap()),
+        requires_update_02: None,
+        requires_update_03: None,
+        requires_update_04: None,
+        requires_update_05: None,
+    };
+    let saved = repo.save_ubo_verification_result(result.clone()).await.unwrap();
+    assert_eq!(saved.ubo_chain_link_id_01, Some(link_id));
+    assert_eq!(saved.requires_update_01.as_ref().map(|u| u.as_str()), Some("Ownership cycle"));
+
+    result.verification_complete = true;
+    result.requires_update_01 = None;
+    let resaved = repo.save_ubo_verification_result(result).await.unwrap();
+    assert!(resaved.verification_complete);
+    assert_eq!(resaved.ubo_chain_link_id_01, Some(link_id));
+    assert_eq!(resaved.requires_update_01, None);
+}
@@ -51,6 +51,8 @@ mod tests {
             created_at: Utc::now(),
             last_updated_at: Utc::now(),
             updated_by_person_id: test_person_id,
+            preferred_language_code: Some(*b"eng"),
+            duplicate_of_customer_id: None,
         }
     }
 
@@ -373,4 +375,134 @@ mod tests {
         assert!(!audit_trail.is_empty());
         assert!(audit_trail.iter().any(|entry| entry.id == audit_entry.id));
     }
-}
\ No newline at end of file
+
+    /// Insert an account solely owned by the customer
+    async fn create_owned_account(pool: &PgPool, customer_id: Uuid) -> Uuid {
+        let account_id = Uuid::new_v4();
+        let test_person_id = Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap();
+        sqlx::query(
+            r#"
+            INSERT INTO accounts (
+                id, product_id, account_type, account_status,
+                signing_condition, currency, open_date, domicile_agency_branch_id,
+                current_balance, available_balance, accrued_interest,
+                created_at, last_updated_at, updated_by_person_id
+            ) VALUES (
+                $1, $2, 'Savings', 'Active',
+                'AnyOwner', 'USD', '2024-01-01', $3,
+                0.00, 0.00, 0.00,
+                NOW(), NOW(), $4
+            )
+            "#
+        )
+        .bind(account_id)
+        .bind(Uuid::new_v4())
+        .bind(Uuid::new_v4())
+        .bind(test_person_id)
+        .execute(pool)
+        .await
+        .expect("Failed to create account");
+        sqlx::query(
+            r#"
+            INSERT INTO account_ownership (id, account_id, customer_id, ownership_type, ownership_percentage)
+            VALUES ($1, $2, $3, 'Single'::ownership_type, 100.00)
+            "#
+        )
+        .bind(Uuid::new_v4())
+        .bind(account_id)
+        .bind(customer_id)
+        .execute(pool)
+        .await
+        .expect("Failed to create account ownership");
+        account_id
+    }
+
+    async fn count_ownerships(pool: &PgPool, customer_id: Uuid) -> i64 {
+        sqlx::query_scalar("SELECT COUNT(*) FROM account_ownership WHERE customer_id = $1")
+            .bind(customer_id)
+            .fetch_one(pool)
+            .await
+            .expect("Failed to count account ownerships")
+    }
+
+    fn create_unique_customer(prefix: &str) -> CustomerModel {
+        let mut customer = create_test_customer();
+        let unique_id = Uuid::new_v4().to_string()[0..6].to_string();
+        customer.id_number = HeaplessString::try_from(format!("{prefix}{unique_id}").as_str()).unwrap();
+        customer.full_name = HeaplessString::try_from(format!("Merge Customer {unique_id}").as_str()).unwrap();
+        customer
+    }
+
+    #[tokio::test]
+    async fn test_merge_moves_account_ownership() {
+        let pool = setup_test_db().await;
+        let repo = CustomerRepositoryImpl::new(pool.clone());
+        let survivor = create_unique_customer("SURV");
+        let duplicate = create_unique_customer("DUPL");
+        repo.create(survivor.clone()).await.expect("Failed to create survivor");
+        repo.create(duplicate.clone()).await.expect("Failed to create duplicate");
+        let survivor_account = create_owned_account(&pool, survivor.id).await;
+        let duplicate_account = create_owned_account(&pool, duplicate.id).await;
+
+        repo.merge_into(survivor.id, duplicate.id, "DUPLICATE_RECORD", survivor.updated_by_person_id).await
+            .expect("Failed to merge customers");
+
+        assert_eq!(count_ownerships(&pool, survivor.id).await, 2);
+        assert_eq!(count_ownerships(&pool, duplicate.id).await, 0);
+        let owned: Vec<Uuid> = sqlx::query_scalar("SELECT account_id FROM account_ownership WHERE customer_id = $1")
+            .bind(survivor.id)
+            .fetch_all(&pool)
+            .await
+            .expect("Failed to load account ownerships");
+        assert!(owned.contains(&survivor_account) && owned.contains(&duplicate_account));
+
+        let merged = repo.find_by_id(duplicate.id).await
+            .expect("Failed to find duplicate")
+            .expect("Duplicate not found");
+        assert_eq!(merged.status, CustomerStatus::Merged);
+        assert_eq!(merged.duplicate_of_customer_id, Some(survivor.id));
+
+        let survivor_audit = repo.get_audit_trail(survivor.id).await
+            .expect("Failed to get audit trail");
+        let duplicate_id = duplicate.id.to_string();
+        assert!(survivor_audit.iter().any(|entry|
+            entry.field_name.as_str() == "merged_customer" &&
+            entry.new_value.as_ref().map(|v| v.as_str()) == Some(duplicate_id.as_str())
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_merge_blocked_by_pending_workflow() {
+        use banking_api::{domain::CustomerMergeBlocker, BankingError};
+
+        let pool = setup_test_db().await;
+        let repo = CustomerRepositoryImpl::new(pool.clone());
+        let survivor = create_unique_customer("SURV");
+        let duplicate = create_unique_customer("DUPL");
+        repo.create(survivor.clone()).await.expect("Failed to create survivor");
+        repo.create(duplicate.clone()).await.expect("Failed to create duplicate");
+        let duplicate_account = create_owned_account(&pool, duplicate.id).await;
+        sqlx::query(
+            r#"
+            INSERT INTO account_workflows (id, account_id, workflow_type, current_step, status, initiated_by, initiated_at)
+            VALUES ($1, $2, 'AccountOpening'::workflow_type, 'InitiateRequest', 'PendingAction', $3, NOW())
+            "#
+        )
+        .bind(Uuid::new_v4())
+        .bind(duplicate_account)
+        .bind(duplicate.updated_by_person_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to create workflow");
+
+        let result = repo.merge_into(survivor.id, duplicate.id, "DUPLICATE_RECORD", survivor.updated_by_person_id).await;
+        assert!(matches!(
+            result,
+            Err(BankingError::CustomerMergeBlocked { blocker: CustomerMergeBlocker::PendingWorkflows, .. })
+        ));
+        // Nothing moved
+        assert_eq!(count_ownerships(&pool, duplicate.id).await, 1);
+        let unchanged = repo.find_by_id(duplicate.id).await.unwrap().unwrap();
+        assert_eq!(unchanged.status, CustomerStatus::Active);
+    }
+}
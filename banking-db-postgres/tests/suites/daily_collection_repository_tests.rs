@@ -0,0 +1,185 @@
+use banking_db::models::collateral::AlertSeverity;
+use banking_db::models::daily_collection::{
+    AgentStatus, CollectionAgentModel, CollectionAlertType, CollectionMethod, CollectionRecordModel,
+    CollectionRecordStatus, PerformanceAlertModel,
+};
+use banking_db::repository::daily_collection_repository::DailyCollectionRepository;
+use banking_db_postgres::repository::daily_collection_repository_impl::DailyCollectionRepositoryImpl;
+use chrono::{NaiveDate, TimeZone, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn record(agent_id: Uuid, customer_id: Uuid, day: u32, hour: u32, status: CollectionRecordStatus) -> CollectionRecordModel {
+    let id = Uuid::new_v4();
+    CollectionRecordModel {
+        id,
+        customer_id,
+        collection_agent_id: agent_id,
+        collection_program_id: Uuid::new_v4(),
+        account_id: Uuid::new_v4(),
+        collection_date: NaiveDate::from_ymd_opt(2024, 5, day).unwrap(),
+        collection_time: Utc.with_ymd_and_hms(2024, 5, day, hour, 0, 0).unwrap(),
+        amount: Decimal::from(500),
+        currency: HeaplessString::try_from("XAF").unwrap(),
+        collection_method: CollectionMethod::Cash,
+        location_id: None,
+        receipt_number: HeaplessString::try_from(format!("R-{}", id.simple()).as_str()).unwrap(),
+        status,
+        notes: None,
+        verification_customer_signature: Some(HeaplessString::try_from("signed").unwrap()),
+        verification_agent_verification_code: None,
+        verification_fingerprint_hash: None,
+        verification_face_recognition_score: None,
+        verification_biometric_method: None,
+        verification_confidence_level: None,
+        verification_customer_photo_hash: None,
+        verification_receipt_photo_hash: None,
+        verification_location_photo_hash: None,
+        verification_photo_timestamp: None,
+        verification_witness_name: None,
+        verification_witness_contact: None,
+        verification_witness_relationship: None,
+        verification_witness_signature: None,
+        verification_timestamp: Some(Utc.with_ymd_and_hms(2024, 5, day, hour, 1, 0).unwrap()),
+        agent_latitude: None,
+        agent_longitude: None,
+        geo_verification_status: None,
+        geo_distance_meters: None,
+        created_at: Utc::now(),
+        processed_at: None,
+        reason_id: None,
+    }
+}
+
+#[tokio::test]
+async fn test_find_collection_records_by_agent_date_range_and_status() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = DailyCollectionRepositoryImpl::new(schema.pool());
+    let (agent, other_agent) = (Uuid::new_v4(), Uuid::new_v4());
+    let (customer, other_customer) = (Uuid::new_v4(), Uuid::new_v4());
+    let date = |d| NaiveDate::from_ymd_opt(2024, 5, d).unwrap();
+
+    // Two collections a day over the 13th to 15th for each agent, one reversed on the 14th
+    for day in 13..=15 {
+        for (agent_id, hour) in [(agent, 9), (other_agent, 10)] {
+            repo.create_collection_record(record(agent_id, customer, day, hour, CollectionRecordStatus::Processed))
+                .await
+                .unwrap();
+            let status = if day == 14 { CollectionRecordStatus::Reversed } else { CollectionRecordStatus::Processed };
+            repo.create_collection_record(record(agent_id, other_customer, day, hour + 6, status))
+                .await
+                .unwrap();
+        }
+    }
+
+    let agent_records = repo
+        .find_collection_records(Some(agent), None, date(13), date(15), None, 1, 50)
+        .await
+        .unwrap();
+    assert_eq!(agent_records.len(), 6);
+    assert!(agent_records.iter().all(|r| r.collection_agent_id == agent));
+    assert!(agent_records.windows(2).all(|w| w[0].collection_time >= w[1].collection_time));
+    assert_eq!(agent_records[0].collection_date, date(15));
+    assert!(agent_records[0].verification_customer_signature.is_some());
+
+    let last_two_days = repo
+        .find_collection_records(Some(agent), None, date(14), date(15), None, 1, 50)
+        .await
+        .unwrap();
+    assert_eq!(last_two_days.len(), 4);
+
+    let reversed = repo
+        .find_collection_records(None, None, date(13), date(15), Some(CollectionRecordStatus::Reversed), 1, 50)
+        .await
+        .unwrap();
+    assert_eq!(reversed.len(), 2);
+    assert!(reversed.iter().all(|r| r.collection_date == date(14) && r.customer_id == other_customer));
+
+    let customer_for_agent = repo
+        .find_collection_records(Some(other_agent), Some(customer), date(13), date(15), None, 1, 50)
+        .await
+        .unwrap();
+    assert_eq!(customer_for_agent.len(), 3);
+
+    let second_page = repo
+        .find_collection_records(Some(agent), None, date(13), date(15), None, 2, 4)
+        .await
+        .unwrap();
+    assert_eq!(second_page.len(), 2);
+    assert_eq!(second_page[1].collection_date, date(13));
+}
+
+fn alert(agent_performance_metrics_id: Uuid, resolution_required: bool) -> PerformanceAlertModel {
+    PerformanceAlertModel {
+        id: Uuid::new_v4(),
+        agent_performance_metrics_id,
+        alert_type: CollectionAlertType::CashDiscrepancy,
+        severity: AlertSeverity::High,
+        message: HeaplessString::try_from("Cash short by 700").unwrap(),
+        acknowledged: false,
+        resolution_required,
+        created_at: Utc::now(),
+        acknowledged_at: None,
+        acknowledged_by_person_id: None,
+        resolved_at: None,
+        resolved_by_person_id: None,
+        resolution_notes: None,
+    }
+}
+
+#[tokio::test]
+async fn test_performance_alert_acknowledge_then_resolve() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = DailyCollectionRepositoryImpl::new(schema.pool());
+    let supervisor = Uuid::new_v4();
+    let agent = repo
+        .create_collection_agent(CollectionAgentModel {
+            id: Uuid::new_v4(),
+            person_id: Uuid::new_v4(),
+            license_number: HeaplessString::try_from("LIC-0001").unwrap(),
+            license_expiry: NaiveDate::from_ymd_opt(2026, 12, 31).unwrap(),
+            status: AgentStatus::Active,
+            assigned_territory_id: Uuid::new_v4(),
+            agent_performance_metrics_id: Uuid::new_v4(),
+            cash_limit: Decimal::from(500_000),
+            device_information_id: Uuid::new_v4(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        })
+        .await
+        .unwrap();
+    let required = repo.create_performance_alert(alert(agent.agent_performance_metrics_id, true)).await.unwrap();
+    let informational = repo.create_performance_alert(alert(agent.agent_performance_metrics_id, false)).await.unwrap();
+
+    // Unacknowledged alerts requiring resolution stay open
+    let refused = repo.resolve_performance_alert(required.id, supervisor, None, Utc::now()).await.unwrap();
+    assert!(refused.is_none());
+    assert_eq!(repo.find_open_alerts_by_agent(agent.id).await.unwrap().len(), 2);
+
+    let acknowledged = repo
+        .acknowledge_performance_alert(required.id, supervisor, Utc::now())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(acknowledged.acknowledged);
+    assert_eq!(acknowledged.acknowledged_by_person_id, Some(supervisor));
+
+    let notes = HeaplessString::try_from("Recounted with agent").unwrap();
+    let resolved = repo
+        .resolve_performance_alert(required.id, supervisor, Some(notes.clone()), Utc::now())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(resolved.resolved_at.is_some());
+    assert_eq!(resolved.resolution_notes, Some(notes));
+
+    let open = repo.find_open_alerts_by_agent(agent.id).await.unwrap();
+    assert_eq!(open.len(), 1);
+    assert_eq!(open[0].id, informational.id);
+
+    // Resolved alerts can be neither acknowledged nor resolved again
+    assert!(repo.acknowledge_performance_alert(required.id, supervisor, Utc::now()).await.unwrap().is_none());
+    assert!(repo.resolve_performance_alert(required.id, supervisor, None, Utc::now()).await.unwrap().is_none());
+}
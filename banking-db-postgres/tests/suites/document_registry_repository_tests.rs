@@ -0,0 +1,61 @@
+use banking_db::models::{DbDocumentLinkKind, DocumentLinkModel, RegisteredDocumentModel};
+use banking_db::repository::DocumentRegistryRepository;
+use banking_db_postgres::repository::document_registry_repository_impl::DocumentRegistryRepositoryImpl;
+use chrono::Utc;
+use heapless::String as HeaplessString;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn document(content_hash: [u8; 32]) -> RegisteredDocumentModel {
+    RegisteredDocumentModel {
+        id: Uuid::new_v4(),
+        content_hash: content_hash.into(),
+        document_type: HeaplessString::try_from("NationalId").unwrap(),
+        document_path: None,
+        size_bytes: Some(2048),
+        registered_at: Utc::now(),
+        registered_by_person_id: Uuid::new_v4(),
+    }
+}
+
+fn link(document_id: Uuid, entity_kind: DbDocumentLinkKind, entity_id: Uuid) -> DocumentLinkModel {
+    DocumentLinkModel {
+        id: Uuid::new_v4(),
+        document_id,
+        entity_kind,
+        entity_id,
+        linked_at: Utc::now(),
+        linked_by_person_id: Uuid::new_v4(),
+    }
+}
+
+#[tokio::test]
+async fn test_register_document_deduplicates_by_content_hash() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = DocumentRegistryRepositoryImpl::new(schema.pg_pool());
+
+    let first = repo.register_document(document([7; 32])).await.unwrap();
+    let again = repo.register_document(document([7; 32])).await.unwrap();
+    let other = repo.register_document(document([8; 32])).await.unwrap();
+
+    assert_eq!(again.id, first.id);
+    assert_ne!(other.id, first.id);
+    assert_eq!(repo.find_document_by_hash(&[7; 32]).await.unwrap().unwrap().id, first.id);
+}
+
+#[tokio::test]
+async fn test_linked_document_is_not_deleted() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = DocumentRegistryRepositoryImpl::new(schema.pg_pool());
+    let registered = repo.register_document(document([9; 32])).await.unwrap();
+    let workflow_id = Uuid::new_v4();
+
+    let linked = repo.link_document(link(registered.id, DbDocumentLinkKind::Workflow, workflow_id)).await.unwrap();
+    let relinked = repo.link_document(link(registered.id, DbDocumentLinkKind::Workflow, workflow_id)).await.unwrap();
+    assert_eq!(relinked.id, linked.id);
+
+    let linked_documents = repo.find_documents_by_link(DbDocumentLinkKind::Workflow, workflow_id).await.unwrap();
+    assert_eq!(linked_documents.len(), 1);
+    assert!(!repo.delete_unlinked_document(registered.id).await.unwrap());
+    assert!(repo.find_document_by_id(registered.id).await.unwrap().is_some());
+}
@@ -0,0 +1,64 @@
+use banking_db::models::{DbEodRunStatus, DbEodStepStatus, EodRunModel, EodRunStepModel};
+use banking_db::repository::EodRunRepository;
+use banking_db_postgres::repository::eod_run_repository_impl::EodRunRepositoryImpl;
+use chrono::{NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn step(run: &EodRunModel, step_name: &str, step_order: i32) -> EodRunStepModel {
+    EodRunStepModel {
+        id: Uuid::new_v4(),
+        eod_run_id: run.id,
+        run_date: run.run_date,
+        step_name: HeaplessString::try_from(step_name).unwrap(),
+        step_order,
+        status: DbEodStepStatus::Pending,
+        started_at: None,
+        finished_at: None,
+        records_processed: 0,
+        records_failed: 0,
+        checkpoint_cursor: None,
+        error: None,
+    }
+}
+
+#[tokio::test]
+async fn test_step_checkpoints_replace_the_saved_progress() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = EodRunRepositoryImpl::new(schema.pg_pool());
+    let run_date = NaiveDate::from_ymd_opt(2024, 5, 20).unwrap();
+
+    let run = repo.create_run(EodRunModel {
+        id: Uuid::new_v4(),
+        run_date,
+        status: DbEodRunStatus::Running,
+        started_at: Utc::now(),
+        finished_at: None,
+        forced_reruns: 0,
+    }).await.unwrap();
+    repo.save_step(step(&run, "OVERDRAWN_TRACKING", 2)).await.unwrap();
+    let mut accrual = repo.save_step(step(&run, "OVERDRAFT_INTEREST", 1)).await.unwrap();
+
+    let cursor = Uuid::new_v4();
+    accrual.status = DbEodStepStatus::Failed;
+    accrual.records_processed = 500;
+    accrual.checkpoint_cursor = Some(cursor);
+    accrual.error = Some(HeaplessString::try_from("Connection reset").unwrap());
+    repo.save_step(accrual).await.unwrap();
+
+    let steps = repo.find_steps(run.id).await.unwrap();
+    assert_eq!(steps.len(), 2);
+    assert_eq!(steps[0].step_name.as_str(), "OVERDRAFT_INTEREST");
+    assert_eq!(steps[0].status, DbEodStepStatus::Failed);
+    assert_eq!(steps[0].records_processed, 500);
+    assert_eq!(steps[0].checkpoint_cursor, Some(cursor));
+    assert_eq!(steps[1].status, DbEodStepStatus::Pending);
+
+    let mut failed = repo.find_run_by_date(run_date).await.unwrap().unwrap();
+    assert_eq!(failed.id, run.id);
+    failed.status = DbEodRunStatus::Failed;
+    repo.update_run(failed).await.unwrap();
+    assert_eq!(repo.find_run_by_date(run_date).await.unwrap().unwrap().status, DbEodRunStatus::Failed);
+    assert!(repo.find_run_by_date(run_date.succ_opt().unwrap()).await.unwrap().is_none());
+}
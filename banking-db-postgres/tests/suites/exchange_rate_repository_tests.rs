@@ -0,0 +1,51 @@
+use banking_db::models::ExchangeRateModel;
+use banking_db::repository::ExchangeRateRepository;
+use banking_db_postgres::repository::exchange_rate_repository_impl::ExchangeRateRepositoryImpl;
+use chrono::{DateTime, TimeZone, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal_macros::dec;
+use rust_decimal::Decimal;
+use banking_db_postgres::test_helper::setup_test_schema;
+
+fn usd_to_xaf(rate: Decimal, effective_at: DateTime<Utc>) -> ExchangeRateModel {
+    ExchangeRateModel {
+        from_currency: HeaplessString::try_from("USD").unwrap(),
+        to_currency: HeaplessString::try_from("XAF").unwrap(),
+        rate,
+        effective_at,
+        source: HeaplessString::try_from("BEAC").unwrap(),
+    }
+}
+
+#[tokio::test]
+async fn test_get_rate_returns_latest_rate_effective_at_time() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = ExchangeRateRepositoryImpl::new(schema.pg_pool());
+    let morning = Utc.with_ymd_and_hms(2024, 6, 14, 8, 0, 0).unwrap();
+    let afternoon = Utc.with_ymd_and_hms(2024, 6, 14, 14, 0, 0).unwrap();
+
+    repo.save_rate(usd_to_xaf(dec!(605.25), morning)).await.unwrap();
+    repo.save_rate(usd_to_xaf(dec!(607.10), afternoon)).await.unwrap();
+
+    let noon = Utc.with_ymd_and_hms(2024, 6, 14, 12, 0, 0).unwrap();
+    let rate = repo.get_rate("USD", "XAF", noon).await.unwrap().unwrap();
+    assert_eq!(rate.rate, dec!(605.25));
+    assert_eq!(rate.effective_at, morning);
+
+    assert!(repo.get_rate("USD", "XAF", morning - chrono::Duration::seconds(1)).await.unwrap().is_none());
+    assert!(repo.get_rate("XAF", "USD", noon).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_republished_rate_replaces_rate_at_same_time() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = ExchangeRateRepositoryImpl::new(schema.pg_pool());
+    let effective_at = Utc.with_ymd_and_hms(2024, 6, 14, 8, 0, 0).unwrap();
+
+    repo.save_rate(usd_to_xaf(dec!(605.25), effective_at)).await.unwrap();
+    repo.save_rate(usd_to_xaf(dec!(605.30), effective_at)).await.unwrap();
+
+    let history = repo.find_rates_by_pair("USD", "XAF", 10).await.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].rate, dec!(605.30));
+}
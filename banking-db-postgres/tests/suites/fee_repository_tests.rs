@@ -47,6 +47,8 @@ mod fee_repository_tests {
             waived_by: None,
             waived_reason_id: None,
             applied_by,
+            reversal_transaction_id: None,
+            audit_log_id: None,
             created_at: Utc::now(),
         }
     }
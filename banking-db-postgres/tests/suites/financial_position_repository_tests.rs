@@ -0,0 +1,45 @@
+use banking_db::repository::FinancialPositionRepository;
+use banking_db_postgres::repository::financial_position_repository_impl::FinancialPositionRepositoryImpl;
+use chrono::NaiveDate;
+use rust_decimal_macros::dec;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_latest_rate_on_or_before_date_per_currency() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let pool = schema.pg_pool();
+    let repo = FinancialPositionRepositoryImpl::new(pool.clone());
+
+    for (from, rate, date) in [
+        ("XAF", dec!(0.0015), NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()),
+        ("XAF", dec!(0.0016), NaiveDate::from_ymd_opt(2024, 6, 20).unwrap()),
+        ("XAF", dec!(0.0017), NaiveDate::from_ymd_opt(2024, 7, 1).unwrap()),
+        ("USD", dec!(0.92), NaiveDate::from_ymd_opt(2024, 5, 1).unwrap()),
+    ] {
+        sqlx::query("INSERT INTO exchange_rates (from_currency, to_currency, rate, effective_at, source) VALUES ($1, 'EUR', $2, $3, 'ECB')")
+            .bind(from)
+            .bind(rate)
+            .bind(date.and_hms_opt(16, 0, 0).unwrap().and_utc())
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    let rates = repo
+        .find_exchange_rates("EUR", NaiveDate::from_ymd_opt(2024, 6, 30).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(rates.len(), 2);
+    assert_eq!(rates[0].from_currency.as_str(), "USD");
+    assert_eq!(rates[1].rate, dec!(0.0016));
+}
+
+#[tokio::test]
+async fn test_customer_without_accounts_has_no_positions() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = FinancialPositionRepositoryImpl::new(schema.pg_pool());
+
+    assert!(repo.find_currency_positions(Uuid::new_v4()).await.unwrap().is_empty());
+}
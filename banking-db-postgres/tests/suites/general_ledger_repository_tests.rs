@@ -0,0 +1,152 @@
+use banking_db::models::{GlSummaryLineModel, TransactionType};
+use banking_db::repository::GeneralLedgerRepository;
+use banking_db_postgres::repository::general_ledger_repository_impl::GeneralLedgerRepositoryImpl;
+use chrono::{NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal_macros::dec;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn run_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 6, 14).unwrap()
+}
+
+async fn insert_account(pool: &PgPool, product_id: Uuid, branch_id: Uuid) -> Uuid {
+    let id = Uuid::new_v4();
+    sqlx::query(
+        r#"
+        INSERT INTO accounts (
+            id, product_id, gl_code_suffix, account_type, account_status, signing_condition,
+            currency, open_date, domicile_agency_branch_id, current_balance, available_balance,
+            accrued_interest, updated_by_person_id
+        )
+        VALUES (
+            $1, $2, '002', 'Savings'::account_type, 'Active'::account_status, 'None'::signing_condition,
+            'XAF', CURRENT_DATE, $3, 0, 0, 0, $4
+        )
+        "#,
+    )
+    .bind(id)
+    .bind(product_id)
+    .bind(branch_id)
+    .bind(Uuid::new_v4())
+    .execute(pool)
+    .await
+    .expect("Failed to create account");
+    id
+}
+
+async fn insert_transaction(pool: &PgPool, account_id: Uuid, transaction_type: &str, amount: Decimal, channel_id: &str, status: &str) {
+    let id = Uuid::new_v4();
+    sqlx::query(
+        r#"
+        INSERT INTO transactions (
+            id, account_id, transaction_code, transaction_type, amount, currency,
+            description, channel_id, transaction_date, value_date, status, reference_number,
+            gl_code, requires_approval, degraded_flags
+        )
+        VALUES (
+            $1, $2, 'GL_TEST', $3::transaction_type, $4, 'XAF',
+            'GL summary test', $5, $6, $7, $8::transaction_status, $9,
+            '2100002', false, 0
+        )
+        "#,
+    )
+    .bind(id)
+    .bind(account_id)
+    .bind(transaction_type)
+    .bind(amount)
+    .bind(channel_id)
+    .bind(run_date().and_hms_opt(10, 30, 0).unwrap().and_utc())
+    .bind(run_date())
+    .bind(status)
+    .bind(format!("GL{}", &id.simple().to_string()[..12]))
+    .execute(pool)
+    .await
+    .expect("Failed to create transaction");
+}
+
+fn line(gl_code: &str, product_id: Uuid, branch_id: Uuid, debit_total: Decimal, credit_total: Decimal, transaction_count: i64) -> GlSummaryLineModel {
+    GlSummaryLineModel {
+        run_date: run_date(),
+        gl_code: HeaplessString::try_from(gl_code).unwrap(),
+        product_id,
+        branch_id,
+        currency: HeaplessString::try_from("XAF").unwrap(),
+        debit_total,
+        credit_total,
+        transaction_count,
+        created_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_posting_totals_group_by_branch_and_channel() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let pool = schema.pg_pool();
+    let repo = GeneralLedgerRepositoryImpl::new(pool.clone());
+    let product_id = Uuid::new_v4();
+    let (douala, yaounde) = (Uuid::new_v4(), Uuid::new_v4());
+
+    let douala_account = insert_account(&pool, product_id, douala).await;
+    let yaounde_account = insert_account(&pool, product_id, yaounde).await;
+    insert_transaction(&pool, douala_account, "Credit", dec!(100000), "BRANCH", "Posted").await;
+    insert_transaction(&pool, douala_account, "Credit", dec!(50000), "BRANCH", "Posted").await;
+    insert_transaction(&pool, douala_account, "Debit", dec!(40000), "BRANCH", "Reversed").await;
+    insert_transaction(&pool, yaounde_account, "Debit", dec!(25000), "MOBILE", "Posted").await;
+    // Neither posted nor reversed
+    insert_transaction(&pool, yaounde_account, "Debit", dec!(900000), "MOBILE", "AwaitingApproval").await;
+    insert_transaction(&pool, yaounde_account, "Credit", dec!(5000), "MOBILE", "Failed").await;
+
+    let totals = repo.find_posting_totals(run_date()).await.unwrap();
+    assert_eq!(totals.len(), 3);
+    let douala_credits = totals
+        .iter()
+        .find(|t| t.branch_id == douala && t.transaction_type == TransactionType::Credit)
+        .unwrap();
+    assert_eq!(douala_credits.amount_total, dec!(150000));
+    assert_eq!(douala_credits.transaction_count, 2);
+    assert_eq!(douala_credits.gl_code_suffix.as_ref().map(|s| s.as_str()), Some("002"));
+    let yaounde_debits = totals.iter().find(|t| t.branch_id == yaounde).unwrap();
+    assert_eq!((yaounde_debits.amount_total, yaounde_debits.channel_id.as_str()), (dec!(25000), "MOBILE"));
+
+    let next_day = run_date().succ_opt().unwrap();
+    assert!(repo.find_posting_totals(next_day).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_replace_gl_summary_overwrites_the_run_date() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = GeneralLedgerRepositoryImpl::new(schema.pg_pool());
+    let product_id = Uuid::new_v4();
+    let (douala, yaounde) = (Uuid::new_v4(), Uuid::new_v4());
+
+    let first_run = vec![
+        line("2100002", product_id, douala, dec!(40000), dec!(150000), 3),
+        line("101000", product_id, douala, dec!(150000), dec!(40000), 3),
+    ];
+    repo.replace_gl_summary(run_date(), first_run).await.unwrap();
+
+    let rerun = vec![
+        line("2100002", product_id, douala, dec!(40000), dec!(150000), 3),
+        line("101000", product_id, douala, dec!(150000), dec!(40000), 3),
+        line("2100002", product_id, yaounde, dec!(25000), Decimal::ZERO, 1),
+        line("192000", product_id, yaounde, Decimal::ZERO, dec!(25000), 1),
+    ];
+    let saved = repo.replace_gl_summary(run_date(), rerun).await.unwrap();
+    assert_eq!(saved.len(), 4);
+
+    let summary = repo.find_gl_summary(run_date()).await.unwrap();
+    assert_eq!(summary.len(), 4);
+    let debits: Decimal = summary.iter().map(|l| l.debit_total).sum();
+    let credits: Decimal = summary.iter().map(|l| l.credit_total).sum();
+    assert_eq!((debits, credits), (dec!(215000), dec!(215000)));
+
+    let mut stray = line("101000", product_id, douala, dec!(1), dec!(1), 1);
+    stray.run_date = run_date().succ_opt().unwrap();
+    assert!(repo.replace_gl_summary(run_date(), vec![stray]).await.is_err());
+    // The failed replacement rolled back
+    assert_eq!(repo.find_gl_summary(run_date()).await.unwrap().len(), 4);
+}
@@ -0,0 +1,56 @@
+use banking_db::models::{DbNoteCategory, DbNoteEntityKind, DbNoteVisibility, InteractionNoteModel};
+use banking_db::repository::InteractionNoteRepository;
+use banking_db_postgres::repository::interaction_note_repository_impl::InteractionNoteRepositoryImpl;
+use chrono::{NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn note(account_id: Uuid, author_person_id: Uuid, follow_up_date: Option<NaiveDate>) -> InteractionNoteModel {
+    InteractionNoteModel {
+        id: Uuid::new_v4(),
+        entity_kind: DbNoteEntityKind::Account,
+        entity_id: account_id,
+        author_person_id,
+        agency_branch_id: Some(Uuid::new_v4()),
+        visibility: DbNoteVisibility::BranchOnly,
+        category: DbNoteCategory::BranchVisit,
+        body: HeaplessString::try_from("Customer will bring updated payslips").unwrap(),
+        corrects_note_id: None,
+        follow_up_date,
+        follow_up_completed_at: None,
+        created_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_follow_ups_leave_pending_lists_once_completed() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = InteractionNoteRepositoryImpl::new(schema.pg_pool());
+    let (account_id, author) = (Uuid::new_v4(), Uuid::new_v4());
+    let date = |d| NaiveDate::from_ymd_opt(2024, 5, d).unwrap();
+
+    let due = repo.create_note(note(account_id, author, Some(date(10)))).await.unwrap();
+    repo.create_note(note(account_id, author, Some(date(20)))).await.unwrap();
+    let mut correction = note(account_id, author, None);
+    correction.corrects_note_id = Some(due.id);
+    correction.visibility = DbNoteVisibility::ComplianceOnly;
+    repo.create_note(correction).await.unwrap();
+
+    let notes = repo.find_notes_by_entity(DbNoteEntityKind::Account, account_id).await.unwrap();
+    assert_eq!(notes.len(), 3);
+    assert_eq!(notes[0].corrects_note_id, Some(due.id));
+    assert_eq!(notes[0].visibility, DbNoteVisibility::ComplianceOnly);
+    assert_eq!(repo.find_pending_follow_ups_by_author(author).await.unwrap().len(), 2);
+    let due_on_15th = repo.find_follow_ups_due(date(15)).await.unwrap();
+    assert!(due_on_15th.iter().any(|n| n.id == due.id));
+    assert!(due_on_15th.iter().all(|n| n.follow_up_date <= Some(date(15))));
+
+    let completed = repo.complete_follow_up(due.id, Utc::now()).await.unwrap();
+    assert!(completed.follow_up_completed_at.is_some());
+    assert_eq!(completed.body, due.body);
+    let pending = repo.find_pending_follow_ups_by_author(author).await.unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].follow_up_date, Some(date(20)));
+    assert!(!repo.find_follow_ups_due(date(15)).await.unwrap().iter().any(|n| n.id == due.id));
+}
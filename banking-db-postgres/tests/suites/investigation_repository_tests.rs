@@ -0,0 +1,65 @@
+use banking_db::models::{DbTimelineSource, TimelineAccessRecordModel};
+use banking_db::repository::InvestigationRepository;
+use banking_db_postgres::repository::investigation_repository_impl::InvestigationRepositoryImpl;
+use chrono::{Duration, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn access(account_id: Uuid, exported: bool) -> TimelineAccessRecordModel {
+    TimelineAccessRecordModel {
+        id: Uuid::new_v4(),
+        account_id,
+        from_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+        to_date: NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+        accessed_by_person_id: Uuid::new_v4(),
+        case_reference: HeaplessString::try_from("CASE-2024-017").unwrap(),
+        events_returned: 42,
+        exported,
+        accessed_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_timeline_access_is_recorded_newest_first() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = InvestigationRepositoryImpl::new(schema.pg_pool());
+    let account_id = Uuid::new_v4();
+
+    let viewed = repo.record_access(access(account_id, false)).await.unwrap();
+    let mut export = access(account_id, true);
+    export.accessed_at = viewed.accessed_at + Duration::seconds(1);
+    repo.record_access(export).await.unwrap();
+
+    let records = repo.find_access_by_account(account_id).await.unwrap();
+    assert_eq!(records.len(), 2);
+    assert!(records[0].exported);
+    assert_eq!(records[1].id, viewed.id);
+    assert_eq!(records[1].case_reference.as_str(), "CASE-2024-017");
+    assert_eq!(records[1].events_returned, 42);
+}
+
+#[tokio::test]
+async fn test_every_timeline_source_is_empty_for_unknown_account() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = InvestigationRepositoryImpl::new(schema.pg_pool());
+    let account_id = Uuid::new_v4();
+    let to = Utc::now();
+    let from = to - Duration::days(30);
+
+    assert!(!repo.account_exists(account_id).await.unwrap());
+    assert_eq!(repo.count_transactions(account_id, from, to).await.unwrap(), 0);
+    for source in [
+        DbTimelineSource::Workflows,
+        DbTimelineSource::WorkflowSteps,
+        DbTimelineSource::StatusChanges,
+        DbTimelineSource::Holds,
+        DbTimelineSource::Restrictions,
+        DbTimelineSource::Transactions,
+        DbTimelineSource::TransactionDays,
+        DbTimelineSource::Notifications,
+    ] {
+        let page = repo.find_timeline_page(source, account_id, from, to, None, 50).await.unwrap();
+        assert!(page.is_empty(), "{source:?} returned rows");
+    }
+}
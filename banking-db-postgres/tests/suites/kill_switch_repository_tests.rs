@@ -0,0 +1,50 @@
+use banking_db::models::{DbKillSwitchScope, DbKillSwitchState, KillSwitchModel};
+use banking_db::repository::KillSwitchRepository;
+use banking_db_postgres::repository::kill_switch_repository_impl::KillSwitchRepositoryImpl;
+use chrono::{Duration, Utc};
+use heapless::String as HeaplessString;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn channel_switch(channel: &str) -> KillSwitchModel {
+    KillSwitchModel {
+        id: Uuid::new_v4(),
+        scope: DbKillSwitchScope::Channel,
+        scope_value: Some(HeaplessString::try_from(channel).unwrap()),
+        state: DbKillSwitchState::Active,
+        reason: HeaplessString::try_from("Mobile gateway incident").unwrap(),
+        activated_by_person_id: Uuid::new_v4(),
+        activated_at: Utc::now(),
+        auto_expire_at: Some(Utc::now() + Duration::hours(2)),
+        deactivated_by_person_id: None,
+        deactivated_at: None,
+    }
+}
+
+#[tokio::test]
+async fn test_deactivated_switch_leaves_active_set_but_stays_in_history() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = KillSwitchRepositoryImpl::new(schema.pg_pool());
+    let started = Utc::now() - Duration::minutes(1);
+
+    let mut mobile = repo.create_kill_switch(channel_switch("MOBILE")).await.unwrap();
+    repo.create_kill_switch(channel_switch("USSD")).await.unwrap();
+    assert_eq!(mobile.scope, DbKillSwitchScope::Channel);
+    assert_eq!(mobile.scope_value.as_ref().map(|v| v.as_str()), Some("MOBILE"));
+    assert_eq!(repo.find_active_kill_switches().await.unwrap().len(), 2);
+
+    mobile.state = DbKillSwitchState::Deactivated;
+    mobile.deactivated_by_person_id = Some(Uuid::new_v4());
+    mobile.deactivated_at = Some(Utc::now());
+    repo.update_kill_switch(mobile.clone()).await.unwrap();
+
+    let active = repo.find_active_kill_switches().await.unwrap();
+    assert_eq!(active.len(), 1);
+    assert_eq!(active[0].scope_value.as_ref().map(|v| v.as_str()), Some("USSD"));
+
+    let history = repo.find_kill_switches_activated_between(started, Utc::now()).await.unwrap();
+    assert_eq!(history.len(), 2);
+    let found = repo.find_kill_switch_by_id(mobile.id).await.unwrap().unwrap();
+    assert_eq!(found.state, DbKillSwitchState::Deactivated);
+    assert_eq!(found.deactivated_by_person_id, mobile.deactivated_by_person_id);
+}
@@ -1,13 +1,42 @@
 // pub mod account_repository_tests;
+// pub mod account_number_repository_tests;
 // pub mod channel_repository_tests;
+// pub mod cheque_repository_tests;
 // pub mod cleanup_demo;
 // pub mod compliance_repository_tests;
 // pub mod customer_repository_tests;
+// pub mod daily_collection_repository_tests;
 // pub mod example_with_cleanup;
 // pub mod fee_repository_tests;
 pub mod commons;
-pub mod test_helper;
 // pub mod reason_and_purpose_repository_tests;
+// pub mod segment_repository_tests;
+// pub mod statement_repository_tests;
+// pub mod orchestration_repository_tests;
+// pub mod tag_repository_tests;
+// pub mod channel_security_repository_tests;
+// pub mod agent_commission_repository_tests;
+// pub mod document_registry_repository_tests;
+// pub mod branch_cash_repository_tests;
+// pub mod notification_repository_tests;
+// pub mod investigation_repository_tests;
+// pub mod bundle_repository_tests;
+// pub mod financial_position_repository_tests;
+// pub mod exchange_rate_repository_tests;
+// pub mod promotion_repository_tests;
+// pub mod savings_goal_repository_tests;
+// pub mod payee_repository_tests;
+// pub mod back_dated_posting_repository_tests;
+// pub mod account_domicile_repository_tests;
+// pub mod kill_switch_repository_tests;
+// pub mod interaction_note_repository_tests;
+// pub mod verification_repository_tests;
+// pub mod warehouse_export_repository_tests;
+// pub mod standing_order_repository_tests;
+// pub mod agent_network_repository_tests;
+// pub mod general_ledger_repository_tests;
+// pub mod eod_run_repository_tests;
 // pub mod transaction_repository_tests;
 // pub mod unit_tests;
-// pub mod workflow_repository_tests;
\ No newline at end of file
+// pub mod workflow_repository_tests;
+// pub mod workflow_escalation_repository_tests;
\ No newline at end of file
@@ -0,0 +1,94 @@
+use banking_db::models::{DbDocumentNotificationStatus, DbMessageChannel, MessageTemplateModel, QueuedNotificationModel};
+use banking_db::repository::NotificationRepository;
+use banking_db_postgres::repository::notification_repository_impl::NotificationRepositoryImpl;
+use chrono::{NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn notification(customer_id: Uuid, business_date: NaiveDate) -> QueuedNotificationModel {
+    QueuedNotificationModel {
+        id: Uuid::new_v4(),
+        idempotency_key: HeaplessString::try_from(format!("DORMANCY_NOTICE:{customer_id}:{business_date}:ab").as_str()).unwrap(),
+        template_code: HeaplessString::try_from("DORMANCY_NOTICE").unwrap(),
+        customer_id,
+        business_date,
+        body: HeaplessString::try_from("Your account has been classified dormant.").unwrap(),
+        status: DbDocumentNotificationStatus::Queued,
+        queued_at: Utc::now(),
+        sent_at: None,
+    }
+}
+
+#[tokio::test]
+async fn test_duplicate_key_returns_first_notification_and_is_counted() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = NotificationRepositoryImpl::new(schema.pg_pool());
+    let customer_id = Uuid::new_v4();
+    let business_date = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+
+    let first = repo.queue_notification(notification(customer_id, business_date)).await.unwrap();
+    let rerun = repo.queue_notification(notification(customer_id, business_date)).await.unwrap();
+
+    assert_eq!(rerun.id, first.id);
+    assert_eq!(repo.find_notifications_by_customer(customer_id, business_date).await.unwrap().len(), 1);
+    let summary = repo.summarize_duplicates(business_date).await.unwrap();
+    assert_eq!(summary.len(), 1);
+    assert_eq!(summary[0].notifications_queued, 1);
+    assert_eq!(summary[0].duplicates_suppressed, 1);
+}
+
+#[tokio::test]
+async fn test_expired_keys_are_deleted_but_notifications_kept() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = NotificationRepositoryImpl::new(schema.pg_pool());
+    let customer_id = Uuid::new_v4();
+    let old_date = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    let recent_date = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+    let old = repo.queue_notification(notification(customer_id, old_date)).await.unwrap();
+    repo.queue_notification(notification(customer_id, recent_date)).await.unwrap();
+
+    assert_eq!(repo.delete_keys_before(NaiveDate::from_ymd_opt(2024, 4, 1).unwrap()).await.unwrap(), 1);
+    assert!(repo.summarize_duplicates(old_date).await.unwrap().is_empty());
+    assert_eq!(repo.summarize_duplicates(recent_date).await.unwrap().len(), 1);
+    assert!(repo.find_notification_by_id(old.id).await.unwrap().is_some());
+}
+
+fn message_template(language_code: [u8; 3], body: &str) -> MessageTemplateModel {
+    MessageTemplateModel {
+        id: Uuid::new_v4(),
+        template_code: HeaplessString::try_from("STATEMENT_READY").unwrap(),
+        language_code,
+        channel: DbMessageChannel::Email,
+        subject: Some(HeaplessString::try_from("Statement {statement_reference}").unwrap()),
+        body: HeaplessString::try_from(body).unwrap(),
+        regulatory: false,
+        updated_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_message_template_is_replaced_per_language() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = NotificationRepositoryImpl::new(schema.pg_pool());
+
+    let english = repo.save_message_template(message_template(*b"eng", "Your statement is ready.")).await.unwrap();
+    repo.save_message_template(message_template(*b"fra", "Votre relevé est prêt.")).await.unwrap();
+    let reworded = repo
+        .save_message_template(message_template(*b"eng", "Your statement {statement_reference} is ready."))
+        .await
+        .unwrap();
+
+    assert_eq!(reworded.id, english.id);
+    let templates = repo.find_message_templates("STATEMENT_READY").await.unwrap();
+    assert_eq!(templates.len(), 2);
+    assert_eq!(templates[0].language_code, *b"eng");
+    assert_eq!(templates[0].body.as_str(), "Your statement {statement_reference} is ready.");
+    assert_eq!(templates[0].channel, DbMessageChannel::Email);
+    assert_eq!(templates[1].language_code, *b"fra");
+
+    // Seeded by the migration in the default language
+    let seeded = repo.find_message_templates("DORMANCY_NOTICE").await.unwrap();
+    assert_eq!(seeded.len(), 1);
+    assert!(seeded[0].body.contains("{account_suffix}"));
+}
@@ -0,0 +1,88 @@
+use banking_db::models::{
+    DbOrchestrationStatus, DbOrchestrationStepStatus, DbOrchestrationType, OrchestrationModel,
+    OrchestrationStepModel,
+};
+use banking_db::repository::OrchestrationRepository;
+use banking_db_postgres::repository::orchestration_repository_impl::OrchestrationRepositoryImpl;
+use chrono::Utc;
+use heapless::String as HeaplessString;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn orchestration() -> OrchestrationModel {
+    OrchestrationModel {
+        id: Uuid::new_v4(),
+        orchestration_type: DbOrchestrationType::CustomerOffboarding,
+        subject_id: Uuid::new_v4(),
+        status: DbOrchestrationStatus::Running,
+        failure_reason: None,
+        started_at: Utc::now(),
+        finished_at: None,
+        initiated_by_person_id: Uuid::new_v4(),
+        resolved_by_person_id: None,
+        resolution_note: None,
+    }
+}
+
+fn step(orchestration_id: Uuid, sequence: i32) -> OrchestrationStepModel {
+    OrchestrationStepModel {
+        id: Uuid::new_v4(),
+        orchestration_id,
+        sequence,
+        step_name: HeaplessString::try_from(format!("step_{sequence}").as_str()).unwrap(),
+        compensation: Some(format!(
+            r#"{{"action":"RestoreAccountStatuses","accounts":[{{"account_id":"{}","status":"Active"}}]}}"#,
+            Uuid::new_v4()
+        )),
+        status: DbOrchestrationStepStatus::Completed,
+        completed_at: Utc::now(),
+        compensated_at: None,
+        last_error: None,
+    }
+}
+
+#[tokio::test]
+async fn test_orchestration_log_tracks_steps_until_terminal_state() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = OrchestrationRepositoryImpl::new(schema.pg_pool());
+
+    let mut orchestration = repo.create_orchestration(orchestration()).await.unwrap();
+    for sequence in [2, 1, 3] {
+        repo.create_step(step(orchestration.id, sequence)).await.unwrap();
+    }
+
+    let steps = repo.find_steps(orchestration.id).await.unwrap();
+    let sequences: Vec<i32> = steps.iter().map(|s| s.sequence).collect();
+    assert_eq!(sequences, vec![1, 2, 3]);
+    let compensation: serde_json::Value = serde_json::from_str(steps[0].compensation.as_deref().unwrap()).unwrap();
+    assert_eq!(compensation["action"], "RestoreAccountStatuses");
+
+    orchestration.status = DbOrchestrationStatus::CompensationFailed;
+    orchestration.failure_reason = Some(HeaplessString::try_from("Step 4 failed").unwrap());
+    repo.update_orchestration(orchestration.clone()).await.unwrap();
+    repo.update_step_status(steps[2].id, DbOrchestrationStepStatus::Compensated, Some(Utc::now()), None)
+        .await
+        .unwrap();
+    repo.update_step_status(
+        steps[1].id,
+        DbOrchestrationStepStatus::CompensationFailed,
+        None,
+        Some(HeaplessString::try_from("Account locked").unwrap()),
+    )
+    .await
+    .unwrap();
+
+    let incomplete = repo.find_incomplete_orchestrations().await.unwrap();
+    assert!(incomplete.iter().any(|o| o.id == orchestration.id));
+    let steps = repo.find_steps(orchestration.id).await.unwrap();
+    assert_eq!(steps[1].status, DbOrchestrationStepStatus::CompensationFailed);
+    assert_eq!(steps[1].last_error.as_deref(), Some("Account locked"));
+    assert!(steps[2].compensated_at.is_some());
+
+    orchestration.status = DbOrchestrationStatus::ManuallyResolved;
+    orchestration.resolved_by_person_id = Some(Uuid::new_v4());
+    orchestration.finished_at = Some(Utc::now());
+    let resolved = repo.update_orchestration(orchestration.clone()).await.unwrap();
+    assert_eq!(resolved.status, DbOrchestrationStatus::ManuallyResolved);
+    assert!(!repo.find_incomplete_orchestrations().await.unwrap().iter().any(|o| o.id == orchestration.id));
+}
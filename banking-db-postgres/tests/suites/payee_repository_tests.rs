@@ -0,0 +1,51 @@
+use banking_db::models::{DbPayeeVerificationMethod, DbPayeeVerificationStatus, PayeeModel};
+use banking_db::repository::PayeeRepository;
+use banking_db_postgres::repository::payee_repository_impl::PayeeRepositoryImpl;
+use chrono::Utc;
+use heapless::String as HeaplessString;
+use rust_decimal_macros::dec;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn payee(customer_id: Uuid) -> PayeeModel {
+    PayeeModel {
+        id: Uuid::new_v4(),
+        customer_id,
+        beneficiary_name: HeaplessString::try_from("Jane Supplier").unwrap(),
+        external_bank_code: HeaplessString::try_from("ECOCCMCX").unwrap(),
+        external_account_number: HeaplessString::try_from("CM2110005000011234567890").unwrap(),
+        verification_status: DbPayeeVerificationStatus::PendingVerification,
+        verification_method: Some(DbPayeeVerificationMethod::PennyTest),
+        penny_test_amount: Some(dec!(0.37)),
+        verified_at: None,
+        verified_by_person_id: None,
+        first_used_at: None,
+        created_at: Utc::now(),
+        last_updated_at: Utc::now(),
+        updated_by_person_id: Uuid::new_v4(),
+    }
+}
+
+#[tokio::test]
+async fn test_payee_verification_round_trip() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = PayeeRepositoryImpl::new(schema.pg_pool());
+    let customer_id = Uuid::new_v4();
+
+    let mut created = repo.create_payee(payee(customer_id)).await.unwrap();
+    assert_eq!(created.verification_method, Some(DbPayeeVerificationMethod::PennyTest));
+    assert_eq!(created.penny_test_amount, Some(dec!(0.37)));
+
+    created.verification_status = DbPayeeVerificationStatus::Verified;
+    created.verified_at = Some(Utc::now());
+    created.first_used_at = Some(Utc::now());
+    repo.update_payee(created.clone()).await.unwrap();
+
+    let found = repo.find_payees_by_customer(customer_id).await.unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].verification_status, DbPayeeVerificationStatus::Verified);
+    assert!(found[0].first_used_at.is_some());
+
+    repo.delete_payee(created.id).await.unwrap();
+    assert!(repo.find_payee_by_id(created.id).await.unwrap().is_none());
+}
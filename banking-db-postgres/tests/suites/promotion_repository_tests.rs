@@ -0,0 +1,53 @@
+use banking_db::models::{DbPromotionRateKind, ProductPromotionModel};
+use banking_db::repository::PromotionRepository;
+use banking_db_postgres::repository::promotion_repository_impl::PromotionRepositoryImpl;
+use chrono::{Duration, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal_macros::dec;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn promotion(product_id: Uuid) -> ProductPromotionModel {
+    ProductPromotionModel {
+        id: Uuid::new_v4(),
+        product_id,
+        promotion_code: HeaplessString::try_from("Q1-SAVER").unwrap(),
+        name: HeaplessString::try_from("Q1 savings boost").unwrap(),
+        rate_kind: DbPromotionRateKind::Bonus,
+        rate_value: dec!(0.01),
+        opened_from: NaiveDate::from_ymd_opt(2024, 1, 1),
+        opened_to: None,
+        segment_code: None,
+        start_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+        end_date: NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+        budget_cap: Some(dec!(5000)),
+        bonus_interest_attributed: dec!(0),
+        budget_exhausted_at: None,
+        is_active: true,
+        created_at: Utc::now(),
+        last_updated_at: Utc::now(),
+        updated_by_person_id: Uuid::new_v4(),
+    }
+}
+
+#[tokio::test]
+async fn test_promotions_in_effect_by_date_and_budget() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = PromotionRepositoryImpl::new(schema.pg_pool());
+
+    let created = repo.create_promotion(promotion(Uuid::new_v4())).await.unwrap();
+    assert_eq!(created.rate_kind, DbPromotionRateKind::Bonus);
+    assert_eq!(created.bonus_interest_attributed, dec!(0));
+
+    let in_q1 = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+    let after_q1 = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+    assert_eq!(repo.find_promotions_in_effect(in_q1).await.unwrap().len(), 1);
+    assert!(repo.find_promotions_in_effect(after_q1).await.unwrap().is_empty());
+
+    let exhausted_at = Utc::now();
+    repo.mark_budget_exhausted(created.id, exhausted_at).await.unwrap();
+    repo.mark_budget_exhausted(created.id, exhausted_at + Duration::hours(1)).await.unwrap();
+    assert!(repo.find_promotions_in_effect(in_q1).await.unwrap().is_empty());
+    let found = repo.find_promotion_by_id(created.id).await.unwrap().unwrap();
+    assert_eq!(found.budget_exhausted_at.unwrap().timestamp(), exhausted_at.timestamp());
+}
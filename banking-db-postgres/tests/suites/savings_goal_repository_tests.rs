@@ -0,0 +1,54 @@
+use banking_db::models::{DbSavingsGoalStatus, SavingsGoalModel};
+use banking_db::repository::SavingsGoalRepository;
+use banking_db_postgres::repository::savings_goal_repository_impl::SavingsGoalRepositoryImpl;
+use chrono::{NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal_macros::dec;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn goal(account_id: Uuid, name: &str) -> SavingsGoalModel {
+    SavingsGoalModel {
+        id: Uuid::new_v4(),
+        account_id,
+        name: HeaplessString::try_from(name).unwrap(),
+        target_amount: dec!(2000),
+        target_date: NaiveDate::from_ymd_opt(2024, 12, 31),
+        allocated_balance: dec!(0),
+        status: DbSavingsGoalStatus::Active,
+        created_at: Utc::now(),
+        closed_at: None,
+        last_updated_at: Utc::now(),
+        updated_by_person_id: Uuid::new_v4(),
+    }
+}
+
+#[tokio::test]
+async fn test_savings_goal_crud() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = SavingsGoalRepositoryImpl::new(schema.pg_pool());
+    let account_id = Uuid::new_v4();
+
+    let school = repo.create_goal(goal(account_id, "School fees")).await.unwrap();
+    let emergency = repo.create_goal(goal(account_id, "Emergency")).await.unwrap();
+    assert_eq!(school.status, DbSavingsGoalStatus::Active);
+
+    let mut closed = emergency.clone();
+    closed.allocated_balance = dec!(0);
+    closed.status = DbSavingsGoalStatus::Closed;
+    closed.closed_at = Some(Utc::now());
+    repo.update_goal(closed).await.unwrap();
+
+    let mut funded = school.clone();
+    funded.allocated_balance = dec!(450.50);
+    repo.update_goal(funded).await.unwrap();
+
+    let goals = repo.find_goals_by_account(account_id).await.unwrap();
+    assert_eq!(goals.len(), 2);
+    assert_eq!(goals[0].id, school.id);
+    assert_eq!(goals[0].allocated_balance, dec!(450.50));
+    assert_eq!(goals[1].status, DbSavingsGoalStatus::Closed);
+
+    repo.delete_goal(emergency.id).await.unwrap();
+    assert!(repo.find_goal_by_id(emergency.id).await.unwrap().is_none());
+}
@@ -0,0 +1,145 @@
+use banking_db::models::{
+    CustomerModel, CustomerStatus, CustomerType, IdentityType, RiskRating, SegmentCriterionModel,
+    SegmentModel,
+};
+use banking_db::repository::{CustomerRepository, SegmentRepository};
+use banking_db_postgres::repository::customer_repository_impl::CustomerRepositoryImpl;
+use banking_db_postgres::repository::segment_repository_impl::SegmentRepositoryImpl;
+use chrono::{Duration, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use banking_db_postgres::test_helper::{setup_test_schema, TestSchema};
+use uuid::Uuid;
+
+fn test_person_id() -> Uuid {
+    Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap()
+}
+
+async fn setup_test_db() -> TestSchema {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+
+    sqlx::query(
+        r#"
+        INSERT INTO persons (id, person_type, display_name, external_identifier)
+        VALUES ($1, 'System', 'Test User', 'test-user')
+        ON CONFLICT (id) DO NOTHING
+        "#
+    )
+    .bind(test_person_id())
+    .execute(&*schema.pool())
+    .await
+    .expect("Failed to create test person");
+
+    schema
+}
+
+fn create_test_customer(risk_rating: RiskRating) -> CustomerModel {
+    let id = Uuid::new_v4();
+    CustomerModel {
+        id,
+        customer_type: CustomerType::Individual,
+        full_name: HeaplessString::try_from(format!("Segment Customer {}", &id.to_string()[0..8]).as_str()).unwrap(),
+        id_type: IdentityType::NationalId,
+        id_number: HeaplessString::try_from(format!("SEG{}", &id.to_string()[0..8]).as_str()).unwrap(),
+        risk_rating,
+        status: CustomerStatus::Active,
+        created_at: Utc::now(),
+        last_updated_at: Utc::now(),
+        updated_by_person_id: test_person_id(),
+        preferred_language_code: Some(*b"eng"),
+        duplicate_of_customer_id: None,
+    }
+}
+
+fn create_high_risk_segment() -> SegmentModel {
+    SegmentModel {
+        id: Uuid::new_v4(),
+        code: HeaplessString::try_from("HIGH_RISK").unwrap(),
+        name: HeaplessString::try_from("High risk customers").unwrap(),
+        description: None,
+        criteria: vec![SegmentCriterionModel::RiskRatingIn { ratings: vec![RiskRating::High] }],
+        is_active: true,
+        created_at: Utc::now(),
+        last_updated_at: Utc::now(),
+        updated_by_person_id: test_person_id(),
+    }
+}
+
+#[tokio::test]
+async fn test_segment_crud_round_trips_criteria() {
+    let schema = setup_test_db().await;
+    let repo = SegmentRepositoryImpl::new(schema.pg_pool());
+    let segment = create_high_risk_segment();
+
+    let created = repo.create_segment(segment.clone()).await.expect("Failed to create segment");
+    assert_eq!(created.criteria, segment.criteria);
+
+    let found = repo.find_segment_by_code("HIGH_RISK").await.unwrap().expect("Segment not found");
+    assert_eq!(found.id, segment.id);
+
+    repo.deactivate_segment(segment.id, test_person_id()).await.unwrap();
+    assert!(repo.find_active_segments().await.unwrap().is_empty());
+
+    repo.delete_segment(segment.id).await.unwrap();
+    assert!(repo.find_segment_by_id(segment.id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_membership_dating_across_consecutive_runs() {
+    let schema = setup_test_db().await;
+    let customer_repo = CustomerRepositoryImpl::new(schema.pg_pool());
+    let repo = SegmentRepositoryImpl::new(schema.pg_pool());
+
+    let mut customer = customer_repo.create(create_test_customer(RiskRating::High)).await.unwrap();
+    let bystander = customer_repo.create(create_test_customer(RiskRating::Low)).await.unwrap();
+    let segment = repo.create_segment(create_high_risk_segment()).await.unwrap();
+
+    let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    let run1 = Utc::now();
+    let run2 = run1 + Duration::days(1);
+    let run3 = run1 + Duration::days(2);
+    let run4 = run1 + Duration::days(3);
+
+    // Run 1: the high risk customer enters
+    let delta = repo.refresh_membership(&segment, date, run1).await.unwrap();
+    assert_eq!(delta.entered_customer_ids, vec![customer.id]);
+    assert!(delta.exited_customer_ids.is_empty());
+
+    // Run 2: nothing changed, the original entry date is kept
+    let delta = repo.refresh_membership(&segment, date + Duration::days(1), run2).await.unwrap();
+    assert!(delta.entered_customer_ids.is_empty());
+    assert!(delta.exited_customer_ids.is_empty());
+    let members = repo.find_current_members(segment.id).await.unwrap();
+    assert_eq!(members.len(), 1);
+    assert_eq!(members[0].entered_at.timestamp_micros(), run1.timestamp_micros());
+
+    // Run 3: the customer no longer qualifies and exits
+    customer.risk_rating = RiskRating::Low;
+    customer = customer_repo.update(customer).await.unwrap();
+    let delta = repo.refresh_membership(&segment, date + Duration::days(2), run3).await.unwrap();
+    assert_eq!(delta.exited_customer_ids, vec![customer.id]);
+    assert!(repo.find_current_members(segment.id).await.unwrap().is_empty());
+    assert!(repo.find_segments_for_customer(customer.id).await.unwrap().is_empty());
+
+    // Run 4: re-entry opens a new membership row, the closed one is kept
+    customer.risk_rating = RiskRating::High;
+    customer_repo.update(customer.clone()).await.unwrap();
+    let delta = repo.refresh_membership(&segment, date + Duration::days(3), run4).await.unwrap();
+    assert_eq!(delta.entered_customer_ids, vec![customer.id]);
+
+    let history = repo.find_membership_history(segment.id, customer.id).await.unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].entered_at.timestamp_micros(), run1.timestamp_micros());
+    assert_eq!(history[0].exited_at.map(|t| t.timestamp_micros()), Some(run3.timestamp_micros()));
+    assert_eq!(history[1].entered_at.timestamp_micros(), run4.timestamp_micros());
+    assert!(history[1].exited_at.is_none());
+
+    // Campaign delta since run 2 sees both the exit and the re-entry
+    let changes = repo.find_membership_changes_since(segment.id, run2).await.unwrap();
+    assert_eq!(changes.entered_customer_ids, vec![customer.id]);
+    assert_eq!(changes.exited_customer_ids, vec![customer.id]);
+
+    let segments = repo.find_segments_for_customer(customer.id).await.unwrap();
+    assert_eq!(segments.len(), 1);
+    assert_eq!(segments[0].id, segment.id);
+    assert!(repo.find_membership_history(segment.id, bystander.id).await.unwrap().is_empty());
+}
@@ -0,0 +1,69 @@
+use banking_db::models::{
+    DbStandingOrderAmountType, DbStandingOrderFailurePolicy, DbStandingOrderFrequency, DbStandingOrderStatus,
+    StandingOrderModel,
+};
+use banking_db::repository::StandingOrderRepository;
+use banking_db_postgres::repository::standing_order_repository_impl::StandingOrderRepositoryImpl;
+use chrono::{NaiveDate, Utc};
+use rust_decimal_macros::dec;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 5, day).unwrap()
+}
+
+fn order(source_account_id: Uuid, next_run_date: NaiveDate) -> StandingOrderModel {
+    StandingOrderModel {
+        id: Uuid::new_v4(),
+        source_account_id,
+        beneficiary_account_id: Some(Uuid::new_v4()),
+        external_reference: None,
+        amount_type: DbStandingOrderAmountType::Fixed,
+        amount: dec!(25000),
+        frequency: DbStandingOrderFrequency::Monthly,
+        start_date: date(2),
+        end_date: None,
+        next_due_date: next_run_date,
+        next_run_date,
+        status: DbStandingOrderStatus::Active,
+        failure_policy: DbStandingOrderFailurePolicy::SuspendAfter,
+        max_consecutive_failures: Some(3),
+        consecutive_failures: 0,
+        last_executed_at: None,
+        created_at: Utc::now(),
+        last_updated_at: Utc::now(),
+        updated_by_person_id: Uuid::new_v4(),
+    }
+}
+
+#[tokio::test]
+async fn test_find_due_returns_active_orders_up_to_run_date() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = StandingOrderRepositoryImpl::new(schema.pg_pool());
+    let account_id = Uuid::new_v4();
+
+    let overdue = repo.create_standing_order(order(account_id, date(16))).await.unwrap();
+    let due_today = repo.create_standing_order(order(account_id, date(20))).await.unwrap();
+    let tomorrow = repo.create_standing_order(order(account_id, date(21))).await.unwrap();
+    let mut suspended = repo.create_standing_order(order(account_id, date(20))).await.unwrap();
+    suspended.status = DbStandingOrderStatus::Suspended;
+    suspended.consecutive_failures = 3;
+    repo.update_standing_order(suspended.clone()).await.unwrap();
+
+    let due = repo.find_due(date(20)).await.unwrap();
+    assert_eq!(due.iter().map(|o| o.id).collect::<Vec<_>>(), vec![overdue.id, due_today.id]);
+    assert_eq!(due[0].max_consecutive_failures, Some(3));
+
+    let mut executed = due_today.clone();
+    executed.next_due_date = date(21);
+    executed.next_run_date = date(21);
+    executed.last_executed_at = Some(Utc::now());
+    repo.update_standing_order(executed).await.unwrap();
+    assert_eq!(repo.find_due(date(20)).await.unwrap().len(), 1);
+    assert_eq!(repo.find_due(date(21)).await.unwrap().len(), 3);
+
+    assert_eq!(repo.find_standing_orders_by_account(account_id).await.unwrap().len(), 4);
+    repo.delete_standing_order(tomorrow.id).await.unwrap();
+    assert!(repo.find_standing_order_by_id(tomorrow.id).await.unwrap().is_none());
+}
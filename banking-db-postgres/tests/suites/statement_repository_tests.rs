@@ -0,0 +1,142 @@
+use banking_db::models::{
+    AccountStatementModel, DbDocumentNotificationStatus, DbStatementDeliveryMethod, DbStatementNotificationType,
+    StatementLineModel, StatementNotificationModel, StatementPrintBatchModel, StatementPrintEntryModel,
+};
+use banking_db::repository::StatementRepository;
+use banking_db_postgres::repository::statement_repository_impl::StatementRepositoryImpl;
+use chrono::{NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn print_entry(batch_id: Uuid, delivery_method: DbStatementDeliveryMethod) -> StatementPrintEntryModel {
+    StatementPrintEntryModel {
+        id: Uuid::new_v4(),
+        batch_id,
+        account_id: Uuid::new_v4(),
+        customer_id: Uuid::new_v4(),
+        statement_reference: HeaplessString::try_from("STM-20240630-1A2B3C4D5E6F").unwrap(),
+        delivery_method,
+    }
+}
+
+#[tokio::test]
+async fn test_cycle_print_extract_is_created_once() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = StatementRepositoryImpl::new(schema.pg_pool());
+    let cycle_date = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+
+    let batch = StatementPrintBatchModel {
+        id: Uuid::new_v4(),
+        cycle_date,
+        entry_count: 2,
+        created_at: Utc::now(),
+    };
+    let entries = vec![
+        print_entry(batch.id, DbStatementDeliveryMethod::Paper),
+        print_entry(batch.id, DbStatementDeliveryMethod::Both),
+    ];
+    let notification = StatementNotificationModel {
+        id: Uuid::new_v4(),
+        notification_type: DbStatementNotificationType::StatementReady,
+        account_id: entries[1].account_id,
+        customer_id: entries[1].customer_id,
+        contact_messaging_id: Some(Uuid::new_v4()),
+        statement_reference: Some(entries[1].statement_reference.clone()),
+        status: DbDocumentNotificationStatus::Queued,
+        queued_at: Utc::now(),
+        sent_at: None,
+    };
+
+    assert!(repo.create_cycle_dispatch(batch.clone(), entries.clone(), vec![notification]).await.unwrap());
+
+    let stored = repo.find_print_batch(cycle_date).await.unwrap().expect("Print batch not found");
+    assert_eq!(stored.id, batch.id);
+    assert_eq!(stored.entry_count, 2);
+    let mut stored_accounts: Vec<Uuid> = repo.find_print_entries(batch.id).await.unwrap().iter().map(|e| e.account_id).collect();
+    let mut expected_accounts: Vec<Uuid> = entries.iter().map(|e| e.account_id).collect();
+    stored_accounts.sort();
+    expected_accounts.sort();
+    assert_eq!(stored_accounts, expected_accounts);
+
+    // A second dispatch of the same cycle is rejected
+    let rerun = StatementPrintBatchModel { id: Uuid::new_v4(), ..batch };
+    let rerun_entries = vec![print_entry(rerun.id, DbStatementDeliveryMethod::Paper)];
+    assert!(!repo.create_cycle_dispatch(rerun.clone(), rerun_entries, vec![]).await.unwrap());
+    assert!(repo.find_print_entries(rerun.id).await.unwrap().is_empty());
+
+    assert!(repo.find_print_batch(NaiveDate::from_ymd_opt(2024, 5, 31).unwrap()).await.unwrap().is_none());
+}
+
+fn stored_statement(
+    account_id: Uuid,
+    statement_number: i32,
+    period_end: NaiveDate,
+    supersedes_statement_id: Option<Uuid>,
+) -> AccountStatementModel {
+    AccountStatementModel {
+        id: Uuid::new_v4(),
+        account_id,
+        statement_number,
+        statement_reference: HeaplessString::try_from("STM-20240630-1A2B3C4D5E6F").unwrap(),
+        period_start: NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(),
+        period_end,
+        opening_balance: Decimal::from(100),
+        total_credits: Decimal::new(2550, 2),
+        total_debits: Decimal::ZERO,
+        closing_balance: Decimal::new(12550, 2),
+        interest_accrued: Decimal::new(42, 2),
+        fees_charged: Decimal::from(5),
+        fee_count: 1,
+        content_hash: [7u8; 32].into(),
+        supersedes_statement_id,
+        generated_at: Utc::now(),
+        generated_by_person_id: Uuid::new_v4(),
+    }
+}
+
+#[tokio::test]
+async fn test_statement_snapshot_round_trip_and_supersedes_chain() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = StatementRepositoryImpl::new(schema.pg_pool());
+    let account_id = Uuid::new_v4();
+    let period_end = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+
+    let period_start = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+    assert!(repo.find_last_statement_number(account_id).await.unwrap().is_none());
+
+    let original = stored_statement(account_id, 1, period_end, None);
+    let line = StatementLineModel {
+        statement_id: original.id,
+        line_number: 1,
+        transaction_id: Uuid::new_v4(),
+        value_date: NaiveDate::from_ymd_opt(2024, 6, 14).unwrap(),
+        description: HeaplessString::try_from("Salary | June").unwrap(),
+        amount: Decimal::new(2550, 2),
+        running_balance: Decimal::new(12550, 2),
+    };
+    repo.create_statement(original.clone(), vec![line.clone()]).await.unwrap();
+
+    let stored = repo.find_statement_by_id(original.id).await.unwrap().expect("Statement not found");
+    assert_eq!(stored.content_hash, original.content_hash);
+    assert_eq!(stored.closing_balance, original.closing_balance);
+    assert_eq!(stored.statement_number, 1);
+    assert_eq!(stored.fees_charged, original.fees_charged);
+    let lines = repo.find_statement_lines(original.id).await.unwrap();
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].description, line.description);
+    assert_eq!(lines[0].amount, line.amount);
+
+    let regenerated = stored_statement(account_id, 2, period_end, Some(original.id));
+    repo.create_statement(regenerated.clone(), vec![]).await.unwrap();
+    assert_eq!(repo.find_last_statement_number(account_id).await.unwrap(), Some(2));
+
+    let current = repo.find_current_statement(account_id, period_start, period_end).await.unwrap().expect("No current statement");
+    assert_eq!(current.id, regenerated.id);
+    assert_eq!(current.supersedes_statement_id, Some(original.id));
+    assert_eq!(repo.find_statements_by_period(period_end).await.unwrap().len(), 2);
+    assert!(repo.find_current_statement(Uuid::new_v4(), period_start, period_end).await.unwrap().is_none());
+    // A statement of part of the period is a different statement
+    assert!(repo.find_current_statement(account_id, NaiveDate::from_ymd_opt(2024, 6, 15).unwrap(), period_end).await.unwrap().is_none());
+}
@@ -0,0 +1,177 @@
+use banking_db::models::{
+    BulkTagTargetModel, CustomerModel, CustomerSearchCriteriaModel, CustomerStatus, CustomerType,
+    DbTagMatchMode, DbTaggableEntityKind, EntityTagModel, IdentityType, RiskRating, TagFilterModel, TagModel,
+};
+use banking_db::repository::{CustomerRepository, TagRepository};
+use banking_db_postgres::repository::customer_repository_impl::CustomerRepositoryImpl;
+use banking_db_postgres::repository::tag_repository_impl::TagRepositoryImpl;
+use chrono::Utc;
+use heapless::String as HeaplessString;
+use banking_db_postgres::test_helper::{setup_test_schema, TestSchema};
+use uuid::Uuid;
+
+fn test_person_id() -> Uuid {
+    Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap()
+}
+
+async fn setup_test_db() -> TestSchema {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+
+    sqlx::query(
+        r#"
+        INSERT INTO persons (id, person_type, display_name, external_identifier)
+        VALUES ($1, 'System', 'Test User', 'test-user')
+        ON CONFLICT (id) DO NOTHING
+        "#
+    )
+    .bind(test_person_id())
+    .execute(&*schema.pool())
+    .await
+    .expect("Failed to create test person");
+
+    schema
+}
+
+fn create_test_customer() -> CustomerModel {
+    let id = Uuid::new_v4();
+    CustomerModel {
+        id,
+        customer_type: CustomerType::Individual,
+        full_name: HeaplessString::try_from(format!("Tag Customer {}", &id.to_string()[0..8]).as_str()).unwrap(),
+        id_type: IdentityType::NationalId,
+        id_number: HeaplessString::try_from(format!("TAG{}", &id.to_string()[0..8]).as_str()).unwrap(),
+        risk_rating: RiskRating::Low,
+        status: CustomerStatus::Active,
+        created_at: Utc::now(),
+        last_updated_at: Utc::now(),
+        updated_by_person_id: test_person_id(),
+        preferred_language_code: Some(*b"eng"),
+        duplicate_of_customer_id: None,
+    }
+}
+
+fn create_tag(code: &str, required_permission: Option<&str>) -> TagModel {
+    TagModel {
+        id: Uuid::new_v4(),
+        code: HeaplessString::try_from(code).unwrap(),
+        description: HeaplessString::try_from(code).unwrap(),
+        applicable_entity_kinds: vec![DbTaggableEntityKind::Customer, DbTaggableEntityKind::Account],
+        required_permission: required_permission.map(|p| HeaplessString::try_from(p).unwrap()),
+        is_active: true,
+        created_at: Utc::now(),
+        last_updated_at: Utc::now(),
+        updated_by_person_id: test_person_id(),
+    }
+}
+
+fn entity_tag(tag_id: Uuid, customer_id: Uuid) -> EntityTagModel {
+    EntityTagModel {
+        id: Uuid::new_v4(),
+        tag_id,
+        entity_kind: DbTaggableEntityKind::Customer,
+        entity_id: customer_id,
+        assigned_at: Utc::now(),
+        assigned_by_person_id: test_person_id(),
+    }
+}
+
+fn search_by_tags(codes: &[&str], match_mode: DbTagMatchMode) -> CustomerSearchCriteriaModel {
+    CustomerSearchCriteriaModel {
+        customer_type: None,
+        status: None,
+        risk_rating: None,
+        tags: Some(TagFilterModel {
+            tag_codes: codes.iter().map(|c| HeaplessString::try_from(*c).unwrap()).collect(),
+            match_mode,
+        }),
+        offset: 0,
+        limit: 50,
+    }
+}
+
+#[tokio::test]
+async fn test_tag_registry_round_trips_protected_tag() {
+    let schema = setup_test_db().await;
+    let repo = TagRepositoryImpl::new(schema.pg_pool());
+
+    let tag = repo.create_tag(create_tag("StaffAccount", Some("TAG_STAFF_ACCOUNT"))).await.unwrap();
+    assert_eq!(tag.applicable_entity_kinds, vec![DbTaggableEntityKind::Customer, DbTaggableEntityKind::Account]);
+    assert_eq!(tag.required_permission.as_deref(), Some("TAG_STAFF_ACCOUNT"));
+
+    let found = repo.find_tag_by_code("StaffAccount").await.unwrap().expect("Tag not found");
+    assert_eq!(found.id, tag.id);
+
+    repo.deactivate_tag(tag.id, test_person_id()).await.unwrap();
+    assert!(repo.find_active_tags().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_customer_search_filters_by_tag() {
+    let schema = setup_test_db().await;
+    let customer_repo = CustomerRepositoryImpl::new(schema.pg_pool());
+    let tag_repo = TagRepositoryImpl::new(schema.pg_pool());
+
+    let vip = tag_repo.create_tag(create_tag("VIP", None)).await.unwrap();
+    let staff = tag_repo.create_tag(create_tag("Staff", None)).await.unwrap();
+    let vip_staff = customer_repo.create(create_test_customer()).await.unwrap();
+    let vip_only = customer_repo.create(create_test_customer()).await.unwrap();
+    customer_repo.create(create_test_customer()).await.unwrap();
+
+    tag_repo.assign_tag(entity_tag(vip.id, vip_staff.id)).await.unwrap();
+    tag_repo.assign_tag(entity_tag(staff.id, vip_staff.id)).await.unwrap();
+    tag_repo.assign_tag(entity_tag(vip.id, vip_only.id)).await.unwrap();
+    // Assigning twice keeps the first assignment
+    let first = tag_repo.assign_tag(entity_tag(vip.id, vip_only.id)).await.unwrap();
+    assert_eq!(tag_repo.find_tags_for_entity(DbTaggableEntityKind::Customer, vip_only.id).await.unwrap().len(), 1);
+
+    let any = customer_repo.search(search_by_tags(&["VIP", "Staff"], DbTagMatchMode::Any)).await.unwrap();
+    let mut any_ids: Vec<Uuid> = any.iter().map(|c| c.id).collect();
+    any_ids.sort();
+    let mut expected = vec![vip_staff.id, vip_only.id];
+    expected.sort();
+    assert_eq!(any_ids, expected);
+
+    let all = customer_repo.search(search_by_tags(&["VIP", "Staff"], DbTagMatchMode::All)).await.unwrap();
+    assert_eq!(all.iter().map(|c| c.id).collect::<Vec<_>>(), vec![vip_staff.id]);
+
+    let portfolio = customer_repo.get_portfolio(vip_staff.id).await.unwrap().expect("Portfolio not found");
+    assert_eq!(portfolio.tags.iter().map(|t| t.as_str()).collect::<Vec<_>>(), vec!["Staff", "VIP"]);
+
+    assert!(tag_repo.remove_tag(vip.id, DbTaggableEntityKind::Customer, first.entity_id).await.unwrap());
+    assert!(!tag_repo.remove_tag(vip.id, DbTaggableEntityKind::Customer, first.entity_id).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_bulk_tagging_pages_matching_customers() {
+    let schema = setup_test_db().await;
+    let customer_repo = CustomerRepositoryImpl::new(schema.pg_pool());
+    let tag_repo = TagRepositoryImpl::new(schema.pg_pool());
+
+    let batch = tag_repo.create_tag(create_tag("LegacyMigrationBatch7", None)).await.unwrap();
+    let reviewed = tag_repo.create_tag(create_tag("Reviewed", None)).await.unwrap();
+    for _ in 0..3 {
+        let customer = customer_repo.create(create_test_customer()).await.unwrap();
+        tag_repo.assign_tag(entity_tag(batch.id, customer.id)).await.unwrap();
+    }
+    customer_repo.create(create_test_customer()).await.unwrap();
+
+    let target = BulkTagTargetModel::Customers(search_by_tags(&["LegacyMigrationBatch7"], DbTagMatchMode::Any));
+    let first_page = tag_repo.find_bulk_target_ids(&target, None, 2).await.unwrap();
+    assert_eq!(first_page.len(), 2);
+    let second_page = tag_repo.find_bulk_target_ids(&target, first_page.last().copied(), 2).await.unwrap();
+    assert_eq!(second_page.len(), 1);
+    assert!(first_page.iter().all(|id| *id < second_page[0]));
+
+    let newly_tagged = tag_repo
+        .assign_tag_bulk(reviewed.id, DbTaggableEntityKind::Customer, first_page.clone(), test_person_id(), Utc::now())
+        .await
+        .unwrap();
+    assert_eq!(newly_tagged, 2);
+    let mut all_ids = first_page;
+    all_ids.extend(second_page);
+    let newly_tagged = tag_repo
+        .assign_tag_bulk(reviewed.id, DbTaggableEntityKind::Customer, all_ids, test_person_id(), Utc::now())
+        .await
+        .unwrap();
+    assert_eq!(newly_tagged, 1);
+}
@@ -43,6 +43,12 @@ fn create_test_transaction(account_id: Uuid) -> TransactionModel {
         requires_approval: false,
         approval_status: None,
         risk_score: Some(Decimal::from_str("25.5").unwrap()),
+        degraded_flags: 0,
+        idempotency_key: None,
+        reversal_of_transaction_id: None,
+        original_amount: None,
+        original_currency: None,
+        exchange_rate: None,
         created_at: Utc::now(),
     }
 }
@@ -64,6 +70,7 @@ fn create_test_account() -> AccountModel {
         currency: HeaplessString::try_from("USD").unwrap(),
         open_date: NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
         domicile_agency_branch_id: domicile_agency_branch_id,
+        account_number: HeaplessString::try_from(account_id.simple().to_string().as_str()).unwrap(),
         current_balance: Decimal::from_str("1000.00").unwrap(),
         available_balance: Decimal::from_str("950.00").unwrap(),
         accrued_interest: Decimal::from_str("12.50").unwrap(),
@@ -115,6 +122,7 @@ fn create_test_account() -> AccountModel {
         created_at: Utc::now(),
         last_updated_at: Utc::now(),
         updated_by_person_id: updated_by_person_id,
+        version: 0,
     }
 }
 
@@ -876,4 +884,274 @@ async fn test_transaction_approval_operations() {
     let approval_count = repo.count_approvals_for_workflow(created_workflow.id).await
         .expect("Failed to count approvals for workflow");
     assert_eq!(approval_count, 1);
-}
\ No newline at end of file
+}
+#[tokio::test]
+async fn test_post_transaction_concurrent_postings_keep_every_delta() {
+    use banking_db::TransactionRepository;
+    use banking_db_postgres::TransactionRepositoryImpl;
+    use std::sync::Arc;
+
+    let pool = setup_test_db().await;
+    let repo = Arc::new(TransactionRepositoryImpl::new(pool.clone()));
+    let account_id = create_test_account_in_db(&pool).await;
+
+    // 20 debits of 10.00 and 20 credits of 5.00 racing on one account
+    let mut handles = Vec::new();
+    for i in 0..40 {
+        let repo = repo.clone();
+        let mut transaction = create_test_transaction(account_id);
+        transaction.status = TransactionStatus::Posted;
+        transaction.external_reference = None;
+        if i % 2 == 0 {
+            transaction.transaction_type = TransactionType::Debit;
+            transaction.amount = Decimal::from_str("10.00").unwrap();
+        } else {
+            transaction.amount = Decimal::from_str("5.00").unwrap();
+        }
+        handles.push(tokio::spawn(async move { repo.post_transaction(transaction).await }));
+    }
+    for handle in handles {
+        handle.await.unwrap().expect("Failed to post transaction");
+    }
+
+    let (current_balance, available_balance, last_activity_date): (Decimal, Decimal, Option<NaiveDate>) =
+        sqlx::query_as("SELECT current_balance, available_balance, last_activity_date FROM accounts WHERE id = $1")
+            .bind(account_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(current_balance, Decimal::from_str("900.00").unwrap());
+    assert_eq!(available_balance, Decimal::from_str("850.00").unwrap());
+    assert!(last_activity_date.is_some());
+
+    let posted = repo.find_by_account_id(account_id, None, None).await.unwrap();
+    assert_eq!(posted.len(), 40);
+}
+
+#[tokio::test]
+async fn test_post_transaction_rejects_debit_beyond_overdraft() {
+    use banking_api::BankingError;
+    use banking_db::TransactionRepository;
+    use banking_db_postgres::TransactionRepositoryImpl;
+
+    let pool = setup_test_db().await;
+    let repo = TransactionRepositoryImpl::new(pool.clone());
+    let account_id = create_test_account_in_db(&pool).await;
+    sqlx::query("UPDATE accounts SET overdraft_limit = 100.00 WHERE id = $1")
+        .bind(account_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // Available 950.00 plus 100.00 overdraft leaves 1050.00 to spend
+    let mut too_large = create_test_transaction(account_id);
+    too_large.status = TransactionStatus::Posted;
+    too_large.transaction_type = TransactionType::Debit;
+    too_large.amount = Decimal::from_str("1050.01").unwrap();
+    match repo.post_transaction(too_large.clone()).await {
+        Err(BankingError::InsufficientFunds { requested, available, .. }) => {
+            assert_eq!(requested, too_large.amount);
+            assert_eq!(available, Decimal::from_str("1050.00").unwrap());
+        }
+        other => panic!("Expected InsufficientFunds, got {other:?}"),
+    }
+    assert!(!repo.exists(too_large.id).await.unwrap());
+
+    let mut into_overdraft = create_test_transaction(account_id);
+    into_overdraft.status = TransactionStatus::Posted;
+    into_overdraft.transaction_type = TransactionType::Debit;
+    into_overdraft.amount = Decimal::from_str("1000.00").unwrap();
+    repo.post_transaction(into_overdraft).await
+        .expect("Debit within the overdraft limit should post");
+
+    let available_balance: Decimal = sqlx::query_scalar("SELECT available_balance FROM accounts WHERE id = $1")
+        .bind(account_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(available_balance, Decimal::from_str("-50.00").unwrap());
+}
+
+#[tokio::test]
+async fn test_post_approved_transaction_posts_once() {
+    use banking_db::TransactionRepository;
+    use banking_db_postgres::TransactionRepositoryImpl;
+
+    let pool = setup_test_db().await;
+    let repo = TransactionRepositoryImpl::new(pool.clone());
+    let account_id = create_test_account_in_db(&pool).await;
+
+    let mut awaiting = create_test_transaction(account_id);
+    awaiting.status = TransactionStatus::AwaitingApproval;
+    awaiting.transaction_type = TransactionType::Debit;
+    awaiting.amount = Decimal::from_str("200.00").unwrap();
+    repo.create(awaiting.clone()).await.expect("Failed to create transaction");
+
+    let posted = repo.post_approved_transaction(awaiting.id).await
+        .expect("Failed to post approved transaction")
+        .expect("Transaction should be awaiting approval");
+    assert_eq!(posted.status, TransactionStatus::Posted);
+    assert!(repo.post_approved_transaction(awaiting.id).await.unwrap().is_none());
+
+    let current_balance: Decimal = sqlx::query_scalar("SELECT current_balance FROM accounts WHERE id = $1")
+        .bind(account_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(current_balance, Decimal::from_str("800.00").unwrap());
+}
+
+#[tokio::test]
+async fn test_reverse_posted_credit_restores_balance_once() {
+    use banking_api::BankingError;
+    use banking_api::domain::ReversalRefusal;
+    use banking_db::TransactionRepository;
+    use banking_db_postgres::TransactionRepositoryImpl;
+
+    let pool = setup_test_db().await;
+    let repo = TransactionRepositoryImpl::new(pool.clone());
+    let account_id = create_test_account_in_db(&pool).await;
+
+    let mut credit = create_test_transaction(account_id);
+    credit.status = TransactionStatus::Posted;
+    credit.amount = Decimal::from_str("250.00").unwrap();
+    repo.post_transaction(credit.clone()).await.expect("Failed to post credit");
+    let last_activity_date: Option<NaiveDate> =
+        sqlx::query_scalar("SELECT last_activity_date FROM accounts WHERE id = $1")
+            .bind(account_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+
+    let mut contra = create_test_transaction(account_id);
+    contra.status = TransactionStatus::Posted;
+    contra.transaction_type = TransactionType::Debit;
+    contra.amount = credit.amount;
+    contra.reversal_of_transaction_id = Some(credit.id);
+    let reversal = repo.reverse_transaction(credit.id, contra.clone()).await
+        .expect("Failed to reverse credit");
+    assert_eq!(reversal.reversal_of_transaction_id, Some(credit.id));
+    assert_eq!(reversal.transaction_type, TransactionType::Debit);
+    assert_eq!(repo.find_by_id(credit.id).await.unwrap().unwrap().status, TransactionStatus::Reversed);
+
+    let (current_balance, available_balance, after_reversal): (Decimal, Decimal, Option<NaiveDate>) =
+        sqlx::query_as("SELECT current_balance, available_balance, last_activity_date FROM accounts WHERE id = $1")
+            .bind(account_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(current_balance, Decimal::from_str("1000.00").unwrap());
+    assert_eq!(available_balance, Decimal::from_str("950.00").unwrap());
+    assert_eq!(after_reversal, last_activity_date);
+
+    let mut second = contra.clone();
+    second.id = Uuid::new_v4();
+    second.reference_number = HeaplessString::try_from(format!("R2{}", second.id.simple()).as_str()).unwrap();
+    match repo.reverse_transaction(credit.id, second).await {
+        Err(BankingError::TransactionNotReversible { transaction_id, refusal }) => {
+            assert_eq!(transaction_id, credit.id);
+            assert_eq!(refusal, ReversalRefusal::AlreadyReversed);
+        }
+        other => panic!("Expected TransactionNotReversible, got {other:?}"),
+    }
+
+    let mut of_reversal = create_test_transaction(account_id);
+    of_reversal.status = TransactionStatus::Posted;
+    of_reversal.amount = credit.amount;
+    of_reversal.reversal_of_transaction_id = Some(reversal.id);
+    assert!(matches!(
+        repo.reverse_transaction(reversal.id, of_reversal).await,
+        Err(BankingError::TransactionNotReversible { refusal: ReversalRefusal::IsReversal, .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_post_transaction_replays_idempotency_key() {
+    use banking_db::TransactionRepository;
+    use banking_db_postgres::TransactionRepositoryImpl;
+
+    let pool = setup_test_db().await;
+    let repo = TransactionRepositoryImpl::new(pool.clone());
+    let account_id = create_test_account_in_db(&pool).await;
+    let key = HeaplessString::try_from(format!("mobile-{}", Uuid::new_v4().simple()).as_str()).unwrap();
+
+    let mut first = create_test_transaction(account_id);
+    first.status = TransactionStatus::Posted;
+    first.idempotency_key = Some(key.clone());
+    let posted = repo.post_transaction(first.clone()).await.expect("First submission should post");
+    assert_eq!(posted.idempotency_key, Some(key.clone()));
+
+    // The retry carries a fresh id; the original comes back and the balance moves once
+    let mut retry = create_test_transaction(account_id);
+    retry.status = TransactionStatus::Posted;
+    retry.idempotency_key = Some(key.clone());
+    let replayed = repo.post_transaction(retry.clone()).await.expect("Retry should return the original");
+    assert_eq!(replayed.id, first.id);
+    assert!(!repo.exists(retry.id).await.unwrap());
+
+    let current_balance: Decimal = sqlx::query_scalar("SELECT current_balance FROM accounts WHERE id = $1")
+        .bind(account_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(current_balance, Decimal::from_str("1100.00").unwrap());
+
+    // Keys are unique per channel only
+    let mut other_channel = create_test_transaction(account_id);
+    other_channel.channel_id = HeaplessString::try_from("Mobile").unwrap();
+    other_channel.idempotency_key = Some(key.clone());
+    assert_eq!(repo.create(other_channel.clone()).await.unwrap().id, other_channel.id);
+
+    let found = repo.find_by_idempotency_key("Atm", key.as_str()).await.unwrap().expect("Key not found");
+    assert_eq!(found.id, first.id);
+}
+
+#[tokio::test]
+async fn test_owner_channel_usage_resets_at_midnight() {
+    use banking_api::domain::ChannelUsageWindow;
+    use banking_db::{ChannelRepository, TransactionRepository};
+    use banking_db_postgres::{ChannelRepositoryImpl, TransactionRepositoryImpl};
+    use chrono::{Duration, TimeZone};
+
+    let pool = setup_test_db().await;
+    let repo = TransactionRepositoryImpl::new(pool.clone());
+    let channel_repo = ChannelRepositoryImpl::new(pool.clone());
+    let account_id = create_test_account_in_db(&pool).await;
+    let channel_id = format!("MOB{}", &Uuid::new_v4().simple().to_string()[..8]);
+
+    // Two debits before midnight on 31 March and one just after, plus a credit that never counts
+    let before_midnight = Utc.with_ymd_and_hms(2024, 3, 31, 23, 59, 59).unwrap();
+    let after_midnight = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+    for (transaction_date, transaction_type) in [
+        (before_midnight - Duration::hours(1), TransactionType::Debit),
+        (before_midnight, TransactionType::Debit),
+        (after_midnight, TransactionType::Debit),
+        (after_midnight, TransactionType::Credit),
+    ] {
+        let mut transaction = create_test_transaction(account_id);
+        transaction.channel_id = HeaplessString::try_from(channel_id.as_str()).unwrap();
+        transaction.transaction_type = transaction_type;
+        transaction.status = TransactionStatus::Posted;
+        transaction.transaction_date = transaction_date;
+        repo.create(transaction).await.expect("Failed to create transaction");
+    }
+
+    let window = ChannelUsageWindow::containing(before_midnight);
+    let march = channel_repo
+        .get_owner_channel_usage(account_id, &channel_id, "USD", window.month_start, window.day_start, window.day_end)
+        .await
+        .expect("Failed to aggregate channel usage");
+    assert_eq!(march.daily_count, 2);
+    assert_eq!(march.daily_amount, Decimal::from_str("200.00").unwrap());
+    assert_eq!(march.monthly_amount, Decimal::from_str("200.00").unwrap());
+
+    // The new day is also a new month; March's debits no longer count
+    let window = ChannelUsageWindow::containing(after_midnight);
+    let april = channel_repo
+        .get_owner_channel_usage(account_id, &channel_id, "USD", window.month_start, window.day_start, window.day_end)
+        .await
+        .expect("Failed to aggregate channel usage");
+    assert_eq!(april.daily_count, 1);
+    assert_eq!(april.daily_amount, Decimal::from_str("100.00").unwrap());
+    assert_eq!(april.monthly_amount, Decimal::from_str("100.00").unwrap());
+}
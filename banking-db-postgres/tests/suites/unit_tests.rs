@@ -22,6 +22,7 @@ fn create_test_account() -> AccountModel {
         currency: HeaplessString::try_from("USD").unwrap(),
         open_date: NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
         domicile_agency_branch_id: domicile_agency_branch_id,
+        account_number: HeaplessString::try_from(account_id.simple().to_string().as_str()).unwrap(),
         current_balance: Decimal::from_str("1000.00").unwrap(),
         available_balance: Decimal::from_str("950.00").unwrap(),
         accrued_interest: Decimal::from_str("12.50").unwrap(),
@@ -73,6 +74,7 @@ fn create_test_account() -> AccountModel {
         created_at: Utc::now(),
         last_updated_at: Utc::now(),
         updated_by_person_id: updated_by_person_id,
+        version: 0,
     }
 }
 
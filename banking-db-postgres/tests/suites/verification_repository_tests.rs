@@ -0,0 +1,75 @@
+use banking_db::models::{DbChallengeStatus, DbVerificationMethod, DbVerificationOperationKind, VerificationChallengeModel};
+use banking_db::repository::VerificationRepository;
+use banking_db_postgres::repository::verification_repository_impl::VerificationRepositoryImpl;
+use chrono::{Duration, Utc};
+use heapless::String as HeaplessString;
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn challenge(customer_id: Uuid) -> VerificationChallengeModel {
+    VerificationChallengeModel {
+        id: Uuid::new_v4(),
+        customer_id,
+        operation_kind: DbVerificationOperationKind::LimitIncrease,
+        channel_id: Uuid::new_v4(),
+        code: HeaplessString::try_from("482913").unwrap(),
+        status: DbChallengeStatus::Pending,
+        failed_attempts: 0,
+        last_failed_at: None,
+        created_at: Utc::now(),
+        expires_at: Utc::now() + Duration::minutes(5),
+        verified_method: None,
+        verified_by_person_id: None,
+        verified_at: None,
+        consumed_at: None,
+    }
+}
+
+#[tokio::test]
+async fn test_verified_challenge_is_consumed_only_once() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = VerificationRepositoryImpl::new(schema.pg_pool());
+    let customer_id = Uuid::new_v4();
+    let started = Utc::now() - Duration::minutes(1);
+
+    let mut failed = repo.create_challenge(challenge(customer_id)).await.unwrap();
+    failed.failed_attempts = 3;
+    failed.last_failed_at = Some(Utc::now());
+    failed.status = DbChallengeStatus::Failed;
+    repo.update_challenge(failed).await.unwrap();
+
+    let mut verified = repo.create_challenge(challenge(customer_id)).await.unwrap();
+    assert!(repo
+        .find_latest_verified_challenge(customer_id, DbVerificationOperationKind::LimitIncrease)
+        .await
+        .unwrap()
+        .is_none());
+    verified.status = DbChallengeStatus::Verified;
+    verified.verified_method = Some(DbVerificationMethod::BranchAssertion);
+    verified.verified_by_person_id = Some(Uuid::new_v4());
+    verified.verified_at = Some(Utc::now());
+    repo.update_challenge(verified.clone()).await.unwrap();
+
+    let since = repo
+        .find_challenges_created_since(customer_id, DbVerificationOperationKind::LimitIncrease, started)
+        .await
+        .unwrap();
+    assert_eq!(since.iter().map(|c| c.failed_attempts).sum::<i32>(), 3);
+    assert!(repo
+        .find_challenges_created_since(customer_id, DbVerificationOperationKind::ContactDetailChange, started)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let latest = repo
+        .find_latest_verified_challenge(customer_id, DbVerificationOperationKind::LimitIncrease)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(latest.id, verified.id);
+    assert_eq!(latest.verified_method, Some(DbVerificationMethod::BranchAssertion));
+
+    let consumed = repo.consume_challenge(verified.id, Utc::now()).await.unwrap().unwrap();
+    assert_eq!(consumed.status, DbChallengeStatus::Consumed);
+    assert!(repo.consume_challenge(verified.id, Utc::now()).await.unwrap().is_none());
+}
@@ -0,0 +1,79 @@
+use banking_db::models::{DbWarehouseEntity, DbWarehouseExportMode, WarehouseExportRunModel, WarehouseTombstoneModel};
+use banking_db::repository::WarehouseExportRepository;
+use banking_db_postgres::repository::warehouse_export_repository_impl::WarehouseExportRepositoryImpl;
+use chrono::{Duration, Utc};
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_tombstones_are_found_within_their_window() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = WarehouseExportRepositoryImpl::new(schema.pg_pool());
+    let now = Utc::now();
+
+    let tombstone = repo
+        .create_tombstone(WarehouseTombstoneModel {
+            id: Uuid::new_v4(),
+            entity: DbWarehouseEntity::Workflow,
+            entity_id: Uuid::new_v4(),
+            deleted_at: now,
+        })
+        .await
+        .unwrap();
+
+    let in_window = repo
+        .find_tombstones(DbWarehouseEntity::Workflow, now - Duration::minutes(1), now, None, 100)
+        .await
+        .unwrap();
+    assert!(in_window.iter().any(|t| t.id == tombstone.id));
+
+    // Windows exclude their lower bound
+    let after = repo
+        .find_tombstones(DbWarehouseEntity::Workflow, now, now + Duration::minutes(1), None, 100)
+        .await
+        .unwrap();
+    assert!(!after.iter().any(|t| t.id == tombstone.id));
+
+    let other_entity = repo
+        .find_tombstones(DbWarehouseEntity::Account, now - Duration::minutes(1), now, None, 100)
+        .await
+        .unwrap();
+    assert!(!other_entity.iter().any(|t| t.id == tombstone.id));
+}
+
+#[tokio::test]
+async fn test_latest_export_run_round_trips() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = WarehouseExportRepositoryImpl::new(schema.pg_pool());
+    let watermark = Utc::now() - Duration::minutes(1);
+
+    repo.create_export_run(WarehouseExportRunModel {
+        id: Uuid::new_v4(),
+        mode: DbWarehouseExportMode::FullSnapshot,
+        schema_version: 1,
+        from_watermark: None,
+        to_watermark: watermark - Duration::days(1),
+        total_rows: 120,
+        completed_at: Utc::now() - Duration::days(1),
+    })
+    .await
+    .unwrap();
+    let incremental = repo
+        .create_export_run(WarehouseExportRunModel {
+            id: Uuid::new_v4(),
+            mode: DbWarehouseExportMode::Incremental,
+            schema_version: 1,
+            from_watermark: Some(watermark - Duration::days(1)),
+            to_watermark: watermark,
+            total_rows: 7,
+            completed_at: Utc::now(),
+        })
+        .await
+        .unwrap();
+
+    let latest = repo.find_latest_export_run().await.unwrap().unwrap();
+    assert_eq!(latest.id, incremental.id);
+    assert_eq!(latest.mode, DbWarehouseExportMode::Incremental);
+    assert_eq!(latest.from_watermark, incremental.from_watermark);
+    assert_eq!(latest.total_rows, 7);
+}
@@ -0,0 +1,72 @@
+use banking_db::models::{WorkflowEscalationModel, WorkflowEscalationStatusModel};
+use banking_db::repository::WorkflowEscalationRepository;
+use banking_db_postgres::repository::workflow_escalation_repository_impl::WorkflowEscalationRepositoryImpl;
+use chrono::{Duration, Utc};
+use banking_db_postgres::test_helper::setup_test_schema;
+use uuid::Uuid;
+
+fn escalation(workflow_id: Uuid, escalated_to_person_id: Uuid, level: i16) -> WorkflowEscalationModel {
+    let now = Utc::now();
+    WorkflowEscalationModel {
+        id: Uuid::new_v4(),
+        workflow_id,
+        escalated_to_person_id,
+        level,
+        status: WorkflowEscalationStatusModel::Open,
+        created_at: now,
+        action_due_at: now + Duration::hours(24),
+        acknowledged_at: None,
+        resolved_at: None,
+        resolved_by_person_id: None,
+        resolution_notes: None,
+        last_updated_at: now,
+    }
+}
+
+#[tokio::test]
+async fn test_open_escalations_leave_queue_once_escalated_or_resolved() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = WorkflowEscalationRepositoryImpl::new(schema.pg_pool());
+    let (supervisor, manager) = (Uuid::new_v4(), Uuid::new_v4());
+    let workflow_id = Uuid::new_v4();
+
+    let first = repo.create_escalation(escalation(workflow_id, supervisor, 1)).await.unwrap();
+    let other = repo.create_escalation(escalation(Uuid::new_v4(), supervisor, 1)).await.unwrap();
+    assert_eq!(repo.find_open_escalations_by_person(supervisor).await.unwrap().len(), 2);
+
+    let due = first.action_due_at;
+    let overdue = repo.find_overdue_escalations(due, 3).await.unwrap();
+    assert_eq!(overdue.len(), 2);
+    assert!(repo.find_overdue_escalations(due, 1).await.unwrap().is_empty());
+
+    repo.mark_escalated(first.id, due).await.unwrap().unwrap();
+    assert!(repo.mark_escalated(first.id, due).await.unwrap().is_none());
+    repo.create_escalation(escalation(workflow_id, manager, 2)).await.unwrap();
+
+    let queue = repo.find_open_escalations_by_person(supervisor).await.unwrap();
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue[0].id, other.id);
+    assert_eq!(repo.find_open_escalations_by_person(manager).await.unwrap().len(), 1);
+
+    let levels: Vec<i16> = repo.find_escalations_by_workflow(workflow_id).await.unwrap().iter().map(|e| e.level).collect();
+    assert_eq!(levels, vec![1, 2]);
+}
+
+#[tokio::test]
+async fn test_transitions_apply_only_from_expected_status() {
+    let schema = setup_test_schema().await.expect("Failed to create test schema");
+    let repo = WorkflowEscalationRepositoryImpl::new(schema.pg_pool());
+    let supervisor = Uuid::new_v4();
+    let created = repo.create_escalation(escalation(Uuid::new_v4(), supervisor, 1)).await.unwrap();
+
+    let acknowledged = repo.acknowledge_escalation(created.id, Utc::now()).await.unwrap().unwrap();
+    assert_eq!(acknowledged.status, WorkflowEscalationStatusModel::Acknowledged);
+    assert!(repo.acknowledge_escalation(created.id, Utc::now()).await.unwrap().is_none());
+    assert!(repo.mark_escalated(created.id, Utc::now()).await.unwrap().is_none());
+
+    let resolved = repo.resolve_escalation(created.id, supervisor, None, Utc::now()).await.unwrap().unwrap();
+    assert_eq!(resolved.status, WorkflowEscalationStatusModel::Resolved);
+    assert_eq!(resolved.resolved_by_person_id, Some(supervisor));
+    assert!(repo.resolve_escalation(created.id, supervisor, None, Utc::now()).await.unwrap().is_none());
+    assert!(repo.find_open_escalations_by_person(supervisor).await.unwrap().is_empty());
+}
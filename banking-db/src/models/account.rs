@@ -17,6 +17,7 @@ pub struct AccountModel {
     pub currency: HeaplessString<3>,
     pub open_date: NaiveDate,
     pub domicile_agency_branch_id: Uuid,
+    pub account_number: HeaplessString<34>,
     
     pub gl_code_suffix: Option<HeaplessString<10>>,
     
@@ -92,6 +93,9 @@ pub struct AccountModel {
     pub last_updated_at: DateTime<Utc>,
     /// References Person.person_id
     pub updated_by_person_id: Uuid,
+    /// Optimistic lock, incremented by every update. An update carrying an
+    /// older version fails with BankingError::StaleVersion.
+    pub version: i32,
 }
 
 /// Database model for Account Ownership
@@ -163,6 +167,25 @@ pub struct AccountStatusChangeRecordModel {
     pub created_at: DateTime<Utc>,
 }
 
+
+/// Database model for Account Balance History, one row per balance update
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+pub struct AccountBalanceChangeRecordModel {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub old_current_balance: Decimal,
+    pub new_current_balance: Decimal,
+    pub old_available_balance: Decimal,
+    pub new_available_balance: Decimal,
+    pub change_source: DbBalanceChangeSource,
+    /// References Transaction.id when the change comes from a posting
+    pub transaction_id: Option<Uuid>,
+    pub changed_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub changed_by_person_id: Uuid,
+}
+
 /// Database model for Final Settlements (from enhancements)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
@@ -172,15 +195,68 @@ pub struct AccountFinalSettlementModel {
     pub settlement_date: NaiveDate,
     pub current_balance: Decimal,
     pub accrued_interest: Decimal,
+    /// Fees applied but not yet posted to the balance
+    pub pending_fees: Decimal,
     pub closure_fees: Decimal,
     pub final_amount: Decimal,
     pub disbursement_method: DbDisbursementMethod,
     pub disbursement_reference: Option<HeaplessString<100>>,
+    pub status: DbSettlementStatus,
     /// References Person.person_id
     pub processed_by_person_id: Uuid,
     pub created_at: DateTime<Utc>,
 }
 
+/// Database model for end-of-day Account Balance Snapshots
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+pub struct AccountBalanceSnapshotModel {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub snapshot_date: NaiveDate,
+    pub closing_balance: Decimal,
+    pub consecutive_overdrawn_days: i32,
+    pub provisioning_bucket: DbProvisioningBucket,
+    pub previous_provisioning_bucket: Option<DbProvisioningBucket>,
+    pub bucket_transition: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+
+/// Database model for an end-of-day overdue assessment of a loan installment;
+/// one row per loan and assessment date
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+pub struct LoanPenaltyAccrualModel {
+    pub id: Uuid,
+    /// References AccountModel.id
+    pub loan_account_id: Uuid,
+    pub assessment_date: NaiveDate,
+    pub due_date: NaiveDate,
+    pub days_past_due: i32,
+    pub overdue_amount: Decimal,
+    pub penalty_rate: Decimal,
+    pub penalty_days: i32,
+    pub penalty_amount: Decimal,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Database model for a daily interest accrual; one row per account and date
+/// marks the account as accrued so retried chunks skip it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+pub struct AccountInterestAccrualModel {
+    pub account_id: Uuid,
+    pub accrual_date: NaiveDate,
+    pub daily_interest: Decimal,
+    pub interest_rate: Decimal,
+    pub principal_balance: Decimal,
+    /// References ProductPromotionModel.id
+    pub promotion_id: Option<Uuid>,
+    pub bonus_interest: Decimal,
+    pub created_at: DateTime<Utc>,
+}
+
 /// Database model for Final Settlement (alias for compatibility)
 pub type FinalSettlementModel = AccountFinalSettlementModel;
 
@@ -358,6 +434,58 @@ impl FromStr for DbDisbursementStatus {
     }
 }
 
+/// Progress of a final settlement; it only moves forward, one step at a time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "settlement_status", rename_all = "PascalCase")]
+pub enum DbSettlementStatus {
+    Pending,
+    /// Negative final amount owed by the customer; confirmed once recovered
+    Receivable,
+    Disbursed,
+    Confirmed,
+}
+
+impl DbSettlementStatus {
+    /// Status a settlement in this status may move to next, if any
+    pub fn next(self) -> Option<DbSettlementStatus> {
+        match self {
+            DbSettlementStatus::Pending => Some(DbSettlementStatus::Disbursed),
+            DbSettlementStatus::Receivable => Some(DbSettlementStatus::Confirmed),
+            DbSettlementStatus::Disbursed => Some(DbSettlementStatus::Confirmed),
+            DbSettlementStatus::Confirmed => None,
+        }
+    }
+
+    pub fn can_advance_to(self, status: DbSettlementStatus) -> bool {
+        self.next() == Some(status)
+    }
+}
+
+impl FromStr for DbSettlementStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Pending" => Ok(DbSettlementStatus::Pending),
+            "Receivable" => Ok(DbSettlementStatus::Receivable),
+            "Disbursed" => Ok(DbSettlementStatus::Disbursed),
+            "Confirmed" => Ok(DbSettlementStatus::Confirmed),
+            _ => Err(()),
+        }
+    }
+}
+
+impl std::fmt::Display for DbSettlementStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DbSettlementStatus::Pending => write!(f, "Pending"),
+            DbSettlementStatus::Receivable => write!(f, "Receivable"),
+            DbSettlementStatus::Disbursed => write!(f, "Disbursed"),
+            DbSettlementStatus::Confirmed => write!(f, "Confirmed"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
 #[sqlx(type_name = "ownership_type", rename_all = "PascalCase")]
 pub enum DbOwnershipType {
@@ -563,6 +691,59 @@ pub enum DbUboStatus {
     UnderReview,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "provisioning_bucket", rename_all = "PascalCase")]
+pub enum DbProvisioningBucket {
+    Current,
+    Bucket1,
+    Bucket2,
+    Bucket3,
+    Bucket4,
+}
+
+impl FromStr for DbProvisioningBucket {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Current" => Ok(DbProvisioningBucket::Current),
+            "Bucket1" => Ok(DbProvisioningBucket::Bucket1),
+            "Bucket2" => Ok(DbProvisioningBucket::Bucket2),
+            "Bucket3" => Ok(DbProvisioningBucket::Bucket3),
+            "Bucket4" => Ok(DbProvisioningBucket::Bucket4),
+            _ => Err(()),
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "balance_change_source", rename_all = "PascalCase")]
+pub enum DbBalanceChangeSource {
+    Transaction,
+    InterestPosting,
+    FeeCharge,
+    LoanRepayment,
+    Reversal,
+    ManualAdjustment,
+}
+
+impl FromStr for DbBalanceChangeSource {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Transaction" => Ok(DbBalanceChangeSource::Transaction),
+            "InterestPosting" => Ok(DbBalanceChangeSource::InterestPosting),
+            "FeeCharge" => Ok(DbBalanceChangeSource::FeeCharge),
+            "LoanRepayment" => Ok(DbBalanceChangeSource::LoanRepayment),
+            "Reversal" => Ok(DbBalanceChangeSource::Reversal),
+            "ManualAdjustment" => Ok(DbBalanceChangeSource::ManualAdjustment),
+            _ => Err(()),
+        }
+    }
+}
+
 impl AccountModel {
     /// Set product id
     pub fn set_product_id(&mut self, product_id: Uuid) {
@@ -0,0 +1,16 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Database model for the domicile change history of accounts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountDomicileChangeModel {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub from_agency_branch_id: Uuid,
+    pub to_agency_branch_id: Uuid,
+    pub effective_date: NaiveDate,
+    pub reason_id: Uuid,
+    pub approved_by_person_id: Uuid,
+    pub recorded_at: DateTime<Utc>,
+}
@@ -0,0 +1,82 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Database model for the account_number_schemes table
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountNumberSchemeModel {
+    pub id: Uuid,
+    pub branch_id: Uuid,
+    /// None for the branch default
+    pub product_id: Option<Uuid>,
+    pub bank_code: HeaplessString<12>,
+    pub branch_code: HeaplessString<10>,
+    pub serial_length: i16,
+    pub iban_country_code: Option<HeaplessString<2>>,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    pub updated_by_person_id: Uuid,
+}
+
+/// # Cache: AccountIdxModelCache
+/// - Concurent
+/// - Filled on create and on lookup; account numbers never change once issued
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountIdxModel {
+    /// # Nature
+    /// - primary
+    pub account_id: Uuid,
+    /// # Nature
+    /// - secondary, unique
+    pub account_number_hash: i64,
+}
+
+#[derive(Debug, Default)]
+pub struct AccountIdxModelCache {
+    by_id: HashMap<Uuid, AccountIdxModel>,
+    by_account_number_hash: HashMap<i64, Uuid>,
+}
+
+impl AccountIdxModelCache {
+    pub fn new(items: Vec<AccountIdxModel>) -> Result<Self, &'static str> {
+        let mut cache = AccountIdxModelCache::default();
+        for item in items {
+            if cache.by_id.contains_key(&item.account_id) {
+                return Err("Duplicate primary key: account_id");
+            }
+            if cache.by_account_number_hash.contains_key(&item.account_number_hash) {
+                return Err("Duplicate unique key: account_number_hash");
+            }
+            cache.add(item);
+        }
+        Ok(cache)
+    }
+
+    pub fn add(&mut self, item: AccountIdxModel) {
+        self.remove(&item.account_id);
+        self.by_account_number_hash.insert(item.account_number_hash, item.account_id);
+        self.by_id.insert(item.account_id, item);
+    }
+
+    pub fn remove(&mut self, account_id: &Uuid) -> Option<AccountIdxModel> {
+        let item = self.by_id.remove(account_id)?;
+        if self.by_account_number_hash.get(&item.account_number_hash) == Some(account_id) {
+            self.by_account_number_hash.remove(&item.account_number_hash);
+        }
+        Some(item)
+    }
+
+    pub fn contains_primary(&self, primary_key: &Uuid) -> bool {
+        self.by_id.contains_key(primary_key)
+    }
+
+    pub fn get_by_primary(&self, primary_key: &Uuid) -> Option<AccountIdxModel> {
+        self.by_id.get(primary_key).cloned()
+    }
+
+    pub fn get_by_account_number_hash(&self, key: &i64) -> Option<Uuid> {
+        self.by_account_number_hash.get(key).copied()
+    }
+}
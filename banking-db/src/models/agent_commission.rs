@@ -0,0 +1,105 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Database model for collection agent commission schemes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentCommissionSchemeModel {
+    pub id: Uuid,
+    pub code: HeaplessString<50>,
+    pub collection_program_id: Option<Uuid>,
+    pub territory_id: Option<Uuid>,
+    pub commission_rate: Decimal,
+    pub target_collection_rate: Option<Decimal>,
+    pub bonus_amount: Decimal,
+    pub deduction_per_discrepancy: Decimal,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    pub updated_by_person_id: Uuid,
+}
+
+/// Database model for monthly agent commission statements
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentCommissionStatementModel {
+    pub id: Uuid,
+    pub collection_agent_id: Uuid,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub collected_amount: Decimal,
+    pub commission_amount: Decimal,
+    pub bonus_amount: Decimal,
+    pub deduction_amount: Decimal,
+    pub net_amount: Decimal,
+    pub discrepancy_count: i32,
+    pub status: DbCommissionStatementStatus,
+    pub calculated_at: DateTime<Utc>,
+    pub invoiced_at: Option<DateTime<Utc>>,
+}
+
+/// Database model for commission statement line items
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentCommissionLineItemModel {
+    pub id: Uuid,
+    pub statement_id: Uuid,
+    pub line_type: DbCommissionLineType,
+    pub scheme_id: Uuid,
+    pub collection_program_id: Option<Uuid>,
+    pub description: HeaplessString<255>,
+    pub basis_amount: Decimal,
+    pub amount: Decimal,
+}
+
+/// Processed collections of an agent in one program, aggregated over a period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentCollectionTotalsModel {
+    pub collection_agent_id: Uuid,
+    pub territory_id: Uuid,
+    pub collection_program_id: Uuid,
+    pub processed_count: i64,
+    pub processed_amount: Decimal,
+    pub attempted_count: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "commission_statement_status", rename_all = "PascalCase")]
+pub enum DbCommissionStatementStatus {
+    Draft,
+    Invoiced,
+}
+
+impl FromStr for DbCommissionStatementStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Draft" => Ok(DbCommissionStatementStatus::Draft),
+            "Invoiced" => Ok(DbCommissionStatementStatus::Invoiced),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "commission_line_type", rename_all = "PascalCase")]
+pub enum DbCommissionLineType {
+    Commission,
+    PerformanceBonus,
+    DiscrepancyDeduction,
+}
+
+impl FromStr for DbCommissionLineType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Commission" => Ok(DbCommissionLineType::Commission),
+            "PerformanceBonus" => Ok(DbCommissionLineType::PerformanceBonus),
+            "DiscrepancyDeduction" => Ok(DbCommissionLineType::DiscrepancyDeduction),
+            _ => Err(()),
+        }
+    }
+}
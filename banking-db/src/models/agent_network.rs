@@ -166,6 +166,7 @@ pub struct AgencyBranchModel {
     // Operational details
     pub operating_hours_id: Uuid,
     pub holiday_plan_id: Uuid,
+    pub calendar_id: Option<HeaplessString<10>>,
     pub temporary_closure_id: Option<Uuid>,  // Changed from temporary_closure_json to match domain
     
     // Contact information - individual messaging fields (up to 5 entries)
@@ -457,3 +458,49 @@ pub struct TerminalLimitsModel {
 }
 
 
+
+/// Level of the agent network hierarchy a traversed node belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HierarchyNodeType {
+    Network,
+    Branch,
+    Terminal,
+}
+
+impl std::str::FromStr for HierarchyNodeType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Network" => Ok(HierarchyNodeType::Network),
+            "Branch" => Ok(HierarchyNodeType::Branch),
+            "Terminal" => Ok(HierarchyNodeType::Terminal),
+            _ => Err(format!("Invalid hierarchy node type: {s}")),
+        }
+    }
+}
+
+/// Node reached by a hierarchy traversal
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HierarchyNodeModel {
+    pub node_id: Uuid,
+    pub node_type: HierarchyNodeType,
+    /// Parent branch, or the network for a root branch; None for the network
+    pub parent_id: Option<Uuid>,
+    /// Levels between the node and the node the traversal started from
+    pub depth: i32,
+}
+
+/// Accounts domiciled at one branch of a rolled-up subtree
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BranchRollupModel {
+    pub agency_branch_id: Uuid,
+    pub parent_agency_branch_id: Option<Uuid>,
+    /// Levels below the branch the rollup was requested for
+    pub depth: i32,
+    pub account_count: i64,
+    pub total_balance: Decimal,
+    /// Totals of the branch and every branch below it
+    pub subtree_account_count: i64,
+    pub subtree_balance: Decimal,
+}
@@ -1,8 +1,12 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 use sqlx::FromRow;
 
+/// prev_hash of the first entry in the chain
+pub const AUDIT_CHAIN_GENESIS_HASH: [u8; 32] = [0; 32];
+
 /// # Repository Trait
 /// - FQN: banking-db/src/repository/person_repository.rs/AuditLogRepository
 /// # Trait method
@@ -19,4 +23,106 @@ pub struct AuditLogModel {
     pub id: Uuid,
     pub updated_at: DateTime<Utc>,
     pub updated_by_person_id: Uuid,
-}
\ No newline at end of file
+    /// Position in the hash chain, assigned by create
+    pub sequence_number: i64,
+    /// record_hash of the entry before this one, assigned by create
+    pub prev_hash: Vec<u8>,
+    /// SHA-256 of prev_hash followed by the canonical bytes, assigned by create
+    pub record_hash: Vec<u8>,
+}
+
+impl AuditLogModel {
+    /// An entry not yet written; create links it into the chain
+    pub fn new(id: Uuid, updated_at: DateTime<Utc>, updated_by_person_id: Uuid) -> Self {
+        Self {
+            id,
+            updated_at,
+            updated_by_person_id,
+            sequence_number: 0,
+            prev_hash: Vec::new(),
+            record_hash: Vec::new(),
+        }
+    }
+
+    /// id, updated_at in microseconds, updated_by_person_id and sequence_number,
+    /// integers big-endian. Microseconds because that is what PostgreSQL stores.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(48);
+        bytes.extend_from_slice(self.id.as_bytes());
+        bytes.extend_from_slice(&self.updated_at.timestamp_micros().to_be_bytes());
+        bytes.extend_from_slice(self.updated_by_person_id.as_bytes());
+        bytes.extend_from_slice(&self.sequence_number.to_be_bytes());
+        bytes
+    }
+
+    pub fn compute_record_hash(&self) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        hasher.update(&self.prev_hash);
+        hasher.update(self.canonical_bytes());
+        hasher.finalize().to_vec()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BrokenAuditLinkReason {
+    /// The sequence skips a number before this entry
+    MissingEntry,
+    /// prev_hash differs from the record_hash of the entry before
+    PrevHashMismatch,
+    /// record_hash differs from the hash of the entry's own content
+    RecordHashMismatch,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BrokenAuditLink {
+    pub audit_log_id: Uuid,
+    pub sequence_number: i64,
+    pub reason: BrokenAuditLinkReason,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditChainVerification {
+    pub entries_checked: i64,
+    /// None when every link in the segment holds
+    pub first_broken_link: Option<BrokenAuditLink>,
+}
+
+impl AuditChainVerification {
+    /// Walk `entries`, ordered by sequence_number, starting from the
+    /// record_hash of the entry before the first one.
+    pub fn walk(predecessor_hash: &[u8], entries: &[AuditLogModel]) -> Self {
+        let mut expected_prev_hash = predecessor_hash;
+        let first_sequence = entries.first().map_or(0, |entry| entry.sequence_number);
+        for (checked, entry) in entries.iter().enumerate() {
+            let reason = if entry.sequence_number != first_sequence + checked as i64 {
+                Some(BrokenAuditLinkReason::MissingEntry)
+            } else if entry.prev_hash != expected_prev_hash {
+                Some(BrokenAuditLinkReason::PrevHashMismatch)
+            } else if entry.record_hash != entry.compute_record_hash() {
+                Some(BrokenAuditLinkReason::RecordHashMismatch)
+            } else {
+                None
+            };
+            if let Some(reason) = reason {
+                return Self {
+                    entries_checked: checked as i64 + 1,
+                    first_broken_link: Some(BrokenAuditLink {
+                        audit_log_id: entry.id,
+                        sequence_number: entry.sequence_number,
+                        reason,
+                    }),
+                };
+            }
+            expected_prev_hash = &entry.record_hash;
+        }
+        Self { entries_checked: entries.len() as i64, first_broken_link: None }
+    }
+}
+
+/// Audited record kinds whose versions carry an audit_log_id
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditEntityType {
+    Person,
+    Location,
+    EntityReference,
+}
@@ -0,0 +1,18 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Database model for the back-dated postings register
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackDatedPostingModel {
+    pub id: Uuid,
+    pub transaction_id: Uuid,
+    pub account_id: Uuid,
+    pub value_date: NaiveDate,
+    pub business_date: NaiveDate,
+    pub days_back: i64,
+    pub accrual_delta: Decimal,
+    pub posted_by_person_id: Uuid,
+    pub posted_at: DateTime<Utc>,
+}
@@ -0,0 +1,122 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Database model for branch vault cash ceilings, one per branch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BranchCashCeilingModel {
+    pub id: Uuid,
+    pub agency_branch_id: Uuid,
+    pub ceiling_amount: Decimal,
+    pub excess_destination_branch_id: Option<Uuid>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    pub updated_by_person_id: Uuid,
+}
+
+/// Terminal cash and posted vault adjustments of a branch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BranchCashPositionModel {
+    pub agency_branch_id: Uuid,
+    pub terminal_cash: Decimal,
+    pub vault_adjustments: Decimal,
+}
+
+/// Database model for cash transfer requests
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashTransferRequestModel {
+    pub id: Uuid,
+    pub from_agency_branch_id: Uuid,
+    pub to_agency_branch_id: Option<Uuid>,
+    pub amount: Decimal,
+    pub status: DbCashTransferStatus,
+    pub origin: DbCashTransferOrigin,
+    pub requested_by_person_id: Option<Uuid>,
+    pub requested_at: DateTime<Utc>,
+    pub dispatch_authorized_by_person_id: Option<Uuid>,
+    pub dispatch_confirmed_by_person_id: Option<Uuid>,
+    pub dispatched_at: Option<DateTime<Utc>>,
+    pub receipt_authorized_by_person_id: Option<Uuid>,
+    pub receipt_confirmed_by_person_id: Option<Uuid>,
+    pub received_at: Option<DateTime<Utc>>,
+    pub cancelled_by_person_id: Option<Uuid>,
+    pub cancellation_reason: Option<HeaplessString<200>>,
+    pub last_updated_at: DateTime<Utc>,
+}
+
+/// Database model for vault adjustments posted by cash transfers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VaultAdjustmentModel {
+    pub id: Uuid,
+    pub agency_branch_id: Option<Uuid>,
+    pub cash_transfer_request_id: Uuid,
+    pub adjustment_type: DbVaultAdjustmentType,
+    pub amount: Decimal,
+    pub posted_at: DateTime<Utc>,
+    pub posted_by_person_id: Uuid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "cash_transfer_status", rename_all = "PascalCase")]
+pub enum DbCashTransferStatus {
+    Requested,
+    InTransit,
+    Received,
+    Cancelled,
+}
+
+impl FromStr for DbCashTransferStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Requested" => Ok(DbCashTransferStatus::Requested),
+            "InTransit" => Ok(DbCashTransferStatus::InTransit),
+            "Received" => Ok(DbCashTransferStatus::Received),
+            "Cancelled" => Ok(DbCashTransferStatus::Cancelled),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "cash_transfer_origin", rename_all = "PascalCase")]
+pub enum DbCashTransferOrigin {
+    CeilingBreach,
+    Manual,
+}
+
+impl FromStr for DbCashTransferOrigin {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "CeilingBreach" => Ok(DbCashTransferOrigin::CeilingBreach),
+            "Manual" => Ok(DbCashTransferOrigin::Manual),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "vault_adjustment_type", rename_all = "PascalCase")]
+pub enum DbVaultAdjustmentType {
+    TransferDispatch,
+    TransferReceipt,
+}
+
+impl FromStr for DbVaultAdjustmentType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "TransferDispatch" => Ok(DbVaultAdjustmentType::TransferDispatch),
+            "TransferReceipt" => Ok(DbVaultAdjustmentType::TransferReceipt),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::models::FeeCategory;
+
+/// Database model for bundle configurations. Components and fee adjustments
+/// are stored as JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductBundleModel {
+    pub id: Uuid,
+    pub bundle_code: HeaplessString<20>,
+    pub name: HeaplessString<100>,
+    pub components: String,
+    pub fee_adjustments: String,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    pub updated_by_person_id: Uuid,
+}
+
+/// Database model for customer bundles
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountBundleModel {
+    pub id: Uuid,
+    pub product_bundle_id: Uuid,
+    pub bundle_code: HeaplessString<20>,
+    pub customer_id: Uuid,
+    pub status: DbBundleStatus,
+    pub orchestration_id: Uuid,
+    pub opened_at: DateTime<Utc>,
+    pub dissolved_at: Option<DateTime<Utc>>,
+    pub dissolution_reason: Option<HeaplessString<255>>,
+}
+
+/// Database model for the accounts of customer bundles
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleAccountModel {
+    pub id: Uuid,
+    pub account_bundle_id: Uuid,
+    pub account_id: Uuid,
+    pub role: DbBundleComponentRole,
+    pub required: bool,
+    pub linked_at: DateTime<Utc>,
+    pub unlinked_at: Option<DateTime<Utc>>,
+}
+
+/// Database model for bundle pricing markers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundlePricingMarkerModel {
+    pub id: Uuid,
+    pub account_bundle_id: Uuid,
+    pub account_id: Uuid,
+    pub fee_category: FeeCategory,
+    pub discount_percentage: Decimal,
+    pub depends_on_account_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub removed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "bundle_status", rename_all = "PascalCase")]
+pub enum DbBundleStatus {
+    Opening,
+    Active,
+    Dissolved,
+    Cancelled,
+}
+
+impl FromStr for DbBundleStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Opening" => Ok(DbBundleStatus::Opening),
+            "Active" => Ok(DbBundleStatus::Active),
+            "Dissolved" => Ok(DbBundleStatus::Dissolved),
+            "Cancelled" => Ok(DbBundleStatus::Cancelled),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "bundle_component_role", rename_all = "PascalCase")]
+pub enum DbBundleComponentRole {
+    Current,
+    Savings,
+    MobileWallet,
+}
+
+impl FromStr for DbBundleComponentRole {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Current" => Ok(DbBundleComponentRole::Current),
+            "Savings" => Ok(DbBundleComponentRole::Savings),
+            "MobileWallet" => Ok(DbBundleComponentRole::MobileWallet),
+            _ => Err(()),
+        }
+    }
+}
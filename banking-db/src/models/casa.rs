@@ -136,6 +136,45 @@ impl FromStr for CompoundingFrequency {
     }
 }
 
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "overdraft_position", rename_all = "PascalCase")]
+pub enum OverdraftPosition {
+    None,
+    Authorized,
+    Unauthorized,
+}
+
+impl FromStr for OverdraftPosition {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "None" => Ok(OverdraftPosition::None),
+            "Authorized" => Ok(OverdraftPosition::Authorized),
+            "Unauthorized" => Ok(OverdraftPosition::Unauthorized),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Daily overdraft debit interest split at the overdraft limit; one row per account and date
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverdraftInterestAccrual {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub accrual_date: NaiveDate,
+    pub position: OverdraftPosition,
+    pub authorized_amount: Decimal,
+    pub authorized_rate: Decimal,
+    pub authorized_interest: Decimal,
+    pub unauthorized_amount: Decimal,
+    pub unauthorized_rate: Decimal,
+    pub unauthorized_interest: Decimal,
+    pub consecutive_unauthorized_days: i32,
+    pub created_at: DateTime<Utc>,
+}
+
 /// CASA account summary for comprehensive reporting
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CasaAccountSummary {
@@ -15,7 +15,8 @@ pub enum ChannelStatus {
 }
 
 /// Channel fee types for categorization
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "channel_fee_type")]
 pub enum ChannelFeeType {
     TransactionFee,
     MaintenanceFee,
@@ -28,7 +29,8 @@ pub enum ChannelFeeType {
 }
 
 /// Channel fee calculation methods
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "channel_fee_calculation_method")]
 pub enum ChannelFeeCalculationMethod {
     Fixed,
     Percentage,
@@ -39,7 +41,7 @@ pub enum ChannelFeeCalculationMethod {
 }
 
 /// Reconciliation status enum
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ReconciliationStatus {
     InProgress,
     Completed,
@@ -47,6 +49,15 @@ pub enum ReconciliationStatus {
     RequiresManualReview,
 }
 
+/// Reconciliation discrepancy classes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiscrepancyType {
+    MissingOurs,
+    MissingTheirs,
+    AmountMismatch,
+    Duplicate,
+}
+
 // Custom serialization functions for database compatibility
 fn serialize_channel_status<S>(value: &ChannelStatus, serializer: S) -> Result<S::Ok, S::Error>
 where
@@ -267,6 +278,31 @@ impl std::fmt::Display for ReconciliationStatus {
     }
 }
 
+impl std::fmt::Display for DiscrepancyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiscrepancyType::MissingOurs => write!(f, "MissingOurs"),
+            DiscrepancyType::MissingTheirs => write!(f, "MissingTheirs"),
+            DiscrepancyType::AmountMismatch => write!(f, "AmountMismatch"),
+            DiscrepancyType::Duplicate => write!(f, "Duplicate"),
+        }
+    }
+}
+
+impl std::str::FromStr for DiscrepancyType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "MissingOurs" => Ok(DiscrepancyType::MissingOurs),
+            "MissingTheirs" => Ok(DiscrepancyType::MissingTheirs),
+            "AmountMismatch" => Ok(DiscrepancyType::AmountMismatch),
+            "Duplicate" => Ok(DiscrepancyType::Duplicate),
+            _ => Err(format!("Unknown discrepancy type: {s}")),
+        }
+    }
+}
+
 impl std::str::FromStr for ReconciliationStatus {
     type Err = String;
     
@@ -405,13 +441,17 @@ pub struct ChannelReconciliationReportModel {
 pub struct ReconciliationDiscrepancyModel {
     pub id: Uuid,
     pub report_id: Uuid,
-    pub transaction_id: Uuid,
+    pub discrepancy_type: DiscrepancyType,
+    pub transaction_id: Option<Uuid>,
+    pub external_reference: Option<HeaplessString<100>>,
     pub description: HeaplessString<200>,
     pub expected_amount: Decimal,
     pub actual_amount: Decimal,
     pub difference: Decimal,
     pub resolved: bool,
     pub resolution_notes: Option<HeaplessString<500>>,
+    pub resolved_by: Option<Uuid>,
+    pub resolved_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -428,4 +468,24 @@ pub struct ChannelFeeModel {
     pub created_at: DateTime<Utc>,
 }
 
+/// Database model for per-channel limits, keyed by channel code and currency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelLimitModel {
+    pub id: Uuid,
+    pub channel_id: HeaplessString<50>,
+    pub currency: HeaplessString<3>,
+    pub per_transaction_max: Option<Decimal>,
+    pub daily_max: Option<Decimal>,
+    pub monthly_max: Option<Decimal>,
+    pub max_count_per_day: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
 
+/// Debits through a channel by the owners of an account, aggregated in one query
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct ChannelUsageModel {
+    pub daily_amount: Decimal,
+    pub daily_count: i64,
+    pub monthly_amount: Decimal,
+}
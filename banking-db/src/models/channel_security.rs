@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::models::Severity;
+
+/// Database model for channel security events. The context is stored as JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelSecurityEventModel {
+    pub id: Uuid,
+    pub channel_id: Uuid,
+    pub customer_id: Option<Uuid>,
+    pub event_type: DbChannelSecurityEventType,
+    pub severity: Severity,
+    pub context: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Database model for temporary channel restrictions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelRestrictionModel {
+    pub id: Uuid,
+    pub customer_id: Uuid,
+    pub channel_id: Uuid,
+    pub reason: HeaplessString<255>,
+    pub rule_code: Option<HeaplessString<50>>,
+    pub starts_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub lifted_at: Option<DateTime<Utc>>,
+    pub lifted_by_person_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "channel_security_event_type", rename_all = "PascalCase")]
+pub enum DbChannelSecurityEventType {
+    FailedAuth,
+    DeviceChange,
+    SimSwapSuspected,
+    PasswordReset,
+}
+
+impl FromStr for DbChannelSecurityEventType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "FailedAuth" => Ok(DbChannelSecurityEventType::FailedAuth),
+            "DeviceChange" => Ok(DbChannelSecurityEventType::DeviceChange),
+            "SimSwapSuspected" => Ok(DbChannelSecurityEventType::SimSwapSuspected),
+            "PasswordReset" => Ok(DbChannelSecurityEventType::PasswordReset),
+            _ => Err(()),
+        }
+    }
+}
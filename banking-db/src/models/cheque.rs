@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Database model for cheque books
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChequeBookModel {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub first_serial_number: i64,
+    pub last_serial_number: i64,
+    pub status: DbChequeBookStatus,
+    pub requested_at: DateTime<Utc>,
+    pub requested_by_person_id: Uuid,
+    pub issued_at: Option<DateTime<Utc>>,
+}
+
+/// Database model for individual cheque leaves
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChequeModel {
+    pub id: Uuid,
+    pub cheque_book_id: Uuid,
+    pub account_id: Uuid,
+    pub serial_number: i64,
+    pub status: DbChequeStatus,
+    pub amount: Option<Decimal>,
+    pub presented_at: Option<DateTime<Utc>>,
+    pub paid_at: Option<DateTime<Utc>>,
+    pub transaction_id: Option<Uuid>,
+    pub return_reason: Option<DbChequeReturnReason>,
+    pub stop_reason: Option<HeaplessString<200>>,
+    pub stopped_at: Option<DateTime<Utc>>,
+    pub stopped_by_person_id: Option<Uuid>,
+    pub last_updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "cheque_book_status", rename_all = "PascalCase")]
+pub enum DbChequeBookStatus {
+    Requested,
+    Issued,
+    Cancelled,
+}
+
+impl FromStr for DbChequeBookStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Requested" => Ok(DbChequeBookStatus::Requested),
+            "Issued" => Ok(DbChequeBookStatus::Issued),
+            "Cancelled" => Ok(DbChequeBookStatus::Cancelled),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "cheque_status", rename_all = "PascalCase")]
+pub enum DbChequeStatus {
+    Unused,
+    Presented,
+    Paid,
+    Bounced,
+    Stopped,
+}
+
+impl FromStr for DbChequeStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Unused" => Ok(DbChequeStatus::Unused),
+            "Presented" => Ok(DbChequeStatus::Presented),
+            "Paid" => Ok(DbChequeStatus::Paid),
+            "Bounced" => Ok(DbChequeStatus::Bounced),
+            "Stopped" => Ok(DbChequeStatus::Stopped),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "cheque_return_reason", rename_all = "PascalCase")]
+pub enum DbChequeReturnReason {
+    InsufficientFunds,
+    PaymentStopped,
+    DuplicatePresentment,
+    UnknownCheque,
+    AccountNotOperational,
+}
+
+impl FromStr for DbChequeReturnReason {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "InsufficientFunds" => Ok(DbChequeReturnReason::InsufficientFunds),
+            "PaymentStopped" => Ok(DbChequeReturnReason::PaymentStopped),
+            "DuplicatePresentment" => Ok(DbChequeReturnReason::DuplicatePresentment),
+            "UnknownCheque" => Ok(DbChequeReturnReason::UnknownCheque),
+            "AccountNotOperational" => Ok(DbChequeReturnReason::AccountNotOperational),
+            _ => Err(()),
+        }
+    }
+}
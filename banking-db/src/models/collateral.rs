@@ -436,7 +436,10 @@ pub struct CollateralValuationModel {
     #[serde(serialize_with = "serialize_valuation_method", deserialize_with = "deserialize_valuation_method")]
     pub valuation_method: ValuationMethod,
     pub market_value: Decimal,
+    pub currency: HeaplessString<3>,
     pub forced_sale_value: Option<Decimal>,
+    /// References PersonModel.person_id of the valuer
+    pub valuer_person_id: Uuid,
     pub appraiser_name: HeaplessString<255>,
     pub appraiser_license: Option<HeaplessString<100>>,
     pub valuation_report_reference: HeaplessString<100>,
@@ -450,6 +453,16 @@ pub struct CollateralValuationModel {
     pub created_by_person_id: Uuid,
 }
 
+/// An active pledge joined with the outstanding principal of its loan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollateralizedLoanModel {
+    pub loan_account_id: Uuid,
+    pub collateral_id: Uuid,
+    /// Currency of the loan account
+    pub currency: HeaplessString<3>,
+    pub outstanding_principal: Decimal,
+}
+
 /// Database model for CollateralPledge
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct CollateralPledgeModel {
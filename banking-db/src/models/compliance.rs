@@ -76,6 +76,8 @@ pub enum AlertType {
     SuspiciousPattern,
     GeographicAnomaly,
     CrossBorderTransaction,
+    SanctionsMatch,
+    UnauthorizedOverdraft,
 }
 
 impl std::fmt::Display for AlertType {
@@ -87,6 +89,8 @@ impl std::fmt::Display for AlertType {
             AlertType::SuspiciousPattern => write!(f, "SuspiciousPattern"),
             AlertType::GeographicAnomaly => write!(f, "GeographicAnomaly"),
             AlertType::CrossBorderTransaction => write!(f, "CrossBorderTransaction"),
+            AlertType::SanctionsMatch => write!(f, "SanctionsMatch"),
+            AlertType::UnauthorizedOverdraft => write!(f, "UnauthorizedOverdraft"),
         }
     }
 }
@@ -102,6 +106,8 @@ impl FromStr for AlertType {
             "SuspiciousPattern" => Ok(AlertType::SuspiciousPattern),
             "GeographicAnomaly" => Ok(AlertType::GeographicAnomaly),
             "CrossBorderTransaction" => Ok(AlertType::CrossBorderTransaction),
+            "SanctionsMatch" => Ok(AlertType::SanctionsMatch),
+            "UnauthorizedOverdraft" => Ok(AlertType::UnauthorizedOverdraft),
             _ => Err(()),
         }
     }
@@ -182,6 +188,7 @@ impl FromStr for AlertStatus {
 #[sqlx(type_name = "sar_status", rename_all = "PascalCase")]
 pub enum SarStatus {
     Draft,
+    Submitted,
     Filed,
     Acknowledged,
     UnderReview,
@@ -334,6 +341,7 @@ pub struct SanctionsMatchModel {
 pub struct SanctionsScreeningModel {
     pub id: Uuid,
     pub customer_id: Uuid,
+    pub screening_type: ScreeningType,
     pub screening_date: DateTime<Utc>,
     pub screening_result: HeaplessString<50>, // Clear, Match, PotentialMatch
     pub match_details: Option<HeaplessString<500>>, // JSON with match information
@@ -462,6 +470,18 @@ pub struct ComplianceRiskScoreModel {
     pub last_updated_at: DateTime<Utc>,
 }
 
+/// Inputs of a customer's risk rating, read from the person, account,
+/// transaction and alert tables
+#[derive(Debug, Clone)]
+pub struct CustomerRiskFactorsModel {
+    pub customer_id: Uuid,
+    pub country_iso2: Option<HeaplessString<2>>,
+    pub account_ids: Vec<Uuid>,
+    pub product_ids: Vec<Uuid>,
+    pub transaction_count: i64,
+    pub alert_count: i64,
+}
+
 /// SAR Data database model - aligned with domain SarData
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SarDataModel {
@@ -491,9 +511,21 @@ pub struct SarDataModel {
     pub supporting_transaction_id_17: Option<Uuid>,
     pub supporting_transaction_id_18: Option<Uuid>,
     pub supporting_transaction_id_19: Option<Uuid>,
+    pub narrative: HeaplessString<1000>,
+    pub customer_full_name: HeaplessString<100>,
+    /// References ComplianceAlert.id
+    pub alert_ids: Vec<Uuid>,
+    /// References Account.id
+    pub account_ids: Vec<Uuid>,
     pub generated_at: DateTime<Utc>,
     #[serde(serialize_with = "serialize_sar_status", deserialize_with = "deserialize_sar_status")]
     pub status: SarStatus,
+    /// References Person.person_id
+    pub created_by_person_id: Uuid,
+    /// References Person.person_id
+    pub submitted_by_person_id: Option<Uuid>,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub last_updated_at: DateTime<Utc>,
 }
 
 /// Extended SAR Data database model (for repository use)
@@ -728,6 +760,8 @@ where
         AlertType::SuspiciousPattern => "SuspiciousPattern",
         AlertType::GeographicAnomaly => "GeographicAnomaly",
         AlertType::CrossBorderTransaction => "CrossBorderTransaction",
+        AlertType::SanctionsMatch => "SanctionsMatch",
+        AlertType::UnauthorizedOverdraft => "UnauthorizedOverdraft",
     };
     serializer.serialize_str(type_str)
 }
@@ -744,6 +778,8 @@ where
         "SuspiciousPattern" => Ok(AlertType::SuspiciousPattern),
         "GeographicAnomaly" => Ok(AlertType::GeographicAnomaly),
         "CrossBorderTransaction" => Ok(AlertType::CrossBorderTransaction),
+        "SanctionsMatch" => Ok(AlertType::SanctionsMatch),
+        "UnauthorizedOverdraft" => Ok(AlertType::UnauthorizedOverdraft),
         _ => Err(serde::de::Error::custom(format!("Unknown alert type: {s}"))),
     }
 }
@@ -897,6 +933,7 @@ where
 {
     let status_str = match status {
         SarStatus::Draft => "Draft",
+        SarStatus::Submitted => "Submitted",
         SarStatus::UnderReview => "UnderReview",
         SarStatus::Filed => "Filed",
         SarStatus::Acknowledged => "Acknowledged",
@@ -912,6 +949,7 @@ where
     let s = String::deserialize(deserializer)?;
     match s.as_str() {
         "Draft" => Ok(SarStatus::Draft),
+        "Submitted" => Ok(SarStatus::Submitted),
         "UnderReview" => Ok(SarStatus::UnderReview),
         "Filed" => Ok(SarStatus::Filed),
         "Acknowledged" => Ok(SarStatus::Acknowledged),
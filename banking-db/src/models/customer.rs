@@ -24,6 +24,9 @@ pub struct CustomerModel {
     pub last_updated_at: DateTime<Utc>,
     /// References Person.person_id
     pub updated_by_person_id: Uuid,
+    pub preferred_language_code: Option<[u8; 3]>,
+    /// References Customer.id of the surviving record; set when this one is merged into it
+    pub duplicate_of_customer_id: Option<Uuid>,
 }
 
 /// Database model for Customer Portfolio summary
@@ -34,12 +37,27 @@ pub struct CustomerPortfolioModel {
     pub total_accounts: i64,
     pub total_balance: Decimal,
     pub total_loan_outstanding: Option<Decimal>,
+    pub total_guarantee_exposure: Option<Decimal>,
     pub last_activity_date: Option<DateTime<Utc>>,
     pub risk_score: Option<Decimal>,
     #[serde(serialize_with = "serialize_kyc_status", deserialize_with = "deserialize_kyc_status")]
     pub kyc_status: KycStatus,
     pub sanctions_checked: bool,
     pub last_screening_date: Option<DateTime<Utc>>,
+    pub tags: Vec<HeaplessString<50>>,
+    pub recent_security_events: Vec<crate::models::ChannelSecurityEventModel>,
+    pub interaction_note_count: i64,
+}
+
+/// Customer search criteria; all set fields must match
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerSearchCriteriaModel {
+    pub customer_type: Option<CustomerType>,
+    pub status: Option<CustomerStatus>,
+    pub risk_rating: Option<RiskRating>,
+    pub tags: Option<crate::models::TagFilterModel>,
+    pub offset: i64,
+    pub limit: i64,
 }
 
 /// Database model for Customer documents
@@ -150,6 +168,8 @@ pub enum CustomerStatus {
     Deceased,
     Dissolved,
     Blacklisted,
+    /// Folded into another customer record, see CustomerModel.duplicate_of_customer_id
+    Merged,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
@@ -266,6 +286,7 @@ impl FromStr for CustomerStatus {
             "PendingVerification" => Ok(CustomerStatus::PendingVerification),
             "Deceased" => Ok(CustomerStatus::Deceased),
             "Dissolved" => Ok(CustomerStatus::Dissolved),
+            "Merged" => Ok(CustomerStatus::Merged),
             "Blacklisted" => Ok(CustomerStatus::Blacklisted),
             _ => Err(()),
         }
@@ -387,6 +408,7 @@ where
         CustomerStatus::PendingVerification => "PendingVerification",
         CustomerStatus::Deceased => "Deceased",
         CustomerStatus::Dissolved => "Dissolved",
+        CustomerStatus::Merged => "Merged",
         CustomerStatus::Blacklisted => "Blacklisted",
     };
     serializer.serialize_str(value_str)
@@ -402,6 +424,7 @@ where
         "PendingVerification" => Ok(CustomerStatus::PendingVerification),
         "Deceased" => Ok(CustomerStatus::Deceased),
         "Dissolved" => Ok(CustomerStatus::Dissolved),
+        "Merged" => Ok(CustomerStatus::Merged),
         "Blacklisted" => Ok(CustomerStatus::Blacklisted),
         _ => Err(serde::de::Error::custom(format!("Invalid CustomerStatus: {value_str}"))),
     }
@@ -141,6 +141,14 @@ pub enum CollectionRecordStatus {
     UnderReview,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "geo_verification_status", rename_all = "PascalCase")]
+pub enum GeoVerificationStatus {
+    Verified,
+    GeoMismatch,
+    MissingCoordinates,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, sqlx::Type)]
 #[sqlx(type_name = "biometric_method", rename_all = "PascalCase")]
 pub enum BiometricMethod {
@@ -273,6 +281,14 @@ pub struct CollectionSecurityFeaturesModel {
     pub last_security_scan: Option<DateTime<Utc>>,
 }
 
+/// An agent's device with its security features, as checked for compliance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentDeviceModel {
+    pub agent_id: Uuid,
+    pub device: DeviceInformationModel,
+    pub security_features: CollectionSecurityFeaturesModel,
+}
+
 /// Database model for Coverage Areas
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
@@ -332,7 +348,10 @@ pub struct PerformanceAlertModel {
     pub resolution_required: bool,
     pub created_at: DateTime<Utc>,
     pub acknowledged_at: Option<DateTime<Utc>>,
+    pub acknowledged_by_person_id: Option<Uuid>,
     pub resolved_at: Option<DateTime<Utc>>,
+    pub resolved_by_person_id: Option<Uuid>,
+    pub resolution_notes: Option<HeaplessString<500>>,
 }
 
 // ======== Collection Program Database Models ========
@@ -372,6 +391,8 @@ pub struct CollectionProgramModel {
     pub fee_frequency: CollectionFeeFrequency,
     
     pub interest_rate: Option<Decimal>,
+    pub geo_verification_radius_meters: Option<i32>,
+    pub geo_mismatch_weekly_threshold: Option<i32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub created_by_person_id: Uuid,
@@ -466,6 +487,12 @@ pub struct CollectionRecordModel {
     pub verification_witness_signature: Option<HeaplessString<200>>,
     pub verification_timestamp: Option<DateTime<Utc>>,
     
+    // Geo verification fields
+    pub agent_latitude: Option<Decimal>,
+    pub agent_longitude: Option<Decimal>,
+    pub geo_verification_status: Option<GeoVerificationStatus>,
+    pub geo_distance_meters: Option<f64>,
+    
     pub created_at: DateTime<Utc>,
     pub processed_at: Option<DateTime<Utc>>,
     pub reason_id: Option<Uuid>,
@@ -0,0 +1,115 @@
+use blake3::Hash;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Database model for generated customer documents
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedDocumentModel {
+    pub id: Uuid,
+    pub document_type: DbGeneratedDocumentType,
+    pub account_id: Uuid,
+    pub customer_id: Uuid,
+    pub language_code: [u8; 3],
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Database model for queued document notifications
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentNotificationModel {
+    pub id: Uuid,
+    /// References GeneratedDocumentModel.id
+    pub document_id: Uuid,
+    pub customer_id: Uuid,
+    pub retrieval_reference: HeaplessString<50>,
+    pub language_code: [u8; 3],
+    pub status: DbDocumentNotificationStatus,
+    pub queued_at: DateTime<Utc>,
+    pub sent_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "generated_document_type", rename_all = "PascalCase")]
+pub enum DbGeneratedDocumentType {
+    WelcomePack,
+}
+
+impl FromStr for DbGeneratedDocumentType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "WelcomePack" => Ok(DbGeneratedDocumentType::WelcomePack),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "document_notification_status", rename_all = "PascalCase")]
+pub enum DbDocumentNotificationStatus {
+    Queued,
+    Sent,
+    Failed,
+}
+
+impl FromStr for DbDocumentNotificationStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Queued" => Ok(DbDocumentNotificationStatus::Queued),
+            "Sent" => Ok(DbDocumentNotificationStatus::Sent),
+            "Failed" => Ok(DbDocumentNotificationStatus::Failed),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Database model for uploaded documents, one row per content hash
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisteredDocumentModel {
+    pub id: Uuid,
+    pub content_hash: Hash,
+    pub document_type: HeaplessString<50>,
+    pub document_path: Option<Hash>,
+    pub size_bytes: Option<i64>,
+    pub registered_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub registered_by_person_id: Uuid,
+}
+
+/// Database model for a workflow or customer referencing a registered document
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentLinkModel {
+    pub id: Uuid,
+    /// References RegisteredDocumentModel.id
+    pub document_id: Uuid,
+    pub entity_kind: DbDocumentLinkKind,
+    pub entity_id: Uuid,
+    pub linked_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub linked_by_person_id: Uuid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "document_link_kind", rename_all = "PascalCase")]
+pub enum DbDocumentLinkKind {
+    Workflow,
+    Customer,
+}
+
+impl FromStr for DbDocumentLinkKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Workflow" => Ok(DbDocumentLinkKind::Workflow),
+            "Customer" => Ok(DbDocumentLinkKind::Customer),
+            _ => Err(()),
+        }
+    }
+}
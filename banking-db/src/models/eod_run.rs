@@ -0,0 +1,77 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Database model for end-of-day runs, one per run date
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EodRunModel {
+    pub id: Uuid,
+    pub run_date: NaiveDate,
+    pub status: DbEodRunStatus,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub forced_reruns: i32,
+}
+
+/// Database model for the progress of one step of an end-of-day run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EodRunStepModel {
+    pub id: Uuid,
+    pub eod_run_id: Uuid,
+    pub run_date: NaiveDate,
+    pub step_name: HeaplessString<50>,
+    pub step_order: i32,
+    pub status: DbEodStepStatus,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub records_processed: i64,
+    pub records_failed: i64,
+    pub checkpoint_cursor: Option<Uuid>,
+    pub error: Option<HeaplessString<500>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "eod_run_status", rename_all = "PascalCase")]
+pub enum DbEodRunStatus {
+    Running,
+    Failed,
+    Completed,
+}
+
+impl FromStr for DbEodRunStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Running" => Ok(DbEodRunStatus::Running),
+            "Failed" => Ok(DbEodRunStatus::Failed),
+            "Completed" => Ok(DbEodRunStatus::Completed),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "eod_step_status", rename_all = "PascalCase")]
+pub enum DbEodStepStatus {
+    Pending,
+    Running,
+    Failed,
+    Completed,
+}
+
+impl FromStr for DbEodStepStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Pending" => Ok(DbEodStepStatus::Pending),
+            "Running" => Ok(DbEodStepStatus::Running),
+            "Failed" => Ok(DbEodStepStatus::Failed),
+            "Completed" => Ok(DbEodStepStatus::Completed),
+            _ => Err(()),
+        }
+    }
+}
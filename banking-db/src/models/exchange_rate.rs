@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Database model for exchange rates; unique per currency pair and effective time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExchangeRateModel {
+    pub from_currency: HeaplessString<3>,
+    pub to_currency: HeaplessString<3>,
+    pub rate: Decimal,
+    pub effective_at: DateTime<Utc>,
+    pub source: HeaplessString<50>,
+}
@@ -41,6 +41,8 @@ pub struct FeeApplicationModel {
     /// References ReasonAndPurpose.id for waiver reason
     pub waived_reason_id: Option<Uuid>,
     pub applied_by: Uuid,
+    pub reversal_transaction_id: Option<Uuid>,
+    pub audit_log_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -264,9 +266,13 @@ where S: Serializer {
         FeeTriggerEvent::MonthlyMaintenance => "MonthlyMaintenance",
         FeeTriggerEvent::QuarterlyMaintenance => "QuarterlyMaintenance",
         FeeTriggerEvent::AnnualMaintenance => "AnnualMaintenance",
+        FeeTriggerEvent::AccountInactivity => "AccountInactivity",
         FeeTriggerEvent::CardIssuance => "CardIssuance",
         FeeTriggerEvent::CardReplacement => "CardReplacement",
         FeeTriggerEvent::CardActivation => "CardActivation",
+        FeeTriggerEvent::ChequeBookIssuance => "ChequeBookIssuance",
+        FeeTriggerEvent::StopPayment => "StopPayment",
+        FeeTriggerEvent::LoanEarlyRepayment => "LoanEarlyRepayment",
         FeeTriggerEvent::Manual => "Manual",
         FeeTriggerEvent::Regulatory => "Regulatory",
     };
@@ -292,9 +298,13 @@ where D: Deserializer<'de> {
         "MonthlyMaintenance" => Ok(FeeTriggerEvent::MonthlyMaintenance),
         "QuarterlyMaintenance" => Ok(FeeTriggerEvent::QuarterlyMaintenance),
         "AnnualMaintenance" => Ok(FeeTriggerEvent::AnnualMaintenance),
+        "AccountInactivity" => Ok(FeeTriggerEvent::AccountInactivity),
         "CardIssuance" => Ok(FeeTriggerEvent::CardIssuance),
         "CardReplacement" => Ok(FeeTriggerEvent::CardReplacement),
         "CardActivation" => Ok(FeeTriggerEvent::CardActivation),
+        "ChequeBookIssuance" => Ok(FeeTriggerEvent::ChequeBookIssuance),
+        "StopPayment" => Ok(FeeTriggerEvent::StopPayment),
+        "LoanEarlyRepayment" => Ok(FeeTriggerEvent::LoanEarlyRepayment),
         "Manual" => Ok(FeeTriggerEvent::Manual),
         "Regulatory" => Ok(FeeTriggerEvent::Regulatory),
         _ => Err(serde::de::Error::custom(format!("Invalid FeeTriggerEvent: {s}"))),
@@ -0,0 +1,16 @@
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Aggregates of a customer's open owned accounts in one currency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrencyPositionModel {
+    pub currency: HeaplessString<3>,
+    pub account_count: i64,
+    pub total_deposits: Decimal,
+    pub loan_exposure: Decimal,
+    pub holds: Decimal,
+    pub legal_holds: Decimal,
+    pub overdraft_used: Decimal,
+    pub overdraft_limit: Decimal,
+}
@@ -0,0 +1,35 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::TransactionType;
+
+/// Posted transactions of a run date grouped by the account's product, GL
+/// suffix and domicile branch, and by currency, channel and direction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlPostingTotalsModel {
+    pub product_id: Uuid,
+    pub gl_code_suffix: Option<HeaplessString<10>>,
+    pub branch_id: Uuid,
+    pub currency: HeaplessString<3>,
+    pub channel_id: HeaplessString<50>,
+    pub transaction_type: TransactionType,
+    pub amount_total: Decimal,
+    pub transaction_count: i64,
+}
+
+/// Database model for the gl_summary table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlSummaryLineModel {
+    pub run_date: NaiveDate,
+    pub gl_code: HeaplessString<50>,
+    pub product_id: Uuid,
+    pub branch_id: Uuid,
+    pub currency: HeaplessString<3>,
+    pub debit_total: Decimal,
+    pub credit_total: Decimal,
+    pub transaction_count: i64,
+    pub created_at: DateTime<Utc>,
+}
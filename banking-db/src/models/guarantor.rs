@@ -0,0 +1,72 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Database model for loan guarantors
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoanGuarantorModel {
+    pub id: Uuid,
+    pub loan_account_id: Uuid,
+    pub guarantor_type: DbGuarantorType,
+    pub guarantor_id: Uuid,
+    /// `None` for an unlimited guarantee
+    pub guarantee_amount: Option<Decimal>,
+    pub document_reference: Option<HeaplessString<100>>,
+    pub status: DbGuarantorStatus,
+    pub released_at: Option<DateTime<Utc>>,
+    pub released_by_person_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    pub updated_by_person_id: Uuid,
+}
+
+/// An active guarantee joined with the outstanding principal of its loan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuaranteedLoanModel {
+    pub loan_account_id: Uuid,
+    /// Currency of the loan account
+    pub currency: HeaplessString<3>,
+    pub outstanding_principal: Decimal,
+    pub guarantee_amount: Option<Decimal>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "guarantor_type", rename_all = "PascalCase")]
+pub enum DbGuarantorType {
+    Customer,
+    Person,
+}
+
+impl FromStr for DbGuarantorType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Customer" => Ok(DbGuarantorType::Customer),
+            "Person" => Ok(DbGuarantorType::Person),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "guarantor_status", rename_all = "PascalCase")]
+pub enum DbGuarantorStatus {
+    Active,
+    Released,
+}
+
+impl FromStr for DbGuarantorStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Active" => Ok(DbGuarantorStatus::Active),
+            "Released" => Ok(DbGuarantorStatus::Released),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Database model for staff interaction notes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InteractionNoteModel {
+    pub id: Uuid,
+    pub entity_kind: DbNoteEntityKind,
+    pub entity_id: Uuid,
+    pub author_person_id: Uuid,
+    pub agency_branch_id: Option<Uuid>,
+    pub visibility: DbNoteVisibility,
+    pub category: DbNoteCategory,
+    pub body: HeaplessString<1000>,
+    pub corrects_note_id: Option<Uuid>,
+    pub follow_up_date: Option<NaiveDate>,
+    pub follow_up_completed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "note_entity_kind", rename_all = "PascalCase")]
+pub enum DbNoteEntityKind {
+    Customer,
+    Account,
+}
+
+impl FromStr for DbNoteEntityKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Customer" => Ok(DbNoteEntityKind::Customer),
+            "Account" => Ok(DbNoteEntityKind::Account),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "note_visibility", rename_all = "PascalCase")]
+pub enum DbNoteVisibility {
+    BranchOnly,
+    BankWide,
+    ComplianceOnly,
+}
+
+impl FromStr for DbNoteVisibility {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "BranchOnly" => Ok(DbNoteVisibility::BranchOnly),
+            "BankWide" => Ok(DbNoteVisibility::BankWide),
+            "ComplianceOnly" => Ok(DbNoteVisibility::ComplianceOnly),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "note_category", rename_all = "PascalCase")]
+pub enum DbNoteCategory {
+    BranchVisit,
+    PhoneCall,
+    Complaint,
+    ServiceRequest,
+    Compliance,
+    General,
+}
+
+impl FromStr for DbNoteCategory {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "BranchVisit" => Ok(DbNoteCategory::BranchVisit),
+            "PhoneCall" => Ok(DbNoteCategory::PhoneCall),
+            "Complaint" => Ok(DbNoteCategory::Complaint),
+            "ServiceRequest" => Ok(DbNoteCategory::ServiceRequest),
+            "Compliance" => Ok(DbNoteCategory::Compliance),
+            "General" => Ok(DbNoteCategory::General),
+            _ => Err(()),
+        }
+    }
+}
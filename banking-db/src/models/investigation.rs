@@ -0,0 +1,95 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Query feeding one part of an account timeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DbTimelineSource {
+    Workflows,
+    WorkflowSteps,
+    StatusChanges,
+    Holds,
+    Restrictions,
+    Transactions,
+    /// Transactions grouped per day
+    TransactionDays,
+    Notifications,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DbTimelineCategory {
+    WorkflowCreated,
+    WorkflowStep,
+    WorkflowCompleted,
+    StatusChange,
+    HoldPlaced,
+    HoldReleased,
+    RestrictionPlaced,
+    RestrictionLifted,
+    Transaction,
+    TransactionSummary,
+    NotificationSent,
+}
+
+impl fmt::Display for DbTimelineCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl FromStr for DbTimelineCategory {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "WorkflowCreated" => Ok(DbTimelineCategory::WorkflowCreated),
+            "WorkflowStep" => Ok(DbTimelineCategory::WorkflowStep),
+            "WorkflowCompleted" => Ok(DbTimelineCategory::WorkflowCompleted),
+            "StatusChange" => Ok(DbTimelineCategory::StatusChange),
+            "HoldPlaced" => Ok(DbTimelineCategory::HoldPlaced),
+            "HoldReleased" => Ok(DbTimelineCategory::HoldReleased),
+            "RestrictionPlaced" => Ok(DbTimelineCategory::RestrictionPlaced),
+            "RestrictionLifted" => Ok(DbTimelineCategory::RestrictionLifted),
+            "Transaction" => Ok(DbTimelineCategory::Transaction),
+            "TransactionSummary" => Ok(DbTimelineCategory::TransactionSummary),
+            "NotificationSent" => Ok(DbTimelineCategory::NotificationSent),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Database model for a normalized timeline row
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineEventModel {
+    pub occurred_at: DateTime<Utc>,
+    pub category: DbTimelineCategory,
+    pub actor_person_id: Option<Uuid>,
+    pub summary: HeaplessString<500>,
+    pub reference_id: Uuid,
+}
+
+/// Position after the last row read from a timeline source
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineCursorModel {
+    pub occurred_at: DateTime<Utc>,
+    pub category: DbTimelineCategory,
+    pub reference_id: Uuid,
+}
+
+/// Database model for the timeline read audit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineAccessRecordModel {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub from_date: NaiveDate,
+    pub to_date: NaiveDate,
+    pub accessed_by_person_id: Uuid,
+    pub case_reference: HeaplessString<100>,
+    pub events_returned: i64,
+    pub exported: bool,
+    pub accessed_at: DateTime<Utc>,
+}
@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Database model for operational kill switches
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KillSwitchModel {
+    pub id: Uuid,
+    pub scope: DbKillSwitchScope,
+    pub scope_value: Option<HeaplessString<50>>,
+    pub state: DbKillSwitchState,
+    pub reason: HeaplessString<255>,
+    pub activated_by_person_id: Uuid,
+    pub activated_at: DateTime<Utc>,
+    pub auto_expire_at: Option<DateTime<Utc>>,
+    pub deactivated_by_person_id: Option<Uuid>,
+    pub deactivated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "kill_switch_scope", rename_all = "PascalCase")]
+pub enum DbKillSwitchScope {
+    Global,
+    TransactionKind,
+    Channel,
+    Product,
+}
+
+impl FromStr for DbKillSwitchScope {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Global" => Ok(DbKillSwitchScope::Global),
+            "TransactionKind" => Ok(DbKillSwitchScope::TransactionKind),
+            "Channel" => Ok(DbKillSwitchScope::Channel),
+            "Product" => Ok(DbKillSwitchScope::Product),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "kill_switch_state", rename_all = "PascalCase")]
+pub enum DbKillSwitchState {
+    Active,
+    Deactivated,
+    Expired,
+}
+
+impl FromStr for DbKillSwitchState {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Active" => Ok(DbKillSwitchState::Active),
+            "Deactivated" => Ok(DbKillSwitchState::Deactivated),
+            "Expired" => Ok(DbKillSwitchState::Expired),
+            _ => Err(()),
+        }
+    }
+}
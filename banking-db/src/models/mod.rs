@@ -2,6 +2,7 @@ pub mod audit;
 pub mod person;
 // pub mod customer;
 // pub mod account;
+// pub mod account_number;
 // pub mod account_hold;
 // pub mod transaction;
 // pub mod agent_network;
@@ -18,6 +19,34 @@ pub mod person;
 // pub mod reason_view;
 // pub mod daily_collection;
 // pub mod product;
+// pub mod document;
+// pub mod segment;
+// pub mod guarantor;
+// pub mod quote;
+// pub mod cheque;
+// pub mod statement;
+// pub mod orchestration;
+// pub mod tag;
+// pub mod channel_security;
+// pub mod agent_commission;
+// pub mod branch_cash;
+// pub mod notification;
+// pub mod investigation;
+// pub mod bundle;
+// pub mod financial_position;
+// pub mod exchange_rate;
+// pub mod promotion;
+// pub mod savings_goal;
+// pub mod payee;
+// pub mod back_dated_posting;
+// pub mod account_domicile;
+// pub mod kill_switch;
+// pub mod interaction_note;
+// pub mod verification;
+// pub mod warehouse;
+// pub mod standing_order;
+// pub mod general_ledger;
+// pub mod eod_run;
 
 pub use audit::*;
 pub use person::*;
@@ -25,8 +54,11 @@ pub use person::*;
 // pub use account::{
 //     AccountModel, AccountOwnershipModel, AccountRelationshipModel, AccountMandateModel,
 //     AccountStatusChangeRecordModel, AccountFinalSettlementModel, FinalSettlementModel,
-//     DisbursementInstructionsModel, UltimateBeneficiaryModel, DbAccountType
+//     DisbursementInstructionsModel, UltimateBeneficiaryModel, DbAccountType,
+//     AccountBalanceSnapshotModel, DbProvisioningBucket, AccountInterestAccrualModel, LoanPenaltyAccrualModel,
+//     AccountBalanceChangeRecordModel, DbBalanceChangeSource, DbSettlementStatus
 // };
+// pub use account_number::{AccountNumberSchemeModel, AccountIdxModel, AccountIdxModelCache};
 // pub use account_hold::{
 //     AccountHoldModel, AccountHoldSummaryModel, AccountHoldReleaseRequestModel,
 //     AccountHoldExpiryJobModel, AccountBalanceCalculationModel,
@@ -40,7 +72,7 @@ pub use person::*;
 //     KycResultModel, KycCheckModel,
 //     ScreeningResultModel, SanctionsMatchModel, SanctionsScreeningModel,
 //     ComplianceAlertModel, ExtendedComplianceAlertModel, UboVerificationResultModel,
-//     UboLinkModel, ComplianceResultModel, ComplianceRiskScoreModel,
+//     UboLinkModel, ComplianceResultModel, ComplianceRiskScoreModel, CustomerRiskFactorsModel,
 //     SarDataModel, ExtendedSarDataModel, ComplianceDocumentModel,
 //     ComplianceCustomerAuditModel, MonitoringResultModel, MonitoringRulesModel,
 //     ComplianceCustomerPortfolioModel,
@@ -60,6 +92,34 @@ pub use person::*;
 // pub use loan::*;
 // pub use reason_view::*;
 // pub use product::*;
+// pub use document::*;
+// pub use segment::*;
+// pub use guarantor::*;
+// pub use quote::*;
+// pub use cheque::*;
+// pub use statement::*;
+// pub use orchestration::*;
+// pub use tag::*;
+// pub use channel_security::*;
+// pub use agent_commission::*;
+// pub use branch_cash::*;
+// pub use notification::*;
+// pub use investigation::*;
+// pub use bundle::*;
+// pub use financial_position::*;
+// pub use exchange_rate::*;
+// pub use promotion::*;
+// pub use savings_goal::*;
+// pub use payee::*;
+// pub use back_dated_posting::*;
+// pub use account_domicile::*;
+// pub use kill_switch::*;
+// pub use interaction_note::*;
+// pub use verification::*;
+// pub use warehouse::*;
+// pub use standing_order::*;
+// pub use general_ledger::*;
+// pub use eod_run::*;
 // pub use daily_collection::{
 //     CollectionAgentModel, CollectionProgramModel, CustomerCollectionProfileModel,
 //     CollectionRecordModel, CollectionBatchModel, CollectionBatchRecordModel, CoverageAreaModel, PerformanceAlertModel,
@@ -70,6 +130,7 @@ pub use person::*;
 //     CollectionFrequency as DbCollectionFrequency, CollectionStatus as DbDailyCollectionStatus,
 //     HolidayHandling as DbHolidayHandling, ReliabilityRating as DbReliabilityRating,
 //     CollectionMethod as DbCollectionMethod, CollectionRecordStatus as DbCollectionRecordStatus,
+//     GeoVerificationStatus as DbGeoVerificationStatus,
 //     BiometricMethod as DbBiometricMethod, BatchStatus as DbDailyCollectionBatchStatus,
 //     CollectionAlertType as DbDailyCollectionAlertType, CollectionFeeFrequency as DbDailyCollectionFeeFrequency,
 // };
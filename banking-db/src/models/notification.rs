@@ -0,0 +1,63 @@
+use std::str::FromStr;
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::DbDocumentNotificationStatus;
+
+/// Database model for queued customer notifications
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedNotificationModel {
+    pub id: Uuid,
+    pub idempotency_key: HeaplessString<200>,
+    pub template_code: HeaplessString<50>,
+    pub customer_id: Uuid,
+    pub business_date: NaiveDate,
+    pub body: HeaplessString<500>,
+    pub status: DbDocumentNotificationStatus,
+    pub queued_at: DateTime<Utc>,
+    pub sent_at: Option<DateTime<Utc>>,
+}
+
+/// Notifications queued and duplicates suppressed for one template and business date
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationDuplicateSummaryModel {
+    pub template_code: HeaplessString<50>,
+    pub notifications_queued: i64,
+    pub duplicates_suppressed: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "message_channel", rename_all = "PascalCase")]
+pub enum DbMessageChannel {
+    Sms,
+    Email,
+    InApp,
+}
+
+impl FromStr for DbMessageChannel {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Sms" => Ok(DbMessageChannel::Sms),
+            "Email" => Ok(DbMessageChannel::Email),
+            "InApp" => Ok(DbMessageChannel::InApp),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Database model for customer message templates, unique per code and language
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageTemplateModel {
+    pub id: Uuid,
+    pub template_code: HeaplessString<50>,
+    pub language_code: [u8; 3],
+    pub channel: DbMessageChannel,
+    pub subject: Option<HeaplessString<100>>,
+    pub body: HeaplessString<500>,
+    pub regulatory: bool,
+    pub updated_at: DateTime<Utc>,
+}
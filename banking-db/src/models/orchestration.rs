@@ -0,0 +1,103 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Database model for the orchestration log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrchestrationModel {
+    pub id: Uuid,
+    pub orchestration_type: DbOrchestrationType,
+    pub subject_id: Uuid,
+    pub status: DbOrchestrationStatus,
+    pub failure_reason: Option<HeaplessString<255>>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub initiated_by_person_id: Uuid,
+    pub resolved_by_person_id: Option<Uuid>,
+    pub resolution_note: Option<HeaplessString<255>>,
+}
+
+/// Database model for orchestration steps. The compensation is stored as JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrchestrationStepModel {
+    pub id: Uuid,
+    pub orchestration_id: Uuid,
+    pub sequence: i32,
+    pub step_name: HeaplessString<100>,
+    pub compensation: Option<String>,
+    pub status: DbOrchestrationStepStatus,
+    pub completed_at: DateTime<Utc>,
+    pub compensated_at: Option<DateTime<Utc>>,
+    pub last_error: Option<HeaplessString<255>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "orchestration_type", rename_all = "PascalCase")]
+pub enum DbOrchestrationType {
+    CustomerOffboarding,
+    ProductRepricing,
+    BundleOpening,
+}
+
+impl FromStr for DbOrchestrationType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "CustomerOffboarding" => Ok(DbOrchestrationType::CustomerOffboarding),
+            "ProductRepricing" => Ok(DbOrchestrationType::ProductRepricing),
+            "BundleOpening" => Ok(DbOrchestrationType::BundleOpening),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "orchestration_status", rename_all = "PascalCase")]
+pub enum DbOrchestrationStatus {
+    Running,
+    Completed,
+    Compensating,
+    Compensated,
+    CompensationFailed,
+    ManuallyResolved,
+}
+
+impl FromStr for DbOrchestrationStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Running" => Ok(DbOrchestrationStatus::Running),
+            "Completed" => Ok(DbOrchestrationStatus::Completed),
+            "Compensating" => Ok(DbOrchestrationStatus::Compensating),
+            "Compensated" => Ok(DbOrchestrationStatus::Compensated),
+            "CompensationFailed" => Ok(DbOrchestrationStatus::CompensationFailed),
+            "ManuallyResolved" => Ok(DbOrchestrationStatus::ManuallyResolved),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "orchestration_step_status", rename_all = "PascalCase")]
+pub enum DbOrchestrationStepStatus {
+    Completed,
+    Compensated,
+    CompensationFailed,
+}
+
+impl FromStr for DbOrchestrationStepStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Completed" => Ok(DbOrchestrationStepStatus::Completed),
+            "Compensated" => Ok(DbOrchestrationStepStatus::Compensated),
+            "CompensationFailed" => Ok(DbOrchestrationStepStatus::CompensationFailed),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Database model for external payees
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayeeModel {
+    pub id: Uuid,
+    pub customer_id: Uuid,
+    pub beneficiary_name: HeaplessString<100>,
+    pub external_bank_code: HeaplessString<20>,
+    pub external_account_number: HeaplessString<34>,
+    pub verification_status: DbPayeeVerificationStatus,
+    pub verification_method: Option<DbPayeeVerificationMethod>,
+    pub penny_test_amount: Option<Decimal>,
+    pub verified_at: Option<DateTime<Utc>>,
+    pub verified_by_person_id: Option<Uuid>,
+    pub first_used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    pub updated_by_person_id: Uuid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "payee_verification_status", rename_all = "PascalCase")]
+pub enum DbPayeeVerificationStatus {
+    Unverified,
+    PendingVerification,
+    Verified,
+    Blocked,
+}
+
+impl FromStr for DbPayeeVerificationStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Unverified" => Ok(DbPayeeVerificationStatus::Unverified),
+            "PendingVerification" => Ok(DbPayeeVerificationStatus::PendingVerification),
+            "Verified" => Ok(DbPayeeVerificationStatus::Verified),
+            "Blocked" => Ok(DbPayeeVerificationStatus::Blocked),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "payee_verification_method", rename_all = "PascalCase")]
+pub enum DbPayeeVerificationMethod {
+    PennyTest,
+    ManualConfirmation,
+}
+
+impl FromStr for DbPayeeVerificationMethod {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "PennyTest" => Ok(DbPayeeVerificationMethod::PennyTest),
+            "ManualConfirmation" => Ok(DbPayeeVerificationMethod::ManualConfirmation),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use chrono::{DateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Channel a contact preference applies to, stored as the `message_channel`
+/// type of the message templates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "message_channel", rename_all = "PascalCase")]
+pub enum ContactChannel {
+    Sms,
+    Email,
+    InApp,
+}
+
+/// # Documentation
+/// - One row per person and channel
+/// - Quiet hours are UTC wall-clock times and may wrap midnight
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContactPreferenceModel {
+    pub id: Uuid,
+    /// References PersonModel.id
+    pub person_id: Uuid,
+    pub channel: ContactChannel,
+    pub allowed: bool,
+    pub quiet_hours_start: Option<NaiveTime>,
+    pub quiet_hours_end: Option<NaiveTime>,
+    /// ISO 639-2 language code
+    pub preferred_language: Option<[u8; 3]>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -72,9 +72,45 @@ pub struct CountryIdxModel {
     pub iso2: HeaplessString<2>,
 }
 
+/// Accented letters and the base letter they fold to when matching country names
+pub const COUNTRY_NAME_ACCENT_FOLDS: &[(char, char)] = &[
+    ('à', 'a'), ('á', 'a'), ('â', 'a'), ('ã', 'a'), ('ä', 'a'), ('å', 'a'),
+    ('ç', 'c'),
+    ('è', 'e'), ('é', 'e'), ('ê', 'e'), ('ë', 'e'),
+    ('ì', 'i'), ('í', 'i'), ('î', 'i'), ('ï', 'i'),
+    ('ñ', 'n'),
+    ('ò', 'o'), ('ó', 'o'), ('ô', 'o'), ('õ', 'o'), ('ö', 'o'), ('ø', 'o'),
+    ('ù', 'u'), ('ú', 'u'), ('û', 'u'), ('ü', 'u'),
+    ('ý', 'y'), ('ÿ', 'y'),
+];
+
+/// Lower-cases a country name and folds its accents, so that "Côte d'Ivoire"
+/// and "COTE D'IVOIRE" compare equal
+pub fn fold_country_name(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .map(|c| {
+            COUNTRY_NAME_ACCENT_FOLDS
+                .iter()
+                .find(|(accented, _)| *accented == c)
+                .map_or(c, |(_, base)| *base)
+        })
+        .collect()
+}
+
+/// Whether a name of the country in any language starts with `folded_prefix`
+pub fn country_name_matches(country: &CountryModel, folded_prefix: &str) -> bool {
+    std::iter::once(Some(&country.name_l1))
+        .chain([country.name_l2.as_ref(), country.name_l3.as_ref()])
+        .flatten()
+        .any(|name| fold_country_name(name).starts_with(folded_prefix))
+}
+
 pub struct CountryIdxModelCache {
     by_id: HashMap<Uuid, CountryIdxModel>,
     by_iso2: HashMap<HeaplessString<2>, Uuid>,
+    /// Names of cached countries, for type-ahead search without the database
+    names: HashMap<Uuid, CountryModel>,
 }
 
 impl CountryIdxModelCache {
@@ -101,6 +137,7 @@ impl CountryIdxModelCache {
         Ok(CountryIdxModelCache {
             by_id,
             by_iso2,
+            names: HashMap::new(),
         })
     }
 
@@ -120,6 +157,7 @@ impl CountryIdxModelCache {
     pub fn remove(&mut self, primary_key: &Uuid) -> Option<CountryIdxModel> {
         if let Some(item) = self.by_id.remove(primary_key) {
             self.by_iso2.remove(&item.iso2);
+            self.names.remove(primary_key);
             Some(item)
         } else {
             None
@@ -138,4 +176,21 @@ impl CountryIdxModelCache {
     pub fn get_by_iso2(&self, key: &HeaplessString<2>) -> Option<Uuid> {
         self.by_iso2.get(key).copied()
     }
+
+    /// Records the names of a cached country; ignored for unknown countries
+    pub fn add_names(&mut self, country: CountryModel) {
+        if self.by_id.contains_key(&country.id) {
+            self.names.insert(country.id, country);
+        }
+    }
+
+    /// Countries with a name starting with `folded_prefix`, which must
+    /// already be folded with [`fold_country_name`]. Unordered.
+    pub fn find_by_name_prefix(&self, folded_prefix: &str) -> Vec<CountryModel> {
+        self.names
+            .values()
+            .filter(|country| country_name_matches(country, folded_prefix))
+            .cloned()
+            .collect()
+    }
 }
\ No newline at end of file
@@ -3,6 +3,9 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::idx_cache::{IdxCachePolicy, IdxLruTracker};
 
 /// Database model for person entity type enum
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
@@ -217,38 +220,56 @@ pub struct EntityReferenceIdxModelCache {
     by_id: HashMap<Uuid, EntityReferenceIdxModel>,
     by_person_id: HashMap<Uuid, Vec<Uuid>>,
     by_reference_external_id_hash: HashMap<i64, Vec<Uuid>>,
+    policy: IdxCachePolicy,
+    // Reads only hold the outer read lock, so recency needs its own
+    recency: Mutex<IdxLruTracker>,
+    /// Entries with uncommitted local changes in some session, never evicted
+    pinned: HashMap<Uuid, usize>,
 }
 
 impl EntityReferenceIdxModelCache {
     pub fn new(items: Vec<EntityReferenceIdxModel>) -> Result<Self, &'static str> {
-        let mut by_id = HashMap::new();
-        let mut by_person_id: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
-        let mut by_reference_external_id_hash: HashMap<i64, Vec<Uuid>> = HashMap::new();
+        Self::with_policy(items, IdxCachePolicy::default())
+    }
+
+    pub fn with_policy(
+        items: Vec<EntityReferenceIdxModel>,
+        policy: IdxCachePolicy,
+    ) -> Result<Self, &'static str> {
+        let mut cache = EntityReferenceIdxModelCache {
+            by_id: HashMap::new(),
+            by_person_id: HashMap::new(),
+            by_reference_external_id_hash: HashMap::new(),
+            policy,
+            recency: Mutex::new(IdxLruTracker::default()),
+            pinned: HashMap::new(),
+        };
 
         for item in items {
-            let primary_key = item.entity_reference_id;
-            if by_id.contains_key(&primary_key) {
+            if cache.by_id.contains_key(&item.entity_reference_id) {
                 return Err("Duplicate primary key: entity_reference_id");
             }
+            cache.add(item);
+        }
 
-            by_person_id
-                .entry(item.person_id)
-                .or_default()
-                .push(primary_key);
+        Ok(cache)
+    }
 
-            by_reference_external_id_hash
-                .entry(item.reference_external_id_hash)
-                .or_default()
-                .push(primary_key);
+    pub fn policy(&self) -> IdxCachePolicy {
+        self.policy
+    }
 
-            by_id.insert(primary_key, item);
-        }
+    /// See [`IdxCachePolicy::is_complete`]
+    pub fn is_complete(&self) -> bool {
+        self.policy.is_complete()
+    }
 
-        Ok(EntityReferenceIdxModelCache {
-            by_id,
-            by_person_id,
-            by_reference_external_id_hash,
-        })
+    pub fn len(&self) -> usize {
+        self.by_id.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_id.is_empty()
     }
 
     pub fn add(&mut self, item: EntityReferenceIdxModel) {
@@ -269,10 +290,15 @@ impl EntityReferenceIdxModelCache {
             .push(primary_key);
 
         self.by_id.insert(primary_key, item);
+        self.touch(primary_key);
+        self.evict_over_capacity(Some(primary_key));
     }
 
     pub fn remove(&mut self, entity_reference_id: &Uuid) -> Option<EntityReferenceIdxModel> {
         if let Some(item) = self.by_id.remove(entity_reference_id) {
+            if self.policy.capacity.is_some() {
+                self.recency.get_mut().unwrap().forget(entity_reference_id);
+            }
             if let Some(ids) = self.by_person_id.get_mut(&item.person_id) {
                 ids.retain(|&id| id != *entity_reference_id);
                 if ids.is_empty() {
@@ -299,19 +325,69 @@ impl EntityReferenceIdxModelCache {
         self.add(item);
     }
 
+    /// Protects an entry from eviction until every pin on it is released
+    pub fn pin(&mut self, primary_key: Uuid) {
+        *self.pinned.entry(primary_key).or_default() += 1;
+    }
+
+    pub fn unpin(&mut self, primary_key: &Uuid) {
+        if let Some(count) = self.pinned.get_mut(primary_key) {
+            *count -= 1;
+            if *count == 0 {
+                self.pinned.remove(primary_key);
+                self.evict_over_capacity(None);
+            }
+        }
+    }
+
+    pub fn is_pinned(&self, primary_key: &Uuid) -> bool {
+        self.pinned.contains_key(primary_key)
+    }
+
+    fn touch(&self, primary_key: Uuid) {
+        if self.policy.capacity.is_some() {
+            self.recency.lock().unwrap().touch(primary_key);
+        }
+    }
+
+    /// Evicts least recently used entries down to the capacity, skipping pinned
+    /// ones and `keep`. The cache stays over capacity if nothing else can go.
+    fn evict_over_capacity(&mut self, keep: Option<Uuid>) {
+        let Some(capacity) = self.policy.capacity else {
+            return;
+        };
+        while self.by_id.len() > capacity {
+            let victim = self.recency.get_mut().unwrap().least_recent(|key| {
+                Some(*key) != keep && !self.pinned.contains_key(key)
+            });
+            match victim {
+                Some(victim) => {
+                    self.remove(&victim);
+                }
+                None => break,
+            }
+        }
+    }
+
     pub fn contains_primary(&self, primary_key: &Uuid) -> bool {
         self.by_id.contains_key(primary_key)
     }
 
     pub fn get_by_primary(&self, primary_key: &Uuid) -> Option<EntityReferenceIdxModel> {
-        self.by_id.get(primary_key).cloned()
+        let item = self.by_id.get(primary_key).cloned();
+        if item.is_some() {
+            self.touch(*primary_key);
+        }
+        item
     }
 
+    /// Cached ids only, unless the cache [is complete](Self::is_complete)
     pub fn get_by_person_id(&self, key: &Uuid) -> Option<&Vec<Uuid>> {
         self.by_person_id.get(key)
     }
 
+    /// Cached ids only, unless the cache [is complete](Self::is_complete)
     pub fn get_by_reference_external_id_hash(&self, key: &i64) -> Option<&Vec<Uuid>> {
         self.by_reference_external_id_hash.get(key)
     }
-}
\ No newline at end of file
+}
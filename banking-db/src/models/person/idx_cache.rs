@@ -0,0 +1,66 @@
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdxCacheLoading {
+    /// Every row is loaded when the cache is built
+    #[default]
+    Eager,
+    /// The cache starts empty and is filled from the database on misses
+    Lazy,
+}
+
+/// How an Idx cache is populated and how large it may grow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IdxCachePolicy {
+    pub loading: IdxCacheLoading,
+    /// Entries kept before the least recently used ones are evicted
+    pub capacity: Option<usize>,
+}
+
+impl IdxCachePolicy {
+    pub fn eager() -> Self {
+        Self::default()
+    }
+
+    pub fn lazy(capacity: Option<usize>) -> Self {
+        Self {
+            loading: IdxCacheLoading::Lazy,
+            capacity,
+        }
+    }
+
+    /// Whether the cache holds every row, so that a miss means the row does not exist
+    pub fn is_complete(&self) -> bool {
+        self.loading == IdxCacheLoading::Eager && self.capacity.is_none()
+    }
+}
+
+/// Access order of cached primary keys, least recent first
+#[derive(Debug, Default)]
+pub struct IdxLruTracker {
+    tick: u64,
+    by_tick: BTreeMap<u64, Uuid>,
+    ticks: HashMap<Uuid, u64>,
+}
+
+impl IdxLruTracker {
+    pub fn touch(&mut self, key: Uuid) {
+        self.tick += 1;
+        if let Some(previous) = self.ticks.insert(key, self.tick) {
+            self.by_tick.remove(&previous);
+        }
+        self.by_tick.insert(self.tick, key);
+    }
+
+    pub fn forget(&mut self, key: &Uuid) {
+        if let Some(tick) = self.ticks.remove(key) {
+            self.by_tick.remove(&tick);
+        }
+    }
+
+    /// Least recently used key for which `evictable` holds
+    pub fn least_recent(&self, evictable: impl Fn(&Uuid) -> bool) -> Option<Uuid> {
+        self.by_tick.values().find(|key| evictable(key)).copied()
+    }
+}
@@ -46,6 +46,17 @@ pub enum ProductAccrualFrequency {
     None,
 }
 
+
+/// Day count convention for interest accrual
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DayCountConvention {
+    #[default]
+    Actual365Fixed,
+    Actual360,
+    ActualActual,
+    Thirty360,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProductRules {
     pub minimum_balance: Decimal,
@@ -65,7 +76,18 @@ pub struct ProductRules {
     pub default_overdraft_limit: Option<Decimal>,
     pub per_transaction_limit: Option<Decimal>,
     pub overdraft_interest_rate: Option<Decimal>,
+    pub unauthorized_overdraft_interest_rate: Option<Decimal>,
     pub accrual_frequency: ProductAccrualFrequency,
+    #[serde(default)]
+    pub day_count_convention: DayCountConvention,
+    pub guarantor_required: bool,
+    pub custody_fee_threshold: Option<Decimal>,
+    pub custody_fee_rate: Option<Decimal>,
+    pub inactivity_fee_threshold_months: Option<i32>,
+    pub inactivity_fee_item_id: Option<Uuid>,
+    pub inactivity_fee_warning_months: Option<i32>,
+    #[serde(default)]
+    pub supported_currencies: Vec<heapless::String<3>>,
 }
 
 // Display implementations for database compatibility
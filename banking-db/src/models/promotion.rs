@@ -0,0 +1,56 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Database model for product interest rate promotions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductPromotionModel {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub promotion_code: HeaplessString<50>,
+    pub name: HeaplessString<100>,
+    pub rate_kind: DbPromotionRateKind,
+    pub rate_value: Decimal,
+    pub opened_from: Option<NaiveDate>,
+    pub opened_to: Option<NaiveDate>,
+    pub segment_code: Option<HeaplessString<50>>,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub budget_cap: Option<Decimal>,
+    /// Sum of the bonus interest on the accruals referencing the promotion; not written
+    pub bonus_interest_attributed: Decimal,
+    pub budget_exhausted_at: Option<DateTime<Utc>>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    pub updated_by_person_id: Uuid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "promotion_rate_kind", rename_all = "PascalCase")]
+pub enum DbPromotionRateKind {
+    Bonus,
+    Override,
+}
+
+impl FromStr for DbPromotionRateKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Bonus" => Ok(DbPromotionRateKind::Bonus),
+            "Override" => Ok(DbPromotionRateKind::Override),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Segment an owner of the account currently belongs to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountSegmentCodeModel {
+    pub account_id: Uuid,
+    pub segment_code: HeaplessString<50>,
+}
@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Database model for pre-sale quotes. The simulation is stored as JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuoteModel {
+    pub id: Uuid,
+    pub quote_type: DbQuoteType,
+    pub product_id: Uuid,
+    pub customer_id: Option<Uuid>,
+    pub simulation: String,
+    pub status: DbQuoteStatus,
+    pub expires_at: DateTime<Utc>,
+    pub converted_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub created_by_person_id: Uuid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "quote_type", rename_all = "PascalCase")]
+pub enum DbQuoteType {
+    Savings,
+    Loan,
+}
+
+impl FromStr for DbQuoteType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Savings" => Ok(DbQuoteType::Savings),
+            "Loan" => Ok(DbQuoteType::Loan),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "quote_status", rename_all = "PascalCase")]
+pub enum DbQuoteStatus {
+    Active,
+    Converted,
+    Expired,
+}
+
+impl FromStr for DbQuoteStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Active" => Ok(DbQuoteStatus::Active),
+            "Converted" => Ok(DbQuoteStatus::Converted),
+            "Expired" => Ok(DbQuoteStatus::Expired),
+            _ => Err(()),
+        }
+    }
+}
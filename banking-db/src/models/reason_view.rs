@@ -347,6 +347,7 @@ pub fn serialize_sar_status<S>(value: &SarStatus, serializer: S) -> Result<S::Ok
 where S: Serializer {
     let value_str = match value {
         SarStatus::Draft => "Draft",
+        SarStatus::Submitted => "Submitted",
         SarStatus::Filed => "Filed",
         SarStatus::Acknowledged => "Acknowledged",
         SarStatus::UnderReview => "UnderReview",
@@ -360,6 +361,7 @@ where D: Deserializer<'de> {
     let value_str: String = String::deserialize(deserializer)?;
     match value_str.as_str() {
         "Draft" => Ok(SarStatus::Draft),
+        "Submitted" => Ok(SarStatus::Submitted),
         "Filed" => Ok(SarStatus::Filed),
         "Acknowledged" => Ok(SarStatus::Acknowledged),
         "UnderReview" => Ok(SarStatus::UnderReview),
@@ -405,6 +407,7 @@ where S: Serializer {
         WorkflowType::AccountReactivation => "AccountReactivation",
         WorkflowType::ComplianceVerification => "ComplianceVerification",
         WorkflowType::MultiPartyApproval => "MultiPartyApproval",
+        WorkflowType::DormancyAssessment => "DormancyAssessment",
     };
     serializer.serialize_str(value_str)
 }
@@ -418,6 +421,7 @@ where D: Deserializer<'de> {
         "AccountReactivation" => Ok(WorkflowType::AccountReactivation),
         "ComplianceVerification" => Ok(WorkflowType::ComplianceVerification),
         "MultiPartyApproval" => Ok(WorkflowType::MultiPartyApproval),
+        "DormancyAssessment" => Ok(WorkflowType::DormancyAssessment),
         _ => Err(serde::de::Error::custom("Invalid WorkflowType value"))
     }
 }
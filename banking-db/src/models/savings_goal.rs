@@ -0,0 +1,41 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Database model for savings goals within an account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavingsGoalModel {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub name: HeaplessString<100>,
+    pub target_amount: Decimal,
+    pub target_date: Option<NaiveDate>,
+    pub allocated_balance: Decimal,
+    pub status: DbSavingsGoalStatus,
+    pub created_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub last_updated_at: DateTime<Utc>,
+    pub updated_by_person_id: Uuid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "savings_goal_status", rename_all = "PascalCase")]
+pub enum DbSavingsGoalStatus {
+    Active,
+    Closed,
+}
+
+impl FromStr for DbSavingsGoalStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Active" => Ok(DbSavingsGoalStatus::Active),
+            "Closed" => Ok(DbSavingsGoalStatus::Closed),
+            _ => Err(()),
+        }
+    }
+}
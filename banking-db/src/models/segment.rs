@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::models::{CustomerStatus, CustomerType, DbAccountStatus, DbAccountType, RiskRating};
+
+/// Database model for customer segments. Criteria are persisted as JSONB.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SegmentModel {
+    pub id: Uuid,
+    pub code: HeaplessString<50>,
+    pub name: HeaplessString<100>,
+    pub description: Option<HeaplessString<255>>,
+    pub criteria: Vec<SegmentCriterionModel>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    pub updated_by_person_id: Uuid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DbSegmentComparison {
+    Equal,
+    NotEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+}
+
+impl FromStr for DbSegmentComparison {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Equal" => Ok(DbSegmentComparison::Equal),
+            "NotEqual" => Ok(DbSegmentComparison::NotEqual),
+            "GreaterThan" => Ok(DbSegmentComparison::GreaterThan),
+            "GreaterThanOrEqual" => Ok(DbSegmentComparison::GreaterThanOrEqual),
+            "LessThan" => Ok(DbSegmentComparison::LessThan),
+            "LessThanOrEqual" => Ok(DbSegmentComparison::LessThanOrEqual),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum SegmentCriterionModel {
+    CustomerTypeIs { customer_type: CustomerType },
+    CustomerStatusIn { statuses: Vec<CustomerStatus> },
+    RiskRatingIn { ratings: Vec<RiskRating> },
+    TotalBalance { operator: DbSegmentComparison, amount: Decimal },
+    HoldsAccountType { account_type: DbAccountType, held: bool },
+    HoldsProduct { product_id: Uuid, held: bool },
+    HasAccountInStatus { statuses: Vec<DbAccountStatus> },
+    DaysSinceLastActivity { operator: DbSegmentComparison, days: i32 },
+    HasTag { tag_code: HeaplessString<50>, tagged: bool },
+    HoldsTaggedAccount { tag_code: HeaplessString<50>, held: bool },
+}
+
+/// Database model for segment membership rows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SegmentMembershipModel {
+    pub id: Uuid,
+    /// References SegmentModel.id
+    pub segment_id: Uuid,
+    pub customer_id: Uuid,
+    pub entered_at: DateTime<Utc>,
+    pub exited_at: Option<DateTime<Utc>>,
+}
+
+/// Customers who entered or left a segment since a point in time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SegmentMembershipDeltaModel {
+    pub segment_id: Uuid,
+    pub since: DateTime<Utc>,
+    pub entered_customer_ids: Vec<Uuid>,
+    pub exited_customer_ids: Vec<Uuid>,
+}
@@ -0,0 +1,120 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Database model for standing orders
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StandingOrderModel {
+    pub id: Uuid,
+    pub source_account_id: Uuid,
+    pub beneficiary_account_id: Option<Uuid>,
+    pub external_reference: Option<HeaplessString<100>>,
+    pub amount_type: DbStandingOrderAmountType,
+    /// Amount transferred, or the balance a sweep leaves in the source account
+    pub amount: Decimal,
+    pub frequency: DbStandingOrderFrequency,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub next_due_date: NaiveDate,
+    pub next_run_date: NaiveDate,
+    pub status: DbStandingOrderStatus,
+    pub failure_policy: DbStandingOrderFailurePolicy,
+    /// Set for SuspendAfter only
+    pub max_consecutive_failures: Option<i32>,
+    pub consecutive_failures: i32,
+    pub last_executed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    pub updated_by_person_id: Uuid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "standing_order_amount_type", rename_all = "PascalCase")]
+pub enum DbStandingOrderAmountType {
+    Fixed,
+    SweepAboveThreshold,
+}
+
+impl FromStr for DbStandingOrderAmountType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Fixed" => Ok(DbStandingOrderAmountType::Fixed),
+            "SweepAboveThreshold" => Ok(DbStandingOrderAmountType::SweepAboveThreshold),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "standing_order_frequency", rename_all = "PascalCase")]
+pub enum DbStandingOrderFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Quarterly,
+    Yearly,
+}
+
+impl FromStr for DbStandingOrderFrequency {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Daily" => Ok(DbStandingOrderFrequency::Daily),
+            "Weekly" => Ok(DbStandingOrderFrequency::Weekly),
+            "Monthly" => Ok(DbStandingOrderFrequency::Monthly),
+            "Quarterly" => Ok(DbStandingOrderFrequency::Quarterly),
+            "Yearly" => Ok(DbStandingOrderFrequency::Yearly),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "standing_order_status", rename_all = "PascalCase")]
+pub enum DbStandingOrderStatus {
+    Active,
+    Suspended,
+    Completed,
+    Cancelled,
+}
+
+impl FromStr for DbStandingOrderStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Active" => Ok(DbStandingOrderStatus::Active),
+            "Suspended" => Ok(DbStandingOrderStatus::Suspended),
+            "Completed" => Ok(DbStandingOrderStatus::Completed),
+            "Cancelled" => Ok(DbStandingOrderStatus::Cancelled),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "standing_order_failure_policy", rename_all = "PascalCase")]
+pub enum DbStandingOrderFailurePolicy {
+    Skip,
+    RetryNextDay,
+    SuspendAfter,
+}
+
+impl FromStr for DbStandingOrderFailurePolicy {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Skip" => Ok(DbStandingOrderFailurePolicy::Skip),
+            "RetryNextDay" => Ok(DbStandingOrderFailurePolicy::RetryNextDay),
+            "SuspendAfter" => Ok(DbStandingOrderFailurePolicy::SuspendAfter),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use blake3::Hash;
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::models::DbDocumentNotificationStatus;
+
+/// Database model for statement delivery preferences, one per account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementDeliveryPreferenceModel {
+    pub account_id: Uuid,
+    pub delivery_method: DbStatementDeliveryMethod,
+    pub contact_messaging_id: Option<Uuid>,
+    pub consent_given_at: DateTime<Utc>,
+    pub consent_channel: HeaplessString<50>,
+    pub last_updated_at: DateTime<Utc>,
+    pub updated_by_person_id: Uuid,
+}
+
+/// Database model for customer contact verification
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerContactModel {
+    pub id: Uuid,
+    pub customer_id: Uuid,
+    pub messaging_id: Uuid,
+    pub verified_at: Option<DateTime<Utc>>,
+    pub verified_by_person_id: Option<Uuid>,
+}
+
+/// Database model for statement consent evidence (append-only)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementConsentRecordModel {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub customer_id: Uuid,
+    pub previous_method: DbStatementDeliveryMethod,
+    pub new_method: DbStatementDeliveryMethod,
+    pub contact_messaging_id: Option<Uuid>,
+    pub contact_verified_at: Option<DateTime<Utc>>,
+    pub consent_channel: HeaplessString<50>,
+    pub consent_reference: HeaplessString<100>,
+    pub consent_given_at: DateTime<Utc>,
+    pub recorded_by_person_id: Uuid,
+}
+
+/// Database model for queued statement notifications
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementNotificationModel {
+    pub id: Uuid,
+    pub notification_type: DbStatementNotificationType,
+    pub account_id: Uuid,
+    pub customer_id: Uuid,
+    pub contact_messaging_id: Option<Uuid>,
+    pub statement_reference: Option<HeaplessString<50>>,
+    pub status: DbDocumentNotificationStatus,
+    pub queued_at: DateTime<Utc>,
+    pub sent_at: Option<DateTime<Utc>>,
+}
+
+/// Account due a cycle statement, with its delivery routing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementRecipientModel {
+    pub account_id: Uuid,
+    pub customer_id: Uuid,
+    pub delivery_method: DbStatementDeliveryMethod,
+    pub contact_messaging_id: Option<Uuid>,
+}
+
+/// Database model for the print extract of a statement cycle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementPrintBatchModel {
+    pub id: Uuid,
+    pub cycle_date: NaiveDate,
+    pub entry_count: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementPrintEntryModel {
+    pub id: Uuid,
+    pub batch_id: Uuid,
+    pub account_id: Uuid,
+    pub customer_id: Uuid,
+    pub statement_reference: HeaplessString<50>,
+    pub delivery_method: DbStatementDeliveryMethod,
+}
+
+/// Account still receiving paper statements
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaperStatementAccountModel {
+    pub account_id: Uuid,
+    pub customer_id: Uuid,
+    pub delivery_method: DbStatementDeliveryMethod,
+    pub has_verified_contact: bool,
+}
+
+/// Posted transaction of a statement period, amount signed by direction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementEntryModel {
+    pub transaction_id: Uuid,
+    pub value_date: NaiveDate,
+    pub description: HeaplessString<200>,
+    pub amount: Decimal,
+    /// When the transaction was posted, later than the value date if backdated
+    pub posted_at: DateTime<Utc>,
+}
+
+/// Interest accrued and fees applied over a statement period
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StatementChargeSummaryModel {
+    pub interest_accrued: Decimal,
+    pub fees_charged: Decimal,
+    pub fee_count: i64,
+}
+
+/// Database model for stored statement snapshots (append-only)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountStatementModel {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    /// Unique per account
+    pub statement_number: i32,
+    pub statement_reference: HeaplessString<50>,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub opening_balance: Decimal,
+    pub total_credits: Decimal,
+    pub total_debits: Decimal,
+    pub closing_balance: Decimal,
+    pub interest_accrued: Decimal,
+    pub fees_charged: Decimal,
+    pub fee_count: i64,
+    pub content_hash: Hash,
+    pub supersedes_statement_id: Option<Uuid>,
+    pub generated_at: DateTime<Utc>,
+    pub generated_by_person_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementLineModel {
+    pub statement_id: Uuid,
+    /// 1-based position on the statement
+    pub line_number: i32,
+    pub transaction_id: Uuid,
+    pub value_date: NaiveDate,
+    pub description: HeaplessString<200>,
+    pub amount: Decimal,
+    pub running_balance: Decimal,
+}
+
+/// Database model for statement integrity alerts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementIntegrityAlertModel {
+    pub id: Uuid,
+    pub statement_id: Uuid,
+    pub account_id: Uuid,
+    pub stored_hash: Hash,
+    pub computed_hash: Hash,
+    pub detected_during: DbStatementIntegrityCheck,
+    pub detected_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "statement_delivery_method", rename_all = "PascalCase")]
+pub enum DbStatementDeliveryMethod {
+    Paper,
+    Electronic,
+    Both,
+}
+
+impl FromStr for DbStatementDeliveryMethod {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Paper" => Ok(DbStatementDeliveryMethod::Paper),
+            "Electronic" => Ok(DbStatementDeliveryMethod::Electronic),
+            "Both" => Ok(DbStatementDeliveryMethod::Both),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "statement_notification_type", rename_all = "PascalCase")]
+pub enum DbStatementNotificationType {
+    PreferenceChanged,
+    StatementReady,
+}
+
+impl FromStr for DbStatementNotificationType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "PreferenceChanged" => Ok(DbStatementNotificationType::PreferenceChanged),
+            "StatementReady" => Ok(DbStatementNotificationType::StatementReady),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "statement_integrity_check", rename_all = "PascalCase")]
+pub enum DbStatementIntegrityCheck {
+    Reprint,
+    ArchiveVerification,
+}
+
+impl FromStr for DbStatementIntegrityCheck {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Reprint" => Ok(DbStatementIntegrityCheck::Reprint),
+            "ArchiveVerification" => Ok(DbStatementIntegrityCheck::ArchiveVerification),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::models::{CustomerSearchCriteriaModel, DbAccountStatus};
+
+/// Database model for the tag registry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagModel {
+    pub id: Uuid,
+    pub code: HeaplessString<50>,
+    pub description: HeaplessString<255>,
+    pub applicable_entity_kinds: Vec<DbTaggableEntityKind>,
+    pub required_permission: Option<HeaplessString<50>>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub updated_by_person_id: Uuid,
+}
+
+/// Database model for the entity_tags join table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityTagModel {
+    pub id: Uuid,
+    pub tag_id: Uuid,
+    pub entity_kind: DbTaggableEntityKind,
+    pub entity_id: Uuid,
+    pub assigned_at: DateTime<Utc>,
+    /// References Person.person_id
+    pub assigned_by_person_id: Uuid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "taggable_entity_kind", rename_all = "PascalCase")]
+pub enum DbTaggableEntityKind {
+    Customer,
+    Account,
+}
+
+impl FromStr for DbTaggableEntityKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Customer" => Ok(DbTaggableEntityKind::Customer),
+            "Account" => Ok(DbTaggableEntityKind::Account),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DbTagMatchMode {
+    Any,
+    All,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TagFilterModel {
+    pub tag_codes: Vec<HeaplessString<50>>,
+    pub match_mode: DbTagMatchMode,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountTagSelectionModel {
+    pub product_id: Option<Uuid>,
+    pub account_status: Option<DbAccountStatus>,
+    pub opened_from: Option<NaiveDate>,
+    pub opened_to: Option<NaiveDate>,
+    pub tags: Option<TagFilterModel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum BulkTagTargetModel {
+    Accounts(AccountTagSelectionModel),
+    Customers(CustomerSearchCriteriaModel),
+}
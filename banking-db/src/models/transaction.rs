@@ -32,9 +32,32 @@ pub struct TransactionModel {
     #[cfg_attr(feature = "sqlx", sqlx(rename = "approval_status"))]
     pub approval_status: Option<TransactionApprovalStatus>,
     pub risk_score: Option<Decimal>,
+    /// Bitset of the non-critical posting steps that were skipped
+    pub degraded_flags: i32,
+    /// Unique per channel_id; NULL for postings without a client key
+    pub idempotency_key: Option<HeaplessString<64>>,
+    /// Set on a contra transaction; unique, so a transaction is reversed at most once
+    pub reversal_of_transaction_id: Option<Uuid>,
+    /// Submitted amount and currency of a foreign-currency transaction; all
+    /// three are NULL when it was submitted in the account currency
+    pub original_amount: Option<Decimal>,
+    pub original_currency: Option<HeaplessString<3>>,
+    pub exchange_rate: Option<Decimal>,
     pub created_at: DateTime<Utc>,
 }
 
+/// Transaction search criteria; all set fields must match
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionSearchCriteriaModel {
+    pub account_id: Option<Uuid>,
+    pub from_date: Option<NaiveDate>,
+    pub to_date: Option<NaiveDate>,
+    pub status: Option<TransactionStatus>,
+    pub account_tags: Option<crate::models::TagFilterModel>,
+    pub offset: i64,
+    pub limit: i64,
+}
+
 /// Database model for Transaction Approvals
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
@@ -0,0 +1,85 @@
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Database model for step-up verification challenges
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerificationChallengeModel {
+    pub id: Uuid,
+    pub customer_id: Uuid,
+    pub operation_kind: DbVerificationOperationKind,
+    pub channel_id: Uuid,
+    pub code: HeaplessString<10>,
+    pub status: DbChallengeStatus,
+    pub failed_attempts: i32,
+    pub last_failed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub verified_method: Option<DbVerificationMethod>,
+    pub verified_by_person_id: Option<Uuid>,
+    pub verified_at: Option<DateTime<Utc>>,
+    pub consumed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "verification_operation_kind", rename_all = "PascalCase")]
+pub enum DbVerificationOperationKind {
+    ContactDetailChange,
+    LimitIncrease,
+}
+
+impl FromStr for DbVerificationOperationKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ContactDetailChange" => Ok(DbVerificationOperationKind::ContactDetailChange),
+            "LimitIncrease" => Ok(DbVerificationOperationKind::LimitIncrease),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "challenge_status", rename_all = "PascalCase")]
+pub enum DbChallengeStatus {
+    Pending,
+    Verified,
+    Consumed,
+    Failed,
+}
+
+impl FromStr for DbChallengeStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Pending" => Ok(DbChallengeStatus::Pending),
+            "Verified" => Ok(DbChallengeStatus::Verified),
+            "Consumed" => Ok(DbChallengeStatus::Consumed),
+            "Failed" => Ok(DbChallengeStatus::Failed),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "verification_method", rename_all = "PascalCase")]
+pub enum DbVerificationMethod {
+    Otp,
+    BranchAssertion,
+}
+
+impl FromStr for DbVerificationMethod {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Otp" => Ok(DbVerificationMethod::Otp),
+            "BranchAssertion" => Ok(DbVerificationMethod::BranchAssertion),
+            _ => Err(()),
+        }
+    }
+}
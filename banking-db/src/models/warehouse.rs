@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Row changed within an export window, as JSON of all its columns
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WarehouseChangeModel {
+    pub entity_id: Uuid,
+    pub changed_at: DateTime<Utc>,
+    pub row: serde_json::Value,
+}
+
+/// Record of a deleted row, kept so that incremental exports see deletions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WarehouseTombstoneModel {
+    pub id: Uuid,
+    pub entity: DbWarehouseEntity,
+    pub entity_id: Uuid,
+    pub deleted_at: DateTime<Utc>,
+}
+
+/// Completed export run; the latest one holds the watermark
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WarehouseExportRunModel {
+    pub id: Uuid,
+    pub mode: DbWarehouseExportMode,
+    pub schema_version: i32,
+    pub from_watermark: Option<DateTime<Utc>>,
+    pub to_watermark: DateTime<Utc>,
+    pub total_rows: i64,
+    pub completed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "warehouse_entity", rename_all = "PascalCase")]
+pub enum DbWarehouseEntity {
+    Account,
+    Transaction,
+    Customer,
+    Workflow,
+}
+
+impl FromStr for DbWarehouseEntity {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Account" => Ok(DbWarehouseEntity::Account),
+            "Transaction" => Ok(DbWarehouseEntity::Transaction),
+            "Customer" => Ok(DbWarehouseEntity::Customer),
+            "Workflow" => Ok(DbWarehouseEntity::Workflow),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "warehouse_export_mode", rename_all = "PascalCase")]
+pub enum DbWarehouseExportMode {
+    Incremental,
+    FullSnapshot,
+}
+
+impl FromStr for DbWarehouseExportMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Incremental" => Ok(DbWarehouseExportMode::Incremental),
+            "FullSnapshot" => Ok(DbWarehouseExportMode::FullSnapshot),
+            _ => Err(()),
+        }
+    }
+}
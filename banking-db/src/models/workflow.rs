@@ -5,6 +5,8 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::account::DbSigningCondition;
+
 /// Database representation of WorkflowType enum
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum WorkflowTypeModel {
@@ -23,6 +25,7 @@ pub enum WorkflowTypeModel {
     LimitChange,
     StatusChange,
     ManualIntervention,
+    DormancyAssessment,
 }
 
 impl std::fmt::Display for WorkflowTypeModel {
@@ -43,6 +46,7 @@ impl std::fmt::Display for WorkflowTypeModel {
             WorkflowTypeModel::LimitChange => write!(f, "LimitChange"),
             WorkflowTypeModel::StatusChange => write!(f, "StatusChange"),
             WorkflowTypeModel::ManualIntervention => write!(f, "ManualIntervention"),
+            WorkflowTypeModel::DormancyAssessment => write!(f, "DormancyAssessment"),
         }
     }
 }
@@ -67,6 +71,7 @@ impl std::str::FromStr for WorkflowTypeModel {
             "LimitChange" => Ok(WorkflowTypeModel::LimitChange),
             "StatusChange" => Ok(WorkflowTypeModel::StatusChange),
             "ManualIntervention" => Ok(WorkflowTypeModel::ManualIntervention),
+            "DormancyAssessment" => Ok(WorkflowTypeModel::DormancyAssessment),
             _ => Err(format!("Invalid workflow type: {s}")),
         }
     }
@@ -196,11 +201,16 @@ pub struct WorkflowStepRecordModel {
 pub struct AccountOpeningRequestModel {
     pub customer_id: Uuid,
     pub product_id: Uuid,
+    pub currency: HeaplessString<3>,
+    pub signing_condition: DbSigningCondition,
+    /// References Customer.id of the owners other than `customer_id`
+    pub joint_owner_ids: Vec<Uuid>,
     pub initial_deposit: Option<Decimal>,
     pub channel: HeaplessString<50>,
     /// References Person.person_id
     pub initiated_by: Uuid,
     pub supporting_documents: Vec<DocumentReferenceModel>,
+    pub quote_id: Option<Uuid>,
 }
 
 /// Closure Request database model
@@ -236,7 +246,8 @@ pub struct DormancyAssessmentModel {
 /// Document Reference database model
 #[derive(Debug, Clone)]
 pub struct DocumentReferenceModel {
-    pub document_id: Hash,
+    pub document_id: Uuid,
+    pub content_hash: Hash,
     pub document_type: HeaplessString<50>,
     pub document_path: Option<Hash>,
 }
@@ -275,3 +286,57 @@ pub struct ApprovalWorkflowModel {
     pub last_updated_at: DateTime<Utc>,
 }
 
+
+/// Workflow Escalation database model
+#[derive(Debug, Clone)]
+pub struct WorkflowEscalationModel {
+    pub id: Uuid,
+    /// References AccountWorkflowModel.id
+    pub workflow_id: Uuid,
+    /// References Person.person_id
+    pub escalated_to_person_id: Uuid,
+    pub level: i16,
+    pub status: WorkflowEscalationStatusModel,
+    pub created_at: DateTime<Utc>,
+    pub action_due_at: DateTime<Utc>,
+    pub acknowledged_at: Option<DateTime<Utc>>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    /// References Person.person_id
+    pub resolved_by_person_id: Option<Uuid>,
+    pub resolution_notes: Option<HeaplessString<500>>,
+    pub last_updated_at: DateTime<Utc>,
+}
+
+/// Database representation of WorkflowEscalationStatus enum
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum WorkflowEscalationStatusModel {
+    Open,
+    Acknowledged,
+    Resolved,
+    Escalated,
+}
+
+impl std::fmt::Display for WorkflowEscalationStatusModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WorkflowEscalationStatusModel::Open => write!(f, "Open"),
+            WorkflowEscalationStatusModel::Acknowledged => write!(f, "Acknowledged"),
+            WorkflowEscalationStatusModel::Resolved => write!(f, "Resolved"),
+            WorkflowEscalationStatusModel::Escalated => write!(f, "Escalated"),
+        }
+    }
+}
+
+impl std::str::FromStr for WorkflowEscalationStatusModel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Open" => Ok(WorkflowEscalationStatusModel::Open),
+            "Acknowledged" => Ok(WorkflowEscalationStatusModel::Acknowledged),
+            "Resolved" => Ok(WorkflowEscalationStatusModel::Resolved),
+            "Escalated" => Ok(WorkflowEscalationStatusModel::Escalated),
+            _ => Err(format!("Invalid workflow escalation status: {s}")),
+        }
+    }
+}
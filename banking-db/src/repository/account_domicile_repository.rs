@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::models::AccountDomicileChangeModel;
+
+#[async_trait]
+pub trait AccountDomicileRepository: Send + Sync {
+    /// Record the change and move the account to the new branch in one transaction
+    async fn transfer_domicile(&self, change: AccountDomicileChangeModel) -> BankingResult<AccountDomicileChangeModel>;
+    async fn find_domicile_history(&self, account_id: Uuid) -> BankingResult<Vec<AccountDomicileChangeModel>>;
+    /// Changes taking effect after `date`, for attributing accounts on that date
+    async fn find_domicile_changes_effective_after(&self, date: NaiveDate) -> BankingResult<Vec<AccountDomicileChangeModel>>;
+}
@@ -18,10 +18,18 @@ pub trait AccountHoldRepository: Send + Sync {
     async fn create_hold(&self, hold: AccountHoldModel) -> BankingResult<AccountHoldModel>;
     async fn find_holds_by_account(&self, account_id: Uuid) -> BankingResult<Vec<AccountHoldModel>>;
     async fn find_active_holds(&self, account_id: Uuid) -> BankingResult<Vec<AccountHoldModel>>;
-    /// Release a hold
+    /// Active holds of any of the accounts in one query, newest first
+    async fn find_active_holds_by_accounts(&self, account_ids: &[Uuid]) -> BankingResult<Vec<AccountHoldModel>>;
+    /// Place a hold and take it off the account's available balance in one
+    /// transaction. Fails with InsufficientFunds when the hold exceeds the
+    /// available balance, unless `allow_overdraw` is set.
+    async fn place_hold(&self, hold: AccountHoldModel, allow_overdraw: bool) -> BankingResult<AccountHoldModel>;
+    /// Release an active hold and give its amount back to the available balance
     /// @param released_by - References Person.person_id
     async fn release_hold(&self, hold_id: Uuid, released_by: Uuid) -> BankingResult<()>;
-    async fn release_expired_holds(&self, reference_date: DateTime<Utc>) -> BankingResult<i64>;
+    /// Expire automatically released holds due by `reference_date`, restoring
+    /// the available balance of their accounts; returns the expired holds
+    async fn release_expired_holds(&self, reference_date: DateTime<Utc>) -> BankingResult<Vec<AccountHoldModel>>;
 
     // ============================================================================
     // ENHANCED HOLD OPERATIONS (integrated from HoldRepository)
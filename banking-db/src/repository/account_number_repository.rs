@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use uuid::Uuid;
+
+use crate::models::AccountNumberSchemeModel;
+
+#[async_trait]
+pub trait AccountNumberRepository: Send + Sync {
+    /// Insert the scheme, or replace the one with the same branch and product
+    async fn save_scheme(&self, scheme: AccountNumberSchemeModel) -> BankingResult<AccountNumberSchemeModel>;
+    /// Scheme of exactly this branch and product; a None product is the branch default
+    async fn find_scheme(&self, branch_id: Uuid, product_id: Option<Uuid>) -> BankingResult<Option<AccountNumberSchemeModel>>;
+    /// Atomically advance the serial of the branch and return it. The first serial is 1,
+    /// and concurrent callers never get the same serial.
+    async fn next_serial(&self, branch_id: Uuid) -> BankingResult<i64>;
+}
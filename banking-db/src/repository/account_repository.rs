@@ -2,10 +2,11 @@ use async_trait::async_trait;
 use banking_api::BankingResult;
 use uuid::Uuid;
 use rust_decimal::Decimal;
-use chrono::{NaiveDate};
+use chrono::{DateTime, NaiveDate, Utc};
 
 use crate::models::{
     AccountModel, AccountOwnershipModel, AccountRelationshipModel, AccountMandateModel, AccountFinalSettlementModel, DbAccountType,
+    AccountBalanceSnapshotModel, AccountInterestAccrualModel, LoanPenaltyAccrualModel, AccountBalanceChangeRecordModel, DbBalanceChangeSource,
 };
 
 #[async_trait]
@@ -18,6 +19,9 @@ pub trait AccountRepository: Send + Sync {
     
     /// Find account by ID
     async fn find_by_id(&self, account_id: Uuid) -> BankingResult<Option<AccountModel>>;
+
+    /// Account with the given compact account number, matched exactly
+    async fn find_by_account_number(&self, account_number: &str) -> BankingResult<Option<AccountModel>>;
     
     /// Find accounts by customer ID
     async fn find_by_customer_id(&self, customer_id: Uuid) -> BankingResult<Vec<AccountModel>>;
@@ -31,8 +35,10 @@ pub trait AccountRepository: Send + Sync {
     /// Find accounts by account type
     async fn find_by_account_type(&self, account_type: DbAccountType) -> BankingResult<Vec<AccountModel>>;
     
-    /// Find accounts eligible for dormancy
-    async fn find_dormancy_candidates(&self, reference_date: NaiveDate, threshold_days: i32) -> BankingResult<Vec<AccountModel>>;
+    /// Find active accounts inactive for at least their dormancy threshold as of `reference_date`.
+    /// An account's own `dormancy_threshold_days` overrides `threshold_days`; `product_id` limits the
+    /// search to one product's accounts.
+    async fn find_dormancy_candidates(&self, reference_date: NaiveDate, threshold_days: i32, product_id: Option<Uuid>) -> BankingResult<Vec<AccountModel>>;
     
     /// Find accounts pending closure
     async fn find_pending_closure(&self) -> BankingResult<Vec<AccountModel>>;
@@ -40,12 +46,48 @@ pub trait AccountRepository: Send + Sync {
     /// Find interest-bearing accounts
     async fn find_interest_bearing_accounts(&self) -> BankingResult<Vec<AccountModel>>;
     
+    /// Keyset page of interest-bearing accounts ordered by id, starting after `after_account_id`.
+    /// Includes current accounts in credit, which may accrue a custody fee
+    async fn find_interest_bearing_accounts_after(&self, after_account_id: Option<Uuid>, limit: i64) -> BankingResult<Vec<AccountModel>>;
+    
     /// Update account status with audit trail
+    /// @param reason_id - References an existing ReasonAndPurpose.id
     /// @param changed_by - References Person.person_id
-    async fn update_status(&self, account_id: Uuid, status: &str, reason: &str, changed_by: Uuid) -> BankingResult<()>;
+    async fn update_status(&self, account_id: Uuid, status: &str, reason_id: Uuid, changed_by: Uuid) -> BankingResult<()>;
     
-    /// Update account balance
-    async fn update_balance(&self, account_id: Uuid, current_balance: Decimal, available_balance: Decimal) -> BankingResult<()>;
+    /// Legacy method - deprecated, use update_status with reason_id instead.
+    /// Creates a ReasonAndPurpose row from the free-text reason on every call
+    #[deprecated(note = "Use update_status with reason_id instead")]
+    async fn update_status_legacy(&self, account_id: Uuid, status: &str, reason: &str, changed_by: Uuid) -> BankingResult<()>;
+
+    /// Update the status of many accounts, one transaction per chunk, recording a status change for each.
+    /// Results are paired with the ids in input order: unknown accounts fail on their own,
+    /// and a chunk that cannot be committed fails every account in it
+    /// @param reason_id - References an existing ReasonAndPurpose.id
+    /// @param changed_by - References Person.person_id
+    async fn bulk_update_status(
+        &self,
+        account_ids: &[Uuid],
+        status: &str,
+        reason_id: Uuid,
+        changed_by: Uuid,
+    ) -> Vec<(Uuid, BankingResult<()>)>;
+    
+    /// Update account balance, recording the old and new balances in the balance history
+    /// @param transaction_id - References Transaction.id when the change comes from a posting
+    /// @param changed_by - References Person.person_id
+    async fn update_balance(
+        &self,
+        account_id: Uuid,
+        current_balance: Decimal,
+        available_balance: Decimal,
+        change_source: DbBalanceChangeSource,
+        transaction_id: Option<Uuid>,
+        changed_by: Uuid,
+    ) -> BankingResult<()>;
+    
+    /// Balance changes made between `from` and `to` inclusive, oldest first
+    async fn get_balance_history(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> BankingResult<Vec<AccountBalanceChangeRecordModel>>;
     
     /// Update accrued interest
     async fn update_accrued_interest(&self, account_id: Uuid, accrued_interest: Decimal) -> BankingResult<()>;
@@ -53,6 +95,24 @@ pub trait AccountRepository: Send + Sync {
     /// Reset accrued interest to zero (after capitalization)
     async fn reset_accrued_interest(&self, account_id: Uuid) -> BankingResult<()>;
     
+    /// Add `amount` of accrued interest to both balances and take it off the accrued
+    /// interest in one transaction, recording the balance change. Accrued interest
+    /// is reset when all of it is posted.
+    /// @param transaction_id - References Transaction.id of the interest posting
+    /// @param changed_by - References Person.person_id
+    async fn post_accrued_interest(
+        &self,
+        account_id: Uuid,
+        amount: Decimal,
+        change_source: DbBalanceChangeSource,
+        transaction_id: Option<Uuid>,
+        changed_by: Uuid,
+    ) -> BankingResult<()>;
+    
+    /// Record a chunk of daily accruals and add them to accrued interest in one transaction.
+    /// Accounts already accrued for the date are skipped; returns the ids accrued by this call.
+    async fn apply_interest_accruals(&self, accruals: Vec<AccountInterestAccrualModel>) -> BankingResult<Vec<Uuid>>;
+    
     /// Account Ownership Operations
     async fn create_ownership(&self, ownership: AccountOwnershipModel) -> BankingResult<AccountOwnershipModel>;
     async fn find_ownership_by_account(&self, account_id: Uuid) -> BankingResult<Vec<AccountOwnershipModel>>;
@@ -77,17 +137,46 @@ pub trait AccountRepository: Send + Sync {
     /// Final Settlement Operations
     async fn create_final_settlement(&self, settlement: AccountFinalSettlementModel) -> BankingResult<AccountFinalSettlementModel>;
     async fn find_settlement_by_account(&self, account_id: Uuid) -> BankingResult<Option<AccountFinalSettlementModel>>;
+    /// Advance a settlement one step, Pending → Disbursed → Confirmed; skipped steps and regressions are rejected
     async fn update_settlement_status(&self, settlement_id: Uuid, status: &str) -> BankingResult<()>;
     
     /// Status History Operations
     async fn get_status_history(&self, account_id: Uuid) -> BankingResult<Vec<crate::models::account::AccountStatusChangeRecordModel>>;
     async fn add_status_change(&self, status_change: crate::models::account::AccountStatusChangeRecordModel) -> BankingResult<crate::models::account::AccountStatusChangeRecordModel>;
     
+    /// Balance Snapshot Operations
+    /// Upserts the snapshot for (account_id, snapshot_date) so EOD reruns overwrite the same row
+    async fn save_balance_snapshot(&self, snapshot: AccountBalanceSnapshotModel) -> BankingResult<AccountBalanceSnapshotModel>;
+    /// Most recent snapshot strictly before the given date
+    async fn find_latest_balance_snapshot_before(&self, account_id: Uuid, before_date: NaiveDate) -> BankingResult<Option<AccountBalanceSnapshotModel>>;
+    async fn find_balance_snapshots_by_date(&self, snapshot_date: NaiveDate) -> BankingResult<Vec<AccountBalanceSnapshotModel>>;
+
+    
+    /// Loan Penalty Accrual Operations
+    /// Upserts the assessment for (loan_account_id, assessment_date) so EOD reruns overwrite the same row
+    async fn save_loan_penalty_accrual(&self, accrual: LoanPenaltyAccrualModel) -> BankingResult<LoanPenaltyAccrualModel>;
+    /// Most recent assessment strictly before the given date
+    async fn find_latest_loan_penalty_accrual_before(&self, loan_account_id: Uuid, before_date: NaiveDate) -> BankingResult<Option<LoanPenaltyAccrualModel>>;
+
+    
+    /// Overdraft Interest Accrual Operations
+    /// Upserts the accrual for (account_id, accrual_date) so EOD reruns overwrite the same row
+    async fn save_overdraft_interest_accrual(&self, accrual: crate::models::casa::OverdraftInterestAccrual) -> BankingResult<crate::models::casa::OverdraftInterestAccrual>;
+    /// Most recent accrual strictly before the given date
+    async fn find_latest_overdraft_interest_accrual_before(&self, account_id: Uuid, before_date: NaiveDate) -> BankingResult<Option<crate::models::casa::OverdraftInterestAccrual>>;
+    
     /// Utility Operations
     async fn exists(&self, account_id: Uuid) -> BankingResult<bool>;
+    /// Accounts with the given ids; missing ids are skipped and order is not preserved
+    async fn find_by_ids(&self, account_ids: &[Uuid]) -> BankingResult<Vec<AccountModel>>;
+    /// Existence of each id, paired in input order
+    async fn exist_by_ids(&self, account_ids: &[Uuid]) -> BankingResult<Vec<(Uuid, bool)>>;
     async fn count_by_customer(&self, customer_id: Uuid) -> BankingResult<i64>;
     async fn count_by_product(&self, product_id: Uuid) -> BankingResult<i64>;
     async fn list(&self, offset: i64, limit: i64) -> BankingResult<Vec<AccountModel>>;
+    /// Keyset page of all accounts ordered by id, starting after `after_account_id`.
+    /// For batch jobs walking every account, where `list` slows down with the offset
+    async fn list_after(&self, after_account_id: Option<Uuid>, limit: i64) -> BankingResult<Vec<AccountModel>>;
     async fn count(&self) -> BankingResult<i64>;
 
     /// Update last activity date for account
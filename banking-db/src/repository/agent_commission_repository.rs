@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::models::{
+    AgentCollectionTotalsModel, AgentCommissionLineItemModel, AgentCommissionSchemeModel,
+    AgentCommissionStatementModel, DbCommissionStatementStatus,
+};
+
+#[async_trait]
+pub trait AgentCommissionRepository: Send + Sync {
+    async fn create_scheme(&self, scheme: AgentCommissionSchemeModel) -> BankingResult<AgentCommissionSchemeModel>;
+    async fn update_scheme(&self, scheme: AgentCommissionSchemeModel) -> BankingResult<AgentCommissionSchemeModel>;
+    async fn find_scheme_by_id(&self, scheme_id: Uuid) -> BankingResult<Option<AgentCommissionSchemeModel>>;
+    async fn find_active_schemes(&self) -> BankingResult<Vec<AgentCommissionSchemeModel>>;
+
+    /// Collections per agent and program with collection_date in [from, to]
+    async fn aggregate_agent_collections(&self, from: NaiveDate, to: NaiveDate) -> BankingResult<Vec<AgentCollectionTotalsModel>>;
+    /// CashDiscrepancy performance alerts per agent created in [from, to]
+    async fn count_cash_discrepancy_alerts(&self, from: NaiveDate, to: NaiveDate) -> BankingResult<HashMap<Uuid, i64>>;
+
+    async fn find_statements_for_period(&self, period_start: NaiveDate) -> BankingResult<Vec<AgentCommissionStatementModel>>;
+    /// Atomically delete the Draft statements of the period and store the new ones.
+    /// Fails without changes if the period holds an Invoiced statement.
+    async fn replace_period_statements(
+        &self,
+        period_start: NaiveDate,
+        statements: Vec<AgentCommissionStatementModel>,
+        line_items: Vec<AgentCommissionLineItemModel>,
+    ) -> BankingResult<()>;
+    async fn find_statement_by_id(&self, statement_id: Uuid) -> BankingResult<Option<AgentCommissionStatementModel>>;
+    /// Statements of the agent, most recent period first
+    async fn find_statements_by_agent(&self, collection_agent_id: Uuid, limit: i64) -> BankingResult<Vec<AgentCommissionStatementModel>>;
+    async fn find_line_items(&self, statement_id: Uuid) -> BankingResult<Vec<AgentCommissionLineItemModel>>;
+    async fn update_statement_status(
+        &self,
+        statement_id: Uuid,
+        status: DbCommissionStatementStatus,
+        invoiced_at: Option<DateTime<Utc>>,
+    ) -> BankingResult<AgentCommissionStatementModel>;
+}
@@ -5,6 +5,7 @@ use rust_decimal::Decimal;
 use chrono::{DateTime, Utc, NaiveDate};
 
 use crate::models::{AgentNetworkModel, AgencyBranchModel, AgentTerminalModel, CashLimitCheckModel};
+use crate::models::agent_network::{BranchRollupModel, HierarchyNodeModel};
 
 #[async_trait]
 pub trait AgentNetworkRepository: Send + Sync {
@@ -46,6 +47,16 @@ pub trait AgentNetworkRepository: Send + Sync {
     async fn find_terminals_needing_sync(&self, threshold: DateTime<Utc>) -> BankingResult<Vec<AgentTerminalModel>>;
     async fn list_terminals(&self, offset: i64, limit: i64) -> BankingResult<Vec<AgentTerminalModel>>;
     
+    /// Hierarchy Traversal
+    /// Branches and terminals below a network or branch, nearest first, at most
+    /// `max_depth` levels down. A cycle fails with BankingError::HierarchyCycle.
+    async fn find_descendants(&self, node_id: Uuid, max_depth: i32) -> BankingResult<Vec<HierarchyNodeModel>>;
+    /// Branches above a branch or terminal, from its parent up to the network root
+    async fn find_ancestors(&self, node_id: Uuid) -> BankingResult<Vec<HierarchyNodeModel>>;
+    /// Account counts and balances of every branch in the subtree rooted at the
+    /// branch, the branch first; empty when the branch does not exist
+    async fn find_branch_rollup(&self, agency_branch_id: Uuid) -> BankingResult<Vec<BranchRollupModel>>;
+
     /// Hierarchical Limit Validation
     async fn get_terminal_limits(&self, terminal_id: Uuid) -> BankingResult<Option<TerminalLimits>>;
     async fn get_branch_limits(&self, agency_branch_id: Uuid) -> BankingResult<Option<BranchLimits>>;
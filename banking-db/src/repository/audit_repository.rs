@@ -1,5 +1,6 @@
-use crate::models::audit::AuditLogModel;
+use crate::models::audit::{AuditChainVerification, AuditEntityType, AuditLogModel};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::Database;
 use std::error::Error;
 use std::fmt;
@@ -8,12 +9,17 @@ use uuid::Uuid;
 #[derive(Debug)]
 pub enum AuditLogRepositoryError {
     RepositoryError(Box<dyn Error + Send + Sync>),
+    NotFound(Uuid),
+    /// Audit entries are hash chained; the named operation would break the chain
+    Immutable(&'static str),
 }
 
 impl fmt::Display for AuditLogRepositoryError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::RepositoryError(err) => write!(f, "Repository error: {err}"),
+            Self::NotFound(id) => write!(f, "Audit log not found: {id}"),
+            Self::Immutable(operation) => write!(f, "Audit logs are immutable and cannot be {operation}"),
         }
     }
 }
@@ -22,6 +28,7 @@ impl Error for AuditLogRepositoryError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             Self::RepositoryError(err) => Some(err.as_ref()),
+            Self::NotFound(_) | Self::Immutable(_) => None,
         }
     }
 }
@@ -34,8 +41,39 @@ impl From<sqlx::Error> for AuditLogRepositoryError {
 
 pub type AuditLogResult<T> = Result<T, AuditLogRepositoryError>;
 
+/// Larger page sizes requested from the audit log queries are cut down to this
+pub const MAX_AUDIT_LOG_PAGE_SIZE: i32 = 500;
+
 #[async_trait]
 pub trait AuditLogRepository<DB: Database>: Send + Sync {
+    /// Append the entry to the hash chain. Returns it with its sequence number
+    /// and hashes, and with updated_at cut to whole microseconds.
     async fn create(&self, audit_log: &AuditLogModel) -> AuditLogResult<AuditLogModel>;
     async fn find_by_id(&self, id: Uuid) -> AuditLogResult<Option<AuditLogModel>>;
+    /// Audit logs that wrote a version of the entity within `[from, to)`,
+    /// newest first. Pages start at 1.
+    async fn find_by_entity(
+        &self,
+        entity_type: AuditEntityType,
+        entity_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        page: i32,
+        page_size: i32,
+    ) -> AuditLogResult<Vec<AuditLogModel>>;
+    /// Audit logs written by the person within `[from, to)`, newest first.
+    /// Pages start at 1.
+    async fn find_by_actor(
+        &self,
+        person_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        page: i32,
+        page_size: i32,
+    ) -> AuditLogResult<Vec<AuditLogModel>>;
+    /// Entries from `from_id` to `to_id`, both included, in chain order
+    async fn find_chain_segment(&self, from_id: Uuid, to_id: Uuid) -> AuditLogResult<Vec<AuditLogModel>>;
+    /// Recompute the hashes from `from_id` to `to_id`, including the link to the
+    /// entry before `from_id`, and report the first link that does not hold
+    async fn verify_chain(&self, from_id: Uuid, to_id: Uuid) -> AuditLogResult<AuditChainVerification>;
 }
\ No newline at end of file
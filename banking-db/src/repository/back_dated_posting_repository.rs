@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::models::BackDatedPostingModel;
+
+#[async_trait]
+pub trait BackDatedPostingRepository: Send + Sync {
+    async fn create_back_dated_posting(&self, posting: BackDatedPostingModel) -> BankingResult<BackDatedPostingModel>;
+    /// Postings made on business dates from `from_date` to `to_date` inclusive
+    async fn find_back_dated_postings(&self, from_date: NaiveDate, to_date: NaiveDate) -> BankingResult<Vec<BackDatedPostingModel>>;
+    async fn find_back_dated_postings_by_account(&self, account_id: Uuid) -> BankingResult<Vec<BackDatedPostingModel>>;
+}
@@ -32,10 +32,12 @@ pub trait BatchRepository<DB: Database, T>: Send + Sync {
     ) -> Result<Vec<T>, Box<dyn Error + Send + Sync>>;
 
     /// Delete multiple items by their IDs
+    /// Repositories that keep history deactivate the rows instead, recording audit_log_id
     /// Returns the number of items deleted
     async fn delete_batch(
         &self,
         ids: &[Uuid],
+        audit_log_id: Uuid,
     ) -> Result<usize, Box<dyn Error + Send + Sync>>;
 
 }
@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::{
+    BranchCashCeilingModel, BranchCashPositionModel, CashTransferRequestModel, VaultAdjustmentModel,
+};
+
+#[async_trait]
+pub trait BranchCashRepository: Send + Sync {
+    /// Insert the ceiling, or replace the existing ceiling of the same branch
+    async fn upsert_ceiling(&self, ceiling: BranchCashCeilingModel) -> BankingResult<BranchCashCeilingModel>;
+    async fn find_ceiling_by_branch(&self, agency_branch_id: Uuid) -> BankingResult<Option<BranchCashCeilingModel>>;
+    async fn find_active_ceilings(&self) -> BankingResult<Vec<BranchCashCeilingModel>>;
+
+    /// Sum of the branch's terminal cash balances and of its vault adjustments
+    async fn get_branch_cash_position(&self, agency_branch_id: Uuid) -> BankingResult<BranchCashPositionModel>;
+
+    async fn create_transfer(&self, transfer: CashTransferRequestModel) -> BankingResult<CashTransferRequestModel>;
+    async fn find_transfer_by_id(&self, transfer_id: Uuid) -> BankingResult<Option<CashTransferRequestModel>>;
+    /// Requested and InTransit transfers, oldest request first
+    async fn find_open_transfers(&self) -> BankingResult<Vec<CashTransferRequestModel>>;
+    /// Store the transfer and post the adjustment atomically. Fails without
+    /// changes if the stored transfer was updated after `expected_last_updated_at`.
+    async fn update_transfer(
+        &self,
+        transfer: CashTransferRequestModel,
+        expected_last_updated_at: DateTime<Utc>,
+        adjustment: Option<VaultAdjustmentModel>,
+    ) -> BankingResult<CashTransferRequestModel>;
+    async fn find_adjustments_by_transfer(&self, transfer_id: Uuid) -> BankingResult<Vec<VaultAdjustmentModel>>;
+}
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::{AccountBundleModel, BundleAccountModel, BundlePricingMarkerModel, ProductBundleModel};
+
+#[async_trait]
+pub trait BundleRepository: Send + Sync {
+    async fn create_product_bundle(&self, bundle: ProductBundleModel) -> BankingResult<ProductBundleModel>;
+    async fn find_product_bundle_by_code(&self, bundle_code: &str) -> BankingResult<Option<ProductBundleModel>>;
+
+    async fn create_account_bundle(&self, bundle: AccountBundleModel) -> BankingResult<AccountBundleModel>;
+    async fn update_account_bundle(&self, bundle: AccountBundleModel) -> BankingResult<AccountBundleModel>;
+    async fn find_account_bundle_by_id(&self, account_bundle_id: Uuid) -> BankingResult<Option<AccountBundleModel>>;
+    /// Newest first
+    async fn find_account_bundles_by_customer(&self, customer_id: Uuid) -> BankingResult<Vec<AccountBundleModel>>;
+
+    async fn create_bundle_account(&self, link: BundleAccountModel) -> BankingResult<BundleAccountModel>;
+    async fn find_bundle_accounts(&self, account_bundle_id: Uuid) -> BankingResult<Vec<BundleAccountModel>>;
+    /// Link of the account that is not unlinked, in an Active bundle
+    async fn find_linked_bundle_account(&self, account_id: Uuid) -> BankingResult<Option<BundleAccountModel>>;
+    async fn unlink_bundle_account(&self, link_id: Uuid, unlinked_at: DateTime<Utc>) -> BankingResult<()>;
+
+    async fn create_pricing_marker(&self, marker: BundlePricingMarkerModel) -> BankingResult<BundlePricingMarkerModel>;
+    /// Including removed markers
+    async fn find_pricing_markers_by_bundle(&self, account_bundle_id: Uuid) -> BankingResult<Vec<BundlePricingMarkerModel>>;
+    /// Markers on the account that are not removed
+    async fn find_active_markers_by_account(&self, account_id: Uuid) -> BankingResult<Vec<BundlePricingMarkerModel>>;
+    /// Markers already removed keep their removal time
+    async fn remove_pricing_markers(&self, marker_ids: &[Uuid], removed_at: DateTime<Utc>) -> BankingResult<()>;
+}
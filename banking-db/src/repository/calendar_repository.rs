@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use banking_api::BankingResult;
+use banking_api::{domain::WeekendMask, BankingResult};
 use uuid::Uuid;
 use chrono::NaiveDate;
 
@@ -30,9 +30,9 @@ pub trait CalendarRepository: Send + Sync {
     async fn subtract_business_days(&self, date: NaiveDate, days: i32, jurisdiction: &str) -> BankingResult<NaiveDate>;
     async fn count_business_days(&self, start_date: NaiveDate, end_date: NaiveDate, jurisdiction: &str) -> BankingResult<i32>;
     
-    /// Weekend Configuration
-    async fn get_weekend_days(&self, jurisdiction: &str) -> BankingResult<Vec<chrono::Weekday>>;
-    async fn set_weekend_days(&self, jurisdiction: &str, weekend_days: Vec<chrono::Weekday>) -> BankingResult<()>;
+    /// Weekend Configuration - `None` when the calendar has no weekend rule of its own
+    async fn get_weekend_days(&self, jurisdiction: &str) -> BankingResult<Option<WeekendMask>>;
+    async fn set_weekend_days(&self, jurisdiction: &str, weekend_days: WeekendMask) -> BankingResult<()>;
     
     /// Recurring Holiday Management
     async fn find_recurring_holidays(&self, jurisdiction: &str) -> BankingResult<Vec<BankHolidayModel>>;
@@ -51,7 +51,7 @@ pub trait CalendarRepository: Send + Sync {
     
     /// Jurisdiction Management
     async fn get_supported_jurisdictions(&self) -> BankingResult<Vec<String>>;
-    async fn add_jurisdiction(&self, jurisdiction: &str, weekend_days: Vec<chrono::Weekday>) -> BankingResult<()>;
+    async fn add_jurisdiction(&self, jurisdiction: &str, weekend_days: WeekendMask) -> BankingResult<()>;
     async fn remove_jurisdiction(&self, jurisdiction: &str) -> BankingResult<()>;
     
     /// Holiday Validation and Import
@@ -1,8 +1,15 @@
 use async_trait::async_trait;
 use banking_api::BankingResult;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::{models::channel::{ChannelModel, ChannelStatus}, ChannelType};
+use crate::{
+    models::channel::{
+        ChannelLimitModel, ChannelModel, ChannelReconciliationReportModel, ChannelStatus, ChannelUsageModel,
+        FeeItemModel, FeeScheduleModel, FeeTierModel, ReconciliationDiscrepancyModel,
+    },
+    ChannelType,
+};
 
 #[async_trait]
 pub trait ChannelRepository: Send + Sync {
@@ -44,6 +51,69 @@ pub trait ChannelRepository: Send + Sync {
     
     /// Count total channels
     async fn count_all(&self) -> BankingResult<i64>;
+
+    /// Create or replace the limits of a channel and currency
+    async fn upsert_limit(&self, limit: ChannelLimitModel) -> BankingResult<ChannelLimitModel>;
+
+    /// Limits in force for a channel code and currency, if any
+    async fn find_effective_limit(&self, channel_id: &str, currency: &str) -> BankingResult<Option<ChannelLimitModel>>;
+
+    /// Posted and pending-approval debits through the channel in the currency,
+    /// across every account held by the owners of `account_id`: the day's total
+    /// and count from `day_start`, and the month's total from `month_start`,
+    /// both up to `day_end`
+    async fn get_owner_channel_usage(
+        &self,
+        account_id: Uuid,
+        channel_id: &str,
+        currency: &str,
+        month_start: DateTime<Utc>,
+        day_start: DateTime<Utc>,
+        day_end: DateTime<Utc>,
+    ) -> BankingResult<ChannelUsageModel>;
+
+    /// Create or replace a fee schedule
+    async fn save_fee_schedule(&self, schedule: FeeScheduleModel) -> BankingResult<FeeScheduleModel>;
+
+    /// Find fee schedule by ID
+    async fn find_fee_schedule_by_id(&self, schedule_id: Uuid) -> BankingResult<Option<FeeScheduleModel>>;
+
+    /// Create or replace a fee item with its tiers, dropping tiers saved
+    /// before that are no longer given. The tier slots of the item returned
+    /// follow tier order.
+    async fn save_fee_item(&self, item: FeeItemModel, tiers: Vec<FeeTierModel>) -> BankingResult<FeeItemModel>;
+
+    /// Find fee item by ID
+    async fn find_fee_item_by_id(&self, fee_item_id: Uuid) -> BankingResult<Option<FeeItemModel>>;
+
+    /// Fee items of a schedule, by fee code
+    async fn find_fee_items_by_schedule(&self, schedule_id: Uuid) -> BankingResult<Vec<FeeItemModel>>;
+
+    /// Tiers of a fee item in tier order
+    async fn find_fee_tiers(&self, fee_item_id: Uuid) -> BankingResult<Vec<FeeTierModel>>;
+
+    /// Save a reconciliation report together with its discrepancies
+    async fn create_reconciliation_report(
+        &self,
+        report: ChannelReconciliationReportModel,
+        discrepancies: Vec<ReconciliationDiscrepancyModel>,
+    ) -> BankingResult<ChannelReconciliationReportModel>;
+
+    async fn find_reconciliation_report_by_id(&self, report_id: Uuid) -> BankingResult<Option<ChannelReconciliationReportModel>>;
+
+    /// Unresolved discrepancies across the channel's reports, oldest first
+    async fn find_unresolved_discrepancies(&self, channel_id: Uuid) -> BankingResult<Vec<ReconciliationDiscrepancyModel>>;
+
+    /// Mark a discrepancy resolved. Once none of its report's discrepancies is
+    /// left unresolved, the report is completed. None if the discrepancy does not
+    /// exist or was already resolved.
+    async fn resolve_discrepancy(
+        &self,
+        discrepancy_id: Uuid,
+        resolution_notes: &str,
+        resolved_by: Uuid,
+        resolved_at: DateTime<Utc>,
+    ) -> BankingResult<Option<ReconciliationDiscrepancyModel>>;
 }
 
 /// Channel statistics structure
@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::{ChannelRestrictionModel, ChannelSecurityEventModel, DbChannelSecurityEventType};
+
+#[async_trait]
+pub trait ChannelSecurityRepository: Send + Sync {
+    async fn create_event(&self, event: ChannelSecurityEventModel) -> BankingResult<ChannelSecurityEventModel>;
+    /// Events of the customer and type with occurred_at in (from, to]
+    async fn count_customer_events(
+        &self,
+        customer_id: Uuid,
+        event_type: DbChannelSecurityEventType,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> BankingResult<u64>;
+    /// Most recent events of the customer, newest first
+    async fn find_recent_events_for_customer(&self, customer_id: Uuid, limit: i64) -> BankingResult<Vec<ChannelSecurityEventModel>>;
+    /// Delete events that occurred before the cutoff; returns the number deleted
+    async fn delete_events_before(&self, cutoff: DateTime<Utc>) -> BankingResult<u64>;
+
+    async fn create_restriction(&self, restriction: ChannelRestrictionModel) -> BankingResult<ChannelRestrictionModel>;
+    async fn find_restriction_by_id(&self, restriction_id: Uuid) -> BankingResult<Option<ChannelRestrictionModel>>;
+    async fn update_restriction(&self, restriction: ChannelRestrictionModel) -> BankingResult<ChannelRestrictionModel>;
+    /// Restrictions not lifted, started by and not expired at `as_of`
+    async fn find_active_restrictions(&self, customer_id: Uuid, as_of: DateTime<Utc>) -> BankingResult<Vec<ChannelRestrictionModel>>;
+}
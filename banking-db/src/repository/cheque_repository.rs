@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::models::{ChequeBookModel, ChequeModel};
+
+#[async_trait]
+pub trait ChequeRepository: Send + Sync {
+    /// Allocate `count` consecutive serial numbers for an account from the
+    /// sequence facility and return the first one
+    async fn reserve_serial_range(&self, account_id: Uuid, count: i64) -> BankingResult<i64>;
+
+    /// Create a cheque book together with its leaves
+    async fn create_cheque_book(&self, book: ChequeBookModel, cheques: Vec<ChequeModel>) -> BankingResult<ChequeBookModel>;
+    async fn find_cheque_book_by_id(&self, book_id: Uuid) -> BankingResult<Option<ChequeBookModel>>;
+    async fn find_cheque_books_by_account(&self, account_id: Uuid) -> BankingResult<Vec<ChequeBookModel>>;
+
+    async fn find_cheque(&self, account_id: Uuid, serial_number: i64) -> BankingResult<Option<ChequeModel>>;
+    async fn update_cheque(&self, cheque: ChequeModel) -> BankingResult<ChequeModel>;
+
+    /// Move an unused or bounced cheque to Presented. Returns false if another
+    /// presentment or a stop got there first.
+    async fn mark_presented(&self, cheque_id: Uuid, amount: Decimal, presented_at: DateTime<Utc>) -> BankingResult<bool>;
+
+    /// Unused leaves of issued books, i.e. cheques not yet presented
+    async fn find_outstanding_cheques(&self, account_id: Uuid) -> BankingResult<Vec<ChequeModel>>;
+}
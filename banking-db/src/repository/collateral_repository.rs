@@ -4,7 +4,8 @@ use rust_decimal::Decimal;
 use uuid::Uuid;
 
 use crate::models::{
-    CollateralAlertType, CollateralEnforcementModel, CollateralModel
+    CollateralAlertModel, CollateralAlertType, CollateralEnforcementModel, CollateralizedLoanModel, CollateralModel,
+    CollateralValuationModel,
 };
 
 /// Repository trait for collateral data persistence operations
@@ -53,14 +54,20 @@ pub trait CollateralRepository: Send + Sync {
 
     // === VALUATION OPERATIONS ===
     
-    /// Save a collateral valuation (using JSON data for now)
-    async fn save_valuation(&self, collateral_id: Uuid, valuation_data: String) -> Result<(), String>;
+    /// Save a new collateral valuation or correct an existing one
+    async fn save_valuation(&self, valuation: &CollateralValuationModel) -> Result<(), String>;
     
-    /// Find all valuations for a collateral (returns JSON data)
-    async fn find_valuations_by_collateral(&self, collateral_id: Uuid) -> Result<Vec<String>, String>;
+    /// Find valuation by ID
+    async fn find_valuation_by_id(&self, valuation_id: Uuid) -> Result<Option<CollateralValuationModel>, String>;
     
-    /// Find the latest valuation for a collateral (returns JSON data)
-    async fn find_latest_valuation(&self, collateral_id: Uuid) -> Result<Option<String>, String>;
+    /// Find all valuations for a collateral, latest first
+    async fn find_valuations_by_collateral(&self, collateral_id: Uuid) -> Result<Vec<CollateralValuationModel>, String>;
+    
+    /// Find the latest valuation for a collateral
+    async fn find_latest_valuation(&self, collateral_id: Uuid) -> Result<Option<CollateralValuationModel>, String>;
+    
+    /// Delete a valuation recorded in error
+    async fn delete_valuation(&self, valuation_id: Uuid) -> Result<(), String>;
     
     /// Find collaterals with valuations due by date
     async fn find_valuations_due(&self, reference_date: NaiveDate) -> Result<Vec<CollateralModel>, String>;
@@ -68,8 +75,8 @@ pub trait CollateralRepository: Send + Sync {
     /// Find collaterals with overdue valuations
     async fn find_overdue_valuations(&self, reference_date: NaiveDate) -> Result<Vec<CollateralModel>, String>;
     
-    /// Find valuations by date range (returns JSON data)
-    async fn find_valuations_by_date_range(&self, from_date: NaiveDate, to_date: NaiveDate) -> Result<Vec<String>, String>;
+    /// Find valuations dated within the range, inclusive
+    async fn find_valuations_by_date_range(&self, from_date: NaiveDate, to_date: NaiveDate) -> Result<Vec<CollateralValuationModel>, String>;
 
     // === PLEDGE OPERATIONS ===
     
@@ -91,6 +98,9 @@ pub trait CollateralRepository: Send + Sync {
     /// Update pledge status
     async fn update_pledge_status(&self, pledge_id: Uuid, status: String, updated_by_person_id: Uuid) -> Result<(), String>;
     
+    /// Find the active pledges of loans not yet closed, with the outstanding principal of each loan
+    async fn find_collateralized_loans(&self) -> Result<Vec<CollateralizedLoanModel>, String>;
+    
     /// Update pledged amount (for partial releases)
     async fn update_pledged_amount(&self, pledge_id: Uuid, new_amount: Decimal, updated_by_person_id: Uuid) -> Result<(), String>;
     
@@ -99,14 +109,14 @@ pub trait CollateralRepository: Send + Sync {
 
     // === ALERT OPERATIONS ===
     
-    /// Save a collateral alert (using JSON data for now)
-    async fn save_alert(&self, collateral_id: Uuid, alert_data: String) -> Result<(), String>;
+    /// Save a collateral alert
+    async fn save_alert(&self, alert: &CollateralAlertModel) -> Result<(), String>;
     
     /// Find alert by ID (returns JSON data)
     async fn find_alert_by_id(&self, alert_id: Uuid) -> Result<Option<String>, String>;
     
-    /// Find all alerts for a collateral (returns JSON data)
-    async fn find_alerts_by_collateral(&self, collateral_id: Uuid) -> Result<Vec<String>, String>;
+    /// Find all alerts for a collateral, latest first
+    async fn find_alerts_by_collateral(&self, collateral_id: Uuid) -> Result<Vec<CollateralAlertModel>, String>;
     
     /// Find active alerts only (returns JSON data)
     async fn find_active_alerts(&self) -> Result<Vec<String>, String>;
@@ -3,7 +3,7 @@ use banking_api::BankingResult;
 use uuid::Uuid;
 use chrono::{DateTime, Utc, NaiveDate};
 
-use crate::models::{SanctionsScreeningModel, ComplianceAlertModel, ComplianceRiskScoreModel, ComplianceResultModel, SarDataModel};
+use crate::models::{SanctionsScreeningModel, SanctionsMatchModel, ScreeningType, ComplianceAlertModel, ComplianceRiskScoreModel, ComplianceResultModel, CustomerRiskFactorsModel, SarDataModel, SarStatus, UboVerificationResultModel};
 use crate::models::account::UltimateBeneficiaryModel;
 use crate::AlertType;
 
@@ -18,6 +18,10 @@ pub trait ComplianceRepository: Send + Sync {
     async fn find_screenings_requiring_review(&self) -> BankingResult<Vec<SanctionsScreeningModel>>;
     async fn update_screening_status(&self, screening_id: Uuid, status: &str, reviewed_by: &str) -> BankingResult<()>;
     async fn find_customers_needing_screening(&self, days_threshold: i32) -> BankingResult<Vec<Uuid>>;
+    /// Record the matches behind a screening hit
+    async fn create_sanctions_matches(&self, screening_id: Uuid, matches: Vec<SanctionsMatchModel>) -> BankingResult<()>;
+    /// Those of `customer_ids` screened for `screening_type` at or after `since`
+    async fn find_customers_screened_since(&self, customer_ids: &[Uuid], screening_type: ScreeningType, since: DateTime<Utc>) -> BankingResult<Vec<Uuid>>;
     
     /// Compliance Alert Operations
     async fn create_alert(&self, alert: ComplianceAlertModel) -> BankingResult<ComplianceAlertModel>;
@@ -40,6 +44,8 @@ pub trait ComplianceRepository: Send + Sync {
     async fn update_ubo_verification_status(&self, ubo_id: Uuid, status: &str, verified_by: &str) -> BankingResult<()>;
     async fn find_ubo_requiring_verification(&self) -> BankingResult<Vec<UltimateBeneficiaryModel>>;
     async fn delete_ubo_link(&self, ubo_id: Uuid) -> BankingResult<()>;
+    /// Record the latest UBO verification of a corporate customer, replacing the previous one
+    async fn save_ubo_verification_result(&self, result: UboVerificationResultModel) -> BankingResult<UboVerificationResultModel>;
     
     /// Risk Score Operations
     async fn create_risk_score(&self, risk_score: ComplianceRiskScoreModel) -> BankingResult<ComplianceRiskScoreModel>;
@@ -47,6 +53,12 @@ pub trait ComplianceRepository: Send + Sync {
     async fn find_risk_score_by_customer(&self, customer_id: Uuid) -> BankingResult<Option<ComplianceRiskScoreModel>>;
     async fn find_high_risk_customers(&self, threshold_score: f64) -> BankingResult<Vec<ComplianceRiskScoreModel>>;
     async fn find_risk_scores_requiring_review(&self, days_threshold: i32) -> BankingResult<Vec<ComplianceRiskScoreModel>>;
+    /// Every score calculated for the customer, newest first
+    async fn find_risk_score_history(&self, customer_id: Uuid) -> BankingResult<Vec<ComplianceRiskScoreModel>>;
+    /// Risk factors of the customer, counting transactions made since `transactions_since`
+    async fn find_risk_factors(&self, customer_id: Uuid, transactions_since: DateTime<Utc>) -> BankingResult<CustomerRiskFactorsModel>;
+    /// Active customers never scored, or last scored before `calculated_before`
+    async fn find_customers_due_for_risk_review(&self, calculated_before: DateTime<Utc>) -> BankingResult<Vec<Uuid>>;
     
     /// Compliance Result Operations
     async fn create_compliance_result(&self, result: ComplianceResultModel) -> BankingResult<ComplianceResultModel>;
@@ -62,6 +74,11 @@ pub trait ComplianceRepository: Send + Sync {
     async fn find_sar_by_status(&self, status: &str) -> BankingResult<Vec<SarDataModel>>;
     async fn update_sar_status(&self, sar_id: Uuid, status: &str, updated_by_person_id: &str) -> BankingResult<()>;
     async fn find_pending_sar_filings(&self) -> BankingResult<Vec<SarDataModel>>;
+    /// Rewrite a SAR that is still a draft, including its move out of Draft;
+    /// SARs past Draft are left unchanged and reported as not found
+    async fn update_draft_sar(&self, sar: SarDataModel) -> BankingResult<SarDataModel>;
+    /// SARs generated within `[from, to)`, optionally in one status, newest first
+    async fn find_sars(&self, status: Option<SarStatus>, from: DateTime<Utc>, to: DateTime<Utc>) -> BankingResult<Vec<SarDataModel>>;
     
     /// Transaction Monitoring Operations
     async fn record_transaction_monitoring(&self, transaction_id: Uuid, monitoring_result: TransactionMonitoringResult) -> BankingResult<()>;
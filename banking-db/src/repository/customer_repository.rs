@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use banking_api::BankingResult;
 use uuid::Uuid;
 
-use crate::{models::{CustomerAuditModel, CustomerDocumentModel, CustomerModel, CustomerPortfolioModel}, CustomerStatus, IdentityType, RiskRating};
+use crate::{models::{CustomerAuditModel, CustomerDocumentModel, CustomerModel, CustomerPortfolioModel, CustomerSearchCriteriaModel}, CustomerStatus, IdentityType, RiskRating};
 
 #[async_trait]
 pub trait CustomerRepository: Send + Sync {
@@ -14,6 +14,9 @@ pub trait CustomerRepository: Send + Sync {
     
     /// Find customer by ID
     async fn find_by_id(&self, customer_id: Uuid) -> BankingResult<Option<CustomerModel>>;
+
+    /// Find customers by ID in bulk; unknown IDs are left out
+    async fn find_by_ids(&self, customer_ids: &[Uuid]) -> BankingResult<Vec<CustomerModel>>;
     
     /// Check if customer exists
     async fn exists(&self, customer_id: Uuid) -> BankingResult<bool>;
@@ -29,6 +32,9 @@ pub trait CustomerRepository: Send + Sync {
     
     /// Get customer portfolio summary
     async fn get_portfolio(&self, customer_id: Uuid) -> BankingResult<Option<CustomerPortfolioModel>>;
+
+    /// Customers matching the criteria, ordered by name
+    async fn search(&self, criteria: CustomerSearchCriteriaModel) -> BankingResult<Vec<CustomerModel>>;
     
     /// Update customer risk rating with audit trail
     /// @param authorized_by - References Person.person_id
@@ -49,6 +55,13 @@ pub trait CustomerRepository: Send + Sync {
     /// Get customer audit trail
     async fn get_audit_trail(&self, customer_id: Uuid) -> BankingResult<Vec<CustomerAuditModel>>;
     
+    /// Move the duplicate's account ownerships, mandates and compliance records
+    /// to the survivor and mark the duplicate Merged, with an audit entry on
+    /// both, in one transaction. Fails with CustomerMergeBlocked while an
+    /// account owned by the duplicate has an active hold or pending workflow.
+    /// @param merged_by - References Person.person_id
+    async fn merge_into(&self, survivor_id: Uuid, duplicate_id: Uuid, reason: &str, merged_by: Uuid) -> BankingResult<()>;
+
     /// Delete customer (soft delete)
     /// @param deleted_by - References Person.person_id
     async fn delete(&self, customer_id: Uuid, deleted_by: Uuid) -> BankingResult<()>;
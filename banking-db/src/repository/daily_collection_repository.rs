@@ -1,5 +1,12 @@
-use crate::models::daily_collection::{CollectionAgentModel, AgentStatus};
+use crate::models::daily_collection::{
+    AgentDeviceModel, AgentStatus, CollectionAgentModel, CollectionBatchModel, CollectionProgramModel,
+    CollectionRecordModel, CollectionRecordStatus, CollectionSecurityFeaturesModel, CollectionStatus,
+    ConnectivityStatus, CoverageAreaModel, CustomerCollectionProfileModel, DeviceInformationModel,
+    GeoVerificationStatus, PerformanceAlertModel, TerritoryModel,
+};
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
 use uuid::Uuid;
 
 #[async_trait]
@@ -9,4 +16,57 @@ pub trait DailyCollectionRepository: Send + Sync {
     async fn get_collection_agent(&self, agent_id: Uuid) -> Result<Option<CollectionAgentModel>, String>;
     async fn find_agents_by_status(&self, status: AgentStatus) -> Result<Vec<CollectionAgentModel>, String>;
     async fn update_agent_status(&self, agent_id: Uuid, status: AgentStatus) -> Result<(), String>;
+    async fn get_device_information(&self, device_id: Uuid) -> Result<Option<DeviceInformationModel>, String>;
+    async fn get_security_features(&self, security_features_id: Uuid) -> Result<Option<CollectionSecurityFeaturesModel>, String>;
+    /// Store the heartbeat's battery and connectivity and set last_sync to `synced_at`.
+    /// Returns None when the device is missing.
+    async fn update_device_heartbeat(
+        &self,
+        device_id: Uuid,
+        battery_level: Option<f32>,
+        connectivity_status: ConnectivityStatus,
+        synced_at: DateTime<Utc>,
+    ) -> Result<Option<DeviceInformationModel>, String>;
+    /// Devices of non-terminated agents that last synced before `synced_before`,
+    /// never synced, or lack encryption or the certificate
+    async fn find_non_compliant_devices(&self, synced_before: DateTime<Utc>) -> Result<Vec<AgentDeviceModel>, String>;
+    async fn get_territory(&self, territory_id: Uuid) -> Result<Option<TerritoryModel>, String>;
+    async fn get_coverage_area(&self, coverage_area_id: Uuid) -> Result<Option<CoverageAreaModel>, String>;
+    async fn get_collection_program(&self, program_id: Uuid) -> Result<Option<CollectionProgramModel>, String>;
+    async fn get_customer_collection_profile(&self, customer_id: Uuid, program_id: Uuid) -> Result<Option<CustomerCollectionProfileModel>, String>;
+
+    /// Profiles in the agent's portfolio with the given status
+    async fn find_customer_profiles_by_agent(&self, agent_id: Uuid, status: CollectionStatus) -> Result<Vec<CustomerCollectionProfileModel>, String>;
+    async fn create_collection_record(&self, record: CollectionRecordModel) -> Result<CollectionRecordModel, String>;
+
+    /// Records collected between `from` and `to` inclusive, newest first; `page` starts at 1
+    #[allow(clippy::too_many_arguments)]
+    async fn find_collection_records(
+        &self,
+        agent_id: Option<Uuid>,
+        customer_id: Option<Uuid>,
+        from: NaiveDate,
+        to: NaiveDate,
+        status: Option<CollectionRecordStatus>,
+        page: i32,
+        page_size: i32,
+    ) -> Result<Vec<CollectionRecordModel>, String>;
+    /// Count an agent's collections with the given geo status recorded since `since`
+    async fn count_agent_collections_by_geo_status(&self, agent_id: Uuid, status: GeoVerificationStatus, since: DateTime<Utc>) -> Result<i64, String>;
+    /// Collections held for supervisor review after a failed geo check, oldest first
+    async fn find_geo_review_queue(&self) -> Result<Vec<CollectionRecordModel>, String>;
+    async fn get_collection_batch(&self, batch_id: Uuid) -> Result<Option<CollectionBatchModel>, String>;
+    async fn find_collection_records_by_ids(&self, record_ids: &[Uuid]) -> Result<Vec<CollectionRecordModel>, String>;
+    /// Store the batch's status, reconciliation fields and processed_at
+    async fn update_batch_reconciliation(&self, batch: CollectionBatchModel) -> Result<CollectionBatchModel, String>;
+    async fn create_performance_alert(&self, alert: PerformanceAlertModel) -> Result<PerformanceAlertModel, String>;
+    async fn get_performance_alert(&self, alert_id: Uuid) -> Result<Option<PerformanceAlertModel>, String>;
+    /// Mark an open alert acknowledged, keeping an earlier acknowledgement.
+    /// Returns None when the alert is missing or already resolved.
+    async fn acknowledge_performance_alert(&self, alert_id: Uuid, person_id: Uuid, acknowledged_at: DateTime<Utc>) -> Result<Option<PerformanceAlertModel>, String>;
+    /// Resolve an open alert unless it requires resolution and is unacknowledged.
+    /// Returns None when the alert is missing or not in a resolvable state.
+    async fn resolve_performance_alert(&self, alert_id: Uuid, person_id: Uuid, resolution_notes: Option<HeaplessString<500>>, resolved_at: DateTime<Utc>) -> Result<Option<PerformanceAlertModel>, String>;
+    /// Unresolved alerts on the agent's performance metrics, oldest first
+    async fn find_open_alerts_by_agent(&self, agent_id: Uuid) -> Result<Vec<PerformanceAlertModel>, String>;
 }
\ No newline at end of file
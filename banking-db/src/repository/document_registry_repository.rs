@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use uuid::Uuid;
+
+use crate::models::{DbDocumentLinkKind, DocumentLinkModel, RegisteredDocumentModel};
+
+#[async_trait]
+pub trait DocumentRegistryRepository: Send + Sync {
+    /// Insert the document unless its content hash is registered; returns the
+    /// registered document either way
+    async fn register_document(&self, document: RegisteredDocumentModel) -> BankingResult<RegisteredDocumentModel>;
+    async fn find_document_by_id(&self, document_id: Uuid) -> BankingResult<Option<RegisteredDocumentModel>>;
+    async fn find_document_by_hash(&self, content_hash: &[u8; 32]) -> BankingResult<Option<RegisteredDocumentModel>>;
+
+    /// Link a document to an entity; returns the existing link when already linked
+    async fn link_document(&self, link: DocumentLinkModel) -> BankingResult<DocumentLinkModel>;
+    async fn find_links_by_document(&self, document_id: Uuid) -> BankingResult<Vec<DocumentLinkModel>>;
+    async fn find_documents_by_link(&self, entity_kind: DbDocumentLinkKind, entity_id: Uuid) -> BankingResult<Vec<RegisteredDocumentModel>>;
+
+    /// Delete the document if nothing links to it; returns false otherwise
+    async fn delete_unlinked_document(&self, document_id: Uuid) -> BankingResult<bool>;
+}
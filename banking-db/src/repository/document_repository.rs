@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::models::{DbDocumentNotificationStatus, DbGeneratedDocumentType, DocumentNotificationModel, GeneratedDocumentModel};
+use banking_api::error::BankingResult;
+
+#[async_trait]
+pub trait DocumentRepository: Send + Sync {
+    /// Generated document operations
+    async fn create_document(&self, document: GeneratedDocumentModel) -> BankingResult<GeneratedDocumentModel>;
+    async fn find_document_by_id(&self, document_id: Uuid) -> BankingResult<Option<GeneratedDocumentModel>>;
+    async fn find_documents_by_account(&self, account_id: Uuid, document_type: DbGeneratedDocumentType) -> BankingResult<Vec<GeneratedDocumentModel>>;
+
+    /// Notification queue operations
+    async fn queue_notification(&self, notification: DocumentNotificationModel) -> BankingResult<DocumentNotificationModel>;
+    async fn find_queued_notifications(&self, limit: i64) -> BankingResult<Vec<DocumentNotificationModel>>;
+    async fn update_notification_status(&self, notification_id: Uuid, status: DbDocumentNotificationStatus) -> BankingResult<()>;
+}
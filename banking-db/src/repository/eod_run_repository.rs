@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::models::{EodRunModel, EodRunStepModel};
+
+#[async_trait]
+pub trait EodRunRepository: Send + Sync {
+    async fn create_run(&self, run: EodRunModel) -> BankingResult<EodRunModel>;
+    async fn update_run(&self, run: EodRunModel) -> BankingResult<EodRunModel>;
+    async fn find_run_by_date(&self, run_date: NaiveDate) -> BankingResult<Option<EodRunModel>>;
+    /// Inserts the step or replaces the saved progress of the run's step with the same name
+    async fn save_step(&self, step: EodRunStepModel) -> BankingResult<EodRunStepModel>;
+    /// Steps of a run in step order
+    async fn find_steps(&self, eod_run_id: Uuid) -> BankingResult<Vec<EodRunStepModel>>;
+}
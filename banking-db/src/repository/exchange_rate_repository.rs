@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::{DateTime, Utc};
+
+use crate::models::ExchangeRateModel;
+
+#[async_trait]
+pub trait ExchangeRateRepository: Send + Sync {
+    /// Record a rate; a rate already published for the pair at the same
+    /// effective time is replaced
+    async fn save_rate(&self, rate: ExchangeRateModel) -> BankingResult<ExchangeRateModel>;
+    /// Latest rate converting `base_currency` into `quote_currency` effective at or before `as_of`
+    async fn get_rate(&self, base_currency: &str, quote_currency: &str, as_of: DateTime<Utc>) -> BankingResult<Option<ExchangeRateModel>>;
+    /// Rate history of the pair, latest first
+    async fn find_rates_by_pair(&self, base_currency: &str, quote_currency: &str, limit: i64) -> BankingResult<Vec<ExchangeRateModel>>;
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::models::{CurrencyPositionModel, ExchangeRateModel};
+
+#[async_trait]
+pub trait FinancialPositionRepository: Send + Sync {
+    /// Balances, loan principal, active holds and overdraft use of the
+    /// customer's open owned accounts, one row per currency
+    async fn find_currency_positions(&self, customer_id: Uuid) -> BankingResult<Vec<CurrencyPositionModel>>;
+
+    /// Latest rate into `to_currency` effective on or before `as_of`, one per source currency
+    async fn find_exchange_rates(&self, to_currency: &str, as_of: NaiveDate) -> BankingResult<Vec<ExchangeRateModel>>;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::NaiveDate;
+
+use crate::models::{GlPostingTotalsModel, GlSummaryLineModel};
+
+#[async_trait]
+pub trait GeneralLedgerRepository: Send + Sync {
+    /// Posted and reversed transactions booked on `run_date`, grouped for the GL summary
+    async fn find_posting_totals(&self, run_date: NaiveDate) -> BankingResult<Vec<GlPostingTotalsModel>>;
+    /// Replaces the summary of the lines' run date, so a rerun of end of day overwrites it
+    async fn replace_gl_summary(&self, run_date: NaiveDate, lines: Vec<GlSummaryLineModel>) -> BankingResult<Vec<GlSummaryLineModel>>;
+    async fn find_gl_summary(&self, run_date: NaiveDate) -> BankingResult<Vec<GlSummaryLineModel>>;
+}
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use uuid::Uuid;
+
+use crate::models::{GuaranteedLoanModel, LoanGuarantorModel};
+
+#[async_trait]
+pub trait GuarantorRepository: Send + Sync {
+    async fn create_guarantor(&self, guarantor: LoanGuarantorModel) -> BankingResult<LoanGuarantorModel>;
+    async fn update_guarantor(&self, guarantor: LoanGuarantorModel) -> BankingResult<LoanGuarantorModel>;
+    async fn find_guarantor_by_id(&self, guarantor_link_id: Uuid) -> BankingResult<Option<LoanGuarantorModel>>;
+
+    /// All guarantors of a loan, released ones included
+    async fn find_guarantors_by_loan(&self, loan_account_id: Uuid) -> BankingResult<Vec<LoanGuarantorModel>>;
+
+    /// Number of active guarantors on a loan
+    async fn count_active_guarantors(&self, loan_account_id: Uuid) -> BankingResult<i64>;
+
+    /// Active guarantees given by a customer or person, with each loan's outstanding principal
+    async fn find_guaranteed_loans(&self, guarantor_id: Uuid) -> BankingResult<Vec<GuaranteedLoanModel>>;
+}
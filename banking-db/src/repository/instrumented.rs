@@ -0,0 +1,684 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::Database;
+use std::collections::HashMap;
+use std::error::Error;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Instant;
+use uuid::Uuid;
+
+use crate::models::audit::{AuditChainVerification, AuditEntityType, AuditLogModel};
+use crate::models::person::{
+    ContactChannel, ContactPreferenceModel, CountryIdxModel, CountryModel, CountrySubdivisionIdxModel, CountrySubdivisionModel,
+    EntityReferenceIdxModel, EntityReferenceModel, LocalityIdxModel, LocalityModel,
+    LocationIdxModel, LocationModel, PersonIdxModel, PersonModel,
+};
+use crate::repository::metrics::{QueryOutcome, RepositoryMetrics, RowCount};
+use crate::repository::{
+    AuditLogRepository, AuditLogResult, BatchRepository, ContactPreferenceRepository,
+    ContactPreferenceResult, CountryRepository, CountryResult, CountrySubdivisionRepository,
+    CountrySubdivisionResult, EntityReferenceRepository, EntityReferenceResult, LocalityRepository,
+    LocalityResult, LocationRepository, LocationResult, PersonRepository, PersonResult,
+    TransactionAware,
+};
+
+/// Wraps a repository and reports every call to a [`RepositoryMetrics`].
+///
+/// It only sees the repository trait, so it times calls the same way whether
+/// the wrapped repository runs on the pool or inside a unit of work.
+pub struct InstrumentedRepository<R: ?Sized> {
+    repository: &'static str,
+    inner: Arc<R>,
+    metrics: Arc<dyn RepositoryMetrics>,
+}
+
+impl<R: ?Sized> InstrumentedRepository<R> {
+    pub fn new(repository: &'static str, inner: Arc<R>, metrics: Arc<dyn RepositoryMetrics>) -> Self {
+        Self {
+            repository,
+            inner,
+            metrics,
+        }
+    }
+
+    /// The wrapped repository, for wiring that needs the concrete type
+    pub fn inner(&self) -> &Arc<R> {
+        &self.inner
+    }
+
+    async fn observe<T, E, F>(&self, method: &'static str, call: F) -> Result<T, E>
+    where
+        T: RowCount,
+        F: Future<Output = Result<T, E>>,
+    {
+        if !self.metrics.is_enabled() {
+            return call.await;
+        }
+        let started = Instant::now();
+        let result = call.await;
+        let (rows, outcome) = match &result {
+            Ok(value) => (value.row_count(), QueryOutcome::Ok),
+            Err(_) => (0, QueryOutcome::Error),
+        };
+        self.metrics
+            .record_query(self.repository, method, started.elapsed(), rows, outcome);
+        result
+    }
+}
+
+/// Transaction hooks are not repository calls and go to the wrapped repository untimed
+#[async_trait]
+impl<R: TransactionAware + ?Sized> TransactionAware for InstrumentedRepository<R> {
+    async fn on_commit(&self) -> BankingResult<()> {
+        self.inner.on_commit().await
+    }
+
+    async fn on_rollback(&self) -> BankingResult<()> {
+        self.inner.on_rollback().await
+    }
+
+    async fn on_savepoint(&self, name: &str) -> BankingResult<()> {
+        self.inner.on_savepoint(name).await
+    }
+
+    async fn on_rollback_to(&self, name: &str) -> BankingResult<()> {
+        self.inner.on_rollback_to(name).await
+    }
+
+    async fn on_release(&self, name: &str) -> BankingResult<()> {
+        self.inner.on_release(name).await
+    }
+}
+
+#[async_trait]
+impl<DB: Database, T: Send + 'static, R: BatchRepository<DB, T> + ?Sized> BatchRepository<DB, T>
+    for InstrumentedRepository<R>
+{
+    async fn create_batch(
+        &self,
+        items: Vec<T>,
+        audit_log_id: Uuid,
+    ) -> Result<Vec<T>, Box<dyn Error + Send + Sync>> {
+        self.observe("create_batch", self.inner.create_batch(items, audit_log_id))
+            .await
+    }
+
+    async fn load_batch(&self, ids: &[Uuid]) -> Result<Vec<Option<T>>, Box<dyn Error + Send + Sync>> {
+        self.observe("load_batch", self.inner.load_batch(ids)).await
+    }
+
+    async fn update_batch(
+        &self,
+        items: Vec<T>,
+        audit_log_id: Uuid,
+    ) -> Result<Vec<T>, Box<dyn Error + Send + Sync>> {
+        self.observe("update_batch", self.inner.update_batch(items, audit_log_id))
+            .await
+    }
+
+    async fn delete_batch(
+        &self,
+        ids: &[Uuid],
+        audit_log_id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        self.observe("delete_batch", self.inner.delete_batch(ids, audit_log_id))
+            .await
+    }
+}
+
+#[async_trait]
+impl<DB: Database, R: AuditLogRepository<DB> + ?Sized> AuditLogRepository<DB>
+    for InstrumentedRepository<R>
+{
+    async fn create(&self, audit_log: &AuditLogModel) -> AuditLogResult<AuditLogModel> {
+        self.observe("create", self.inner.create(audit_log)).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AuditLogResult<Option<AuditLogModel>> {
+        self.observe("find_by_id", self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_entity(
+        &self,
+        entity_type: AuditEntityType,
+        entity_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        page: i32,
+        page_size: i32,
+    ) -> AuditLogResult<Vec<AuditLogModel>> {
+        self.observe(
+            "find_by_entity",
+            self.inner
+                .find_by_entity(entity_type, entity_id, from, to, page, page_size),
+        )
+        .await
+    }
+
+    async fn find_by_actor(
+        &self,
+        person_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        page: i32,
+        page_size: i32,
+    ) -> AuditLogResult<Vec<AuditLogModel>> {
+        self.observe(
+            "find_by_actor",
+            self.inner.find_by_actor(person_id, from, to, page, page_size),
+        )
+        .await
+    }
+
+    async fn find_chain_segment(
+        &self,
+        from_id: Uuid,
+        to_id: Uuid,
+    ) -> AuditLogResult<Vec<AuditLogModel>> {
+        self.observe("find_chain_segment", self.inner.find_chain_segment(from_id, to_id))
+            .await
+    }
+
+    async fn verify_chain(&self, from_id: Uuid, to_id: Uuid) -> AuditLogResult<AuditChainVerification> {
+        self.observe("verify_chain", self.inner.verify_chain(from_id, to_id))
+            .await
+    }
+}
+
+#[async_trait]
+impl<DB: Database, R: CountryRepository<DB> + ?Sized> CountryRepository<DB>
+    for InstrumentedRepository<R>
+{
+    async fn save(&self, country: CountryModel) -> CountryResult<CountryModel> {
+        self.observe("save", self.inner.save(country)).await
+    }
+
+    async fn load(&self, id: Uuid) -> CountryResult<CountryModel> {
+        self.observe("load", self.inner.load(id)).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> CountryResult<Option<CountryIdxModel>> {
+        self.observe("find_by_id", self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_iso2(
+        &self,
+        iso2: &str,
+        page: i32,
+        page_size: i32,
+    ) -> CountryResult<Vec<CountryIdxModel>> {
+        self.observe("find_by_iso2", self.inner.find_by_iso2(iso2, page, page_size))
+            .await
+    }
+
+    async fn find_by_ids(&self, ids: &[Uuid]) -> CountryResult<Vec<CountryIdxModel>> {
+        self.observe("find_by_ids", self.inner.find_by_ids(ids)).await
+    }
+
+    async fn exists_by_id(&self, id: Uuid) -> CountryResult<bool> {
+        self.observe("exists_by_id", self.inner.exists_by_id(id)).await
+    }
+
+    async fn find_ids_by_iso2(&self, iso2: &str) -> CountryResult<Vec<Uuid>> {
+        self.observe("find_ids_by_iso2", self.inner.find_ids_by_iso2(iso2))
+            .await
+    }
+
+    async fn exist_by_ids(&self, ids: &[Uuid]) -> CountryResult<Vec<(Uuid, bool)>> {
+        self.observe("exist_by_ids", self.inner.exist_by_ids(ids)).await
+    }
+
+    async fn find_by_name_prefix(
+        &self,
+        prefix: &str,
+        limit: i32,
+    ) -> CountryResult<Vec<CountryModel>> {
+        self.observe("find_by_name_prefix", self.inner.find_by_name_prefix(prefix, limit))
+            .await
+    }
+}
+
+#[async_trait]
+impl<DB: Database, R: CountrySubdivisionRepository<DB> + ?Sized> CountrySubdivisionRepository<DB>
+    for InstrumentedRepository<R>
+{
+    async fn save(
+        &self,
+        country_subdivision: CountrySubdivisionModel,
+    ) -> CountrySubdivisionResult<CountrySubdivisionModel> {
+        self.observe("save", self.inner.save(country_subdivision)).await
+    }
+
+    async fn load(&self, id: Uuid) -> CountrySubdivisionResult<CountrySubdivisionModel> {
+        self.observe("load", self.inner.load(id)).await
+    }
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+    ) -> CountrySubdivisionResult<Option<CountrySubdivisionIdxModel>> {
+        self.observe("find_by_id", self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_country_id(
+        &self,
+        country_id: Uuid,
+        page: i32,
+        page_size: i32,
+    ) -> CountrySubdivisionResult<Vec<CountrySubdivisionIdxModel>> {
+        self.observe(
+            "find_by_country_id",
+            self.inner.find_by_country_id(country_id, page, page_size),
+        )
+        .await
+    }
+
+    async fn find_by_code(
+        &self,
+        country_id: Uuid,
+        code: &str,
+    ) -> CountrySubdivisionResult<Option<CountrySubdivisionIdxModel>> {
+        self.observe("find_by_code", self.inner.find_by_code(country_id, code))
+            .await
+    }
+
+    async fn find_by_ids(
+        &self,
+        ids: &[Uuid],
+    ) -> CountrySubdivisionResult<Vec<CountrySubdivisionIdxModel>> {
+        self.observe("find_by_ids", self.inner.find_by_ids(ids)).await
+    }
+
+    async fn exists_by_id(&self, id: Uuid) -> CountrySubdivisionResult<bool> {
+        self.observe("exists_by_id", self.inner.exists_by_id(id)).await
+    }
+
+    async fn find_ids_by_country_id(&self, country_id: Uuid) -> CountrySubdivisionResult<Vec<Uuid>> {
+        self.observe("find_ids_by_country_id", self.inner.find_ids_by_country_id(country_id))
+            .await
+    }
+}
+
+#[async_trait]
+impl<DB: Database, R: LocalityRepository<DB> + ?Sized> LocalityRepository<DB>
+    for InstrumentedRepository<R>
+{
+    async fn save(&self, locality: LocalityModel) -> LocalityResult<LocalityModel> {
+        self.observe("save", self.inner.save(locality)).await
+    }
+
+    async fn load(&self, id: Uuid) -> LocalityResult<LocalityModel> {
+        self.observe("load", self.inner.load(id)).await
+    }
+
+    async fn load_including_deactivated(&self, id: Uuid) -> LocalityResult<LocalityModel> {
+        self.observe(
+            "load_including_deactivated",
+            self.inner.load_including_deactivated(id),
+        )
+        .await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> LocalityResult<Option<LocalityIdxModel>> {
+        self.observe("find_by_id", self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_country_subdivision_id(
+        &self,
+        country_subdivision_id: Uuid,
+        page: i32,
+        page_size: i32,
+    ) -> LocalityResult<Vec<LocalityIdxModel>> {
+        self.observe(
+            "find_by_country_subdivision_id",
+            self.inner
+                .find_by_country_subdivision_id(country_subdivision_id, page, page_size),
+        )
+        .await
+    }
+
+    async fn find_by_code(
+        &self,
+        country_id: Uuid,
+        code: &str,
+    ) -> LocalityResult<Option<LocalityIdxModel>> {
+        self.observe("find_by_code", self.inner.find_by_code(country_id, code))
+            .await
+    }
+
+    async fn find_by_ids(&self, ids: &[Uuid]) -> LocalityResult<Vec<LocalityIdxModel>> {
+        self.observe("find_by_ids", self.inner.find_by_ids(ids)).await
+    }
+
+    async fn exists_by_id(&self, id: Uuid) -> LocalityResult<bool> {
+        self.observe("exists_by_id", self.inner.exists_by_id(id)).await
+    }
+
+    async fn exist_by_ids(&self, ids: &[Uuid]) -> LocalityResult<Vec<bool>> {
+        self.observe("exist_by_ids", self.inner.exist_by_ids(ids)).await
+    }
+
+    async fn find_ids_by_country_subdivision_id(
+        &self,
+        country_subdivision_id: Uuid,
+    ) -> LocalityResult<Vec<Uuid>> {
+        self.observe(
+            "find_ids_by_country_subdivision_id",
+            self.inner
+                .find_ids_by_country_subdivision_id(country_subdivision_id),
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl<DB: Database, R: LocationRepository<DB> + ?Sized> LocationRepository<DB>
+    for InstrumentedRepository<R>
+{
+    async fn save(&self, location: LocationModel, audit_log_id: Uuid) -> LocationResult<LocationModel> {
+        self.observe("save", self.inner.save(location, audit_log_id)).await
+    }
+
+    async fn load(&self, id: Uuid) -> LocationResult<LocationModel> {
+        self.observe("load", self.inner.load(id)).await
+    }
+
+    async fn load_including_deactivated(&self, id: Uuid) -> LocationResult<LocationModel> {
+        self.observe(
+            "load_including_deactivated",
+            self.inner.load_including_deactivated(id),
+        )
+        .await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> LocationResult<Option<LocationIdxModel>> {
+        self.observe("find_by_id", self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_ids(&self, ids: &[Uuid]) -> LocationResult<Vec<LocationIdxModel>> {
+        self.observe("find_by_ids", self.inner.find_by_ids(ids)).await
+    }
+
+    async fn find_by_locality_id(
+        &self,
+        locality_id: Uuid,
+        page: i32,
+        page_size: i32,
+    ) -> LocationResult<Vec<LocationIdxModel>> {
+        self.observe(
+            "find_by_locality_id",
+            self.inner.find_by_locality_id(locality_id, page, page_size),
+        )
+        .await
+    }
+
+    async fn exists_by_id(&self, id: Uuid) -> LocationResult<bool> {
+        self.observe("exists_by_id", self.inner.exists_by_id(id)).await
+    }
+
+    async fn find_ids_by_locality_id(&self, locality_id: Uuid) -> LocationResult<Vec<Uuid>> {
+        self.observe("find_ids_by_locality_id", self.inner.find_ids_by_locality_id(locality_id))
+            .await
+    }
+
+    async fn exist_by_ids(&self, ids: &[Uuid]) -> LocationResult<Vec<(Uuid, bool)>> {
+        self.observe("exist_by_ids", self.inner.exist_by_ids(ids)).await
+    }
+
+    async fn find_within_bounding_box(
+        &self,
+        min_latitude: Decimal,
+        min_longitude: Decimal,
+        max_latitude: Decimal,
+        max_longitude: Decimal,
+        page: i32,
+        page_size: i32,
+    ) -> LocationResult<Vec<LocationModel>> {
+        self.observe(
+            "find_within_bounding_box",
+            self.inner.find_within_bounding_box(
+                min_latitude,
+                min_longitude,
+                max_latitude,
+                max_longitude,
+                page,
+                page_size,
+            ),
+        )
+        .await
+    }
+
+    async fn find_nearest(
+        &self,
+        latitude: Decimal,
+        longitude: Decimal,
+        limit: i32,
+    ) -> LocationResult<Vec<LocationModel>> {
+        self.observe("find_nearest", self.inner.find_nearest(latitude, longitude, limit))
+            .await
+    }
+}
+
+#[async_trait]
+impl<DB: Database, R: PersonRepository<DB> + ?Sized> PersonRepository<DB>
+    for InstrumentedRepository<R>
+{
+    async fn save(&self, person: PersonModel, audit_log_id: Uuid) -> PersonResult<PersonModel> {
+        self.observe("save", self.inner.save(person, audit_log_id)).await
+    }
+
+    async fn load(&self, id: Uuid) -> PersonResult<PersonModel> {
+        self.observe("load", self.inner.load(id)).await
+    }
+
+    async fn load_including_deactivated(&self, id: Uuid) -> PersonResult<PersonModel> {
+        self.observe(
+            "load_including_deactivated",
+            self.inner.load_including_deactivated(id),
+        )
+        .await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> PersonResult<Option<PersonIdxModel>> {
+        self.observe("find_by_id", self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_ids(&self, ids: &[Uuid]) -> PersonResult<Vec<PersonIdxModel>> {
+        self.observe("find_by_ids", self.inner.find_by_ids(ids)).await
+    }
+
+    async fn exists_by_id(&self, id: Uuid) -> PersonResult<bool> {
+        self.observe("exists_by_id", self.inner.exists_by_id(id)).await
+    }
+
+    async fn exist_by_ids(&self, ids: &[Uuid]) -> PersonResult<Vec<(Uuid, bool)>> {
+        self.observe("exist_by_ids", self.inner.exist_by_ids(ids)).await
+    }
+
+    async fn get_ids_by_external_identifier(&self, identifier: &str) -> PersonResult<Vec<Uuid>> {
+        self.observe(
+            "get_ids_by_external_identifier",
+            self.inner.get_ids_by_external_identifier(identifier),
+        )
+        .await
+    }
+
+    async fn get_by_external_identifier(
+        &self,
+        identifier: &str,
+    ) -> PersonResult<Vec<PersonIdxModel>> {
+        self.observe(
+            "get_by_external_identifier",
+            self.inner.get_by_external_identifier(identifier),
+        )
+        .await
+    }
+
+    async fn find_by_duplicate_of_person_id(
+        &self,
+        person_id: Uuid,
+    ) -> PersonResult<Vec<PersonIdxModel>> {
+        self.observe(
+            "find_by_duplicate_of_person_id",
+            self.inner.find_by_duplicate_of_person_id(person_id),
+        )
+        .await
+    }
+
+    async fn find_by_organization_person_id(
+        &self,
+        person_id: Uuid,
+    ) -> PersonResult<Vec<PersonIdxModel>> {
+        self.observe(
+            "find_by_organization_person_id",
+            self.inner.find_by_organization_person_id(person_id),
+        )
+        .await
+    }
+
+    async fn find_duplicate_candidates(
+        &self,
+        person_id: Uuid,
+        external_identifier: Option<&str>,
+        normalized_name: &str,
+        messaging_values: &[String],
+    ) -> PersonResult<Vec<PersonModel>> {
+        self.observe(
+            "find_duplicate_candidates",
+            self.inner.find_duplicate_candidates(
+                person_id,
+                external_identifier,
+                normalized_name,
+                messaging_values,
+            ),
+        )
+        .await
+    }
+
+    async fn find_ids_created_since(&self, since: DateTime<Utc>) -> PersonResult<Vec<Uuid>> {
+        self.observe("find_ids_created_since", self.inner.find_ids_created_since(since))
+            .await
+    }
+}
+
+#[async_trait]
+impl<DB: Database, R: EntityReferenceRepository<DB> + ?Sized> EntityReferenceRepository<DB>
+    for InstrumentedRepository<R>
+{
+    async fn save(
+        &self,
+        entity_ref: EntityReferenceModel,
+        audit_log_id: Uuid,
+    ) -> EntityReferenceResult<EntityReferenceModel> {
+        self.observe("save", self.inner.save(entity_ref, audit_log_id)).await
+    }
+
+    async fn load(&self, id: Uuid) -> EntityReferenceResult<EntityReferenceModel> {
+        self.observe("load", self.inner.load(id)).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> EntityReferenceResult<Option<EntityReferenceIdxModel>> {
+        self.observe("find_by_id", self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_person_id(
+        &self,
+        person_id: Uuid,
+        page: i32,
+        page_size: i32,
+    ) -> EntityReferenceResult<Vec<EntityReferenceIdxModel>> {
+        self.observe(
+            "find_by_person_id",
+            self.inner.find_by_person_id(person_id, page, page_size),
+        )
+        .await
+    }
+
+    async fn find_by_reference_external_id(
+        &self,
+        reference_external_id: &str,
+        page: i32,
+        page_size: i32,
+    ) -> EntityReferenceResult<Vec<EntityReferenceIdxModel>> {
+        self.observe(
+            "find_by_reference_external_id",
+            self.inner
+                .find_by_reference_external_id(reference_external_id, page, page_size),
+        )
+        .await
+    }
+
+    async fn find_by_reference_external_ids(
+        &self,
+        external_ids: &[&str],
+        page: i32,
+        page_size: i32,
+    ) -> EntityReferenceResult<HashMap<String, Vec<EntityReferenceIdxModel>>> {
+        self.observe(
+            "find_by_reference_external_ids",
+            self.inner
+                .find_by_reference_external_ids(external_ids, page, page_size),
+        )
+        .await
+    }
+
+    async fn find_by_ids(&self, ids: &[Uuid]) -> EntityReferenceResult<Vec<EntityReferenceIdxModel>> {
+        self.observe("find_by_ids", self.inner.find_by_ids(ids)).await
+    }
+
+    async fn exists_by_id(&self, id: Uuid) -> EntityReferenceResult<bool> {
+        self.observe("exists_by_id", self.inner.exists_by_id(id)).await
+    }
+
+    async fn find_ids_by_person_id(&self, person_id: Uuid) -> EntityReferenceResult<Vec<Uuid>> {
+        self.observe("find_ids_by_person_id", self.inner.find_ids_by_person_id(person_id))
+            .await
+    }
+
+    async fn exist_by_ids(&self, ids: &[Uuid]) -> EntityReferenceResult<Vec<(Uuid, bool)>> {
+        self.observe("exist_by_ids", self.inner.exist_by_ids(ids)).await
+    }
+}
+
+#[async_trait]
+impl<DB: Database, R: ContactPreferenceRepository<DB> + ?Sized> ContactPreferenceRepository<DB>
+    for InstrumentedRepository<R>
+{
+    async fn save(
+        &self,
+        preference: ContactPreferenceModel,
+    ) -> ContactPreferenceResult<ContactPreferenceModel> {
+        self.observe("save", self.inner.save(preference)).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> ContactPreferenceResult<Option<ContactPreferenceModel>> {
+        self.observe("find_by_id", self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_person_id(
+        &self,
+        person_id: Uuid,
+    ) -> ContactPreferenceResult<Vec<ContactPreferenceModel>> {
+        self.observe("find_by_person_id", self.inner.find_by_person_id(person_id))
+            .await
+    }
+
+    async fn find_by_person_and_channel(
+        &self,
+        person_id: Uuid,
+        channel: ContactChannel,
+    ) -> ContactPreferenceResult<Option<ContactPreferenceModel>> {
+        self.observe(
+            "find_by_person_and_channel",
+            self.inner.find_by_person_and_channel(person_id, channel),
+        )
+        .await
+    }
+
+    async fn delete(&self, id: Uuid) -> ContactPreferenceResult<()> {
+        self.observe("delete", self.inner.delete(id)).await
+    }
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+use crate::models::{DbNoteEntityKind, InteractionNoteModel};
+
+/// Notes are never updated or deleted; only the follow-up can be closed
+#[async_trait]
+pub trait InteractionNoteRepository: Send + Sync {
+    async fn create_note(&self, note: InteractionNoteModel) -> BankingResult<InteractionNoteModel>;
+    async fn find_note_by_id(&self, note_id: Uuid) -> BankingResult<Option<InteractionNoteModel>>;
+    /// Every note on the entity regardless of visibility, newest first
+    async fn find_notes_by_entity(&self, entity_kind: DbNoteEntityKind, entity_id: Uuid) -> BankingResult<Vec<InteractionNoteModel>>;
+    /// Open follow-ups of the author, earliest due first
+    async fn find_pending_follow_ups_by_author(&self, author_person_id: Uuid) -> BankingResult<Vec<InteractionNoteModel>>;
+    /// Open follow-ups due on or before `date`, by author then due date
+    async fn find_follow_ups_due(&self, date: NaiveDate) -> BankingResult<Vec<InteractionNoteModel>>;
+    async fn complete_follow_up(&self, note_id: Uuid, completed_at: DateTime<Utc>) -> BankingResult<InteractionNoteModel>;
+}
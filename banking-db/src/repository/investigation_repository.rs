@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::{DbTimelineSource, TimelineAccessRecordModel, TimelineCursorModel, TimelineEventModel};
+
+#[async_trait]
+pub trait InvestigationRepository: Send + Sync {
+    async fn account_exists(&self, account_id: Uuid) -> BankingResult<bool>;
+
+    /// Up to `limit` rows of the source in [from, to), ordered by time, category
+    /// name and reference id, starting after `after`
+    async fn find_timeline_page(
+        &self,
+        source: DbTimelineSource,
+        account_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        after: Option<TimelineCursorModel>,
+        limit: i64,
+    ) -> BankingResult<Vec<TimelineEventModel>>;
+
+    async fn count_transactions(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> BankingResult<i64>;
+
+    async fn record_access(&self, record: TimelineAccessRecordModel) -> BankingResult<TimelineAccessRecordModel>;
+    /// Newest first
+    async fn find_access_by_account(&self, account_id: Uuid) -> BankingResult<Vec<TimelineAccessRecordModel>>;
+}
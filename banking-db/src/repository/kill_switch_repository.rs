@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::KillSwitchModel;
+
+#[async_trait]
+pub trait KillSwitchRepository: Send + Sync {
+    async fn create_kill_switch(&self, kill_switch: KillSwitchModel) -> BankingResult<KillSwitchModel>;
+    async fn update_kill_switch(&self, kill_switch: KillSwitchModel) -> BankingResult<KillSwitchModel>;
+    async fn find_kill_switch_by_id(&self, kill_switch_id: Uuid) -> BankingResult<Option<KillSwitchModel>>;
+    /// Switches in the Active state, including those past their expiry not yet recorded
+    async fn find_active_kill_switches(&self) -> BankingResult<Vec<KillSwitchModel>>;
+    /// Switches activated from `from` to `to` inclusive, oldest first
+    async fn find_kill_switches_activated_between(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> BankingResult<Vec<KillSwitchModel>>;
+}
@@ -0,0 +1,197 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::models::audit::{AuditChainVerification, AuditLogModel};
+use crate::models::person::{
+    ContactPreferenceModel, CountryModel, CountrySubdivisionModel, EntityReferenceModel, LocalityModel,
+    LocationModel, PersonModel,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QueryOutcome {
+    Ok,
+    Error,
+}
+
+/// Receives one call per repository method invocation
+pub trait RepositoryMetrics: Send + Sync {
+    fn record_query(
+        &self,
+        repository: &'static str,
+        method: &'static str,
+        duration: Duration,
+        rows: u64,
+        outcome: QueryOutcome,
+    );
+
+    /// Lets instrumented repositories skip timing altogether
+    fn is_enabled(&self) -> bool {
+        true
+    }
+
+    /// Aggregates recorded so far, for implementations that keep them in-process
+    fn metrics_snapshot(&self) -> Option<RepositoryMetricsSnapshot> {
+        None
+    }
+}
+
+/// Installed when metrics are not wanted
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopRepositoryMetrics;
+
+impl RepositoryMetrics for NoopRepositoryMetrics {
+    fn record_query(&self, _: &'static str, _: &'static str, _: Duration, _: u64, _: QueryOutcome) {}
+
+    fn is_enabled(&self) -> bool {
+        false
+    }
+}
+
+/// Records returned by a repository method, as reported to [`RepositoryMetrics`]
+pub trait RowCount {
+    fn row_count(&self) -> u64;
+}
+
+impl<T> RowCount for Vec<T> {
+    fn row_count(&self) -> u64 {
+        self.len() as u64
+    }
+}
+
+impl<T> RowCount for Option<T> {
+    fn row_count(&self) -> u64 {
+        self.is_some() as u64
+    }
+}
+
+impl<K, V> RowCount for HashMap<K, V> {
+    fn row_count(&self) -> u64 {
+        self.len() as u64
+    }
+}
+
+impl RowCount for bool {
+    fn row_count(&self) -> u64 {
+        *self as u64
+    }
+}
+
+/// Rows affected, as returned by batch deletes
+impl RowCount for usize {
+    fn row_count(&self) -> u64 {
+        *self as u64
+    }
+}
+
+impl RowCount for () {
+    fn row_count(&self) -> u64 {
+        0
+    }
+}
+
+macro_rules! single_row {
+    ($($model:ty),* $(,)?) => {
+        $(impl RowCount for $model {
+            fn row_count(&self) -> u64 {
+                1
+            }
+        })*
+    };
+}
+
+single_row!(
+    AuditLogModel,
+    AuditChainVerification,
+    ContactPreferenceModel,
+    CountryModel,
+    CountrySubdivisionModel,
+    EntityReferenceModel,
+    LocalityModel,
+    LocationModel,
+    PersonModel,
+);
+
+/// Upper bounds of the latency histogram buckets; a final bucket catches the rest
+pub const LATENCY_BUCKETS_MS: [u64; 8] = [1, 5, 10, 50, 100, 500, 1_000, 5_000];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryStatistics {
+    pub calls: u64,
+    pub errors: u64,
+    pub rows: u64,
+    pub total_duration: Duration,
+    pub max_duration: Duration,
+    /// Call counts per [`LATENCY_BUCKETS_MS`] bound, plus one overflow bucket
+    pub latency_histogram: [u64; LATENCY_BUCKETS_MS.len() + 1],
+}
+
+impl QueryStatistics {
+    fn record(&mut self, duration: Duration, rows: u64, outcome: QueryOutcome) {
+        self.calls += 1;
+        if outcome == QueryOutcome::Error {
+            self.errors += 1;
+        }
+        self.rows += rows;
+        self.total_duration += duration;
+        self.max_duration = self.max_duration.max(duration);
+        let millis = duration.as_millis() as u64;
+        let bucket = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|bound| millis <= *bound)
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+        self.latency_histogram[bucket] += 1;
+    }
+}
+
+/// Statistics keyed by `(repository, method)`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RepositoryMetricsSnapshot {
+    pub queries: BTreeMap<(&'static str, &'static str), QueryStatistics>,
+}
+
+impl RepositoryMetricsSnapshot {
+    pub fn get(&self, repository: &str, method: &str) -> Option<&QueryStatistics> {
+        self.queries
+            .iter()
+            .find(|((r, m), _)| *r == repository && *m == method)
+            .map(|(_, stats)| stats)
+    }
+}
+
+/// Aggregates counts and latency histograms in memory
+#[derive(Debug, Default)]
+pub struct InMemoryRepositoryMetrics {
+    queries: Mutex<BTreeMap<(&'static str, &'static str), QueryStatistics>>,
+}
+
+impl InMemoryRepositoryMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl RepositoryMetrics for InMemoryRepositoryMetrics {
+    fn record_query(
+        &self,
+        repository: &'static str,
+        method: &'static str,
+        duration: Duration,
+        rows: u64,
+        outcome: QueryOutcome,
+    ) {
+        self.queries
+            .lock()
+            .unwrap()
+            .entry((repository, method))
+            .or_default()
+            .record(duration, rows, outcome);
+    }
+
+    fn metrics_snapshot(&self) -> Option<RepositoryMetricsSnapshot> {
+        Some(RepositoryMetricsSnapshot {
+            queries: self.queries.lock().unwrap().clone(),
+        })
+    }
+}
+
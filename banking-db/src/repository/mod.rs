@@ -2,14 +2,18 @@ pub mod audit_repository;
 pub mod unit_of_work;
 pub mod transaction_aware;
 pub mod batch_repository;
+pub mod instrumented;
+pub mod metrics;
 pub mod person;
 // pub mod customer_repository;
 // pub mod account_repository;
+// pub mod account_number_repository;
 // pub mod account_hold_repository;
 // pub mod transaction_repository;
 // pub mod agent_network_repository;
 // pub mod compliance_repository;
 // pub mod workflow_repository;
+// pub mod workflow_escalation_repository;
 // pub mod calendar_repository;
 // pub mod daily_collection_repository;
 // pub mod fee_repository;
@@ -17,17 +21,50 @@ pub mod person;
 // pub mod collateral_repository;
 // pub mod channel_repository;
 // pub mod product_repository;
+// pub mod document_repository;
+// pub mod segment_repository;
+// pub mod guarantor_repository;
+// pub mod quote_repository;
+// pub mod cheque_repository;
+// pub mod statement_repository;
+// pub mod orchestration_repository;
+// pub mod tag_repository;
+// pub mod channel_security_repository;
+// pub mod agent_commission_repository;
+// pub mod document_registry_repository;
+// pub mod branch_cash_repository;
+// pub mod notification_repository;
+// pub mod investigation_repository;
+// pub mod bundle_repository;
+// pub mod financial_position_repository;
+// pub mod exchange_rate_repository;
+// pub mod promotion_repository;
+// pub mod savings_goal_repository;
+// pub mod payee_repository;
+// pub mod back_dated_posting_repository;
+// pub mod account_domicile_repository;
+// pub mod kill_switch_repository;
+// pub mod interaction_note_repository;
+// pub mod verification_repository;
+// pub mod warehouse_export_repository;
+// pub mod standing_order_repository;
+// pub mod general_ledger_repository;
+// pub mod eod_run_repository;
 
 pub use audit_repository::*;
 pub use batch_repository::*;
+pub use instrumented::*;
+pub use metrics::*;
 pub use person::*;
 // pub use customer_repository::*;
 // pub use account_repository::*;
+// pub use account_number_repository::*;
 // pub use account_hold_repository::*;
 // pub use transaction_repository::*;
 // pub use agent_network_repository::*;
 // pub use compliance_repository::*;
 // pub use workflow_repository::*;
+// pub use workflow_escalation_repository::*;
 // pub use calendar_repository::*;
 // pub use fee_repository::*;
 // pub use reason_and_purpose_repository::*;
@@ -35,5 +72,34 @@ pub use person::*;
 // pub use channel_repository::*;
 // pub use daily_collection_repository::*;
 // pub use product_repository::*;
+// pub use document_repository::*;
+// pub use segment_repository::*;
+// pub use guarantor_repository::*;
+// pub use quote_repository::*;
+// pub use cheque_repository::*;
+// pub use statement_repository::*;
+// pub use orchestration_repository::*;
+// pub use tag_repository::*;
+// pub use channel_security_repository::*;
+// pub use agent_commission_repository::*;
+// pub use document_registry_repository::*;
+// pub use branch_cash_repository::*;
+// pub use notification_repository::*;
+// pub use investigation_repository::*;
+// pub use bundle_repository::*;
+// pub use financial_position_repository::*;
+// pub use exchange_rate_repository::*;
+// pub use promotion_repository::*;
+// pub use savings_goal_repository::*;
+// pub use payee_repository::*;
+// pub use back_dated_posting_repository::*;
+// pub use account_domicile_repository::*;
+// pub use kill_switch_repository::*;
+// pub use interaction_note_repository::*;
+// pub use verification_repository::*;
+// pub use warehouse_export_repository::*;
+// pub use standing_order_repository::*;
+// pub use general_ledger_repository::*;
+// pub use eod_run_repository::*;
 pub use unit_of_work::*;
 pub use transaction_aware::*;
\ No newline at end of file
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::models::{MessageTemplateModel, NotificationDuplicateSummaryModel, QueuedNotificationModel};
+
+#[async_trait]
+pub trait NotificationRepository: Send + Sync {
+    /// Queue the notification under its idempotency key. If the key exists,
+    /// counts a suppressed duplicate on it and returns the notification first
+    /// queued under the key instead.
+    async fn queue_notification(&self, notification: QueuedNotificationModel) -> BankingResult<QueuedNotificationModel>;
+    async fn find_notification_by_id(&self, notification_id: Uuid) -> BankingResult<Option<QueuedNotificationModel>>;
+    async fn find_notifications_by_customer(&self, customer_id: Uuid, business_date: NaiveDate) -> BankingResult<Vec<QueuedNotificationModel>>;
+
+    /// Per template counts for keys of the business date still retained
+    async fn summarize_duplicates(&self, business_date: NaiveDate) -> BankingResult<Vec<NotificationDuplicateSummaryModel>>;
+    /// Delete idempotency keys with a business date before `cutoff`; queued notifications are kept
+    async fn delete_keys_before(&self, cutoff: NaiveDate) -> BankingResult<u64>;
+
+    /// Insert the template, or replace the one saved for its code and language
+    async fn save_message_template(&self, template: MessageTemplateModel) -> BankingResult<MessageTemplateModel>;
+    /// Every language of a template code
+    async fn find_message_templates(&self, template_code: &str) -> BankingResult<Vec<MessageTemplateModel>>;
+}
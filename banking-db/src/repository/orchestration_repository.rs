@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use uuid::Uuid;
+
+use crate::models::{DbOrchestrationStepStatus, OrchestrationModel, OrchestrationStepModel};
+
+#[async_trait]
+pub trait OrchestrationRepository: Send + Sync {
+    async fn create_orchestration(&self, orchestration: OrchestrationModel) -> BankingResult<OrchestrationModel>;
+    async fn find_orchestration_by_id(&self, orchestration_id: Uuid) -> BankingResult<Option<OrchestrationModel>>;
+    async fn update_orchestration(&self, orchestration: OrchestrationModel) -> BankingResult<OrchestrationModel>;
+
+    /// Orchestrations not in Completed, Compensated or ManuallyResolved, oldest first
+    async fn find_incomplete_orchestrations(&self) -> BankingResult<Vec<OrchestrationModel>>;
+
+    /// Append a step; its sequence must be unique within the orchestration
+    async fn create_step(&self, step: OrchestrationStepModel) -> BankingResult<OrchestrationStepModel>;
+    /// Steps of an orchestration in execution order
+    async fn find_steps(&self, orchestration_id: Uuid) -> BankingResult<Vec<OrchestrationStepModel>>;
+    async fn update_step_status(
+        &self,
+        step_id: Uuid,
+        status: DbOrchestrationStepStatus,
+        compensated_at: Option<DateTime<Utc>>,
+        last_error: Option<HeaplessString<255>>,
+    ) -> BankingResult<()>;
+}
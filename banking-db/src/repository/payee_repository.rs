@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use uuid::Uuid;
+
+use crate::models::PayeeModel;
+
+#[async_trait]
+pub trait PayeeRepository: Send + Sync {
+    async fn create_payee(&self, payee: PayeeModel) -> BankingResult<PayeeModel>;
+    async fn update_payee(&self, payee: PayeeModel) -> BankingResult<PayeeModel>;
+    async fn find_payee_by_id(&self, payee_id: Uuid) -> BankingResult<Option<PayeeModel>>;
+    async fn find_payees_by_customer(&self, customer_id: Uuid) -> BankingResult<Vec<PayeeModel>>;
+    async fn delete_payee(&self, payee_id: Uuid) -> BankingResult<()>;
+}
@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use sqlx::Database;
+use std::error::Error;
+use std::fmt;
+use uuid::Uuid;
+
+use crate::models::person::{ContactChannel, ContactPreferenceModel};
+
+/// Domain-specific errors for ContactPreference repository operations
+#[derive(Debug)]
+pub enum ContactPreferenceRepositoryError {
+    /// Person not found
+    PersonNotFound(Uuid),
+
+    /// Contact preference not found
+    ContactPreferenceNotFound(Uuid),
+
+    /// Only one bound of the quiet hours was given
+    IncompleteQuietHours(Uuid),
+
+    /// Generic repository error
+    RepositoryError(Box<dyn Error + Send + Sync>),
+}
+
+impl fmt::Display for ContactPreferenceRepositoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PersonNotFound(id) => write!(f, "Person not found: {id}"),
+            Self::ContactPreferenceNotFound(id) => {
+                write!(f, "Contact preference with this ID not found: {id}")
+            }
+            Self::IncompleteQuietHours(id) => {
+                write!(f, "Contact preference {id} needs both a start and an end of quiet hours")
+            }
+            Self::RepositoryError(err) => write!(f, "Repository error: {err}"),
+        }
+    }
+}
+
+impl Error for ContactPreferenceRepositoryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::RepositoryError(err) => Some(err.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Result type using ContactPreferenceRepositoryError
+pub type ContactPreferenceResult<T> = Result<T, ContactPreferenceRepositoryError>;
+
+#[async_trait]
+pub trait ContactPreferenceRepository<DB: Database>: Send + Sync {
+    /// Insert the preference, or replace the one the person has for its channel.
+    /// The returned model keeps the id already stored for the channel.
+    async fn save(
+        &self,
+        preference: ContactPreferenceModel,
+    ) -> ContactPreferenceResult<ContactPreferenceModel>;
+    async fn find_by_id(&self, id: Uuid) -> ContactPreferenceResult<Option<ContactPreferenceModel>>;
+    async fn find_by_person_id(
+        &self,
+        person_id: Uuid,
+    ) -> ContactPreferenceResult<Vec<ContactPreferenceModel>>;
+    async fn find_by_person_and_channel(
+        &self,
+        person_id: Uuid,
+        channel: ContactChannel,
+    ) -> ContactPreferenceResult<Option<ContactPreferenceModel>>;
+    async fn delete(&self, id: Uuid) -> ContactPreferenceResult<()>;
+}
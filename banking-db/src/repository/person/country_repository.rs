@@ -3,7 +3,7 @@ use sqlx::Database;
 use std::error::Error;
 use uuid::Uuid;
 
-use crate::models::person::{CountryIdxModel, CountryModel};
+use crate::models::person::{fold_country_name, CountryIdxModel, CountryModel};
 
 #[derive(Debug)]
 pub enum CountryRepositoryError {
@@ -12,6 +12,7 @@ pub enum CountryRepositoryError {
     ManyCountriesExist(Vec<Uuid>),
     DuplicateCountryISO2(String),
     InvalidCountryISO2(String),
+    InvalidNamePrefix(String),
     RepositoryError(Box<dyn Error + Send + Sync>),
 }
 
@@ -23,6 +24,10 @@ impl std::fmt::Display for CountryRepositoryError {
             Self::ManyCountriesExist(ids) => write!(f, "Countries exist: {ids:?}"),
             Self::DuplicateCountryISO2(iso2) => write!(f, "Duplicate country ISO2: {iso2}"),
             Self::InvalidCountryISO2(iso2) => write!(f, "Invalid country ISO2: {iso2}"),
+            Self::InvalidNamePrefix(prefix) => write!(
+                f,
+                "Country name prefix must have at least {MIN_COUNTRY_NAME_PREFIX_LEN} characters: {prefix:?}"
+            ),
             Self::RepositoryError(e) => write!(f, "Repository error: {e}"),
         }
     }
@@ -32,6 +37,18 @@ impl Error for CountryRepositoryError {}
 
 pub type CountryResult<T> = Result<T, CountryRepositoryError>;
 
+/// Shortest country name prefix accepted by `find_by_name_prefix`, once trimmed
+pub const MIN_COUNTRY_NAME_PREFIX_LEN: usize = 2;
+
+/// Trims and folds a type-ahead prefix, rejecting prefixes too short to be selective
+pub fn normalize_country_name_prefix(prefix: &str) -> CountryResult<String> {
+    let prefix = prefix.trim();
+    if prefix.chars().count() < MIN_COUNTRY_NAME_PREFIX_LEN {
+        return Err(CountryRepositoryError::InvalidNamePrefix(prefix.to_string()));
+    }
+    Ok(fold_country_name(prefix))
+}
+
 #[async_trait]
 pub trait CountryRepository<DB: Database>: Send + Sync {
     async fn save(&self, country: CountryModel) -> CountryResult<CountryModel>;
@@ -47,4 +64,11 @@ pub trait CountryRepository<DB: Database>: Send + Sync {
     async fn exists_by_id(&self, id: Uuid) -> CountryResult<bool>;
     async fn find_ids_by_iso2(&self, iso2: &str) -> CountryResult<Vec<Uuid>>;
     async fn exist_by_ids(&self, ids: &[Uuid]) -> CountryResult<Vec<(Uuid, bool)>>;
+    /// Countries with a name in any language starting with `prefix`,
+    /// ignoring case and accents, ordered by primary name
+    async fn find_by_name_prefix(
+        &self,
+        prefix: &str,
+        limit: i32,
+    ) -> CountryResult<Vec<CountryModel>>;
 }
\ No newline at end of file
@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use sqlx::Database;
 use uuid::Uuid;
 use crate::models::person::{EntityReferenceIdxModel, EntityReferenceModel};
+use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
 
@@ -80,6 +81,15 @@ pub trait EntityReferenceRepository<DB: Database>: Send + Sync {
         page: i32,
         page_size: i32,
     ) -> EntityReferenceResult<Vec<EntityReferenceIdxModel>>;
+    /// Batch form of `find_by_reference_external_id`, paging the references of
+    /// each external id. Keyed by the external ids as given; ids without any
+    /// reference are absent from the map.
+    async fn find_by_reference_external_ids(
+        &self,
+        external_ids: &[&str],
+        page: i32,
+        page_size: i32,
+    ) -> EntityReferenceResult<HashMap<String, Vec<EntityReferenceIdxModel>>>;
     async fn find_by_ids(
         &self,
         ids: &[Uuid],
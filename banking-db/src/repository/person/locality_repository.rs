@@ -61,6 +61,7 @@ pub type LocalityResult<T> = Result<T, LocalityRepositoryError>;
 pub trait LocalityRepository<DB: Database>: Send + Sync {
     async fn save(&self, locality: LocalityModel) -> LocalityResult<LocalityModel>;
     async fn load(&self, id: Uuid) -> LocalityResult<LocalityModel>;
+    async fn load_including_deactivated(&self, id: Uuid) -> LocalityResult<LocalityModel>;
     async fn find_by_id(&self, id: Uuid) -> LocalityResult<Option<LocalityIdxModel>>;
     async fn find_by_country_subdivision_id(
         &self,
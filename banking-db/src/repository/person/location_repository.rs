@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use rust_decimal::Decimal;
 use sqlx::Database;
 use std::error::Error;
 use std::fmt;
@@ -97,6 +98,7 @@ pub trait LocationRepository<DB: Database>: Send + Sync {
         audit_log_id: Uuid,
     ) -> LocationResult<LocationModel>;
     async fn load(&self, id: Uuid) -> LocationResult<LocationModel>;
+    async fn load_including_deactivated(&self, id: Uuid) -> LocationResult<LocationModel>;
     async fn find_by_id(&self, id: Uuid) -> LocationResult<Option<LocationIdxModel>>;
     async fn find_by_ids(&self, ids: &[Uuid]) -> LocationResult<Vec<LocationIdxModel>>;
     async fn find_by_locality_id(
@@ -108,4 +110,21 @@ pub trait LocationRepository<DB: Database>: Send + Sync {
     async fn exists_by_id(&self, id: Uuid) -> LocationResult<bool>;
     async fn find_ids_by_locality_id(&self, locality_id: Uuid) -> LocationResult<Vec<Uuid>>;
     async fn exist_by_ids(&self, ids: &[Uuid]) -> LocationResult<Vec<(Uuid, bool)>>;
+    /// Active locations inside the box, paged from 1; rejects coordinates out of range
+    async fn find_within_bounding_box(
+        &self,
+        min_latitude: Decimal,
+        min_longitude: Decimal,
+        max_latitude: Decimal,
+        max_longitude: Decimal,
+        page: i32,
+        page_size: i32,
+    ) -> LocationResult<Vec<LocationModel>>;
+    /// Active geocoded locations ordered by distance to the point, closest first
+    async fn find_nearest(
+        &self,
+        latitude: Decimal,
+        longitude: Decimal,
+        limit: i32,
+    ) -> LocationResult<Vec<LocationModel>>;
 }
\ No newline at end of file
@@ -1,5 +1,6 @@
 pub mod person_repository;
 pub mod repos;
+pub mod contact_preference_repository;
 pub mod country_repository;
 pub mod country_subdivision_repository;
 pub mod entity_reference_repository;
@@ -8,6 +9,7 @@ pub mod location_repository;
 
 pub use person_repository::*;
 pub use repos::*;
+pub use contact_preference_repository::*;
 pub use country_repository::*;
 pub use country_subdivision_repository::*;
 pub use entity_reference_repository::*;
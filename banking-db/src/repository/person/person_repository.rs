@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::Database;
 use std::error::Error;
 use std::fmt;
@@ -208,6 +209,8 @@ pub type PersonResult<T> = Result<T, PersonRepositoryError>;
 pub trait PersonRepository<DB: Database>: Send + Sync {
     async fn save(&self, person: PersonModel, audit_log_id: Uuid) -> PersonResult<PersonModel>;
     async fn load(&self, id: Uuid) -> PersonResult<PersonModel>;
+    /// Load a record even after delete_batch has deactivated it, for compliance history
+    async fn load_including_deactivated(&self, id: Uuid) -> PersonResult<PersonModel>;
     async fn find_by_id(&self, id: Uuid) -> PersonResult<Option<PersonIdxModel>>;
     async fn find_by_ids(&self, ids: &[Uuid]) -> PersonResult<Vec<PersonIdxModel>>;
     async fn exists_by_id(&self, id: Uuid) -> PersonResult<bool>;
@@ -216,4 +219,15 @@ pub trait PersonRepository<DB: Database>: Send + Sync {
     async fn get_by_external_identifier(&self, identifier: &str) -> PersonResult<Vec<PersonIdxModel>>;
     async fn find_by_duplicate_of_person_id(&self, person_id: Uuid) -> PersonResult<Vec<PersonIdxModel>>;
     async fn find_by_organization_person_id(&self, person_id: Uuid) -> PersonResult<Vec<PersonIdxModel>>;
+    /// Active persons other than `person_id` sharing the external identifier, the normalized
+    /// display name or one of the lowercased messaging values
+    async fn find_duplicate_candidates(
+        &self,
+        person_id: Uuid,
+        external_identifier: Option<&str>,
+        normalized_name: &str,
+        messaging_values: &[String],
+    ) -> PersonResult<Vec<PersonModel>>;
+    /// Active persons whose first version was audited at or after `since`, oldest first
+    async fn find_ids_created_since(&self, since: DateTime<Utc>) -> PersonResult<Vec<Uuid>>;
 }
\ No newline at end of file
@@ -1,13 +1,18 @@
-use crate::repository::{country_repository::CountryRepository, country_subdivision_repository::CountrySubdivisionRepository, entity_reference_repository::EntityReferenceRepository, location_repository::LocationRepository, locality_repository::LocalityRepository, person_repository::PersonRepository};
+use crate::models::person::{CountrySubdivisionModel, LocalityModel};
+use crate::repository::{batch_repository::BatchRepository, contact_preference_repository::ContactPreferenceRepository, country_repository::CountryRepository, country_subdivision_repository::CountrySubdivisionRepository, entity_reference_repository::EntityReferenceRepository, location_repository::LocationRepository, locality_repository::LocalityRepository, person_repository::PersonRepository};
 use sqlx::Database;
 
 pub trait PersonRepos<DB: Database>: Send + Sync {
     type PersonRepo: PersonRepository<DB> + Send + Sync;
     type CountryRepo: CountryRepository<DB> + Send + Sync;
-    type CountrySubdivisionRepo: CountrySubdivisionRepository<DB> + Send + Sync;
-    type LocalityRepo: LocalityRepository<DB> + Send + Sync;
+    type CountrySubdivisionRepo: CountrySubdivisionRepository<DB>
+        + BatchRepository<DB, CountrySubdivisionModel>
+        + Send
+        + Sync;
+    type LocalityRepo: LocalityRepository<DB> + BatchRepository<DB, LocalityModel> + Send + Sync;
     type LocationRepo: LocationRepository<DB> + Send + Sync;
     type EntityReferenceRepo: EntityReferenceRepository<DB> + Send + Sync;
+    type ContactPreferenceRepo: ContactPreferenceRepository<DB> + Send + Sync;
 
     fn persons(&self) -> &Self::PersonRepo;
     fn countries(&self) -> &Self::CountryRepo;
@@ -15,4 +20,5 @@ pub trait PersonRepos<DB: Database>: Send + Sync {
     fn localities(&self) -> &Self::LocalityRepo;
     fn locations(&self) -> &Self::LocationRepo;
     fn entity_references(&self) -> &Self::EntityReferenceRepo;
+    fn contact_preferences(&self) -> &Self::ContactPreferenceRepo;
 }
\ No newline at end of file
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+use crate::models::{AccountSegmentCodeModel, ProductPromotionModel};
+
+#[async_trait]
+pub trait PromotionRepository: Send + Sync {
+    async fn create_promotion(&self, promotion: ProductPromotionModel) -> BankingResult<ProductPromotionModel>;
+    async fn update_promotion(&self, promotion: ProductPromotionModel) -> BankingResult<ProductPromotionModel>;
+    async fn find_promotion_by_id(&self, promotion_id: Uuid) -> BankingResult<Option<ProductPromotionModel>>;
+    async fn find_promotions_by_product(&self, product_id: Uuid) -> BankingResult<Vec<ProductPromotionModel>>;
+    async fn delete_promotion(&self, promotion_id: Uuid) -> BankingResult<()>;
+
+    /// Active promotions covering the date whose budget is not exhausted
+    async fn find_promotions_in_effect(&self, date: NaiveDate) -> BankingResult<Vec<ProductPromotionModel>>;
+
+    /// Current segments of the owners of the given accounts
+    async fn find_account_segment_codes(&self, account_ids: &[Uuid]) -> BankingResult<Vec<AccountSegmentCodeModel>>;
+
+    /// Keeps the first exhaustion time if already set
+    async fn mark_budget_exhausted(&self, promotion_id: Uuid, exhausted_at: DateTime<Utc>) -> BankingResult<()>;
+}
@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::{DbQuoteStatus, QuoteModel};
+
+#[async_trait]
+pub trait QuoteRepository: Send + Sync {
+    async fn create_quote(&self, quote: QuoteModel) -> BankingResult<QuoteModel>;
+    async fn find_quote_by_id(&self, quote_id: Uuid) -> BankingResult<Option<QuoteModel>>;
+    async fn find_quotes_by_customer(&self, customer_id: Uuid) -> BankingResult<Vec<QuoteModel>>;
+
+    /// Move an active quote to a new status. Returns false if the quote was
+    /// no longer active, so a quote is converted at most once.
+    async fn transition_active_quote(
+        &self,
+        quote_id: Uuid,
+        status: DbQuoteStatus,
+        converted_at: Option<DateTime<Utc>>,
+    ) -> BankingResult<bool>;
+
+    /// Mark active quotes past their expiry as expired
+    async fn expire_quotes(&self, as_of: DateTime<Utc>) -> BankingResult<u64>;
+}
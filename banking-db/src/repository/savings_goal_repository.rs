@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use uuid::Uuid;
+
+use crate::models::SavingsGoalModel;
+
+#[async_trait]
+pub trait SavingsGoalRepository: Send + Sync {
+    async fn create_goal(&self, goal: SavingsGoalModel) -> BankingResult<SavingsGoalModel>;
+    async fn update_goal(&self, goal: SavingsGoalModel) -> BankingResult<SavingsGoalModel>;
+    async fn find_goal_by_id(&self, goal_id: Uuid) -> BankingResult<Option<SavingsGoalModel>>;
+    async fn find_goals_by_account(&self, account_id: Uuid) -> BankingResult<Vec<SavingsGoalModel>>;
+    async fn delete_goal(&self, goal_id: Uuid) -> BankingResult<()>;
+}
@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::models::{SegmentMembershipDeltaModel, SegmentMembershipModel, SegmentModel};
+use banking_api::error::BankingResult;
+
+#[async_trait]
+pub trait SegmentRepository: Send + Sync {
+    /// Segment definition operations
+    async fn create_segment(&self, segment: SegmentModel) -> BankingResult<SegmentModel>;
+    async fn update_segment(&self, segment: SegmentModel) -> BankingResult<SegmentModel>;
+    async fn find_segment_by_id(&self, segment_id: Uuid) -> BankingResult<Option<SegmentModel>>;
+    async fn find_segment_by_code(&self, code: &str) -> BankingResult<Option<SegmentModel>>;
+    async fn find_active_segments(&self) -> BankingResult<Vec<SegmentModel>>;
+    async fn deactivate_segment(&self, segment_id: Uuid, updated_by_person_id: Uuid) -> BankingResult<()>;
+    async fn delete_segment(&self, segment_id: Uuid) -> BankingResult<()>;
+
+    /// Evaluate the segment criteria as a set and reconcile stored membership:
+    /// customers newly matching get an open membership dated `evaluated_at`,
+    /// members no longer matching have their membership closed at `evaluated_at`.
+    /// `reference_date` anchors date-relative criteria.
+    async fn refresh_membership(
+        &self,
+        segment: &SegmentModel,
+        reference_date: chrono::NaiveDate,
+        evaluated_at: DateTime<Utc>,
+    ) -> BankingResult<SegmentMembershipDeltaModel>;
+
+    /// Membership queries
+    async fn find_current_members(&self, segment_id: Uuid) -> BankingResult<Vec<SegmentMembershipModel>>;
+    async fn find_membership_history(&self, segment_id: Uuid, customer_id: Uuid) -> BankingResult<Vec<SegmentMembershipModel>>;
+    async fn find_membership_changes_since(&self, segment_id: Uuid, since: DateTime<Utc>) -> BankingResult<SegmentMembershipDeltaModel>;
+    async fn find_segments_for_customer(&self, customer_id: Uuid) -> BankingResult<Vec<SegmentModel>>;
+}
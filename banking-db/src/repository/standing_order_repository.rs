@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::models::StandingOrderModel;
+
+#[async_trait]
+pub trait StandingOrderRepository: Send + Sync {
+    async fn create_standing_order(&self, order: StandingOrderModel) -> BankingResult<StandingOrderModel>;
+    async fn update_standing_order(&self, order: StandingOrderModel) -> BankingResult<StandingOrderModel>;
+    async fn find_standing_order_by_id(&self, order_id: Uuid) -> BankingResult<Option<StandingOrderModel>>;
+    async fn find_standing_orders_by_account(&self, source_account_id: Uuid) -> BankingResult<Vec<StandingOrderModel>>;
+    async fn delete_standing_order(&self, order_id: Uuid) -> BankingResult<()>;
+    /// Active orders with a run date on or before `run_date`, oldest run date first
+    async fn find_due(&self, run_date: NaiveDate) -> BankingResult<Vec<StandingOrderModel>>;
+}
@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::models::{
+    AccountStatementModel, CustomerContactModel, PaperStatementAccountModel, StatementChargeSummaryModel,
+    StatementConsentRecordModel, StatementDeliveryPreferenceModel, StatementEntryModel, StatementIntegrityAlertModel, StatementLineModel,
+    StatementNotificationModel, StatementPrintBatchModel, StatementPrintEntryModel, StatementRecipientModel,
+};
+
+#[async_trait]
+pub trait StatementRepository: Send + Sync {
+    async fn find_preference(&self, account_id: Uuid) -> BankingResult<Option<StatementDeliveryPreferenceModel>>;
+
+    /// Upsert the preference, append its consent record and queue the change
+    /// notification in one transaction
+    async fn save_preference_change(
+        &self,
+        preference: StatementDeliveryPreferenceModel,
+        consent: StatementConsentRecordModel,
+        notification: StatementNotificationModel,
+    ) -> BankingResult<StatementDeliveryPreferenceModel>;
+
+    async fn find_consent_records(&self, account_id: Uuid) -> BankingResult<Vec<StatementConsentRecordModel>>;
+
+    async fn find_customer_contact(&self, customer_id: Uuid, messaging_id: Uuid) -> BankingResult<Option<CustomerContactModel>>;
+
+    /// Accounts open on the cycle date with their primary holder and delivery
+    /// routing; accounts without a preference are routed to paper
+    async fn find_statement_recipients(&self, cycle_date: NaiveDate) -> BankingResult<Vec<StatementRecipientModel>>;
+
+    /// Store a cycle's print extract and statement notifications in one
+    /// transaction. Returns false if the cycle already has a batch.
+    async fn create_cycle_dispatch(
+        &self,
+        batch: StatementPrintBatchModel,
+        entries: Vec<StatementPrintEntryModel>,
+        notifications: Vec<StatementNotificationModel>,
+    ) -> BankingResult<bool>;
+
+    async fn find_print_batch(&self, cycle_date: NaiveDate) -> BankingResult<Option<StatementPrintBatchModel>>;
+    async fn find_print_entries(&self, batch_id: Uuid) -> BankingResult<Vec<StatementPrintEntryModel>>;
+
+    async fn find_paper_statement_accounts(&self) -> BankingResult<Vec<PaperStatementAccountModel>>;
+
+    /// Signed sum of the account's posted transactions valued before the date
+    async fn find_opening_balance(&self, account_id: Uuid, period_start: NaiveDate) -> BankingResult<Decimal>;
+
+    /// Posted transactions valued within the period, in posting order
+    async fn find_statement_entries(
+        &self,
+        account_id: Uuid,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> BankingResult<Vec<StatementEntryModel>>;
+
+    /// Interest accrued and applied fees of the account within the period
+    async fn find_statement_charges(
+        &self,
+        account_id: Uuid,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> BankingResult<StatementChargeSummaryModel>;
+
+    /// Highest statement number stored for the account
+    async fn find_last_statement_number(&self, account_id: Uuid) -> BankingResult<Option<i32>>;
+
+    /// Store a statement and its lines in one transaction
+    async fn create_statement(
+        &self,
+        statement: AccountStatementModel,
+        lines: Vec<StatementLineModel>,
+    ) -> BankingResult<AccountStatementModel>;
+
+    async fn find_statement_by_id(&self, statement_id: Uuid) -> BankingResult<Option<AccountStatementModel>>;
+
+    /// Lines of a statement by line number
+    async fn find_statement_lines(&self, statement_id: Uuid) -> BankingResult<Vec<StatementLineModel>>;
+
+    /// Statement of the account and period that no other statement supersedes
+    async fn find_current_statement(
+        &self,
+        account_id: Uuid,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> BankingResult<Option<AccountStatementModel>>;
+
+    /// All statements of a cycle, superseded ones included
+    async fn find_statements_by_period(&self, period_end: NaiveDate) -> BankingResult<Vec<AccountStatementModel>>;
+
+    async fn create_integrity_alert(&self, alert: StatementIntegrityAlertModel) -> BankingResult<StatementIntegrityAlertModel>;
+}
@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::{BulkTagTargetModel, DbTaggableEntityKind, EntityTagModel, TagModel};
+
+#[async_trait]
+pub trait TagRepository: Send + Sync {
+    /// Tag registry
+    async fn create_tag(&self, tag: TagModel) -> BankingResult<TagModel>;
+    async fn update_tag(&self, tag: TagModel) -> BankingResult<TagModel>;
+    async fn find_tag_by_id(&self, tag_id: Uuid) -> BankingResult<Option<TagModel>>;
+    async fn find_tag_by_code(&self, code: &str) -> BankingResult<Option<TagModel>>;
+    async fn find_active_tags(&self) -> BankingResult<Vec<TagModel>>;
+    async fn deactivate_tag(&self, tag_id: Uuid, updated_by_person_id: Uuid) -> BankingResult<()>;
+
+    /// Whether the customer or account exists
+    async fn entity_exists(&self, entity_kind: DbTaggableEntityKind, entity_id: Uuid) -> BankingResult<bool>;
+
+    /// Attach a tag; returns the existing assignment when the entity already carries it
+    async fn assign_tag(&self, entity_tag: EntityTagModel) -> BankingResult<EntityTagModel>;
+    /// Detach a tag; returns false when the entity did not carry it
+    async fn remove_tag(&self, tag_id: Uuid, entity_kind: DbTaggableEntityKind, entity_id: Uuid) -> BankingResult<bool>;
+    async fn find_tags_for_entity(&self, entity_kind: DbTaggableEntityKind, entity_id: Uuid) -> BankingResult<Vec<TagModel>>;
+
+    /// Ids of the entities matching the target after `after_entity_id`, in id order
+    async fn find_bulk_target_ids(
+        &self,
+        target: &BulkTagTargetModel,
+        after_entity_id: Option<Uuid>,
+        limit: i64,
+    ) -> BankingResult<Vec<Uuid>>;
+
+    /// Attach a tag to the entities in one statement; returns how many were newly tagged
+    async fn assign_tag_bulk(
+        &self,
+        tag_id: Uuid,
+        entity_kind: DbTaggableEntityKind,
+        entity_ids: Vec<Uuid>,
+        assigned_by_person_id: Uuid,
+        assigned_at: DateTime<Utc>,
+    ) -> BankingResult<u64>;
+}
@@ -5,4 +5,20 @@ use banking_api::BankingResult;
 pub trait TransactionAware: Send + Sync {
     async fn on_commit(&self) -> BankingResult<()>;
     async fn on_rollback(&self) -> BankingResult<()>;
-}
\ No newline at end of file
+
+    /// Called after savepoint `name` has been created in the transaction
+    async fn on_savepoint(&self, _name: &str) -> BankingResult<()> {
+        Ok(())
+    }
+
+    /// Called after the transaction has been rolled back to savepoint `name`.
+    /// The savepoint stays open, as in PostgreSQL.
+    async fn on_rollback_to(&self, _name: &str) -> BankingResult<()> {
+        Ok(())
+    }
+
+    /// Called after savepoint `name`, and any savepoint created after it, has been released
+    async fn on_release(&self, _name: &str) -> BankingResult<()> {
+        Ok(())
+    }
+}
@@ -4,14 +4,29 @@ use uuid::Uuid;
 use rust_decimal::Decimal;
 use chrono::{DateTime, Utc, NaiveDate};
 
-use crate::models::{TransactionModel};
+use crate::models::{TransactionModel, TransactionSearchCriteriaModel};
 use crate::models::workflow::{ApprovalWorkflowModel, WorkflowTransactionApprovalModel};
 
 #[async_trait]
 pub trait TransactionRepository: Send + Sync {
-    /// Create a new transaction record
+    /// Create a new transaction record. (channel_id, idempotency_key) is unique in the
+    /// transactions table: when the key was already used nothing is inserted and the
+    /// original transaction is returned, so callers compare ids to detect a replay.
     async fn create(&self, transaction: TransactionModel) -> BankingResult<TransactionModel>;
     
+    /// Record a posted transaction and move the account balances by its amount
+    /// in one database transaction. The account row is locked for the update,
+    /// so concurrent postings to the same account serialize. A debit that would
+    /// take the available balance below the overdraft limit is rejected with
+    /// `BankingError::InsufficientFunds` and nothing is written. A replayed
+    /// idempotency key returns the original transaction without moving the balance.
+    async fn post_transaction(&self, transaction: TransactionModel) -> BankingResult<TransactionModel>;
+
+    /// Post a transaction that was awaiting approval, marking it approved and
+    /// moving the account balances under the same lock and funds check as
+    /// `post_transaction`. Returns None when the transaction is not awaiting approval.
+    async fn post_approved_transaction(&self, transaction_id: Uuid) -> BankingResult<Option<TransactionModel>>;
+    
     /// Update existing transaction record
     async fn update(&self, transaction: TransactionModel) -> BankingResult<TransactionModel>;
     
@@ -23,9 +38,15 @@ pub trait TransactionRepository: Send + Sync {
     
     /// Find transactions by account ID with date range
     async fn find_by_account_date_range(&self, account_id: Uuid, from_date: NaiveDate, to_date: NaiveDate) -> BankingResult<Vec<TransactionModel>>;
+
+    /// Find transactions matching the criteria, most recent first
+    async fn search(&self, criteria: TransactionSearchCriteriaModel) -> BankingResult<Vec<TransactionModel>>;
     
     /// Find transactions by reference number
     async fn find_by_reference(&self, reference_number: &str) -> BankingResult<Option<TransactionModel>>;
+
+    /// Find the transaction submitted on a channel under an idempotency key
+    async fn find_by_idempotency_key(&self, channel_id: &str, idempotency_key: &str) -> BankingResult<Option<TransactionModel>>;
     
     /// Find transactions by external reference
     async fn find_by_external_reference(&self, external_reference: &str) -> BankingResult<Vec<TransactionModel>>;
@@ -63,7 +84,12 @@ pub trait TransactionRepository: Send + Sync {
     /// Calculate daily transaction volume for network
     async fn calculate_daily_volume_by_network(&self, network_id: Uuid, date: NaiveDate) -> BankingResult<Decimal>;
     
-    /// Reverse a transaction
+    /// Post `reversal_transaction`, which carries reversal_of_transaction_id, and
+    /// mark the original Reversed in one database transaction. The original is
+    /// locked first, so of two concurrent reversals one fails with
+    /// `BankingError::TransactionNotReversible`, as does the reversal of anything
+    /// but a posted original. The balances move back under the lock and funds
+    /// check of `post_transaction`; the account's last_activity_date is kept.
     async fn reverse_transaction(&self, original_transaction_id: Uuid, reversal_transaction: TransactionModel) -> BankingResult<TransactionModel>;
     
     /// Find transactions for reconciliation
@@ -88,4 +114,8 @@ pub trait TransactionRepository: Send + Sync {
     async fn count_by_account(&self, account_id: Uuid, from_date: Option<NaiveDate>, to_date: Option<NaiveDate>) -> BankingResult<i64>;
     async fn list(&self, offset: i64, limit: i64) -> BankingResult<Vec<TransactionModel>>;
     async fn count(&self) -> BankingResult<i64>;
+
+    /// Transactions with any of the `flags_mask` degraded flags set, oldest first
+    async fn find_degraded(&self, flags_mask: i32, limit: i64) -> BankingResult<Vec<TransactionModel>>;
+    async fn update_degraded_flags(&self, transaction_id: Uuid, degraded_flags: i32) -> BankingResult<()>;
 }
\ No newline at end of file
@@ -12,6 +12,11 @@ use crate::repository::{
 pub trait UnitOfWork<DB: Database>: Send + Sync {
     type Session: UnitOfWorkSession<DB>;
     async fn begin(&self) -> BankingResult<Self::Session>;
+
+    /// Rebuilds the shared country subdivision and locality Idx caches from the
+    /// database. Committed sessions only add entries, so after bulk updates that
+    /// change codes the caches must be reloaded to drop the stale ones.
+    async fn refresh_geo_idx_caches(&self) -> BankingResult<()>;
 }
 
 #[async_trait]
@@ -23,6 +28,13 @@ pub trait UnitOfWorkSession<DB: Database>: Send + Sync {
     fn person_repos(&self) -> &Self::PersonRepos;
     fn register_transaction_aware(&self, observer: Arc<dyn TransactionAware>);
 
+    /// Creates savepoint `name`; repositories snapshot their transaction-local state
+    async fn savepoint(&self, name: &str) -> BankingResult<()>;
+    /// Undoes everything done since savepoint `name`, which stays open
+    async fn rollback_to(&self, name: &str) -> BankingResult<()>;
+    /// Keeps the work done since savepoint `name` and discards the savepoint
+    async fn release(&self, name: &str) -> BankingResult<()>;
+
     async fn commit(self) -> BankingResult<()>;
     async fn rollback(self) -> BankingResult<()>;
 }
\ No newline at end of file
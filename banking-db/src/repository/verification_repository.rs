@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::{DbVerificationOperationKind, VerificationChallengeModel};
+
+#[async_trait]
+pub trait VerificationRepository: Send + Sync {
+    async fn create_challenge(&self, challenge: VerificationChallengeModel) -> BankingResult<VerificationChallengeModel>;
+    async fn update_challenge(&self, challenge: VerificationChallengeModel) -> BankingResult<VerificationChallengeModel>;
+    async fn find_challenge_by_id(&self, challenge_id: Uuid) -> BankingResult<Option<VerificationChallengeModel>>;
+    /// Challenges of the customer for the operation kind created from `since` on
+    async fn find_challenges_created_since(
+        &self,
+        customer_id: Uuid,
+        operation_kind: DbVerificationOperationKind,
+        since: DateTime<Utc>,
+    ) -> BankingResult<Vec<VerificationChallengeModel>>;
+    /// Most recently verified challenge not yet consumed
+    async fn find_latest_verified_challenge(
+        &self,
+        customer_id: Uuid,
+        operation_kind: DbVerificationOperationKind,
+    ) -> BankingResult<Option<VerificationChallengeModel>>;
+    /// Mark a verified challenge consumed. None when it is no longer in the
+    /// Verified state, e.g. consumed by a concurrent operation.
+    async fn consume_challenge(&self, challenge_id: Uuid, consumed_at: DateTime<Utc>) -> BankingResult<Option<VerificationChallengeModel>>;
+}
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::{DbWarehouseEntity, WarehouseChangeModel, WarehouseExportRunModel, WarehouseTombstoneModel};
+
+/// Change capture for the warehouse export. Windows are `(from, to]` on the
+/// change-tracking timestamp; pages are keyset-ordered by (timestamp, id).
+#[async_trait]
+pub trait WarehouseExportRepository: Send + Sync {
+    /// Rows whose change timestamp lies in the window, after the `after`
+    /// cursor. Without `from` every row up to `to` is returned.
+    async fn find_changed_rows(
+        &self,
+        entity: DbWarehouseEntity,
+        from: Option<DateTime<Utc>>,
+        to: DateTime<Utc>,
+        after: Option<(DateTime<Utc>, Uuid)>,
+        limit: i64,
+    ) -> BankingResult<Vec<WarehouseChangeModel>>;
+
+    /// Tombstones in the window, after the `after` cursor of (deleted_at, tombstone id)
+    async fn find_tombstones(
+        &self,
+        entity: DbWarehouseEntity,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        after: Option<(DateTime<Utc>, Uuid)>,
+        limit: i64,
+    ) -> BankingResult<Vec<WarehouseTombstoneModel>>;
+
+    async fn create_tombstone(&self, tombstone: WarehouseTombstoneModel) -> BankingResult<WarehouseTombstoneModel>;
+
+    async fn create_export_run(&self, run: WarehouseExportRunModel) -> BankingResult<WarehouseExportRunModel>;
+    async fn find_latest_export_run(&self) -> BankingResult<Option<WarehouseExportRunModel>>;
+}
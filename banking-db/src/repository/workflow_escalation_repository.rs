@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use banking_api::BankingResult;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use uuid::Uuid;
+
+use crate::models::WorkflowEscalationModel;
+
+#[async_trait]
+pub trait WorkflowEscalationRepository: Send + Sync {
+    async fn create_escalation(&self, escalation: WorkflowEscalationModel) -> BankingResult<WorkflowEscalationModel>;
+    async fn find_escalation_by_id(&self, escalation_id: Uuid) -> BankingResult<Option<WorkflowEscalationModel>>;
+    /// Every escalation of the workflow, lowest level first
+    async fn find_escalations_by_workflow(&self, workflow_id: Uuid) -> BankingResult<Vec<WorkflowEscalationModel>>;
+    /// Open and acknowledged escalations assigned to the person, oldest first
+    /// @param person_id - References Person.person_id
+    async fn find_open_escalations_by_person(&self, person_id: Uuid) -> BankingResult<Vec<WorkflowEscalationModel>>;
+    /// Open escalations below `max_level` whose action period ended by `reference_time`
+    async fn find_overdue_escalations(&self, reference_time: DateTime<Utc>, max_level: i16) -> BankingResult<Vec<WorkflowEscalationModel>>;
+
+    /// Status transitions apply only from the expected status; None means the
+    /// escalation was actioned or escalated concurrently
+    async fn acknowledge_escalation(&self, escalation_id: Uuid, acknowledged_at: DateTime<Utc>) -> BankingResult<Option<WorkflowEscalationModel>>;
+    async fn resolve_escalation(
+        &self,
+        escalation_id: Uuid,
+        resolved_by_person_id: Uuid,
+        resolution_notes: Option<HeaplessString<500>>,
+        resolved_at: DateTime<Utc>,
+    ) -> BankingResult<Option<WorkflowEscalationModel>>;
+    /// Mark an open escalation as handed to the next level
+    async fn mark_escalated(&self, escalation_id: Uuid, escalated_at: DateTime<Utc>) -> BankingResult<Option<WorkflowEscalationModel>>;
+}
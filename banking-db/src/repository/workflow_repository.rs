@@ -2,8 +2,9 @@ use async_trait::async_trait;
 use banking_api::BankingResult;
 use uuid::Uuid;
 use chrono::{DateTime, Utc, NaiveDate};
+use heapless::String as HeaplessString;
 
-use crate::models::{AccountWorkflowModel, WorkflowStepRecordModel};
+use crate::models::{AccountWorkflowModel, WorkflowStatusModel, WorkflowStepRecordModel, WorkflowTypeModel};
 
 #[async_trait]
 pub trait WorkflowRepository: Send + Sync {
@@ -13,20 +14,33 @@ pub trait WorkflowRepository: Send + Sync {
     async fn find_workflow_by_id(&self, workflow_id: Uuid) -> BankingResult<Option<AccountWorkflowModel>>;
     async fn find_workflows_by_account(&self, account_id: Uuid) -> BankingResult<Vec<AccountWorkflowModel>>;
     async fn find_active_workflow(&self, account_id: Uuid, workflow_type: &str) -> BankingResult<Option<AccountWorkflowModel>>;
+    /// In-progress and pending-action workflows of any of the accounts in one query, newest first
+    async fn find_open_workflows_by_accounts(&self, account_ids: &[Uuid]) -> BankingResult<Vec<AccountWorkflowModel>>;
     async fn find_workflows_by_type(&self, workflow_type: &str) -> BankingResult<Vec<AccountWorkflowModel>>;
     async fn find_workflows_by_status(&self, status: &str) -> BankingResult<Vec<AccountWorkflowModel>>;
     async fn find_workflows_by_initiator(&self, initiated_by: &str) -> BankingResult<Vec<AccountWorkflowModel>>;
+    /// Page of workflows matching every criterion set in `filter`, newest first
+    async fn search_workflows(&self, filter: &WorkflowFilter, page: WorkflowPage) -> BankingResult<WorkflowSearchResult>;
     
     /// Workflow Status Management
     async fn update_workflow_status(&self, workflow_id: Uuid, status: &str, notes: &str) -> BankingResult<()>;
     async fn update_workflow_step(&self, workflow_id: Uuid, current_step: &str) -> BankingResult<()>;
-    async fn advance_workflow_step(&self, workflow_id: Uuid, step: &str, notes: &str) -> BankingResult<()>;
+    /// Move the workflow to `step` and record the step in its history
+    /// @param completed_by - References Person.person_id
+    async fn advance_workflow_step(
+        &self,
+        workflow_id: Uuid,
+        step: &str,
+        completed_by: Uuid,
+        notes: &str,
+        supporting_documents: Vec<HeaplessString<100>>,
+    ) -> BankingResult<()>;
     async fn complete_workflow(&self, workflow_id: Uuid, completion_notes: &str) -> BankingResult<()>;
     async fn fail_workflow(&self, workflow_id: Uuid, failure_reason: &str) -> BankingResult<()>;
     async fn cancel_workflow(&self, workflow_id: Uuid, reason: &str) -> BankingResult<()>;
     
     /// Workflow Step Record Operations
-    async fn add_step_record(&self, step_record: WorkflowStepRecordModel) -> BankingResult<WorkflowStepRecordModel>;
+    async fn add_step_record(&self, workflow_id: Uuid, step_record: WorkflowStepRecordModel) -> BankingResult<WorkflowStepRecordModel>;
     async fn find_step_records_by_workflow(&self, workflow_id: Uuid) -> BankingResult<Vec<WorkflowStepRecordModel>>;
     async fn find_latest_step_record(&self, workflow_id: Uuid) -> BankingResult<Option<WorkflowStepRecordModel>>;
     
@@ -83,6 +97,32 @@ pub trait WorkflowRepository: Send + Sync {
     async fn count_all_workflows(&self) -> BankingResult<i64>;
 }
 
+/// Criteria for `search_workflows`; unset criteria match every workflow
+#[derive(Debug, Clone, Default)]
+pub struct WorkflowFilter {
+    pub status: Option<WorkflowStatusModel>,
+    pub workflow_type: Option<WorkflowTypeModel>,
+    /// References Person.person_id
+    pub initiated_by: Option<Uuid>,
+    pub account_id: Option<Uuid>,
+    /// UTC initiation dates, both bounds included
+    pub initiated_from: Option<NaiveDate>,
+    pub initiated_to: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkflowPage {
+    pub offset: i64,
+    pub limit: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct WorkflowSearchResult {
+    pub workflows: Vec<AccountWorkflowModel>,
+    /// Matching workflows across all pages
+    pub total_count: i64,
+}
+
 /// Supporting structures for workflow reporting
 pub struct WorkflowMetricsReport {
     pub period_start: NaiveDate,
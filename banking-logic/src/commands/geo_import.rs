@@ -0,0 +1,308 @@
+use banking_api::command::person::{
+    GeoDataset, GeoImportReport, GeoLocalityRecord, GeoSubdivisionRecord, ImportGeoDataCommand,
+};
+use banking_api::error::BankingError;
+use banking_api::BankingResult;
+use banking_db::models::person::{CountrySubdivisionModel, LocalityModel};
+use banking_db::repository::{
+    BatchRepository, CountryRepository, CountrySubdivisionRepository, LocalityRepository,
+    PersonRepos, UnitOfWorkSession,
+};
+use heapless::String as HeaplessString;
+use sqlx::Database;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// A subdivision row that passed validation, with its country resolved.
+/// Ids are provisional until the row is matched against the stored data.
+#[derive(Clone)]
+struct PreparedSubdivision {
+    subdivision: CountrySubdivisionModel,
+    localities: Vec<LocalityModel>,
+}
+
+fn internal(err: impl std::fmt::Display) -> BankingError {
+    BankingError::Internal(err.to_string())
+}
+
+fn absorb(report: &mut GeoImportReport, chunk: GeoImportReport) {
+    report.created += chunk.created;
+    report.updated += chunk.updated;
+    report.skipped += chunk.skipped;
+    report.rejected.extend(chunk.rejected);
+}
+
+fn heapless<const N: usize>(value: &str, field: &str) -> Result<HeaplessString<N>, String> {
+    HeaplessString::try_from(value.trim())
+        .map_err(|_| format!("{field} '{value}' exceeds {N} characters"))
+}
+
+fn optional_heapless<const N: usize>(
+    value: &Option<String>,
+    field: &str,
+) -> Result<Option<HeaplessString<N>>, String> {
+    value
+        .as_deref()
+        .filter(|v| !v.trim().is_empty())
+        .map(|v| heapless(v, field))
+        .transpose()
+}
+
+fn to_subdivision_model(
+    record: &GeoSubdivisionRecord,
+    country_id: Uuid,
+) -> Result<CountrySubdivisionModel, String> {
+    Ok(CountrySubdivisionModel {
+        id: Uuid::new_v4(),
+        country_id,
+        code: heapless(&record.code, "code")?,
+        name_l1: heapless(&record.name_l1, "name_l1")?,
+        name_l2: optional_heapless(&record.name_l2, "name_l2")?,
+        name_l3: optional_heapless(&record.name_l3, "name_l3")?,
+    })
+}
+
+fn to_locality_model(record: &GeoLocalityRecord) -> Result<LocalityModel, String> {
+    Ok(LocalityModel {
+        id: Uuid::new_v4(),
+        country_subdivision_id: Uuid::nil(),
+        code: heapless(&record.code, "code")?,
+        name_l1: heapless(&record.name_l1, "name_l1")?,
+        name_l2: optional_heapless(&record.name_l2, "name_l2")?,
+        name_l3: optional_heapless(&record.name_l3, "name_l3")?,
+    })
+}
+
+fn reject_localities(report: &mut GeoImportReport, record: &GeoSubdivisionRecord) {
+    for locality in &record.localities {
+        report.reject(
+            &locality.code,
+            format!("subdivision {} was rejected", record.code),
+        );
+    }
+}
+
+/// Rejects rows that can never be written: unknown countries, codes repeated
+/// within the dataset and values that do not fit the model.
+async fn prepare<DB, S>(
+    session: &S,
+    dataset: &GeoDataset,
+    report: &mut GeoImportReport,
+) -> BankingResult<Vec<PreparedSubdivision>>
+where
+    DB: Database,
+    S: UnitOfWorkSession<DB>,
+{
+    let countries = session.person_repos().countries();
+    let mut country_ids: HashMap<String, Option<Uuid>> = HashMap::new();
+    let mut subdivision_codes = HashSet::new();
+    let mut locality_codes = HashSet::new();
+    let mut prepared = Vec::with_capacity(dataset.subdivisions.len());
+
+    for record in &dataset.subdivisions {
+        if !subdivision_codes.insert(record.code.trim().to_string()) {
+            report.reject(&record.code, "duplicate subdivision code in dataset");
+            reject_localities(report, record);
+            continue;
+        }
+
+        let iso2 = record.country_iso2.trim().to_uppercase();
+        let country_id = match country_ids.get(&iso2) {
+            Some(country_id) => *country_id,
+            None => {
+                let country_id = countries
+                    .find_by_iso2(&iso2, 1, 1)
+                    .await
+                    .map_err(internal)?
+                    .first()
+                    .map(|idx| idx.country_id);
+                country_ids.insert(iso2.clone(), country_id);
+                country_id
+            }
+        };
+        let Some(country_id) = country_id else {
+            report.reject(&record.code, format!("country {iso2} not found"));
+            reject_localities(report, record);
+            continue;
+        };
+
+        let subdivision = match to_subdivision_model(record, country_id) {
+            Ok(subdivision) => subdivision,
+            Err(reason) => {
+                report.reject(&record.code, reason);
+                reject_localities(report, record);
+                continue;
+            }
+        };
+
+        let mut localities = Vec::with_capacity(record.localities.len());
+        for locality in &record.localities {
+            if !locality_codes.insert(locality.code.trim().to_string()) {
+                report.reject(&locality.code, "duplicate locality code in dataset");
+                continue;
+            }
+            match to_locality_model(locality) {
+                Ok(model) => localities.push(model),
+                Err(reason) => report.reject(&locality.code, reason),
+            }
+        }
+
+        prepared.push(PreparedSubdivision {
+            subdivision,
+            localities,
+        });
+    }
+
+    Ok(prepared)
+}
+
+/// Upserts a chunk through the batch repositories, matching stored rows on their codes
+async fn write_chunk<DB, S>(
+    session: &S,
+    chunk: &[PreparedSubdivision],
+    audit_log_id: Uuid,
+) -> BankingResult<GeoImportReport>
+where
+    DB: Database,
+    S: UnitOfWorkSession<DB>,
+{
+    let subdivision_repo = session.person_repos().country_subdivisions();
+    let locality_repo = session.person_repos().localities();
+    let mut counts = GeoImportReport::default();
+    let mut subdivisions_to_create = Vec::new();
+    let mut subdivisions_to_update = Vec::new();
+    let mut localities_to_create = Vec::new();
+    let mut localities_to_update = Vec::new();
+
+    for prepared in chunk {
+        let mut subdivision = prepared.subdivision.clone();
+        match subdivision_repo
+            .find_by_code(subdivision.country_id, subdivision.code.as_str())
+            .await
+            .map_err(internal)?
+        {
+            Some(idx) => {
+                let existing = subdivision_repo
+                    .load(idx.country_subdivision_id)
+                    .await
+                    .map_err(internal)?;
+                subdivision.id = existing.id;
+                if existing.country_id == subdivision.country_id
+                    && existing.name_l1 == subdivision.name_l1
+                    && existing.name_l2 == subdivision.name_l2
+                    && existing.name_l3 == subdivision.name_l3
+                {
+                    counts.skipped += 1;
+                } else {
+                    subdivisions_to_update.push(subdivision.clone());
+                }
+            }
+            None => subdivisions_to_create.push(subdivision.clone()),
+        }
+
+        for locality in &prepared.localities {
+            let mut locality = locality.clone();
+            locality.country_subdivision_id = subdivision.id;
+            match locality_repo
+                .find_by_code(subdivision.country_id, locality.code.as_str())
+                .await
+                .map_err(internal)?
+            {
+                Some(idx) => {
+                    let existing = locality_repo.load(idx.locality_id).await.map_err(internal)?;
+                    locality.id = existing.id;
+                    if existing.country_subdivision_id == locality.country_subdivision_id
+                        && existing.name_l1 == locality.name_l1
+                        && existing.name_l2 == locality.name_l2
+                        && existing.name_l3 == locality.name_l3
+                    {
+                        counts.skipped += 1;
+                    } else {
+                        localities_to_update.push(locality);
+                    }
+                }
+                None => localities_to_create.push(locality),
+            }
+        }
+    }
+
+    counts.created = subdivisions_to_create.len() + localities_to_create.len();
+    counts.updated = subdivisions_to_update.len() + localities_to_update.len();
+
+    subdivision_repo
+        .create_batch(subdivisions_to_create, audit_log_id)
+        .await
+        .map_err(internal)?;
+    subdivision_repo
+        .update_batch(subdivisions_to_update, audit_log_id)
+        .await
+        .map_err(internal)?;
+    locality_repo
+        .create_batch(localities_to_create, audit_log_id)
+        .await
+        .map_err(internal)?;
+    locality_repo
+        .update_batch(localities_to_update, audit_log_id)
+        .await
+        .map_err(internal)?;
+
+    Ok(counts)
+}
+
+/// Runs an `ImportGeoDataCommand` inside `session`.
+///
+/// Each chunk is written under its own savepoint. When a chunk fails it is
+/// rolled back and replayed one subdivision at a time, so only the offending
+/// subdivision and its localities end up rejected. The caller commits the
+/// session and refreshes the shared Idx caches.
+pub async fn import_geo_data<DB, S>(
+    session: &S,
+    command: &ImportGeoDataCommand,
+) -> BankingResult<GeoImportReport>
+where
+    DB: Database,
+    S: UnitOfWorkSession<DB>,
+{
+    let mut report = GeoImportReport::default();
+    let prepared = prepare(session, &command.dataset, &mut report).await?;
+
+    for (index, chunk) in prepared.chunks(command.chunk_size.max(1)).enumerate() {
+        let savepoint = format!("geo_import_{index}");
+        session.savepoint(&savepoint).await?;
+        match write_chunk(session, chunk, command.audit_log_id).await {
+            Ok(counts) => {
+                session.release(&savepoint).await?;
+                absorb(&mut report, counts);
+                continue;
+            }
+            Err(_) => {
+                session.rollback_to(&savepoint).await?;
+                session.release(&savepoint).await?;
+            }
+        }
+
+        for (row, prepared) in chunk.iter().enumerate() {
+            let savepoint = format!("geo_import_{index}_{row}");
+            session.savepoint(&savepoint).await?;
+            match write_chunk(session, std::slice::from_ref(prepared), command.audit_log_id).await {
+                Ok(counts) => {
+                    session.release(&savepoint).await?;
+                    absorb(&mut report, counts);
+                }
+                Err(err) => {
+                    session.rollback_to(&savepoint).await?;
+                    session.release(&savepoint).await?;
+                    report.reject(prepared.subdivision.code.as_str(), err.to_string());
+                    for locality in &prepared.localities {
+                        report.reject(
+                            locality.code.as_str(),
+                            format!("subdivision {} was rejected", prepared.subdivision.code),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(report)
+}
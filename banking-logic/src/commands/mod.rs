@@ -1 +1,5 @@
-pub mod person;
\ No newline at end of file
+pub mod geo_import;
+pub mod person;
+// pub mod orchestration;
+// pub mod offboarding;
+// pub mod repricing;
\ No newline at end of file
@@ -0,0 +1,135 @@
+use async_trait::async_trait;
+use heapless::String as HeaplessString;
+use uuid::Uuid;
+
+use banking_api::{
+    command::Command,
+    domain::{AccountStatus, AccountStatusSnapshot, CompensationAction, MandateStatus, MandateStatusSnapshot, Orchestration, OrchestrationType},
+    BankingError,
+};
+use banking_db::models::{DbAccountStatus, DbMandateStatus};
+use rust_decimal::Decimal;
+
+use super::orchestration::{finish_orchestration, OrchestrationContext};
+use crate::mappers::AccountMapper;
+
+/// Offboards a customer: freezes the owned accounts, revokes the mandates
+/// granted to the customer and moves the zero-balance accounts to pending
+/// closure. A failing step undoes the previous ones.
+pub struct OffboardCustomerCommand {
+    pub customer_id: Uuid,
+    pub reason: HeaplessString<255>,
+    /// References Person.person_id
+    pub initiated_by_person_id: Uuid,
+}
+
+impl OffboardCustomerCommand {
+    #[allow(deprecated)]
+    async fn run_steps(&self, context: &OrchestrationContext, orchestration_id: Uuid) -> Result<(), BankingError> {
+        let mut open_accounts = Vec::new();
+        for ownership in context.account_repository.find_accounts_by_owner(self.customer_id).await? {
+            let account = context.account_repository
+                .find_by_id(ownership.account_id)
+                .await?
+                .ok_or(BankingError::AccountNotFound(ownership.account_id))?;
+            if account.account_status != DbAccountStatus::Closed {
+                open_accounts.push(account);
+            }
+        }
+
+        // Step 1: freeze the owned accounts
+        let to_freeze: Vec<AccountStatusSnapshot> = open_accounts
+            .iter()
+            .filter(|a| a.account_status != DbAccountStatus::Frozen)
+            .map(|a| AccountStatusSnapshot {
+                account_id: a.id,
+                status: AccountMapper::account_status_from_db(a.account_status),
+            })
+            .collect();
+        context.orchestration_service
+            .record_step(
+                orchestration_id,
+                "freeze_accounts",
+                Some(CompensationAction::RestoreAccountStatuses { accounts: to_freeze.clone() }),
+            )
+            .await?;
+        for snapshot in &to_freeze {
+            context.account_repository
+                .update_status_legacy(
+                    snapshot.account_id,
+                    &AccountStatus::Frozen.to_string(),
+                    &self.reason,
+                    self.initiated_by_person_id,
+                )
+                .await?;
+        }
+
+        // Step 2: revoke the mandates granted to the customer
+        let to_revoke: Vec<MandateStatusSnapshot> = context.account_repository
+            .find_mandates_by_grantee(self.customer_id)
+            .await?
+            .into_iter()
+            .filter(|m| m.status == DbMandateStatus::Active || m.status == DbMandateStatus::Suspended)
+            .map(|m| MandateStatusSnapshot {
+                mandate_id: m.id,
+                status: AccountMapper::mandate_status_from_db(m.status),
+            })
+            .collect();
+        context.orchestration_service
+            .record_step(
+                orchestration_id,
+                "revoke_mandates",
+                Some(CompensationAction::RestoreMandateStatuses { mandates: to_revoke.clone() }),
+            )
+            .await?;
+        for snapshot in &to_revoke {
+            context.account_repository
+                .update_mandate_status(snapshot.mandate_id, &MandateStatus::Revoked.to_string())
+                .await?;
+        }
+
+        // Step 3: accounts without balance go to pending closure, the others stay frozen
+        let to_close: Vec<AccountStatusSnapshot> = open_accounts
+            .iter()
+            .filter(|a| a.current_balance == Decimal::ZERO)
+            .map(|a| AccountStatusSnapshot {
+                account_id: a.id,
+                status: AccountStatus::Frozen,
+            })
+            .collect();
+        context.orchestration_service
+            .record_step(
+                orchestration_id,
+                "request_closure",
+                Some(CompensationAction::RestoreAccountStatuses { accounts: to_close.clone() }),
+            )
+            .await?;
+        for snapshot in &to_close {
+            context.account_repository
+                .update_status_legacy(
+                    snapshot.account_id,
+                    &AccountStatus::PendingClosure.to_string(),
+                    &self.reason,
+                    self.initiated_by_person_id,
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Command for OffboardCustomerCommand {
+    type Context = OrchestrationContext;
+    type Result = Orchestration;
+
+    async fn execute(&self, context: &Self::Context) -> Result<Self::Result, BankingError> {
+        let orchestration = context.orchestration_service
+            .start_orchestration(OrchestrationType::CustomerOffboarding, self.customer_id, self.initiated_by_person_id)
+            .await?;
+
+        let outcome = self.run_steps(context, orchestration.id).await;
+        finish_orchestration(context.orchestration_service.as_ref(), orchestration.id, outcome).await
+    }
+}
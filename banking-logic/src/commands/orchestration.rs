@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use banking_api::{domain::Orchestration, service::OrchestrationService, BankingResult};
+use banking_db::repository::{AccountRepository, ProductRepository};
+
+/// Context of the commands that run as compensated orchestrations
+pub struct OrchestrationContext {
+    pub orchestration_service: Arc<dyn OrchestrationService>,
+    pub account_repository: Arc<dyn AccountRepository>,
+    pub product_repository: Arc<dyn ProductRepository>,
+}
+
+/// Completes the orchestration when all steps succeeded. Otherwise runs the
+/// compensations of the registered steps and returns the step error.
+pub async fn finish_orchestration(
+    orchestration_service: &dyn OrchestrationService,
+    orchestration_id: Uuid,
+    outcome: BankingResult<()>,
+) -> BankingResult<Orchestration> {
+    match outcome {
+        Ok(()) => orchestration_service.complete_orchestration(orchestration_id).await,
+        Err(e) => {
+            let orchestration = orchestration_service
+                .fail_orchestration(orchestration_id, &e.to_string())
+                .await?;
+            tracing::warn!(
+                "Orchestration {} failed and ended {:?}: {e}",
+                orchestration.id,
+                orchestration.status
+            );
+            Err(e)
+        }
+    }
+}
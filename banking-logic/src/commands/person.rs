@@ -1,7 +1,9 @@
 use banking_api::command::person::{PersonCommand, PersonCommandExecutor};
-use banking_api::command::{person::Services, Command, CommandResult};
+use banking_api::command::{person::Services, Command, CommandExecutionOptions, CommandResult};
 use banking_api::error::BankingError;
 use banking_db::repository::unit_of_work::{UnitOfWork, UnitOfWorkSession};
+use crate::commands::geo_import::import_geo_data;
+use crate::config::BankingConfig;
 use sqlx::Database;
 use std::any::Any;
 use std::marker::PhantomData;
@@ -13,11 +15,15 @@ use std::sync::Arc;
 /// concrete repository types are known.
 pub trait ServiceFactory<DB: Database, S: UnitOfWorkSession<DB>>: Send + Sync {
     fn build_services(&self, session: &S) -> Services;
+
+    /// Configuration loaded and validated at startup, shared by the services it builds
+    fn banking_config(&self) -> Arc<BankingConfig>;
 }
 
 pub struct CommandExecutorImpl<DB: Database, F, UoW: UnitOfWork<DB>> {
     service_factory: F,
     uow: Arc<UoW>,
+    options: CommandExecutionOptions,
     _marker: PhantomData<DB>,
 }
 
@@ -26,9 +32,35 @@ impl<DB: Database, F, UoW: UnitOfWork<DB>> CommandExecutorImpl<DB, F, UoW> {
         Self {
             service_factory,
             uow,
+            options: CommandExecutionOptions::default(),
             _marker: PhantomData,
         }
     }
+
+    pub fn with_options(mut self, options: CommandExecutionOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+async fn run_command<DB: Database, S: UnitOfWorkSession<DB>>(
+    command: PersonCommand,
+    session: &S,
+    services: &Services,
+) -> Result<CommandResult, BankingError> {
+    match command {
+        PersonCommand::AddPersonOfInterest(cmd) => cmd
+            .execute(services)
+            .await
+            .map(|r| Box::new(r) as Box<dyn Any + Send>),
+        PersonCommand::PopulateGeoData(cmd) => cmd
+            .execute(services)
+            .await
+            .map(|r| Box::new(r) as Box<dyn Any + Send>),
+        PersonCommand::ImportGeoData(cmd) => import_geo_data(session, &cmd)
+            .await
+            .map(|r| Box::new(r) as Box<dyn Any + Send>),
+    }
 }
 
 #[async_trait::async_trait]
@@ -44,21 +76,16 @@ where
     ) -> Result<CommandResult, BankingError> {
         let session = self.uow.begin().await?;
         let services = self.service_factory.build_services(&session);
+        let refresh_geo_caches = matches!(command, PersonCommand::ImportGeoData(_));
 
-        let result = match command {
-            PersonCommand::AddPersonOfInterest(cmd) => cmd
-                .execute(&services)
-                .await
-                .map(|r| Box::new(r) as Box<dyn Any + Send>),
-            PersonCommand::PopulateGeoData(cmd) => cmd
-                .execute(&services)
-                .await
-                .map(|r| Box::new(r) as Box<dyn Any + Send>),
-        };
+        let result = run_command(command, &session, &services).await;
 
         match result {
             Ok(res) => {
                 session.commit().await?;
+                if refresh_geo_caches {
+                    self.uow.refresh_geo_idx_caches().await?;
+                }
                 Ok(res)
             }
             Err(e) => {
@@ -67,4 +94,43 @@ where
             }
         }
     }
-}
\ No newline at end of file
+
+    async fn execute_all(
+        &self,
+        commands: Vec<PersonCommand>,
+    ) -> Result<Vec<Result<CommandResult, BankingError>>, BankingError> {
+        let session = self.uow.begin().await?;
+        let services = self.service_factory.build_services(&session);
+        let refresh_geo_caches = commands
+            .iter()
+            .any(|command| matches!(command, PersonCommand::ImportGeoData(_)));
+
+        let mut results = Vec::with_capacity(commands.len());
+        for (index, command) in commands.into_iter().enumerate() {
+            if self.options.savepoint_per_command {
+                let savepoint = format!("command_{index}");
+                session.savepoint(&savepoint).await?;
+                let result = run_command(command, &session, &services).await;
+                if result.is_err() {
+                    session.rollback_to(&savepoint).await?;
+                }
+                session.release(&savepoint).await?;
+                results.push(result);
+            } else {
+                match run_command(command, &session, &services).await {
+                    Ok(res) => results.push(Ok(res)),
+                    Err(e) => {
+                        session.rollback().await?;
+                        return Err(e);
+                    }
+                }
+            }
+        }
+
+        session.commit().await?;
+        if refresh_geo_caches {
+            self.uow.refresh_geo_idx_caches().await?;
+        }
+        Ok(results)
+    }
+}
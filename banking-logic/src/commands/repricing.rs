@@ -0,0 +1,120 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use banking_api::{
+    command::Command,
+    domain::{CompensationAction, LoanRateSnapshot, Orchestration, OrchestrationType},
+    BankingError,
+};
+use banking_db::models::{DbAccountStatus, DbAccountType};
+
+use super::orchestration::{finish_orchestration, OrchestrationContext};
+
+/// Reprices a product: the overdraft rate on the product and the contractual
+/// rate of its open loan accounts. A failing step undoes the previous ones.
+pub struct RepriceProductCommand {
+    pub product_id: Uuid,
+    pub overdraft_interest_rate: Option<Decimal>,
+    pub loan_interest_rate: Option<Decimal>,
+    /// References Person.person_id
+    pub initiated_by_person_id: Uuid,
+}
+
+impl RepriceProductCommand {
+    fn validate(&self) -> Result<(), BankingError> {
+        if self.overdraft_interest_rate.is_none() && self.loan_interest_rate.is_none() {
+            return Err(BankingError::ValidationError {
+                field: "rates".to_string(),
+                message: "At least one rate must be repriced".to_string(),
+            });
+        }
+        for (field, rate) in [
+            ("overdraft_interest_rate", self.overdraft_interest_rate),
+            ("loan_interest_rate", self.loan_interest_rate),
+        ] {
+            if rate.is_some_and(|r| r < Decimal::ZERO) {
+                return Err(BankingError::ValidationError {
+                    field: field.to_string(),
+                    message: "Rate cannot be negative".to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    async fn run_steps(&self, context: &OrchestrationContext, orchestration_id: Uuid) -> Result<(), BankingError> {
+        // Step 1: product overdraft rate
+        if let Some(overdraft_interest_rate) = self.overdraft_interest_rate {
+            let mut product = context.product_repository
+                .find_product_by_id(self.product_id)
+                .await?
+                .ok_or(BankingError::ProductNotFound(self.product_id))?;
+            context.orchestration_service
+                .record_step(
+                    orchestration_id,
+                    "reprice_overdraft_rate",
+                    Some(CompensationAction::RestoreProductOverdraftRate {
+                        product_id: self.product_id,
+                        overdraft_interest_rate: product.rules.overdraft_interest_rate,
+                    }),
+                )
+                .await?;
+            product.rules.overdraft_interest_rate = Some(overdraft_interest_rate);
+            product.last_updated_at = Utc::now();
+            product.updated_by_person_id = self.initiated_by_person_id;
+            context.product_repository.update_product(product).await?;
+        }
+
+        // Step 2: contractual rate of the open loan accounts
+        if let Some(loan_interest_rate) = self.loan_interest_rate {
+            let loans: Vec<_> = context.account_repository
+                .find_by_product_id(self.product_id)
+                .await?
+                .into_iter()
+                .filter(|a| a.account_type == DbAccountType::Loan && a.account_status != DbAccountStatus::Closed)
+                .collect();
+            context.orchestration_service
+                .record_step(
+                    orchestration_id,
+                    "reprice_loan_accounts",
+                    Some(CompensationAction::RestoreLoanInterestRates {
+                        accounts: loans
+                            .iter()
+                            .map(|a| LoanRateSnapshot {
+                                account_id: a.id,
+                                loan_interest_rate: a.loan_interest_rate,
+                            })
+                            .collect(),
+                    }),
+                )
+                .await?;
+            for mut account in loans {
+                account.loan_interest_rate = Some(loan_interest_rate);
+                account.last_updated_at = Utc::now();
+                account.updated_by_person_id = self.initiated_by_person_id;
+                context.account_repository.update(account).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Command for RepriceProductCommand {
+    type Context = OrchestrationContext;
+    type Result = Orchestration;
+
+    async fn execute(&self, context: &Self::Context) -> Result<Self::Result, BankingError> {
+        self.validate()?;
+
+        let orchestration = context.orchestration_service
+            .start_orchestration(OrchestrationType::ProductRepricing, self.product_id, self.initiated_by_person_id)
+            .await?;
+
+        let outcome = self.run_steps(context, orchestration.id).await;
+        finish_orchestration(context.orchestration_service.as_ref(), orchestration.id, outcome).await
+    }
+}
@@ -0,0 +1,964 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use chrono::Duration;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        AccrualOptions, CurrencyCode, ExchangeRatePolicy, PayeeTransferPolicy, PayeeVerificationMethod, ProvisioningThresholds, RiskScoringRules,
+        WorkflowEscalationPolicy, NOTIFICATION_KEY_RETENTION_DAYS,
+    },
+};
+
+/// Environment variables named `BANKING__<SECTION>__<KEY>` override the file,
+/// e.g. `BANKING__EOD__DEFAULT_DORMANCY_DAYS=120`
+pub const ENV_PREFIX: &str = "BANKING__";
+const ENV_SEPARATOR: &str = "__";
+
+/// Keys whose values are never printed by `config_report`
+const SECRET_KEYS: &[&str] = &["notifications.gateway_api_key"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Toml,
+    Json,
+}
+
+impl ConfigFormat {
+    /// JSON for `.json` files, TOML otherwise
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("json") => ConfigFormat::Json,
+            _ => ConfigFormat::Toml,
+        }
+    }
+}
+
+/// Thresholds and knobs of the banking-logic engines. Sections and keys missing
+/// from the source keep their defaults, which match the former hardcoded values.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BankingConfig {
+    pub interest: InterestSettings,
+    pub fees: FeeSettings,
+    pub compliance: ComplianceSettings,
+    pub collections: CollectionSettings,
+    pub eod: EodSettings,
+    pub limits: LimitSettings,
+    pub notifications: NotificationSettings,
+    pub investigation: InvestigationSettings,
+    pub payees: PayeeSettings,
+    pub posting: PostingSettings,
+    pub degradation: DegradationSettings,
+    pub kill_switches: KillSwitchSettings,
+    pub verification: VerificationSettings,
+    pub warehouse: WarehouseExportSettings,
+    pub workflows: WorkflowSettings,
+}
+
+/// Chunked daily accrual run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct InterestSettings {
+    pub accrual_parallelism: usize,
+    pub accrual_chunk_size: i64,
+    pub max_chunk_attempts: u32,
+}
+
+impl Default for InterestSettings {
+    fn default() -> Self {
+        let options = AccrualOptions::default();
+        Self {
+            accrual_parallelism: options.parallelism,
+            accrual_chunk_size: options.chunk_size,
+            max_chunk_attempts: options.max_chunk_attempts,
+        }
+    }
+}
+
+impl InterestSettings {
+    pub fn accrual_options(&self) -> AccrualOptions {
+        AccrualOptions {
+            parallelism: self.accrual_parallelism,
+            chunk_size: self.accrual_chunk_size,
+            max_chunk_attempts: self.max_chunk_attempts,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FeeSettings {
+    /// Accounts fetched per periodic fee run
+    pub eligible_accounts_batch_size: i64,
+}
+
+impl Default for FeeSettings {
+    fn default() -> Self {
+        Self { eligible_accounts_batch_size: 1000 }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ComplianceSettings {
+    pub large_cash_threshold: Decimal,
+    /// Failed authentications within the window that raise an alert
+    pub failed_auth_alert_threshold: u32,
+    pub failed_auth_window_minutes: u32,
+    pub failed_auth_restriction_minutes: u32,
+    /// Batch screening skips customers screened for the same type this recently
+    pub screening_freshness_days: i64,
+    /// Consecutive days beyond the overdraft limit that raise an alert
+    pub unauthorized_overdraft_alert_days: i32,
+    pub risk_scoring: RiskScoringRules,
+    /// Days an enhanced due diligence workflow stays open before it times out
+    pub edd_timeout_days: i64,
+    /// Code of the reason recorded on SARs drafted from alerts
+    pub sar_reason_code: String,
+    /// Share of a corporate customer, in percent, from which a natural person
+    /// owning it is a reportable beneficial owner
+    pub ubo_reporting_threshold: Decimal,
+}
+
+impl Default for ComplianceSettings {
+    fn default() -> Self {
+        Self {
+            large_cash_threshold: Decimal::from(10000),
+            failed_auth_alert_threshold: 5,
+            failed_auth_window_minutes: 10,
+            failed_auth_restriction_minutes: 30,
+            screening_freshness_days: 30,
+            unauthorized_overdraft_alert_days: 3,
+            risk_scoring: RiskScoringRules::default(),
+            edd_timeout_days: 30,
+            sar_reason_code: "SUSPICIOUS_ACTIVITY".to_string(),
+            ubo_reporting_threshold: Decimal::from(25),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CollectionSettings {
+    /// Look-back for collections recorded outside the customer geo-fence
+    pub geo_mismatch_window_days: i64,
+    /// Calendar whose business days collection schedules follow
+    pub calendar_jurisdiction: String,
+    /// Agents whose device has not synced for longer may not record collections
+    pub device_max_sync_age_hours: i64,
+}
+
+impl CollectionSettings {
+    pub fn device_max_sync_age(&self) -> Duration {
+        Duration::hours(self.device_max_sync_age_hours)
+    }
+}
+
+impl Default for CollectionSettings {
+    fn default() -> Self {
+        Self {
+            geo_mismatch_window_days: 7,
+            calendar_jurisdiction: "CM".to_string(),
+            device_max_sync_age_hours: 72,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EodSettings {
+    /// Inactivity days before dormancy for products without their own rule
+    pub default_dormancy_days: i32,
+    pub provisioning: ProvisioningThresholds,
+    /// Accounts read per query by jobs walking every account
+    pub account_page_size: i64,
+    /// Days after an installment's due date before penalty interest is charged
+    pub penalty_grace_days: i32,
+    /// Business-day calendar used to move scheduled runs off holidays, and for
+    /// accounts whose domicile branch does not name a calendar of its own
+    pub calendar_jurisdiction: String,
+    /// Clearing GL codes carrying the opposite leg of customer postings, by channel
+    pub clearing_gl_codes: Vec<ClearingGlCode>,
+    /// Clearing GL code of channels without their own
+    pub default_clearing_gl_code: String,
+}
+
+impl Default for EodSettings {
+    fn default() -> Self {
+        Self {
+            default_dormancy_days: 180,
+            provisioning: ProvisioningThresholds::default(),
+            account_page_size: 1_000,
+            penalty_grace_days: 5,
+            calendar_jurisdiction: banking_api::domain::DEFAULT_CALENDAR.to_string(),
+            clearing_gl_codes: Vec::new(),
+            default_clearing_gl_code: "199000".to_string(),
+        }
+    }
+}
+
+impl EodSettings {
+    pub fn clearing_gl_code(&self, channel_id: &str) -> &str {
+        self.clearing_gl_codes
+            .iter()
+            .find(|clearing| clearing.channel_id == channel_id)
+            .map_or(self.default_clearing_gl_code.as_str(), |clearing| clearing.gl_code.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClearingGlCode {
+    pub channel_id: String,
+    pub gl_code: String,
+}
+
+/// Amounts above which a transaction needs approval, by account signing
+/// condition and by currency and product
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LimitSettings {
+    pub any_owner_approval_threshold: Decimal,
+    pub sole_owner_approval_threshold: Decimal,
+    pub approval_thresholds: Vec<ApprovalThreshold>,
+    /// Hours an approval workflow stays open before it times out
+    pub approval_timeout_hours: i64,
+    /// Code of the reason recorded on the hold over a debit awaiting approval
+    pub approval_hold_reason_code: String,
+}
+
+impl Default for LimitSettings {
+    fn default() -> Self {
+        Self {
+            any_owner_approval_threshold: Decimal::new(10000, 2),
+            sole_owner_approval_threshold: Decimal::new(50000, 2),
+            approval_thresholds: Vec::new(),
+            approval_timeout_hours: 24,
+            approval_hold_reason_code: "PENDING_APPROVAL".to_string(),
+        }
+    }
+}
+
+impl LimitSettings {
+    /// Threshold for a transaction in `currency` on an account of `product_id`;
+    /// one set for the product wins over one for the whole currency
+    pub fn approval_threshold(&self, currency: &str, product_id: Uuid) -> Option<Decimal> {
+        let set_for = |product: Option<Uuid>| {
+            self.approval_thresholds
+                .iter()
+                .find(|threshold| threshold.currency == currency && threshold.product_id == product)
+        };
+        set_for(Some(product_id)).or_else(|| set_for(None)).map(|threshold| threshold.amount)
+    }
+}
+
+/// Four-eyes approval applies to transactions above `amount`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApprovalThreshold {
+    pub currency: String,
+    /// Applies to every product when unset
+    #[serde(default)]
+    pub product_id: Option<Uuid>,
+    pub amount: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NotificationSettings {
+    /// Days an idempotency key is kept after its business date
+    pub key_retention_days: i64,
+    pub max_delivery_attempts: u32,
+    /// Delay before the first retry; doubled per attempt up to `retry_max_delay_seconds`
+    pub retry_base_delay_seconds: i64,
+    pub retry_max_delay_seconds: i64,
+    pub gateway_api_key: Option<String>,
+    /// Language a message template is rendered in when the customer's is unavailable
+    pub default_language_code: String,
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            key_retention_days: NOTIFICATION_KEY_RETENTION_DAYS,
+            max_delivery_attempts: 3,
+            retry_base_delay_seconds: 60,
+            retry_max_delay_seconds: 3600,
+            gateway_api_key: None,
+            default_language_code: "eng".to_string(),
+        }
+    }
+}
+
+impl NotificationSettings {
+    /// Wait before retrying after `failed_attempts` failures; None once attempts are exhausted
+    pub fn retry_delay(&self, failed_attempts: u32) -> Option<Duration> {
+        if failed_attempts == 0 || failed_attempts >= self.max_delivery_attempts {
+            return None;
+        }
+        let factor = 2i64.checked_pow(failed_attempts - 1).unwrap_or(i64::MAX);
+        let seconds = self.retry_base_delay_seconds.saturating_mul(factor).min(self.retry_max_delay_seconds);
+        Some(Duration::seconds(seconds))
+    }
+
+    pub fn default_language(&self) -> [u8; 3] {
+        self.default_language_code.as_bytes().try_into().unwrap_or(*b"eng")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct InvestigationSettings {
+    /// Above this many transactions in the range, a timeline lists one summary per day
+    pub transaction_summary_threshold: i64,
+    /// Rows read per timeline source query
+    pub timeline_page_size: i64,
+}
+
+impl Default for InvestigationSettings {
+    fn default() -> Self {
+        Self {
+            transaction_summary_threshold: 500,
+            timeline_page_size: 200,
+        }
+    }
+}
+
+/// Verification of new external payees and the limits until they are trusted
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PayeeSettings {
+    pub verification_method: PayeeVerificationMethod,
+    pub unverified_transfer_limit: Decimal,
+    /// Hours after a payee is added during which the reduced limit applies
+    pub cooling_off_hours: i64,
+    pub cooling_off_transfer_limit: Decimal,
+}
+
+impl Default for PayeeSettings {
+    fn default() -> Self {
+        Self {
+            verification_method: PayeeVerificationMethod::PennyTest,
+            unverified_transfer_limit: Decimal::new(10000, 2),
+            cooling_off_hours: 24,
+            cooling_off_transfer_limit: Decimal::new(50000, 2),
+        }
+    }
+}
+
+impl PayeeSettings {
+    pub fn transfer_policy(&self) -> PayeeTransferPolicy {
+        PayeeTransferPolicy {
+            unverified_transfer_limit: self.unverified_transfer_limit,
+            cooling_off_hours: self.cooling_off_hours,
+            cooling_off_transfer_limit: self.cooling_off_transfer_limit,
+        }
+    }
+}
+
+/// Controls on posting transactions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PostingSettings {
+    /// Days a value date may lie before the business date; older postings are rejected
+    pub max_back_date_days: i64,
+    /// Days after posting a transaction may still be reversed; 0 for same-day reversals only
+    pub reversal_window_days: i64,
+    /// Foreign-currency postings at an older rate are posted with a warning, or rejected
+    pub max_exchange_rate_age_hours: i64,
+    pub reject_stale_exchange_rates: bool,
+}
+
+impl Default for PostingSettings {
+    fn default() -> Self {
+        Self {
+            max_back_date_days: 30,
+            reversal_window_days: 0,
+            max_exchange_rate_age_hours: 24,
+            reject_stale_exchange_rates: false,
+        }
+    }
+}
+
+impl PostingSettings {
+    pub fn exchange_rate_policy(&self) -> ExchangeRatePolicy {
+        ExchangeRatePolicy {
+            max_age: Duration::hours(self.max_exchange_rate_age_hours),
+            reject_stale: self.reject_stale_exchange_rates,
+        }
+    }
+}
+
+/// Circuit breakers guarding the non-critical posting steps
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DegradationSettings {
+    /// Consecutive failures that open a breaker
+    pub breaker_failure_threshold: u32,
+    /// Seconds an open breaker skips its step before letting one probe through
+    pub breaker_open_seconds: i64,
+}
+
+impl Default for DegradationSettings {
+    fn default() -> Self {
+        Self {
+            breaker_failure_threshold: 5,
+            breaker_open_seconds: 30,
+        }
+    }
+}
+
+/// Operational kill switches halting postings during incidents
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KillSwitchSettings {
+    /// How long the posting path reuses the active switches it loaded. Switches
+    /// changed on another instance take effect within this window.
+    pub cache_ttl_millis: u64,
+    /// Addresses told of every activation, deactivation and expiry
+    pub ops_distribution_list: Vec<String>,
+}
+
+impl Default for KillSwitchSettings {
+    fn default() -> Self {
+        Self {
+            cache_ttl_millis: 2_000,
+            ops_distribution_list: Vec::new(),
+        }
+    }
+}
+
+/// Step-up verification challenges for high-risk operations
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct VerificationSettings {
+    pub challenge_ttl_seconds: i64,
+    /// Wrong codes after which a challenge fails
+    pub max_attempts_per_challenge: u32,
+    /// Wrong codes within the window, across challenges, that lock the
+    /// customer out of the operation kind
+    pub lockout_failure_threshold: u32,
+    pub failure_window_minutes: i64,
+    /// Counted from the last wrong code
+    pub lockout_minutes: i64,
+    /// How long a verified challenge unlocks its operation
+    pub verified_validity_minutes: i64,
+}
+
+impl Default for VerificationSettings {
+    fn default() -> Self {
+        Self {
+            challenge_ttl_seconds: 300,
+            max_attempts_per_challenge: 3,
+            lockout_failure_threshold: 5,
+            failure_window_minutes: 30,
+            lockout_minutes: 30,
+            verified_validity_minutes: 10,
+        }
+    }
+}
+
+/// Nightly export to the data warehouse
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WarehouseExportSettings {
+    /// Rows read per query while exporting an entity
+    pub page_size: i64,
+    /// Runs end this long before the export starts so that transactions
+    /// still committing are picked up by the next run
+    pub settle_seconds: i64,
+}
+
+impl Default for WarehouseExportSettings {
+    fn default() -> Self {
+        Self {
+            page_size: 1_000,
+            settle_seconds: 60,
+        }
+    }
+}
+
+/// Escalation of timed-out workflows to the initiator's supervisors
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WorkflowSettings {
+    /// Time a supervisor has to acknowledge or resolve an escalation before
+    /// it goes to the next level
+    pub escalation_action_hours: i64,
+    /// Highest escalation level; 1 stops at the direct supervisor
+    pub max_escalation_depth: u8,
+}
+
+impl WorkflowSettings {
+    pub fn escalation_policy(&self) -> WorkflowEscalationPolicy {
+        WorkflowEscalationPolicy {
+            action_period: Duration::hours(self.escalation_action_hours),
+            max_depth: self.max_escalation_depth,
+        }
+    }
+}
+
+impl Default for WorkflowSettings {
+    fn default() -> Self {
+        Self {
+            escalation_action_hours: 24,
+            max_escalation_depth: 3,
+        }
+    }
+}
+
+impl BankingConfig {
+    /// Read a TOML or JSON file, apply `BANKING__` environment overrides and validate
+    pub fn load(path: &Path) -> BankingResult<Arc<Self>> {
+        let source = std::fs::read_to_string(path).map_err(|e| BankingError::ConfigValidationFailed {
+            validation_errors: vec![format!("Cannot read {}: {e}", path.display())],
+        })?;
+        Self::from_source(&source, ConfigFormat::from_path(path), std::env::vars()).map(Arc::new)
+    }
+
+    /// Parse the source, apply the overrides found in `env` (variables without
+    /// the prefix are ignored) and validate. Every violation is reported at once.
+    pub fn from_source<I>(source: &str, format: ConfigFormat, env: I) -> BankingResult<Self>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let parsed: BankingConfig = match format {
+            ConfigFormat::Toml => toml::from_str(source).map_err(|e| e.to_string()),
+            ConfigFormat::Json => serde_json::from_str(source).map_err(|e| e.to_string()),
+        }
+        .map_err(|e| BankingError::ConfigValidationFailed {
+            validation_errors: vec![format!("Cannot parse configuration: {e}")],
+        })?;
+
+        let mut violations = Vec::new();
+        let mut value = serde_json::to_value(&parsed)
+            .map_err(|e| BankingError::Internal(format!("Failed to serialize configuration: {e}")))?;
+        apply_env_overrides(&mut value, env, &mut violations);
+
+        match serde_json::from_value::<BankingConfig>(value) {
+            Ok(config) => {
+                config.collect_violations(&mut violations);
+                if violations.is_empty() {
+                    return Ok(config);
+                }
+            }
+            Err(e) => violations.push(format!("Environment overrides: {e}")),
+        }
+        Err(BankingError::ConfigValidationFailed { validation_errors: violations })
+    }
+
+    pub fn validate(&self) -> BankingResult<()> {
+        let mut validation_errors = Vec::new();
+        self.collect_violations(&mut validation_errors);
+        if !validation_errors.is_empty() {
+            return Err(BankingError::ConfigValidationFailed { validation_errors });
+        }
+        Ok(())
+    }
+
+    /// Effective configuration as sorted `section.key = value` lines for support, secrets redacted
+    pub fn config_report(&self) -> String {
+        let mut lines = Vec::new();
+        if let Ok(value) = serde_json::to_value(self) {
+            report_lines("", &value, &mut lines);
+        }
+        lines.sort();
+        lines.join("\n")
+    }
+
+    fn collect_violations(&self, violations: &mut Vec<String>) {
+        let interest = &self.interest;
+        if interest.accrual_parallelism == 0 {
+            violations.push("interest.accrual_parallelism must be at least 1".to_string());
+        }
+        if interest.accrual_chunk_size <= 0 {
+            violations.push("interest.accrual_chunk_size must be positive".to_string());
+        }
+        if interest.max_chunk_attempts == 0 {
+            violations.push("interest.max_chunk_attempts must be at least 1".to_string());
+        }
+
+        if self.fees.eligible_accounts_batch_size <= 0 {
+            violations.push("fees.eligible_accounts_batch_size must be positive".to_string());
+        }
+
+        let compliance = &self.compliance;
+        if compliance.large_cash_threshold <= Decimal::ZERO {
+            violations.push("compliance.large_cash_threshold must be positive".to_string());
+        }
+        if compliance.failed_auth_alert_threshold == 0 {
+            violations.push("compliance.failed_auth_alert_threshold must be at least 1".to_string());
+        }
+        if compliance.failed_auth_window_minutes == 0 {
+            violations.push("compliance.failed_auth_window_minutes must be at least 1".to_string());
+        }
+        if compliance.screening_freshness_days < 0 {
+            violations.push("compliance.screening_freshness_days must not be negative".to_string());
+        }
+        if compliance.unauthorized_overdraft_alert_days < 1 {
+            violations.push("compliance.unauthorized_overdraft_alert_days must be at least 1".to_string());
+        }
+        let scoring = &compliance.risk_scoring;
+        if scoring.medium_threshold <= Decimal::ZERO || scoring.medium_threshold >= scoring.high_threshold {
+            violations.push(format!(
+                "compliance.risk_scoring thresholds must be positive and increasing, got {}, {}",
+                scoring.medium_threshold, scoring.high_threshold
+            ));
+        }
+        if scoring.velocity_window_days <= 0 {
+            violations.push("compliance.risk_scoring.velocity_window_days must be positive".to_string());
+        }
+        for country in &scoring.country_scores {
+            if country.iso2.len() != 2 {
+                violations.push(format!("compliance.risk_scoring country {:?} is not an ISO 3166-1 alpha-2 code", country.iso2));
+            }
+        }
+        if compliance.edd_timeout_days <= 0 {
+            violations.push("compliance.edd_timeout_days must be positive".to_string());
+        }
+        if compliance.sar_reason_code.trim().is_empty() {
+            violations.push("compliance.sar_reason_code must not be empty".to_string());
+        }
+        if compliance.ubo_reporting_threshold <= Decimal::ZERO || compliance.ubo_reporting_threshold > Decimal::ONE_HUNDRED {
+            violations.push(format!(
+                "compliance.ubo_reporting_threshold must be above 0 and at most 100, got {}",
+                compliance.ubo_reporting_threshold
+            ));
+        }
+
+        if self.collections.geo_mismatch_window_days <= 0 {
+            violations.push("collections.geo_mismatch_window_days must be positive".to_string());
+        }
+        if self.collections.calendar_jurisdiction.trim().is_empty() {
+            violations.push("collections.calendar_jurisdiction must not be empty".to_string());
+        }
+        if self.collections.device_max_sync_age_hours <= 0 {
+            violations.push("collections.device_max_sync_age_hours must be positive".to_string());
+        }
+
+        let eod = &self.eod;
+        if !(1..=3650).contains(&eod.default_dormancy_days) {
+            violations.push("eod.default_dormancy_days must be between 1 and 3650".to_string());
+        }
+        let buckets = &eod.provisioning;
+        if buckets.bucket1_max_days <= 0
+            || buckets.bucket1_max_days >= buckets.bucket2_max_days
+            || buckets.bucket2_max_days >= buckets.bucket3_max_days
+        {
+            violations.push(format!(
+                "eod.provisioning bucket bounds must be positive and increasing, got {}, {}, {}",
+                buckets.bucket1_max_days, buckets.bucket2_max_days, buckets.bucket3_max_days
+            ));
+        }
+        if eod.account_page_size <= 0 {
+            violations.push("eod.account_page_size must be positive".to_string());
+        }
+        if eod.penalty_grace_days < 0 {
+            violations.push("eod.penalty_grace_days must not be negative".to_string());
+        }
+        if eod.calendar_jurisdiction.trim().is_empty() {
+            violations.push("eod.calendar_jurisdiction must not be empty".to_string());
+        }
+        let gl_codes = std::iter::once(&eod.default_clearing_gl_code)
+            .chain(eod.clearing_gl_codes.iter().map(|clearing| &clearing.gl_code));
+        for gl_code in gl_codes {
+            if gl_code.trim().is_empty() || gl_code.len() > 50 {
+                violations.push(format!("eod clearing GL code {gl_code:?} must have 1 to 50 characters"));
+            }
+        }
+
+        let limits = &self.limits;
+        if limits.any_owner_approval_threshold <= Decimal::ZERO {
+            violations.push("limits.any_owner_approval_threshold must be positive".to_string());
+        }
+        if limits.sole_owner_approval_threshold <= Decimal::ZERO {
+            violations.push("limits.sole_owner_approval_threshold must be positive".to_string());
+        }
+        for threshold in &limits.approval_thresholds {
+            if CurrencyCode::try_from(threshold.currency.as_str()).is_err() {
+                violations.push(format!("limits.approval_thresholds currency {:?} is not an ISO 4217 code", threshold.currency));
+            }
+            if threshold.amount <= Decimal::ZERO {
+                violations.push(format!("limits.approval_thresholds amount for {} must be positive", threshold.currency));
+            }
+        }
+        if limits.approval_timeout_hours <= 0 {
+            violations.push("limits.approval_timeout_hours must be positive".to_string());
+        }
+        if limits.approval_hold_reason_code.trim().is_empty() {
+            violations.push("limits.approval_hold_reason_code must not be empty".to_string());
+        }
+
+        let notifications = &self.notifications;
+        if notifications.key_retention_days <= 0 {
+            violations.push("notifications.key_retention_days must be positive".to_string());
+        }
+        if notifications.max_delivery_attempts == 0 {
+            violations.push("notifications.max_delivery_attempts must be at least 1".to_string());
+        }
+        if notifications.retry_base_delay_seconds <= 0 {
+            violations.push("notifications.retry_base_delay_seconds must be positive".to_string());
+        }
+        if notifications.retry_base_delay_seconds > notifications.retry_max_delay_seconds {
+            violations.push(format!(
+                "notifications.retry_base_delay_seconds ({}) exceeds retry_max_delay_seconds ({})",
+                notifications.retry_base_delay_seconds, notifications.retry_max_delay_seconds
+            ));
+        }
+        let default_language = notifications.default_language_code.as_bytes();
+        if default_language.len() != 3 || !default_language.iter().all(u8::is_ascii_lowercase) {
+            violations.push(format!(
+                "notifications.default_language_code {:?} is not an ISO 639-2 code",
+                notifications.default_language_code
+            ));
+        }
+
+        if self.investigation.transaction_summary_threshold < 0 {
+            violations.push("investigation.transaction_summary_threshold cannot be negative".to_string());
+        }
+        if self.investigation.timeline_page_size <= 0 {
+            violations.push("investigation.timeline_page_size must be positive".to_string());
+        }
+
+        let payees = &self.payees;
+        if payees.unverified_transfer_limit < Decimal::ZERO {
+            violations.push("payees.unverified_transfer_limit cannot be negative".to_string());
+        }
+        if payees.cooling_off_hours < 0 {
+            violations.push("payees.cooling_off_hours cannot be negative".to_string());
+        }
+        if payees.cooling_off_transfer_limit <= Decimal::ZERO {
+            violations.push("payees.cooling_off_transfer_limit must be positive".to_string());
+        }
+
+        if self.posting.max_back_date_days < 0 {
+            violations.push("posting.max_back_date_days cannot be negative".to_string());
+        }
+        if self.posting.reversal_window_days < 0 {
+            violations.push("posting.reversal_window_days cannot be negative".to_string());
+        }
+        if self.posting.max_exchange_rate_age_hours <= 0 {
+            violations.push("posting.max_exchange_rate_age_hours must be positive".to_string());
+        }
+
+        if self.degradation.breaker_failure_threshold == 0 {
+            violations.push("degradation.breaker_failure_threshold must be positive".to_string());
+        }
+        if self.degradation.breaker_open_seconds <= 0 {
+            violations.push("degradation.breaker_open_seconds must be positive".to_string());
+        }
+
+        if self.kill_switches.cache_ttl_millis == 0 {
+            violations.push("kill_switches.cache_ttl_millis must be positive".to_string());
+        }
+
+        let verification = &self.verification;
+        if verification.challenge_ttl_seconds <= 0 {
+            violations.push("verification.challenge_ttl_seconds must be positive".to_string());
+        }
+        if verification.max_attempts_per_challenge == 0 {
+            violations.push("verification.max_attempts_per_challenge must be positive".to_string());
+        }
+        if verification.lockout_failure_threshold == 0 {
+            violations.push("verification.lockout_failure_threshold must be positive".to_string());
+        }
+        if verification.failure_window_minutes <= 0 {
+            violations.push("verification.failure_window_minutes must be positive".to_string());
+        }
+        if verification.lockout_minutes <= 0 {
+            violations.push("verification.lockout_minutes must be positive".to_string());
+        }
+        if verification.verified_validity_minutes <= 0 {
+            violations.push("verification.verified_validity_minutes must be positive".to_string());
+        }
+
+        if self.warehouse.page_size <= 0 {
+            violations.push("warehouse.page_size must be positive".to_string());
+        }
+        if self.warehouse.settle_seconds < 0 {
+            violations.push("warehouse.settle_seconds cannot be negative".to_string());
+        }
+
+        if self.workflows.escalation_action_hours <= 0 {
+            violations.push("workflows.escalation_action_hours must be positive".to_string());
+        }
+        if self.workflows.max_escalation_depth == 0 {
+            violations.push("workflows.max_escalation_depth must be at least 1".to_string());
+        }
+    }
+}
+
+/// Set each `BANKING__SECTION__KEY` on the serialized configuration. Strings,
+/// decimals and unset options take the raw value; numbers and flags must parse.
+fn apply_env_overrides<I>(config: &mut Value, env: I, violations: &mut Vec<String>)
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    for (name, raw) in env {
+        let Some(path) = name.strip_prefix(ENV_PREFIX) else {
+            continue;
+        };
+        let slot = path
+            .split(ENV_SEPARATOR)
+            .try_fold(&mut *config, |node, key| node.get_mut(key.to_lowercase().as_str()));
+        match slot {
+            Some(slot) if slot.is_string() || slot.is_null() => *slot = Value::String(raw),
+            Some(slot) if !slot.is_object() => match serde_json::from_str::<Value>(&raw) {
+                Ok(parsed) => *slot = parsed,
+                Err(_) => violations.push(format!("{name} has unparseable value {raw:?}")),
+            },
+            _ => violations.push(format!("{name} does not name a configuration key")),
+        }
+    }
+}
+
+fn report_lines(prefix: &str, value: &Value, lines: &mut Vec<String>) {
+    match value {
+        Value::Object(fields) => {
+            for (key, field) in fields {
+                let path = if prefix.is_empty() { key.clone() } else { format!("{prefix}.{key}") };
+                report_lines(&path, field, lines);
+            }
+        }
+        Value::Null => lines.push(format!("{prefix} = (unset)")),
+        _ if SECRET_KEYS.contains(&prefix) => lines.push(format!("{prefix} = ***")),
+        _ => lines.push(format!("{prefix} = {value}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect()
+    }
+
+    fn violations(result: BankingResult<BankingConfig>) -> Vec<String> {
+        match result {
+            Err(BankingError::ConfigValidationFailed { validation_errors }) => validation_errors,
+            other => panic!("expected validation failure, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_empty_source_yields_previous_defaults() {
+        let config = BankingConfig::from_source("", ConfigFormat::Toml, env(&[])).unwrap();
+
+        assert_eq!(config, BankingConfig::default());
+        assert_eq!(config.eod.default_dormancy_days, 180);
+        assert_eq!(config.limits.any_owner_approval_threshold, Decimal::new(10000, 2));
+    }
+
+    #[test]
+    fn test_all_violations_are_reported_together() {
+        let source = r#"
+            [interest]
+            accrual_parallelism = 0
+
+            [eod.provisioning]
+            bucket1_max_days = 30
+            bucket2_max_days = 90
+            bucket3_max_days = 60
+
+            [notifications]
+            retry_base_delay_seconds = 600
+            retry_max_delay_seconds = 300
+        "#;
+
+        let errors = violations(BankingConfig::from_source(source, ConfigFormat::Toml, env(&[])));
+
+        assert_eq!(errors.len(), 3);
+        assert!(errors.iter().any(|e| e.starts_with("interest.accrual_parallelism")));
+        assert!(errors.iter().any(|e| e.starts_with("eod.provisioning")));
+        assert!(errors.iter().any(|e| e.starts_with("notifications.retry_base_delay_seconds")));
+    }
+
+    #[test]
+    fn test_environment_overrides_take_precedence_over_file() {
+        let source = r#"{"eod": {"default_dormancy_days": 120}, "limits": {"any_owner_approval_threshold": "250.00"}}"#;
+        let overrides = env(&[
+            ("BANKING__EOD__DEFAULT_DORMANCY_DAYS", "200"),
+            ("BANKING__LIMITS__SOLE_OWNER_APPROVAL_THRESHOLD", "750.50"),
+            ("BANKING__NOTIFICATIONS__GATEWAY_API_KEY", "sk-live-123"),
+            ("PATH", "/usr/bin"),
+        ]);
+
+        let config = BankingConfig::from_source(source, ConfigFormat::Json, overrides).unwrap();
+
+        assert_eq!(config.eod.default_dormancy_days, 200);
+        assert_eq!(config.limits.any_owner_approval_threshold, Decimal::new(25000, 2));
+        assert_eq!(config.limits.sole_owner_approval_threshold, Decimal::new(75050, 2));
+        assert_eq!(config.notifications.gateway_api_key.as_deref(), Some("sk-live-123"));
+
+        let report = config.config_report();
+        assert!(report.contains("eod.default_dormancy_days = 200"));
+        assert!(report.contains("notifications.gateway_api_key = ***"));
+        assert!(!report.contains("sk-live-123"));
+    }
+
+    #[test]
+    fn test_bad_environment_overrides_are_reported_with_validation_errors() {
+        let overrides = env(&[
+            ("BANKING__EOD__DORMANCY", "200"),
+            ("BANKING__INTEREST__MAX_CHUNK_ATTEMPTS", "three"),
+            ("BANKING__COLLECTIONS__GEO_MISMATCH_WINDOW_DAYS", "0"),
+        ]);
+
+        let errors = violations(BankingConfig::from_source("", ConfigFormat::Toml, overrides));
+
+        assert_eq!(errors.len(), 3);
+        assert!(errors[0].contains("BANKING__EOD__DORMANCY"));
+        assert!(errors[1].contains("BANKING__INTEREST__MAX_CHUNK_ATTEMPTS"));
+        assert!(errors[2].starts_with("collections.geo_mismatch_window_days"));
+    }
+
+    #[test]
+    fn test_retry_delay_doubles_up_to_cap_and_stops_after_last_attempt() {
+        let settings = NotificationSettings {
+            max_delivery_attempts: 5,
+            retry_base_delay_seconds: 60,
+            retry_max_delay_seconds: 200,
+            ..NotificationSettings::default()
+        };
+
+        assert_eq!(settings.retry_delay(1), Some(Duration::seconds(60)));
+        assert_eq!(settings.retry_delay(2), Some(Duration::seconds(120)));
+        assert_eq!(settings.retry_delay(3), Some(Duration::seconds(200)));
+        assert_eq!(settings.retry_delay(5), None);
+    }
+
+    #[test]
+    fn test_product_approval_threshold_wins_over_currency_threshold() {
+        let product_id = Uuid::new_v4();
+        let source = format!(r#"
+            [[limits.approval_thresholds]]
+            currency = "XAF"
+            amount = "5000000"
+
+            [[limits.approval_thresholds]]
+            currency = "XAF"
+            product_id = "{product_id}"
+            amount = "1000000"
+        "#);
+
+        let limits = BankingConfig::from_source(&source, ConfigFormat::Toml, env(&[])).unwrap().limits;
+
+        assert_eq!(limits.approval_threshold("XAF", product_id), Some(Decimal::from(1_000_000)));
+        assert_eq!(limits.approval_threshold("XAF", Uuid::new_v4()), Some(Decimal::from(5_000_000)));
+        assert_eq!(limits.approval_threshold("EUR", product_id), None);
+
+        let errors = violations(BankingConfig::from_source(
+            "[[limits.approval_thresholds]]\ncurrency = \"XYZ\"\namount = \"0\"",
+            ConfigFormat::Toml,
+            env(&[]),
+        ));
+        assert_eq!(errors.len(), 2);
+    }
+}
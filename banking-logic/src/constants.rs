@@ -1,3 +1,4 @@
+use banking_api::domain::{ReasonCategory, ReasonContext, ReasonedOperation};
 use uuid::Uuid;
 
 /// System person ID - used for automated system operations
@@ -16,4 +17,117 @@ pub const BATCH_PROCESSOR_PERSON_ID: Uuid = Uuid::from_u128(0x00000000_0000_0000
 pub const COMPLIANCE_PERSON_ID: Uuid = Uuid::from_u128(0x00000000_0000_0000_0000_000000000004);
 
 /// Lifecycle automation person ID - used for account lifecycle processes
-pub const LIFECYCLE_AUTOMATION_PERSON_ID: Uuid = Uuid::from_u128(0x00000000_0000_0000_0000_000000000005);
\ No newline at end of file
+pub const LIFECYCLE_AUTOMATION_PERSON_ID: Uuid = Uuid::from_u128(0x00000000_0000_0000_0000_000000000005);
+
+/// Reason categories and contexts accepted by an operation that must carry a reason
+pub struct ReasonRequirement {
+    pub operation: ReasonedOperation,
+    pub categories: &'static [ReasonCategory],
+    pub contexts: &'static [ReasonContext],
+}
+
+/// Reasons accepted by each reasoned operation
+pub const REASON_REQUIREMENTS: &[ReasonRequirement] = &[
+    ReasonRequirement {
+        operation: ReasonedOperation::AccountStatusChange,
+        categories: &[
+            ReasonCategory::AccountClosure,
+            ReasonCategory::AccountSuspension,
+            ReasonCategory::AccountReactivation,
+            ReasonCategory::StatusChange,
+        ],
+        contexts: &[ReasonContext::Account, ReasonContext::General],
+    },
+    ReasonRequirement {
+        operation: ReasonedOperation::CustomerStatusChange,
+        categories: &[
+            ReasonCategory::StatusChange,
+            ReasonCategory::ComplianceFlag,
+            ReasonCategory::SuspiciousActivity,
+            ReasonCategory::SanctionsHit,
+            ReasonCategory::KycVerificationFailure,
+        ],
+        contexts: &[ReasonContext::Customer, ReasonContext::Compliance, ReasonContext::AmlCtf, ReasonContext::Kyc],
+    },
+    ReasonRequirement {
+        operation: ReasonedOperation::HoldPlacement,
+        categories: &[ReasonCategory::HoldReason],
+        contexts: &[ReasonContext::Account, ReasonContext::Transaction, ReasonContext::Compliance],
+    },
+    ReasonRequirement {
+        operation: ReasonedOperation::HoldRelease,
+        categories: &[ReasonCategory::HoldReason],
+        contexts: &[ReasonContext::Account, ReasonContext::Transaction, ReasonContext::Compliance],
+    },
+    ReasonRequirement {
+        operation: ReasonedOperation::AccountRestriction,
+        categories: &[
+            ReasonCategory::AccountSuspension,
+            ReasonCategory::ComplianceFlag,
+            ReasonCategory::AmlInvestigation,
+            ReasonCategory::SanctionsHit,
+        ],
+        contexts: &[ReasonContext::Account, ReasonContext::Compliance, ReasonContext::AmlCtf],
+    },
+    ReasonRequirement {
+        operation: ReasonedOperation::FeeWaiver,
+        categories: &[ReasonCategory::ComplaintReason, ReasonCategory::ServiceRequest],
+        contexts: &[ReasonContext::Account, ReasonContext::Customer, ReasonContext::General],
+    },
+    ReasonRequirement {
+        operation: ReasonedOperation::TransactionReversal,
+        categories: &[ReasonCategory::TransactionReversal],
+        contexts: &[ReasonContext::Transaction],
+    },
+    ReasonRequirement {
+        operation: ReasonedOperation::LegalHold,
+        categories: &[ReasonCategory::HoldReason, ReasonCategory::ComplianceFlag],
+        contexts: &[ReasonContext::Account, ReasonContext::Compliance],
+    },
+    ReasonRequirement {
+        operation: ReasonedOperation::LoanRestructure,
+        categories: &[ReasonCategory::StatusChange, ReasonCategory::ServiceRequest],
+        contexts: &[ReasonContext::Loan],
+    },
+    ReasonRequirement {
+        operation: ReasonedOperation::WorkflowRejection,
+        categories: &[
+            ReasonCategory::KycMissingDocument,
+            ReasonCategory::KycDocumentRejection,
+            ReasonCategory::KycVerificationFailure,
+            ReasonCategory::ComplianceFlag,
+        ],
+        contexts: &[ReasonContext::Account, ReasonContext::Kyc, ReasonContext::Compliance],
+    },
+    ReasonRequirement {
+        operation: ReasonedOperation::AccountDomicileTransfer,
+        categories: &[ReasonCategory::ServiceRequest, ReasonCategory::StatusChange],
+        contexts: &[ReasonContext::Account, ReasonContext::Customer, ReasonContext::General],
+    },
+    ReasonRequirement {
+        operation: ReasonedOperation::CustomerMerge,
+        categories: &[ReasonCategory::ServiceRequest, ReasonCategory::StatusChange],
+        contexts: &[ReasonContext::Customer, ReasonContext::General],
+    },
+    ReasonRequirement {
+        operation: ReasonedOperation::SuspiciousActivityReport,
+        categories: &[
+            ReasonCategory::SuspiciousActivity,
+            ReasonCategory::AmlAlert,
+            ReasonCategory::AmlInvestigation,
+            ReasonCategory::UnusualPattern,
+            ReasonCategory::CtfRiskFlag,
+            ReasonCategory::SanctionsHit,
+        ],
+        contexts: &[ReasonContext::AmlCtf, ReasonContext::Compliance],
+    },
+];
+
+/// Duplicate person score for a shared external identifier
+pub const DUPLICATE_SCORE_EXTERNAL_IDENTIFIER: u32 = 60;
+
+/// Duplicate person score for equal display names after normalization
+pub const DUPLICATE_SCORE_NORMALIZED_NAME: u32 = 25;
+
+/// Duplicate person score for at least one shared messaging value
+pub const DUPLICATE_SCORE_MESSAGING: u32 = 15;
@@ -3,6 +3,7 @@ pub mod mappers;
 pub mod integration;
 pub mod validation;
 pub mod constants;
+pub mod config;
 pub mod commands;
 
 pub use services::person_service_impl;
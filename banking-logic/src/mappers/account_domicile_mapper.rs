@@ -0,0 +1,34 @@
+use banking_api::domain::AccountDomicileChange;
+use banking_db::models::AccountDomicileChangeModel;
+
+pub struct AccountDomicileMapper;
+
+impl AccountDomicileMapper {
+    /// Map from domain AccountDomicileChange to database AccountDomicileChangeModel
+    pub fn to_model(change: AccountDomicileChange) -> AccountDomicileChangeModel {
+        AccountDomicileChangeModel {
+            id: change.id,
+            account_id: change.account_id,
+            from_agency_branch_id: change.from_agency_branch_id,
+            to_agency_branch_id: change.to_agency_branch_id,
+            effective_date: change.effective_date,
+            reason_id: change.reason_id,
+            approved_by_person_id: change.approved_by_person_id,
+            recorded_at: change.recorded_at,
+        }
+    }
+
+    /// Map from database AccountDomicileChangeModel to domain AccountDomicileChange
+    pub fn from_model(model: AccountDomicileChangeModel) -> AccountDomicileChange {
+        AccountDomicileChange {
+            id: model.id,
+            account_id: model.account_id,
+            from_agency_branch_id: model.from_agency_branch_id,
+            to_agency_branch_id: model.to_agency_branch_id,
+            effective_date: model.effective_date,
+            reason_id: model.reason_id,
+            approved_by_person_id: model.approved_by_person_id,
+            recorded_at: model.recorded_at,
+        }
+    }
+}
@@ -3,17 +3,24 @@ use banking_api::domain::{
     AccountStatusChangeRecord, UltimateBeneficiary, AccountType, AccountStatus, SigningCondition,
     DisbursementMethod, DisbursementStatus, OwnershipType, EntityType, RelationshipType,
     RelationshipStatus, PermissionType, MandateStatus, ControlType, VerificationStatus, UboStatus,
+    AccountBalanceSnapshot, ProvisioningBucket, CurrencyCode, AccountBalanceChangeRecord, BalanceChangeSource,
+    FinalSettlement, LoanPenaltyAccrual,
 };
 use banking_db::{
     DbAccountStatus, DbAccountType, DbControlType, DbDisbursementMethod, DbDisbursementStatus,
     DbEntityType, DbMandateStatus, DbOwnershipType, DbPermissionType, DbRelationshipStatus,
-    DbRelationshipType, DbSigningCondition, DbUboStatus, DbVerificationStatus,
+    DbRelationshipType, DbSigningCondition, DbUboStatus, DbVerificationStatus, DbProvisioningBucket,
 };
 use banking_db::models::{
     AccountBalanceCalculationModel, AccountMandateModel, AccountModel, AccountOwnershipModel,
     AccountRelationshipModel, AccountStatusChangeRecordModel, UltimateBeneficiaryModel,
+    AccountBalanceSnapshotModel, AccountBalanceChangeRecordModel, DbBalanceChangeSource,
+    AccountFinalSettlementModel, DbSettlementStatus, LoanPenaltyAccrualModel,
 };
+use chrono::{NaiveDate, Utc};
 use heapless::{String as HeaplessString};
+use rust_decimal::Decimal;
+use uuid::Uuid;
 
 pub struct AccountMapper;
 
@@ -26,9 +33,10 @@ impl AccountMapper {
             account_type: Self::account_type_to_db(account.account_type),
             account_status: Self::account_status_to_db(account.account_status),
             signing_condition: Self::signing_condition_to_db(account.signing_condition),
-            currency: account.currency,
+            currency: account.currency.into(),
             open_date: account.open_date,
             domicile_agency_branch_id: account.domicile_agency_branch_id,
+            account_number: account.account_number,
             current_balance: account.current_balance,
             available_balance: account.available_balance,
             accrued_interest: account.accrued_interest,
@@ -83,6 +91,8 @@ impl AccountMapper {
             created_at: account.created_at,
             last_updated_at: account.last_updated_at,
             updated_by_person_id: account.updated_by_person_id,
+            // Accounts are only created from the domain; updates go through loaded models
+            version: 0,
         }
     }
 
@@ -94,9 +104,10 @@ impl AccountMapper {
             account_type: Self::account_type_from_db(model.account_type),
             account_status: Self::account_status_from_db(model.account_status),
             signing_condition: Self::signing_condition_from_db(model.signing_condition),
-            currency: model.currency,
+            currency: CurrencyCode::try_from(model.currency)?,
             open_date: model.open_date,
             domicile_agency_branch_id: model.domicile_agency_branch_id,
+            account_number: model.account_number,
             current_balance: model.current_balance,
             available_balance: model.available_balance,
             accrued_interest: model.accrued_interest,
@@ -353,8 +364,85 @@ impl AccountMapper {
         }
     }
 
+    // AccountBalanceSnapshot mappers
+    pub fn balance_snapshot_to_model(snapshot: AccountBalanceSnapshot) -> AccountBalanceSnapshotModel {
+        AccountBalanceSnapshotModel {
+            id: snapshot.id,
+            account_id: snapshot.account_id,
+            snapshot_date: snapshot.snapshot_date,
+            closing_balance: snapshot.closing_balance,
+            consecutive_overdrawn_days: snapshot.consecutive_overdrawn_days,
+            provisioning_bucket: Self::provisioning_bucket_to_db(snapshot.provisioning_bucket),
+            previous_provisioning_bucket: snapshot.previous_provisioning_bucket.map(Self::provisioning_bucket_to_db),
+            bucket_transition: snapshot.bucket_transition,
+            created_at: snapshot.created_at,
+        }
+    }
+
+    pub fn balance_snapshot_from_model(model: AccountBalanceSnapshotModel) -> AccountBalanceSnapshot {
+        AccountBalanceSnapshot {
+            id: model.id,
+            account_id: model.account_id,
+            snapshot_date: model.snapshot_date,
+            closing_balance: model.closing_balance,
+            consecutive_overdrawn_days: model.consecutive_overdrawn_days,
+            provisioning_bucket: Self::provisioning_bucket_from_db(model.provisioning_bucket),
+            previous_provisioning_bucket: model.previous_provisioning_bucket.map(Self::provisioning_bucket_from_db),
+            bucket_transition: model.bucket_transition,
+            created_at: model.created_at,
+        }
+    }
+
+
+    // LoanPenaltyAccrual mappers
+    pub fn loan_penalty_accrual_to_model(accrual: LoanPenaltyAccrual) -> LoanPenaltyAccrualModel {
+        LoanPenaltyAccrualModel {
+            id: accrual.id,
+            loan_account_id: accrual.loan_account_id,
+            assessment_date: accrual.assessment_date,
+            due_date: accrual.due_date,
+            days_past_due: accrual.days_past_due,
+            overdue_amount: accrual.overdue_amount,
+            penalty_rate: accrual.penalty_rate,
+            penalty_days: accrual.penalty_days,
+            penalty_amount: accrual.penalty_amount,
+            created_at: accrual.created_at,
+        }
+    }
+
+    pub fn loan_penalty_accrual_from_model(model: LoanPenaltyAccrualModel) -> LoanPenaltyAccrual {
+        LoanPenaltyAccrual {
+            id: model.id,
+            loan_account_id: model.loan_account_id,
+            assessment_date: model.assessment_date,
+            due_date: model.due_date,
+            days_past_due: model.days_past_due,
+            overdue_amount: model.overdue_amount,
+            penalty_rate: model.penalty_rate,
+            penalty_days: model.penalty_days,
+            penalty_amount: model.penalty_amount,
+            created_at: model.created_at,
+        }
+    }
+
+    // AccountBalanceChangeRecord mappers
+    pub fn balance_change_from_model(model: AccountBalanceChangeRecordModel) -> AccountBalanceChangeRecord {
+        AccountBalanceChangeRecord {
+            id: model.id,
+            account_id: model.account_id,
+            old_current_balance: model.old_current_balance,
+            new_current_balance: model.new_current_balance,
+            old_available_balance: model.old_available_balance,
+            new_available_balance: model.new_available_balance,
+            change_source: Self::balance_change_source_from_db(model.change_source),
+            transaction_id: model.transaction_id,
+            changed_at: model.changed_at,
+            changed_by_person_id: model.changed_by_person_id,
+        }
+    }
+
     // Helper methods for enum conversions
-    fn account_type_to_db(account_type: AccountType) -> DbAccountType {
+    pub fn account_type_to_db(account_type: AccountType) -> DbAccountType {
         match account_type {
             AccountType::Savings => DbAccountType::Savings,
             AccountType::Current => DbAccountType::Current,
@@ -362,7 +450,7 @@ impl AccountMapper {
         }
     }
 
-    fn account_type_from_db(db_type: DbAccountType) -> AccountType {
+    pub fn account_type_from_db(db_type: DbAccountType) -> AccountType {
         match db_type {
             DbAccountType::Savings => AccountType::Savings,
             DbAccountType::Current => AccountType::Current,
@@ -370,7 +458,7 @@ impl AccountMapper {
         }
     }
 
-    fn account_status_to_db(account_status: AccountStatus) -> DbAccountStatus {
+    pub fn account_status_to_db(account_status: AccountStatus) -> DbAccountStatus {
         match account_status {
             AccountStatus::PendingApproval => DbAccountStatus::PendingApproval,
             AccountStatus::Active => DbAccountStatus::Active,
@@ -382,7 +470,47 @@ impl AccountMapper {
         }
     }
 
-    fn account_status_from_db(db_status: DbAccountStatus) -> AccountStatus {
+    /// Pending settlement record of the amounts an account is closed with
+    pub fn final_settlement_to_model(
+        account_id: Uuid,
+        settlement_date: NaiveDate,
+        settlement: &FinalSettlement,
+        method: DisbursementMethod,
+        processed_by_person_id: Uuid,
+    ) -> AccountFinalSettlementModel {
+        AccountFinalSettlementModel {
+            id: Uuid::new_v4(),
+            account_id,
+            settlement_date,
+            current_balance: settlement.current_balance,
+            accrued_interest: settlement.accrued_interest,
+            pending_fees: settlement.pending_fees,
+            closure_fees: settlement.closure_fees,
+            final_amount: settlement.final_amount,
+            disbursement_method: Self::disbursement_method_to_db(method),
+            disbursement_reference: None,
+            status: if settlement.is_receivable() {
+                DbSettlementStatus::Receivable
+            } else {
+                DbSettlementStatus::Pending
+            },
+            processed_by_person_id,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn final_settlement_from_model(model: &AccountFinalSettlementModel) -> FinalSettlement {
+        FinalSettlement {
+            current_balance: model.current_balance,
+            accrued_interest: model.accrued_interest,
+            pending_fees: model.pending_fees,
+            closure_fees: model.closure_fees,
+            final_amount: model.final_amount,
+            requires_disbursement: model.final_amount > Decimal::ZERO,
+        }
+    }
+
+    pub fn account_status_from_db(db_status: DbAccountStatus) -> AccountStatus {
         match db_status {
             DbAccountStatus::PendingApproval => AccountStatus::PendingApproval,
             DbAccountStatus::Active => AccountStatus::Active,
@@ -394,7 +522,7 @@ impl AccountMapper {
         }
     }
 
-    fn signing_condition_to_db(signing_condition: SigningCondition) -> DbSigningCondition {
+    pub fn signing_condition_to_db(signing_condition: SigningCondition) -> DbSigningCondition {
         match signing_condition {
             SigningCondition::None => DbSigningCondition::None,
             SigningCondition::AnyOwner => DbSigningCondition::AnyOwner,
@@ -402,7 +530,7 @@ impl AccountMapper {
         }
     }
 
-    fn signing_condition_from_db(db_condition: DbSigningCondition) -> SigningCondition {
+    pub fn signing_condition_from_db(db_condition: DbSigningCondition) -> SigningCondition {
         match db_condition {
             DbSigningCondition::None => SigningCondition::None,
             DbSigningCondition::AllOwners => SigningCondition::AllOwners,
@@ -557,7 +685,7 @@ impl AccountMapper {
         }
     }
 
-    fn mandate_status_from_db(db_status: DbMandateStatus) -> MandateStatus {
+    pub fn mandate_status_from_db(db_status: DbMandateStatus) -> MandateStatus {
         match db_status {
             DbMandateStatus::Active => MandateStatus::Active,
             DbMandateStatus::Suspended => MandateStatus::Suspended,
@@ -617,4 +745,46 @@ impl AccountMapper {
             DbUboStatus::UnderReview => UboStatus::UnderReview,
         }
     }
+
+    fn provisioning_bucket_to_db(bucket: ProvisioningBucket) -> DbProvisioningBucket {
+        match bucket {
+            ProvisioningBucket::Current => DbProvisioningBucket::Current,
+            ProvisioningBucket::Bucket1 => DbProvisioningBucket::Bucket1,
+            ProvisioningBucket::Bucket2 => DbProvisioningBucket::Bucket2,
+            ProvisioningBucket::Bucket3 => DbProvisioningBucket::Bucket3,
+            ProvisioningBucket::Bucket4 => DbProvisioningBucket::Bucket4,
+        }
+    }
+
+    fn provisioning_bucket_from_db(db_bucket: DbProvisioningBucket) -> ProvisioningBucket {
+        match db_bucket {
+            DbProvisioningBucket::Current => ProvisioningBucket::Current,
+            DbProvisioningBucket::Bucket1 => ProvisioningBucket::Bucket1,
+            DbProvisioningBucket::Bucket2 => ProvisioningBucket::Bucket2,
+            DbProvisioningBucket::Bucket3 => ProvisioningBucket::Bucket3,
+            DbProvisioningBucket::Bucket4 => ProvisioningBucket::Bucket4,
+        }
+    }
+
+    pub fn balance_change_source_to_db(source: BalanceChangeSource) -> DbBalanceChangeSource {
+        match source {
+            BalanceChangeSource::Transaction => DbBalanceChangeSource::Transaction,
+            BalanceChangeSource::InterestPosting => DbBalanceChangeSource::InterestPosting,
+            BalanceChangeSource::FeeCharge => DbBalanceChangeSource::FeeCharge,
+            BalanceChangeSource::LoanRepayment => DbBalanceChangeSource::LoanRepayment,
+            BalanceChangeSource::Reversal => DbBalanceChangeSource::Reversal,
+            BalanceChangeSource::ManualAdjustment => DbBalanceChangeSource::ManualAdjustment,
+        }
+    }
+
+    pub fn balance_change_source_from_db(db_source: DbBalanceChangeSource) -> BalanceChangeSource {
+        match db_source {
+            DbBalanceChangeSource::Transaction => BalanceChangeSource::Transaction,
+            DbBalanceChangeSource::InterestPosting => BalanceChangeSource::InterestPosting,
+            DbBalanceChangeSource::FeeCharge => BalanceChangeSource::FeeCharge,
+            DbBalanceChangeSource::LoanRepayment => BalanceChangeSource::LoanRepayment,
+            DbBalanceChangeSource::Reversal => BalanceChangeSource::Reversal,
+            DbBalanceChangeSource::ManualAdjustment => BalanceChangeSource::ManualAdjustment,
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,38 @@
+use banking_api::domain::AccountNumberScheme;
+use banking_db::models::AccountNumberSchemeModel;
+
+pub struct AccountNumberMapper;
+
+impl AccountNumberMapper {
+    /// Map from domain AccountNumberScheme to database AccountNumberSchemeModel
+    pub fn scheme_to_model(scheme: AccountNumberScheme) -> AccountNumberSchemeModel {
+        AccountNumberSchemeModel {
+            id: scheme.id,
+            branch_id: scheme.branch_id,
+            product_id: scheme.product_id,
+            bank_code: scheme.bank_code,
+            branch_code: scheme.branch_code,
+            serial_length: scheme.serial_length as i16,
+            iban_country_code: scheme.iban_country_code,
+            created_at: scheme.created_at,
+            last_updated_at: scheme.last_updated_at,
+            updated_by_person_id: scheme.updated_by_person_id,
+        }
+    }
+
+    /// Map from database AccountNumberSchemeModel to domain AccountNumberScheme
+    pub fn scheme_from_model(model: AccountNumberSchemeModel) -> AccountNumberScheme {
+        AccountNumberScheme {
+            id: model.id,
+            branch_id: model.branch_id,
+            product_id: model.product_id,
+            bank_code: model.bank_code,
+            branch_code: model.branch_code,
+            serial_length: model.serial_length as u8,
+            iban_country_code: model.iban_country_code,
+            created_at: model.created_at,
+            last_updated_at: model.last_updated_at,
+            updated_by_person_id: model.updated_by_person_id,
+        }
+    }
+}
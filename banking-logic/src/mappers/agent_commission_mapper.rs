@@ -0,0 +1,155 @@
+use banking_api::domain::{
+    AgentCollectionTotals, AgentCommissionLineItem, AgentCommissionScheme, AgentCommissionStatement,
+    CommissionLineType, CommissionStatementStatus,
+};
+use banking_db::models::{
+    AgentCollectionTotalsModel, AgentCommissionLineItemModel, AgentCommissionSchemeModel,
+    AgentCommissionStatementModel, DbCommissionLineType, DbCommissionStatementStatus,
+};
+
+pub struct AgentCommissionMapper;
+
+impl AgentCommissionMapper {
+    /// Map from domain AgentCommissionScheme to database AgentCommissionSchemeModel
+    pub fn scheme_to_model(scheme: AgentCommissionScheme) -> AgentCommissionSchemeModel {
+        AgentCommissionSchemeModel {
+            id: scheme.id,
+            code: scheme.code,
+            collection_program_id: scheme.collection_program_id,
+            territory_id: scheme.territory_id,
+            commission_rate: scheme.commission_rate,
+            target_collection_rate: scheme.target_collection_rate,
+            bonus_amount: scheme.bonus_amount,
+            deduction_per_discrepancy: scheme.deduction_per_discrepancy,
+            is_active: scheme.is_active,
+            created_at: scheme.created_at,
+            last_updated_at: scheme.last_updated_at,
+            updated_by_person_id: scheme.updated_by_person_id,
+        }
+    }
+
+    /// Map from database AgentCommissionSchemeModel to domain AgentCommissionScheme
+    pub fn scheme_from_model(model: AgentCommissionSchemeModel) -> AgentCommissionScheme {
+        AgentCommissionScheme {
+            id: model.id,
+            code: model.code,
+            collection_program_id: model.collection_program_id,
+            territory_id: model.territory_id,
+            commission_rate: model.commission_rate,
+            target_collection_rate: model.target_collection_rate,
+            bonus_amount: model.bonus_amount,
+            deduction_per_discrepancy: model.deduction_per_discrepancy,
+            is_active: model.is_active,
+            created_at: model.created_at,
+            last_updated_at: model.last_updated_at,
+            updated_by_person_id: model.updated_by_person_id,
+        }
+    }
+
+    /// Map from domain AgentCommissionStatement to database AgentCommissionStatementModel
+    pub fn statement_to_model(statement: AgentCommissionStatement) -> AgentCommissionStatementModel {
+        AgentCommissionStatementModel {
+            id: statement.id,
+            collection_agent_id: statement.collection_agent_id,
+            period_start: statement.period_start,
+            period_end: statement.period_end,
+            collected_amount: statement.collected_amount,
+            commission_amount: statement.commission_amount,
+            bonus_amount: statement.bonus_amount,
+            deduction_amount: statement.deduction_amount,
+            net_amount: statement.net_amount,
+            discrepancy_count: statement.discrepancy_count as i32,
+            status: Self::statement_status_to_db(statement.status),
+            calculated_at: statement.calculated_at,
+            invoiced_at: statement.invoiced_at,
+        }
+    }
+
+    /// Map from database AgentCommissionStatementModel to domain AgentCommissionStatement
+    pub fn statement_from_model(model: AgentCommissionStatementModel) -> AgentCommissionStatement {
+        AgentCommissionStatement {
+            id: model.id,
+            collection_agent_id: model.collection_agent_id,
+            period_start: model.period_start,
+            period_end: model.period_end,
+            collected_amount: model.collected_amount,
+            commission_amount: model.commission_amount,
+            bonus_amount: model.bonus_amount,
+            deduction_amount: model.deduction_amount,
+            net_amount: model.net_amount,
+            discrepancy_count: model.discrepancy_count.max(0) as u32,
+            status: Self::statement_status_from_db(model.status),
+            calculated_at: model.calculated_at,
+            invoiced_at: model.invoiced_at,
+        }
+    }
+
+    /// Map from domain AgentCommissionLineItem to database AgentCommissionLineItemModel
+    pub fn line_item_to_model(line_item: AgentCommissionLineItem) -> AgentCommissionLineItemModel {
+        AgentCommissionLineItemModel {
+            id: line_item.id,
+            statement_id: line_item.statement_id,
+            line_type: Self::line_type_to_db(line_item.line_type),
+            scheme_id: line_item.scheme_id,
+            collection_program_id: line_item.collection_program_id,
+            description: line_item.description,
+            basis_amount: line_item.basis_amount,
+            amount: line_item.amount,
+        }
+    }
+
+    /// Map from database AgentCommissionLineItemModel to domain AgentCommissionLineItem
+    pub fn line_item_from_model(model: AgentCommissionLineItemModel) -> AgentCommissionLineItem {
+        AgentCommissionLineItem {
+            id: model.id,
+            statement_id: model.statement_id,
+            line_type: Self::line_type_from_db(model.line_type),
+            scheme_id: model.scheme_id,
+            collection_program_id: model.collection_program_id,
+            description: model.description,
+            basis_amount: model.basis_amount,
+            amount: model.amount,
+        }
+    }
+
+    pub fn collection_totals_from_model(model: AgentCollectionTotalsModel) -> AgentCollectionTotals {
+        AgentCollectionTotals {
+            collection_agent_id: model.collection_agent_id,
+            territory_id: model.territory_id,
+            collection_program_id: model.collection_program_id,
+            processed_count: model.processed_count,
+            processed_amount: model.processed_amount,
+            attempted_count: model.attempted_count,
+        }
+    }
+
+    pub fn statement_status_to_db(status: CommissionStatementStatus) -> DbCommissionStatementStatus {
+        match status {
+            CommissionStatementStatus::Draft => DbCommissionStatementStatus::Draft,
+            CommissionStatementStatus::Invoiced => DbCommissionStatementStatus::Invoiced,
+        }
+    }
+
+    fn statement_status_from_db(status: DbCommissionStatementStatus) -> CommissionStatementStatus {
+        match status {
+            DbCommissionStatementStatus::Draft => CommissionStatementStatus::Draft,
+            DbCommissionStatementStatus::Invoiced => CommissionStatementStatus::Invoiced,
+        }
+    }
+
+    fn line_type_to_db(line_type: CommissionLineType) -> DbCommissionLineType {
+        match line_type {
+            CommissionLineType::Commission => DbCommissionLineType::Commission,
+            CommissionLineType::PerformanceBonus => DbCommissionLineType::PerformanceBonus,
+            CommissionLineType::DiscrepancyDeduction => DbCommissionLineType::DiscrepancyDeduction,
+        }
+    }
+
+    fn line_type_from_db(line_type: DbCommissionLineType) -> CommissionLineType {
+        match line_type {
+            DbCommissionLineType::Commission => CommissionLineType::Commission,
+            DbCommissionLineType::PerformanceBonus => CommissionLineType::PerformanceBonus,
+            DbCommissionLineType::DiscrepancyDeduction => CommissionLineType::DiscrepancyDeduction,
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use banking_api::domain::{
-    AgentNetwork, AgencyBranch, AgentTerminal,
+    AgentNetwork, AgencyBranch, AgentTerminal, BranchRollupNode,
     NetworkType, NetworkStatus, BranchStatus, TerminalType, TerminalStatus, BranchType, BranchRiskRating
 };
 use banking_api::domain::person::MessagingType as DomainMessagingType;
@@ -13,7 +13,7 @@ use banking_db::models::agent_network::{
     // New models for agent network related structures
     HollidayPlanModel, TemporaryClosureModel,
     OperatingHoursModel, BranchCapabilitiesModel, SecurityAccessModel,
-    RequiredDocumentModel, ComplianceCertModel
+    RequiredDocumentModel, ComplianceCertModel, BranchRollupModel
 };
 use uuid::Uuid;
 
@@ -77,6 +77,7 @@ impl AgentNetworkMapper {
             // Operational details - normalized to UUID references
             operating_hours_id: branch.operating_hours_id,
             holiday_plan_id: branch.holiday_plan_id,
+            calendar_id: branch.calendar_id,
             temporary_closure_id: branch.temporary_closure_id, // UUID reference to temporary closure
             
             // Contact information - individual messaging fields
@@ -143,6 +144,7 @@ impl AgentNetworkMapper {
             // Operational details
             operating_hours_id: model.operating_hours_id,
             holiday_plan_id: model.holiday_plan_id,
+            calendar_id: model.calendar_id,
             temporary_closure_id: model.temporary_closure_id,
             
             // Contact information - individual messaging fields
@@ -223,6 +225,19 @@ impl AgentNetworkMapper {
         }
     }
 
+    /// Map from database BranchRollupModel to domain BranchRollupNode
+    pub fn rollup_node_from_model(model: BranchRollupModel) -> BranchRollupNode {
+        BranchRollupNode {
+            agency_branch_id: model.agency_branch_id,
+            parent_agency_branch_id: model.parent_agency_branch_id,
+            depth: model.depth,
+            account_count: model.account_count,
+            total_balance: model.total_balance,
+            subtree_account_count: model.subtree_account_count,
+            subtree_balance: model.subtree_balance,
+        }
+    }
+
     // Helper methods for enum conversions
     fn network_type_to_db(network_type: NetworkType) -> DbNetworkType {
         match network_type {
@@ -1,5 +1,5 @@
-use banking_api::domain::audit::AuditLog;
-use banking_db::models::audit::AuditLogModel;
+use banking_api::domain::audit::{AuditChainEntry, AuditEntityType, AuditLog};
+use banking_db::models::audit::{AuditEntityType as DbAuditEntityType, AuditLogModel};
 
 pub fn map_to_domain(model: &AuditLogModel) -> AuditLog {
     AuditLog {
@@ -10,9 +10,26 @@ pub fn map_to_domain(model: &AuditLogModel) -> AuditLog {
 }
 
 pub fn map_to_model(domain: &AuditLog) -> AuditLogModel {
-    AuditLogModel {
-        id: domain.id,
-        updated_at: domain.updated_at,
-        updated_by_person_id: domain.updated_by_person_id,
+    AuditLogModel::new(domain.id, domain.updated_at, domain.updated_by_person_id)
+}
+
+pub fn map_to_chain_entry(model: &AuditLogModel) -> AuditChainEntry {
+    AuditChainEntry {
+        audit_log: map_to_domain(model),
+        sequence_number: model.sequence_number,
+        prev_hash: to_hex(&model.prev_hash),
+        record_hash: to_hex(&model.record_hash),
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+pub fn map_entity_type_to_model(entity_type: AuditEntityType) -> DbAuditEntityType {
+    match entity_type {
+        AuditEntityType::Person => DbAuditEntityType::Person,
+        AuditEntityType::Location => DbAuditEntityType::Location,
+        AuditEntityType::EntityReference => DbAuditEntityType::EntityReference,
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,36 @@
+use banking_api::domain::BackDatedPosting;
+use banking_db::models::BackDatedPostingModel;
+
+pub struct BackDatedPostingMapper;
+
+impl BackDatedPostingMapper {
+    /// Map from domain BackDatedPosting to database BackDatedPostingModel
+    pub fn to_model(posting: BackDatedPosting) -> BackDatedPostingModel {
+        BackDatedPostingModel {
+            id: posting.id,
+            transaction_id: posting.transaction_id,
+            account_id: posting.account_id,
+            value_date: posting.value_date,
+            business_date: posting.business_date,
+            days_back: posting.days_back,
+            accrual_delta: posting.accrual_delta,
+            posted_by_person_id: posting.posted_by_person_id,
+            posted_at: posting.posted_at,
+        }
+    }
+
+    /// Map from database BackDatedPostingModel to domain BackDatedPosting
+    pub fn from_model(model: BackDatedPostingModel) -> BackDatedPosting {
+        BackDatedPosting {
+            id: model.id,
+            transaction_id: model.transaction_id,
+            account_id: model.account_id,
+            value_date: model.value_date,
+            business_date: model.business_date,
+            days_back: model.days_back,
+            accrual_delta: model.accrual_delta,
+            posted_by_person_id: model.posted_by_person_id,
+            posted_at: model.posted_at,
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use banking_api::domain::{
+    BranchCashCeiling, BranchCashPosition, CashTransferOrigin, CashTransferRequest, CashTransferStatus,
+    VaultAdjustment, VaultAdjustmentType,
+};
+use banking_db::models::{
+    BranchCashCeilingModel, BranchCashPositionModel, CashTransferRequestModel, DbCashTransferOrigin,
+    DbCashTransferStatus, DbVaultAdjustmentType, VaultAdjustmentModel,
+};
+
+pub struct BranchCashMapper;
+
+impl BranchCashMapper {
+    /// Map from domain BranchCashCeiling to database BranchCashCeilingModel
+    pub fn ceiling_to_model(ceiling: BranchCashCeiling) -> BranchCashCeilingModel {
+        BranchCashCeilingModel {
+            id: ceiling.id,
+            agency_branch_id: ceiling.agency_branch_id,
+            ceiling_amount: ceiling.ceiling_amount,
+            excess_destination_branch_id: ceiling.excess_destination_branch_id,
+            is_active: ceiling.is_active,
+            created_at: ceiling.created_at,
+            last_updated_at: ceiling.last_updated_at,
+            updated_by_person_id: ceiling.updated_by_person_id,
+        }
+    }
+
+    /// Map from database BranchCashCeilingModel to domain BranchCashCeiling
+    pub fn ceiling_from_model(model: BranchCashCeilingModel) -> BranchCashCeiling {
+        BranchCashCeiling {
+            id: model.id,
+            agency_branch_id: model.agency_branch_id,
+            ceiling_amount: model.ceiling_amount,
+            excess_destination_branch_id: model.excess_destination_branch_id,
+            is_active: model.is_active,
+            created_at: model.created_at,
+            last_updated_at: model.last_updated_at,
+            updated_by_person_id: model.updated_by_person_id,
+        }
+    }
+
+    pub fn position_from_model(model: BranchCashPositionModel) -> BranchCashPosition {
+        BranchCashPosition {
+            agency_branch_id: model.agency_branch_id,
+            terminal_cash: model.terminal_cash,
+            vault_adjustments: model.vault_adjustments,
+        }
+    }
+
+    /// Map from domain CashTransferRequest to database CashTransferRequestModel
+    pub fn transfer_to_model(transfer: CashTransferRequest) -> CashTransferRequestModel {
+        CashTransferRequestModel {
+            id: transfer.id,
+            from_agency_branch_id: transfer.from_agency_branch_id,
+            to_agency_branch_id: transfer.to_agency_branch_id,
+            amount: transfer.amount,
+            status: Self::transfer_status_to_db(transfer.status),
+            origin: Self::transfer_origin_to_db(transfer.origin),
+            requested_by_person_id: transfer.requested_by_person_id,
+            requested_at: transfer.requested_at,
+            dispatch_authorized_by_person_id: transfer.dispatch_authorized_by_person_id,
+            dispatch_confirmed_by_person_id: transfer.dispatch_confirmed_by_person_id,
+            dispatched_at: transfer.dispatched_at,
+            receipt_authorized_by_person_id: transfer.receipt_authorized_by_person_id,
+            receipt_confirmed_by_person_id: transfer.receipt_confirmed_by_person_id,
+            received_at: transfer.received_at,
+            cancelled_by_person_id: transfer.cancelled_by_person_id,
+            cancellation_reason: transfer.cancellation_reason,
+            last_updated_at: transfer.last_updated_at,
+        }
+    }
+
+    /// Map from database CashTransferRequestModel to domain CashTransferRequest
+    pub fn transfer_from_model(model: CashTransferRequestModel) -> CashTransferRequest {
+        CashTransferRequest {
+            id: model.id,
+            from_agency_branch_id: model.from_agency_branch_id,
+            to_agency_branch_id: model.to_agency_branch_id,
+            amount: model.amount,
+            status: Self::transfer_status_from_db(model.status),
+            origin: Self::transfer_origin_from_db(model.origin),
+            requested_by_person_id: model.requested_by_person_id,
+            requested_at: model.requested_at,
+            dispatch_authorized_by_person_id: model.dispatch_authorized_by_person_id,
+            dispatch_confirmed_by_person_id: model.dispatch_confirmed_by_person_id,
+            dispatched_at: model.dispatched_at,
+            receipt_authorized_by_person_id: model.receipt_authorized_by_person_id,
+            receipt_confirmed_by_person_id: model.receipt_confirmed_by_person_id,
+            received_at: model.received_at,
+            cancelled_by_person_id: model.cancelled_by_person_id,
+            cancellation_reason: model.cancellation_reason,
+            last_updated_at: model.last_updated_at,
+        }
+    }
+
+    pub fn adjustment_to_model(adjustment: VaultAdjustment) -> VaultAdjustmentModel {
+        VaultAdjustmentModel {
+            id: adjustment.id,
+            agency_branch_id: adjustment.agency_branch_id,
+            cash_transfer_request_id: adjustment.cash_transfer_request_id,
+            adjustment_type: match adjustment.adjustment_type {
+                VaultAdjustmentType::TransferDispatch => DbVaultAdjustmentType::TransferDispatch,
+                VaultAdjustmentType::TransferReceipt => DbVaultAdjustmentType::TransferReceipt,
+            },
+            amount: adjustment.amount,
+            posted_at: adjustment.posted_at,
+            posted_by_person_id: adjustment.posted_by_person_id,
+        }
+    }
+
+    pub fn adjustment_from_model(model: VaultAdjustmentModel) -> VaultAdjustment {
+        VaultAdjustment {
+            id: model.id,
+            agency_branch_id: model.agency_branch_id,
+            cash_transfer_request_id: model.cash_transfer_request_id,
+            adjustment_type: match model.adjustment_type {
+                DbVaultAdjustmentType::TransferDispatch => VaultAdjustmentType::TransferDispatch,
+                DbVaultAdjustmentType::TransferReceipt => VaultAdjustmentType::TransferReceipt,
+            },
+            amount: model.amount,
+            posted_at: model.posted_at,
+            posted_by_person_id: model.posted_by_person_id,
+        }
+    }
+
+    pub fn transfer_status_to_db(status: CashTransferStatus) -> DbCashTransferStatus {
+        match status {
+            CashTransferStatus::Requested => DbCashTransferStatus::Requested,
+            CashTransferStatus::InTransit => DbCashTransferStatus::InTransit,
+            CashTransferStatus::Received => DbCashTransferStatus::Received,
+            CashTransferStatus::Cancelled => DbCashTransferStatus::Cancelled,
+        }
+    }
+
+    pub fn transfer_status_from_db(status: DbCashTransferStatus) -> CashTransferStatus {
+        match status {
+            DbCashTransferStatus::Requested => CashTransferStatus::Requested,
+            DbCashTransferStatus::InTransit => CashTransferStatus::InTransit,
+            DbCashTransferStatus::Received => CashTransferStatus::Received,
+            DbCashTransferStatus::Cancelled => CashTransferStatus::Cancelled,
+        }
+    }
+
+    fn transfer_origin_to_db(origin: CashTransferOrigin) -> DbCashTransferOrigin {
+        match origin {
+            CashTransferOrigin::CeilingBreach => DbCashTransferOrigin::CeilingBreach,
+            CashTransferOrigin::Manual => DbCashTransferOrigin::Manual,
+        }
+    }
+
+    fn transfer_origin_from_db(origin: DbCashTransferOrigin) -> CashTransferOrigin {
+        match origin {
+            DbCashTransferOrigin::CeilingBreach => CashTransferOrigin::CeilingBreach,
+            DbCashTransferOrigin::Manual => CashTransferOrigin::Manual,
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        AccountBundle, BundleAccount, BundleComponent, BundleComponentRole, BundleFeeAdjustment, BundlePricingMarker,
+        BundleStatus, ProductBundle,
+    },
+};
+use banking_db::models::{
+    AccountBundleModel, BundleAccountModel, BundlePricingMarkerModel, DbBundleComponentRole, DbBundleStatus,
+    ProductBundleModel,
+};
+
+pub struct BundleMapper;
+
+impl BundleMapper {
+    /// Map from domain ProductBundle to database ProductBundleModel
+    pub fn product_bundle_to_model(bundle: ProductBundle) -> BankingResult<ProductBundleModel> {
+        let components = serde_json::to_string(&bundle.components)
+            .map_err(|e| BankingError::Internal(format!("Failed to serialize bundle components: {e}")))?;
+        let fee_adjustments = serde_json::to_string(&bundle.fee_adjustments)
+            .map_err(|e| BankingError::Internal(format!("Failed to serialize bundle fee adjustments: {e}")))?;
+
+        Ok(ProductBundleModel {
+            id: bundle.id,
+            bundle_code: bundle.bundle_code,
+            name: bundle.name,
+            components,
+            fee_adjustments,
+            is_active: bundle.is_active,
+            created_at: bundle.created_at,
+            last_updated_at: bundle.last_updated_at,
+            updated_by_person_id: bundle.updated_by_person_id,
+        })
+    }
+
+    /// Map from database ProductBundleModel to domain ProductBundle
+    pub fn product_bundle_from_model(model: ProductBundleModel) -> BankingResult<ProductBundle> {
+        let components: Vec<BundleComponent> = serde_json::from_str(&model.components)
+            .map_err(|e| BankingError::Internal(format!("Invalid components for bundle {}: {e}", model.bundle_code)))?;
+        let fee_adjustments: Vec<BundleFeeAdjustment> = serde_json::from_str(&model.fee_adjustments)
+            .map_err(|e| BankingError::Internal(format!("Invalid fee adjustments for bundle {}: {e}", model.bundle_code)))?;
+
+        Ok(ProductBundle {
+            id: model.id,
+            bundle_code: model.bundle_code,
+            name: model.name,
+            components,
+            fee_adjustments,
+            is_active: model.is_active,
+            created_at: model.created_at,
+            last_updated_at: model.last_updated_at,
+            updated_by_person_id: model.updated_by_person_id,
+        })
+    }
+
+    /// Map from domain AccountBundle to database AccountBundleModel
+    pub fn account_bundle_to_model(bundle: AccountBundle) -> AccountBundleModel {
+        AccountBundleModel {
+            id: bundle.id,
+            product_bundle_id: bundle.product_bundle_id,
+            bundle_code: bundle.bundle_code,
+            customer_id: bundle.customer_id,
+            status: Self::status_to_db(bundle.status),
+            orchestration_id: bundle.orchestration_id,
+            opened_at: bundle.opened_at,
+            dissolved_at: bundle.dissolved_at,
+            dissolution_reason: bundle.dissolution_reason,
+        }
+    }
+
+    /// Map from database AccountBundleModel to domain AccountBundle
+    pub fn account_bundle_from_model(model: AccountBundleModel) -> AccountBundle {
+        AccountBundle {
+            id: model.id,
+            product_bundle_id: model.product_bundle_id,
+            bundle_code: model.bundle_code,
+            customer_id: model.customer_id,
+            status: Self::status_from_db(model.status),
+            orchestration_id: model.orchestration_id,
+            opened_at: model.opened_at,
+            dissolved_at: model.dissolved_at,
+            dissolution_reason: model.dissolution_reason,
+        }
+    }
+
+    pub fn bundle_account_to_model(link: BundleAccount) -> BundleAccountModel {
+        BundleAccountModel {
+            id: link.id,
+            account_bundle_id: link.account_bundle_id,
+            account_id: link.account_id,
+            role: Self::role_to_db(link.role),
+            required: link.required,
+            linked_at: link.linked_at,
+            unlinked_at: link.unlinked_at,
+        }
+    }
+
+    pub fn bundle_account_from_model(model: BundleAccountModel) -> BundleAccount {
+        BundleAccount {
+            id: model.id,
+            account_bundle_id: model.account_bundle_id,
+            account_id: model.account_id,
+            role: Self::role_from_db(model.role),
+            required: model.required,
+            linked_at: model.linked_at,
+            unlinked_at: model.unlinked_at,
+        }
+    }
+
+    pub fn marker_to_model(marker: BundlePricingMarker) -> BundlePricingMarkerModel {
+        BundlePricingMarkerModel {
+            id: marker.id,
+            account_bundle_id: marker.account_bundle_id,
+            account_id: marker.account_id,
+            fee_category: marker.fee_category,
+            discount_percentage: marker.discount_percentage,
+            depends_on_account_id: marker.depends_on_account_id,
+            created_at: marker.created_at,
+            removed_at: marker.removed_at,
+        }
+    }
+
+    pub fn marker_from_model(model: BundlePricingMarkerModel) -> BundlePricingMarker {
+        BundlePricingMarker {
+            id: model.id,
+            account_bundle_id: model.account_bundle_id,
+            account_id: model.account_id,
+            fee_category: model.fee_category,
+            discount_percentage: model.discount_percentage,
+            depends_on_account_id: model.depends_on_account_id,
+            created_at: model.created_at,
+            removed_at: model.removed_at,
+        }
+    }
+
+    pub fn status_to_db(status: BundleStatus) -> DbBundleStatus {
+        match status {
+            BundleStatus::Opening => DbBundleStatus::Opening,
+            BundleStatus::Active => DbBundleStatus::Active,
+            BundleStatus::Dissolved => DbBundleStatus::Dissolved,
+            BundleStatus::Cancelled => DbBundleStatus::Cancelled,
+        }
+    }
+
+    fn status_from_db(status: DbBundleStatus) -> BundleStatus {
+        match status {
+            DbBundleStatus::Opening => BundleStatus::Opening,
+            DbBundleStatus::Active => BundleStatus::Active,
+            DbBundleStatus::Dissolved => BundleStatus::Dissolved,
+            DbBundleStatus::Cancelled => BundleStatus::Cancelled,
+        }
+    }
+
+    fn role_to_db(role: BundleComponentRole) -> DbBundleComponentRole {
+        match role {
+            BundleComponentRole::Current => DbBundleComponentRole::Current,
+            BundleComponentRole::Savings => DbBundleComponentRole::Savings,
+            BundleComponentRole::MobileWallet => DbBundleComponentRole::MobileWallet,
+        }
+    }
+
+    fn role_from_db(role: DbBundleComponentRole) -> BundleComponentRole {
+        match role {
+            DbBundleComponentRole::Current => BundleComponentRole::Current,
+            DbBundleComponentRole::Savings => BundleComponentRole::Savings,
+            DbBundleComponentRole::MobileWallet => BundleComponentRole::MobileWallet,
+        }
+    }
+}
@@ -4,7 +4,7 @@ use banking_api::domain::{
     DormancyRisk, CasaComplianceStatus, OverdraftProcessingJob, ProcessingJobStatus,
     OverdraftLimitAdjustment, CasaApprovalStatus,
     InterestPostingRecord,
-    InterestType, PostingStatus, AccountType
+    InterestType, PostingStatus, AccountType, OverdraftInterestAccrual, OverdraftPosition,
 };
 use banking_api::domain::customer::{KycStatus as DomainKycStatus, RiskRating as DomainRiskRating};
 use banking_db::models::{
@@ -16,7 +16,8 @@ use banking_db::models::{
     OverdraftProcessingJob as DbOverdraftProcessingJob, ProcessingJobStatus as DbProcessingJobStatus,
     OverdraftLimitAdjustment as DbOverdraftLimitAdjustment, CasaApprovalStatus as DbCasaApprovalStatus,
     InterestPostingRecord as DbInterestPostingRecord,
-    InterestType as DbInterestType, PostingStatus as DbPostingStatus
+    InterestType as DbInterestType, PostingStatus as DbPostingStatus,
+    OverdraftInterestAccrual as DbOverdraftInterestAccrual, OverdraftPosition as DbOverdraftPosition,
 };
 use banking_db::models::customer::{KycStatus as DbKycStatus, RiskRating as DbRiskRating};
 
@@ -139,6 +140,43 @@ impl CasaMapper {
         }
     }
 
+
+    /// Map from domain OverdraftInterestAccrual to database model
+    pub fn overdraft_interest_accrual_to_model(accrual: OverdraftInterestAccrual) -> DbOverdraftInterestAccrual {
+        DbOverdraftInterestAccrual {
+            id: accrual.id,
+            account_id: accrual.account_id,
+            accrual_date: accrual.accrual_date,
+            position: Self::overdraft_position_to_db(accrual.position),
+            authorized_amount: accrual.authorized_amount,
+            authorized_rate: accrual.authorized_rate,
+            authorized_interest: accrual.authorized_interest,
+            unauthorized_amount: accrual.unauthorized_amount,
+            unauthorized_rate: accrual.unauthorized_rate,
+            unauthorized_interest: accrual.unauthorized_interest,
+            consecutive_unauthorized_days: accrual.consecutive_unauthorized_days,
+            created_at: accrual.created_at,
+        }
+    }
+
+    /// Map from database OverdraftInterestAccrual to domain model
+    pub fn overdraft_interest_accrual_from_model(model: DbOverdraftInterestAccrual) -> OverdraftInterestAccrual {
+        OverdraftInterestAccrual {
+            id: model.id,
+            account_id: model.account_id,
+            accrual_date: model.accrual_date,
+            position: Self::overdraft_position_from_db(model.position),
+            authorized_amount: model.authorized_amount,
+            authorized_rate: model.authorized_rate,
+            authorized_interest: model.authorized_interest,
+            unauthorized_amount: model.unauthorized_amount,
+            unauthorized_rate: model.unauthorized_rate,
+            unauthorized_interest: model.unauthorized_interest,
+            consecutive_unauthorized_days: model.consecutive_unauthorized_days,
+            created_at: model.created_at,
+        }
+    }
+
     /// Map from domain OverdraftProcessingJob to database model
     pub fn processing_job_to_model(job: OverdraftProcessingJob) -> DbOverdraftProcessingJob {
         DbOverdraftProcessingJob {
@@ -381,6 +419,23 @@ impl CasaMapper {
         }
     }
 
+
+    pub fn overdraft_position_to_db(position: OverdraftPosition) -> DbOverdraftPosition {
+        match position {
+            OverdraftPosition::None => DbOverdraftPosition::None,
+            OverdraftPosition::Authorized => DbOverdraftPosition::Authorized,
+            OverdraftPosition::Unauthorized => DbOverdraftPosition::Unauthorized,
+        }
+    }
+
+    pub fn overdraft_position_from_db(position: DbOverdraftPosition) -> OverdraftPosition {
+        match position {
+            DbOverdraftPosition::None => OverdraftPosition::None,
+            DbOverdraftPosition::Authorized => OverdraftPosition::Authorized,
+            DbOverdraftPosition::Unauthorized => OverdraftPosition::Unauthorized,
+        }
+    }
+
     pub fn processing_job_status_to_db(status: ProcessingJobStatus) -> DbProcessingJobStatus {
         match status {
             ProcessingJobStatus::Scheduled => DbProcessingJobStatus::Scheduled,
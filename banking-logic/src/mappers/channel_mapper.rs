@@ -1,9 +1,10 @@
 use banking_api::{domain::{channel::{
-    Channel, ChannelFeeCalculationMethod, ChannelFeeTier, ChannelFeeType, ChannelStatus, Discrepancy, FeeItem, FeeSchedule, ReconciliationReport, ReconciliationStatus
+    Channel, ChannelFeeCalculationMethod, ChannelFeeTier, ChannelFeeType, ChannelLimit, ChannelStatus, ChannelUsage,
+    Discrepancy, DiscrepancyType, FeeItem, FeeSchedule, ReconciliationReport, ReconciliationStatus
 }}, ChannelType};
 use banking_db::models::channel::{
     ChannelModel, FeeScheduleModel, FeeItemModel, FeeTierModel,
-    ChannelReconciliationReportModel, ReconciliationDiscrepancyModel
+    ChannelReconciliationReportModel, ReconciliationDiscrepancyModel, ChannelLimitModel, ChannelUsageModel
 };
 use chrono::Utc;
 
@@ -156,7 +157,7 @@ impl ChannelMapper {
             total_amount: report.total_amount,
             status,
             generated_at: report.generated_at,
-            completed_at: None,
+            completed_at: report.completed_at,
             created_at: Utc::now(),
         }
     }
@@ -200,17 +201,59 @@ impl ChannelMapper {
         Discrepancy {
             id: model.id,
             report_id: model.report_id,
+            discrepancy_type: Self::discrepancy_type_from_db(model.discrepancy_type),
             transaction_id: model.transaction_id,
+            external_reference: model.external_reference,
             description,
             expected_amount: model.expected_amount,
             actual_amount: model.actual_amount,
             difference: model.difference,
             resolved: model.resolved,
             resolution_notes: model.resolution_notes,
+            resolved_by: model.resolved_by,
+            resolved_at: model.resolved_at,
             created_at: model.created_at,
         }
     }
 
+    /// Convert domain Discrepancy to database ReconciliationDiscrepancyModel
+    pub fn to_discrepancy_model(discrepancy: Discrepancy) -> ReconciliationDiscrepancyModel {
+        ReconciliationDiscrepancyModel {
+            id: discrepancy.id,
+            report_id: discrepancy.report_id,
+            discrepancy_type: Self::discrepancy_type_to_db(discrepancy.discrepancy_type),
+            transaction_id: discrepancy.transaction_id,
+            external_reference: discrepancy.external_reference,
+            description: discrepancy.description,
+            expected_amount: discrepancy.expected_amount,
+            actual_amount: discrepancy.actual_amount,
+            difference: discrepancy.difference,
+            resolved: discrepancy.resolved,
+            resolution_notes: discrepancy.resolution_notes,
+            resolved_by: discrepancy.resolved_by,
+            resolved_at: discrepancy.resolved_at,
+            created_at: discrepancy.created_at,
+        }
+    }
+
+    pub fn discrepancy_type_to_db(discrepancy_type: DiscrepancyType) -> banking_db::models::channel::DiscrepancyType {
+        match discrepancy_type {
+            DiscrepancyType::MissingOurs => banking_db::models::channel::DiscrepancyType::MissingOurs,
+            DiscrepancyType::MissingTheirs => banking_db::models::channel::DiscrepancyType::MissingTheirs,
+            DiscrepancyType::AmountMismatch => banking_db::models::channel::DiscrepancyType::AmountMismatch,
+            DiscrepancyType::Duplicate => banking_db::models::channel::DiscrepancyType::Duplicate,
+        }
+    }
+
+    pub fn discrepancy_type_from_db(discrepancy_type: banking_db::models::channel::DiscrepancyType) -> DiscrepancyType {
+        match discrepancy_type {
+            banking_db::models::channel::DiscrepancyType::MissingOurs => DiscrepancyType::MissingOurs,
+            banking_db::models::channel::DiscrepancyType::MissingTheirs => DiscrepancyType::MissingTheirs,
+            banking_db::models::channel::DiscrepancyType::AmountMismatch => DiscrepancyType::AmountMismatch,
+            banking_db::models::channel::DiscrepancyType::Duplicate => DiscrepancyType::Duplicate,
+        }
+    }
+
     /// Convert domain FeeItem to database FeeItemModel
     pub fn to_fee_item_model(item: FeeItem, schedule_id: uuid::Uuid) -> FeeItemModel {
         let fee_type = match item.fee_type {
@@ -362,4 +405,43 @@ impl ChannelMapper {
             created_at: Utc::now(),
         }
     }
-}
\ No newline at end of file
+
+    /// Convert domain ChannelLimit to database ChannelLimitModel
+    pub fn to_channel_limit_model(limit: ChannelLimit) -> ChannelLimitModel {
+        ChannelLimitModel {
+            id: limit.id,
+            channel_id: limit.channel_id,
+            currency: limit.currency,
+            per_transaction_max: limit.per_transaction_max,
+            daily_max: limit.daily_max,
+            monthly_max: limit.monthly_max,
+            max_count_per_day: limit.max_count_per_day,
+            created_at: limit.created_at,
+            updated_at: limit.updated_at,
+        }
+    }
+
+    /// Convert database ChannelLimitModel to domain ChannelLimit
+    pub fn from_channel_limit_model(model: ChannelLimitModel) -> ChannelLimit {
+        ChannelLimit {
+            id: model.id,
+            channel_id: model.channel_id,
+            currency: model.currency,
+            per_transaction_max: model.per_transaction_max,
+            daily_max: model.daily_max,
+            monthly_max: model.monthly_max,
+            max_count_per_day: model.max_count_per_day,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+
+    /// Convert database ChannelUsageModel to domain ChannelUsage
+    pub fn from_channel_usage_model(model: ChannelUsageModel) -> ChannelUsage {
+        ChannelUsage {
+            daily_amount: model.daily_amount,
+            daily_count: model.daily_count,
+            monthly_amount: model.monthly_amount,
+        }
+    }
+}
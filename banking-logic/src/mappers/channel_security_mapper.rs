@@ -0,0 +1,94 @@
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{ChannelRestriction, ChannelSecurityEvent, ChannelSecurityEventType},
+};
+use banking_db::models::{ChannelRestrictionModel, ChannelSecurityEventModel, DbChannelSecurityEventType};
+use crate::mappers::ComplianceMapper;
+
+pub struct ChannelSecurityMapper;
+
+impl ChannelSecurityMapper {
+    /// Map from domain ChannelSecurityEvent to database ChannelSecurityEventModel
+    pub fn event_to_model(event: ChannelSecurityEvent) -> ChannelSecurityEventModel {
+        ChannelSecurityEventModel {
+            id: event.id,
+            channel_id: event.channel_id,
+            customer_id: event.customer_id,
+            event_type: Self::event_type_to_db(event.event_type),
+            severity: ComplianceMapper::domain_severity_to_db_severity(event.severity),
+            context: event.context.map(|context| context.to_string()),
+            occurred_at: event.occurred_at,
+            recorded_at: event.recorded_at,
+        }
+    }
+
+    /// Map from database ChannelSecurityEventModel to domain ChannelSecurityEvent
+    pub fn event_from_model(model: ChannelSecurityEventModel) -> BankingResult<ChannelSecurityEvent> {
+        let context = model.context
+            .as_deref()
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|e| BankingError::Internal(format!("Invalid context for security event {}: {e}", model.id)))?;
+
+        Ok(ChannelSecurityEvent {
+            id: model.id,
+            channel_id: model.channel_id,
+            customer_id: model.customer_id,
+            event_type: Self::event_type_from_db(model.event_type),
+            severity: ComplianceMapper::db_severity_to_domain_severity(model.severity),
+            context,
+            occurred_at: model.occurred_at,
+            recorded_at: model.recorded_at,
+        })
+    }
+
+    /// Map from domain ChannelRestriction to database ChannelRestrictionModel
+    pub fn restriction_to_model(restriction: ChannelRestriction) -> ChannelRestrictionModel {
+        ChannelRestrictionModel {
+            id: restriction.id,
+            customer_id: restriction.customer_id,
+            channel_id: restriction.channel_id,
+            reason: restriction.reason,
+            rule_code: restriction.rule_code,
+            starts_at: restriction.starts_at,
+            expires_at: restriction.expires_at,
+            lifted_at: restriction.lifted_at,
+            lifted_by_person_id: restriction.lifted_by_person_id,
+            created_at: restriction.created_at,
+        }
+    }
+
+    /// Map from database ChannelRestrictionModel to domain ChannelRestriction
+    pub fn restriction_from_model(model: ChannelRestrictionModel) -> ChannelRestriction {
+        ChannelRestriction {
+            id: model.id,
+            customer_id: model.customer_id,
+            channel_id: model.channel_id,
+            reason: model.reason,
+            rule_code: model.rule_code,
+            starts_at: model.starts_at,
+            expires_at: model.expires_at,
+            lifted_at: model.lifted_at,
+            lifted_by_person_id: model.lifted_by_person_id,
+            created_at: model.created_at,
+        }
+    }
+
+    pub fn event_type_to_db(event_type: ChannelSecurityEventType) -> DbChannelSecurityEventType {
+        match event_type {
+            ChannelSecurityEventType::FailedAuth => DbChannelSecurityEventType::FailedAuth,
+            ChannelSecurityEventType::DeviceChange => DbChannelSecurityEventType::DeviceChange,
+            ChannelSecurityEventType::SimSwapSuspected => DbChannelSecurityEventType::SimSwapSuspected,
+            ChannelSecurityEventType::PasswordReset => DbChannelSecurityEventType::PasswordReset,
+        }
+    }
+
+    fn event_type_from_db(event_type: DbChannelSecurityEventType) -> ChannelSecurityEventType {
+        match event_type {
+            DbChannelSecurityEventType::FailedAuth => ChannelSecurityEventType::FailedAuth,
+            DbChannelSecurityEventType::DeviceChange => ChannelSecurityEventType::DeviceChange,
+            DbChannelSecurityEventType::SimSwapSuspected => ChannelSecurityEventType::SimSwapSuspected,
+            DbChannelSecurityEventType::PasswordReset => ChannelSecurityEventType::PasswordReset,
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use banking_api::domain::{
+    Cheque, ChequeBook, ChequeBookStatus, ChequeReturnReason, ChequeStatus,
+};
+use banking_db::models::{
+    ChequeBookModel, ChequeModel, DbChequeBookStatus, DbChequeReturnReason, DbChequeStatus,
+};
+
+pub struct ChequeMapper;
+
+impl ChequeMapper {
+    /// Map from domain ChequeBook to database ChequeBookModel
+    pub fn book_to_model(book: ChequeBook) -> ChequeBookModel {
+        ChequeBookModel {
+            id: book.id,
+            account_id: book.account_id,
+            first_serial_number: book.first_serial_number,
+            last_serial_number: book.last_serial_number,
+            status: Self::book_status_to_db(book.status),
+            requested_at: book.requested_at,
+            requested_by_person_id: book.requested_by_person_id,
+            issued_at: book.issued_at,
+        }
+    }
+
+    /// Map from database ChequeBookModel to domain ChequeBook
+    pub fn book_from_model(model: ChequeBookModel) -> ChequeBook {
+        ChequeBook {
+            id: model.id,
+            account_id: model.account_id,
+            first_serial_number: model.first_serial_number,
+            last_serial_number: model.last_serial_number,
+            status: Self::book_status_from_db(model.status),
+            requested_at: model.requested_at,
+            requested_by_person_id: model.requested_by_person_id,
+            issued_at: model.issued_at,
+        }
+    }
+
+    /// Map from domain Cheque to database ChequeModel
+    pub fn to_model(cheque: Cheque) -> ChequeModel {
+        ChequeModel {
+            id: cheque.id,
+            cheque_book_id: cheque.cheque_book_id,
+            account_id: cheque.account_id,
+            serial_number: cheque.serial_number,
+            status: Self::cheque_status_to_db(cheque.status),
+            amount: cheque.amount,
+            presented_at: cheque.presented_at,
+            paid_at: cheque.paid_at,
+            transaction_id: cheque.transaction_id,
+            return_reason: cheque.return_reason.map(Self::return_reason_to_db),
+            stop_reason: cheque.stop_reason,
+            stopped_at: cheque.stopped_at,
+            stopped_by_person_id: cheque.stopped_by_person_id,
+            last_updated_at: cheque.last_updated_at,
+        }
+    }
+
+    /// Map from database ChequeModel to domain Cheque
+    pub fn from_model(model: ChequeModel) -> Cheque {
+        Cheque {
+            id: model.id,
+            cheque_book_id: model.cheque_book_id,
+            account_id: model.account_id,
+            serial_number: model.serial_number,
+            status: Self::cheque_status_from_db(model.status),
+            amount: model.amount,
+            presented_at: model.presented_at,
+            paid_at: model.paid_at,
+            transaction_id: model.transaction_id,
+            return_reason: model.return_reason.map(Self::return_reason_from_db),
+            stop_reason: model.stop_reason,
+            stopped_at: model.stopped_at,
+            stopped_by_person_id: model.stopped_by_person_id,
+            last_updated_at: model.last_updated_at,
+        }
+    }
+
+    fn book_status_to_db(status: ChequeBookStatus) -> DbChequeBookStatus {
+        match status {
+            ChequeBookStatus::Requested => DbChequeBookStatus::Requested,
+            ChequeBookStatus::Issued => DbChequeBookStatus::Issued,
+            ChequeBookStatus::Cancelled => DbChequeBookStatus::Cancelled,
+        }
+    }
+
+    fn book_status_from_db(status: DbChequeBookStatus) -> ChequeBookStatus {
+        match status {
+            DbChequeBookStatus::Requested => ChequeBookStatus::Requested,
+            DbChequeBookStatus::Issued => ChequeBookStatus::Issued,
+            DbChequeBookStatus::Cancelled => ChequeBookStatus::Cancelled,
+        }
+    }
+
+    fn cheque_status_to_db(status: ChequeStatus) -> DbChequeStatus {
+        match status {
+            ChequeStatus::Unused => DbChequeStatus::Unused,
+            ChequeStatus::Presented => DbChequeStatus::Presented,
+            ChequeStatus::Paid => DbChequeStatus::Paid,
+            ChequeStatus::Bounced => DbChequeStatus::Bounced,
+            ChequeStatus::Stopped => DbChequeStatus::Stopped,
+        }
+    }
+
+    fn cheque_status_from_db(status: DbChequeStatus) -> ChequeStatus {
+        match status {
+            DbChequeStatus::Unused => ChequeStatus::Unused,
+            DbChequeStatus::Presented => ChequeStatus::Presented,
+            DbChequeStatus::Paid => ChequeStatus::Paid,
+            DbChequeStatus::Bounced => ChequeStatus::Bounced,
+            DbChequeStatus::Stopped => ChequeStatus::Stopped,
+        }
+    }
+
+    fn return_reason_to_db(reason: ChequeReturnReason) -> DbChequeReturnReason {
+        match reason {
+            ChequeReturnReason::InsufficientFunds => DbChequeReturnReason::InsufficientFunds,
+            ChequeReturnReason::PaymentStopped => DbChequeReturnReason::PaymentStopped,
+            ChequeReturnReason::DuplicatePresentment => DbChequeReturnReason::DuplicatePresentment,
+            ChequeReturnReason::UnknownCheque => DbChequeReturnReason::UnknownCheque,
+            ChequeReturnReason::AccountNotOperational => DbChequeReturnReason::AccountNotOperational,
+        }
+    }
+
+    fn return_reason_from_db(reason: DbChequeReturnReason) -> ChequeReturnReason {
+        match reason {
+            DbChequeReturnReason::InsufficientFunds => ChequeReturnReason::InsufficientFunds,
+            DbChequeReturnReason::PaymentStopped => ChequeReturnReason::PaymentStopped,
+            DbChequeReturnReason::DuplicatePresentment => ChequeReturnReason::DuplicatePresentment,
+            DbChequeReturnReason::UnknownCheque => ChequeReturnReason::UnknownCheque,
+            DbChequeReturnReason::AccountNotOperational => ChequeReturnReason::AccountNotOperational,
+        }
+    }
+}
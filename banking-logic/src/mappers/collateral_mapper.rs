@@ -121,7 +121,9 @@ impl CollateralMapper {
             valuation_date: valuation.valuation_date,
             valuation_method: Self::valuation_method_to_db(valuation.valuation_method),
             market_value: valuation.market_value,
+            currency: valuation.currency,
             forced_sale_value: valuation.forced_sale_value,
+            valuer_person_id: valuation.valuer_person_id,
             appraiser_name: valuation.appraiser_name,
             appraiser_license: valuation.appraiser_license,
             valuation_report_reference: valuation.valuation_report_reference,
@@ -141,7 +143,9 @@ impl CollateralMapper {
             valuation_date: model.valuation_date,
             valuation_method: Self::valuation_method_from_db(model.valuation_method),
             market_value: model.market_value,
+            currency: model.currency,
             forced_sale_value: model.forced_sale_value,
+            valuer_person_id: model.valuer_person_id,
             appraiser_name: model.appraiser_name,
             appraiser_license: model.appraiser_license,
             valuation_report_reference: model.valuation_report_reference,
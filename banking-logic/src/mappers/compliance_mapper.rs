@@ -2,13 +2,15 @@ use banking_api::domain::{
     KycResult, KycCheck, CheckResult, ScreeningResult, ScreeningType, SanctionsMatch,
     RiskLevel, MonitoringResult, ComplianceAlert, Severity, AlertStatus,
     compliance::ComplianceAlertType as AlertType,
-    SarData, SarStatus, UboVerificationResult, UboLink, MonitoringRules
+    SarData, SarStatus, UboVerificationResult, UboLink, MonitoringRules,
+    CustomerRiskAssessment, CustomerRiskFactors, RiskRating,
 };
 use banking_db::models::{
     // Domain-aligned models
     KycResultModel, KycCheckModel, ScreeningResultModel, SanctionsMatchModel,
     ComplianceAlertModel, SarDataModel, UboVerificationResultModel, UboLinkModel,
     MonitoringResultModel, MonitoringRulesModel, ComplianceResultModel,
+    ComplianceRiskScoreModel, CustomerRiskFactorsModel,
     // Legacy models for repository compatibility
     SanctionsScreeningModel,
     // Enums
@@ -23,6 +25,9 @@ use heapless::String as HeaplessString;
 use chrono::Utc;
 use uuid::Uuid;
 
+/// Calculation method recorded on scores from the configured factor rules
+pub const RISK_CALCULATION_METHOD: &str = "FactorScoring";
+
 pub struct ComplianceMapper;
 
 impl ComplianceMapper {
@@ -108,6 +113,29 @@ impl ComplianceMapper {
         }
     }
 
+    /// Map from database ComplianceAlertModel to domain ComplianceAlert
+    pub fn compliance_alert_from_model(model: ComplianceAlertModel) -> ComplianceAlert {
+        let alert = model.alert_data;
+        ComplianceAlert {
+            id: alert.id,
+            customer_id: alert.customer_id,
+            account_id: alert.account_id,
+            transaction_id: alert.transaction_id,
+            alert_type: Self::db_alert_type_to_domain_alert_type(alert.alert_type),
+            description: alert.description,
+            severity: Self::db_severity_to_domain_severity(alert.severity),
+            triggered_at: alert.triggered_at,
+            status: Self::db_alert_status_to_domain_alert_status(alert.status),
+            assigned_to_person_id: alert.assigned_to_person_id,
+            resolved_at: alert.resolved_at,
+            resolved_by_person_id: alert.resolved_by_person_id,
+            resolution_notes: alert.resolution_notes,
+            metadata: alert.metadata,
+            created_at: alert.created_at,
+            last_updated_at: alert.last_updated_at,
+        }
+    }
+
     /// Map from domain SarData to database SarDataModel
     pub fn sar_data_to_model(sar_data: SarData) -> SarDataModel {
         SarDataModel {
@@ -134,12 +162,58 @@ impl ComplianceMapper {
             supporting_transaction_id_17: sar_data.supporting_transaction_id_17,
             supporting_transaction_id_18: sar_data.supporting_transaction_id_18,
             supporting_transaction_id_19: sar_data.supporting_transaction_id_19,
+            narrative: sar_data.narrative,
+            customer_full_name: sar_data.customer_full_name,
+            alert_ids: sar_data.alert_ids,
+            account_ids: sar_data.account_ids,
             generated_at: sar_data.generated_at,
             status: Self::domain_sar_status_to_db_sar_status(sar_data.status),
+            created_by_person_id: sar_data.created_by_person_id,
+            submitted_by_person_id: sar_data.submitted_by_person_id,
+            submitted_at: sar_data.submitted_at,
+            last_updated_at: sar_data.last_updated_at,
+        }
+    }
+
+    /// Map from database SarDataModel to domain SarData
+    pub fn sar_data_from_model(model: SarDataModel) -> SarData {
+        SarData {
+            id: model.id,
+            customer_id: model.customer_id,
+            reason_id: model.reason_id,
+            additional_details: model.additional_details,
+            supporting_transaction_id_01: model.supporting_transaction_id_01,
+            supporting_transaction_id_02: model.supporting_transaction_id_02,
+            supporting_transaction_id_03: model.supporting_transaction_id_03,
+            supporting_transaction_id_04: model.supporting_transaction_id_04,
+            supporting_transaction_id_05: model.supporting_transaction_id_05,
+            supporting_transaction_id_06: model.supporting_transaction_id_06,
+            supporting_transaction_id_07: model.supporting_transaction_id_07,
+            supporting_transaction_id_08: model.supporting_transaction_id_08,
+            supporting_transaction_id_09: model.supporting_transaction_id_09,
+            supporting_transaction_id_10: model.supporting_transaction_id_10,
+            supporting_transaction_id_11: model.supporting_transaction_id_11,
+            supporting_transaction_id_12: model.supporting_transaction_id_12,
+            supporting_transaction_id_13: model.supporting_transaction_id_13,
+            supporting_transaction_id_14: model.supporting_transaction_id_14,
+            supporting_transaction_id_15: model.supporting_transaction_id_15,
+            supporting_transaction_id_16: model.supporting_transaction_id_16,
+            supporting_transaction_id_17: model.supporting_transaction_id_17,
+            supporting_transaction_id_18: model.supporting_transaction_id_18,
+            supporting_transaction_id_19: model.supporting_transaction_id_19,
+            narrative: model.narrative,
+            customer_full_name: model.customer_full_name,
+            alert_ids: model.alert_ids,
+            account_ids: model.account_ids,
+            generated_at: model.generated_at,
+            status: Self::db_sar_status_to_domain_sar_status(model.status),
+            created_by_person_id: model.created_by_person_id,
+            submitted_by_person_id: model.submitted_by_person_id,
+            submitted_at: model.submitted_at,
+            last_updated_at: model.last_updated_at,
         }
     }
 
-    /// Map from domain UboVerificationResult to database UboVerificationResultModel
     pub fn ubo_verification_result_to_model(ubo_result: UboVerificationResult) -> UboVerificationResultModel {
         UboVerificationResultModel {
             corporate_customer_id: ubo_result.corporate_customer_id,
@@ -196,11 +270,13 @@ impl ComplianceMapper {
     /// Legacy compatibility - Map from domain KycResult to database KycRecordModel
     /// Legacy compatibility - Map from domain ScreeningResult to database SanctionsScreeningModel
     pub fn screening_result_to_screening_model(screening_result: ScreeningResult) -> SanctionsScreeningModel {
+        let outcome = if screening_result.found_sanctions_match_01.is_some() { "Match" } else { "Clear" };
         SanctionsScreeningModel {
             id: Uuid::new_v4(),
             customer_id: screening_result.customer_id,
+            screening_type: Self::domain_screening_type_to_db_screening_type(screening_result.screening_type),
             screening_date: screening_result.screened_at,
-            screening_result: Self::screening_type_to_heapless_string(screening_result.screening_type),
+            screening_result: HeaplessString::try_from(outcome).unwrap_or_default(),
             match_details: None, // TODO: Convert matches_found to JSON
             risk_score: None,
             screening_provider: HeaplessString::try_from("DefaultProvider").unwrap_or_default(),
@@ -261,6 +337,8 @@ impl ComplianceMapper {
             AlertType::SuspiciousPattern => DbAlertType::SuspiciousPattern,
             AlertType::GeographicAnomaly => DbAlertType::GeographicAnomaly,
             AlertType::CrossBorderTransaction => DbAlertType::CrossBorderTransaction,
+            AlertType::SanctionsMatch => DbAlertType::SanctionsMatch,
+            AlertType::UnauthorizedOverdraft => DbAlertType::UnauthorizedOverdraft,
         }
     }
 
@@ -291,6 +369,8 @@ impl ComplianceMapper {
             DbAlertType::SuspiciousPattern => AlertType::SuspiciousPattern,
             DbAlertType::GeographicAnomaly => AlertType::GeographicAnomaly,
             DbAlertType::CrossBorderTransaction => AlertType::CrossBorderTransaction,
+            DbAlertType::SanctionsMatch => AlertType::SanctionsMatch,
+            DbAlertType::UnauthorizedOverdraft => AlertType::UnauthorizedOverdraft,
         }
     }
 
@@ -316,6 +396,7 @@ impl ComplianceMapper {
     pub fn domain_sar_status_to_db_sar_status(status: SarStatus) -> DbSarStatus {
         match status {
             SarStatus::Draft => DbSarStatus::Draft,
+            SarStatus::Submitted => DbSarStatus::Submitted,
             SarStatus::Filed => DbSarStatus::Filed,
             SarStatus::Acknowledged => DbSarStatus::Acknowledged,
             SarStatus::UnderReview => DbSarStatus::UnderReview,
@@ -323,6 +404,17 @@ impl ComplianceMapper {
         }
     }
 
+    pub fn db_sar_status_to_domain_sar_status(status: DbSarStatus) -> SarStatus {
+        match status {
+            DbSarStatus::Draft => SarStatus::Draft,
+            DbSarStatus::Submitted => SarStatus::Submitted,
+            DbSarStatus::Filed => SarStatus::Filed,
+            DbSarStatus::Acknowledged => SarStatus::Acknowledged,
+            DbSarStatus::UnderReview => SarStatus::UnderReview,
+            DbSarStatus::Closed => SarStatus::Closed,
+        }
+    }
+
     pub fn domain_control_type_to_db_control_type(control_type: banking_api::domain::account::ControlType) -> DbControlType {
         match control_type {
             banking_api::domain::account::ControlType::DirectOwnership => DbControlType::DirectOwnership,
@@ -432,4 +524,44 @@ impl ComplianceMapper {
             banking_api::domain::compliance::ComplianceStatus::Pending => DbComplianceStatus::Pending,
         }
     }
+
+    /// Map a risk assessment to the risk score row recording it, with the
+    /// factor scores as a JSON array
+    pub fn risk_assessment_to_model(assessment: &CustomerRiskAssessment) -> ComplianceRiskScoreModel {
+        let factors = serde_json::to_string(&assessment.factor_scores).unwrap_or_default();
+        ComplianceRiskScoreModel {
+            id: Uuid::new_v4(),
+            customer_id: assessment.customer_id,
+            risk_score: assessment.score,
+            risk_category: HeaplessString::try_from(Self::risk_category(assessment.rating)).unwrap_or_default(),
+            calculation_method: HeaplessString::try_from(RISK_CALCULATION_METHOD).unwrap_or_default(),
+            factors_considered: HeaplessString::try_from(factors.as_str()).unwrap_or_default(),
+            calculated_at: assessment.assessed_at,
+            calculated_by: HeaplessString::try_from("SYSTEM").unwrap_or_default(),
+            valid_until: None,
+            notes: None,
+            created_at: assessment.assessed_at,
+            last_updated_at: assessment.assessed_at,
+        }
+    }
+
+    pub fn risk_category(rating: RiskRating) -> &'static str {
+        match rating {
+            RiskRating::Low => "Low",
+            RiskRating::Medium => "Medium",
+            RiskRating::High => "High",
+            RiskRating::Blacklisted => "Blacklisted",
+        }
+    }
+
+    pub fn risk_factors_from_model(model: CustomerRiskFactorsModel) -> CustomerRiskFactors {
+        CustomerRiskFactors {
+            customer_id: model.customer_id,
+            country_iso2: model.country_iso2,
+            account_ids: model.account_ids,
+            product_ids: model.product_ids,
+            transaction_count: model.transaction_count,
+            alert_count: model.alert_count,
+        }
+    }
 }
\ No newline at end of file
@@ -1,15 +1,17 @@
 use banking_api::domain::{
     Customer, CustomerAudit, CustomerComplianceStatus, CustomerDocument, CustomerPortfolio,
-    CustomerStatus, CustomerType, DocumentStatus, IdentityType, KycStatus, RiskRating,
+    CustomerSearchCriteria, CustomerStatus, CustomerType, DocumentStatus, IdentityType, KycStatus, RiskRating,
     RiskSummary,
 };
 use banking_db::models::{
     CustomerAuditModel, CustomerComplianceStatusModel, CustomerDocumentModel, CustomerModel,
-    CustomerPortfolioModel, CustomerStatus as DbCustomerStatus, CustomerType as DbCustomerType,
+    CustomerPortfolioModel, CustomerSearchCriteriaModel, CustomerStatus as DbCustomerStatus, CustomerType as DbCustomerType,
     DocumentStatus as DbDocumentStatus, IdentityType as DbIdentityType, KycStatus as DbKycStatus,
     RiskRating as DbRiskRating, RiskSummaryModel,
 };
 
+use crate::mappers::{ChannelSecurityMapper, TagMapper};
+
 pub struct CustomerMapper;
 
 impl CustomerMapper {
@@ -26,6 +28,8 @@ impl CustomerMapper {
             created_at: customer.created_at,
             last_updated_at: customer.last_updated_at,
             updated_by_person_id: customer.updated_by_person_id,
+            preferred_language_code: customer.preferred_language_code,
+            duplicate_of_customer_id: customer.duplicate_of_customer_id,
         }
     }
 
@@ -42,21 +46,43 @@ impl CustomerMapper {
             created_at: model.created_at,
             last_updated_at: model.last_updated_at,
             updated_by_person_id: model.updated_by_person_id,
+            preferred_language_code: model.preferred_language_code,
+            duplicate_of_customer_id: model.duplicate_of_customer_id,
         })
     }
 
     /// Map from database CustomerPortfolioModel to domain CustomerPortfolio
-    pub fn portfolio_from_model(model: CustomerPortfolioModel) -> CustomerPortfolio {
-        CustomerPortfolio {
+    pub fn portfolio_from_model(model: CustomerPortfolioModel) -> banking_api::BankingResult<CustomerPortfolio> {
+        let recent_security_events = model.recent_security_events
+            .into_iter()
+            .map(ChannelSecurityMapper::event_from_model)
+            .collect::<banking_api::BankingResult<Vec<_>>>()?;
+
+        Ok(CustomerPortfolio {
             customer_id: model.customer_id,
             total_accounts: model.total_accounts,
             total_balance: model.total_balance,
             total_loan_outstanding: model.total_loan_outstanding,
+            total_guarantee_exposure: model.total_guarantee_exposure,
             last_activity_date: model.last_activity_date,
             risk_score: model.risk_score,
             kyc_status: Self::kyc_status_from_db(model.kyc_status),
             sanctions_checked: model.sanctions_checked,
             last_screening_date: model.last_screening_date,
+            tags: model.tags,
+            recent_security_events,
+            interaction_note_count: model.interaction_note_count,
+        })
+    }
+
+    pub fn search_criteria_to_model(criteria: CustomerSearchCriteria) -> CustomerSearchCriteriaModel {
+        CustomerSearchCriteriaModel {
+            customer_type: criteria.customer_type.map(Self::customer_type_to_db),
+            status: criteria.status.map(Self::customer_status_to_db),
+            risk_rating: criteria.risk_rating.map(Self::risk_rating_to_db),
+            tags: criteria.tags.map(TagMapper::filter_to_model),
+            offset: criteria.offset,
+            limit: criteria.limit,
         }
     }
 
@@ -123,6 +149,7 @@ impl CustomerMapper {
             CustomerStatus::PendingVerification => DbCustomerStatus::PendingVerification,
             CustomerStatus::Deceased => DbCustomerStatus::Deceased,
             CustomerStatus::Dissolved => DbCustomerStatus::Dissolved,
+            CustomerStatus::Merged => DbCustomerStatus::Merged,
             CustomerStatus::Blacklisted => DbCustomerStatus::Blacklisted,
         }
     }
@@ -133,6 +160,7 @@ impl CustomerMapper {
             DbCustomerStatus::PendingVerification => CustomerStatus::PendingVerification,
             DbCustomerStatus::Deceased => CustomerStatus::Deceased,
             DbCustomerStatus::Dissolved => CustomerStatus::Dissolved,
+            DbCustomerStatus::Merged => CustomerStatus::Merged,
             DbCustomerStatus::Blacklisted => CustomerStatus::Blacklisted,
         }
     }
@@ -419,6 +419,31 @@ impl DailyCollectionMapper {
         }
     }
 
+    // GeoVerificationStatus
+    pub fn geo_verification_status_to_db(
+        status: domain::GeoVerificationStatus,
+    ) -> db_models::GeoVerificationStatus {
+        match status {
+            domain::GeoVerificationStatus::Verified => db_models::GeoVerificationStatus::Verified,
+            domain::GeoVerificationStatus::GeoMismatch => db_models::GeoVerificationStatus::GeoMismatch,
+            domain::GeoVerificationStatus::MissingCoordinates => {
+                db_models::GeoVerificationStatus::MissingCoordinates
+            }
+        }
+    }
+
+    pub fn geo_verification_status_from_db(
+        status: db_models::GeoVerificationStatus,
+    ) -> domain::GeoVerificationStatus {
+        match status {
+            db_models::GeoVerificationStatus::Verified => domain::GeoVerificationStatus::Verified,
+            db_models::GeoVerificationStatus::GeoMismatch => domain::GeoVerificationStatus::GeoMismatch,
+            db_models::GeoVerificationStatus::MissingCoordinates => {
+                domain::GeoVerificationStatus::MissingCoordinates
+            }
+        }
+    }
+
     // BiometricMethod
     pub fn biometric_method_to_db(method: domain::BiometricMethod) -> db_models::BiometricMethod {
         match method {
@@ -586,10 +611,12 @@ impl DailyCollectionMapper {
         }
     }
 
+    /// Slots are filled from open alerts only; resolved alerts free their slot
     pub fn agent_performance_metrics_from_db(
         model: db_models::AgentPerformanceMetricsModel,
         alerts: Vec<domain::PerformanceAlert>,
     ) -> domain::AgentPerformanceMetrics {
+        let alerts: Vec<_> = alerts.into_iter().filter(|alert| alert.is_open()).collect();
         domain::AgentPerformanceMetrics {
             id: model.id,
             collection_rate: model.collection_rate,
@@ -652,7 +679,10 @@ impl DailyCollectionMapper {
             resolution_required: alert.resolution_required,
             created_at: alert.created_at,
             acknowledged_at: alert.acknowledged_at,
+            acknowledged_by_person_id: alert.acknowledged_by_person_id,
             resolved_at: alert.resolved_at,
+            resolved_by_person_id: alert.resolved_by_person_id,
+            resolution_notes: alert.resolution_notes,
         }
     }
 
@@ -669,7 +699,10 @@ impl DailyCollectionMapper {
             resolution_required: model.resolution_required,
             created_at: model.created_at,
             acknowledged_at: model.acknowledged_at,
+            acknowledged_by_person_id: model.acknowledged_by_person_id,
             resolved_at: model.resolved_at,
+            resolved_by_person_id: model.resolved_by_person_id,
+            resolution_notes: model.resolution_notes,
         }
     }
 
@@ -770,6 +803,8 @@ impl DailyCollectionMapper {
             fee_early_termination_fee: fee.early_termination_fee,
             fee_frequency: Self::collection_fee_frequency_to_db(fee.fee_frequency),
             interest_rate: program.interest_rate,
+            geo_verification_radius_meters: program.geo_verification_radius_meters,
+            geo_mismatch_weekly_threshold: program.geo_mismatch_weekly_threshold,
             created_at: program.created_at,
             updated_at: program.updated_at,
             created_by_person_id: program.created_by_person_id,
@@ -824,6 +859,8 @@ impl DailyCollectionMapper {
             graduation_criteria_id,
             fee_structure_id,
             interest_rate: model.interest_rate,
+            geo_verification_radius_meters: model.geo_verification_radius_meters,
+            geo_mismatch_weekly_threshold: model.geo_mismatch_weekly_threshold,
             created_at: model.created_at,
             updated_at: model.updated_at,
             created_by_person_id: model.created_by_person_id,
@@ -994,6 +1031,12 @@ impl DailyCollectionMapper {
             verification_witness_relationship: witness.map(|w| w.witness_relationship.clone()),
             verification_witness_signature: witness.and_then(|w| w.witness_signature.clone()),
             verification_timestamp: verification.map(|v| v.verification_timestamp),
+            agent_latitude: record.agent_latitude,
+            agent_longitude: record.agent_longitude,
+            geo_verification_status: record
+                .geo_verification_status
+                .map(Self::geo_verification_status_to_db),
+            geo_distance_meters: record.geo_distance_meters,
             created_at: record.created_at,
             processed_at: record.processed_at,
             reason_id: record.reason_id,
@@ -1034,6 +1077,12 @@ impl DailyCollectionMapper {
             } else {
                 None
             },
+            agent_latitude: model.agent_latitude,
+            agent_longitude: model.agent_longitude,
+            geo_verification_status: model
+                .geo_verification_status
+                .map(Self::geo_verification_status_from_db),
+            geo_distance_meters: model.geo_distance_meters,
             created_at: model.created_at,
             processed_at: model.processed_at,
             reason_id: model.reason_id,
@@ -0,0 +1,93 @@
+use banking_api::domain::{
+    DocumentNotification, DocumentNotificationStatus, GeneratedDocument, GeneratedDocumentType,
+};
+use banking_db::models::{
+    DbDocumentNotificationStatus, DbGeneratedDocumentType, DocumentNotificationModel,
+    GeneratedDocumentModel,
+};
+
+pub struct DocumentMapper;
+
+impl DocumentMapper {
+    /// Map from domain GeneratedDocument to database GeneratedDocumentModel
+    pub fn document_to_model(document: GeneratedDocument) -> GeneratedDocumentModel {
+        GeneratedDocumentModel {
+            id: document.id,
+            document_type: Self::document_type_to_db(document.document_type),
+            account_id: document.account_id,
+            customer_id: document.customer_id,
+            language_code: document.language_code,
+            content: document.content,
+            created_at: document.created_at,
+        }
+    }
+
+    /// Map from database GeneratedDocumentModel to domain GeneratedDocument
+    pub fn document_from_model(model: GeneratedDocumentModel) -> GeneratedDocument {
+        GeneratedDocument {
+            id: model.id,
+            document_type: Self::document_type_from_db(model.document_type),
+            account_id: model.account_id,
+            customer_id: model.customer_id,
+            language_code: model.language_code,
+            content: model.content,
+            created_at: model.created_at,
+        }
+    }
+
+    /// Map from domain DocumentNotification to database DocumentNotificationModel
+    pub fn notification_to_model(notification: DocumentNotification) -> DocumentNotificationModel {
+        DocumentNotificationModel {
+            id: notification.id,
+            document_id: notification.document_id,
+            customer_id: notification.customer_id,
+            retrieval_reference: notification.retrieval_reference,
+            language_code: notification.language_code,
+            status: Self::notification_status_to_db(notification.status),
+            queued_at: notification.queued_at,
+            sent_at: notification.sent_at,
+        }
+    }
+
+    /// Map from database DocumentNotificationModel to domain DocumentNotification
+    pub fn notification_from_model(model: DocumentNotificationModel) -> DocumentNotification {
+        DocumentNotification {
+            id: model.id,
+            document_id: model.document_id,
+            customer_id: model.customer_id,
+            retrieval_reference: model.retrieval_reference,
+            language_code: model.language_code,
+            status: Self::notification_status_from_db(model.status),
+            queued_at: model.queued_at,
+            sent_at: model.sent_at,
+        }
+    }
+
+    pub fn document_type_to_db(document_type: GeneratedDocumentType) -> DbGeneratedDocumentType {
+        match document_type {
+            GeneratedDocumentType::WelcomePack => DbGeneratedDocumentType::WelcomePack,
+        }
+    }
+
+    fn document_type_from_db(document_type: DbGeneratedDocumentType) -> GeneratedDocumentType {
+        match document_type {
+            DbGeneratedDocumentType::WelcomePack => GeneratedDocumentType::WelcomePack,
+        }
+    }
+
+    pub fn notification_status_to_db(status: DocumentNotificationStatus) -> DbDocumentNotificationStatus {
+        match status {
+            DocumentNotificationStatus::Queued => DbDocumentNotificationStatus::Queued,
+            DocumentNotificationStatus::Sent => DbDocumentNotificationStatus::Sent,
+            DocumentNotificationStatus::Failed => DbDocumentNotificationStatus::Failed,
+        }
+    }
+
+    pub fn notification_status_from_db(status: DbDocumentNotificationStatus) -> DocumentNotificationStatus {
+        match status {
+            DbDocumentNotificationStatus::Queued => DocumentNotificationStatus::Queued,
+            DbDocumentNotificationStatus::Sent => DocumentNotificationStatus::Sent,
+            DbDocumentNotificationStatus::Failed => DocumentNotificationStatus::Failed,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use banking_api::domain::{DocumentLink, DocumentLinkKind, RegisteredDocument};
+use banking_db::models::{DbDocumentLinkKind, DocumentLinkModel, RegisteredDocumentModel};
+
+pub struct DocumentRegistryMapper;
+
+impl DocumentRegistryMapper {
+    /// Map from domain RegisteredDocument to database RegisteredDocumentModel
+    pub fn to_model(document: RegisteredDocument) -> RegisteredDocumentModel {
+        RegisteredDocumentModel {
+            id: document.id,
+            content_hash: document.content_hash,
+            document_type: document.document_type,
+            document_path: document.document_path,
+            size_bytes: document.size_bytes.map(|size| size as i64),
+            registered_at: document.registered_at,
+            registered_by_person_id: document.registered_by_person_id,
+        }
+    }
+
+    /// Map from database RegisteredDocumentModel to domain RegisteredDocument
+    pub fn from_model(model: RegisteredDocumentModel) -> RegisteredDocument {
+        RegisteredDocument {
+            id: model.id,
+            content_hash: model.content_hash,
+            document_type: model.document_type,
+            document_path: model.document_path,
+            size_bytes: model.size_bytes.map(|size| size as u64),
+            registered_at: model.registered_at,
+            registered_by_person_id: model.registered_by_person_id,
+        }
+    }
+
+    pub fn link_to_model(link: DocumentLink) -> DocumentLinkModel {
+        DocumentLinkModel {
+            id: link.id,
+            document_id: link.document_id,
+            entity_kind: Self::link_kind_to_db(link.entity_kind),
+            entity_id: link.entity_id,
+            linked_at: link.linked_at,
+            linked_by_person_id: link.linked_by_person_id,
+        }
+    }
+
+    pub fn link_from_model(model: DocumentLinkModel) -> DocumentLink {
+        DocumentLink {
+            id: model.id,
+            document_id: model.document_id,
+            entity_kind: Self::link_kind_from_db(model.entity_kind),
+            entity_id: model.entity_id,
+            linked_at: model.linked_at,
+            linked_by_person_id: model.linked_by_person_id,
+        }
+    }
+
+    pub fn link_kind_to_db(kind: DocumentLinkKind) -> DbDocumentLinkKind {
+        match kind {
+            DocumentLinkKind::Workflow => DbDocumentLinkKind::Workflow,
+            DocumentLinkKind::Customer => DbDocumentLinkKind::Customer,
+        }
+    }
+
+    pub fn link_kind_from_db(kind: DbDocumentLinkKind) -> DocumentLinkKind {
+        match kind {
+            DbDocumentLinkKind::Workflow => DocumentLinkKind::Workflow,
+            DbDocumentLinkKind::Customer => DocumentLinkKind::Customer,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use banking_api::domain::{EodRun, EodRunStatus, EodRunStep, EodStepStatus};
+use banking_db::models::{DbEodRunStatus, DbEodStepStatus, EodRunModel, EodRunStepModel};
+
+pub struct EodRunMapper;
+
+impl EodRunMapper {
+    /// Map from domain EodRun to database EodRunModel; steps are saved separately
+    pub fn run_to_model(run: &EodRun) -> EodRunModel {
+        EodRunModel {
+            id: run.id,
+            run_date: run.run_date,
+            status: match run.status {
+                EodRunStatus::Running => DbEodRunStatus::Running,
+                EodRunStatus::Failed => DbEodRunStatus::Failed,
+                EodRunStatus::Completed => DbEodRunStatus::Completed,
+            },
+            started_at: run.started_at,
+            finished_at: run.finished_at,
+            forced_reruns: run.forced_reruns,
+        }
+    }
+
+    /// Map from database models to domain EodRun
+    pub fn run_from_model(model: EodRunModel, steps: Vec<EodRunStepModel>) -> EodRun {
+        EodRun {
+            id: model.id,
+            run_date: model.run_date,
+            status: match model.status {
+                DbEodRunStatus::Running => EodRunStatus::Running,
+                DbEodRunStatus::Failed => EodRunStatus::Failed,
+                DbEodRunStatus::Completed => EodRunStatus::Completed,
+            },
+            started_at: model.started_at,
+            finished_at: model.finished_at,
+            forced_reruns: model.forced_reruns,
+            steps: steps.into_iter().map(Self::step_from_model).collect(),
+        }
+    }
+
+    pub fn step_to_model(step: &EodRunStep) -> EodRunStepModel {
+        EodRunStepModel {
+            id: step.id,
+            eod_run_id: step.eod_run_id,
+            run_date: step.run_date,
+            step_name: step.step_name.clone(),
+            step_order: step.step_order,
+            status: match step.status {
+                EodStepStatus::Pending => DbEodStepStatus::Pending,
+                EodStepStatus::Running => DbEodStepStatus::Running,
+                EodStepStatus::Failed => DbEodStepStatus::Failed,
+                EodStepStatus::Completed => DbEodStepStatus::Completed,
+            },
+            started_at: step.started_at,
+            finished_at: step.finished_at,
+            records_processed: step.records_processed,
+            records_failed: step.records_failed,
+            checkpoint_cursor: step.checkpoint_cursor,
+            error: step.error.clone(),
+        }
+    }
+
+    pub fn step_from_model(model: EodRunStepModel) -> EodRunStep {
+        EodRunStep {
+            id: model.id,
+            eod_run_id: model.eod_run_id,
+            run_date: model.run_date,
+            step_name: model.step_name,
+            step_order: model.step_order,
+            status: match model.status {
+                DbEodStepStatus::Pending => EodStepStatus::Pending,
+                DbEodStepStatus::Running => EodStepStatus::Running,
+                DbEodStepStatus::Failed => EodStepStatus::Failed,
+                DbEodStepStatus::Completed => EodStepStatus::Completed,
+            },
+            started_at: model.started_at,
+            finished_at: model.finished_at,
+            records_processed: model.records_processed,
+            records_failed: model.records_failed,
+            checkpoint_cursor: model.checkpoint_cursor,
+            error: model.error,
+        }
+    }
+}
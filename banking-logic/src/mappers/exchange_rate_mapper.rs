@@ -0,0 +1,26 @@
+use banking_api::domain::ExchangeRate;
+use banking_db::models::ExchangeRateModel;
+
+pub struct ExchangeRateMapper;
+
+impl ExchangeRateMapper {
+    pub fn to_model(rate: ExchangeRate) -> ExchangeRateModel {
+        ExchangeRateModel {
+            from_currency: rate.from_currency,
+            to_currency: rate.to_currency,
+            rate: rate.rate,
+            effective_at: rate.effective_at,
+            source: rate.source,
+        }
+    }
+
+    pub fn from_model(model: ExchangeRateModel) -> ExchangeRate {
+        ExchangeRate {
+            from_currency: model.from_currency,
+            to_currency: model.to_currency,
+            rate: model.rate,
+            effective_at: model.effective_at,
+            source: model.source,
+        }
+    }
+}
@@ -33,6 +33,8 @@ impl FeeMapper {
             waived_by: fee.waived_by,
             waived_reason_id: fee.waived_reason_id,
             applied_by: fee.applied_by,
+            reversal_transaction_id: fee.reversal_transaction_id,
+            audit_log_id: fee.audit_log_id,
             created_at: fee.created_at,
         }
     }
@@ -62,6 +64,8 @@ impl FeeMapper {
             waived_by: model.waived_by,
             waived_reason_id: model.waived_reason_id,
             applied_by: model.applied_by,
+            reversal_transaction_id: model.reversal_transaction_id,
+            audit_log_id: model.audit_log_id,
             created_at: model.created_at,
         })
     }
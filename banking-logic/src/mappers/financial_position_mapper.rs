@@ -0,0 +1,22 @@
+use banking_api::domain::CurrencyPosition;
+use banking_db::models::CurrencyPositionModel;
+use rust_decimal::Decimal;
+
+pub struct FinancialPositionMapper;
+
+impl FinancialPositionMapper {
+    /// Guarantees are not part of the account aggregation and start at zero
+    pub fn position_from_model(model: CurrencyPositionModel) -> CurrencyPosition {
+        CurrencyPosition {
+            currency: model.currency,
+            account_count: model.account_count,
+            total_deposits: model.total_deposits,
+            loan_exposure: model.loan_exposure,
+            guarantees_given: Decimal::ZERO,
+            holds: model.holds,
+            legal_holds: model.legal_holds,
+            overdraft_used: model.overdraft_used,
+            overdraft_limit: model.overdraft_limit,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use banking_api::{BankingResult, domain::{CurrencyCode, GlSummaryLine}};
+use banking_db::models::GlSummaryLineModel;
+use chrono::{DateTime, Utc};
+
+pub struct GeneralLedgerMapper;
+
+impl GeneralLedgerMapper {
+    /// Map from domain GlSummaryLine to database GlSummaryLineModel
+    pub fn summary_line_to_model(line: GlSummaryLine, created_at: DateTime<Utc>) -> GlSummaryLineModel {
+        GlSummaryLineModel {
+            run_date: line.run_date,
+            gl_code: line.gl_code,
+            product_id: line.product_id,
+            branch_id: line.branch_id,
+            currency: line.currency.into(),
+            debit_total: line.debit_total,
+            credit_total: line.credit_total,
+            transaction_count: line.transaction_count,
+            created_at,
+        }
+    }
+
+    /// Map from database GlSummaryLineModel to domain GlSummaryLine
+    pub fn summary_line_from_model(model: GlSummaryLineModel) -> BankingResult<GlSummaryLine> {
+        Ok(GlSummaryLine {
+            run_date: model.run_date,
+            gl_code: model.gl_code,
+            product_id: model.product_id,
+            branch_id: model.branch_id,
+            currency: CurrencyCode::try_from(model.currency)?,
+            debit_total: model.debit_total,
+            credit_total: model.credit_total,
+            transaction_count: model.transaction_count,
+        })
+    }
+}
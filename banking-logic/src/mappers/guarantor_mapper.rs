@@ -0,0 +1,70 @@
+use banking_api::domain::{GuarantorStatus, GuarantorType, LoanGuarantor};
+use banking_db::models::{DbGuarantorStatus, DbGuarantorType, LoanGuarantorModel};
+
+pub struct GuarantorMapper;
+
+impl GuarantorMapper {
+    /// Map from domain LoanGuarantor to database LoanGuarantorModel
+    pub fn to_model(guarantor: LoanGuarantor) -> LoanGuarantorModel {
+        LoanGuarantorModel {
+            id: guarantor.id,
+            loan_account_id: guarantor.loan_account_id,
+            guarantor_type: Self::guarantor_type_to_db(guarantor.guarantor_type),
+            guarantor_id: guarantor.guarantor_id,
+            guarantee_amount: guarantor.guarantee_amount,
+            document_reference: guarantor.document_reference,
+            status: Self::guarantor_status_to_db(guarantor.status),
+            released_at: guarantor.released_at,
+            released_by_person_id: guarantor.released_by_person_id,
+            created_at: guarantor.created_at,
+            last_updated_at: guarantor.last_updated_at,
+            updated_by_person_id: guarantor.updated_by_person_id,
+        }
+    }
+
+    /// Map from database LoanGuarantorModel to domain LoanGuarantor
+    pub fn from_model(model: LoanGuarantorModel) -> LoanGuarantor {
+        LoanGuarantor {
+            id: model.id,
+            loan_account_id: model.loan_account_id,
+            guarantor_type: Self::guarantor_type_from_db(model.guarantor_type),
+            guarantor_id: model.guarantor_id,
+            guarantee_amount: model.guarantee_amount,
+            document_reference: model.document_reference,
+            status: Self::guarantor_status_from_db(model.status),
+            released_at: model.released_at,
+            released_by_person_id: model.released_by_person_id,
+            created_at: model.created_at,
+            last_updated_at: model.last_updated_at,
+            updated_by_person_id: model.updated_by_person_id,
+        }
+    }
+
+    fn guarantor_type_to_db(guarantor_type: GuarantorType) -> DbGuarantorType {
+        match guarantor_type {
+            GuarantorType::Customer => DbGuarantorType::Customer,
+            GuarantorType::Person => DbGuarantorType::Person,
+        }
+    }
+
+    fn guarantor_type_from_db(guarantor_type: DbGuarantorType) -> GuarantorType {
+        match guarantor_type {
+            DbGuarantorType::Customer => GuarantorType::Customer,
+            DbGuarantorType::Person => GuarantorType::Person,
+        }
+    }
+
+    fn guarantor_status_to_db(status: GuarantorStatus) -> DbGuarantorStatus {
+        match status {
+            GuarantorStatus::Active => DbGuarantorStatus::Active,
+            GuarantorStatus::Released => DbGuarantorStatus::Released,
+        }
+    }
+
+    fn guarantor_status_from_db(status: DbGuarantorStatus) -> GuarantorStatus {
+        match status {
+            DbGuarantorStatus::Active => GuarantorStatus::Active,
+            DbGuarantorStatus::Released => GuarantorStatus::Released,
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use banking_api::domain::{InteractionNote, NoteCategory, NoteEntityKind, NoteVisibility};
+use banking_db::models::{DbNoteCategory, DbNoteEntityKind, DbNoteVisibility, InteractionNoteModel};
+
+pub struct InteractionNoteMapper;
+
+impl InteractionNoteMapper {
+    /// Map from domain InteractionNote to database InteractionNoteModel
+    pub fn to_model(note: InteractionNote) -> InteractionNoteModel {
+        InteractionNoteModel {
+            id: note.id,
+            entity_kind: Self::entity_kind_to_db(note.entity_kind),
+            entity_id: note.entity_id,
+            author_person_id: note.author_person_id,
+            agency_branch_id: note.agency_branch_id,
+            visibility: Self::visibility_to_db(note.visibility),
+            category: Self::category_to_db(note.category),
+            body: note.body,
+            corrects_note_id: note.corrects_note_id,
+            follow_up_date: note.follow_up_date,
+            follow_up_completed_at: note.follow_up_completed_at,
+            created_at: note.created_at,
+        }
+    }
+
+    /// Map from database InteractionNoteModel to domain InteractionNote
+    pub fn from_model(model: InteractionNoteModel) -> InteractionNote {
+        InteractionNote {
+            id: model.id,
+            entity_kind: Self::entity_kind_from_db(model.entity_kind),
+            entity_id: model.entity_id,
+            author_person_id: model.author_person_id,
+            agency_branch_id: model.agency_branch_id,
+            visibility: Self::visibility_from_db(model.visibility),
+            category: Self::category_from_db(model.category),
+            body: model.body,
+            corrects_note_id: model.corrects_note_id,
+            follow_up_date: model.follow_up_date,
+            follow_up_completed_at: model.follow_up_completed_at,
+            created_at: model.created_at,
+        }
+    }
+
+    pub fn entity_kind_to_db(kind: NoteEntityKind) -> DbNoteEntityKind {
+        match kind {
+            NoteEntityKind::Customer => DbNoteEntityKind::Customer,
+            NoteEntityKind::Account => DbNoteEntityKind::Account,
+        }
+    }
+
+    fn entity_kind_from_db(kind: DbNoteEntityKind) -> NoteEntityKind {
+        match kind {
+            DbNoteEntityKind::Customer => NoteEntityKind::Customer,
+            DbNoteEntityKind::Account => NoteEntityKind::Account,
+        }
+    }
+
+    fn visibility_to_db(visibility: NoteVisibility) -> DbNoteVisibility {
+        match visibility {
+            NoteVisibility::BranchOnly => DbNoteVisibility::BranchOnly,
+            NoteVisibility::BankWide => DbNoteVisibility::BankWide,
+            NoteVisibility::ComplianceOnly => DbNoteVisibility::ComplianceOnly,
+        }
+    }
+
+    fn visibility_from_db(visibility: DbNoteVisibility) -> NoteVisibility {
+        match visibility {
+            DbNoteVisibility::BranchOnly => NoteVisibility::BranchOnly,
+            DbNoteVisibility::BankWide => NoteVisibility::BankWide,
+            DbNoteVisibility::ComplianceOnly => NoteVisibility::ComplianceOnly,
+        }
+    }
+
+    fn category_to_db(category: NoteCategory) -> DbNoteCategory {
+        match category {
+            NoteCategory::BranchVisit => DbNoteCategory::BranchVisit,
+            NoteCategory::PhoneCall => DbNoteCategory::PhoneCall,
+            NoteCategory::Complaint => DbNoteCategory::Complaint,
+            NoteCategory::ServiceRequest => DbNoteCategory::ServiceRequest,
+            NoteCategory::Compliance => DbNoteCategory::Compliance,
+            NoteCategory::General => DbNoteCategory::General,
+        }
+    }
+
+    fn category_from_db(category: DbNoteCategory) -> NoteCategory {
+        match category {
+            DbNoteCategory::BranchVisit => NoteCategory::BranchVisit,
+            DbNoteCategory::PhoneCall => NoteCategory::PhoneCall,
+            DbNoteCategory::Complaint => NoteCategory::Complaint,
+            DbNoteCategory::ServiceRequest => NoteCategory::ServiceRequest,
+            DbNoteCategory::Compliance => NoteCategory::Compliance,
+            DbNoteCategory::General => NoteCategory::General,
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use banking_api::domain::{TimelineAccessRecord, TimelineCategory, TimelineEvent};
+use banking_db::models::{DbTimelineCategory, TimelineAccessRecordModel, TimelineEventModel};
+
+pub struct InvestigationMapper;
+
+impl InvestigationMapper {
+    pub fn event_from_model(model: TimelineEventModel) -> TimelineEvent {
+        TimelineEvent {
+            occurred_at: model.occurred_at,
+            category: Self::category_from_db(model.category),
+            actor_person_id: model.actor_person_id,
+            summary: model.summary,
+            reference_id: model.reference_id,
+        }
+    }
+
+    pub fn access_to_model(record: TimelineAccessRecord) -> TimelineAccessRecordModel {
+        TimelineAccessRecordModel {
+            id: record.id,
+            account_id: record.account_id,
+            from_date: record.from_date,
+            to_date: record.to_date,
+            accessed_by_person_id: record.accessed_by_person_id,
+            case_reference: record.case_reference,
+            events_returned: record.events_returned,
+            exported: record.exported,
+            accessed_at: record.accessed_at,
+        }
+    }
+
+    pub fn access_from_model(model: TimelineAccessRecordModel) -> TimelineAccessRecord {
+        TimelineAccessRecord {
+            id: model.id,
+            account_id: model.account_id,
+            from_date: model.from_date,
+            to_date: model.to_date,
+            accessed_by_person_id: model.accessed_by_person_id,
+            case_reference: model.case_reference,
+            events_returned: model.events_returned,
+            exported: model.exported,
+            accessed_at: model.accessed_at,
+        }
+    }
+
+    pub fn category_from_db(category: DbTimelineCategory) -> TimelineCategory {
+        match category {
+            DbTimelineCategory::WorkflowCreated => TimelineCategory::WorkflowCreated,
+            DbTimelineCategory::WorkflowStep => TimelineCategory::WorkflowStep,
+            DbTimelineCategory::WorkflowCompleted => TimelineCategory::WorkflowCompleted,
+            DbTimelineCategory::StatusChange => TimelineCategory::StatusChange,
+            DbTimelineCategory::HoldPlaced => TimelineCategory::HoldPlaced,
+            DbTimelineCategory::HoldReleased => TimelineCategory::HoldReleased,
+            DbTimelineCategory::RestrictionPlaced => TimelineCategory::RestrictionPlaced,
+            DbTimelineCategory::RestrictionLifted => TimelineCategory::RestrictionLifted,
+            DbTimelineCategory::Transaction => TimelineCategory::Transaction,
+            DbTimelineCategory::TransactionSummary => TimelineCategory::TransactionSummary,
+            DbTimelineCategory::NotificationSent => TimelineCategory::NotificationSent,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use banking_api::domain::{KillSwitch, KillSwitchScope, KillSwitchState};
+use banking_db::models::{DbKillSwitchScope, DbKillSwitchState, KillSwitchModel};
+
+pub struct KillSwitchMapper;
+
+impl KillSwitchMapper {
+    /// Map from domain KillSwitch to database KillSwitchModel
+    pub fn to_model(kill_switch: KillSwitch) -> KillSwitchModel {
+        KillSwitchModel {
+            id: kill_switch.id,
+            scope: Self::scope_to_db(kill_switch.scope),
+            scope_value: kill_switch.scope_value,
+            state: Self::state_to_db(kill_switch.state),
+            reason: kill_switch.reason,
+            activated_by_person_id: kill_switch.activated_by_person_id,
+            activated_at: kill_switch.activated_at,
+            auto_expire_at: kill_switch.auto_expire_at,
+            deactivated_by_person_id: kill_switch.deactivated_by_person_id,
+            deactivated_at: kill_switch.deactivated_at,
+        }
+    }
+
+    /// Map from database KillSwitchModel to domain KillSwitch
+    pub fn from_model(model: KillSwitchModel) -> KillSwitch {
+        KillSwitch {
+            id: model.id,
+            scope: Self::scope_from_db(model.scope),
+            scope_value: model.scope_value,
+            state: Self::state_from_db(model.state),
+            reason: model.reason,
+            activated_by_person_id: model.activated_by_person_id,
+            activated_at: model.activated_at,
+            auto_expire_at: model.auto_expire_at,
+            deactivated_by_person_id: model.deactivated_by_person_id,
+            deactivated_at: model.deactivated_at,
+        }
+    }
+
+    fn scope_to_db(scope: KillSwitchScope) -> DbKillSwitchScope {
+        match scope {
+            KillSwitchScope::Global => DbKillSwitchScope::Global,
+            KillSwitchScope::TransactionKind => DbKillSwitchScope::TransactionKind,
+            KillSwitchScope::Channel => DbKillSwitchScope::Channel,
+            KillSwitchScope::Product => DbKillSwitchScope::Product,
+        }
+    }
+
+    fn scope_from_db(scope: DbKillSwitchScope) -> KillSwitchScope {
+        match scope {
+            DbKillSwitchScope::Global => KillSwitchScope::Global,
+            DbKillSwitchScope::TransactionKind => KillSwitchScope::TransactionKind,
+            DbKillSwitchScope::Channel => KillSwitchScope::Channel,
+            DbKillSwitchScope::Product => KillSwitchScope::Product,
+        }
+    }
+
+    fn state_to_db(state: KillSwitchState) -> DbKillSwitchState {
+        match state {
+            KillSwitchState::Active => DbKillSwitchState::Active,
+            KillSwitchState::Deactivated => DbKillSwitchState::Deactivated,
+            KillSwitchState::Expired => DbKillSwitchState::Expired,
+        }
+    }
+
+    fn state_from_db(state: DbKillSwitchState) -> KillSwitchState {
+        match state {
+            DbKillSwitchState::Active => KillSwitchState::Active,
+            DbKillSwitchState::Deactivated => KillSwitchState::Deactivated,
+            DbKillSwitchState::Expired => KillSwitchState::Expired,
+        }
+    }
+}
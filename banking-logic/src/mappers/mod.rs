@@ -1,6 +1,7 @@
 pub mod person_mapper;
 // pub mod customer_mapper;
 // pub mod account_mapper;
+// pub mod account_number_mapper;
 // pub mod account_hold_mapper;
 // pub mod agent_network_mapper;
 // pub mod transaction_mapper;
@@ -15,10 +16,40 @@ pub mod person_mapper;
 // pub mod loan_mapper;
 // pub mod reason_and_purpose_mapper;
 // pub mod product_mapper;
+// pub mod document_mapper;
+// pub mod segment_mapper;
+// pub mod guarantor_mapper;
+// pub mod quote_mapper;
+// pub mod cheque_mapper;
+// pub mod statement_mapper;
+// pub mod orchestration_mapper;
+// pub mod tag_mapper;
+// pub mod channel_security_mapper;
+// pub mod agent_commission_mapper;
+// pub mod document_registry_mapper;
+// pub mod branch_cash_mapper;
+// pub mod notification_mapper;
+// pub mod investigation_mapper;
+// pub mod bundle_mapper;
+// pub mod financial_position_mapper;
+// pub mod exchange_rate_mapper;
+// pub mod promotion_mapper;
+// pub mod savings_goal_mapper;
+// pub mod payee_mapper;
+// pub mod back_dated_posting_mapper;
+// pub mod account_domicile_mapper;
+// pub mod kill_switch_mapper;
+// pub mod interaction_note_mapper;
+// pub mod verification_mapper;
+// pub mod warehouse_mapper;
+// pub mod standing_order_mapper;
+// pub mod general_ledger_mapper;
+// pub mod eod_run_mapper;
 
 pub use person_mapper::*;
 // pub use customer_mapper::*;
 // pub use account_mapper::*;
+// pub use account_number_mapper::*;
 // pub use agent_network_mapper::*;
 // pub use transaction_mapper::*;
 // pub use calendar_mapper::*;
@@ -32,4 +63,33 @@ pub use person_mapper::*;
 // pub use reason_and_purpose_mapper::*;
 // pub use daily_collection_mapper::*;
 // pub use product_mapper::*;
+// pub use document_mapper::*;
+// pub use segment_mapper::*;
+// pub use guarantor_mapper::*;
+// pub use quote_mapper::*;
+// pub use cheque_mapper::*;
+// pub use statement_mapper::*;
+// pub use orchestration_mapper::*;
+// pub use tag_mapper::*;
+// pub use channel_security_mapper::*;
+// pub use agent_commission_mapper::*;
+// pub use document_registry_mapper::*;
+// pub use branch_cash_mapper::*;
+// pub use notification_mapper::*;
+// pub use investigation_mapper::*;
+// pub use bundle_mapper::*;
+// pub use financial_position_mapper::*;
+// pub use exchange_rate_mapper::*;
+// pub use promotion_mapper::*;
+// pub use savings_goal_mapper::*;
+// pub use payee_mapper::*;
+// pub use back_dated_posting_mapper::*;
+// pub use account_domicile_mapper::*;
+// pub use kill_switch_mapper::*;
+// pub use interaction_note_mapper::*;
+// pub use verification_mapper::*;
+// pub use warehouse_mapper::*;
+// pub use standing_order_mapper::*;
+// pub use general_ledger_mapper::*;
+// pub use eod_run_mapper::*;
 pub mod audit;
\ No newline at end of file
@@ -0,0 +1,88 @@
+use banking_api::domain::{MessageChannel, MessageTemplate, QueuedNotification, SuppressedNotificationSummary};
+use banking_db::models::{DbMessageChannel, MessageTemplateModel, NotificationDuplicateSummaryModel, QueuedNotificationModel};
+
+use crate::mappers::DocumentMapper;
+
+pub struct NotificationMapper;
+
+impl NotificationMapper {
+    /// Map from domain QueuedNotification to database QueuedNotificationModel
+    pub fn to_model(notification: QueuedNotification) -> QueuedNotificationModel {
+        QueuedNotificationModel {
+            id: notification.id,
+            idempotency_key: notification.idempotency_key,
+            template_code: notification.template_code,
+            customer_id: notification.customer_id,
+            business_date: notification.business_date,
+            body: notification.body,
+            status: DocumentMapper::notification_status_to_db(notification.status),
+            queued_at: notification.queued_at,
+            sent_at: notification.sent_at,
+        }
+    }
+
+    /// Map from database QueuedNotificationModel to domain QueuedNotification
+    pub fn from_model(model: QueuedNotificationModel) -> QueuedNotification {
+        QueuedNotification {
+            id: model.id,
+            idempotency_key: model.idempotency_key,
+            template_code: model.template_code,
+            customer_id: model.customer_id,
+            business_date: model.business_date,
+            body: model.body,
+            status: DocumentMapper::notification_status_from_db(model.status),
+            queued_at: model.queued_at,
+            sent_at: model.sent_at,
+        }
+    }
+
+    pub fn summary_from_model(model: NotificationDuplicateSummaryModel) -> SuppressedNotificationSummary {
+        SuppressedNotificationSummary {
+            template_code: model.template_code,
+            notifications_queued: model.notifications_queued,
+            duplicates_suppressed: model.duplicates_suppressed,
+        }
+    }
+
+    pub fn template_to_model(template: MessageTemplate) -> MessageTemplateModel {
+        MessageTemplateModel {
+            id: template.id,
+            template_code: template.template_code,
+            language_code: template.language_code,
+            channel: Self::channel_to_db(template.channel),
+            subject: template.subject,
+            body: template.body,
+            regulatory: template.regulatory,
+            updated_at: template.updated_at,
+        }
+    }
+
+    pub fn template_from_model(model: MessageTemplateModel) -> MessageTemplate {
+        MessageTemplate {
+            id: model.id,
+            template_code: model.template_code,
+            language_code: model.language_code,
+            channel: Self::channel_from_db(model.channel),
+            subject: model.subject,
+            body: model.body,
+            regulatory: model.regulatory,
+            updated_at: model.updated_at,
+        }
+    }
+
+    pub fn channel_to_db(channel: MessageChannel) -> DbMessageChannel {
+        match channel {
+            MessageChannel::Sms => DbMessageChannel::Sms,
+            MessageChannel::Email => DbMessageChannel::Email,
+            MessageChannel::InApp => DbMessageChannel::InApp,
+        }
+    }
+
+    pub fn channel_from_db(channel: DbMessageChannel) -> MessageChannel {
+        match channel {
+            DbMessageChannel::Sms => MessageChannel::Sms,
+            DbMessageChannel::Email => MessageChannel::Email,
+            DbMessageChannel::InApp => MessageChannel::InApp,
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        CompensationAction, Orchestration, OrchestrationStatus, OrchestrationStep, OrchestrationStepStatus,
+        OrchestrationType,
+    },
+};
+use banking_db::models::{
+    DbOrchestrationStatus, DbOrchestrationStepStatus, DbOrchestrationType, OrchestrationModel,
+    OrchestrationStepModel,
+};
+
+pub struct OrchestrationMapper;
+
+impl OrchestrationMapper {
+    /// Map from domain Orchestration to database OrchestrationModel
+    pub fn to_model(orchestration: Orchestration) -> OrchestrationModel {
+        OrchestrationModel {
+            id: orchestration.id,
+            orchestration_type: Self::orchestration_type_to_db(orchestration.orchestration_type),
+            subject_id: orchestration.subject_id,
+            status: Self::status_to_db(orchestration.status),
+            failure_reason: orchestration.failure_reason,
+            started_at: orchestration.started_at,
+            finished_at: orchestration.finished_at,
+            initiated_by_person_id: orchestration.initiated_by_person_id,
+            resolved_by_person_id: orchestration.resolved_by_person_id,
+            resolution_note: orchestration.resolution_note,
+        }
+    }
+
+    /// Map from database OrchestrationModel to domain Orchestration
+    pub fn from_model(model: OrchestrationModel) -> Orchestration {
+        Orchestration {
+            id: model.id,
+            orchestration_type: Self::orchestration_type_from_db(model.orchestration_type),
+            subject_id: model.subject_id,
+            status: Self::status_from_db(model.status),
+            failure_reason: model.failure_reason,
+            started_at: model.started_at,
+            finished_at: model.finished_at,
+            initiated_by_person_id: model.initiated_by_person_id,
+            resolved_by_person_id: model.resolved_by_person_id,
+            resolution_note: model.resolution_note,
+        }
+    }
+
+    /// Map from domain OrchestrationStep to database OrchestrationStepModel
+    pub fn step_to_model(step: OrchestrationStep) -> BankingResult<OrchestrationStepModel> {
+        let compensation = step.compensation
+            .map(|action| serde_json::to_string(&action))
+            .transpose()
+            .map_err(|e| BankingError::Internal(format!("Failed to serialize compensation action: {e}")))?;
+
+        Ok(OrchestrationStepModel {
+            id: step.id,
+            orchestration_id: step.orchestration_id,
+            sequence: step.sequence,
+            step_name: step.step_name,
+            compensation,
+            status: Self::step_status_to_db(step.status),
+            completed_at: step.completed_at,
+            compensated_at: step.compensated_at,
+            last_error: step.last_error,
+        })
+    }
+
+    /// Map from database OrchestrationStepModel to domain OrchestrationStep
+    pub fn step_from_model(model: OrchestrationStepModel) -> BankingResult<OrchestrationStep> {
+        let compensation: Option<CompensationAction> = model.compensation
+            .as_deref()
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|e| BankingError::Internal(format!("Invalid compensation action for step {}: {e}", model.id)))?;
+
+        Ok(OrchestrationStep {
+            id: model.id,
+            orchestration_id: model.orchestration_id,
+            sequence: model.sequence,
+            step_name: model.step_name,
+            compensation,
+            status: Self::step_status_from_db(model.status),
+            completed_at: model.completed_at,
+            compensated_at: model.compensated_at,
+            last_error: model.last_error,
+        })
+    }
+
+    pub fn step_status_to_db(status: OrchestrationStepStatus) -> DbOrchestrationStepStatus {
+        match status {
+            OrchestrationStepStatus::Completed => DbOrchestrationStepStatus::Completed,
+            OrchestrationStepStatus::Compensated => DbOrchestrationStepStatus::Compensated,
+            OrchestrationStepStatus::CompensationFailed => DbOrchestrationStepStatus::CompensationFailed,
+        }
+    }
+
+    fn step_status_from_db(status: DbOrchestrationStepStatus) -> OrchestrationStepStatus {
+        match status {
+            DbOrchestrationStepStatus::Completed => OrchestrationStepStatus::Completed,
+            DbOrchestrationStepStatus::Compensated => OrchestrationStepStatus::Compensated,
+            DbOrchestrationStepStatus::CompensationFailed => OrchestrationStepStatus::CompensationFailed,
+        }
+    }
+
+    fn orchestration_type_to_db(orchestration_type: OrchestrationType) -> DbOrchestrationType {
+        match orchestration_type {
+            OrchestrationType::CustomerOffboarding => DbOrchestrationType::CustomerOffboarding,
+            OrchestrationType::ProductRepricing => DbOrchestrationType::ProductRepricing,
+            OrchestrationType::BundleOpening => DbOrchestrationType::BundleOpening,
+        }
+    }
+
+    fn orchestration_type_from_db(orchestration_type: DbOrchestrationType) -> OrchestrationType {
+        match orchestration_type {
+            DbOrchestrationType::CustomerOffboarding => OrchestrationType::CustomerOffboarding,
+            DbOrchestrationType::ProductRepricing => OrchestrationType::ProductRepricing,
+            DbOrchestrationType::BundleOpening => OrchestrationType::BundleOpening,
+        }
+    }
+
+    fn status_to_db(status: OrchestrationStatus) -> DbOrchestrationStatus {
+        match status {
+            OrchestrationStatus::Running => DbOrchestrationStatus::Running,
+            OrchestrationStatus::Completed => DbOrchestrationStatus::Completed,
+            OrchestrationStatus::Compensating => DbOrchestrationStatus::Compensating,
+            OrchestrationStatus::Compensated => DbOrchestrationStatus::Compensated,
+            OrchestrationStatus::CompensationFailed => DbOrchestrationStatus::CompensationFailed,
+            OrchestrationStatus::ManuallyResolved => DbOrchestrationStatus::ManuallyResolved,
+        }
+    }
+
+    fn status_from_db(status: DbOrchestrationStatus) -> OrchestrationStatus {
+        match status {
+            DbOrchestrationStatus::Running => OrchestrationStatus::Running,
+            DbOrchestrationStatus::Completed => OrchestrationStatus::Completed,
+            DbOrchestrationStatus::Compensating => OrchestrationStatus::Compensating,
+            DbOrchestrationStatus::Compensated => OrchestrationStatus::Compensated,
+            DbOrchestrationStatus::CompensationFailed => OrchestrationStatus::CompensationFailed,
+            DbOrchestrationStatus::ManuallyResolved => OrchestrationStatus::ManuallyResolved,
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use banking_api::domain::{Payee, PayeeVerificationMethod, PayeeVerificationStatus};
+use banking_db::models::{DbPayeeVerificationMethod, DbPayeeVerificationStatus, PayeeModel};
+
+pub struct PayeeMapper;
+
+impl PayeeMapper {
+    /// Map from domain Payee to database PayeeModel
+    pub fn to_model(payee: Payee) -> PayeeModel {
+        PayeeModel {
+            id: payee.id,
+            customer_id: payee.customer_id,
+            beneficiary_name: payee.beneficiary_name,
+            external_bank_code: payee.external_bank_code,
+            external_account_number: payee.external_account_number,
+            verification_status: Self::status_to_db(payee.verification_status),
+            verification_method: payee.verification_method.map(Self::method_to_db),
+            penny_test_amount: payee.penny_test_amount,
+            verified_at: payee.verified_at,
+            verified_by_person_id: payee.verified_by_person_id,
+            first_used_at: payee.first_used_at,
+            created_at: payee.created_at,
+            last_updated_at: payee.last_updated_at,
+            updated_by_person_id: payee.updated_by_person_id,
+        }
+    }
+
+    /// Map from database PayeeModel to domain Payee
+    pub fn from_model(model: PayeeModel) -> Payee {
+        Payee {
+            id: model.id,
+            customer_id: model.customer_id,
+            beneficiary_name: model.beneficiary_name,
+            external_bank_code: model.external_bank_code,
+            external_account_number: model.external_account_number,
+            verification_status: Self::status_from_db(model.verification_status),
+            verification_method: model.verification_method.map(Self::method_from_db),
+            penny_test_amount: model.penny_test_amount,
+            verified_at: model.verified_at,
+            verified_by_person_id: model.verified_by_person_id,
+            first_used_at: model.first_used_at,
+            created_at: model.created_at,
+            last_updated_at: model.last_updated_at,
+            updated_by_person_id: model.updated_by_person_id,
+        }
+    }
+
+    pub fn status_to_db(status: PayeeVerificationStatus) -> DbPayeeVerificationStatus {
+        match status {
+            PayeeVerificationStatus::Unverified => DbPayeeVerificationStatus::Unverified,
+            PayeeVerificationStatus::PendingVerification => DbPayeeVerificationStatus::PendingVerification,
+            PayeeVerificationStatus::Verified => DbPayeeVerificationStatus::Verified,
+            PayeeVerificationStatus::Blocked => DbPayeeVerificationStatus::Blocked,
+        }
+    }
+
+    pub fn status_from_db(status: DbPayeeVerificationStatus) -> PayeeVerificationStatus {
+        match status {
+            DbPayeeVerificationStatus::Unverified => PayeeVerificationStatus::Unverified,
+            DbPayeeVerificationStatus::PendingVerification => PayeeVerificationStatus::PendingVerification,
+            DbPayeeVerificationStatus::Verified => PayeeVerificationStatus::Verified,
+            DbPayeeVerificationStatus::Blocked => PayeeVerificationStatus::Blocked,
+        }
+    }
+
+    fn method_to_db(method: PayeeVerificationMethod) -> DbPayeeVerificationMethod {
+        match method {
+            PayeeVerificationMethod::PennyTest => DbPayeeVerificationMethod::PennyTest,
+            PayeeVerificationMethod::ManualConfirmation => DbPayeeVerificationMethod::ManualConfirmation,
+        }
+    }
+
+    fn method_from_db(method: DbPayeeVerificationMethod) -> PayeeVerificationMethod {
+        match method {
+            DbPayeeVerificationMethod::PennyTest => PayeeVerificationMethod::PennyTest,
+            DbPayeeVerificationMethod::ManualConfirmation => PayeeVerificationMethod::ManualConfirmation,
+        }
+    }
+}
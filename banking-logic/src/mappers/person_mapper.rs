@@ -1,10 +1,11 @@
 use banking_api::domain::person::{
     Location, LocationType, Locality, Country, EntityReference, Person,
-    PersonType, RelationshipRole, CountrySubdivision,
+    PersonType, RelationshipRole, CountrySubdivision, ContactPreference,
 };
+use banking_api::domain::MessageChannel;
 use banking_db::models::person::{
     LocationModel, LocalityModel, CountryModel, EntityReferenceModel, PersonModel,
-    CountrySubdivisionModel,
+    CountrySubdivisionModel, ContactChannel, ContactPreferenceModel,
 };
 
 pub trait ToDomain<D> {
@@ -156,6 +157,35 @@ impl ToModel<EntityReferenceModel> for EntityReference {
     }
 }
 
+impl ToDomain<ContactPreference> for ContactPreferenceModel {
+    fn to_domain(self) -> ContactPreference {
+        ContactPreference {
+            id: self.id,
+            person_id: self.person_id,
+            channel: self.channel.to_domain(),
+            allowed: self.allowed,
+            quiet_hours_start: self.quiet_hours_start,
+            quiet_hours_end: self.quiet_hours_end,
+            preferred_language: self.preferred_language,
+        }
+    }
+}
+
+impl ToModel<ContactPreferenceModel> for ContactPreference {
+    fn to_model(self) -> ContactPreferenceModel {
+        ContactPreferenceModel {
+            id: self.id,
+            person_id: self.person_id,
+            channel: self.channel.to_model(),
+            allowed: self.allowed,
+            quiet_hours_start: self.quiet_hours_start,
+            quiet_hours_end: self.quiet_hours_end,
+            preferred_language: self.preferred_language,
+            updated_at: chrono::Utc::now(),
+        }
+    }
+}
+
 impl ToDomain<Person> for PersonModel {
     fn to_domain(self) -> Person {
         Person {
@@ -288,4 +318,23 @@ impl ToModel<banking_db::models::person::RelationshipRole> for RelationshipRole
             RelationshipRole::Other => banking_db::models::person::RelationshipRole::Other,
         }
     }
-}
\ No newline at end of file
+}
+impl ToDomain<MessageChannel> for ContactChannel {
+    fn to_domain(self) -> MessageChannel {
+        match self {
+            ContactChannel::Sms => MessageChannel::Sms,
+            ContactChannel::Email => MessageChannel::Email,
+            ContactChannel::InApp => MessageChannel::InApp,
+        }
+    }
+}
+
+impl ToModel<ContactChannel> for MessageChannel {
+    fn to_model(self) -> ContactChannel {
+        match self {
+            MessageChannel::Sms => ContactChannel::Sms,
+            MessageChannel::Email => ContactChannel::Email,
+            MessageChannel::InApp => ContactChannel::InApp,
+        }
+    }
+}
@@ -1,12 +1,14 @@
 use banking_api::domain::{
     GlMapping as ApiGlMapping, InterestRateTier as ApiInterestRateTier, Product as ApiProduct,
     ProductRules as ApiProductRules, ProductType as ApiProductType,
-    PostingFrequency as ApiPostingFrequency, ProductAccrualFrequency as ApiProductAccrualFrequency
+    PostingFrequency as ApiPostingFrequency, ProductAccrualFrequency as ApiProductAccrualFrequency,
+    DayCountConvention as ApiDayCountConvention
 };
 use banking_db::models::{
     GlMappingModel as DbGlMapping, InterestRateTierModel as DbInterestRateTier,
     ProductModel as DbProduct, ProductRules as DbProductRules, ProductType as DbProductType,
-    PostingFrequency as DbPostingFrequency, ProductAccrualFrequency as DbProductAccrualFrequency
+    PostingFrequency as DbPostingFrequency, ProductAccrualFrequency as DbProductAccrualFrequency,
+    DayCountConvention as DbDayCountConvention
 };
 pub struct ProductMapper;
 
@@ -82,11 +84,25 @@ impl ProductRulesMapper {
             default_overdraft_limit: api_model.default_overdraft_limit,
             per_transaction_limit: api_model.per_transaction_limit,
             overdraft_interest_rate: api_model.overdraft_interest_rate,
+            unauthorized_overdraft_interest_rate: api_model.unauthorized_overdraft_interest_rate,
             accrual_frequency: match api_model.accrual_frequency {
                 ApiProductAccrualFrequency::Daily => DbProductAccrualFrequency::Daily,
                 ApiProductAccrualFrequency::BusinessDaysOnly => DbProductAccrualFrequency::BusinessDaysOnly,
                 ApiProductAccrualFrequency::None => DbProductAccrualFrequency::None,
             },
+            day_count_convention: match api_model.day_count_convention {
+                ApiDayCountConvention::Actual365Fixed => DbDayCountConvention::Actual365Fixed,
+                ApiDayCountConvention::Actual360 => DbDayCountConvention::Actual360,
+                ApiDayCountConvention::ActualActual => DbDayCountConvention::ActualActual,
+                ApiDayCountConvention::Thirty360 => DbDayCountConvention::Thirty360,
+            },
+            guarantor_required: api_model.guarantor_required,
+            custody_fee_threshold: api_model.custody_fee_threshold,
+            custody_fee_rate: api_model.custody_fee_rate,
+            inactivity_fee_threshold_months: api_model.inactivity_fee_threshold_months,
+            inactivity_fee_item_id: api_model.inactivity_fee_item_id,
+            inactivity_fee_warning_months: api_model.inactivity_fee_warning_months,
+            supported_currencies: api_model.supported_currencies,
         }
     }
 
@@ -115,11 +131,25 @@ impl ProductRulesMapper {
             default_overdraft_limit: db_model.default_overdraft_limit,
             per_transaction_limit: db_model.per_transaction_limit,
             overdraft_interest_rate: db_model.overdraft_interest_rate,
+            unauthorized_overdraft_interest_rate: db_model.unauthorized_overdraft_interest_rate,
             accrual_frequency: match db_model.accrual_frequency {
                 DbProductAccrualFrequency::Daily => ApiProductAccrualFrequency::Daily,
                 DbProductAccrualFrequency::BusinessDaysOnly => ApiProductAccrualFrequency::BusinessDaysOnly,
                 DbProductAccrualFrequency::None => ApiProductAccrualFrequency::None,
             },
+            day_count_convention: match db_model.day_count_convention {
+                DbDayCountConvention::Actual365Fixed => ApiDayCountConvention::Actual365Fixed,
+                DbDayCountConvention::Actual360 => ApiDayCountConvention::Actual360,
+                DbDayCountConvention::ActualActual => ApiDayCountConvention::ActualActual,
+                DbDayCountConvention::Thirty360 => ApiDayCountConvention::Thirty360,
+            },
+            guarantor_required: db_model.guarantor_required,
+            custody_fee_threshold: db_model.custody_fee_threshold,
+            custody_fee_rate: db_model.custody_fee_rate,
+            inactivity_fee_threshold_months: db_model.inactivity_fee_threshold_months,
+            inactivity_fee_item_id: db_model.inactivity_fee_item_id,
+            inactivity_fee_warning_months: db_model.inactivity_fee_warning_months,
+            supported_currencies: db_model.supported_currencies,
         }
     }
 }
@@ -0,0 +1,63 @@
+use banking_api::domain::{ProductPromotion, PromotionRate};
+use banking_db::models::{DbPromotionRateKind, ProductPromotionModel};
+
+pub struct PromotionMapper;
+
+impl PromotionMapper {
+    /// Map from domain ProductPromotion to database ProductPromotionModel
+    pub fn to_model(promotion: ProductPromotion) -> ProductPromotionModel {
+        let (rate_kind, rate_value) = match promotion.rate {
+            PromotionRate::Bonus(bonus) => (DbPromotionRateKind::Bonus, bonus),
+            PromotionRate::Override(rate) => (DbPromotionRateKind::Override, rate),
+        };
+
+        ProductPromotionModel {
+            id: promotion.id,
+            product_id: promotion.product_id,
+            promotion_code: promotion.promotion_code,
+            name: promotion.name,
+            rate_kind,
+            rate_value,
+            opened_from: promotion.opened_from,
+            opened_to: promotion.opened_to,
+            segment_code: promotion.segment_code,
+            start_date: promotion.start_date,
+            end_date: promotion.end_date,
+            budget_cap: promotion.budget_cap,
+            bonus_interest_attributed: promotion.bonus_interest_attributed,
+            budget_exhausted_at: promotion.budget_exhausted_at,
+            is_active: promotion.is_active,
+            created_at: promotion.created_at,
+            last_updated_at: promotion.last_updated_at,
+            updated_by_person_id: promotion.updated_by_person_id,
+        }
+    }
+
+    /// Map from database ProductPromotionModel to domain ProductPromotion
+    pub fn from_model(model: ProductPromotionModel) -> ProductPromotion {
+        let rate = match model.rate_kind {
+            DbPromotionRateKind::Bonus => PromotionRate::Bonus(model.rate_value),
+            DbPromotionRateKind::Override => PromotionRate::Override(model.rate_value),
+        };
+
+        ProductPromotion {
+            id: model.id,
+            product_id: model.product_id,
+            promotion_code: model.promotion_code,
+            name: model.name,
+            rate,
+            opened_from: model.opened_from,
+            opened_to: model.opened_to,
+            segment_code: model.segment_code,
+            start_date: model.start_date,
+            end_date: model.end_date,
+            budget_cap: model.budget_cap,
+            bonus_interest_attributed: model.bonus_interest_attributed,
+            budget_exhausted_at: model.budget_exhausted_at,
+            is_active: model.is_active,
+            created_at: model.created_at,
+            last_updated_at: model.last_updated_at,
+            updated_by_person_id: model.updated_by_person_id,
+        }
+    }
+}
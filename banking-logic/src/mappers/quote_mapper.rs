@@ -0,0 +1,77 @@
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{Quote, QuoteSimulation, QuoteStatus, QuoteType},
+};
+use banking_db::models::{DbQuoteStatus, DbQuoteType, QuoteModel};
+
+pub struct QuoteMapper;
+
+impl QuoteMapper {
+    /// Map from domain Quote to database QuoteModel
+    pub fn to_model(quote: Quote) -> BankingResult<QuoteModel> {
+        let simulation = serde_json::to_string(&quote.simulation)
+            .map_err(|e| BankingError::Internal(format!("Failed to serialize quote simulation: {e}")))?;
+
+        Ok(QuoteModel {
+            id: quote.id,
+            quote_type: Self::quote_type_to_db(quote.quote_type),
+            product_id: quote.product_id,
+            customer_id: quote.customer_id,
+            simulation,
+            status: Self::quote_status_to_db(quote.status),
+            expires_at: quote.expires_at,
+            converted_at: quote.converted_at,
+            created_at: quote.created_at,
+            created_by_person_id: quote.created_by_person_id,
+        })
+    }
+
+    /// Map from database QuoteModel to domain Quote
+    pub fn from_model(model: QuoteModel) -> BankingResult<Quote> {
+        let simulation: QuoteSimulation = serde_json::from_str(&model.simulation)
+            .map_err(|e| BankingError::Internal(format!("Invalid quote simulation for quote {}: {e}", model.id)))?;
+
+        Ok(Quote {
+            id: model.id,
+            quote_type: Self::quote_type_from_db(model.quote_type),
+            product_id: model.product_id,
+            customer_id: model.customer_id,
+            simulation,
+            status: Self::quote_status_from_db(model.status),
+            expires_at: model.expires_at,
+            converted_at: model.converted_at,
+            created_at: model.created_at,
+            created_by_person_id: model.created_by_person_id,
+        })
+    }
+
+    fn quote_type_to_db(quote_type: QuoteType) -> DbQuoteType {
+        match quote_type {
+            QuoteType::Savings => DbQuoteType::Savings,
+            QuoteType::Loan => DbQuoteType::Loan,
+        }
+    }
+
+    fn quote_type_from_db(quote_type: DbQuoteType) -> QuoteType {
+        match quote_type {
+            DbQuoteType::Savings => QuoteType::Savings,
+            DbQuoteType::Loan => QuoteType::Loan,
+        }
+    }
+
+    pub fn quote_status_to_db(status: QuoteStatus) -> DbQuoteStatus {
+        match status {
+            QuoteStatus::Active => DbQuoteStatus::Active,
+            QuoteStatus::Converted => DbQuoteStatus::Converted,
+            QuoteStatus::Expired => DbQuoteStatus::Expired,
+        }
+    }
+
+    fn quote_status_from_db(status: DbQuoteStatus) -> QuoteStatus {
+        match status {
+            DbQuoteStatus::Active => QuoteStatus::Active,
+            DbQuoteStatus::Converted => QuoteStatus::Converted,
+            DbQuoteStatus::Expired => QuoteStatus::Expired,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use banking_api::domain::{SavingsGoal, SavingsGoalStatus};
+use banking_db::models::{DbSavingsGoalStatus, SavingsGoalModel};
+
+pub struct SavingsGoalMapper;
+
+impl SavingsGoalMapper {
+    /// Map from domain SavingsGoal to database SavingsGoalModel
+    pub fn to_model(goal: SavingsGoal) -> SavingsGoalModel {
+        SavingsGoalModel {
+            id: goal.id,
+            account_id: goal.account_id,
+            name: goal.name,
+            target_amount: goal.target_amount,
+            target_date: goal.target_date,
+            allocated_balance: goal.allocated_balance,
+            status: Self::status_to_db(goal.status),
+            created_at: goal.created_at,
+            closed_at: goal.closed_at,
+            last_updated_at: goal.last_updated_at,
+            updated_by_person_id: goal.updated_by_person_id,
+        }
+    }
+
+    /// Map from database SavingsGoalModel to domain SavingsGoal
+    pub fn from_model(model: SavingsGoalModel) -> SavingsGoal {
+        SavingsGoal {
+            id: model.id,
+            account_id: model.account_id,
+            name: model.name,
+            target_amount: model.target_amount,
+            target_date: model.target_date,
+            allocated_balance: model.allocated_balance,
+            status: Self::status_from_db(model.status),
+            created_at: model.created_at,
+            closed_at: model.closed_at,
+            last_updated_at: model.last_updated_at,
+            updated_by_person_id: model.updated_by_person_id,
+        }
+    }
+
+    pub fn status_to_db(status: SavingsGoalStatus) -> DbSavingsGoalStatus {
+        match status {
+            SavingsGoalStatus::Active => DbSavingsGoalStatus::Active,
+            SavingsGoalStatus::Closed => DbSavingsGoalStatus::Closed,
+        }
+    }
+
+    pub fn status_from_db(status: DbSavingsGoalStatus) -> SavingsGoalStatus {
+        match status {
+            DbSavingsGoalStatus::Active => SavingsGoalStatus::Active,
+            DbSavingsGoalStatus::Closed => SavingsGoalStatus::Closed,
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use banking_api::domain::{
+    Segment, SegmentComparison, SegmentCriterion, SegmentMembership, SegmentMembershipDelta,
+};
+use banking_db::models::{
+    DbSegmentComparison, SegmentCriterionModel, SegmentMembershipDeltaModel,
+    SegmentMembershipModel, SegmentModel,
+};
+
+use crate::mappers::{AccountMapper, CustomerMapper};
+
+pub struct SegmentMapper;
+
+impl SegmentMapper {
+    /// Map from domain Segment to database SegmentModel
+    pub fn to_model(segment: Segment) -> SegmentModel {
+        SegmentModel {
+            id: segment.id,
+            code: segment.code,
+            name: segment.name,
+            description: segment.description,
+            criteria: segment.criteria.into_iter().map(Self::criterion_to_model).collect(),
+            is_active: segment.is_active,
+            created_at: segment.created_at,
+            last_updated_at: segment.last_updated_at,
+            updated_by_person_id: segment.updated_by_person_id,
+        }
+    }
+
+    /// Map from database SegmentModel to domain Segment
+    pub fn from_model(model: SegmentModel) -> Segment {
+        Segment {
+            id: model.id,
+            code: model.code,
+            name: model.name,
+            description: model.description,
+            criteria: model.criteria.into_iter().map(Self::criterion_from_model).collect(),
+            is_active: model.is_active,
+            created_at: model.created_at,
+            last_updated_at: model.last_updated_at,
+            updated_by_person_id: model.updated_by_person_id,
+        }
+    }
+
+    /// Map from database SegmentMembershipModel to domain SegmentMembership
+    pub fn membership_from_model(model: SegmentMembershipModel) -> SegmentMembership {
+        SegmentMembership {
+            id: model.id,
+            segment_id: model.segment_id,
+            customer_id: model.customer_id,
+            entered_at: model.entered_at,
+            exited_at: model.exited_at,
+        }
+    }
+
+    /// Map from database SegmentMembershipDeltaModel to domain SegmentMembershipDelta
+    pub fn delta_from_model(model: SegmentMembershipDeltaModel) -> SegmentMembershipDelta {
+        SegmentMembershipDelta {
+            segment_id: model.segment_id,
+            since: model.since,
+            entered_customer_ids: model.entered_customer_ids,
+            exited_customer_ids: model.exited_customer_ids,
+        }
+    }
+
+    fn criterion_to_model(criterion: SegmentCriterion) -> SegmentCriterionModel {
+        match criterion {
+            SegmentCriterion::CustomerTypeIs { customer_type } => SegmentCriterionModel::CustomerTypeIs {
+                customer_type: CustomerMapper::customer_type_to_db(customer_type),
+            },
+            SegmentCriterion::CustomerStatusIn { statuses } => SegmentCriterionModel::CustomerStatusIn {
+                statuses: statuses.into_iter().map(CustomerMapper::customer_status_to_db).collect(),
+            },
+            SegmentCriterion::RiskRatingIn { ratings } => SegmentCriterionModel::RiskRatingIn {
+                ratings: ratings.into_iter().map(CustomerMapper::risk_rating_to_db).collect(),
+            },
+            SegmentCriterion::TotalBalance { operator, amount } => SegmentCriterionModel::TotalBalance {
+                operator: Self::operator_to_db(operator),
+                amount,
+            },
+            SegmentCriterion::HoldsAccountType { account_type, held } => SegmentCriterionModel::HoldsAccountType {
+                account_type: AccountMapper::account_type_to_db(account_type),
+                held,
+            },
+            SegmentCriterion::HoldsProduct { product_id, held } => SegmentCriterionModel::HoldsProduct {
+                product_id,
+                held,
+            },
+            SegmentCriterion::HasAccountInStatus { statuses } => SegmentCriterionModel::HasAccountInStatus {
+                statuses: statuses.into_iter().map(AccountMapper::account_status_to_db).collect(),
+            },
+            SegmentCriterion::DaysSinceLastActivity { operator, days } => SegmentCriterionModel::DaysSinceLastActivity {
+                operator: Self::operator_to_db(operator),
+                days,
+            },
+            SegmentCriterion::HasTag { tag_code, tagged } => SegmentCriterionModel::HasTag { tag_code, tagged },
+            SegmentCriterion::HoldsTaggedAccount { tag_code, held } => SegmentCriterionModel::HoldsTaggedAccount {
+                tag_code,
+                held,
+            },
+        }
+    }
+
+    fn criterion_from_model(model: SegmentCriterionModel) -> SegmentCriterion {
+        match model {
+            SegmentCriterionModel::CustomerTypeIs { customer_type } => SegmentCriterion::CustomerTypeIs {
+                customer_type: CustomerMapper::customer_type_from_db(customer_type),
+            },
+            SegmentCriterionModel::CustomerStatusIn { statuses } => SegmentCriterion::CustomerStatusIn {
+                statuses: statuses.into_iter().map(CustomerMapper::customer_status_from_db).collect(),
+            },
+            SegmentCriterionModel::RiskRatingIn { ratings } => SegmentCriterion::RiskRatingIn {
+                ratings: ratings.into_iter().map(CustomerMapper::risk_rating_from_db).collect(),
+            },
+            SegmentCriterionModel::TotalBalance { operator, amount } => SegmentCriterion::TotalBalance {
+                operator: Self::operator_from_db(operator),
+                amount,
+            },
+            SegmentCriterionModel::HoldsAccountType { account_type, held } => SegmentCriterion::HoldsAccountType {
+                account_type: AccountMapper::account_type_from_db(account_type),
+                held,
+            },
+            SegmentCriterionModel::HoldsProduct { product_id, held } => SegmentCriterion::HoldsProduct {
+                product_id,
+                held,
+            },
+            SegmentCriterionModel::HasAccountInStatus { statuses } => SegmentCriterion::HasAccountInStatus {
+                statuses: statuses.into_iter().map(AccountMapper::account_status_from_db).collect(),
+            },
+            SegmentCriterionModel::DaysSinceLastActivity { operator, days } => SegmentCriterion::DaysSinceLastActivity {
+                operator: Self::operator_from_db(operator),
+                days,
+            },
+            SegmentCriterionModel::HasTag { tag_code, tagged } => SegmentCriterion::HasTag { tag_code, tagged },
+            SegmentCriterionModel::HoldsTaggedAccount { tag_code, held } => SegmentCriterion::HoldsTaggedAccount {
+                tag_code,
+                held,
+            },
+        }
+    }
+
+    fn operator_to_db(operator: SegmentComparison) -> DbSegmentComparison {
+        match operator {
+            SegmentComparison::Equal => DbSegmentComparison::Equal,
+            SegmentComparison::NotEqual => DbSegmentComparison::NotEqual,
+            SegmentComparison::GreaterThan => DbSegmentComparison::GreaterThan,
+            SegmentComparison::GreaterThanOrEqual => DbSegmentComparison::GreaterThanOrEqual,
+            SegmentComparison::LessThan => DbSegmentComparison::LessThan,
+            SegmentComparison::LessThanOrEqual => DbSegmentComparison::LessThanOrEqual,
+        }
+    }
+
+    fn operator_from_db(operator: DbSegmentComparison) -> SegmentComparison {
+        match operator {
+            DbSegmentComparison::Equal => SegmentComparison::Equal,
+            DbSegmentComparison::NotEqual => SegmentComparison::NotEqual,
+            DbSegmentComparison::GreaterThan => SegmentComparison::GreaterThan,
+            DbSegmentComparison::GreaterThanOrEqual => SegmentComparison::GreaterThanOrEqual,
+            DbSegmentComparison::LessThan => SegmentComparison::LessThan,
+            DbSegmentComparison::LessThanOrEqual => SegmentComparison::LessThanOrEqual,
+        }
+    }
+}
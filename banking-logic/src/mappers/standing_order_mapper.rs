@@ -0,0 +1,124 @@
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        StandingOrder, StandingOrderAmount, StandingOrderFailurePolicy, StandingOrderFrequency, StandingOrderStatus,
+    },
+};
+use banking_db::models::{
+    DbStandingOrderAmountType, DbStandingOrderFailurePolicy, DbStandingOrderFrequency, DbStandingOrderStatus,
+    StandingOrderModel,
+};
+
+pub struct StandingOrderMapper;
+
+impl StandingOrderMapper {
+    /// Map from domain StandingOrder to database StandingOrderModel
+    pub fn to_model(order: StandingOrder) -> StandingOrderModel {
+        let (amount_type, amount) = match order.amount {
+            StandingOrderAmount::Fixed(amount) => (DbStandingOrderAmountType::Fixed, amount),
+            StandingOrderAmount::SweepAboveThreshold(threshold) => (DbStandingOrderAmountType::SweepAboveThreshold, threshold),
+        };
+        let (failure_policy, max_consecutive_failures) = match order.failure_policy {
+            StandingOrderFailurePolicy::Skip => (DbStandingOrderFailurePolicy::Skip, None),
+            StandingOrderFailurePolicy::RetryNextDay => (DbStandingOrderFailurePolicy::RetryNextDay, None),
+            StandingOrderFailurePolicy::SuspendAfter(max) => (DbStandingOrderFailurePolicy::SuspendAfter, Some(max)),
+        };
+        StandingOrderModel {
+            id: order.id,
+            source_account_id: order.source_account_id,
+            beneficiary_account_id: order.beneficiary_account_id,
+            external_reference: order.external_reference,
+            amount_type,
+            amount,
+            frequency: Self::frequency_to_db(order.frequency),
+            start_date: order.start_date,
+            end_date: order.end_date,
+            next_due_date: order.next_due_date,
+            next_run_date: order.next_run_date,
+            status: Self::status_to_db(order.status),
+            failure_policy,
+            max_consecutive_failures,
+            consecutive_failures: order.consecutive_failures,
+            last_executed_at: order.last_executed_at,
+            created_at: order.created_at,
+            last_updated_at: order.last_updated_at,
+            updated_by_person_id: order.updated_by_person_id,
+        }
+    }
+
+    /// Map from database StandingOrderModel to domain StandingOrder
+    pub fn from_model(model: StandingOrderModel) -> BankingResult<StandingOrder> {
+        let amount = match model.amount_type {
+            DbStandingOrderAmountType::Fixed => StandingOrderAmount::Fixed(model.amount),
+            DbStandingOrderAmountType::SweepAboveThreshold => StandingOrderAmount::SweepAboveThreshold(model.amount),
+        };
+        let failure_policy = match (model.failure_policy, model.max_consecutive_failures) {
+            (DbStandingOrderFailurePolicy::Skip, _) => StandingOrderFailurePolicy::Skip,
+            (DbStandingOrderFailurePolicy::RetryNextDay, _) => StandingOrderFailurePolicy::RetryNextDay,
+            (DbStandingOrderFailurePolicy::SuspendAfter, Some(max)) => StandingOrderFailurePolicy::SuspendAfter(max),
+            (DbStandingOrderFailurePolicy::SuspendAfter, None) => {
+                return Err(BankingError::Internal(format!(
+                    "Standing order {} suspends after failures but has no failure limit",
+                    model.id
+                )));
+            }
+        };
+        Ok(StandingOrder {
+            id: model.id,
+            source_account_id: model.source_account_id,
+            beneficiary_account_id: model.beneficiary_account_id,
+            external_reference: model.external_reference,
+            amount,
+            frequency: Self::frequency_from_db(model.frequency),
+            start_date: model.start_date,
+            end_date: model.end_date,
+            next_due_date: model.next_due_date,
+            next_run_date: model.next_run_date,
+            status: Self::status_from_db(model.status),
+            failure_policy,
+            consecutive_failures: model.consecutive_failures,
+            last_executed_at: model.last_executed_at,
+            created_at: model.created_at,
+            last_updated_at: model.last_updated_at,
+            updated_by_person_id: model.updated_by_person_id,
+        })
+    }
+
+    pub fn frequency_to_db(frequency: StandingOrderFrequency) -> DbStandingOrderFrequency {
+        match frequency {
+            StandingOrderFrequency::Daily => DbStandingOrderFrequency::Daily,
+            StandingOrderFrequency::Weekly => DbStandingOrderFrequency::Weekly,
+            StandingOrderFrequency::Monthly => DbStandingOrderFrequency::Monthly,
+            StandingOrderFrequency::Quarterly => DbStandingOrderFrequency::Quarterly,
+            StandingOrderFrequency::Yearly => DbStandingOrderFrequency::Yearly,
+        }
+    }
+
+    pub fn frequency_from_db(frequency: DbStandingOrderFrequency) -> StandingOrderFrequency {
+        match frequency {
+            DbStandingOrderFrequency::Daily => StandingOrderFrequency::Daily,
+            DbStandingOrderFrequency::Weekly => StandingOrderFrequency::Weekly,
+            DbStandingOrderFrequency::Monthly => StandingOrderFrequency::Monthly,
+            DbStandingOrderFrequency::Quarterly => StandingOrderFrequency::Quarterly,
+            DbStandingOrderFrequency::Yearly => StandingOrderFrequency::Yearly,
+        }
+    }
+
+    pub fn status_to_db(status: StandingOrderStatus) -> DbStandingOrderStatus {
+        match status {
+            StandingOrderStatus::Active => DbStandingOrderStatus::Active,
+            StandingOrderStatus::Suspended => DbStandingOrderStatus::Suspended,
+            StandingOrderStatus::Completed => DbStandingOrderStatus::Completed,
+            StandingOrderStatus::Cancelled => DbStandingOrderStatus::Cancelled,
+        }
+    }
+
+    pub fn status_from_db(status: DbStandingOrderStatus) -> StandingOrderStatus {
+        match status {
+            DbStandingOrderStatus::Active => StandingOrderStatus::Active,
+            DbStandingOrderStatus::Suspended => StandingOrderStatus::Suspended,
+            DbStandingOrderStatus::Completed => StandingOrderStatus::Completed,
+            DbStandingOrderStatus::Cancelled => StandingOrderStatus::Cancelled,
+        }
+    }
+}
@@ -0,0 +1,298 @@
+use banking_api::domain::{
+    AccountStatement, CustomerContact, PaperStatementAccount, StatementChargeSummary, StatementConsentRecord,
+    StatementDeliveryMethod,
+    StatementDeliveryPreference, StatementEntry, StatementIntegrityAlert, StatementIntegrityCheck, StatementLine,
+    StatementNotification, StatementNotificationType, StatementPrintBatch, StatementPrintEntry,
+    StatementRecipient, StatementTotals,
+};
+use banking_db::models::{
+    AccountStatementModel, CustomerContactModel, DbStatementDeliveryMethod, DbStatementIntegrityCheck,
+    DbStatementNotificationType, PaperStatementAccountModel, StatementChargeSummaryModel, StatementConsentRecordModel,
+    StatementDeliveryPreferenceModel, StatementEntryModel, StatementIntegrityAlertModel, StatementLineModel,
+    StatementNotificationModel, StatementPrintBatchModel, StatementPrintEntryModel, StatementRecipientModel,
+};
+use crate::mappers::DocumentMapper;
+
+pub struct StatementMapper;
+
+impl StatementMapper {
+    /// Map from domain StatementDeliveryPreference to database StatementDeliveryPreferenceModel
+    pub fn preference_to_model(preference: StatementDeliveryPreference) -> StatementDeliveryPreferenceModel {
+        StatementDeliveryPreferenceModel {
+            account_id: preference.account_id,
+            delivery_method: Self::delivery_method_to_db(preference.delivery_method),
+            contact_messaging_id: preference.contact_messaging_id,
+            consent_given_at: preference.consent_given_at,
+            consent_channel: preference.consent_channel,
+            last_updated_at: preference.last_updated_at,
+            updated_by_person_id: preference.updated_by_person_id,
+        }
+    }
+
+    /// Map from database StatementDeliveryPreferenceModel to domain StatementDeliveryPreference
+    pub fn preference_from_model(model: StatementDeliveryPreferenceModel) -> StatementDeliveryPreference {
+        StatementDeliveryPreference {
+            account_id: model.account_id,
+            delivery_method: Self::delivery_method_from_db(model.delivery_method),
+            contact_messaging_id: model.contact_messaging_id,
+            consent_given_at: model.consent_given_at,
+            consent_channel: model.consent_channel,
+            last_updated_at: model.last_updated_at,
+            updated_by_person_id: model.updated_by_person_id,
+        }
+    }
+
+    /// Map from database CustomerContactModel to domain CustomerContact
+    pub fn contact_from_model(model: CustomerContactModel) -> CustomerContact {
+        CustomerContact {
+            id: model.id,
+            customer_id: model.customer_id,
+            messaging_id: model.messaging_id,
+            verified_at: model.verified_at,
+            verified_by_person_id: model.verified_by_person_id,
+        }
+    }
+
+    /// Map from domain StatementConsentRecord to database StatementConsentRecordModel
+    pub fn consent_to_model(consent: StatementConsentRecord) -> StatementConsentRecordModel {
+        StatementConsentRecordModel {
+            id: consent.id,
+            account_id: consent.account_id,
+            customer_id: consent.customer_id,
+            previous_method: Self::delivery_method_to_db(consent.previous_method),
+            new_method: Self::delivery_method_to_db(consent.new_method),
+            contact_messaging_id: consent.contact_messaging_id,
+            contact_verified_at: consent.contact_verified_at,
+            consent_channel: consent.consent_channel,
+            consent_reference: consent.consent_reference,
+            consent_given_at: consent.consent_given_at,
+            recorded_by_person_id: consent.recorded_by_person_id,
+        }
+    }
+
+    /// Map from database StatementConsentRecordModel to domain StatementConsentRecord
+    pub fn consent_from_model(model: StatementConsentRecordModel) -> StatementConsentRecord {
+        StatementConsentRecord {
+            id: model.id,
+            account_id: model.account_id,
+            customer_id: model.customer_id,
+            previous_method: Self::delivery_method_from_db(model.previous_method),
+            new_method: Self::delivery_method_from_db(model.new_method),
+            contact_messaging_id: model.contact_messaging_id,
+            contact_verified_at: model.contact_verified_at,
+            consent_channel: model.consent_channel,
+            consent_reference: model.consent_reference,
+            consent_given_at: model.consent_given_at,
+            recorded_by_person_id: model.recorded_by_person_id,
+        }
+    }
+
+    /// Map from domain StatementNotification to database StatementNotificationModel
+    pub fn notification_to_model(notification: StatementNotification) -> StatementNotificationModel {
+        StatementNotificationModel {
+            id: notification.id,
+            notification_type: Self::notification_type_to_db(notification.notification_type),
+            account_id: notification.account_id,
+            customer_id: notification.customer_id,
+            contact_messaging_id: notification.contact_messaging_id,
+            statement_reference: notification.statement_reference,
+            status: DocumentMapper::notification_status_to_db(notification.status),
+            queued_at: notification.queued_at,
+            sent_at: notification.sent_at,
+        }
+    }
+
+    /// Map from database StatementRecipientModel to domain StatementRecipient
+    pub fn recipient_from_model(model: StatementRecipientModel) -> StatementRecipient {
+        StatementRecipient {
+            account_id: model.account_id,
+            customer_id: model.customer_id,
+            delivery_method: Self::delivery_method_from_db(model.delivery_method),
+            contact_messaging_id: model.contact_messaging_id,
+        }
+    }
+
+    /// Map from domain StatementPrintBatch to database StatementPrintBatchModel
+    pub fn batch_to_model(batch: StatementPrintBatch) -> StatementPrintBatchModel {
+        StatementPrintBatchModel {
+            id: batch.id,
+            cycle_date: batch.cycle_date,
+            entry_count: batch.entry_count,
+            created_at: batch.created_at,
+        }
+    }
+
+    /// Map from database StatementPrintBatchModel to domain StatementPrintBatch
+    pub fn batch_from_model(model: StatementPrintBatchModel) -> StatementPrintBatch {
+        StatementPrintBatch {
+            id: model.id,
+            cycle_date: model.cycle_date,
+            entry_count: model.entry_count,
+            created_at: model.created_at,
+        }
+    }
+
+    /// Map from domain StatementPrintEntry to database StatementPrintEntryModel
+    pub fn entry_to_model(entry: StatementPrintEntry) -> StatementPrintEntryModel {
+        StatementPrintEntryModel {
+            id: entry.id,
+            batch_id: entry.batch_id,
+            account_id: entry.account_id,
+            customer_id: entry.customer_id,
+            statement_reference: entry.statement_reference,
+            delivery_method: Self::delivery_method_to_db(entry.delivery_method),
+        }
+    }
+
+    /// Map from database StatementPrintEntryModel to domain StatementPrintEntry
+    pub fn entry_from_model(model: StatementPrintEntryModel) -> StatementPrintEntry {
+        StatementPrintEntry {
+            id: model.id,
+            batch_id: model.batch_id,
+            account_id: model.account_id,
+            customer_id: model.customer_id,
+            statement_reference: model.statement_reference,
+            delivery_method: Self::delivery_method_from_db(model.delivery_method),
+        }
+    }
+
+    /// Map from database PaperStatementAccountModel to domain PaperStatementAccount
+    pub fn paper_account_from_model(model: PaperStatementAccountModel) -> PaperStatementAccount {
+        PaperStatementAccount {
+            account_id: model.account_id,
+            customer_id: model.customer_id,
+            delivery_method: Self::delivery_method_from_db(model.delivery_method),
+            has_verified_contact: model.has_verified_contact,
+        }
+    }
+
+    pub fn entry_from_source(model: StatementEntryModel) -> StatementEntry {
+        StatementEntry {
+            transaction_id: model.transaction_id,
+            value_date: model.value_date,
+            description: model.description,
+            amount: model.amount,
+        }
+    }
+
+    pub fn charges_from_model(model: StatementChargeSummaryModel) -> StatementChargeSummary {
+        StatementChargeSummary {
+            interest_accrued: model.interest_accrued,
+            fees_charged: model.fees_charged,
+            fee_count: model.fee_count,
+        }
+    }
+
+    /// Map from domain AccountStatement to the database statement and its lines
+    pub fn statement_to_model(statement: AccountStatement) -> (AccountStatementModel, Vec<StatementLineModel>) {
+        let lines = statement
+            .lines
+            .into_iter()
+            .enumerate()
+            .map(|(index, line)| StatementLineModel {
+                statement_id: statement.id,
+                line_number: index as i32 + 1,
+                transaction_id: line.transaction_id,
+                value_date: line.value_date,
+                description: line.description,
+                amount: line.amount,
+                running_balance: line.running_balance,
+            })
+            .collect();
+        let model = AccountStatementModel {
+            id: statement.id,
+            account_id: statement.account_id,
+            statement_number: statement.statement_number,
+            statement_reference: statement.statement_reference,
+            period_start: statement.period_start,
+            period_end: statement.period_end,
+            opening_balance: statement.totals.opening_balance,
+            total_credits: statement.totals.total_credits,
+            total_debits: statement.totals.total_debits,
+            closing_balance: statement.totals.closing_balance,
+            interest_accrued: statement.charges.interest_accrued,
+            fees_charged: statement.charges.fees_charged,
+            fee_count: statement.charges.fee_count,
+            content_hash: statement.content_hash,
+            supersedes_statement_id: statement.supersedes_statement_id,
+            generated_at: statement.generated_at,
+            generated_by_person_id: statement.generated_by_person_id,
+        };
+        (model, lines)
+    }
+
+    /// Map from the database statement and its lines to domain AccountStatement
+    pub fn statement_from_model(model: AccountStatementModel, lines: Vec<StatementLineModel>) -> AccountStatement {
+        AccountStatement {
+            id: model.id,
+            account_id: model.account_id,
+            statement_number: model.statement_number,
+            statement_reference: model.statement_reference,
+            period_start: model.period_start,
+            period_end: model.period_end,
+            lines: lines
+                .into_iter()
+                .map(|line| StatementLine {
+                    transaction_id: line.transaction_id,
+                    value_date: line.value_date,
+                    description: line.description,
+                    amount: line.amount,
+                    running_balance: line.running_balance,
+                })
+                .collect(),
+            totals: StatementTotals {
+                opening_balance: model.opening_balance,
+                total_credits: model.total_credits,
+                total_debits: model.total_debits,
+                closing_balance: model.closing_balance,
+            },
+            charges: StatementChargeSummary {
+                interest_accrued: model.interest_accrued,
+                fees_charged: model.fees_charged,
+                fee_count: model.fee_count,
+            },
+            content_hash: model.content_hash,
+            supersedes_statement_id: model.supersedes_statement_id,
+            generated_at: model.generated_at,
+            generated_by_person_id: model.generated_by_person_id,
+        }
+    }
+
+    pub fn alert_to_model(alert: StatementIntegrityAlert) -> StatementIntegrityAlertModel {
+        StatementIntegrityAlertModel {
+            id: alert.id,
+            statement_id: alert.statement_id,
+            account_id: alert.account_id,
+            stored_hash: alert.stored_hash,
+            computed_hash: alert.computed_hash,
+            detected_during: match alert.detected_during {
+                StatementIntegrityCheck::Reprint => DbStatementIntegrityCheck::Reprint,
+                StatementIntegrityCheck::ArchiveVerification => DbStatementIntegrityCheck::ArchiveVerification,
+            },
+            detected_at: alert.detected_at,
+        }
+    }
+
+    fn delivery_method_to_db(method: StatementDeliveryMethod) -> DbStatementDeliveryMethod {
+        match method {
+            StatementDeliveryMethod::Paper => DbStatementDeliveryMethod::Paper,
+            StatementDeliveryMethod::Electronic => DbStatementDeliveryMethod::Electronic,
+            StatementDeliveryMethod::Both => DbStatementDeliveryMethod::Both,
+        }
+    }
+
+    fn delivery_method_from_db(method: DbStatementDeliveryMethod) -> StatementDeliveryMethod {
+        match method {
+            DbStatementDeliveryMethod::Paper => StatementDeliveryMethod::Paper,
+            DbStatementDeliveryMethod::Electronic => StatementDeliveryMethod::Electronic,
+            DbStatementDeliveryMethod::Both => StatementDeliveryMethod::Both,
+        }
+    }
+
+    fn notification_type_to_db(notification_type: StatementNotificationType) -> DbStatementNotificationType {
+        match notification_type {
+            StatementNotificationType::PreferenceChanged => DbStatementNotificationType::PreferenceChanged,
+            StatementNotificationType::StatementReady => DbStatementNotificationType::StatementReady,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use banking_api::domain::{
+    AccountTagSelection, BulkTagTarget, EntityTag, Tag, TagFilter, TagMatchMode, TaggableEntityKind,
+};
+use banking_db::models::{
+    AccountTagSelectionModel, BulkTagTargetModel, DbTagMatchMode, DbTaggableEntityKind, EntityTagModel,
+    TagFilterModel, TagModel,
+};
+use crate::mappers::{AccountMapper, CustomerMapper};
+
+pub struct TagMapper;
+
+impl TagMapper {
+    /// Map from domain Tag to database TagModel
+    pub fn to_model(tag: Tag) -> TagModel {
+        TagModel {
+            id: tag.id,
+            code: tag.code,
+            description: tag.description,
+            applicable_entity_kinds: tag.applicable_entity_kinds.into_iter().map(Self::entity_kind_to_db).collect(),
+            required_permission: tag.required_permission,
+            is_active: tag.is_active,
+            created_at: tag.created_at,
+            last_updated_at: tag.last_updated_at,
+            updated_by_person_id: tag.updated_by_person_id,
+        }
+    }
+
+    /// Map from database TagModel to domain Tag
+    pub fn from_model(model: TagModel) -> Tag {
+        Tag {
+            id: model.id,
+            code: model.code,
+            description: model.description,
+            applicable_entity_kinds: model.applicable_entity_kinds.into_iter().map(Self::entity_kind_from_db).collect(),
+            required_permission: model.required_permission,
+            is_active: model.is_active,
+            created_at: model.created_at,
+            last_updated_at: model.last_updated_at,
+            updated_by_person_id: model.updated_by_person_id,
+        }
+    }
+
+    pub fn entity_tag_to_model(entity_tag: EntityTag) -> EntityTagModel {
+        EntityTagModel {
+            id: entity_tag.id,
+            tag_id: entity_tag.tag_id,
+            entity_kind: Self::entity_kind_to_db(entity_tag.entity_kind),
+            entity_id: entity_tag.entity_id,
+            assigned_at: entity_tag.assigned_at,
+            assigned_by_person_id: entity_tag.assigned_by_person_id,
+        }
+    }
+
+    pub fn entity_tag_from_model(model: EntityTagModel) -> EntityTag {
+        EntityTag {
+            id: model.id,
+            tag_id: model.tag_id,
+            entity_kind: Self::entity_kind_from_db(model.entity_kind),
+            entity_id: model.entity_id,
+            assigned_at: model.assigned_at,
+            assigned_by_person_id: model.assigned_by_person_id,
+        }
+    }
+
+    pub fn filter_to_model(filter: TagFilter) -> TagFilterModel {
+        TagFilterModel {
+            tag_codes: filter.tag_codes,
+            match_mode: match filter.match_mode {
+                TagMatchMode::Any => DbTagMatchMode::Any,
+                TagMatchMode::All => DbTagMatchMode::All,
+            },
+        }
+    }
+
+    pub fn bulk_target_to_model(target: BulkTagTarget) -> BulkTagTargetModel {
+        match target {
+            BulkTagTarget::Accounts(selection) => BulkTagTargetModel::Accounts(Self::account_selection_to_model(selection)),
+            BulkTagTarget::Customers(criteria) => BulkTagTargetModel::Customers(CustomerMapper::search_criteria_to_model(criteria)),
+        }
+    }
+
+    fn account_selection_to_model(selection: AccountTagSelection) -> AccountTagSelectionModel {
+        AccountTagSelectionModel {
+            product_id: selection.product_id,
+            account_status: selection.account_status.map(AccountMapper::account_status_to_db),
+            opened_from: selection.opened_from,
+            opened_to: selection.opened_to,
+            tags: selection.tags.map(Self::filter_to_model),
+        }
+    }
+
+    pub fn entity_kind_to_db(kind: TaggableEntityKind) -> DbTaggableEntityKind {
+        match kind {
+            TaggableEntityKind::Customer => DbTaggableEntityKind::Customer,
+            TaggableEntityKind::Account => DbTaggableEntityKind::Account,
+        }
+    }
+
+    fn entity_kind_from_db(kind: DbTaggableEntityKind) -> TaggableEntityKind {
+        match kind {
+            DbTaggableEntityKind::Customer => TaggableEntityKind::Customer,
+            DbTaggableEntityKind::Account => TaggableEntityKind::Account,
+        }
+    }
+}
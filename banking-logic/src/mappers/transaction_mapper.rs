@@ -1,24 +1,29 @@
 use banking_api::domain::{
-    self as domain, GlEntry, Transaction, TransactionAudit, TransactionRequest,
-    TransactionResult, TransactionValidationResult, TransactionType as ApiTransactionType,
+    self as domain, CurrencyCode, GlEntry, Transaction, TransactionAudit, TransactionRequest,
+    TransactionResult, TransactionSearchCriteria, TransactionValidationResult,
+    TransactionType as ApiTransactionType,
 };
 use banking_db::models::{
     self as db, GlEntryModel, TransactionAuditModel, TransactionModel, TransactionRequestModel,
-    TransactionResultModel, TransactionValidationResultModel, TransactionType as DbTransactionType,
+    TransactionResultModel, TransactionSearchCriteriaModel, TransactionValidationResultModel,
+    TransactionType as DbTransactionType,
 };
 
+use crate::mappers::TagMapper;
+
 pub struct TransactionMapper;
 
 impl TransactionMapper {
     /// Map from domain Transaction to database TransactionModel
     pub fn to_model(transaction: Transaction) -> TransactionModel {
+        let fx_conversion = transaction.fx_conversion;
         TransactionModel {
             id: transaction.id,
             account_id: transaction.account_id,
             transaction_code: transaction.transaction_code,
             transaction_type: Self::transaction_type_to_db(transaction.transaction_type),
             amount: transaction.amount,
-            currency: transaction.currency,
+            currency: transaction.currency.into(),
             description: transaction.description,
             channel_id: transaction.channel_id,
             terminal_id: transaction.terminal_id,
@@ -32,6 +37,12 @@ impl TransactionMapper {
             requires_approval: transaction.requires_approval,
             approval_status: transaction.approval_status.map(Self::transaction_approval_status_to_db),
             risk_score: transaction.risk_score,
+            degraded_flags: transaction.degraded_flags.bits() as i32,
+            idempotency_key: transaction.idempotency_key,
+            reversal_of_transaction_id: transaction.reversal_of_transaction_id,
+            original_amount: fx_conversion.as_ref().map(|c| c.original_amount),
+            original_currency: fx_conversion.as_ref().map(|c| c.original_currency.clone().into()),
+            exchange_rate: fx_conversion.map(|c| c.exchange_rate),
             created_at: transaction.created_at,
         }
     }
@@ -44,7 +55,7 @@ impl TransactionMapper {
             transaction_code: model.transaction_code,
             transaction_type: Self::transaction_type_from_db(model.transaction_type),
             amount: model.amount,
-            currency: model.currency,
+            currency: CurrencyCode::try_from(model.currency)?,
             description: model.description,
             channel_id: model.channel_id,
             terminal_id: model.terminal_id,
@@ -58,10 +69,33 @@ impl TransactionMapper {
             requires_approval: model.requires_approval,
             approval_status: model.approval_status.map(Self::transaction_approval_status_from_db),
             risk_score: model.risk_score,
+            degraded_flags: domain::DegradedFlags::from_bits(model.degraded_flags as u32),
+            idempotency_key: model.idempotency_key,
+            reversal_of_transaction_id: model.reversal_of_transaction_id,
+            fx_conversion: match (model.original_amount, model.original_currency, model.exchange_rate) {
+                (Some(original_amount), Some(original_currency), Some(exchange_rate)) => Some(domain::FxConversion {
+                    original_amount,
+                    original_currency: CurrencyCode::try_from(original_currency)?,
+                    exchange_rate,
+                }),
+                _ => None,
+            },
             created_at: model.created_at,
         })
     }
 
+    pub fn search_criteria_to_model(criteria: TransactionSearchCriteria) -> TransactionSearchCriteriaModel {
+        TransactionSearchCriteriaModel {
+            account_id: criteria.account_id,
+            from_date: criteria.from_date,
+            to_date: criteria.to_date,
+            status: criteria.status.map(Self::transaction_status_to_db),
+            account_tags: criteria.account_tags.map(TagMapper::filter_to_model),
+            offset: criteria.offset,
+            limit: criteria.limit,
+        }
+    }
+
     // Helper methods for enum conversions
     pub fn transaction_type_to_db(t: ApiTransactionType) -> DbTransactionType {
         match t {
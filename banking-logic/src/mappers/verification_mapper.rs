@@ -0,0 +1,92 @@
+use banking_api::domain::{ChallengeStatus, VerificationChallenge, VerificationMethod, VerificationOperationKind};
+use banking_db::models::{DbChallengeStatus, DbVerificationMethod, DbVerificationOperationKind, VerificationChallengeModel};
+
+pub struct VerificationMapper;
+
+impl VerificationMapper {
+    /// Map from domain VerificationChallenge to database VerificationChallengeModel
+    pub fn to_model(challenge: VerificationChallenge) -> VerificationChallengeModel {
+        VerificationChallengeModel {
+            id: challenge.id,
+            customer_id: challenge.customer_id,
+            operation_kind: Self::operation_kind_to_db(challenge.operation_kind),
+            channel_id: challenge.channel_id,
+            code: challenge.code,
+            status: Self::status_to_db(challenge.status),
+            failed_attempts: challenge.failed_attempts as i32,
+            last_failed_at: challenge.last_failed_at,
+            created_at: challenge.created_at,
+            expires_at: challenge.expires_at,
+            verified_method: challenge.verified_method.map(Self::method_to_db),
+            verified_by_person_id: challenge.verified_by_person_id,
+            verified_at: challenge.verified_at,
+            consumed_at: challenge.consumed_at,
+        }
+    }
+
+    /// Map from database VerificationChallengeModel to domain VerificationChallenge
+    pub fn from_model(model: VerificationChallengeModel) -> VerificationChallenge {
+        VerificationChallenge {
+            id: model.id,
+            customer_id: model.customer_id,
+            operation_kind: Self::operation_kind_from_db(model.operation_kind),
+            channel_id: model.channel_id,
+            code: model.code,
+            status: Self::status_from_db(model.status),
+            failed_attempts: model.failed_attempts.max(0) as u32,
+            last_failed_at: model.last_failed_at,
+            created_at: model.created_at,
+            expires_at: model.expires_at,
+            verified_method: model.verified_method.map(Self::method_from_db),
+            verified_by_person_id: model.verified_by_person_id,
+            verified_at: model.verified_at,
+            consumed_at: model.consumed_at,
+        }
+    }
+
+    pub fn operation_kind_to_db(kind: VerificationOperationKind) -> DbVerificationOperationKind {
+        match kind {
+            VerificationOperationKind::ContactDetailChange => DbVerificationOperationKind::ContactDetailChange,
+            VerificationOperationKind::LimitIncrease => DbVerificationOperationKind::LimitIncrease,
+        }
+    }
+
+    fn operation_kind_from_db(kind: DbVerificationOperationKind) -> VerificationOperationKind {
+        match kind {
+            DbVerificationOperationKind::ContactDetailChange => VerificationOperationKind::ContactDetailChange,
+            DbVerificationOperationKind::LimitIncrease => VerificationOperationKind::LimitIncrease,
+        }
+    }
+
+    fn status_to_db(status: ChallengeStatus) -> DbChallengeStatus {
+        match status {
+            ChallengeStatus::Pending => DbChallengeStatus::Pending,
+            ChallengeStatus::Verified => DbChallengeStatus::Verified,
+            ChallengeStatus::Consumed => DbChallengeStatus::Consumed,
+            ChallengeStatus::Failed => DbChallengeStatus::Failed,
+        }
+    }
+
+    fn status_from_db(status: DbChallengeStatus) -> ChallengeStatus {
+        match status {
+            DbChallengeStatus::Pending => ChallengeStatus::Pending,
+            DbChallengeStatus::Verified => ChallengeStatus::Verified,
+            DbChallengeStatus::Consumed => ChallengeStatus::Consumed,
+            DbChallengeStatus::Failed => ChallengeStatus::Failed,
+        }
+    }
+
+    fn method_to_db(method: VerificationMethod) -> DbVerificationMethod {
+        match method {
+            VerificationMethod::Otp => DbVerificationMethod::Otp,
+            VerificationMethod::BranchAssertion => DbVerificationMethod::BranchAssertion,
+        }
+    }
+
+    fn method_from_db(method: DbVerificationMethod) -> VerificationMethod {
+        match method {
+            DbVerificationMethod::Otp => VerificationMethod::Otp,
+            DbVerificationMethod::BranchAssertion => VerificationMethod::BranchAssertion,
+        }
+    }
+}
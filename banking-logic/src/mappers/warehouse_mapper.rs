@@ -0,0 +1,38 @@
+use banking_api::domain::{WarehouseEntity, WarehouseExportMode};
+use banking_db::models::{DbWarehouseEntity, DbWarehouseExportMode};
+
+pub struct WarehouseMapper;
+
+impl WarehouseMapper {
+    pub fn entity_to_db(entity: WarehouseEntity) -> DbWarehouseEntity {
+        match entity {
+            WarehouseEntity::Account => DbWarehouseEntity::Account,
+            WarehouseEntity::Transaction => DbWarehouseEntity::Transaction,
+            WarehouseEntity::Customer => DbWarehouseEntity::Customer,
+            WarehouseEntity::Workflow => DbWarehouseEntity::Workflow,
+        }
+    }
+
+    pub fn entity_from_db(entity: DbWarehouseEntity) -> WarehouseEntity {
+        match entity {
+            DbWarehouseEntity::Account => WarehouseEntity::Account,
+            DbWarehouseEntity::Transaction => WarehouseEntity::Transaction,
+            DbWarehouseEntity::Customer => WarehouseEntity::Customer,
+            DbWarehouseEntity::Workflow => WarehouseEntity::Workflow,
+        }
+    }
+
+    pub fn mode_to_db(mode: WarehouseExportMode) -> DbWarehouseExportMode {
+        match mode {
+            WarehouseExportMode::Incremental => DbWarehouseExportMode::Incremental,
+            WarehouseExportMode::FullSnapshot => DbWarehouseExportMode::FullSnapshot,
+        }
+    }
+
+    pub fn mode_from_db(mode: DbWarehouseExportMode) -> WarehouseExportMode {
+        match mode {
+            DbWarehouseExportMode::Incremental => WarehouseExportMode::Incremental,
+            DbWarehouseExportMode::FullSnapshot => WarehouseExportMode::FullSnapshot,
+        }
+    }
+}
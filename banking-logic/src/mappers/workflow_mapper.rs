@@ -1,15 +1,18 @@
+use banking_api::BankingResult;
 use banking_api::domain::{
-    AccountWorkflow, WorkflowType, WorkflowStep, WorkflowStatus, WorkflowStepRecord,
+    AccountWorkflow, CurrencyCode, WorkflowType, WorkflowStep, WorkflowStatus, WorkflowStepRecord,
     AccountOpeningRequest, ClosureRequest, ClosureReason, FinalSettlement,
-    DormancyAssessment, DocumentReference
+    DormancyAssessment, DocumentReference, WorkflowEscalation, WorkflowEscalationStatus
 };
 use banking_db::models::{
     AccountWorkflowModel, WorkflowTypeModel, WorkflowStepModel, WorkflowStatusModel,
     WorkflowStepRecordModel, AccountOpeningRequestModel, ClosureRequestModel,
     ClosureReasonModel, WorkflowFinalSettlementModel, DormancyAssessmentModel,
-    DocumentReferenceModel
+    DocumentReferenceModel, WorkflowEscalationModel, WorkflowEscalationStatusModel
 };
 
+use crate::mappers::AccountMapper;
+
 pub struct WorkflowMapper;
 
 impl WorkflowMapper {
@@ -17,7 +20,7 @@ impl WorkflowMapper {
     pub fn to_model(workflow: AccountWorkflow) -> AccountWorkflowModel {
         AccountWorkflowModel {
             id: workflow.id,
-            account_id: workflow.id,
+            account_id: workflow.account_id,
             workflow_type: Self::workflow_type_to_db(workflow.workflow_type),
             current_step: Self::workflow_step_to_db(workflow.current_step),
             status: Self::workflow_status_to_db(workflow.status),
@@ -75,6 +78,9 @@ impl WorkflowMapper {
         AccountOpeningRequestModel {
             customer_id: request.customer_id,
             product_id: request.product_id,
+            currency: request.currency.into(),
+            signing_condition: AccountMapper::signing_condition_to_db(request.signing_condition),
+            joint_owner_ids: request.joint_owner_ids,
             initial_deposit: request.initial_deposit,
             channel: request.channel,
             initiated_by: request.initiated_by,
@@ -82,14 +88,18 @@ impl WorkflowMapper {
                 .into_iter()
                 .map(Self::document_reference_to_model)
                 .collect(),
+            quote_id: request.quote_id,
         }
     }
 
     /// Map from database AccountOpeningRequestModel to domain AccountOpeningRequest
-    pub fn opening_request_from_model(model: AccountOpeningRequestModel) -> AccountOpeningRequest {
-        AccountOpeningRequest {
+    pub fn opening_request_from_model(model: AccountOpeningRequestModel) -> BankingResult<AccountOpeningRequest> {
+        Ok(AccountOpeningRequest {
             customer_id: model.customer_id,
             product_id: model.product_id,
+            currency: CurrencyCode::try_from(model.currency)?,
+            signing_condition: AccountMapper::signing_condition_from_db(model.signing_condition),
+            joint_owner_ids: model.joint_owner_ids,
             initial_deposit: model.initial_deposit,
             channel: model.channel,
             initiated_by: model.initiated_by,
@@ -97,7 +107,8 @@ impl WorkflowMapper {
                 .into_iter()
                 .map(Self::document_reference_from_model)
                 .collect(),
-        }
+            quote_id: model.quote_id,
+        })
     }
 
     /// Map from domain ClosureRequest to database ClosureRequestModel
@@ -169,6 +180,7 @@ impl WorkflowMapper {
     pub fn document_reference_to_model(doc_ref: DocumentReference) -> DocumentReferenceModel {
         DocumentReferenceModel {
             document_id: doc_ref.document_id,
+            content_hash: doc_ref.content_hash,
             document_type: doc_ref.document_type,
             document_path: doc_ref.document_path,
         }
@@ -178,6 +190,7 @@ impl WorkflowMapper {
     pub fn document_reference_from_model(model: DocumentReferenceModel) -> DocumentReference {
         DocumentReference {
             document_id: model.document_id,
+            content_hash: model.content_hash,
             document_type: model.document_type,
             document_path: model.document_path,
         }
@@ -191,6 +204,7 @@ impl WorkflowMapper {
             WorkflowType::AccountReactivation => WorkflowTypeModel::KycUpdate,
             WorkflowType::ComplianceVerification => WorkflowTypeModel::ComplianceCheck,
             WorkflowType::MultiPartyApproval => WorkflowTypeModel::TransactionApproval,
+            WorkflowType::DormancyAssessment => WorkflowTypeModel::DormancyAssessment,
         }
     }
 
@@ -226,6 +240,62 @@ impl WorkflowMapper {
         }
     }
 
+    /// Map from domain WorkflowEscalation to database WorkflowEscalationModel
+    pub fn escalation_to_model(escalation: WorkflowEscalation) -> WorkflowEscalationModel {
+        WorkflowEscalationModel {
+            id: escalation.id,
+            workflow_id: escalation.workflow_id,
+            escalated_to_person_id: escalation.escalated_to_person_id,
+            level: i16::from(escalation.level),
+            status: Self::escalation_status_to_db(escalation.status),
+            created_at: escalation.created_at,
+            action_due_at: escalation.action_due_at,
+            acknowledged_at: escalation.acknowledged_at,
+            resolved_at: escalation.resolved_at,
+            resolved_by_person_id: escalation.resolved_by_person_id,
+            resolution_notes: escalation.resolution_notes,
+            last_updated_at: escalation.created_at, // Will be updated in DB
+        }
+    }
+
+    /// Map from database WorkflowEscalationModel to domain WorkflowEscalation
+    pub fn escalation_from_model(model: WorkflowEscalationModel) -> banking_api::BankingResult<WorkflowEscalation> {
+        Ok(WorkflowEscalation {
+            id: model.id,
+            workflow_id: model.workflow_id,
+            escalated_to_person_id: model.escalated_to_person_id,
+            level: u8::try_from(model.level).map_err(|_| banking_api::BankingError::ValidationError {
+                field: "level".to_string(),
+                message: format!("Escalation level {} is out of range", model.level),
+            })?,
+            status: Self::escalation_status_from_db(model.status),
+            created_at: model.created_at,
+            action_due_at: model.action_due_at,
+            acknowledged_at: model.acknowledged_at,
+            resolved_at: model.resolved_at,
+            resolved_by_person_id: model.resolved_by_person_id,
+            resolution_notes: model.resolution_notes,
+        })
+    }
+
+    fn escalation_status_to_db(status: WorkflowEscalationStatus) -> WorkflowEscalationStatusModel {
+        match status {
+            WorkflowEscalationStatus::Open => WorkflowEscalationStatusModel::Open,
+            WorkflowEscalationStatus::Acknowledged => WorkflowEscalationStatusModel::Acknowledged,
+            WorkflowEscalationStatus::Resolved => WorkflowEscalationStatusModel::Resolved,
+            WorkflowEscalationStatus::Escalated => WorkflowEscalationStatusModel::Escalated,
+        }
+    }
+
+    fn escalation_status_from_db(status: WorkflowEscalationStatusModel) -> WorkflowEscalationStatus {
+        match status {
+            WorkflowEscalationStatusModel::Open => WorkflowEscalationStatus::Open,
+            WorkflowEscalationStatusModel::Acknowledged => WorkflowEscalationStatus::Acknowledged,
+            WorkflowEscalationStatusModel::Resolved => WorkflowEscalationStatus::Resolved,
+            WorkflowEscalationStatusModel::Escalated => WorkflowEscalationStatus::Escalated,
+        }
+    }
+
     // Database to Domain enum conversions
     fn workflow_type_from_db(workflow_type: WorkflowTypeModel) -> WorkflowType {
         match workflow_type {
@@ -244,6 +314,7 @@ impl WorkflowMapper {
             WorkflowTypeModel::LimitChange => WorkflowType::MultiPartyApproval,
             WorkflowTypeModel::StatusChange => WorkflowType::MultiPartyApproval,
             WorkflowTypeModel::ManualIntervention => WorkflowType::MultiPartyApproval,
+            WorkflowTypeModel::DormancyAssessment => WorkflowType::DormancyAssessment,
         }
     }
 
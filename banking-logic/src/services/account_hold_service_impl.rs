@@ -4,15 +4,16 @@ use async_trait::async_trait;
 use banking_api::{
     domain::{
         AccountHold, AccountHoldExpiryJob, AccountHoldReleaseRequest, AccountHoldSummary,
-        HoldPriority, HoldStatus, HoldType, PlaceHoldRequest,
+        HoldPriority, HoldStatus, HoldType, PlaceHoldRequest, ReasonId,
     },
     service::{
         account_hold_service::AccountHoldService, HighHoldAccount, HoldAnalytics, HoldAuthorizationLevel,
         JudicialHoldReport,
     },
-    BankingResult,
+    BankingError, BankingResult,
 };
 use banking_db::repository::{AccountHoldRepository, AccountRepository};
+use heapless::String as HeaplessString;
 use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use uuid::Uuid;
@@ -49,20 +50,29 @@ impl AccountHoldService for AccountHoldServiceImpl {
         Ok(domain_holds)
     }
 
+    /// Release an active hold; its amount returns to the available balance
     async fn release_hold(&self, hold_id: Uuid, released_by_person_id: Uuid) -> BankingResult<()> {
         self.account_hold_repo
             .release_hold(hold_id, released_by_person_id)
             .await
     }
 
+    /// Place a hold against the available balance. Only hold types that may
+    /// overdraw are accepted beyond what is available.
     async fn place_hold(
         &self,
         request: PlaceHoldRequest,
     ) -> BankingResult<AccountHold> {
-        let id = Uuid::new_v4();
-        let model = (request, id).into();
-        let created_hold = self.account_hold_repo.create_hold(model).await?;
-        Ok(AccountHoldMapper::account_hold_from_model(created_hold))
+        if request.amount <= Decimal::ZERO {
+            return Err(BankingError::ValidationError {
+                field: "amount".to_string(),
+                message: "Hold amount must be positive".to_string(),
+            });
+        }
+        let allow_overdraw = request.hold_type.may_overdraw();
+        let model = (request, Uuid::new_v4()).into();
+        let placed_hold = self.account_hold_repo.place_hold(model, allow_overdraw).await?;
+        Ok(AccountHoldMapper::account_hold_from_model(placed_hold))
     }
 
     async fn release_hold_with_request(
@@ -86,7 +96,7 @@ impl AccountHoldService for AccountHoldServiceImpl {
     async fn cancel_hold(
         &self,
         _hold_id: Uuid,
-        _cancellation_reason_id: Uuid,
+        _cancellation_reason_id: ReasonId,
         _cancelled_by_person_id: Uuid,
     ) -> BankingResult<AccountHold> {
         unimplemented!()
@@ -150,6 +160,20 @@ impl AccountHoldService for AccountHoldServiceImpl {
         unimplemented!()
     }
 
+    async fn expire_holds(&self, as_of: DateTime<Utc>) -> BankingResult<AccountHoldExpiryJob> {
+        let expired = self.account_hold_repo.release_expired_holds(as_of).await?;
+        Ok(AccountHoldExpiryJob {
+            id: Uuid::new_v4(),
+            processing_date: as_of.date_naive(),
+            expired_holds_count: expired.len() as u32,
+            total_released_amount: expired.iter().map(|hold| hold.amount).sum(),
+            processed_at: Utc::now(),
+            error_01: HeaplessString::new(),
+            error_02: HeaplessString::new(),
+            error_03: HeaplessString::new(),
+        })
+    }
+
     async fn process_automatic_releases(
         &self,
         _processing_date: NaiveDate,
@@ -162,7 +186,7 @@ impl AccountHoldService for AccountHoldServiceImpl {
         _account_ids: Vec<Uuid>,
         _hold_type: HoldType,
         _amount_per_account: Decimal,
-        _reason_id: Uuid,
+        _reason_id: ReasonId,
         _placed_by_person_id: Uuid,
         _expires_at: Option<DateTime<Utc>>,
     ) -> BankingResult<Vec<AccountHold>> {
@@ -172,7 +196,7 @@ impl AccountHoldService for AccountHoldServiceImpl {
     async fn bulk_release_holds(
         &self,
         _hold_ids: Vec<Uuid>,
-        _release_reason_id: Uuid,
+        _release_reason_id: ReasonId,
         _released_by_person_id: Uuid,
     ) -> BankingResult<Vec<AccountHold>> {
         unimplemented!()
@@ -184,7 +208,7 @@ impl AccountHoldService for AccountHoldServiceImpl {
         _transaction_amount: Decimal,
         _override_priority: HoldPriority,
         _authorized_by_person_id: Uuid,
-        _override_reason_id: Uuid,
+        _override_reason_id: ReasonId,
     ) -> BankingResult<Vec<AccountHold>> {
         unimplemented!()
     }
@@ -0,0 +1,244 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use heapless::String as HeaplessString;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{Account, AccountNumberScheme},
+    service::AccountNumberService,
+};
+use banking_db::repository::{AccountNumberRepository, AccountRepository};
+use crate::mappers::{AccountMapper, AccountNumberMapper};
+
+/// Widest serial a scheme may use: 18 digits always fit in an i64
+const MAX_SERIAL_LENGTH: u8 = 18;
+
+/// Production implementation of AccountNumberService
+pub struct AccountNumberServiceImpl {
+    account_number_repository: Arc<dyn AccountNumberRepository>,
+    account_repository: Arc<dyn AccountRepository>,
+}
+
+impl AccountNumberServiceImpl {
+    pub fn new(
+        account_number_repository: Arc<dyn AccountNumberRepository>,
+        account_repository: Arc<dyn AccountRepository>,
+    ) -> Self {
+        Self {
+            account_number_repository,
+            account_repository,
+        }
+    }
+
+    fn validate_scheme(scheme: &AccountNumberScheme) -> BankingResult<()> {
+        let invalid = |field: &str, message: String| BankingError::ValidationError {
+            field: field.to_string(),
+            message,
+        };
+
+        for (field, code) in [("bank_code", scheme.bank_code.as_str()), ("branch_code", scheme.branch_code.as_str())] {
+            if code.is_empty() || !code.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return Err(invalid(field, format!("{field} must be letters and digits, got '{code}'")));
+            }
+        }
+        if scheme.serial_length == 0 || scheme.serial_length > MAX_SERIAL_LENGTH {
+            return Err(invalid(
+                "serial_length",
+                format!("Serial length must be between 1 and {MAX_SERIAL_LENGTH}"),
+            ));
+        }
+        // Every number of the scheme has the length of the first one
+        scheme
+            .compose(1)
+            .map(|_| ())
+            .map_err(|err| invalid("account_number_scheme", err.to_string()))
+    }
+}
+
+#[async_trait]
+impl AccountNumberService for AccountNumberServiceImpl {
+    async fn configure_scheme(&self, scheme: AccountNumberScheme) -> BankingResult<AccountNumberScheme> {
+        Self::validate_scheme(&scheme)?;
+        let saved = self
+            .account_number_repository
+            .save_scheme(AccountNumberMapper::scheme_to_model(scheme))
+            .await?;
+        Ok(AccountNumberMapper::scheme_from_model(saved))
+    }
+
+    async fn find_applicable_scheme(&self, branch_id: Uuid, product_id: Uuid) -> BankingResult<Option<AccountNumberScheme>> {
+        let scheme = match self.account_number_repository.find_scheme(branch_id, Some(product_id)).await? {
+            Some(scheme) => Some(scheme),
+            None => self.account_number_repository.find_scheme(branch_id, None).await?,
+        };
+        Ok(scheme.map(AccountNumberMapper::scheme_from_model))
+    }
+
+    async fn generate_account_number(&self, branch_id: Uuid, product_id: Uuid) -> BankingResult<HeaplessString<34>> {
+        let scheme = self
+            .find_applicable_scheme(branch_id, product_id)
+            .await?
+            .ok_or(BankingError::AccountNumberSchemeNotFound { branch_id, product_id })?;
+
+        // A serial taken here is never handed out again, even if the opening fails later
+        let serial = self.account_number_repository.next_serial(branch_id).await?;
+        scheme.compose(serial).map_err(|err| BankingError::ValidationError {
+            field: "account_number_scheme".to_string(),
+            message: err.to_string(),
+        })
+    }
+
+    async fn find_account_by_number(&self, account_number: &str) -> BankingResult<Option<Account>> {
+        let compact: String = account_number
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_ascii_uppercase();
+        self.account_repository
+            .find_by_account_number(&compact)
+            .await?
+            .map(AccountMapper::from_model)
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{HashMap, HashSet};
+    use std::sync::Mutex;
+    use banking_db::models::AccountNumberSchemeModel;
+    use chrono::Utc;
+    use crate::services::test_doubles::InMemoryAccountRepository;
+
+    #[derive(Default)]
+    struct MockAccountNumberRepository {
+        schemes: Mutex<Vec<AccountNumberSchemeModel>>,
+        serials: Mutex<HashMap<Uuid, i64>>,
+    }
+
+    #[async_trait]
+    impl AccountNumberRepository for MockAccountNumberRepository {
+        async fn save_scheme(&self, scheme: AccountNumberSchemeModel) -> BankingResult<AccountNumberSchemeModel> {
+            let mut schemes = self.schemes.lock().unwrap();
+            schemes.retain(|s| !(s.branch_id == scheme.branch_id && s.product_id == scheme.product_id));
+            schemes.push(scheme.clone());
+            Ok(scheme)
+        }
+        async fn find_scheme(&self, branch_id: Uuid, product_id: Option<Uuid>) -> BankingResult<Option<AccountNumberSchemeModel>> {
+            Ok(self.schemes
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|s| s.branch_id == branch_id && s.product_id == product_id)
+                .cloned())
+        }
+        async fn next_serial(&self, branch_id: Uuid) -> BankingResult<i64> {
+            let mut serials = self.serials.lock().unwrap();
+            let serial = serials.entry(branch_id).or_insert(0);
+            *serial += 1;
+            Ok(*serial)
+        }
+    }
+
+    fn scheme(branch_id: Uuid, product_id: Option<Uuid>, bank_code: &str, iban_country_code: Option<&str>) -> AccountNumberScheme {
+        AccountNumberScheme {
+            id: Uuid::new_v4(),
+            branch_id,
+            product_id,
+            bank_code: HeaplessString::try_from(bank_code).unwrap(),
+            branch_code: HeaplessString::try_from("00001").unwrap(),
+            serial_length: 11,
+            iban_country_code: iban_country_code.map(|code| HeaplessString::try_from(code).unwrap()),
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    fn service() -> (AccountNumberServiceImpl, Arc<InMemoryAccountRepository>) {
+        let accounts = Arc::new(InMemoryAccountRepository::default());
+        let service = AccountNumberServiceImpl::new(Arc::new(MockAccountNumberRepository::default()), accounts.clone());
+        (service, accounts)
+    }
+
+    #[tokio::test]
+    async fn test_product_scheme_takes_precedence_over_branch_default() {
+        let (service, _) = service();
+        let branch_id = Uuid::new_v4();
+        let product_id = Uuid::new_v4();
+        service.configure_scheme(scheme(branch_id, None, "10005", Some("CM"))).await.unwrap();
+        service.configure_scheme(scheme(branch_id, Some(product_id), "10006", None)).await.unwrap();
+
+        let bban = service.generate_account_number(branch_id, product_id).await.unwrap();
+        assert!(bban.as_str().starts_with("100060000100000000001"), "{bban}");
+        assert_eq!(bban.len(), 23);
+
+        // Another product falls back to the branch default and continues the branch serial
+        let iban = service.generate_account_number(branch_id, Uuid::new_v4()).await.unwrap();
+        assert!(iban.as_str().starts_with("CM"), "{iban}");
+        assert_eq!(&iban.as_str()[4..25], "100050000100000000002");
+        assert!(banking_api::domain::is_valid_iban(iban.as_str()));
+
+        let other_branch = Uuid::new_v4();
+        let missing = service.generate_account_number(other_branch, product_id).await;
+        assert!(matches!(
+            missing,
+            Err(BankingError::AccountNumberSchemeNotFound { branch_id, product_id: p }) if branch_id == other_branch && p == product_id
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_configure_rejects_schemes_producing_invalid_numbers() {
+        let (service, _) = service();
+        let branch_id = Uuid::new_v4();
+
+        // 5 + 5 + 10 + 2 characters of BBAN give a 26 character IBAN; France uses 27
+        let mut wrong_length = scheme(branch_id, None, "20041", Some("FR"));
+        wrong_length.serial_length = 10;
+        let mut no_serial = scheme(branch_id, None, "10005", None);
+        no_serial.serial_length = 0;
+        for invalid in [
+            wrong_length,
+            no_serial,
+            scheme(branch_id, None, "100-05", None),
+            scheme(branch_id, None, "10005", Some("US")),
+        ] {
+            let result = service.configure_scheme(invalid.clone()).await;
+            assert!(matches!(result, Err(BankingError::ValidationError { .. })), "{invalid:?} was accepted");
+        }
+        assert!(service.find_applicable_scheme(branch_id, Uuid::new_v4()).await.unwrap().is_none());
+
+        let mut french = scheme(branch_id, None, "20041", Some("FR"));
+        french.serial_length = 11;
+        assert!(service.configure_scheme(french).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_generation_produces_no_duplicates() {
+        let (service, _) = service();
+        let service = Arc::new(service);
+        let branch_id = Uuid::new_v4();
+        service.configure_scheme(scheme(branch_id, None, "10005", Some("CM"))).await.unwrap();
+
+        let tasks: Vec<_> = (0..50)
+            .map(|_| {
+                let service = service.clone();
+                tokio::spawn(async move { service.generate_account_number(branch_id, Uuid::new_v4()).await.unwrap() })
+            })
+            .collect();
+        let mut numbers = HashSet::new();
+        for task in tasks {
+            numbers.insert(task.await.unwrap());
+        }
+        assert_eq!(numbers.len(), 50);
+    }
+
+    #[tokio::test]
+    async fn test_find_account_by_number_uses_compact_form() {
+        let (service, accounts) = service();
+        assert!(service.find_account_by_number("cm21 1000 5000 0100 0000 0004 262").await.unwrap().is_none());
+        assert_eq!(*accounts.queried_account_numbers.lock().unwrap(), vec!["CM2110005000010000000004262".to_string()]);
+    }
+}
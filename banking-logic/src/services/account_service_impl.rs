@@ -3,31 +3,45 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use banking_api::{
     domain::{
-        Account, AccountBalanceCalculation, AccountStatus, AccountHoldSummary,
+        Account, AccountBalanceCalculation, AccountStatus, AccountHoldSummary, TaggedAccountView, ReasonId, TaggableEntityKind,
+        AccountDomicileChange, ReasonedOperation, AccountBalanceChangeRecord,
     },
     service::{AccountService, HoldAuthorizationLevel, HoldAnalytics, HighHoldAccount, JudicialHoldReport},
-    BankingResult,
+    BankingError, BankingResult,
 };
 use banking_db::{
+    models::agent_network::BranchStatus as DbBranchStatus,
     repository::{
-        AccountRepository,
+        AccountDomicileRepository, AccountRepository, AgentNetworkRepository, ReasonAndPurposeRepository, TagRepository,
     },
 };
 use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 
-use crate::mappers::AccountMapper;
+use crate::mappers::{AccountDomicileMapper, AccountMapper, TagMapper};
+use crate::validation::ReasonValidation;
 
 #[derive(Clone)]
 pub struct AccountServiceImpl {
     account_repo: Arc<dyn AccountRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+    agent_network_repo: Arc<dyn AgentNetworkRepository>,
+    reason_repo: Arc<dyn ReasonAndPurposeRepository>,
+    domicile_repo: Arc<dyn AccountDomicileRepository>,
 }
 
 impl AccountServiceImpl {
-    pub fn new(account_repo: Arc<dyn AccountRepository>) -> Self {
-        Self { account_repo }
+    pub fn new(
+        account_repo: Arc<dyn AccountRepository>,
+        tag_repo: Arc<dyn TagRepository>,
+        agent_network_repo: Arc<dyn AgentNetworkRepository>,
+        reason_repo: Arc<dyn ReasonAndPurposeRepository>,
+        domicile_repo: Arc<dyn AccountDomicileRepository>,
+    ) -> Self {
+        Self { account_repo, tag_repo, agent_network_repo, reason_repo, domicile_repo }
     }
 }
 
@@ -44,10 +58,72 @@ impl AccountService for AccountServiceImpl {
         Ok(result.map(|m| AccountMapper::from_model(m).unwrap()))
     }
 
+    async fn get_account_view(&self, account_id: Uuid) -> BankingResult<TaggedAccountView> {
+        let model = self.account_repo
+            .find_by_id(account_id)
+            .await?
+            .ok_or(BankingError::AccountNotFound(account_id))?;
+        let tags = self.tag_repo
+            .find_tags_for_entity(TagMapper::entity_kind_to_db(TaggableEntityKind::Account), account_id)
+            .await?;
+
+        Ok(TaggedAccountView {
+            account: AccountMapper::from_model(model)?,
+            tags: tags.into_iter().map(|tag| tag.code).collect(),
+        })
+    }
+
     async fn update_account_status(&self, _account_id: Uuid, _status: AccountStatus, _authorized_by_person_id: Uuid) -> BankingResult<()> {
         unimplemented!()
     }
 
+    async fn bulk_update_account_status(
+        &self,
+        account_ids: &[Uuid],
+        status: AccountStatus,
+        reason_id: ReasonId,
+        changed_by_person_id: Uuid,
+    ) -> BankingResult<Vec<(Uuid, BankingResult<()>)>> {
+        ReasonValidation::require(self.reason_repo.as_ref(), reason_id, ReasonedOperation::AccountStatusChange).await?;
+
+        let current: HashMap<Uuid, AccountStatus> = self.account_repo
+            .find_by_ids(account_ids)
+            .await?
+            .into_iter()
+            .map(|account| (account.id, AccountMapper::account_status_from_db(account.account_status)))
+            .collect();
+        let (valid, rejected) = partition_status_targets(account_ids, &current, status);
+
+        let updated = self.account_repo
+            .bulk_update_status(&valid, &status.to_string(), reason_id.as_uuid(), changed_by_person_id)
+            .await;
+        let mut outcomes: HashMap<Uuid, BankingResult<()>> = updated.into_iter().collect();
+        outcomes.extend(rejected);
+
+        let mut seen = HashSet::new();
+
+        tracing::info!(
+            "Bulk status change to {} applied to {} of {} accounts",
+            status,
+            outcomes.values().filter(|result| result.is_ok()).count(),
+            account_ids.len()
+        );
+        Ok(account_ids
+            .iter()
+            .map(|id| {
+                let result = if seen.insert(*id) {
+                    outcomes.remove(id).unwrap_or(Err(BankingError::AccountNotFound(*id)))
+                } else {
+                    Err(BankingError::ValidationError {
+                        field: "account_ids".to_string(),
+                        message: format!("Account {id} is listed more than once"),
+                    })
+                };
+                (*id, result)
+            })
+            .collect())
+    }
+
     async fn calculate_balance(&self, _account_id: Uuid) -> BankingResult<Decimal> {
         unimplemented!()
     }
@@ -56,7 +132,7 @@ impl AccountService for AccountServiceImpl {
         unimplemented!()
     }
 
-    async fn apply_hold(&self, _account_id: Uuid, _amount: Decimal, _reason_id: Uuid, _additional_details: Option<&str>) -> BankingResult<()> {
+    async fn apply_hold(&self, _account_id: Uuid, _amount: Decimal, _reason_id: ReasonId, _additional_details: Option<&str>) -> BankingResult<()> {
         unimplemented!()
     }
 
@@ -84,6 +160,21 @@ impl AccountService for AccountServiceImpl {
         unimplemented!()
     }
 
+
+    async fn get_balance_history(
+        &self,
+        account_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> BankingResult<Vec<AccountBalanceChangeRecord>> {
+        Ok(self.account_repo
+            .get_balance_history(account_id, from, to)
+            .await?
+            .into_iter()
+            .map(AccountMapper::balance_change_from_model)
+            .collect())
+    }
+
     async fn reset_accrued_interest(&self, _account_id: Uuid) -> BankingResult<()> {
         unimplemented!()
     }
@@ -106,6 +197,88 @@ impl AccountService for AccountServiceImpl {
         unimplemented!()
     }
 
+    async fn transfer_account_domicile(
+        &self,
+        account_id: Uuid,
+        new_branch_id: Uuid,
+        effective_date: NaiveDate,
+        reason_id: ReasonId,
+        approved_by_person_id: Uuid,
+    ) -> BankingResult<AccountDomicileChange> {
+        let account = self.account_repo
+            .find_by_id(account_id)
+            .await?
+            .ok_or(BankingError::AccountNotFound(account_id))?;
+        let current_branch_id = account.domicile_agency_branch_id;
+        if new_branch_id == current_branch_id {
+            return Err(BankingError::ValidationFailed(format!(
+                "Account {account_id} is already domiciled at branch {new_branch_id}"
+            )));
+        }
+        ReasonValidation::require(self.reason_repo.as_ref(), reason_id, ReasonedOperation::AccountDomicileTransfer).await?;
+
+        let current_branch = self.agent_network_repo
+            .find_branch_by_id(current_branch_id)
+            .await?
+            .ok_or_else(|| BankingError::Internal(format!("Branch {current_branch_id} not found")))?;
+        let new_branch = self.agent_network_repo
+            .find_branch_by_id(new_branch_id)
+            .await?
+            .ok_or_else(|| BankingError::ValidationFailed(format!("Branch {new_branch_id} not found")))?;
+        if new_branch.status != DbBranchStatus::Active {
+            return Err(BankingError::ValidationFailed(
+                format!("Branch {new_branch_id} is not active (status: {:?})", new_branch.status)
+            ));
+        }
+        if new_branch.agent_network_id != current_branch.agent_network_id {
+            return Err(BankingError::ValidationFailed(format!(
+                "Branch {new_branch_id} does not belong to network {} of the current branch",
+                current_branch.agent_network_id
+            )));
+        }
+
+        // Attribution by date needs the history in effective date order
+        let history = self.domicile_repo.find_domicile_history(account_id).await?;
+        let not_before = history
+            .iter()
+            .map(|c| c.effective_date)
+            .max()
+            .unwrap_or(account.open_date);
+        if effective_date < not_before {
+            return Err(BankingError::ValidationError {
+                field: "effective_date".to_string(),
+                message: format!("Effective date {effective_date} is before {not_before}"),
+            });
+        }
+
+        let change = AccountDomicileChange {
+            id: Uuid::new_v4(),
+            account_id,
+            from_agency_branch_id: current_branch_id,
+            to_agency_branch_id: new_branch_id,
+            effective_date,
+            reason_id: reason_id.as_uuid(),
+            approved_by_person_id,
+            recorded_at: Utc::now(),
+        };
+        let recorded = self.domicile_repo.transfer_domicile(AccountDomicileMapper::to_model(change)).await?;
+
+        tracing::info!(
+            "Account {} moved from branch {} to branch {} effective {}",
+            account_id, current_branch_id, new_branch_id, effective_date
+        );
+        Ok(AccountDomicileMapper::from_model(recorded))
+    }
+
+    async fn get_domicile_history(&self, account_id: Uuid) -> BankingResult<Vec<AccountDomicileChange>> {
+        Ok(self.domicile_repo
+            .find_domicile_history(account_id)
+            .await?
+            .into_iter()
+            .map(AccountDomicileMapper::from_model)
+            .collect())
+    }
+
 
     async fn calculate_available_balance_detailed(
         &self,
@@ -159,7 +332,7 @@ impl AccountService for AccountServiceImpl {
         _account_ids: Vec<Uuid>,
         _hold_type: banking_api::domain::HoldType,
         _amount_per_account: Decimal,
-        _reason_id: Uuid,
+        _reason_id: ReasonId,
         _placed_by_person_id: Uuid,
         _expires_at: Option<DateTime<Utc>>,
     ) -> BankingResult<Vec<banking_api::domain::AccountHold>> {
@@ -169,7 +342,7 @@ impl AccountService for AccountServiceImpl {
     async fn bulk_release_holds(
         &self,
         _hold_ids: Vec<Uuid>,
-        _release_reason_id: Uuid,
+        _release_reason_id: ReasonId,
         _released_by_person_id: Uuid,
     ) -> BankingResult<Vec<banking_api::domain::AccountHold>> {
         todo!()
@@ -181,7 +354,7 @@ impl AccountService for AccountServiceImpl {
         _transaction_amount: Decimal,
         _override_priority: banking_api::domain::HoldPriority,
         _authorized_by_person_id: Uuid,
-        _override_reason_id: Uuid,
+        _override_reason_id: ReasonId,
     ) -> BankingResult<Vec<banking_api::domain::AccountHold>> {
         todo!()
     }
@@ -252,4 +425,63 @@ impl AccountService for AccountServiceImpl {
     ) -> BankingResult<JudicialHoldReport> {
         todo!()
     }
-}
\ No newline at end of file
+}
+/// Splits a bulk status change into accounts that may move to `status` and per-account rejections.
+/// Each id is considered once, in input order
+fn partition_status_targets(
+    account_ids: &[Uuid],
+    current: &HashMap<Uuid, AccountStatus>,
+    status: AccountStatus,
+) -> (Vec<Uuid>, Vec<(Uuid, BankingResult<()>)>) {
+    let mut seen = HashSet::new();
+    let mut valid = Vec::new();
+    let mut rejected = Vec::new();
+    for id in account_ids.iter().filter(|id| seen.insert(**id)) {
+        match current.get(id) {
+            None => rejected.push((*id, Err(BankingError::AccountNotFound(*id)))),
+            Some(from) if !from.can_transition_to(status) => rejected.push((
+                *id,
+                Err(BankingError::ValidationError {
+                    field: "status_transition".to_string(),
+                    message: format!("Invalid status transition from {from} to {status}"),
+                }),
+            )),
+            Some(_) => valid.push(*id),
+        }
+    }
+    (valid, rejected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partition_status_targets_rejects_closed_and_unknown_accounts() {
+        let (active, dormant, closed, unknown) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let current = HashMap::from([
+            (active, AccountStatus::Active),
+            (dormant, AccountStatus::Dormant),
+            (closed, AccountStatus::Closed),
+        ]);
+
+        let (valid, rejected) =
+            partition_status_targets(&[active, closed, unknown, dormant, active], &current, AccountStatus::Frozen);
+
+        // Dormant accounts must be reactivated before they can be frozen
+        assert_eq!(valid, vec![active]);
+        assert_eq!(rejected.len(), 3);
+        assert!(matches!(
+            &rejected[0],
+            (id, Err(BankingError::ValidationError { field, .. })) if *id == closed && field == "status_transition"
+        ));
+        assert!(matches!(&rejected[1], (id, Err(BankingError::AccountNotFound(missing)))
+            if *id == unknown && *missing == unknown));
+        assert!(matches!(&rejected[2], (id, Err(BankingError::ValidationError { .. })) if *id == dormant));
+
+        let (valid, rejected) = partition_status_targets(&[active, dormant, closed], &current, AccountStatus::Closed);
+        assert_eq!(valid, vec![active, dormant]);
+        assert_eq!(rejected.len(), 1);
+        assert_eq!(rejected[0].0, closed);
+    }
+}
@@ -0,0 +1,542 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        AgentCollectionTotals, AgentCommissionLineItem, AgentCommissionRun, AgentCommissionScheme,
+        AgentCommissionStatement, AgentCommissionStatementDetail, CommissionLineType, CommissionPeriod,
+        CommissionStatementStatus,
+    },
+    service::AgentCommissionService,
+};
+use banking_db::repository::AgentCommissionRepository;
+use crate::mappers::AgentCommissionMapper;
+
+/// Production implementation of AgentCommissionService
+pub struct AgentCommissionServiceImpl {
+    agent_commission_repository: Arc<dyn AgentCommissionRepository>,
+}
+
+impl AgentCommissionServiceImpl {
+    pub fn new(agent_commission_repository: Arc<dyn AgentCommissionRepository>) -> Self {
+        Self { agent_commission_repository }
+    }
+
+    /// Reject a scheme whose program or territory already has another active scheme,
+    /// so that every collection resolves to a single scheme
+    async fn check_scheme(&self, scheme: &AgentCommissionScheme) -> BankingResult<()> {
+        scheme.validate().map_err(|message| BankingError::ValidationError {
+            field: "scheme".to_string(),
+            message,
+        })?;
+        if !scheme.is_active {
+            return Ok(());
+        }
+
+        let overlapping = self.agent_commission_repository
+            .find_active_schemes()
+            .await?
+            .into_iter()
+            .find(|other| {
+                other.id != scheme.id
+                    && other.collection_program_id == scheme.collection_program_id
+                    && other.territory_id == scheme.territory_id
+            });
+        if let Some(other) = overlapping {
+            return Err(BankingError::ValidationError {
+                field: "scheme".to_string(),
+                message: format!("Active scheme {} already covers this program or territory", other.code),
+            });
+        }
+        Ok(())
+    }
+
+    /// Refuse to overwrite a calculated period unless forced, and an invoiced one at all
+    async fn check_recalculation(&self, period_start: NaiveDate, force: bool) -> BankingResult<usize> {
+        let existing = self.agent_commission_repository
+            .find_statements_for_period(period_start)
+            .await?
+            .into_iter()
+            .map(AgentCommissionMapper::statement_from_model)
+            .collect::<Vec<_>>();
+
+        if existing.iter().any(|s| s.status == CommissionStatementStatus::Invoiced) {
+            return Err(BankingError::ValidationError {
+                field: "period".to_string(),
+                message: format!("Commissions for the period starting {period_start} are invoiced and cannot be recalculated"),
+            });
+        }
+        if !existing.is_empty() && !force {
+            return Err(BankingError::ValidationError {
+                field: "force".to_string(),
+                message: format!("Commissions for the period starting {period_start} are already calculated"),
+            });
+        }
+        Ok(existing.len())
+    }
+}
+
+/// A program scheme takes precedence over the scheme of the agent's territory
+fn resolve_scheme<'a>(schemes: &'a [AgentCommissionScheme], totals: &AgentCollectionTotals) -> Option<&'a AgentCommissionScheme> {
+    schemes
+        .iter()
+        .find(|s| s.collection_program_id == Some(totals.collection_program_id))
+        .or_else(|| schemes.iter().find(|s| s.territory_id == Some(totals.territory_id)))
+}
+
+fn line_item(
+    statement_id: Uuid,
+    line_type: CommissionLineType,
+    scheme: &AgentCommissionScheme,
+    collection_program_id: Option<Uuid>,
+    description: String,
+    basis_amount: Decimal,
+    amount: Decimal,
+) -> AgentCommissionLineItem {
+    AgentCommissionLineItem {
+        id: Uuid::new_v4(),
+        statement_id,
+        line_type,
+        scheme_id: scheme.id,
+        collection_program_id,
+        description: HeaplessString::try_from(description.as_str()).unwrap_or_default(),
+        basis_amount,
+        amount,
+    }
+}
+
+#[async_trait]
+impl AgentCommissionService for AgentCommissionServiceImpl {
+    async fn create_scheme(&self, scheme: AgentCommissionScheme) -> BankingResult<AgentCommissionScheme> {
+        self.check_scheme(&scheme).await?;
+        let created = self.agent_commission_repository
+            .create_scheme(AgentCommissionMapper::scheme_to_model(scheme))
+            .await?;
+        Ok(AgentCommissionMapper::scheme_from_model(created))
+    }
+
+    async fn update_scheme(&self, mut scheme: AgentCommissionScheme) -> BankingResult<AgentCommissionScheme> {
+        if self.agent_commission_repository.find_scheme_by_id(scheme.id).await?.is_none() {
+            return Err(BankingError::NotFound(format!("Commission scheme {} not found", scheme.id)));
+        }
+        self.check_scheme(&scheme).await?;
+
+        scheme.last_updated_at = Utc::now();
+        let updated = self.agent_commission_repository
+            .update_scheme(AgentCommissionMapper::scheme_to_model(scheme))
+            .await?;
+        Ok(AgentCommissionMapper::scheme_from_model(updated))
+    }
+
+    async fn find_scheme_by_id(&self, scheme_id: Uuid) -> BankingResult<Option<AgentCommissionScheme>> {
+        let scheme = self.agent_commission_repository.find_scheme_by_id(scheme_id).await?;
+        Ok(scheme.map(AgentCommissionMapper::scheme_from_model))
+    }
+
+    async fn find_active_schemes(&self) -> BankingResult<Vec<AgentCommissionScheme>> {
+        let schemes = self.agent_commission_repository.find_active_schemes().await?;
+        Ok(schemes.into_iter().map(AgentCommissionMapper::scheme_from_model).collect())
+    }
+
+    async fn calculate_agent_commissions(&self, period: CommissionPeriod, force: bool) -> BankingResult<AgentCommissionRun> {
+        let (period_start, period_end) = period.bounds().map_err(|message| BankingError::ValidationError {
+            field: "period".to_string(),
+            message,
+        })?;
+        let replaced_statements = self.check_recalculation(period_start, force).await?;
+
+        let schemes = self.find_active_schemes().await?;
+        let discrepancies = self.agent_commission_repository
+            .count_cash_discrepancy_alerts(period_start, period_end)
+            .await?;
+        let mut totals_by_agent: BTreeMap<Uuid, Vec<AgentCollectionTotals>> = BTreeMap::new();
+        for totals in self.agent_commission_repository.aggregate_agent_collections(period_start, period_end).await? {
+            let totals = AgentCommissionMapper::collection_totals_from_model(totals);
+            totals_by_agent.entry(totals.collection_agent_id).or_default().push(totals);
+        }
+
+        let now = Utc::now();
+        let mut run = AgentCommissionRun {
+            period_start,
+            period_end,
+            statements: Vec::new(),
+            replaced_statements,
+            agents_without_scheme: Vec::new(),
+        };
+        let mut line_items = Vec::new();
+
+        for (agent_id, agent_totals) in totals_by_agent {
+            let statement_id = Uuid::new_v4();
+            let mut lines = Vec::new();
+            let mut applied: Vec<&AgentCommissionScheme> = Vec::new();
+            let mut collected_amount = Decimal::ZERO;
+
+            for totals in &agent_totals {
+                let Some(scheme) = resolve_scheme(&schemes, totals) else {
+                    if !run.agents_without_scheme.contains(&agent_id) {
+                        run.agents_without_scheme.push(agent_id);
+                    }
+                    continue;
+                };
+                lines.push(line_item(
+                    statement_id,
+                    CommissionLineType::Commission,
+                    scheme,
+                    Some(totals.collection_program_id),
+                    format!(
+                        "{}% of {} processed collections",
+                        (scheme.commission_rate * Decimal::ONE_HUNDRED).normalize(),
+                        totals.processed_count
+                    ),
+                    totals.processed_amount,
+                    scheme.commission_on(totals.processed_amount),
+                ));
+                let collection_rate = totals.collection_rate();
+                if scheme.earns_bonus(collection_rate) {
+                    lines.push(line_item(
+                        statement_id,
+                        CommissionLineType::PerformanceBonus,
+                        scheme,
+                        Some(totals.collection_program_id),
+                        format!("Collection rate {} above target", collection_rate.round_dp(4)),
+                        totals.processed_amount,
+                        scheme.bonus_amount,
+                    ));
+                }
+                applied.push(scheme);
+                collected_amount += totals.processed_amount;
+            }
+            if lines.is_empty() {
+                continue;
+            }
+
+            // Discrepancies are raised on the agent, not a program: the strictest applied scheme counts
+            let discrepancy_count = discrepancies.get(&agent_id).copied().unwrap_or(0).max(0) as u32;
+            if discrepancy_count > 0 {
+                if let Some(scheme) = applied.iter().max_by_key(|s| s.deduction_per_discrepancy) {
+                    let deduction = scheme.deduction_for(discrepancy_count);
+                    if deduction > Decimal::ZERO {
+                        lines.push(line_item(
+                            statement_id,
+                            CommissionLineType::DiscrepancyDeduction,
+                            scheme,
+                            None,
+                            format!("{discrepancy_count} cash discrepancy alerts"),
+                            scheme.deduction_per_discrepancy,
+                            deduction,
+                        ));
+                    }
+                }
+            }
+
+            let sum = |line_type: CommissionLineType| -> Decimal {
+                lines.iter().filter(|l| l.line_type == line_type).map(|l| l.amount).sum()
+            };
+            let commission_amount = sum(CommissionLineType::Commission);
+            let bonus_amount = sum(CommissionLineType::PerformanceBonus);
+            let deduction_amount = sum(CommissionLineType::DiscrepancyDeduction);
+            run.statements.push(AgentCommissionStatement {
+                id: statement_id,
+                collection_agent_id: agent_id,
+                period_start,
+                period_end,
+                collected_amount,
+                commission_amount,
+                bonus_amount,
+                deduction_amount,
+                net_amount: commission_amount + bonus_amount - deduction_amount,
+                discrepancy_count,
+                status: CommissionStatementStatus::Draft,
+                calculated_at: now,
+                invoiced_at: None,
+            });
+            line_items.extend(lines);
+        }
+
+        self.agent_commission_repository
+            .replace_period_statements(
+                period_start,
+                run.statements.iter().cloned().map(AgentCommissionMapper::statement_to_model).collect(),
+                line_items.into_iter().map(AgentCommissionMapper::line_item_to_model).collect(),
+            )
+            .await?;
+        tracing::info!(
+            "Calculated {} agent commission statements for {period_start}, replacing {replaced_statements}",
+            run.statements.len()
+        );
+        Ok(run)
+    }
+
+    async fn find_agent_commission_statements(&self, collection_agent_id: Uuid, limit: i64) -> BankingResult<Vec<AgentCommissionStatementDetail>> {
+        if limit <= 0 {
+            return Err(BankingError::ValidationError {
+                field: "limit".to_string(),
+                message: "Limit must be positive".to_string(),
+            });
+        }
+
+        let statements = self.agent_commission_repository
+            .find_statements_by_agent(collection_agent_id, limit)
+            .await?;
+        let mut details = Vec::with_capacity(statements.len());
+        for statement in statements {
+            let line_items = self.agent_commission_repository
+                .find_line_items(statement.id)
+                .await?
+                .into_iter()
+                .map(AgentCommissionMapper::line_item_from_model)
+                .collect();
+            details.push(AgentCommissionStatementDetail {
+                statement: AgentCommissionMapper::statement_from_model(statement),
+                line_items,
+            });
+        }
+        Ok(details)
+    }
+
+    async fn mark_statement_invoiced(&self, statement_id: Uuid) -> BankingResult<AgentCommissionStatement> {
+        let statement = self.agent_commission_repository
+            .find_statement_by_id(statement_id)
+            .await?
+            .map(AgentCommissionMapper::statement_from_model)
+            .ok_or_else(|| BankingError::NotFound(format!("Commission statement {statement_id} not found")))?;
+        if statement.status == CommissionStatementStatus::Invoiced {
+            return Err(BankingError::ValidationError {
+                field: "statement_id".to_string(),
+                message: format!("Commission statement {statement_id} is already invoiced"),
+            });
+        }
+
+        let updated = self.agent_commission_repository
+            .update_statement_status(
+                statement_id,
+                AgentCommissionMapper::statement_status_to_db(CommissionStatementStatus::Invoiced),
+                Some(Utc::now()),
+            )
+            .await?;
+        Ok(AgentCommissionMapper::statement_from_model(updated))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use banking_db::models::{
+        AgentCollectionTotalsModel, AgentCommissionLineItemModel, AgentCommissionSchemeModel,
+        AgentCommissionStatementModel, DbCommissionStatementStatus,
+    };
+    use chrono::DateTime;
+
+    #[derive(Default)]
+    struct MockAgentCommissionRepository {
+        schemes: Mutex<Vec<AgentCommissionSchemeModel>>,
+        totals: Mutex<Vec<AgentCollectionTotalsModel>>,
+        discrepancies: Mutex<HashMap<Uuid, i64>>,
+        statements: Mutex<Vec<AgentCommissionStatementModel>>,
+        line_items: Mutex<Vec<AgentCommissionLineItemModel>>,
+    }
+
+    #[async_trait]
+    impl AgentCommissionRepository for MockAgentCommissionRepository {
+        async fn create_scheme(&self, scheme: AgentCommissionSchemeModel) -> BankingResult<AgentCommissionSchemeModel> {
+            self.schemes.lock().unwrap().push(scheme.clone());
+            Ok(scheme)
+        }
+
+        async fn update_scheme(&self, _scheme: AgentCommissionSchemeModel) -> BankingResult<AgentCommissionSchemeModel> {
+            unimplemented!()
+        }
+
+        async fn find_scheme_by_id(&self, scheme_id: Uuid) -> BankingResult<Option<AgentCommissionSchemeModel>> {
+            Ok(self.schemes.lock().unwrap().iter().find(|s| s.id == scheme_id).cloned())
+        }
+
+        async fn find_active_schemes(&self) -> BankingResult<Vec<AgentCommissionSchemeModel>> {
+            Ok(self.schemes.lock().unwrap().iter().filter(|s| s.is_active).cloned().collect())
+        }
+
+        async fn aggregate_agent_collections(&self, _from: NaiveDate, _to: NaiveDate) -> BankingResult<Vec<AgentCollectionTotalsModel>> {
+            Ok(self.totals.lock().unwrap().clone())
+        }
+
+        async fn count_cash_discrepancy_alerts(&self, _from: NaiveDate, _to: NaiveDate) -> BankingResult<HashMap<Uuid, i64>> {
+            Ok(self.discrepancies.lock().unwrap().clone())
+        }
+
+        async fn find_statements_for_period(&self, period_start: NaiveDate) -> BankingResult<Vec<AgentCommissionStatementModel>> {
+            Ok(self.statements.lock().unwrap().iter().filter(|s| s.period_start == period_start).cloned().collect())
+        }
+
+        async fn replace_period_statements(
+            &self,
+            period_start: NaiveDate,
+            statements: Vec<AgentCommissionStatementModel>,
+            line_items: Vec<AgentCommissionLineItemModel>,
+        ) -> BankingResult<()> {
+            let mut stored = self.statements.lock().unwrap();
+            let mut stored_line_items = self.line_items.lock().unwrap();
+            let replaced: Vec<Uuid> = stored
+                .iter()
+                .filter(|s| s.period_start == period_start && s.status == DbCommissionStatementStatus::Draft)
+                .map(|s| s.id)
+                .collect();
+            stored.retain(|s| !replaced.contains(&s.id));
+            stored_line_items.retain(|l| !replaced.contains(&l.statement_id));
+            stored.extend(statements);
+            stored_line_items.extend(line_items);
+            Ok(())
+        }
+
+        async fn find_statement_by_id(&self, statement_id: Uuid) -> BankingResult<Option<AgentCommissionStatementModel>> {
+            Ok(self.statements.lock().unwrap().iter().find(|s| s.id == statement_id).cloned())
+        }
+
+        async fn find_statements_by_agent(&self, collection_agent_id: Uuid, limit: i64) -> BankingResult<Vec<AgentCommissionStatementModel>> {
+            let mut statements: Vec<AgentCommissionStatementModel> = self.statements.lock().unwrap()
+                .iter()
+                .filter(|s| s.collection_agent_id == collection_agent_id)
+                .cloned()
+                .collect();
+            statements.sort_by_key(|s| std::cmp::Reverse(s.period_start));
+            statements.truncate(limit as usize);
+            Ok(statements)
+        }
+
+        async fn find_line_items(&self, statement_id: Uuid) -> BankingResult<Vec<AgentCommissionLineItemModel>> {
+            Ok(self.line_items.lock().unwrap().iter().filter(|l| l.statement_id == statement_id).cloned().collect())
+        }
+
+        async fn update_statement_status(
+            &self,
+            statement_id: Uuid,
+            status: DbCommissionStatementStatus,
+            invoiced_at: Option<DateTime<Utc>>,
+        ) -> BankingResult<AgentCommissionStatementModel> {
+            let mut statements = self.statements.lock().unwrap();
+            let statement = statements.iter_mut().find(|s| s.id == statement_id).unwrap();
+            statement.status = status;
+            statement.invoiced_at = invoiced_at;
+            Ok(statement.clone())
+        }
+    }
+
+    const PERIOD: CommissionPeriod = CommissionPeriod { year: 2024, month: 5 };
+
+    fn program_scheme(collection_program_id: Uuid) -> AgentCommissionScheme {
+        AgentCommissionScheme {
+            id: Uuid::new_v4(),
+            code: HeaplessString::try_from("DAILY_SAVINGS").unwrap(),
+            collection_program_id: Some(collection_program_id),
+            territory_id: None,
+            commission_rate: Decimal::new(2, 2),
+            target_collection_rate: Some(Decimal::new(9, 1)),
+            bonus_amount: Decimal::from(5000),
+            deduction_per_discrepancy: Decimal::from(1000),
+            is_active: true,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    fn totals(collection_agent_id: Uuid, collection_program_id: Uuid, processed: i64, attempted: i64) -> AgentCollectionTotalsModel {
+        AgentCollectionTotalsModel {
+            collection_agent_id,
+            territory_id: Uuid::new_v4(),
+            collection_program_id,
+            processed_count: processed,
+            processed_amount: Decimal::from(processed * 10000),
+            attempted_count: attempted,
+        }
+    }
+
+    async fn setup(collection_program_id: Uuid) -> (Arc<MockAgentCommissionRepository>, AgentCommissionServiceImpl) {
+        let repository = Arc::new(MockAgentCommissionRepository::default());
+        let service = AgentCommissionServiceImpl::new(repository.clone());
+        service.create_scheme(program_scheme(collection_program_id)).await.unwrap();
+        (repository, service)
+    }
+
+    #[tokio::test]
+    async fn test_bonus_only_above_target_collection_rate() {
+        let program_id = Uuid::new_v4();
+        let (repository, service) = setup(program_id).await;
+        let above_target = Uuid::new_v4();
+        let at_target = Uuid::new_v4();
+        let uncovered = Uuid::new_v4();
+        repository.totals.lock().unwrap().extend([
+            totals(above_target, program_id, 19, 20),
+            totals(at_target, program_id, 18, 20),
+            totals(uncovered, Uuid::new_v4(), 20, 20),
+        ]);
+
+        let run = service.calculate_agent_commissions(PERIOD, false).await.unwrap();
+        assert_eq!(run.statements.len(), 2);
+        assert_eq!(run.agents_without_scheme, vec![uncovered]);
+
+        let statement = |agent_id: Uuid| run.statements.iter().find(|s| s.collection_agent_id == agent_id).unwrap();
+        // 2% of 190,000 collected plus the bonus
+        assert_eq!(statement(above_target).commission_amount, Decimal::from(3800));
+        assert_eq!(statement(above_target).bonus_amount, Decimal::from(5000));
+        assert_eq!(statement(above_target).net_amount, Decimal::from(8800));
+        // A 90% collection rate only meets the target
+        assert_eq!(statement(at_target).bonus_amount, Decimal::ZERO);
+        assert_eq!(statement(at_target).net_amount, Decimal::from(3600));
+    }
+
+    #[tokio::test]
+    async fn test_cash_discrepancies_are_deducted() {
+        let program_id = Uuid::new_v4();
+        let (repository, service) = setup(program_id).await;
+        let agent_id = Uuid::new_v4();
+        repository.totals.lock().unwrap().push(totals(agent_id, program_id, 19, 20));
+        repository.discrepancies.lock().unwrap().insert(agent_id, 2);
+
+        let run = service.calculate_agent_commissions(PERIOD, false).await.unwrap();
+        let statement = &run.statements[0];
+        assert_eq!(statement.discrepancy_count, 2);
+        assert_eq!(statement.deduction_amount, Decimal::from(2000));
+        assert_eq!(statement.net_amount, Decimal::from(6800));
+
+        let details = service.find_agent_commission_statements(agent_id, 12).await.unwrap();
+        assert_eq!(details.len(), 1);
+        let line_types: Vec<CommissionLineType> = details[0].line_items.iter().map(|l| l.line_type).collect();
+        assert_eq!(line_types, vec![
+            CommissionLineType::Commission,
+            CommissionLineType::PerformanceBonus,
+            CommissionLineType::DiscrepancyDeduction,
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_recalculation_requires_force_and_an_uninvoiced_period() {
+        let program_id = Uuid::new_v4();
+        let (repository, service) = setup(program_id).await;
+        let agent_id = Uuid::new_v4();
+        repository.totals.lock().unwrap().push(totals(agent_id, program_id, 10, 20));
+
+        let first = service.calculate_agent_commissions(PERIOD, false).await.unwrap();
+        assert!(service.calculate_agent_commissions(PERIOD, false).await.is_err());
+
+        // A late collection is picked up by a forced recalculation, which replaces the draft
+        repository.totals.lock().unwrap()[0] = totals(agent_id, program_id, 11, 20);
+        let recalculated = service.calculate_agent_commissions(PERIOD, true).await.unwrap();
+        assert_eq!(recalculated.replaced_statements, 1);
+        assert_eq!(repository.statements.lock().unwrap().len(), 1);
+        assert_eq!(repository.line_items.lock().unwrap().len(), 1);
+        assert!(recalculated.statements[0].commission_amount > first.statements[0].commission_amount);
+
+        service.mark_statement_invoiced(recalculated.statements[0].id).await.unwrap();
+        let result = service.calculate_agent_commissions(PERIOD, true).await;
+        assert!(matches!(result, Err(BankingError::ValidationError { .. })));
+        assert_eq!(repository.statements.lock().unwrap()[0].status, DbCommissionStatementStatus::Invoiced);
+    }
+}
@@ -1,25 +1,34 @@
 use crate::mappers::audit::audit_log_mapper;
 use async_trait::async_trait;
 use banking_api::{
-    domain::audit::AuditLog,
+    domain::audit::{AuditChainSegment, AuditEntityType, AuditLog, AuditLogEntry},
     service::audit::audit_log_service::{AuditLogService, AuditLogServiceError, AuditLogServiceResult},
 };
 use banking_db::{
-    models::audit::AuditLogModel,
+    models::audit::{AuditChainVerification, AuditLogModel, BrokenAuditLink},
     repository::audit_repository::{AuditLogRepository, AuditLogRepositoryError},
+    repository::{PersonRepository, PersonRepositoryError},
 };
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
 use sqlx::Postgres;
+use std::collections::HashMap;
 use std::sync::Arc;
 use uuid::Uuid;
 
 pub struct AuditLogServiceImpl {
     audit_log_repository: Arc<dyn AuditLogRepository<Postgres>>,
+    person_repository: Arc<dyn PersonRepository<Postgres>>,
 }
 
 impl AuditLogServiceImpl {
-    pub fn new(audit_log_repository: Arc<dyn AuditLogRepository<Postgres>>) -> Self {
+    pub fn new(
+        audit_log_repository: Arc<dyn AuditLogRepository<Postgres>>,
+        person_repository: Arc<dyn PersonRepository<Postgres>>,
+    ) -> Self {
         Self {
             audit_log_repository,
+            person_repository,
         }
     }
 
@@ -28,8 +37,50 @@ impl AuditLogServiceImpl {
             AuditLogRepositoryError::RepositoryError(err) => {
                 AuditLogServiceError::RepositoryError(err.to_string())
             }
+            AuditLogRepositoryError::NotFound(id) => AuditLogServiceError::NotFound(id),
+            err @ AuditLogRepositoryError::Immutable(_) => {
+                AuditLogServiceError::RepositoryError(err.to_string())
+            }
+        }
+    }
+
+    fn broken_chain(link: BrokenAuditLink) -> AuditLogServiceError {
+        AuditLogServiceError::BrokenChain {
+            audit_log_id: link.audit_log_id,
+            sequence_number: link.sequence_number,
+            reason: format!("{:?}", link.reason),
         }
     }
+
+    /// Attach each actor's display name, loading every distinct actor once.
+    /// Deactivated actors keep their name; ids unknown to the person registry get none.
+    async fn with_actor_names(&self, audit_logs: Vec<AuditLogModel>) -> AuditLogServiceResult<Vec<AuditLogEntry>> {
+        let mut names: HashMap<Uuid, Option<HeaplessString<100>>> = HashMap::new();
+        for audit_log in &audit_logs {
+            let actor = audit_log.updated_by_person_id;
+            if names.contains_key(&actor) {
+                continue;
+            }
+            let name = match self.person_repository.load_including_deactivated(actor).await {
+                Ok(person) => Some(person.display_name),
+                Err(PersonRepositoryError::RepositoryError(err))
+                    if matches!(err.downcast_ref::<sqlx::Error>(), Some(sqlx::Error::RowNotFound)) =>
+                {
+                    None
+                }
+                Err(err) => return Err(AuditLogServiceError::RepositoryError(err.to_string())),
+            };
+            names.insert(actor, name);
+        }
+
+        Ok(audit_logs
+            .iter()
+            .map(|audit_log| AuditLogEntry {
+                audit_log: audit_log_mapper::map_to_domain(audit_log),
+                actor_display_name: names.get(&audit_log.updated_by_person_id).cloned().flatten(),
+            })
+            .collect())
+    }
 }
 
 #[async_trait]
@@ -38,11 +89,7 @@ impl AuditLogService for AuditLogServiceImpl {
         &self,
         updated_by_person_id: Uuid,
     ) -> AuditLogServiceResult<AuditLog> {
-        let audit_log = AuditLogModel {
-            id: Uuid::new_v4(),
-            updated_at: chrono::Utc::now(),
-            updated_by_person_id,
-        };
+        let audit_log = AuditLogModel::new(Uuid::new_v4(), chrono::Utc::now(), updated_by_person_id);
         let created_audit_log = self
             .audit_log_repository
             .create(&audit_log)
@@ -59,4 +106,70 @@ impl AuditLogService for AuditLogServiceImpl {
             .map_err(Self::map_domain_error)?;
         Ok(audit_log.map(|log| audit_log_mapper::map_to_domain(&log)))
     }
-}
\ No newline at end of file
+
+    async fn find_entity_history(
+        &self,
+        entity_type: AuditEntityType,
+        entity_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        page: i32,
+        page_size: i32,
+    ) -> AuditLogServiceResult<Vec<AuditLogEntry>> {
+        let audit_logs = self
+            .audit_log_repository
+            .find_by_entity(
+                audit_log_mapper::map_entity_type_to_model(entity_type),
+                entity_id,
+                from,
+                to,
+                page,
+                page_size,
+            )
+            .await
+            .map_err(Self::map_domain_error)?;
+        self.with_actor_names(audit_logs).await
+    }
+
+    async fn find_actor_history(
+        &self,
+        person_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        page: i32,
+        page_size: i32,
+    ) -> AuditLogServiceResult<Vec<AuditLogEntry>> {
+        let audit_logs = self
+            .audit_log_repository
+            .find_by_actor(person_id, from, to, page, page_size)
+            .await
+            .map_err(Self::map_domain_error)?;
+        self.with_actor_names(audit_logs).await
+    }
+
+    async fn export_verified_segment(&self, from_id: Uuid, to_id: Uuid) -> AuditLogServiceResult<AuditChainSegment> {
+        let verification = self
+            .audit_log_repository
+            .verify_chain(from_id, to_id)
+            .await
+            .map_err(Self::map_domain_error)?;
+        if let Some(link) = verification.first_broken_link {
+            return Err(Self::broken_chain(link));
+        }
+        let entries = self
+            .audit_log_repository
+            .find_chain_segment(from_id, to_id)
+            .await
+            .map_err(Self::map_domain_error)?;
+        // Re-check what is exported, in case an entry changed after the verification
+        let first_prev_hash = entries.first().map(|entry| entry.prev_hash.clone()).unwrap_or_default();
+        if let Some(link) = AuditChainVerification::walk(&first_prev_hash, &entries).first_broken_link {
+            return Err(Self::broken_chain(link));
+        }
+
+        Ok(AuditChainSegment {
+            entries: entries.iter().map(audit_log_mapper::map_to_chain_entry).collect(),
+            verified_at: Utc::now(),
+        })
+    }
+}
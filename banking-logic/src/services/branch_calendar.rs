@@ -0,0 +1,18 @@
+use uuid::Uuid;
+
+use banking_api::BankingResult;
+use banking_db::repository::AgentNetworkRepository;
+
+/// Holiday calendar of a branch. Branches that do not name a calendar of
+/// their own, and branches that cannot be found, use `fallback_calendar_id`.
+pub async fn branch_calendar_id(
+    agent_network_repository: &dyn AgentNetworkRepository,
+    agency_branch_id: Uuid,
+    fallback_calendar_id: &str,
+) -> BankingResult<String> {
+    let calendar_id = agent_network_repository
+        .find_branch_by_id(agency_branch_id)
+        .await?
+        .and_then(|branch| branch.calendar_id);
+    Ok(calendar_id.map_or_else(|| fallback_calendar_id.to_string(), |id| id.to_string()))
+}
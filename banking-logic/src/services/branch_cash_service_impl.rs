@@ -0,0 +1,530 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        BranchCashCeiling, BranchCashCeilingBreach, BranchCashCeilingReport, BranchCashPosition,
+        CashTransferAgingBucket, CashTransferAgingReport, CashTransferAgingTotal, CashTransferOrigin,
+        CashTransferRequest, CashTransferStatus, OpenCashTransfer, VaultAdjustment, VaultAdjustmentType,
+    },
+    service::BranchCashService,
+};
+use banking_db::repository::BranchCashRepository;
+use crate::mappers::BranchCashMapper;
+
+const AGING_BUCKETS: [CashTransferAgingBucket; 4] = [
+    CashTransferAgingBucket::UpToOneDay,
+    CashTransferAgingBucket::TwoToThreeDays,
+    CashTransferAgingBucket::FourToSevenDays,
+    CashTransferAgingBucket::OverSevenDays,
+];
+
+/// Production implementation of BranchCashService
+pub struct BranchCashServiceImpl {
+    branch_cash_repository: Arc<dyn BranchCashRepository>,
+}
+
+impl BranchCashServiceImpl {
+    pub fn new(branch_cash_repository: Arc<dyn BranchCashRepository>) -> Self {
+        Self { branch_cash_repository }
+    }
+
+    async fn load_transfer(&self, transfer_id: Uuid) -> BankingResult<CashTransferRequest> {
+        self.find_cash_transfer_by_id(transfer_id)
+            .await?
+            .ok_or_else(|| BankingError::NotFound(format!("Cash transfer {transfer_id} not found")))
+    }
+
+    async fn open_transfers(&self) -> BankingResult<Vec<CashTransferRequest>> {
+        let transfers = self.branch_cash_repository.find_open_transfers().await?;
+        Ok(transfers.into_iter().map(BranchCashMapper::transfer_from_model).collect())
+    }
+
+    /// Requested transfers from the branch; the cash is still in its vault
+    fn pending_amount(open_transfers: &[CashTransferRequest], agency_branch_id: Uuid) -> Decimal {
+        open_transfers
+            .iter()
+            .filter(|t| t.from_agency_branch_id == agency_branch_id && t.status == CashTransferStatus::Requested)
+            .map(|t| t.amount)
+            .sum()
+    }
+
+    async fn store_transition(
+        &self,
+        transfer: CashTransferRequest,
+        expected_last_updated_at: DateTime<Utc>,
+        adjustment: Option<VaultAdjustment>,
+    ) -> BankingResult<CashTransferRequest> {
+        let updated = self.branch_cash_repository
+            .update_transfer(
+                BranchCashMapper::transfer_to_model(transfer),
+                expected_last_updated_at,
+                adjustment.map(BranchCashMapper::adjustment_to_model),
+            )
+            .await?;
+        Ok(BranchCashMapper::transfer_from_model(updated))
+    }
+}
+
+fn adjustment(
+    transfer: &CashTransferRequest,
+    agency_branch_id: Option<Uuid>,
+    adjustment_type: VaultAdjustmentType,
+    amount: Decimal,
+    person_id: Uuid,
+    now: DateTime<Utc>,
+) -> VaultAdjustment {
+    VaultAdjustment {
+        id: Uuid::new_v4(),
+        agency_branch_id,
+        cash_transfer_request_id: transfer.id,
+        adjustment_type,
+        amount,
+        posted_at: now,
+        posted_by_person_id: person_id,
+    }
+}
+
+fn transition_error(message: String) -> BankingError {
+    BankingError::ValidationError {
+        field: "transfer_id".to_string(),
+        message,
+    }
+}
+
+#[async_trait]
+impl BranchCashService for BranchCashServiceImpl {
+    async fn set_cash_ceiling(&self, mut ceiling: BranchCashCeiling) -> BankingResult<BranchCashCeiling> {
+        ceiling.validate().map_err(|message| BankingError::ValidationError {
+            field: "ceiling".to_string(),
+            message,
+        })?;
+
+        ceiling.last_updated_at = Utc::now();
+        let stored = self.branch_cash_repository
+            .upsert_ceiling(BranchCashMapper::ceiling_to_model(ceiling))
+            .await?;
+        Ok(BranchCashMapper::ceiling_from_model(stored))
+    }
+
+    async fn find_cash_ceiling(&self, agency_branch_id: Uuid) -> BankingResult<Option<BranchCashCeiling>> {
+        let ceiling = self.branch_cash_repository.find_ceiling_by_branch(agency_branch_id).await?;
+        Ok(ceiling.map(BranchCashMapper::ceiling_from_model))
+    }
+
+    async fn get_branch_cash_position(&self, agency_branch_id: Uuid) -> BankingResult<BranchCashPosition> {
+        let position = self.branch_cash_repository.get_branch_cash_position(agency_branch_id).await?;
+        Ok(BranchCashMapper::position_from_model(position))
+    }
+
+    async fn check_cash_ceilings(&self, processing_date: NaiveDate) -> BankingResult<BranchCashCeilingReport> {
+        let ceilings: Vec<BranchCashCeiling> = self.branch_cash_repository
+            .find_active_ceilings()
+            .await?
+            .into_iter()
+            .map(BranchCashMapper::ceiling_from_model)
+            .collect();
+        let open_transfers = self.open_transfers().await?;
+
+        let mut report = BranchCashCeilingReport {
+            processing_date,
+            branches_checked: 0,
+            breaches: Vec::new(),
+            errors: Vec::new(),
+        };
+        for ceiling in ceilings {
+            report.branches_checked += 1;
+            let position = match self.get_branch_cash_position(ceiling.agency_branch_id).await {
+                Ok(position) => position.total(),
+                Err(e) => {
+                    report.errors.push(format!("Branch {}: {e}", ceiling.agency_branch_id));
+                    continue;
+                }
+            };
+            if position <= ceiling.ceiling_amount {
+                continue;
+            }
+
+            let pending_transfer_amount = Self::pending_amount(&open_transfers, ceiling.agency_branch_id);
+            let uncovered = position - ceiling.ceiling_amount - pending_transfer_amount;
+            let mut transfer_request_id = None;
+            if uncovered > Decimal::ZERO {
+                let transfer = CashTransferRequest::new(
+                    ceiling.agency_branch_id,
+                    ceiling.excess_destination_branch_id,
+                    uncovered,
+                    CashTransferOrigin::CeilingBreach,
+                    None,
+                    Utc::now(),
+                );
+                match self.branch_cash_repository.create_transfer(BranchCashMapper::transfer_to_model(transfer)).await {
+                    Ok(created) => transfer_request_id = Some(created.id),
+                    Err(e) => report.errors.push(format!("Branch {}: {e}", ceiling.agency_branch_id)),
+                }
+            }
+            tracing::warn!(
+                "Branch {} holds {position} above its cash ceiling of {}",
+                ceiling.agency_branch_id,
+                ceiling.ceiling_amount
+            );
+            report.breaches.push(BranchCashCeilingBreach {
+                agency_branch_id: ceiling.agency_branch_id,
+                position,
+                ceiling_amount: ceiling.ceiling_amount,
+                pending_transfer_amount,
+                transfer_request_id,
+            });
+        }
+
+        tracing::info!(
+            "Checked cash ceilings of {} branches for {processing_date}: {} breaches",
+            report.branches_checked,
+            report.breaches.len()
+        );
+        Ok(report)
+    }
+
+    async fn request_cash_transfer(
+        &self,
+        from_agency_branch_id: Uuid,
+        to_agency_branch_id: Option<Uuid>,
+        amount: Decimal,
+        requested_by_person_id: Uuid,
+    ) -> BankingResult<CashTransferRequest> {
+        if amount <= Decimal::ZERO {
+            return Err(BankingError::ValidationError {
+                field: "amount".to_string(),
+                message: "Transfer amount must be positive".to_string(),
+            });
+        }
+        if to_agency_branch_id == Some(from_agency_branch_id) {
+            return Err(BankingError::ValidationError {
+                field: "to_agency_branch_id".to_string(),
+                message: "Cash cannot be transferred to the sending branch".to_string(),
+            });
+        }
+
+        let position = self.get_branch_cash_position(from_agency_branch_id).await?.total();
+        let pending = Self::pending_amount(&self.open_transfers().await?, from_agency_branch_id);
+        if amount > position - pending {
+            return Err(BankingError::ValidationError {
+                field: "amount".to_string(),
+                message: format!("Branch holds {position} with {pending} already requested for transfer"),
+            });
+        }
+
+        let transfer = CashTransferRequest::new(
+            from_agency_branch_id,
+            to_agency_branch_id,
+            amount,
+            CashTransferOrigin::Manual,
+            Some(requested_by_person_id),
+            Utc::now(),
+        );
+        let created = self.branch_cash_repository
+            .create_transfer(BranchCashMapper::transfer_to_model(transfer))
+            .await?;
+        Ok(BranchCashMapper::transfer_from_model(created))
+    }
+
+    async fn authorize_dispatch(&self, transfer_id: Uuid, person_id: Uuid) -> BankingResult<CashTransferRequest> {
+        let mut transfer = self.load_transfer(transfer_id).await?;
+        let expected_last_updated_at = transfer.last_updated_at;
+        let now = Utc::now();
+
+        let dispatched = transfer.authorize_dispatch(person_id, now).map_err(transition_error)?;
+        let dispatch_adjustment = if dispatched {
+            // Cash counts may have moved since the request
+            let position = self.get_branch_cash_position(transfer.from_agency_branch_id).await?.total();
+            if transfer.amount > position {
+                return Err(BankingError::ValidationError {
+                    field: "amount".to_string(),
+                    message: format!("Branch holds {position}, less than the {} to dispatch", transfer.amount),
+                });
+            }
+            Some(adjustment(
+                &transfer,
+                Some(transfer.from_agency_branch_id),
+                VaultAdjustmentType::TransferDispatch,
+                -transfer.amount,
+                person_id,
+                now,
+            ))
+        } else {
+            None
+        };
+
+        self.store_transition(transfer, expected_last_updated_at, dispatch_adjustment).await
+    }
+
+    async fn confirm_receipt(&self, transfer_id: Uuid, person_id: Uuid) -> BankingResult<CashTransferRequest> {
+        let mut transfer = self.load_transfer(transfer_id).await?;
+        let expected_last_updated_at = transfer.last_updated_at;
+        let now = Utc::now();
+
+        let received = transfer.confirm_receipt(person_id, now).map_err(transition_error)?;
+        let receipt_adjustment = received.then(|| adjustment(
+            &transfer,
+            transfer.to_agency_branch_id,
+            VaultAdjustmentType::TransferReceipt,
+            transfer.amount,
+            person_id,
+            now,
+        ));
+
+        self.store_transition(transfer, expected_last_updated_at, receipt_adjustment).await
+    }
+
+    async fn cancel_cash_transfer(&self, transfer_id: Uuid, person_id: Uuid, reason: &str) -> BankingResult<CashTransferRequest> {
+        let reason = HeaplessString::try_from(reason).map_err(|_| BankingError::ValidationError {
+            field: "reason".to_string(),
+            message: "Cancellation reason too long".to_string(),
+        })?;
+        let mut transfer = self.load_transfer(transfer_id).await?;
+        let expected_last_updated_at = transfer.last_updated_at;
+
+        transfer.cancel(person_id, reason, Utc::now()).map_err(transition_error)?;
+        self.store_transition(transfer, expected_last_updated_at, None).await
+    }
+
+    async fn find_cash_transfer_by_id(&self, transfer_id: Uuid) -> BankingResult<Option<CashTransferRequest>> {
+        let transfer = self.branch_cash_repository.find_transfer_by_id(transfer_id).await?;
+        Ok(transfer.map(BranchCashMapper::transfer_from_model))
+    }
+
+    async fn find_vault_adjustments(&self, transfer_id: Uuid) -> BankingResult<Vec<VaultAdjustment>> {
+        let adjustments = self.branch_cash_repository.find_adjustments_by_transfer(transfer_id).await?;
+        Ok(adjustments.into_iter().map(BranchCashMapper::adjustment_from_model).collect())
+    }
+
+    async fn get_open_transfer_aging(&self, as_of: NaiveDate) -> BankingResult<CashTransferAgingReport> {
+        let transfers: Vec<OpenCashTransfer> = self.open_transfers()
+            .await?
+            .into_iter()
+            .map(|transfer| {
+                let age_days = transfer.age_days(as_of);
+                OpenCashTransfer {
+                    transfer,
+                    age_days,
+                    aging_bucket: CashTransferAgingBucket::for_age(age_days),
+                }
+            })
+            .collect();
+
+        let mut totals = Vec::new();
+        for status in [CashTransferStatus::Requested, CashTransferStatus::InTransit] {
+            for aging_bucket in AGING_BUCKETS {
+                let matching: Vec<&OpenCashTransfer> = transfers
+                    .iter()
+                    .filter(|t| t.transfer.status == status && t.aging_bucket == aging_bucket)
+                    .collect();
+                if !matching.is_empty() {
+                    totals.push(CashTransferAgingTotal {
+                        status,
+                        aging_bucket,
+                        transfer_count: matching.len(),
+                        amount: matching.iter().map(|t| t.transfer.amount).sum(),
+                    });
+                }
+            }
+        }
+
+        Ok(CashTransferAgingReport { as_of, transfers, totals })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use banking_db::models::{
+        BranchCashCeilingModel, BranchCashPositionModel, CashTransferRequestModel, DbCashTransferStatus,
+        VaultAdjustmentModel,
+    };
+
+    #[derive(Default)]
+    struct MockBranchCashRepository {
+        ceilings: Mutex<Vec<BranchCashCeilingModel>>,
+        terminal_cash: Mutex<HashMap<Uuid, Decimal>>,
+        transfers: Mutex<Vec<CashTransferRequestModel>>,
+        adjustments: Mutex<Vec<VaultAdjustmentModel>>,
+    }
+
+    #[async_trait]
+    impl BranchCashRepository for MockBranchCashRepository {
+        async fn upsert_ceiling(&self, ceiling: BranchCashCeilingModel) -> BankingResult<BranchCashCeilingModel> {
+            let mut ceilings = self.ceilings.lock().unwrap();
+            ceilings.retain(|c| c.agency_branch_id != ceiling.agency_branch_id);
+            ceilings.push(ceiling.clone());
+            Ok(ceiling)
+        }
+
+        async fn find_ceiling_by_branch(&self, agency_branch_id: Uuid) -> BankingResult<Option<BranchCashCeilingModel>> {
+            Ok(self.ceilings.lock().unwrap().iter().find(|c| c.agency_branch_id == agency_branch_id).cloned())
+        }
+
+        async fn find_active_ceilings(&self) -> BankingResult<Vec<BranchCashCeilingModel>> {
+            Ok(self.ceilings.lock().unwrap().iter().filter(|c| c.is_active).cloned().collect())
+        }
+
+        async fn get_branch_cash_position(&self, agency_branch_id: Uuid) -> BankingResult<BranchCashPositionModel> {
+            Ok(BranchCashPositionModel {
+                agency_branch_id,
+                terminal_cash: self.terminal_cash.lock().unwrap().get(&agency_branch_id).copied().unwrap_or_default(),
+                vault_adjustments: self.adjustments.lock().unwrap()
+                    .iter()
+                    .filter(|a| a.agency_branch_id == Some(agency_branch_id))
+                    .map(|a| a.amount)
+                    .sum(),
+            })
+        }
+
+        async fn create_transfer(&self, transfer: CashTransferRequestModel) -> BankingResult<CashTransferRequestModel> {
+            self.transfers.lock().unwrap().push(transfer.clone());
+            Ok(transfer)
+        }
+
+        async fn find_transfer_by_id(&self, transfer_id: Uuid) -> BankingResult<Option<CashTransferRequestModel>> {
+            Ok(self.transfers.lock().unwrap().iter().find(|t| t.id == transfer_id).cloned())
+        }
+
+        async fn find_open_transfers(&self) -> BankingResult<Vec<CashTransferRequestModel>> {
+            Ok(self.transfers.lock().unwrap()
+                .iter()
+                .filter(|t| matches!(t.status, DbCashTransferStatus::Requested | DbCashTransferStatus::InTransit))
+                .cloned()
+                .collect())
+        }
+
+        async fn update_transfer(
+            &self,
+            transfer: CashTransferRequestModel,
+            expected_last_updated_at: DateTime<Utc>,
+            adjustment: Option<VaultAdjustmentModel>,
+        ) -> BankingResult<CashTransferRequestModel> {
+            let mut transfers = self.transfers.lock().unwrap();
+            let stored = transfers.iter_mut().find(|t| t.id == transfer.id).unwrap();
+            if stored.last_updated_at != expected_last_updated_at {
+                return Err(BankingError::ValidationError {
+                    field: "transfer_id".to_string(),
+                    message: "Cash transfer was modified concurrently".to_string(),
+                });
+            }
+            *stored = transfer.clone();
+            self.adjustments.lock().unwrap().extend(adjustment);
+            Ok(transfer)
+        }
+
+        async fn find_adjustments_by_transfer(&self, transfer_id: Uuid) -> BankingResult<Vec<VaultAdjustmentModel>> {
+            Ok(self.adjustments.lock().unwrap().iter().filter(|a| a.cash_transfer_request_id == transfer_id).cloned().collect())
+        }
+    }
+
+    fn ceiling(agency_branch_id: Uuid, ceiling_amount: i64) -> BranchCashCeiling {
+        BranchCashCeiling {
+            id: Uuid::new_v4(),
+            agency_branch_id,
+            ceiling_amount: Decimal::from(ceiling_amount),
+            excess_destination_branch_id: None,
+            is_active: true,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    fn setup() -> (Arc<MockBranchCashRepository>, BranchCashServiceImpl) {
+        let repository = Arc::new(MockBranchCashRepository::default());
+        let service = BranchCashServiceImpl::new(repository.clone());
+        (repository, service)
+    }
+
+    #[tokio::test]
+    async fn test_ceiling_breach_requests_transfer_of_uncovered_excess() {
+        let (repository, service) = setup();
+        let over = Uuid::new_v4();
+        let under = Uuid::new_v4();
+        service.set_cash_ceiling(ceiling(over, 5_000_000)).await.unwrap();
+        service.set_cash_ceiling(ceiling(under, 5_000_000)).await.unwrap();
+        repository.terminal_cash.lock().unwrap().extend([
+            (over, Decimal::from(7_000_000)),
+            (under, Decimal::from(5_000_000)),
+        ]);
+        let processing_date = NaiveDate::from_ymd_opt(2024, 6, 28).unwrap();
+
+        let report = service.check_cash_ceilings(processing_date).await.unwrap();
+        assert_eq!(report.branches_checked, 2);
+        assert_eq!(report.breaches.len(), 1);
+        let breach = &report.breaches[0];
+        assert_eq!(breach.agency_branch_id, over);
+        assert_eq!(breach.excess(), Decimal::from(2_000_000));
+        let transfer = service.find_cash_transfer_by_id(breach.transfer_request_id.unwrap()).await.unwrap().unwrap();
+        assert_eq!(transfer.amount, Decimal::from(2_000_000));
+        assert_eq!(transfer.to_agency_branch_id, None);
+        assert_eq!(transfer.origin, CashTransferOrigin::CeilingBreach);
+
+        // The pending request covers the excess until more cash comes in
+        let again = service.check_cash_ceilings(processing_date).await.unwrap();
+        assert_eq!(again.breaches[0].pending_transfer_amount, Decimal::from(2_000_000));
+        assert!(again.breaches[0].transfer_request_id.is_none());
+
+        repository.terminal_cash.lock().unwrap().insert(over, Decimal::from(7_500_000));
+        let topped_up = service.check_cash_ceilings(processing_date).await.unwrap();
+        let top_up_id = topped_up.breaches[0].transfer_request_id.unwrap();
+        let top_up = service.find_cash_transfer_by_id(top_up_id).await.unwrap().unwrap();
+        assert_eq!(top_up.amount, Decimal::from(500_000));
+    }
+
+    #[tokio::test]
+    async fn test_transfer_dispatch_and_receipt_each_need_two_persons() {
+        let (repository, service) = setup();
+        let sender = Uuid::new_v4();
+        let receiver = Uuid::new_v4();
+        repository.terminal_cash.lock().unwrap().extend([
+            (sender, Decimal::from(3_000_000)),
+            (receiver, Decimal::from(1_000_000)),
+        ]);
+
+        assert!(service.request_cash_transfer(sender, Some(receiver), Decimal::from(4_000_000), Uuid::new_v4()).await.is_err());
+        let transfer = service
+            .request_cash_transfer(sender, Some(receiver), Decimal::from(2_000_000), Uuid::new_v4())
+            .await
+            .unwrap();
+
+        let (teller, manager) = (Uuid::new_v4(), Uuid::new_v4());
+        let first = service.authorize_dispatch(transfer.id, teller).await.unwrap();
+        assert_eq!(first.status, CashTransferStatus::Requested);
+        assert!(service.authorize_dispatch(transfer.id, teller).await.is_err());
+        assert!(service.find_vault_adjustments(transfer.id).await.unwrap().is_empty());
+
+        let dispatched = service.authorize_dispatch(transfer.id, manager).await.unwrap();
+        assert_eq!(dispatched.status, CashTransferStatus::InTransit);
+        // In transit, the cash counts against neither branch
+        assert_eq!(service.get_branch_cash_position(sender).await.unwrap().total(), Decimal::from(1_000_000));
+        assert_eq!(service.get_branch_cash_position(receiver).await.unwrap().total(), Decimal::from(1_000_000));
+        assert!(service.cancel_cash_transfer(transfer.id, manager, "No escort").await.is_err());
+
+        assert!(service.confirm_receipt(transfer.id, manager).await.is_err());
+        let vault_officer = Uuid::new_v4();
+        service.confirm_receipt(transfer.id, vault_officer).await.unwrap();
+        assert!(service.confirm_receipt(transfer.id, vault_officer).await.is_err());
+        let received = service.confirm_receipt(transfer.id, Uuid::new_v4()).await.unwrap();
+        assert_eq!(received.status, CashTransferStatus::Received);
+        assert_eq!(service.get_branch_cash_position(receiver).await.unwrap().total(), Decimal::from(3_000_000));
+
+        let adjustments = service.find_vault_adjustments(transfer.id).await.unwrap();
+        let amounts: Vec<(Option<Uuid>, Decimal)> = adjustments.iter().map(|a| (a.agency_branch_id, a.amount)).collect();
+        assert_eq!(amounts, vec![
+            (Some(sender), Decimal::from(-2_000_000)),
+            (Some(receiver), Decimal::from(2_000_000)),
+        ]);
+        let aging = service.get_open_transfer_aging(Utc::now().date_naive()).await.unwrap();
+        assert!(aging.transfers.is_empty());
+    }
+}
@@ -0,0 +1,827 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        AccountBundle, BundleAccount, BundleClosureOutcome, BundleComponent, BundleComponentRole, BundlePricingMarker,
+        BundleStatus, BundleView, CompensationAction, OrchestrationType, ProductBundle,
+    },
+    service::{AccountNumberService, BundleService, OrchestrationService},
+};
+use banking_db::models::{
+    AccountModel, AccountOwnershipModel, DbAccountStatus, DbBundleStatus, DbOwnershipType, DbSigningCondition,
+    ProductModel,
+};
+use banking_db::repository::{AccountRepository, BundleRepository, ProductRepository};
+use crate::mappers::{AccountMapper, BundleMapper};
+
+/// Production implementation of BundleService
+pub struct BundleServiceImpl {
+    bundle_repository: Arc<dyn BundleRepository>,
+    account_repository: Arc<dyn AccountRepository>,
+    product_repository: Arc<dyn ProductRepository>,
+    orchestration_service: Arc<dyn OrchestrationService>,
+    account_number_service: Arc<dyn AccountNumberService>,
+}
+
+impl BundleServiceImpl {
+    pub fn new(
+        bundle_repository: Arc<dyn BundleRepository>,
+        account_repository: Arc<dyn AccountRepository>,
+        product_repository: Arc<dyn ProductRepository>,
+        orchestration_service: Arc<dyn OrchestrationService>,
+        account_number_service: Arc<dyn AccountNumberService>,
+    ) -> Self {
+        Self {
+            bundle_repository,
+            account_repository,
+            product_repository,
+            orchestration_service,
+            account_number_service,
+        }
+    }
+
+    fn validate_bundle(bundle: &ProductBundle) -> BankingResult<()> {
+        let invalid = |field: &str, message: String| BankingError::ValidationError {
+            field: field.to_string(),
+            message,
+        };
+
+        if bundle.bundle_code.trim().is_empty() {
+            return Err(invalid("bundle_code", "Bundle code is required".to_string()));
+        }
+        if bundle.components.len() < 2 {
+            return Err(invalid("components", "A bundle needs at least two components".to_string()));
+        }
+        let mut roles = HashSet::new();
+        for component in &bundle.components {
+            if !roles.insert(component.role) {
+                return Err(invalid("components", format!("Role {:?} appears more than once", component.role)));
+            }
+        }
+        for adjustment in &bundle.fee_adjustments {
+            for role in [adjustment.role, adjustment.while_active_role] {
+                if !roles.contains(&role) {
+                    return Err(invalid("fee_adjustments", format!("Role {role:?} is not a component of the bundle")));
+                }
+            }
+            if adjustment.role == adjustment.while_active_role {
+                return Err(invalid(
+                    "fee_adjustments",
+                    format!("The {:?} discount cannot depend on its own account", adjustment.role),
+                ));
+            }
+            if adjustment.discount_percentage <= Decimal::ZERO || adjustment.discount_percentage > Decimal::ONE_HUNDRED {
+                return Err(invalid(
+                    "fee_adjustments",
+                    "Discount percentage must be above 0 and at most 100".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn component_account(
+        account_id: Uuid,
+        account_number: HeaplessString<34>,
+        component: &BundleComponent,
+        product: &ProductModel,
+        currency: HeaplessString<3>,
+        domicile_agency_branch_id: Uuid,
+        opened_by_person_id: Uuid,
+    ) -> AccountModel {
+        let now = Utc::now();
+        AccountModel {
+            id: account_id,
+            product_id: component.product_id,
+            account_type: AccountMapper::account_type_to_db(component.account_type.clone()),
+            account_status: DbAccountStatus::Active,
+            signing_condition: DbSigningCondition::None,
+            currency,
+            open_date: now.date_naive(),
+            domicile_agency_branch_id,
+            account_number,
+            gl_code_suffix: None,
+            current_balance: Decimal::ZERO,
+            available_balance: Decimal::ZERO,
+            accrued_interest: Decimal::ZERO,
+            overdraft_limit: product.rules.default_overdraft_limit.filter(|_| product.rules.overdraft_allowed),
+            original_principal: None,
+            outstanding_principal: None,
+            loan_interest_rate: None,
+            loan_term_months: None,
+            disbursement_date: None,
+            maturity_date: None,
+            installment_amount: None,
+            next_due_date: None,
+            penalty_rate: None,
+            collateral_id: None,
+            loan_purpose_id: None,
+            close_date: None,
+            last_activity_date: None,
+            dormancy_threshold_days: Some(product.rules.dormancy_threshold_days),
+            reactivation_required: false,
+            pending_closure_reason_id: None,
+            last_disbursement_instruction_id: None,
+            status_changed_by_person_id: None,
+            status_change_reason_id: None,
+            status_change_timestamp: None,
+            most_significant_account_hold_id: None,
+            account_ownership_id: None,
+            access01_account_relationship_id: None,
+            access02_account_relationship_id: None,
+            access03_account_relationship_id: None,
+            access04_account_relationship_id: None,
+            access05_account_relationship_id: None,
+            access06_account_relationship_id: None,
+            access07_account_relationship_id: None,
+            access11_account_mandate_id: None,
+            access12_account_mandate_id: None,
+            access13_account_mandate_id: None,
+            access14_account_mandate_id: None,
+            access15_account_mandate_id: None,
+            access16_account_mandate_id: None,
+            access17_account_mandate_id: None,
+            interest01_ultimate_beneficiary_id: None,
+            interest02_ultimate_beneficiary_id: None,
+            interest03_ultimate_beneficiary_id: None,
+            interest04_ultimate_beneficiary_id: None,
+            interest05_ultimate_beneficiary_id: None,
+            interest06_ultimate_beneficiary_id: None,
+            interest07_ultimate_beneficiary_id: None,
+            created_at: now,
+            last_updated_at: now,
+            updated_by_person_id: opened_by_person_id,
+        }
+    }
+
+    async fn run_opening_steps(
+        &self,
+        bundle: &ProductBundle,
+        account_bundle: AccountBundle,
+        currency: HeaplessString<3>,
+        domicile_agency_branch_id: Uuid,
+        opened_by_person_id: Uuid,
+    ) -> BankingResult<()> {
+        let orchestration_id = account_bundle.orchestration_id;
+
+        // Step 1: the customer bundle; its cancellation also removes the pricing markers
+        self.orchestration_service
+            .record_step(
+                orchestration_id,
+                "create_bundle",
+                Some(CompensationAction::CancelBundle { account_bundle_id: account_bundle.id }),
+            )
+            .await?;
+        let created = self.bundle_repository
+            .create_account_bundle(BundleMapper::account_bundle_to_model(account_bundle))
+            .await?;
+
+        // Steps 2..: the component accounts in configuration order
+        let mut opened: HashMap<BundleComponentRole, Uuid> = HashMap::new();
+        for component in &bundle.components {
+            let product = self.product_repository
+                .find_product_by_id(component.product_id)
+                .await?
+                .filter(|p| p.is_active)
+                .ok_or(BankingError::ProductNotFound(component.product_id))?;
+
+            let account_id = Uuid::new_v4();
+            let account_number = self.account_number_service
+                .generate_account_number(domicile_agency_branch_id, component.product_id)
+                .await?;
+            self.orchestration_service
+                .record_step(
+                    orchestration_id,
+                    &format!("open_{:?}_account", component.role),
+                    Some(CompensationAction::CloseOpenedAccount { account_id }),
+                )
+                .await?;
+            self.account_repository
+                .create(Self::component_account(
+                    account_id,
+                    account_number,
+                    component,
+                    &product,
+                    currency.clone(),
+                    domicile_agency_branch_id,
+                    opened_by_person_id,
+                ))
+                .await?;
+            self.account_repository
+                .create_ownership(AccountOwnershipModel {
+                    id: Uuid::new_v4(),
+                    account_id,
+                    customer_id: created.customer_id,
+                    ownership_type: DbOwnershipType::Single,
+                    ownership_percentage: Some(Decimal::ONE_HUNDRED),
+                    created_at: Utc::now(),
+                })
+                .await?;
+            self.bundle_repository
+                .create_bundle_account(BundleMapper::bundle_account_to_model(BundleAccount {
+                    id: Uuid::new_v4(),
+                    account_bundle_id: created.id,
+                    account_id,
+                    role: component.role,
+                    required: component.required,
+                    linked_at: Utc::now(),
+                    unlinked_at: None,
+                }))
+                .await?;
+            opened.insert(component.role, account_id);
+        }
+
+        // Bundle pricing for the fee engine
+        for adjustment in &bundle.fee_adjustments {
+            self.bundle_repository
+                .create_pricing_marker(BundleMapper::marker_to_model(BundlePricingMarker {
+                    id: Uuid::new_v4(),
+                    account_bundle_id: created.id,
+                    account_id: opened[&adjustment.role],
+                    fee_category: adjustment.fee_category.clone(),
+                    discount_percentage: adjustment.discount_percentage,
+                    depends_on_account_id: opened[&adjustment.while_active_role],
+                    created_at: Utc::now(),
+                    removed_at: None,
+                }))
+                .await?;
+        }
+
+        let mut activated = created;
+        activated.status = DbBundleStatus::Active;
+        self.bundle_repository.update_account_bundle(activated).await?;
+        Ok(())
+    }
+
+    async fn load_view(&self, bundle: AccountBundle) -> BankingResult<BundleView> {
+        let accounts = self.bundle_repository
+            .find_bundle_accounts(bundle.id)
+            .await?
+            .into_iter()
+            .map(BundleMapper::bundle_account_from_model)
+            .collect();
+        let pricing_markers = self.bundle_repository
+            .find_pricing_markers_by_bundle(bundle.id)
+            .await?
+            .into_iter()
+            .map(BundleMapper::marker_from_model)
+            .collect();
+        Ok(BundleView {
+            bundle,
+            accounts,
+            pricing_markers,
+        })
+    }
+}
+
+#[async_trait]
+impl BundleService for BundleServiceImpl {
+    async fn create_bundle(&self, bundle: ProductBundle) -> BankingResult<ProductBundle> {
+        Self::validate_bundle(&bundle)?;
+        for component in &bundle.components {
+            self.product_repository
+                .find_product_by_id(component.product_id)
+                .await?
+                .ok_or(BankingError::ProductNotFound(component.product_id))?;
+        }
+
+        let created = self.bundle_repository
+            .create_product_bundle(BundleMapper::product_bundle_to_model(bundle)?)
+            .await?;
+        BundleMapper::product_bundle_from_model(created)
+    }
+
+    async fn find_bundle_by_code(&self, bundle_code: &str) -> BankingResult<Option<ProductBundle>> {
+        self.bundle_repository
+            .find_product_bundle_by_code(bundle_code)
+            .await?
+            .map(BundleMapper::product_bundle_from_model)
+            .transpose()
+    }
+
+    async fn open_bundle(
+        &self,
+        customer_id: Uuid,
+        bundle_code: &str,
+        currency: &str,
+        domicile_agency_branch_id: Uuid,
+        opened_by_person_id: Uuid,
+    ) -> BankingResult<BundleView> {
+        let bundle = self.find_bundle_by_code(bundle_code)
+            .await?
+            .filter(|b| b.is_active)
+            .ok_or_else(|| BankingError::NotFound(format!("Product bundle {bundle_code} not found")))?;
+        let currency = HeaplessString::try_from(currency).map_err(|_| BankingError::ValidationError {
+            field: "currency".to_string(),
+            message: "Currency must be a 3 letter code".to_string(),
+        })?;
+
+        let orchestration = self.orchestration_service
+            .start_orchestration(OrchestrationType::BundleOpening, customer_id, opened_by_person_id)
+            .await?;
+        let account_bundle = AccountBundle {
+            id: Uuid::new_v4(),
+            product_bundle_id: bundle.id,
+            bundle_code: bundle.bundle_code.clone(),
+            customer_id,
+            status: BundleStatus::Opening,
+            orchestration_id: orchestration.id,
+            opened_at: Utc::now(),
+            dissolved_at: None,
+            dissolution_reason: None,
+        };
+        let account_bundle_id = account_bundle.id;
+
+        let outcome = self
+            .run_opening_steps(&bundle, account_bundle, currency, domicile_agency_branch_id, opened_by_person_id)
+            .await;
+        match outcome {
+            Ok(()) => {
+                self.orchestration_service.complete_orchestration(orchestration.id).await?;
+            }
+            Err(e) => {
+                let orchestration = self.orchestration_service
+                    .fail_orchestration(orchestration.id, &e.to_string())
+                    .await?;
+                tracing::warn!(
+                    "Opening of bundle {} for customer {} failed and ended {:?}: {e}",
+                    bundle.bundle_code,
+                    customer_id,
+                    orchestration.status
+                );
+                return Err(e);
+            }
+        }
+
+        tracing::info!("Opened bundle {} for customer {}", bundle.bundle_code, customer_id);
+        self.find_bundle_view(account_bundle_id).await
+    }
+
+    async fn handle_account_closure(
+        &self,
+        account_id: Uuid,
+        closed_by_person_id: Uuid,
+    ) -> BankingResult<Option<BundleClosureOutcome>> {
+        let Some(link) = self.bundle_repository.find_linked_bundle_account(account_id).await? else {
+            return Ok(None);
+        };
+        let mut bundle = self.bundle_repository
+            .find_account_bundle_by_id(link.account_bundle_id)
+            .await?
+            .ok_or_else(|| BankingError::NotFound(format!("Bundle {} not found", link.account_bundle_id)))?;
+
+        let now = Utc::now();
+        self.bundle_repository.unlink_bundle_account(link.id, now).await?;
+        let remaining = self.bundle_repository
+            .find_bundle_accounts(bundle.id)
+            .await?
+            .into_iter()
+            .filter(|a| a.unlinked_at.is_none() && a.account_id != account_id)
+            .count();
+        let dissolved = link.required || remaining < 2;
+
+        // Without the closed account the discounts it carried or sustained end;
+        // a dissolved bundle loses all its pricing
+        let withdrawn: Vec<_> = self.bundle_repository
+            .find_pricing_markers_by_bundle(bundle.id)
+            .await?
+            .into_iter()
+            .filter(|m| m.removed_at.is_none())
+            .filter(|m| dissolved || m.account_id == account_id || m.depends_on_account_id == account_id)
+            .collect();
+        if !withdrawn.is_empty() {
+            let marker_ids: Vec<Uuid> = withdrawn.iter().map(|m| m.id).collect();
+            self.bundle_repository.remove_pricing_markers(&marker_ids, now).await?;
+        }
+        let mut repriced_account_ids: Vec<Uuid> = withdrawn
+            .iter()
+            .map(|m| m.account_id)
+            .filter(|id| *id != account_id)
+            .collect();
+        repriced_account_ids.sort();
+        repriced_account_ids.dedup();
+
+        if dissolved {
+            bundle.status = DbBundleStatus::Dissolved;
+            bundle.dissolved_at = Some(now);
+            bundle.dissolution_reason = HeaplessString::try_from(format!("Account {account_id} closed").as_str()).ok();
+            self.bundle_repository.update_account_bundle(bundle.clone()).await?;
+        }
+
+        tracing::info!(
+            "Closure of account {} by {}: bundle {} {}, {} account(s) re-priced",
+            account_id,
+            closed_by_person_id,
+            bundle.id,
+            if dissolved { "dissolved" } else { "kept" },
+            repriced_account_ids.len()
+        );
+
+        Ok(Some(BundleClosureOutcome {
+            account_bundle_id: bundle.id,
+            closed_account_id: account_id,
+            dissolved,
+            repriced_account_ids,
+        }))
+    }
+
+    async fn find_bundles_by_customer(&self, customer_id: Uuid) -> BankingResult<Vec<BundleView>> {
+        let bundles = self.bundle_repository.find_account_bundles_by_customer(customer_id).await?;
+        let mut views = Vec::with_capacity(bundles.len());
+        for bundle in bundles {
+            views.push(self.load_view(BundleMapper::account_bundle_from_model(bundle)).await?);
+        }
+        Ok(views)
+    }
+
+    async fn find_bundle_view(&self, account_bundle_id: Uuid) -> BankingResult<BundleView> {
+        let bundle = self.bundle_repository
+            .find_account_bundle_by_id(account_bundle_id)
+            .await?
+            .ok_or_else(|| BankingError::NotFound(format!("Bundle {account_bundle_id} not found")))?;
+        self.load_view(BundleMapper::account_bundle_from_model(bundle)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use banking_api::domain::{AccountNumberScheme, AccountType, BundleFeeAdjustment, FeeCategory};
+    use banking_db::models::{
+        AccountBundleModel, BundleAccountModel, BundlePricingMarkerModel, DbOrchestrationStatus,
+        DbOrchestrationStepStatus, OrchestrationModel, OrchestrationStepModel, ProductBundleModel, ProductRules,
+        ProductType, PostingFrequency, ProductAccrualFrequency, DayCountConvention,
+    };
+    use banking_db::repository::OrchestrationRepository;
+    use chrono::{DateTime, NaiveDate};
+    use crate::services::{OrchestrationServiceImpl, RepositoryCompensationHandler};
+    use crate::services::test_doubles::InMemoryAccountRepository;
+
+    #[derive(Default)]
+    struct MockBundleRepository {
+        product_bundles: Mutex<Vec<ProductBundleModel>>,
+        bundles: Mutex<HashMap<Uuid, AccountBundleModel>>,
+        links: Mutex<Vec<BundleAccountModel>>,
+        markers: Mutex<Vec<BundlePricingMarkerModel>>,
+    }
+
+    #[async_trait]
+    impl BundleRepository for MockBundleRepository {
+        async fn create_product_bundle(&self, bundle: ProductBundleModel) -> BankingResult<ProductBundleModel> {
+            self.product_bundles.lock().unwrap().push(bundle.clone());
+            Ok(bundle)
+        }
+        async fn find_product_bundle_by_code(&self, bundle_code: &str) -> BankingResult<Option<ProductBundleModel>> {
+            Ok(self.product_bundles.lock().unwrap().iter().find(|b| b.bundle_code.as_str() == bundle_code).cloned())
+        }
+        async fn create_account_bundle(&self, bundle: AccountBundleModel) -> BankingResult<AccountBundleModel> {
+            self.bundles.lock().unwrap().insert(bundle.id, bundle.clone());
+            Ok(bundle)
+        }
+        async fn update_account_bundle(&self, bundle: AccountBundleModel) -> BankingResult<AccountBundleModel> {
+            self.bundles.lock().unwrap().insert(bundle.id, bundle.clone());
+            Ok(bundle)
+        }
+        async fn find_account_bundle_by_id(&self, account_bundle_id: Uuid) -> BankingResult<Option<AccountBundleModel>> {
+            Ok(self.bundles.lock().unwrap().get(&account_bundle_id).cloned())
+        }
+        async fn find_account_bundles_by_customer(&self, customer_id: Uuid) -> BankingResult<Vec<AccountBundleModel>> {
+            Ok(self.bundles.lock().unwrap().values().filter(|b| b.customer_id == customer_id).cloned().collect())
+        }
+        async fn create_bundle_account(&self, link: BundleAccountModel) -> BankingResult<BundleAccountModel> {
+            self.links.lock().unwrap().push(link.clone());
+            Ok(link)
+        }
+        async fn find_bundle_accounts(&self, account_bundle_id: Uuid) -> BankingResult<Vec<BundleAccountModel>> {
+            Ok(self.links.lock().unwrap().iter().filter(|l| l.account_bundle_id == account_bundle_id).cloned().collect())
+        }
+        async fn find_linked_bundle_account(&self, account_id: Uuid) -> BankingResult<Option<BundleAccountModel>> {
+            let bundles = self.bundles.lock().unwrap();
+            Ok(self.links.lock().unwrap()
+                .iter()
+                .find(|l| {
+                    l.account_id == account_id
+                        && l.unlinked_at.is_none()
+                        && bundles.get(&l.account_bundle_id).is_some_and(|b| b.status == DbBundleStatus::Active)
+                })
+                .cloned())
+        }
+        async fn unlink_bundle_account(&self, link_id: Uuid, unlinked_at: DateTime<Utc>) -> BankingResult<()> {
+            let mut links = self.links.lock().unwrap();
+            links.iter_mut().find(|l| l.id == link_id).unwrap().unlinked_at = Some(unlinked_at);
+            Ok(())
+        }
+        async fn create_pricing_marker(&self, marker: BundlePricingMarkerModel) -> BankingResult<BundlePricingMarkerModel> {
+            self.markers.lock().unwrap().push(marker.clone());
+            Ok(marker)
+        }
+        async fn find_pricing_markers_by_bundle(&self, account_bundle_id: Uuid) -> BankingResult<Vec<BundlePricingMarkerModel>> {
+            Ok(self.markers.lock().unwrap().iter().filter(|m| m.account_bundle_id == account_bundle_id).cloned().collect())
+        }
+        async fn find_active_markers_by_account(&self, account_id: Uuid) -> BankingResult<Vec<BundlePricingMarkerModel>> {
+            Ok(self.markers.lock().unwrap()
+                .iter()
+                .filter(|m| m.account_id == account_id && m.removed_at.is_none())
+                .cloned()
+                .collect())
+        }
+        async fn remove_pricing_markers(&self, marker_ids: &[Uuid], removed_at: DateTime<Utc>) -> BankingResult<()> {
+            for marker in self.markers.lock().unwrap().iter_mut() {
+                if marker_ids.contains(&marker.id) && marker.removed_at.is_none() {
+                    marker.removed_at = Some(removed_at);
+                }
+            }
+            Ok(())
+        }
+    }
+
+    struct MockProductRepository;
+
+    #[async_trait]
+    impl ProductRepository for MockProductRepository {
+        async fn create_product(&self, _product: ProductModel) -> BankingResult<ProductModel> { todo!() }
+        async fn find_product_by_id(&self, product_id: Uuid) -> BankingResult<Option<ProductModel>> {
+            Ok(Some(ProductModel {
+                id: product_id,
+                name_l1: HeaplessString::try_from("Bundle component").unwrap(),
+                name_l2: HeaplessString::new(),
+                name_l3: HeaplessString::new(),
+                description: HeaplessString::new(),
+                is_active: true,
+                valid_from: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                valid_to: None,
+                product_type: ProductType::CASA,
+                rules: ProductRules {
+                    minimum_balance: Decimal::ZERO,
+                    maximum_balance: None,
+                    daily_transaction_limit: None,
+                    monthly_transaction_limit: None,
+                    overdraft_allowed: false,
+                    overdraft_limit: None,
+                    interest_calculation_method: HeaplessString::try_from("DailyBalance").unwrap(),
+                    interest_posting_frequency: PostingFrequency::Monthly,
+                    dormancy_threshold_days: 365,
+                    minimum_opening_balance: Decimal::ZERO,
+                    closure_fee: Decimal::ZERO,
+                    maintenance_fee: Some(Decimal::from(5)),
+                    maintenance_fee_frequency: None,
+                    default_dormancy_days: None,
+                    default_overdraft_limit: None,
+                    per_transaction_limit: None,
+                    overdraft_interest_rate: None,
+                    unauthorized_overdraft_interest_rate: None,
+                    accrual_frequency: ProductAccrualFrequency::Daily,
+                    day_count_convention: DayCountConvention::Actual365Fixed,
+                    guarantor_required: false,
+                    custody_fee_threshold: None,
+                    custody_fee_rate: None,
+                    inactivity_fee_threshold_months: None,
+                    inactivity_fee_item_id: None,
+                    inactivity_fee_warning_months: None,
+                    supported_currencies: Vec::new(),
+                },
+                created_at: Utc::now(),
+                last_updated_at: Utc::now(),
+                updated_by_person_id: Uuid::new_v4(),
+            }))
+        }
+        async fn update_product(&self, _product: ProductModel) -> BankingResult<ProductModel> { todo!() }
+        async fn deactivate_product(&self, _product_id: Uuid, _updated_by_person_id: Uuid) -> BankingResult<()> { todo!() }
+        async fn reactivate_product(&self, _product_id: Uuid, _updated_by_person_id: Uuid) -> BankingResult<()> { todo!() }
+        async fn find_active_products(&self) -> BankingResult<Vec<ProductModel>> { todo!() }
+        async fn find_products_by_type(&self, _product_type: ProductType) -> BankingResult<Vec<ProductModel>> { todo!() }
+        async fn find_interest_rate_tiers_by_product_id(&self, _product_id: Uuid) -> BankingResult<Vec<banking_db::models::product::InterestRateTierModel>> { todo!() }
+        async fn find_gl_mapping_by_product_id(&self, _product_id: Uuid) -> BankingResult<Option<banking_db::models::product::GlMappingModel>> { todo!() }
+    }
+
+    #[derive(Default)]
+    struct MockOrchestrationRepository {
+        orchestrations: Mutex<HashMap<Uuid, OrchestrationModel>>,
+        steps: Mutex<Vec<OrchestrationStepModel>>,
+    }
+
+    #[async_trait]
+    impl OrchestrationRepository for MockOrchestrationRepository {
+        async fn create_orchestration(&self, orchestration: OrchestrationModel) -> BankingResult<OrchestrationModel> {
+            self.orchestrations.lock().unwrap().insert(orchestration.id, orchestration.clone());
+            Ok(orchestration)
+        }
+        async fn find_orchestration_by_id(&self, orchestration_id: Uuid) -> BankingResult<Option<OrchestrationModel>> {
+            Ok(self.orchestrations.lock().unwrap().get(&orchestration_id).cloned())
+        }
+        async fn update_orchestration(&self, orchestration: OrchestrationModel) -> BankingResult<OrchestrationModel> {
+            self.orchestrations.lock().unwrap().insert(orchestration.id, orchestration.clone());
+            Ok(orchestration)
+        }
+        async fn find_incomplete_orchestrations(&self) -> BankingResult<Vec<OrchestrationModel>> {
+            Ok(self.orchestrations.lock().unwrap()
+                .values()
+                .filter(|o| !matches!(
+                    o.status,
+                    DbOrchestrationStatus::Completed | DbOrchestrationStatus::Compensated | DbOrchestrationStatus::ManuallyResolved
+                ))
+                .cloned()
+                .collect())
+        }
+        async fn create_step(&self, step: OrchestrationStepModel) -> BankingResult<OrchestrationStepModel> {
+            self.steps.lock().unwrap().push(step.clone());
+            Ok(step)
+        }
+        async fn find_steps(&self, orchestration_id: Uuid) -> BankingResult<Vec<OrchestrationStepModel>> {
+            let mut steps: Vec<OrchestrationStepModel> = self.steps.lock().unwrap()
+                .iter()
+                .filter(|s| s.orchestration_id == orchestration_id)
+                .cloned()
+                .collect();
+            steps.sort_by_key(|s| s.sequence);
+            Ok(steps)
+        }
+        async fn update_step_status(
+            &self,
+            step_id: Uuid,
+            status: DbOrchestrationStepStatus,
+            compensated_at: Option<DateTime<Utc>>,
+            last_error: Option<HeaplessString<255>>,
+        ) -> BankingResult<()> {
+            let mut steps = self.steps.lock().unwrap();
+            let step = steps.iter_mut().find(|s| s.id == step_id).unwrap();
+            step.status = status;
+            step.compensated_at = compensated_at;
+            step.last_error = last_error;
+            Ok(())
+        }
+    }
+
+    /// Hands out sequential numbers for any branch and product
+    #[derive(Default)]
+    struct MockAccountNumberService {
+        last_serial: Mutex<i64>,
+    }
+
+    #[async_trait]
+    impl AccountNumberService for MockAccountNumberService {
+        async fn configure_scheme(&self, _scheme: AccountNumberScheme) -> BankingResult<AccountNumberScheme> { todo!() }
+        async fn find_applicable_scheme(&self, _branch_id: Uuid, _product_id: Uuid) -> BankingResult<Option<AccountNumberScheme>> { todo!() }
+        async fn generate_account_number(&self, _branch_id: Uuid, _product_id: Uuid) -> BankingResult<HeaplessString<34>> {
+            let mut serial = self.last_serial.lock().unwrap();
+            *serial += 1;
+            Ok(HeaplessString::try_from(format!("1000500001{:011}", *serial).as_str()).unwrap())
+        }
+        async fn find_account_by_number(&self, _account_number: &str) -> BankingResult<Option<banking_api::domain::Account>> { todo!() }
+    }
+
+    struct Fixture {
+        service: BundleServiceImpl,
+        bundles: Arc<MockBundleRepository>,
+        accounts: Arc<InMemoryAccountRepository>,
+        orchestrations: Arc<MockOrchestrationRepository>,
+        wallet_product_id: Uuid,
+    }
+
+    /// Current (required), savings and mobile wallet; the savings maintenance
+    /// fee is waived while the current account is Active
+    async fn fixture() -> Fixture {
+        let bundles = Arc::new(MockBundleRepository::default());
+        let accounts = Arc::new(InMemoryAccountRepository::default());
+        let products = Arc::new(MockProductRepository);
+        let orchestrations = Arc::new(MockOrchestrationRepository::default());
+        let orchestration_service = Arc::new(OrchestrationServiceImpl::new(
+            orchestrations.clone(),
+            Arc::new(RepositoryCompensationHandler::new(accounts.clone(), products.clone(), bundles.clone())),
+        ));
+        let service = BundleServiceImpl::new(
+            bundles.clone(),
+            accounts.clone(),
+            products,
+            orchestration_service,
+            Arc::new(MockAccountNumberService::default()),
+        );
+
+        let component = |role, account_type, required| BundleComponent {
+            role,
+            product_id: Uuid::new_v4(),
+            account_type,
+            required,
+        };
+        let components = vec![
+            component(BundleComponentRole::Current, AccountType::Current, true),
+            component(BundleComponentRole::Savings, AccountType::Savings, false),
+            component(BundleComponentRole::MobileWallet, AccountType::Current, false),
+        ];
+        let wallet_product_id = components[2].product_id;
+        service
+            .create_bundle(ProductBundle {
+                id: Uuid::new_v4(),
+                bundle_code: HeaplessString::try_from("SMART3").unwrap(),
+                name: HeaplessString::try_from("Smart banking pack").unwrap(),
+                components,
+                fee_adjustments: vec![BundleFeeAdjustment {
+                    role: BundleComponentRole::Savings,
+                    fee_category: FeeCategory::Maintenance,
+                    discount_percentage: Decimal::ONE_HUNDRED,
+                    while_active_role: BundleComponentRole::Current,
+                }],
+                is_active: true,
+                created_at: Utc::now(),
+                last_updated_at: Utc::now(),
+                updated_by_person_id: Uuid::new_v4(),
+            })
+            .await
+            .unwrap();
+
+        Fixture {
+            service,
+            bundles,
+            accounts,
+            orchestrations,
+            wallet_product_id,
+        }
+    }
+
+    fn account_of(view: &BundleView, role: BundleComponentRole) -> Uuid {
+        view.accounts.iter().find(|a| a.role == role).unwrap().account_id
+    }
+
+    #[tokio::test]
+    async fn test_failed_wallet_opening_closes_opened_accounts_and_cancels_bundle() {
+        let fixture = fixture().await;
+        *fixture.accounts.failing_product.lock().unwrap() = Some(fixture.wallet_product_id);
+        let customer_id = Uuid::new_v4();
+
+        let result = fixture.service
+            .open_bundle(customer_id, "SMART3", "XAF", Uuid::new_v4(), Uuid::new_v4())
+            .await;
+        assert!(result.is_err());
+
+        // The current and savings accounts were opened, then closed by compensation
+        let accounts = fixture.accounts.accounts.lock().unwrap().clone();
+        assert_eq!(accounts.len(), 2);
+        assert!(accounts.values().all(|a| a.account_status == DbAccountStatus::Closed));
+
+        let views = fixture.service.find_bundles_by_customer(customer_id).await.unwrap();
+        assert_eq!(views.len(), 1);
+        assert_eq!(views[0].bundle.status, BundleStatus::Cancelled);
+        assert_eq!(views[0].accounts.len(), 2);
+        assert!(views[0].pricing_markers.is_empty());
+
+        let orchestration = fixture.orchestrations.orchestrations.lock().unwrap()[&views[0].bundle.orchestration_id].clone();
+        assert_eq!(orchestration.status, DbOrchestrationStatus::Compensated);
+        assert!(fixture.orchestrations.steps.lock().unwrap()
+            .iter()
+            .all(|s| s.status == DbOrchestrationStepStatus::Compensated));
+        // A cancelled bundle plays no part in later closures
+        let current = account_of(&views[0], BundleComponentRole::Current);
+        assert!(fixture.service.handle_account_closure(current, Uuid::new_v4()).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_component_closure_reprices_and_dissolves_bundle() {
+        let fixture = fixture().await;
+        let customer_id = Uuid::new_v4();
+        let view = fixture.service
+            .open_bundle(customer_id, "SMART3", "XAF", Uuid::new_v4(), Uuid::new_v4())
+            .await
+            .unwrap();
+        assert_eq!(view.bundle.status, BundleStatus::Active);
+        assert_eq!(view.accounts.len(), 3);
+        let numbers: HashSet<String> = fixture.accounts.accounts.lock().unwrap()
+            .values()
+            .map(|a| a.account_number.to_string())
+            .collect();
+        assert_eq!(numbers.len(), 3);
+        let current = account_of(&view, BundleComponentRole::Current);
+        let savings = account_of(&view, BundleComponentRole::Savings);
+        let wallet = account_of(&view, BundleComponentRole::MobileWallet);
+        assert_eq!(view.pricing_markers.len(), 1);
+        assert_eq!(view.pricing_markers[0].account_id, savings);
+        assert_eq!(view.pricing_markers[0].depends_on_account_id, current);
+        assert_eq!(view.pricing_markers[0].apply(Decimal::from(5)), Decimal::ZERO);
+
+        // The wallet is optional and sustains no discount: the bundle keeps its pricing
+        let outcome = fixture.service.handle_account_closure(wallet, Uuid::new_v4()).await.unwrap().unwrap();
+        assert!(!outcome.dissolved);
+        assert!(outcome.repriced_account_ids.is_empty());
+        assert_eq!(fixture.bundles.find_active_markers_by_account(savings).await.unwrap().len(), 1);
+
+        // Closing the current account dissolves the bundle and re-prices the savings account
+        let outcome = fixture.service.handle_account_closure(current, Uuid::new_v4()).await.unwrap().unwrap();
+        assert!(outcome.dissolved);
+        assert_eq!(outcome.repriced_account_ids, vec![savings]);
+        assert!(fixture.bundles.find_active_markers_by_account(savings).await.unwrap().is_empty());
+
+        let views = fixture.service.find_bundles_by_customer(customer_id).await.unwrap();
+        assert_eq!(views[0].bundle.status, BundleStatus::Dissolved);
+        assert!(views[0].bundle.dissolved_at.is_some());
+        assert!(views[0].pricing_markers.iter().all(|m| m.removed_at.is_some()));
+        // The savings account is no longer part of an active bundle
+        assert!(fixture.service.handle_account_closure(savings, Uuid::new_v4()).await.unwrap().is_none());
+    }
+}
@@ -4,7 +4,7 @@ use chrono::NaiveDate;
 use uuid::Uuid;
 
 use banking_api::{
-    domain::{BankHoliday, BusinessDayCalculation, HolidayType, DateShiftRule, WeekendDays, Weekday},
+    domain::{BankHoliday, BusinessDayCalculation, HolidayType, DateShiftRule, WeekendDays, WeekendMask, Weekday, DEFAULT_CALENDAR},
     error::{BankingError, BankingResult},
     service::CalendarService,
 };
@@ -22,76 +22,33 @@ impl CalendarServiceImpl {
         Self { calendar_repository }
     }
 
-    /// Get weekend configuration for jurisdiction with validation
-    async fn get_weekend_days_for_jurisdiction(&self, jurisdiction: &str) -> BankingResult<WeekendDays> {
-        // Validate jurisdiction format
-        if jurisdiction.trim().is_empty() {
-            return Err(BankingError::ValidationFailed("Jurisdiction cannot be empty".to_string()));
+    /// Weekend rule of a calendar, falling back to the default calendar and then
+    /// to Saturday/Sunday when neither has been configured
+    async fn weekend_mask_for(&self, calendar_id: &str) -> BankingResult<WeekendMask> {
+        if calendar_id.trim().is_empty() {
+            return Err(BankingError::ValidationFailed("Calendar id cannot be empty".to_string()));
         }
 
-        let chrono_weekdays = self.calendar_repository
-            .get_weekend_days(jurisdiction)
-            .await?;
-        
-        // Convert chrono weekdays back to WeekendDays domain object
-        let now = chrono::Utc::now();
-        let system_user_id = Uuid::parse_str("00000000-0000-0000-0000-000000000000")
-            .map_err(|e| BankingError::ValidationError {
-                field: "system_user_id".to_string(),
-                message: format!("Invalid UUID: {e}")
-            })?;
-        
-        let name_l1 = heapless::String::try_from(jurisdiction)
-            .map_err(|_| BankingError::ValidationError {
-                field: "jurisdiction".to_string(),
-                message: "Jurisdiction name too long".to_string(),
-            })?;
-        
-        let mut weekend_days = WeekendDays {
-            id: Uuid::new_v4(),
-            name_l1,
-            name_l2: None,
-            name_l3: None,
-            weekend_day_01: None,
-            weekend_day_02: None,
-            weekend_day_03: None,
-            weekend_day_04: None,
-            weekend_day_05: None,
-            weekend_day_06: None,
-            weekend_day_07: None,
-            valid_from: now,
-            valid_to: None,
-            created_by_person_id: system_user_id,
-            created_at: now,
-        };
-        
-        // Map chrono weekdays to WeekendDays fields
-        for (i, &weekday) in chrono_weekdays.iter().enumerate() {
-            let domain_weekday = Self::chrono_weekday_to_domain_weekday(weekday);
-            match i {
-                0 => weekend_days.weekend_day_01 = Some(domain_weekday),
-                1 => weekend_days.weekend_day_02 = Some(domain_weekday),
-                2 => weekend_days.weekend_day_03 = Some(domain_weekday),
-                3 => weekend_days.weekend_day_04 = Some(domain_weekday),
-                4 => weekend_days.weekend_day_05 = Some(domain_weekday),
-                5 => weekend_days.weekend_day_06 = Some(domain_weekday),
-                6 => weekend_days.weekend_day_07 = Some(domain_weekday),
-                _ => break, // More than 7 days, ignore extras
+        if let Some(mask) = self.calendar_repository.get_weekend_days(calendar_id).await? {
+            return Ok(mask);
+        }
+        if calendar_id != DEFAULT_CALENDAR {
+            if let Some(mask) = self.calendar_repository.get_weekend_days(DEFAULT_CALENDAR).await? {
+                return Ok(mask);
             }
         }
-        
-        Ok(weekend_days)
+        Ok(WeekendMask::default())
     }
 
-    /// Get holidays for jurisdiction and date range
+    /// Get holidays for calendar and date range
     async fn get_holidays_for_date_range(
         &self,
-        jurisdiction: &str,
+        calendar_id: &str,
         start_date: NaiveDate,
         end_date: NaiveDate,
     ) -> BankingResult<Vec<NaiveDate>> {
         let holidays = self.calendar_repository
-            .find_holidays_in_range(start_date, end_date, jurisdiction)
+            .find_holidays_in_range(start_date, end_date, calendar_id)
             .await?;
 
         Ok(holidays.into_iter().map(|h| h.holiday_date).collect())
@@ -120,67 +77,50 @@ impl CalendarServiceImpl {
             Weekday::Sunday => chrono::Weekday::Sun,
         }
     }
-
-    fn chrono_weekday_to_domain_weekday(weekday: chrono::Weekday) -> Weekday {
-        match weekday {
-            chrono::Weekday::Mon => Weekday::Monday,
-            chrono::Weekday::Tue => Weekday::Tuesday,
-            chrono::Weekday::Wed => Weekday::Wednesday,
-            chrono::Weekday::Thu => Weekday::Thursday,
-            chrono::Weekday::Fri => Weekday::Friday,
-            chrono::Weekday::Sat => Weekday::Saturday,
-            chrono::Weekday::Sun => Weekday::Sunday,
-        }
-    }
 }
 
 #[async_trait]
 impl CalendarService for CalendarServiceImpl {
     /// Check if a date is a business day with comprehensive validation
-    async fn is_business_day(&self, date: NaiveDate, jurisdiction: &str) -> BankingResult<bool> {
-        // Get weekend configuration with validation
-        let weekend_days = self.get_weekend_days_for_jurisdiction(jurisdiction).await?;
-        let weekdays = Self::get_weekdays_from_weekend_days(&weekend_days);
+    async fn is_business_day(&self, date: NaiveDate, calendar_id: &str) -> BankingResult<bool> {
+        let weekdays = self.weekend_mask_for(calendar_id).await?.weekdays();
         
         // Get holidays for the specific date
-        let holidays = self.get_holidays_for_date_range(jurisdiction, date, date).await?;
+        let holidays = self.get_holidays_for_date_range(calendar_id, date, date).await?;
 
         // Use validation helper to determine if it's a business day
         Ok(CalendarValidation::is_business_day_from_weekdays(date, &weekdays, &holidays))
     }
 
     /// Get next business day with validation
-    async fn next_business_day(&self, date: NaiveDate, jurisdiction: &str) -> BankingResult<NaiveDate> {
-        let weekend_days = self.get_weekend_days_for_jurisdiction(jurisdiction).await?;
-        let weekdays = Self::get_weekdays_from_weekend_days(&weekend_days);
+    async fn next_business_day(&self, date: NaiveDate, calendar_id: &str) -> BankingResult<NaiveDate> {
+        let weekdays = self.weekend_mask_for(calendar_id).await?.weekdays();
         
         // Get holidays for a reasonable range (next 30 days to handle consecutive holidays)
         let end_range = date + chrono::Duration::days(30);
-        let holidays = self.get_holidays_for_date_range(jurisdiction, date, end_range).await?;
+        let holidays = self.get_holidays_for_date_range(calendar_id, date, end_range).await?;
 
         Ok(CalendarValidation::next_business_day_from_weekdays(date, &weekdays, &holidays))
     }
 
     /// Get previous business day with validation
-    async fn previous_business_day(&self, date: NaiveDate, jurisdiction: &str) -> BankingResult<NaiveDate> {
-        let weekend_days = self.get_weekend_days_for_jurisdiction(jurisdiction).await?;
-        let weekdays = Self::get_weekdays_from_weekend_days(&weekend_days);
+    async fn previous_business_day(&self, date: NaiveDate, calendar_id: &str) -> BankingResult<NaiveDate> {
+        let weekdays = self.weekend_mask_for(calendar_id).await?.weekdays();
         
         // Get holidays for a reasonable range (previous 30 days)
         let start_range = date - chrono::Duration::days(30);
-        let holidays = self.get_holidays_for_date_range(jurisdiction, start_range, date).await?;
+        let holidays = self.get_holidays_for_date_range(calendar_id, start_range, date).await?;
 
         Ok(CalendarValidation::previous_business_day_from_weekdays(date, &weekdays, &holidays))
     }
 
     /// Add business days to a date with validation
-    async fn add_business_days(&self, date: NaiveDate, days: i32, jurisdiction: &str) -> BankingResult<NaiveDate> {
+    async fn add_business_days(&self, date: NaiveDate, days: i32, calendar_id: &str) -> BankingResult<NaiveDate> {
         if days == 0 {
             return Ok(date);
         }
 
-        let weekend_days = self.get_weekend_days_for_jurisdiction(jurisdiction).await?;
-        let weekdays = Self::get_weekdays_from_weekend_days(&weekend_days);
+        let weekdays = self.weekend_mask_for(calendar_id).await?.weekdays();
         
         // Get holidays for a reasonable range based on days to add
         let range_days = (days.abs() * 2).max(60); // Buffer for weekends and holidays
@@ -190,7 +130,7 @@ impl CalendarService for CalendarServiceImpl {
             (date - chrono::Duration::days(range_days as i64), date)
         };
         
-        let holidays = self.get_holidays_for_date_range(jurisdiction, start_range, end_range).await?;
+        let holidays = self.get_holidays_for_date_range(calendar_id, start_range, end_range).await?;
 
         let mut current_date = date;
         let mut remaining_days = days.abs();
@@ -213,14 +153,13 @@ impl CalendarService for CalendarServiceImpl {
     }
 
     /// Count business days between two dates with validation
-    async fn count_business_days(&self, from: NaiveDate, to: NaiveDate, jurisdiction: &str) -> BankingResult<i32> {
+    async fn count_business_days(&self, from: NaiveDate, to: NaiveDate, calendar_id: &str) -> BankingResult<i32> {
         if from >= to {
             return Ok(0);
         }
 
-        let weekend_days = self.get_weekend_days_for_jurisdiction(jurisdiction).await?;
-        let weekdays = Self::get_weekdays_from_weekend_days(&weekend_days);
-        let holidays = self.get_holidays_for_date_range(jurisdiction, from, to).await?;
+        let weekdays = self.weekend_mask_for(calendar_id).await?.weekdays();
+        let holidays = self.get_holidays_for_date_range(calendar_id, from, to).await?;
 
         Ok(CalendarValidation::count_business_days_from_weekdays(from, to, &weekdays, &holidays))
     }
@@ -265,10 +204,10 @@ impl CalendarService for CalendarServiceImpl {
         ));
     }
 
-    /// Get all holidays for a jurisdiction and year
-    async fn get_holidays(&self, jurisdiction: &str, year: i32) -> BankingResult<Vec<BankHoliday>> {
+    /// Get all holidays for a calendar and year
+    async fn get_holidays(&self, calendar_id: &str, year: i32) -> BankingResult<Vec<BankHoliday>> {
         // Validate inputs
-        if jurisdiction.trim().is_empty() {
+        if calendar_id.trim().is_empty() {
             return Err(BankingError::ValidationFailed("Jurisdiction cannot be empty".to_string()));
         }
 
@@ -281,7 +220,7 @@ impl CalendarService for CalendarServiceImpl {
         let end_date = NaiveDate::from_ymd_opt(year, 12, 31).unwrap();
         
         let holiday_models = self.calendar_repository
-            .find_holidays_in_range(start_date, end_date, jurisdiction)
+            .find_holidays_in_range(start_date, end_date, calendar_id)
             .await?;
 
         Ok(holiday_models.into_iter()
@@ -290,10 +229,9 @@ impl CalendarService for CalendarServiceImpl {
     }
 
     /// Calculate business day with rule application
-    async fn calculate_business_day(&self, date: NaiveDate, jurisdiction: &str) -> BankingResult<BusinessDayCalculation> {
-        let weekend_days = self.get_weekend_days_for_jurisdiction(jurisdiction).await?;
-        let weekdays = Self::get_weekdays_from_weekend_days(&weekend_days);
-        let holidays = self.get_holidays_for_date_range(jurisdiction, date, date).await?;
+    async fn calculate_business_day(&self, date: NaiveDate, calendar_id: &str) -> BankingResult<BusinessDayCalculation> {
+        let weekdays = self.weekend_mask_for(calendar_id).await?.weekdays();
+        let holidays = self.get_holidays_for_date_range(calendar_id, date, date).await?;
 
         let is_business_day = CalendarValidation::is_business_day_from_weekdays(date, &weekdays, &holidays);
         let _is_weekend = CalendarValidation::is_weekend_day_from_weekdays(date, &weekdays);
@@ -316,7 +254,7 @@ impl CalendarService for CalendarServiceImpl {
             adjusted_date,
             is_business_day,
             applied_rule,
-            jurisdiction,
+            calendar_id,
         ).map_err(|e| BankingError::ValidationError {
             field: "jurisdiction".to_string(),
             message: e.to_string(),
@@ -327,20 +265,19 @@ impl CalendarService for CalendarServiceImpl {
     async fn batch_calculate_business_days(
         &self,
         dates: Vec<NaiveDate>,
-        jurisdiction: &str,
+        calendar_id: &str,
     ) -> BankingResult<Vec<BusinessDayCalculation>> {
         if dates.is_empty() {
             return Ok(Vec::new());
         }
 
-        let weekend_days = self.get_weekend_days_for_jurisdiction(jurisdiction).await?;
-        let weekdays = Self::get_weekdays_from_weekend_days(&weekend_days);
+        let weekdays = self.weekend_mask_for(calendar_id).await?.weekdays();
         
         // Get the date range for all dates
         let min_date = *dates.iter().min().unwrap();
         let max_date = *dates.iter().max().unwrap();
         
-        let holidays = self.get_holidays_for_date_range(jurisdiction, min_date, max_date).await?;
+        let holidays = self.get_holidays_for_date_range(calendar_id, min_date, max_date).await?;
 
         let mut results = Vec::with_capacity(dates.len());
         
@@ -366,7 +303,7 @@ impl CalendarService for CalendarServiceImpl {
                 adjusted_date,
                 is_business_day,
                 applied_rule,
-                jurisdiction,
+                calendar_id,
             ).map_err(|e| BankingError::ValidationError {
                 field: "jurisdiction".to_string(),
                 message: e.to_string(),
@@ -378,19 +315,22 @@ impl CalendarService for CalendarServiceImpl {
     }
 
     /// Check if date falls on weekend
-    async fn is_weekend(&self, date: NaiveDate, jurisdiction: &str) -> BankingResult<bool> {
-        let weekend_days = self.get_weekend_days_for_jurisdiction(jurisdiction).await?;
-        let weekdays = Self::get_weekdays_from_weekend_days(&weekend_days);
+    async fn is_weekend(&self, date: NaiveDate, calendar_id: &str) -> BankingResult<bool> {
+        let weekdays = self.weekend_mask_for(calendar_id).await?.weekdays();
         Ok(CalendarValidation::is_weekend_day_from_weekdays(date, &weekdays))
     }
 
     /// Create a new weekend days configuration
     async fn create_weekend_days(&self, weekend_days: WeekendDays) -> BankingResult<WeekendDays> {
-        // Convert the domain weekend days to chrono weekdays for the repository
-        let chrono_weekdays = Self::get_weekdays_from_weekend_days(&weekend_days);
+        // Convert the domain weekend days to a weekend mask for the repository
+        let weekend_mask = WeekendMask::from_weekdays(&Self::get_weekdays_from_weekend_days(&weekend_days));
+        weekend_mask.validate().map_err(|e| BankingError::ValidationError {
+            field: "weekend_days".to_string(),
+            message: e.to_string(),
+        })?;
         
-        // Set the weekend days in the repository using the jurisdiction
-        self.calendar_repository.set_weekend_days(&weekend_days.name_l1, chrono_weekdays).await?;
+        // Set the weekend days in the repository using the calendar id
+        self.calendar_repository.set_weekend_days(&weekend_days.name_l1, weekend_mask).await?;
         
         // Return the same weekend days object since we don't have ID-based retrieval
         Ok(weekend_days)
@@ -399,7 +339,7 @@ impl CalendarService for CalendarServiceImpl {
     /// Get a weekend days configuration by its ID
     async fn get_weekend_days_by_id(&self, _weekend_days_id: Uuid) -> BankingResult<Option<WeekendDays>> {
         // Since the repository doesn't support ID-based weekend days retrieval,
-        // and we don't have jurisdiction information from just the ID,
+        // and we don't have calendar information from just the ID,
         // we return an error indicating this operation is not supported
         Err(BankingError::NotImplemented(
             "Weekend days retrieval by ID is not supported by the current repository implementation".to_string()
@@ -408,11 +348,15 @@ impl CalendarService for CalendarServiceImpl {
 
     /// Update an existing weekend days configuration
     async fn update_weekend_days(&self, weekend_days: WeekendDays) -> BankingResult<WeekendDays> {
-        // Convert the domain weekend days to chrono weekdays for the repository
-        let chrono_weekdays = Self::get_weekdays_from_weekend_days(&weekend_days);
+        // Convert the domain weekend days to a weekend mask for the repository
+        let weekend_mask = WeekendMask::from_weekdays(&Self::get_weekdays_from_weekend_days(&weekend_days));
+        weekend_mask.validate().map_err(|e| BankingError::ValidationError {
+            field: "weekend_days".to_string(),
+            message: e.to_string(),
+        })?;
         
-        // Update the weekend days in the repository using the jurisdiction
-        self.calendar_repository.set_weekend_days(&weekend_days.name_l1, chrono_weekdays).await?;
+        // Update the weekend days in the repository using the calendar id
+        self.calendar_repository.set_weekend_days(&weekend_days.name_l1, weekend_mask).await?;
         
         // Return the same weekend days object since we don't have ID-based retrieval
         Ok(weekend_days)
@@ -421,10 +365,139 @@ impl CalendarService for CalendarServiceImpl {
     /// Delete a weekend days configuration by its ID
     async fn delete_weekend_days(&self, _weekend_days_id: Uuid) -> BankingResult<()> {
         // Since the repository doesn't support ID-based weekend days deletion,
-        // and we don't have jurisdiction information from just the ID,
+        // and we don't have calendar information from just the ID,
         // we return an error indicating this operation is not supported
         Err(BankingError::NotImplemented(
             "Weekend days deletion by ID is not supported by the current repository implementation".to_string()
         ))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use banking_db::models::{BankHolidayModel, HolidayType as HolidayTypeModel};
+    use banking_db::repository::{CalendarSummaryReport, ImportResult, ValidationResult};
+
+    #[derive(Default)]
+    struct MockCalendarRepository {
+        weekends: HashMap<String, WeekendMask>,
+        holidays: Vec<BankHolidayModel>,
+    }
+
+    #[async_trait]
+    impl CalendarRepository for MockCalendarRepository {
+        async fn create_holiday(&self, _holiday: BankHolidayModel) -> BankingResult<BankHolidayModel> { unimplemented!() }
+        async fn update_holiday(&self, _holiday: BankHolidayModel) -> BankingResult<BankHolidayModel> { unimplemented!() }
+        async fn find_holiday_by_id(&self, _holiday_id: Uuid) -> BankingResult<Option<BankHolidayModel>> { unimplemented!() }
+        async fn find_holiday_by_date(&self, _date: NaiveDate, _jurisdiction: &str) -> BankingResult<Option<BankHolidayModel>> { unimplemented!() }
+        async fn find_holidays_by_jurisdiction(&self, _jurisdiction: &str) -> BankingResult<Vec<BankHolidayModel>> { unimplemented!() }
+        async fn find_holidays_by_type(&self, _holiday_type: &str) -> BankingResult<Vec<BankHolidayModel>> { unimplemented!() }
+        async fn find_holidays_in_range(&self, start_date: NaiveDate, end_date: NaiveDate, jurisdiction: &str) -> BankingResult<Vec<BankHolidayModel>> {
+            Ok(self
+                .holidays
+                .iter()
+                .filter(|h| h.jurisdiction.as_str() == jurisdiction && (start_date..=end_date).contains(&h.holiday_date))
+                .cloned()
+                .collect())
+        }
+        async fn find_holidays_by_year(&self, _year: i32, _jurisdiction: &str) -> BankingResult<Vec<BankHolidayModel>> { unimplemented!() }
+        async fn delete_holiday(&self, _holiday_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn is_holiday(&self, _date: NaiveDate, _jurisdiction: &str) -> BankingResult<bool> { unimplemented!() }
+        async fn is_weekend(&self, _date: NaiveDate, _jurisdiction: &str) -> BankingResult<bool> { unimplemented!() }
+        async fn is_business_day(&self, _date: NaiveDate, _jurisdiction: &str) -> BankingResult<bool> { unimplemented!() }
+        async fn next_business_day(&self, _date: NaiveDate, _jurisdiction: &str) -> BankingResult<NaiveDate> { unimplemented!() }
+        async fn previous_business_day(&self, _date: NaiveDate, _jurisdiction: &str) -> BankingResult<NaiveDate> { unimplemented!() }
+        async fn add_business_days(&self, _date: NaiveDate, _days: i32, _jurisdiction: &str) -> BankingResult<NaiveDate> { unimplemented!() }
+        async fn subtract_business_days(&self, _date: NaiveDate, _days: i32, _jurisdiction: &str) -> BankingResult<NaiveDate> { unimplemented!() }
+        async fn count_business_days(&self, _start_date: NaiveDate, _end_date: NaiveDate, _jurisdiction: &str) -> BankingResult<i32> { unimplemented!() }
+        async fn get_weekend_days(&self, jurisdiction: &str) -> BankingResult<Option<WeekendMask>> {
+            Ok(self.weekends.get(jurisdiction).copied())
+        }
+        async fn set_weekend_days(&self, _jurisdiction: &str, _weekend_days: WeekendMask) -> BankingResult<()> { unimplemented!() }
+        async fn find_recurring_holidays(&self, _jurisdiction: &str) -> BankingResult<Vec<BankHolidayModel>> { unimplemented!() }
+        async fn generate_recurring_holidays(&self, _year: i32, _jurisdiction: &str) -> BankingResult<Vec<BankHolidayModel>> { unimplemented!() }
+        async fn create_recurring_holidays_for_year(&self, _year: i32, _jurisdiction: &str) -> BankingResult<i64> { unimplemented!() }
+        async fn apply_date_shift_rule(&self, _date: NaiveDate, _jurisdiction: &str, _shift_rule: &str) -> BankingResult<NaiveDate> { unimplemented!() }
+        async fn get_maturity_date(&self, _start_date: NaiveDate, _term_months: i32, _jurisdiction: &str) -> BankingResult<NaiveDate> { unimplemented!() }
+        async fn get_payment_due_date(&self, _original_date: NaiveDate, _jurisdiction: &str, _product_id: Option<Uuid>) -> BankingResult<NaiveDate> { unimplemented!() }
+        async fn bulk_create_holidays(&self, _holidays: Vec<BankHolidayModel>) -> BankingResult<i64> { unimplemented!() }
+        async fn delete_holidays_by_year(&self, _year: i32, _jurisdiction: &str) -> BankingResult<i64> { unimplemented!() }
+        async fn cleanup_past_holidays(&self, _before_date: NaiveDate) -> BankingResult<i64> { unimplemented!() }
+        async fn get_supported_jurisdictions(&self) -> BankingResult<Vec<String>> { unimplemented!() }
+        async fn add_jurisdiction(&self, _jurisdiction: &str, _weekend_days: WeekendMask) -> BankingResult<()> { unimplemented!() }
+        async fn remove_jurisdiction(&self, _jurisdiction: &str) -> BankingResult<()> { unimplemented!() }
+        async fn validate_holiday_data(&self, _holidays: Vec<BankHolidayModel>) -> BankingResult<ValidationResult> { unimplemented!() }
+        async fn import_holidays_from_source(&self, _jurisdiction: &str, _year: i32, _source: &str) -> BankingResult<ImportResult> { unimplemented!() }
+        async fn get_calendar_summary(&self, _jurisdiction: &str, _year: i32) -> BankingResult<CalendarSummaryReport> { unimplemented!() }
+        async fn get_business_days_in_month(&self, _year: i32, _month: u32, _jurisdiction: &str) -> BankingResult<i32> { unimplemented!() }
+        async fn get_business_days_in_year(&self, _year: i32, _jurisdiction: &str) -> BankingResult<i32> { unimplemented!() }
+        async fn refresh_calendar_cache(&self, _jurisdiction: &str) -> BankingResult<()> { unimplemented!() }
+        async fn invalidate_calendar_cache(&self, _jurisdiction: &str) -> BankingResult<()> { unimplemented!() }
+        async fn holiday_exists(&self, _date: NaiveDate, _jurisdiction: &str) -> BankingResult<bool> { unimplemented!() }
+        async fn count_holidays(&self, _jurisdiction: &str) -> BankingResult<i64> { unimplemented!() }
+        async fn count_holidays_by_type(&self, _holiday_type: &str, _jurisdiction: &str) -> BankingResult<i64> { unimplemented!() }
+        async fn list_holidays(&self, _offset: i64, _limit: i64) -> BankingResult<Vec<BankHolidayModel>> { unimplemented!() }
+    }
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    fn holiday(jurisdiction: &str, holiday_date: NaiveDate) -> BankHolidayModel {
+        BankHolidayModel {
+            id: Uuid::new_v4(),
+            jurisdiction: heapless::String::try_from(jurisdiction).unwrap(),
+            holiday_date,
+            holiday_name: heapless::String::try_from("Regional holiday").unwrap(),
+            holiday_type: HolidayTypeModel::Regional,
+            is_recurring: false,
+            description: None,
+            created_by_person_id: Uuid::new_v4(),
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_friday_saturday_weekend_calendar() {
+        let mut repository = MockCalendarRepository::default();
+        repository.weekends.insert("AE".to_string(), WeekendMask::FRIDAY_SATURDAY);
+        let service = CalendarServiceImpl::new(Arc::new(repository));
+
+        // 2024-03-14 is a Thursday
+        let thursday = date(3, 14);
+        assert!(!service.is_business_day(date(3, 15), "AE").await.unwrap());
+        assert!(service.is_business_day(date(3, 17), "AE").await.unwrap());
+        assert_eq!(service.next_business_day(thursday, "AE").await.unwrap(), date(3, 17));
+
+        // Calendars without a weekend rule of their own keep Saturday/Sunday
+        assert!(service.is_business_day(date(3, 15), DEFAULT_CALENDAR).await.unwrap());
+        assert_eq!(service.next_business_day(date(3, 15), "CM").await.unwrap(), date(3, 18));
+    }
+
+    #[tokio::test]
+    async fn test_two_calendars_disagree_about_the_same_date() {
+        let regional_holiday = date(5, 14);
+        let repository = MockCalendarRepository {
+            holidays: vec![holiday("CM-NW", regional_holiday)],
+            ..Default::default()
+        };
+        let service = CalendarServiceImpl::new(Arc::new(repository));
+
+        assert!(!service.is_business_day(regional_holiday, "CM-NW").await.unwrap());
+        assert!(service.is_business_day(regional_holiday, "CM-LT").await.unwrap());
+        assert_eq!(service.next_business_day(date(5, 13), "CM-NW").await.unwrap(), date(5, 15));
+        assert_eq!(service.next_business_day(date(5, 13), "CM-LT").await.unwrap(), regional_holiday);
+    }
+
+    #[tokio::test]
+    async fn test_unconfigured_calendar_uses_default_weekend() {
+        let mut repository = MockCalendarRepository::default();
+        repository.weekends.insert(DEFAULT_CALENDAR.to_string(), WeekendMask::FRIDAY_SATURDAY);
+        let service = CalendarServiceImpl::new(Arc::new(repository));
+
+        assert!(service.is_weekend(date(3, 15), "CM-NW").await.unwrap());
+        assert!(!service.is_weekend(date(3, 17), "CM-NW").await.unwrap());
+    }
+}
@@ -0,0 +1,525 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        ChannelReconciliationResult, CurrencyCode, Discrepancy, DiscrepancyType, ExternalSettlementEntry,
+        ReconciliationReport, ReconciliationStatus,
+    },
+    service::ChannelReconciliationService,
+};
+use banking_db::models::TransactionModel;
+use banking_db::repository::{ChannelRepository, TransactionRepository};
+use crate::mappers::ChannelMapper;
+
+/// Production implementation of ChannelReconciliationService
+pub struct ChannelReconciliationServiceImpl {
+    channel_repository: Arc<dyn ChannelRepository>,
+    transaction_repository: Arc<dyn TransactionRepository>,
+}
+
+impl ChannelReconciliationServiceImpl {
+    pub fn new(
+        channel_repository: Arc<dyn ChannelRepository>,
+        transaction_repository: Arc<dyn TransactionRepository>,
+    ) -> Self {
+        Self { channel_repository, transaction_repository }
+    }
+}
+
+/// Decimal places of the transaction currency; two when the code is not ISO 4217
+fn minor_units(transaction: &TransactionModel) -> u32 {
+    CurrencyCode::try_from(transaction.currency.as_str()).map_or(2, |currency| currency.minor_units())
+}
+
+fn description(text: String) -> HeaplessString<200> {
+    let truncated: String = text.chars().take(200).collect();
+    HeaplessString::try_from(truncated.as_str()).unwrap_or_default()
+}
+
+/// Classify every disagreement between our transactions and the external
+/// entries. A reference listed again after its first entry is a duplicate,
+/// whatever its amount.
+fn find_discrepancies(
+    report_id: Uuid,
+    ours: &[TransactionModel],
+    theirs: &[ExternalSettlementEntry],
+) -> Vec<Discrepancy> {
+    let mut by_reference: HashMap<&str, &TransactionModel> = HashMap::new();
+    for transaction in ours {
+        by_reference.insert(transaction.reference_number.as_str(), transaction);
+    }
+    for transaction in ours {
+        if let Some(external_reference) = &transaction.external_reference {
+            by_reference.entry(external_reference.as_str()).or_insert(transaction);
+        }
+    }
+
+    let now = Utc::now();
+    let discrepancy = |discrepancy_type,
+                       transaction_id,
+                       external_reference: Option<&HeaplessString<100>>,
+                       text: String,
+                       expected_amount: Decimal,
+                       actual_amount: Decimal| Discrepancy {
+        id: Uuid::new_v4(),
+        report_id,
+        discrepancy_type,
+        transaction_id,
+        external_reference: external_reference.cloned(),
+        description: description(text),
+        expected_amount,
+        actual_amount,
+        difference: actual_amount - expected_amount,
+        resolved: false,
+        resolution_notes: None,
+        resolved_by: None,
+        resolved_at: None,
+        created_at: now,
+    };
+
+    let mut discrepancies = Vec::new();
+    let mut seen_references: HashSet<&str> = HashSet::new();
+    let mut matched: HashSet<Uuid> = HashSet::new();
+    for entry in theirs {
+        let reference = entry.reference.as_str();
+        let transaction = by_reference.get(reference).copied();
+        let actual_amount = transaction
+            .map_or(entry.amount, |t| entry.amount_in_major_units(t.amount, minor_units(t)));
+
+        let repeated = !seen_references.insert(reference) || transaction.is_some_and(|t| matched.contains(&t.id));
+        if repeated {
+            discrepancies.push(discrepancy(
+                DiscrepancyType::Duplicate,
+                transaction.map(|t| t.id),
+                Some(&entry.reference),
+                format!("External file lists {reference} more than once"),
+                Decimal::ZERO,
+                actual_amount,
+            ));
+            continue;
+        }
+        let Some(transaction) = transaction else {
+            discrepancies.push(discrepancy(
+                DiscrepancyType::MissingOurs,
+                None,
+                Some(&entry.reference),
+                format!("No channel transaction for external reference {reference}"),
+                Decimal::ZERO,
+                actual_amount,
+            ));
+            continue;
+        };
+        matched.insert(transaction.id);
+        if actual_amount != transaction.amount {
+            discrepancies.push(discrepancy(
+                DiscrepancyType::AmountMismatch,
+                Some(transaction.id),
+                Some(&entry.reference),
+                format!("Settled {actual_amount} for {reference}, posted {}", transaction.amount),
+                transaction.amount,
+                actual_amount,
+            ));
+        }
+    }
+
+    for transaction in ours.iter().filter(|t| !matched.contains(&t.id)) {
+        discrepancies.push(discrepancy(
+            DiscrepancyType::MissingTheirs,
+            Some(transaction.id),
+            None,
+            format!("Transaction {} missing from the external file", transaction.reference_number),
+            transaction.amount,
+            Decimal::ZERO,
+        ));
+    }
+    discrepancies
+}
+
+#[async_trait]
+impl ChannelReconciliationService for ChannelReconciliationServiceImpl {
+    async fn reconcile_channel(
+        &self,
+        channel_id: Uuid,
+        settlement_date: NaiveDate,
+        external_entries: Vec<ExternalSettlementEntry>,
+    ) -> BankingResult<ChannelReconciliationResult> {
+        let channel = self.channel_repository
+            .find_by_id(channel_id)
+            .await?
+            .ok_or_else(|| BankingError::NotFound(format!("Channel {channel_id}")))?;
+        // Transactions carry the channel code, not its id
+        let ours = self.transaction_repository
+            .find_for_reconciliation(channel.channel_code.as_str(), settlement_date)
+            .await?;
+
+        let now = Utc::now();
+        let report_id = Uuid::new_v4();
+        let discrepancies = find_discrepancies(report_id, &ours, &external_entries);
+        let (status, completed_at) = if discrepancies.is_empty() {
+            (ReconciliationStatus::Completed, Some(now))
+        } else {
+            (ReconciliationStatus::RequiresManualReview, None)
+        };
+        let report = ReconciliationReport {
+            id: report_id,
+            channel_id,
+            reconciliation_date: settlement_date,
+            total_transactions: ours.len() as i64,
+            total_amount: ours.iter().map(|t| t.amount).sum(),
+            status,
+            generated_at: now,
+            completed_at,
+            created_at: now,
+        };
+
+        let saved = self.channel_repository
+            .create_reconciliation_report(
+                ChannelMapper::to_reconciliation_report_model(report),
+                discrepancies.iter().cloned().map(ChannelMapper::to_discrepancy_model).collect(),
+            )
+            .await?;
+        tracing::info!(
+            "Reconciled channel {} for {settlement_date}: {} transactions, {} external entries, {} discrepancies",
+            channel.channel_code, ours.len(), external_entries.len(), discrepancies.len()
+        );
+
+        Ok(ChannelReconciliationResult {
+            report: ChannelMapper::from_reconciliation_report_model(saved),
+            discrepancies,
+        })
+    }
+
+    async fn get_unresolved_discrepancies(&self, channel_id: Uuid) -> BankingResult<Vec<Discrepancy>> {
+        let discrepancies = self.channel_repository.find_unresolved_discrepancies(channel_id).await?;
+        Ok(discrepancies.into_iter().map(ChannelMapper::from_discrepancy_model).collect())
+    }
+
+    async fn resolve_discrepancy(&self, discrepancy_id: Uuid, resolution: &str, resolved_by: Uuid) -> BankingResult<Discrepancy> {
+        if resolution.trim().is_empty() || resolution.len() > 500 {
+            return Err(BankingError::ValidationError {
+                field: "resolution".to_string(),
+                message: "Resolution must be between 1 and 500 characters".to_string(),
+            });
+        }
+        let resolved = self.channel_repository
+            .resolve_discrepancy(discrepancy_id, resolution, resolved_by, Utc::now())
+            .await?
+            .ok_or_else(|| BankingError::NotFound(format!("Unresolved discrepancy {discrepancy_id}")))?;
+        Ok(ChannelMapper::from_discrepancy_model(resolved))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use std::sync::Mutex;
+    use chrono::DateTime;
+    use banking_db::models::{
+        ApprovalWorkflowModel, TransactionSearchCriteriaModel, TransactionStatus, TransactionType,
+        workflow::WorkflowTransactionApprovalModel,
+    };
+    use banking_db::models::channel::{
+        ChannelLimitModel, ChannelModel, ChannelReconciliationReportModel, ChannelStatus, ChannelUsageModel,
+        FeeItemModel, FeeScheduleModel, FeeTierModel, ReconciliationDiscrepancyModel,
+    };
+    use banking_db::repository::ChannelStats;
+    use banking_db::ChannelType;
+
+    const CHANNEL_CODE: &str = "MOBILE";
+
+    #[derive(Default)]
+    struct MockChannelRepository {
+        channel_id: Uuid,
+        reports: Mutex<Vec<ChannelReconciliationReportModel>>,
+        discrepancies: Mutex<Vec<ReconciliationDiscrepancyModel>>,
+    }
+
+    #[async_trait]
+    impl ChannelRepository for MockChannelRepository {
+        async fn find_by_id(&self, channel_id: Uuid) -> BankingResult<Option<ChannelModel>> {
+            if channel_id != self.channel_id {
+                return Ok(None);
+            }
+            Ok(Some(ChannelModel {
+                id: channel_id,
+                channel_code: HeaplessString::try_from(CHANNEL_CODE).unwrap(),
+                channel_name: HeaplessString::try_from("Mobile banking").unwrap(),
+                channel_type: ChannelType::MobileApp,
+                status: ChannelStatus::Active,
+                daily_limit: None,
+                per_transaction_limit: None,
+                supported_currency01: Some(HeaplessString::try_from("USD").unwrap()),
+                supported_currency02: None,
+                supported_currency03: None,
+                requires_additional_auth: false,
+                fee_schedule_id: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            }))
+        }
+
+        async fn create_reconciliation_report(
+            &self,
+            report: ChannelReconciliationReportModel,
+            discrepancies: Vec<ReconciliationDiscrepancyModel>,
+        ) -> BankingResult<ChannelReconciliationReportModel> {
+            self.reports.lock().unwrap().push(report.clone());
+            self.discrepancies.lock().unwrap().extend(discrepancies);
+            Ok(report)
+        }
+
+        async fn find_reconciliation_report_by_id(&self, report_id: Uuid) -> BankingResult<Option<ChannelReconciliationReportModel>> {
+            Ok(self.reports.lock().unwrap().iter().find(|r| r.id == report_id).cloned())
+        }
+
+        async fn find_unresolved_discrepancies(&self, channel_id: Uuid) -> BankingResult<Vec<ReconciliationDiscrepancyModel>> {
+            let reports = self.reports.lock().unwrap();
+            Ok(self.discrepancies.lock().unwrap()
+                .iter()
+                .filter(|d| !d.resolved && reports.iter().any(|r| r.id == d.report_id && r.channel_id == channel_id))
+                .cloned()
+                .collect())
+        }
+
+        async fn resolve_discrepancy(
+            &self,
+            discrepancy_id: Uuid,
+            resolution_notes: &str,
+            resolved_by: Uuid,
+            resolved_at: DateTime<Utc>,
+        ) -> BankingResult<Option<ReconciliationDiscrepancyModel>> {
+            let mut discrepancies = self.discrepancies.lock().unwrap();
+            let Some(discrepancy) = discrepancies.iter_mut().find(|d| d.id == discrepancy_id && !d.resolved) else {
+                return Ok(None);
+            };
+            discrepancy.resolved = true;
+            discrepancy.resolution_notes = Some(HeaplessString::try_from(resolution_notes).unwrap());
+            discrepancy.resolved_by = Some(resolved_by);
+            discrepancy.resolved_at = Some(resolved_at);
+            Ok(Some(discrepancy.clone()))
+        }
+
+        async fn create(&self, _channel: ChannelModel) -> BankingResult<ChannelModel> { unimplemented!() }
+        async fn update(&self, _channel: ChannelModel) -> BankingResult<ChannelModel> { unimplemented!() }
+        async fn find_by_code(&self, _channel_code: &str) -> BankingResult<Option<ChannelModel>> { unimplemented!() }
+        async fn find_by_type(&self, _channel_type: ChannelType) -> BankingResult<Vec<ChannelModel>> { unimplemented!() }
+        async fn find_active(&self) -> BankingResult<Vec<ChannelModel>> { unimplemented!() }
+        async fn update_status(&self, _channel_id: Uuid, _status: ChannelStatus) -> BankingResult<()> { unimplemented!() }
+        async fn exists(&self, _channel_id: Uuid) -> BankingResult<bool> { unimplemented!() }
+        async fn find_by_currency(&self, _currency: &str) -> BankingResult<Vec<ChannelModel>> { unimplemented!() }
+        async fn get_channel_stats(&self, _channel_id: Uuid) -> BankingResult<ChannelStats> { unimplemented!() }
+        async fn soft_delete(&self, _channel_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn find_all_paginated(&self, _limit: i64, _offset: i64) -> BankingResult<Vec<ChannelModel>> { unimplemented!() }
+        async fn count_all(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn upsert_limit(&self, _limit: ChannelLimitModel) -> BankingResult<ChannelLimitModel> { unimplemented!() }
+        async fn find_effective_limit(&self, _channel_id: &str, _currency: &str) -> BankingResult<Option<ChannelLimitModel>> { unimplemented!() }
+        async fn get_owner_channel_usage(
+            &self,
+            _account_id: Uuid,
+            _channel_id: &str,
+            _currency: &str,
+            _month_start: DateTime<Utc>,
+            _day_start: DateTime<Utc>,
+            _day_end: DateTime<Utc>,
+        ) -> BankingResult<ChannelUsageModel> { unimplemented!() }
+        async fn save_fee_schedule(&self, _schedule: FeeScheduleModel) -> BankingResult<FeeScheduleModel> { unimplemented!() }
+        async fn find_fee_schedule_by_id(&self, _schedule_id: Uuid) -> BankingResult<Option<FeeScheduleModel>> { unimplemented!() }
+        async fn save_fee_item(&self, _item: FeeItemModel, _tiers: Vec<FeeTierModel>) -> BankingResult<FeeItemModel> { unimplemented!() }
+        async fn find_fee_item_by_id(&self, _fee_item_id: Uuid) -> BankingResult<Option<FeeItemModel>> { unimplemented!() }
+        async fn find_fee_items_by_schedule(&self, _schedule_id: Uuid) -> BankingResult<Vec<FeeItemModel>> { unimplemented!() }
+        async fn find_fee_tiers(&self, _fee_item_id: Uuid) -> BankingResult<Vec<FeeTierModel>> { unimplemented!() }
+    }
+
+    struct MockTransactionRepository {
+        transactions: Vec<TransactionModel>,
+    }
+
+    #[async_trait]
+    impl TransactionRepository for MockTransactionRepository {
+        async fn find_for_reconciliation(&self, channel_id: &str, date: NaiveDate) -> BankingResult<Vec<TransactionModel>> {
+            Ok(self.transactions.iter().filter(|t| t.channel_id.as_str() == channel_id && t.value_date == date).cloned().collect())
+        }
+        async fn create(&self, _transaction: TransactionModel) -> BankingResult<TransactionModel> { todo!() }
+        async fn post_transaction(&self, _transaction: TransactionModel) -> BankingResult<TransactionModel> { todo!() }
+        async fn post_approved_transaction(&self, _transaction_id: Uuid) -> BankingResult<Option<TransactionModel>> { todo!() }
+        async fn update(&self, _transaction: TransactionModel) -> BankingResult<TransactionModel> { todo!() }
+        async fn find_by_id(&self, _transaction_id: Uuid) -> BankingResult<Option<TransactionModel>> { todo!() }
+        async fn find_by_account_id(&self, _account_id: Uuid, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_by_account_date_range(&self, _account_id: Uuid, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn search(&self, _criteria: TransactionSearchCriteriaModel) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_by_reference(&self, _reference_number: &str) -> BankingResult<Option<TransactionModel>> { todo!() }
+        async fn find_by_idempotency_key(&self, _channel_id: &str, _idempotency_key: &str) -> BankingResult<Option<TransactionModel>> { todo!() }
+        async fn find_by_external_reference(&self, _external_reference: &str) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_by_status(&self, _status: &str) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_requiring_approval(&self) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_by_terminal_id(&self, _terminal_id: Uuid, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_by_agent_person_id(&self, _agent_person_id: Uuid, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_by_channel(&self, _channel_id: &str, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn update_status(&self, _transaction_id: Uuid, _status: &str, _reason: &str) -> BankingResult<()> { todo!() }
+        async fn update_approval_status(&self, _transaction_id: Uuid, _approval_status: &str) -> BankingResult<()> { todo!() }
+        async fn find_last_customer_transaction(&self, _account_id: Uuid) -> BankingResult<Option<TransactionModel>> { todo!() }
+        async fn calculate_daily_volume_by_terminal(&self, _terminal_id: Uuid, _date: NaiveDate) -> BankingResult<Decimal> { todo!() }
+        async fn calculate_daily_volume_by_branch(&self, _branch_id: Uuid, _date: NaiveDate) -> BankingResult<Decimal> { todo!() }
+        async fn calculate_daily_volume_by_network(&self, _network_id: Uuid, _date: NaiveDate) -> BankingResult<Decimal> { todo!() }
+        async fn reverse_transaction(&self, _original_transaction_id: Uuid, _reversal_transaction: TransactionModel) -> BankingResult<TransactionModel> { todo!() }
+        async fn create_workflow(&self, _workflow: ApprovalWorkflowModel) -> BankingResult<ApprovalWorkflowModel> { todo!() }
+        async fn find_workflow_by_id(&self, _workflow_id: Uuid) -> BankingResult<Option<ApprovalWorkflowModel>> { todo!() }
+        async fn find_workflow_by_transaction(&self, _transaction_id: Uuid) -> BankingResult<Option<ApprovalWorkflowModel>> { todo!() }
+        async fn update_workflow_status(&self, _workflow_id: Uuid, _status: &str) -> BankingResult<()> { todo!() }
+        async fn find_pending_workflows(&self) -> BankingResult<Vec<ApprovalWorkflowModel>> { todo!() }
+        async fn find_expired_workflows(&self, _reference_time: DateTime<Utc>) -> BankingResult<Vec<ApprovalWorkflowModel>> { todo!() }
+        async fn create_approval(&self, _approval: WorkflowTransactionApprovalModel) -> BankingResult<WorkflowTransactionApprovalModel> { todo!() }
+        async fn find_approvals_by_workflow(&self, _workflow_id: Uuid) -> BankingResult<Vec<WorkflowTransactionApprovalModel>> { todo!() }
+        async fn find_approvals_by_approver(&self, _approver_person_id: Uuid) -> BankingResult<Vec<WorkflowTransactionApprovalModel>> { todo!() }
+        async fn count_approvals_for_workflow(&self, _workflow_id: Uuid) -> BankingResult<i64> { todo!() }
+        async fn exists(&self, _transaction_id: Uuid) -> BankingResult<bool> { todo!() }
+        async fn count_by_account(&self, _account_id: Uuid, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<i64> { todo!() }
+        async fn list(&self, _offset: i64, _limit: i64) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn count(&self) -> BankingResult<i64> { todo!() }
+        async fn find_degraded(&self, _flags_mask: i32, _limit: i64) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn update_degraded_flags(&self, _transaction_id: Uuid, _degraded_flags: i32) -> BankingResult<()> { todo!() }
+    }
+
+    fn settlement_date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, 28).unwrap()
+    }
+
+    fn transaction(reference: &str, amount: &str) -> TransactionModel {
+        TransactionModel {
+            id: Uuid::new_v4(),
+            account_id: Uuid::new_v4(),
+            transaction_code: HeaplessString::try_from("MOBDR").unwrap(),
+            transaction_type: TransactionType::Debit,
+            amount: Decimal::from_str(amount).unwrap(),
+            currency: HeaplessString::try_from("USD").unwrap(),
+            description: HeaplessString::try_from("Mobile transfer").unwrap(),
+            channel_id: HeaplessString::try_from(CHANNEL_CODE).unwrap(),
+            terminal_id: None,
+            agent_person_id: None,
+            transaction_date: Utc::now(),
+            value_date: settlement_date(),
+            status: TransactionStatus::Posted,
+            reference_number: HeaplessString::try_from(reference).unwrap(),
+            external_reference: None,
+            gl_code: HeaplessString::try_from("2100").unwrap(),
+            requires_approval: false,
+            approval_status: None,
+            risk_score: None,
+            degraded_flags: 0,
+            idempotency_key: None,
+            reversal_of_transaction_id: None,
+            original_amount: None,
+            original_currency: None,
+            exchange_rate: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// The settlement file lists amounts in cents
+    fn entry(reference: &str, amount_in_cents: i64) -> ExternalSettlementEntry {
+        ExternalSettlementEntry {
+            reference: HeaplessString::try_from(reference).unwrap(),
+            amount: Decimal::from(amount_in_cents),
+        }
+    }
+
+    fn service(transactions: Vec<TransactionModel>) -> (ChannelReconciliationServiceImpl, Uuid) {
+        let channel_id = Uuid::new_v4();
+        let channels = MockChannelRepository { channel_id, ..Default::default() };
+        let service = ChannelReconciliationServiceImpl::new(
+            Arc::new(channels),
+            Arc::new(MockTransactionRepository { transactions }),
+        );
+        (service, channel_id)
+    }
+
+    #[tokio::test]
+    async fn test_each_mismatch_class_is_reported_and_resolvable() {
+        let matched = transaction("MOB-001", "100.00");
+        let short_settled = transaction("MOB-002", "250.00");
+        let repeated = transaction("MOB-003", "75.50");
+        let unsettled = transaction("MOB-004", "40.00");
+        let (service, channel_id) = service(vec![
+            matched.clone(),
+            short_settled.clone(),
+            repeated.clone(),
+            unsettled.clone(),
+        ]);
+
+        let result = service
+            .reconcile_channel(channel_id, settlement_date(), vec![
+                entry("MOB-001", 10_000),
+                entry("MOB-002", 24_000),
+                entry("MOB-003", 7_550),
+                entry("MOB-003", 7_550),
+                entry("EXT-999", 5_000),
+            ])
+            .await
+            .unwrap();
+
+        let report = &result.report;
+        assert_eq!(report.status, ReconciliationStatus::RequiresManualReview);
+        assert!(report.completed_at.is_none());
+        assert_eq!(report.total_transactions, 4);
+        assert_eq!(report.total_amount, Decimal::from_str("465.50").unwrap());
+
+        let find = |discrepancy_type| {
+            let found: Vec<&Discrepancy> = result.discrepancies.iter().filter(|d| d.discrepancy_type == discrepancy_type).collect();
+            assert_eq!(found.len(), 1, "{discrepancy_type:?}");
+            found[0]
+        };
+        assert_eq!(result.discrepancies.len(), 4);
+        let mismatch = find(DiscrepancyType::AmountMismatch);
+        assert_eq!(mismatch.transaction_id, Some(short_settled.id));
+        assert_eq!(mismatch.expected_amount, Decimal::from(250));
+        assert_eq!(mismatch.actual_amount, Decimal::from(240));
+        assert_eq!(mismatch.difference, Decimal::from(-10));
+        let duplicate = find(DiscrepancyType::Duplicate);
+        assert_eq!(duplicate.transaction_id, Some(repeated.id));
+        assert_eq!(duplicate.actual_amount, Decimal::from_str("75.50").unwrap());
+        let missing_ours = find(DiscrepancyType::MissingOurs);
+        assert_eq!(missing_ours.transaction_id, None);
+        assert_eq!(missing_ours.external_reference.as_ref().map(|r| r.as_str()), Some("EXT-999"));
+        let missing_theirs = find(DiscrepancyType::MissingTheirs);
+        assert_eq!(missing_theirs.transaction_id, Some(unsettled.id));
+        assert_eq!(missing_theirs.difference, Decimal::from(-40));
+        assert!(result.discrepancies.iter().all(|d| d.transaction_id != Some(matched.id)));
+
+        let resolver = Uuid::new_v4();
+        let resolved = service.resolve_discrepancy(duplicate.id, "Network resent the line", resolver).await.unwrap();
+        assert!(resolved.resolved);
+        assert_eq!(resolved.resolved_by, Some(resolver));
+        assert!(matches!(
+            service.resolve_discrepancy(duplicate.id, "Again", resolver).await,
+            Err(BankingError::NotFound(_))
+        ));
+        assert_eq!(service.get_unresolved_discrepancies(channel_id).await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_clean_settlement_completes_report() {
+        let (service, channel_id) = service(vec![transaction("MOB-001", "100.00"), transaction("MOB-002", "0.99")]);
+
+        let result = service
+            .reconcile_channel(channel_id, settlement_date(), vec![entry("MOB-002", 99), entry("MOB-001", 10_000)])
+            .await
+            .unwrap();
+
+        assert!(result.discrepancies.is_empty());
+        assert_eq!(result.report.status, ReconciliationStatus::Completed);
+        assert!(result.report.completed_at.is_some());
+        assert!(service.get_unresolved_discrepancies(channel_id).await.unwrap().is_empty());
+        assert!(matches!(
+            service.reconcile_channel(Uuid::new_v4(), settlement_date(), vec![]).await,
+            Err(BankingError::NotFound(_))
+        ));
+    }
+}
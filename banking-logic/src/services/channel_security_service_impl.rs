@@ -0,0 +1,589 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use heapless::String as HeaplessString;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        AlertStatus, ChannelRestriction, ChannelSecurityEvent, ComplianceAlert, ComplianceAlertType,
+        SecurityEventOutcome, SecurityEventRetention, SecurityVelocityRule,
+    },
+    service::{ChannelSecurityService, ComplianceService},
+};
+use banking_db::repository::ChannelSecurityRepository;
+use crate::mappers::ChannelSecurityMapper;
+
+/// Production implementation of ChannelSecurityService
+pub struct ChannelSecurityServiceImpl {
+    channel_security_repository: Arc<dyn ChannelSecurityRepository>,
+    compliance_service: Arc<dyn ComplianceService>,
+    retention: SecurityEventRetention,
+}
+
+impl ChannelSecurityServiceImpl {
+    pub fn new(
+        channel_security_repository: Arc<dyn ChannelSecurityRepository>,
+        compliance_service: Arc<dyn ComplianceService>,
+    ) -> Self {
+        Self {
+            channel_security_repository,
+            compliance_service,
+            retention: SecurityEventRetention::default(),
+        }
+    }
+
+    pub fn with_retention(mut self, retention: SecurityEventRetention) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    async fn raise_velocity_alert(
+        &self,
+        rule: &SecurityVelocityRule,
+        event: &ChannelSecurityEvent,
+        customer_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> BankingResult<Option<Uuid>> {
+        let Some(severity) = rule.alert_severity.clone() else {
+            return Ok(None);
+        };
+        let description = format!(
+            "{} {:?} events within {} minutes on channel {}",
+            rule.threshold, rule.event_type, rule.window_minutes, event.channel_id
+        );
+        let metadata = serde_json::json!({
+            "rule_code": rule.code.as_str(),
+            "channel_id": event.channel_id,
+            "security_event_id": event.id,
+        })
+        .to_string();
+
+        let alert = self.compliance_service
+            .raise_alert(ComplianceAlert {
+                id: Uuid::new_v4(),
+                customer_id: Some(customer_id),
+                account_id: None,
+                transaction_id: None,
+                alert_type: ComplianceAlertType::VelocityCheck,
+                description: HeaplessString::try_from(description.as_str()).unwrap_or_default(),
+                severity,
+                triggered_at: now,
+                status: AlertStatus::New,
+                assigned_to_person_id: None,
+                resolved_at: None,
+                resolved_by_person_id: None,
+                resolution_notes: None,
+                metadata: HeaplessString::try_from(metadata.as_str()).ok(),
+                created_at: now,
+                last_updated_at: now,
+            })
+            .await?;
+        Ok(Some(alert.id))
+    }
+
+    async fn impose_restriction(
+        &self,
+        rule: &SecurityVelocityRule,
+        event: &ChannelSecurityEvent,
+        customer_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> BankingResult<Option<ChannelRestriction>> {
+        let Some(restriction_minutes) = rule.restriction_minutes else {
+            return Ok(None);
+        };
+        let reason = format!("Security rule {} fired on {:?} events", rule.code, rule.event_type);
+
+        let restriction = ChannelRestriction {
+            id: Uuid::new_v4(),
+            customer_id,
+            channel_id: event.channel_id,
+            reason: HeaplessString::try_from(reason.as_str()).unwrap_or_default(),
+            rule_code: Some(rule.code.clone()),
+            starts_at: now,
+            expires_at: Some(now + Duration::minutes(restriction_minutes as i64)),
+            lifted_at: None,
+            lifted_by_person_id: None,
+            created_at: now,
+        };
+        let created = self.channel_security_repository
+            .create_restriction(ChannelSecurityMapper::restriction_to_model(restriction))
+            .await?;
+        Ok(Some(ChannelSecurityMapper::restriction_from_model(created)))
+    }
+}
+
+#[async_trait]
+impl ChannelSecurityService for ChannelSecurityServiceImpl {
+    async fn record_security_event(&self, mut event: ChannelSecurityEvent) -> BankingResult<SecurityEventOutcome> {
+        let now = Utc::now();
+        event.recorded_at = now;
+        let created = self.channel_security_repository
+            .create_event(ChannelSecurityMapper::event_to_model(event))
+            .await?;
+        let event = ChannelSecurityMapper::event_from_model(created)?;
+
+        let mut outcome = SecurityEventOutcome {
+            event,
+            fired_rule_codes: Vec::new(),
+            compliance_alert_ids: Vec::new(),
+            restrictions: Vec::new(),
+        };
+        // Velocity is tracked per customer; anonymous events are only stored
+        let Some(customer_id) = outcome.event.customer_id else {
+            return Ok(outcome);
+        };
+        let rules = self.compliance_service.get_monitoring_rules().await?;
+        if !rules.velocity_checks {
+            return Ok(outcome);
+        }
+
+        for rule in rules.security_velocity_rules.iter().filter(|r| r.event_type == outcome.event.event_type) {
+            if let Err(message) = rule.validate() {
+                tracing::warn!("Skipping invalid security velocity rule {}: {message}", rule.code);
+                continue;
+            }
+            let events_in_window = self.channel_security_repository
+                .count_customer_events(
+                    customer_id,
+                    ChannelSecurityMapper::event_type_to_db(rule.event_type),
+                    rule.window_start(outcome.event.occurred_at),
+                    outcome.event.occurred_at,
+                )
+                .await?;
+            if !rule.fires_on(events_in_window) {
+                continue;
+            }
+
+            outcome.fired_rule_codes.push(rule.code.clone());
+            if let Some(alert_id) = self.raise_velocity_alert(rule, &outcome.event, customer_id, now).await? {
+                outcome.compliance_alert_ids.push(alert_id);
+            }
+            if let Some(restriction) = self.impose_restriction(rule, &outcome.event, customer_id, now).await? {
+                outcome.restrictions.push(restriction);
+            }
+        }
+
+        Ok(outcome)
+    }
+
+    async fn find_recent_events_for_customer(&self, customer_id: Uuid, limit: i64) -> BankingResult<Vec<ChannelSecurityEvent>> {
+        self.channel_security_repository
+            .find_recent_events_for_customer(customer_id, limit)
+            .await?
+            .into_iter()
+            .map(ChannelSecurityMapper::event_from_model)
+            .collect()
+    }
+
+    async fn restrict_channel(
+        &self,
+        customer_id: Uuid,
+        channel_id: Uuid,
+        reason: HeaplessString<255>,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> BankingResult<ChannelRestriction> {
+        let now = Utc::now();
+        if expires_at.is_some_and(|expires_at| expires_at <= now) {
+            return Err(BankingError::ValidationError {
+                field: "expires_at".to_string(),
+                message: "Restriction must expire in the future".to_string(),
+            });
+        }
+
+        let restriction = ChannelRestriction {
+            id: Uuid::new_v4(),
+            customer_id,
+            channel_id,
+            reason,
+            rule_code: None,
+            starts_at: now,
+            expires_at,
+            lifted_at: None,
+            lifted_by_person_id: None,
+            created_at: now,
+        };
+        let created = self.channel_security_repository
+            .create_restriction(ChannelSecurityMapper::restriction_to_model(restriction))
+            .await?;
+        Ok(ChannelSecurityMapper::restriction_from_model(created))
+    }
+
+    async fn lift_restriction(&self, restriction_id: Uuid, lifted_by_person_id: Uuid) -> BankingResult<ChannelRestriction> {
+        let mut restriction = self.channel_security_repository
+            .find_restriction_by_id(restriction_id)
+            .await?
+            .map(ChannelSecurityMapper::restriction_from_model)
+            .ok_or_else(|| BankingError::NotFound(format!("Channel restriction {restriction_id} not found")))?;
+        if restriction.lifted_at.is_some() {
+            return Err(BankingError::ValidationError {
+                field: "restriction_id".to_string(),
+                message: format!("Channel restriction {restriction_id} is already lifted"),
+            });
+        }
+
+        restriction.lifted_at = Some(Utc::now());
+        restriction.lifted_by_person_id = Some(lifted_by_person_id);
+        let updated = self.channel_security_repository
+            .update_restriction(ChannelSecurityMapper::restriction_to_model(restriction))
+            .await?;
+        Ok(ChannelSecurityMapper::restriction_from_model(updated))
+    }
+
+    async fn find_active_restrictions(&self, customer_id: Uuid, as_of: DateTime<Utc>) -> BankingResult<Vec<ChannelRestriction>> {
+        let restrictions = self.channel_security_repository
+            .find_active_restrictions(customer_id, as_of)
+            .await?;
+        Ok(restrictions.into_iter().map(ChannelSecurityMapper::restriction_from_model).collect())
+    }
+
+    async fn is_channel_restricted(&self, customer_id: Uuid, channel_id: Uuid, as_of: DateTime<Utc>) -> BankingResult<bool> {
+        let restrictions = self.find_active_restrictions(customer_id, as_of).await?;
+        Ok(restrictions.iter().any(|r| r.channel_id == channel_id && r.is_active_at(as_of)))
+    }
+
+    async fn purge_expired_events(&self, as_of: DateTime<Utc>) -> BankingResult<u64> {
+        let deleted = self.channel_security_repository
+            .delete_events_before(self.retention.cutoff(as_of))
+            .await?;
+        tracing::info!("Purged {deleted} channel security events past retention");
+        Ok(deleted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use banking_api::{
+        Customer, Transaction,
+        domain::{
+            ChannelSecurityEventType, KycResult, MonitoringResult, MonitoringRules, RiskRatingRecalculation,
+            SarData, SarStatus, ScreeningResult, Severity, UboVerificationResult, VerificationStatus,
+        },
+        service::{ComplianceReport, EnhancedDueDiligenceResult, RiskRecalculationSummary, UboStructureReport},
+    };
+    use banking_db::models::{ChannelRestrictionModel, ChannelSecurityEventModel, DbChannelSecurityEventType};
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+
+    #[derive(Default)]
+    struct MockChannelSecurityRepository {
+        events: Mutex<Vec<ChannelSecurityEventModel>>,
+        restrictions: Mutex<Vec<ChannelRestrictionModel>>,
+    }
+
+    #[async_trait]
+    impl ChannelSecurityRepository for MockChannelSecurityRepository {
+        async fn create_event(&self, event: ChannelSecurityEventModel) -> BankingResult<ChannelSecurityEventModel> {
+            self.events.lock().unwrap().push(event.clone());
+            Ok(event)
+        }
+
+        async fn count_customer_events(
+            &self,
+            customer_id: Uuid,
+            event_type: DbChannelSecurityEventType,
+            from: DateTime<Utc>,
+            to: DateTime<Utc>,
+        ) -> BankingResult<u64> {
+            Ok(self.events.lock().unwrap()
+                .iter()
+                .filter(|e| e.customer_id == Some(customer_id) && e.event_type == event_type)
+                .filter(|e| e.occurred_at > from && e.occurred_at <= to)
+                .count() as u64)
+        }
+
+        async fn find_recent_events_for_customer(&self, customer_id: Uuid, limit: i64) -> BankingResult<Vec<ChannelSecurityEventModel>> {
+            let mut events: Vec<ChannelSecurityEventModel> = self.events.lock().unwrap()
+                .iter()
+                .filter(|e| e.customer_id == Some(customer_id))
+                .cloned()
+                .collect();
+            events.sort_by_key(|e| std::cmp::Reverse(e.occurred_at));
+            events.truncate(limit as usize);
+            Ok(events)
+        }
+
+        async fn delete_events_before(&self, cutoff: DateTime<Utc>) -> BankingResult<u64> {
+            let mut events = self.events.lock().unwrap();
+            let before = events.len();
+            events.retain(|e| e.occurred_at >= cutoff);
+            Ok((before - events.len()) as u64)
+        }
+
+        async fn create_restriction(&self, restriction: ChannelRestrictionModel) -> BankingResult<ChannelRestrictionModel> {
+            self.restrictions.lock().unwrap().push(restriction.clone());
+            Ok(restriction)
+        }
+
+        async fn find_restriction_by_id(&self, restriction_id: Uuid) -> BankingResult<Option<ChannelRestrictionModel>> {
+            Ok(self.restrictions.lock().unwrap().iter().find(|r| r.id == restriction_id).cloned())
+        }
+
+        async fn update_restriction(&self, restriction: ChannelRestrictionModel) -> BankingResult<ChannelRestrictionModel> {
+            let mut restrictions = self.restrictions.lock().unwrap();
+            let existing = restrictions.iter_mut().find(|r| r.id == restriction.id).unwrap();
+            *existing = restriction.clone();
+            Ok(restriction)
+        }
+
+        async fn find_active_restrictions(&self, customer_id: Uuid, as_of: DateTime<Utc>) -> BankingResult<Vec<ChannelRestrictionModel>> {
+            Ok(self.restrictions.lock().unwrap()
+                .iter()
+                .filter(|r| r.customer_id == customer_id && r.lifted_at.is_none() && r.starts_at <= as_of)
+                .filter(|r| r.expires_at.is_none_or(|expires_at| expires_at > as_of))
+                .cloned()
+                .collect())
+        }
+    }
+
+    /// Compliance service holding the failed authentication rule and recording raised alerts
+    #[derive(Default)]
+    struct MockComplianceService {
+        alerts: Mutex<Vec<ComplianceAlert>>,
+    }
+
+    #[async_trait]
+    impl ComplianceService for MockComplianceService {
+        async fn perform_kyc_check(&self, _customer: &Customer) -> BankingResult<KycResult> {
+            unimplemented!()
+        }
+
+        async fn screen_against_sanctions(&self, _customer: &Customer) -> BankingResult<ScreeningResult> {
+            unimplemented!()
+        }
+
+        async fn monitor_transaction(&self, _transaction: &Transaction) -> BankingResult<MonitoringResult> {
+            unimplemented!()
+        }
+
+        async fn generate_sar_data(&self, _customer_id: Uuid, _reason_id: Uuid, _additional_details: Option<HeaplessString<500>>) -> BankingResult<SarData> {
+            unimplemented!()
+        }
+
+        #[allow(deprecated)]
+        async fn generate_sar_data_legacy(&self, _customer_id: Uuid, _reason: String) -> BankingResult<SarData> {
+            unimplemented!()
+        }
+
+        async fn create_sar_from_alert(&self, _alert_id: Uuid, _narrative: HeaplessString<1000>, _created_by: Uuid) -> BankingResult<SarData> {
+            unimplemented!()
+        }
+
+        async fn add_alert_to_sar(&self, _sar_id: Uuid, _alert_id: Uuid, _updated_by: Uuid) -> BankingResult<SarData> {
+            unimplemented!()
+        }
+
+        async fn update_sar_narrative(&self, _sar_id: Uuid, _narrative: HeaplessString<1000>) -> BankingResult<SarData> {
+            unimplemented!()
+        }
+
+        async fn submit_sar(&self, _sar_id: Uuid, _submitted_by: Uuid) -> BankingResult<SarData> {
+            unimplemented!()
+        }
+
+        async fn list_sars(&self, _status: Option<SarStatus>, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<Vec<SarData>> {
+            unimplemented!()
+        }
+
+        async fn verify_ubo_chain(&self, _corporate_customer_id: Uuid) -> BankingResult<UboVerificationResult> {
+            unimplemented!()
+        }
+
+        async fn update_ubo_status(&self, _ubo_link_id: Uuid, _status: VerificationStatus) -> BankingResult<()> {
+            unimplemented!()
+        }
+
+        async fn validate_ubo_structure(&self, _corporate_customer_id: Uuid) -> BankingResult<UboStructureReport> {
+            unimplemented!()
+        }
+
+        async fn batch_screen_customers(&self, _customer_ids: Vec<Uuid>) -> BankingResult<Vec<ScreeningResult>> {
+            unimplemented!()
+        }
+
+        async fn get_pending_compliance_alerts(&self) -> BankingResult<Vec<ComplianceAlert>> {
+            Ok(self.alerts.lock().unwrap().clone())
+        }
+
+        async fn raise_alert(&self, alert: ComplianceAlert) -> BankingResult<ComplianceAlert> {
+            self.alerts.lock().unwrap().push(alert.clone());
+            Ok(alert)
+        }
+
+        async fn update_alert_status(&self, _alert_id: Uuid, _status: AlertStatus, _updated_by_person_id: Uuid) -> BankingResult<()> {
+            unimplemented!()
+        }
+
+        async fn generate_compliance_report(&self, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<ComplianceReport> {
+            unimplemented!()
+        }
+
+        async fn requires_enhanced_due_diligence(&self, _customer_id: Uuid) -> BankingResult<bool> {
+            unimplemented!()
+        }
+
+        async fn perform_enhanced_due_diligence(&self, _customer_id: Uuid) -> BankingResult<EnhancedDueDiligenceResult> {
+            unimplemented!()
+        }
+
+        async fn update_risk_profile(&self, _customer_id: Uuid, _risk_factors: Vec<HeaplessString<100>>) -> BankingResult<()> {
+            unimplemented!()
+        }
+
+        async fn recalculate_risk_rating(&self, _customer_id: Uuid) -> BankingResult<RiskRatingRecalculation> {
+            unimplemented!()
+        }
+
+        async fn recalculate_all_due(&self, _review_interval_days: i64) -> BankingResult<RiskRecalculationSummary> {
+            unimplemented!()
+        }
+
+        async fn get_monitoring_rules(&self) -> BankingResult<MonitoringRules> {
+            Ok(MonitoringRules {
+                structuring_detection: true,
+                velocity_checks: true,
+                geographic_risk_assessment: true,
+                large_cash_threshold: Decimal::from(10000),
+                suspicious_pattern_detection: true,
+                cross_border_transaction_monitoring: true,
+                security_velocity_rules: vec![SecurityVelocityRule {
+                    code: HeaplessString::try_from("FAILED_AUTH_BURST").unwrap(),
+                    event_type: ChannelSecurityEventType::FailedAuth,
+                    threshold: 5,
+                    window_minutes: 10,
+                    alert_severity: Some(Severity::Medium),
+                    restriction_minutes: Some(30),
+                    is_active: true,
+                }],
+            })
+        }
+
+        async fn update_monitoring_rules(&self, _rules: MonitoringRules) -> BankingResult<()> {
+            unimplemented!()
+        }
+    }
+
+    fn failed_auth(customer_id: Uuid, channel_id: Uuid, occurred_at: DateTime<Utc>) -> ChannelSecurityEvent {
+        ChannelSecurityEvent {
+            id: Uuid::new_v4(),
+            channel_id,
+            customer_id: Some(customer_id),
+            event_type: ChannelSecurityEventType::FailedAuth,
+            severity: Severity::Low,
+            context: Some(serde_json::json!({ "reason": "WrongPin" })),
+            occurred_at,
+            recorded_at: occurred_at,
+        }
+    }
+
+    fn setup() -> (Arc<MockComplianceService>, ChannelSecurityServiceImpl) {
+        let compliance_service = Arc::new(MockComplianceService::default());
+        let service = ChannelSecurityServiceImpl::new(
+            Arc::new(MockChannelSecurityRepository::default()),
+            compliance_service.clone(),
+        );
+        (compliance_service, service)
+    }
+
+    #[tokio::test]
+    async fn test_fifth_failed_auth_within_window_raises_alert_and_restricts() {
+        let (compliance_service, service) = setup();
+        let customer_id = Uuid::new_v4();
+        let channel_id = Uuid::new_v4();
+        let start = Utc::now() - Duration::minutes(9);
+
+        // An attempt outside the window and one by another customer do not count
+        service.record_security_event(failed_auth(customer_id, channel_id, start - Duration::minutes(15))).await.unwrap();
+        service.record_security_event(failed_auth(Uuid::new_v4(), channel_id, start)).await.unwrap();
+        for minute in 0..4 {
+            let outcome = service
+                .record_security_event(failed_auth(customer_id, channel_id, start + Duration::minutes(minute)))
+                .await
+                .unwrap();
+            assert!(outcome.fired_rule_codes.is_empty());
+        }
+        assert!(compliance_service.alerts.lock().unwrap().is_empty());
+
+        let outcome = service
+            .record_security_event(failed_auth(customer_id, channel_id, start + Duration::minutes(8)))
+            .await
+            .unwrap();
+        assert_eq!(outcome.fired_rule_codes.len(), 1);
+        assert_eq!(outcome.compliance_alert_ids.len(), 1);
+        assert_eq!(outcome.restrictions.len(), 1);
+        let alerts = compliance_service.alerts.lock().unwrap().clone();
+        assert_eq!(alerts.len(), 1);
+        assert!(matches!(alerts[0].alert_type, ComplianceAlertType::VelocityCheck));
+        assert_eq!(alerts[0].customer_id, Some(customer_id));
+
+        // A further attempt in the same burst does not fire again
+        let outcome = service
+            .record_security_event(failed_auth(customer_id, channel_id, start + Duration::minutes(9)))
+            .await
+            .unwrap();
+        assert!(outcome.fired_rule_codes.is_empty());
+        assert_eq!(compliance_service.alerts.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_velocity_restriction_expires_automatically() {
+        let (_, service) = setup();
+        let customer_id = Uuid::new_v4();
+        let channel_id = Uuid::new_v4();
+        let other_channel_id = Uuid::new_v4();
+        let now = Utc::now();
+
+        let mut restriction = None;
+        for minute in 0..5 {
+            let outcome = service
+                .record_security_event(failed_auth(customer_id, channel_id, now - Duration::minutes(4 - minute)))
+                .await
+                .unwrap();
+            restriction = outcome.restrictions.into_iter().next().or(restriction);
+        }
+        let restriction = restriction.expect("Restriction not imposed");
+        let imposed_at = restriction.starts_at;
+
+        assert!(service.is_channel_restricted(customer_id, channel_id, imposed_at + Duration::minutes(29)).await.unwrap());
+        assert!(!service.is_channel_restricted(customer_id, other_channel_id, imposed_at).await.unwrap());
+        assert!(!service.is_channel_restricted(customer_id, channel_id, imposed_at + Duration::minutes(30)).await.unwrap());
+        assert!(service.find_active_restrictions(customer_id, imposed_at + Duration::minutes(31)).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_lifted_restriction_no_longer_applies() {
+        let (_, service) = setup();
+        let customer_id = Uuid::new_v4();
+        let channel_id = Uuid::new_v4();
+        let reason = HeaplessString::try_from("Customer reported stolen phone").unwrap();
+
+        let restriction = service.restrict_channel(customer_id, channel_id, reason, None).await.unwrap();
+        assert!(service.is_channel_restricted(customer_id, channel_id, Utc::now()).await.unwrap());
+
+        service.lift_restriction(restriction.id, Uuid::new_v4()).await.unwrap();
+        assert!(!service.is_channel_restricted(customer_id, channel_id, Utc::now()).await.unwrap());
+        assert!(service.lift_restriction(restriction.id, Uuid::new_v4()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_purge_keeps_events_within_retention() {
+        let repository = Arc::new(MockChannelSecurityRepository::default());
+        let service = ChannelSecurityServiceImpl::new(repository.clone(), Arc::new(MockComplianceService::default()))
+            .with_retention(SecurityEventRetention { retention_days: 90 });
+        let customer_id = Uuid::new_v4();
+        let now = Utc::now();
+
+        for days_ago in [200, 91, 10] {
+            service
+                .record_security_event(failed_auth(customer_id, Uuid::new_v4(), now - Duration::days(days_ago)))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(service.purge_expired_events(now).await.unwrap(), 2);
+        assert_eq!(repository.events.lock().unwrap().len(), 1);
+    }
+}
@@ -1,10 +1,14 @@
+use std::sync::Arc;
 use async_trait::async_trait;
-use chrono::NaiveDate;
+use chrono::{NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
 use uuid::Uuid;
 
 use banking_api::{
     domain::{Transaction, Channel, ChannelFee, ReconciliationReport, ChannelType, ChannelStatus},
-    error::BankingResult,
+    error::{BankingError, BankingResult},
+    service::FeeService,
     service::channel_service::{
         ChannelProcessor, ChannelValidationResult, MaintenanceResult, ChannelMetrics
     },
@@ -15,11 +19,12 @@ use crate::mappers::{ChannelMapper};
 /// Implementation of the ChannelProcessor service
 pub struct ChannelServiceImpl<R: ChannelRepository> {
     repository: R,
+    fee_service: Arc<dyn FeeService>,
 }
 
 impl<R: ChannelRepository> ChannelServiceImpl<R> {
-    pub fn new(repository: R) -> Self {
-        Self { repository }
+    pub fn new(repository: R, fee_service: Arc<dyn FeeService>) -> Self {
+        Self { repository, fee_service }
     }
 }
 
@@ -48,16 +53,49 @@ impl<R: ChannelRepository + Send + Sync> ChannelProcessor for ChannelServiceImpl
         }
     }
 
-    /// Apply channel-specific fees
-    async fn apply_channel_fees(&self, _transaction: &Transaction, channel: &Channel) -> BankingResult<Vec<ChannelFee>> {
-        let fees = Vec::new();
-        
-        if let Some(_fee_schedule_id) = channel.fee_schedule_id {
-            // TODO: Load fee schedule and calculate applicable fees
-            // This would require querying the fee schedule by ID
-            // and applying the fee calculation rules
+    /// Apply channel-specific fees: each item of the channel's fee schedule
+    /// that applies to the transaction, if the schedule is in force on its
+    /// value date and in its currency
+    async fn apply_channel_fees(&self, transaction: &Transaction, channel: &Channel) -> BankingResult<Vec<ChannelFee>> {
+        let mut fees = Vec::new();
+        let Some(fee_schedule_id) = channel.fee_schedule_id else {
+            return Ok(fees);
+        };
+
+        let schedule = self.repository
+            .find_fee_schedule_by_id(fee_schedule_id)
+            .await?
+            .ok_or_else(|| BankingError::NotFound(format!("Fee schedule {fee_schedule_id} not found")))?;
+        let schedule = ChannelMapper::from_fee_schedule_model(schedule, Vec::new())
+            .map_err(BankingError::Internal)?;
+        if !schedule.is_in_force(transaction.value_date) || schedule.currency.as_str() != transaction.currency.as_str() {
+            return Ok(fees);
         }
-        
+
+        let transaction_type = transaction.transaction_type.to_string();
+        for model in self.repository.find_fee_items_by_schedule(schedule.id).await? {
+            let item = ChannelMapper::from_fee_item_model(model, Vec::new());
+            if !item.applies_to(transaction.transaction_code.as_str(), &transaction_type) {
+                continue;
+            }
+            let amount = self.fee_service.calculate_fee(&item, transaction.amount, &transaction.currency).await?;
+            if amount == Decimal::ZERO {
+                continue;
+            }
+            fees.push(ChannelFee {
+                id: Uuid::new_v4(),
+                fee_type: item.fee_type,
+                amount,
+                currency: schedule.currency.clone(),
+                description: HeaplessString::try_from(item.fee_name.as_str()).map_err(|_| BankingError::ValidationError {
+                    field: "description".to_string(),
+                    message: "Fee description too long".to_string(),
+                })?,
+                applies_to_transaction_id: transaction.id,
+                created_at: Utc::now(),
+            });
+        }
+
         Ok(fees)
     }
 
@@ -0,0 +1,251 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        Account, AccountStatus, AccountType, Cheque, ChequeBook, ChequeBookStatus, ChequeReturnReason,
+        DegradedFlags, FeeTriggerEvent, Transaction, TransactionStatus, TransactionType,
+    },
+    service::{AccountService, ChequeService, FeeService, ProductService, TransactionService},
+};
+use banking_db::repository::{AccountRepository, ChequeRepository};
+use crate::mappers::{AccountMapper, ChequeMapper};
+
+/// Leaves per cheque book accepted on request
+const MAX_CHEQUE_BOOK_LEAVES: u32 = 100;
+
+/// Configuration struct for ChequeServiceImpl to avoid too many constructor arguments
+pub struct ChequeServiceConfig {
+    pub cheque_repository: Arc<dyn ChequeRepository>,
+    pub account_repository: Arc<dyn AccountRepository>,
+    pub account_service: Arc<dyn AccountService>,
+    pub transaction_service: Arc<dyn TransactionService>,
+    pub fee_service: Arc<dyn FeeService>,
+    pub product_service: Arc<dyn ProductService>,
+}
+
+/// Production implementation of ChequeService
+pub struct ChequeServiceImpl {
+    cheque_repository: Arc<dyn ChequeRepository>,
+    account_repository: Arc<dyn AccountRepository>,
+    account_service: Arc<dyn AccountService>,
+    transaction_service: Arc<dyn TransactionService>,
+    fee_service: Arc<dyn FeeService>,
+    product_service: Arc<dyn ProductService>,
+}
+
+impl ChequeServiceImpl {
+    pub fn new(config: ChequeServiceConfig) -> Self {
+        Self {
+            cheque_repository: config.cheque_repository,
+            account_repository: config.account_repository,
+            account_service: config.account_service,
+            transaction_service: config.transaction_service,
+            fee_service: config.fee_service,
+            product_service: config.product_service,
+        }
+    }
+}
+
+#[async_trait]
+impl ChequeService for ChequeServiceImpl {
+    async fn request_cheque_book(&self, account_id: Uuid, leaves: u32, requested_by_person_id: Uuid) -> BankingResult<ChequeBook> {
+        let account_model = self.account_repository
+            .find_by_id(account_id)
+            .await?
+            .ok_or(BankingError::AccountNotFound(account_id))?;
+        let account = AccountMapper::from_model(account_model)?;
+
+        if account.account_type != AccountType::Current {
+            return Err(BankingError::ValidationError {
+                field: "account_id".to_string(),
+                message: "Cheque books can only be issued on current accounts".to_string(),
+            });
+        }
+        if account.account_status != AccountStatus::Active {
+            return Err(BankingError::AccountNotOperational {
+                account_id,
+                reason: format!("Account status is {:?}", account.account_status),
+            });
+        }
+        if leaves == 0 || leaves > MAX_CHEQUE_BOOK_LEAVES {
+            return Err(BankingError::ValidationError {
+                field: "leaves".to_string(),
+                message: format!("A cheque book must have between 1 and {MAX_CHEQUE_BOOK_LEAVES} leaves"),
+            });
+        }
+
+        let first_serial_number = self.cheque_repository
+            .reserve_serial_range(account_id, i64::from(leaves))
+            .await?;
+        let now = Utc::now();
+        let book = ChequeBook {
+            id: Uuid::new_v4(),
+            account_id,
+            first_serial_number,
+            last_serial_number: first_serial_number + i64::from(leaves) - 1,
+            status: ChequeBookStatus::Issued,
+            requested_at: now,
+            requested_by_person_id,
+            issued_at: Some(now),
+        };
+        let cheques = (book.first_serial_number..=book.last_serial_number)
+            .map(|serial_number| ChequeMapper::to_model(Cheque::new_leaf(book.id, account_id, serial_number, now)))
+            .collect();
+
+        let created = self.cheque_repository
+            .create_cheque_book(ChequeMapper::book_to_model(book), cheques)
+            .await?;
+        Ok(ChequeMapper::book_from_model(created))
+    }
+
+    async fn record_cheque_presented(
+        &self,
+        account_id: Uuid,
+        serial_number: i64,
+        amount: Decimal,
+        channel_id: HeaplessString<50>,
+    ) -> BankingResult<Cheque> {
+        if amount <= Decimal::ZERO {
+            return Err(BankingError::InvalidTransactionAmount(format!("Cheque amount must be positive, got {amount}")));
+        }
+
+        let account_model = self.account_repository
+            .find_by_id(account_id)
+            .await?
+            .ok_or(BankingError::AccountNotFound(account_id))?;
+        let account = AccountMapper::from_model(account_model)?;
+        if account.account_status != AccountStatus::Active {
+            return Err(ChequeReturnReason::AccountNotOperational.into_error(account_id, serial_number));
+        }
+
+        // Looking up by account and serial also rejects cheques drawn on another account
+        let mut cheque = self.cheque_repository
+            .find_cheque(account_id, serial_number)
+            .await?
+            .map(ChequeMapper::from_model)
+            .ok_or_else(|| ChequeReturnReason::UnknownCheque.into_error(account_id, serial_number))?;
+
+        let now = Utc::now();
+        cheque.present(amount, now)
+            .map_err(|reason| reason.into_error(account_id, serial_number))?;
+        if !self.cheque_repository.mark_presented(cheque.id, amount, now).await? {
+            return Err(ChequeReturnReason::DuplicatePresentment.into_error(account_id, serial_number));
+        }
+
+        let available = self.account_service.calculate_available_balance(account_id).await?;
+        if available < amount {
+            cheque.mark_bounced(ChequeReturnReason::InsufficientFunds, now);
+            let updated = self.cheque_repository.update_cheque(ChequeMapper::to_model(cheque)).await?;
+            return Ok(ChequeMapper::from_model(updated));
+        }
+
+        let debit = self.build_cheque_debit(&account, &cheque, amount, channel_id).await?;
+        match self.transaction_service.process_transaction(debit).await {
+            Ok(posted) => cheque.mark_paid(posted.id, Utc::now()),
+            // Funds can still be consumed between the check and the posting
+            Err(BankingError::InsufficientFunds { .. }) => {
+                cheque.mark_bounced(ChequeReturnReason::InsufficientFunds, Utc::now());
+            }
+            // Any other posting rejection returns the cheque unpaid
+            Err(e) => {
+                cheque.mark_bounced(ChequeReturnReason::AccountNotOperational, Utc::now());
+                self.cheque_repository.update_cheque(ChequeMapper::to_model(cheque)).await?;
+                return Err(e);
+            }
+        }
+
+        let updated = self.cheque_repository.update_cheque(ChequeMapper::to_model(cheque)).await?;
+        Ok(ChequeMapper::from_model(updated))
+    }
+
+    async fn stop_cheque(
+        &self,
+        account_id: Uuid,
+        serial_number: i64,
+        reason: HeaplessString<200>,
+        stopped_by_person_id: Uuid,
+    ) -> BankingResult<Cheque> {
+        let mut cheque = self.cheque_repository
+            .find_cheque(account_id, serial_number)
+            .await?
+            .map(ChequeMapper::from_model)
+            .ok_or_else(|| ChequeReturnReason::UnknownCheque.into_error(account_id, serial_number))?;
+
+        cheque.stop(reason, stopped_by_person_id, Utc::now())
+            .map_err(|reason| reason.into_error(account_id, serial_number))?;
+        let updated = self.cheque_repository.update_cheque(ChequeMapper::to_model(cheque)).await?;
+
+        self.fee_service
+            .apply_event_based_fees(account_id, updated.id, FeeTriggerEvent::StopPayment, None, None)
+            .await?;
+
+        Ok(ChequeMapper::from_model(updated))
+    }
+
+    async fn get_outstanding_cheques(&self, account_id: Uuid) -> BankingResult<Vec<Cheque>> {
+        let cheques = self.cheque_repository.find_outstanding_cheques(account_id).await?;
+        Ok(cheques.into_iter().map(ChequeMapper::from_model).collect())
+    }
+}
+
+impl ChequeServiceImpl {
+    async fn build_cheque_debit(
+        &self,
+        account: &Account,
+        cheque: &Cheque,
+        amount: Decimal,
+        channel_id: HeaplessString<50>,
+    ) -> BankingResult<Transaction> {
+        let gl_mapping = self.product_service.get_gl_mapping(account.product_id).await?;
+        let now = Utc::now();
+
+        Ok(Transaction {
+            id: Uuid::new_v4(),
+            account_id: account.id,
+            transaction_code: HeaplessString::try_from("CHQ_PAY").map_err(|_| BankingError::ValidationError {
+                field: "transaction_code".to_string(),
+                message: "Transaction code too long".to_string(),
+            })?,
+            transaction_type: TransactionType::Debit,
+            amount,
+            currency: account.currency.clone(),
+            description: HeaplessString::try_from(format!("Cheque {} paid", cheque.serial_number).as_str())
+                .map_err(|_| BankingError::ValidationError {
+                    field: "description".to_string(),
+                    message: "Description too long".to_string(),
+                })?,
+            channel_id,
+            terminal_id: None,
+            agent_person_id: None,
+            transaction_date: now,
+            value_date: now.date_naive(),
+            status: TransactionStatus::Pending,
+            // Generated by the transaction service
+            reference_number: HeaplessString::new(),
+            external_reference: Some(
+                HeaplessString::try_from(format!("CHQ{}", cheque.serial_number).as_str()).map_err(|_| BankingError::ValidationError {
+                    field: "external_reference".to_string(),
+                    message: "External reference too long".to_string(),
+                })?,
+            ),
+            gl_code: HeaplessString::try_from(gl_mapping.customer_account_code.as_str()).map_err(|_| BankingError::ValidationError {
+                field: "gl_code".to_string(),
+                message: "GL code too long".to_string(),
+            })?,
+            requires_approval: false,
+            approval_status: None,
+            risk_score: None,
+            degraded_flags: DegradedFlags::NONE,
+            idempotency_key: None,
+            reversal_of_transaction_id: None,
+            fx_conversion: None,
+            created_at: now,
+        })
+    }
+}
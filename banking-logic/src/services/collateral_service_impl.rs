@@ -10,12 +10,15 @@ use banking_api::{
         Collateral, CollateralAlert, CollateralEnforcement, CollateralPledge, CollateralPortfolioSummary,
         CollateralValuation, ConcentrationAnalysis, RiskDistribution, ValuationStatusSummary,
         ComplianceSummary, CovenantCompliance, AlertSeverity, EnforcementMethod, CollateralType,
-        CollateralRiskRating, EnforcementStatus
+        CollateralRiskRating, EnforcementStatus, CollateralAlertType, CollateralAlertStatus, ExchangeRate,
     },
     service::CollateralService,
 };
+use banking_db::models::{CollateralAlertStatus as DbCollateralAlertStatus, CollateralAlertType as DbCollateralAlertType};
 use banking_db::repository::CollateralRepository;
 
+use crate::mappers::CollateralMapper;
+
 /// Production implementation of CollateralService
 /// Provides comprehensive collateral asset management including pledges, valuations, monitoring, and enforcement
 /// NOTE: This is a stub implementation - CollateralMapper needs to be implemented for full functionality
@@ -75,6 +78,35 @@ impl CollateralServiceImpl {
 
         Ok(())
     }
+
+    /// Whether the collateral already has an LTV breach alert nobody has closed
+    async fn has_open_ltv_alert(&self, collateral_id: Uuid) -> Result<bool, String> {
+        Ok(self.collateral_repository
+            .find_alerts_by_collateral(collateral_id)
+            .await?
+            .iter()
+            .any(|alert| {
+                alert.alert_type == DbCollateralAlertType::LtvBreach
+                    && matches!(
+                        alert.status,
+                        DbCollateralAlertStatus::Open | DbCollateralAlertStatus::InProgress | DbCollateralAlertStatus::Escalated
+                    )
+            }))
+    }
+}
+
+/// Amount in `to_currency`, through the rate between the two currencies in either direction
+fn convert_amount(amount: Decimal, from_currency: &str, to_currency: &str, rates: &[ExchangeRate]) -> Option<Decimal> {
+    if from_currency == to_currency {
+        return Some(amount);
+    }
+    if let Some(rate) = rates.iter().find(|r| r.from_currency == from_currency && r.to_currency == to_currency) {
+        return Some(amount * rate.rate);
+    }
+    rates
+        .iter()
+        .find(|r| r.from_currency == to_currency && r.to_currency == from_currency && !r.rate.is_zero())
+        .map(|rate| amount / rate.rate)
 }
 
 #[async_trait]
@@ -157,29 +189,25 @@ impl CollateralService for CollateralServiceImpl {
 
     // === VALUATION MANAGEMENT ===
     
-    async fn create_valuation(&self, mut valuation: CollateralValuation) -> Result<Uuid, String> {
-        valuation.created_at = Utc::now();
-        
-        // TODO: Implement CollateralMapper::valuation_domain_to_model conversion
-        let valuation_data = serde_json::to_string(&valuation).unwrap_or_default();
-        self.collateral_repository.save_valuation(valuation.collateral_id, valuation_data).await?;
-        
-        Ok(valuation.id)
+    async fn create_valuation(&self, valuation: CollateralValuation) -> Result<Uuid, String> {
+        Ok(self.record_valuation(valuation).await?.id)
     }
     
     async fn get_valuations_by_collateral(&self, collateral_id: Uuid) -> Result<Vec<CollateralValuation>, String> {
-        let _models = self.collateral_repository.find_valuations_by_collateral(collateral_id).await?;
-        // TODO: Implement CollateralMapper::valuation_model_to_domain for Vec conversion
-        Err("CollateralMapper valuation_model_to_domain not yet implemented".to_string())
+        self.collateral_repository
+            .find_valuations_by_collateral(collateral_id)
+            .await?
+            .into_iter()
+            .map(|model| CollateralMapper::valuation_from_model(model).map_err(|e| e.to_string()))
+            .collect()
     }
     
     async fn get_latest_valuation(&self, collateral_id: Uuid) -> Result<Option<CollateralValuation>, String> {
-        if let Some(_model) = self.collateral_repository.find_latest_valuation(collateral_id).await? {
-            // TODO: Implement CollateralMapper::valuation_model_to_domain conversion
-            Err("CollateralMapper valuation_model_to_domain not yet implemented".to_string())
-        } else {
-            Ok(None)
-        }
+        self.collateral_repository
+            .find_latest_valuation(collateral_id)
+            .await?
+            .map(|model| CollateralMapper::valuation_from_model(model).map_err(|e| e.to_string()))
+            .transpose()
     }
     
     async fn get_valuations_due(&self, reference_date: NaiveDate) -> Result<Vec<Collateral>, String> {
@@ -194,6 +222,35 @@ impl CollateralService for CollateralServiceImpl {
         Err("CollateralMapper model_to_domain for Vec not yet implemented".to_string())
     }
     
+    async fn record_valuation(&self, mut valuation: CollateralValuation) -> Result<CollateralValuation, String> {
+        let collateral = self.collateral_repository
+            .find_collateral_by_id(valuation.collateral_id)
+            .await?
+            .ok_or_else(|| "Collateral not found".to_string())?;
+        if valuation.market_value <= Decimal::ZERO {
+            return Err("Valuation market value must be positive".to_string());
+        }
+        if valuation.currency != collateral.currency {
+            return Err(format!(
+                "Valuation currency {} differs from collateral currency {}",
+                valuation.currency, collateral.currency
+            ));
+        }
+
+        valuation.created_at = Utc::now();
+        self.collateral_repository
+            .save_valuation(&CollateralMapper::valuation_to_model(valuation.clone()))
+            .await?;
+
+        // A valuation entered late for an earlier date stays in the history only
+        if valuation.valuation_date >= collateral.valuation_date {
+            self.collateral_repository
+                .update_market_value(valuation.collateral_id, valuation.market_value, valuation.valuation_date, valuation.created_by_person_id)
+                .await?;
+        }
+        Ok(valuation)
+    }
+    
     async fn update_market_value(&self, collateral_id: Uuid, new_value: Decimal, valuation_date: NaiveDate, updated_by_person_id: Uuid) -> Result<(), String> {
         self.collateral_repository.update_market_value(collateral_id, new_value, valuation_date, updated_by_person_id).await
     }
@@ -272,6 +329,68 @@ impl CollateralService for CollateralServiceImpl {
         Err("Covenant compliance update not yet implemented".to_string())
     }
     
+    async fn detect_ltv_breaches(&self, max_ltv_percentage: Decimal, rates: &[ExchangeRate]) -> Result<Vec<CollateralAlert>, String> {
+        let mut loans: Vec<(Uuid, HeaplessString<3>, Decimal, Vec<Uuid>)> = Vec::new();
+        for pledge in self.collateral_repository.find_collateralized_loans().await? {
+            match loans.iter_mut().find(|(loan_account_id, ..)| *loan_account_id == pledge.loan_account_id) {
+                Some((.., collateral_ids)) => collateral_ids.push(pledge.collateral_id),
+                None => loans.push((pledge.loan_account_id, pledge.currency, pledge.outstanding_principal, vec![pledge.collateral_id])),
+            }
+        }
+
+        let mut raised = Vec::new();
+        'loans: for (loan_account_id, currency, outstanding_principal, collateral_ids) in loans {
+            let mut collateral_value = Decimal::ZERO;
+            for &collateral_id in &collateral_ids {
+                let Some(valuation) = self.collateral_repository.find_latest_valuation(collateral_id).await? else {
+                    continue;
+                };
+                match convert_amount(valuation.market_value, &valuation.currency, &currency, rates) {
+                    Some(value) => collateral_value += value,
+                    None => {
+                        tracing::warn!(
+                            "LTV of loan {}: no rate from {} to {}, skipped",
+                            loan_account_id, valuation.currency, currency
+                        );
+                        continue 'loans;
+                    }
+                }
+            }
+            if collateral_value <= Decimal::ZERO {
+                continue;
+            }
+
+            let ltv = (outstanding_principal * Decimal::from(100) / collateral_value).round_dp(2);
+            if ltv <= max_ltv_percentage {
+                continue;
+            }
+            let severity = if ltv >= Decimal::from(100) { AlertSeverity::Critical } else { AlertSeverity::High };
+            let message = format!("Loan {loan_account_id} LTV {ltv}% exceeds {max_ltv_percentage}%");
+            for collateral_id in collateral_ids {
+                if self.has_open_ltv_alert(collateral_id).await? {
+                    continue;
+                }
+                let alert = CollateralAlert {
+                    id: Uuid::new_v4(),
+                    collateral_id,
+                    alert_type: CollateralAlertType::LtvBreach,
+                    severity: severity.clone(),
+                    message: HeaplessString::try_from(message.as_str()).unwrap_or_default(),
+                    trigger_date: Utc::now(),
+                    due_date: None,
+                    status: CollateralAlertStatus::Open,
+                    assigned_to_person_id: None,
+                    resolution_notes: None,
+                    resolved_at: None,
+                    resolved_by_person_id: None,
+                };
+                self.collateral_repository.save_alert(&CollateralMapper::alert_to_model(alert.clone())).await?;
+                raised.push(alert);
+            }
+        }
+        Ok(raised)
+    }
+    
     async fn calculate_available_value(&self, collateral_id: Uuid) -> Result<Decimal, String> {
         if let Some(_collateral) = self.get_collateral(collateral_id).await? {
             // TODO: Would work if get_collateral was implemented
@@ -290,18 +409,17 @@ impl CollateralService for CollateralServiceImpl {
     
     async fn create_alert(&self, mut alert: CollateralAlert) -> Result<Uuid, String> {
         alert.trigger_date = Utc::now();
-        
-        // TODO: Implement CollateralMapper::alert_domain_to_model conversion
-        let alert_data = serde_json::to_string(&alert).unwrap_or_default();
-        self.collateral_repository.save_alert(alert.collateral_id, alert_data).await?;
-        
+        self.collateral_repository.save_alert(&CollateralMapper::alert_to_model(alert.clone())).await?;
         Ok(alert.id)
     }
     
     async fn get_alerts_by_collateral(&self, collateral_id: Uuid) -> Result<Vec<CollateralAlert>, String> {
-        let _models = self.collateral_repository.find_alerts_by_collateral(collateral_id).await?;
-        // TODO: Implement CollateralMapper::alert_model_to_domain for Vec conversion
-        Err("CollateralMapper alert_model_to_domain not yet implemented".to_string())
+        self.collateral_repository
+            .find_alerts_by_collateral(collateral_id)
+            .await?
+            .into_iter()
+            .map(|model| CollateralMapper::alert_from_model(model).map_err(|e| e.to_string()))
+            .collect()
     }
     
     async fn get_alerts_by_severity(&self, severity: AlertSeverity) -> Result<Vec<CollateralAlert>, String> {
@@ -447,3 +565,264 @@ impl CollateralService for CollateralServiceImpl {
         Err("Collateral optimization recommendations not yet implemented".to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use banking_api::domain::ValuationMethod;
+    use banking_db::models::{
+        CollateralAlertModel, CollateralCategory, CollateralEnforcementModel, CollateralModel, CollateralRiskRating as DbCollateralRiskRating,
+        CollateralStatus, CollateralType as DbCollateralType, CollateralValuationModel, CollateralizedLoanModel, CustodyLocation,
+        PerfectionStatus,
+    };
+
+    /// Holds collaterals, their valuations and alerts; `loans` are the pledged loans
+    #[derive(Default)]
+    struct MockCollateralRepository {
+        collaterals: Mutex<Vec<CollateralModel>>,
+        valuations: Mutex<Vec<CollateralValuationModel>>,
+        alerts: Mutex<Vec<CollateralAlertModel>>,
+        loans: Vec<CollateralizedLoanModel>,
+    }
+
+    #[async_trait]
+    impl CollateralRepository for MockCollateralRepository {
+        async fn save_collateral(&self, _collateral: &CollateralModel) -> Result<(), String> { unimplemented!() }
+        async fn find_collateral_by_id(&self, collateral_id: Uuid) -> Result<Option<CollateralModel>, String> {
+            Ok(self.collaterals.lock().unwrap().iter().find(|c| c.id == collateral_id).cloned())
+        }
+        async fn find_collaterals_by_customer(&self, _customer_id: Uuid) -> Result<Vec<CollateralModel>, String> { unimplemented!() }
+        async fn find_collaterals_by_type(&self, _collateral_type: String) -> Result<Vec<CollateralModel>, String> { unimplemented!() }
+        async fn find_collaterals_by_status(&self, _status: String) -> Result<Vec<CollateralModel>, String> { unimplemented!() }
+        async fn search_collaterals(&self, _collateral_type: Option<String>, _risk_rating: Option<String>, _status: Option<String>, _limit: u32, _offset: u32) -> Result<Vec<CollateralModel>, String> { unimplemented!() }
+        async fn count_collaterals(&self, _collateral_type: Option<String>, _risk_rating: Option<String>, _status: Option<String>) -> Result<u64, String> { unimplemented!() }
+        async fn update_collateral_status(&self, _collateral_id: Uuid, _status: String, _updated_by_person_id: Uuid) -> Result<(), String> { unimplemented!() }
+        async fn update_market_value(&self, collateral_id: Uuid, new_value: Decimal, valuation_date: NaiveDate, _updated_by_person_id: Uuid) -> Result<(), String> {
+            let mut collaterals = self.collaterals.lock().unwrap();
+            let collateral = collaterals.iter_mut().find(|c| c.id == collateral_id).unwrap();
+            collateral.current_market_value = new_value;
+            collateral.valuation_date = valuation_date;
+            Ok(())
+        }
+        async fn save_valuation(&self, valuation: &CollateralValuationModel) -> Result<(), String> {
+            self.valuations.lock().unwrap().push(valuation.clone());
+            Ok(())
+        }
+        async fn find_valuation_by_id(&self, _valuation_id: Uuid) -> Result<Option<CollateralValuationModel>, String> { unimplemented!() }
+        async fn find_valuations_by_collateral(&self, _collateral_id: Uuid) -> Result<Vec<CollateralValuationModel>, String> { unimplemented!() }
+        async fn find_latest_valuation(&self, collateral_id: Uuid) -> Result<Option<CollateralValuationModel>, String> {
+            Ok(self.valuations.lock().unwrap().iter()
+                .filter(|v| v.collateral_id == collateral_id)
+                .max_by_key(|v| (v.valuation_date, v.created_at))
+                .cloned())
+        }
+        async fn delete_valuation(&self, _valuation_id: Uuid) -> Result<(), String> { unimplemented!() }
+        async fn find_valuations_due(&self, _reference_date: NaiveDate) -> Result<Vec<CollateralModel>, String> { unimplemented!() }
+        async fn find_overdue_valuations(&self, _reference_date: NaiveDate) -> Result<Vec<CollateralModel>, String> { unimplemented!() }
+        async fn find_valuations_by_date_range(&self, _from_date: NaiveDate, _to_date: NaiveDate) -> Result<Vec<CollateralValuationModel>, String> { unimplemented!() }
+        async fn save_pledge(&self, _collateral_id: Uuid, _pledge_data: String) -> Result<(), String> { unimplemented!() }
+        async fn find_pledge_by_id(&self, _pledge_id: Uuid) -> Result<Option<String>, String> { unimplemented!() }
+        async fn find_pledges_by_collateral(&self, _collateral_id: Uuid) -> Result<Vec<String>, String> { unimplemented!() }
+        async fn find_pledges_by_loan_account(&self, _loan_account_id: Uuid) -> Result<Vec<String>, String> { unimplemented!() }
+        async fn find_active_pledges_by_collateral(&self, _collateral_id: Uuid) -> Result<Vec<String>, String> { unimplemented!() }
+        async fn update_pledge_status(&self, _pledge_id: Uuid, _status: String, _updated_by_person_id: Uuid) -> Result<(), String> { unimplemented!() }
+        async fn find_collateralized_loans(&self) -> Result<Vec<CollateralizedLoanModel>, String> {
+            Ok(self.loans.clone())
+        }
+        async fn update_pledged_amount(&self, _pledge_id: Uuid, _new_amount: Decimal, _updated_by_person_id: Uuid) -> Result<(), String> { unimplemented!() }
+        async fn find_pledges_by_priority(&self, _priority: String) -> Result<Vec<String>, String> { unimplemented!() }
+        async fn save_alert(&self, alert: &CollateralAlertModel) -> Result<(), String> {
+            self.alerts.lock().unwrap().push(alert.clone());
+            Ok(())
+        }
+        async fn find_alert_by_id(&self, _alert_id: Uuid) -> Result<Option<String>, String> { unimplemented!() }
+        async fn find_alerts_by_collateral(&self, collateral_id: Uuid) -> Result<Vec<CollateralAlertModel>, String> {
+            Ok(self.alerts.lock().unwrap().iter().filter(|a| a.collateral_id == collateral_id).cloned().collect())
+        }
+        async fn find_active_alerts(&self) -> Result<Vec<String>, String> { unimplemented!() }
+        async fn find_alerts_by_severity(&self, _severity: String) -> Result<Vec<String>, String> { unimplemented!() }
+        async fn find_alerts_by_type(&self, _alert_type: DbCollateralAlertType) -> Result<Vec<String>, String> { unimplemented!() }
+        async fn find_alerts_by_assignee(&self, _assigned_to: Uuid) -> Result<Vec<String>, String> { unimplemented!() }
+        async fn update_alert_status(&self, _alert_id: Uuid, _status: String, _updated_by_person_id: Uuid) -> Result<(), String> { unimplemented!() }
+        async fn resolve_alert(&self, _alert_id: Uuid, _resolution_notes: String, _resolved_by: Uuid) -> Result<(), String> { unimplemented!() }
+        async fn save_enforcement(&self, _enforcement: &CollateralEnforcementModel) -> Result<(), String> { unimplemented!() }
+        async fn find_enforcement_by_id(&self, _enforcement_id: Uuid) -> Result<Option<CollateralEnforcementModel>, String> { unimplemented!() }
+        async fn find_enforcements_by_collateral(&self, _collateral_id: Uuid) -> Result<Vec<CollateralEnforcementModel>, String> { unimplemented!() }
+        async fn find_enforcements_by_loan_account(&self, _loan_account_id: Uuid) -> Result<Vec<CollateralEnforcementModel>, String> { unimplemented!() }
+        async fn find_enforcements_by_status(&self, _status: String) -> Result<Vec<CollateralEnforcementModel>, String> { unimplemented!() }
+        async fn update_enforcement_status(&self, _enforcement_id: Uuid, _status: String, _updated_by_person_id: Uuid) -> Result<(), String> { unimplemented!() }
+        async fn complete_enforcement(&self, _enforcement_id: Uuid, _recovery_amount: Decimal, _enforcement_costs: Decimal, _net_recovery: Decimal, _completed_by: Uuid) -> Result<(), String> { unimplemented!() }
+        async fn calculate_total_portfolio_value(&self, _portfolio_id: Uuid) -> Result<Decimal, String> { unimplemented!() }
+        async fn calculate_total_pledged_value(&self, _portfolio_id: Uuid) -> Result<Decimal, String> { unimplemented!() }
+        async fn calculate_weighted_average_ltv(&self, _portfolio_id: Uuid) -> Result<Decimal, String> { unimplemented!() }
+        async fn get_concentration_by_type(&self, _portfolio_id: Uuid) -> Result<Vec<(String, u32, Decimal)>, String> { unimplemented!() }
+        async fn get_concentration_by_location(&self, _portfolio_id: Uuid) -> Result<Vec<(String, u32, Decimal)>, String> { unimplemented!() }
+        async fn get_risk_distribution(&self, _portfolio_id: Uuid) -> Result<Vec<(String, u32, Decimal)>, String> { unimplemented!() }
+        async fn get_valuation_status_summary(&self, _portfolio_id: Uuid) -> Result<(u32, u32, u32, i32), String> { unimplemented!() }
+        async fn get_compliance_summary(&self, _portfolio_id: Uuid) -> Result<(u32, u32, u32, u32), String> { unimplemented!() }
+        async fn find_collaterals_by_ltv_threshold(&self, _min_ltv: Decimal, _max_ltv: Option<Decimal>) -> Result<Vec<CollateralModel>, String> { unimplemented!() }
+        async fn batch_update_market_values(&self, _updates: Vec<(Uuid, Decimal, NaiveDate)>, _updated_by_person_id: Uuid) -> Result<u32, String> { unimplemented!() }
+        async fn batch_create_alerts(&self, _alert_data: Vec<String>) -> Result<u32, String> { unimplemented!() }
+        async fn batch_update_pledge_statuses(&self, _updates: Vec<(Uuid, String)>, _updated_by_person_id: Uuid) -> Result<u32, String> { unimplemented!() }
+        async fn find_collaterals_by_custody_location(&self, _custody_location: String) -> Result<Vec<CollateralModel>, String> { unimplemented!() }
+        async fn find_collaterals_requiring_insurance_review(&self, _reference_date: NaiveDate) -> Result<Vec<CollateralModel>, String> { unimplemented!() }
+        async fn find_collaterals_with_expiring_perfection(&self, _days_ahead: i32) -> Result<Vec<CollateralModel>, String> { unimplemented!() }
+        async fn get_collateral_performance_history(&self, _collateral_id: Uuid, _from_date: NaiveDate, _to_date: NaiveDate) -> Result<Vec<(NaiveDate, Decimal)>, String> { unimplemented!() }
+        async fn find_collaterals_by_environmental_risk(&self, _risk_level: String) -> Result<Vec<CollateralModel>, String> { unimplemented!() }
+        async fn find_covenant_breaches(&self, _reference_date: NaiveDate) -> Result<Vec<String>, String> { unimplemented!() }
+        async fn update_covenant_compliance(&self, _pledge_id: Uuid, _compliance_data: String, _updated_by_person_id: Uuid) -> Result<(), String> { unimplemented!() }
+        async fn find_pledges_requiring_covenant_review(&self, _reference_date: NaiveDate) -> Result<Vec<String>, String> { unimplemented!() }
+        async fn get_collateral_audit_trail(&self, _collateral_id: Uuid) -> Result<Vec<String>, String> { unimplemented!() }
+        async fn get_pledge_audit_trail(&self, _pledge_id: Uuid) -> Result<Vec<String>, String> { unimplemented!() }
+        async fn get_valuation_history(&self, _collateral_id: Uuid) -> Result<Vec<String>, String> { unimplemented!() }
+        async fn archive_old_alerts(&self, _cutoff_date: NaiveDate) -> Result<u32, String> { unimplemented!() }
+        async fn archive_completed_enforcements(&self, _cutoff_date: NaiveDate) -> Result<u32, String> { unimplemented!() }
+        async fn cleanup_temporary_valuations(&self, _cutoff_date: NaiveDate) -> Result<u32, String> { unimplemented!() }
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, day).unwrap()
+    }
+
+    fn collateral(currency: &str, market_value: i64) -> CollateralModel {
+        CollateralModel {
+            id: Uuid::new_v4(),
+            collateral_type: DbCollateralType::ResidentialProperty,
+            collateral_category: CollateralCategory::Immovable,
+            description: HeaplessString::try_from("Villa, Bonapriso").unwrap(),
+            external_reference: HeaplessString::try_from("TF-1024").unwrap(),
+            original_value: Decimal::from(market_value),
+            current_market_value: Decimal::from(market_value),
+            appraised_value: None,
+            currency: HeaplessString::try_from(currency).unwrap(),
+            valuation_date: date(1),
+            next_valuation_date: None,
+            valuation_frequency_months: Some(12),
+            pledged_value: Decimal::ZERO,
+            available_value: Decimal::from(market_value),
+            lien_amount: None,
+            margin_percentage: Decimal::from(20),
+            forced_sale_value: None,
+            custody_location: CustodyLocation::ClientPremises,
+            physical_location: None,
+            custodian_details_id: None,
+            legal_title_holder_person_id: Uuid::new_v4(),
+            perfection_status: PerfectionStatus::Perfected,
+            perfection_date: None,
+            perfection_expiry_date: None,
+            registration_number: None,
+            registration_authority_person_id: None,
+            insurance_required: false,
+            insurance_coverage: None,
+            risk_rating: DbCollateralRiskRating::Good,
+            environmental_risk: None,
+            status: CollateralStatus::Active,
+            pledge_date: date(1),
+            release_date: None,
+            maturity_date: None,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            created_by_person_id: Uuid::new_v4(),
+            updated_by_person_id: Uuid::new_v4(),
+            last_valuation_by_person_id: None,
+            next_review_date: None,
+        }
+    }
+
+    fn valuation(collateral_id: Uuid, currency: &str, market_value: i64, valuation_date: NaiveDate) -> CollateralValuation {
+        CollateralValuation {
+            id: Uuid::new_v4(),
+            collateral_id,
+            valuation_date,
+            valuation_method: ValuationMethod::ExpertAppraisal,
+            market_value: Decimal::from(market_value),
+            currency: HeaplessString::try_from(currency).unwrap(),
+            forced_sale_value: None,
+            valuer_person_id: Uuid::new_v4(),
+            appraiser_name: HeaplessString::try_from("Cabinet Ngando").unwrap(),
+            appraiser_license: None,
+            valuation_report_reference: HeaplessString::try_from("VR-2026-031").unwrap(),
+            validity_period_months: 12,
+            next_valuation_due: valuation_date,
+            valuation_notes: None,
+            created_at: Utc::now(),
+            created_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    fn eur_to_xaf() -> ExchangeRate {
+        ExchangeRate {
+            from_currency: HeaplessString::try_from("EUR").unwrap(),
+            to_currency: HeaplessString::try_from("XAF").unwrap(),
+            rate: Decimal::new(655957, 3),
+            effective_at: date(1).and_hms_opt(9, 0, 0).unwrap().and_utc(),
+            source: HeaplessString::try_from("BEAC").unwrap(),
+        }
+    }
+
+    fn pledged_loan(collateral_id: Uuid, currency: &str, outstanding_principal: i64) -> CollateralizedLoanModel {
+        CollateralizedLoanModel {
+            loan_account_id: Uuid::new_v4(),
+            collateral_id,
+            currency: HeaplessString::try_from(currency).unwrap(),
+            outstanding_principal: Decimal::from(outstanding_principal),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_falling_valuation_raises_one_ltv_alert() {
+        let house = collateral("EUR", 12_000);
+        let repository = Arc::new(MockCollateralRepository {
+            collaterals: Mutex::new(vec![house.clone()]),
+            loans: vec![pledged_loan(house.id, "XAF", 6_000_000)],
+            ..Default::default()
+        });
+        let service = CollateralServiceImpl::new(repository.clone());
+        let rates = [eur_to_xaf()];
+        let max_ltv = Decimal::from(80);
+
+        // 12,000 EUR is 7,871,484 XAF: LTV 76.22%
+        service.record_valuation(valuation(house.id, "EUR", 12_000, date(2))).await.unwrap();
+        assert!(service.detect_ltv_breaches(max_ltv, &rates).await.unwrap().is_empty());
+
+        // 10,000 EUR is 6,559,570 XAF: LTV 91.47%
+        service.record_valuation(valuation(house.id, "EUR", 10_000, date(20))).await.unwrap();
+        assert_eq!(repository.collaterals.lock().unwrap()[0].current_market_value, Decimal::from(10_000));
+        let alerts = service.detect_ltv_breaches(max_ltv, &rates).await.unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].collateral_id, house.id);
+        assert!(matches!(alerts[0].alert_type, CollateralAlertType::LtvBreach));
+        assert!(matches!(alerts[0].severity, AlertSeverity::High));
+        assert!(alerts[0].message.contains("91.47%"));
+
+        assert!(service.detect_ltv_breaches(max_ltv, &rates).await.unwrap().is_empty());
+        assert_eq!(repository.alerts.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_valuations_stay_in_collateral_currency_and_loans_without_rate_are_skipped() {
+        let house = collateral("EUR", 12_000);
+        let repository = Arc::new(MockCollateralRepository {
+            collaterals: Mutex::new(vec![house.clone()]),
+            loans: vec![pledged_loan(house.id, "XAF", 60_000_000)],
+            ..Default::default()
+        });
+        let service = CollateralServiceImpl::new(repository.clone());
+
+        let foreign = service.record_valuation(valuation(house.id, "XAF", 7_000_000, date(2))).await;
+        assert!(foreign.is_err());
+        assert!(repository.valuations.lock().unwrap().is_empty());
+
+        service.record_valuation(valuation(house.id, "EUR", 12_000, date(2))).await.unwrap();
+        // An older valuation keys in late: history only
+        service.record_valuation(valuation(house.id, "EUR", 15_000, date(1))).await.unwrap();
+        assert_eq!(repository.collaterals.lock().unwrap()[0].current_market_value, Decimal::from(12_000));
+        assert_eq!(repository.valuations.lock().unwrap().len(), 2);
+
+        assert!(service.detect_ltv_breaches(Decimal::from(80), &[]).await.unwrap().is_empty());
+        let alerts = service.detect_ltv_breaches(Decimal::from(80), &[eur_to_xaf()]).await.unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert!(matches!(alerts[0].severity, AlertSeverity::Critical));
+    }
+}
@@ -1,31 +1,171 @@
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use async_trait::async_trait;
-use chrono::{Utc, NaiveDate};
+use chrono::{DateTime, Duration, Utc, NaiveDate};
 use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
 use rust_decimal::prelude::FromPrimitive;
 use uuid::Uuid;
 
 use banking_api::{
-    BankingResult, Customer, Transaction,
+    BankingError, BankingResult, Customer, Transaction,
     domain::{
-        KycResult, ScreeningResult, MonitoringResult, SarData, UboVerificationResult,
+        KycResult, ScreeningResult, MonitoringResult, SarData, SarStatus, UboVerificationResult,
         VerificationStatus, ComplianceAlert, AlertStatus, MonitoringRules, RiskLevel,
-        customer::KycStatus, compliance::ScreeningType
+        ChannelSecurityEventType, SecurityVelocityRule, Severity, RiskRating, RiskRatingRecalculation,
+        AccountWorkflow, WorkflowType, WorkflowStep, WorkflowStatus, ReasonId, ReasonedOperation,
+        customer::KycStatus, compliance::{ComplianceAlertType, SanctionsMatch, ScreeningType}
+    },
+    service::{
+        ComplianceService, ComplianceReport, EnhancedDueDiligenceResult, BatchScreeningSummary,
+        RiskRecalculationSummary, SanctionsMatcher, UboOverAllocation, UboOwnership, UboStructureReport,
     },
-    service::{ComplianceService, ComplianceReport, EnhancedDueDiligenceResult},
 };
-use banking_db::repository::ComplianceRepository;
-use crate::mappers::ComplianceMapper;
+use banking_db::models::{CustomerType, ExtendedComplianceAlertModel, WorkflowTypeModel};
+use banking_db::models::account::{DbUboStatus, UltimateBeneficiaryModel};
+use banking_db::repository::{ComplianceRepository, CustomerRepository, ReasonAndPurposeRepository, WorkflowRepository};
+use crate::config::{BankingConfig, ComplianceSettings};
+use crate::constants::SYSTEM_PERSON_ID;
+use crate::mappers::{ComplianceMapper, CustomerMapper, WorkflowMapper};
+use crate::validation::ReasonValidation;
+
+/// Ownership links above a corporate customer
+#[derive(Default)]
+struct UboGraph {
+    /// Active links by owned entity
+    links: HashMap<Uuid, Vec<UltimateBeneficiaryModel>>,
+    natural_persons: HashSet<Uuid>,
+}
+
+/// Lowest match confidence (percent) raising an alert of each severity
+const CRITICAL_MATCH_SCORE: i64 = 90;
+const HIGH_MATCH_SCORE: i64 = 75;
+const MEDIUM_MATCH_SCORE: i64 = 50;
+
+const EDD_ACTION: &str = "Enhanced due diligence: risk rating raised to High";
 
 /// Production implementation of ComplianceService
 /// Provides comprehensive compliance management including KYC, AML, and regulatory reporting
 pub struct ComplianceServiceImpl {
     compliance_repository: Arc<dyn ComplianceRepository>,
+    customer_repository: Arc<dyn CustomerRepository>,
+    workflow_repository: Arc<dyn WorkflowRepository>,
+    reason_repository: Arc<dyn ReasonAndPurposeRepository>,
+    sanctions_matcher: Arc<dyn SanctionsMatcher>,
+    config: Arc<BankingConfig>,
 }
 
 impl ComplianceServiceImpl {
-    pub fn new(compliance_repository: Arc<dyn ComplianceRepository>) -> Self {
-        Self { compliance_repository }
+    pub fn new(
+        compliance_repository: Arc<dyn ComplianceRepository>,
+        customer_repository: Arc<dyn CustomerRepository>,
+        workflow_repository: Arc<dyn WorkflowRepository>,
+        reason_repository: Arc<dyn ReasonAndPurposeRepository>,
+        sanctions_matcher: Arc<dyn SanctionsMatcher>,
+        config: Arc<BankingConfig>,
+    ) -> Self {
+        Self { compliance_repository, customer_repository, workflow_repository, reason_repository, sanctions_matcher, config }
+    }
+
+    /// Reason recorded on SARs drafted from alerts
+    async fn sar_reason_id(&self) -> BankingResult<Uuid> {
+        let code = &self.config.compliance.sar_reason_code;
+        let reason = self.reason_repository
+            .find_by_code(code)
+            .await?
+            .ok_or_else(|| BankingError::Internal(format!("SAR reason {code} is not configured")))?;
+        ReasonValidation::check(ReasonId(reason.id), Some(&reason), ReasonedOperation::SuspiciousActivityReport)?;
+        Ok(reason.id)
+    }
+
+    async fn find_sar(&self, sar_id: Uuid) -> BankingResult<SarData> {
+        self.compliance_repository
+            .find_sar_by_id(sar_id)
+            .await?
+            .map(ComplianceMapper::sar_data_from_model)
+            .ok_or_else(|| BankingError::NotFound(format!("SAR {sar_id} not found")))
+    }
+
+    async fn find_alert(&self, alert_id: Uuid) -> BankingResult<ExtendedComplianceAlertModel> {
+        self.compliance_repository
+            .find_alert_by_id(alert_id)
+            .await?
+            .map(|alert| alert.alert_data)
+            .ok_or_else(|| BankingError::NotFound(format!("Compliance alert {alert_id} not found")))
+    }
+
+    async fn escalate_alert(&self, alert_id: Uuid, escalated_by: Uuid) -> BankingResult<()> {
+        self.compliance_repository
+            .update_alert_status(alert_id, "Escalated", Some(escalated_by))
+            .await
+    }
+
+    async fn save_draft_sar(&self, sar: SarData) -> BankingResult<SarData> {
+        let model = self.compliance_repository
+            .update_draft_sar(ComplianceMapper::sar_data_to_model(sar))
+            .await?;
+        Ok(ComplianceMapper::sar_data_from_model(model))
+    }
+
+    /// Active ownership links of the customer and of every entity above it.
+    /// Entities already loaded are not fetched again, so cycles terminate.
+    async fn load_ubo_graph(&self, corporate_customer_id: Uuid) -> BankingResult<UboGraph> {
+        let mut graph = UboGraph::default();
+        let mut pending = vec![corporate_customer_id];
+        while let Some(entity_id) = pending.pop() {
+            if graph.links.contains_key(&entity_id) {
+                continue;
+            }
+            let links: Vec<UltimateBeneficiaryModel> = self.compliance_repository
+                .find_ubo_by_corporate(entity_id)
+                .await?
+                .into_iter()
+                .filter(|link| link.status == DbUboStatus::Active)
+                .collect();
+            let owner_ids: Vec<Uuid> = links.iter().map(|link| link.beneficiary_customer_id).collect();
+            let owners = self.customer_repository.find_by_ids(&owner_ids).await?;
+            if let Some(missing) = owner_ids.iter().find(|id| !owners.iter().any(|owner| owner.id == **id)) {
+                return Err(BankingError::CustomerNotFound(*missing));
+            }
+            for owner in owners {
+                match owner.customer_type {
+                    CustomerType::Individual => {
+                        graph.natural_persons.insert(owner.id);
+                    }
+                    CustomerType::Corporate => pending.push(owner.id),
+                }
+            }
+            graph.links.insert(entity_id, links);
+        }
+        Ok(graph)
+    }
+
+    /// Opens an enhanced due diligence workflow on each of the accounts, except
+    /// those with a compliance check already under way
+    async fn open_edd_workflows(&self, account_ids: &[Uuid], now: DateTime<Utc>) -> BankingResult<Vec<Uuid>> {
+        let workflow_type = WorkflowTypeModel::ComplianceCheck.to_string();
+        let mut workflow_ids = Vec::new();
+        for &account_id in account_ids {
+            if self.workflow_repository.find_active_workflow(account_id, &workflow_type).await?.is_some() {
+                continue;
+            }
+            let workflow = AccountWorkflow {
+                id: Uuid::new_v4(),
+                account_id,
+                workflow_type: WorkflowType::ComplianceVerification,
+                current_step: WorkflowStep::ComplianceCheck,
+                status: WorkflowStatus::PendingAction,
+                initiated_by: SYSTEM_PERSON_ID,
+                initiated_at: now,
+                completed_at: None,
+                steps_completed: Vec::new(),
+                next_action_required: HeaplessString::try_from(EDD_ACTION).ok(),
+                timeout_at: Some(now + Duration::days(self.config.compliance.edd_timeout_days)),
+            };
+            let created = self.workflow_repository.create_workflow(&WorkflowMapper::to_model(workflow)).await?;
+            workflow_ids.push(created.id);
+        }
+        Ok(workflow_ids)
     }
 
     /// Internal validation for KYC requirements
@@ -149,6 +289,7 @@ impl ComplianceService for ComplianceServiceImpl {
 
     /// Generate SAR (Suspicious Activity Report) data with reason ID validation
     async fn generate_sar_data(&self, customer_id: Uuid, reason_id: Uuid, additional_details: Option<HeaplessString<500>>) -> BankingResult<SarData> {
+        let now = Utc::now();
         let sar_data = SarData {
             id: Uuid::new_v4(),
             customer_id,
@@ -173,8 +314,16 @@ impl ComplianceService for ComplianceServiceImpl {
             supporting_transaction_id_17: None,
             supporting_transaction_id_18: None,
             supporting_transaction_id_19: None,
-            generated_at: Utc::now(),
-            status: banking_api::domain::SarStatus::Draft,
+            narrative: HeaplessString::new(),
+            customer_full_name: HeaplessString::new(),
+            alert_ids: Vec::new(),
+            account_ids: Vec::new(),
+            generated_at: now,
+            status: SarStatus::Draft,
+            created_by_person_id: SYSTEM_PERSON_ID,
+            submitted_by_person_id: None,
+            submitted_at: None,
+            last_updated_at: now,
         };
 
         // Store SAR data
@@ -192,6 +341,115 @@ impl ComplianceService for ComplianceServiceImpl {
         self.generate_sar_data(customer_id, default_reason_id, None).await
     }
 
+    async fn create_sar_from_alert(&self, alert_id: Uuid, narrative: HeaplessString<1000>, created_by: Uuid) -> BankingResult<SarData> {
+        let alert = self.find_alert(alert_id).await?;
+        let customer_id = alert.customer_id.ok_or_else(|| BankingError::ValidationError {
+            field: "alert_id".to_string(),
+            message: format!("Compliance alert {alert_id} is not raised on a customer"),
+        })?;
+        let customer = self.customer_repository
+            .find_by_id(customer_id)
+            .await?
+            .ok_or(BankingError::CustomerNotFound(customer_id))?;
+        let reason_id = self.sar_reason_id().await?;
+
+        let now = Utc::now();
+        let mut sar = SarData {
+            id: Uuid::new_v4(),
+            customer_id,
+            reason_id,
+            additional_details: None,
+            supporting_transaction_id_01: None,
+            supporting_transaction_id_02: None,
+            supporting_transaction_id_03: None,
+            supporting_transaction_id_04: None,
+            supporting_transaction_id_05: None,
+            supporting_transaction_id_06: None,
+            supporting_transaction_id_07: None,
+            supporting_transaction_id_08: None,
+            supporting_transaction_id_09: None,
+            supporting_transaction_id_10: None,
+            supporting_transaction_id_11: None,
+            supporting_transaction_id_12: None,
+            supporting_transaction_id_13: None,
+            supporting_transaction_id_14: None,
+            supporting_transaction_id_15: None,
+            supporting_transaction_id_16: None,
+            supporting_transaction_id_17: None,
+            supporting_transaction_id_18: None,
+            supporting_transaction_id_19: None,
+            narrative,
+            customer_full_name: customer.full_name,
+            alert_ids: Vec::new(),
+            account_ids: Vec::new(),
+            generated_at: now,
+            status: SarStatus::Draft,
+            created_by_person_id: created_by,
+            submitted_by_person_id: None,
+            submitted_at: None,
+            last_updated_at: now,
+        };
+        sar.cover_alert(alert.id, alert.account_id, alert.transaction_id)?;
+
+        let created = self.compliance_repository
+            .create_sar_data(ComplianceMapper::sar_data_to_model(sar))
+            .await?;
+        self.escalate_alert(alert_id, created_by).await?;
+        Ok(ComplianceMapper::sar_data_from_model(created))
+    }
+
+    async fn add_alert_to_sar(&self, sar_id: Uuid, alert_id: Uuid, updated_by: Uuid) -> BankingResult<SarData> {
+        let mut sar = self.find_sar(sar_id).await?;
+        sar.ensure_editable()?;
+        let alert = self.find_alert(alert_id).await?;
+        if alert.customer_id != Some(sar.customer_id) {
+            return Err(BankingError::ValidationError {
+                field: "alert_id".to_string(),
+                message: format!("Compliance alert {alert_id} is not raised on customer {} of SAR {sar_id}", sar.customer_id),
+            });
+        }
+
+        sar.cover_alert(alert.id, alert.account_id, alert.transaction_id)?;
+        sar.last_updated_at = Utc::now();
+        let sar = self.save_draft_sar(sar).await?;
+        self.escalate_alert(alert_id, updated_by).await?;
+        Ok(sar)
+    }
+
+    async fn update_sar_narrative(&self, sar_id: Uuid, narrative: HeaplessString<1000>) -> BankingResult<SarData> {
+        let mut sar = self.find_sar(sar_id).await?;
+        sar.ensure_editable()?;
+        sar.narrative = narrative;
+        sar.last_updated_at = Utc::now();
+        self.save_draft_sar(sar).await
+    }
+
+    async fn submit_sar(&self, sar_id: Uuid, submitted_by: Uuid) -> BankingResult<SarData> {
+        let mut sar = self.find_sar(sar_id).await?;
+        if sar.narrative.trim().is_empty() {
+            return Err(BankingError::ValidationError {
+                field: "narrative".to_string(),
+                message: format!("SAR {sar_id} needs a narrative before it is submitted"),
+            });
+        }
+        sar.submit(submitted_by, Utc::now())?;
+        self.save_draft_sar(sar).await
+    }
+
+    async fn list_sars(&self, status: Option<SarStatus>, from_date: NaiveDate, to_date: NaiveDate) -> BankingResult<Vec<SarData>> {
+        let from = from_date.and_hms_opt(0, 0, 0).unwrap().and_utc();
+        let to = to_date
+            .succ_opt()
+            .ok_or_else(|| BankingError::DateCalculationError(format!("No day after {to_date}")))?
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc();
+        let sars = self.compliance_repository
+            .find_sars(status.map(ComplianceMapper::domain_sar_status_to_db_sar_status), from, to)
+            .await?;
+        Ok(sars.into_iter().map(ComplianceMapper::sar_data_from_model).collect())
+    }
+
     /// Ultimate Beneficial Owner verification
     async fn verify_ubo_chain(&self, corporate_customer_id: Uuid) -> BankingResult<UboVerificationResult> {
         // Simulate UBO verification
@@ -213,6 +471,56 @@ impl ComplianceService for ComplianceServiceImpl {
         Ok(ubo_result)
     }
 
+    async fn validate_ubo_structure(&self, corporate_customer_id: Uuid) -> BankingResult<UboStructureReport> {
+        let corporate = self.customer_repository
+            .find_by_id(corporate_customer_id)
+            .await?
+            .ok_or(BankingError::CustomerNotFound(corporate_customer_id))?;
+        if corporate.customer_type != CustomerType::Corporate {
+            return Err(BankingError::ValidationError {
+                field: "corporate_customer_id".to_string(),
+                message: format!("Customer {corporate_customer_id} is not a corporate customer"),
+            });
+        }
+
+        let graph = self.load_ubo_graph(corporate_customer_id).await?;
+        let report = analyse_ubo_structure(
+            corporate_customer_id,
+            &graph,
+            self.config.compliance.ubo_reporting_threshold,
+            Utc::now(),
+        );
+
+        let mut link_ids = report.owners
+            .iter()
+            .filter(|owner| owner.is_reportable)
+            .flat_map(|owner| owner.ubo_link_ids.iter().copied());
+        let mut issues = report.over_allocated_entities
+            .iter()
+            .map(|entity| format!("Owners of {} hold {}%", entity.entity_customer_id, entity.total_percentage.normalize()))
+            .chain(report.ownership_cycles.iter().map(|cycle| format!("Ownership cycle through {}", cycle[0])))
+            .filter_map(|issue| HeaplessString::try_from(issue.as_str()).ok());
+        let result = UboVerificationResult {
+            corporate_customer_id,
+            ubo_chain_link_id_01: link_ids.next(),
+            ubo_chain_link_id_02: link_ids.next(),
+            ubo_chain_link_id_03: link_ids.next(),
+            ubo_chain_link_id_04: link_ids.next(),
+            ubo_chain_link_id_05: link_ids.next(),
+            verification_complete: report.is_valid,
+            requires_update_01: issues.next(),
+            requires_update_02: issues.next(),
+            requires_update_03: issues.next(),
+            requires_update_04: issues.next(),
+            requires_update_05: issues.next(),
+        };
+        self.compliance_repository
+            .save_ubo_verification_result(ComplianceMapper::ubo_verification_result_to_model(result))
+            .await?;
+
+        Ok(report)
+    }
+
     async fn update_ubo_status(&self, ubo_link_id: Uuid, status: VerificationStatus) -> BankingResult<()> {
         // Convert status to string for database storage
         let status_str = match status {
@@ -251,6 +559,148 @@ impl ComplianceService for ComplianceServiceImpl {
         Ok(results)
     }
 
+    /// Screen customers in bulk against one screening type.
+    /// Customers screened for the type within the freshness window are
+    /// skipped. Every screening is recorded, so a run cut short by a matcher
+    /// failure resumes where it stopped; hits also record their matches and
+    /// raise an alert whose severity follows the strongest match.
+    async fn screen_customers_batch(&self, customer_ids: &[Uuid], screening_type: ScreeningType) -> BankingResult<BatchScreeningSummary> {
+        let mut seen = HashSet::new();
+        let requested: Vec<Uuid> = customer_ids.iter().copied().filter(|id| seen.insert(*id)).collect();
+        let now = Utc::now();
+        let since = now - Duration::days(self.config.compliance.screening_freshness_days);
+
+        let fresh: HashSet<Uuid> = self.compliance_repository
+            .find_customers_screened_since(
+                &requested,
+                ComplianceMapper::domain_screening_type_to_db_screening_type(screening_type.clone()),
+                since,
+            )
+            .await?
+            .into_iter()
+            .collect();
+        let due: Vec<Uuid> = requested.iter().copied().filter(|id| !fresh.contains(id)).collect();
+
+        let mut customers: HashMap<Uuid, _> = self.customer_repository
+            .find_by_ids(&due)
+            .await?
+            .into_iter()
+            .map(|model| (model.id, model))
+            .collect();
+        if let Some(missing) = due.iter().find(|id| !customers.contains_key(id)) {
+            return Err(banking_api::BankingError::CustomerNotFound(*missing));
+        }
+
+        let mut summary = BatchScreeningSummary {
+            skipped: (requested.len() - due.len()) as i64,
+            ..Default::default()
+        };
+        for customer_id in due {
+            let Some(model) = customers.remove(&customer_id) else { continue };
+            let customer = CustomerMapper::from_model(model)?;
+            let mut matches = self.sanctions_matcher.find_matches(&customer, screening_type.clone()).await?;
+            matches.sort_by(|a, b| b.confidence_score.cmp(&a.confidence_score));
+            let strongest = matches.first().map(|m| match_severity(m.confidence_score));
+
+            let mut top_matches = matches.iter().cloned();
+            let screening_result = ScreeningResult {
+                customer_id,
+                screening_type: screening_type.clone(),
+                found_sanctions_match_01: top_matches.next(),
+                found_sanctions_match_02: top_matches.next(),
+                found_sanctions_match_03: top_matches.next(),
+                risk_level: strongest.as_ref().map(severity_risk_level).unwrap_or(RiskLevel::Low),
+                screened_at: now,
+                requires_manual_review: strongest.is_some(),
+            };
+            let screening = self.compliance_repository
+                .create_sanctions_screening(ComplianceMapper::screening_result_to_screening_model(screening_result))
+                .await?;
+            summary.screened += 1;
+
+            let Some(severity) = strongest else { continue };
+            summary.hits += 1;
+            let alert = sanctions_alert(customer_id, screening.id, &screening_type, &matches[0], severity, now);
+            self.compliance_repository
+                .create_sanctions_matches(
+                    screening.id,
+                    matches.into_iter().map(ComplianceMapper::sanctions_match_to_model).collect(),
+                )
+                .await?;
+            self.compliance_repository
+                .create_alert(ComplianceMapper::compliance_alert_to_model(alert))
+                .await?;
+            summary.alerts_created += 1;
+        }
+
+        Ok(summary)
+    }
+
+    async fn recalculate_risk_rating(&self, customer_id: Uuid) -> BankingResult<RiskRatingRecalculation> {
+        let customer = self.customer_repository
+            .find_by_id(customer_id)
+            .await?
+            .ok_or(banking_api::BankingError::CustomerNotFound(customer_id))?;
+        let rules = &self.config.compliance.risk_scoring;
+        let now = Utc::now();
+
+        let factors = ComplianceMapper::risk_factors_from_model(
+            self.compliance_repository
+                .find_risk_factors(customer_id, now - Duration::days(rules.velocity_window_days))
+                .await?,
+        );
+        let assessment = rules.assess(&factors, now);
+        self.compliance_repository
+            .create_risk_score(ComplianceMapper::risk_assessment_to_model(&assessment))
+            .await?;
+
+        let mut recalculation = RiskRatingRecalculation {
+            assessment,
+            previous_rating: CustomerMapper::risk_rating_from_db(customer.risk_rating),
+            edd_workflow_ids: Vec::new(),
+        };
+        if recalculation.rating_changed() {
+            let rating = recalculation.assessment.rating;
+            self.customer_repository
+                .update_risk_rating(customer_id, CustomerMapper::risk_rating_to_db(rating), SYSTEM_PERSON_ID)
+                .await?;
+            if rating == RiskRating::High {
+                recalculation.edd_workflow_ids = self.open_edd_workflows(&factors.account_ids, now).await?;
+            }
+        }
+        Ok(recalculation)
+    }
+
+    async fn recalculate_all_due(&self, review_interval_days: i64) -> BankingResult<RiskRecalculationSummary> {
+        if review_interval_days <= 0 {
+            return Err(banking_api::BankingError::ValidationError {
+                field: "review_interval_days".to_string(),
+                message: "Review interval must be positive".to_string(),
+            });
+        }
+        let due = self.compliance_repository
+            .find_customers_due_for_risk_review(Utc::now() - Duration::days(review_interval_days))
+            .await?;
+
+        let mut summary = RiskRecalculationSummary::default();
+        for customer_id in due {
+            match self.recalculate_risk_rating(customer_id).await {
+                Ok(recalculation) => {
+                    summary.recalculated += 1;
+                    if recalculation.rating_changed() {
+                        summary.rating_changes += 1;
+                    }
+                    summary.edd_workflows_opened += recalculation.edd_workflow_ids.len() as i64;
+                }
+                Err(error) => {
+                    tracing::warn!("Risk rating recalculation failed for customer {}: {}", customer_id, error);
+                    summary.failed += 1;
+                }
+            }
+        }
+        Ok(summary)
+    }
+
     /// Get compliance alerts for review
     async fn get_pending_compliance_alerts(&self) -> BankingResult<Vec<ComplianceAlert>> {
         let alert_models = self.compliance_repository.find_alerts_by_status("Open").await?;
@@ -282,6 +732,18 @@ impl ComplianceService for ComplianceServiceImpl {
         Ok(alerts)
     }
 
+    /// Raise a compliance alert detected outside transaction monitoring
+    async fn raise_alert(&self, alert: ComplianceAlert) -> BankingResult<ComplianceAlert> {
+        let model = self.compliance_repository
+            .create_alert(ComplianceMapper::compliance_alert_to_model(alert.clone()))
+            .await?;
+
+        Ok(ComplianceAlert {
+            id: model.alert_data.id,
+            ..alert
+        })
+    }
+
     /// Update compliance alert status
     async fn update_alert_status(&self, alert_id: Uuid, status: AlertStatus, updated_by_person_id: Uuid) -> BankingResult<()> {
         let status_str = match status {
@@ -362,9 +824,10 @@ impl ComplianceService for ComplianceServiceImpl {
             structuring_detection: true,
             velocity_checks: true,
             geographic_risk_assessment: true,
-            large_cash_threshold: rust_decimal::Decimal::from(10000),
+            large_cash_threshold: self.config.compliance.large_cash_threshold,
             suspicious_pattern_detection: true,
             cross_border_transaction_monitoring: true,
+            security_velocity_rules: default_security_velocity_rules(&self.config.compliance),
         };
 
         Ok(rules)
@@ -376,4 +839,1030 @@ impl ComplianceService for ComplianceServiceImpl {
         // For now, just return success
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// Alert severity for a match, from its confidence score in percent
+fn match_severity(confidence_score: Decimal) -> Severity {
+    if confidence_score >= Decimal::from(CRITICAL_MATCH_SCORE) {
+        Severity::Critical
+    } else if confidence_score >= Decimal::from(HIGH_MATCH_SCORE) {
+        Severity::High
+    } else if confidence_score >= Decimal::from(MEDIUM_MATCH_SCORE) {
+        Severity::Medium
+    } else {
+        Severity::Low
+    }
+}
+
+fn severity_risk_level(severity: &Severity) -> RiskLevel {
+    match severity {
+        Severity::Low => RiskLevel::Low,
+        Severity::Medium => RiskLevel::Medium,
+        Severity::High => RiskLevel::High,
+        Severity::Critical => RiskLevel::Critical,
+    }
+}
+
+/// Alert raised for a screening hit, described by its strongest match
+fn sanctions_alert(
+    customer_id: Uuid,
+    screening_id: Uuid,
+    screening_type: &ScreeningType,
+    strongest: &SanctionsMatch,
+    severity: Severity,
+    now: DateTime<Utc>,
+) -> ComplianceAlert {
+    let description = format!(
+        "{screening_type:?} screening matched {} on {} with confidence {}",
+        strongest.matched_name, strongest.list_source, strongest.confidence_score
+    );
+    ComplianceAlert {
+        id: Uuid::new_v4(),
+        customer_id: Some(customer_id),
+        account_id: None,
+        transaction_id: None,
+        alert_type: ComplianceAlertType::SanctionsMatch,
+        description: HeaplessString::try_from(description.as_str()).unwrap_or_default(),
+        severity,
+        triggered_at: now,
+        status: AlertStatus::New,
+        assigned_to_person_id: None,
+        resolved_at: None,
+        resolved_by_person_id: None,
+        resolution_notes: None,
+        metadata: HeaplessString::try_from(format!("{{\"screening_id\":\"{screening_id}\"}}").as_str()).ok(),
+        created_at: now,
+        last_updated_at: now,
+    }
+}
+
+/// Security event rules applied until the rules are stored; the failed
+/// authentication burst takes its limits from configuration
+fn default_security_velocity_rules(settings: &ComplianceSettings) -> Vec<SecurityVelocityRule> {
+    vec![
+        SecurityVelocityRule {
+            code: HeaplessString::try_from("FAILED_AUTH_BURST").unwrap_or_default(),
+            event_type: ChannelSecurityEventType::FailedAuth,
+            threshold: settings.failed_auth_alert_threshold,
+            window_minutes: settings.failed_auth_window_minutes,
+            alert_severity: Some(Severity::Medium),
+            restriction_minutes: Some(settings.failed_auth_restriction_minutes),
+            is_active: true,
+        },
+        SecurityVelocityRule {
+            code: HeaplessString::try_from("SIM_SWAP").unwrap_or_default(),
+            event_type: ChannelSecurityEventType::SimSwapSuspected,
+            threshold: 1,
+            window_minutes: 60,
+            alert_severity: Some(Severity::High),
+            restriction_minutes: Some(24 * 60),
+            is_active: true,
+        },
+    ]
+}
+
+/// Ownership of a corporate customer from its link graph: over-allocated
+/// entities, cycles, and each natural person's share with indirect holdings
+/// multiplied along every path from the customer
+fn analyse_ubo_structure(
+    corporate_customer_id: Uuid,
+    graph: &UboGraph,
+    reporting_threshold: Decimal,
+    validated_at: DateTime<Utc>,
+) -> UboStructureReport {
+    let mut over_allocated_entities: Vec<UboOverAllocation> = graph.links
+        .iter()
+        .map(|(entity_id, links)| UboOverAllocation {
+            entity_customer_id: *entity_id,
+            total_percentage: links.iter().filter_map(|link| link.ownership_percentage).sum(),
+        })
+        .filter(|entity| entity.total_percentage > Decimal::ONE_HUNDRED)
+        .collect();
+    over_allocated_entities.sort_by_key(|entity| entity.entity_customer_id);
+
+    let mut holdings = HashMap::new();
+    let mut ownership_cycles = Vec::new();
+    walk_ownership(
+        graph,
+        &mut vec![corporate_customer_id],
+        Decimal::ONE_HUNDRED,
+        &mut holdings,
+        &mut ownership_cycles,
+    );
+
+    let mut owners: Vec<UboOwnership> = holdings
+        .into_values()
+        .map(|mut owner| {
+            owner.total_percentage = owner.direct_percentage + owner.indirect_percentage;
+            owner.is_reportable = owner.total_percentage >= reporting_threshold;
+            owner
+        })
+        .collect();
+    owners.sort_by(|a, b| b.total_percentage.cmp(&a.total_percentage).then(a.beneficiary_customer_id.cmp(&b.beneficiary_customer_id)));
+
+    let is_valid = over_allocated_entities.is_empty() && ownership_cycles.is_empty();
+    UboStructureReport {
+        corporate_customer_id,
+        owners,
+        over_allocated_entities,
+        ownership_cycles,
+        is_valid,
+        validated_at,
+    }
+}
+
+/// Follows the owners of the last entity of `path`, which holds `share` percent
+/// of the validated customer. An owner already on the path closes a cycle and
+/// is not followed further.
+fn walk_ownership(
+    graph: &UboGraph,
+    path: &mut Vec<Uuid>,
+    share: Decimal,
+    holdings: &mut HashMap<Uuid, UboOwnership>,
+    cycles: &mut Vec<Vec<Uuid>>,
+) {
+    let entity_id = path[path.len() - 1];
+    for link in graph.links.get(&entity_id).into_iter().flatten() {
+        let owner_id = link.beneficiary_customer_id;
+        let owned = share * link.ownership_percentage.unwrap_or(Decimal::ZERO) / Decimal::ONE_HUNDRED;
+
+        if graph.natural_persons.contains(&owner_id) {
+            if owned.is_zero() {
+                continue;
+            }
+            let holding = holdings.entry(owner_id).or_insert_with(|| UboOwnership {
+                beneficiary_customer_id: owner_id,
+                direct_percentage: Decimal::ZERO,
+                indirect_percentage: Decimal::ZERO,
+                total_percentage: Decimal::ZERO,
+                is_reportable: false,
+                ubo_link_ids: Vec::new(),
+            });
+            if path.len() == 1 {
+                holding.direct_percentage += owned;
+            } else {
+                holding.indirect_percentage += owned;
+            }
+            if !holding.ubo_link_ids.contains(&link.id) {
+                holding.ubo_link_ids.push(link.id);
+            }
+        } else if let Some(start) = path.iter().position(|id| *id == owner_id) {
+            let mut cycle = path[start..].to_vec();
+            cycle.push(owner_id);
+            if !cycles.iter().any(|known| is_same_cycle(known, &cycle)) {
+                cycles.push(cycle);
+            }
+        } else {
+            path.push(owner_id);
+            walk_ownership(graph, path, owned, holdings, cycles);
+            path.pop();
+        }
+    }
+}
+
+/// Whether two closed cycles go through the same entities in the same order,
+/// whichever entity they start from
+fn is_same_cycle(a: &[Uuid], b: &[Uuid]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let (a, b) = (&a[1..], &b[1..]);
+    (0..a.len()).any(|offset| a.iter().cycle().skip(offset).take(a.len()).eq(b.iter()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use banking_api::domain::{ProductRiskScore, ReasonCategory, ReasonContext, ReasonSeverity, RiskScoringRules, VelocityBand};
+    use banking_db::models::{
+        AccountWorkflowModel, AlertStatus as AlertStatusModel, ComplianceAlertModel, ComplianceResultModel, ComplianceRiskScoreModel, CustomerAuditModel,
+        CustomerDocumentModel, CustomerModel, CustomerPortfolioModel, CustomerRiskFactorsModel, CustomerSearchCriteriaModel,
+        CustomerStatus, CustomerType, IdentityType, ReasonAndPurpose as ReasonAndPurposeModel,
+        RiskRating as RiskRatingModel, SanctionsMatchModel, SanctionsScreeningModel, SarDataModel,
+        SarStatus as SarStatusModel, ScreeningType as ScreeningTypeModel,
+        Severity as SeverityModel, UboVerificationResultModel, WorkflowStatusModel, WorkflowStepModel,
+        WorkflowStepRecordModel, account::{DbControlType, DbVerificationStatus, UltimateBeneficiaryModel},
+    };
+    use banking_db::repository::compliance_repository::{
+        AlertSummaryReport, ComplianceSummaryReport, SanctionsComplianceReport, TransactionMonitoringRecord,
+        TransactionMonitoringResult,
+    };
+    use banking_db::repository::reason_and_purpose_repository::{
+        BulkOperationResult, DataIntegrityReport, LocalizedReasonModel, ReasonChangeRecord, ReasonUsageStatistics,
+        ReasonValidationRules,
+    };
+    use banking_db::repository::workflow_repository::{
+        WorkflowBottleneckReport, WorkflowFilter, WorkflowMetricsReport, WorkflowPage, WorkflowPerformanceReport,
+        WorkflowSearchResult,
+    };
+    use banking_db::AlertType;
+
+    /// Records what the batch persists; `fresh` customers count as recently
+    /// screened and `due_for_review` ones as due for a risk rating review.
+    /// `ubo_links` is the ownership graph UBO validation walks.
+    #[derive(Default)]
+    struct MockComplianceRepository {
+        fresh: Vec<Uuid>,
+        screenings: Mutex<Vec<SanctionsScreeningModel>>,
+        matches: Mutex<Vec<(Uuid, SanctionsMatchModel)>>,
+        alerts: Mutex<Vec<ComplianceAlertModel>>,
+        risk_factors: Mutex<HashMap<Uuid, CustomerRiskFactorsModel>>,
+        risk_scores: Mutex<Vec<ComplianceRiskScoreModel>>,
+        due_for_review: Vec<Uuid>,
+        sars: Mutex<Vec<SarDataModel>>,
+        ubo_links: Vec<UltimateBeneficiaryModel>,
+        ubo_results: Mutex<Vec<UboVerificationResultModel>>,
+    }
+
+    #[async_trait]
+    impl ComplianceRepository for MockComplianceRepository {
+        async fn create_sanctions_screening(&self, screening: SanctionsScreeningModel) -> BankingResult<SanctionsScreeningModel> {
+            self.screenings.lock().unwrap().push(screening.clone());
+            Ok(screening)
+        }
+        async fn find_screening_by_id(&self, _screening_id: Uuid) -> BankingResult<Option<SanctionsScreeningModel>> { unimplemented!() }
+        async fn find_screening_by_customer(&self, _customer_id: Uuid) -> BankingResult<Vec<SanctionsScreeningModel>> { unimplemented!() }
+        async fn find_latest_screening(&self, _customer_id: Uuid) -> BankingResult<Option<SanctionsScreeningModel>> { unimplemented!() }
+        async fn find_positive_screenings(&self) -> BankingResult<Vec<SanctionsScreeningModel>> { unimplemented!() }
+        async fn find_screenings_requiring_review(&self) -> BankingResult<Vec<SanctionsScreeningModel>> { unimplemented!() }
+        async fn update_screening_status(&self, _screening_id: Uuid, _status: &str, _reviewed_by: &str) -> BankingResult<()> { unimplemented!() }
+        async fn find_customers_needing_screening(&self, _days_threshold: i32) -> BankingResult<Vec<Uuid>> { unimplemented!() }
+        async fn create_sanctions_matches(&self, screening_id: Uuid, matches: Vec<SanctionsMatchModel>) -> BankingResult<()> {
+            self.matches.lock().unwrap().extend(matches.into_iter().map(|m| (screening_id, m)));
+            Ok(())
+        }
+        async fn find_customers_screened_since(&self, customer_ids: &[Uuid], _screening_type: ScreeningTypeModel, _since: DateTime<Utc>) -> BankingResult<Vec<Uuid>> {
+            Ok(customer_ids.iter().copied().filter(|id| self.fresh.contains(id)).collect())
+        }
+        async fn create_alert(&self, alert: ComplianceAlertModel) -> BankingResult<ComplianceAlertModel> {
+            self.alerts.lock().unwrap().push(alert.clone());
+            Ok(alert)
+        }
+        async fn find_alert_by_id(&self, alert_id: Uuid) -> BankingResult<Option<ComplianceAlertModel>> {
+            Ok(self.alerts.lock().unwrap().iter().find(|a| a.alert_data.id == alert_id).cloned())
+        }
+        async fn find_alerts_by_customer(&self, _customer_id: Uuid) -> BankingResult<Vec<ComplianceAlertModel>> { unimplemented!() }
+        async fn find_alerts_by_transaction(&self, _transaction_id: Uuid) -> BankingResult<Vec<ComplianceAlertModel>> { unimplemented!() }
+        async fn find_alerts_by_type(&self, _alert_type: AlertType) -> BankingResult<Vec<ComplianceAlertModel>> { unimplemented!() }
+        async fn find_alerts_by_status(&self, _status: &str) -> BankingResult<Vec<ComplianceAlertModel>> { unimplemented!() }
+        async fn find_open_alerts(&self) -> BankingResult<Vec<ComplianceAlertModel>> { unimplemented!() }
+        async fn update_alert_status(&self, alert_id: Uuid, status: &str, _resolved_by_person_id: Option<Uuid>) -> BankingResult<()> {
+            let mut alerts = self.alerts.lock().unwrap();
+            let alert = alerts.iter_mut().find(|a| a.alert_data.id == alert_id).unwrap();
+            alert.alert_data.status = status.parse().unwrap();
+            Ok(())
+        }
+        async fn find_alerts_by_severity(&self, _severity: &str) -> BankingResult<Vec<ComplianceAlertModel>> { unimplemented!() }
+        async fn create_ubo_link(&self, _ubo: UltimateBeneficiaryModel) -> BankingResult<UltimateBeneficiaryModel> { unimplemented!() }
+        async fn update_ubo_link(&self, _ubo: UltimateBeneficiaryModel) -> BankingResult<UltimateBeneficiaryModel> { unimplemented!() }
+        async fn find_ubo_by_id(&self, _ubo_id: Uuid) -> BankingResult<Option<UltimateBeneficiaryModel>> { unimplemented!() }
+        async fn find_ubo_by_corporate(&self, corporate_customer_id: Uuid) -> BankingResult<Vec<UltimateBeneficiaryModel>> {
+            Ok(self.ubo_links.iter().filter(|link| link.corporate_customer_id == corporate_customer_id).cloned().collect())
+        }
+        async fn find_ubo_by_beneficiary(&self, _beneficiary_customer_id: Uuid) -> BankingResult<Vec<UltimateBeneficiaryModel>> { unimplemented!() }
+        async fn find_ubo_by_verification_status(&self, _status: &str) -> BankingResult<Vec<UltimateBeneficiaryModel>> { unimplemented!() }
+        async fn update_ubo_verification_status(&self, _ubo_id: Uuid, _status: &str, _verified_by: &str) -> BankingResult<()> { unimplemented!() }
+        async fn find_ubo_requiring_verification(&self) -> BankingResult<Vec<UltimateBeneficiaryModel>> { unimplemented!() }
+        async fn delete_ubo_link(&self, _ubo_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn save_ubo_verification_result(&self, result: UboVerificationResultModel) -> BankingResult<UboVerificationResultModel> {
+            self.ubo_results.lock().unwrap().push(result.clone());
+            Ok(result)
+        }
+        async fn create_risk_score(&self, risk_score: ComplianceRiskScoreModel) -> BankingResult<ComplianceRiskScoreModel> {
+            self.risk_scores.lock().unwrap().push(risk_score.clone());
+            Ok(risk_score)
+        }
+        async fn update_risk_score(&self, _risk_score: ComplianceRiskScoreModel) -> BankingResult<ComplianceRiskScoreModel> { unimplemented!() }
+        async fn find_risk_score_by_customer(&self, _customer_id: Uuid) -> BankingResult<Option<ComplianceRiskScoreModel>> { unimplemented!() }
+        async fn find_high_risk_customers(&self, _threshold_score: f64) -> BankingResult<Vec<ComplianceRiskScoreModel>> { unimplemented!() }
+        async fn find_risk_scores_requiring_review(&self, _days_threshold: i32) -> BankingResult<Vec<ComplianceRiskScoreModel>> { unimplemented!() }
+        async fn find_risk_score_history(&self, customer_id: Uuid) -> BankingResult<Vec<ComplianceRiskScoreModel>> {
+            Ok(self.risk_scores.lock().unwrap().iter().rev().filter(|s| s.customer_id == customer_id).cloned().collect())
+        }
+        async fn find_risk_factors(&self, customer_id: Uuid, _transactions_since: DateTime<Utc>) -> BankingResult<CustomerRiskFactorsModel> {
+            Ok(self.risk_factors.lock().unwrap()[&customer_id].clone())
+        }
+        async fn find_customers_due_for_risk_review(&self, _calculated_before: DateTime<Utc>) -> BankingResult<Vec<Uuid>> {
+            Ok(self.due_for_review.clone())
+        }
+        async fn create_compliance_result(&self, _result: ComplianceResultModel) -> BankingResult<ComplianceResultModel> { unimplemented!() }
+        async fn find_compliance_result_by_id(&self, _result_id: Uuid) -> BankingResult<Option<ComplianceResultModel>> { unimplemented!() }
+        async fn find_compliance_results_by_account(&self, _account_id: Uuid) -> BankingResult<Vec<ComplianceResultModel>> { unimplemented!() }
+        async fn find_compliance_results_by_check_type(&self, _check_type: &str) -> BankingResult<Vec<ComplianceResultModel>> { unimplemented!() }
+        async fn find_failed_compliance_results(&self) -> BankingResult<Vec<ComplianceResultModel>> { unimplemented!() }
+        async fn create_sar_data(&self, sar: SarDataModel) -> BankingResult<SarDataModel> {
+            self.sars.lock().unwrap().push(sar.clone());
+            Ok(sar)
+        }
+        async fn find_sar_by_id(&self, sar_id: Uuid) -> BankingResult<Option<SarDataModel>> {
+            Ok(self.sars.lock().unwrap().iter().find(|s| s.id == sar_id).cloned())
+        }
+        async fn find_sar_by_customer(&self, _customer_id: Uuid) -> BankingResult<Vec<SarDataModel>> { unimplemented!() }
+        async fn find_sar_by_status(&self, _status: &str) -> BankingResult<Vec<SarDataModel>> { unimplemented!() }
+        async fn update_sar_status(&self, _sar_id: Uuid, _status: &str, _updated_by_person_id: &str) -> BankingResult<()> { unimplemented!() }
+        async fn find_pending_sar_filings(&self) -> BankingResult<Vec<SarDataModel>> { unimplemented!() }
+        async fn update_draft_sar(&self, sar: SarDataModel) -> BankingResult<SarDataModel> {
+            let mut sars = self.sars.lock().unwrap();
+            match sars.iter_mut().find(|s| s.id == sar.id && s.status == SarStatusModel::Draft) {
+                Some(stored) => {
+                    *stored = sar.clone();
+                    Ok(sar)
+                }
+                None => Err(BankingError::NotFound(format!("Draft SAR {} not found", sar.id))),
+            }
+        }
+        async fn find_sars(&self, status: Option<SarStatusModel>, from: DateTime<Utc>, to: DateTime<Utc>) -> BankingResult<Vec<SarDataModel>> {
+            Ok(self.sars.lock().unwrap().iter()
+                .filter(|s| status.is_none_or(|status| s.status == status))
+                .filter(|s| s.generated_at >= from && s.generated_at < to)
+                .cloned()
+                .collect())
+        }
+        async fn record_transaction_monitoring(&self, _transaction_id: Uuid, _monitoring_result: TransactionMonitoringResult) -> BankingResult<()> { unimplemented!() }
+        async fn find_flagged_transactions(&self, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<Vec<TransactionMonitoringRecord>> { unimplemented!() }
+        async fn find_transactions_by_pattern(&self, _pattern_type: &str) -> BankingResult<Vec<TransactionMonitoringRecord>> { unimplemented!() }
+        async fn generate_compliance_summary(&self, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<ComplianceSummaryReport> { unimplemented!() }
+        async fn generate_sanctions_report(&self, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<SanctionsComplianceReport> { unimplemented!() }
+        async fn generate_alert_summary(&self, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<AlertSummaryReport> { unimplemented!() }
+        async fn count_sanctions_screenings(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn count_compliance_alerts(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn count_ubo_links(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn count_open_alerts(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn count_pending_reviews(&self) -> BankingResult<i64> { unimplemented!() }
+    }
+
+    struct MockCustomerRepository {
+        customers: Mutex<Vec<CustomerModel>>,
+    }
+
+    #[async_trait]
+    impl CustomerRepository for MockCustomerRepository {
+        async fn find_by_ids(&self, customer_ids: &[Uuid]) -> BankingResult<Vec<CustomerModel>> {
+            Ok(self.customers.lock().unwrap().iter().filter(|c| customer_ids.contains(&c.id)).cloned().collect())
+        }
+        async fn create(&self, _customer: CustomerModel) -> BankingResult<CustomerModel> { unimplemented!() }
+        async fn update(&self, _customer: CustomerModel) -> BankingResult<CustomerModel> { unimplemented!() }
+        async fn find_by_id(&self, customer_id: Uuid) -> BankingResult<Option<CustomerModel>> {
+            Ok(self.customers.lock().unwrap().iter().find(|c| c.id == customer_id).cloned())
+        }
+        async fn exists(&self, _customer_id: Uuid) -> BankingResult<bool> { unimplemented!() }
+        async fn find_by_identity(&self, _id_type: IdentityType, _id_number: &str) -> BankingResult<Option<CustomerModel>> { unimplemented!() }
+        async fn find_by_risk_rating(&self, _risk_rating: RiskRatingModel) -> BankingResult<Vec<CustomerModel>> { unimplemented!() }
+        async fn find_requiring_review(&self) -> BankingResult<Vec<CustomerModel>> { unimplemented!() }
+        async fn get_portfolio(&self, _customer_id: Uuid) -> BankingResult<Option<CustomerPortfolioModel>> { unimplemented!() }
+        async fn search(&self, _criteria: CustomerSearchCriteriaModel) -> BankingResult<Vec<CustomerModel>> { unimplemented!() }
+        async fn update_risk_rating(&self, customer_id: Uuid, risk_rating: RiskRatingModel, _authorized_by: Uuid) -> BankingResult<()> {
+            let mut customers = self.customers.lock().unwrap();
+            if let Some(customer) = customers.iter_mut().find(|c| c.id == customer_id) {
+                customer.risk_rating = risk_rating;
+            }
+            Ok(())
+        }
+        async fn update_status(&self, _customer_id: Uuid, _status: CustomerStatus, _reason: &str) -> BankingResult<()> { unimplemented!() }
+        async fn add_document(&self, _document: CustomerDocumentModel) -> BankingResult<CustomerDocumentModel> { unimplemented!() }
+        async fn get_documents(&self, _customer_id: Uuid) -> BankingResult<Vec<CustomerDocumentModel>> { unimplemented!() }
+        async fn add_audit_entry(&self, _audit: CustomerAuditModel) -> BankingResult<CustomerAuditModel> { unimplemented!() }
+        async fn get_audit_trail(&self, _customer_id: Uuid) -> BankingResult<Vec<CustomerAuditModel>> { unimplemented!() }
+        async fn merge_into(&self, _survivor_id: Uuid, _duplicate_id: Uuid, _reason: &str, _merged_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn delete(&self, _customer_id: Uuid, _deleted_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn list(&self, _offset: i64, _limit: i64) -> BankingResult<Vec<CustomerModel>> { unimplemented!() }
+        async fn count(&self) -> BankingResult<i64> { unimplemented!() }
+    }
+
+    /// Keeps created workflows so active ones can be found again
+    #[derive(Default)]
+    struct MockWorkflowRepository {
+        workflows: Mutex<Vec<AccountWorkflowModel>>,
+    }
+
+    #[async_trait]
+    impl WorkflowRepository for MockWorkflowRepository {
+        async fn create_workflow(&self, workflow: &AccountWorkflowModel) -> BankingResult<AccountWorkflowModel> {
+            self.workflows.lock().unwrap().push(workflow.clone());
+            Ok(workflow.clone())
+        }
+        async fn update_workflow(&self, _workflow: AccountWorkflowModel) -> BankingResult<AccountWorkflowModel> { unimplemented!() }
+        async fn find_workflow_by_id(&self, _workflow_id: Uuid) -> BankingResult<Option<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_by_account(&self, _account_id: Uuid) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_active_workflow(&self, account_id: Uuid, workflow_type: &str) -> BankingResult<Option<AccountWorkflowModel>> {
+            Ok(self.workflows.lock().unwrap().iter()
+                .find(|w| {
+                    w.account_id == account_id
+                        && w.workflow_type.to_string() == workflow_type
+                        && matches!(w.status, WorkflowStatusModel::InProgress | WorkflowStatusModel::PendingAction)
+                })
+                .cloned())
+        }
+        async fn find_open_workflows_by_accounts(&self, _account_ids: &[Uuid]) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_by_type(&self, _workflow_type: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_by_status(&self, _status: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_by_initiator(&self, _initiated_by: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn search_workflows(&self, _filter: &WorkflowFilter, _page: WorkflowPage) -> BankingResult<WorkflowSearchResult> { unimplemented!() }
+        async fn update_workflow_status(&self, _workflow_id: Uuid, _status: &str, _notes: &str) -> BankingResult<()> { unimplemented!() }
+        async fn update_workflow_step(&self, _workflow_id: Uuid, _current_step: &str) -> BankingResult<()> { unimplemented!() }
+        async fn advance_workflow_step(&self, _workflow_id: Uuid, _step: &str, _completed_by: Uuid, _notes: &str, _supporting_documents: Vec<HeaplessString<100>>) -> BankingResult<()> { unimplemented!() }
+        async fn complete_workflow(&self, _workflow_id: Uuid, _completion_notes: &str) -> BankingResult<()> { unimplemented!() }
+        async fn fail_workflow(&self, _workflow_id: Uuid, _failure_reason: &str) -> BankingResult<()> { unimplemented!() }
+        async fn cancel_workflow(&self, _workflow_id: Uuid, _reason: &str) -> BankingResult<()> { unimplemented!() }
+        async fn add_step_record(&self, _workflow_id: Uuid, _step_record: WorkflowStepRecordModel) -> BankingResult<WorkflowStepRecordModel> { unimplemented!() }
+        async fn find_step_records_by_workflow(&self, _workflow_id: Uuid) -> BankingResult<Vec<WorkflowStepRecordModel>> { unimplemented!() }
+        async fn find_latest_step_record(&self, _workflow_id: Uuid) -> BankingResult<Option<WorkflowStepRecordModel>> { unimplemented!() }
+        async fn find_pending_workflows(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_in_progress_workflows(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_expired_workflows(&self, _reference_time: DateTime<Utc>) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_requiring_action(&self, _action_type: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_account_opening_workflows(&self, _status: Option<&str>) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_pending_kyc_workflows(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_pending_document_verification(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_account_closure_workflows(&self, _status: Option<&str>) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_pending_final_settlement(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_pending_disbursement(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_reactivation_workflows(&self, _status: Option<&str>) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_pending_mini_kyc(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_compliance_workflows(&self, _status: Option<&str>) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_by_customer_risk(&self, _risk_rating: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_approval_workflows(&self, _status: Option<&str>) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_by_approver(&self, _approver_id: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_awaiting_approval(&self, _approver_id: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn get_workflow_metrics(&self, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<WorkflowMetricsReport> { unimplemented!() }
+        async fn get_workflow_performance(&self, _workflow_type: &str, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<WorkflowPerformanceReport> { unimplemented!() }
+        async fn get_workflow_bottlenecks(&self) -> BankingResult<Vec<WorkflowBottleneckReport>> { unimplemented!() }
+        async fn get_average_completion_time(&self, _workflow_type: &str) -> BankingResult<Option<f64>> { unimplemented!() }
+        async fn cleanup_completed_workflows(&self, _retention_days: i32) -> BankingResult<i64> { unimplemented!() }
+        async fn cleanup_cancelled_workflows(&self, _retention_days: i32) -> BankingResult<i64> { unimplemented!() }
+        async fn find_stale_workflows(&self, _stale_threshold_hours: i32) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn bulk_update_workflow_status(&self, _workflow_ids: Vec<Uuid>, _status: &str) -> BankingResult<i64> { unimplemented!() }
+        async fn bulk_timeout_expired_workflows(&self, _reference_time: DateTime<Utc>) -> BankingResult<i64> { unimplemented!() }
+        async fn workflow_exists(&self, _workflow_id: Uuid) -> BankingResult<bool> { unimplemented!() }
+        async fn count_workflows_by_type(&self, _workflow_type: &str) -> BankingResult<i64> { unimplemented!() }
+        async fn count_workflows_by_status(&self, _status: &str) -> BankingResult<i64> { unimplemented!() }
+        async fn count_pending_workflows(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn list_workflows(&self, _offset: i64, _limit: i64) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn count_all_workflows(&self) -> BankingResult<i64> { unimplemented!() }
+    }
+
+    /// Knows a single reason, looked up by its code
+    struct MockReasonRepository {
+        reason: Option<ReasonAndPurposeModel>,
+    }
+
+    #[async_trait]
+    impl ReasonAndPurposeRepository for MockReasonRepository {
+        async fn create(&self, _reason: ReasonAndPurposeModel) -> BankingResult<ReasonAndPurposeModel> { unimplemented!() }
+        async fn find_by_id(&self, _reason_id: Uuid) -> BankingResult<Option<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_by_code(&self, code: &str) -> BankingResult<Option<ReasonAndPurposeModel>> {
+            Ok(self.reason.clone().filter(|reason| reason.code.as_str() == code))
+        }
+        async fn update(&self, _reason: ReasonAndPurposeModel) -> BankingResult<ReasonAndPurposeModel> { unimplemented!() }
+        async fn delete(&self, _reason_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn deactivate(&self, _reason_id: Uuid, _deactivated_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn reactivate(&self, _reason_id: Uuid, _reactivated_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn find_all_active(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_by_category(&self, _category: ReasonCategory) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_by_context(&self, _context: ReasonContext) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_by_category_and_context(&self, _category: ReasonCategory, _context: ReasonContext) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_by_severity(&self, _severity: ReasonSeverity) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn search_by_content(&self, _search_term: &str, _language_codes: Option<Vec<[u8; 3]>>) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_for_display(&self, _category: Option<ReasonCategory>, _context: Option<ReasonContext>, _active_only: bool) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_reportable_compliance_reasons(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_sar_triggering_reasons(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_ctr_triggering_reasons(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_aml_ctf_reasons(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_kyc_reasons(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_by_jurisdiction(&self, _jurisdiction_code: [u8; 2]) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_escalation_required_reasons(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn get_usage_count(&self, _reason_id: Uuid, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<u64> { unimplemented!() }
+        async fn get_usage_statistics(&self, _reason_id: Uuid, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<ReasonUsageStatistics> { unimplemented!() }
+        async fn get_top_used_reasons_by_category(&self, _category: ReasonCategory, _limit: i32, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<Vec<ReasonUsageStatistics>> { unimplemented!() }
+        async fn find_unused_reasons(&self, _since_date: NaiveDate) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn record_usage(&self, _reason_id: Uuid, _context: ReasonContext, _used_by: &str, _additional_context: Option<&str>) -> BankingResult<()> { unimplemented!() }
+        async fn get_change_history(&self, _reason_id: Uuid) -> BankingResult<Vec<ReasonChangeRecord>> { unimplemented!() }
+        async fn record_change(&self, _change_record: ReasonChangeRecord) -> BankingResult<ReasonChangeRecord> { unimplemented!() }
+        async fn code_exists(&self, _code: &str, _exclude_id: Option<Uuid>) -> BankingResult<bool> { unimplemented!() }
+        async fn is_active(&self, _reason_id: Uuid) -> BankingResult<bool> { unimplemented!() }
+        async fn is_valid_for_context(&self, _reason_id: Uuid, _context: ReasonContext) -> BankingResult<bool> { unimplemented!() }
+        async fn get_validation_rules(&self, _reason_id: Uuid) -> BankingResult<Option<ReasonValidationRules>> { unimplemented!() }
+        async fn bulk_insert(&self, _reasons: Vec<ReasonAndPurposeModel>) -> BankingResult<BulkOperationResult> { unimplemented!() }
+        async fn bulk_update_display_orders(&self, _category: ReasonCategory, _order_updates: Vec<(Uuid, i32)>, _updated_by_person_id: &str) -> BankingResult<()> { unimplemented!() }
+        async fn bulk_update_status(&self, _reason_ids: Vec<Uuid>, _is_active: bool, _updated_by_person_id: &str) -> BankingResult<BulkOperationResult> { unimplemented!() }
+        async fn update_localized_content(&self, _reason_id: Uuid, _language_code: [u8; 3], _content: &str, _updated_by_person_id: &str) -> BankingResult<()> { unimplemented!() }
+        async fn remove_localized_content(&self, _reason_id: Uuid, _language_code: [u8; 3], _updated_by_person_id: &str) -> BankingResult<()> { unimplemented!() }
+        async fn find_with_languages(&self, _language_codes: &[[u8; 3]], _category: Option<ReasonCategory>, _context: Option<ReasonContext>) -> BankingResult<Vec<LocalizedReasonModel>> { unimplemented!() }
+        async fn find_missing_localization(&self, _language_code: [u8; 3], _category: Option<ReasonCategory>) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn count_total(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn count_by_category(&self, _category: ReasonCategory) -> BankingResult<i64> { unimplemented!() }
+        async fn count_by_context(&self, _context: ReasonContext) -> BankingResult<i64> { unimplemented!() }
+        async fn validate_data_integrity(&self) -> BankingResult<DataIntegrityReport> { unimplemented!() }
+        async fn get_categories_in_use(&self) -> BankingResult<Vec<ReasonCategory>> { unimplemented!() }
+        async fn get_contexts_in_use(&self) -> BankingResult<Vec<ReasonContext>> { unimplemented!() }
+    }
+
+    /// Returns canned matches per customer and remembers who it was asked about
+    #[derive(Default)]
+    struct FakeSanctionsMatcher {
+        matches: HashMap<Uuid, Vec<SanctionsMatch>>,
+        screened: Mutex<Vec<Uuid>>,
+    }
+
+    #[async_trait]
+    impl SanctionsMatcher for FakeSanctionsMatcher {
+        async fn find_matches(&self, customer: &Customer, _screening_type: ScreeningType) -> BankingResult<Vec<SanctionsMatch>> {
+            self.screened.lock().unwrap().push(customer.id);
+            Ok(self.matches.get(&customer.id).cloned().unwrap_or_default())
+        }
+    }
+
+    fn customer(name: &str) -> CustomerModel {
+        CustomerModel {
+            id: Uuid::new_v4(),
+            customer_type: CustomerType::Individual,
+            full_name: HeaplessString::try_from(name).unwrap(),
+            id_type: IdentityType::NationalId,
+            id_number: HeaplessString::try_from("CM-0001").unwrap(),
+            risk_rating: RiskRatingModel::Low,
+            status: CustomerStatus::Active,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+            preferred_language_code: None,
+            duplicate_of_customer_id: None,
+        }
+    }
+
+    fn corporate(name: &str) -> CustomerModel {
+        CustomerModel { customer_type: CustomerType::Corporate, ..customer(name) }
+    }
+
+    /// `owner` holding `percentage` of `owned`
+    fn ubo_link(owned: &CustomerModel, owner: &CustomerModel, percentage: i64) -> UltimateBeneficiaryModel {
+        UltimateBeneficiaryModel {
+            id: Uuid::new_v4(),
+            corporate_customer_id: owned.id,
+            beneficiary_customer_id: owner.id,
+            ownership_percentage: Some(Decimal::from(percentage)),
+            control_type: DbControlType::DirectOwnership,
+            description: None,
+            status: DbUboStatus::Active,
+            verification_status: DbVerificationStatus::Verified,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn sar_reason() -> ReasonAndPurposeModel {
+        ReasonAndPurposeModel {
+            id: Uuid::new_v4(),
+            code: HeaplessString::try_from("SUSPICIOUS_ACTIVITY").unwrap(),
+            category: ReasonCategory::SuspiciousActivity,
+            context: ReasonContext::AmlCtf,
+            l1_content: None,
+            l2_content: None,
+            l3_content: None,
+            l1_language_code: None,
+            l2_language_code: None,
+            l3_language_code: None,
+            requires_details: false,
+            is_active: true,
+            severity: None,
+            display_order: 0,
+            compliance_metadata: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            created_by_person_id: Uuid::new_v4(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    fn alert(customer_id: Option<Uuid>, account_id: Uuid) -> ComplianceAlertModel {
+        ComplianceAlertModel {
+            alert_data: ExtendedComplianceAlertModel {
+                id: Uuid::new_v4(),
+                customer_id,
+                account_id: Some(account_id),
+                transaction_id: Some(Uuid::new_v4()),
+                alert_type: AlertType::StructuringDetection,
+                severity: SeverityModel::High,
+                description: HeaplessString::try_from("Repeated deposits just under the threshold").unwrap(),
+                triggered_at: Utc::now(),
+                status: AlertStatusModel::New,
+                assigned_to_person_id: None,
+                resolved_at: None,
+                resolved_by_person_id: None,
+                resolution_notes: None,
+                metadata: None,
+                created_at: Utc::now(),
+                last_updated_at: Utc::now(),
+            },
+        }
+    }
+
+    fn list_match(name: &str, confidence_score: i64) -> SanctionsMatch {
+        SanctionsMatch {
+            matched_name: HeaplessString::try_from(name).unwrap(),
+            confidence_score: Decimal::from(confidence_score),
+            details: None,
+            list_source: HeaplessString::try_from("OFAC").unwrap(),
+        }
+    }
+
+    fn service(
+        repository: Arc<MockComplianceRepository>,
+        customers: Vec<CustomerModel>,
+        matcher: Arc<FakeSanctionsMatcher>,
+    ) -> ComplianceServiceImpl {
+        ComplianceServiceImpl::new(
+            repository,
+            Arc::new(MockCustomerRepository { customers: Mutex::new(customers) }),
+            Arc::new(MockWorkflowRepository::default()),
+            Arc::new(MockReasonRepository { reason: Some(sar_reason()) }),
+            matcher,
+            Arc::new(BankingConfig::default()),
+        )
+    }
+
+    /// Products score 40 when risky, 20 points from 10 transactions, 10 per
+    /// alert; Medium from 20 and High from 50. Countries score nothing.
+    fn risk_service(
+        repository: Arc<MockComplianceRepository>,
+        customers: Arc<MockCustomerRepository>,
+        workflows: Arc<MockWorkflowRepository>,
+        risky_product: Uuid,
+    ) -> ComplianceServiceImpl {
+        let mut config = BankingConfig::default();
+        config.compliance.risk_scoring = RiskScoringRules {
+            country_scores: Vec::new(),
+            default_country_score: Decimal::ZERO,
+            product_scores: vec![ProductRiskScore { product_id: risky_product, score: Decimal::from(40) }],
+            velocity_window_days: 90,
+            velocity_bands: vec![VelocityBand { min_transactions: 10, score: Decimal::from(20) }],
+            score_per_alert: Decimal::from(10),
+            max_alert_score: Decimal::from(30),
+            medium_threshold: Decimal::from(20),
+            high_threshold: Decimal::from(50),
+        };
+        ComplianceServiceImpl::new(
+            repository,
+            customers,
+            workflows,
+            Arc::new(MockReasonRepository { reason: None }),
+            Arc::new(FakeSanctionsMatcher::default()),
+            Arc::new(config),
+        )
+    }
+
+    fn risk_factors(customer_id: Uuid, holdings: &[(Uuid, Uuid)], transaction_count: i64, alert_count: i64) -> CustomerRiskFactorsModel {
+        CustomerRiskFactorsModel {
+            customer_id,
+            country_iso2: Some(HeaplessString::try_from("CM").unwrap()),
+            account_ids: holdings.iter().map(|(account_id, _)| *account_id).collect(),
+            product_ids: holdings.iter().map(|(_, product_id)| *product_id).collect(),
+            transaction_count,
+            alert_count,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recently_screened_customers_are_skipped() {
+        let (fresh, due) = (customer("Recently Screened"), customer("Never Screened"));
+        let repository = Arc::new(MockComplianceRepository { fresh: vec![fresh.id], ..Default::default() });
+        let matcher = Arc::new(FakeSanctionsMatcher::default());
+        let ids = [fresh.id, due.id, due.id];
+        let service = service(repository.clone(), vec![fresh, due.clone()], matcher.clone());
+
+        let summary = service.screen_customers_batch(&ids, ScreeningType::Sanctions).await.unwrap();
+
+        assert_eq!(summary, BatchScreeningSummary { screened: 1, skipped: 1, hits: 0, alerts_created: 0 });
+        assert_eq!(*matcher.screened.lock().unwrap(), vec![due.id]);
+        let screenings = repository.screenings.lock().unwrap();
+        assert_eq!(screenings.len(), 1);
+        assert_eq!(screenings[0].customer_id, due.id);
+        assert_eq!(screenings[0].screening_type, ScreeningTypeModel::Sanctions);
+        assert_eq!(screenings[0].screening_result.as_str(), "Clear");
+        assert!(repository.alerts.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_hits_record_matches_and_raise_alert_from_strongest_match() {
+        let (hit, clear) = (customer("Listed Person"), customer("Clear Person"));
+        let matcher = Arc::new(FakeSanctionsMatcher {
+            matches: HashMap::from([(hit.id, vec![list_match("Listed Persson", 60), list_match("Listed Person", 95)])]),
+            ..Default::default()
+        });
+        let repository = Arc::new(MockComplianceRepository::default());
+        let service = service(repository.clone(), vec![hit.clone(), clear.clone()], matcher);
+
+        let summary = service.screen_customers_batch(&[hit.id, clear.id], ScreeningType::Sanctions).await.unwrap();
+
+        assert_eq!(summary, BatchScreeningSummary { screened: 2, skipped: 0, hits: 1, alerts_created: 1 });
+        let screenings = repository.screenings.lock().unwrap();
+        let hit_screening = screenings.iter().find(|s| s.customer_id == hit.id).unwrap();
+        assert_eq!(hit_screening.screening_result.as_str(), "Match");
+        let matches = repository.matches.lock().unwrap();
+        assert_eq!(matches.len(), 2);
+        assert!(matches.iter().all(|(screening_id, _)| *screening_id == hit_screening.id));
+
+        let alerts = repository.alerts.lock().unwrap();
+        assert_eq!(alerts.len(), 1);
+        let alert = &alerts[0].alert_data;
+        assert_eq!(alert.customer_id, Some(hit.id));
+        assert_eq!(alert.alert_type, AlertType::SanctionsMatch);
+        assert_eq!(alert.severity, SeverityModel::Critical);
+        assert!(alert.description.contains("Listed Person on OFAC"));
+    }
+
+    #[tokio::test]
+    async fn test_rating_rises_to_high_and_opens_edd_once() {
+        let holder = customer("Rising Risk");
+        let (savings, savings_product) = (Uuid::new_v4(), Uuid::new_v4());
+        let (wallet, risky_product) = (Uuid::new_v4(), Uuid::new_v4());
+        let repository = Arc::new(MockComplianceRepository::default());
+        let customers = Arc::new(MockCustomerRepository { customers: Mutex::new(vec![holder.clone()]) });
+        let workflows = Arc::new(MockWorkflowRepository::default());
+        let service = risk_service(repository.clone(), customers.clone(), workflows.clone(), risky_product);
+        let set_factors = |factors| { repository.risk_factors.lock().unwrap().insert(holder.id, factors); };
+        let rating = || customers.customers.lock().unwrap()[0].risk_rating;
+
+        set_factors(risk_factors(holder.id, &[(savings, savings_product)], 3, 0));
+        let quiet = service.recalculate_risk_rating(holder.id).await.unwrap();
+        assert_eq!(quiet.assessment.score, Decimal::ZERO);
+        assert!(!quiet.rating_changed());
+        assert_eq!(rating(), RiskRatingModel::Low);
+
+        set_factors(risk_factors(holder.id, &[(savings, savings_product)], 12, 0));
+        let busy = service.recalculate_risk_rating(holder.id).await.unwrap();
+        assert_eq!((busy.previous_rating, busy.assessment.rating), (RiskRating::Low, RiskRating::Medium));
+        assert!(busy.edd_workflow_ids.is_empty());
+        assert_eq!(rating(), RiskRatingModel::Medium);
+
+        set_factors(risk_factors(holder.id, &[(savings, savings_product), (wallet, risky_product)], 12, 1));
+        let exposed = service.recalculate_risk_rating(holder.id).await.unwrap();
+        assert_eq!(exposed.assessment.score, Decimal::from(70));
+        assert_eq!((exposed.previous_rating, exposed.assessment.rating), (RiskRating::Medium, RiskRating::High));
+        assert_eq!(rating(), RiskRatingModel::High);
+        assert_eq!(exposed.edd_workflow_ids.len(), 2);
+        {
+            let opened = workflows.workflows.lock().unwrap();
+            let mut accounts: Vec<Uuid> = opened.iter().map(|w| w.account_id).collect();
+            accounts.sort();
+            let mut expected = vec![savings, wallet];
+            expected.sort();
+            assert_eq!(accounts, expected);
+            assert!(opened.iter().all(|w| {
+                w.workflow_type == WorkflowTypeModel::ComplianceCheck
+                    && w.current_step == WorkflowStepModel::ComplianceCheck
+                    && w.status == WorkflowStatusModel::PendingAction
+                    && w.initiated_by == SYSTEM_PERSON_ID
+            }));
+        }
+
+        // Staying High opens nothing more
+        let again = service.recalculate_risk_rating(holder.id).await.unwrap();
+        assert!(!again.rating_changed());
+        assert!(again.edd_workflow_ids.is_empty());
+        assert_eq!(workflows.workflows.lock().unwrap().len(), 2);
+
+        let history = repository.find_risk_score_history(holder.id).await.unwrap();
+        let categories: Vec<&str> = history.iter().map(|s| s.risk_category.as_str()).collect();
+        assert_eq!(categories, vec!["High", "High", "Medium", "Low"]);
+        assert!(history.iter().all(|s| s.calculation_method.as_str() == "FactorScoring"));
+        assert!(history[0].factors_considered.contains("ProductMix"));
+    }
+
+    #[tokio::test]
+    async fn test_batch_recalculates_due_customers_and_leaves_blacklist_alone() {
+        let risky_product = Uuid::new_v4();
+        let (rising, mut blacklisted) = (customer("Rising Risk"), customer("Blacklisted"));
+        blacklisted.risk_rating = RiskRatingModel::Blacklisted;
+        let unknown = Uuid::new_v4();
+        let repository = Arc::new(MockComplianceRepository {
+            due_for_review: vec![rising.id, blacklisted.id, unknown],
+            ..Default::default()
+        });
+        let (rising_account, blacklisted_account) = (Uuid::new_v4(), Uuid::new_v4());
+        repository.risk_factors.lock().unwrap().extend([
+            (rising.id, risk_factors(rising.id, &[(rising_account, risky_product)], 15, 0)),
+            (blacklisted.id, risk_factors(blacklisted.id, &[(blacklisted_account, risky_product)], 15, 0)),
+        ]);
+        let customers = Arc::new(MockCustomerRepository { customers: Mutex::new(vec![rising.clone(), blacklisted.clone()]) });
+        let workflows = Arc::new(MockWorkflowRepository::default());
+        let service = risk_service(repository.clone(), customers.clone(), workflows.clone(), risky_product);
+
+        let summary = service.recalculate_all_due(180).await.unwrap();
+
+        assert_eq!(summary, RiskRecalculationSummary { recalculated: 2, rating_changes: 1, edd_workflows_opened: 1, failed: 1 });
+        let ratings: HashMap<Uuid, RiskRatingModel> =
+            customers.customers.lock().unwrap().iter().map(|c| (c.id, c.risk_rating)).collect();
+        assert_eq!(ratings[&rising.id], RiskRatingModel::High);
+        assert_eq!(ratings[&blacklisted.id], RiskRatingModel::Blacklisted);
+        let opened = workflows.workflows.lock().unwrap();
+        assert_eq!(opened.len(), 1);
+        assert_eq!(opened[0].account_id, rising_account);
+        // Both scores are on record even though only one rating moved
+        assert_eq!(repository.risk_scores.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_match_severity_thresholds() {
+        assert!(matches!(match_severity(Decimal::from(90)), Severity::Critical));
+        assert!(matches!(match_severity(Decimal::new(8999, 2)), Severity::High));
+        assert!(matches!(match_severity(Decimal::from(50)), Severity::Medium));
+        assert!(matches!(match_severity(Decimal::from(49)), Severity::Low));
+    }
+
+    #[tokio::test]
+    async fn test_sar_drafted_from_alerts_is_locked_once_submitted() {
+        let holder = customer("Structuring Suspect");
+        let account_id = Uuid::new_v4();
+        let (first, second) = (alert(Some(holder.id), account_id), alert(Some(holder.id), account_id));
+        let late = alert(Some(holder.id), account_id);
+        let repository = Arc::new(MockComplianceRepository {
+            alerts: Mutex::new(vec![first.clone(), second.clone(), late.clone()]),
+            ..Default::default()
+        });
+        let service = service(repository.clone(), vec![holder.clone()], Arc::new(FakeSanctionsMatcher::default()));
+        let (analyst, mlro) = (Uuid::new_v4(), Uuid::new_v4());
+        let alert_status = |alert_id: Uuid| {
+            repository.alerts.lock().unwrap().iter().find(|a| a.alert_data.id == alert_id).unwrap().alert_data.status
+        };
+
+        let draft = service
+            .create_sar_from_alert(first.alert_data.id, HeaplessString::try_from("Initial findings").unwrap(), analyst)
+            .await
+            .unwrap();
+        assert_eq!(draft.status, SarStatus::Draft);
+        assert_eq!(draft.customer_id, holder.id);
+        assert_eq!(draft.customer_full_name.as_str(), "Structuring Suspect");
+        assert_eq!(draft.created_by_person_id, analyst);
+        assert_eq!(draft.alert_ids, vec![first.alert_data.id]);
+        assert_eq!(draft.account_ids, vec![account_id]);
+        assert_eq!(draft.supporting_transaction_ids(), vec![first.alert_data.transaction_id.unwrap()]);
+        assert_eq!(alert_status(first.alert_data.id), AlertStatusModel::Escalated);
+        assert_eq!(alert_status(second.alert_data.id), AlertStatusModel::New);
+
+        let extended = service.add_alert_to_sar(draft.id, second.alert_data.id, analyst).await.unwrap();
+        assert_eq!(extended.alert_ids, vec![first.alert_data.id, second.alert_data.id]);
+        assert_eq!(extended.account_ids, vec![account_id]);
+        assert_eq!(extended.supporting_transaction_ids().len(), 2);
+        assert_eq!(alert_status(second.alert_data.id), AlertStatusModel::Escalated);
+
+        service
+            .update_sar_narrative(draft.id, HeaplessString::try_from("Eleven deposits of 9,900 over two weeks").unwrap())
+            .await
+            .unwrap();
+        let submitted = service.submit_sar(draft.id, mlro).await.unwrap();
+        assert_eq!(submitted.status, SarStatus::Submitted);
+        assert_eq!(submitted.submitted_by_person_id, Some(mlro));
+        assert!(submitted.submitted_at.is_some());
+        assert_eq!(submitted.narrative.as_str(), "Eleven deposits of 9,900 over two weeks");
+
+        let edit = service.update_sar_narrative(draft.id, HeaplessString::try_from("Rewritten").unwrap()).await;
+        assert!(matches!(edit, Err(BankingError::SarLocked { status: SarStatus::Submitted, .. })));
+        let widen = service.add_alert_to_sar(draft.id, late.alert_data.id, analyst).await;
+        assert!(matches!(widen, Err(BankingError::SarLocked { status: SarStatus::Submitted, .. })));
+        assert_eq!(alert_status(late.alert_data.id), AlertStatusModel::New);
+        assert!(matches!(service.submit_sar(draft.id, mlro).await, Err(BankingError::SarLocked { .. })));
+
+        let today = Utc::now().date_naive();
+        let listed = service.list_sars(Some(SarStatus::Submitted), today, today).await.unwrap();
+        assert_eq!(listed.iter().map(|sar| sar.id).collect::<Vec<_>>(), vec![draft.id]);
+        assert!(service.list_sars(Some(SarStatus::Draft), today, today).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sar_alerts_must_belong_to_its_customer() {
+        let (holder, other) = (customer("Structuring Suspect"), customer("Someone Else"));
+        let raised = alert(Some(holder.id), Uuid::new_v4());
+        let foreign = alert(Some(other.id), Uuid::new_v4());
+        let unattached = alert(None, Uuid::new_v4());
+        let repository = Arc::new(MockComplianceRepository {
+            alerts: Mutex::new(vec![raised.clone(), foreign.clone(), unattached.clone()]),
+            ..Default::default()
+        });
+        let service = service(repository.clone(), vec![holder, other], Arc::new(FakeSanctionsMatcher::default()));
+        let analyst = Uuid::new_v4();
+
+        let orphan = service.create_sar_from_alert(unattached.alert_data.id, HeaplessString::new(), analyst).await;
+        assert!(matches!(orphan, Err(BankingError::ValidationError { .. })));
+        assert!(repository.sars.lock().unwrap().is_empty());
+
+        let draft = service.create_sar_from_alert(raised.alert_data.id, HeaplessString::new(), analyst).await.unwrap();
+        let mixed = service.add_alert_to_sar(draft.id, foreign.alert_data.id, analyst).await;
+        assert!(matches!(mixed, Err(BankingError::ValidationError { .. })));
+        let status = repository.alerts.lock().unwrap().iter()
+            .find(|a| a.alert_data.id == foreign.alert_data.id)
+            .unwrap()
+            .alert_data
+            .status;
+        assert_eq!(status, AlertStatusModel::New);
+
+        // A draft without a narrative cannot go to the MLRO
+        let unexplained = service.submit_sar(draft.id, analyst).await;
+        assert!(matches!(unexplained, Err(BankingError::ValidationError { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_indirect_holder_through_intermediate_entity_is_reportable() {
+        let (holding, subsidiary) = (corporate("Holding SA"), corporate("Subsidiary SARL"));
+        let (founder, investor, minority) = (customer("Founder"), customer("Investor"), customer("Minority"));
+        // founder 40% of the holding; investor 50% and minority 40% of the subsidiary owning 60% of it
+        let founder_link = ubo_link(&holding, &founder, 40);
+        let subsidiary_link = ubo_link(&holding, &subsidiary, 60);
+        let investor_link = ubo_link(&subsidiary, &investor, 50);
+        let minority_link = ubo_link(&subsidiary, &minority, 40);
+        let mut dissolved = ubo_link(&subsidiary, &founder, 30);
+        dissolved.status = DbUboStatus::Inactive;
+        let repository = Arc::new(MockComplianceRepository {
+            ubo_links: vec![founder_link.clone(), subsidiary_link, investor_link.clone(), minority_link.clone(), dissolved],
+            ..Default::default()
+        });
+        let customers = vec![holding.clone(), subsidiary, founder.clone(), investor.clone(), minority.clone()];
+        let service = service(repository.clone(), customers, Arc::new(FakeSanctionsMatcher::default()));
+
+        let report = service.validate_ubo_structure(holding.id).await.unwrap();
+
+        assert!(report.is_valid);
+        let owners: Vec<_> = report.owners.iter()
+            .map(|o| (o.beneficiary_customer_id, o.direct_percentage, o.indirect_percentage, o.is_reportable))
+            .collect();
+        assert_eq!(owners, vec![
+            (founder.id, Decimal::from(40), Decimal::ZERO, true),
+            (investor.id, Decimal::ZERO, Decimal::from(30), true),
+            (minority.id, Decimal::ZERO, Decimal::from(24), false),
+        ]);
+        assert_eq!(report.owners[1].total_percentage, Decimal::from(30));
+        assert_eq!(report.owners[1].ubo_link_ids, vec![investor_link.id]);
+
+        let results = repository.ubo_results.lock().unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].verification_complete);
+        assert_eq!(results[0].ubo_chain_link_id_01, Some(founder_link.id));
+        assert_eq!(results[0].ubo_chain_link_id_02, Some(investor_link.id));
+        assert_eq!(results[0].ubo_chain_link_id_03, None);
+        assert_eq!(results[0].requires_update_01, None);
+    }
+
+    #[tokio::test]
+    async fn test_ownership_cycle_is_reported_once() {
+        let (parent, middle, top) = (corporate("Parent SA"), corporate("Middle SA"), corporate("Top SA"));
+        let owner = customer("Owner");
+        // parent <- middle 60% <- top 100% <- parent 50%, with the owner holding the other half of top
+        let repository = Arc::new(MockComplianceRepository {
+            ubo_links: vec![
+                ubo_link(&parent, &middle, 60),
+                ubo_link(&middle, &top, 100),
+                ubo_link(&top, &parent, 50),
+                ubo_link(&top, &owner, 50),
+            ],
+            ..Default::default()
+        });
+        let customers = vec![parent.clone(), middle.clone(), top.clone(), owner.clone()];
+        let service = service(repository.clone(), customers, Arc::new(FakeSanctionsMatcher::default()));
+
+        let report = service.validate_ubo_structure(parent.id).await.unwrap();
+
+        assert!(!report.is_valid);
+        assert_eq!(report.ownership_cycles, vec![vec![parent.id, middle.id, top.id, parent.id]]);
+        assert_eq!(report.owners.len(), 1);
+        assert_eq!(report.owners[0].indirect_percentage, Decimal::from(30));
+
+        let results = repository.ubo_results.lock().unwrap();
+        assert!(!results[0].verification_complete);
+        let issue = results[0].requires_update_01.as_ref().unwrap();
+        assert_eq!(issue.as_str(), format!("Ownership cycle through {}", parent.id));
+    }
+
+    #[tokio::test]
+    async fn test_owners_holding_over_100_percent_fail_validation() {
+        let (company, shell) = (corporate("Company SA"), corporate("Shell SA"));
+        let (majority, shell_owner) = (customer("Majority"), customer("Shell Owner"));
+        let repository = Arc::new(MockComplianceRepository {
+            ubo_links: vec![
+                ubo_link(&company, &majority, 70),
+                ubo_link(&company, &shell, 50),
+                ubo_link(&shell, &shell_owner, 100),
+            ],
+            ..Default::default()
+        });
+        let customers = vec![company.clone(), shell, majority, shell_owner];
+        let service = service(repository.clone(), customers, Arc::new(FakeSanctionsMatcher::default()));
+
+        let report = service.validate_ubo_structure(company.id).await.unwrap();
+
+        assert!(!report.is_valid);
+        assert!(report.ownership_cycles.is_empty());
+        assert_eq!(report.over_allocated_entities, vec![UboOverAllocation {
+            entity_customer_id: company.id,
+            total_percentage: Decimal::from(120),
+        }]);
+        let results = repository.ubo_results.lock().unwrap();
+        assert!(!results[0].verification_complete);
+        assert_eq!(
+            results[0].requires_update_01.as_ref().unwrap().as_str(),
+            format!("Owners of {} hold 120%", company.id)
+        );
+
+        // Only corporate customers have beneficial owners
+        let individual = service.validate_ubo_structure(report.owners[0].beneficiary_customer_id).await;
+        assert!(matches!(individual, Err(BankingError::ValidationError { .. })));
+    }
+}
@@ -1,27 +1,60 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use async_trait::async_trait;
 use chrono::Utc;
+use heapless::String as HeaplessString;
 use uuid::Uuid;
 
 use banking_api::{
     domain::{
-        Customer, CustomerAudit, CustomerDocument, CustomerPortfolio, CustomerStatus, RiskRating,
+        Customer, CustomerAudit, CustomerDocument, CustomerPortfolio, CustomerSearchCriteria, CustomerStatus,
+        ReasonId, ReasonedOperation, RiskRating,
     },
-    service::CustomerService,
+    service::{CustomerService, EntityReferenceService},
+    views::customer_views::{CustomerAccountView, CustomerPortfolioView},
     BankingResult,
 };
-use banking_db::repository::CustomerRepository;
-use crate::mappers::CustomerMapper;
+use banking_db::repository::{
+    AccountHoldRepository, AccountRepository, ComplianceRepository, CustomerRepository, ReasonAndPurposeRepository,
+    WorkflowRepository,
+};
+use crate::mappers::{AccountHoldMapper, AccountMapper, ComplianceMapper, CustomerMapper, WorkflowMapper};
+use crate::validation::ReasonValidation;
+
+/// Number of compliance alerts shown in the customer 360 view
+const RECENT_ALERT_LIMIT: usize = 20;
 
 /// Production implementation of CustomerService
 /// Provides comprehensive Customer Information File (CIF) management
 pub struct CustomerServiceImpl {
     customer_repository: Arc<dyn CustomerRepository>,
+    reason_repository: Arc<dyn ReasonAndPurposeRepository>,
+    account_repository: Arc<dyn AccountRepository>,
+    hold_repository: Arc<dyn AccountHoldRepository>,
+    workflow_repository: Arc<dyn WorkflowRepository>,
+    compliance_repository: Arc<dyn ComplianceRepository>,
+    entity_reference_service: Arc<dyn EntityReferenceService>,
 }
 
 impl CustomerServiceImpl {
-    pub fn new(customer_repository: Arc<dyn CustomerRepository>) -> Self {
-        Self { customer_repository }
+    pub fn new(
+        customer_repository: Arc<dyn CustomerRepository>,
+        reason_repository: Arc<dyn ReasonAndPurposeRepository>,
+        account_repository: Arc<dyn AccountRepository>,
+        hold_repository: Arc<dyn AccountHoldRepository>,
+        workflow_repository: Arc<dyn WorkflowRepository>,
+        compliance_repository: Arc<dyn ComplianceRepository>,
+        entity_reference_service: Arc<dyn EntityReferenceService>,
+    ) -> Self {
+        Self {
+            customer_repository,
+            reason_repository,
+            account_repository,
+            hold_repository,
+            workflow_repository,
+            compliance_repository,
+            entity_reference_service,
+        }
     }
 }
 
@@ -125,7 +158,7 @@ impl CustomerService for CustomerServiceImpl {
         &self,
         customer_id: Uuid,
         status: CustomerStatus,
-        reason_id: Uuid,
+        reason_id: ReasonId,
         _additional_details: Option<&str>,
     ) -> BankingResult<()> {
         // Ensure customer exists
@@ -133,11 +166,16 @@ impl CustomerService for CustomerServiceImpl {
             return Err(banking_api::BankingError::CustomerNotFound(customer_id));
         }
 
-        // TODO: Validate reason_id against ReasonAndPurpose table
+        let reason = ReasonValidation::require(
+            self.reason_repository.as_ref(),
+            reason_id,
+            ReasonedOperation::CustomerStatusChange,
+        )
+        .await?;
         // TODO: Store additional_details if provided
-        
-        // For now, convert reason_id to string for legacy compatibility
-        let reason_string = format!("Reason ID: {reason_id}");
+
+        // The repository still records the status reason as text
+        let reason_string = format!("{} (Reason ID: {reason_id})", reason.code);
         
         // Update status with audit trail
         self.customer_repository
@@ -170,6 +208,54 @@ impl CustomerService for CustomerServiceImpl {
         Ok(())
     }
     
+    /// Merge a duplicate customer record into the survivor
+    async fn merge_customers(
+        &self,
+        survivor_id: Uuid,
+        duplicate_id: Uuid,
+        reason_id: ReasonId,
+        merged_by: Uuid,
+    ) -> BankingResult<()> {
+        if survivor_id == duplicate_id {
+            return Err(banking_api::BankingError::ValidationError {
+                field: "duplicate_id".to_string(),
+                message: "A customer cannot be merged into itself".to_string(),
+            });
+        }
+
+        for (field, customer_id) in [("survivor_id", survivor_id), ("duplicate_id", duplicate_id)] {
+            let customer = self.customer_repository
+                .find_by_id(customer_id)
+                .await?
+                .ok_or(banking_api::BankingError::CustomerNotFound(customer_id))?;
+            if customer.status == banking_db::CustomerStatus::Merged {
+                return Err(banking_api::BankingError::ValidationError {
+                    field: field.to_string(),
+                    message: format!("Customer {customer_id} has already been merged"),
+                });
+            }
+        }
+
+        let reason = ReasonValidation::require(
+            self.reason_repository.as_ref(),
+            reason_id,
+            ReasonedOperation::CustomerMerge,
+        )
+        .await?;
+        let reason_string = format!("{} (Reason ID: {reason_id})", reason.code);
+
+        self.customer_repository
+            .merge_into(survivor_id, duplicate_id, &reason_string, merged_by)
+            .await?;
+
+        tracing::info!(
+            "Customer {} merged into {} by {}",
+            duplicate_id, survivor_id, merged_by
+        );
+
+        Ok(())
+    }
+
     /// Legacy method - deprecated, use update_customer_status with reason_id instead
     async fn update_customer_status_legacy(
         &self,
@@ -220,7 +306,94 @@ impl CustomerService for CustomerServiceImpl {
             .await?
             .ok_or(banking_api::BankingError::CustomerNotFound(customer_id))?;
 
-        Ok(CustomerMapper::portfolio_from_model(portfolio_model))
+        CustomerMapper::portfolio_from_model(portfolio_model)
+    }
+
+    /// Assemble the customer 360 view with one batched query per section
+    async fn get_customer_360(&self, customer_id: Uuid) -> BankingResult<CustomerPortfolioView> {
+        let customer_model = self.customer_repository
+            .find_by_id(customer_id)
+            .await?
+            .ok_or(banking_api::BankingError::CustomerNotFound(customer_id))?;
+        let customer = CustomerMapper::from_model(customer_model)?;
+
+        let ownerships = self.account_repository.find_accounts_by_owner(customer_id).await?;
+        let account_ids: Vec<Uuid> = ownerships.iter().map(|ownership| ownership.account_id).collect();
+        let (account_models, holds, workflows) = if account_ids.is_empty() {
+            (Vec::new(), Vec::new(), Vec::new())
+        } else {
+            (
+                self.account_repository.find_by_ids(&account_ids).await?,
+                self.hold_repository.find_active_holds_by_accounts(&account_ids).await?,
+                self.workflow_repository.find_open_workflows_by_accounts(&account_ids).await?,
+            )
+        };
+
+        let mut account_models: HashMap<Uuid, _> = account_models
+            .into_iter()
+            .map(|account| (account.id, account))
+            .collect();
+        let mut accounts = Vec::with_capacity(ownerships.len());
+        for ownership in ownerships {
+            if let Some(account) = account_models.remove(&ownership.account_id) {
+                accounts.push(CustomerAccountView {
+                    account: AccountMapper::from_model(account)?,
+                    ownership: AccountMapper::account_ownership_from_model(ownership),
+                });
+            }
+        }
+
+        let mut alerts = self.compliance_repository.find_alerts_by_customer(customer_id).await?;
+        alerts.sort_by(|a, b| b.alert_data.triggered_at.cmp(&a.alert_data.triggered_at));
+        alerts.truncate(RECENT_ALERT_LIMIT);
+
+        let external_id = HeaplessString::try_from(customer_id.to_string().as_str())
+            .map_err(|_| banking_api::BankingError::Internal("Customer id does not fit an entity reference".to_string()))?;
+        let entity_references = self.entity_reference_service
+            .find_entity_references_by_reference_external_id(external_id)
+            .await
+            .map_err(|e| banking_api::BankingError::Internal(format!("Failed to find entity references: {e}")))?;
+
+        Ok(CustomerPortfolioView {
+            risk_rating: customer.risk_rating,
+            customer,
+            accounts,
+            active_holds: holds.into_iter().map(AccountHoldMapper::account_hold_from_model).collect(),
+            open_workflows: workflows
+                .into_iter()
+                .map(WorkflowMapper::from_model)
+                .collect::<BankingResult<Vec<_>>>()?,
+            recent_alerts: alerts.into_iter().map(ComplianceMapper::compliance_alert_from_model).collect(),
+            entity_references,
+            assembled_at: Utc::now(),
+        })
+    }
+
+    /// Find customers matching the criteria, ordered by name
+    async fn search_customers(&self, criteria: CustomerSearchCriteria) -> BankingResult<Vec<Customer>> {
+        if criteria.limit <= 0 || criteria.offset < 0 {
+            return Err(banking_api::BankingError::ValidationError {
+                field: "limit".to_string(),
+                message: "Search requires a positive limit and a non-negative offset".to_string(),
+            });
+        }
+        if let Some(tags) = &criteria.tags {
+            tags.validate().map_err(|message| banking_api::BankingError::ValidationError {
+                field: "tags".to_string(),
+                message,
+            })?;
+        }
+
+        let customer_models = self.customer_repository
+            .search(CustomerMapper::search_criteria_to_model(criteria))
+            .await?;
+
+        let mut customers = Vec::new();
+        for model in customer_models {
+            customers.push(CustomerMapper::from_model(model)?);
+        }
+
+        Ok(customers)
     }
 
     /// Find customers by identity document
@@ -419,14 +592,47 @@ impl CustomerServiceImpl {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use banking_api::domain::{CustomerType, IdentityType};
+    use banking_api::domain::{
+        AuditLog, CustomerType, EntityReference, IdentityType, ReasonCategory, ReasonContext,
+    };
+    use banking_api::service::EntityReferenceServiceResult;
+    use banking_api::BankingError;
+    use banking_db::models::{
+        AccountBalanceCalculationModel, AccountBalanceChangeRecordModel, AccountBalanceSnapshotModel,
+        AccountFinalSettlementModel, AccountHoldExpiryJobModel, AccountHoldModel, AccountHoldReleaseRequestModel,
+        AccountHoldSummaryModel, AccountInterestAccrualModel, AccountMandateModel, AccountModel, AccountOwnershipModel,
+        AccountRelationshipModel, AccountWorkflowModel, AlertStatus as AlertStatusModel, ComplianceAlertModel,
+        ComplianceResultModel, ComplianceRiskScoreModel, CustomerModel, CustomerRiskFactorsModel, DbAccountStatus,
+        DbAccountType, DbBalanceChangeSource, DbOwnershipType, DbSigningCondition, ExtendedComplianceAlertModel,
+        HighHoldRatioAccount, HoldAgingBucket, HoldAnalyticsSummary, HoldOverrideRecord, HoldPriority,
+        HoldPrioritySummary, HoldReleaseRecordModel, HoldStatus, HoldType, HoldValidationError, JudicialHoldReportData,
+        LoanPenaltyAccrualModel, ReasonAndPurpose as ReasonAndPurposeModel, SanctionsMatchModel,
+        SanctionsScreeningModel, SarDataModel, SarStatus, ScreeningType, Severity as SeverityModel,
+        UboVerificationResultModel, WorkflowStepRecordModel, account::UltimateBeneficiaryModel,
+    };
+    use banking_db::repository::{
+        BulkOperationResult, DataIntegrityReport, LocalizedReasonModel, ReasonChangeRecord,
+        ReasonUsageStatistics, ReasonValidationRules,
+    };
+    use banking_db::repository::compliance_repository::{
+        AlertSummaryReport, ComplianceSummaryReport, SanctionsComplianceReport, TransactionMonitoringRecord,
+        TransactionMonitoringResult,
+    };
+    use banking_db::repository::workflow_repository::{
+        WorkflowBottleneckReport, WorkflowFilter, WorkflowMetricsReport, WorkflowPage, WorkflowPerformanceReport,
+        WorkflowSearchResult,
+    };
+    use banking_db::AlertType;
+    use chrono::{DateTime, Duration, NaiveDate};
+    use heapless::String as HeaplessString;
+    use rust_decimal::Decimal;
 
     // Mock repository for testing would go here
     // This is a simplified example - in production you'd use a proper mock framework
 
     #[tokio::test]
     async fn test_validate_customer_data() {
-        let service = CustomerServiceImpl::new(Arc::new(MockCustomerRepository {}));
+        let service = customer_service(MockCustomerRepository::default(), None);
 
         #[allow(deprecated)]
         let valid_customer = Customer::new(
@@ -459,8 +665,274 @@ mod tests {
         assert!(invalid_customer.validate().is_err());
     }
 
+    fn reason(category: ReasonCategory, context: ReasonContext) -> ReasonAndPurposeModel {
+        ReasonAndPurposeModel {
+            id: Uuid::new_v4(),
+            code: HeaplessString::try_from("CUSTOMER_REASON").unwrap(),
+            category,
+            context,
+            l1_content: None,
+            l2_content: None,
+            l3_content: None,
+            l1_language_code: None,
+            l2_language_code: None,
+            l3_language_code: None,
+            requires_details: false,
+            is_active: true,
+            severity: None,
+            display_order: 0,
+            compliance_metadata: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            created_by_person_id: Uuid::new_v4(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_customer_status_requires_matching_reason() {
+        let reversal = reason(ReasonCategory::TransactionReversal, ReasonContext::Transaction);
+        let reversal_id = ReasonId(reversal.id);
+        let service = customer_service(MockCustomerRepository::default(), Some(reversal));
+        let result = service
+            .update_customer_status(Uuid::new_v4(), CustomerStatus::Blacklisted, reversal_id, None)
+            .await;
+        assert!(matches!(
+            result,
+            Err(BankingError::ReasonMismatch { operation: ReasonedOperation::CustomerStatusChange, .. })
+        ));
+
+        let flag = reason(ReasonCategory::ComplianceFlag, ReasonContext::Compliance);
+        let flag_id = ReasonId(flag.id);
+        let service = customer_service(MockCustomerRepository::default(), Some(flag));
+        assert!(service
+            .update_customer_status(Uuid::new_v4(), CustomerStatus::Blacklisted, flag_id, None)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_merge_customers_rejects_self_merge() {
+        let service = customer_service(MockCustomerRepository::default(), None);
+        let customer_id = Uuid::new_v4();
+        let result = service
+            .merge_customers(customer_id, customer_id, ReasonId(Uuid::new_v4()), Uuid::new_v4())
+            .await;
+        assert!(matches!(result, Err(BankingError::ValidationError { field, .. }) if field == "duplicate_id"));
+    }
+
+    #[tokio::test]
+    async fn test_get_customer_360_assembles_every_section() {
+        let customer = customer_model();
+        let customer_id = customer.id;
+        let current = account_model(Decimal::new(150_000, 2));
+        let savings = account_model(Decimal::new(2_500_000, 2));
+        let hold = hold_model(savings.id, Decimal::new(50_000, 2));
+        let older_alert = alert_model(customer_id, Utc::now() - Duration::days(3));
+        let alert = alert_model(customer_id, Utc::now());
+        let service = CustomerServiceImpl::new(
+            Arc::new(MockCustomerRepository { customer: Some(customer) }),
+            Arc::new(MockReasonRepository { reason: None }),
+            Arc::new(MockAccountRepository {
+                ownerships: vec![ownership_model(current.id, customer_id), ownership_model(savings.id, customer_id)],
+                accounts: vec![savings.clone(), current.clone()],
+            }),
+            Arc::new(MockAccountHoldRepository { holds: vec![hold.clone()] }),
+            Arc::new(MockWorkflowRepository),
+            Arc::new(MockComplianceRepository { alerts: vec![older_alert.clone(), alert.clone()] }),
+            Arc::new(MockEntityReferenceService),
+        );
+
+        let view = service.get_customer_360(customer_id).await.unwrap();
+
+        assert_eq!(view.customer.id, customer_id);
+        assert_eq!(view.risk_rating, RiskRating::Medium);
+        let snapshot = serde_json::to_value(&view).unwrap();
+        let accounts = snapshot["accounts"].as_array().unwrap();
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[0]["account"]["id"], serde_json::json!(current.id));
+        assert_eq!(accounts[0]["account"]["current_balance"], serde_json::json!("1500.00"));
+        assert_eq!(accounts[0]["ownership"]["customer_id"], serde_json::json!(customer_id));
+        assert_eq!(accounts[1]["account"]["id"], serde_json::json!(savings.id));
+        assert_eq!(accounts[1]["account"]["available_balance"], serde_json::json!("25000.00"));
+        let holds = snapshot["active_holds"].as_array().unwrap();
+        assert_eq!(holds.len(), 1);
+        assert_eq!(holds[0]["id"], serde_json::json!(hold.id));
+        assert_eq!(holds[0]["account_id"], serde_json::json!(savings.id));
+        assert_eq!(holds[0]["amount"], serde_json::json!("500.00"));
+        let alerts = snapshot["recent_alerts"].as_array().unwrap();
+        assert_eq!(alerts.len(), 2);
+        assert_eq!(alerts[0]["id"], serde_json::json!(alert.alert_data.id));
+        assert_eq!(alerts[0]["severity"], serde_json::json!("High"));
+        assert_eq!(alerts[1]["id"], serde_json::json!(older_alert.alert_data.id));
+        assert_eq!(snapshot["open_workflows"], serde_json::json!([]));
+        assert_eq!(snapshot["entity_references"], serde_json::json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_get_customer_360_unknown_customer_is_not_found() {
+        let service = customer_service(MockCustomerRepository::default(), None);
+        let customer_id = Uuid::new_v4();
+        let result = service.get_customer_360(customer_id).await;
+        assert!(matches!(result, Err(BankingError::CustomerNotFound(id)) if id == customer_id));
+    }
+
+    /// Service over the given customers with every other repository empty
+    fn customer_service(customers: MockCustomerRepository, reason: Option<ReasonAndPurposeModel>) -> CustomerServiceImpl {
+        CustomerServiceImpl::new(
+            Arc::new(customers),
+            Arc::new(MockReasonRepository { reason }),
+            Arc::new(MockAccountRepository::default()),
+            Arc::new(MockAccountHoldRepository::default()),
+            Arc::new(MockWorkflowRepository),
+            Arc::new(MockComplianceRepository::default()),
+            Arc::new(MockEntityReferenceService),
+        )
+    }
+
+    fn customer_model() -> CustomerModel {
+        CustomerModel {
+            id: Uuid::new_v4(),
+            customer_type: banking_db::models::CustomerType::Individual,
+            full_name: HeaplessString::try_from("Jane Doe").unwrap(),
+            id_type: banking_db::models::IdentityType::NationalId,
+            id_number: HeaplessString::try_from("ID654321").unwrap(),
+            risk_rating: banking_db::models::RiskRating::Medium,
+            status: banking_db::models::CustomerStatus::Active,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+            preferred_language_code: None,
+            duplicate_of_customer_id: None,
+        }
+    }
+
+    fn ownership_model(account_id: Uuid, customer_id: Uuid) -> AccountOwnershipModel {
+        AccountOwnershipModel {
+            id: Uuid::new_v4(),
+            account_id,
+            customer_id,
+            ownership_type: DbOwnershipType::Single,
+            ownership_percentage: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn account_model(balance: Decimal) -> AccountModel {
+        AccountModel {
+            id: Uuid::new_v4(),
+            product_id: Uuid::new_v4(),
+            account_type: DbAccountType::Savings,
+            account_status: DbAccountStatus::Active,
+            signing_condition: DbSigningCondition::None,
+            currency: HeaplessString::try_from("USD").unwrap(),
+            open_date: NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+            domicile_agency_branch_id: Uuid::new_v4(),
+            account_number: HeaplessString::try_from("10005000010000000004262").unwrap(),
+            gl_code_suffix: None,
+            current_balance: balance,
+            available_balance: balance,
+            accrued_interest: Decimal::ZERO,
+            overdraft_limit: None,
+            original_principal: None,
+            outstanding_principal: None,
+            loan_interest_rate: None,
+            loan_term_months: None,
+            disbursement_date: None,
+            maturity_date: None,
+            installment_amount: None,
+            next_due_date: None,
+            penalty_rate: None,
+            collateral_id: None,
+            loan_purpose_id: None,
+            close_date: None,
+            last_activity_date: None,
+            dormancy_threshold_days: None,
+            reactivation_required: false,
+            pending_closure_reason_id: None,
+            last_disbursement_instruction_id: None,
+            status_changed_by_person_id: None,
+            status_change_reason_id: None,
+            status_change_timestamp: None,
+            most_significant_account_hold_id: None,
+            account_ownership_id: None,
+            access01_account_relationship_id: None,
+            access02_account_relationship_id: None,
+            access03_account_relationship_id: None,
+            access04_account_relationship_id: None,
+            access05_account_relationship_id: None,
+            access06_account_relationship_id: None,
+            access07_account_relationship_id: None,
+            access11_account_mandate_id: None,
+            access12_account_mandate_id: None,
+            access13_account_mandate_id: None,
+            access14_account_mandate_id: None,
+            access15_account_mandate_id: None,
+            access16_account_mandate_id: None,
+            access17_account_mandate_id: None,
+            interest01_ultimate_beneficiary_id: None,
+            interest02_ultimate_beneficiary_id: None,
+            interest03_ultimate_beneficiary_id: None,
+            interest04_ultimate_beneficiary_id: None,
+            interest05_ultimate_beneficiary_id: None,
+            interest06_ultimate_beneficiary_id: None,
+            interest07_ultimate_beneficiary_id: None,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    fn hold_model(account_id: Uuid, amount: Decimal) -> AccountHoldModel {
+        AccountHoldModel {
+            id: Uuid::new_v4(),
+            account_id,
+            amount,
+            hold_type: HoldType::JudicialLien,
+            reason_id: Uuid::new_v4(),
+            additional_details: None,
+            placed_by_person_id: Uuid::new_v4(),
+            placed_at: Utc::now(),
+            expires_at: None,
+            status: HoldStatus::Active,
+            released_at: None,
+            released_by_person_id: None,
+            priority: HoldPriority::High,
+            source_reference: None,
+            automatic_release: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn alert_model(customer_id: Uuid, triggered_at: DateTime<Utc>) -> ComplianceAlertModel {
+        ComplianceAlertModel {
+            alert_data: ExtendedComplianceAlertModel {
+                id: Uuid::new_v4(),
+                customer_id: Some(customer_id),
+                account_id: None,
+                transaction_id: None,
+                alert_type: AlertType::VelocityCheck,
+                severity: SeverityModel::High,
+                description: HeaplessString::try_from("Unusual transaction velocity").unwrap(),
+                triggered_at,
+                status: AlertStatusModel::New,
+                assigned_to_person_id: None,
+                resolved_at: None,
+                resolved_by_person_id: None,
+                resolution_notes: None,
+                metadata: None,
+                created_at: triggered_at,
+                last_updated_at: triggered_at,
+            },
+        }
+    }
+
     // Mock repository implementation for testing
-    struct MockCustomerRepository;
+    #[derive(Default)]
+    struct MockCustomerRepository {
+        customer: Option<CustomerModel>,
+    }
 
     #[async_trait]
     impl CustomerRepository for MockCustomerRepository {
@@ -472,7 +944,12 @@ mod tests {
             unimplemented!()
         }
 
-        async fn find_by_id(&self, _customer_id: Uuid) -> BankingResult<Option<banking_db::models::CustomerModel>> {
+        async fn find_by_id(&self, customer_id: Uuid) -> BankingResult<Option<banking_db::models::CustomerModel>> {
+            Ok(self.customer.clone().filter(|c| c.id == customer_id))
+        }
+
+
+        async fn find_by_ids(&self, _customer_ids: &[Uuid]) -> BankingResult<Vec<banking_db::models::CustomerModel>> {
             unimplemented!()
         }
 
@@ -492,6 +969,10 @@ mod tests {
             unimplemented!()
         }
 
+        async fn search(&self, _criteria: banking_db::models::CustomerSearchCriteriaModel) -> BankingResult<Vec<banking_db::models::CustomerModel>> {
+            unimplemented!()
+        }
+
         async fn update_risk_rating(&self, _customer_id: Uuid, _risk_rating: banking_db::models::RiskRating, _authorized_by: Uuid) -> BankingResult<()> {
             Ok(())
         }
@@ -516,6 +997,10 @@ mod tests {
             unimplemented!()
         }
 
+        async fn merge_into(&self, _survivor_id: Uuid, _duplicate_id: Uuid, _reason: &str, _merged_by: Uuid) -> BankingResult<()> {
+            unimplemented!()
+        }
+
         async fn delete(&self, _customer_id: Uuid, _deleted_by: Uuid) -> BankingResult<()> {
             unimplemented!()
         }
@@ -532,4 +1017,462 @@ mod tests {
             unimplemented!()
         }
     }
+
+    struct MockReasonRepository {
+        reason: Option<ReasonAndPurposeModel>,
+    }
+
+    #[async_trait]
+    impl ReasonAndPurposeRepository for MockReasonRepository {
+        async fn create(&self, _reason: ReasonAndPurposeModel) -> BankingResult<ReasonAndPurposeModel> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(&self, _reason_id: Uuid) -> BankingResult<Option<ReasonAndPurposeModel>> {
+            Ok(self.reason.clone())
+        }
+
+        async fn find_by_code(&self, _code: &str) -> BankingResult<Option<ReasonAndPurposeModel>> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _reason: ReasonAndPurposeModel) -> BankingResult<ReasonAndPurposeModel> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _reason_id: Uuid) -> BankingResult<()> {
+            unimplemented!()
+        }
+
+        async fn deactivate(&self, _reason_id: Uuid, _deactivated_by: Uuid) -> BankingResult<()> {
+            unimplemented!()
+        }
+
+        async fn reactivate(&self, _reason_id: Uuid, _reactivated_by: Uuid) -> BankingResult<()> {
+            unimplemented!()
+        }
+
+        async fn find_all_active(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> {
+            unimplemented!()
+        }
+
+        async fn find_by_category(&self, _category: ReasonCategory) -> BankingResult<Vec<ReasonAndPurposeModel>> {
+            unimplemented!()
+        }
+
+        async fn find_by_context(&self, _context: ReasonContext) -> BankingResult<Vec<ReasonAndPurposeModel>> {
+            unimplemented!()
+        }
+
+        async fn find_by_category_and_context(&self, _category: ReasonCategory, _context: ReasonContext) -> BankingResult<Vec<ReasonAndPurposeModel>> {
+            unimplemented!()
+        }
+
+        async fn find_by_severity(&self, _severity: ReasonSeverity) -> BankingResult<Vec<ReasonAndPurposeModel>> {
+            unimplemented!()
+        }
+
+        async fn search_by_content(&self, _search_term: &str, _language_codes: Option<Vec<[u8; 3]>>) -> BankingResult<Vec<ReasonAndPurposeModel>> {
+            unimplemented!()
+        }
+
+        async fn find_for_display(&self, _category: Option<ReasonCategory>, _context: Option<ReasonContext>, _active_only: bool) -> BankingResult<Vec<ReasonAndPurposeModel>> {
+            unimplemented!()
+        }
+
+        async fn find_reportable_compliance_reasons(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> {
+            unimplemented!()
+        }
+
+        async fn find_sar_triggering_reasons(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> {
+            unimplemented!()
+        }
+
+        async fn find_ctr_triggering_reasons(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> {
+            unimplemented!()
+        }
+
+        async fn find_aml_ctf_reasons(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> {
+            unimplemented!()
+        }
+
+        async fn find_kyc_reasons(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> {
+            unimplemented!()
+        }
+
+        async fn find_by_jurisdiction(&self, _jurisdiction_code: [u8; 2]) -> BankingResult<Vec<ReasonAndPurposeModel>> {
+            unimplemented!()
+        }
+
+        async fn find_escalation_required_reasons(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> {
+            unimplemented!()
+        }
+
+        async fn get_usage_count(&self, _reason_id: Uuid, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<u64> {
+            unimplemented!()
+        }
+
+        async fn get_usage_statistics(&self, _reason_id: Uuid, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<ReasonUsageStatistics> {
+            unimplemented!()
+        }
+
+        async fn get_top_used_reasons_by_category(&self, _category: ReasonCategory, _limit: i32, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<Vec<ReasonUsageStatistics>> {
+            unimplemented!()
+        }
+
+        async fn find_unused_reasons(&self, _since_date: NaiveDate) -> BankingResult<Vec<ReasonAndPurposeModel>> {
+            unimplemented!()
+        }
+
+        async fn record_usage(&self, _reason_id: Uuid, _context: ReasonContext, _used_by: &str, _additional_context: Option<&str>) -> BankingResult<()> {
+            unimplemented!()
+        }
+
+        async fn get_change_history(&self, _reason_id: Uuid) -> BankingResult<Vec<ReasonChangeRecord>> {
+            unimplemented!()
+        }
+
+        async fn record_change(&self, _change_record: ReasonChangeRecord) -> BankingResult<ReasonChangeRecord> {
+            unimplemented!()
+        }
+
+        async fn code_exists(&self, _code: &str, _exclude_id: Option<Uuid>) -> BankingResult<bool> {
+            unimplemented!()
+        }
+
+        async fn is_active(&self, _reason_id: Uuid) -> BankingResult<bool> {
+            unimplemented!()
+        }
+
+        async fn is_valid_for_context(&self, _reason_id: Uuid, _context: ReasonContext) -> BankingResult<bool> {
+            unimplemented!()
+        }
+
+        async fn get_validation_rules(&self, _reason_id: Uuid) -> BankingResult<Option<ReasonValidationRules>> {
+            unimplemented!()
+        }
+
+        async fn bulk_insert(&self, _reasons: Vec<ReasonAndPurposeModel>) -> BankingResult<BulkOperationResult> {
+            unimplemented!()
+        }
+
+        async fn bulk_update_display_orders(&self, _category: ReasonCategory, _order_updates: Vec<(Uuid, i32)>, _updated_by_person_id: &str) -> BankingResult<()> {
+            unimplemented!()
+        }
+
+        async fn bulk_update_status(&self, _reason_ids: Vec<Uuid>, _is_active: bool, _updated_by_person_id: &str) -> BankingResult<BulkOperationResult> {
+            unimplemented!()
+        }
+
+        async fn update_localized_content(&self, _reason_id: Uuid, _language_code: [u8; 3], _content: &str, _updated_by_person_id: &str) -> BankingResult<()> {
+            unimplemented!()
+        }
+
+        async fn remove_localized_content(&self, _reason_id: Uuid, _language_code: [u8; 3], _updated_by_person_id: &str) -> BankingResult<()> {
+            unimplemented!()
+        }
+
+        async fn find_with_languages(&self, _language_codes: &[[u8; 3]], _category: Option<ReasonCategory>, _context: Option<ReasonContext>) -> BankingResult<Vec<LocalizedReasonModel>> {
+            unimplemented!()
+        }
+
+        async fn find_missing_localization(&self, _language_code: [u8; 3], _category: Option<ReasonCategory>) -> BankingResult<Vec<ReasonAndPurposeModel>> {
+            unimplemented!()
+        }
+
+        async fn count_total(&self) -> BankingResult<i64> {
+            unimplemented!()
+        }
+
+        async fn count_by_category(&self, _category: ReasonCategory) -> BankingResult<i64> {
+            unimplemented!()
+        }
+
+        async fn count_by_context(&self, _context: ReasonContext) -> BankingResult<i64> {
+            unimplemented!()
+        }
+
+        async fn validate_data_integrity(&self) -> BankingResult<DataIntegrityReport> {
+            unimplemented!()
+        }
+
+        async fn get_categories_in_use(&self) -> BankingResult<Vec<ReasonCategory>> {
+            unimplemented!()
+        }
+
+        async fn get_contexts_in_use(&self) -> BankingResult<Vec<ReasonContext>> {
+            unimplemented!()
+        }
+    }
+
+    /// Accounts owned through `ownerships`; `find_by_ids` returns them in storage order
+    #[derive(Default)]
+    struct MockAccountRepository {
+        ownerships: Vec<AccountOwnershipModel>,
+        accounts: Vec<AccountModel>,
+    }
+
+    #[async_trait]
+    impl AccountRepository for MockAccountRepository {
+        async fn create(&self, _account: AccountModel) -> BankingResult<AccountModel> { unimplemented!() }
+        async fn update(&self, _account: AccountModel) -> BankingResult<AccountModel> { unimplemented!() }
+        async fn find_by_id(&self, _account_id: Uuid) -> BankingResult<Option<AccountModel>> { unimplemented!() }
+        async fn find_by_customer_id(&self, _customer_id: Uuid) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn find_by_product_id(&self, _product_id: Uuid) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn find_by_status(&self, _status: &str) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn find_by_account_type(&self, _account_type: DbAccountType) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn find_dormancy_candidates(&self, _reference_date: NaiveDate, _threshold_days: i32, _product_id: Option<Uuid>) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn find_pending_closure(&self) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn find_interest_bearing_accounts(&self) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn find_interest_bearing_accounts_after(&self, _after_account_id: Option<Uuid>, _limit: i64) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn update_status(&self, _account_id: Uuid, _status: &str, _reason_id: Uuid, _changed_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn update_status_legacy(&self, _account_id: Uuid, _status: &str, _reason: &str, _changed_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn bulk_update_status(&self, _account_ids: &[Uuid], _status: &str, _reason_id: Uuid, _changed_by: Uuid) -> Vec<(Uuid, BankingResult<()>)> { unimplemented!() }
+        async fn update_balance(&self, _account_id: Uuid, _current_balance: Decimal, _available_balance: Decimal, _change_source: DbBalanceChangeSource, _transaction_id: Option<Uuid>, _changed_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn get_balance_history(&self, _account_id: Uuid, _from: DateTime<Utc>, _to: DateTime<Utc>) -> BankingResult<Vec<AccountBalanceChangeRecordModel>> { unimplemented!() }
+        async fn update_accrued_interest(&self, _account_id: Uuid, _accrued_interest: Decimal) -> BankingResult<()> { unimplemented!() }
+        async fn reset_accrued_interest(&self, _account_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn post_accrued_interest(&self, _account_id: Uuid, _amount: Decimal, _change_source: DbBalanceChangeSource, _transaction_id: Option<Uuid>, _changed_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn apply_interest_accruals(&self, _accruals: Vec<AccountInterestAccrualModel>) -> BankingResult<Vec<Uuid>> { unimplemented!() }
+        async fn create_ownership(&self, _ownership: AccountOwnershipModel) -> BankingResult<AccountOwnershipModel> { unimplemented!() }
+        async fn find_ownership_by_account(&self, _account_id: Uuid) -> BankingResult<Vec<AccountOwnershipModel>> { unimplemented!() }
+        async fn find_accounts_by_owner(&self, customer_id: Uuid) -> BankingResult<Vec<AccountOwnershipModel>> {
+            Ok(self.ownerships.iter().filter(|o| o.customer_id == customer_id).cloned().collect())
+        }
+        async fn delete_ownership(&self, _ownership_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn create_relationship(&self, _relationship: AccountRelationshipModel) -> BankingResult<AccountRelationshipModel> { unimplemented!() }
+        async fn find_relationships_by_account(&self, _account_id: Uuid) -> BankingResult<Vec<AccountRelationshipModel>> { unimplemented!() }
+        async fn find_relationships_by_entity(&self, _entity_id: Uuid, _entity_type: &str) -> BankingResult<Vec<AccountRelationshipModel>> { unimplemented!() }
+        async fn update_relationship(&self, _relationship: AccountRelationshipModel) -> BankingResult<AccountRelationshipModel> { unimplemented!() }
+        async fn delete_relationship(&self, _relationship_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn create_mandate(&self, _mandate: AccountMandateModel) -> BankingResult<AccountMandateModel> { unimplemented!() }
+        async fn find_mandates_by_account(&self, _account_id: Uuid) -> BankingResult<Vec<AccountMandateModel>> { unimplemented!() }
+        async fn find_mandates_by_grantee(&self, _grantee_customer_id: Uuid) -> BankingResult<Vec<AccountMandateModel>> { unimplemented!() }
+        async fn update_mandate_status(&self, _mandate_id: Uuid, _status: &str) -> BankingResult<()> { unimplemented!() }
+        async fn find_active_mandates(&self, _account_id: Uuid) -> BankingResult<Vec<AccountMandateModel>> { unimplemented!() }
+        async fn create_final_settlement(&self, _settlement: AccountFinalSettlementModel) -> BankingResult<AccountFinalSettlementModel> { unimplemented!() }
+        async fn find_settlement_by_account(&self, _account_id: Uuid) -> BankingResult<Option<AccountFinalSettlementModel>> { unimplemented!() }
+        async fn update_settlement_status(&self, _settlement_id: Uuid, _status: &str) -> BankingResult<()> { unimplemented!() }
+        async fn get_status_history(&self, _account_id: Uuid) -> BankingResult<Vec<banking_db::models::account::AccountStatusChangeRecordModel>> { unimplemented!() }
+        async fn add_status_change(&self, _status_change: banking_db::models::account::AccountStatusChangeRecordModel) -> BankingResult<banking_db::models::account::AccountStatusChangeRecordModel> { unimplemented!() }
+        async fn save_balance_snapshot(&self, _snapshot: AccountBalanceSnapshotModel) -> BankingResult<AccountBalanceSnapshotModel> { unimplemented!() }
+        async fn find_latest_balance_snapshot_before(&self, _account_id: Uuid, _before_date: NaiveDate) -> BankingResult<Option<AccountBalanceSnapshotModel>> { unimplemented!() }
+        async fn find_balance_snapshots_by_date(&self, _snapshot_date: NaiveDate) -> BankingResult<Vec<AccountBalanceSnapshotModel>> { unimplemented!() }
+        async fn save_loan_penalty_accrual(&self, _accrual: LoanPenaltyAccrualModel) -> BankingResult<LoanPenaltyAccrualModel> { unimplemented!() }
+        async fn find_latest_loan_penalty_accrual_before(&self, _loan_account_id: Uuid, _before_date: NaiveDate) -> BankingResult<Option<LoanPenaltyAccrualModel>> { unimplemented!() }
+        async fn save_overdraft_interest_accrual(&self, _accrual: banking_db::models::casa::OverdraftInterestAccrual) -> BankingResult<banking_db::models::casa::OverdraftInterestAccrual> { unimplemented!() }
+        async fn find_latest_overdraft_interest_accrual_before(&self, _account_id: Uuid, _before_date: NaiveDate) -> BankingResult<Option<banking_db::models::casa::OverdraftInterestAccrual>> { unimplemented!() }
+        async fn exists(&self, _account_id: Uuid) -> BankingResult<bool> { unimplemented!() }
+        async fn find_by_ids(&self, account_ids: &[Uuid]) -> BankingResult<Vec<AccountModel>> {
+            Ok(self.accounts.iter().filter(|a| account_ids.contains(&a.id)).cloned().collect())
+        }
+        async fn exist_by_ids(&self, _account_ids: &[Uuid]) -> BankingResult<Vec<(Uuid, bool)>> { unimplemented!() }
+        async fn count_by_customer(&self, _customer_id: Uuid) -> BankingResult<i64> { unimplemented!() }
+        async fn count_by_product(&self, _product_id: Uuid) -> BankingResult<i64> { unimplemented!() }
+        async fn list(&self, _offset: i64, _limit: i64) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn find_by_account_number(&self, _account_number: &str) -> BankingResult<Option<AccountModel>> { unimplemented!() }
+        async fn list_after(&self, _after_account_id: Option<Uuid>, _limit: i64) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn count(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn update_last_activity_date(&self, _account_id: Uuid, _activity_date: chrono::NaiveDate) -> BankingResult<()> { unimplemented!() }
+    }
+
+    #[derive(Default)]
+    struct MockAccountHoldRepository {
+        holds: Vec<AccountHoldModel>,
+    }
+
+    #[async_trait]
+    impl AccountHoldRepository for MockAccountHoldRepository {
+        async fn create_hold(&self, _hold: AccountHoldModel) -> BankingResult<AccountHoldModel> { unimplemented!() }
+        async fn find_holds_by_account(&self, _account_id: Uuid) -> BankingResult<Vec<AccountHoldModel>> { unimplemented!() }
+        async fn find_active_holds(&self, _account_id: Uuid) -> BankingResult<Vec<AccountHoldModel>> { unimplemented!() }
+        async fn find_active_holds_by_accounts(&self, account_ids: &[Uuid]) -> BankingResult<Vec<AccountHoldModel>> {
+            Ok(self.holds.iter().filter(|h| account_ids.contains(&h.account_id)).cloned().collect())
+        }
+        async fn place_hold(&self, _hold: AccountHoldModel, _allow_overdraw: bool) -> BankingResult<AccountHoldModel> { unimplemented!() }
+        async fn release_hold(&self, _hold_id: Uuid, _released_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn release_expired_holds(&self, _reference_date: DateTime<Utc>) -> BankingResult<Vec<AccountHoldModel>> { unimplemented!() }
+        async fn update_hold(&self, _hold: AccountHoldModel) -> BankingResult<AccountHoldModel> { unimplemented!() }
+        async fn get_hold_by_id(&self, _hold_id: Uuid) -> BankingResult<Option<AccountHoldModel>> { unimplemented!() }
+        async fn get_active_holds_for_account(&self, _account_id: Uuid, _hold_types: Option<Vec<String>>) -> BankingResult<Vec<AccountHoldModel>> { unimplemented!() }
+        async fn get_holds_by_status(&self, _account_id: Option<Uuid>, _status: String, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<Vec<AccountHoldModel>> { unimplemented!() }
+        async fn get_holds_by_type(&self, _hold_type: String, _status: Option<String>, _account_ids: Option<Vec<Uuid>>, _limit: Option<i32>) -> BankingResult<Vec<AccountHoldModel>> { unimplemented!() }
+        async fn get_hold_history(&self, _account_id: Uuid, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>, _include_released: bool) -> BankingResult<Vec<AccountHoldModel>> { unimplemented!() }
+        async fn calculate_total_holds(&self, _account_id: Uuid, _exclude_hold_types: Option<Vec<String>>) -> BankingResult<Decimal> { unimplemented!() }
+        async fn get_hold_amounts_by_priority(&self, _account_id: Uuid) -> BankingResult<Vec<HoldPrioritySummary>> { unimplemented!() }
+        async fn cache_balance_calculation(&self, _calculation: AccountBalanceCalculationModel) -> BankingResult<AccountBalanceCalculationModel> { unimplemented!() }
+        async fn get_cached_balance_calculation(&self, _account_id: Uuid, _max_age_seconds: u64) -> BankingResult<Option<AccountBalanceCalculationModel>> { unimplemented!() }
+        async fn release_hold_detailed(&self, _hold_id: Uuid, _release_amount: Option<Decimal>, _release_reason_id: Uuid, _released_by: Uuid, _released_at: DateTime<Utc>) -> BankingResult<AccountHoldModel> { unimplemented!() }
+        async fn create_hold_release_record(&self, _release_record: HoldReleaseRecordModel) -> BankingResult<HoldReleaseRecordModel> { unimplemented!() }
+        async fn get_hold_release_records(&self, _hold_id: Uuid) -> BankingResult<Vec<HoldReleaseRecordModel>> { unimplemented!() }
+        async fn bulk_release_holds(&self, _hold_ids: Vec<Uuid>, _release_reason_id: Uuid, _released_by: Uuid) -> BankingResult<Vec<AccountHoldModel>> { unimplemented!() }
+        async fn get_expired_holds(&self, _cutoff_date: DateTime<Utc>, _hold_types: Option<Vec<String>>, _limit: Option<i32>) -> BankingResult<Vec<AccountHoldModel>> { unimplemented!() }
+        async fn get_auto_release_eligible_holds(&self, _processing_date: NaiveDate, _hold_types: Option<Vec<String>>) -> BankingResult<Vec<AccountHoldModel>> { unimplemented!() }
+        async fn create_hold_expiry_job(&self, _job: AccountHoldExpiryJobModel) -> BankingResult<AccountHoldExpiryJobModel> { unimplemented!() }
+        async fn update_hold_expiry_job(&self, _job: AccountHoldExpiryJobModel) -> BankingResult<AccountHoldExpiryJobModel> { unimplemented!() }
+        async fn bulk_place_holds(&self, _holds: Vec<AccountHoldModel>) -> BankingResult<Vec<AccountHoldModel>> { unimplemented!() }
+        async fn update_hold_priorities(&self, _account_id: Uuid, _hold_priority_updates: Vec<(Uuid, String)>, _updated_by_person_id: Uuid) -> BankingResult<Vec<AccountHoldModel>> { unimplemented!() }
+        async fn get_overrideable_holds(&self, _account_id: Uuid, _required_amount: Decimal, _override_priority: String) -> BankingResult<Vec<AccountHoldModel>> { unimplemented!() }
+        async fn create_hold_override(&self, _account_id: Uuid, _overridden_holds: Vec<Uuid>, _override_amount: Decimal, _authorized_by: Uuid, _override_reason_id: Uuid) -> BankingResult<HoldOverrideRecord> { unimplemented!() }
+        async fn get_judicial_holds_by_reference(&self, _court_reference: String) -> BankingResult<Vec<AccountHoldModel>> { unimplemented!() }
+        async fn update_loan_pledge_holds(&self, _loan_account_id: Uuid, _collateral_account_ids: Vec<Uuid>, _new_pledge_amount: Decimal, _updated_by_person_id: Uuid) -> BankingResult<Vec<AccountHoldModel>> { unimplemented!() }
+        async fn get_compliance_holds_by_alert(&self, _compliance_alert_id: Uuid) -> BankingResult<Vec<AccountHoldModel>> { unimplemented!() }
+        async fn get_hold_analytics(&self, _from_date: NaiveDate, _to_date: NaiveDate, _hold_types: Option<Vec<String>>) -> BankingResult<HoldAnalyticsSummary> { unimplemented!() }
+        async fn get_high_hold_ratio_accounts(&self, _minimum_ratio: Decimal, _exclude_hold_types: Option<Vec<String>>, _limit: i32) -> BankingResult<Vec<HighHoldRatioAccount>> { unimplemented!() }
+        async fn generate_judicial_hold_report(&self, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<JudicialHoldReportData> { unimplemented!() }
+        async fn get_hold_aging_report(&self, _hold_types: Option<Vec<String>>, _aging_buckets: Vec<i32>) -> BankingResult<Vec<HoldAgingBucket>> { unimplemented!() }
+        async fn validate_hold_amounts(&self, _account_id: Uuid) -> BankingResult<Vec<HoldValidationError>> { unimplemented!() }
+        async fn find_orphaned_holds(&self, _limit: Option<i32>) -> BankingResult<Vec<AccountHoldModel>> { unimplemented!() }
+        async fn cleanup_old_holds(&self, _cutoff_date: NaiveDate, _hold_statuses: Vec<String>) -> BankingResult<u32> { unimplemented!() }
+        async fn create_balance_calculation(&self, _calc: AccountBalanceCalculationModel) -> BankingResult<AccountBalanceCalculationModel> { unimplemented!() }
+        async fn find_balance_calculation_by_id(&self, _id: Uuid) -> BankingResult<Option<AccountBalanceCalculationModel>> { unimplemented!() }
+        async fn create_hold_summary(&self, _summary: AccountHoldSummaryModel) -> BankingResult<AccountHoldSummaryModel> { unimplemented!() }
+        async fn find_hold_summaries_by_calc_id(&self, _calc_id: Uuid) -> BankingResult<Vec<AccountHoldSummaryModel>> { unimplemented!() }
+        async fn create_hold_release_request(&self, _request: AccountHoldReleaseRequestModel) -> BankingResult<AccountHoldReleaseRequestModel> { unimplemented!() }
+        async fn create_place_hold_request(&self, _request: AccountHoldModel) -> BankingResult<AccountHoldModel> { unimplemented!() }
+    }
+
+    struct MockWorkflowRepository;
+
+    #[async_trait]
+    impl WorkflowRepository for MockWorkflowRepository {
+        async fn create_workflow(&self, _workflow: &AccountWorkflowModel) -> BankingResult<AccountWorkflowModel> { unimplemented!() }
+        async fn update_workflow(&self, _workflow: AccountWorkflowModel) -> BankingResult<AccountWorkflowModel> { unimplemented!() }
+        async fn find_workflow_by_id(&self, _workflow_id: Uuid) -> BankingResult<Option<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_by_account(&self, _account_id: Uuid) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_active_workflow(&self, _account_id: Uuid, _workflow_type: &str) -> BankingResult<Option<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_open_workflows_by_accounts(&self, _account_ids: &[Uuid]) -> BankingResult<Vec<AccountWorkflowModel>> {
+            Ok(Vec::new())
+        }
+        async fn find_workflows_by_type(&self, _workflow_type: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_by_status(&self, _status: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_by_initiator(&self, _initiated_by: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn search_workflows(&self, _filter: &WorkflowFilter, _page: WorkflowPage) -> BankingResult<WorkflowSearchResult> { unimplemented!() }
+        async fn update_workflow_status(&self, _workflow_id: Uuid, _status: &str, _notes: &str) -> BankingResult<()> { unimplemented!() }
+        async fn update_workflow_step(&self, _workflow_id: Uuid, _current_step: &str) -> BankingResult<()> { unimplemented!() }
+        async fn advance_workflow_step(&self, _workflow_id: Uuid, _step: &str, _completed_by: Uuid, _notes: &str, _supporting_documents: Vec<HeaplessString<100>>) -> BankingResult<()> { unimplemented!() }
+        async fn complete_workflow(&self, _workflow_id: Uuid, _completion_notes: &str) -> BankingResult<()> { unimplemented!() }
+        async fn fail_workflow(&self, _workflow_id: Uuid, _failure_reason: &str) -> BankingResult<()> { unimplemented!() }
+        async fn cancel_workflow(&self, _workflow_id: Uuid, _reason: &str) -> BankingResult<()> { unimplemented!() }
+        async fn add_step_record(&self, _workflow_id: Uuid, _step_record: WorkflowStepRecordModel) -> BankingResult<WorkflowStepRecordModel> { unimplemented!() }
+        async fn find_step_records_by_workflow(&self, _workflow_id: Uuid) -> BankingResult<Vec<WorkflowStepRecordModel>> { unimplemented!() }
+        async fn find_latest_step_record(&self, _workflow_id: Uuid) -> BankingResult<Option<WorkflowStepRecordModel>> { unimplemented!() }
+        async fn find_pending_workflows(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_in_progress_workflows(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_expired_workflows(&self, _reference_time: DateTime<Utc>) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_requiring_action(&self, _action_type: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_account_opening_workflows(&self, _status: Option<&str>) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_pending_kyc_workflows(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_pending_document_verification(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_account_closure_workflows(&self, _status: Option<&str>) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_pending_final_settlement(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_pending_disbursement(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_reactivation_workflows(&self, _status: Option<&str>) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_pending_mini_kyc(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_compliance_workflows(&self, _status: Option<&str>) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_by_customer_risk(&self, _risk_rating: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_approval_workflows(&self, _status: Option<&str>) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_by_approver(&self, _approver_id: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_awaiting_approval(&self, _approver_id: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn get_workflow_metrics(&self, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<WorkflowMetricsReport> { unimplemented!() }
+        async fn get_workflow_performance(&self, _workflow_type: &str, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<WorkflowPerformanceReport> { unimplemented!() }
+        async fn get_workflow_bottlenecks(&self) -> BankingResult<Vec<WorkflowBottleneckReport>> { unimplemented!() }
+        async fn get_average_completion_time(&self, _workflow_type: &str) -> BankingResult<Option<f64>> { unimplemented!() }
+        async fn cleanup_completed_workflows(&self, _retention_days: i32) -> BankingResult<i64> { unimplemented!() }
+        async fn cleanup_cancelled_workflows(&self, _retention_days: i32) -> BankingResult<i64> { unimplemented!() }
+        async fn find_stale_workflows(&self, _stale_threshold_hours: i32) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn bulk_update_workflow_status(&self, _workflow_ids: Vec<Uuid>, _status: &str) -> BankingResult<i64> { unimplemented!() }
+        async fn bulk_timeout_expired_workflows(&self, _reference_time: DateTime<Utc>) -> BankingResult<i64> { unimplemented!() }
+        async fn workflow_exists(&self, _workflow_id: Uuid) -> BankingResult<bool> { unimplemented!() }
+        async fn count_workflows_by_type(&self, _workflow_type: &str) -> BankingResult<i64> { unimplemented!() }
+        async fn count_workflows_by_status(&self, _status: &str) -> BankingResult<i64> { unimplemented!() }
+        async fn count_pending_workflows(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn list_workflows(&self, _offset: i64, _limit: i64) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn count_all_workflows(&self) -> BankingResult<i64> { unimplemented!() }
+    }
+
+    #[derive(Default)]
+    struct MockComplianceRepository {
+        alerts: Vec<ComplianceAlertModel>,
+    }
+
+    #[async_trait]
+    impl ComplianceRepository for MockComplianceRepository {
+        async fn create_sanctions_screening(&self, _screening: SanctionsScreeningModel) -> BankingResult<SanctionsScreeningModel> { unimplemented!() }
+        async fn find_screening_by_id(&self, _screening_id: Uuid) -> BankingResult<Option<SanctionsScreeningModel>> { unimplemented!() }
+        async fn find_screening_by_customer(&self, _customer_id: Uuid) -> BankingResult<Vec<SanctionsScreeningModel>> { unimplemented!() }
+        async fn find_latest_screening(&self, _customer_id: Uuid) -> BankingResult<Option<SanctionsScreeningModel>> { unimplemented!() }
+        async fn find_positive_screenings(&self) -> BankingResult<Vec<SanctionsScreeningModel>> { unimplemented!() }
+        async fn find_screenings_requiring_review(&self) -> BankingResult<Vec<SanctionsScreeningModel>> { unimplemented!() }
+        async fn update_screening_status(&self, _screening_id: Uuid, _status: &str, _reviewed_by: &str) -> BankingResult<()> { unimplemented!() }
+        async fn find_customers_needing_screening(&self, _days_threshold: i32) -> BankingResult<Vec<Uuid>> { unimplemented!() }
+        async fn create_sanctions_matches(&self, _screening_id: Uuid, _matches: Vec<SanctionsMatchModel>) -> BankingResult<()> { unimplemented!() }
+        async fn find_customers_screened_since(&self, _customer_ids: &[Uuid], _screening_type: ScreeningType, _since: DateTime<Utc>) -> BankingResult<Vec<Uuid>> { unimplemented!() }
+        async fn create_alert(&self, _alert: ComplianceAlertModel) -> BankingResult<ComplianceAlertModel> { unimplemented!() }
+        async fn find_alert_by_id(&self, _alert_id: Uuid) -> BankingResult<Option<ComplianceAlertModel>> { unimplemented!() }
+        async fn find_alerts_by_customer(&self, customer_id: Uuid) -> BankingResult<Vec<ComplianceAlertModel>> {
+            Ok(self.alerts.iter().filter(|a| a.alert_data.customer_id == Some(customer_id)).cloned().collect())
+        }
+        async fn find_alerts_by_transaction(&self, _transaction_id: Uuid) -> BankingResult<Vec<ComplianceAlertModel>> { unimplemented!() }
+        async fn find_alerts_by_type(&self, _alert_type: AlertType) -> BankingResult<Vec<ComplianceAlertModel>> { unimplemented!() }
+        async fn find_alerts_by_status(&self, _status: &str) -> BankingResult<Vec<ComplianceAlertModel>> { unimplemented!() }
+        async fn find_open_alerts(&self) -> BankingResult<Vec<ComplianceAlertModel>> { unimplemented!() }
+        async fn update_alert_status(&self, _alert_id: Uuid, _status: &str, _resolved_by_person_id: Option<Uuid>) -> BankingResult<()> { unimplemented!() }
+        async fn find_alerts_by_severity(&self, _severity: &str) -> BankingResult<Vec<ComplianceAlertModel>> { unimplemented!() }
+        async fn create_ubo_link(&self, _ubo: UltimateBeneficiaryModel) -> BankingResult<UltimateBeneficiaryModel> { unimplemented!() }
+        async fn update_ubo_link(&self, _ubo: UltimateBeneficiaryModel) -> BankingResult<UltimateBeneficiaryModel> { unimplemented!() }
+        async fn find_ubo_by_id(&self, _ubo_id: Uuid) -> BankingResult<Option<UltimateBeneficiaryModel>> { unimplemented!() }
+        async fn find_ubo_by_corporate(&self, _corporate_customer_id: Uuid) -> BankingResult<Vec<UltimateBeneficiaryModel>> { unimplemented!() }
+        async fn find_ubo_by_beneficiary(&self, _beneficiary_customer_id: Uuid) -> BankingResult<Vec<UltimateBeneficiaryModel>> { unimplemented!() }
+        async fn find_ubo_by_verification_status(&self, _status: &str) -> BankingResult<Vec<UltimateBeneficiaryModel>> { unimplemented!() }
+        async fn update_ubo_verification_status(&self, _ubo_id: Uuid, _status: &str, _verified_by: &str) -> BankingResult<()> { unimplemented!() }
+        async fn find_ubo_requiring_verification(&self) -> BankingResult<Vec<UltimateBeneficiaryModel>> { unimplemented!() }
+        async fn delete_ubo_link(&self, _ubo_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn save_ubo_verification_result(&self, _result: UboVerificationResultModel) -> BankingResult<UboVerificationResultModel> { unimplemented!() }
+        async fn create_risk_score(&self, _risk_score: ComplianceRiskScoreModel) -> BankingResult<ComplianceRiskScoreModel> { unimplemented!() }
+        async fn update_risk_score(&self, _risk_score: ComplianceRiskScoreModel) -> BankingResult<ComplianceRiskScoreModel> { unimplemented!() }
+        async fn find_risk_score_by_customer(&self, _customer_id: Uuid) -> BankingResult<Option<ComplianceRiskScoreModel>> { unimplemented!() }
+        async fn find_high_risk_customers(&self, _threshold_score: f64) -> BankingResult<Vec<ComplianceRiskScoreModel>> { unimplemented!() }
+        async fn find_risk_scores_requiring_review(&self, _days_threshold: i32) -> BankingResult<Vec<ComplianceRiskScoreModel>> { unimplemented!() }
+        async fn find_risk_score_history(&self, _customer_id: Uuid) -> BankingResult<Vec<ComplianceRiskScoreModel>> { unimplemented!() }
+        async fn find_risk_factors(&self, _customer_id: Uuid, _transactions_since: DateTime<Utc>) -> BankingResult<CustomerRiskFactorsModel> { unimplemented!() }
+        async fn find_customers_due_for_risk_review(&self, _calculated_before: DateTime<Utc>) -> BankingResult<Vec<Uuid>> { unimplemented!() }
+        async fn create_compliance_result(&self, _result: ComplianceResultModel) -> BankingResult<ComplianceResultModel> { unimplemented!() }
+        async fn find_compliance_result_by_id(&self, _result_id: Uuid) -> BankingResult<Option<ComplianceResultModel>> { unimplemented!() }
+        async fn find_compliance_results_by_account(&self, _account_id: Uuid) -> BankingResult<Vec<ComplianceResultModel>> { unimplemented!() }
+        async fn find_compliance_results_by_check_type(&self, _check_type: &str) -> BankingResult<Vec<ComplianceResultModel>> { unimplemented!() }
+        async fn find_failed_compliance_results(&self) -> BankingResult<Vec<ComplianceResultModel>> { unimplemented!() }
+        async fn create_sar_data(&self, _sar: SarDataModel) -> BankingResult<SarDataModel> { unimplemented!() }
+        async fn find_sar_by_id(&self, _sar_id: Uuid) -> BankingResult<Option<SarDataModel>> { unimplemented!() }
+        async fn find_sar_by_customer(&self, _customer_id: Uuid) -> BankingResult<Vec<SarDataModel>> { unimplemented!() }
+        async fn find_sar_by_status(&self, _status: &str) -> BankingResult<Vec<SarDataModel>> { unimplemented!() }
+        async fn update_sar_status(&self, _sar_id: Uuid, _status: &str, _updated_by_person_id: &str) -> BankingResult<()> { unimplemented!() }
+        async fn find_pending_sar_filings(&self) -> BankingResult<Vec<SarDataModel>> { unimplemented!() }
+        async fn update_draft_sar(&self, _sar: SarDataModel) -> BankingResult<SarDataModel> { unimplemented!() }
+        async fn find_sars(&self, _status: Option<SarStatus>, _from: DateTime<Utc>, _to: DateTime<Utc>) -> BankingResult<Vec<SarDataModel>> { unimplemented!() }
+        async fn record_transaction_monitoring(&self, _transaction_id: Uuid, _monitoring_result: TransactionMonitoringResult) -> BankingResult<()> { unimplemented!() }
+        async fn find_flagged_transactions(&self, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<Vec<TransactionMonitoringRecord>> { unimplemented!() }
+        async fn find_transactions_by_pattern(&self, _pattern_type: &str) -> BankingResult<Vec<TransactionMonitoringRecord>> { unimplemented!() }
+        async fn generate_compliance_summary(&self, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<ComplianceSummaryReport> { unimplemented!() }
+        async fn generate_sanctions_report(&self, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<SanctionsComplianceReport> { unimplemented!() }
+        async fn generate_alert_summary(&self, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<AlertSummaryReport> { unimplemented!() }
+        async fn count_sanctions_screenings(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn count_compliance_alerts(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn count_ubo_links(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn count_open_alerts(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn count_pending_reviews(&self) -> BankingResult<i64> { unimplemented!() }
+    }
+
+    struct MockEntityReferenceService;
+
+    #[async_trait]
+    impl EntityReferenceService for MockEntityReferenceService {
+        async fn create_entity_reference(&self, _entity_reference: EntityReference, _audit_log: AuditLog) -> EntityReferenceServiceResult<EntityReference> { unimplemented!() }
+        async fn fix_entity_reference(&self, _entity_reference: EntityReference) -> EntityReferenceServiceResult<EntityReference> { unimplemented!() }
+        async fn find_entity_reference_by_id(&self, _id: Uuid) -> EntityReferenceServiceResult<Option<EntityReference>> { unimplemented!() }
+        async fn find_entity_references_by_person_id(&self, _person_id: Uuid) -> EntityReferenceServiceResult<Vec<EntityReference>> { unimplemented!() }
+        async fn find_entity_references_by_reference_external_id(&self, _reference_external_id: HeaplessString<50>) -> EntityReferenceServiceResult<Vec<EntityReference>> {
+            Ok(Vec::new())
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,234 @@
+use std::sync::Arc;
+use chrono::{Duration, Months, NaiveDate};
+use rust_decimal::Decimal;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::daily_collection::{CollectionFrequency, CollectionSchedule, HolidayHandling},
+    service::CalendarService,
+};
+
+/// Occurrences tried before giving up on finding one that is a business day
+const MAX_SKIPPED_OCCURRENCES: usize = 366;
+
+/// Collection due under a schedule once its holiday handling is applied
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NextCollection {
+    pub collection_date: NaiveDate,
+    /// Doubled when a CollectDouble schedule carries a missed occurrence over
+    pub expected_amount: Decimal,
+}
+
+/// Works out collection dates from a schedule and the business-day calendar
+pub struct CollectionScheduler {
+    calendar_service: Arc<dyn CalendarService>,
+    jurisdiction: String,
+}
+
+impl CollectionScheduler {
+    pub fn new(calendar_service: Arc<dyn CalendarService>, jurisdiction: impl Into<String>) -> Self {
+        Self {
+            calendar_service,
+            jurisdiction: jurisdiction.into(),
+        }
+    }
+
+    /// Next collection after `from`, the date of the previous collection.
+    /// When the due date is not a business day the schedule's holiday handling
+    /// decides: Skip drops the occurrence, NextBusinessDay and
+    /// PreviousBusinessDay move it, and CollectDouble collects it together with
+    /// the next business-day occurrence. A previous business day that is not
+    /// after `from` would repeat a collection, so it moves forward instead.
+    pub async fn compute_next_collection_date(
+        &self,
+        schedule: &CollectionSchedule,
+        from: NaiveDate,
+        expected_amount: Decimal,
+    ) -> BankingResult<NextCollection> {
+        let due = next_occurrence(schedule.frequency, from)?;
+        if self.is_business_day(due).await? {
+            return Ok(NextCollection { collection_date: due, expected_amount });
+        }
+
+        let next = match schedule.holiday_handling {
+            HolidayHandling::Skip => NextCollection {
+                collection_date: self.next_business_occurrence(schedule.frequency, due).await?,
+                expected_amount,
+            },
+            HolidayHandling::CollectDouble => NextCollection {
+                collection_date: self.next_business_occurrence(schedule.frequency, due).await?,
+                expected_amount: expected_amount * Decimal::from(2),
+            },
+            HolidayHandling::NextBusinessDay => NextCollection {
+                collection_date: self.calendar_service.next_business_day(due, &self.jurisdiction).await?,
+                expected_amount,
+            },
+            HolidayHandling::PreviousBusinessDay => {
+                let previous = self.calendar_service.previous_business_day(due, &self.jurisdiction).await?;
+                let collection_date = if previous > from {
+                    previous
+                } else {
+                    self.calendar_service.next_business_day(due, &self.jurisdiction).await?
+                };
+                NextCollection { collection_date, expected_amount }
+            }
+        };
+        Ok(next)
+    }
+
+    /// First occurrence after `due` that falls on a business day
+    async fn next_business_occurrence(
+        &self,
+        frequency: CollectionFrequency,
+        mut due: NaiveDate,
+    ) -> BankingResult<NaiveDate> {
+        for _ in 0..MAX_SKIPPED_OCCURRENCES {
+            due = next_occurrence(frequency, due)?;
+            if self.is_business_day(due).await? {
+                return Ok(due);
+            }
+        }
+        Err(BankingError::Internal(format!(
+            "No {frequency} collection on a business day within {MAX_SKIPPED_OCCURRENCES} occurrences after {due}"
+        )))
+    }
+
+    async fn is_business_day(&self, date: NaiveDate) -> BankingResult<bool> {
+        self.calendar_service.is_business_day(date, &self.jurisdiction).await
+    }
+}
+
+/// Occurrence one period after `date`; month-based periods clamp to month end
+fn next_occurrence(frequency: CollectionFrequency, date: NaiveDate) -> BankingResult<NaiveDate> {
+    let next = match frequency {
+        CollectionFrequency::Daily => date.checked_add_signed(Duration::days(1)),
+        CollectionFrequency::Weekly => date.checked_add_signed(Duration::days(7)),
+        CollectionFrequency::Monthly => date.checked_add_months(Months::new(1)),
+        CollectionFrequency::Quarterly => date.checked_add_months(Months::new(3)),
+        CollectionFrequency::Yearly => date.checked_add_months(Months::new(12)),
+    };
+    next.ok_or_else(|| BankingError::Internal(format!("Cannot compute {frequency} collection after {date}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use banking_api::domain::{BankHoliday, BusinessDayCalculation, WeekendDays};
+    use chrono::{Datelike, NaiveTime, Weekday};
+    use heapless::String as HeaplessString;
+    use uuid::Uuid;
+
+    /// Saturday/Sunday weekend plus a fixed set of holidays
+    struct MockCalendarService {
+        holidays: Vec<NaiveDate>,
+    }
+
+    impl MockCalendarService {
+        fn is_open(&self, date: NaiveDate) -> bool {
+            !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !self.holidays.contains(&date)
+        }
+    }
+
+    #[async_trait]
+    impl CalendarService for MockCalendarService {
+        async fn is_business_day(&self, date: NaiveDate, _jurisdiction: &str) -> BankingResult<bool> {
+            Ok(self.is_open(date))
+        }
+        async fn next_business_day(&self, date: NaiveDate, _jurisdiction: &str) -> BankingResult<NaiveDate> {
+            let mut next = date + Duration::days(1);
+            while !self.is_open(next) {
+                next += Duration::days(1);
+            }
+            Ok(next)
+        }
+        async fn previous_business_day(&self, date: NaiveDate, _jurisdiction: &str) -> BankingResult<NaiveDate> {
+            let mut previous = date - Duration::days(1);
+            while !self.is_open(previous) {
+                previous -= Duration::days(1);
+            }
+            Ok(previous)
+        }
+        async fn add_business_days(&self, _date: NaiveDate, _days: i32, _jurisdiction: &str) -> BankingResult<NaiveDate> { unimplemented!() }
+        async fn count_business_days(&self, _from: NaiveDate, _to: NaiveDate, _jurisdiction: &str) -> BankingResult<i32> { unimplemented!() }
+        async fn add_bank_holiday(&self, _holiday: BankHoliday) -> BankingResult<()> { unimplemented!() }
+        async fn remove_bank_holiday(&self, _holiday_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn get_holidays(&self, _jurisdiction: &str, _year: i32) -> BankingResult<Vec<BankHoliday>> { unimplemented!() }
+        async fn calculate_business_day(&self, _date: NaiveDate, _jurisdiction: &str) -> BankingResult<BusinessDayCalculation> { unimplemented!() }
+        async fn batch_calculate_business_days(&self, _dates: Vec<NaiveDate>, _jurisdiction: &str) -> BankingResult<Vec<BusinessDayCalculation>> { unimplemented!() }
+        async fn is_weekend(&self, _date: NaiveDate, _jurisdiction: &str) -> BankingResult<bool> { unimplemented!() }
+        async fn create_weekend_days(&self, _weekend_days: WeekendDays) -> BankingResult<WeekendDays> { unimplemented!() }
+        async fn get_weekend_days_by_id(&self, _weekend_days_id: Uuid) -> BankingResult<Option<WeekendDays>> { unimplemented!() }
+        async fn update_weekend_days(&self, _weekend_days: WeekendDays) -> BankingResult<WeekendDays> { unimplemented!() }
+        async fn delete_weekend_days(&self, _weekend_days_id: Uuid) -> BankingResult<()> { unimplemented!() }
+    }
+
+    fn may(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 5, day).unwrap()
+    }
+
+    /// Friday 17 May 2024 is a holiday, followed by the weekend
+    fn scheduler() -> CollectionScheduler {
+        CollectionScheduler::new(Arc::new(MockCalendarService { holidays: vec![may(17)] }), "CM")
+    }
+
+    fn schedule(frequency: CollectionFrequency, holiday_handling: HolidayHandling) -> CollectionSchedule {
+        CollectionSchedule {
+            id: Uuid::new_v4(),
+            frequency,
+            collection_time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            timezone: HeaplessString::try_from("Africa/Douala").unwrap(),
+            holiday_handling,
+        }
+    }
+
+    fn amount() -> Decimal {
+        Decimal::new(500, 0)
+    }
+
+    #[tokio::test]
+    async fn test_skip_drops_holiday_occurrence() {
+        let scheduler = scheduler();
+        let weekly = schedule(CollectionFrequency::Weekly, HolidayHandling::Skip);
+
+        let regular = scheduler.compute_next_collection_date(&weekly, may(2), amount()).await.unwrap();
+        assert_eq!(regular, NextCollection { collection_date: may(9), expected_amount: amount() });
+
+        let skipped = scheduler.compute_next_collection_date(&weekly, may(10), amount()).await.unwrap();
+        assert_eq!(skipped, NextCollection { collection_date: may(24), expected_amount: amount() });
+    }
+
+    #[tokio::test]
+    async fn test_next_business_day_crosses_holiday_and_weekend() {
+        let daily = schedule(CollectionFrequency::Daily, HolidayHandling::NextBusinessDay);
+
+        let next = scheduler().compute_next_collection_date(&daily, may(16), amount()).await.unwrap();
+
+        assert_eq!(next, NextCollection { collection_date: may(20), expected_amount: amount() });
+    }
+
+    #[tokio::test]
+    async fn test_previous_business_day_never_lands_on_or_before_from() {
+        let scheduler = scheduler();
+        let weekly = schedule(CollectionFrequency::Weekly, HolidayHandling::PreviousBusinessDay);
+        let moved_back = scheduler.compute_next_collection_date(&weekly, may(10), amount()).await.unwrap();
+        assert_eq!(moved_back.collection_date, may(16));
+
+        // Due Saturday 18th; Thursday 16th is before the collection on the 17th
+        let daily = schedule(CollectionFrequency::Daily, HolidayHandling::PreviousBusinessDay);
+        let moved_forward = scheduler.compute_next_collection_date(&daily, may(17), amount()).await.unwrap();
+        assert_eq!(moved_forward, NextCollection { collection_date: may(20), expected_amount: amount() });
+    }
+
+    #[tokio::test]
+    async fn test_collect_double_carries_missed_occurrence_over() {
+        let scheduler = scheduler();
+        let weekly = schedule(CollectionFrequency::Weekly, HolidayHandling::CollectDouble);
+
+        let next = scheduler.compute_next_collection_date(&weekly, may(10), amount()).await.unwrap();
+        assert_eq!(next, NextCollection { collection_date: may(24), expected_amount: Decimal::new(1000, 0) });
+
+        let regular = scheduler.compute_next_collection_date(&weekly, may(24), amount()).await.unwrap();
+        assert_eq!(regular, NextCollection { collection_date: may(31), expected_amount: amount() });
+    }
+}
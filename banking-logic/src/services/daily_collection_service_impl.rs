@@ -1,31 +1,58 @@
 use async_trait::async_trait;
+use banking_api::domain::collateral::AlertSeverity;
 use banking_api::domain::daily_collection::{
-    AgentStatus, CollectionAgent, CollectionBatch, CollectionProgram, CollectionRecord,
-    CollectionRecordStatus, CollectionStatus, CustomerCollectionProfile, ProgramStatus,
+    AgentStatus, CollectionAgent, CollectionAlertType, CollectionBatch, CollectionProgram,
+    CollectionMethod, CollectionRecord, CollectionRecordStatus, CollectionStatus, ConnectivityStatus,
+    CustomerCollectionProfile, DeviceInformation, GeoVerificationStatus, PerformanceAlert, ProgramStatus,
+    ReconciliationData,
 };
+use banking_api::domain::person::Location;
 use banking_api::service::daily_collection_service::{
-    AgentPerformanceReport, AgentPerformanceUpdate, AgentRanking, CollectionRoute,
+    AgentPerformanceReport, AgentPerformanceUpdate, AgentRanking, CollectionPriority, CollectionRecordDetail, CollectionRoute,
     CollectionScheduleUpdate, CollectionStatistics, CollectionTrends, DailyCollectionSummary,
-    DailyCollectionService, ProgramPerformanceReport, RankingCriteria, ScheduledCollection,
+    DailyCollectionService, NonCompliantDevice, ProgramPerformanceReport, RankingCriteria, ScheduledCollection,
     TrendGranularity,
 };
+use banking_api::service::{CalendarService, LocationService};
 use banking_api::{error::BankingError, BankingResult};
 use banking_db::repository::daily_collection_repository::DailyCollectionRepository;
-use chrono::NaiveDate;
+use chrono::{Duration, NaiveDate, Utc};
+use heapless::String as HeaplessString;
 use rust_decimal::Decimal;
+use std::collections::HashSet;
 use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::config::BankingConfig;
 use crate::mappers::daily_collection_mapper::DailyCollectionMapper;
+use crate::services::daily_collection_scheduling::{CollectionScheduler, NextCollection};
+
+/// Page size when listing the locations inside an agent's coverage area
+const COVERAGE_LOCATION_PAGE_SIZE: i32 = 500;
+
+/// Look-back window for counting an agent's geo mismatches
 
 pub struct DailyCollectionServiceImpl {
     daily_collection_repository: Arc<dyn DailyCollectionRepository>,
+    location_service: Arc<dyn LocationService>,
+    collection_scheduler: CollectionScheduler,
+    config: Arc<BankingConfig>,
 }
 
 impl DailyCollectionServiceImpl {
-    pub fn new(daily_collection_repository: Arc<dyn DailyCollectionRepository>) -> Self {
+    pub fn new(
+        daily_collection_repository: Arc<dyn DailyCollectionRepository>,
+        location_service: Arc<dyn LocationService>,
+        calendar_service: Arc<dyn CalendarService>,
+        config: Arc<BankingConfig>,
+    ) -> Self {
+        let collection_scheduler =
+            CollectionScheduler::new(calendar_service, config.collections.calendar_jurisdiction.clone());
         Self {
             daily_collection_repository,
+            location_service,
+            collection_scheduler,
+            config,
         }
     }
 }
@@ -136,9 +163,43 @@ impl DailyCollectionService for DailyCollectionServiceImpl {
 
     async fn record_collection(
         &self,
-        _collection: CollectionRecord,
+        collection: CollectionRecord,
     ) -> BankingResult<CollectionRecord> {
-        unimplemented!()
+        self.ensure_device_compliant(collection.collection_agent_id).await?;
+        self.record_with_geo_check(collection).await
+    }
+
+    async fn sync_collections(
+        &self,
+        mut collections: Vec<CollectionRecord>,
+    ) -> BankingResult<Vec<CollectionRecord>> {
+        // Replay in capture order so mismatch counts match what happened in the field
+        collections.sort_by_key(|c| c.collection_time);
+
+        // Reject the whole sync before recording any of it
+        let agent_ids: HashSet<Uuid> = collections.iter().map(|c| c.collection_agent_id).collect();
+        for agent_id in agent_ids {
+            self.ensure_device_compliant(agent_id).await?;
+        }
+
+        let mut recorded = Vec::with_capacity(collections.len());
+        for collection in collections {
+            recorded.push(self.record_with_geo_check(collection).await?);
+        }
+        Ok(recorded)
+    }
+
+    async fn find_geo_review_queue(&self) -> BankingResult<Vec<CollectionRecord>> {
+        let result = self
+            .daily_collection_repository
+            .find_geo_review_queue()
+            .await
+            .map_err(BankingError::Internal)?;
+
+        Ok(result
+            .into_iter()
+            .map(|model| DailyCollectionMapper::collection_record_from_db(model).0)
+            .collect())
     }
 
     async fn process_collection_batch(
@@ -171,6 +232,53 @@ impl DailyCollectionService for DailyCollectionServiceImpl {
         unimplemented!()
     }
 
+    async fn find_collection_records(
+        &self,
+        agent_id: Option<Uuid>,
+        customer_id: Option<Uuid>,
+        from: NaiveDate,
+        to: NaiveDate,
+        status: Option<CollectionRecordStatus>,
+        page: i32,
+        page_size: i32,
+    ) -> BankingResult<Vec<CollectionRecordDetail>> {
+        if from > to {
+            return Err(BankingError::ValidationError {
+                field: "from".to_string(),
+                message: format!("Start date {from} is after end date {to}"),
+            });
+        }
+        if page < 1 || page_size < 1 {
+            return Err(BankingError::ValidationError {
+                field: "page".to_string(),
+                message: "Page and page size must be at least 1".to_string(),
+            });
+        }
+
+        let result = self
+            .daily_collection_repository
+            .find_collection_records(
+                agent_id,
+                customer_id,
+                from,
+                to,
+                status.map(DailyCollectionMapper::collection_record_status_to_db),
+                page,
+                page_size,
+            )
+            .await
+            .map_err(BankingError::Internal)?;
+
+        Ok(result
+            .into_iter()
+            .map(|model| {
+                let (record, verification, biometric_data, photo_evidence, witness_information) =
+                    DailyCollectionMapper::collection_record_from_db(model);
+                CollectionRecordDetail { record, verification, biometric_data, photo_evidence, witness_information }
+            })
+            .collect())
+    }
+
     async fn find_collections_by_status(
         &self,
         _status: CollectionRecordStatus,
@@ -207,6 +315,67 @@ impl DailyCollectionService for DailyCollectionServiceImpl {
         unimplemented!()
     }
 
+    async fn reconcile_batch(
+        &self,
+        batch_id: Uuid,
+        counted_cash: Decimal,
+        variance_tolerance: Decimal,
+        reconciled_by_person_id: Uuid,
+    ) -> BankingResult<ReconciliationData> {
+        if variance_tolerance < Decimal::ZERO {
+            return Err(BankingError::ValidationError {
+                field: "variance_tolerance".to_string(),
+                message: "Variance tolerance cannot be negative".to_string(),
+            });
+        }
+
+        let mut batch = self
+            .daily_collection_repository
+            .get_collection_batch(batch_id)
+            .await
+            .map_err(BankingError::Internal)?
+            .ok_or_else(|| BankingError::NotFound(format!("Collection batch {batch_id} not found")))?;
+        let records: Vec<CollectionRecord> = self
+            .daily_collection_repository
+            .find_collection_records_by_ids(&batch.collection_records)
+            .await
+            .map_err(BankingError::Internal)?
+            .into_iter()
+            .map(|model| DailyCollectionMapper::collection_record_from_db(model).0)
+            .collect();
+
+        let pending_records = records
+            .iter()
+            .filter(|record| record.status == CollectionRecordStatus::Pending)
+            .count();
+        if pending_records > 0 {
+            return Err(BankingError::CollectionBatchHasPendingRecords { batch_id, pending_records });
+        }
+
+        let reconciliation = ReconciliationData::for_batch(
+            batch_id,
+            &records,
+            counted_cash,
+            reconciled_by_person_id,
+            Utc::now(),
+        );
+        batch.status = DailyCollectionMapper::batch_status_to_db(reconciliation.batch_status(variance_tolerance));
+        batch.reconciliation_expected_amount = Some(reconciliation.expected_amount);
+        batch.reconciliation_actual_amount = Some(reconciliation.actual_amount);
+        batch.reconciliation_variance = Some(reconciliation.variance);
+        batch.reconciliation_variance_reason = None;
+        batch.reconciled_by_person_id = Some(reconciled_by_person_id);
+        batch.reconciliation_timestamp = Some(reconciliation.reconciliation_timestamp);
+        batch.reconciliation_adjustment_required = Some(reconciliation.adjustment_required);
+        batch.processed_at = Some(reconciliation.reconciliation_timestamp);
+        self.daily_collection_repository
+            .update_batch_reconciliation(batch)
+            .await
+            .map_err(BankingError::Internal)?;
+
+        Ok(reconciliation)
+    }
+
     async fn get_collection_statistics(
         &self,
         _start_date: NaiveDate,
@@ -327,6 +496,95 @@ impl DailyCollectionService for DailyCollectionServiceImpl {
         unimplemented!()
     }
 
+    async fn update_device_heartbeat(
+        &self,
+        device_id: Uuid,
+        battery_level: Option<f32>,
+        connectivity_status: ConnectivityStatus,
+    ) -> BankingResult<DeviceInformation> {
+        let updated = self
+            .daily_collection_repository
+            .update_device_heartbeat(
+                device_id,
+                battery_level,
+                DailyCollectionMapper::connectivity_status_to_db(connectivity_status),
+                Utc::now(),
+            )
+            .await
+            .map_err(BankingError::Internal)?
+            .ok_or_else(|| BankingError::NotFound(format!("Device {device_id} not found")))?;
+
+        Ok(DailyCollectionMapper::device_information_from_db(updated))
+    }
+
+    async fn find_non_compliant_devices(&self) -> BankingResult<Vec<NonCompliantDevice>> {
+        let now = Utc::now();
+        let max_sync_age = self.config.collections.device_max_sync_age();
+        let devices = self
+            .daily_collection_repository
+            .find_non_compliant_devices(now - max_sync_age)
+            .await
+            .map_err(BankingError::Internal)?;
+
+        Ok(devices
+            .into_iter()
+            .map(|model| {
+                let device = DailyCollectionMapper::device_information_from_db(model.device);
+                let security_features =
+                    DailyCollectionMapper::collection_security_features_from_db(model.security_features);
+                NonCompliantDevice {
+                    agent_id: model.agent_id,
+                    failures: device.compliance_failures(&security_features, now, max_sync_age),
+                    device,
+                }
+            })
+            .collect())
+    }
+
+    async fn acknowledge_alert(&self, alert_id: Uuid, person_id: Uuid) -> BankingResult<PerformanceAlert> {
+        let mut alert = self.load_performance_alert(alert_id).await?;
+        let acknowledged_at = Utc::now();
+        alert.acknowledge(person_id, acknowledged_at)?;
+
+        // None means the alert was resolved since it was loaded
+        let stored = self
+            .daily_collection_repository
+            .acknowledge_performance_alert(alert_id, person_id, acknowledged_at)
+            .await
+            .map_err(BankingError::Internal)?
+            .ok_or(BankingError::PerformanceAlertAlreadyResolved(alert_id))?;
+        Ok(DailyCollectionMapper::performance_alert_from_db(stored))
+    }
+
+    async fn resolve_alert(
+        &self,
+        alert_id: Uuid,
+        person_id: Uuid,
+        resolution_notes: Option<HeaplessString<500>>,
+    ) -> BankingResult<PerformanceAlert> {
+        let mut alert = self.load_performance_alert(alert_id).await?;
+        let resolved_at = Utc::now();
+        alert.resolve(person_id, resolution_notes.clone(), resolved_at)?;
+
+        // Acknowledgement is never withdrawn, so None means a concurrent resolution
+        let stored = self
+            .daily_collection_repository
+            .resolve_performance_alert(alert_id, person_id, resolution_notes, resolved_at)
+            .await
+            .map_err(BankingError::Internal)?
+            .ok_or(BankingError::PerformanceAlertAlreadyResolved(alert_id))?;
+        Ok(DailyCollectionMapper::performance_alert_from_db(stored))
+    }
+
+    async fn find_open_alerts_by_agent(&self, agent_id: Uuid) -> BankingResult<Vec<PerformanceAlert>> {
+        let alerts = self
+            .daily_collection_repository
+            .find_open_alerts_by_agent(agent_id)
+            .await
+            .map_err(BankingError::Internal)?;
+        Ok(alerts.into_iter().map(DailyCollectionMapper::performance_alert_from_db).collect())
+    }
+
     async fn generate_collection_routes(
         &self,
         _agent_id: Uuid,
@@ -337,10 +595,61 @@ impl DailyCollectionService for DailyCollectionServiceImpl {
 
     async fn get_scheduled_collections(
         &self,
-        _agent_id: Uuid,
-        _collection_date: NaiveDate,
+        agent_id: Uuid,
+        collection_date: NaiveDate,
     ) -> BankingResult<Vec<ScheduledCollection>> {
-        unimplemented!()
+        let profiles = self
+            .daily_collection_repository
+            .find_customer_profiles_by_agent(
+                agent_id,
+                DailyCollectionMapper::collection_status_to_db(CollectionStatus::Active),
+            )
+            .await
+            .map_err(BankingError::Internal)?;
+
+        let coverage_location_ids = self.coverage_location_ids(agent_id).await?;
+
+        let mut itinerary = Vec::new();
+        for model in profiles {
+            let profile = DailyCollectionMapper::customer_collection_profile_from_db(model);
+            let due = self.next_collection_for(&profile).await?;
+            if due.collection_date > collection_date {
+                continue;
+            }
+
+            // Collections missed on an earlier day stay on the itinerary until made
+            let (priority, notes) = if due.collection_date < collection_date {
+                (CollectionPriority::High, Some(format!("Overdue since {}", due.collection_date)))
+            } else if due.expected_amount > profile.daily_amount {
+                (CollectionPriority::Normal, Some("Includes the collection missed on a holiday".to_string()))
+            } else {
+                (CollectionPriority::Normal, None)
+            };
+            // Kept on the itinerary, but flagged so the route can be reassigned
+            let outside_coverage = coverage_location_ids
+                .as_ref()
+                .is_some_and(|ids| !ids.contains(&profile.collection_location_id));
+            let notes = match (notes, outside_coverage) {
+                (Some(note), true) => Some(format!("{note}; outside the agent's coverage area")),
+                (None, true) => Some("Outside the agent's coverage area".to_string()),
+                (notes, false) => notes,
+            };
+            itinerary.push(ScheduledCollection {
+                customer_id: profile.customer_id,
+                collection_date,
+                scheduled_time: collection_date
+                    .and_time(profile.collection_schedule.collection_time)
+                    .and_utc(),
+                expected_amount: due.expected_amount,
+                collection_method: CollectionMethod::Cash,
+                location_id: profile.collection_location_id,
+                priority,
+                notes,
+            });
+        }
+        itinerary.sort_by_key(|collection| collection.scheduled_time);
+
+        Ok(itinerary)
     }
 
     async fn update_collection_schedule(
@@ -384,4 +693,243 @@ impl DailyCollectionService for DailyCollectionServiceImpl {
     ) -> BankingResult<Vec<AgentRanking>> {
         unimplemented!()
     }
-}
\ No newline at end of file
+}
+
+impl DailyCollectionServiceImpl {
+    async fn load_performance_alert(&self, alert_id: Uuid) -> BankingResult<PerformanceAlert> {
+        self.daily_collection_repository
+            .get_performance_alert(alert_id)
+            .await
+            .map_err(BankingError::Internal)?
+            .map(DailyCollectionMapper::performance_alert_from_db)
+            .ok_or_else(|| BankingError::NotFound(format!("Performance alert {alert_id} not found")))
+    }
+
+    /// Fail with the first condition the agent's device fails, so that a stale
+    /// or insecure device cannot record collections
+    async fn ensure_device_compliant(&self, agent_id: Uuid) -> BankingResult<()> {
+        let agent = self
+            .daily_collection_repository
+            .get_collection_agent(agent_id)
+            .await
+            .map_err(BankingError::Internal)?
+            .ok_or_else(|| BankingError::NotFound(format!("Collection agent {agent_id} not found")))?;
+        let device_id = agent.device_information_id;
+        let device = self
+            .daily_collection_repository
+            .get_device_information(device_id)
+            .await
+            .map_err(BankingError::Internal)?
+            .map(DailyCollectionMapper::device_information_from_db)
+            .ok_or_else(|| BankingError::NotFound(format!("Device {device_id} of collection agent {agent_id} not found")))?;
+        let security_features = self
+            .daily_collection_repository
+            .get_security_features(device.security_features_id)
+            .await
+            .map_err(BankingError::Internal)?
+            .map(DailyCollectionMapper::collection_security_features_from_db)
+            .ok_or_else(|| {
+                BankingError::NotFound(format!("Security features of device {device_id} not found"))
+            })?;
+
+        let failures = device.compliance_failures(
+            &security_features,
+            Utc::now(),
+            self.config.collections.device_max_sync_age(),
+        );
+        match failures.into_iter().next() {
+            Some(reason) => Err(BankingError::DeviceNonCompliant { agent_id, device_id, reason }),
+            None => Ok(()),
+        }
+    }
+
+    /// Locations inside the bounding box of the agent's coverage area; None when
+    /// the area has no boundary to check against
+    async fn coverage_location_ids(&self, agent_id: Uuid) -> BankingResult<Option<HashSet<Uuid>>> {
+        let agent = self
+            .daily_collection_repository
+            .get_collection_agent(agent_id)
+            .await
+            .map_err(BankingError::Internal)?
+            .ok_or_else(|| BankingError::NotFound(format!("Collection agent {agent_id} not found")))?;
+        let Some(territory) = self
+            .daily_collection_repository
+            .get_territory(agent.assigned_territory_id)
+            .await
+            .map_err(BankingError::Internal)?
+        else {
+            return Ok(None);
+        };
+        let Some(area) = self
+            .daily_collection_repository
+            .get_coverage_area(territory.coverage_area_id)
+            .await
+            .map_err(BankingError::Internal)?
+        else {
+            return Ok(None);
+        };
+        let Some((min_lat, min_long, max_lat, max_long)) =
+            DailyCollectionMapper::coverage_area_from_db(area).bounding_box()
+        else {
+            return Ok(None);
+        };
+
+        let mut ids = HashSet::new();
+        for page in 1.. {
+            let locations = self
+                .location_service
+                .find_locations_within_bounding_box(
+                    min_lat,
+                    min_long,
+                    max_lat,
+                    max_long,
+                    page,
+                    COVERAGE_LOCATION_PAGE_SIZE,
+                )
+                .await
+                .map_err(|e| BankingError::LocationError(e.to_string()))?;
+            let last_page = locations.len() < COVERAGE_LOCATION_PAGE_SIZE as usize;
+            ids.extend(locations.into_iter().map(|location| location.id));
+            if last_page {
+                break;
+            }
+        }
+        Ok(Some(ids))
+    }
+
+    /// Collection due after the customer's last one; a customer not collected
+    /// from yet is due from the enrollment date
+    async fn next_collection_for(&self, profile: &CustomerCollectionProfile) -> BankingResult<NextCollection> {
+        match profile.collection_performance_metrics.last_collection_date {
+            Some(last_collection_date) => {
+                self.collection_scheduler
+                    .compute_next_collection_date(
+                        &profile.collection_schedule,
+                        last_collection_date,
+                        profile.daily_amount,
+                    )
+                    .await
+            }
+            None => Ok(NextCollection {
+                collection_date: profile.enrollment_date,
+                expected_amount: profile.daily_amount,
+            }),
+        }
+    }
+
+    /// Persist a collection after checking it against the customer's collection
+    /// location. Out-of-fence collections are kept but held for review.
+    async fn record_with_geo_check(&self, mut collection: CollectionRecord) -> BankingResult<CollectionRecord> {
+        let program_model = self
+            .daily_collection_repository
+            .get_collection_program(collection.collection_program_id)
+            .await
+            .map_err(BankingError::Internal)?
+            .ok_or_else(|| {
+                BankingError::NotFound(format!(
+                    "Collection program {} not found",
+                    collection.collection_program_id
+                ))
+            })?;
+        let (program, _, _) = DailyCollectionMapper::collection_program_from_db(program_model);
+
+        let customer_location = match program.geo_verification_radius_meters {
+            Some(_) => self.find_customer_collection_location(&collection).await?,
+            None => None,
+        };
+        if let Some(verification) = program.verify_collection_location(&collection, customer_location.as_ref()) {
+            collection.apply_geo_verification(verification);
+        }
+
+        let record_model = DailyCollectionMapper::collection_record_to_db(&collection, None, None, None, None);
+        let created = self
+            .daily_collection_repository
+            .create_collection_record(record_model)
+            .await
+            .map_err(BankingError::Internal)?;
+        let (record, _, _, _, _) = DailyCollectionMapper::collection_record_from_db(created);
+
+        if record.geo_verification_status == Some(GeoVerificationStatus::GeoMismatch) {
+            self.escalate_repeat_geo_mismatch(&program, &record).await?;
+        }
+
+        Ok(record)
+    }
+
+    async fn find_customer_collection_location(&self, collection: &CollectionRecord) -> BankingResult<Option<Location>> {
+        let profile = self
+            .daily_collection_repository
+            .get_customer_collection_profile(collection.customer_id, collection.collection_program_id)
+            .await
+            .map_err(BankingError::Internal)?;
+
+        match profile {
+            Some(profile) => self
+                .location_service
+                .find_location_by_id(profile.collection_location_id)
+                .await
+                .map_err(|e| BankingError::LocationError(e.to_string())),
+            None => Ok(None),
+        }
+    }
+
+    /// Raise a compliance alert on the agent when their mismatches within the
+    /// configured window reach the program threshold
+    async fn escalate_repeat_geo_mismatch(
+        &self,
+        program: &CollectionProgram,
+        record: &CollectionRecord,
+    ) -> BankingResult<()> {
+        let window_days = self.config.collections.geo_mismatch_window_days;
+        let since = record.collection_time - Duration::days(window_days);
+        let mismatches = self
+            .daily_collection_repository
+            .count_agent_collections_by_geo_status(
+                record.collection_agent_id,
+                DailyCollectionMapper::geo_verification_status_to_db(GeoVerificationStatus::GeoMismatch),
+                since,
+            )
+            .await
+            .map_err(BankingError::Internal)?;
+        if !program.geo_mismatch_escalates(mismatches) {
+            return Ok(());
+        }
+
+        let agent = self
+            .daily_collection_repository
+            .get_collection_agent(record.collection_agent_id)
+            .await
+            .map_err(BankingError::Internal)?
+            .ok_or_else(|| {
+                BankingError::NotFound(format!("Collection agent {} not found", record.collection_agent_id))
+            })?;
+
+        let message = format!(
+            "{mismatches} collections recorded outside customer geo-fence in {window_days} days"
+        );
+        let alert = PerformanceAlert {
+            id: Uuid::new_v4(),
+            agent_performance_metrics_id: agent.agent_performance_metrics_id,
+            alert_type: CollectionAlertType::ComplianceViolation,
+            severity: AlertSeverity::High,
+            message: HeaplessString::try_from(message.as_str()).map_err(|_| BankingError::ValidationError {
+                field: "message".to_string(),
+                message: "Alert message too long".to_string(),
+            })?,
+            created_at: Utc::now(),
+            acknowledged: false,
+            resolution_required: true,
+            acknowledged_at: None,
+            acknowledged_by_person_id: None,
+            resolved_at: None,
+            resolved_by_person_id: None,
+            resolution_notes: None,
+        };
+        self.daily_collection_repository
+            .create_performance_alert(DailyCollectionMapper::performance_alert_to_db(alert))
+            .await
+            .map_err(BankingError::Internal)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,342 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use heapless::String as HeaplessString;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        hash_content, ContentHash, DocumentIntegrityCheck, DocumentLink, DocumentLinkKind, DocumentLinkage,
+        DocumentReference, DocumentRegistration, RegisteredDocument,
+    },
+    service::DocumentRegistryService,
+};
+use banking_db::repository::DocumentRegistryRepository;
+use crate::mappers::DocumentRegistryMapper;
+
+/// Production implementation of DocumentRegistryService
+pub struct DocumentRegistryServiceImpl {
+    document_registry_repository: Arc<dyn DocumentRegistryRepository>,
+}
+
+impl DocumentRegistryServiceImpl {
+    pub fn new(document_registry_repository: Arc<dyn DocumentRegistryRepository>) -> Self {
+        Self { document_registry_repository }
+    }
+
+    /// Register the document unless its content is registered already; the
+    /// registry returns the first registration of the content either way
+    async fn register(&self, document: RegisteredDocument) -> BankingResult<RegisteredDocument> {
+        let registered = self
+            .document_registry_repository
+            .register_document(DocumentRegistryMapper::to_model(document))
+            .await?;
+        Ok(DocumentRegistryMapper::from_model(registered))
+    }
+
+    async fn load(&self, document_id: Uuid) -> BankingResult<RegisteredDocument> {
+        self.document_registry_repository
+            .find_document_by_id(document_id)
+            .await?
+            .map(DocumentRegistryMapper::from_model)
+            .ok_or_else(|| BankingError::NotFound(format!("Document {document_id} not found")))
+    }
+}
+
+#[async_trait]
+impl DocumentRegistryService for DocumentRegistryServiceImpl {
+    async fn register_document(
+        &self,
+        document_type: &str,
+        content: &[u8],
+        registered_by_person_id: Uuid,
+    ) -> BankingResult<DocumentRegistration> {
+        if content.is_empty() {
+            return Err(BankingError::ValidationError {
+                field: "content".to_string(),
+                message: "Document content is empty".to_string(),
+            });
+        }
+        let document_type = HeaplessString::try_from(document_type).map_err(|_| BankingError::ValidationError {
+            field: "document_type".to_string(),
+            message: "Document type exceeds maximum length of 50 characters".to_string(),
+        })?;
+
+        let document_id = Uuid::new_v4();
+        let document = self
+            .register(RegisteredDocument {
+                id: document_id,
+                content_hash: hash_content(content),
+                document_type,
+                document_path: None,
+                size_bytes: Some(content.len() as u64),
+                registered_at: Utc::now(),
+                registered_by_person_id,
+            })
+            .await?;
+
+        Ok(DocumentRegistration {
+            is_duplicate: document.id != document_id,
+            document,
+        })
+    }
+
+    async fn attach_documents(
+        &self,
+        entity_kind: DocumentLinkKind,
+        entity_id: Uuid,
+        references: Vec<DocumentReference>,
+        linked_by_person_id: Uuid,
+    ) -> BankingResult<Vec<DocumentReference>> {
+        let mut attached: Vec<DocumentReference> = Vec::with_capacity(references.len());
+        for reference in references {
+            let document = self
+                .register(RegisteredDocument {
+                    id: reference.document_id,
+                    content_hash: reference.content_hash,
+                    document_type: reference.document_type,
+                    document_path: reference.document_path,
+                    size_bytes: None,
+                    registered_at: Utc::now(),
+                    registered_by_person_id: linked_by_person_id,
+                })
+                .await?;
+            if attached.iter().any(|a| a.document_id == document.id) {
+                continue;
+            }
+
+            let link = DocumentLink {
+                id: Uuid::new_v4(),
+                document_id: document.id,
+                entity_kind,
+                entity_id,
+                linked_at: Utc::now(),
+                linked_by_person_id,
+            };
+            self.document_registry_repository
+                .link_document(DocumentRegistryMapper::link_to_model(link))
+                .await?;
+            attached.push(document.reference());
+        }
+        Ok(attached)
+    }
+
+    async fn find_document_by_id(&self, document_id: Uuid) -> BankingResult<Option<RegisteredDocument>> {
+        Ok(self
+            .document_registry_repository
+            .find_document_by_id(document_id)
+            .await?
+            .map(DocumentRegistryMapper::from_model))
+    }
+
+    async fn find_linked_documents(&self, entity_kind: DocumentLinkKind, entity_id: Uuid) -> BankingResult<Vec<RegisteredDocument>> {
+        let documents = self
+            .document_registry_repository
+            .find_documents_by_link(DocumentRegistryMapper::link_kind_to_db(entity_kind), entity_id)
+            .await?;
+        Ok(documents.into_iter().map(DocumentRegistryMapper::from_model).collect())
+    }
+
+    async fn verify_document_integrity(&self, document_id: Uuid, supplied_hash: ContentHash) -> BankingResult<DocumentIntegrityCheck> {
+        let document = self.load(document_id).await?;
+        let check = DocumentIntegrityCheck {
+            document_id,
+            registered_hash: document.content_hash,
+            supplied_hash,
+        };
+        if !check.is_intact() {
+            tracing::warn!(
+                "Document {} content hash mismatch: registered {}, supplied {}",
+                document_id,
+                check.registered_hash.to_hex(),
+                check.supplied_hash.to_hex()
+            );
+        }
+        Ok(check)
+    }
+
+    async fn get_document_linkage(&self, document_id: Uuid) -> BankingResult<DocumentLinkage> {
+        self.load(document_id).await?;
+        let links: Vec<DocumentLink> = self
+            .document_registry_repository
+            .find_links_by_document(document_id)
+            .await?
+            .into_iter()
+            .map(DocumentRegistryMapper::link_from_model)
+            .collect();
+        Ok(DocumentLinkage::from_links(document_id, &links))
+    }
+
+    async fn purge_document(&self, document_id: Uuid) -> BankingResult<()> {
+        let linkage = self.get_document_linkage(document_id).await?;
+        let still_referenced = || BankingError::ValidationError {
+            field: "document_id".to_string(),
+            message: format!(
+                "Document {document_id} is still referenced by {} workflows and {} customers",
+                linkage.workflow_count(),
+                linkage.customer_count()
+            ),
+        };
+        if linkage.is_referenced() {
+            return Err(still_referenced());
+        }
+
+        // A link added since the linkage was read keeps the document
+        if !self.document_registry_repository.delete_unlinked_document(document_id).await? {
+            return Err(still_referenced());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use banking_db::models::{DbDocumentLinkKind, DocumentLinkModel, RegisteredDocumentModel};
+
+    #[derive(Default)]
+    struct MockDocumentRegistryRepository {
+        documents: Mutex<Vec<RegisteredDocumentModel>>,
+        links: Mutex<Vec<DocumentLinkModel>>,
+    }
+
+    #[async_trait]
+    impl DocumentRegistryRepository for MockDocumentRegistryRepository {
+        async fn register_document(&self, document: RegisteredDocumentModel) -> BankingResult<RegisteredDocumentModel> {
+            let mut documents = self.documents.lock().unwrap();
+            if let Some(existing) = documents.iter().find(|d| d.content_hash == document.content_hash) {
+                return Ok(existing.clone());
+            }
+            documents.push(document.clone());
+            Ok(document)
+        }
+
+        async fn find_document_by_id(&self, document_id: Uuid) -> BankingResult<Option<RegisteredDocumentModel>> {
+            Ok(self.documents.lock().unwrap().iter().find(|d| d.id == document_id).cloned())
+        }
+
+        async fn find_document_by_hash(&self, content_hash: &[u8; 32]) -> BankingResult<Option<RegisteredDocumentModel>> {
+            Ok(self.documents.lock().unwrap().iter().find(|d| d.content_hash.as_bytes() == content_hash).cloned())
+        }
+
+        async fn link_document(&self, link: DocumentLinkModel) -> BankingResult<DocumentLinkModel> {
+            let mut links = self.links.lock().unwrap();
+            if let Some(existing) = links.iter().find(|l| {
+                l.document_id == link.document_id && l.entity_kind == link.entity_kind && l.entity_id == link.entity_id
+            }) {
+                return Ok(existing.clone());
+            }
+            links.push(link.clone());
+            Ok(link)
+        }
+
+        async fn find_links_by_document(&self, document_id: Uuid) -> BankingResult<Vec<DocumentLinkModel>> {
+            Ok(self.links.lock().unwrap().iter().filter(|l| l.document_id == document_id).cloned().collect())
+        }
+
+        async fn find_documents_by_link(&self, entity_kind: DbDocumentLinkKind, entity_id: Uuid) -> BankingResult<Vec<RegisteredDocumentModel>> {
+            let links = self.links.lock().unwrap();
+            Ok(self
+                .documents
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|d| {
+                    links.iter().any(|l| l.document_id == d.id && l.entity_kind == entity_kind && l.entity_id == entity_id)
+                })
+                .cloned()
+                .collect())
+        }
+
+        async fn delete_unlinked_document(&self, document_id: Uuid) -> BankingResult<bool> {
+            if self.links.lock().unwrap().iter().any(|l| l.document_id == document_id) {
+                return Ok(false);
+            }
+            self.documents.lock().unwrap().retain(|d| d.id != document_id);
+            Ok(true)
+        }
+    }
+
+    fn service() -> (DocumentRegistryServiceImpl, Arc<MockDocumentRegistryRepository>) {
+        let repository = Arc::new(MockDocumentRegistryRepository::default());
+        (DocumentRegistryServiceImpl::new(repository.clone()), repository)
+    }
+
+    #[tokio::test]
+    async fn test_identical_content_returns_existing_document() {
+        let (service, repository) = service();
+        let person_id = Uuid::new_v4();
+
+        let first = service.register_document("NationalId", b"id scan", person_id).await.unwrap();
+        let second = service.register_document("NationalId", b"id scan", person_id).await.unwrap();
+
+        assert!(!first.is_duplicate);
+        assert!(second.is_duplicate);
+        assert_eq!(second.document.id, first.document.id);
+        assert_eq!(repository.documents.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_distinct_content_gets_distinct_documents() {
+        let (service, repository) = service();
+        let person_id = Uuid::new_v4();
+
+        let front = service.register_document("NationalId", b"id scan front", person_id).await.unwrap();
+        let back = service.register_document("NationalId", b"id scan back", person_id).await.unwrap();
+
+        assert!(!back.is_duplicate);
+        assert_ne!(back.document.id, front.document.id);
+        assert_ne!(back.document.content_hash, front.document.content_hash);
+        assert_eq!(repository.documents.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_integrity_mismatch_is_reported() {
+        let (service, _) = service();
+        let registration = service.register_document("Passport", b"passport scan", Uuid::new_v4()).await.unwrap();
+        let document_id = registration.document.id;
+
+        let intact = service.verify_document_integrity(document_id, hash_content(b"passport scan")).await.unwrap();
+        assert!(intact.is_intact());
+
+        let tampered = service.verify_document_integrity(document_id, hash_content(b"edited passport scan")).await.unwrap();
+        assert!(!tampered.is_intact());
+        assert_eq!(tampered.registered_hash, registration.document.content_hash);
+
+        assert!(matches!(
+            service.verify_document_integrity(Uuid::new_v4(), hash_content(b"passport scan")).await,
+            Err(BankingError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_attached_references_share_one_document_and_block_purge() {
+        let (service, _) = service();
+        let person_id = Uuid::new_v4();
+        let customer_id = Uuid::new_v4();
+        let first_workflow = Uuid::new_v4();
+        let second_workflow = Uuid::new_v4();
+
+        // The same scan attached to two workflows under freshly created references
+        let first = service
+            .attach_documents(DocumentLinkKind::Workflow, first_workflow, vec![DocumentReference::new("NationalId", b"id scan").unwrap()], person_id)
+            .await
+            .unwrap();
+        let second = service
+            .attach_documents(DocumentLinkKind::Workflow, second_workflow, vec![DocumentReference::new("NationalId", b"id scan").unwrap()], person_id)
+            .await
+            .unwrap();
+        service.attach_documents(DocumentLinkKind::Customer, customer_id, first.clone(), person_id).await.unwrap();
+
+        let document_id = first[0].document_id;
+        assert_eq!(second[0].document_id, document_id);
+        let linkage = service.get_document_linkage(document_id).await.unwrap();
+        assert_eq!(linkage.workflow_count(), 2);
+        assert_eq!(linkage.customer_ids, vec![customer_id]);
+
+        assert!(matches!(service.purge_document(document_id).await, Err(BankingError::ValidationError { .. })));
+        assert!(service.find_document_by_id(document_id).await.unwrap().is_some());
+    }
+}
@@ -0,0 +1,353 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{EodRun, EodRunStatus, EodRunStep, EodStepStatus},
+};
+use banking_db::repository::EodRunRepository;
+
+use crate::mappers::EodRunMapper;
+
+/// Outcome of one chunk of an end-of-day step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EodChunk {
+    pub records_processed: i64,
+    pub records_failed: i64,
+    /// Last record of the chunk, where the next chunk starts; `None` once the step is done
+    pub next_cursor: Option<Uuid>,
+}
+
+impl EodChunk {
+    /// The step has nothing left to process
+    pub fn finished(records_processed: i64, records_failed: i64) -> Self {
+        Self { records_processed, records_failed, next_cursor: None }
+    }
+}
+
+/// A step of the end-of-day run. Steps walking accounts process one keyset
+/// page per chunk, so their progress is checkpointed between pages; other
+/// steps finish in a single chunk.
+#[async_trait]
+pub trait EodStep: Send + Sync {
+    /// Name the step's progress is saved under; must stay stable across releases
+    fn name(&self) -> &'static str;
+
+    /// Processes at most `chunk_size` records after `cursor`
+    async fn run_chunk(&self, run_date: NaiveDate, cursor: Option<Uuid>, chunk_size: i64) -> BankingResult<EodChunk>;
+}
+
+/// Runs registered end-of-day steps in order, saving each step's progress
+/// after every chunk so a failed run can be resumed where it stopped.
+pub struct EodOrchestrator {
+    eod_run_repository: Arc<dyn EodRunRepository>,
+    chunk_size: i64,
+}
+
+impl EodOrchestrator {
+    pub fn new(eod_run_repository: Arc<dyn EodRunRepository>, chunk_size: i64) -> Self {
+        Self { eod_run_repository, chunk_size }
+    }
+
+    /// Runs the steps for the date. A completed run is returned untouched
+    /// unless `force`, which runs every step again from the start; an
+    /// unfinished run is resumed.
+    pub async fn run(&self, run_date: NaiveDate, force: bool, steps: &[&dyn EodStep]) -> BankingResult<EodRun> {
+        let run = match self.find_run(run_date).await? {
+            None => {
+                let run = EodRun::start(run_date, Utc::now());
+                self.eod_run_repository.create_run(EodRunMapper::run_to_model(&run)).await?;
+                run
+            }
+            Some(run) if run.status == EodRunStatus::Completed && !force => return Ok(run),
+            Some(mut run) if run.status == EodRunStatus::Completed => {
+                run.status = EodRunStatus::Running;
+                run.started_at = Utc::now();
+                run.finished_at = None;
+                run.forced_reruns += 1;
+                for step in run.steps.iter_mut() {
+                    step.reset();
+                    self.eod_run_repository.save_step(EodRunMapper::step_to_model(step)).await?;
+                }
+                self.eod_run_repository.update_run(EodRunMapper::run_to_model(&run)).await?;
+                run
+            }
+            Some(run) => run,
+        };
+        self.execute(run, steps).await
+    }
+
+    /// Continues the run of the date: completed steps are skipped and a
+    /// partially finished step continues after its last checkpoint
+    pub async fn resume(&self, run_date: NaiveDate, steps: &[&dyn EodStep]) -> BankingResult<EodRun> {
+        let run = self
+            .find_run(run_date)
+            .await?
+            .ok_or_else(|| BankingError::NotFound(format!("No end-of-day run for {run_date}")))?;
+        if run.status == EodRunStatus::Completed {
+            return Ok(run);
+        }
+        self.execute(run, steps).await
+    }
+
+    pub async fn find_run(&self, run_date: NaiveDate) -> BankingResult<Option<EodRun>> {
+        let Some(model) = self.eod_run_repository.find_run_by_date(run_date).await? else {
+            return Ok(None);
+        };
+        let steps = self.eod_run_repository.find_steps(model.id).await?;
+        Ok(Some(EodRunMapper::run_from_model(model, steps)))
+    }
+
+    async fn execute(&self, mut run: EodRun, steps: &[&dyn EodStep]) -> BankingResult<EodRun> {
+        if run.status != EodRunStatus::Running {
+            run.status = EodRunStatus::Running;
+            self.eod_run_repository.update_run(EodRunMapper::run_to_model(&run)).await?;
+        }
+
+        for (step_order, step) in steps.iter().enumerate() {
+            let index = match run.steps.iter().position(|s| s.step_name.as_str() == step.name()) {
+                Some(index) => index,
+                None => {
+                    let step_name = HeaplessString::try_from(step.name()).map_err(|_| {
+                        BankingError::Internal(format!("End-of-day step name {} is too long", step.name()))
+                    })?;
+                    run.steps.push(EodRunStep::pending(&run, step_name, step_order as i32));
+                    run.steps.len() - 1
+                }
+            };
+            let state = &mut run.steps[index];
+            if state.status == EodStepStatus::Completed {
+                continue;
+            }
+            state.step_order = step_order as i32;
+
+            if let Err(e) = self.run_step(state, *step).await {
+                run.status = EodRunStatus::Failed;
+                self.eod_run_repository.update_run(EodRunMapper::run_to_model(&run)).await?;
+                return Err(e);
+            }
+        }
+
+        run.status = EodRunStatus::Completed;
+        run.finished_at = Some(Utc::now());
+        self.eod_run_repository.update_run(EodRunMapper::run_to_model(&run)).await?;
+        run.steps.sort_by_key(|s| s.step_order);
+        Ok(run)
+    }
+
+    /// Runs the step chunk by chunk from its checkpoint. A failing chunk is
+    /// not counted; it runs again in full when the step is resumed.
+    async fn run_step(&self, state: &mut EodRunStep, step: &dyn EodStep) -> BankingResult<()> {
+        state.status = EodStepStatus::Running;
+        state.started_at.get_or_insert_with(Utc::now);
+        state.error = None;
+        self.eod_run_repository.save_step(EodRunMapper::step_to_model(state)).await?;
+
+        loop {
+            let chunk = match step.run_chunk(state.run_date, state.checkpoint_cursor, self.chunk_size).await {
+                Ok(chunk) => chunk,
+                Err(e) => {
+                    state.status = EodStepStatus::Failed;
+                    state.error = Self::step_error(&e);
+                    self.eod_run_repository.save_step(EodRunMapper::step_to_model(state)).await?;
+                    return Err(e);
+                }
+            };
+
+            state.records_processed += chunk.records_processed;
+            state.records_failed += chunk.records_failed;
+            match chunk.next_cursor {
+                Some(cursor) => state.checkpoint_cursor = Some(cursor),
+                None => {
+                    state.status = EodStepStatus::Completed;
+                    state.finished_at = Some(Utc::now());
+                }
+            }
+            self.eod_run_repository.save_step(EodRunMapper::step_to_model(state)).await?;
+            if state.status == EodStepStatus::Completed {
+                return Ok(());
+            }
+        }
+    }
+
+    fn step_error(error: &BankingError) -> Option<HeaplessString<500>> {
+        let mut message = error.to_string();
+        while message.len() > 500 {
+            message.pop();
+        }
+        HeaplessString::try_from(message.as_str()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use banking_db::models::{EodRunModel, EodRunStepModel};
+
+    #[derive(Default)]
+    struct MockEodRunRepository {
+        runs: Mutex<HashMap<NaiveDate, EodRunModel>>,
+        steps: Mutex<HashMap<(Uuid, String), EodRunStepModel>>,
+    }
+
+    #[async_trait]
+    impl EodRunRepository for MockEodRunRepository {
+        async fn create_run(&self, run: EodRunModel) -> BankingResult<EodRunModel> {
+            self.runs.lock().unwrap().insert(run.run_date, run.clone());
+            Ok(run)
+        }
+        async fn update_run(&self, run: EodRunModel) -> BankingResult<EodRunModel> {
+            self.runs.lock().unwrap().insert(run.run_date, run.clone());
+            Ok(run)
+        }
+        async fn find_run_by_date(&self, run_date: NaiveDate) -> BankingResult<Option<EodRunModel>> {
+            Ok(self.runs.lock().unwrap().get(&run_date).cloned())
+        }
+        async fn save_step(&self, step: EodRunStepModel) -> BankingResult<EodRunStepModel> {
+            self.steps.lock().unwrap().insert((step.eod_run_id, step.step_name.to_string()), step.clone());
+            Ok(step)
+        }
+        async fn find_steps(&self, eod_run_id: Uuid) -> BankingResult<Vec<EodRunStepModel>> {
+            let mut steps: Vec<_> = self.steps.lock().unwrap().values().filter(|s| s.eod_run_id == eod_run_id).cloned().collect();
+            steps.sort_by_key(|s| s.step_order);
+            Ok(steps)
+        }
+    }
+
+    /// Walks accounts in id order like the account-paged EOD steps, failing
+    /// once when it reaches the account at `fail_at`
+    struct MockAccountStep {
+        accounts: Vec<Uuid>,
+        fail_at: Mutex<Option<usize>>,
+        processed: Mutex<Vec<Uuid>>,
+    }
+
+    #[async_trait]
+    impl EodStep for MockAccountStep {
+        fn name(&self) -> &'static str {
+            "INTEREST_ACCRUAL"
+        }
+
+        async fn run_chunk(&self, _run_date: NaiveDate, cursor: Option<Uuid>, chunk_size: i64) -> BankingResult<EodChunk> {
+            let start = cursor.map_or(0, |cursor| self.accounts.iter().position(|a| *a == cursor).unwrap() + 1);
+            let page: Vec<Uuid> = self.accounts.iter().skip(start).take(chunk_size as usize).copied().collect();
+            for (offset, account_id) in page.iter().enumerate() {
+                if *self.fail_at.lock().unwrap() == Some(start + offset) {
+                    *self.fail_at.lock().unwrap() = None;
+                    return Err(BankingError::Internal("Database connection lost".to_string()));
+                }
+                self.processed.lock().unwrap().push(*account_id);
+            }
+            Ok(EodChunk {
+                records_processed: page.len() as i64,
+                records_failed: 0,
+                next_cursor: page.last().copied().filter(|_| page.len() as i64 == chunk_size),
+            })
+        }
+    }
+
+    /// Step finishing in one chunk, counting its runs
+    struct MockSingleStep {
+        name: &'static str,
+        runs: Mutex<i32>,
+    }
+
+    #[async_trait]
+    impl EodStep for MockSingleStep {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        async fn run_chunk(&self, _run_date: NaiveDate, _cursor: Option<Uuid>, _chunk_size: i64) -> BankingResult<EodChunk> {
+            *self.runs.lock().unwrap() += 1;
+            Ok(EodChunk::finished(1, 0))
+        }
+    }
+
+    fn single_step(name: &'static str) -> MockSingleStep {
+        MockSingleStep { name, runs: Mutex::new(0) }
+    }
+
+    fn account_step(account_count: usize, fail_at: Option<usize>) -> MockAccountStep {
+        let mut accounts: Vec<Uuid> = (0..account_count).map(|_| Uuid::new_v4()).collect();
+        accounts.sort();
+        MockAccountStep { accounts, fail_at: Mutex::new(fail_at), processed: Mutex::new(Vec::new()) }
+    }
+
+    fn run_date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 5, 20).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_resume_processes_only_the_accounts_after_the_checkpoint() {
+        let orchestrator = EodOrchestrator::new(Arc::new(MockEodRunRepository::default()), 3);
+        let hold_expiry = single_step("HOLD_EXPIRY");
+        // Fails on the fourth account, the first of the second chunk
+        let accrual = account_step(7, Some(3));
+        let gl_summary = single_step("GL_SUMMARY");
+        let steps: [&dyn EodStep; 3] = [&hold_expiry, &accrual, &gl_summary];
+
+        let error = orchestrator.run(run_date(), false, &steps).await.unwrap_err();
+        assert!(matches!(error, BankingError::Internal(_)));
+        assert_eq!(*accrual.processed.lock().unwrap(), accrual.accounts[..3].to_vec());
+        assert_eq!(*gl_summary.runs.lock().unwrap(), 0);
+
+        let failed = orchestrator.find_run(run_date()).await.unwrap().unwrap();
+        assert_eq!(failed.status, EodRunStatus::Failed);
+        let step = failed.step("INTEREST_ACCRUAL").unwrap();
+        assert_eq!(step.status, EodStepStatus::Failed);
+        assert_eq!(step.records_processed, 3);
+        assert_eq!(step.checkpoint_cursor, Some(accrual.accounts[2]));
+        assert_eq!(step.error.as_ref().unwrap().as_str(), "Internal error: Database connection lost");
+
+        accrual.processed.lock().unwrap().clear();
+        let resumed = orchestrator.resume(run_date(), &steps).await.unwrap();
+        assert_eq!(*accrual.processed.lock().unwrap(), accrual.accounts[3..].to_vec());
+        assert_eq!(*hold_expiry.runs.lock().unwrap(), 1);
+        assert_eq!(*gl_summary.runs.lock().unwrap(), 1);
+
+        assert_eq!(resumed.status, EodRunStatus::Completed);
+        let step = resumed.step("INTEREST_ACCRUAL").unwrap();
+        assert_eq!(step.status, EodStepStatus::Completed);
+        assert_eq!(step.records_processed, 7);
+        assert!(step.error.is_none());
+        let names: Vec<&str> = resumed.steps.iter().map(|s| s.step_name.as_str()).collect();
+        assert_eq!(names, vec!["HOLD_EXPIRY", "INTEREST_ACCRUAL", "GL_SUMMARY"]);
+    }
+
+    #[tokio::test]
+    async fn test_completed_run_is_only_repeated_when_forced() {
+        let orchestrator = EodOrchestrator::new(Arc::new(MockEodRunRepository::default()), 3);
+        let accrual = account_step(4, None);
+        let steps: [&dyn EodStep; 1] = [&accrual];
+
+        orchestrator.run(run_date(), false, &steps).await.unwrap();
+        assert_eq!(accrual.processed.lock().unwrap().len(), 4);
+
+        let again = orchestrator.run(run_date(), false, &steps).await.unwrap();
+        let resumed = orchestrator.resume(run_date(), &steps).await.unwrap();
+        assert_eq!(accrual.processed.lock().unwrap().len(), 4);
+        assert_eq!(again.forced_reruns, 0);
+        assert_eq!(resumed.status, EodRunStatus::Completed);
+
+        let forced = orchestrator.run(run_date(), true, &steps).await.unwrap();
+        assert_eq!(accrual.processed.lock().unwrap().len(), 8);
+        assert_eq!(forced.forced_reruns, 1);
+        assert_eq!(forced.step("INTEREST_ACCRUAL").unwrap().records_processed, 4);
+    }
+
+    #[tokio::test]
+    async fn test_resume_without_a_run_is_rejected() {
+        let orchestrator = EodOrchestrator::new(Arc::new(MockEodRunRepository::default()), 3);
+        let accrual = account_step(1, None);
+
+        let error = orchestrator.resume(run_date(), &[&accrual]).await.unwrap_err();
+        assert!(matches!(error, BankingError::NotFound(_)));
+        assert!(accrual.processed.lock().unwrap().is_empty());
+    }
+}
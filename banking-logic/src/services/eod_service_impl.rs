@@ -5,25 +5,259 @@ use chrono::{NaiveDate, Utc};
 use uuid::Uuid;
 
 use banking_api::{
-    BankingResult,
+    BankingError, BankingResult,
     service::{
         EodService, EodReport, EodReportStatus, RegulatoryReport, EodProcessingResult,
-        DormancyReport, MaintenanceReport, RegulatoryNotification,
-        InterestService, FeeService, CalendarService, AccountLifecycleService
+        DormancyReport, InactivityFeeReport, MaintenanceReport, RegulatoryNotification,
+        InterestService, FeeService, CalendarService, AccountLifecycleService, CasaService,
+        ProvisioningReport, ProvisioningExposure, ProvisioningBucketTransition,
+        SegmentService, StatementService, BranchCashService,
+        NotificationService, TransactionService, account_hold_service::AccountHoldService,
+    },
+    domain::{
+        AccountBalanceSnapshot, CurrencyCode, HoldType, InactivityFeeStage, OverdraftPosition, LoanInstallmentDue, LoanPenaltyAccrual, ProvisioningBucket,
+        DegradedFlags, EodRun, GlPostingTotals, GlSummary, GlSummaryLine, StandingOrder, StandingOrderStatus, Transaction,
+        TransactionStatus, TransactionType, STANDING_ORDER_CHANNEL_ID, customer_gl_code, domicile_branch_on,
+        is_statement_cycle_end,
     },
 };
 use banking_db::{repository::{
-    AccountRepository, CalendarRepository, ProductRepository, TransactionRepository,
-    WorkflowRepository,
-}, DbAccountType};
+    AccountDomicileRepository, AccountRepository, AgentNetworkRepository, CalendarRepository, EodRunRepository, GeneralLedgerRepository,
+    ProductRepository, StandingOrderRepository, TransactionRepository, WorkflowRepository,
+}, models::AccountModel, DbAccountStatus, DbAccountType};
+use heapless::String as HeaplessString;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::config::BankingConfig;
+use crate::mappers::{
+    AccountDomicileMapper, AccountMapper, GeneralLedgerMapper, ProductMapper, StandingOrderMapper, TransactionMapper,
+};
+use crate::services::branch_calendar::branch_calendar_id;
+use crate::services::eod_orchestration::{EodChunk, EodOrchestrator, EodStep};
+use crate::services::standing_order_scheduling::StandingOrderScheduler;
+
+/// Outcome of running one due standing order occurrence
+enum StandingOrderRun {
+    /// Transferred, or a sweep with nothing above its threshold
+    Completed,
+    Unfunded { requested: Decimal, available: Decimal },
+    /// The source was debited but the internal beneficiary could not be credited
+    CreditFailed { debit_id: Uuid, error: BankingError },
+}
+
+/// Counts of an account-paged step, kept across its pages
+#[derive(Default)]
+struct PageTally {
+    processed: i64,
+    successful: i64,
+    errors: Vec<String>,
+    warnings: Vec<String>,
+}
+
+impl PageTally {
+    fn into_report(self, processing_date: NaiveDate, report_type: &str, started_at: chrono::DateTime<Utc>) -> EodReport {
+        EodReport {
+            processing_date,
+            report_type: report_type.to_string(),
+            status: if self.errors.is_empty() { EodReportStatus::Completed } else { EodReportStatus::CompletedWithWarnings },
+            started_at,
+            completed_at: Some(Utc::now()),
+            records_processed: self.processed,
+            records_successful: self.successful,
+            records_failed: self.processed - self.successful,
+            errors: self.errors,
+            warnings: self.warnings,
+        }
+    }
+
+    /// Chunk of a resumable run; a short page is the last one
+    fn into_chunk(self, page: &[AccountModel], page_size: i64) -> EodChunk {
+        EodChunk {
+            records_processed: self.processed,
+            records_failed: self.processed - self.successful,
+            next_cursor: page.last().map(|a| a.id).filter(|_| page.len() as i64 == page_size),
+        }
+    }
+}
+
+/// Steps of the resumable EOD run, in the order of `run_eod_processing`
+#[derive(Debug, Clone, Copy)]
+enum EodStage {
+    HoldExpiry,
+    StandingOrders,
+    InterestAccrual,
+    OverdraftInterest,
+    InterestCapitalization,
+    PeriodicFees,
+    LoanUpdates,
+    OverduePenalties,
+    OverdrawnTracking,
+    Dormancy,
+    InactivityFees,
+    AccountMaintenance,
+    RegulatoryReports,
+    SegmentEvaluation,
+    StatementCycle,
+    CashCeilingCheck,
+    GlSummary,
+    Cleanup,
+}
+
+impl EodStage {
+    const ALL: [EodStage; 18] = [
+        EodStage::HoldExpiry,
+        EodStage::StandingOrders,
+        EodStage::InterestAccrual,
+        EodStage::OverdraftInterest,
+        EodStage::InterestCapitalization,
+        EodStage::PeriodicFees,
+        EodStage::LoanUpdates,
+        EodStage::OverduePenalties,
+        EodStage::OverdrawnTracking,
+        EodStage::Dormancy,
+        EodStage::InactivityFees,
+        EodStage::AccountMaintenance,
+        EodStage::RegulatoryReports,
+        EodStage::SegmentEvaluation,
+        EodStage::StatementCycle,
+        EodStage::CashCeilingCheck,
+        EodStage::GlSummary,
+        EodStage::Cleanup,
+    ];
+}
+
+/// An EOD stage run by the orchestrator. Account-paged stages take one
+/// keyset page per chunk; the others run whole as a single chunk.
+struct EodServiceStep<'a> {
+    service: &'a EodServiceImpl,
+    stage: EodStage,
+}
+
+impl EodServiceStep<'_> {
+    fn report_chunk(report: EodReport) -> EodChunk {
+        EodChunk::finished(report.records_processed, report.records_failed)
+    }
+}
+
+#[async_trait]
+impl EodStep for EodServiceStep<'_> {
+    fn name(&self) -> &'static str {
+        match self.stage {
+            EodStage::HoldExpiry => "HOLD_EXPIRY",
+            EodStage::StandingOrders => "STANDING_ORDERS",
+            EodStage::InterestAccrual => "INTEREST_ACCRUAL",
+            EodStage::OverdraftInterest => "OVERDRAFT_INTEREST",
+            EodStage::InterestCapitalization => "INTEREST_CAPITALIZATION",
+            EodStage::PeriodicFees => "PERIODIC_FEES",
+            EodStage::LoanUpdates => "LOAN_UPDATES",
+            EodStage::OverduePenalties => "OVERDUE_PENALTIES",
+            EodStage::OverdrawnTracking => "OVERDRAWN_TRACKING",
+            EodStage::Dormancy => "DORMANCY",
+            EodStage::InactivityFees => "INACTIVITY_FEES",
+            EodStage::AccountMaintenance => "ACCOUNT_MAINTENANCE",
+            EodStage::RegulatoryReports => "REGULATORY_REPORTS",
+            EodStage::SegmentEvaluation => "SEGMENT_EVALUATION",
+            EodStage::StatementCycle => "STATEMENT_CYCLE",
+            EodStage::CashCeilingCheck => "CASH_CEILING_CHECK",
+            EodStage::GlSummary => "GL_SUMMARY",
+            EodStage::Cleanup => "CLEANUP",
+        }
+    }
 
-use crate::mappers::ProductMapper;
+    async fn run_chunk(&self, run_date: NaiveDate, cursor: Option<Uuid>, chunk_size: i64) -> BankingResult<EodChunk> {
+        let service = self.service;
+        match self.stage {
+            EodStage::HoldExpiry => {
+                let end_of_day = run_date.and_hms_opt(23, 59, 59).unwrap_or_default().and_utc();
+                let job = service.account_hold_service.expire_holds(end_of_day).await?;
+                Ok(EodChunk::finished(job.expired_holds_count as i64, 0))
+            }
+            EodStage::StandingOrders => Ok(Self::report_chunk(service.execute_standing_orders(run_date).await?)),
+            EodStage::InterestAccrual => {
+                let report = service.interest_service
+                    .accrue_daily_interest(run_date, service.banking_config.interest.accrual_options())
+                    .await?;
+                Ok(EodChunk::finished(
+                    report.accounts_processed + report.accounts_already_accrued + report.accounts_failed,
+                    report.accounts_failed,
+                ))
+            }
+            EodStage::OverdraftInterest => {
+                let page = service.account_repository.list_after(cursor, chunk_size).await?;
+                let mut tally = PageTally::default();
+                service.overdraft_interest_page(&page, run_date, &mut tally).await;
+                Ok(tally.into_chunk(&page, chunk_size))
+            }
+            EodStage::InterestCapitalization => {
+                let report = service.interest_service.capitalize_interest(run_date).await?;
+                Ok(EodChunk::finished(report.accounts_processed, report.errors.len() as i64))
+            }
+            EodStage::PeriodicFees => Ok(Self::report_chunk(service.apply_periodic_fees(run_date).await?)),
+            EodStage::LoanUpdates => Ok(Self::report_chunk(service.update_delinquent_loans(run_date).await?)),
+            EodStage::OverduePenalties => {
+                let page = service.account_repository.list_after(cursor, chunk_size).await?;
+                let mut tally = PageTally::default();
+                service.overdue_penalties_page(&page, run_date, &mut tally).await;
+                Ok(tally.into_chunk(&page, chunk_size))
+            }
+            EodStage::OverdrawnTracking => {
+                let page = service.account_repository.list_after(cursor, chunk_size).await?;
+                let mut tally = PageTally::default();
+                service.overdrawn_days_page(&page, run_date, &mut tally).await;
+                Ok(tally.into_chunk(&page, chunk_size))
+            }
+            EodStage::Dormancy => {
+                let report = service.process_dormancy_candidates(run_date).await?;
+                Ok(EodChunk::finished(report.accounts_evaluated as i64, report.errors_encountered.len() as i64))
+            }
+            EodStage::InactivityFees => {
+                let report = service.assess_inactivity_fees(run_date).await?;
+                Ok(EodChunk::finished(report.accounts_evaluated as i64, report.errors_encountered.len() as i64))
+            }
+            EodStage::AccountMaintenance => {
+                let report = service.run_account_maintenance(run_date).await?;
+                Ok(EodChunk::finished(report.pending_closures_processed as i64, report.errors_encountered.len() as i64))
+            }
+            EodStage::RegulatoryReports => {
+                let reports = service.generate_regulatory_reports(run_date).await?;
+                Ok(EodChunk::finished(reports.len() as i64, 0))
+            }
+            EodStage::SegmentEvaluation => {
+                let report = service.segment_service.evaluate_segments(run_date).await?;
+                Ok(EodChunk::finished(report.segments_evaluated as i64, report.errors.len() as i64))
+            }
+            EodStage::StatementCycle => {
+                if !is_statement_cycle_end(run_date) {
+                    return Ok(EodChunk::finished(0, 0));
+                }
+                let report = service.statement_service.dispatch_cycle_statements(run_date).await?;
+                Ok(EodChunk::finished(report.accounts_processed, 0))
+            }
+            EodStage::CashCeilingCheck => {
+                let report = service.branch_cash_service.check_cash_ceilings(run_date).await?;
+                Ok(EodChunk::finished(report.branches_checked as i64, report.errors.len() as i64))
+            }
+            EodStage::GlSummary => {
+                let lines = service.generate_gl_summary(run_date).await?;
+                Ok(EodChunk::finished(lines.len() as i64, 0))
+            }
+            EodStage::Cleanup => {
+                service.reset_daily_counters().await?;
+                service.archive_completed_workflows().await?;
+                service.notification_service.purge_expired_keys(run_date).await?;
+                Ok(EodChunk::finished(0, 0))
+            }
+        }
+    }
+}
 
 /// Production implementation of EodService
 /// Orchestrates end-of-day processing across all banking operations
 #[allow(dead_code)]
 pub struct EodServiceImpl {
     account_repository: Arc<dyn AccountRepository>,
+    account_domicile_repository: Arc<dyn AccountDomicileRepository>,
+    agent_network_repository: Arc<dyn AgentNetworkRepository>,
     transaction_repository: Arc<dyn TransactionRepository>,
     workflow_repository: Arc<dyn WorkflowRepository>,
     calendar_repository: Arc<dyn CalendarRepository>,
@@ -32,11 +266,26 @@ pub struct EodServiceImpl {
     fee_service: Arc<dyn FeeService>,
     calendar_service: Arc<dyn CalendarService>,
     lifecycle_service: Arc<dyn AccountLifecycleService>,
+    segment_service: Arc<dyn SegmentService>,
+    statement_service: Arc<dyn StatementService>,
+    branch_cash_service: Arc<dyn BranchCashService>,
+    notification_service: Arc<dyn NotificationService>,
+    account_hold_service: Arc<dyn AccountHoldService>,
+    casa_service: Arc<dyn CasaService>,
+    standing_order_repository: Arc<dyn StandingOrderRepository>,
+    transaction_service: Arc<dyn TransactionService>,
+    general_ledger_repository: Arc<dyn GeneralLedgerRepository>,
+    eod_run_repository: Arc<dyn EodRunRepository>,
+    banking_config: Arc<BankingConfig>,
 }
 
 /// Configuration struct for EodServiceImpl to avoid too many constructor arguments
 pub struct EodServiceConfig {
     pub account_repository: Arc<dyn AccountRepository>,
+    /// Domicile history for attributing accounts to branches on past dates
+    pub account_domicile_repository: Arc<dyn AccountDomicileRepository>,
+    /// Domicile branches name the holiday calendar their accounts' standing orders roll on
+    pub agent_network_repository: Arc<dyn AgentNetworkRepository>,
     pub transaction_repository: Arc<dyn TransactionRepository>,
     pub workflow_repository: Arc<dyn WorkflowRepository>,
     pub calendar_repository: Arc<dyn CalendarRepository>,
@@ -45,12 +294,31 @@ pub struct EodServiceConfig {
     pub fee_service: Arc<dyn FeeService>,
     pub calendar_service: Arc<dyn CalendarService>,
     pub lifecycle_service: Arc<dyn AccountLifecycleService>,
+    pub segment_service: Arc<dyn SegmentService>,
+    pub statement_service: Arc<dyn StatementService>,
+    pub branch_cash_service: Arc<dyn BranchCashService>,
+    pub notification_service: Arc<dyn NotificationService>,
+    /// Expires holds due by the end of the processing date
+    pub account_hold_service: Arc<dyn AccountHoldService>,
+    /// Accrues overdraft debit interest and flags unauthorized overdrafts
+    pub casa_service: Arc<dyn CasaService>,
+    pub standing_order_repository: Arc<dyn StandingOrderRepository>,
+    /// Posts standing order transfers through the regular funds check
+    pub transaction_service: Arc<dyn TransactionService>,
+    /// Day's posting totals and the saved GL summaries
+    pub general_ledger_repository: Arc<dyn GeneralLedgerRepository>,
+    /// Step progress of resumable runs
+    pub eod_run_repository: Arc<dyn EodRunRepository>,
+    /// Provisioning buckets, dormancy default and interest accrual tuning
+    pub banking_config: Arc<BankingConfig>,
 }
 
 impl EodServiceImpl {
     pub fn new(config: EodServiceConfig) -> Self {
         Self {
             account_repository: config.account_repository,
+            account_domicile_repository: config.account_domicile_repository,
+            agent_network_repository: config.agent_network_repository,
             transaction_repository: config.transaction_repository,
             workflow_repository: config.workflow_repository,
             calendar_repository: config.calendar_repository,
@@ -59,6 +327,17 @@ impl EodServiceImpl {
             fee_service: config.fee_service,
             calendar_service: config.calendar_service,
             lifecycle_service: config.lifecycle_service,
+            segment_service: config.segment_service,
+            statement_service: config.statement_service,
+            branch_cash_service: config.branch_cash_service,
+            notification_service: config.notification_service,
+            account_hold_service: config.account_hold_service,
+            casa_service: config.casa_service,
+            standing_order_repository: config.standing_order_repository,
+            transaction_service: config.transaction_service,
+            general_ledger_repository: config.general_ledger_repository,
+            eod_run_repository: config.eod_run_repository,
+            banking_config: config.banking_config,
         }
     }
 
@@ -103,6 +382,296 @@ impl EodServiceImpl {
             warnings: vec![],
         }
     }
+
+    /// Post one due occurrence and move the order along its schedule, rolling
+    /// dates on the calendar of the source account's domicile branch. Errors
+    /// leave the order untouched so the occurrence runs again on the next day.
+    async fn run_standing_order(
+        &self,
+        order: &mut StandingOrder,
+        run_date: NaiveDate,
+    ) -> BankingResult<StandingOrderRun> {
+        let source = self.account_repository
+            .find_by_id(order.source_account_id)
+            .await?
+            .ok_or(BankingError::AccountNotFound(order.source_account_id))?;
+        let calendar_id = branch_calendar_id(
+            self.agent_network_repository.as_ref(),
+            source.domicile_agency_branch_id,
+            &self.banking_config.eod.calendar_jurisdiction,
+        ).await?;
+        let scheduler = StandingOrderScheduler::new(self.calendar_service.clone(), calendar_id);
+        let beneficiary = match order.beneficiary_account_id {
+            Some(beneficiary_id) => {
+                let beneficiary = self.account_repository
+                    .find_by_id(beneficiary_id)
+                    .await?
+                    .ok_or(BankingError::AccountNotFound(beneficiary_id))?;
+                if beneficiary.currency != source.currency {
+                    return Err(BankingError::ValidationError {
+                        field: "beneficiary_account_id".to_string(),
+                        message: format!(
+                            "Beneficiary account is in {}, source account in {}",
+                            beneficiary.currency, source.currency
+                        ),
+                    });
+                }
+                Some(beneficiary)
+            }
+            None => None,
+        };
+
+        let Some(amount) = order.amount.transfer_amount(source.available_balance) else {
+            scheduler.advance(order).await?;
+            return Ok(StandingOrderRun::Completed);
+        };
+
+        let debited = if amount > source.available_balance {
+            Err(BankingError::InsufficientFunds {
+                account_id: source.id,
+                requested: amount,
+                available: source.available_balance,
+            })
+        } else {
+            let debit = Self::standing_order_posting(order, &source, TransactionType::Debit, amount, run_date)?;
+            self.transaction_service.process_transaction(debit).await
+        };
+        let debit = match debited {
+            Ok(debit) => debit,
+            // Funds can still be consumed between the check and the posting
+            Err(BankingError::InsufficientFunds { requested, available, .. }) => {
+                if order.record_insufficient_funds() {
+                    scheduler.retry(order, run_date).await?;
+                } else if order.status == StandingOrderStatus::Active {
+                    scheduler.advance(order).await?;
+                }
+                return Ok(StandingOrderRun::Unfunded { requested, available });
+            }
+            Err(e) => return Err(e),
+        };
+
+        let credit = beneficiary
+            .map(|beneficiary| Self::standing_order_posting(order, &beneficiary, TransactionType::Credit, amount, run_date))
+            .transpose()?;
+
+        // The debit stands either way, so the order moves on even if the credit fails
+        order.record_execution(Utc::now());
+        scheduler.advance(order).await?;
+        if let Some(credit) = credit {
+            if let Err(error) = self.transaction_service.process_transaction(credit).await {
+                return Ok(StandingOrderRun::CreditFailed { debit_id: debit.id, error });
+            }
+        }
+        Ok(StandingOrderRun::Completed)
+    }
+
+    /// Leg of a standing order transfer. The idempotency key names the
+    /// occurrence, so a rerun of the day does not post it twice.
+    fn standing_order_posting(
+        order: &StandingOrder,
+        account: &AccountModel,
+        transaction_type: TransactionType,
+        amount: Decimal,
+        run_date: NaiveDate,
+    ) -> BankingResult<Transaction> {
+        let leg = if transaction_type == TransactionType::Debit { "D" } else { "C" };
+        let now = Utc::now();
+
+        Ok(Transaction {
+            id: Uuid::new_v4(),
+            account_id: account.id,
+            transaction_code: HeaplessString::try_from("STO").map_err(|_| BankingError::ValidationError {
+                field: "transaction_code".to_string(),
+                message: "Transaction code too long".to_string(),
+            })?,
+            transaction_type,
+            amount,
+            currency: CurrencyCode::try_from(account.currency.as_str())?,
+            description: HeaplessString::try_from(format!("Standing order {}", order.id).as_str())
+                .map_err(|_| BankingError::ValidationError {
+                    field: "description".to_string(),
+                    message: "Description too long".to_string(),
+                })?,
+            channel_id: HeaplessString::try_from(STANDING_ORDER_CHANNEL_ID).map_err(|_| BankingError::ValidationError {
+                field: "channel_id".to_string(),
+                message: "Channel ID too long".to_string(),
+            })?,
+            terminal_id: None,
+            agent_person_id: None,
+            transaction_date: now,
+            value_date: run_date,
+            status: TransactionStatus::Pending,
+            // Generated by the transaction service, as is the GL code
+            reference_number: HeaplessString::new(),
+            external_reference: order.external_reference.clone(),
+            gl_code: HeaplessString::new(),
+            requires_approval: false,
+            approval_status: None,
+            risk_score: None,
+            degraded_flags: DegradedFlags::NONE,
+            idempotency_key: Some(
+                HeaplessString::try_from(
+                    format!("{}-{}-{leg}", order.id.simple(), order.next_due_date.format("%Y%m%d")).as_str(),
+                )
+                .map_err(|_| BankingError::ValidationError {
+                    field: "idempotency_key".to_string(),
+                    message: "Idempotency key too long".to_string(),
+                })?,
+            ),
+            reversal_of_transaction_id: None,
+            fx_conversion: None,
+            created_at: now,
+        })
+    }
+
+    /// Orchestrator checkpointing account-paged steps after every page
+    fn eod_orchestrator(&self) -> EodOrchestrator {
+        EodOrchestrator::new(self.eod_run_repository.clone(), self.banking_config.eod.account_page_size)
+    }
+
+    fn eod_steps(&self) -> Vec<EodServiceStep<'_>> {
+        EodStage::ALL.iter().map(|&stage| EodServiceStep { service: self, stage }).collect()
+    }
+
+    /// One page of `process_overdraft_interest`
+    async fn overdraft_interest_page(&self, page: &[AccountModel], processing_date: NaiveDate, tally: &mut PageTally) {
+        let alert_after_days = self.banking_config.compliance.unauthorized_overdraft_alert_days;
+
+        let casa_accounts = page.iter().filter(|a| {
+            matches!(a.account_type, DbAccountType::Savings | DbAccountType::Current)
+                && a.account_status != DbAccountStatus::Closed
+        });
+        for account in casa_accounts {
+            match self.casa_service.accrue_overdraft_interest(account.id, processing_date).await {
+                Ok(None) => {}
+                Ok(Some(accrual)) => {
+                    tally.processed += 1;
+                    tally.successful += 1;
+                    if accrual.position == OverdraftPosition::Unauthorized
+                        && accrual.consecutive_unauthorized_days >= alert_after_days
+                    {
+                        tally.warnings.push(format!(
+                            "Account {}: {} beyond overdraft limit for {} days",
+                            account.id, accrual.unauthorized_amount, accrual.consecutive_unauthorized_days,
+                        ));
+                    }
+                }
+                Err(e) => {
+                    tally.processed += 1;
+                    tally.errors.push(format!("Account {}: {e}", account.id));
+                }
+            }
+        }
+    }
+
+    /// One page of `assess_overdue_penalties`
+    async fn overdue_penalties_page(&self, page: &[AccountModel], run_date: NaiveDate, tally: &mut PageTally) {
+        let grace_days = self.banking_config.eod.penalty_grace_days;
+
+        let open_loans = page.iter().filter(|a| {
+            a.account_type == DbAccountType::Loan && a.account_status != DbAccountStatus::Closed
+        });
+        for account in open_loans {
+            let (Some(due_date), Some(installment_amount)) = (account.next_due_date, account.installment_amount) else {
+                continue;
+            };
+            tally.processed += 1;
+
+            let previous = match self
+                .account_repository
+                .find_latest_loan_penalty_accrual_before(account.id, run_date)
+                .await
+            {
+                Ok(previous) => previous.map(AccountMapper::loan_penalty_accrual_from_model),
+                Err(e) => {
+                    tally.errors.push(format!("Loan {}: {e}", account.id));
+                    continue;
+                }
+            };
+
+            let installment = LoanInstallmentDue {
+                loan_account_id: account.id,
+                due_date,
+                installment_amount,
+                penalty_rate: account.penalty_rate.unwrap_or(Decimal::ZERO),
+            };
+            let mut accrual = LoanPenaltyAccrual::assess(&installment, run_date, grace_days, previous.as_ref());
+            let was_overdue = previous.as_ref().is_some_and(|p| p.days_past_due > 0);
+            if accrual.days_past_due == 0 && !was_overdue {
+                tally.successful += 1;
+                continue;
+            }
+
+            let minor_units = match CurrencyCode::try_from(account.currency.as_str()) {
+                Ok(currency) => currency.minor_units(),
+                Err(e) => {
+                    tally.errors.push(format!("Loan {}: {e}", account.id));
+                    continue;
+                }
+            };
+            accrual.penalty_amount = accrual
+                .penalty_amount
+                .round_dp_with_strategy(minor_units, RoundingStrategy::MidpointNearestEven);
+
+            match self
+                .account_repository
+                .save_loan_penalty_accrual(AccountMapper::loan_penalty_accrual_to_model(accrual))
+                .await
+            {
+                Ok(_) => tally.successful += 1,
+                Err(e) => tally.errors.push(format!("Loan {}: {e}", account.id)),
+            }
+        }
+    }
+
+    /// One page of `track_overdrawn_days`
+    async fn overdrawn_days_page(&self, page: &[AccountModel], processing_date: NaiveDate, tally: &mut PageTally) {
+        // Loans are provisioned from schedule arrears, so only CASA accounts are tracked here
+        let casa_accounts = page.iter().filter(|a| {
+            matches!(a.account_type, DbAccountType::Savings | DbAccountType::Current)
+                && a.account_status != DbAccountStatus::Closed
+        });
+        for account in casa_accounts {
+            tally.processed += 1;
+        
+            let previous = match self
+                .account_repository
+                .find_latest_balance_snapshot_before(account.id, processing_date)
+                .await
+            {
+                Ok(previous) => previous.map(AccountMapper::balance_snapshot_from_model),
+                Err(e) => {
+                    tally.errors.push(format!("Account {}: {e}", account.id));
+                    continue;
+                }
+            };
+        
+            let snapshot = AccountBalanceSnapshot::next(
+                account.id,
+                processing_date,
+                account.current_balance,
+                previous.as_ref(),
+                &self.banking_config.eod.provisioning,
+            );
+            if snapshot.bucket_transition {
+                tally.warnings.push(format!(
+                    "Account {}: provisioning bucket {:?} -> {:?}",
+                    account.id,
+                    snapshot.previous_provisioning_bucket.unwrap_or(ProvisioningBucket::Current),
+                    snapshot.provisioning_bucket,
+                ));
+            }
+
+            match self
+                .account_repository
+                .save_balance_snapshot(AccountMapper::balance_snapshot_to_model(snapshot))
+                .await
+            {
+                Ok(_) => tally.successful += 1,
+                Err(e) => tally.errors.push(format!("Account {}: {e}", account.id)),
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -113,17 +682,22 @@ impl EodService for EodServiceImpl {
         let processing_date = chrono::Utc::now().date_naive();
         let started_at = Utc::now();
         
-        match self.interest_service.accrue_daily_interest(processing_date).await {
+        match self.interest_service.accrue_daily_interest(processing_date, self.banking_config.interest.accrual_options()).await {
             Ok(accrual_report) => {
+                let records_successful = accrual_report.accounts_processed + accrual_report.accounts_already_accrued;
                 Ok(EodReport {
                     processing_date,
                     report_type: "DAILY_INTEREST_ACCRUAL".to_string(),
-                    status: EodReportStatus::Completed,
+                    status: if accrual_report.errors.is_empty() {
+                        EodReportStatus::Completed
+                    } else {
+                        EodReportStatus::CompletedWithWarnings
+                    },
                     started_at,
                     completed_at: Some(Utc::now()),
-                    records_processed: accrual_report.accounts_processed,
-                    records_successful: accrual_report.accounts_processed,
-                    records_failed: 0, // AccrualReport doesn't track failed accounts separately
+                    records_processed: records_successful + accrual_report.accounts_failed,
+                    records_successful,
+                    records_failed: accrual_report.accounts_failed,
                     errors: accrual_report.errors,
                     warnings: vec![],
                 })
@@ -163,6 +737,99 @@ impl EodService for EodServiceImpl {
         }
     }
 
+
+    /// Accrue overdraft interest on every open CASA account through the CASA service
+    async fn process_overdraft_interest(&self, processing_date: NaiveDate) -> BankingResult<EodReport> {
+        let started_at = Utc::now();
+        
+        let page_size = self.banking_config.eod.account_page_size;
+        let mut tally = PageTally::default();
+
+        let mut after_account_id = None;
+        loop {
+            let page = self.account_repository.list_after(after_account_id, page_size).await?;
+            let Some(last) = page.last() else { break };
+            after_account_id = Some(last.id);
+
+            self.overdraft_interest_page(&page, processing_date, &mut tally).await;
+
+            if (page.len() as i64) < page_size {
+                break;
+            }
+        }
+
+        Ok(tally.into_report(processing_date, "OVERDRAFT_INTEREST_ACCRUAL", started_at))
+    }
+
+    /// Run the standing orders due by the run date. Unfunded occurrences are
+    /// warnings handled by each order's failure policy; the order is saved
+    /// with its next run date either way.
+    async fn execute_standing_orders(&self, run_date: NaiveDate) -> BankingResult<EodReport> {
+        let started_at = Utc::now();
+        
+        let due_orders = self.standing_order_repository.find_due(run_date).await?;
+        let mut processed = 0;
+        let mut successful = 0;
+        let mut errors = vec![];
+        let mut warnings = vec![];
+
+        for model in due_orders {
+            processed += 1;
+            let order_id = model.id;
+            let mut order = match StandingOrderMapper::from_model(model) {
+                Ok(order) => order,
+                Err(e) => {
+                    errors.push(format!("Standing order {order_id}: {e}"));
+                    continue;
+                }
+            };
+
+            let run = match self.run_standing_order(&mut order, run_date).await {
+                Ok(run) => run,
+                Err(e) => {
+                    errors.push(format!("Standing order {order_id}: {e}"));
+                    continue;
+                }
+            };
+            match run {
+                StandingOrderRun::Completed => successful += 1,
+                StandingOrderRun::Unfunded { requested, available } => {
+                    successful += 1;
+                    let outcome = match order.status {
+                        StandingOrderStatus::Active => format!("next run {}", order.next_run_date),
+                        status => format!("order {status:?}"),
+                    };
+                    warnings.push(format!(
+                        "Standing order {order_id}: insufficient funds, {requested} requested, {available} available; {outcome}"
+                    ));
+                }
+                StandingOrderRun::CreditFailed { debit_id, error } => {
+                    errors.push(format!(
+                        "Standing order {order_id}: debit {debit_id} posted but beneficiary credit failed: {error}"
+                    ));
+                }
+            }
+
+            order.last_updated_at = Utc::now();
+            if let Err(e) = self.standing_order_repository.update_standing_order(StandingOrderMapper::to_model(order)).await {
+                errors.push(format!("Standing order {order_id}: {e}"));
+            }
+        }
+
+        Ok(EodReport {
+            processing_date: run_date,
+            report_type: "STANDING_ORDER_EXECUTION".to_string(),
+            status: if errors.is_empty() { EodReportStatus::Completed } else { EodReportStatus::CompletedWithWarnings },
+            started_at,
+            completed_at: Some(Utc::now()),
+            records_processed: processed,
+            records_successful: successful,
+            records_failed: processed - successful,
+            errors,
+            warnings,
+        })
+    }
+
     /// Apply periodic fees (monthly maintenance, overdraft fees, etc.)
     async fn apply_periodic_fees(&self, processing_date: NaiveDate) -> BankingResult<EodReport> {
         let started_at = Utc::now();
@@ -204,6 +871,7 @@ impl EodService for EodServiceImpl {
     }
 
     /// Update delinquent loans based on payment history
+    #[allow(deprecated)]
     async fn update_delinquent_loans(&self, processing_date: NaiveDate) -> BankingResult<EodReport> {
         let started_at = Utc::now();
         
@@ -222,7 +890,7 @@ impl EodService for EodServiceImpl {
             // In production, this would involve complex payment history analysis
             if account.current_balance > rust_decimal::Decimal::ZERO {
                 // Update account status if overdue
-                match self.account_repository.update_status(
+                match self.account_repository.update_status_legacy(
                     account.id,
                     "Delinquent",
                     "EOD delinquency check",
@@ -250,6 +918,119 @@ impl EodService for EodServiceImpl {
         })
     }
 
+
+    /// Assess each open loan's next installment against the run date. Loans
+    /// never overdue are skipped; a loan that has caught up gets one more row
+    /// resetting its counter, leaving the penalties already assessed in place.
+    async fn assess_overdue_penalties(&self, run_date: NaiveDate) -> BankingResult<EodReport> {
+        let started_at = Utc::now();
+        
+        let page_size = self.banking_config.eod.account_page_size;
+        let mut tally = PageTally::default();
+
+        let mut after_account_id = None;
+        loop {
+            let page = self.account_repository.list_after(after_account_id, page_size).await?;
+            let Some(last) = page.last() else { break };
+            after_account_id = Some(last.id);
+
+            self.overdue_penalties_page(&page, run_date, &mut tally).await;
+
+            if (page.len() as i64) < page_size {
+                break;
+            }
+        }
+
+        Ok(tally.into_report(run_date, "OVERDUE_LOAN_PENALTIES", started_at))
+    }
+
+    /// Snapshot end-of-day balances and advance the consecutive overdrawn-day counters
+    async fn track_overdrawn_days(&self, processing_date: NaiveDate) -> BankingResult<EodReport> {
+        let started_at = Utc::now();
+        
+        let page_size = self.banking_config.eod.account_page_size;
+        let mut tally = PageTally::default();
+
+        // Keyset pages keep memory flat and each query cheap however many accounts there are
+        let mut after_account_id = None;
+        loop {
+            let page = self.account_repository.list_after(after_account_id, page_size).await?;
+            let Some(last) = page.last() else { break };
+            after_account_id = Some(last.id);
+
+            self.overdrawn_days_page(&page, processing_date, &mut tally).await;
+
+            if (page.len() as i64) < page_size {
+                break;
+            }
+        }
+
+        Ok(tally.into_report(processing_date, "OVERDRAWN_DAYS_TRACKING", started_at))
+    }
+
+    /// Aggregate overdrawn exposure per provisioning bucket, product and branch
+    async fn get_provisioning_report(&self, as_of_date: NaiveDate) -> BankingResult<ProvisioningReport> {
+        let snapshots = self.account_repository.find_balance_snapshots_by_date(as_of_date).await?;
+        // Accounts moved after the report date still count at the branch they had then
+        let mut later_domicile_changes: HashMap<Uuid, Vec<_>> = HashMap::new();
+        for change in self.account_domicile_repository.find_domicile_changes_effective_after(as_of_date).await? {
+            later_domicile_changes
+                .entry(change.account_id)
+                .or_default()
+                .push(AccountDomicileMapper::from_model(change));
+        }
+
+        let mut exposures: HashMap<(ProvisioningBucket, Uuid, Uuid), (i32, Decimal)> = HashMap::new();
+        let mut bucket_transitions = vec![];
+
+        for snapshot in snapshots.into_iter().map(AccountMapper::balance_snapshot_from_model) {
+            if snapshot.bucket_transition {
+                bucket_transitions.push(ProvisioningBucketTransition {
+                    account_id: snapshot.account_id,
+                    from_bucket: snapshot.previous_provisioning_bucket.unwrap_or(ProvisioningBucket::Current),
+                    to_bucket: snapshot.provisioning_bucket,
+                    consecutive_overdrawn_days: snapshot.consecutive_overdrawn_days,
+                });
+            }
+            if snapshot.provisioning_bucket == ProvisioningBucket::Current {
+                continue;
+            }
+            
+            let Some(account) = self.account_repository.find_by_id(snapshot.account_id).await? else {
+                continue;
+            };
+            let branch_id = domicile_branch_on(
+                account.domicile_agency_branch_id,
+                later_domicile_changes.get(&account.id).map(Vec::as_slice).unwrap_or_default(),
+                as_of_date,
+            );
+            let entry = exposures
+                .entry((snapshot.provisioning_bucket, account.product_id, branch_id))
+                .or_insert((0, Decimal::ZERO));
+            entry.0 += 1;
+            entry.1 += snapshot.closing_balance.abs();
+        }
+
+        let exposures = exposures
+            .into_iter()
+            .map(|((bucket, product_id, domicile_agency_branch_id), (account_count, exposure))| {
+                ProvisioningExposure {
+                    bucket,
+                    product_id,
+                    domicile_agency_branch_id,
+                    account_count,
+                    exposure,
+                }
+            })
+            .collect();
+
+        Ok(ProvisioningReport {
+            as_of_date,
+            exposures,
+            bucket_transitions,
+        })
+    }
+
     /// Generate regulatory reports for compliance
     async fn generate_regulatory_reports(&self, processing_date: NaiveDate) -> BankingResult<Vec<RegulatoryReport>> {
         let mut reports = vec![];
@@ -279,6 +1060,55 @@ impl EodService for EodServiceImpl {
         Ok(reports)
     }
 
+    /// Summarise the day's postings by GL code. Each posting books its amount
+    /// on the account's customer GL code and on the clearing GL code of its
+    /// channel, in opposite directions.
+    async fn generate_gl_summary(&self, run_date: NaiveDate) -> BankingResult<Vec<GlSummaryLine>> {
+        let mut customer_account_codes: HashMap<Uuid, String> = HashMap::new();
+        let mut summary = GlSummary::new(run_date);
+
+        for totals in self.general_ledger_repository.find_posting_totals(run_date).await? {
+            let customer_account_code = match customer_account_codes.get(&totals.product_id) {
+                Some(code) => code.clone(),
+                None => {
+                    let code = self.product_repository
+                        .find_gl_mapping_by_product_id(totals.product_id)
+                        .await?
+                        .ok_or_else(|| BankingError::NotFound(format!("GL mapping not found for product {}", totals.product_id)))?
+                        .customer_account_code
+                        .to_string();
+                    customer_account_codes.insert(totals.product_id, code.clone());
+                    code
+                }
+            };
+            let gl_code = customer_gl_code(&customer_account_code, totals.gl_code_suffix.as_deref());
+            let contra_gl_code = self.banking_config.eod.clearing_gl_code(totals.channel_id.as_str());
+
+            summary.add(&GlPostingTotals {
+                product_id: totals.product_id,
+                branch_id: totals.branch_id,
+                currency: CurrencyCode::try_from(totals.currency)?,
+                customer_gl_code: HeaplessString::try_from(gl_code.as_str())
+                    .map_err(|_| BankingError::Internal(format!("GL code {gl_code} too long")))?,
+                contra_gl_code: HeaplessString::try_from(contra_gl_code)
+                    .map_err(|_| BankingError::Internal(format!("GL code {contra_gl_code} too long")))?,
+                transaction_type: TransactionMapper::transaction_type_from_db(totals.transaction_type),
+                amount: totals.amount_total,
+                transaction_count: totals.transaction_count,
+            });
+        }
+
+        let lines = summary.into_lines()?;
+        let created_at = Utc::now();
+        let models = lines
+            .iter()
+            .cloned()
+            .map(|line| GeneralLedgerMapper::summary_line_to_model(line, created_at))
+            .collect();
+        self.general_ledger_repository.replace_gl_summary(run_date, models).await?;
+        Ok(lines)
+    }
+
     /// Reset daily transaction counters and limits
     async fn reset_daily_counters(&self) -> BankingResult<()> {
         // In a production system, this would reset:
@@ -304,37 +1134,74 @@ impl EodService for EodServiceImpl {
 
     /// Determine next business day for processing
     async fn determine_next_processing_date(&self, current_date: NaiveDate) -> BankingResult<NaiveDate> {
-        self.calendar_service.add_business_days(current_date, 1, "DEFAULT").await
+        self.calendar_service.add_business_days(current_date, 1, &self.banking_config.eod.calendar_jurisdiction).await
     }
 
     /// Run complete EOD processing workflow
     async fn run_eod_processing(&self, processing_date: NaiveDate) -> BankingResult<EodProcessingResult> {
         let started_at = Utc::now();
         
-        // Step 1: Interest accrual
-        let interest_accrual = self.interest_service.accrue_daily_interest(processing_date).await?;
+        // Step 1: Hold expiry, so later steps see the restored available balances
+        let end_of_day = processing_date.and_hms_opt(23, 59, 59).unwrap_or_default().and_utc();
+        let hold_expiry = self.account_hold_service.expire_holds(end_of_day).await?;
+        
+        // Step 2: Standing orders, so interest accrues on the balances they leave
+        let standing_orders = self.execute_standing_orders(processing_date).await?;
         
-        // Step 2: Interest capitalization (if applicable)
+        // Step 3: Interest accrual
+        let interest_accrual = self.interest_service.accrue_daily_interest(processing_date, self.banking_config.interest.accrual_options()).await?;
+        
+        // Step 4: Overdraft interest, split at each account's overdraft limit
+        let overdraft_interest = self.process_overdraft_interest(processing_date).await?;
+        
+        // Step 5: Interest capitalization (if applicable)
         let interest_capitalization = self.interest_service.capitalize_interest(processing_date).await?;
         
-        // Step 3: Fee processing
+        // Step 6: Fee processing
         let fee_processing = self.apply_periodic_fees(processing_date).await?;
         
-        // Step 4: Loan updates
+        // Step 7: Loan updates
         let loan_updates = self.update_delinquent_loans(processing_date).await?;
         
-        // Step 5: Dormancy processing
+        // Step 8: Days past due and penalty interest on overdue installments
+        let overdue_penalties = self.assess_overdue_penalties(processing_date).await?;
+        
+        // Step 9: Overdrawn-day tracking for provisioning
+        let overdrawn_tracking = self.track_overdrawn_days(processing_date).await?;
+        
+        // Step 10: Dormancy processing
         let dormancy_processing = self.process_dormancy_candidates(processing_date).await?;
         
-        // Step 6: Account maintenance
+        // Step 11: Inactivity fees, once today's newly dormant accounts are known
+        let inactivity_fees = self.assess_inactivity_fees(processing_date).await?;
+        
+        // Step 12: Account maintenance
         let maintenance_processing = self.run_account_maintenance(processing_date).await?;
         
-        // Step 7: Regulatory reports
+        // Step 13: Regulatory reports
         let regulatory_reports = self.generate_regulatory_reports(processing_date).await?;
         
-        // Step 8: Cleanup
+        // Step 14: Customer segment membership, after balances and statuses are final
+        let segment_evaluation = self.segment_service.evaluate_segments(processing_date).await?;
+        
+        // Step 15: Cycle statements, routed by each account's delivery preference
+        let statement_cycle = if is_statement_cycle_end(processing_date) {
+            Some(self.statement_service.dispatch_cycle_statements(processing_date).await?)
+        } else {
+            None
+        };
+        
+        // Step 16: Branch vault cash against insurance ceilings
+        let cash_ceiling_check = self.branch_cash_service.check_cash_ceilings(processing_date).await?;
+        
+        // Step 17: GL summary, once the day's postings are complete
+        let gl_summary = self.generate_gl_summary(processing_date).await?;
+        
+        // Step 18: Cleanup
         self.reset_daily_counters().await?;
         self.archive_completed_workflows().await?;
+        self.notification_service.purge_expired_keys(processing_date).await?;
+        let notification_duplicates = self.notification_service.get_duplicate_report(processing_date).await?;
         
         let completed_at = Utc::now();
         
@@ -349,18 +1216,43 @@ impl EodService for EodServiceImpl {
             processing_date,
             started_at,
             completed_at,
+            hold_expiry,
+            standing_orders,
             interest_accrual,
             interest_capitalization,
+            overdraft_interest,
             fee_processing,
             loan_updates,
+            overdue_penalties,
+            overdrawn_tracking,
             dormancy_processing,
+            inactivity_fees,
             maintenance_processing,
             regulatory_reports,
+            segment_evaluation,
+            statement_cycle,
+            cash_ceiling_check,
+            gl_summary,
+            notification_duplicates,
             overall_status,
         })
     }
 
+    /// Run the EOD steps through the orchestrator, recording each step's progress
+    async fn run_eod(&self, run_date: NaiveDate, force: bool) -> BankingResult<EodRun> {
+        let steps = self.eod_steps();
+        let steps: Vec<&dyn EodStep> = steps.iter().map(|step| step as &dyn EodStep).collect();
+        self.eod_orchestrator().run(run_date, force, &steps).await
+    }
+
+    async fn resume(&self, run_date: NaiveDate) -> BankingResult<EodRun> {
+        let steps = self.eod_steps();
+        let steps: Vec<&dyn EodStep> = steps.iter().map(|step| step as &dyn EodStep).collect();
+        self.eod_orchestrator().resume(run_date, &steps).await
+    }
+
     /// Process accounts that are candidates for dormancy
+    #[allow(deprecated)]
     async fn process_dormancy_candidates(
         &self,
         processing_date: NaiveDate,
@@ -378,17 +1270,19 @@ impl EodService for EodServiceImpl {
             let threshold = self.get_dormancy_threshold(product.id).await?;
             let candidates = self
                 .account_repository
-                .find_dormancy_candidates(processing_date, threshold)
+                .find_dormancy_candidates(processing_date, threshold, Some(product.id))
                 .await?;
             dormancy_candidates.extend(candidates);
         }
 
         let mut accounts_marked_dormant = 0;
         let mut accounts_by_product = HashMap::new();
+        let mut notifications_generated = 0;
+        let mut notifications_suppressed = 0;
         let mut errors = vec![];
         
         for account in &dormancy_candidates {
-            match self.account_repository.update_status(
+            match self.account_repository.update_status_legacy(
                 account.id,
                 "Dormant",
                 "EOD dormancy processing",
@@ -399,7 +1293,29 @@ impl EodService for EodServiceImpl {
                     let product_id = account.product_id.to_string();
                     *accounts_by_product.entry(product_id).or_insert(0) += 1;
                 }
-                Err(e) => errors.push(format!("Account {}: {e}", account.id)),
+                Err(e) => {
+                    errors.push(format!("Account {}: {e}", account.id));
+                    continue;
+                }
+            }
+
+            // Keyed notices make a rerun of this step safe: owners already told today are skipped
+            let owners = match self.account_repository.find_ownership_by_account(account.id).await {
+                Ok(owners) => owners,
+                Err(e) => {
+                    errors.push(format!("Account {} owners: {e}", account.id));
+                    continue;
+                }
+            };
+            for owner in owners {
+                match self.notification_service
+                    .queue_dormancy_notice(owner.customer_id, account.id, processing_date)
+                    .await
+                {
+                    Ok(outcome) if outcome.was_duplicate => notifications_suppressed += 1,
+                    Ok(_) => notifications_generated += 1,
+                    Err(e) => errors.push(format!("Account {} notice to {}: {e}", account.id, owner.customer_id)),
+                }
             }
         }
 
@@ -408,11 +1324,103 @@ impl EodService for EodServiceImpl {
             accounts_evaluated: dormancy_candidates.len() as i32,
             accounts_marked_dormant,
             accounts_by_product,
-            notifications_generated: accounts_marked_dormant, // Simplified: 1 notification per account
+            notifications_generated,
+            notifications_suppressed,
             errors_encountered: errors,
         })
     }
 
+    /// Charge the inactivity fee of each dormant account past its product's
+    /// threshold and warn the owners of those within the warning lead time.
+    /// Keyed fees and warnings make a rerun of the step charge and warn no one twice.
+    async fn assess_inactivity_fees(&self, run_date: NaiveDate) -> BankingResult<InactivityFeeReport> {
+        let dormant_accounts = self.account_repository.find_by_status("Dormant").await?;
+        let mut rules_by_product: HashMap<Uuid, Option<banking_api::domain::ProductRules>> = HashMap::new();
+        let mut report = InactivityFeeReport {
+            processing_date: run_date,
+            accounts_evaluated: 0,
+            fees_charged: 0,
+            amount_charged: Decimal::ZERO,
+            fees_not_charged: 0,
+            skipped_for_legal_hold: 0,
+            warnings_generated: 0,
+            warnings_suppressed: 0,
+            errors_encountered: vec![],
+        };
+
+        for account in &dormant_accounts {
+            let Some(last_activity_date) = account.last_activity_date else { continue };
+            if !rules_by_product.contains_key(&account.product_id) {
+                let rules = match self.product_repository.find_product_by_id(account.product_id).await {
+                    Ok(product) => product.map(|p| ProductMapper::from_db(p).rules),
+                    Err(e) => {
+                        report.errors_encountered.push(format!("Account {} product: {e}", account.id));
+                        continue;
+                    }
+                };
+                rules_by_product.insert(account.product_id, rules);
+            }
+            let Some(rules) = rules_by_product.get(&account.product_id).and_then(Option::as_ref) else { continue };
+            let (Some(stage), Some(fee_item_id)) = (
+                rules.inactivity_fee_stage(last_activity_date, run_date),
+                rules.inactivity_fee_item_id,
+            ) else {
+                continue;
+            };
+            report.accounts_evaluated += 1;
+
+            match stage {
+                InactivityFeeStage::Due { cycle_start } => {
+                    let liens = match self.account_hold_service
+                        .get_active_holds_with_types(account.id, Some(vec![HoldType::JudicialLien]))
+                        .await
+                    {
+                        Ok(liens) => liens,
+                        Err(e) => {
+                            report.errors_encountered.push(format!("Account {} holds: {e}", account.id));
+                            continue;
+                        }
+                    };
+                    if !liens.is_empty() {
+                        report.skipped_for_legal_hold += 1;
+                        continue;
+                    }
+                    match self.fee_service.apply_inactivity_fee(account.id, fee_item_id, cycle_start, run_date).await {
+                        Ok(Some(fee)) => {
+                            report.fees_charged += 1;
+                            report.amount_charged += fee.amount;
+                        }
+                        Ok(None) => report.fees_not_charged += 1,
+                        Err(e) => report.errors_encountered.push(format!("Account {} inactivity fee: {e}", account.id)),
+                    }
+                }
+                InactivityFeeStage::Warning { warned_from, fee_date } => {
+                    let owners = match self.account_repository.find_ownership_by_account(account.id).await {
+                        Ok(owners) => owners,
+                        Err(e) => {
+                            report.errors_encountered.push(format!("Account {} owners: {e}", account.id));
+                            continue;
+                        }
+                    };
+                    for owner in owners {
+                        match self.notification_service
+                            .queue_inactivity_fee_warning(owner.customer_id, account.id, fee_date, warned_from)
+                            .await
+                        {
+                            Ok(outcome) if outcome.was_duplicate => report.warnings_suppressed += 1,
+                            Ok(_) => report.warnings_generated += 1,
+                            Err(e) => report.errors_encountered.push(format!(
+                                "Account {} inactivity warning to {}: {e}", account.id, owner.customer_id
+                            )),
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
     /// Process accounts pending closure
     async fn process_pending_closures(&self, processing_date: NaiveDate) -> BankingResult<MaintenanceReport> {
         let pending_closures = self.account_repository.find_pending_closure().await?;
@@ -491,7 +1499,7 @@ impl EodService for EodServiceImpl {
                 return Ok(days);
             }
         }
-        Ok(180)
+        Ok(self.banking_config.eod.default_dormancy_days)
     }
     
     async fn calculate_inactivity_period(
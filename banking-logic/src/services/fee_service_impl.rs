@@ -7,14 +7,24 @@ use uuid::Uuid;
 
 use banking_api::{
     BankingResult, BankingError,
-    service::{FeeService, FeeRevenueSummary},
+    service::{FeeService, FeeRevenueSummary, TransactionService},
+    service::audit::audit_log_service::AuditLogService,
     domain::{
         FeeApplication, FeeApplicationStatus, FeeTriggerEvent, FeeType, 
         FeeProcessingJob, FeeJobType, ProductFeeSchedule, ProductFee,
-        FeeWaiver, FeeCategory, FeeCalculationMethod
+        FeeWaiver, FeeCategory, FeeCalculationMethod, FeeItem, FeeCalculation, ChannelFeeTier,
+        ChannelFeeCalculationMethod, CurrencyCode, DegradedFlags, ReasonId, ReasonedOperation,
+        Transaction, TransactionStatus, TransactionType, inactivity_fee_charge,
     },
 };
-use banking_db::repository::{FeeRepository, AccountRepository, ProductRepository};
+use banking_db::models::{AccountModel, DbAccountStatus};
+use banking_db::repository::{
+    FeeRepository, AccountRepository, BundleRepository, ChannelRepository, ProductRepository, ReasonAndPurposeRepository,
+};
+use crate::config::BankingConfig;
+use crate::constants::SYSTEM_PERSON_ID;
+use crate::mappers::{BundleMapper, ChannelMapper, FeeMapper};
+use crate::validation::ReasonValidation;
 
 /// Production implementation of FeeService
 /// Handles both event-based and batch-based fee processing with Product Catalog integration
@@ -23,6 +33,12 @@ pub struct FeeServiceImpl {
     account_repository: Arc<dyn AccountRepository>,
     #[allow(dead_code)]
     product_repository: Arc<dyn ProductRepository>,
+    bundle_repository: Arc<dyn BundleRepository>,
+    channel_repository: Arc<dyn ChannelRepository>,
+    reason_repository: Arc<dyn ReasonAndPurposeRepository>,
+    transaction_service: Arc<dyn TransactionService>,
+    audit_log_service: Arc<dyn AuditLogService>,
+    config: Arc<BankingConfig>,
 }
 
 impl FeeServiceImpl {
@@ -30,12 +46,171 @@ impl FeeServiceImpl {
         fee_repository: Arc<dyn FeeRepository>,
         account_repository: Arc<dyn AccountRepository>,
         product_repository: Arc<dyn ProductRepository>,
+        bundle_repository: Arc<dyn BundleRepository>,
+        channel_repository: Arc<dyn ChannelRepository>,
+        reason_repository: Arc<dyn ReasonAndPurposeRepository>,
+        transaction_service: Arc<dyn TransactionService>,
+        audit_log_service: Arc<dyn AuditLogService>,
+        config: Arc<BankingConfig>,
     ) -> Self {
         Self {
             fee_repository,
             account_repository,
             product_repository,
+            bundle_repository,
+            channel_repository,
+            reason_repository,
+            transaction_service,
+            audit_log_service,
+            config,
+        }
+    }
+
+    async fn find_fee_application(&self, fee_application_id: Uuid) -> BankingResult<FeeApplication> {
+        let model = self.fee_repository
+            .get_fee_application_by_id(fee_application_id)
+            .await?
+            .ok_or_else(|| BankingError::NotFound(format!("Fee application {fee_application_id} not found")))?;
+        FeeMapper::fee_application_from_model(model)
+    }
+
+    /// Whoever applied a fee cannot also waive or reverse it
+    fn check_not_applier(fee: &FeeApplication, person_id: Uuid) -> BankingResult<()> {
+        if fee.applied_by == person_id {
+            return Err(BankingError::FeeSelfApproval { fee_application_id: fee.id, person_id });
+        }
+        Ok(())
+    }
+
+    async fn write_audit_log(&self, person_id: Uuid) -> BankingResult<Uuid> {
+        let audit_log = self.audit_log_service
+            .create_audit_log(person_id)
+            .await
+            .map_err(|e| BankingError::Internal(format!("Failed to write fee audit log: {e}")))?;
+        Ok(audit_log.id)
+    }
+
+    /// Credit giving the fee back, referencing the original fee transaction.
+    /// The idempotency key makes a retried posting return the first credit.
+    fn build_fee_reversal_credit(fee: &FeeApplication, original: &Transaction, reason_code: &str) -> BankingResult<Transaction> {
+        let now = Utc::now();
+        Ok(Transaction {
+            id: Uuid::new_v4(),
+            account_id: fee.account_id,
+            transaction_code: HeaplessString::try_from("FEE_REV").map_err(|_| BankingError::ValidationError {
+                field: "transaction_code".to_string(),
+                message: "Transaction code too long".to_string(),
+            })?,
+            transaction_type: TransactionType::Credit,
+            amount: fee.amount,
+            currency: original.currency.clone(),
+            description: HeaplessString::try_from(format!("Fee reversal: {} - {reason_code}", fee.fee_code).as_str())
+                .map_err(|_| BankingError::ValidationError {
+                    field: "description".to_string(),
+                    message: "Description too long".to_string(),
+                })?,
+            channel_id: HeaplessString::try_from("SYSTEM_REVERSAL").map_err(|_| BankingError::ValidationError {
+                field: "channel_id".to_string(),
+                message: "Channel ID too long".to_string(),
+            })?,
+            terminal_id: None,
+            agent_person_id: None,
+            transaction_date: now,
+            value_date: now.date_naive(),
+            status: TransactionStatus::Pending,
+            // Generated by the transaction service
+            reference_number: HeaplessString::new(),
+            external_reference: Some(original.reference_number.clone()),
+            gl_code: original.gl_code.clone(),
+            requires_approval: false,
+            approval_status: None,
+            risk_score: Some(Decimal::ZERO), // System transaction
+            degraded_flags: DegradedFlags::NONE,
+            idempotency_key: Some(HeaplessString::try_from(format!("FEE_REV_{}", fee.id).as_str()).map_err(|_| {
+                BankingError::ValidationError {
+                    field: "idempotency_key".to_string(),
+                    message: "Idempotency key too long".to_string(),
+                }
+            })?),
+            reversal_of_transaction_id: None,
+            fx_conversion: None,
+            created_at: now,
+        })
+    }
+
+    /// Debit charging the inactivity fee of a cycle. The idempotency key names
+    /// the account and cycle, so a rerun of the day does not post it twice.
+    fn build_inactivity_fee_debit(
+        account: &AccountModel,
+        amount: Decimal,
+        currency: CurrencyCode,
+        cycle_start: NaiveDate,
+        run_date: NaiveDate,
+    ) -> BankingResult<Transaction> {
+        let now = Utc::now();
+        Ok(Transaction {
+            id: Uuid::new_v4(),
+            account_id: account.id,
+            transaction_code: HeaplessString::try_from("FEE_INA").map_err(|_| BankingError::ValidationError {
+                field: "transaction_code".to_string(),
+                message: "Transaction code too long".to_string(),
+            })?,
+            transaction_type: TransactionType::Debit,
+            amount,
+            currency,
+            description: HeaplessString::try_from(format!("Inactivity fee from {cycle_start}").as_str())
+                .map_err(|_| BankingError::ValidationError {
+                    field: "description".to_string(),
+                    message: "Description too long".to_string(),
+                })?,
+            channel_id: HeaplessString::try_from("SYSTEM").map_err(|_| BankingError::ValidationError {
+                field: "channel_id".to_string(),
+                message: "Channel ID too long".to_string(),
+            })?,
+            terminal_id: None,
+            agent_person_id: None,
+            transaction_date: now,
+            value_date: run_date,
+            status: TransactionStatus::Pending,
+            // Generated by the transaction service, as is the GL code
+            reference_number: HeaplessString::new(),
+            external_reference: None,
+            gl_code: HeaplessString::new(),
+            requires_approval: false,
+            approval_status: None,
+            risk_score: Some(Decimal::ZERO), // System transaction
+            degraded_flags: DegradedFlags::NONE,
+            idempotency_key: Some(
+                HeaplessString::try_from(format!("INACT_{}_{}", account.id.simple(), cycle_start.format("%Y%m%d")).as_str())
+                    .map_err(|_| BankingError::ValidationError {
+                        field: "idempotency_key".to_string(),
+                        message: "Idempotency key too long".to_string(),
+                    })?,
+            ),
+            reversal_of_transaction_id: None,
+            fx_conversion: None,
+            created_at: now,
+        })
+    }
+
+    /// Applies the bundle pricing markers of the account for the category,
+    /// as long as the account each discount depends on is still Active
+    async fn apply_bundle_pricing(&self, account_id: Uuid, fee_category: &FeeCategory, fee_amount: Decimal) -> BankingResult<Decimal> {
+        let mut amount = fee_amount;
+        for model in self.bundle_repository.find_active_markers_by_account(account_id).await? {
+            let marker = BundleMapper::marker_from_model(model);
+            if &marker.fee_category != fee_category {
+                continue;
+            }
+            let sustained = self.account_repository
+                .find_by_id(marker.depends_on_account_id)
+                .await?
+                .is_some_and(|a| a.account_status == DbAccountStatus::Active);
+            if sustained {
+                amount = marker.apply(amount);
+            }
         }
+        Ok(amount)
     }
 }
 
@@ -87,6 +262,7 @@ impl FeeService for FeeServiceImpl {
                 Some(account.current_balance),
                 channel.as_deref(),
             ).await?;
+            let fee_amount = self.apply_bundle_pricing(account_id, &product_fee.fee_category, fee_amount).await?;
 
             if fee_amount == Decimal::ZERO {
                 continue;
@@ -116,6 +292,8 @@ impl FeeService for FeeServiceImpl {
                 waived_by: None,
                 waived_reason_id: None,
                 applied_by: Uuid::new_v4(), // System user UUID
+                reversal_transaction_id: None,
+                audit_log_id: None,
                 created_at: Utc::now(),
             };
 
@@ -199,6 +377,8 @@ impl FeeService for FeeServiceImpl {
                 waived_by: None,
                 waived_reason_id: None,
                 applied_by: Uuid::new_v4(), // Preview system UUID
+                reversal_transaction_id: None,
+                audit_log_id: None,
                 created_at: Utc::now(),
             };
 
@@ -398,8 +578,8 @@ impl FeeService for FeeServiceImpl {
             product_ids,
             category_strings,
             processing_date,
-            0,    // offset
-            1000, // limit
+            0, // offset
+            self.config.fees.eligible_accounts_batch_size,
         ).await
     }
 
@@ -432,6 +612,7 @@ impl FeeService for FeeServiceImpl {
                     Some(account.current_balance),
                     None,
                 ).await?;
+                let fee_amount = self.apply_bundle_pricing(account_id, &product_fee.fee_category, fee_amount).await?;
 
                 if fee_amount > Decimal::ZERO {
                     let fee_application = FeeApplication {
@@ -457,6 +638,8 @@ impl FeeService for FeeServiceImpl {
                         waived_by: None,
                         waived_reason_id: None,
                         applied_by: Uuid::new_v4(), // Batch system UUID
+                        reversal_transaction_id: None,
+                        audit_log_id: None,
                         created_at: Utc::now(),
                     };
 
@@ -472,6 +655,92 @@ impl FeeService for FeeServiceImpl {
         Ok(applied_fees)
     }
 
+    async fn apply_inactivity_fee(
+        &self,
+        account_id: Uuid,
+        fee_item_id: Uuid,
+        cycle_start: NaiveDate,
+        run_date: NaiveDate,
+    ) -> BankingResult<Option<FeeApplication>> {
+        // A fee of the cycle counts even if it was reversed or waived since
+        let already_charged = self.fee_repository
+            .get_fee_applications_for_account(account_id, Some(cycle_start), None, None)
+            .await?
+            .iter()
+            .any(|fee| fee.trigger_event == FeeTriggerEvent::AccountInactivity && fee.status != FeeApplicationStatus::Failed);
+        if already_charged {
+            return Ok(None);
+        }
+
+        let account = self.account_repository
+            .find_by_id(account_id)
+            .await?
+            .ok_or(BankingError::AccountNotFound(account_id))?;
+        let fee_item = self.channel_repository
+            .find_fee_item_by_id(fee_item_id)
+            .await?
+            .map(|model| ChannelMapper::from_fee_item_model(model, Vec::new()))
+            .ok_or_else(|| BankingError::NotFound(format!("Fee item {fee_item_id} not found")))?;
+        let currency = CurrencyCode::try_from(account.currency.as_str())?;
+
+        let fee = self.calculate_fee(&fee_item, account.current_balance, &currency).await?;
+        let amount = inactivity_fee_charge(fee, account.current_balance.min(account.available_balance));
+        if amount.is_zero() {
+            return Ok(None);
+        }
+        if amount < fee {
+            tracing::info!("Inactivity fee of {} on account {} charged {} only", fee, account_id, amount);
+        }
+
+        let debit = Self::build_inactivity_fee_debit(&account, amount, currency, cycle_start, run_date)?;
+        let posted = self.transaction_service.process_transaction(debit).await?;
+
+        let now = Utc::now();
+        let fee_application = FeeApplication {
+            id: Uuid::new_v4(),
+            account_id,
+            transaction_id: Some(posted.id),
+            fee_type: FeeType::Periodic,
+            fee_category: FeeCategory::Maintenance,
+            product_id: account.product_id,
+            fee_code: HeaplessString::try_from(fee_item.fee_code.as_str()).map_err(|_| BankingError::ValidationError {
+                field: "fee_code".to_string(),
+                message: format!("Fee code {} too long for a fee application", fee_item.fee_code),
+            })?,
+            description: HeaplessString::try_from(fee_item.fee_name.as_str()).map_err(|_| BankingError::ValidationError {
+                field: "description".to_string(),
+                message: "Description too long".to_string(),
+            })?,
+            amount,
+            currency: account.currency.clone(),
+            calculation_method: match fee_item.calculation_method {
+                ChannelFeeCalculationMethod::Fixed => FeeCalculationMethod::Fixed,
+                ChannelFeeCalculationMethod::Percentage => FeeCalculationMethod::Percentage,
+                ChannelFeeCalculationMethod::Tiered => FeeCalculationMethod::Tiered,
+                ChannelFeeCalculationMethod::BalanceBased => FeeCalculationMethod::BalanceBased,
+                ChannelFeeCalculationMethod::RuleBased | ChannelFeeCalculationMethod::Hybrid => FeeCalculationMethod::RuleBased,
+            },
+            calculation_base_amount: Some(account.current_balance),
+            fee_rate: fee_item.fee_percentage,
+            trigger_event: FeeTriggerEvent::AccountInactivity,
+            status: FeeApplicationStatus::Applied,
+            applied_at: now,
+            value_date: run_date,
+            reversal_deadline: None,
+            waived: false,
+            waived_by: None,
+            waived_reason_id: None,
+            applied_by: SYSTEM_PERSON_ID,
+            reversal_transaction_id: None,
+            audit_log_id: None,
+            created_at: now,
+        };
+        let created = self.fee_repository
+            .create_fee_application(FeeMapper::fee_application_to_model(fee_application))
+            .await?;
+        FeeMapper::fee_application_from_model(created).map(Some)
+    }
+
     // Additional implementations for remaining trait methods...
     // Placeholder implementations for compilation
 
@@ -483,6 +752,31 @@ impl FeeService for FeeServiceImpl {
         todo!("Implement fee waiver processing")
     }
 
+    async fn waive_fee(&self, fee_application_id: Uuid, reason_id: ReasonId, approved_by: Uuid) -> BankingResult<FeeApplication> {
+        let mut fee = self.find_fee_application(fee_application_id).await?;
+        if fee.status == FeeApplicationStatus::Waived {
+            return Ok(fee);
+        }
+        Self::check_not_applier(&fee, approved_by)?;
+        if fee.status != FeeApplicationStatus::Pending {
+            return Err(BankingError::ValidationError {
+                field: "status".to_string(),
+                message: format!("Fee application {fee_application_id} cannot be waived from status {:?}", fee.status),
+            });
+        }
+        ReasonValidation::require(self.reason_repository.as_ref(), reason_id, ReasonedOperation::FeeWaiver).await?;
+
+        fee.status = FeeApplicationStatus::Waived;
+        fee.waived = true;
+        fee.waived_by = Some(approved_by);
+        fee.waived_reason_id = Some(reason_id.as_uuid());
+        fee.audit_log_id = Some(self.write_audit_log(approved_by).await?);
+        let updated = self.fee_repository.update_fee_application(FeeMapper::fee_application_to_model(fee)).await?;
+
+        tracing::info!("Fee application {} waived by {}", fee_application_id, approved_by);
+        FeeMapper::fee_application_from_model(updated)
+    }
+
     async fn apply_automatic_waivers(&self, _account_id: Uuid, _fee_applications: Vec<FeeApplication>) -> BankingResult<Vec<FeeApplication>> {
         todo!("Implement automatic fee waivers")
     }
@@ -533,14 +827,56 @@ impl FeeService for FeeServiceImpl {
         todo!("Implement tiered fee calculation")
     }
 
+    async fn calculate_fee(&self, fee_item: &FeeItem, amount: Decimal, currency: &CurrencyCode) -> BankingResult<Decimal> {
+        let tiers = match fee_item.calculation_method {
+            ChannelFeeCalculationMethod::Tiered => self.channel_repository
+                .find_fee_tiers(fee_item.id)
+                .await?
+                .into_iter()
+                .map(ChannelMapper::from_fee_tier_model)
+                .collect(),
+            _ => Vec::new(),
+        };
+        fee_item.calculation(tiers)?.calculate(amount, currency)
+    }
+
+    async fn save_fee_item(&self, fee_item: FeeItem, tiers: Vec<ChannelFeeTier>) -> BankingResult<FeeItem> {
+        let tiers = match fee_item.calculation(tiers)? {
+            FeeCalculation::Tiered { tiers, .. } => tiers,
+            FeeCalculation::Flat { .. } | FeeCalculation::Percentage { .. } => Vec::new(),
+        };
+        if tiers.len() > 11 {
+            return Err(BankingError::ValidationError {
+                field: "tiers".to_string(),
+                message: format!("Fee {} has {} tiers, at most 11 are allowed", fee_item.fee_code, tiers.len()),
+            });
+        }
+
+        let tiers = tiers.into_iter().map(|tier| ChannelMapper::to_fee_tier_model(tier, fee_item.id)).collect();
+        let schedule_id = fee_item.schedule_id;
+        let saved = self.channel_repository
+            .save_fee_item(ChannelMapper::to_fee_item_model(fee_item, schedule_id), tiers)
+            .await?;
+        Ok(ChannelMapper::from_fee_item_model(saved, Vec::new()))
+    }
+
     async fn check_fee_conditions(&self, _account_id: Uuid, _product_fee: &ProductFee, _transaction_context: Option<&str>) -> BankingResult<bool> {
         // Placeholder - in production would check all fee conditions
         Ok(true)
     }
 
     // Additional placeholder implementations for remaining methods...
-    async fn get_account_fee_history(&self, _account_id: Uuid, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>, _fee_types: Option<Vec<FeeType>>) -> BankingResult<Vec<FeeApplication>> {
-        todo!("Implement account fee history")
+    async fn get_account_fee_history(&self, account_id: Uuid, from_date: Option<NaiveDate>, to_date: Option<NaiveDate>, fee_types: Option<Vec<FeeType>>) -> BankingResult<Vec<FeeApplication>> {
+        let fees = self.fee_repository
+            .get_fee_applications_for_account(account_id, from_date, to_date, None)
+            .await?
+            .into_iter()
+            .map(FeeMapper::fee_application_from_model)
+            .collect::<BankingResult<Vec<_>>>()?;
+        Ok(match fee_types {
+            Some(fee_types) => fees.into_iter().filter(|fee| fee_types.contains(&fee.fee_type)).collect(),
+            None => fees,
+        })
     }
 
     async fn get_fee_applications_by_status(&self, _status: FeeApplicationStatus, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<Vec<FeeApplication>> {
@@ -559,7 +895,743 @@ impl FeeService for FeeServiceImpl {
         todo!("Implement fee application reversal")
     }
 
+    async fn reverse_fee(&self, fee_application_id: Uuid, reason_id: ReasonId, performed_by: Uuid) -> BankingResult<FeeApplication> {
+        let mut fee = self.find_fee_application(fee_application_id).await?;
+        if fee.status == FeeApplicationStatus::Reversed {
+            return Ok(fee);
+        }
+        Self::check_not_applier(&fee, performed_by)?;
+        if fee.status != FeeApplicationStatus::Applied {
+            return Err(BankingError::ValidationError {
+                field: "status".to_string(),
+                message: format!("Fee application {fee_application_id} cannot be reversed from status {:?}", fee.status),
+            });
+        }
+        let reason = ReasonValidation::require(
+            self.reason_repository.as_ref(),
+            reason_id,
+            ReasonedOperation::TransactionReversal,
+        )
+        .await?;
+
+        let transaction_id = fee.transaction_id.ok_or_else(|| BankingError::ValidationError {
+            field: "transaction_id".to_string(),
+            message: format!("Fee application {fee_application_id} has no fee transaction to reverse"),
+        })?;
+        let original = self.transaction_service
+            .find_transaction_by_id(transaction_id)
+            .await?
+            .ok_or(BankingError::TransactionNotFound(transaction_id.to_string()))?;
+        let credit = Self::build_fee_reversal_credit(&fee, &original, reason.code.as_str())?;
+        let posted = self.transaction_service.process_transaction(credit).await?;
+
+        fee.status = FeeApplicationStatus::Reversed;
+        fee.reversal_transaction_id = Some(posted.id);
+        fee.audit_log_id = Some(self.write_audit_log(performed_by).await?);
+        let updated = self.fee_repository.update_fee_application(FeeMapper::fee_application_to_model(fee)).await?;
+
+        tracing::info!(
+            "Fee application {} reversed by {} with credit {}. Reason ID: {}",
+            fee_application_id, performed_by, posted.id, reason_id
+        );
+        FeeMapper::fee_application_from_model(updated)
+    }
+
     async fn bulk_reverse_account_fees(&self, _account_id: Uuid, _reason: String, _reversed_by: String, _fee_types: Option<Vec<FeeType>>) -> BankingResult<Vec<FeeApplication>> {
         todo!("Implement bulk fee reversal")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use chrono::DateTime;
+    use banking_api::domain::{ReasonCategory, ReasonContext, ReasonSeverity, audit::{AuditChainSegment, AuditEntityType, AuditLog, AuditLogEntry}};
+    use banking_api::service::audit::audit_log_service::AuditLogServiceResult;
+    use banking_db::models::{
+        AccountModel, AccountOwnershipModel, AccountBundleModel, BundleAccountModel, BundlePricingMarkerModel,
+        FeeApplicationModel, FeeCalculationCacheModel, FeeProcessingJobModel, FeeWaiverModel, ProductBundleModel,
+        ProductFeeScheduleModel, ProductModel, ProductType, ReasonAndPurpose as ReasonAndPurposeModel,
+        DbAccountType, DbSigningCondition,
+        channel::{
+            ChannelFeeCalculationMethod as DbChannelFeeCalculationMethod, ChannelFeeType as DbChannelFeeType, ChannelLimitModel,
+            ChannelModel, ChannelStatus, ChannelUsageModel, FeeItemModel, FeeScheduleModel, FeeTierModel,
+        },
+    };
+    use banking_db::repository::{FeeStatistic, TopFeeAccount, channel_repository::ChannelStats};
+    use banking_db::repository::reason_and_purpose_repository::{
+        BulkOperationResult, DataIntegrityReport, LocalizedReasonModel, ReasonChangeRecord, ReasonUsageStatistics,
+        ReasonValidationRules,
+    };
+    use banking_db::ChannelType;
+
+    #[derive(Default)]
+    struct MockFeeRepository {
+        applications: Mutex<HashMap<Uuid, FeeApplicationModel>>,
+    }
+
+    #[async_trait]
+    impl FeeRepository for MockFeeRepository {
+        async fn create_fee_application(&self, fee_application: FeeApplicationModel) -> BankingResult<FeeApplicationModel> {
+            self.applications.lock().unwrap().insert(fee_application.id, fee_application.clone());
+            Ok(fee_application)
+        }
+        async fn update_fee_application(&self, fee_application: FeeApplicationModel) -> BankingResult<FeeApplicationModel> {
+            self.applications.lock().unwrap().insert(fee_application.id, fee_application.clone());
+            Ok(fee_application)
+        }
+        async fn get_fee_application_by_id(&self, fee_application_id: Uuid) -> BankingResult<Option<FeeApplicationModel>> {
+            Ok(self.applications.lock().unwrap().get(&fee_application_id).cloned())
+        }
+        async fn get_fee_applications_for_account(&self, account_id: Uuid, from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>, _status_filter: Option<String>) -> BankingResult<Vec<FeeApplicationModel>> {
+            Ok(self.applications
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|fee| fee.account_id == account_id && from_date.is_none_or(|from| fee.value_date >= from))
+                .cloned()
+                .collect())
+        }
+        async fn get_fee_applications_by_status(&self, _status: String, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>, _limit: Option<i32>) -> BankingResult<Vec<FeeApplicationModel>> { unimplemented!() }
+        async fn bulk_create_fee_applications(&self, _fee_applications: Vec<FeeApplicationModel>) -> BankingResult<Vec<FeeApplicationModel>> { unimplemented!() }
+        async fn create_fee_waiver(&self, _fee_waiver: FeeWaiverModel) -> BankingResult<FeeWaiverModel> { unimplemented!() }
+        async fn update_fee_waiver_approval(&self, _waiver_id: Uuid, _approved_by: String, _approved_at: DateTime<Utc>) -> BankingResult<FeeWaiverModel> { unimplemented!() }
+        async fn get_fee_waivers_for_account(&self, _account_id: Uuid, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<Vec<FeeWaiverModel>> { unimplemented!() }
+        async fn get_pending_fee_waivers(&self, _limit: Option<i32>) -> BankingResult<Vec<FeeWaiverModel>> { unimplemented!() }
+        async fn create_fee_processing_job(&self, _job: FeeProcessingJobModel) -> BankingResult<FeeProcessingJobModel> { unimplemented!() }
+        async fn update_fee_processing_job(&self, _job: FeeProcessingJobModel) -> BankingResult<FeeProcessingJobModel> { unimplemented!() }
+        async fn get_fee_processing_job_by_id(&self, _job_id: Uuid) -> BankingResult<Option<FeeProcessingJobModel>> { unimplemented!() }
+        async fn get_fee_processing_jobs(&self, _status: Option<String>, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<Vec<FeeProcessingJobModel>> { unimplemented!() }
+        async fn get_accounts_eligible_for_fees(&self, _product_ids: Option<Vec<Uuid>>, _fee_categories: Vec<String>, _processing_date: NaiveDate, _offset: i32, _limit: i32) -> BankingResult<Vec<Uuid>> { unimplemented!() }
+        async fn cache_product_fee_schedule(&self, _schedule: ProductFeeScheduleModel) -> BankingResult<ProductFeeScheduleModel> { unimplemented!() }
+        async fn get_cached_product_fee_schedule(&self, _product_id: Uuid, _effective_date: NaiveDate) -> BankingResult<Option<ProductFeeScheduleModel>> { unimplemented!() }
+        async fn invalidate_fee_schedule_cache(&self, _product_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn cache_fee_calculation(&self, _cache_entry: FeeCalculationCacheModel) -> BankingResult<FeeCalculationCacheModel> { unimplemented!() }
+        async fn get_cached_fee_calculation(&self, _calculation_key: String) -> BankingResult<Option<FeeCalculationCacheModel>> { unimplemented!() }
+        async fn clean_expired_fee_cache(&self, _cutoff_date: DateTime<Utc>) -> BankingResult<u32> { unimplemented!() }
+        async fn get_fee_revenue_summary(&self, _from_date: NaiveDate, _to_date: NaiveDate, _product_ids: Option<Vec<Uuid>>, _fee_categories: Option<Vec<String>>) -> BankingResult<banking_db::repository::FeeRevenueSummary> { unimplemented!() }
+        async fn get_top_fee_accounts(&self, _from_date: NaiveDate, _to_date: NaiveDate, _limit: i32) -> BankingResult<Vec<TopFeeAccount>> { unimplemented!() }
+        async fn get_fee_application_statistics(&self, _from_date: NaiveDate, _to_date: NaiveDate, _group_by: String) -> BankingResult<Vec<FeeStatistic>> { unimplemented!() }
+        async fn reverse_fee_application(&self, _fee_application_id: Uuid, _reversal_reason: String, _reversed_by: String, _reversed_at: DateTime<Utc>) -> BankingResult<FeeApplicationModel> { unimplemented!() }
+        async fn bulk_reverse_account_fees(&self, _account_id: Uuid, _fee_application_ids: Vec<Uuid>, _reversal_reason: String, _reversed_by: String) -> BankingResult<Vec<FeeApplicationModel>> { unimplemented!() }
+    }
+
+    /// Serves the accounts it is given
+    #[derive(Default)]
+    struct MockAccountRepository {
+        accounts: Vec<AccountModel>,
+    }
+
+    #[async_trait]
+    impl AccountRepository for MockAccountRepository {
+        async fn create(&self, _account: AccountModel) -> BankingResult<AccountModel> { unimplemented!() }
+        async fn find_by_id(&self, account_id: Uuid) -> BankingResult<Option<AccountModel>> {
+            Ok(self.accounts.iter().find(|account| account.id == account_id).cloned())
+        }
+        async fn update_status(&self, _account_id: Uuid, _status: &str, _reason_id: Uuid, _changed_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn update_status_legacy(&self, _account_id: Uuid, _status: &str, _reason: &str, _changed_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn bulk_update_status(&self, _account_ids: &[Uuid], _status: &str, _reason_id: Uuid, _changed_by: Uuid) -> Vec<(Uuid, BankingResult<()>)> { unimplemented!() }
+        async fn create_ownership(&self, _ownership: AccountOwnershipModel) -> BankingResult<AccountOwnershipModel> { unimplemented!() }
+        async fn update(&self, _account: AccountModel) -> BankingResult<AccountModel> { unimplemented!() }
+        async fn find_by_customer_id(&self, _customer_id: Uuid) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn find_by_product_id(&self, _product_id: Uuid) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn find_by_status(&self, _status: &str) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn find_by_account_type(&self, _account_type: banking_db::models::DbAccountType) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn find_dormancy_candidates(&self, _reference_date: NaiveDate, _threshold_days: i32, _product_id: Option<Uuid>) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn find_pending_closure(&self) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn find_interest_bearing_accounts(&self) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn find_interest_bearing_accounts_after(&self, _after_account_id: Option<Uuid>, _limit: i64) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn update_balance(&self, _account_id: Uuid, _current_balance: Decimal, _available_balance: Decimal, _change_source: banking_db::models::DbBalanceChangeSource, _transaction_id: Option<Uuid>, _changed_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn get_balance_history(&self, _account_id: Uuid, _from: DateTime<Utc>, _to: DateTime<Utc>) -> BankingResult<Vec<banking_db::models::AccountBalanceChangeRecordModel>> { unimplemented!() }
+        async fn update_accrued_interest(&self, _account_id: Uuid, _accrued_interest: Decimal) -> BankingResult<()> { unimplemented!() }
+        async fn reset_accrued_interest(&self, _account_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn post_accrued_interest(&self, _account_id: Uuid, _amount: Decimal, _change_source: banking_db::models::DbBalanceChangeSource, _transaction_id: Option<Uuid>, _changed_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn apply_interest_accruals(&self, _accruals: Vec<banking_db::models::AccountInterestAccrualModel>) -> BankingResult<Vec<Uuid>> { unimplemented!() }
+        async fn find_ownership_by_account(&self, _account_id: Uuid) -> BankingResult<Vec<AccountOwnershipModel>> { unimplemented!() }
+        async fn find_accounts_by_owner(&self, _customer_id: Uuid) -> BankingResult<Vec<AccountOwnershipModel>> { unimplemented!() }
+        async fn delete_ownership(&self, _ownership_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn create_relationship(&self, _relationship: banking_db::models::AccountRelationshipModel) -> BankingResult<banking_db::models::AccountRelationshipModel> { unimplemented!() }
+        async fn find_relationships_by_account(&self, _account_id: Uuid) -> BankingResult<Vec<banking_db::models::AccountRelationshipModel>> { unimplemented!() }
+        async fn find_relationships_by_entity(&self, _entity_id: Uuid, _relationship_type: &str) -> BankingResult<Vec<banking_db::models::AccountRelationshipModel>> { unimplemented!() }
+        async fn update_relationship(&self, _relationship: banking_db::models::AccountRelationshipModel) -> BankingResult<banking_db::models::AccountRelationshipModel> { unimplemented!() }
+        async fn delete_relationship(&self, _relationship_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn create_mandate(&self, _mandate: banking_db::models::AccountMandateModel) -> BankingResult<banking_db::models::AccountMandateModel> { unimplemented!() }
+        async fn find_mandates_by_account(&self, _account_id: Uuid) -> BankingResult<Vec<banking_db::models::AccountMandateModel>> { unimplemented!() }
+        async fn find_mandates_by_grantee(&self, _grantee_id: Uuid) -> BankingResult<Vec<banking_db::models::AccountMandateModel>> { unimplemented!() }
+        async fn update_mandate_status(&self, _mandate_id: Uuid, _status: &str) -> BankingResult<()> { unimplemented!() }
+        async fn find_active_mandates(&self, _account_id: Uuid) -> BankingResult<Vec<banking_db::models::AccountMandateModel>> { unimplemented!() }
+        async fn create_final_settlement(&self, _settlement: banking_db::models::AccountFinalSettlementModel) -> BankingResult<banking_db::models::AccountFinalSettlementModel> { unimplemented!() }
+        async fn find_settlement_by_account(&self, _account_id: Uuid) -> BankingResult<Option<banking_db::models::AccountFinalSettlementModel>> { unimplemented!() }
+        async fn update_settlement_status(&self, _settlement_id: Uuid, _status: &str) -> BankingResult<()> { unimplemented!() }
+        async fn get_status_history(&self, _account_id: Uuid) -> BankingResult<Vec<banking_db::models::AccountStatusChangeRecordModel>> { unimplemented!() }
+        async fn add_status_change(&self, _status_change: banking_db::models::AccountStatusChangeRecordModel) -> BankingResult<banking_db::models::AccountStatusChangeRecordModel> { unimplemented!() }
+        async fn save_balance_snapshot(&self, _snapshot: banking_db::models::AccountBalanceSnapshotModel) -> BankingResult<banking_db::models::AccountBalanceSnapshotModel> { unimplemented!() }
+        async fn find_latest_balance_snapshot_before(&self, _account_id: Uuid, _before_date: NaiveDate) -> BankingResult<Option<banking_db::models::AccountBalanceSnapshotModel>> { unimplemented!() }
+        async fn find_balance_snapshots_by_date(&self, _snapshot_date: NaiveDate) -> BankingResult<Vec<banking_db::models::AccountBalanceSnapshotModel>> { unimplemented!() }
+        async fn save_loan_penalty_accrual(&self, _accrual: banking_db::models::LoanPenaltyAccrualModel) -> BankingResult<banking_db::models::LoanPenaltyAccrualModel> { unimplemented!() }
+        async fn find_latest_loan_penalty_accrual_before(&self, _loan_account_id: Uuid, _before_date: NaiveDate) -> BankingResult<Option<banking_db::models::LoanPenaltyAccrualModel>> { unimplemented!() }
+        async fn save_overdraft_interest_accrual(&self, _accrual: banking_db::models::casa::OverdraftInterestAccrual) -> BankingResult<banking_db::models::casa::OverdraftInterestAccrual> { unimplemented!() }
+        async fn find_latest_overdraft_interest_accrual_before(&self, _account_id: Uuid, _before_date: NaiveDate) -> BankingResult<Option<banking_db::models::casa::OverdraftInterestAccrual>> { unimplemented!() }
+        async fn exists(&self, _account_id: Uuid) -> BankingResult<bool> { unimplemented!() }
+        async fn find_by_ids(&self, _account_ids: &[Uuid]) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn exist_by_ids(&self, _account_ids: &[Uuid]) -> BankingResult<Vec<(Uuid, bool)>> { unimplemented!() }
+        async fn count_by_customer(&self, _customer_id: Uuid) -> BankingResult<i64> { unimplemented!() }
+        async fn count_by_product(&self, _product_id: Uuid) -> BankingResult<i64> { unimplemented!() }
+        async fn list(&self, _limit: i64, _offset: i64) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn find_by_account_number(&self, _account_number: &str) -> BankingResult<Option<AccountModel>> { unimplemented!() }
+        async fn list_after(&self, _after_account_id: Option<Uuid>, _limit: i64) -> BankingResult<Vec<AccountModel>> { unimplemented!() }
+        async fn count(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn update_last_activity_date(&self, _account_id: Uuid, _activity_date: NaiveDate) -> BankingResult<()> { unimplemented!() }
+    }
+
+    struct MockProductRepository;
+
+    #[async_trait]
+    impl ProductRepository for MockProductRepository {
+        async fn create_product(&self, _product: ProductModel) -> BankingResult<ProductModel> { unimplemented!() }
+        async fn find_product_by_id(&self, _product_id: Uuid) -> BankingResult<Option<ProductModel>> { unimplemented!() }
+        async fn update_product(&self, _product: ProductModel) -> BankingResult<ProductModel> { unimplemented!() }
+        async fn deactivate_product(&self, _product_id: Uuid, _updated_by_person_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn reactivate_product(&self, _product_id: Uuid, _updated_by_person_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn find_active_products(&self) -> BankingResult<Vec<ProductModel>> { unimplemented!() }
+        async fn find_products_by_type(&self, _product_type: ProductType) -> BankingResult<Vec<ProductModel>> { unimplemented!() }
+        async fn find_interest_rate_tiers_by_product_id(&self, _product_id: Uuid) -> BankingResult<Vec<banking_db::models::product::InterestRateTierModel>> { unimplemented!() }
+        async fn find_gl_mapping_by_product_id(&self, _product_id: Uuid) -> BankingResult<Option<banking_db::models::product::GlMappingModel>> { unimplemented!() }
+    }
+
+    struct MockBundleRepository;
+
+    #[async_trait]
+    impl BundleRepository for MockBundleRepository {
+        async fn create_product_bundle(&self, _bundle: ProductBundleModel) -> BankingResult<ProductBundleModel> { unimplemented!() }
+        async fn find_product_bundle_by_code(&self, _bundle_code: &str) -> BankingResult<Option<ProductBundleModel>> { unimplemented!() }
+        async fn create_account_bundle(&self, _bundle: AccountBundleModel) -> BankingResult<AccountBundleModel> { unimplemented!() }
+        async fn update_account_bundle(&self, _bundle: AccountBundleModel) -> BankingResult<AccountBundleModel> { unimplemented!() }
+        async fn find_account_bundle_by_id(&self, _account_bundle_id: Uuid) -> BankingResult<Option<AccountBundleModel>> { unimplemented!() }
+        async fn find_account_bundles_by_customer(&self, _customer_id: Uuid) -> BankingResult<Vec<AccountBundleModel>> { unimplemented!() }
+        async fn create_bundle_account(&self, _link: BundleAccountModel) -> BankingResult<BundleAccountModel> { unimplemented!() }
+        async fn find_bundle_accounts(&self, _account_bundle_id: Uuid) -> BankingResult<Vec<BundleAccountModel>> { unimplemented!() }
+        async fn find_linked_bundle_account(&self, _account_id: Uuid) -> BankingResult<Option<BundleAccountModel>> { unimplemented!() }
+        async fn unlink_bundle_account(&self, _link_id: Uuid, _unlinked_at: DateTime<Utc>) -> BankingResult<()> { unimplemented!() }
+        async fn create_pricing_marker(&self, _marker: BundlePricingMarkerModel) -> BankingResult<BundlePricingMarkerModel> { unimplemented!() }
+        async fn find_pricing_markers_by_bundle(&self, _account_bundle_id: Uuid) -> BankingResult<Vec<BundlePricingMarkerModel>> { unimplemented!() }
+        async fn find_active_markers_by_account(&self, _account_id: Uuid) -> BankingResult<Vec<BundlePricingMarkerModel>> { unimplemented!() }
+        async fn remove_pricing_markers(&self, _marker_ids: &[Uuid], _removed_at: DateTime<Utc>) -> BankingResult<()> { unimplemented!() }
+    }
+
+    /// Serves the fee items it is given
+    #[derive(Default)]
+    struct MockChannelRepository {
+        fee_items: Vec<FeeItemModel>,
+    }
+
+    #[async_trait]
+    impl ChannelRepository for MockChannelRepository {
+        async fn create(&self, _channel: ChannelModel) -> BankingResult<ChannelModel> { unimplemented!() }
+        async fn update(&self, _channel: ChannelModel) -> BankingResult<ChannelModel> { unimplemented!() }
+        async fn find_by_id(&self, _channel_id: Uuid) -> BankingResult<Option<ChannelModel>> { unimplemented!() }
+        async fn find_by_code(&self, _channel_code: &str) -> BankingResult<Option<ChannelModel>> { unimplemented!() }
+        async fn find_by_type(&self, _channel_type: ChannelType) -> BankingResult<Vec<ChannelModel>> { unimplemented!() }
+        async fn find_active(&self) -> BankingResult<Vec<ChannelModel>> { unimplemented!() }
+        async fn update_status(&self, _channel_id: Uuid, _status: ChannelStatus) -> BankingResult<()> { unimplemented!() }
+        async fn exists(&self, _channel_id: Uuid) -> BankingResult<bool> { unimplemented!() }
+        async fn find_by_currency(&self, _currency: &str) -> BankingResult<Vec<ChannelModel>> { unimplemented!() }
+        async fn get_channel_stats(&self, _channel_id: Uuid) -> BankingResult<ChannelStats> { unimplemented!() }
+        async fn soft_delete(&self, _channel_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn find_all_paginated(&self, _limit: i64, _offset: i64) -> BankingResult<Vec<ChannelModel>> { unimplemented!() }
+        async fn count_all(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn upsert_limit(&self, _limit: ChannelLimitModel) -> BankingResult<ChannelLimitModel> { unimplemented!() }
+        async fn find_effective_limit(&self, _channel_id: &str, _currency: &str) -> BankingResult<Option<ChannelLimitModel>> { unimplemented!() }
+        async fn get_owner_channel_usage(&self, _account_id: Uuid, _channel_id: &str, _currency: &str, _month_start: DateTime<Utc>, _day_start: DateTime<Utc>, _day_end: DateTime<Utc>) -> BankingResult<ChannelUsageModel> { unimplemented!() }
+        async fn save_fee_schedule(&self, _schedule: FeeScheduleModel) -> BankingResult<FeeScheduleModel> { unimplemented!() }
+        async fn find_fee_schedule_by_id(&self, _schedule_id: Uuid) -> BankingResult<Option<FeeScheduleModel>> { unimplemented!() }
+        async fn save_fee_item(&self, _item: FeeItemModel, _tiers: Vec<FeeTierModel>) -> BankingResult<FeeItemModel> { unimplemented!() }
+        async fn find_fee_item_by_id(&self, fee_item_id: Uuid) -> BankingResult<Option<FeeItemModel>> {
+            Ok(self.fee_items.iter().find(|item| item.id == fee_item_id).cloned())
+        }
+        async fn find_fee_items_by_schedule(&self, _schedule_id: Uuid) -> BankingResult<Vec<FeeItemModel>> { unimplemented!() }
+        async fn find_fee_tiers(&self, _fee_item_id: Uuid) -> BankingResult<Vec<FeeTierModel>> { unimplemented!() }
+    }
+
+    /// Knows the reasons it is given, looked up by id
+    struct MockReasonRepository {
+        reasons: Vec<ReasonAndPurposeModel>,
+    }
+
+    #[async_trait]
+    impl ReasonAndPurposeRepository for MockReasonRepository {
+        async fn create(&self, _reason: ReasonAndPurposeModel) -> BankingResult<ReasonAndPurposeModel> { unimplemented!() }
+        async fn find_by_id(&self, reason_id: Uuid) -> BankingResult<Option<ReasonAndPurposeModel>> {
+            Ok(self.reasons.iter().find(|reason| reason.id == reason_id).cloned())
+        }
+        async fn find_by_code(&self, _code: &str) -> BankingResult<Option<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn update(&self, _reason: ReasonAndPurposeModel) -> BankingResult<ReasonAndPurposeModel> { unimplemented!() }
+        async fn delete(&self, _reason_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn deactivate(&self, _reason_id: Uuid, _deactivated_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn reactivate(&self, _reason_id: Uuid, _reactivated_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn find_all_active(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_by_category(&self, _category: ReasonCategory) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_by_context(&self, _context: ReasonContext) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_by_category_and_context(&self, _category: ReasonCategory, _context: ReasonContext) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_by_severity(&self, _severity: ReasonSeverity) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn search_by_content(&self, _search_term: &str, _language_codes: Option<Vec<[u8; 3]>>) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_for_display(&self, _category: Option<ReasonCategory>, _context: Option<ReasonContext>, _active_only: bool) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_reportable_compliance_reasons(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_sar_triggering_reasons(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_ctr_triggering_reasons(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_aml_ctf_reasons(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_kyc_reasons(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_by_jurisdiction(&self, _jurisdiction_code: [u8; 2]) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn find_escalation_required_reasons(&self) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn get_usage_count(&self, _reason_id: Uuid, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<u64> { unimplemented!() }
+        async fn get_usage_statistics(&self, _reason_id: Uuid, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<ReasonUsageStatistics> { unimplemented!() }
+        async fn get_top_used_reasons_by_category(&self, _category: ReasonCategory, _limit: i32, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<Vec<ReasonUsageStatistics>> { unimplemented!() }
+        async fn find_unused_reasons(&self, _since_date: NaiveDate) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn record_usage(&self, _reason_id: Uuid, _context: ReasonContext, _used_by: &str, _additional_context: Option<&str>) -> BankingResult<()> { unimplemented!() }
+        async fn get_change_history(&self, _reason_id: Uuid) -> BankingResult<Vec<ReasonChangeRecord>> { unimplemented!() }
+        async fn record_change(&self, _change_record: ReasonChangeRecord) -> BankingResult<ReasonChangeRecord> { unimplemented!() }
+        async fn code_exists(&self, _code: &str, _exclude_id: Option<Uuid>) -> BankingResult<bool> { unimplemented!() }
+        async fn is_active(&self, _reason_id: Uuid) -> BankingResult<bool> { unimplemented!() }
+        async fn is_valid_for_context(&self, _reason_id: Uuid, _context: ReasonContext) -> BankingResult<bool> { unimplemented!() }
+        async fn get_validation_rules(&self, _reason_id: Uuid) -> BankingResult<Option<ReasonValidationRules>> { unimplemented!() }
+        async fn bulk_insert(&self, _reasons: Vec<ReasonAndPurposeModel>) -> BankingResult<BulkOperationResult> { unimplemented!() }
+        async fn bulk_update_display_orders(&self, _category: ReasonCategory, _order_updates: Vec<(Uuid, i32)>, _updated_by_person_id: &str) -> BankingResult<()> { unimplemented!() }
+        async fn bulk_update_status(&self, _reason_ids: Vec<Uuid>, _is_active: bool, _updated_by_person_id: &str) -> BankingResult<BulkOperationResult> { unimplemented!() }
+        async fn update_localized_content(&self, _reason_id: Uuid, _language_code: [u8; 3], _content: &str, _updated_by_person_id: &str) -> BankingResult<()> { unimplemented!() }
+        async fn remove_localized_content(&self, _reason_id: Uuid, _language_code: [u8; 3], _updated_by_person_id: &str) -> BankingResult<()> { unimplemented!() }
+        async fn find_with_languages(&self, _language_codes: &[[u8; 3]], _category: Option<ReasonCategory>, _context: Option<ReasonContext>) -> BankingResult<Vec<LocalizedReasonModel>> { unimplemented!() }
+        async fn find_missing_localization(&self, _language_code: [u8; 3], _category: Option<ReasonCategory>) -> BankingResult<Vec<ReasonAndPurposeModel>> { unimplemented!() }
+        async fn count_total(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn count_by_category(&self, _category: ReasonCategory) -> BankingResult<i64> { unimplemented!() }
+        async fn count_by_context(&self, _context: ReasonContext) -> BankingResult<i64> { unimplemented!() }
+        async fn validate_data_integrity(&self) -> BankingResult<DataIntegrityReport> { unimplemented!() }
+        async fn get_categories_in_use(&self) -> BankingResult<Vec<ReasonCategory>> { unimplemented!() }
+        async fn get_contexts_in_use(&self) -> BankingResult<Vec<ReasonContext>> { unimplemented!() }
+    }
+
+    /// Serves the original fee transaction and records the postings
+    struct MockTransactionService {
+        original: Transaction,
+        posted: Mutex<Vec<Transaction>>,
+    }
+
+    #[async_trait]
+    impl TransactionService for MockTransactionService {
+        async fn process_transaction(&self, transaction: Transaction) -> BankingResult<Transaction> {
+            self.posted.lock().unwrap().push(transaction.clone());
+            Ok(transaction)
+        }
+        async fn post_back_dated_transaction(&self, _transaction: Transaction, _actor: &banking_api::domain::PostingActor, _business_date: NaiveDate) -> BankingResult<banking_api::domain::BackDatedPosting> { unimplemented!() }
+        async fn find_back_dated_postings(&self, _from: NaiveDate, _to: NaiveDate) -> BankingResult<Vec<banking_api::domain::BackDatedPosting>> { unimplemented!() }
+        async fn backfill_degraded_transactions(&self, _limit: i64) -> BankingResult<banking_api::service::DegradedBackfillReport> { unimplemented!() }
+        async fn get_circuit_breaker_metrics(&self) -> BankingResult<Vec<banking_api::domain::CircuitBreakerMetrics>> { unimplemented!() }
+        async fn validate_transaction_limits(&self, _transaction: &Transaction) -> BankingResult<banking_api::domain::TransactionValidationResult> { unimplemented!() }
+        async fn reverse_transaction(&self, _transaction_id: Uuid, _reason_id: ReasonId, _requested_by: Uuid) -> BankingResult<Transaction> { unimplemented!() }
+        async fn reverse_transaction_legacy(&self, _transaction_id: Uuid, _reason: String) -> BankingResult<()> { unimplemented!() }
+        async fn find_transactions_by_account(&self, _account_id: Uuid, _from: NaiveDate, _to: NaiveDate) -> BankingResult<Vec<Transaction>> { unimplemented!() }
+        async fn search_transactions(&self, _criteria: banking_api::domain::TransactionSearchCriteria) -> BankingResult<Vec<Transaction>> { unimplemented!() }
+        async fn initiate_approval_workflow(&self, _transaction: Transaction) -> BankingResult<banking_api::domain::TransactionApprovalWorkflow> { unimplemented!() }
+        async fn approve_transaction(&self, _transaction_id: Uuid, _approver_person_id: Uuid) -> BankingResult<Transaction> { unimplemented!() }
+        async fn reject_transaction(&self, _transaction_id: Uuid, _approver_person_id: Uuid, _reason_id: ReasonId) -> BankingResult<Transaction> { unimplemented!() }
+        async fn validate_account_transactional_status(&self, _account_id: Uuid, _transaction_type: TransactionType) -> BankingResult<banking_api::domain::TransactionValidationResult> { unimplemented!() }
+        async fn get_permitted_operations(&self, _account_id: Uuid) -> BankingResult<Vec<banking_api::domain::PermittedOperation>> { unimplemented!() }
+        async fn process_closure_transaction(&self, _account_id: Uuid, _settlement: banking_api::domain::FinalSettlement) -> BankingResult<Transaction> { unimplemented!() }
+        async fn reverse_pending_transactions(&self, _account_id: Uuid, _reason_id: ReasonId, _additional_details: Option<&str>) -> BankingResult<Vec<Transaction>> { unimplemented!() }
+        async fn reverse_pending_transactions_legacy(&self, _account_id: Uuid, _reason: String) -> BankingResult<Vec<Transaction>> { unimplemented!() }
+        async fn process_transaction_request(&self, _request: banking_api::domain::TransactionRequest) -> BankingResult<banking_api::domain::TransactionResult> { unimplemented!() }
+        async fn find_transaction_by_id(&self, transaction_id: Uuid) -> BankingResult<Option<Transaction>> {
+            Ok(Some(self.original.clone()).filter(|t| t.id == transaction_id))
+        }
+        async fn find_transaction_by_reference(&self, _reference_number: &str) -> BankingResult<Option<Transaction>> { unimplemented!() }
+        async fn get_transaction_audit_trail(&self, _transaction_id: Uuid) -> BankingResult<Vec<banking_api::service::TransactionAuditEntry>> { unimplemented!() }
+        async fn update_transaction_status(&self, _transaction_id: Uuid, _status: TransactionStatus, _reason: String) -> BankingResult<()> { unimplemented!() }
+    }
+
+    /// Remembers who each audit log entry was written for
+    #[derive(Default)]
+    struct MockAuditLogService {
+        entries: Mutex<Vec<Uuid>>,
+    }
+
+    #[async_trait]
+    impl AuditLogService for MockAuditLogService {
+        async fn create_audit_log(&self, updated_by_person_id: Uuid) -> AuditLogServiceResult<AuditLog> {
+            self.entries.lock().unwrap().push(updated_by_person_id);
+            Ok(AuditLog { id: Uuid::new_v4(), updated_at: Utc::now(), updated_by_person_id })
+        }
+        async fn find_audit_log_by_id(&self, _id: Uuid) -> AuditLogServiceResult<Option<AuditLog>> { unimplemented!() }
+        async fn find_entity_history(
+            &self,
+            _entity_type: AuditEntityType,
+            _entity_id: Uuid,
+            _from: DateTime<Utc>,
+            _to: DateTime<Utc>,
+            _page: i32,
+            _page_size: i32,
+        ) -> AuditLogServiceResult<Vec<AuditLogEntry>> { unimplemented!() }
+        async fn find_actor_history(
+            &self,
+            _person_id: Uuid,
+            _from: DateTime<Utc>,
+            _to: DateTime<Utc>,
+            _page: i32,
+            _page_size: i32,
+        ) -> AuditLogServiceResult<Vec<AuditLogEntry>> { unimplemented!() }
+        async fn export_verified_segment(&self, _from_id: Uuid, _to_id: Uuid) -> AuditLogServiceResult<AuditChainSegment> { unimplemented!() }
+    }
+
+    fn reason(category: ReasonCategory, context: ReasonContext) -> ReasonAndPurposeModel {
+        ReasonAndPurposeModel {
+            id: Uuid::new_v4(),
+            code: HeaplessString::try_from("FEE_CORRECTION").unwrap(),
+            category,
+            context,
+            l1_content: None,
+            l2_content: None,
+            l3_content: None,
+            l1_language_code: None,
+            l2_language_code: None,
+            l3_language_code: None,
+            requires_details: false,
+            is_active: true,
+            severity: None,
+            display_order: 0,
+            compliance_metadata: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            created_by_person_id: Uuid::new_v4(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    fn fee_transaction(account_id: Uuid) -> Transaction {
+        Transaction {
+            id: Uuid::new_v4(),
+            account_id,
+            transaction_code: HeaplessString::try_from("FEE").unwrap(),
+            transaction_type: TransactionType::Debit,
+            amount: Decimal::new(250, 2),
+            currency: CurrencyCode::try_from("XAF").unwrap(),
+            description: HeaplessString::try_from("ATM withdrawal fee").unwrap(),
+            channel_id: HeaplessString::try_from("SYSTEM").unwrap(),
+            terminal_id: None,
+            agent_person_id: None,
+            transaction_date: Utc::now(),
+            value_date: Utc::now().date_naive(),
+            status: TransactionStatus::Posted,
+            reference_number: HeaplessString::try_from("TXN-FEE-0001").unwrap(),
+            external_reference: None,
+            gl_code: HeaplessString::try_from("4100").unwrap(),
+            requires_approval: false,
+            approval_status: None,
+            risk_score: None,
+            degraded_flags: DegradedFlags::NONE,
+            idempotency_key: None,
+            reversal_of_transaction_id: None,
+            fx_conversion: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn fee_application(transaction: &Transaction, status: FeeApplicationStatus, applied_by: Uuid) -> FeeApplication {
+        FeeApplication {
+            id: Uuid::new_v4(),
+            account_id: transaction.account_id,
+            transaction_id: Some(transaction.id),
+            fee_type: FeeType::EventBased,
+            fee_category: FeeCategory::Transaction,
+            product_id: Uuid::new_v4(),
+            fee_code: HeaplessString::try_from("ATM_WD").unwrap(),
+            description: HeaplessString::try_from("ATM withdrawal fee").unwrap(),
+            amount: transaction.amount,
+            currency: HeaplessString::try_from("XAF").unwrap(),
+            calculation_method: FeeCalculationMethod::Fixed,
+            calculation_base_amount: None,
+            fee_rate: None,
+            trigger_event: FeeTriggerEvent::AtmWithdrawal,
+            status,
+            applied_at: Utc::now(),
+            value_date: transaction.value_date,
+            reversal_deadline: None,
+            waived: false,
+            waived_by: None,
+            waived_reason_id: None,
+            applied_by,
+            reversal_transaction_id: None,
+            audit_log_id: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    struct Fixture {
+        service: FeeServiceImpl,
+        fee_repository: Arc<MockFeeRepository>,
+        transaction_service: Arc<MockTransactionService>,
+        audit_log_service: Arc<MockAuditLogService>,
+        original: Transaction,
+        waiver_reason: ReasonId,
+        reversal_reason: ReasonId,
+    }
+
+    fn fixture() -> Fixture {
+        fixture_with(MockAccountRepository::default(), MockChannelRepository::default())
+    }
+
+    fn fixture_with(account_repository: MockAccountRepository, channel_repository: MockChannelRepository) -> Fixture {
+        let original = fee_transaction(Uuid::new_v4());
+        let waiver_reason = reason(ReasonCategory::ServiceRequest, ReasonContext::Account);
+        let reversal_reason = reason(ReasonCategory::TransactionReversal, ReasonContext::Transaction);
+        let fee_repository = Arc::new(MockFeeRepository::default());
+        let transaction_service = Arc::new(MockTransactionService { original: original.clone(), posted: Mutex::new(Vec::new()) });
+        let audit_log_service = Arc::new(MockAuditLogService::default());
+        let service = FeeServiceImpl::new(
+            fee_repository.clone(),
+            Arc::new(account_repository),
+            Arc::new(MockProductRepository),
+            Arc::new(MockBundleRepository),
+            Arc::new(channel_repository),
+            Arc::new(MockReasonRepository { reasons: vec![waiver_reason.clone(), reversal_reason.clone()] }),
+            transaction_service.clone(),
+            audit_log_service.clone(),
+            Arc::new(BankingConfig::default()),
+        );
+        Fixture {
+            service,
+            fee_repository,
+            transaction_service,
+            audit_log_service,
+            original,
+            waiver_reason: ReasonId(waiver_reason.id),
+            reversal_reason: ReasonId(reversal_reason.id),
+        }
+    }
+
+    async fn store(fixture: &Fixture, fee: &FeeApplication) {
+        fixture.fee_repository
+            .create_fee_application(FeeMapper::fee_application_to_model(fee.clone()))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_reversing_a_fee_twice_returns_the_first_reversal() {
+        let fixture = fixture();
+        let teller = Uuid::new_v4();
+        let supervisor = Uuid::new_v4();
+        let fee = fee_application(&fixture.original, FeeApplicationStatus::Applied, teller);
+        store(&fixture, &fee).await;
+
+        let reversed = fixture.service.reverse_fee(fee.id, fixture.reversal_reason, supervisor).await.unwrap();
+        assert_eq!(reversed.status, FeeApplicationStatus::Reversed);
+        let credit = fixture.transaction_service.posted.lock().unwrap()[0].clone();
+        assert_eq!(reversed.reversal_transaction_id, Some(credit.id));
+        assert_eq!(credit.transaction_type, TransactionType::Credit);
+        assert_eq!(credit.account_id, fee.account_id);
+        assert_eq!(credit.amount, fee.amount);
+        assert_eq!(credit.external_reference, Some(fixture.original.reference_number.clone()));
+        assert_eq!(*fixture.audit_log_service.entries.lock().unwrap(), vec![supervisor]);
+
+        // The second call neither posts nor audits again
+        let again = fixture.service.reverse_fee(fee.id, fixture.reversal_reason, supervisor).await.unwrap();
+        assert_eq!(again.reversal_transaction_id, Some(credit.id));
+        assert_eq!(again.audit_log_id, reversed.audit_log_id);
+        assert_eq!(fixture.transaction_service.posted.lock().unwrap().len(), 1);
+        assert_eq!(fixture.audit_log_service.entries.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_whoever_applied_a_fee_cannot_waive_or_reverse_it() {
+        let fixture = fixture();
+        let teller = Uuid::new_v4();
+        let applied = fee_application(&fixture.original, FeeApplicationStatus::Applied, teller);
+        let pending = fee_application(&fixture.original, FeeApplicationStatus::Pending, teller);
+        store(&fixture, &applied).await;
+        store(&fixture, &pending).await;
+
+        let result = fixture.service.reverse_fee(applied.id, fixture.reversal_reason, teller).await;
+        assert!(matches!(
+            result,
+            Err(BankingError::FeeSelfApproval { fee_application_id, person_id })
+                if fee_application_id == applied.id && person_id == teller
+        ));
+        let result = fixture.service.waive_fee(pending.id, fixture.waiver_reason, teller).await;
+        assert!(matches!(result, Err(BankingError::FeeSelfApproval { .. })));
+        assert!(fixture.transaction_service.posted.lock().unwrap().is_empty());
+        assert!(fixture.audit_log_service.entries.lock().unwrap().is_empty());
+
+        // Someone else can waive the pending fee
+        let supervisor = Uuid::new_v4();
+        let waived = fixture.service.waive_fee(pending.id, fixture.waiver_reason, supervisor).await.unwrap();
+        assert_eq!(waived.status, FeeApplicationStatus::Waived);
+        assert_eq!(waived.waived_by, Some(supervisor));
+        assert_eq!(waived.waived_reason_id, Some(fixture.waiver_reason.as_uuid()));
+        assert!(waived.audit_log_id.is_some());
+    }
+
+    fn dormant_account(balance: Decimal) -> AccountModel {
+        AccountModel {
+            id: Uuid::new_v4(),
+            product_id: Uuid::new_v4(),
+            account_type: DbAccountType::Current,
+            account_status: DbAccountStatus::Dormant,
+            signing_condition: DbSigningCondition::None,
+            currency: HeaplessString::try_from("XAF").unwrap(),
+            open_date: NaiveDate::from_ymd_opt(2022, 3, 1).unwrap(),
+            domicile_agency_branch_id: Uuid::new_v4(),
+            account_number: HeaplessString::try_from("10005000010000000004262").unwrap(),
+            gl_code_suffix: None,
+            current_balance: balance,
+            available_balance: balance,
+            accrued_interest: Decimal::ZERO,
+            overdraft_limit: None,
+            original_principal: None,
+            outstanding_principal: None,
+            loan_interest_rate: None,
+            loan_term_months: None,
+            disbursement_date: None,
+            maturity_date: None,
+            installment_amount: None,
+            next_due_date: None,
+            penalty_rate: None,
+            collateral_id: None,
+            loan_purpose_id: None,
+            close_date: None,
+            last_activity_date: NaiveDate::from_ymd_opt(2023, 5, 10),
+            dormancy_threshold_days: None,
+            reactivation_required: true,
+            pending_closure_reason_id: None,
+            last_disbursement_instruction_id: None,
+            status_changed_by_person_id: None,
+            status_change_reason_id: None,
+            status_change_timestamp: None,
+            most_significant_account_hold_id: None,
+            account_ownership_id: None,
+            access01_account_relationship_id: None,
+            access02_account_relationship_id: None,
+            access03_account_relationship_id: None,
+            access04_account_relationship_id: None,
+            access05_account_relationship_id: None,
+            access06_account_relationship_id: None,
+            access07_account_relationship_id: None,
+            access11_account_mandate_id: None,
+            access12_account_mandate_id: None,
+            access13_account_mandate_id: None,
+            access14_account_mandate_id: None,
+            access15_account_mandate_id: None,
+            access16_account_mandate_id: None,
+            access17_account_mandate_id: None,
+            interest01_ultimate_beneficiary_id: None,
+            interest02_ultimate_beneficiary_id: None,
+            interest03_ultimate_beneficiary_id: None,
+            interest04_ultimate_beneficiary_id: None,
+            interest05_ultimate_beneficiary_id: None,
+            interest06_ultimate_beneficiary_id: None,
+            interest07_ultimate_beneficiary_id: None,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    fn inactivity_fee_item(fee_amount: Decimal) -> FeeItemModel {
+        FeeItemModel {
+            id: Uuid::new_v4(),
+            schedule_id: Uuid::new_v4(),
+            fee_code: HeaplessString::try_from("INACTIVITY").unwrap(),
+            fee_name: HeaplessString::try_from("Dormant account fee").unwrap(),
+            fee_type: DbChannelFeeType::MaintenanceFee,
+            calculation_method: DbChannelFeeCalculationMethod::Fixed,
+            fee_amount: Some(fee_amount),
+            fee_percentage: None,
+            minimum_fee: None,
+            maximum_fee: None,
+            tier01_channel_fee_tier_id: None,
+            tier02_channel_fee_tier_id: None,
+            tier03_channel_fee_tier_id: None,
+            tier04_channel_fee_tier_id: None,
+            tier05_channel_fee_tier_id: None,
+            tier06_channel_fee_tier_id: None,
+            tier07_channel_fee_tier_id: None,
+            tier08_channel_fee_tier_id: None,
+            tier09_channel_fee_tier_id: None,
+            tier10_channel_fee_tier_id: None,
+            tier11_channel_fee_tier_id: None,
+            applies_to_transaction_type_01: None,
+            applies_to_transaction_type_02: None,
+            applies_to_transaction_type_03: None,
+            applies_to_transaction_type_04: None,
+            applies_to_transaction_type_05: None,
+            applies_to_transaction_type_06: None,
+            applies_to_transaction_type_07: None,
+            applies_to_transaction_type_08: None,
+            applies_to_transaction_type_09: None,
+            applies_to_transaction_type_10: None,
+            applies_to_transaction_type_11: None,
+            is_waivable: true,
+            requires_approval_for_waiver: false,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn inactivity_fixture(account: &AccountModel, fee_item: &FeeItemModel) -> Fixture {
+        fixture_with(
+            MockAccountRepository { accounts: vec![account.clone()] },
+            MockChannelRepository { fee_items: vec![fee_item.clone()] },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_inactivity_fee_larger_than_the_balance_charges_the_remainder_only() {
+        let account = dormant_account(Decimal::from(1_200));
+        let fee_item = inactivity_fee_item(Decimal::from(2_500));
+        let fixture = inactivity_fixture(&account, &fee_item);
+        let cycle_start = NaiveDate::from_ymd_opt(2024, 5, 10).unwrap();
+
+        let fee = fixture.service
+            .apply_inactivity_fee(account.id, fee_item.id, cycle_start, cycle_start)
+            .await
+            .unwrap()
+            .expect("the balance covers part of the fee");
+        assert_eq!(fee.amount, Decimal::from(1_200));
+        assert_eq!(fee.trigger_event, FeeTriggerEvent::AccountInactivity);
+        assert_eq!(fee.fee_code.as_str(), "INACTIVITY");
+        let debit = fixture.transaction_service.posted.lock().unwrap()[0].clone();
+        assert_eq!(fee.transaction_id, Some(debit.id));
+        assert_eq!(debit.transaction_type, TransactionType::Debit);
+        assert_eq!(debit.amount, Decimal::from(1_200));
+
+        // An empty account is charged nothing at all
+        let empty = dormant_account(Decimal::ZERO);
+        let fixture = inactivity_fixture(&empty, &fee_item);
+        let fee = fixture.service.apply_inactivity_fee(empty.id, fee_item.id, cycle_start, cycle_start).await.unwrap();
+        assert!(fee.is_none());
+        assert!(fixture.transaction_service.posted.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_inactivity_fee_is_charged_once_per_cycle() {
+        let account = dormant_account(Decimal::from(10_000));
+        let fee_item = inactivity_fee_item(Decimal::from(2_500));
+        let fixture = inactivity_fixture(&account, &fee_item);
+        let cycle_start = NaiveDate::from_ymd_opt(2024, 5, 10).unwrap();
+
+        let first = fixture.service.apply_inactivity_fee(account.id, fee_item.id, cycle_start, cycle_start).await.unwrap();
+        assert_eq!(first.map(|fee| fee.amount), Some(Decimal::from(2_500)));
+
+        // Rerunning the day, or running on a later day of the same cycle, charges nothing more
+        let rerun = fixture.service.apply_inactivity_fee(account.id, fee_item.id, cycle_start, cycle_start).await.unwrap();
+        assert!(rerun.is_none());
+        let later_day = cycle_start + chrono::Duration::days(3);
+        let later = fixture.service.apply_inactivity_fee(account.id, fee_item.id, cycle_start, later_day).await.unwrap();
+        assert!(later.is_none());
+        assert_eq!(fixture.transaction_service.posted.lock().unwrap().len(), 1);
+
+        // The next cycle is charged again
+        let next_cycle = NaiveDate::from_ymd_opt(2024, 6, 10).unwrap();
+        let next = fixture.service.apply_inactivity_fee(account.id, fee_item.id, next_cycle, next_cycle).await.unwrap();
+        assert!(next.is_some());
+        assert_eq!(fixture.transaction_service.posted.lock().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,252 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use heapless::String as HeaplessString;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{CurrencyPosition, FinancialPositionView, GuaranteedLoanExposure},
+    service::FinancialPositionService,
+};
+use banking_db::repository::{CustomerRepository, FinancialPositionRepository, GuarantorRepository};
+use crate::mappers::{ExchangeRateMapper, FinancialPositionMapper};
+
+/// Production implementation of FinancialPositionService
+pub struct FinancialPositionServiceImpl {
+    financial_position_repository: Arc<dyn FinancialPositionRepository>,
+    guarantor_repository: Arc<dyn GuarantorRepository>,
+    customer_repository: Arc<dyn CustomerRepository>,
+}
+
+impl FinancialPositionServiceImpl {
+    pub fn new(
+        financial_position_repository: Arc<dyn FinancialPositionRepository>,
+        guarantor_repository: Arc<dyn GuarantorRepository>,
+        customer_repository: Arc<dyn CustomerRepository>,
+    ) -> Self {
+        Self {
+            financial_position_repository,
+            guarantor_repository,
+            customer_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl FinancialPositionService for FinancialPositionServiceImpl {
+    async fn get_customer_financial_position(&self, customer_id: Uuid, base_currency: &str) -> BankingResult<FinancialPositionView> {
+        let base_currency: HeaplessString<3> = HeaplessString::try_from(base_currency)
+            .ok()
+            .filter(|c| c.len() == 3)
+            .ok_or_else(|| BankingError::ValidationError {
+                field: "base_currency".to_string(),
+                message: "Currency must be a 3-character ISO code".to_string(),
+            })?;
+        if !self.customer_repository.exists(customer_id).await? {
+            return Err(BankingError::CustomerNotFound(customer_id));
+        }
+
+        let mut currencies: Vec<CurrencyPosition> = self.financial_position_repository
+            .find_currency_positions(customer_id)
+            .await?
+            .into_iter()
+            .map(FinancialPositionMapper::position_from_model)
+            .collect();
+
+        // Guarantee exposure is capped per loan like the guarantor exposure view
+        let mut has_unlimited_guarantee = false;
+        for loan in self.guarantor_repository.find_guaranteed_loans(customer_id).await? {
+            has_unlimited_guarantee |= loan.guarantee_amount.is_none();
+            let exposure = GuaranteedLoanExposure::new(loan.loan_account_id, loan.outstanding_principal, loan.guarantee_amount);
+            match currencies.iter_mut().find(|p| p.currency == loan.currency) {
+                Some(position) => position.guarantees_given += exposure.exposure,
+                None => currencies.push(CurrencyPosition {
+                    guarantees_given: exposure.exposure,
+                    ..CurrencyPosition::empty(loan.currency)
+                }),
+            }
+        }
+        currencies.sort_by(|a, b| a.currency.cmp(&b.currency));
+
+        let as_of = Utc::now().date_naive();
+        let rates = self.financial_position_repository
+            .find_exchange_rates(&base_currency, as_of)
+            .await?
+            .into_iter()
+            .map(ExchangeRateMapper::from_model)
+            .collect();
+
+        let view = FinancialPositionView::build(customer_id, base_currency, as_of, currencies, rates, has_unlimited_guarantee, Utc::now());
+        if !view.is_fully_converted() {
+            tracing::warn!(
+                "Financial position of customer {}: no {} rate for {:?}",
+                customer_id, view.base_currency, view.unconverted_currencies
+            );
+        }
+        Ok(view)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+    use banking_db::models::{
+        CurrencyPositionModel, ExchangeRateModel, GuaranteedLoanModel, LoanGuarantorModel,
+    };
+
+    fn currency(code: &str) -> HeaplessString<3> {
+        HeaplessString::try_from(code).unwrap()
+    }
+
+    fn position(code: &str, total_deposits: i64, loan_exposure: i64, holds: i64, legal_holds: i64) -> CurrencyPositionModel {
+        CurrencyPositionModel {
+            currency: currency(code),
+            account_count: 1,
+            total_deposits: Decimal::from(total_deposits),
+            loan_exposure: Decimal::from(loan_exposure),
+            holds: Decimal::from(holds),
+            legal_holds: Decimal::from(legal_holds),
+            overdraft_used: Decimal::ZERO,
+            overdraft_limit: Decimal::ZERO,
+        }
+    }
+
+    struct MockFinancialPositionRepository;
+
+    #[async_trait]
+    impl FinancialPositionRepository for MockFinancialPositionRepository {
+        async fn find_currency_positions(&self, _customer_id: Uuid) -> BankingResult<Vec<CurrencyPositionModel>> {
+            Ok(vec![
+                position("XAF", 1_000_000, 0, 0, 0),
+                CurrencyPositionModel {
+                    overdraft_used: Decimal::from(100),
+                    overdraft_limit: Decimal::from(400),
+                    ..position("EUR", 2_000, 5_000, 300, 250)
+                },
+                position("GHS", 10_000, 0, 0, 0),
+            ])
+        }
+        async fn find_exchange_rates(&self, to_currency: &str, as_of: NaiveDate) -> BankingResult<Vec<ExchangeRateModel>> {
+            assert_eq!(to_currency, "EUR");
+            Ok(vec![
+                ExchangeRateModel {
+                    from_currency: currency("XAF"),
+                    to_currency: currency("EUR"),
+                    rate: Decimal::new(15, 4),
+                    effective_at: as_of.and_hms_opt(16, 0, 0).unwrap().and_utc(),
+                    source: HeaplessString::try_from("ECB").unwrap(),
+                },
+                ExchangeRateModel {
+                    from_currency: currency("USD"),
+                    to_currency: currency("EUR"),
+                    rate: Decimal::new(92, 2),
+                    effective_at: as_of.and_hms_opt(16, 0, 0).unwrap().and_utc(),
+                    source: HeaplessString::try_from("ECB").unwrap(),
+                },
+            ])
+        }
+    }
+
+    struct MockGuarantorRepository;
+
+    #[async_trait]
+    impl GuarantorRepository for MockGuarantorRepository {
+        async fn find_guaranteed_loans(&self, _guarantor_id: Uuid) -> BankingResult<Vec<GuaranteedLoanModel>> {
+            Ok(vec![
+                // Capped below the outstanding principal
+                GuaranteedLoanModel {
+                    loan_account_id: Uuid::new_v4(),
+                    currency: currency("EUR"),
+                    outstanding_principal: Decimal::from(8_000),
+                    guarantee_amount: Some(Decimal::from(3_000)),
+                },
+                // A currency the customer holds no account in
+                GuaranteedLoanModel {
+                    loan_account_id: Uuid::new_v4(),
+                    currency: currency("USD"),
+                    outstanding_principal: Decimal::from(1_000),
+                    guarantee_amount: None,
+                },
+            ])
+        }
+        async fn create_guarantor(&self, _guarantor: LoanGuarantorModel) -> BankingResult<LoanGuarantorModel> { unimplemented!() }
+        async fn update_guarantor(&self, _guarantor: LoanGuarantorModel) -> BankingResult<LoanGuarantorModel> { unimplemented!() }
+        async fn find_guarantor_by_id(&self, _guarantor_link_id: Uuid) -> BankingResult<Option<LoanGuarantorModel>> { unimplemented!() }
+        async fn find_guarantors_by_loan(&self, _loan_account_id: Uuid) -> BankingResult<Vec<LoanGuarantorModel>> { unimplemented!() }
+        async fn count_active_guarantors(&self, _loan_account_id: Uuid) -> BankingResult<i64> { unimplemented!() }
+    }
+
+    struct MockCustomerRepository;
+
+    #[async_trait]
+    impl CustomerRepository for MockCustomerRepository {
+        async fn exists(&self, _customer_id: Uuid) -> BankingResult<bool> { Ok(true) }
+        async fn create(&self, _customer: banking_db::models::CustomerModel) -> BankingResult<banking_db::models::CustomerModel> { unimplemented!() }
+        async fn update(&self, _customer: banking_db::models::CustomerModel) -> BankingResult<banking_db::models::CustomerModel> { unimplemented!() }
+        async fn find_by_id(&self, _customer_id: Uuid) -> BankingResult<Option<banking_db::models::CustomerModel>> { unimplemented!() }
+
+        async fn find_by_ids(&self, _customer_ids: &[Uuid]) -> BankingResult<Vec<banking_db::models::CustomerModel>> { unimplemented!() }
+        async fn find_by_identity(&self, _id_type: banking_db::models::IdentityType, _id_number: &str) -> BankingResult<Option<banking_db::models::CustomerModel>> { unimplemented!() }
+        async fn find_by_risk_rating(&self, _risk_rating: banking_db::models::RiskRating) -> BankingResult<Vec<banking_db::models::CustomerModel>> { unimplemented!() }
+        async fn find_requiring_review(&self) -> BankingResult<Vec<banking_db::models::CustomerModel>> { unimplemented!() }
+        async fn get_portfolio(&self, _customer_id: Uuid) -> BankingResult<Option<banking_db::models::CustomerPortfolioModel>> { unimplemented!() }
+        async fn search(&self, _criteria: banking_db::models::CustomerSearchCriteriaModel) -> BankingResult<Vec<banking_db::models::CustomerModel>> { unimplemented!() }
+        async fn update_risk_rating(&self, _customer_id: Uuid, _risk_rating: banking_db::models::RiskRating, _authorized_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn update_status(&self, _customer_id: Uuid, _status: banking_db::models::CustomerStatus, _reason: &str) -> BankingResult<()> { unimplemented!() }
+        async fn add_document(&self, _document: banking_db::models::CustomerDocumentModel) -> BankingResult<banking_db::models::CustomerDocumentModel> { unimplemented!() }
+        async fn get_documents(&self, _customer_id: Uuid) -> BankingResult<Vec<banking_db::models::CustomerDocumentModel>> { unimplemented!() }
+        async fn add_audit_entry(&self, _audit: banking_db::models::CustomerAuditModel) -> BankingResult<banking_db::models::CustomerAuditModel> { unimplemented!() }
+        async fn get_audit_trail(&self, _customer_id: Uuid) -> BankingResult<Vec<banking_db::models::CustomerAuditModel>> { unimplemented!() }
+        async fn merge_into(&self, _survivor_id: Uuid, _duplicate_id: Uuid, _reason: &str, _merged_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn delete(&self, _customer_id: Uuid, _deleted_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn list(&self, _offset: i64, _limit: i64) -> BankingResult<Vec<banking_db::models::CustomerModel>> { unimplemented!() }
+        async fn count(&self) -> BankingResult<i64> { unimplemented!() }
+    }
+
+    fn service() -> FinancialPositionServiceImpl {
+        FinancialPositionServiceImpl::new(
+            Arc::new(MockFinancialPositionRepository),
+            Arc::new(MockGuarantorRepository),
+            Arc::new(MockCustomerRepository),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_multi_currency_position_reports_unconvertible_currency_as_detail() {
+        let customer_id = Uuid::new_v4();
+
+        let view = service().get_customer_financial_position(customer_id, "EUR").await.unwrap();
+
+        let codes: Vec<&str> = view.currencies.iter().map(|p| p.currency.as_str()).collect();
+        assert_eq!(codes, vec!["EUR", "GHS", "USD", "XAF"]);
+        let eur = &view.currencies[0];
+        assert_eq!(eur.guarantees_given, Decimal::from(3_000));
+        assert_eq!(eur.legal_holds, Decimal::from(250));
+        let usd = &view.currencies[2];
+        assert_eq!(usd.account_count, 0);
+        assert_eq!(usd.guarantees_given, Decimal::from(1_000));
+
+        // XAF and USD convert, GHS has no rate and stays out of the totals
+        let rate_sources: Vec<&str> = view.rates_used.iter().map(|r| r.from_currency.as_str()).collect();
+        assert_eq!(rate_sources, vec!["USD", "XAF"]);
+        assert_eq!(view.unconverted_currencies, vec![currency("GHS")]);
+        assert_eq!(view.currencies[1].total_deposits, Decimal::from(10_000));
+
+        assert_eq!(view.totals.total_deposits, Decimal::from(3_500));
+        assert_eq!(view.totals.loan_exposure, Decimal::from(5_000));
+        assert_eq!(view.totals.guarantees_given, Decimal::from(3_920));
+        assert_eq!(view.totals.holds, Decimal::from(300));
+        assert_eq!(view.totals.overdraft_utilization(), Some(Decimal::from(25)));
+        assert!(view.has_unlimited_guarantee);
+        assert_eq!(view.customer_id, customer_id);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_base_currency_is_rejected() {
+        let result = service().get_customer_financial_position(Uuid::new_v4(), "EURO").await;
+        assert!(matches!(result, Err(BankingError::ValidationError { ref field, .. }) if field == "base_currency"));
+    }
+}
@@ -0,0 +1,194 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        GuaranteedLoanExposure, GuarantorExposure, GuarantorStatus, GuarantorType, KycStatus,
+        LoanGuarantor,
+    },
+    service::{ComplianceService, GuarantorService, ProductService},
+};
+use banking_db::{
+    models::DbGuarantorStatus,
+    repository::{AccountRepository, CustomerRepository, GuarantorRepository},
+    DbAccountType,
+};
+use crate::mappers::{CustomerMapper, GuarantorMapper};
+
+/// Configuration struct for GuarantorServiceImpl to avoid too many constructor arguments
+pub struct GuarantorServiceConfig {
+    pub guarantor_repository: Arc<dyn GuarantorRepository>,
+    pub account_repository: Arc<dyn AccountRepository>,
+    pub customer_repository: Arc<dyn CustomerRepository>,
+    pub compliance_service: Arc<dyn ComplianceService>,
+    pub product_service: Arc<dyn ProductService>,
+}
+
+/// Production implementation of GuarantorService
+pub struct GuarantorServiceImpl {
+    guarantor_repository: Arc<dyn GuarantorRepository>,
+    account_repository: Arc<dyn AccountRepository>,
+    customer_repository: Arc<dyn CustomerRepository>,
+    compliance_service: Arc<dyn ComplianceService>,
+    product_service: Arc<dyn ProductService>,
+}
+
+impl GuarantorServiceImpl {
+    pub fn new(config: GuarantorServiceConfig) -> Self {
+        Self {
+            guarantor_repository: config.guarantor_repository,
+            account_repository: config.account_repository,
+            customer_repository: config.customer_repository,
+            compliance_service: config.compliance_service,
+            product_service: config.product_service,
+        }
+    }
+}
+
+#[async_trait]
+impl GuarantorService for GuarantorServiceImpl {
+    async fn add_guarantor(&self, mut guarantor: LoanGuarantor) -> BankingResult<LoanGuarantor> {
+        let account = self.account_repository
+            .find_by_id(guarantor.loan_account_id)
+            .await?
+            .ok_or(BankingError::AccountNotFound(guarantor.loan_account_id))?;
+        if account.account_type != DbAccountType::Loan {
+            return Err(BankingError::ValidationError {
+                field: "loan_account_id".to_string(),
+                message: "Guarantors can only be attached to loan accounts".to_string(),
+            });
+        }
+
+        if let Some(amount) = guarantor.guarantee_amount {
+            if amount <= Decimal::ZERO {
+                return Err(BankingError::ValidationError {
+                    field: "guarantee_amount".to_string(),
+                    message: "Guarantee amount must be positive".to_string(),
+                });
+            }
+        }
+
+        let existing = self.guarantor_repository.find_guarantors_by_loan(guarantor.loan_account_id).await?;
+        if existing.iter().any(|g| g.guarantor_id == guarantor.guarantor_id && g.status == DbGuarantorStatus::Active) {
+            return Err(BankingError::ValidationError {
+                field: "guarantor_id".to_string(),
+                message: "Guarantor is already active on this loan".to_string(),
+            });
+        }
+
+        match guarantor.guarantor_type {
+            GuarantorType::Customer => self.validate_customer_guarantor(&guarantor).await?,
+            // Non-customers have no KYC record; the signed agreement is the evidence
+            GuarantorType::Person => {
+                if guarantor.document_reference.is_none() {
+                    return Err(BankingError::ValidationError {
+                        field: "document_reference".to_string(),
+                        message: "A guarantee agreement reference is required for non-customer guarantors".to_string(),
+                    });
+                }
+            }
+        }
+
+        let now = Utc::now();
+        guarantor.status = GuarantorStatus::Active;
+        guarantor.released_at = None;
+        guarantor.released_by_person_id = None;
+        guarantor.created_at = now;
+        guarantor.last_updated_at = now;
+
+        let created = self.guarantor_repository
+            .create_guarantor(GuarantorMapper::to_model(guarantor))
+            .await?;
+        Ok(GuarantorMapper::from_model(created))
+    }
+
+    async fn release_guarantor(&self, guarantor_link_id: Uuid, released_by_person_id: Uuid) -> BankingResult<LoanGuarantor> {
+        let model = self.guarantor_repository
+            .find_guarantor_by_id(guarantor_link_id)
+            .await?
+            .ok_or_else(|| BankingError::NotFound(format!("Loan guarantor {guarantor_link_id} not found")))?;
+        let mut guarantor = GuarantorMapper::from_model(model);
+        if guarantor.status == GuarantorStatus::Released {
+            return Err(BankingError::ValidationError {
+                field: "status".to_string(),
+                message: "Guarantor is already released".to_string(),
+            });
+        }
+
+        let account = self.account_repository
+            .find_by_id(guarantor.loan_account_id)
+            .await?
+            .ok_or(BankingError::AccountNotFound(guarantor.loan_account_id))?;
+        let rules = self.product_service.get_product_rules(account.product_id).await?;
+        if rules.guarantor_required {
+            let active = self.guarantor_repository.count_active_guarantors(guarantor.loan_account_id).await?;
+            if active <= 1 {
+                return Err(BankingError::ValidationError {
+                    field: "guarantor_id".to_string(),
+                    message: "Cannot release the last guarantor of a loan whose product requires one".to_string(),
+                });
+            }
+        }
+
+        let now = Utc::now();
+        guarantor.status = GuarantorStatus::Released;
+        guarantor.released_at = Some(now);
+        guarantor.released_by_person_id = Some(released_by_person_id);
+        guarantor.last_updated_at = now;
+        guarantor.updated_by_person_id = released_by_person_id;
+
+        let updated = self.guarantor_repository
+            .update_guarantor(GuarantorMapper::to_model(guarantor))
+            .await?;
+        Ok(GuarantorMapper::from_model(updated))
+    }
+
+    async fn find_guarantors_for_loan(&self, loan_account_id: Uuid) -> BankingResult<Vec<LoanGuarantor>> {
+        let guarantors = self.guarantor_repository.find_guarantors_by_loan(loan_account_id).await?;
+        Ok(guarantors.into_iter().map(GuarantorMapper::from_model).collect())
+    }
+
+    async fn get_guarantor_exposure(&self, guarantor_id: Uuid) -> BankingResult<GuarantorExposure> {
+        let loans = self.guarantor_repository
+            .find_guaranteed_loans(guarantor_id)
+            .await?
+            .into_iter()
+            .map(|loan| GuaranteedLoanExposure::new(loan.loan_account_id, loan.outstanding_principal, loan.guarantee_amount))
+            .collect();
+
+        Ok(GuarantorExposure::aggregate(guarantor_id, loans))
+    }
+}
+
+impl GuarantorServiceImpl {
+    /// A customer guarantor must not own the loan and must have acceptable KYC
+    async fn validate_customer_guarantor(&self, guarantor: &LoanGuarantor) -> BankingResult<()> {
+        let borrowers = self.account_repository.find_ownership_by_account(guarantor.loan_account_id).await?;
+        if borrowers.iter().any(|owner| owner.customer_id == guarantor.guarantor_id) {
+            return Err(BankingError::ValidationError {
+                field: "guarantor_id".to_string(),
+                message: "A borrower cannot guarantee their own loan".to_string(),
+            });
+        }
+
+        let customer_model = self.customer_repository
+            .find_by_id(guarantor.guarantor_id)
+            .await?
+            .ok_or(BankingError::CustomerNotFound(guarantor.guarantor_id))?;
+        let customer = CustomerMapper::from_model(customer_model)?;
+
+        let kyc = self.compliance_service.perform_kyc_check(&customer).await?;
+        if !matches!(kyc.status, KycStatus::Approved | KycStatus::Complete) {
+            return Err(BankingError::KycIncomplete {
+                customer_id: customer.id,
+                missing_documents: vec![format!("KYC status {:?}, {} required documents missing", kyc.status, kyc.missing_documents_count())],
+            });
+        }
+
+        Ok(())
+    }
+}
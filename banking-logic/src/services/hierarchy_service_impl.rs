@@ -7,7 +7,7 @@ use uuid::Uuid;
 use banking_api::{
     BankingResult, BankingError,
     domain::{
-        AgentNetwork, AgencyBranch, AgentTerminal,
+        AgentNetwork, AgencyBranch, AgentTerminal, BranchRollup,
         NetworkStatus, BranchStatus, TerminalStatus
     },
     service::{HierarchyService, NetworkHierarchy, BranchHierarchy},
@@ -509,6 +509,54 @@ impl HierarchyService for HierarchyServiceImpl {
             branches,
         })
     }
+
+    /// Account counts and balances of a branch and every branch below it
+    async fn get_branch_rollup(&self, branch_id: Uuid) -> BankingResult<BranchRollup> {
+        let nodes: Vec<_> = self.agent_network_repository
+            .find_branch_rollup(branch_id)
+            .await?
+            .into_iter()
+            .map(AgentNetworkMapper::rollup_node_from_model)
+            .collect();
+
+        let root = nodes
+            .first()
+            .ok_or_else(|| BankingError::NotFound(format!("Branch {branch_id} not found")))?;
+
+        Ok(BranchRollup {
+            agency_branch_id: branch_id,
+            account_count: root.subtree_account_count,
+            total_balance: root.subtree_balance,
+            nodes,
+        })
+    }
+
+    async fn get_reporting_line(&self, person_id: Uuid) -> BankingResult<Vec<Uuid>> {
+        let terminals = self.agent_network_repository
+            .find_terminals_by_agent(person_id)
+            .await?;
+        let mut next_branch_id = terminals
+            .iter()
+            .find(|terminal| terminal.status == DbTerminalStatus::Active)
+            .or(terminals.first())
+            .map(|terminal| terminal.agency_branch_id);
+
+        let mut line = Vec::new();
+        while let Some(branch_id) = next_branch_id {
+            let branch = self.agent_network_repository
+                .find_branch_by_id(branch_id)
+                .await?
+                .ok_or_else(|| BankingError::Internal(format!("Branch {branch_id} not found")))?;
+            // Skipping the person starts a branch manager's line at the parent branch
+            if let Some(manager_id) = branch.branch_manager_person_id {
+                if manager_id != person_id && !line.contains(&manager_id) {
+                    line.push(manager_id);
+                }
+            }
+            next_branch_id = branch.parent_agency_branch_id;
+        }
+        Ok(line)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,336 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{InteractionNote, InteractionNoteRequest, NoteEntityKind, NoteReaderScope, NoteVisibility},
+    service::{InteractionNoteService, StaffNotifier},
+};
+use banking_db::repository::InteractionNoteRepository;
+use crate::mappers::InteractionNoteMapper;
+
+/// Production implementation of InteractionNoteService
+pub struct InteractionNoteServiceImpl {
+    interaction_note_repository: Arc<dyn InteractionNoteRepository>,
+    staff_notifier: Arc<dyn StaffNotifier>,
+}
+
+impl InteractionNoteServiceImpl {
+    pub fn new(
+        interaction_note_repository: Arc<dyn InteractionNoteRepository>,
+        staff_notifier: Arc<dyn StaffNotifier>,
+    ) -> Self {
+        Self {
+            interaction_note_repository,
+            staff_notifier,
+        }
+    }
+
+    async fn find_note(&self, note_id: Uuid) -> BankingResult<InteractionNote> {
+        self.interaction_note_repository
+            .find_note_by_id(note_id)
+            .await?
+            .map(InteractionNoteMapper::from_model)
+            .ok_or_else(|| BankingError::NotFound(format!("Interaction note {note_id} not found")))
+    }
+
+    /// One reminder per author listing all of their due follow-ups
+    async fn remind_author(&self, author_person_id: Uuid, notes: &[InteractionNote], date: NaiveDate) -> BankingResult<()> {
+        let subject = format!("{} follow-up(s) due on {date}", notes.len());
+        let body = notes
+            .iter()
+            .map(|note| format!(
+                "- {:?} {} ({:?}), due {}: {}",
+                note.entity_kind,
+                note.entity_id,
+                note.category,
+                note.follow_up_date.map(|due| due.to_string()).unwrap_or_default(),
+                note.body,
+            ))
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.staff_notifier.notify_staff(author_person_id, &subject, &body).await
+    }
+}
+
+#[async_trait]
+impl InteractionNoteService for InteractionNoteServiceImpl {
+    async fn add_note(&self, request: InteractionNoteRequest, author_person_id: Uuid, scope: NoteReaderScope) -> BankingResult<InteractionNote> {
+        let invalid = |field: &str, message: &str| BankingError::ValidationError {
+            field: field.to_string(),
+            message: message.to_string(),
+        };
+        if request.body.trim().is_empty() {
+            return Err(invalid("body", "A note needs a body"));
+        }
+        if request.follow_up_date.is_some_and(|due| due < Utc::now().date_naive()) {
+            return Err(invalid("follow_up_date", "Follow-up date must not be in the past"));
+        }
+        // Branch staff write on behalf of their own branch
+        let agency_branch_id = match (scope, request.agency_branch_id) {
+            (NoteReaderScope::Branch(own), Some(branch_id)) if branch_id != own => {
+                return Err(invalid("agency_branch_id", "Branch staff can only write notes for their own branch"));
+            }
+            (NoteReaderScope::Branch(own), _) => Some(own),
+            (_, branch_id) => branch_id,
+        };
+        if request.visibility == NoteVisibility::BranchOnly && agency_branch_id.is_none() {
+            return Err(invalid("agency_branch_id", "A branch-only note needs a branch"));
+        }
+        if let Some(corrected_id) = request.corrects_note_id {
+            // A note the author cannot read is reported as missing
+            let corrected = self.find_note(corrected_id).await?;
+            if !corrected.is_visible_to(scope) {
+                return Err(BankingError::NotFound(format!("Interaction note {corrected_id} not found")));
+            }
+            if (corrected.entity_kind, corrected.entity_id) != (request.entity_kind, request.entity_id) {
+                return Err(invalid("corrects_note_id", "A correction must be on the same entity as the note it corrects"));
+            }
+        }
+
+        let note = InteractionNote {
+            id: Uuid::new_v4(),
+            entity_kind: request.entity_kind,
+            entity_id: request.entity_id,
+            author_person_id,
+            agency_branch_id,
+            visibility: request.visibility,
+            category: request.category,
+            body: request.body,
+            corrects_note_id: request.corrects_note_id,
+            follow_up_date: request.follow_up_date,
+            follow_up_completed_at: None,
+            created_at: Utc::now(),
+        };
+        Ok(InteractionNoteMapper::from_model(
+            self.interaction_note_repository.create_note(InteractionNoteMapper::to_model(note)).await?,
+        ))
+    }
+
+    async fn get_notes(&self, entity_kind: NoteEntityKind, entity_id: Uuid, scope: NoteReaderScope) -> BankingResult<Vec<InteractionNote>> {
+        Ok(self.interaction_note_repository
+            .find_notes_by_entity(InteractionNoteMapper::entity_kind_to_db(entity_kind), entity_id)
+            .await?
+            .into_iter()
+            .map(InteractionNoteMapper::from_model)
+            .filter(|note| note.is_visible_to(scope))
+            .collect())
+    }
+
+    async fn pending_follow_ups(&self, author_person_id: Uuid) -> BankingResult<Vec<InteractionNote>> {
+        Ok(self.interaction_note_repository
+            .find_pending_follow_ups_by_author(author_person_id)
+            .await?
+            .into_iter()
+            .map(InteractionNoteMapper::from_model)
+            .collect())
+    }
+
+    async fn complete_follow_up(&self, note_id: Uuid, person_id: Uuid) -> BankingResult<InteractionNote> {
+        let note = self.find_note(note_id).await?;
+        if note.author_person_id != person_id {
+            return Err(BankingError::UnauthorizedOperation(format!(
+                "Only the author can complete the follow-up of note {note_id}"
+            )));
+        }
+        if note.follow_up_date.is_none() || note.follow_up_completed_at.is_some() {
+            return Err(BankingError::ValidationError {
+                field: "follow_up_date".to_string(),
+                message: format!("Note {note_id} has no open follow-up"),
+            });
+        }
+        Ok(InteractionNoteMapper::from_model(
+            self.interaction_note_repository.complete_follow_up(note_id, Utc::now()).await?,
+        ))
+    }
+
+    async fn notify_due_follow_ups(&self, date: NaiveDate) -> BankingResult<Vec<InteractionNote>> {
+        let due: Vec<InteractionNote> = self.interaction_note_repository
+            .find_follow_ups_due(date)
+            .await?
+            .into_iter()
+            .map(InteractionNoteMapper::from_model)
+            .collect();
+
+        let mut notified = Vec::new();
+        for notes in due.chunk_by(|a, b| a.author_person_id == b.author_person_id) {
+            let author_person_id = notes[0].author_person_id;
+            // One unreachable author does not hold up the rest of the sweep
+            match self.remind_author(author_person_id, notes, date).await {
+                Ok(()) => notified.extend_from_slice(notes),
+                Err(e) => tracing::error!("Failed to remind {author_person_id} of due follow-ups: {e}"),
+            }
+        }
+        Ok(notified)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use banking_api::domain::NoteCategory;
+    use banking_db::models::{DbNoteEntityKind, InteractionNoteModel};
+    use chrono::{DateTime, Duration};
+    use heapless::String as HeaplessString;
+
+    #[derive(Default)]
+    struct MockInteractionNoteRepository {
+        notes: Mutex<HashMap<Uuid, InteractionNoteModel>>,
+    }
+
+    impl MockInteractionNoteRepository {
+        fn all(&self) -> Vec<InteractionNoteModel> {
+            let mut notes: Vec<_> = self.notes.lock().unwrap().values().cloned().collect();
+            notes.sort_by_key(|n| (n.author_person_id, n.follow_up_date, n.created_at));
+            notes
+        }
+    }
+
+    #[async_trait]
+    impl InteractionNoteRepository for MockInteractionNoteRepository {
+        async fn create_note(&self, note: InteractionNoteModel) -> BankingResult<InteractionNoteModel> {
+            self.notes.lock().unwrap().insert(note.id, note.clone());
+            Ok(note)
+        }
+        async fn find_note_by_id(&self, note_id: Uuid) -> BankingResult<Option<InteractionNoteModel>> {
+            Ok(self.notes.lock().unwrap().get(&note_id).cloned())
+        }
+        async fn find_notes_by_entity(&self, entity_kind: DbNoteEntityKind, entity_id: Uuid) -> BankingResult<Vec<InteractionNoteModel>> {
+            Ok(self.all().into_iter().filter(|n| n.entity_kind == entity_kind && n.entity_id == entity_id).collect())
+        }
+        async fn find_pending_follow_ups_by_author(&self, _author_person_id: Uuid) -> BankingResult<Vec<InteractionNoteModel>> { todo!() }
+        async fn find_follow_ups_due(&self, date: NaiveDate) -> BankingResult<Vec<InteractionNoteModel>> {
+            Ok(self.all()
+                .into_iter()
+                .filter(|n| n.follow_up_completed_at.is_none() && n.follow_up_date.is_some_and(|due| due <= date))
+                .collect())
+        }
+        async fn complete_follow_up(&self, note_id: Uuid, completed_at: DateTime<Utc>) -> BankingResult<InteractionNoteModel> {
+            let mut notes = self.notes.lock().unwrap();
+            let note = notes.get_mut(&note_id).unwrap();
+            note.follow_up_completed_at = Some(completed_at);
+            Ok(note.clone())
+        }
+    }
+
+    /// Records reminders as (person, subject, body)
+    #[derive(Default)]
+    struct MockStaffNotifier {
+        sent: Mutex<Vec<(Uuid, String, String)>>,
+    }
+
+    #[async_trait]
+    impl StaffNotifier for MockStaffNotifier {
+        async fn notify_staff(&self, person_id: Uuid, subject: &str, body: &str) -> BankingResult<()> {
+            self.sent.lock().unwrap().push((person_id, subject.to_string(), body.to_string()));
+            Ok(())
+        }
+    }
+
+    fn service() -> (InteractionNoteServiceImpl, Arc<MockStaffNotifier>) {
+        let notifier = Arc::new(MockStaffNotifier::default());
+        let service = InteractionNoteServiceImpl::new(Arc::new(MockInteractionNoteRepository::default()), notifier.clone());
+        (service, notifier)
+    }
+
+    fn request(account_id: Uuid, visibility: NoteVisibility, body: &str) -> InteractionNoteRequest {
+        InteractionNoteRequest {
+            entity_kind: NoteEntityKind::Account,
+            entity_id: account_id,
+            agency_branch_id: None,
+            visibility,
+            category: NoteCategory::BranchVisit,
+            body: HeaplessString::try_from(body).unwrap(),
+            corrects_note_id: None,
+            follow_up_date: None,
+        }
+    }
+
+    async fn bodies(service: &InteractionNoteServiceImpl, account_id: Uuid, scope: NoteReaderScope) -> Vec<String> {
+        let mut bodies: Vec<String> = service
+            .get_notes(NoteEntityKind::Account, account_id, scope)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|note| note.body.to_string())
+            .collect();
+        bodies.sort();
+        bodies
+    }
+
+    #[tokio::test]
+    async fn test_reads_are_filtered_by_scope() {
+        let (service, _) = service();
+        let (account_id, douala, yaounde) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let teller = Uuid::new_v4();
+
+        service.add_note(request(account_id, NoteVisibility::BranchOnly, "branch"), teller, NoteReaderScope::Branch(douala)).await.unwrap();
+        service.add_note(request(account_id, NoteVisibility::BankWide, "bank"), teller, NoteReaderScope::Branch(douala)).await.unwrap();
+        // Branch staff may flag to compliance without reading it back
+        let flagged = service
+            .add_note(request(account_id, NoteVisibility::ComplianceOnly, "compliance"), teller, NoteReaderScope::Branch(douala))
+            .await
+            .unwrap();
+        assert_eq!(flagged.agency_branch_id, Some(douala));
+
+        assert_eq!(bodies(&service, account_id, NoteReaderScope::Branch(douala)).await, ["bank", "branch"]);
+        assert_eq!(bodies(&service, account_id, NoteReaderScope::Branch(yaounde)).await, ["bank"]);
+        assert_eq!(bodies(&service, account_id, NoteReaderScope::HeadOffice).await, ["bank", "branch"]);
+        assert_eq!(bodies(&service, account_id, NoteReaderScope::Compliance).await, ["bank", "branch", "compliance"]);
+
+        // Correcting a note outside the scope looks like correcting a missing one
+        let mut correction = request(account_id, NoteVisibility::BranchOnly, "corrected");
+        correction.corrects_note_id = Some(flagged.id);
+        let result = service.add_note(correction.clone(), teller, NoteReaderScope::Branch(douala)).await;
+        assert!(matches!(result, Err(BankingError::NotFound(_))));
+        // Outside branch scope a branch-only note must name its branch
+        let without_branch = service.add_note(correction, Uuid::new_v4(), NoteReaderScope::Compliance).await;
+        assert!(matches!(without_branch, Err(BankingError::ValidationError { ref field, .. }) if field == "agency_branch_id"));
+    }
+
+    #[tokio::test]
+    async fn test_sweep_reminds_each_author_once_until_completed() {
+        let (service, notifier) = service();
+        let (account_id, branch) = (Uuid::new_v4(), Uuid::new_v4());
+        let (alice, bruno) = (Uuid::new_v4(), Uuid::new_v4());
+        let today = Utc::now().date_naive();
+
+        let follow_up = |body: &str, due: NaiveDate| InteractionNoteRequest {
+            follow_up_date: Some(due),
+            ..request(account_id, NoteVisibility::BranchOnly, body)
+        };
+        let scope = NoteReaderScope::Branch(branch);
+        let payslips = service.add_note(follow_up("Collect payslips", today), alice, scope).await.unwrap();
+        service.add_note(follow_up("Call back on card", today), alice, scope).await.unwrap();
+        service.add_note(follow_up("Check standing order", today), bruno, scope).await.unwrap();
+        service.add_note(follow_up("Renewal visit", today + Duration::days(7)), bruno, scope).await.unwrap();
+
+        let notified = service.notify_due_follow_ups(today).await.unwrap();
+        assert_eq!(notified.len(), 3);
+        {
+            let sent = notifier.sent.lock().unwrap();
+            assert_eq!(sent.len(), 2);
+            let to_alice = sent.iter().find(|(person, _, _)| *person == alice).unwrap();
+            assert!(to_alice.1.starts_with("2 follow-up(s)"));
+            assert!(to_alice.2.contains("Collect payslips") && to_alice.2.contains("Call back on card"));
+            let to_bruno = sent.iter().find(|(person, _, _)| *person == bruno).unwrap();
+            assert!(!to_bruno.2.contains("Renewal visit"));
+        }
+
+        assert!(matches!(
+            service.complete_follow_up(payslips.id, bruno).await,
+            Err(BankingError::UnauthorizedOperation(_))
+        ));
+        service.complete_follow_up(payslips.id, alice).await.unwrap();
+        notifier.sent.lock().unwrap().clear();
+        let notified = service.notify_due_follow_ups(today).await.unwrap();
+        assert_eq!(notified.len(), 2);
+        let sent = notifier.sent.lock().unwrap();
+        let to_alice = sent.iter().find(|(person, _, _)| *person == alice).unwrap();
+        assert!(!to_alice.2.contains("Collect payslips"));
+    }
+}
@@ -1,29 +1,140 @@
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
 use async_trait::async_trait;
-use chrono::{NaiveDate, Utc};
-use rust_decimal::Decimal;
+use chrono::{Months, NaiveDate, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
+use tokio::{sync::Semaphore, task::JoinSet};
 use uuid::Uuid;
 use heapless::String as HeaplessString;
 
 use banking_api::{
     BankingResult, BankingError,
-    service::{InterestService, CalendarService},
-    domain::{AccountType, TransactionType, TransactionStatus, Transaction},
+    service::{
+        AccountAccrual, AccrualChunkSummary, AccrualReport, InterestService,
+        CalendarService, DepositAccrualKind, DepositInterestSummary, InterestProjectionAssumptions, InterestSummaryView,
+        LoanInterestSummary,
+    },
+    domain::{
+        AccrualOptions, Account, AccountType, AmortizationMethod, AmortizationSchedule, CurrencyCode, DayCountConvention,
+        GenerateAmortizationScheduleRequest, PaymentFrequency, ProductPromotion, ResolvedInterestRate, TransactionType, TransactionStatus, Transaction,
+        SYSTEM_CHANNEL_ID,
+    },
 };
 use banking_db::{
-    repository::{AccountRepository, TransactionRepository},
+    models::{AccountInterestAccrualModel, AccountModel, DbBalanceChangeSource, ProductAccrualFrequency},
+    repository::{AccountRepository, PromotionRepository, TransactionRepository},
 };
 use crate::{
-    mappers::{AccountMapper, TransactionMapper},
+    constants::SYSTEM_PERSON_ID,
+    mappers::{AccountMapper, ProductRulesMapper, PromotionMapper, TransactionMapper},
 };
 use banking_db::repository::ProductRepository;
 
+/// Transaction code of interest posted to an account balance
+const INTEREST_POSTING_CODE: &str = "INT_POST";
+
+/// Transaction code of a capitalized custody fee debited from an account balance
+const CUSTODY_FEE_POSTING_CODE: &str = "CUST_FEE";
+
+/// Banker's rounding to the minor units of the currency
+fn round_to_minor_units(amount: Decimal, currency: &CurrencyCode) -> Decimal {
+    amount.round_dp_with_strategy(currency.minor_units(), RoundingStrategy::MidpointNearestEven)
+}
+
+/// Promotions in effect for one accrual run. The remaining budgets are shared
+/// by the chunks of the run so parallel chunks do not spend a cap twice.
+struct PromotionRun {
+    promotions: Vec<ProductPromotion>,
+    remaining_budgets: Mutex<HashMap<Uuid, Decimal>>,
+}
+
+impl PromotionRun {
+    fn new(promotions: Vec<ProductPromotion>) -> Self {
+        let remaining_budgets = promotions
+            .iter()
+            .filter_map(|p| p.remaining_budget().map(|left| (p.id, left)))
+            .collect();
+        Self {
+            promotions,
+            remaining_budgets: Mutex::new(remaining_budgets),
+        }
+    }
+
+    fn needs_segments(&self) -> bool {
+        self.promotions.iter().any(|p| p.segment_code.is_some())
+    }
+
+    /// Move a savings accrual to the best eligible promotion with budget left.
+    /// The bonus over the product rate is taken from the budget and cut to what
+    /// is left, so the accrual that exhausts a cap only gets the remainder.
+    fn apply(
+        &self,
+        account: &Account,
+        segment_codes: &[HeaplessString<50>],
+        accrual: &mut AccountAccrual,
+        convention: DayCountConvention,
+        date: NaiveDate,
+    ) {
+        // Loans carry their own account-level rate, which beats any promotion
+        if account.account_type != AccountType::Savings || accrual.principal_balance <= Decimal::ZERO {
+            return;
+        }
+        let product_rate = accrual.interest_rate;
+        let mut budgets = self.remaining_budgets.lock().unwrap();
+        let Some(promotion) = self.promotions
+            .iter()
+            .filter(|p| p.product_id == account.product_id && p.is_eligible(account.open_date, segment_codes))
+            .filter(|p| budgets.get(&p.id).is_none_or(|left| *left > Decimal::ZERO))
+            .max_by_key(|p| p.apply(product_rate))
+        else {
+            return;
+        };
+
+        let resolved = ResolvedInterestRate::resolve(product_rate, Some(promotion), None);
+        let mut bonus = convention.daily_accrual(accrual.principal_balance, resolved.rate, date) - accrual.daily_interest;
+        if let Some(left) = budgets.get_mut(&promotion.id) {
+            if bonus > Decimal::ZERO {
+                bonus = bonus.min(*left);
+                *left -= bonus;
+            }
+        }
+        accrual.daily_interest += bonus;
+        accrual.interest_rate = resolved.rate;
+        accrual.promotion_id = Some(promotion.id);
+        accrual.bonus_interest = bonus;
+    }
+
+    /// Return the budget taken by accruals that were not applied
+    fn release(&self, accruals: &[AccountAccrual]) {
+        let mut budgets = self.remaining_budgets.lock().unwrap();
+        for accrual in accruals.iter().filter(|a| a.bonus_interest > Decimal::ZERO) {
+            if let Some(left) = accrual.promotion_id.and_then(|id| budgets.get_mut(&id)) {
+                *left += accrual.bonus_interest;
+            }
+        }
+    }
+
+    fn exhausted(&self) -> Vec<Uuid> {
+        let budgets = self.remaining_budgets.lock().unwrap();
+        let mut exhausted: Vec<Uuid> = budgets
+            .iter()
+            .filter(|(_, left)| **left <= Decimal::ZERO)
+            .map(|(id, _)| *id)
+            .collect();
+        exhausted.sort();
+        exhausted
+    }
+}
+
 /// Production implementation of InterestService
 /// Provides product catalog-driven interest calculations with business day awareness
+#[derive(Clone)]
 pub struct InterestServiceImpl {
     account_repository: Arc<dyn AccountRepository>,
     transaction_repository: Arc<dyn TransactionRepository>,
     product_repository: Arc<dyn ProductRepository>,
+    promotion_repository: Arc<dyn PromotionRepository>,
     calendar_service: Arc<dyn CalendarService>,
 }
 
@@ -32,12 +143,14 @@ impl InterestServiceImpl {
         account_repository: Arc<dyn AccountRepository>,
         transaction_repository: Arc<dyn TransactionRepository>,
         product_repository: Arc<dyn ProductRepository>,
+        promotion_repository: Arc<dyn PromotionRepository>,
         calendar_service: Arc<dyn CalendarService>,
     ) -> Self {
         Self {
             account_repository,
             transaction_repository,
             product_repository,
+            promotion_repository,
             calendar_service,
         }
     }
@@ -55,23 +168,10 @@ impl InterestService for InterestServiceImpl {
 
         let account = AccountMapper::from_model(account_model)?;
 
-        // Only calculate interest for interest-bearing accounts
-        let daily_interest = match account.account_type {
-            AccountType::Savings => {
-                self.calculate_savings_daily_interest(&account).await?
-            }
-            AccountType::Loan => {
-                self.calculate_loan_daily_interest(&account).await?
-            }
-            AccountType::Current => {
-                // Current accounts typically don't earn interest, but may have overdraft interest
-                if account.current_balance < Decimal::ZERO {
-                    self.calculate_overdraft_daily_interest(&account).await?
-                } else {
-                    Decimal::ZERO
-                }
-            }
-        };
+        // Current accounts don't earn interest, but may pay a custody fee
+        let today = Utc::now().date_naive();
+        let convention = self.day_count_convention(account.product_id).await?;
+        let daily_interest = self.calculate_account_accrual(&account, convention, today).await?.daily_interest;
 
         tracing::debug!(
             "Daily interest calculated for account {}: {}",
@@ -90,8 +190,8 @@ impl InterestService for InterestServiceImpl {
 
         let account = AccountMapper::from_model(account_model)?;
 
-        // Only post interest if there's accrued interest
-        if account.accrued_interest <= Decimal::ZERO {
+        // Only post if something accrued; a negative accrual is a custody fee
+        if account.accrued_interest == Decimal::ZERO {
             return Ok(());
         }
 
@@ -101,69 +201,7 @@ impl InterestService for InterestServiceImpl {
             return Ok(());
         }
 
-        // Create interest credit transaction
-        let interest_transaction = Transaction {
-            id: Uuid::new_v4(),
-            account_id,
-            transaction_code: HeaplessString::try_from("INT_POST").map_err(|_| BankingError::ValidationError {
-                field: "transaction_code".to_string(),
-                message: "Transaction code too long".to_string(),
-            })?,
-            transaction_type: TransactionType::Credit,
-            amount: account.accrued_interest,
-            currency: account.currency.clone(),
-            description: {
-                let desc_str = format!("Interest posting for period ending {today}");
-                HeaplessString::try_from(desc_str.as_str()).map_err(|_| BankingError::ValidationError {
-                    field: "description".to_string(),
-                    message: "Description too long".to_string(),
-                })?
-            },
-            channel_id: HeaplessString::try_from("SYSTEM").map_err(|_| BankingError::ValidationError {
-                field: "channel_id".to_string(),
-                message: "Channel ID too long".to_string(),
-            })?,
-            terminal_id: None,
-            agent_person_id: None,
-            transaction_date: Utc::now(),
-            value_date: today,
-            status: TransactionStatus::Posted,
-            reference_number: {
-                let ref_num = self.generate_interest_reference(&account, today).await?;
-                HeaplessString::try_from(ref_num.as_str()).map_err(|_| BankingError::ValidationError {
-                    field: "reference_number".to_string(),
-                    message: "Reference number too long".to_string(),
-                })?
-            },
-            external_reference: None,
-            gl_code: {
-                let gl_code_str = self.get_interest_gl_code(account.product_id).await?;
-                HeaplessString::try_from(gl_code_str.as_str()).map_err(|_| BankingError::ValidationError {
-                    field: "gl_code".to_string(),
-                    message: "GL code too long".to_string(),
-                })?
-            },
-            requires_approval: false,
-            approval_status: None,
-            risk_score: Some(Decimal::ZERO), // System transaction, no risk
-            created_at: Utc::now(),
-        };
-
-        // Post the interest transaction
-        let transaction_model = TransactionMapper::to_model(interest_transaction);
-        self.transaction_repository.create(transaction_model).await?;
-
-        // Update account balance and reset accrued interest
-        let new_balance = account.current_balance + account.accrued_interest;
-        let new_available = account.available_balance + account.accrued_interest;
-        self.account_repository.update_balance(account_id, new_balance, new_available).await?;
-        self.account_repository.reset_accrued_interest(account_id).await?;
-
-        tracing::info!(
-            "Posted interest of {} to account {}. New balance: {}",
-            account.accrued_interest, account_id, new_balance
-        );
-
+        self.post_accrued_interest(account_id, today).await?;
         Ok(())
     }
 
@@ -241,16 +279,7 @@ impl InterestService for InterestServiceImpl {
         let product_rules = product.rules;
 
         while current_date <= to_date {
-            // Check if we should accrue interest on this date
-            let should_accrue = match product_rules.accrual_frequency {
-                banking_db::models::ProductAccrualFrequency::Daily => true,
-                banking_db::models::ProductAccrualFrequency::BusinessDaysOnly => {
-                    self.calendar_service.is_business_day(current_date, account.currency.as_str()).await?
-                }
-                banking_db::models::ProductAccrualFrequency::None => false,
-            };
-
-            if should_accrue {
+            if self.accrues_on(&product_rules.accrual_frequency, current_date, &account).await? {
                 let daily_interest = self.calculate_historical_daily_interest(&account, current_date).await?;
                 total_accrued += daily_interest;
             }
@@ -304,9 +333,232 @@ impl InterestService for InterestServiceImpl {
         }
     }
 
+    /// One day's accrual rounded as it would be posted; accrual runs keep the
+    /// full precision and only round when posting
+    async fn calculate_daily_accrual(&self, account_id: Uuid, date: NaiveDate) -> BankingResult<Decimal> {
+        let account_model = self.account_repository
+            .find_by_id(account_id)
+            .await?
+            .ok_or(BankingError::AccountNotFound(account_id))?;
+        let account = AccountMapper::from_model(account_model)?;
+
+        let convention = self.day_count_convention(account.product_id).await?;
+        let accrual = self.calculate_account_accrual(&account, convention, date).await?;
+        Ok(round_to_minor_units(accrual.daily_interest, &account.currency))
+    }
+
+    /// The accrued interest is posted rounded to the currency's minor units with
+    /// a system transaction valued at `period_end`; the balance update and the
+    /// accrual reset then run in one repository transaction.
+    async fn post_accrued_interest(&self, account_id: Uuid, period_end: NaiveDate) -> BankingResult<Decimal> {
+        let account_model = self.account_repository
+            .find_by_id(account_id)
+            .await?
+            .ok_or(BankingError::AccountNotFound(account_id))?;
+        let account = AccountMapper::from_model(account_model)?;
+
+        let amount = round_to_minor_units(account.accrued_interest, &account.currency);
+        if amount.is_zero() {
+            return Ok(Decimal::ZERO);
+        }
+
+        // Interest is credited; a custody fee is debited as a system posting
+        let (transaction_type, transaction_code, description, gl_code_str) = match DepositAccrualKind::of(amount) {
+            DepositAccrualKind::CreditInterest => (
+                TransactionType::Credit,
+                INTEREST_POSTING_CODE,
+                format!("Interest posting for period ending {period_end}"),
+                self.get_interest_gl_code(account.product_id).await?,
+            ),
+            DepositAccrualKind::CustodyFee => (
+                TransactionType::Debit,
+                CUSTODY_FEE_POSTING_CODE,
+                format!("{} for period ending {period_end}", DepositAccrualKind::CustodyFee.label()),
+                self.get_custody_fee_gl_code(account.product_id).await?,
+            ),
+        };
+        let interest_transaction = Transaction {
+            id: Uuid::new_v4(),
+            account_id,
+            transaction_code: HeaplessString::try_from(transaction_code).map_err(|_| BankingError::ValidationError {
+                field: "transaction_code".to_string(),
+                message: "Transaction code too long".to_string(),
+            })?,
+            transaction_type,
+            amount: amount.abs(),
+            currency: account.currency.clone(),
+            description: {
+                let desc_str = description;
+                HeaplessString::try_from(desc_str.as_str()).map_err(|_| BankingError::ValidationError {
+                    field: "description".to_string(),
+                    message: "Description too long".to_string(),
+                })?
+            },
+            channel_id: HeaplessString::try_from(SYSTEM_CHANNEL_ID).map_err(|_| BankingError::ValidationError {
+                field: "channel_id".to_string(),
+                message: "Channel ID too long".to_string(),
+            })?,
+            terminal_id: None,
+            agent_person_id: None,
+            transaction_date: Utc::now(),
+            value_date: period_end,
+            status: TransactionStatus::Posted,
+            reference_number: {
+                let ref_num = self.generate_interest_reference(&account, period_end).await?;
+                HeaplessString::try_from(ref_num.as_str()).map_err(|_| BankingError::ValidationError {
+                    field: "reference_number".to_string(),
+                    message: "Reference number too long".to_string(),
+                })?
+            },
+            external_reference: None,
+            gl_code: {
+                HeaplessString::try_from(gl_code_str.as_str()).map_err(|_| BankingError::ValidationError {
+                    field: "gl_code".to_string(),
+                    message: "GL code too long".to_string(),
+                })?
+            },
+            requires_approval: false,
+            approval_status: None,
+            risk_score: Some(Decimal::ZERO), // System transaction, no risk
+            degraded_flags: banking_api::domain::DegradedFlags::NONE,
+            idempotency_key: None,
+            reversal_of_transaction_id: None,
+            fx_conversion: None,
+            created_at: Utc::now(),
+        };
+
+        // Post the interest transaction
+        let transaction_id = interest_transaction.id;
+        let transaction_model = TransactionMapper::to_model(interest_transaction);
+        self.transaction_repository.create(transaction_model).await?;
+
+        // Update account balance and reset accrued interest
+        let change_source = match DepositAccrualKind::of(amount) {
+            DepositAccrualKind::CreditInterest => DbBalanceChangeSource::InterestPosting,
+            DepositAccrualKind::CustodyFee => DbBalanceChangeSource::FeeCharge,
+        };
+        self.account_repository
+            .post_accrued_interest(account_id, amount, change_source, Some(transaction_id), SYSTEM_PERSON_ID)
+            .await?;
+
+        tracing::info!(
+            "Posted {} of {} to account {} for period ending {}",
+            transaction_code, amount, account_id, period_end
+        );
+
+        Ok(amount)
+    }
+
     /// Accrue daily interest for all interest-bearing accounts
-    async fn accrue_daily_interest(&self, _processing_date: NaiveDate) -> BankingResult<banking_api::service::AccrualReport> {
-        todo!("Implement daily interest accrual")
+    async fn accrue_daily_interest(&self, processing_date: NaiveDate, options: AccrualOptions) -> BankingResult<AccrualReport> {
+        let started_at = Utc::now();
+        let clock = Instant::now();
+        let semaphore = Arc::new(Semaphore::new(options.parallelism.max(1)));
+        let mut chunks = JoinSet::new();
+        let mut errors = vec![];
+        let mut after_account_id = None;
+        let mut chunk_index = 0;
+        // Expired promotions drop out here by their end date
+        let promotions = Arc::new(PromotionRun::new(
+            self.promotion_repository
+                .find_promotions_in_effect(processing_date)
+                .await?
+                .into_iter()
+                .map(PromotionMapper::from_model)
+                .collect(),
+        ));
+
+        loop {
+            // Taking the permit before paging bounds the accounts held in memory
+            let permit = semaphore
+                .clone()
+                .acquire_owned()
+                .await
+                .map_err(|e| BankingError::Internal(format!("Accrual semaphore closed: {e}")))?;
+            let page = match self
+                .account_repository
+                .find_interest_bearing_accounts_after(after_account_id, options.chunk_size)
+                .await
+            {
+                Ok(page) => page,
+                Err(e) => {
+                    // Chunks already started still complete; a rerun picks up the rest
+                    errors.push(format!("Failed to load accounts after {after_account_id:?}: {e}"));
+                    break;
+                }
+            };
+            let Some(last_account) = page.last() else {
+                break;
+            };
+            after_account_id = Some(last_account.id);
+            let is_last_page = (page.len() as i64) < options.chunk_size;
+
+            let worker = self.clone();
+            let promotions = promotions.clone();
+            chunks.spawn(async move {
+                let result = worker
+                    .accrue_chunk(chunk_index, page, processing_date, &promotions, options.max_chunk_attempts)
+                    .await;
+                drop(permit);
+                result
+            });
+            chunk_index += 1;
+
+            if is_last_page {
+                break;
+            }
+        }
+
+        let mut report = AccrualReport {
+            processing_date,
+            accounts_processed: 0,
+            accounts_already_accrued: 0,
+            accounts_failed: 0,
+            total_interest_accrued: Decimal::ZERO,
+            account_accruals: vec![],
+            chunks: vec![],
+            started_at,
+            completed_at: started_at,
+            wall_clock_ms: 0,
+            exhausted_promotion_ids: vec![],
+            errors,
+        };
+        while let Some(joined) = chunks.join_next().await {
+            let (summary, accruals, accounts_in_chunk) = joined
+                .map_err(|e| BankingError::Internal(format!("Accrual chunk task failed: {e}")))?;
+            report.accounts_processed += summary.accounts_processed;
+            report.accounts_already_accrued += summary.accounts_already_accrued;
+            report.total_interest_accrued += summary.total_interest_accrued;
+            if let Some(error) = &summary.error {
+                report.accounts_failed += accounts_in_chunk;
+                report.errors.push(format!("Chunk {}: {error}", summary.chunk_index));
+            }
+            report.account_accruals.extend(accruals);
+            report.chunks.push(summary);
+        }
+        report.chunks.sort_by_key(|c| c.chunk_index);
+
+        for promotion_id in promotions.exhausted() {
+            tracing::warn!("Promotion {promotion_id} exhausted its budget cap on {processing_date}, no further bonus interest");
+            match self.promotion_repository.mark_budget_exhausted(promotion_id, Utc::now()).await {
+                Ok(()) => report.exhausted_promotion_ids.push(promotion_id),
+                Err(e) => report.errors.push(format!("Failed to mark promotion {promotion_id} exhausted: {e}")),
+            }
+        }
+        report.completed_at = Utc::now();
+        report.wall_clock_ms = clock.elapsed().as_millis() as i64;
+
+        tracing::info!(
+            "Interest accrual for {}: {} accounts accrued, {} already accrued, {} failed in {} chunks, {} ms",
+            processing_date,
+            report.accounts_processed,
+            report.accounts_already_accrued,
+            report.accounts_failed,
+            report.chunks.len(),
+            report.wall_clock_ms
+        );
+
+        Ok(report)
     }
 
     /// Capitalize accrued interest into account balance
@@ -328,58 +580,379 @@ impl InterestService for InterestServiceImpl {
     async fn should_accrue_interest(&self, _account_id: Uuid, _processing_date: NaiveDate) -> BankingResult<bool> {
         todo!("Implement should accrue interest check")
     }
+
+    /// Interest summary for customer display as of today
+    async fn get_interest_summary(&self, account_id: Uuid) -> BankingResult<InterestSummaryView> {
+        self.interest_summary_as_of(account_id, Utc::now().date_naive()).await
+    }
+
+    /// Accrual keeps no balance history, so every day of the window is taken at
+    /// the current balance as the daily accrual does. Each day the product accrues
+    /// on adds the accrual with the change less the accrual without it.
+    async fn recalculate_back_dated_accrual(
+        &self,
+        account_id: Uuid,
+        balance_change: Decimal,
+        from_date: NaiveDate,
+        to_date: NaiveDate,
+    ) -> BankingResult<Decimal> {
+        let account_model = self.account_repository
+            .find_by_id(account_id)
+            .await?
+            .ok_or(BankingError::AccountNotFound(account_id))?;
+        let account = AccountMapper::from_model(account_model)?;
+        let product = self.product_repository.find_product_by_id(account.product_id).await?
+            .ok_or(BankingError::ProductNotFound(account.product_id))?;
+
+        let mut without_change = account.clone();
+        without_change.current_balance -= balance_change;
+        let convention = ProductRulesMapper::from_db(product.rules.clone()).day_count_convention;
+
+        let mut accrual_days = 0;
+        let mut delta = Decimal::ZERO;
+        let mut date = from_date;
+        while date < to_date {
+            if self.accrues_on(&product.rules.accrual_frequency, date, &account).await? {
+                accrual_days += 1;
+                delta += self.calculate_account_accrual(&account, convention, date).await?.daily_interest
+                    - self.calculate_account_accrual(&without_change, convention, date).await?.daily_interest;
+            }
+            date += chrono::Duration::days(1);
+        }
+
+        if delta != Decimal::ZERO {
+            self.account_repository
+                .update_accrued_interest(account_id, account.accrued_interest + delta)
+                .await?;
+        }
+        tracing::info!(
+            "Back-dated accrual delta of {} over {} days applied to account {}",
+            delta, accrual_days, account_id
+        );
+        Ok(delta)
+    }
 }
 
 impl InterestServiceImpl {
-    /// Calculate daily interest for savings accounts with tiered rates
-    async fn calculate_savings_daily_interest(&self, account: &banking_api::domain::Account) -> BankingResult<Decimal> {
-        if account.current_balance <= Decimal::ZERO {
-            return Ok(Decimal::ZERO);
+    /// Accrue one chunk, retrying it as a whole on failure. Returns the chunk
+    /// summary, the accruals applied by this run and the chunk size.
+    async fn accrue_chunk(
+        &self,
+        chunk_index: i64,
+        accounts: Vec<AccountModel>,
+        processing_date: NaiveDate,
+        promotions: &PromotionRun,
+        max_attempts: u32,
+    ) -> (AccrualChunkSummary, Vec<AccountAccrual>, i64) {
+        let clock = Instant::now();
+        let mut summary = AccrualChunkSummary {
+            chunk_index,
+            first_account_id: accounts.first().map(|a| a.id).unwrap_or_default(),
+            last_account_id: accounts.last().map(|a| a.id).unwrap_or_default(),
+            accounts_processed: 0,
+            accounts_already_accrued: 0,
+            total_interest_accrued: Decimal::ZERO,
+            attempts: 0,
+            duration_ms: 0,
+            error: None,
+        };
+        let mut accruals = vec![];
+
+        while summary.attempts < max_attempts.max(1) {
+            summary.attempts += 1;
+            match self.try_accrue_chunk(&accounts, processing_date, promotions).await {
+                Ok((applied, already_accrued)) => {
+                    summary.accounts_processed = applied.len() as i64;
+                    summary.accounts_already_accrued = already_accrued;
+                    summary.total_interest_accrued = applied.iter().map(|a| a.daily_interest).sum();
+                    summary.error = None;
+                    accruals = applied;
+                    break;
+                }
+                Err(e) => {
+                    tracing::warn!("Interest accrual chunk {chunk_index} attempt {} failed: {e}", summary.attempts);
+                    summary.error = Some(e.to_string());
+                }
+            }
         }
 
-        // Get tiered interest rate based on balance
-        let interest_rate = self.get_tiered_savings_rate(account.product_id, account.current_balance).await?;
+        summary.duration_ms = clock.elapsed().as_millis() as i64;
+        (summary, accruals, accounts.len() as i64)
+    }
 
-        // Calculate simple daily interest: (Balance * Rate) / 365
-        let daily_interest = (account.current_balance * interest_rate) / Decimal::from(365);
+    /// Calculate the chunk's accruals and apply them in a single repository call.
+    /// Returns the accruals applied and the number of accounts already accrued.
+    async fn try_accrue_chunk(
+        &self,
+        accounts: &[AccountModel],
+        processing_date: NaiveDate,
+        promotions: &PromotionRun,
+    ) -> BankingResult<(Vec<AccountAccrual>, i64)> {
+        let mut segment_codes: HashMap<Uuid, Vec<HeaplessString<50>>> = HashMap::new();
+        if promotions.needs_segments() {
+            let account_ids: Vec<Uuid> = accounts.iter().map(|a| a.id).collect();
+            for code in self.promotion_repository.find_account_segment_codes(&account_ids).await? {
+                segment_codes.entry(code.account_id).or_default().push(code.segment_code);
+            }
+        }
 
-        Ok(daily_interest)
-    }
+        let mut calculated = Vec::with_capacity(accounts.len());
+        for model in accounts {
+            let account = AccountMapper::from_model(model.clone())?;
+            let convention = self.day_count_convention(account.product_id).await?;
+            let accrual = self.calculate_account_accrual(&account, convention, processing_date).await?;
+            calculated.push((account, accrual, convention));
+        }
 
-    /// Calculate daily interest for loan accounts
-    async fn calculate_loan_daily_interest(&self, account: &banking_api::domain::Account) -> BankingResult<Decimal> {
-        let outstanding_principal = account.outstanding_principal.unwrap_or(Decimal::ZERO);
-        
-        if outstanding_principal <= Decimal::ZERO {
-            return Ok(Decimal::ZERO);
+        // Budget is only taken once every accrual of the chunk is calculated
+        let mut accruals = Vec::with_capacity(calculated.len());
+        for (account, mut accrual, convention) in calculated {
+            let codes = segment_codes.get(&account.id).map(Vec::as_slice).unwrap_or_default();
+            promotions.apply(&account, codes, &mut accrual, convention, processing_date);
+            // Custody fees accrue as negative interest
+            if accrual.daily_interest != Decimal::ZERO {
+                accruals.push(accrual);
+            }
         }
 
-        let loan_rate = account.loan_interest_rate.unwrap_or(Decimal::ZERO);
+        let now = Utc::now();
+        let models = accruals
+            .iter()
+            .map(|accrual| AccountInterestAccrualModel {
+                account_id: accrual.account_id,
+                accrual_date: processing_date,
+                daily_interest: accrual.daily_interest,
+                interest_rate: accrual.interest_rate,
+                principal_balance: accrual.principal_balance,
+                promotion_id: accrual.promotion_id,
+                bonus_interest: accrual.bonus_interest,
+                created_at: now,
+            })
+            .collect();
+        let applied: HashSet<Uuid> = match self.account_repository.apply_interest_accruals(models).await {
+            Ok(applied) => applied.into_iter().collect(),
+            Err(e) => {
+                promotions.release(&accruals);
+                return Err(e);
+            }
+        };
 
-        // Calculate daily interest on outstanding principal
-        let daily_interest = (outstanding_principal * loan_rate) / Decimal::from(365);
+        let already_accrued = (accruals.len() - applied.len()) as i64;
+        let (accruals, skipped): (Vec<_>, Vec<_>) = accruals
+            .into_iter()
+            .partition(|accrual| applied.contains(&accrual.account_id));
+        promotions.release(&skipped);
+        Ok((accruals, already_accrued))
+    }
 
-        Ok(daily_interest)
+    /// Unrounded accrual of `date` for an account from its interest-bearing balance and rate
+    async fn calculate_account_accrual(
+        &self,
+        account: &Account,
+        convention: DayCountConvention,
+        date: NaiveDate,
+    ) -> BankingResult<AccountAccrual> {
+        let (principal_balance, interest_rate) = match account.account_type {
+            AccountType::Savings if account.current_balance > Decimal::ZERO => {
+                let rate = self.get_tiered_savings_rate(account.product_id, account.current_balance).await?;
+                (account.current_balance, rate)
+            }
+            AccountType::Loan => (
+                account.outstanding_principal.unwrap_or(Decimal::ZERO).max(Decimal::ZERO),
+                account.loan_interest_rate.unwrap_or(Decimal::ZERO),
+            ),
+            // Overdraft debit interest is accrued by the CASA service, split at the
+            // overdraft limit; only the days the balance is above the threshold accrue a fee
+            AccountType::Current => self.custody_fee_basis(account).await?.unwrap_or((Decimal::ZERO, Decimal::ZERO)),
+            _ => (Decimal::ZERO, Decimal::ZERO),
+        };
+
+        Ok(AccountAccrual {
+            account_id: account.id,
+            daily_interest: convention.daily_accrual(principal_balance, interest_rate, date),
+            interest_rate,
+            principal_balance,
+            promotion_id: None,
+            bonus_interest: Decimal::ZERO,
+        })
     }
 
-    /// Calculate daily overdraft interest for current accounts
-    async fn calculate_overdraft_daily_interest(&self, account: &banking_api::domain::Account) -> BankingResult<Decimal> {
-        if account.current_balance >= Decimal::ZERO {
-            return Ok(Decimal::ZERO);
+    /// Day count convention of the product an account belongs to
+    async fn day_count_convention(&self, product_id: Uuid) -> BankingResult<DayCountConvention> {
+        let product = self.product_repository.find_product_by_id(product_id).await?
+            .ok_or(BankingError::ProductNotFound(product_id))?;
+        Ok(ProductRulesMapper::from_db(product.rules).day_count_convention)
+    }
+
+    /// Part of a current account balance above the product's custody fee
+    /// threshold and the negative rate it accrues at
+    async fn custody_fee_basis(&self, account: &Account) -> BankingResult<Option<(Decimal, Decimal)>> {
+        let product = self.product_repository.find_product_by_id(account.product_id).await?
+            .ok_or(BankingError::ProductNotFound(account.product_id))?;
+        Ok(ProductRulesMapper::from_db(product.rules).custody_fee_basis(account.current_balance))
+    }
+
+    /// Whether interest accrues on a date under the product's accrual frequency
+    async fn accrues_on(&self, frequency: &ProductAccrualFrequency, date: NaiveDate, account: &Account) -> BankingResult<bool> {
+        match frequency {
+            ProductAccrualFrequency::Daily => Ok(true),
+            ProductAccrualFrequency::BusinessDaysOnly => {
+                self.calendar_service.is_business_day(date, account.currency.as_str()).await
+            }
+            ProductAccrualFrequency::None => Ok(false),
         }
+    }
+
+    async fn interest_summary_as_of(&self, account_id: Uuid, as_of: NaiveDate) -> BankingResult<InterestSummaryView> {
+        let account_model = self.account_repository
+            .find_by_id(account_id)
+            .await?
+            .ok_or(BankingError::AccountNotFound(account_id))?;
+        let account = AccountMapper::from_model(account_model)?;
+
+        match account.account_type {
+            AccountType::Loan => Ok(InterestSummaryView::Loan(Self::loan_interest_summary(&account, as_of)?)),
+            _ => Ok(InterestSummaryView::Deposit(self.deposit_interest_summary(&account, as_of).await?)),
+        }
+    }
+
+    /// Year to date interest and a year end projection. The projection runs the
+    /// daily accrual of today (same rate, balance and day count) over the days
+    /// left in the year that the product accrues on.
+    async fn deposit_interest_summary(&self, account: &Account, as_of: NaiveDate) -> BankingResult<DepositInterestSummary> {
+        use chrono::Datelike;
+        let year_start = NaiveDate::from_ymd_opt(as_of.year(), 1, 1)
+            .ok_or(BankingError::DateCalculationError(format!("Invalid date: {as_of}")))?;
+        let year_end = NaiveDate::from_ymd_opt(as_of.year(), 12, 31)
+            .ok_or(BankingError::DateCalculationError(format!("Invalid date: {as_of}")))?;
+
+        let postings = self.transaction_repository
+            .find_by_account_date_range(account.id, year_start, as_of)
+            .await?;
+        let posted_year_to_date = |code: &str| -> Decimal {
+            postings
+                .iter()
+                .filter(|t| t.transaction_code.as_str() == code && t.status == banking_db::models::TransactionStatus::Posted)
+                .map(|t| t.amount)
+                .sum()
+        };
+        let capitalized_year_to_date = posted_year_to_date(INTEREST_POSTING_CODE);
+        let custody_fees_year_to_date = posted_year_to_date(CUSTODY_FEE_POSTING_CODE);
 
-        let overdraft_amount = account.current_balance.abs();
-        
-        // Get overdraft interest rate from product catalog
         let product = self.product_repository.find_product_by_id(account.product_id).await?
             .ok_or(BankingError::ProductNotFound(account.product_id))?;
-        let product_rules = product.rules;
-        let overdraft_rate = product_rules.overdraft_interest_rate.unwrap_or(Decimal::ZERO);
+        let convention = ProductRulesMapper::from_db(product.rules.clone()).day_count_convention;
+        let accrual = self.calculate_account_accrual(account, convention, as_of).await?;
+
+        let projection_from = as_of + chrono::Duration::days(1);
+        let mut accrual_days = 0;
+        let mut projected_accrual = Decimal::ZERO;
+        let mut date = projection_from;
+        while date <= year_end {
+            if self.accrues_on(&product.rules.accrual_frequency, date, account).await? {
+                accrual_days += 1;
+                projected_accrual += convention.daily_accrual(accrual.principal_balance, accrual.interest_rate, date);
+            }
+            date += chrono::Duration::days(1);
+        }
+
+        let projected_accrual_to_year_end = round_to_minor_units(projected_accrual, &account.currency);
+        Ok(DepositInterestSummary {
+            account_id: account.id,
+            currency: account.currency.clone().into(),
+            as_of,
+            capitalized_year_to_date,
+            custody_fees_year_to_date,
+            accrued_not_capitalized: account.accrued_interest,
+            accrual_kind: DepositAccrualKind::of(if accrual.daily_interest.is_zero() {
+                account.accrued_interest
+            } else {
+                accrual.daily_interest
+            }),
+            projected_accrual_to_year_end,
+            projected_year_total: round_to_minor_units(
+                capitalized_year_to_date - custody_fees_year_to_date + account.accrued_interest + projected_accrual_to_year_end,
+                &account.currency,
+            ),
+            is_estimate: true,
+            assumptions: InterestProjectionAssumptions {
+                annual_interest_rate: accrual.interest_rate,
+                principal_balance: accrual.principal_balance,
+                day_count_basis: convention.year_basis(projection_from) as u32,
+                projection_from,
+                projection_to: year_end,
+                accrual_days,
+            },
+        })
+    }
+
+    /// Loan payment history is not stored, so the figures follow the amortization
+    /// schedule: installments whose principal has been repaid count as paid, and
+    /// the remaining interest is that of a schedule on the outstanding principal
+    /// over the installments left.
+    fn loan_interest_summary(account: &Account, as_of: NaiveDate) -> BankingResult<LoanInterestSummary> {
+        let outstanding_principal = account.outstanding_principal.unwrap_or(Decimal::ZERO).max(Decimal::ZERO);
+        let annual_interest_rate = account.loan_interest_rate.unwrap_or(Decimal::ZERO);
+        let term_months = account.loan_term_months.unwrap_or(0);
+        if term_months <= 0 {
+            return Err(BankingError::ValidationError {
+                field: "loan_term_months".to_string(),
+                message: format!("Loan account {} has no term", account.id),
+            });
+        }
 
-        // Calculate daily overdraft interest
-        let daily_interest = (overdraft_amount * overdraft_rate) / Decimal::from(365);
+        let first_payment_date = account.disbursement_date
+            .unwrap_or(account.open_date)
+            .checked_add_months(Months::new(1))
+            .ok_or_else(|| BankingError::Internal("Cannot compute first payment date".to_string()))?;
+        // Account rates are fractions; amortization works in percent
+        let schedule = |principal_amount: Decimal, term_months: u32, first_payment_date: NaiveDate| {
+            AmortizationSchedule::generate(&GenerateAmortizationScheduleRequest {
+                loan_account_id: account.id,
+                principal_amount,
+                annual_interest_rate: annual_interest_rate * Decimal::from(100),
+                term_months,
+                first_payment_date,
+                payment_frequency: PaymentFrequency::Monthly,
+                calculation_method: AmortizationMethod::EqualInstallments,
+            })
+        };
 
-        Ok(daily_interest)
+        let original_schedule = schedule(
+            account.original_principal.unwrap_or(outstanding_principal),
+            term_months as u32,
+            first_payment_date,
+        );
+        let paid_installments: Vec<_> = original_schedule.schedule_entries
+            .iter()
+            .take_while(|e| e.closing_principal_balance.round_dp(2) >= outstanding_principal.round_dp(2))
+            .collect();
+        let interest_paid_to_date = paid_installments
+            .last()
+            .map(|e| e.cumulative_interest_paid)
+            .unwrap_or(Decimal::ZERO);
+        let remaining_installments = term_months as u32 - paid_installments.len() as u32;
+
+        let remaining_interest_to_maturity = match original_schedule.schedule_entries.get(paid_installments.len()) {
+            Some(next) if outstanding_principal > Decimal::ZERO => {
+                schedule(outstanding_principal, remaining_installments, next.due_date).total_interest
+            }
+            _ => Decimal::ZERO,
+        };
+
+        Ok(LoanInterestSummary {
+            account_id: account.id,
+            currency: account.currency.clone().into(),
+            as_of,
+            annual_interest_rate,
+            outstanding_principal,
+            interest_paid_to_date: interest_paid_to_date.round_dp(2),
+            accrued_not_due: account.accrued_interest,
+            remaining_interest_to_maturity: remaining_interest_to_maturity.round_dp(2),
+            remaining_installments,
+            maturity_date: account.maturity_date.or(Some(original_schedule.maturity_date)),
+            is_estimate: true,
+        })
     }
 
     /// Get tiered savings rate based on balance
@@ -400,10 +973,11 @@ impl InterestServiceImpl {
     }
 
     /// Calculate historical daily interest for a specific date
-    async fn calculate_historical_daily_interest(&self, account: &banking_api::domain::Account, _date: NaiveDate) -> BankingResult<Decimal> {
+    async fn calculate_historical_daily_interest(&self, account: &banking_api::domain::Account, date: NaiveDate) -> BankingResult<Decimal> {
         // In production, this would get the balance as of the specific date
         // For now, use current balance
-        self.calculate_daily_interest(account.id).await
+        let convention = self.day_count_convention(account.product_id).await?;
+        Ok(self.calculate_account_accrual(account, convention, date).await?.daily_interest)
     }
 
     /// Generate reference number for interest transactions
@@ -415,6 +989,13 @@ impl InterestServiceImpl {
         ))
     }
 
+    /// Custody fees are bank income, booked to the product's fee income GL
+    async fn get_custody_fee_gl_code(&self, product_id: Uuid) -> BankingResult<String> {
+        let gl_mapping = self.product_repository.find_gl_mapping_by_product_id(product_id).await?
+            .ok_or_else(|| BankingError::Internal(format!("No GL mapping for product {product_id}")))?;
+        Ok(gl_mapping.fee_income_code.to_string())
+    }
+
     /// Get GL code for interest transactions
     async fn get_interest_gl_code(&self, product_id: Uuid) -> BankingResult<String> {
         // In production, this would come from product catalog GL mapping
@@ -513,18 +1094,26 @@ impl InterestServiceImpl {
 mod tests {
     use super::*;
     use chrono::Utc;
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+    use std::time::Duration;
+    use banking_db::{DbAccountStatus, DbAccountType, DbSigningCondition};
+
+    const SLOW_CHUNK_DELAY: Duration = Duration::from_millis(200);
 
     #[tokio::test]
     async fn test_calculate_loan_installment() {
-        let mock_account_repo = Arc::new(MockAccountRepository);
-        let mock_transaction_repo = Arc::new(MockTransactionRepository);
-        let mock_product_client = Arc::new(MockProductRepository);
+        let mock_account_repo = Arc::new(MockAccountRepository::default());
+        let mock_transaction_repo = Arc::new(MockTransactionRepository::default());
+        let mock_product_client = Arc::new(MockProductRepository::default());
+        let mock_promotion_repo = Arc::new(MockPromotionRepository::new(mock_account_repo.clone(), vec![]));
         let mock_calendar = Arc::new(MockCalendarService);
 
         let service = InterestServiceImpl::new(
             mock_account_repo,
             mock_transaction_repo,
             mock_product_client,
+            mock_promotion_repo,
             mock_calendar,
         );
 
@@ -539,19 +1128,669 @@ mod tests {
         assert!(installment > Decimal::new(8500, 2) && installment < Decimal::new(8600, 2));
     }
 
+    fn accrual_service(account_repository: Arc<MockAccountRepository>) -> InterestServiceImpl {
+        promotion_service(account_repository, vec![]).0
+    }
+
+    fn promotion_service(
+        account_repository: Arc<MockAccountRepository>,
+        promotions: Vec<banking_db::models::ProductPromotionModel>,
+    ) -> (InterestServiceImpl, Arc<MockPromotionRepository>) {
+        let promotion_repository = Arc::new(MockPromotionRepository::new(account_repository.clone(), promotions));
+        let service = InterestServiceImpl::new(
+            account_repository,
+            Arc::new(MockTransactionRepository::default()),
+            Arc::new(MockProductRepository::default()),
+            promotion_repository.clone(),
+            Arc::new(MockCalendarService),
+        );
+        (service, promotion_repository)
+    }
+
+    /// Q1 2024 promotion adding one point to the savings rate for accounts opened in Q1
+    fn q1_promotion(product_id: Uuid, budget_cap: Option<Decimal>) -> banking_db::models::ProductPromotionModel {
+        banking_db::models::ProductPromotionModel {
+            id: Uuid::new_v4(),
+            product_id,
+            promotion_code: HeaplessString::try_from("Q1-SAVER").unwrap(),
+            name: HeaplessString::try_from("Q1 savings boost").unwrap(),
+            rate_kind: banking_db::models::DbPromotionRateKind::Bonus,
+            rate_value: Decimal::new(1, 2),
+            opened_from: NaiveDate::from_ymd_opt(2024, 1, 1),
+            opened_to: NaiveDate::from_ymd_opt(2024, 3, 31),
+            segment_code: None,
+            start_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+            budget_cap,
+            bonus_interest_attributed: Decimal::ZERO,
+            budget_exhausted_at: None,
+            is_active: true,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    /// Savings account of the product accruing 3.50 a day at the product rate
+    fn seed_savings_account(repository: &MockAccountRepository, product_id: Uuid, open_date: NaiveDate) -> Uuid {
+        let mut account = savings_account_model(Decimal::from(36_500));
+        account.product_id = product_id;
+        account.open_date = open_date;
+        let account_id = account.id;
+        repository.accounts.lock().unwrap().insert(account_id, account);
+        account_id
+    }
+
+    #[tokio::test]
+    async fn test_promotion_applies_to_accounts_opened_in_range_until_end_date() {
+        let repository = Arc::new(MockAccountRepository::default());
+        let product_id = Uuid::new_v4();
+        let new_account = seed_savings_account(&repository, product_id, NaiveDate::from_ymd_opt(2024, 1, 15).unwrap());
+        let old_account = seed_savings_account(&repository, product_id, NaiveDate::from_ymd_opt(2023, 6, 1).unwrap());
+        let mut loan = loan_account_model(Decimal::from(36_500), Decimal::new(10, 2));
+        loan.product_id = product_id;
+        let loan_id = loan.id;
+        repository.accounts.lock().unwrap().insert(loan_id, loan);
+        let promotion = q1_promotion(product_id, None);
+        let (service, _) = promotion_service(repository.clone(), vec![promotion.clone()]);
+
+        let report = service
+            .accrue_daily_interest(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(), AccrualOptions::default())
+            .await
+            .unwrap();
+        let accrual = |account_id: Uuid| report.account_accruals.iter().find(|a| a.account_id == account_id).unwrap().clone();
+        assert_eq!(accrual(new_account).daily_interest, Decimal::new(450, 2));
+        assert_eq!(accrual(new_account).bonus_interest, Decimal::ONE);
+        assert_eq!(accrual(new_account).promotion_id, Some(promotion.id));
+        assert_eq!(accrual(old_account).daily_interest, Decimal::new(350, 2));
+        assert_eq!(accrual(old_account).promotion_id, None);
+        // The loan keeps its account-level rate
+        assert_eq!(accrual(loan_id).daily_interest, Decimal::from(10));
+
+        // The day after the end date the product rate is back without any expiry run
+        let report = service
+            .accrue_daily_interest(NaiveDate::from_ymd_opt(2024, 4, 1).unwrap(), AccrualOptions::default())
+            .await
+            .unwrap();
+        let after = report.account_accruals.iter().find(|a| a.account_id == new_account).unwrap();
+        assert_eq!(after.daily_interest, Decimal::new(350, 2));
+        assert_eq!(after.promotion_id, None);
+    }
+
+    #[tokio::test]
+    async fn test_budget_cap_exhausted_mid_period_stops_the_bonus() {
+        let repository = Arc::new(MockAccountRepository::default());
+        let product_id = Uuid::new_v4();
+        let account_id = seed_savings_account(&repository, product_id, NaiveDate::from_ymd_opt(2024, 1, 15).unwrap());
+        let promotion = q1_promotion(product_id, Some(Decimal::new(250, 2)));
+        let (service, promotion_repository) = promotion_service(repository.clone(), vec![promotion.clone()]);
+
+        let mut reports = vec![];
+        for day in 1..=4 {
+            let date = NaiveDate::from_ymd_opt(2024, 2, day).unwrap();
+            reports.push(service.accrue_daily_interest(date, AccrualOptions::default()).await.unwrap());
+        }
+
+        let bonuses: Vec<Decimal> = reports.iter().map(|r| r.account_accruals[0].bonus_interest).collect();
+        assert_eq!(bonuses, vec![Decimal::ONE, Decimal::ONE, Decimal::new(50, 2), Decimal::ZERO]);
+        assert!(reports[1].exhausted_promotion_ids.is_empty());
+        assert_eq!(reports[2].exhausted_promotion_ids, vec![promotion.id]);
+        assert!(reports[3].exhausted_promotion_ids.is_empty());
+        assert_eq!(promotion_repository.exhausted.lock().unwrap().len(), 1);
+        assert_eq!(repository.bonus_by_promotion.lock().unwrap()[&promotion.id], Decimal::new(250, 2));
+        assert_eq!(
+            repository.accounts.lock().unwrap()[&account_id].accrued_interest,
+            Decimal::new(1650, 2)
+        );
+    }
+
+    /// Current account of a product charging 0.73% a year above 1,000,000
+    fn custody_fee_service(
+        account_repository: Arc<MockAccountRepository>,
+        balance: Decimal,
+    ) -> (InterestServiceImpl, Arc<MockTransactionRepository>, Uuid) {
+        let mut current = savings_account_model(balance);
+        current.account_type = DbAccountType::Current;
+        let account_id = current.id;
+        account_repository.accounts.lock().unwrap().insert(account_id, current);
+        let transaction_repository = Arc::new(MockTransactionRepository::default());
+        let service = InterestServiceImpl::new(
+            account_repository.clone(),
+            transaction_repository.clone(),
+            Arc::new(MockProductRepository {
+                custody_fee: Some((Decimal::from(1_000_000), Decimal::new(-73, 4))),
+                ..Default::default()
+            }),
+            Arc::new(MockPromotionRepository::new(account_repository, vec![])),
+            Arc::new(MockCalendarService),
+        );
+        (service, transaction_repository, account_id)
+    }
+
+    #[tokio::test]
+    async fn test_custody_fee_accrues_only_on_the_balance_above_threshold() {
+        let repository = Arc::new(MockAccountRepository::default());
+        let (service, _, account_id) = custody_fee_service(repository.clone(), Decimal::from(1_500_000));
+
+        // 500,000 above the threshold at -0.73% is -10.00 a day
+        let report = service
+            .accrue_daily_interest(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(), AccrualOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(report.account_accruals[0].daily_interest, Decimal::from(-10));
+        assert_eq!(report.total_interest_accrued, Decimal::from(-10));
+
+        // A day below the threshold accrues nothing
+        repository.accounts.lock().unwrap().get_mut(&account_id).unwrap().current_balance = Decimal::from(900_000);
+        let report = service
+            .accrue_daily_interest(NaiveDate::from_ymd_opt(2024, 3, 2).unwrap(), AccrualOptions::default())
+            .await
+            .unwrap();
+        assert!(report.account_accruals.is_empty());
+        assert_eq!(repository.accounts.lock().unwrap()[&account_id].accrued_interest, Decimal::from(-10));
+    }
+
+    #[tokio::test]
+    async fn test_negative_accrual_capitalizes_as_custody_fee_debit() {
+        let repository = Arc::new(MockAccountRepository::default());
+        let (service, transactions, account_id) = custody_fee_service(repository.clone(), Decimal::from(1_500_000));
+        repository.accounts.lock().unwrap().get_mut(&account_id).unwrap().accrued_interest = Decimal::from(-300);
+
+        service.post_periodic_interest(account_id).await.unwrap();
+
+        let created = transactions.created.lock().unwrap();
+        assert_eq!(created.len(), 1);
+        assert_eq!(created[0].transaction_code.as_str(), CUSTODY_FEE_POSTING_CODE);
+        assert_eq!(created[0].transaction_type, banking_db::models::TransactionType::Debit);
+        assert_eq!(created[0].amount, Decimal::from(300));
+        assert_eq!(created[0].gl_code.as_str(), "7200");
+        assert!(created[0].description.starts_with("Custody fee"));
+        let account = repository.accounts.lock().unwrap()[&account_id].clone();
+        assert_eq!(account.current_balance, Decimal::from(1_499_700));
+        assert_eq!(account.accrued_interest, Decimal::ZERO);
+    }
+
+    /// Loan accounts accruing 10.00 a day each, returned in id order
+    fn seed_loan_accounts(repository: &MockAccountRepository, count: usize) -> Vec<Uuid> {
+        let mut accounts = repository.accounts.lock().unwrap();
+        for _ in 0..count {
+            let account = loan_account_model(Decimal::from(36_500), Decimal::new(10, 2));
+            accounts.insert(account.id, account);
+        }
+        accounts.keys().copied().collect()
+    }
+
+    fn loan_account_model(outstanding_principal: Decimal, loan_interest_rate: Decimal) -> AccountModel {
+        AccountModel {
+            id: Uuid::new_v4(),
+            product_id: Uuid::new_v4(),
+            account_type: DbAccountType::Loan,
+            account_status: DbAccountStatus::Active,
+            signing_condition: DbSigningCondition::None,
+            currency: HeaplessString::try_from("USD").unwrap(),
+            open_date: NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+            domicile_agency_branch_id: Uuid::new_v4(),
+            account_number: HeaplessString::try_from("10005000010000000004262").unwrap(),
+            gl_code_suffix: None,
+            current_balance: Decimal::ZERO,
+            available_balance: Decimal::ZERO,
+            accrued_interest: Decimal::ZERO,
+            overdraft_limit: None,
+            original_principal: Some(outstanding_principal),
+            outstanding_principal: Some(outstanding_principal),
+            loan_interest_rate: Some(loan_interest_rate),
+            loan_term_months: Some(12),
+            disbursement_date: None,
+            maturity_date: None,
+            installment_amount: None,
+            next_due_date: None,
+            penalty_rate: None,
+            collateral_id: None,
+            loan_purpose_id: None,
+            close_date: None,
+            last_activity_date: None,
+            dormancy_threshold_days: None,
+            reactivation_required: false,
+            pending_closure_reason_id: None,
+            last_disbursement_instruction_id: None,
+            status_changed_by_person_id: None,
+            status_change_reason_id: None,
+            status_change_timestamp: None,
+            most_significant_account_hold_id: None,
+            account_ownership_id: None,
+            access01_account_relationship_id: None,
+            access02_account_relationship_id: None,
+            access03_account_relationship_id: None,
+            access04_account_relationship_id: None,
+            access05_account_relationship_id: None,
+            access06_account_relationship_id: None,
+            access07_account_relationship_id: None,
+            access11_account_mandate_id: None,
+            access12_account_mandate_id: None,
+            access13_account_mandate_id: None,
+            access14_account_mandate_id: None,
+            access15_account_mandate_id: None,
+            access16_account_mandate_id: None,
+            access17_account_mandate_id: None,
+            interest01_ultimate_beneficiary_id: None,
+            interest02_ultimate_beneficiary_id: None,
+            interest03_ultimate_beneficiary_id: None,
+            interest04_ultimate_beneficiary_id: None,
+            interest05_ultimate_beneficiary_id: None,
+            interest06_ultimate_beneficiary_id: None,
+            interest07_ultimate_beneficiary_id: None,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    fn savings_account_model(balance: Decimal) -> AccountModel {
+        AccountModel {
+            account_type: DbAccountType::Savings,
+            current_balance: balance,
+            available_balance: balance,
+            original_principal: None,
+            outstanding_principal: None,
+            loan_interest_rate: None,
+            loan_term_months: None,
+            ..loan_account_model(Decimal::ZERO, Decimal::ZERO)
+        }
+    }
+
+    /// Savings product accruing every calendar day
+    fn product_model(product_id: Uuid) -> banking_db::models::ProductModel {
+        banking_db::models::ProductModel {
+            id: product_id,
+            name_l1: HeaplessString::try_from("Savings").unwrap(),
+            name_l2: HeaplessString::new(),
+            name_l3: HeaplessString::new(),
+            description: HeaplessString::new(),
+            is_active: true,
+            valid_from: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            valid_to: None,
+            product_type: banking_db::models::ProductType::CASA,
+            rules: banking_db::models::ProductRules {
+                minimum_balance: Decimal::ZERO,
+                maximum_balance: None,
+                daily_transaction_limit: None,
+                monthly_transaction_limit: None,
+                overdraft_allowed: false,
+                overdraft_limit: None,
+                interest_calculation_method: HeaplessString::try_from("DailyBalance").unwrap(),
+                interest_posting_frequency: banking_db::models::PostingFrequency::Monthly,
+                dormancy_threshold_days: 365,
+                minimum_opening_balance: Decimal::ZERO,
+                closure_fee: Decimal::ZERO,
+                maintenance_fee: None,
+                maintenance_fee_frequency: None,
+                default_dormancy_days: None,
+                default_overdraft_limit: None,
+                per_transaction_limit: None,
+                overdraft_interest_rate: None,
+                unauthorized_overdraft_interest_rate: None,
+                accrual_frequency: ProductAccrualFrequency::Daily,
+                day_count_convention: banking_db::models::DayCountConvention::Actual365Fixed,
+                guarantor_required: false,
+                custody_fee_threshold: None,
+                custody_fee_rate: None,
+                inactivity_fee_threshold_months: None,
+                inactivity_fee_item_id: None,
+                inactivity_fee_warning_months: None,
+                supported_currencies: Vec::new(),
+            },
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_year_end_projection_matches_running_accrual_forward() {
+        let repository = Arc::new(MockAccountRepository::default());
+        let mut savings = savings_account_model(Decimal::from(10_000));
+        savings.accrued_interest = Decimal::new(4250, 2);
+        let account_id = savings.id;
+        repository.accounts.lock().unwrap().insert(account_id, savings);
+        let service = accrual_service(repository.clone());
+
+        let as_of = NaiveDate::from_ymd_opt(2024, 12, 10).unwrap();
+        let InterestSummaryView::Deposit(summary) = service.interest_summary_as_of(account_id, as_of).await.unwrap() else {
+            panic!("Savings account must get a deposit summary");
+        };
+        assert!(summary.is_estimate);
+        assert_eq!(summary.assumptions.annual_interest_rate, Decimal::new(35, 3));
+        assert_eq!(summary.assumptions.day_count_basis, 365);
+        assert_eq!(summary.assumptions.accrual_days, 21);
+
+        // Run the real accrual over every day the projection covers
+        let mut date = summary.assumptions.projection_from;
+        while date <= summary.assumptions.projection_to {
+            service.accrue_daily_interest(date, AccrualOptions::default()).await.unwrap();
+            date += chrono::Duration::days(1);
+        }
+        let accrued = repository.accounts.lock().unwrap()[&account_id].accrued_interest;
+        assert_eq!(summary.projected_accrual_to_year_end, (accrued - Decimal::new(4250, 2)).round_dp(2));
+        assert_eq!(summary.projected_year_total, accrued.round_dp(2));
+    }
+
+    #[tokio::test]
+    async fn test_back_dated_accrual_delta_covers_the_window() {
+        let repository = Arc::new(MockAccountRepository::default());
+        // A deposit of 1,000.00 valued 10 June is posted on 14 June
+        let mut savings = savings_account_model(Decimal::from(11_000));
+        savings.accrued_interest = Decimal::from(5);
+        let account_id = savings.id;
+        repository.accounts.lock().unwrap().insert(account_id, savings);
+        let service = accrual_service(repository.clone());
+
+        let value_date = NaiveDate::from_ymd_opt(2024, 6, 10).unwrap();
+        let business_date = NaiveDate::from_ymd_opt(2024, 6, 14).unwrap();
+        let delta = service
+            .recalculate_back_dated_accrual(account_id, Decimal::from(1_000), value_date, business_date)
+            .await
+            .unwrap();
+        // 10, 11, 12 and 13 June accrued without the deposit
+        let daily = DayCountConvention::Actual365Fixed.daily_accrual(Decimal::from(1_000), Decimal::new(35, 3), value_date);
+        let expected = daily * Decimal::from(4);
+        assert_eq!(delta, expected);
+        assert_eq!(delta.round_dp(2), Decimal::new(38, 2));
+        assert_eq!(repository.accounts.lock().unwrap()[&account_id].accrued_interest, Decimal::from(5) + expected);
+
+        // A back-dated withdrawal takes the interest back off
+        let delta = service
+            .recalculate_back_dated_accrual(account_id, Decimal::from(-1_000), value_date, business_date)
+            .await
+            .unwrap();
+        assert_eq!(delta, -expected);
+    }
+
+    fn day_count_service(
+        account_repository: Arc<MockAccountRepository>,
+        day_count_convention: banking_db::models::DayCountConvention,
+    ) -> (InterestServiceImpl, Arc<MockTransactionRepository>) {
+        let transaction_repository = Arc::new(MockTransactionRepository::default());
+        let service = InterestServiceImpl::new(
+            account_repository.clone(),
+            transaction_repository.clone(),
+            Arc::new(MockProductRepository { day_count_convention, ..Default::default() }),
+            Arc::new(MockPromotionRepository::new(account_repository, vec![])),
+            Arc::new(MockCalendarService),
+        );
+        (service, transaction_repository)
+    }
+
+    #[tokio::test]
+    async fn test_daily_accrual_golden_figures_over_february() {
+        use banking_db::models::DayCountConvention as Db;
+
+        // 100,000.00 at 5% accrues 5,000.00 a year; February 2024 has 29 days, 2023 has 28
+        let golden = [
+            (Db::Actual365Fixed, 2024, Decimal::new(39730, 2)),
+            (Db::Actual365Fixed, 2023, Decimal::new(38360, 2)),
+            (Db::Actual360, 2024, Decimal::new(40281, 2)),
+            (Db::Actual360, 2023, Decimal::new(38892, 2)),
+            (Db::ActualActual, 2024, Decimal::new(39614, 2)),
+            (Db::ActualActual, 2023, Decimal::new(38360, 2)),
+            // The last day of February also counts the days to the 30th
+            (Db::Thirty360, 2024, Decimal::new(41670, 2)),
+            (Db::Thirty360, 2023, Decimal::new(41670, 2)),
+        ];
+        for (convention, year, expected) in golden {
+            let repository = Arc::new(MockAccountRepository::default());
+            let loan = loan_account_model(Decimal::from(100_000), Decimal::new(5, 2));
+            let account_id = loan.id;
+            repository.accounts.lock().unwrap().insert(account_id, loan);
+            let (service, _) = day_count_service(repository, convention);
+
+            let mut total = Decimal::ZERO;
+            let mut date = NaiveDate::from_ymd_opt(year, 2, 1).unwrap();
+            while date < NaiveDate::from_ymd_opt(year, 3, 1).unwrap() {
+                total += service.calculate_daily_accrual(account_id, date).await.unwrap();
+                date += chrono::Duration::days(1);
+            }
+            assert_eq!(total, expected, "{convention:?} over February {year}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_daily_accrual_rounds_half_to_even_in_minor_units() {
+        let date = NaiveDate::from_ymd_opt(2024, 5, 2).unwrap();
+        // 13.685, 2.5 and 7.5 a day at 5% Actual/365F
+        for (currency, principal, expected) in [
+            ("USD", Decimal::new(9_990_050, 2), Decimal::new(1368, 2)),
+            ("XAF", Decimal::from(18_250), Decimal::from(2)),
+            ("XAF", Decimal::from(54_750), Decimal::from(8)),
+        ] {
+            let repository = Arc::new(MockAccountRepository::default());
+            let mut loan = loan_account_model(principal, Decimal::new(5, 2));
+            loan.currency = HeaplessString::try_from(currency).unwrap();
+            let account_id = loan.id;
+            repository.accounts.lock().unwrap().insert(account_id, loan);
+            let (service, _) = day_count_service(repository, banking_db::models::DayCountConvention::Actual365Fixed);
+
+            assert_eq!(service.calculate_daily_accrual(account_id, date).await.unwrap(), expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_post_accrued_interest_moves_rounded_accrual_into_balance() {
+        let repository = Arc::new(MockAccountRepository::default());
+        let mut savings = savings_account_model(Decimal::from(10_000));
+        savings.accrued_interest = Decimal::new(41_125, 3);
+        let account_id = savings.id;
+        repository.accounts.lock().unwrap().insert(account_id, savings);
+        let (service, transactions) = day_count_service(repository.clone(), banking_db::models::DayCountConvention::Actual360);
+        let period_end = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+
+        let posted = service.post_accrued_interest(account_id, period_end).await.unwrap();
+
+        assert_eq!(posted, Decimal::new(4112, 2));
+        let created = transactions.created.lock().unwrap();
+        assert_eq!(created.len(), 1);
+        assert_eq!(created[0].transaction_code.as_str(), INTEREST_POSTING_CODE);
+        assert_eq!(created[0].amount, posted);
+        assert_eq!(created[0].value_date, period_end);
+        let account = repository.accounts.lock().unwrap()[&account_id].clone();
+        assert_eq!(account.current_balance, Decimal::new(1_004_112, 2));
+        assert_eq!(account.available_balance, Decimal::new(1_004_112, 2));
+        // Half a minor unit rounded off stays accrued for the next period
+        assert_eq!(account.accrued_interest, Decimal::new(5, 3));
+
+        // Nothing left to post in the minor units of the currency
+        drop(created);
+        assert_eq!(service.post_accrued_interest(account_id, period_end).await.unwrap(), Decimal::ZERO);
+        assert_eq!(transactions.created.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_loan_summary_splits_scheduled_interest_at_outstanding_principal() {
+        let repository = Arc::new(MockAccountRepository::default());
+        let mut loan = loan_account_model(Decimal::from(12_000), Decimal::new(12, 2));
+        loan.disbursement_date = Some(loan.open_date);
+        let full_schedule = AmortizationSchedule::generate(&GenerateAmortizationScheduleRequest {
+            loan_account_id: loan.id,
+            principal_amount: Decimal::from(12_000),
+            annual_interest_rate: Decimal::from(12),
+            term_months: 12,
+            first_payment_date: NaiveDate::from_ymd_opt(2024, 2, 15).unwrap(),
+            payment_frequency: PaymentFrequency::Monthly,
+            calculation_method: AmortizationMethod::EqualInstallments,
+        });
+        // Three installments repaid
+        let third = &full_schedule.schedule_entries[2];
+        loan.outstanding_principal = Some(third.closing_principal_balance);
+        let account_id = loan.id;
+        repository.accounts.lock().unwrap().insert(account_id, loan);
+        let service = accrual_service(repository);
+
+        let as_of = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        let InterestSummaryView::Loan(summary) = service.interest_summary_as_of(account_id, as_of).await.unwrap() else {
+            panic!("Loan account must get a loan summary");
+        };
+        assert_eq!(summary.remaining_installments, 9);
+        assert_eq!(summary.interest_paid_to_date, third.cumulative_interest_paid.round_dp(2));
+        let remaining_interest = full_schedule.total_interest - third.cumulative_interest_paid;
+        assert!((summary.remaining_interest_to_maturity - remaining_interest).abs() < Decimal::new(1, 2));
+    }
+
+    #[tokio::test]
+    async fn test_slow_chunk_does_not_hold_back_other_chunks() {
+        let repository = Arc::new(MockAccountRepository::default());
+        let account_ids = seed_loan_accounts(&repository, 8);
+        *repository.slow_account.lock().unwrap() = Some(account_ids[0]);
+        let service = accrual_service(repository.clone());
+
+        let options = AccrualOptions { parallelism: 2, chunk_size: 2, max_chunk_attempts: 1 };
+        let processing_date = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+        let report = service.accrue_daily_interest(processing_date, options).await.unwrap();
+
+        // The first chunk keeps one permit busy while the others go through the second
+        let completed = repository.completed_chunks.lock().unwrap().clone();
+        assert_eq!(completed.len(), 4);
+        assert_eq!(completed.last(), Some(&account_ids[0]));
+
+        assert_eq!(report.accounts_processed, 8);
+        assert_eq!(report.total_interest_accrued, Decimal::from(80));
+        assert_eq!(report.chunks.len(), 4);
+        assert!(report.chunks[0].duration_ms >= SLOW_CHUNK_DELAY.as_millis() as i64);
+        assert!(report.chunks[1..].iter().all(|c| c.duration_ms < SLOW_CHUNK_DELAY.as_millis() as i64));
+        assert!(report.wall_clock_ms >= report.chunks[0].duration_ms);
+    }
+
+    #[tokio::test]
+    async fn test_retried_chunks_accrue_each_account_exactly_once() {
+        let repository = Arc::new(MockAccountRepository::default());
+        let account_ids = seed_loan_accounts(&repository, 12);
+        // Chunk 0 fails before its transaction commits, chunk 2 after
+        repository.fail_before_commit.lock().unwrap().insert(account_ids[1]);
+        repository.fail_after_commit.lock().unwrap().insert(account_ids[7]);
+        let service = accrual_service(repository.clone());
+
+        let options = AccrualOptions { parallelism: 3, chunk_size: 3, max_chunk_attempts: 3 };
+        let processing_date = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+        let report = service.accrue_daily_interest(processing_date, options).await.unwrap();
+
+        assert_eq!(report.accounts_failed, 0);
+        assert!(report.errors.is_empty());
+        assert_eq!(report.accounts_processed + report.accounts_already_accrued, 12);
+        assert_eq!(report.chunks[0].attempts, 2);
+        assert_eq!(report.chunks[1].attempts, 1);
+        assert_eq!(report.chunks[2].attempts, 2);
+        // The commit that was reported as failed is recognised on retry
+        assert_eq!(report.chunks[2].accounts_already_accrued, 3);
+
+        let accrued_once = |repository: &MockAccountRepository| {
+            repository.accounts.lock().unwrap().values().all(|a| a.accrued_interest == Decimal::from(10))
+        };
+        assert!(accrued_once(&repository));
+        assert_eq!(repository.accrual_markers.lock().unwrap().len(), 12);
+
+        // Rerunning the date accrues nothing more
+        let rerun = service.accrue_daily_interest(processing_date, options).await.unwrap();
+        assert_eq!(rerun.accounts_processed, 0);
+        assert_eq!(rerun.accounts_already_accrued, 12);
+        assert!(accrued_once(&repository));
+    }
+
     // Mock implementations for testing
-    struct MockAccountRepository;
-    struct MockTransactionRepository;
+    #[derive(Default)]
+    struct MockAccountRepository {
+        accounts: Mutex<BTreeMap<Uuid, AccountModel>>,
+        accrual_markers: Mutex<HashSet<(Uuid, NaiveDate)>>,
+        /// A chunk containing one of these accounts fails once, before or after committing
+        fail_before_commit: Mutex<HashSet<Uuid>>,
+        fail_after_commit: Mutex<HashSet<Uuid>>,
+        /// A chunk containing this account is delayed by SLOW_CHUNK_DELAY
+        slow_account: Mutex<Option<Uuid>>,
+        /// First account of each chunk, in commit order
+        completed_chunks: Mutex<Vec<Uuid>>,
+        /// Bonus interest of the applied accruals per promotion
+        bonus_by_promotion: Mutex<BTreeMap<Uuid, Decimal>>,
+    }
+
+    impl MockAccountRepository {
+        fn take_injected_failure(failures: &Mutex<HashSet<Uuid>>, accruals: &[AccountInterestAccrualModel]) -> bool {
+            let mut failures = failures.lock().unwrap();
+            accruals.iter().any(|a| failures.remove(&a.account_id))
+        }
+    }
+    #[derive(Default)]
+    struct MockTransactionRepository {
+        created: Mutex<Vec<banking_db::models::TransactionModel>>,
+    }
     struct MockCalendarService;
-    struct MockProductRepository;
+
+    #[derive(Default)]
+    struct MockProductRepository {
+        /// Custody fee threshold and rate; such products post every day
+        custody_fee: Option<(Decimal, Decimal)>,
+        day_count_convention: banking_db::models::DayCountConvention,
+    }
+
+    /// Promotions whose attributed bonus is read from the account repository's applied accruals
+    struct MockPromotionRepository {
+        account_repository: Arc<MockAccountRepository>,
+        promotions: Mutex<Vec<banking_db::models::ProductPromotionModel>>,
+        exhausted: Mutex<Vec<Uuid>>,
+    }
+
+    impl MockPromotionRepository {
+        fn new(account_repository: Arc<MockAccountRepository>, promotions: Vec<banking_db::models::ProductPromotionModel>) -> Self {
+            Self {
+                account_repository,
+                promotions: Mutex::new(promotions),
+                exhausted: Mutex::new(vec![]),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl PromotionRepository for MockPromotionRepository {
+        async fn find_promotions_in_effect(&self, date: NaiveDate) -> BankingResult<Vec<banking_db::models::ProductPromotionModel>> {
+            let attributed = self.account_repository.bonus_by_promotion.lock().unwrap().clone();
+            Ok(self.promotions
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|p| p.is_active && p.budget_exhausted_at.is_none() && p.start_date <= date && date <= p.end_date)
+                .map(|p| banking_db::models::ProductPromotionModel {
+                    bonus_interest_attributed: attributed.get(&p.id).copied().unwrap_or_default(),
+                    ..p.clone()
+                })
+                .collect())
+        }
+        async fn find_account_segment_codes(&self, _account_ids: &[Uuid]) -> BankingResult<Vec<banking_db::models::AccountSegmentCodeModel>> {
+            Ok(vec![])
+        }
+        async fn mark_budget_exhausted(&self, promotion_id: Uuid, exhausted_at: chrono::DateTime<Utc>) -> BankingResult<()> {
+            for promotion in self.promotions.lock().unwrap().iter_mut().filter(|p| p.id == promotion_id) {
+                promotion.budget_exhausted_at.get_or_insert(exhausted_at);
+            }
+            self.exhausted.lock().unwrap().push(promotion_id);
+            Ok(())
+        }
+        async fn create_promotion(&self, _promotion: banking_db::models::ProductPromotionModel) -> BankingResult<banking_db::models::ProductPromotionModel> { todo!() }
+        async fn update_promotion(&self, _promotion: banking_db::models::ProductPromotionModel) -> BankingResult<banking_db::models::ProductPromotionModel> { todo!() }
+        async fn find_promotion_by_id(&self, _promotion_id: Uuid) -> BankingResult<Option<banking_db::models::ProductPromotionModel>> { todo!() }
+        async fn find_promotions_by_product(&self, _product_id: Uuid) -> BankingResult<Vec<banking_db::models::ProductPromotionModel>> { todo!() }
+        async fn delete_promotion(&self, _promotion_id: Uuid) -> BankingResult<()> { todo!() }
+    }
 
     #[async_trait]
     impl ProductRepository for MockProductRepository {
         async fn create_product(&self, _product: banking_db::models::ProductModel) -> BankingResult<banking_db::models::ProductModel> {
             todo!()
         }
-        async fn find_product_by_id(&self, _product_id: Uuid) -> BankingResult<Option<banking_db::models::ProductModel>> {
-            todo!()
+        async fn find_product_by_id(&self, product_id: Uuid) -> BankingResult<Option<banking_db::models::ProductModel>> {
+            let mut product = product_model(product_id);
+            product.rules.day_count_convention = self.day_count_convention;
+            if let Some((threshold, rate)) = self.custody_fee {
+                product.rules.custody_fee_threshold = Some(threshold);
+                product.rules.custody_fee_rate = Some(rate);
+                product.rules.interest_posting_frequency = banking_db::models::PostingFrequency::Daily;
+            }
+            Ok(Some(product))
         }
         async fn update_product(&self, _product: banking_db::models::ProductModel) -> BankingResult<banking_db::models::ProductModel> {
             todo!()
@@ -569,21 +1808,69 @@ mod tests {
             todo!()
         }
         async fn find_interest_rate_tiers_by_product_id(&self, _product_id: Uuid) -> BankingResult<Vec<banking_db::models::product::InterestRateTierModel>> {
-            Ok(vec![])
-        }
-        async fn find_gl_mapping_by_product_id(&self, _product_id: Uuid) -> BankingResult<Option<banking_db::models::product::GlMappingModel>> {
-            Ok(None)
+            Ok(vec![banking_db::models::product::InterestRateTierModel {
+                minimum_balance: Decimal::ZERO,
+                maximum_balance: None,
+                interest_rate: Decimal::new(35, 3),
+                tier_name: HeaplessString::try_from("Standard").unwrap(),
+            }])
+        }
+        async fn find_gl_mapping_by_product_id(&self, product_id: Uuid) -> BankingResult<Option<banking_db::models::product::GlMappingModel>> {
+            Ok(Some(banking_db::models::product::GlMappingModel {
+                product_id,
+                customer_account_code: HeaplessString::try_from("2100").unwrap(),
+                interest_expense_code: HeaplessString::try_from("6100").unwrap(),
+                fee_income_code: HeaplessString::try_from("7200").unwrap(),
+                overdraft_code: None,
+            }))
         }
     }
 
     #[async_trait]
     impl AccountRepository for MockAccountRepository {
-        async fn find_by_id(&self, _account_id: Uuid) -> BankingResult<Option<banking_db::models::AccountModel>> {
-            Ok(None)
+        async fn find_by_id(&self, account_id: Uuid) -> BankingResult<Option<banking_db::models::AccountModel>> {
+            Ok(self.accounts.lock().unwrap().get(&account_id).cloned())
+        }
+        async fn update_balance(
+            &self,
+            account_id: Uuid,
+            current_balance: Decimal,
+            available_balance: Decimal,
+            _change_source: banking_db::models::DbBalanceChangeSource,
+            _transaction_id: Option<Uuid>,
+            _changed_by: Uuid,
+        ) -> BankingResult<()> {
+            if let Some(account) = self.accounts.lock().unwrap().get_mut(&account_id) {
+                account.current_balance = current_balance;
+                account.available_balance = available_balance;
+            }
+            Ok(())
+        }
+        async fn get_balance_history(&self, _account_id: Uuid, _from: chrono::DateTime<Utc>, _to: chrono::DateTime<Utc>) -> BankingResult<Vec<banking_db::models::AccountBalanceChangeRecordModel>> { todo!() }
+        async fn reset_accrued_interest(&self, account_id: Uuid) -> BankingResult<()> {
+            if let Some(account) = self.accounts.lock().unwrap().get_mut(&account_id) {
+                account.accrued_interest = Decimal::ZERO;
+            }
+            Ok(())
+        }
+        async fn post_accrued_interest(
+            &self,
+            account_id: Uuid,
+            amount: Decimal,
+            _change_source: banking_db::models::DbBalanceChangeSource,
+            _transaction_id: Option<Uuid>,
+            _changed_by: Uuid,
+        ) -> BankingResult<()> {
+            let mut accounts = self.accounts.lock().unwrap();
+            let account = accounts.get_mut(&account_id).ok_or(BankingError::AccountNotFound(account_id))?;
+            account.current_balance += amount;
+            account.available_balance += amount;
+            account.accrued_interest -= amount;
+            Ok(())
         }
-        async fn update_balance(&self, _account_id: Uuid, _current_balance: Decimal, _available_balance: Decimal) -> BankingResult<()> { Ok(()) }
-        async fn reset_accrued_interest(&self, _account_id: Uuid) -> BankingResult<()> { Ok(()) }
         async fn exists(&self, _account_id: Uuid) -> BankingResult<bool> { Ok(true) }
+        async fn find_by_ids(&self, _account_ids: &[Uuid]) -> BankingResult<Vec<banking_db::models::AccountModel>> { todo!() }
+        async fn exist_by_ids(&self, _account_ids: &[Uuid]) -> BankingResult<Vec<(Uuid, bool)>> { todo!() }
         
         // Add all other required methods with todo!()
         async fn create(&self, _account: banking_db::models::AccountModel) -> BankingResult<banking_db::models::AccountModel> { todo!() }
@@ -592,11 +1879,57 @@ mod tests {
         async fn find_by_account_type(&self, _account_type: banking_db::models::DbAccountType) -> BankingResult<Vec<banking_db::models::AccountModel>> { Ok(vec![]) }
         async fn find_by_product_id(&self, _product_id: Uuid) -> BankingResult<Vec<banking_db::models::AccountModel>> { todo!() }
         async fn find_by_status(&self, _status: &str) -> BankingResult<Vec<banking_db::models::AccountModel>> { todo!() }
-        async fn find_dormancy_candidates(&self, _reference_date: chrono::NaiveDate, _threshold_days: i32) -> BankingResult<Vec<banking_db::models::AccountModel>> { todo!() }
+        async fn find_dormancy_candidates(&self, _reference_date: chrono::NaiveDate, _threshold_days: i32, _product_id: Option<Uuid>) -> BankingResult<Vec<banking_db::models::AccountModel>> { todo!() }
         async fn find_pending_closure(&self) -> BankingResult<Vec<banking_db::models::AccountModel>> { todo!() }
         async fn find_interest_bearing_accounts(&self) -> BankingResult<Vec<banking_db::models::AccountModel>> { todo!() }
-        async fn update_status(&self, _account_id: Uuid, _status: &str, _reason: &str, _changed_by: Uuid) -> BankingResult<()> { todo!() }
-        async fn update_accrued_interest(&self, _account_id: Uuid, _accrued_interest: Decimal) -> BankingResult<()> { todo!() }
+        async fn find_interest_bearing_accounts_after(&self, after_account_id: Option<Uuid>, limit: i64) -> BankingResult<Vec<banking_db::models::AccountModel>> {
+            let accounts = self.accounts.lock().unwrap();
+            Ok(accounts
+                .values()
+                .filter(|a| after_account_id.is_none_or(|after| a.id > after))
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+        async fn apply_interest_accruals(&self, accruals: Vec<AccountInterestAccrualModel>) -> BankingResult<Vec<Uuid>> {
+            let slow_account = *self.slow_account.lock().unwrap();
+            if slow_account.is_some_and(|slow| accruals.iter().any(|a| a.account_id == slow)) {
+                tokio::time::sleep(SLOW_CHUNK_DELAY).await;
+            }
+            if Self::take_injected_failure(&self.fail_before_commit, &accruals) {
+                return Err(BankingError::Internal("Injected failure before commit".to_string()));
+            }
+
+            let mut applied = vec![];
+            {
+                let mut markers = self.accrual_markers.lock().unwrap();
+                let mut accounts = self.accounts.lock().unwrap();
+                for accrual in &accruals {
+                    if markers.insert((accrual.account_id, accrual.accrual_date)) {
+                        accounts.get_mut(&accrual.account_id).unwrap().accrued_interest += accrual.daily_interest;
+                        if let Some(promotion_id) = accrual.promotion_id {
+                            *self.bonus_by_promotion.lock().unwrap().entry(promotion_id).or_default() += accrual.bonus_interest;
+                        }
+                        applied.push(accrual.account_id);
+                    }
+                }
+            }
+            if let Some(first) = accruals.first() {
+                self.completed_chunks.lock().unwrap().push(first.account_id);
+            }
+
+            if Self::take_injected_failure(&self.fail_after_commit, &accruals) {
+                return Err(BankingError::Internal("Injected failure after commit".to_string()));
+            }
+            Ok(applied)
+        }
+        async fn update_status(&self, _account_id: Uuid, _status: &str, _reason_id: Uuid, _changed_by: Uuid) -> BankingResult<()> { todo!() }
+        async fn update_status_legacy(&self, _account_id: Uuid, _status: &str, _reason: &str, _changed_by: Uuid) -> BankingResult<()> { todo!() }
+        async fn bulk_update_status(&self, _account_ids: &[Uuid], _status: &str, _reason_id: Uuid, _changed_by: Uuid) -> Vec<(Uuid, BankingResult<()>)> { todo!() }
+        async fn update_accrued_interest(&self, account_id: Uuid, accrued_interest: Decimal) -> BankingResult<()> {
+            self.accounts.lock().unwrap().get_mut(&account_id).unwrap().accrued_interest = accrued_interest;
+            Ok(())
+        }
         async fn create_ownership(&self, _ownership: banking_db::models::AccountOwnershipModel) -> BankingResult<banking_db::models::AccountOwnershipModel> { todo!() }
         async fn find_ownership_by_account(&self, _account_id: Uuid) -> BankingResult<Vec<banking_db::models::AccountOwnershipModel>> { todo!() }
         async fn find_accounts_by_owner(&self, _customer_id: Uuid) -> BankingResult<Vec<banking_db::models::AccountOwnershipModel>> { todo!() }
@@ -623,9 +1956,18 @@ mod tests {
         ) -> BankingResult<banking_db::models::AccountStatusChangeRecordModel> {
             todo!()
         }
+        async fn save_balance_snapshot(&self, _snapshot: banking_db::models::AccountBalanceSnapshotModel) -> BankingResult<banking_db::models::AccountBalanceSnapshotModel> { todo!() }
+        async fn find_latest_balance_snapshot_before(&self, _account_id: Uuid, _before_date: chrono::NaiveDate) -> BankingResult<Option<banking_db::models::AccountBalanceSnapshotModel>> { todo!() }
+        async fn find_balance_snapshots_by_date(&self, _snapshot_date: chrono::NaiveDate) -> BankingResult<Vec<banking_db::models::AccountBalanceSnapshotModel>> { todo!() }
+        async fn save_loan_penalty_accrual(&self, _accrual: banking_db::models::LoanPenaltyAccrualModel) -> BankingResult<banking_db::models::LoanPenaltyAccrualModel> { todo!() }
+        async fn find_latest_loan_penalty_accrual_before(&self, _loan_account_id: Uuid, _before_date: chrono::NaiveDate) -> BankingResult<Option<banking_db::models::LoanPenaltyAccrualModel>> { todo!() }
+        async fn save_overdraft_interest_accrual(&self, _accrual: banking_db::models::casa::OverdraftInterestAccrual) -> BankingResult<banking_db::models::casa::OverdraftInterestAccrual> { todo!() }
+        async fn find_latest_overdraft_interest_accrual_before(&self, _account_id: Uuid, _before_date: chrono::NaiveDate) -> BankingResult<Option<banking_db::models::casa::OverdraftInterestAccrual>> { todo!() }
         async fn count_by_customer(&self, _customer_id: Uuid) -> BankingResult<i64> { todo!() }
         async fn count_by_product(&self, _product_id: Uuid) -> BankingResult<i64> { todo!() }
         async fn list(&self, _limit: i64, _offset: i64) -> BankingResult<Vec<banking_db::models::AccountModel>> { todo!() }
+        async fn find_by_account_number(&self, _account_number: &str) -> BankingResult<Option<banking_db::models::AccountModel>> { todo!() }
+        async fn list_after(&self, _after_account_id: Option<Uuid>, _limit: i64) -> BankingResult<Vec<banking_db::models::AccountModel>> { todo!() }
         async fn count(&self) -> BankingResult<i64> { todo!() }
         async fn update_last_activity_date(&self, _account_id: Uuid, _activity_date: chrono::NaiveDate) -> BankingResult<()> { todo!() }
 
@@ -634,8 +1976,16 @@ mod tests {
     #[async_trait]
     impl TransactionRepository for MockTransactionRepository {
         async fn create(&self, transaction: banking_db::models::TransactionModel) -> BankingResult<banking_db::models::TransactionModel> {
+            self.created.lock().unwrap().push(transaction.clone());
             Ok(transaction)
         }
+
+        async fn post_transaction(&self, _transaction: banking_db::models::TransactionModel) -> BankingResult<banking_db::models::TransactionModel> {
+            unimplemented!()
+        }
+        async fn post_approved_transaction(&self, _transaction_id: Uuid) -> BankingResult<Option<banking_db::models::TransactionModel>> {
+            unimplemented!()
+        }
         async fn update(&self, transaction: banking_db::models::TransactionModel) -> BankingResult<banking_db::models::TransactionModel> {
             Ok(transaction)
         }
@@ -648,9 +1998,15 @@ mod tests {
         async fn find_by_account_date_range(&self, _account_id: Uuid, _from: NaiveDate, _to: NaiveDate) -> BankingResult<Vec<banking_db::models::TransactionModel>> {
             Ok(Vec::new())
         }
+        async fn search(&self, _criteria: banking_db::models::TransactionSearchCriteriaModel) -> BankingResult<Vec<banking_db::models::TransactionModel>> {
+            Ok(Vec::new())
+        }
         async fn find_by_reference(&self, _reference_number: &str) -> BankingResult<Option<banking_db::models::TransactionModel>> {
             Ok(None)
         }
+        async fn find_by_idempotency_key(&self, _channel_id: &str, _idempotency_key: &str) -> BankingResult<Option<banking_db::models::TransactionModel>> {
+            Ok(None)
+        }
         async fn find_by_external_reference(&self, _external_reference: &str) -> BankingResult<Vec<banking_db::models::TransactionModel>> {
             Ok(Vec::new())
         }
@@ -735,6 +2091,12 @@ mod tests {
         async fn count(&self) -> BankingResult<i64> {
             Ok(0)
         }
+        async fn find_degraded(&self, _flags_mask: i32, _limit: i64) -> BankingResult<Vec<banking_db::models::TransactionModel>> {
+            Ok(Vec::new())
+        }
+        async fn update_degraded_flags(&self, _transaction_id: Uuid, _degraded_flags: i32) -> BankingResult<()> {
+            Ok(())
+        }
     }
 
     #[async_trait]
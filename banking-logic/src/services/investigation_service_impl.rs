@@ -0,0 +1,398 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{AccountTimeline, TimelineAccessRecord, TimelineEvent},
+    service::InvestigationService,
+};
+use banking_db::models::{DbTimelineSource, TimelineCursorModel};
+use banking_db::repository::InvestigationRepository;
+use crate::config::BankingConfig;
+use crate::mappers::InvestigationMapper;
+
+/// Sources merged into every timeline; transactions are added separately,
+/// one row per transaction or one per day
+const TIMELINE_SOURCES: [DbTimelineSource; 6] = [
+    DbTimelineSource::Workflows,
+    DbTimelineSource::WorkflowSteps,
+    DbTimelineSource::StatusChanges,
+    DbTimelineSource::Holds,
+    DbTimelineSource::Restrictions,
+    DbTimelineSource::Notifications,
+];
+
+/// One source read page by page in timeline order
+struct TimelineStream {
+    source: DbTimelineSource,
+    page: VecDeque<TimelineEvent>,
+    after: Option<TimelineCursorModel>,
+    exhausted: bool,
+}
+
+impl TimelineStream {
+    fn new(source: DbTimelineSource) -> Self {
+        Self { source, page: VecDeque::new(), after: None, exhausted: false }
+    }
+}
+
+/// Production implementation of InvestigationService
+pub struct InvestigationServiceImpl {
+    investigation_repository: Arc<dyn InvestigationRepository>,
+    config: Arc<BankingConfig>,
+}
+
+impl InvestigationServiceImpl {
+    pub fn new(investigation_repository: Arc<dyn InvestigationRepository>, config: Arc<BankingConfig>) -> Self {
+        Self { investigation_repository, config }
+    }
+
+    async fn assemble_timeline(
+        &self,
+        account_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+        accessed_by_person_id: Uuid,
+        case_reference: &str,
+    ) -> BankingResult<AccountTimeline> {
+        if from > to {
+            return Err(BankingError::ValidationError {
+                field: "from".to_string(),
+                message: "Timeline start is after its end".to_string(),
+            });
+        }
+        let case_reference = HeaplessString::try_from(case_reference.trim()).map_err(|_| BankingError::ValidationError {
+            field: "case_reference".to_string(),
+            message: "Case reference too long".to_string(),
+        })?;
+        if case_reference.is_empty() {
+            return Err(BankingError::ValidationError {
+                field: "case_reference".to_string(),
+                message: "A case reference is required to read an account timeline".to_string(),
+            });
+        }
+        if !self.investigation_repository.account_exists(account_id).await? {
+            return Err(BankingError::AccountNotFound(account_id));
+        }
+
+        let window_start = from.and_hms_opt(0, 0, 0).unwrap().and_utc();
+        let window_end = to
+            .succ_opt()
+            .ok_or_else(|| BankingError::DateCalculationError(format!("No day after {to}")))?
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc();
+
+        let transaction_count = self.investigation_repository
+            .count_transactions(account_id, window_start, window_end)
+            .await?;
+        let transactions_summarized = transaction_count > self.config.investigation.transaction_summary_threshold;
+        let transaction_source = if transactions_summarized {
+            DbTimelineSource::TransactionDays
+        } else {
+            DbTimelineSource::Transactions
+        };
+
+        let mut streams: Vec<TimelineStream> = TIMELINE_SOURCES
+            .iter()
+            .copied()
+            .chain(std::iter::once(transaction_source))
+            .map(TimelineStream::new)
+            .collect();
+        let mut events = Vec::new();
+        loop {
+            for stream in streams.iter_mut() {
+                self.fill_stream(stream, account_id, window_start, window_end).await?;
+            }
+            let next = (0..streams.len())
+                .filter(|&index| !streams[index].page.is_empty())
+                .min_by_key(|&index| streams[index].page[0].sort_key());
+            let Some(index) = next else {
+                break;
+            };
+            events.extend(streams[index].page.pop_front());
+        }
+
+        Ok(AccountTimeline {
+            account_id,
+            from_date: from,
+            to_date: to,
+            generated_at: Utc::now(),
+            generated_by_person_id: accessed_by_person_id,
+            case_reference,
+            transaction_count,
+            transactions_summarized,
+            events,
+        })
+    }
+
+    /// Read the next page of the source once the buffered one is used up
+    async fn fill_stream(
+        &self,
+        stream: &mut TimelineStream,
+        account_id: Uuid,
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+    ) -> BankingResult<()> {
+        if !stream.page.is_empty() || stream.exhausted {
+            return Ok(());
+        }
+        let page_size = self.config.investigation.timeline_page_size;
+        let rows = self.investigation_repository
+            .find_timeline_page(stream.source, account_id, window_start, window_end, stream.after.take(), page_size)
+            .await?;
+
+        stream.exhausted = (rows.len() as i64) < page_size;
+        stream.after = rows.last().map(|last| TimelineCursorModel {
+            occurred_at: last.occurred_at,
+            category: last.category,
+            reference_id: last.reference_id,
+        });
+        stream.page = rows.into_iter().map(InvestigationMapper::event_from_model).collect();
+        Ok(())
+    }
+
+    async fn record_access(&self, timeline: &AccountTimeline, exported: bool) -> BankingResult<()> {
+        let record = TimelineAccessRecord {
+            id: Uuid::new_v4(),
+            account_id: timeline.account_id,
+            from_date: timeline.from_date,
+            to_date: timeline.to_date,
+            accessed_by_person_id: timeline.generated_by_person_id,
+            case_reference: timeline.case_reference.clone(),
+            events_returned: timeline.events.len() as i64,
+            exported,
+            accessed_at: timeline.generated_at,
+        };
+        self.investigation_repository
+            .record_access(InvestigationMapper::access_to_model(record))
+            .await?;
+        tracing::info!(
+            "Timeline of account {} read by {} for case {}",
+            timeline.account_id, timeline.generated_by_person_id, timeline.case_reference
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl InvestigationService for InvestigationServiceImpl {
+    async fn build_account_timeline(
+        &self,
+        account_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+        accessed_by_person_id: Uuid,
+        case_reference: &str,
+    ) -> BankingResult<Vec<TimelineEvent>> {
+        let timeline = self.assemble_timeline(account_id, from, to, accessed_by_person_id, case_reference).await?;
+        self.record_access(&timeline, false).await?;
+        Ok(timeline.events)
+    }
+
+    async fn export_account_timeline(
+        &self,
+        account_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+        accessed_by_person_id: Uuid,
+        case_reference: &str,
+    ) -> BankingResult<String> {
+        let timeline = self.assemble_timeline(account_id, from, to, accessed_by_person_id, case_reference).await?;
+        let export = serde_json::to_string_pretty(&timeline)
+            .map_err(|e| BankingError::Internal(format!("Failed to export account timeline: {e}")))?;
+        self.record_access(&timeline, true).await?;
+        Ok(export)
+    }
+
+    async fn find_timeline_access(&self, account_id: Uuid) -> BankingResult<Vec<TimelineAccessRecord>> {
+        let records = self.investigation_repository.find_access_by_account(account_id).await?;
+        Ok(records.into_iter().map(InvestigationMapper::access_from_model).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use chrono::TimeZone;
+    use banking_api::domain::TimelineCategory;
+    use banking_db::models::{DbTimelineCategory, TimelineAccessRecordModel, TimelineEventModel};
+
+    /// Rows per source, paged like the database: ordered and keyset-filtered
+    #[derive(Default)]
+    struct MockInvestigationRepository {
+        rows: Mutex<HashMap<DbTimelineSource, Vec<TimelineEventModel>>>,
+        page_reads: Mutex<Vec<DbTimelineSource>>,
+        access: Mutex<Vec<TimelineAccessRecordModel>>,
+    }
+
+    impl MockInvestigationRepository {
+        fn add(&self, source: DbTimelineSource, occurred_at: DateTime<Utc>, category: DbTimelineCategory, reference_id: Uuid) {
+            self.rows.lock().unwrap().entry(source).or_default().push(TimelineEventModel {
+                occurred_at,
+                category,
+                actor_person_id: None,
+                summary: HeaplessString::try_from(category.to_string().as_str()).unwrap(),
+                reference_id,
+            });
+        }
+    }
+
+    fn model_key(row: &TimelineEventModel) -> (DateTime<Utc>, String, Uuid) {
+        (row.occurred_at, row.category.to_string(), row.reference_id)
+    }
+
+    #[async_trait]
+    impl InvestigationRepository for MockInvestigationRepository {
+        async fn account_exists(&self, _account_id: Uuid) -> BankingResult<bool> {
+            Ok(true)
+        }
+
+        async fn find_timeline_page(
+            &self,
+            source: DbTimelineSource,
+            _account_id: Uuid,
+            from: DateTime<Utc>,
+            to: DateTime<Utc>,
+            after: Option<TimelineCursorModel>,
+            limit: i64,
+        ) -> BankingResult<Vec<TimelineEventModel>> {
+            self.page_reads.lock().unwrap().push(source);
+            let after = after.map(|cursor| (cursor.occurred_at, cursor.category.to_string(), cursor.reference_id));
+            let mut rows: Vec<TimelineEventModel> = self.rows.lock().unwrap()
+                .get(&source)
+                .cloned()
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|row| row.occurred_at >= from && row.occurred_at < to)
+                .filter(|row| after.as_ref().is_none_or(|after| model_key(row) > *after))
+                .collect();
+            rows.sort_by_key(model_key);
+            rows.truncate(limit as usize);
+            Ok(rows)
+        }
+
+        async fn count_transactions(&self, _account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> BankingResult<i64> {
+            Ok(self.rows.lock().unwrap()
+                .get(&DbTimelineSource::Transactions)
+                .map_or(0, |rows| rows.iter().filter(|row| row.occurred_at >= from && row.occurred_at < to).count() as i64))
+        }
+
+        async fn record_access(&self, record: TimelineAccessRecordModel) -> BankingResult<TimelineAccessRecordModel> {
+            self.access.lock().unwrap().push(record.clone());
+            Ok(record)
+        }
+
+        async fn find_access_by_account(&self, account_id: Uuid) -> BankingResult<Vec<TimelineAccessRecordModel>> {
+            Ok(self.access.lock().unwrap().iter().rev().filter(|r| r.account_id == account_id).cloned().collect())
+        }
+    }
+
+    fn config(transaction_summary_threshold: i64, timeline_page_size: i64) -> Arc<BankingConfig> {
+        let mut config = BankingConfig::default();
+        config.investigation.transaction_summary_threshold = transaction_summary_threshold;
+        config.investigation.timeline_page_size = timeline_page_size;
+        Arc::new(config)
+    }
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 6, day, hour, 0, 0).unwrap()
+    }
+
+    fn june(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_timeline_merges_sources_chronologically_with_stable_ties() {
+        let repository = Arc::new(MockInvestigationRepository::default());
+        let workflow_id = Uuid::new_v4();
+        let hold_id = Uuid::new_v4();
+        let (first_txn, second_txn) = (Uuid::from_u128(1), Uuid::from_u128(2));
+        repository.add(DbTimelineSource::Workflows, at(3, 9), DbTimelineCategory::WorkflowCreated, workflow_id);
+        repository.add(DbTimelineSource::Workflows, at(5, 12), DbTimelineCategory::WorkflowCompleted, workflow_id);
+        repository.add(DbTimelineSource::WorkflowSteps, at(4, 10), DbTimelineCategory::WorkflowStep, workflow_id);
+        repository.add(DbTimelineSource::Holds, at(4, 10), DbTimelineCategory::HoldPlaced, hold_id);
+        repository.add(DbTimelineSource::Holds, at(6, 8), DbTimelineCategory::HoldReleased, hold_id);
+        repository.add(DbTimelineSource::StatusChanges, at(5, 12), DbTimelineCategory::StatusChange, Uuid::new_v4());
+        repository.add(DbTimelineSource::Transactions, at(4, 11), DbTimelineCategory::Transaction, second_txn);
+        repository.add(DbTimelineSource::Transactions, at(4, 11), DbTimelineCategory::Transaction, first_txn);
+        repository.add(DbTimelineSource::Notifications, at(6, 9), DbTimelineCategory::NotificationSent, Uuid::new_v4());
+        // Outside the requested dates
+        repository.add(DbTimelineSource::Transactions, at(8, 9), DbTimelineCategory::Transaction, Uuid::new_v4());
+
+        // A page size of one forces every source to be read in several pages
+        let service = InvestigationServiceImpl::new(repository.clone(), config(100, 1));
+        let account_id = Uuid::new_v4();
+        let investigator_id = Uuid::new_v4();
+        let events = service
+            .build_account_timeline(account_id, june(1), june(7), investigator_id, "CASE-2024-017")
+            .await
+            .unwrap();
+
+        let categories: Vec<TimelineCategory> = events.iter().map(|event| event.category).collect();
+        assert_eq!(
+            categories,
+            vec![
+                TimelineCategory::WorkflowCreated,
+                // Same instant: ordered by category name
+                TimelineCategory::HoldPlaced,
+                TimelineCategory::WorkflowStep,
+                TimelineCategory::Transaction,
+                TimelineCategory::Transaction,
+                TimelineCategory::StatusChange,
+                TimelineCategory::WorkflowCompleted,
+                TimelineCategory::HoldReleased,
+                TimelineCategory::NotificationSent,
+            ]
+        );
+        // Same instant and category: ordered by reference id
+        assert_eq!(events[3].reference_id, first_txn);
+        assert_eq!(events[4].reference_id, second_txn);
+        assert!(repository.page_reads.lock().unwrap().iter().filter(|s| **s == DbTimelineSource::Holds).count() >= 2);
+
+        let access = service.find_timeline_access(account_id).await.unwrap();
+        assert_eq!(access.len(), 1);
+        assert_eq!(access[0].accessed_by_person_id, investigator_id);
+        assert_eq!(access[0].events_returned, 9);
+        assert!(!access[0].exported);
+    }
+
+    #[tokio::test]
+    async fn test_high_volume_accounts_list_transactions_per_day() {
+        let repository = Arc::new(MockInvestigationRepository::default());
+        for hour in 0..4 {
+            repository.add(DbTimelineSource::Transactions, at(3, 8 + hour), DbTimelineCategory::Transaction, Uuid::new_v4());
+            repository.add(DbTimelineSource::Transactions, at(4, 8 + hour), DbTimelineCategory::Transaction, Uuid::new_v4());
+        }
+        repository.add(DbTimelineSource::TransactionDays, at(3, 8), DbTimelineCategory::TransactionSummary, Uuid::new_v4());
+        repository.add(DbTimelineSource::TransactionDays, at(4, 8), DbTimelineCategory::TransactionSummary, Uuid::new_v4());
+        let account_id = Uuid::new_v4();
+
+        // At the threshold transactions are listed one by one
+        let service = InvestigationServiceImpl::new(repository.clone(), config(8, 50));
+        let events = service.build_account_timeline(account_id, june(1), june(30), Uuid::new_v4(), "CASE-1").await.unwrap();
+        assert_eq!(events.len(), 8);
+        assert!(events.iter().all(|event| event.category == TimelineCategory::Transaction));
+
+        // Above it, one summary per day
+        let service = InvestigationServiceImpl::new(repository.clone(), config(7, 50));
+        let export = service.export_account_timeline(account_id, june(1), june(30), Uuid::new_v4(), "CASE-1").await.unwrap();
+        let timeline: AccountTimeline = serde_json::from_str(&export).unwrap();
+        assert!(timeline.transactions_summarized);
+        assert_eq!(timeline.transaction_count, 8);
+        assert_eq!(timeline.events.len(), 2);
+        assert!(timeline.events.iter().all(|event| event.category == TimelineCategory::TransactionSummary));
+        assert!(timeline.events[0].occurred_at < timeline.events[1].occurred_at);
+
+        let access = service.find_timeline_access(account_id).await.unwrap();
+        assert_eq!(access.len(), 2);
+        assert!(access[0].exported);
+    }
+}
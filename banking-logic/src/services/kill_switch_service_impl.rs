@@ -0,0 +1,483 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        KillSwitch, KillSwitchActivationRecord, KillSwitchRequest, KillSwitchScope, KillSwitchState, PostingActor,
+        Transaction, KILL_SWITCH_PERMISSION,
+    },
+    service::{KillSwitchService, OpsAlertSender},
+};
+use banking_db::repository::{AccountRepository, KillSwitchRepository};
+use crate::config::BankingConfig;
+use crate::mappers::KillSwitchMapper;
+
+/// Active switches as last loaded by this instance
+struct CachedSwitches {
+    switches: Vec<KillSwitch>,
+    loaded_at: Instant,
+}
+
+/// Production implementation of KillSwitchService
+pub struct KillSwitchServiceImpl {
+    kill_switch_repository: Arc<dyn KillSwitchRepository>,
+    account_repository: Arc<dyn AccountRepository>,
+    ops_alert_sender: Arc<dyn OpsAlertSender>,
+    config: Arc<BankingConfig>,
+    cache: Mutex<Option<CachedSwitches>>,
+}
+
+impl KillSwitchServiceImpl {
+    pub fn new(
+        kill_switch_repository: Arc<dyn KillSwitchRepository>,
+        account_repository: Arc<dyn AccountRepository>,
+        ops_alert_sender: Arc<dyn OpsAlertSender>,
+        config: Arc<BankingConfig>,
+    ) -> Self {
+        Self {
+            kill_switch_repository,
+            account_repository,
+            ops_alert_sender,
+            config,
+            cache: Mutex::new(None),
+        }
+    }
+
+    fn authorize(actor: &PostingActor, action: &str) -> BankingResult<()> {
+        if !actor.has_permission(KILL_SWITCH_PERMISSION) {
+            return Err(BankingError::UnauthorizedOperation(format!(
+                "{action} a kill switch requires permission {KILL_SWITCH_PERMISSION}"
+            )));
+        }
+        Ok(())
+    }
+
+    fn validate_request(request: &KillSwitchRequest) -> BankingResult<()> {
+        let invalid = |field: &str, message: &str| BankingError::ValidationError {
+            field: field.to_string(),
+            message: message.to_string(),
+        };
+        if request.reason.trim().is_empty() {
+            return Err(invalid("reason", "A kill switch needs a reason channels can show"));
+        }
+        if request.auto_expire_at.is_some_and(|expiry| expiry <= Utc::now()) {
+            return Err(invalid("auto_expire_at", "Expiry must be in the future"));
+        }
+        match (request.scope, &request.scope_value) {
+            (KillSwitchScope::Global, None) => Ok(()),
+            (KillSwitchScope::Global, Some(_)) => Err(invalid("scope_value", "A global switch takes no scope value")),
+            (_, None) => Err(invalid("scope_value", "Only a global switch may omit the scope value")),
+            (KillSwitchScope::Product, Some(value)) if Uuid::parse_str(value.as_str()).is_err() => {
+                Err(invalid("scope_value", "A product switch needs a product id"))
+            }
+            (_, Some(_)) => Ok(()),
+        }
+    }
+
+    /// Drop the cached switches so this instance sees its own changes at once
+    fn invalidate_cache(&self) {
+        *self.cache.lock().unwrap() = None;
+    }
+
+    /// Switches in force, reloaded once the cache is older than the TTL.
+    /// Switches found past their expiry on reload are recorded as expired.
+    async fn switches_in_force(&self) -> BankingResult<Vec<KillSwitch>> {
+        let ttl = Duration::from_millis(self.config.kill_switches.cache_ttl_millis);
+        let cached = self.cache
+            .lock()
+            .unwrap()
+            .as_ref()
+            .filter(|cached| cached.loaded_at.elapsed() < ttl)
+            .map(|cached| cached.switches.clone());
+        if let Some(switches) = cached {
+            let now = Utc::now();
+            return Ok(switches.into_iter().filter(|s| s.is_in_force(now)).collect());
+        }
+
+        let now = Utc::now();
+        self.expire_switches(now).await?;
+        let switches: Vec<KillSwitch> = self.kill_switch_repository
+            .find_active_kill_switches()
+            .await?
+            .into_iter()
+            .map(KillSwitchMapper::from_model)
+            .filter(|s| s.is_in_force(now))
+            .collect();
+        *self.cache.lock().unwrap() = Some(CachedSwitches {
+            switches: switches.clone(),
+            loaded_at: Instant::now(),
+        });
+        Ok(switches)
+    }
+
+    /// Failing to reach the distribution list never undoes the change
+    async fn notify_ops(&self, kill_switch: &KillSwitch, event: &str) {
+        let subject = format!("Kill switch {event}: {:?} {}", kill_switch.scope, scope_label(kill_switch));
+        let body = format!(
+            "Kill switch {} ({:?} {}) {event} at {}.\nReason: {}\nActivated by {} at {}{}",
+            kill_switch.id,
+            kill_switch.scope,
+            scope_label(kill_switch),
+            kill_switch.deactivated_at.unwrap_or(kill_switch.activated_at),
+            kill_switch.reason,
+            kill_switch.activated_by_person_id,
+            kill_switch.activated_at,
+            kill_switch.auto_expire_at.map(|expiry| format!(", expires {expiry}")).unwrap_or_default(),
+        );
+        let recipients = &self.config.kill_switches.ops_distribution_list;
+        if recipients.is_empty() {
+            tracing::error!("No ops distribution list configured; {subject}");
+            return;
+        }
+        if let Err(e) = self.ops_alert_sender.send_ops_alert(recipients, &subject, &body).await {
+            tracing::error!("Failed to notify ops of kill switch {}: {e}", kill_switch.id);
+        }
+    }
+}
+
+fn scope_label(kill_switch: &KillSwitch) -> &str {
+    kill_switch.scope_value.as_ref().map_or("(all postings)", |value| value.as_str())
+}
+
+#[async_trait]
+impl KillSwitchService for KillSwitchServiceImpl {
+    async fn activate_switch(&self, request: KillSwitchRequest, actor: &PostingActor) -> BankingResult<KillSwitch> {
+        Self::authorize(actor, "Activating")?;
+        Self::validate_request(&request)?;
+
+        let kill_switch = KillSwitch {
+            id: Uuid::new_v4(),
+            scope: request.scope,
+            scope_value: request.scope_value,
+            state: KillSwitchState::Active,
+            reason: request.reason,
+            activated_by_person_id: actor.person_id,
+            activated_at: Utc::now(),
+            auto_expire_at: request.auto_expire_at,
+            deactivated_by_person_id: None,
+            deactivated_at: None,
+        };
+        let created = KillSwitchMapper::from_model(
+            self.kill_switch_repository.create_kill_switch(KillSwitchMapper::to_model(kill_switch)).await?,
+        );
+        self.invalidate_cache();
+        tracing::warn!("Kill switch {} activated by {}: {:?} {}", created.id, actor.person_id, created.scope, scope_label(&created));
+        self.notify_ops(&created, "activated").await;
+        Ok(created)
+    }
+
+    async fn deactivate_switch(&self, switch_id: Uuid, actor: &PostingActor) -> BankingResult<KillSwitch> {
+        Self::authorize(actor, "Deactivating")?;
+        let mut kill_switch = self.kill_switch_repository
+            .find_kill_switch_by_id(switch_id)
+            .await?
+            .map(KillSwitchMapper::from_model)
+            .ok_or_else(|| BankingError::NotFound(format!("Kill switch {switch_id} not found")))?;
+        if kill_switch.state != KillSwitchState::Active {
+            return Err(BankingError::ValidationError {
+                field: "state".to_string(),
+                message: format!("Kill switch {switch_id} is already {:?}", kill_switch.state),
+            });
+        }
+
+        kill_switch.state = KillSwitchState::Deactivated;
+        kill_switch.deactivated_by_person_id = Some(actor.person_id);
+        kill_switch.deactivated_at = Some(Utc::now());
+        let updated = KillSwitchMapper::from_model(
+            self.kill_switch_repository.update_kill_switch(KillSwitchMapper::to_model(kill_switch)).await?,
+        );
+        self.invalidate_cache();
+        tracing::warn!("Kill switch {} deactivated by {}", switch_id, actor.person_id);
+        self.notify_ops(&updated, "deactivated").await;
+        Ok(updated)
+    }
+
+    async fn check_posting(&self, transaction: &Transaction) -> BankingResult<()> {
+        let switches = self.switches_in_force().await?;
+        if switches.is_empty() {
+            return Ok(());
+        }
+        // The account is only read when a product switch could apply
+        let product_id = if switches.iter().any(|s| s.scope == KillSwitchScope::Product) {
+            self.account_repository.find_by_id(transaction.account_id).await?.map(|a| a.product_id)
+        } else {
+            None
+        };
+
+        match switches.iter().find(|s| s.halts(transaction, product_id)) {
+            Some(kill_switch) => Err(BankingError::KillSwitchActive {
+                switch_id: kill_switch.id,
+                scope: kill_switch.scope,
+                scope_value: kill_switch.scope_value.as_ref().map(|value| value.to_string()),
+                reason: kill_switch.reason.to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    async fn expire_switches(&self, now: DateTime<Utc>) -> BankingResult<Vec<KillSwitch>> {
+        let mut expired = Vec::new();
+        for model in self.kill_switch_repository.find_active_kill_switches().await? {
+            let mut kill_switch = KillSwitchMapper::from_model(model);
+            let Some(expiry) = kill_switch.auto_expire_at.filter(|expiry| *expiry <= now) else {
+                continue;
+            };
+            kill_switch.state = KillSwitchState::Expired;
+            kill_switch.deactivated_at = Some(expiry);
+            let updated = KillSwitchMapper::from_model(
+                self.kill_switch_repository.update_kill_switch(KillSwitchMapper::to_model(kill_switch)).await?,
+            );
+            tracing::warn!("Kill switch {} expired at {}", updated.id, expiry);
+            self.notify_ops(&updated, "expired").await;
+            expired.push(updated);
+        }
+        if !expired.is_empty() {
+            self.invalidate_cache();
+        }
+        Ok(expired)
+    }
+
+    async fn activation_history(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> BankingResult<Vec<KillSwitchActivationRecord>> {
+        let now = Utc::now();
+        Ok(self.kill_switch_repository
+            .find_kill_switches_activated_between(from, to)
+            .await?
+            .into_iter()
+            .map(|model| {
+                let switch = KillSwitchMapper::from_model(model);
+                KillSwitchActivationRecord {
+                    duration_seconds: switch.duration_seconds(now),
+                    switch,
+                }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use crate::config::KillSwitchSettings;
+    use crate::services::test_doubles::InMemoryAccountRepository;
+    use banking_api::domain::{CurrencyCode, DegradedFlags, TransactionStatus, TransactionType};
+    use banking_db::models::KillSwitchModel;
+    use chrono::NaiveDate;
+    use heapless::String as HeaplessString;
+    use rust_decimal::Decimal;
+
+    const CACHE_TTL: Duration = Duration::from_millis(100);
+
+    #[derive(Default)]
+    struct MockKillSwitchRepository {
+        switches: Mutex<HashMap<Uuid, KillSwitchModel>>,
+    }
+
+    #[async_trait]
+    impl KillSwitchRepository for MockKillSwitchRepository {
+        async fn create_kill_switch(&self, kill_switch: KillSwitchModel) -> BankingResult<KillSwitchModel> {
+            self.switches.lock().unwrap().insert(kill_switch.id, kill_switch.clone());
+            Ok(kill_switch)
+        }
+        async fn update_kill_switch(&self, kill_switch: KillSwitchModel) -> BankingResult<KillSwitchModel> {
+            self.switches.lock().unwrap().insert(kill_switch.id, kill_switch.clone());
+            Ok(kill_switch)
+        }
+        async fn find_kill_switch_by_id(&self, kill_switch_id: Uuid) -> BankingResult<Option<KillSwitchModel>> {
+            Ok(self.switches.lock().unwrap().get(&kill_switch_id).cloned())
+        }
+        async fn find_active_kill_switches(&self) -> BankingResult<Vec<KillSwitchModel>> {
+            Ok(self.switches
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|s| s.state == banking_db::models::DbKillSwitchState::Active)
+                .cloned()
+                .collect())
+        }
+        async fn find_kill_switches_activated_between(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> BankingResult<Vec<KillSwitchModel>> {
+            Ok(self.switches
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|s| from <= s.activated_at && s.activated_at <= to)
+                .cloned()
+                .collect())
+        }
+    }
+
+    /// Records alerts as (recipients, subject)
+    #[derive(Default)]
+    struct MockOpsAlertSender {
+        alerts: Mutex<Vec<(Vec<String>, String)>>,
+    }
+
+    #[async_trait]
+    impl OpsAlertSender for MockOpsAlertSender {
+        async fn send_ops_alert(&self, recipients: &[String], subject: &str, _body: &str) -> BankingResult<()> {
+            self.alerts.lock().unwrap().push((recipients.to_vec(), subject.to_string()));
+            Ok(())
+        }
+    }
+
+    /// Service instance over a shared repository, as run by one node
+    fn instance(repository: Arc<MockKillSwitchRepository>, alerts: Arc<MockOpsAlertSender>) -> KillSwitchServiceImpl {
+        let config = BankingConfig {
+            kill_switches: KillSwitchSettings {
+                cache_ttl_millis: CACHE_TTL.as_millis() as u64,
+                ops_distribution_list: vec!["ops-oncall@bank.example".to_string()],
+            },
+            ..BankingConfig::default()
+        };
+        KillSwitchServiceImpl::new(repository, Arc::new(InMemoryAccountRepository::default()), alerts, Arc::new(config))
+    }
+
+    fn operator(permissions: &[&str]) -> PostingActor {
+        PostingActor {
+            person_id: Uuid::new_v4(),
+            permissions: permissions.iter().map(|p| HeaplessString::try_from(*p).unwrap()).collect(),
+        }
+    }
+
+    fn channel_request(channel: &str) -> KillSwitchRequest {
+        KillSwitchRequest {
+            scope: KillSwitchScope::Channel,
+            scope_value: Some(HeaplessString::try_from(channel).unwrap()),
+            reason: HeaplessString::try_from("Mobile gateway incident").unwrap(),
+            auto_expire_at: None,
+        }
+    }
+
+    fn posting(channel: &str) -> Transaction {
+        Transaction {
+            id: Uuid::new_v4(),
+            account_id: Uuid::new_v4(),
+            transaction_code: HeaplessString::try_from("EXT_TRF").unwrap(),
+            transaction_type: TransactionType::Debit,
+            amount: Decimal::from(100),
+            currency: CurrencyCode::try_from("XAF").unwrap(),
+            description: HeaplessString::try_from("External transfer").unwrap(),
+            channel_id: HeaplessString::try_from(channel).unwrap(),
+            terminal_id: None,
+            agent_person_id: None,
+            transaction_date: Utc::now(),
+            value_date: NaiveDate::from_ymd_opt(2024, 6, 14).unwrap(),
+            status: TransactionStatus::Pending,
+            reference_number: HeaplessString::try_from("REF-1").unwrap(),
+            external_reference: None,
+            gl_code: HeaplessString::try_from("2100").unwrap(),
+            requires_approval: false,
+            approval_status: None,
+            risk_score: None,
+            degraded_flags: DegradedFlags::NONE,
+            idempotency_key: None,
+            reversal_of_transaction_id: None,
+            fx_conversion: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_activation_elsewhere_is_visible_once_the_cache_ttl_passes() {
+        let repository = Arc::new(MockKillSwitchRepository::default());
+        let alerts = Arc::new(MockOpsAlertSender::default());
+        let posting_node = instance(repository.clone(), alerts.clone());
+        let ops_node = instance(repository, alerts);
+
+        // The posting node loads the empty switch set into its cache
+        assert!(posting_node.check_posting(&posting("MOBILE")).await.is_ok());
+
+        let kill_switch = ops_node
+            .activate_switch(channel_request("MOBILE"), &operator(&[KILL_SWITCH_PERMISSION]))
+            .await
+            .unwrap();
+        // The activating node sees its own switch at once
+        assert!(ops_node.check_posting(&posting("MOBILE")).await.is_err());
+        // The other node keeps its cache until the TTL passes
+        assert!(posting_node.check_posting(&posting("MOBILE")).await.is_ok());
+
+        tokio::time::sleep(CACHE_TTL + Duration::from_millis(20)).await;
+        match posting_node.check_posting(&posting("MOBILE")).await {
+            Err(BankingError::KillSwitchActive { switch_id, scope, scope_value, reason }) => {
+                assert_eq!(switch_id, kill_switch.id);
+                assert_eq!(scope, KillSwitchScope::Channel);
+                assert_eq!(scope_value.as_deref(), Some("MOBILE"));
+                assert_eq!(reason, "Mobile gateway incident");
+            }
+            other => panic!("Expected the kill switch to halt the posting, got {other:?}"),
+        }
+        // Other channels keep posting
+        assert!(posting_node.check_posting(&posting("BRANCH")).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_switch_past_its_expiry_deactivates_automatically() {
+        let repository = Arc::new(MockKillSwitchRepository::default());
+        let alerts = Arc::new(MockOpsAlertSender::default());
+        let service = instance(repository.clone(), alerts.clone());
+        let activated_at = Utc::now() - chrono::Duration::hours(2);
+        let kill_switch = KillSwitch {
+            id: Uuid::new_v4(),
+            scope: KillSwitchScope::Global,
+            scope_value: None,
+            state: KillSwitchState::Active,
+            reason: HeaplessString::try_from("Core migration window").unwrap(),
+            activated_by_person_id: Uuid::new_v4(),
+            activated_at,
+            auto_expire_at: Some(activated_at + chrono::Duration::minutes(90)),
+            deactivated_by_person_id: None,
+            deactivated_at: None,
+        };
+        repository.create_kill_switch(KillSwitchMapper::to_model(kill_switch.clone())).await.unwrap();
+
+        assert!(service.check_posting(&posting("BRANCH")).await.is_ok());
+        let stored = KillSwitchMapper::from_model(repository.find_kill_switch_by_id(kill_switch.id).await.unwrap().unwrap());
+        assert_eq!(stored.state, KillSwitchState::Expired);
+        assert_eq!(stored.deactivated_at, kill_switch.auto_expire_at);
+        assert_eq!(alerts.alerts.lock().unwrap().len(), 1);
+        assert!(alerts.alerts.lock().unwrap()[0].1.starts_with("Kill switch expired"));
+
+        // The audit reports the time the switch was in force, not the time until it was noticed
+        let history = service.activation_history(activated_at, Utc::now()).await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].duration_seconds, 90 * 60);
+
+        // An expired switch cannot be deactivated again
+        assert!(service.deactivate_switch(kill_switch.id, &operator(&[KILL_SWITCH_PERMISSION])).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_switches_need_the_permission_and_always_alert_ops() {
+        let repository = Arc::new(MockKillSwitchRepository::default());
+        let alerts = Arc::new(MockOpsAlertSender::default());
+        let service = instance(repository.clone(), alerts.clone());
+
+        let teller = operator(&["BackDatePosting"]);
+        assert!(matches!(
+            service.activate_switch(channel_request("MOBILE"), &teller).await,
+            Err(BankingError::UnauthorizedOperation(_))
+        ));
+        assert!(repository.switches.lock().unwrap().is_empty());
+        assert!(alerts.alerts.lock().unwrap().is_empty());
+
+        let duty_manager = operator(&[KILL_SWITCH_PERMISSION]);
+        let kill_switch = service.activate_switch(channel_request("MOBILE"), &duty_manager).await.unwrap();
+        assert!(matches!(
+            service.deactivate_switch(kill_switch.id, &teller).await,
+            Err(BankingError::UnauthorizedOperation(_))
+        ));
+        let deactivated = service.deactivate_switch(kill_switch.id, &duty_manager).await.unwrap();
+        assert_eq!(deactivated.deactivated_by_person_id, Some(duty_manager.person_id));
+        assert!(service.check_posting(&posting("MOBILE")).await.is_ok());
+
+        let alerts = alerts.alerts.lock().unwrap();
+        assert_eq!(alerts.len(), 2);
+        assert!(alerts.iter().all(|(recipients, _)| recipients == &vec!["ops-oncall@bank.example".to_string()]));
+        assert!(alerts[0].1.starts_with("Kill switch activated"));
+        assert!(alerts[1].1.starts_with("Kill switch deactivated"));
+    }
+}
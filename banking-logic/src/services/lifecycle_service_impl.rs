@@ -1,34 +1,56 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{NaiveDate, Utc};
 use heapless::String as HeaplessString;
 use rust_decimal::Decimal;
 use uuid::Uuid;
 
 use banking_api::{
     BankingResult,
-    service::{AccountLifecycleService, CalendarService, ComplianceCheckType, ComplianceCheckResult},
+    service::{
+        AccountLifecycleService, BundleService, CalendarService, ComplianceCheckType, ComplianceCheckResult,
+        DocumentRegistryService, InterestService, WelcomePackService,
+    },
     domain::{
         AccountWorkflow, WorkflowType, WorkflowStep, WorkflowStatus,
         AccountOpeningRequest, ClosureRequest, DormancyAssessment,
         FinalSettlement, AccountStatus, KycResult, AccountStatusChangeRecord,
+        ContentHash, DocumentLinkKind, ReasonId, ReasonedOperation,
+        Account, DisbursementInstructions, DisbursementMethod, FeeApplicationStatus,
     },
 };
-use banking_db::repository::{AccountRepository, WorkflowRepository};
+use banking_db::models::{AccountFinalSettlementModel, AccountModel, DbSettlementStatus};
+use banking_db::repository::{
+    AccountHoldRepository, AccountRepository, CustomerRepository, FeeRepository, ReasonAndPurposeRepository,
+    WorkflowRepository,
+};
 use crate::{
     mappers::{AccountMapper, WorkflowMapper},
+    validation::{AccountOpeningValidation, ReasonValidation},
     constants::*,
 };
 use banking_db::repository::ProductRepository;
 
+/// Dormancy threshold for products that do not set their own
+const DEFAULT_DORMANCY_THRESHOLD_DAYS: i32 = 90;
+
 /// Production implementation of AccountLifecycleService
 /// Handles comprehensive account lifecycle management with workflow orchestration
 pub struct AccountLifecycleServiceImpl {
     account_repository: Arc<dyn AccountRepository>,
     workflow_repository: Arc<dyn WorkflowRepository>,
     product_repository: Arc<dyn ProductRepository>,
+    customer_repository: Arc<dyn CustomerRepository>,
+    account_hold_repository: Arc<dyn AccountHoldRepository>,
+    fee_repository: Arc<dyn FeeRepository>,
+    interest_service: Arc<dyn InterestService>,
     #[allow(dead_code)]
     calendar_service: Arc<dyn CalendarService>,
+    welcome_pack_service: Arc<dyn WelcomePackService>,
+    document_registry_service: Arc<dyn DocumentRegistryService>,
+    bundle_service: Arc<dyn BundleService>,
+    reason_repository: Arc<dyn ReasonAndPurposeRepository>,
 }
 
 impl AccountLifecycleServiceImpl {
@@ -36,13 +58,29 @@ impl AccountLifecycleServiceImpl {
         account_repository: Arc<dyn AccountRepository>,
         workflow_repository: Arc<dyn WorkflowRepository>,
         product_repository: Arc<dyn ProductRepository>,
+        customer_repository: Arc<dyn CustomerRepository>,
+        account_hold_repository: Arc<dyn AccountHoldRepository>,
+        fee_repository: Arc<dyn FeeRepository>,
+        interest_service: Arc<dyn InterestService>,
         calendar_service: Arc<dyn CalendarService>,
+        welcome_pack_service: Arc<dyn WelcomePackService>,
+        document_registry_service: Arc<dyn DocumentRegistryService>,
+        bundle_service: Arc<dyn BundleService>,
+        reason_repository: Arc<dyn ReasonAndPurposeRepository>,
     ) -> Self {
         Self {
             account_repository,
             workflow_repository,
             product_repository,
+            customer_repository,
+            account_hold_repository,
+            fee_repository,
+            interest_service,
             calendar_service,
+            welcome_pack_service,
+            document_registry_service,
+            bundle_service,
+            reason_repository,
         }
     }
 }
@@ -51,8 +89,7 @@ impl AccountLifecycleServiceImpl {
 impl AccountLifecycleService for AccountLifecycleServiceImpl {
     /// Initiate account opening workflow with comprehensive validation
     async fn initiate_account_opening(&self, request: AccountOpeningRequest) -> BankingResult<AccountWorkflow> {
-        // Validate product eligibility
-        self.validate_product_eligibility(&request).await?;
+        self.validate_opening_request(&request).await?;
 
         // Create workflow
         let workflow = AccountWorkflow {
@@ -76,6 +113,14 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
         let workflow_model = self.to_workflow_model(&workflow);
         self.workflow_repository.create_workflow(&workflow_model).await?;
 
+        // Content attached before resolves to its registered document instead of a new one
+        let attached = self.document_registry_service
+            .attach_documents(DocumentLinkKind::Workflow, workflow.id, request.supporting_documents, request.initiated_by)
+            .await?;
+        self.document_registry_service
+            .attach_documents(DocumentLinkKind::Customer, request.customer_id, attached, request.initiated_by)
+            .await?;
+
         tracing::info!(
             "Account opening workflow {} initiated for customer {} with product {}",
             workflow.id, request.customer_id, request.product_id
@@ -101,7 +146,9 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
                 self.advance_workflow_step(
                     workflow.id,
                     WorkflowStep::DocumentVerification,
+                    SYSTEM_PERSON_ID,
                     "KYC verification completed successfully",
+                    Vec::new(),
                 ).await?;
 
                 tracing::info!(
@@ -150,7 +197,9 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
                 self.advance_workflow_step(
                     workflow.id,
                     WorkflowStep::DocumentVerification,
+                    SYSTEM_PERSON_ID,
                     "KYC verification completed successfully",
+                    Vec::new(),
                 ).await?;
             }
             banking_api::domain::KycStatus::RequiresUpdate => {
@@ -178,7 +227,69 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
         Ok(())
     }
 
+    /// Verify the workflow's documents against the hashes registered at upload
+    async fn complete_document_verification(&self, workflow_id: Uuid, reviewed_hashes: Vec<(Uuid, ContentHash)>, verified_by: Uuid) -> BankingResult<()> {
+        let documents = self.document_registry_service
+            .find_linked_documents(DocumentLinkKind::Workflow, workflow_id)
+            .await?;
+
+        let not_reviewed: Vec<String> = documents
+            .iter()
+            .filter(|d| !reviewed_hashes.iter().any(|(document_id, _)| *document_id == d.id))
+            .map(|d| d.id.to_string())
+            .collect();
+        if !not_reviewed.is_empty() {
+            return Err(banking_api::BankingError::ValidationError {
+                field: "reviewed_hashes".to_string(),
+                message: format!("Documents not reviewed: {}", not_reviewed.join(", ")),
+            });
+        }
+
+        for (document_id, supplied_hash) in reviewed_hashes {
+            if !documents.iter().any(|d| d.id == document_id) {
+                return Err(banking_api::BankingError::ValidationError {
+                    field: "reviewed_hashes".to_string(),
+                    message: format!("Document {document_id} is not attached to workflow {workflow_id}"),
+                });
+            }
+            let check = self.document_registry_service
+                .verify_document_integrity(document_id, supplied_hash)
+                .await?;
+            if !check.is_intact() {
+                self.fail_workflow(
+                    workflow_id,
+                    &format!("Document {document_id} changed between upload and review"),
+                ).await?;
+
+                return Err(banking_api::BankingError::ValidationError {
+                    field: "document_integrity".to_string(),
+                    message: format!("Document {document_id} does not match the uploaded content"),
+                });
+            }
+        }
+
+        let supporting_documents = documents
+            .iter()
+            .map(|d| HeaplessString::try_from(d.id.to_string().as_str()).unwrap_or_default())
+            .collect();
+        self.advance_workflow_step(
+            workflow_id,
+            WorkflowStep::ApprovalRequired,
+            verified_by,
+            "Supporting documents verified",
+            supporting_documents,
+        ).await?;
+
+        tracing::info!(
+            "Documents of workflow {} verified by {}",
+            workflow_id, verified_by
+        );
+
+        Ok(())
+    }
+
     /// Activate account after all verifications complete
+    #[allow(deprecated)]
     async fn activate_account(&self, account_id: Uuid, authorized_by: Uuid) -> BankingResult<()> {
         // Validate account exists and is in pending country_subdivision
         let account_model = self.account_repository
@@ -197,7 +308,7 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
 
         // Update account status to Active
         self.account_repository
-            .update_status(account_id, "Active", "Account approved and activated", authorized_by)
+            .update_status_legacy(account_id, "Active", "Account approved and activated", authorized_by)
             .await?;
 
         // Complete workflow
@@ -213,6 +324,8 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
             account_id, authorized_by
         );
 
+        self.issue_welcome_pack(account_id, account.account_status).await;
+
         Ok(())
     }
 
@@ -230,7 +343,7 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
             .ok_or(banking_api::BankingError::ProductNotFound(account.product_id))?;
         let product_rules = product.rules;
         let threshold_days = account.dormancy_threshold_days
-            .unwrap_or(product_rules.default_dormancy_days.unwrap_or(90));
+            .unwrap_or(product_rules.default_dormancy_days.unwrap_or(DEFAULT_DORMANCY_THRESHOLD_DAYS));
 
         // Calculate days since last activity
         let days_inactive = if let Some(last_activity) = account.last_activity_date {
@@ -260,6 +373,7 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
     }
 
     /// Mark account as dormant (automated process)
+    #[allow(deprecated)]
     async fn mark_account_dormant(&self, account_id: Uuid, system_triggered: bool) -> BankingResult<()> {
         // Validate dormancy eligibility
         let assessment = self.check_dormancy_eligibility(account_id).await?;
@@ -280,7 +394,7 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
         };
 
         self.account_repository
-            .update_status(account_id, "Dormant", "Account marked dormant due to inactivity", updated_by_person_id)
+            .update_status_legacy(account_id, "Dormant", "Account marked dormant due to inactivity", updated_by_person_id)
             .await?;
 
         tracing::info!(
@@ -292,6 +406,7 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
     }
 
     /// Initiate account reactivation workflow
+    #[allow(deprecated)]
     async fn initiate_reactivation(&self, account_id: Uuid, requested_by: Uuid) -> BankingResult<AccountWorkflow> {
         let account_model = self.account_repository
             .find_by_id(account_id)
@@ -327,7 +442,7 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
 
         // Update account status to pending reactivation
         self.account_repository
-            .update_status(account_id, "PendingReactivation", "Account reactivation initiated", SYSTEM_PERSON_ID)
+            .update_status_legacy(account_id, "PendingReactivation", "Account reactivation initiated", SYSTEM_PERSON_ID)
             .await?;
 
         // Convert to model and persist workflow
@@ -343,6 +458,7 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
     }
 
     /// Complete mini-KYC for account reactivation
+    #[allow(deprecated)]
     async fn complete_mini_kyc(&self, account_id: Uuid, verification_result: KycResult) -> BankingResult<()> {
         let workflow = self.workflow_repository
             .find_active_workflow(account_id, "AccountReactivation")
@@ -356,7 +472,7 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
             banking_api::domain::KycStatus::Approved => {
                 // Reactivate account
                 self.account_repository
-                    .update_status(account_id, "Active", "Account reactivated successfully", LIFECYCLE_AUTOMATION_PERSON_ID)
+                    .update_status_legacy(account_id, "Active", "Account reactivated successfully", LIFECYCLE_AUTOMATION_PERSON_ID)
                     .await?;
 
                 // Complete workflow
@@ -370,7 +486,7 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
             banking_api::domain::KycStatus::Rejected => {
                 // Keep account dormant, fail workflow
                 self.account_repository
-                    .update_status(account_id, "Dormant", "KYC verification failed", SYSTEM_PERSON_ID)
+                    .update_status_legacy(account_id, "Dormant", "KYC verification failed", SYSTEM_PERSON_ID)
                     .await?;
 
                 self.fail_workflow(
@@ -407,7 +523,7 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
             banking_api::domain::KycStatus::Complete => {
                 // Treat Complete same as Approved
                 self.account_repository
-                    .update_status(account_id, "Active", "Account reactivated successfully", LIFECYCLE_AUTOMATION_PERSON_ID)
+                    .update_status_legacy(account_id, "Active", "Account reactivated successfully", LIFECYCLE_AUTOMATION_PERSON_ID)
                     .await?;
                 self.complete_workflow(workflow.id, "Account reactivated after mini-KYC").await?;
             }
@@ -421,7 +537,7 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
             banking_api::domain::KycStatus::Failed => {
                 // Treat Failed same as Rejected
                 self.account_repository
-                    .update_status(account_id, "Dormant", "KYC verification failed", SYSTEM_PERSON_ID)
+                    .update_status_legacy(account_id, "Dormant", "KYC verification failed", SYSTEM_PERSON_ID)
                     .await?;
                 self.fail_workflow(
                     workflow.id,
@@ -438,6 +554,7 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
     }
 
     /// Initiate account closure workflow
+    #[allow(deprecated)]
     async fn initiate_closure(&self, account_id: Uuid, closure_request: ClosureRequest) -> BankingResult<AccountWorkflow> {
         let account_model = self.account_repository
             .find_by_id(account_id)
@@ -474,7 +591,7 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
 
         // Update account status
         self.account_repository
-            .update_status(account_id, "PendingClosure", "Account closure requested", closure_request.requested_by)
+            .update_status_legacy(account_id, "PendingClosure", "Account closure requested", closure_request.requested_by)
             .await?;
 
         // Convert to model and persist workflow
@@ -491,6 +608,11 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
 
     /// Calculate final settlement amounts for account closure
     async fn calculate_final_settlement(&self, account_id: Uuid) -> BankingResult<FinalSettlement> {
+        // A closure resumed after a restart keeps the amounts it was settled with
+        if let Some(persisted) = self.account_repository.find_settlement_by_account(account_id).await? {
+            return Ok(AccountMapper::final_settlement_from_model(&persisted));
+        }
+
         let account_model = self.account_repository
             .find_by_id(account_id)
             .await?
@@ -498,47 +620,42 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
 
         let account = AccountMapper::from_model(account_model)?;
 
-        // Calculate accrued interest up to today
-        let _today = chrono::Utc::now().date_naive();
-        let accrued_interest = if account.accrued_interest > Decimal::ZERO {
-            account.accrued_interest
-        } else {
-            // Calculate from last interest posting date
-            Decimal::ZERO // Simplified for this implementation
-        };
-
-        // Get closure fees from product catalog
-        let product = self.product_repository.find_product_by_id(account.product_id).await?
-            .ok_or(banking_api::BankingError::ProductNotFound(account.product_id))?;
-        let product_rules = product.rules;
-        let closure_fees = product_rules.closure_fee;
-
-        // Calculate final amount
-        let final_amount = account.current_balance + accrued_interest - closure_fees;
-
-        let settlement = FinalSettlement {
-            current_balance: account.current_balance,
-            accrued_interest,
-            pending_fees: Decimal::ZERO, // Would calculate pending fees
-            closure_fees,
-            final_amount,
-            requires_disbursement: final_amount > Decimal::ZERO,
-        };
-
-        tracing::debug!(
-            "Final settlement calculated for account {}: Final amount = {}",
-            account_id, final_amount
-        );
-
-        Ok(settlement)
+        self.settle(&account, Utc::now().date_naive()).await
     }
 
     /// Process final disbursement for account closure
     async fn process_final_disbursement(&self, account_id: Uuid, disbursement: banking_api::domain::DisbursementInstructions) -> BankingResult<()> {
-        let settlement = self.calculate_final_settlement(account_id).await?;
+        // The settlement is recorded before anything is paid out, so a resumed closure finds it
+        let persisted = match self.account_repository.find_settlement_by_account(account_id).await? {
+            Some(persisted) => persisted,
+            None => {
+                let settlement = self
+                    .compute_final_settlement(account_id, Utc::now().date_naive(), &disbursement)
+                    .await?;
+                self.account_repository.create_final_settlement(settlement).await?
+            }
+        };
+        if persisted.status == DbSettlementStatus::Receivable {
+            tracing::info!(
+                "Final settlement {} of account {} leaves {} owed by the customer; recovered as a receivable",
+                persisted.id, account_id, -persisted.final_amount
+            );
+            return Ok(());
+        }
+        if persisted.status != DbSettlementStatus::Pending {
+            tracing::info!(
+                "Final settlement {} of account {} is already {}; nothing to disburse",
+                persisted.id, account_id, persisted.status
+            );
+            return Ok(());
+        }
+        let settlement = AccountMapper::final_settlement_from_model(&persisted);
 
         if !settlement.requires_disbursement {
-            return Ok(()); // No disbursement needed
+            // No disbursement needed
+            return self.account_repository
+                .update_settlement_status(persisted.id, &DbSettlementStatus::Disbursed.to_string())
+                .await;
         }
 
         // Process disbursement based on method
@@ -588,16 +705,44 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
         }
 
         // In production, this would create disbursement transactions
-        Ok(())
+        self.account_repository
+            .update_settlement_status(persisted.id, &DbSettlementStatus::Disbursed.to_string())
+            .await
     }
 
     /// Finalize account closure
+    #[allow(deprecated)]
     async fn finalize_closure(&self, account_id: Uuid) -> BankingResult<()> {
+        let settlement = self.account_repository.find_settlement_by_account(account_id).await?;
+        if let Some(settlement) = &settlement {
+            if settlement.status == DbSettlementStatus::Pending {
+                return Err(banking_api::BankingError::ValidationError {
+                    field: "settlement_status".to_string(),
+                    message: format!("Final settlement {} of account {account_id} has not been disbursed", settlement.id),
+                });
+            }
+            if settlement.status == DbSettlementStatus::Receivable {
+                return Err(banking_api::BankingError::ValidationError {
+                    field: "settlement_status".to_string(),
+                    message: format!(
+                        "Final settlement {} of account {account_id} leaves {} to recover from the customer",
+                        settlement.id, -settlement.final_amount
+                    ),
+                });
+            }
+        }
+
         // Update account status to closed
         self.account_repository
-            .update_status(account_id, "Closed", "Account closure completed", SYSTEM_PERSON_ID)
+            .update_status_legacy(account_id, "Closed", "Account closure completed", SYSTEM_PERSON_ID)
             .await?;
 
+        if let Some(settlement) = settlement.filter(|s| s.status == DbSettlementStatus::Disbursed) {
+            self.account_repository
+                .update_settlement_status(settlement.id, &DbSettlementStatus::Confirmed.to_string())
+                .await?;
+        }
+
         // Complete workflow if exists
         if let Ok(Some(workflow)) = self.workflow_repository
             .find_active_workflow(account_id, "AccountClosure")
@@ -606,6 +751,9 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
             self.complete_workflow(workflow.id, "Account closure finalized").await?;
         }
 
+        // Re-price or dissolve the bundle the account belonged to
+        self.bundle_service.handle_account_closure(account_id, SYSTEM_PERSON_ID).await?;
+
         tracing::info!("Account {} closure finalized", account_id);
 
         Ok(())
@@ -616,15 +764,18 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
         &self,
         account_id: Uuid,
         new_status: AccountStatus,
-        reason_id: Uuid,
+        reason_id: ReasonId,
         _additional_context: Option<&str>,
         authorized_by: Uuid,
     ) -> BankingResult<()> {
-        // TODO: Validate reason_id against ReasonAndPurpose table
+        let reason = ReasonValidation::require(
+            self.reason_repository.as_ref(),
+            reason_id,
+            ReasonedOperation::AccountStatusChange,
+        )
+        .await?;
         // TODO: Store additional_context if provided
-        
-        // For now, convert reason_id to string for legacy compatibility
-        let reason = format!("Reason ID: {reason_id}");
+        let reason = reason.code.to_string();
         let account_model = self.account_repository
             .find_by_id(account_id)
             .await?
@@ -640,7 +791,7 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
             .update_status(
                 account_id,
                 &Self::account_status_to_string(new_status),
-                "Manual status update",
+                reason_id.0,
                 authorized_by,
             )
             .await?;
@@ -650,6 +801,10 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
             account_id, account.account_status, new_status, authorized_by, reason
         );
 
+        if new_status == AccountStatus::Active {
+            self.issue_welcome_pack(account_id, account.account_status).await;
+        }
+
         Ok(())
     }
 
@@ -680,8 +835,13 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
     }
 
     /// Reject workflow with reason ID validation
-    async fn reject_workflow(&self, _id: Uuid, _reason_id: Uuid, _additional_details: Option<&str>, _rejected_by: Uuid) -> BankingResult<()> {
-        // TODO: Validate reason_id against ReasonAndPurpose table
+    async fn reject_workflow(&self, _id: Uuid, reason_id: ReasonId, _additional_details: Option<&str>, _rejected_by: Uuid) -> BankingResult<()> {
+        ReasonValidation::require(
+            self.reason_repository.as_ref(),
+            reason_id,
+            ReasonedOperation::WorkflowRejection,
+        )
+        .await?;
         // TODO: Store additional_details if provided
         todo!("Implement reject_workflow with reason_id")
     }
@@ -729,8 +889,49 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
     }
 
     /// Batch process dormancy
-    async fn batch_process_dormancy(&self, _processing_date: chrono::NaiveDate) -> BankingResult<banking_api::service::DormancyReport> {
-        todo!("Implement batch_process_dormancy")
+    /// Candidates are found per product so the product threshold applies unless the account overrides it
+    async fn batch_process_dormancy(
+        &self,
+        processing_date: chrono::NaiveDate,
+        product_id: Option<Uuid>,
+    ) -> BankingResult<banking_api::service::DormancyReport> {
+        let products = match product_id {
+            Some(product_id) => vec![self.product_repository
+                .find_product_by_id(product_id)
+                .await?
+                .ok_or(banking_api::BankingError::ProductNotFound(product_id))?],
+            None => self.product_repository.find_active_products().await?,
+        };
+
+        let mut accounts_evaluated = 0;
+        let mut accounts_by_product = HashMap::new();
+        let mut errors = Vec::new();
+        for product in products {
+            let threshold_days = product.rules.default_dormancy_days.unwrap_or(DEFAULT_DORMANCY_THRESHOLD_DAYS);
+            let candidates = self.account_repository
+                .find_dormancy_candidates(processing_date, threshold_days, Some(product.id))
+                .await?;
+            accounts_evaluated += candidates.len() as i32;
+
+            for account in candidates {
+                match self.open_dormancy_assessment(&account, threshold_days, processing_date).await {
+                    Ok(true) => *accounts_by_product.entry(product.id.to_string()).or_insert(0) += 1,
+                    Ok(false) => {}
+                    Err(e) => errors.push(format!("Account {}: {e}", account.id)),
+                }
+            }
+        }
+
+        // Accounts only become dormant once their assessment workflow is approved
+        Ok(banking_api::service::DormancyReport {
+            processing_date,
+            accounts_evaluated,
+            accounts_marked_dormant: 0,
+            accounts_by_product,
+            notifications_generated: 0,
+            notifications_suppressed: 0,
+            errors_encountered: errors,
+        })
     }
 
     /// Batch process closures
@@ -750,30 +951,151 @@ impl AccountLifecycleService for AccountLifecycleServiceImpl {
 }
 
 impl AccountLifecycleServiceImpl {
-    /// Validate product eligibility for account opening
-    async fn validate_product_eligibility(&self, request: &AccountOpeningRequest) -> BankingResult<()> {
-        // Check if product exists
-        let _product_rules = self.product_repository
-            .find_product_by_id(request.product_id)
+    /// Final settlement of a closing account as of `settlement_date`, ready to
+    /// be recorded. A positive amount is paid out by `disbursement`, which must
+    /// reach one of the owners' own accounts; a negative amount is recorded as
+    /// a receivable and not disbursed.
+    pub async fn compute_final_settlement(
+        &self,
+        account_id: Uuid,
+        settlement_date: NaiveDate,
+        disbursement: &DisbursementInstructions,
+    ) -> BankingResult<AccountFinalSettlementModel> {
+        let account_model = self.account_repository
+            .find_by_id(account_id)
+            .await?
+            .ok_or(banking_api::BankingError::AccountNotFound(account_id))?;
+        let account = AccountMapper::from_model(account_model)?;
+
+        let settlement = self.settle(&account, settlement_date).await?;
+        if settlement.requires_disbursement {
+            self.validate_disbursement_target(account_id, disbursement).await?;
+        }
+
+        Ok(AccountMapper::final_settlement_to_model(
+            account_id,
+            settlement_date,
+            &settlement,
+            disbursement.method.clone(),
+            SYSTEM_PERSON_ID,
+        ))
+    }
+
+    /// Balance plus interest accrued up to `settlement_date`, less unposted
+    /// fees and the product closure fee
+    async fn settle(&self, account: &Account, settlement_date: NaiveDate) -> BankingResult<FinalSettlement> {
+        let active_hold_ids: Vec<Uuid> = self.account_hold_repository
+            .find_active_holds(account.id)
             .await?
-            .ok_or(banking_api::BankingError::InvalidProductId(request.product_id))?;
+            .iter()
+            .map(|hold| hold.id)
+            .collect();
+
+        // Accrued interest covers the days EOD has run; the rest are accrued here
+        let today = Utc::now().date_naive();
+        let unaccrued_interest = if settlement_date >= today {
+            self.interest_service
+                .calculate_accrued_interest(account.id, today, settlement_date)
+                .await?
+        } else {
+            Decimal::ZERO
+        };
+
+        let pending_fees: Decimal = self.fee_repository
+            .get_fee_applications_for_account(
+                account.id,
+                None,
+                Some(settlement_date),
+                Some(FeeApplicationStatus::Pending.to_string()),
+            )
+            .await?
+            .iter()
+            .filter(|fee| fee.status == FeeApplicationStatus::Pending && !fee.waived)
+            .map(|fee| fee.amount)
+            .sum();
+
+        let product = self.product_repository.find_product_by_id(account.product_id).await?
+            .ok_or(banking_api::BankingError::ProductNotFound(account.product_id))?;
 
-        // Additional eligibility checks would go here
-        // (customer type compatibility, minimum deposits, etc.)
+        let settlement = FinalSettlement::settle(
+            account.id,
+            account.current_balance,
+            account.accrued_interest + unaccrued_interest,
+            pending_fees,
+            product.rules.closure_fee,
+            &active_hold_ids,
+        )?;
 
+        tracing::debug!(
+            "Final settlement calculated for account {} as of {}: Final amount = {}",
+            account.id, settlement_date, settlement.final_amount
+        );
+
+        Ok(settlement)
+    }
+
+    /// A transfer must land in another account held by one of the owners of
+    /// the account being closed; other methods pay the owners directly
+    async fn validate_disbursement_target(&self, account_id: Uuid, disbursement: &DisbursementInstructions) -> BankingResult<()> {
+        if !matches!(disbursement.method, DisbursementMethod::Transfer) {
+            return Ok(());
+        }
+        let target_account_id = disbursement.target_account_id.ok_or_else(|| banking_api::BankingError::ValidationError {
+            field: "target_account_id".to_string(),
+            message: "A transfer disbursement needs a target account".to_string(),
+        })?;
+        if target_account_id == account_id {
+            return Err(banking_api::BankingError::ValidationError {
+                field: "target_account_id".to_string(),
+                message: "The closing account cannot receive its own settlement".to_string(),
+            });
+        }
+
+        for ownership in self.account_repository.find_ownership_by_account(account_id).await? {
+            let registered = self.account_repository.find_accounts_by_owner(ownership.customer_id).await?;
+            if registered.iter().any(|owned| owned.account_id == target_account_id) {
+                return Ok(());
+            }
+        }
+        Err(banking_api::BankingError::ValidationError {
+            field: "target_account_id".to_string(),
+            message: format!("Account {target_account_id} is not registered to an owner of account {account_id}"),
+        })
+    }
+
+    /// Refuse an opening request listing every check it fails
+    async fn validate_opening_request(&self, request: &AccountOpeningRequest) -> BankingResult<()> {
+        let result = AccountOpeningValidation::validate(
+            self.product_repository.as_ref(),
+            self.customer_repository.as_ref(),
+            request,
+            Utc::now().date_naive(),
+        ).await?;
+
+        if !result.is_valid() {
+            let reasons = result
+                .get_failure_reasons()
+                .iter()
+                .map(|(field, message, code)| format!("{field}: {message} ({code})"))
+                .collect::<Vec<String>>()
+                .join(", ");
+            return Err(banking_api::BankingError::ValidationFailed(reasons));
+        }
         Ok(())
     }
 
-    /// Advance workflow to next step
+    /// Advance workflow to next step, recording who completed it
     async fn advance_workflow_step(
         &self,
         id: Uuid,
         next_step: WorkflowStep,
+        completed_by: Uuid,
         notes: &str,
+        supporting_documents: Vec<HeaplessString<100>>,
     ) -> BankingResult<()> {
         let step_str = format!("{next_step:?}");
         self.workflow_repository
-            .update_workflow_step(id, &step_str)
+            .advance_workflow_step(id, &step_str, completed_by, notes, supporting_documents)
             .await?;
 
         tracing::debug!(
@@ -838,19 +1160,7 @@ impl AccountLifecycleServiceImpl {
         current: AccountStatus,
         new: AccountStatus,
     ) -> BankingResult<()> {
-        use AccountStatus::*;
-
-        let valid_transitions = match current {
-            PendingApproval => vec![Active, Closed],
-            Active => vec![Dormant, Frozen, PendingClosure, Closed],
-            Dormant => vec![Active, PendingReactivation, Closed],
-            Frozen => vec![Active, Closed], // Only compliance can unfreeze
-            PendingReactivation => vec![Active, Dormant],
-            PendingClosure => vec![Closed, Active], // Can revert closure
-            Closed => vec![], // Closed is terminal
-        };
-
-        if !valid_transitions.contains(&new) {
+        if !current.can_transition_to(new) {
             return Err(banking_api::BankingError::ValidationError {
                 field: "status_transition".to_string(),
                 message: format!("Invalid status transition from {current:?} to {new:?}"),
@@ -861,12 +1171,67 @@ impl AccountLifecycleServiceImpl {
     }
 
     /// Convert domain AccountWorkflow to database AccountWorkflowModel
+    /// Open a DormancyAssessment workflow for a candidate; returns false if one is already active
+    async fn open_dormancy_assessment(
+        &self,
+        account: &AccountModel,
+        product_threshold_days: i32,
+        processing_date: chrono::NaiveDate,
+    ) -> BankingResult<bool> {
+        if self.workflow_repository
+            .find_active_workflow(account.id, "DormancyAssessment")
+            .await?
+            .is_some()
+        {
+            return Ok(false);
+        }
+
+        let threshold_days = account.dormancy_threshold_days.unwrap_or(product_threshold_days);
+        let days_inactive = account.last_activity_date
+            .map(|last_activity| (processing_date - last_activity).num_days())
+            .unwrap_or_default();
+        let workflow = AccountWorkflow {
+            id: Uuid::new_v4(),
+            account_id: account.id,
+            workflow_type: WorkflowType::DormancyAssessment,
+            current_step: WorkflowStep::ApprovalRequired,
+            status: WorkflowStatus::PendingAction,
+            initiated_by: LIFECYCLE_AUTOMATION_PERSON_ID,
+            initiated_at: Utc::now(),
+            completed_at: None,
+            steps_completed: Vec::new(),
+            next_action_required: Some(
+                heapless::String::try_from(
+                    format!("Review dormancy: {days_inactive} days inactive, threshold {threshold_days} days").as_str()
+                )
+                .unwrap_or_else(|_| heapless::String::new())
+            ),
+            timeout_at: None,
+        };
+        self.workflow_repository.create_workflow(&self.to_workflow_model(&workflow)).await?;
+
+        tracing::info!(
+            "Dormancy assessment workflow {} opened for account {}",
+            workflow.id, account.id
+        );
+
+        Ok(true)
+    }
+
     fn to_workflow_model(&self, workflow: &AccountWorkflow) -> banking_db::models::AccountWorkflowModel {
         WorkflowMapper::to_model(workflow.clone())
     }
 }
 
 impl AccountLifecycleServiceImpl {
+    /// Issue the welcome pack on first activation. The status change is already
+    /// committed, so a failure here is logged rather than propagated.
+    async fn issue_welcome_pack(&self, account_id: Uuid, previous_status: AccountStatus) {
+        if let Err(e) = self.welcome_pack_service.issue_on_activation(account_id, previous_status).await {
+            tracing::warn!("Failed to issue welcome pack for account {}: {}", account_id, e);
+        }
+    }
+
     // Helper function for status conversion (temporary until repository is updated)
     fn account_status_to_string(status: AccountStatus) -> String {
         match status {
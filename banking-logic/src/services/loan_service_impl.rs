@@ -1,6 +1,7 @@
+use std::sync::Arc;
 use async_trait::async_trait;
-use chrono::{Datelike, NaiveDate, Utc};
-use rust_decimal::Decimal;
+use chrono::{Datelike, Months, NaiveDate, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
 use uuid::Uuid;
 
 use banking_api::{
@@ -10,19 +11,23 @@ use banking_api::{
         PaymentAllocation, PrepaymentHandling, LoanRestructuring, LoanDelinquencyJob,
         LoanPortfolioSummary, CollectionAction, PaymentType, PrepaymentType,
         DelinquencyStage, RestructuringType, CollectionActionType, PaymentMethod,
-        InstallmentStatus, GenerateAmortizationScheduleRequest, CreateCollectionActionRequest
+        InstallmentStatus, GenerateAmortizationScheduleRequest, CreateCollectionActionRequest,
+        AmortizationMethod, CurrencyCode, DayCountConvention, FeeTriggerEvent, PaymentFrequency,
+        PaymentStatus, DEFAULT_CALENDAR,
     },
     service::{
-        LoanService, NotificationChannel, CollectionRecommendation, RestructuringTerms,
+        CalendarService, FeeService, LoanService, NotificationChannel, CollectionRecommendation, RestructuringTerms,
         RestructuringEligibility, AttentionType, LoanAttentionItem, DelinquencyAgingReport,
         PortfolioRiskMetrics,
         CollectionEffectivenessReport,
-        LoanAccountStatus, EarlySettlementCalculation, LoanWriteOff
+        LoanAccountStatus, EarlySettlementCalculation, LoanWriteOff, PayoffQuote
     },
 };
-use banking_db::repository::{AccountRepository, TransactionRepository};
+use banking_db::models::{AccountModel, DbAccountStatus, DbAccountType, DbBalanceChangeSource};
+use banking_db::repository::{AccountRepository, AgentNetworkRepository, TransactionRepository};
 
 use crate::mappers::LoanMapper;
+use crate::services::branch_calendar::branch_calendar_id;
 
 /// Implementation of the LoanService trait
 /// 
@@ -32,6 +37,9 @@ pub struct LoanServiceImpl<A: AccountRepository, T: TransactionRepository> {
     account_repository: A,
     #[allow(dead_code)]
     transaction_repository: T,
+    fee_service: Arc<dyn FeeService>,
+    agent_network_repository: Arc<dyn AgentNetworkRepository>,
+    calendar_service: Arc<dyn CalendarService>,
 }
 
 impl<A: AccountRepository, T: TransactionRepository> 
@@ -40,12 +48,97 @@ impl<A: AccountRepository, T: TransactionRepository>
     pub fn new(
         account_repository: A,
         transaction_repository: T,
+        fee_service: Arc<dyn FeeService>,
+        agent_network_repository: Arc<dyn AgentNetworkRepository>,
+        calendar_service: Arc<dyn CalendarService>,
     ) -> Self {
         Self {
             account_repository,
             transaction_repository,
+            fee_service,
+            agent_network_repository,
+            calendar_service,
         }
     }
+
+    /// Moves installments due on a non-business day of the loan's domicile
+    /// branch calendar to the next business day
+    async fn roll_due_dates(&self, account: &AccountModel, schedule: &mut AmortizationSchedule) -> BankingResult<()> {
+        let calendar_id = branch_calendar_id(
+            self.agent_network_repository.as_ref(),
+            account.domicile_agency_branch_id,
+            DEFAULT_CALENDAR,
+        ).await?;
+        for entry in schedule.schedule_entries.iter_mut() {
+            if !self.calendar_service.is_business_day(entry.due_date, &calendar_id).await? {
+                entry.due_date = self.calendar_service.next_business_day(entry.due_date, &calendar_id).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Loan account that is still open
+    async fn find_open_loan(&self, loan_account_id: Uuid) -> BankingResult<AccountModel> {
+        let account = self.account_repository
+            .find_by_id(loan_account_id)
+            .await?
+            .ok_or(BankingError::AccountNotFound(loan_account_id))?;
+        if account.account_type != DbAccountType::Loan || account.account_status == DbAccountStatus::Closed {
+            return Err(BankingError::ValidationError {
+                field: "loan_account_id".to_string(),
+                message: format!("Account {loan_account_id} is not an open loan"),
+            });
+        }
+        Ok(account)
+    }
+
+    /// Early repayment fees the product charges on `principal` repaid ahead of schedule
+    async fn early_termination_fee(&self, loan_account_id: Uuid, principal: Decimal) -> BankingResult<Decimal> {
+        let fees = self.fee_service
+            .preview_event_fees(loan_account_id, FeeTriggerEvent::LoanEarlyRepayment, Some(principal), None)
+            .await?;
+        Ok(fees.iter().map(|fee| fee.amount).sum())
+    }
+}
+
+/// Banker's rounding to the minor units of the account currency
+fn round_to_minor_units(amount: Decimal, account: &AccountModel) -> BankingResult<Decimal> {
+    let currency = CurrencyCode::try_from(account.currency.as_str())?;
+    Ok(amount.round_dp_with_strategy(currency.minor_units(), RoundingStrategy::MidpointNearestEven))
+}
+
+/// Interest on the outstanding principal from the last installment date, or
+/// disbursement for a loan yet to pay one, up to `as_of_date` on Actual/365.
+/// Account rates are fractions.
+fn interest_since_last_installment(account: &AccountModel, as_of_date: NaiveDate) -> BankingResult<Decimal> {
+    let principal = account.outstanding_principal.unwrap_or(Decimal::ZERO).max(Decimal::ZERO);
+    let annual_rate = account.loan_interest_rate.unwrap_or(Decimal::ZERO);
+    let disbursement_date = account.disbursement_date.unwrap_or(account.open_date);
+    let period_start = match account.next_due_date {
+        Some(next_due_date) => next_due_date
+            .checked_sub_months(Months::new(1))
+            .ok_or_else(|| BankingError::Internal(format!("Cannot compute installment before {next_due_date}")))?
+            .max(disbursement_date),
+        None => disbursement_date,
+    };
+
+    let convention = DayCountConvention::Actual365Fixed;
+    let days = convention.day_count(period_start, as_of_date).max(0);
+    let interest = principal * annual_rate * Decimal::from(days) / Decimal::from(convention.year_basis(as_of_date));
+    round_to_minor_units(interest, account)
+}
+
+/// Next due date and the number of monthly installments left up to maturity
+fn remaining_installments(account: &AccountModel) -> BankingResult<(NaiveDate, u32)> {
+    let (Some(next_due_date), Some(maturity_date)) = (account.next_due_date, account.maturity_date) else {
+        return Err(BankingError::ValidationError {
+            field: "loan_account_id".to_string(),
+            message: format!("Loan account {} has no installment schedule", account.id),
+        });
+    };
+    let months = (maturity_date.year() - next_due_date.year()) * 12
+        + maturity_date.month() as i32 - next_due_date.month() as i32;
+    Ok((next_due_date, (months + 1).max(1) as u32))
 }
 
 #[async_trait]
@@ -61,7 +154,7 @@ impl<A: AccountRepository + Send + Sync, T: TransactionRepository + Send + Sync>
         request: GenerateAmortizationScheduleRequest,
     ) -> BankingResult<AmortizationSchedule> {
         // Validate loan account exists
-        let _account = self.account_repository
+        let account = self.account_repository
             .find_by_id(request.loan_account_id)
             .await?
             .ok_or_else(|| BankingError::ValidationError { 
@@ -69,92 +162,8 @@ impl<A: AccountRepository + Send + Sync, T: TransactionRepository + Send + Sync>
                 message: "Loan account not found".to_string() 
             })?;
 
-        // Generate amortization entries based on payment frequency and method
-        let mut schedule_entries = Vec::new();
-        let mut current_principal = request.principal_amount;
-        let monthly_rate = request.annual_interest_rate / Decimal::from(12) / Decimal::from(100);
-        
-        // Calculate monthly payment for equal installments method
-        let monthly_payment = if monthly_rate > Decimal::ZERO {
-            // Use manual power calculation since Decimal doesn't have powi
-            let mut rate_factor = Decimal::ONE;
-            for _ in 0..request.term_months {
-                rate_factor *= Decimal::ONE + monthly_rate;
-            }
-            (current_principal * monthly_rate * rate_factor) / (rate_factor - Decimal::ONE)
-        } else {
-            current_principal / Decimal::from(request.term_months)
-        };
-
-        let mut cumulative_principal = Decimal::ZERO;
-        let mut cumulative_interest = Decimal::ZERO;
-        let mut current_date = request.first_payment_date;
-
-        for i in 1..=request.term_months {
-            let interest_component = current_principal * monthly_rate;
-            let principal_component = monthly_payment - interest_component;
-            current_principal -= principal_component;
-            cumulative_principal += principal_component;
-            cumulative_interest += interest_component;
-
-            let entry = AmortizationEntry {
-                id: Uuid::new_v4(),
-                schedule_id: Uuid::new_v4(), // Will be set when schedule is created
-                installment_number: i,
-                due_date: current_date,
-                opening_principal_balance: current_principal + principal_component,
-                installment_amount: monthly_payment,
-                principal_component,
-                interest_component,
-                closing_principal_balance: current_principal,
-                cumulative_principal_paid: cumulative_principal,
-                cumulative_interest_paid: cumulative_interest,
-                payment_status: InstallmentStatus::Scheduled,
-                paid_date: None,
-                paid_amount: None,
-                days_overdue: None,
-            };
-
-            schedule_entries.push(entry);
-            
-            // Add one month to current date
-            current_date = current_date.with_day(1)
-                .unwrap()
-                .checked_add_months(chrono::Months::new(1))
-                .unwrap()
-                .with_day(request.first_payment_date.day().min(28))
-                .unwrap_or(current_date);
-        }
-
-        let schedule_id = Uuid::new_v4();
-        
-        // Update all entries with the correct schedule_id
-        for entry in &mut schedule_entries {
-            entry.schedule_id = schedule_id;
-        }
-
-        let maturity_date = schedule_entries.last()
-            .map(|e| e.due_date)
-            .unwrap_or(request.first_payment_date);
-
-        let total_interest = cumulative_interest;
-        let total_payments = request.principal_amount + total_interest;
-
-        let schedule = AmortizationSchedule {
-            id: schedule_id,
-            loan_account_id: request.loan_account_id,
-            original_principal: request.principal_amount,
-            interest_rate: request.annual_interest_rate,
-            term_months: request.term_months,
-            installment_amount: monthly_payment,
-            first_payment_date: request.first_payment_date,
-            maturity_date,
-            total_interest,
-            total_payments,
-            schedule_entries,
-            created_at: Utc::now(),
-            last_updated_at: Utc::now(),
-        };
+        let mut schedule = AmortizationSchedule::generate(&request);
+        self.roll_due_dates(&account, &mut schedule).await?;
 
         // Save to repository
         let _db_schedule = LoanMapper::amortization_schedule_to_model(schedule.clone());
@@ -294,6 +303,9 @@ impl<A: AccountRepository + Send + Sync, T: TransactionRepository + Send + Sync>
             loan_account_id,
             new_balance,
             new_balance, // For loans, current and available balance are typically the same
+            DbBalanceChangeSource::LoanRepayment,
+            None,
+            processed_by,
         ).await?;
 
         Ok(payment)
@@ -877,6 +889,170 @@ impl<A: AccountRepository + Send + Sync, T: TransactionRepository + Send + Sync>
         Err(BankingError::NotImplemented("Early settlement calculation not yet implemented".to_string()))
     }
     
+    async fn calculate_payoff_quote(
+        &self,
+        loan_account_id: Uuid,
+        as_of_date: NaiveDate,
+    ) -> BankingResult<PayoffQuote> {
+        let account = self.find_open_loan(loan_account_id).await?;
+        let outstanding_principal = account.outstanding_principal.unwrap_or(Decimal::ZERO).max(Decimal::ZERO);
+        let accrued_interest = interest_since_last_installment(&account, as_of_date)?;
+        let early_termination_fee = self.early_termination_fee(loan_account_id, outstanding_principal).await?;
+
+        Ok(PayoffQuote {
+            loan_account_id,
+            as_of_date,
+            outstanding_principal,
+            accrued_interest,
+            early_termination_fee,
+            total_payoff_amount: outstanding_principal + accrued_interest + early_termination_fee,
+        })
+    }
+    
+    async fn apply_early_repayment(
+        &self,
+        loan_account_id: Uuid,
+        amount: Decimal,
+        value_date: NaiveDate,
+        prepayment_type: PrepaymentType,
+        processed_by: Uuid,
+    ) -> BankingResult<LoanPayment> {
+        if !matches!(prepayment_type, PrepaymentType::TermReduction | PrepaymentType::InstallmentReduction) {
+            return Err(BankingError::ValidationError {
+                field: "prepayment_type".to_string(),
+                message: "Early repayments either shorten the term or reduce the installment".to_string(),
+            });
+        }
+        let mut account = self.find_open_loan(loan_account_id).await?;
+        let outstanding_principal = account.outstanding_principal.unwrap_or(Decimal::ZERO).max(Decimal::ZERO);
+
+        // Interest to date first, then the fee on the principal repaid early
+        let interest_payment = interest_since_last_installment(&account, value_date)?;
+        let principal_repaid = (amount - interest_payment).clamp(Decimal::ZERO, outstanding_principal);
+        let fees_payment = self.early_termination_fee(loan_account_id, principal_repaid).await?;
+        let principal_payment = amount - interest_payment - fees_payment;
+        if principal_payment <= Decimal::ZERO {
+            return Err(BankingError::ValidationError {
+                field: "amount".to_string(),
+                message: format!(
+                    "Early repayment must exceed interest of {interest_payment} and fees of {fees_payment} due on {value_date}"
+                ),
+            });
+        }
+        if principal_payment > outstanding_principal {
+            return Err(BankingError::ValidationError {
+                field: "amount".to_string(),
+                message: format!(
+                    "Early repayment exceeds the payoff amount of {}",
+                    outstanding_principal + interest_payment + fees_payment
+                ),
+            });
+        }
+
+        let payment_id = Uuid::new_v4();
+        let new_outstanding_principal = outstanding_principal - principal_payment;
+        let settled = new_outstanding_principal == Decimal::ZERO;
+        let prepayment_handling = if settled {
+            // Every future installment is settled by the payoff
+            account.next_due_date = None;
+            account.installment_amount = None;
+            account.close_date = Some(value_date);
+            None
+        } else {
+            let (next_due_date, installments_left) = remaining_installments(&account)?;
+            let schedule_for = |principal_amount: Decimal, term_months: u32| {
+                AmortizationSchedule::generate(&GenerateAmortizationScheduleRequest {
+                    loan_account_id,
+                    principal_amount,
+                    // Account rates are fractions; amortization works in percent
+                    annual_interest_rate: account.loan_interest_rate.unwrap_or(Decimal::ZERO) * Decimal::from(100),
+                    term_months,
+                    first_payment_date: next_due_date,
+                    payment_frequency: PaymentFrequency::Monthly,
+                    calculation_method: AmortizationMethod::EqualInstallments,
+                })
+            };
+            let schedule = match prepayment_type {
+                // Fewest installments the current installment amount still covers
+                PrepaymentType::TermReduction => {
+                    let installment = account.installment_amount
+                        .unwrap_or_else(|| schedule_for(outstanding_principal, installments_left).installment_amount);
+                    (1..installments_left)
+                        .map(|term_months| schedule_for(new_outstanding_principal, term_months))
+                        .find(|schedule| schedule.installment_amount <= installment)
+                        .unwrap_or_else(|| schedule_for(new_outstanding_principal, installments_left))
+                }
+                _ => schedule_for(new_outstanding_principal, installments_left),
+            };
+
+            let new_installment_amount = round_to_minor_units(schedule.installment_amount, &account)?;
+            account.installment_amount = Some(new_installment_amount);
+            account.maturity_date = Some(schedule.maturity_date);
+            Some(PrepaymentHandling {
+                handling_type: prepayment_type.clone(),
+                excess_amount: principal_payment,
+                new_outstanding_principal,
+                term_reduction_months: matches!(prepayment_type, PrepaymentType::TermReduction)
+                    .then_some(installments_left - schedule.term_months),
+                new_installment_amount: Some(new_installment_amount),
+                new_maturity_date: Some(schedule.maturity_date),
+                schedule_regenerated: true,
+                customer_choice: true,
+            })
+        };
+
+        // Interest to the value date is paid, so accrual restarts from zero
+        account.outstanding_principal = Some(new_outstanding_principal);
+        account.accrued_interest = Decimal::ZERO;
+        account.last_activity_date = Some(value_date);
+        let current_balance = account.current_balance;
+        self.account_repository.update(account).await?;
+        self.account_repository.update_balance(
+            loan_account_id,
+            current_balance - principal_payment,
+            current_balance - principal_payment,
+            DbBalanceChangeSource::LoanRepayment,
+            None,
+            processed_by,
+        ).await?;
+        if fees_payment > Decimal::ZERO {
+            self.fee_service
+                .apply_event_based_fees(loan_account_id, payment_id, FeeTriggerEvent::LoanEarlyRepayment, Some(principal_repaid), None)
+                .await?;
+        }
+        if settled {
+            self.account_repository
+                .update_status_legacy(loan_account_id, "Closed", "Loan repaid in full", processed_by)
+                .await?;
+        }
+
+        Ok(LoanPayment {
+            id: payment_id,
+            loan_account_id,
+            payment_date: value_date,
+            payment_amount: amount,
+            payment_type: if settled { PaymentType::Settlement } else { PaymentType::Prepayment },
+            payment_method: PaymentMethod::BankTransfer,
+            allocation: PaymentAllocation {
+                id: Uuid::new_v4(),
+                payment_id,
+                penalty_interest_payment: Decimal::ZERO,
+                overdue_interest_payment: Decimal::ZERO,
+                current_interest_payment: interest_payment,
+                principal_payment,
+                fees_payment,
+                charges_payment: Decimal::ZERO,
+                excess_amount: Decimal::ZERO,
+                prepayment_handling,
+            },
+            payment_status: PaymentStatus::Processed,
+            external_reference: None,
+            processed_by,
+            processed_at: Utc::now(),
+            reversal_info: None,
+        })
+    }
+    
     async fn process_loan_write_off(
         &self,
         _loan_account_id: Uuid,
@@ -886,4 +1062,458 @@ impl<A: AccountRepository + Send + Sync, T: TransactionRepository + Send + Sync>
     ) -> BankingResult<LoanWriteOff> {
         Err(BankingError::NotImplemented("Loan write-off not yet implemented".to_string()))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use chrono::DateTime;
+    use heapless::String as HeaplessString;
+    use banking_api::domain::{
+        BankHoliday, BusinessDayCalculation, WeekendDays, WeekendMask,
+        ChannelFeeTier, FeeApplication, FeeApplicationStatus, FeeCalculationMethod, FeeCategory, FeeItem, FeeJobType,
+        FeeProcessingJob, FeeWaiver, ProductFee, ProductFeeSchedule, ReasonId, fee::FeeType,
+    };
+    use banking_api::service::FeeRevenueSummary;
+    use banking_db::models::{AgencyBranchModel, AgentNetworkModel, AgentTerminalModel, CashLimitCheckModel};
+    use banking_db::models::agent_network::{BranchRollupModel, HierarchyNodeModel};
+    use banking_db::repository::{
+        BranchLimits, BranchPerformanceReport, CashAlert, CashLimitValidationResult, CashStatus, LimitValidationResult,
+        NetworkLimits, NetworkPerformanceReport, TerminalLimits, TerminalPerformanceReport,
+    };
+    use crate::services::test_doubles::InMemoryAccountRepository;
+    use banking_db::models::{
+        ApprovalWorkflowModel, DbSigningCondition, TransactionModel,
+        TransactionSearchCriteriaModel, workflow::WorkflowTransactionApprovalModel,
+    };
+
+    struct MockTransactionRepository;
+
+    #[async_trait]
+    impl TransactionRepository for MockTransactionRepository {
+        async fn find_degraded(&self, _flags_mask: i32, _limit: i64) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn update_degraded_flags(&self, _transaction_id: Uuid, _degraded_flags: i32) -> BankingResult<()> { todo!() }
+        async fn create(&self, _transaction: TransactionModel) -> BankingResult<TransactionModel> { todo!() }
+        async fn post_transaction(&self, _transaction: TransactionModel) -> BankingResult<TransactionModel> { todo!() }
+
+        async fn post_approved_transaction(&self, _transaction_id: Uuid) -> BankingResult<Option<TransactionModel>> { todo!() }
+        async fn update(&self, _transaction: TransactionModel) -> BankingResult<TransactionModel> { todo!() }
+        async fn find_by_id(&self, _transaction_id: Uuid) -> BankingResult<Option<TransactionModel>> { todo!() }
+        async fn find_by_account_id(&self, _account_id: Uuid, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_by_account_date_range(&self, _account_id: Uuid, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn search(&self, _criteria: TransactionSearchCriteriaModel) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_by_reference(&self, _reference_number: &str) -> BankingResult<Option<TransactionModel>> { todo!() }
+        async fn find_by_idempotency_key(&self, _channel_id: &str, _idempotency_key: &str) -> BankingResult<Option<TransactionModel>> { todo!() }
+        async fn find_by_external_reference(&self, _external_reference: &str) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_by_status(&self, _status: &str) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_requiring_approval(&self) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_by_terminal_id(&self, _terminal_id: Uuid, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_by_agent_person_id(&self, _agent_person_id: Uuid, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_by_channel(&self, _channel_id: &str, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn update_status(&self, _transaction_id: Uuid, _status: &str, _reason: &str) -> BankingResult<()> { todo!() }
+        async fn update_approval_status(&self, _transaction_id: Uuid, _approval_status: &str) -> BankingResult<()> { todo!() }
+        async fn find_last_customer_transaction(&self, _account_id: Uuid) -> BankingResult<Option<TransactionModel>> { todo!() }
+        async fn calculate_daily_volume_by_terminal(&self, _terminal_id: Uuid, _date: NaiveDate) -> BankingResult<Decimal> { todo!() }
+        async fn calculate_daily_volume_by_branch(&self, _branch_id: Uuid, _date: NaiveDate) -> BankingResult<Decimal> { todo!() }
+        async fn calculate_daily_volume_by_network(&self, _network_id: Uuid, _date: NaiveDate) -> BankingResult<Decimal> { todo!() }
+        async fn reverse_transaction(&self, _original_transaction_id: Uuid, _reversal_transaction: TransactionModel) -> BankingResult<TransactionModel> { todo!() }
+        async fn find_for_reconciliation(&self, _channel_id: &str, _date: NaiveDate) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn create_workflow(&self, _workflow: ApprovalWorkflowModel) -> BankingResult<ApprovalWorkflowModel> { todo!() }
+        async fn find_workflow_by_id(&self, _workflow_id: Uuid) -> BankingResult<Option<ApprovalWorkflowModel>> { todo!() }
+        async fn find_workflow_by_transaction(&self, _transaction_id: Uuid) -> BankingResult<Option<ApprovalWorkflowModel>> { todo!() }
+        async fn update_workflow_status(&self, _workflow_id: Uuid, _status: &str) -> BankingResult<()> { todo!() }
+        async fn find_pending_workflows(&self) -> BankingResult<Vec<ApprovalWorkflowModel>> { todo!() }
+        async fn find_expired_workflows(&self, _reference_time: DateTime<Utc>) -> BankingResult<Vec<ApprovalWorkflowModel>> { todo!() }
+        async fn create_approval(&self, _approval: WorkflowTransactionApprovalModel) -> BankingResult<WorkflowTransactionApprovalModel> { todo!() }
+        async fn find_approvals_by_workflow(&self, _workflow_id: Uuid) -> BankingResult<Vec<WorkflowTransactionApprovalModel>> { todo!() }
+        async fn find_approvals_by_approver(&self, _approver_person_id: Uuid) -> BankingResult<Vec<WorkflowTransactionApprovalModel>> { todo!() }
+        async fn count_approvals_for_workflow(&self, _workflow_id: Uuid) -> BankingResult<i64> { todo!() }
+        async fn exists(&self, _transaction_id: Uuid) -> BankingResult<bool> { todo!() }
+        async fn count_by_account(&self, _account_id: Uuid, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<i64> { todo!() }
+        async fn list(&self, _offset: i64, _limit: i64) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn count(&self) -> BankingResult<i64> { todo!() }
+    }
+
+    /// Charges a fixed fee on early repayments and records the fees applied
+    struct MockFeeService {
+        early_repayment_fee: Decimal,
+        applied: Mutex<Vec<FeeApplication>>,
+    }
+
+    impl MockFeeService {
+        fn fee_application(&self, account_id: Uuid, base_amount: Option<Decimal>) -> FeeApplication {
+            FeeApplication {
+                id: Uuid::new_v4(),
+                account_id,
+                transaction_id: None,
+                fee_type: FeeType::EventBased,
+                fee_category: FeeCategory::Loan,
+                product_id: Uuid::new_v4(),
+                fee_code: HeaplessString::try_from("LOAN_PREPAY").unwrap(),
+                description: HeaplessString::try_from("Early repayment fee").unwrap(),
+                amount: self.early_repayment_fee,
+                currency: HeaplessString::try_from("EUR").unwrap(),
+                calculation_method: FeeCalculationMethod::Fixed,
+                calculation_base_amount: base_amount,
+                fee_rate: None,
+                trigger_event: FeeTriggerEvent::LoanEarlyRepayment,
+                status: FeeApplicationStatus::Applied,
+                applied_at: Utc::now(),
+                value_date: Utc::now().date_naive(),
+                reversal_deadline: None,
+                waived: false,
+                waived_by: None,
+                waived_reason_id: None,
+                applied_by: Uuid::new_v4(),
+                reversal_transaction_id: None,
+                audit_log_id: None,
+                created_at: Utc::now(),
+            }
+        }
+
+        fn fees(&self, account_id: Uuid, trigger_event: FeeTriggerEvent, base_amount: Option<Decimal>) -> Vec<FeeApplication> {
+            if trigger_event == FeeTriggerEvent::LoanEarlyRepayment && self.early_repayment_fee > Decimal::ZERO {
+                vec![self.fee_application(account_id, base_amount)]
+            } else {
+                Vec::new()
+            }
+        }
+    }
+
+    #[async_trait]
+    impl FeeService for MockFeeService {
+        async fn apply_event_based_fees(&self, account_id: Uuid, _transaction_id: Uuid, trigger_event: FeeTriggerEvent, transaction_amount: Option<Decimal>, _channel: Option<String>) -> BankingResult<Vec<FeeApplication>> {
+            let fees = self.fees(account_id, trigger_event, transaction_amount);
+            self.applied.lock().unwrap().extend(fees.iter().cloned());
+            Ok(fees)
+        }
+        async fn preview_event_fees(&self, account_id: Uuid, trigger_event: FeeTriggerEvent, transaction_amount: Option<Decimal>, _channel: Option<String>) -> BankingResult<Vec<FeeApplication>> {
+            Ok(self.fees(account_id, trigger_event, transaction_amount))
+        }
+        async fn validate_transaction_with_fees(&self, _account_id: Uuid, _transaction_amount: Decimal, _trigger_event: FeeTriggerEvent) -> BankingResult<bool> { todo!() }
+        async fn schedule_batch_fee_job(&self, _job_type: FeeJobType, _processing_date: NaiveDate, _target_products: Option<Vec<Uuid>>, _target_categories: Vec<FeeCategory>) -> BankingResult<FeeProcessingJob> { todo!() }
+        async fn execute_batch_fee_job(&self, _job_id: Uuid) -> BankingResult<FeeProcessingJob> { todo!() }
+        async fn get_eligible_accounts_for_fees(&self, _fee_categories: Vec<FeeCategory>, _processing_date: NaiveDate, _product_ids: Option<Vec<Uuid>>) -> BankingResult<Vec<Uuid>> { todo!() }
+        async fn apply_periodic_fees_for_account(&self, _account_id: Uuid, _processing_date: NaiveDate, _fee_categories: Vec<FeeCategory>) -> BankingResult<Vec<FeeApplication>> { todo!() }
+        async fn apply_inactivity_fee(&self, _account_id: Uuid, _fee_item_id: Uuid, _cycle_start: NaiveDate, _run_date: NaiveDate) -> BankingResult<Option<FeeApplication>> { todo!() }
+        async fn request_fee_waiver(&self, _fee_application_id: Uuid, _reason: String, _requested_by: String) -> BankingResult<FeeWaiver> { todo!() }
+        async fn process_fee_waiver(&self, _waiver_id: Uuid, _approved: bool, _approved_by: String, _notes: Option<String>) -> BankingResult<FeeWaiver> { todo!() }
+        async fn waive_fee(&self, _fee_application_id: Uuid, _reason_id: ReasonId, _approved_by: Uuid) -> BankingResult<FeeApplication> { todo!() }
+        async fn apply_automatic_waivers(&self, _account_id: Uuid, _fee_applications: Vec<FeeApplication>) -> BankingResult<Vec<FeeApplication>> { todo!() }
+        async fn get_product_fee_schedule(&self, _product_id: Uuid) -> BankingResult<ProductFeeSchedule> { todo!() }
+        async fn refresh_fee_rules_cache(&self, _product_id: Option<Uuid>) -> BankingResult<()> { todo!() }
+        async fn get_applicable_fees(&self, _product_id: Uuid, _trigger_event: FeeTriggerEvent) -> BankingResult<Vec<ProductFee>> { todo!() }
+        async fn calculate_fee_amount(&self, _product_fee: &ProductFee, _base_amount: Option<Decimal>, _account_balance: Option<Decimal>, _additional_context: Option<&str>) -> BankingResult<Decimal> { todo!() }
+        async fn calculate_tiered_fee(&self, _product_fee: &ProductFee, _base_amount: Decimal) -> BankingResult<Decimal> { todo!() }
+        async fn calculate_fee(&self, _fee_item: &FeeItem, _amount: Decimal, _currency: &CurrencyCode) -> BankingResult<Decimal> { todo!() }
+        async fn save_fee_item(&self, _fee_item: FeeItem, _tiers: Vec<ChannelFeeTier>) -> BankingResult<FeeItem> { todo!() }
+        async fn check_fee_conditions(&self, _account_id: Uuid, _product_fee: &ProductFee, _transaction_context: Option<&str>) -> BankingResult<bool> { todo!() }
+        async fn get_account_fee_history(&self, _account_id: Uuid, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>, _fee_types: Option<Vec<FeeType>>) -> BankingResult<Vec<FeeApplication>> { todo!() }
+        async fn get_fee_applications_by_status(&self, _status: FeeApplicationStatus, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<Vec<FeeApplication>> { todo!() }
+        async fn get_fee_job_status(&self, _job_id: Uuid) -> BankingResult<FeeProcessingJob> { todo!() }
+        async fn get_fee_revenue_summary(&self, _from_date: NaiveDate, _to_date: NaiveDate, _fee_categories: Option<Vec<FeeCategory>>, _product_ids: Option<Vec<Uuid>>) -> BankingResult<FeeRevenueSummary> { todo!() }
+        async fn reverse_fee_application(&self, _fee_application_id: Uuid, _reversal_reason: String, _reversed_by: String) -> BankingResult<FeeApplication> { todo!() }
+        async fn reverse_fee(&self, _fee_application_id: Uuid, _reason_id: ReasonId, _performed_by: Uuid) -> BankingResult<FeeApplication> { todo!() }
+        async fn bulk_reverse_account_fees(&self, _account_id: Uuid, _reason: String, _reversed_by: String, _fee_types: Option<Vec<FeeType>>) -> BankingResult<Vec<FeeApplication>> { todo!() }
+    }
+
+    /// No branch names a calendar, so every loan rolls on the default calendar
+    struct MockAgentNetworkRepository;
+
+    #[async_trait]
+    impl AgentNetworkRepository for MockAgentNetworkRepository {
+        async fn create_network(&self, _network: AgentNetworkModel) -> BankingResult<AgentNetworkModel> { unimplemented!() }
+        async fn update_network(&self, _network: AgentNetworkModel) -> BankingResult<AgentNetworkModel> { unimplemented!() }
+        async fn find_network_by_id(&self, _network_id: Uuid) -> BankingResult<Option<AgentNetworkModel>> { unimplemented!() }
+        async fn find_networks_by_status(&self, _status: &str) -> BankingResult<Vec<AgentNetworkModel>> { unimplemented!() }
+        async fn find_networks_by_type(&self, _network_type: &str) -> BankingResult<Vec<AgentNetworkModel>> { unimplemented!() }
+        async fn update_network_daily_volume(&self, _network_id: Uuid, _amount: Decimal) -> BankingResult<()> { unimplemented!() }
+        async fn reset_network_daily_counters(&self) -> BankingResult<()> { unimplemented!() }
+        async fn list_networks(&self, _offset: i64, _limit: i64) -> BankingResult<Vec<AgentNetworkModel>> { unimplemented!() }
+        async fn create_branch(&self, _branch: AgencyBranchModel) -> BankingResult<AgencyBranchModel> { unimplemented!() }
+        async fn update_branch(&self, _branch: AgencyBranchModel) -> BankingResult<AgencyBranchModel> { unimplemented!() }
+        async fn find_branch_by_id(&self, _branch_id: Uuid) -> BankingResult<Option<AgencyBranchModel>> { Ok(None) }
+        async fn find_branches_by_network(&self, _agent_network_id: Uuid) -> BankingResult<Vec<AgencyBranchModel>> { unimplemented!() }
+        async fn find_branches_by_parent(&self, _parent_agency_branch_id: Uuid) -> BankingResult<Vec<AgencyBranchModel>> { unimplemented!() }
+        async fn find_branches_by_status(&self, _status: &str) -> BankingResult<Vec<AgencyBranchModel>> { unimplemented!() }
+        async fn find_root_branches(&self, _agent_network_id: Uuid) -> BankingResult<Vec<AgencyBranchModel>> { unimplemented!() }
+        async fn find_branch_hierarchy(&self, _agency_branch_id: Uuid) -> BankingResult<Vec<AgencyBranchModel>> { unimplemented!() }
+        async fn update_branch_daily_volume(&self, _agency_branch_id: Uuid, _amount: Decimal) -> BankingResult<()> { unimplemented!() }
+        async fn reset_branch_daily_counters(&self) -> BankingResult<()> { unimplemented!() }
+        async fn get_branch_gl_prefix(&self, _agency_branch_id: Uuid) -> BankingResult<Option<String>> { unimplemented!() }
+        async fn list_branches(&self, _offset: i64, _limit: i64) -> BankingResult<Vec<AgencyBranchModel>> { unimplemented!() }
+        async fn create_terminal(&self, _terminal: AgentTerminalModel) -> BankingResult<AgentTerminalModel> { unimplemented!() }
+        async fn update_terminal(&self, _terminal: AgentTerminalModel) -> BankingResult<AgentTerminalModel> { unimplemented!() }
+        async fn find_terminal_by_id(&self, _terminal_id: Uuid) -> BankingResult<Option<AgentTerminalModel>> { unimplemented!() }
+        async fn find_terminals_by_branch(&self, _agency_branch_id: Uuid) -> BankingResult<Vec<AgentTerminalModel>> { unimplemented!() }
+        async fn find_terminals_by_agent(&self, _agent_person_id: Uuid) -> BankingResult<Vec<AgentTerminalModel>> { unimplemented!() }
+        async fn find_terminals_by_type(&self, _terminal_type: &str) -> BankingResult<Vec<AgentTerminalModel>> { unimplemented!() }
+        async fn find_terminals_by_status(&self, _status: &str) -> BankingResult<Vec<AgentTerminalModel>> { unimplemented!() }
+        async fn update_terminal_daily_volume(&self, _terminal_id: Uuid, _amount: Decimal) -> BankingResult<()> { unimplemented!() }
+        async fn reset_terminal_daily_counters(&self) -> BankingResult<()> { unimplemented!() }
+        async fn update_terminal_sync(&self, _terminal_id: Uuid, _sync_time: DateTime<Utc>) -> BankingResult<()> { unimplemented!() }
+        async fn find_terminals_needing_sync(&self, _threshold: DateTime<Utc>) -> BankingResult<Vec<AgentTerminalModel>> { unimplemented!() }
+        async fn list_terminals(&self, _offset: i64, _limit: i64) -> BankingResult<Vec<AgentTerminalModel>> { unimplemented!() }
+        async fn find_descendants(&self, _node_id: Uuid, _max_depth: i32) -> BankingResult<Vec<HierarchyNodeModel>> { unimplemented!() }
+        async fn find_ancestors(&self, _node_id: Uuid) -> BankingResult<Vec<HierarchyNodeModel>> { unimplemented!() }
+        async fn find_branch_rollup(&self, _agency_branch_id: Uuid) -> BankingResult<Vec<BranchRollupModel>> { unimplemented!() }
+        async fn get_terminal_limits(&self, _terminal_id: Uuid) -> BankingResult<Option<TerminalLimits>> { unimplemented!() }
+        async fn get_branch_limits(&self, _agency_branch_id: Uuid) -> BankingResult<Option<BranchLimits>> { unimplemented!() }
+        async fn get_network_limits(&self, _agent_network_id: Uuid) -> BankingResult<Option<NetworkLimits>> { unimplemented!() }
+        async fn validate_hierarchical_limits(&self, _terminal_id: Uuid, _amount: Decimal) -> BankingResult<LimitValidationResult> { unimplemented!() }
+        async fn get_current_daily_volume_terminal(&self, _terminal_id: Uuid) -> BankingResult<Decimal> { unimplemented!() }
+        async fn get_current_daily_volume_branch(&self, _agency_branch_id: Uuid) -> BankingResult<Decimal> { unimplemented!() }
+        async fn get_current_daily_volume_network(&self, _agent_network_id: Uuid) -> BankingResult<Decimal> { unimplemented!() }
+        async fn get_network_performance(&self, _agent_network_id: Uuid, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<NetworkPerformanceReport> { unimplemented!() }
+        async fn get_branch_performance(&self, _agency_branch_id: Uuid, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<BranchPerformanceReport> { unimplemented!() }
+        async fn get_terminal_performance(&self, _terminal_id: Uuid, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<TerminalPerformanceReport> { unimplemented!() }
+        async fn update_branch_cash_balance(&self, _agency_branch_id: Uuid, _new_balance: Decimal) -> BankingResult<()> { unimplemented!() }
+        async fn update_terminal_cash_balance(&self, _terminal_id: Uuid, _new_balance: Decimal) -> BankingResult<()> { unimplemented!() }
+        async fn validate_cash_limit(&self, _entity_id: Uuid, _entity_type: &str, _requested_amount: Decimal, _operation_type: &str) -> BankingResult<CashLimitValidationResult> { unimplemented!() }
+        async fn record_cash_limit_check(&self, _check: CashLimitCheckModel) -> BankingResult<CashLimitCheckModel> { unimplemented!() }
+        async fn get_cash_limit_history(&self, _entity_id: Uuid, _from_date: DateTime<Utc>, _to_date: DateTime<Utc>) -> BankingResult<Vec<CashLimitCheckModel>> { unimplemented!() }
+        async fn get_branch_cash_status(&self, _agency_branch_id: Uuid) -> BankingResult<Option<CashStatus>> { unimplemented!() }
+        async fn get_terminal_cash_status(&self, _terminal_id: Uuid) -> BankingResult<Option<CashStatus>> { unimplemented!() }
+        async fn get_low_cash_alerts(&self, _threshold_percentage: f64) -> BankingResult<Vec<CashAlert>> { unimplemented!() }
+        async fn network_exists(&self, _network_id: Uuid) -> BankingResult<bool> { unimplemented!() }
+        async fn branch_exists(&self, _agency_branch_id: Uuid) -> BankingResult<bool> { unimplemented!() }
+        async fn terminal_exists(&self, _terminal_id: Uuid) -> BankingResult<bool> { unimplemented!() }
+        async fn count_networks(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn count_branches(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn count_terminals(&self) -> BankingResult<i64> { unimplemented!() }
+    }
+
+    /// Saturday/Sunday weekend; only the default calendar is expected
+    struct MockCalendarService;
+
+    #[async_trait]
+    impl CalendarService for MockCalendarService {
+        async fn is_business_day(&self, date: NaiveDate, calendar_id: &str) -> BankingResult<bool> {
+            assert_eq!(calendar_id, DEFAULT_CALENDAR);
+            Ok(!WeekendMask::SATURDAY_SUNDAY.is_weekend(date))
+        }
+        async fn next_business_day(&self, date: NaiveDate, calendar_id: &str) -> BankingResult<NaiveDate> {
+            assert_eq!(calendar_id, DEFAULT_CALENDAR);
+            let mut next = date + chrono::Duration::days(1);
+            while WeekendMask::SATURDAY_SUNDAY.is_weekend(next) {
+                next += chrono::Duration::days(1);
+            }
+            Ok(next)
+        }
+        async fn previous_business_day(&self, _date: NaiveDate, _calendar_id: &str) -> BankingResult<NaiveDate> { unimplemented!() }
+        async fn add_business_days(&self, _date: NaiveDate, _days: i32, _calendar_id: &str) -> BankingResult<NaiveDate> { unimplemented!() }
+        async fn count_business_days(&self, _from: NaiveDate, _to: NaiveDate, _calendar_id: &str) -> BankingResult<i32> { unimplemented!() }
+        async fn add_bank_holiday(&self, _holiday: BankHoliday) -> BankingResult<()> { unimplemented!() }
+        async fn remove_bank_holiday(&self, _holiday_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn get_holidays(&self, _calendar_id: &str, _year: i32) -> BankingResult<Vec<BankHoliday>> { unimplemented!() }
+        async fn calculate_business_day(&self, _date: NaiveDate, _calendar_id: &str) -> BankingResult<BusinessDayCalculation> { unimplemented!() }
+        async fn batch_calculate_business_days(&self, _dates: Vec<NaiveDate>, _calendar_id: &str) -> BankingResult<Vec<BusinessDayCalculation>> { unimplemented!() }
+        async fn is_weekend(&self, _date: NaiveDate, _calendar_id: &str) -> BankingResult<bool> { unimplemented!() }
+        async fn create_weekend_days(&self, _weekend_days: WeekendDays) -> BankingResult<WeekendDays> { unimplemented!() }
+        async fn get_weekend_days_by_id(&self, _weekend_days_id: Uuid) -> BankingResult<Option<WeekendDays>> { unimplemented!() }
+        async fn update_weekend_days(&self, _weekend_days: WeekendDays) -> BankingResult<WeekendDays> { unimplemented!() }
+        async fn delete_weekend_days(&self, _weekend_days_id: Uuid) -> BankingResult<()> { unimplemented!() }
+    }
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    /// 6,000.00 EUR at 12% with six monthly installments of 1,035.29 left,
+    /// the last one paid on 15 March 2024
+    fn loan_account_model() -> AccountModel {
+        let outstanding_principal = Decimal::from(6_000);
+        AccountModel {
+            id: Uuid::new_v4(),
+            product_id: Uuid::new_v4(),
+            account_type: DbAccountType::Loan,
+            account_status: DbAccountStatus::Active,
+            signing_condition: DbSigningCondition::None,
+            currency: HeaplessString::try_from("EUR").unwrap(),
+            open_date: date(1, 15),
+            domicile_agency_branch_id: Uuid::new_v4(),
+            account_number: HeaplessString::try_from("10005000010000000004262").unwrap(),
+            gl_code_suffix: None,
+            current_balance: outstanding_principal,
+            available_balance: outstanding_principal,
+            accrued_interest: Decimal::new(2_959, 2),
+            overdraft_limit: None,
+            original_principal: Some(Decimal::from(7_500)),
+            outstanding_principal: Some(outstanding_principal),
+            loan_interest_rate: Some(Decimal::new(12, 2)),
+            loan_term_months: Some(8),
+            disbursement_date: Some(date(1, 15)),
+            maturity_date: Some(date(9, 15)),
+            installment_amount: Some(Decimal::new(103_529, 2)),
+            next_due_date: Some(date(4, 15)),
+            penalty_rate: None,
+            collateral_id: None,
+            loan_purpose_id: None,
+            close_date: None,
+            last_activity_date: None,
+            dormancy_threshold_days: None,
+            reactivation_required: false,
+            pending_closure_reason_id: None,
+            last_disbursement_instruction_id: None,
+            status_changed_by_person_id: None,
+            status_change_reason_id: None,
+            status_change_timestamp: None,
+            most_significant_account_hold_id: None,
+            account_ownership_id: None,
+            access01_account_relationship_id: None,
+            access02_account_relationship_id: None,
+            access03_account_relationship_id: None,
+            access04_account_relationship_id: None,
+            access05_account_relationship_id: None,
+            access06_account_relationship_id: None,
+            access07_account_relationship_id: None,
+            access11_account_mandate_id: None,
+            access12_account_mandate_id: None,
+            access13_account_mandate_id: None,
+            access14_account_mandate_id: None,
+            access15_account_mandate_id: None,
+            access16_account_mandate_id: None,
+            access17_account_mandate_id: None,
+            interest01_ultimate_beneficiary_id: None,
+            interest02_ultimate_beneficiary_id: None,
+            interest03_ultimate_beneficiary_id: None,
+            interest04_ultimate_beneficiary_id: None,
+            interest05_ultimate_beneficiary_id: None,
+            interest06_ultimate_beneficiary_id: None,
+            interest07_ultimate_beneficiary_id: None,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+            version: 0,
+        }
+    }
+
+    fn loan_service(
+        account: &AccountModel,
+        early_repayment_fee: Decimal,
+    ) -> (LoanServiceImpl<InMemoryAccountRepository, MockTransactionRepository>, Arc<MockFeeService>) {
+        let account_repository = InMemoryAccountRepository::with_accounts([account.clone()]);
+        let fee_service = Arc::new(MockFeeService { early_repayment_fee, applied: Mutex::new(Vec::new()) });
+        let service = LoanServiceImpl::new(
+            account_repository,
+            MockTransactionRepository,
+            fee_service.clone(),
+            Arc::new(MockAgentNetworkRepository),
+            Arc::new(MockCalendarService),
+        );
+        (service, fee_service)
+    }
+
+    fn stored(service: &LoanServiceImpl<InMemoryAccountRepository, MockTransactionRepository>, account_id: Uuid) -> AccountModel {
+        service.account_repository.account(account_id).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_schedule_due_dates_roll_off_the_branch_calendar_weekend() {
+        let account = loan_account_model();
+        let (service, _) = loan_service(&account, Decimal::ZERO);
+
+        // 15 June 2024 is a Saturday, 15 July a Monday and 15 August a Thursday
+        let schedule = service
+            .generate_amortization_schedule(GenerateAmortizationScheduleRequest {
+                loan_account_id: account.id,
+                principal_amount: Decimal::from(3_000),
+                annual_interest_rate: Decimal::from(12),
+                term_months: 3,
+                first_payment_date: date(6, 15),
+                payment_frequency: PaymentFrequency::Monthly,
+                calculation_method: AmortizationMethod::EqualInstallments,
+            })
+            .await
+            .unwrap();
+
+        let due_dates: Vec<NaiveDate> = schedule.schedule_entries.iter().map(|e| e.due_date).collect();
+        assert_eq!(due_dates, vec![date(6, 17), date(7, 15), date(8, 15)]);
+    }
+
+    #[tokio::test]
+    async fn test_full_payoff_mid_cycle_closes_loan() {
+        let account = loan_account_model();
+        let (service, fee_service) = loan_service(&account, Decimal::from(50));
+
+        // 15 days of interest since the 15 March installment: 6000 * 12% * 15 / 365
+        let quote = service.calculate_payoff_quote(account.id, date(3, 30)).await.unwrap();
+        assert_eq!(quote.outstanding_principal, Decimal::from(6_000));
+        assert_eq!(quote.accrued_interest, Decimal::new(2_959, 2));
+        assert_eq!(quote.early_termination_fee, Decimal::from(50));
+        assert_eq!(quote.total_payoff_amount, Decimal::new(607_959, 2));
+
+        let payment = service
+            .apply_early_repayment(account.id, quote.total_payoff_amount, date(3, 30), PrepaymentType::TermReduction, Uuid::new_v4())
+            .await
+            .unwrap();
+        assert!(matches!(payment.payment_type, PaymentType::Settlement));
+        assert_eq!(payment.allocation.current_interest_payment, Decimal::new(2_959, 2));
+        assert_eq!(payment.allocation.fees_payment, Decimal::from(50));
+        assert_eq!(payment.allocation.principal_payment, Decimal::from(6_000));
+        assert!(payment.allocation.prepayment_handling.is_none());
+        assert_eq!(fee_service.applied.lock().unwrap().len(), 1);
+
+        let closed = stored(&service, account.id);
+        assert_eq!(closed.account_status, DbAccountStatus::Closed);
+        assert_eq!(closed.outstanding_principal, Some(Decimal::ZERO));
+        assert_eq!(closed.current_balance, Decimal::ZERO);
+        assert_eq!(closed.accrued_interest, Decimal::ZERO);
+        assert_eq!(closed.close_date, Some(date(3, 30)));
+        assert_eq!(closed.next_due_date, None);
+
+        // Paying more than the payoff amount is rejected
+        let (service, _) = loan_service(&account, Decimal::from(50));
+        let overpaid = service
+            .apply_early_repayment(account.id, Decimal::from(7_000), date(3, 30), PrepaymentType::TermReduction, Uuid::new_v4())
+            .await;
+        assert!(matches!(overpaid, Err(BankingError::ValidationError { field, .. }) if field == "amount"));
+    }
+
+    #[tokio::test]
+    async fn test_partial_prepayment_shortens_term_by_one_installment() {
+        let account = loan_account_model();
+        let (service, fee_service) = loan_service(&account, Decimal::ZERO);
+
+        // 9.86 of interest for 5 days, the rest repays 1,000.00 of principal
+        let payment = service
+            .apply_early_repayment(account.id, Decimal::new(100_986, 2), date(3, 20), PrepaymentType::TermReduction, Uuid::new_v4())
+            .await
+            .unwrap();
+        assert!(matches!(payment.payment_type, PaymentType::Prepayment));
+        assert_eq!(payment.allocation.current_interest_payment, Decimal::new(986, 2));
+        assert_eq!(payment.allocation.principal_payment, Decimal::from(1_000));
+        assert!(fee_service.applied.lock().unwrap().is_empty());
+
+        // Five installments of 1,030.20 repay 5,000.00 within the old installment of 1,035.29
+        let handling = payment.allocation.prepayment_handling.unwrap();
+        assert_eq!(handling.new_outstanding_principal, Decimal::from(5_000));
+        assert_eq!(handling.term_reduction_months, Some(1));
+        assert_eq!(handling.new_installment_amount, Some(Decimal::new(103_020, 2)));
+        assert_eq!(handling.new_maturity_date, Some(date(8, 15)));
+
+        let updated = stored(&service, account.id);
+        assert_eq!(updated.account_status, DbAccountStatus::Active);
+        assert_eq!(updated.outstanding_principal, Some(Decimal::from(5_000)));
+        assert_eq!(updated.current_balance, Decimal::from(5_000));
+        assert_eq!(updated.maturity_date, Some(date(8, 15)));
+        assert_eq!(updated.installment_amount, Some(Decimal::new(103_020, 2)));
+    }
+
+    #[tokio::test]
+    async fn test_partial_prepayment_reduces_installment_over_same_term() {
+        let account = loan_account_model();
+        let (service, _) = loan_service(&account, Decimal::ZERO);
+
+        let payment = service
+            .apply_early_repayment(account.id, Decimal::from(1_000), date(3, 15), PrepaymentType::InstallmentReduction, Uuid::new_v4())
+            .await
+            .unwrap();
+
+        let handling = payment.allocation.prepayment_handling.unwrap();
+        assert_eq!(handling.term_reduction_months, None);
+        assert_eq!(handling.new_installment_amount, Some(Decimal::new(86_274, 2)));
+        assert_eq!(handling.new_maturity_date, Some(date(9, 15)));
+    }
+}
@@ -1,5 +1,6 @@
 // pub mod customer_service_impl;
 // pub mod account_service_impl;
+// pub mod account_number_service_impl;
 // pub mod account_hold_service_impl;
 // pub mod hierarchy_service_impl;
 // pub mod transaction_service_impl;
@@ -8,19 +9,52 @@
 // pub mod calendar_service_impl;
 // pub mod compliance_service_impl;
 // pub mod daily_collection_service_impl;
+
+// pub mod daily_collection_scheduling;
 // pub mod channel_service_impl;
+// pub mod channel_reconciliation_service_impl;
 // pub mod loan_service_impl;
 // pub mod casa_service_impl;
 // pub mod collateral_service_impl;
 // pub mod fee_service_impl;
 // pub mod eod_service_impl;
 // pub mod product_service_impl;
+// pub mod welcome_pack_service_impl;
+// pub mod segment_service_impl;
+// pub mod guarantor_service_impl;
+// pub mod simulation_service_impl;
+// pub mod cheque_service_impl;
+// pub mod statement_service_impl;
+// pub mod orchestration_service_impl;
+// pub mod tag_service_impl;
+// pub mod channel_security_service_impl;
+// pub mod agent_commission_service_impl;
+// pub mod document_registry_service_impl;
+// pub mod branch_cash_service_impl;
+// pub mod notification_service_impl;
+// pub mod investigation_service_impl;
+// pub mod bundle_service_impl;
+// pub mod financial_position_service_impl;
+// pub mod savings_goal_service_impl;
+// pub mod payee_service_impl;
+// pub mod posting_degradation;
+// pub mod kill_switch_service_impl;
+// pub mod interaction_note_service_impl;
+// pub mod verification_service_impl;
+// pub mod warehouse_export_service_impl;
+// pub mod workflow_escalation_service_impl;
+// pub mod standing_order_scheduling;
+// pub mod branch_calendar;
+// pub mod eod_orchestration;
+// #[cfg(test)]
+// pub(crate) mod test_doubles;
 pub mod audit;
 pub mod repositories;
 pub mod person;
 
 // pub use customer_service_impl::*;
 // pub use account_service_impl::*;
+// pub use account_number_service_impl::*;
 // pub use hierarchy_service_impl::*;
 // pub use transaction_service_impl::*;
 // pub use interest_service_impl::*;
@@ -28,6 +62,7 @@ pub mod person;
 // pub use calendar_service_impl::*;
 // pub use compliance_service_impl::*;
 // pub use channel_service_impl::*;
+// pub use channel_reconciliation_service_impl::*;
 // pub use loan_service_impl::*;
 // pub use casa_service_impl::*;
 // pub use collateral_service_impl::*;
@@ -35,5 +70,29 @@ pub mod person;
 // pub use eod_service_impl::*;
 // pub use daily_collection_service_impl::*;
 // pub use product_service_impl::*;
+// pub use welcome_pack_service_impl::*;
+// pub use segment_service_impl::*;
+// pub use guarantor_service_impl::*;
+// pub use simulation_service_impl::*;
+// pub use cheque_service_impl::*;
+// pub use statement_service_impl::*;
+// pub use orchestration_service_impl::*;
+// pub use tag_service_impl::*;
+// pub use channel_security_service_impl::*;
+// pub use agent_commission_service_impl::*;
+// pub use document_registry_service_impl::*;
+// pub use branch_cash_service_impl::*;
+// pub use notification_service_impl::*;
+// pub use investigation_service_impl::*;
+// pub use bundle_service_impl::*;
+// pub use financial_position_service_impl::*;
+// pub use savings_goal_service_impl::*;
+// pub use payee_service_impl::*;
+// pub use posting_degradation::*;
+// pub use kill_switch_service_impl::*;
+// pub use interaction_note_service_impl::*;
+// pub use verification_service_impl::*;
+// pub use warehouse_export_service_impl::*;
+// pub use workflow_escalation_service_impl::*;
 pub use audit::*;
 pub use person::*;
\ No newline at end of file
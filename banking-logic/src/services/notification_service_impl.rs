@@ -0,0 +1,804 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        person::ContactPreference, ChannelDispatch, DispatchStatus, DocumentNotificationStatus,
+        MessageSendRequest, MessageTemplate, MessageTemplateRequest, NotificationDuplicateReport,
+        NotificationQueueOutcome, NotificationRequest, NotificationTemplate, QueuedNotification, RenderedMessage,
+    },
+    service::NotificationService,
+};
+use banking_db::repository::{ContactPreferenceRepository, CustomerRepository, NotificationRepository};
+use sqlx::Postgres;
+use crate::config::BankingConfig;
+use crate::mappers::{NotificationMapper, ToDomain, ToModel};
+
+/// Production implementation of NotificationService
+pub struct NotificationServiceImpl {
+    notification_repository: Arc<dyn NotificationRepository>,
+    customer_repository: Arc<dyn CustomerRepository>,
+    contact_preference_repository: Arc<dyn ContactPreferenceRepository<Postgres>>,
+    config: Arc<BankingConfig>,
+}
+
+impl NotificationServiceImpl {
+    pub fn new(
+        notification_repository: Arc<dyn NotificationRepository>,
+        customer_repository: Arc<dyn CustomerRepository>,
+        contact_preference_repository: Arc<dyn ContactPreferenceRepository<Postgres>>,
+        config: Arc<BankingConfig>,
+    ) -> Self {
+        Self { notification_repository, customer_repository, contact_preference_repository, config }
+    }
+
+    async fn queue_template(
+        &self,
+        template: NotificationTemplate,
+        customer_id: Uuid,
+        business_date: NaiveDate,
+        context: &[(&str, String)],
+    ) -> BankingResult<NotificationQueueOutcome> {
+        self.render_and_queue(NotificationRequest {
+            template,
+            customer_id,
+            business_date,
+            context: context.iter().map(|(name, value)| (name.to_string(), value.clone())).collect::<BTreeMap<_, _>>(),
+        })
+        .await
+    }
+
+    /// Like `queue_template`, but worded by the stored template in the customer's language
+    async fn queue_stored_template(
+        &self,
+        template: NotificationTemplate,
+        customer_id: Uuid,
+        business_date: NaiveDate,
+        context: &[(&str, String)],
+    ) -> BankingResult<NotificationQueueOutcome> {
+        let customer = self.customer_repository
+            .find_by_id(customer_id)
+            .await?
+            .ok_or(BankingError::CustomerNotFound(customer_id))?;
+        let language_preferences: Vec<[u8; 3]> = customer.preferred_language_code.into_iter().collect();
+        let params: HashMap<String, String> = context.iter().map(|(name, value)| (name.to_string(), value.clone())).collect();
+        let rendered = self.render_message(template.code(), &language_preferences, &params).await?;
+
+        let request = NotificationRequest {
+            template,
+            customer_id,
+            business_date,
+            context: params.into_iter().collect(),
+        };
+        self.queue_rendered(&request, &rendered.body).await
+    }
+
+    async fn queue_rendered(&self, request: &NotificationRequest, rendered: &str) -> BankingResult<NotificationQueueOutcome> {
+        let body = HeaplessString::try_from(rendered).map_err(|_| BankingError::ValidationError {
+            field: "body".to_string(),
+            message: "Rendered notification too long".to_string(),
+        })?;
+        let key = request.idempotency_key();
+
+        let notification = QueuedNotification {
+            id: Uuid::new_v4(),
+            idempotency_key: key.as_key(),
+            template_code: key.template_code,
+            customer_id: request.customer_id,
+            business_date: request.business_date,
+            body,
+            status: DocumentNotificationStatus::Queued,
+            queued_at: Utc::now(),
+            sent_at: None,
+        };
+        let notification_id = notification.id;
+        let queued = self.notification_repository
+            .queue_notification(NotificationMapper::to_model(notification))
+            .await?;
+
+        let was_duplicate = queued.id != notification_id;
+        if was_duplicate {
+            tracing::info!(
+                "Suppressed duplicate {} notification for customer {} on {}",
+                queued.template_code, request.customer_id, request.business_date
+            );
+        }
+        Ok(NotificationQueueOutcome {
+            notification: NotificationMapper::from_model(queued),
+            was_duplicate,
+        })
+    }
+}
+
+/// Bounded copy of a template field, naming the field and its limit when too long
+fn bounded<const N: usize>(value: &str, field: &str) -> BankingResult<HeaplessString<N>> {
+    HeaplessString::try_from(value).map_err(|_| BankingError::ValidationError {
+        field: field.to_string(),
+        message: format!("Template {field} is {} bytes; at most {N} can be stored", value.len()),
+    })
+}
+
+/// The full id keys the message; only the last six characters are shown to the customer
+pub(crate) fn account_suffix(account_id: Uuid) -> String {
+    let simple = account_id.simple().to_string().to_uppercase();
+    simple[simple.len() - 6..].to_string()
+}
+
+#[async_trait]
+impl NotificationService for NotificationServiceImpl {
+    async fn render_and_queue(&self, request: NotificationRequest) -> BankingResult<NotificationQueueOutcome> {
+        let rendered = request.template.render(&request.context).map_err(|message| BankingError::ValidationError {
+            field: "context".to_string(),
+            message,
+        })?;
+        self.queue_rendered(&request, &rendered).await
+    }
+
+    async fn save_message_template(&self, request: MessageTemplateRequest) -> BankingResult<MessageTemplate> {
+        let template = MessageTemplate {
+            id: Uuid::new_v4(),
+            template_code: bounded(&request.template_code, "template_code")?,
+            language_code: request.language_code,
+            channel: request.channel,
+            subject: request.subject.as_deref().map(|subject| bounded(subject, "subject")).transpose()?,
+            body: bounded(&request.body, "body")?,
+            regulatory: request.regulatory,
+            updated_at: Utc::now(),
+        };
+        let saved = self.notification_repository
+            .save_message_template(NotificationMapper::template_to_model(template))
+            .await?;
+        Ok(NotificationMapper::template_from_model(saved))
+    }
+
+    async fn render_message(
+        &self,
+        template_code: &str,
+        language_preferences: &[[u8; 3]],
+        params: &HashMap<String, String>,
+    ) -> BankingResult<RenderedMessage> {
+        let templates: Vec<MessageTemplate> = self.notification_repository
+            .find_message_templates(template_code)
+            .await?
+            .into_iter()
+            .map(NotificationMapper::template_from_model)
+            .collect();
+        let default_language = self.config.notifications.default_language();
+        let template = MessageTemplate::select(&templates, language_preferences, default_language).ok_or_else(|| {
+            BankingError::NotFound(format!(
+                "Message template {template_code} in neither the preferred languages nor {}",
+                String::from_utf8_lossy(&default_language)
+            ))
+        })?;
+        template.render(params).map_err(|message| BankingError::ValidationError {
+            field: "params".to_string(),
+            message,
+        })
+    }
+
+    async fn render_and_send(&self, request: MessageSendRequest) -> BankingResult<Vec<ChannelDispatch>> {
+        let mut dispatches = Vec::with_capacity(request.channels.len());
+        for channel in request.channels.iter().copied() {
+            let preference: Option<ContactPreference> = self.contact_preference_repository
+                .find_by_person_and_channel(request.person_id, channel.to_model())
+                .await
+                .map_err(|e| BankingError::Internal(format!("Failed to load contact preference: {e}")))?
+                .map(ToDomain::to_domain);
+            let language_preferences: Vec<[u8; 3]> =
+                preference.as_ref().and_then(|preference| preference.preferred_language).into_iter().collect();
+            let message = self.render_message(&request.template_code, &language_preferences, &request.params).await?;
+
+            let dispatch = match preference {
+                Some(preference) if !preference.allowed && !message.regulatory => {
+                    tracing::info!(
+                        "Suppressed {} for person {} on {:?}: channel opted out",
+                        request.template_code, request.person_id, channel
+                    );
+                    ChannelDispatch { channel, status: DispatchStatus::Suppressed, send_at: None, message: None }
+                }
+                preference => {
+                    let send_at = preference
+                        .map(|preference| preference.next_allowed_at(request.requested_at))
+                        .unwrap_or(request.requested_at);
+                    let status = if send_at > request.requested_at { DispatchStatus::Deferred } else { DispatchStatus::Send };
+                    ChannelDispatch { channel, status, send_at: Some(send_at), message: Some(message) }
+                }
+            };
+            dispatches.push(dispatch);
+        }
+        Ok(dispatches)
+    }
+
+    async fn queue_dormancy_notice(
+        &self,
+        customer_id: Uuid,
+        account_id: Uuid,
+        business_date: NaiveDate,
+    ) -> BankingResult<NotificationQueueOutcome> {
+        self.queue_stored_template(
+            NotificationTemplate::DormancyNotice,
+            customer_id,
+            business_date,
+            &[("account_id", account_id.to_string()), ("account_suffix", account_suffix(account_id))],
+        )
+        .await
+    }
+
+    async fn queue_statement_ready(
+        &self,
+        customer_id: Uuid,
+        statement_reference: &HeaplessString<50>,
+        business_date: NaiveDate,
+    ) -> BankingResult<NotificationQueueOutcome> {
+        self.queue_template(
+            NotificationTemplate::StatementReady,
+            customer_id,
+            business_date,
+            &[("statement_reference", statement_reference.to_string())],
+        )
+        .await
+    }
+
+    async fn queue_mandate_expiry_reminder(
+        &self,
+        customer_id: Uuid,
+        account_id: Uuid,
+        expiry_date: NaiveDate,
+        business_date: NaiveDate,
+    ) -> BankingResult<NotificationQueueOutcome> {
+        self.queue_template(
+            NotificationTemplate::MandateExpiryReminder,
+            customer_id,
+            business_date,
+            &[
+                ("account_id", account_id.to_string()),
+                ("account_suffix", account_suffix(account_id)),
+                ("expiry_date", expiry_date.to_string()),
+            ],
+        )
+        .await
+    }
+
+    async fn queue_collection_reminder(
+        &self,
+        customer_id: Uuid,
+        amount: Decimal,
+        due_date: NaiveDate,
+        business_date: NaiveDate,
+    ) -> BankingResult<NotificationQueueOutcome> {
+        self.queue_stored_template(
+            NotificationTemplate::CollectionReminder,
+            customer_id,
+            business_date,
+            &[("amount", amount.to_string()), ("due_date", due_date.to_string())],
+        )
+        .await
+    }
+
+    async fn queue_savings_goals_reduced(
+        &self,
+        customer_id: Uuid,
+        account_id: Uuid,
+        amount: Decimal,
+        business_date: NaiveDate,
+    ) -> BankingResult<NotificationQueueOutcome> {
+        self.queue_template(
+            NotificationTemplate::SavingsGoalsReduced,
+            customer_id,
+            business_date,
+            &[
+                ("account_id", account_id.to_string()),
+                ("account_suffix", account_suffix(account_id)),
+                ("amount", amount.to_string()),
+            ],
+        )
+        .await
+    }
+
+    async fn queue_inactivity_fee_warning(
+        &self,
+        customer_id: Uuid,
+        account_id: Uuid,
+        fee_date: NaiveDate,
+        warned_from: NaiveDate,
+    ) -> BankingResult<NotificationQueueOutcome> {
+        self.queue_stored_template(
+            NotificationTemplate::InactivityFeeWarning,
+            customer_id,
+            warned_from,
+            &[
+                ("account_id", account_id.to_string()),
+                ("account_suffix", account_suffix(account_id)),
+                ("fee_date", fee_date.to_string()),
+            ],
+        )
+        .await
+    }
+
+    async fn find_notifications_by_customer(&self, customer_id: Uuid, business_date: NaiveDate) -> BankingResult<Vec<QueuedNotification>> {
+        let notifications = self.notification_repository
+            .find_notifications_by_customer(customer_id, business_date)
+            .await?;
+        Ok(notifications.into_iter().map(NotificationMapper::from_model).collect())
+    }
+
+    async fn get_duplicate_report(&self, business_date: NaiveDate) -> BankingResult<NotificationDuplicateReport> {
+        let summaries = self.notification_repository.summarize_duplicates(business_date).await?;
+        Ok(NotificationDuplicateReport {
+            business_date,
+            by_template: summaries.into_iter().map(NotificationMapper::summary_from_model).collect(),
+        })
+    }
+
+    async fn purge_expired_keys(&self, as_of: NaiveDate) -> BankingResult<u64> {
+        let cutoff = as_of - Duration::days(self.config.notifications.key_retention_days);
+        let purged = self.notification_repository.delete_keys_before(cutoff).await?;
+        tracing::info!("Purged {purged} notification idempotency keys dated before {cutoff}");
+        Ok(purged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use banking_api::domain::MessageChannel;
+    use chrono::{DateTime, NaiveTime};
+    use banking_db::models::{
+        CustomerAuditModel, CustomerDocumentModel, CustomerModel, CustomerPortfolioModel, CustomerSearchCriteriaModel,
+        CustomerStatus, CustomerType, IdentityType, MessageTemplateModel, NotificationDuplicateSummaryModel,
+        QueuedNotificationModel, RiskRating,
+    };
+    use banking_db::models::person::{ContactChannel, ContactPreferenceModel};
+    use banking_db::repository::ContactPreferenceResult;
+
+    /// Idempotency keys map to the first notification id and the suppressed count
+    #[derive(Default)]
+    struct MockNotificationRepository {
+        notifications: Mutex<Vec<QueuedNotificationModel>>,
+        keys: Mutex<HashMap<String, (Uuid, NaiveDate, i64)>>,
+        templates: Mutex<Vec<MessageTemplateModel>>,
+    }
+
+    #[async_trait]
+    impl NotificationRepository for MockNotificationRepository {
+        async fn queue_notification(&self, notification: QueuedNotificationModel) -> BankingResult<QueuedNotificationModel> {
+            let mut keys = self.keys.lock().unwrap();
+            if let Some((existing_id, _, suppressed)) = keys.get_mut(notification.idempotency_key.as_str()) {
+                *suppressed += 1;
+                let existing_id = *existing_id;
+                return Ok(self.notifications.lock().unwrap().iter().find(|n| n.id == existing_id).cloned().unwrap());
+            }
+            keys.insert(notification.idempotency_key.to_string(), (notification.id, notification.business_date, 0));
+            self.notifications.lock().unwrap().push(notification.clone());
+            Ok(notification)
+        }
+
+        async fn find_notification_by_id(&self, notification_id: Uuid) -> BankingResult<Option<QueuedNotificationModel>> {
+            Ok(self.notifications.lock().unwrap().iter().find(|n| n.id == notification_id).cloned())
+        }
+
+        async fn find_notifications_by_customer(&self, customer_id: Uuid, business_date: NaiveDate) -> BankingResult<Vec<QueuedNotificationModel>> {
+            Ok(self.notifications.lock().unwrap()
+                .iter()
+                .filter(|n| n.customer_id == customer_id && n.business_date == business_date)
+                .cloned()
+                .collect())
+        }
+
+        async fn summarize_duplicates(&self, business_date: NaiveDate) -> BankingResult<Vec<NotificationDuplicateSummaryModel>> {
+            let keys = self.keys.lock().unwrap();
+            let notifications = self.notifications.lock().unwrap();
+            let mut summaries: Vec<NotificationDuplicateSummaryModel> = Vec::new();
+            for (notification_id, date, suppressed) in keys.values() {
+                if *date != business_date {
+                    continue;
+                }
+                let template_code = notifications.iter().find(|n| n.id == *notification_id).unwrap().template_code.clone();
+                match summaries.iter_mut().find(|s| s.template_code == template_code) {
+                    Some(summary) => {
+                        summary.notifications_queued += 1;
+                        summary.duplicates_suppressed += suppressed;
+                    }
+                    None => summaries.push(NotificationDuplicateSummaryModel {
+                        template_code,
+                        notifications_queued: 1,
+                        duplicates_suppressed: *suppressed,
+                    }),
+                }
+            }
+            Ok(summaries)
+        }
+
+        async fn delete_keys_before(&self, cutoff: NaiveDate) -> BankingResult<u64> {
+            let mut keys = self.keys.lock().unwrap();
+            let before = keys.len();
+            keys.retain(|_, (_, date, _)| *date >= cutoff);
+            Ok((before - keys.len()) as u64)
+        }
+
+        async fn save_message_template(&self, mut template: MessageTemplateModel) -> BankingResult<MessageTemplateModel> {
+            let mut templates = self.templates.lock().unwrap();
+            let existing = templates
+                .iter()
+                .position(|t| t.template_code == template.template_code && t.language_code == template.language_code);
+            if let Some(index) = existing {
+                template.id = templates.remove(index).id;
+            }
+            templates.push(template.clone());
+            Ok(template)
+        }
+
+        async fn find_message_templates(&self, template_code: &str) -> BankingResult<Vec<MessageTemplateModel>> {
+            Ok(self.templates.lock().unwrap().iter().filter(|t| t.template_code.as_str() == template_code).cloned().collect())
+        }
+    }
+
+    /// Every customer exists; only those given a preferred language have one
+    #[derive(Default)]
+    struct MockCustomerRepository {
+        languages: Mutex<HashMap<Uuid, [u8; 3]>>,
+    }
+
+    #[async_trait]
+    impl CustomerRepository for MockCustomerRepository {
+        async fn find_by_id(&self, customer_id: Uuid) -> BankingResult<Option<CustomerModel>> {
+            Ok(Some(CustomerModel {
+                id: customer_id,
+                customer_type: CustomerType::Individual,
+                full_name: HeaplessString::try_from("Jane Doe").unwrap(),
+                id_type: IdentityType::NationalId,
+                id_number: HeaplessString::try_from("ID654321").unwrap(),
+                risk_rating: RiskRating::Low,
+                status: CustomerStatus::Active,
+                created_at: Utc::now(),
+                last_updated_at: Utc::now(),
+                updated_by_person_id: Uuid::new_v4(),
+                preferred_language_code: self.languages.lock().unwrap().get(&customer_id).copied(),
+                duplicate_of_customer_id: None,
+            }))
+        }
+
+        async fn exists(&self, _customer_id: Uuid) -> BankingResult<bool> { Ok(true) }
+        async fn create(&self, _customer: CustomerModel) -> BankingResult<CustomerModel> { unimplemented!() }
+        async fn update(&self, _customer: CustomerModel) -> BankingResult<CustomerModel> { unimplemented!() }
+        async fn find_by_ids(&self, _customer_ids: &[Uuid]) -> BankingResult<Vec<CustomerModel>> { unimplemented!() }
+        async fn find_by_identity(&self, _id_type: IdentityType, _id_number: &str) -> BankingResult<Option<CustomerModel>> { unimplemented!() }
+        async fn find_by_risk_rating(&self, _risk_rating: RiskRating) -> BankingResult<Vec<CustomerModel>> { unimplemented!() }
+        async fn find_requiring_review(&self) -> BankingResult<Vec<CustomerModel>> { unimplemented!() }
+        async fn get_portfolio(&self, _customer_id: Uuid) -> BankingResult<Option<CustomerPortfolioModel>> { unimplemented!() }
+        async fn search(&self, _criteria: CustomerSearchCriteriaModel) -> BankingResult<Vec<CustomerModel>> { unimplemented!() }
+        async fn update_risk_rating(&self, _customer_id: Uuid, _risk_rating: RiskRating, _authorized_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn update_status(&self, _customer_id: Uuid, _status: CustomerStatus, _reason: &str) -> BankingResult<()> { unimplemented!() }
+        async fn add_document(&self, _document: CustomerDocumentModel) -> BankingResult<CustomerDocumentModel> { unimplemented!() }
+        async fn get_documents(&self, _customer_id: Uuid) -> BankingResult<Vec<CustomerDocumentModel>> { unimplemented!() }
+        async fn add_audit_entry(&self, _audit: CustomerAuditModel) -> BankingResult<CustomerAuditModel> { unimplemented!() }
+        async fn get_audit_trail(&self, _customer_id: Uuid) -> BankingResult<Vec<CustomerAuditModel>> { unimplemented!() }
+        async fn merge_into(&self, _survivor_id: Uuid, _duplicate_id: Uuid, _reason: &str, _merged_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn delete(&self, _customer_id: Uuid, _deleted_by: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn list(&self, _offset: i64, _limit: i64) -> BankingResult<Vec<CustomerModel>> { unimplemented!() }
+        async fn count(&self) -> BankingResult<i64> { unimplemented!() }
+    }
+
+    #[derive(Default)]
+    struct MockContactPreferenceRepository {
+        preferences: Mutex<Vec<ContactPreferenceModel>>,
+    }
+
+    #[async_trait]
+    impl ContactPreferenceRepository<Postgres> for MockContactPreferenceRepository {
+        async fn save(&self, preference: ContactPreferenceModel) -> ContactPreferenceResult<ContactPreferenceModel> {
+            let mut preferences = self.preferences.lock().unwrap();
+            preferences.retain(|p| p.person_id != preference.person_id || p.channel != preference.channel);
+            preferences.push(preference.clone());
+            Ok(preference)
+        }
+
+        async fn find_by_id(&self, id: Uuid) -> ContactPreferenceResult<Option<ContactPreferenceModel>> {
+            Ok(self.preferences.lock().unwrap().iter().find(|p| p.id == id).cloned())
+        }
+
+        async fn find_by_person_id(&self, person_id: Uuid) -> ContactPreferenceResult<Vec<ContactPreferenceModel>> {
+            Ok(self.preferences.lock().unwrap().iter().filter(|p| p.person_id == person_id).cloned().collect())
+        }
+
+        async fn find_by_person_and_channel(
+            &self,
+            person_id: Uuid,
+            channel: ContactChannel,
+        ) -> ContactPreferenceResult<Option<ContactPreferenceModel>> {
+            Ok(self.preferences.lock().unwrap().iter().find(|p| p.person_id == person_id && p.channel == channel).cloned())
+        }
+
+        async fn delete(&self, _id: Uuid) -> ContactPreferenceResult<()> { unimplemented!() }
+    }
+
+    fn template_request(template_code: &str, language_code: [u8; 3], body: &str) -> MessageTemplateRequest {
+        MessageTemplateRequest {
+            template_code: template_code.to_string(),
+            language_code,
+            channel: MessageChannel::Sms,
+            subject: None,
+            body: body.to_string(),
+            regulatory: false,
+        }
+    }
+
+    /// Service over the given repositories with the default-language wording
+    /// the migration seeds
+    async fn service(
+        repository: Arc<MockNotificationRepository>,
+        customers: Arc<MockCustomerRepository>,
+    ) -> NotificationServiceImpl {
+        service_with_preferences(repository, customers, Arc::default()).await
+    }
+
+    async fn service_with_preferences(
+        repository: Arc<MockNotificationRepository>,
+        customers: Arc<MockCustomerRepository>,
+        preferences: Arc<MockContactPreferenceRepository>,
+    ) -> NotificationServiceImpl {
+        let service = NotificationServiceImpl::new(repository, customers, preferences, Arc::new(BankingConfig::default()));
+        for template in [NotificationTemplate::DormancyNotice, NotificationTemplate::CollectionReminder] {
+            service.save_message_template(template_request(template.code(), *b"eng", template.body())).await.unwrap();
+        }
+        service
+    }
+
+    fn params(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect()
+    }
+
+    fn business_date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, 30).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_rerun_of_dormancy_step_queues_one_notice_per_customer() {
+        let repository = Arc::new(MockNotificationRepository::default());
+        let service = service(repository.clone(), Arc::default()).await;
+        let dormant_accounts = [(Uuid::new_v4(), Uuid::new_v4()), (Uuid::new_v4(), Uuid::new_v4())];
+
+        // The step fails after the notices and is run again twice
+        for _ in 0..3 {
+            for (customer_id, account_id) in dormant_accounts {
+                service.queue_dormancy_notice(customer_id, account_id, business_date()).await.unwrap();
+            }
+        }
+
+        for (customer_id, account_id) in dormant_accounts {
+            let notices = service.find_notifications_by_customer(customer_id, business_date()).await.unwrap();
+            assert_eq!(notices.len(), 1);
+            assert!(notices[0].body.contains(account_suffix(account_id).as_str()));
+        }
+        let report = service.get_duplicate_report(business_date()).await.unwrap();
+        assert_eq!(report.by_template.len(), 1);
+        assert_eq!(report.by_template[0].notifications_queued, 2);
+        assert_eq!(report.total_suppressed(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_returns_original_and_other_context_is_queued() {
+        let repository = Arc::new(MockNotificationRepository::default());
+        let service = service(repository.clone(), Arc::default()).await;
+        let customer_id = Uuid::new_v4();
+        let due_date = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+
+        let first = service.queue_collection_reminder(customer_id, Decimal::from(5000), due_date, business_date()).await.unwrap();
+        let again = service.queue_collection_reminder(customer_id, Decimal::from(5000), due_date, business_date()).await.unwrap();
+        let other = service.queue_collection_reminder(customer_id, Decimal::from(7000), due_date, business_date()).await.unwrap();
+
+        assert!(!first.was_duplicate);
+        assert!(again.was_duplicate);
+        assert_eq!(again.notification.id, first.notification.id);
+        assert!(!other.was_duplicate);
+        assert_eq!(service.find_notifications_by_customer(customer_id, business_date()).await.unwrap().len(), 2);
+
+        // Past the retention period the keys are gone
+        let retention_days = BankingConfig::default().notifications.key_retention_days;
+        let purge_date = business_date() + Duration::days(retention_days + 1);
+        assert_eq!(service.purge_expired_keys(purge_date).await.unwrap(), 2);
+        assert!(service.get_duplicate_report(business_date()).await.unwrap().by_template.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_render_message_falls_back_to_default_language() {
+        let service = service(Arc::default(), Arc::default()).await;
+        service
+            .save_message_template(template_request("COLLECTION_REMINDER", *b"fra", "Votre cotisation de {amount} est due le {due_date}."))
+            .await
+            .unwrap();
+        let values = params(&[("amount", "5000"), ("due_date", "2024-07-01")]);
+
+        let french = service.render_message("COLLECTION_REMINDER", &[*b"swa", *b"fra"], &values).await.unwrap();
+        assert_eq!(french.language_code, *b"fra");
+        assert_eq!(french.body, "Votre cotisation de 5000 est due le 2024-07-01.");
+
+        let english = service.render_message("COLLECTION_REMINDER", &[*b"swa"], &values).await.unwrap();
+        assert_eq!(english.language_code, *b"eng");
+        assert_eq!(english.body, "Your contribution of 5000 is due on 2024-07-01.");
+
+        assert!(matches!(
+            service.render_message("UNKNOWN_TEMPLATE", &[*b"eng"], &values).await,
+            Err(BankingError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_render_message_rejects_unresolved_placeholder() {
+        let service = service(Arc::default(), Arc::default()).await;
+
+        let result = service.render_message("COLLECTION_REMINDER", &[], &params(&[("amount", "5000")])).await;
+
+        match result {
+            Err(BankingError::ValidationError { field, message }) => {
+                assert_eq!(field, "params");
+                assert!(message.contains("{due_date}"));
+            }
+            other => panic!("expected a validation error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_saved_template_renders_in_customer_language() {
+        let repository = Arc::new(MockNotificationRepository::default());
+        let customers = Arc::new(MockCustomerRepository::default());
+        let service = service(repository.clone(), customers.clone()).await;
+        let saved = service
+            .save_message_template(MessageTemplateRequest {
+                subject: Some("Compte {account_suffix}".to_string()),
+                channel: MessageChannel::Email,
+                ..template_request("DORMANCY_NOTICE", *b"fra", "Votre compte se terminant par {account_suffix} est inactif.")
+            })
+            .await
+            .unwrap();
+        let customer_id = Uuid::new_v4();
+        let account_id = Uuid::new_v4();
+        customers.languages.lock().unwrap().insert(customer_id, *b"fra");
+
+        let rendered = service
+            .render_message("DORMANCY_NOTICE", &[*b"fra"], &params(&[("account_suffix", "ABC123")]))
+            .await
+            .unwrap();
+        assert_eq!(rendered.template_code, saved.template_code);
+        assert_eq!(rendered.channel, MessageChannel::Email);
+        assert_eq!(rendered.subject.as_deref(), Some("Compte ABC123"));
+        assert_eq!(rendered.body, "Votre compte se terminant par ABC123 est inactif.");
+
+        let outcome = service.queue_dormancy_notice(customer_id, account_id, business_date()).await.unwrap();
+        assert_eq!(
+            outcome.notification.body.as_str(),
+            format!("Votre compte se terminant par {} est inactif.", account_suffix(account_id))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_rejects_template_longer_than_stored_bounds() {
+        let repository = Arc::new(MockNotificationRepository::default());
+        let service = service(repository.clone(), Arc::default()).await;
+
+        let result = service
+            .save_message_template(template_request("STATEMENT_READY", *b"eng", &"x".repeat(501)))
+            .await;
+
+        match result {
+            Err(BankingError::ValidationError { field, message }) => {
+                assert_eq!(field, "body");
+                assert_eq!(message, "Template body is 501 bytes; at most 500 can be stored");
+            }
+            other => panic!("expected a validation error, got {other:?}"),
+        }
+        assert!(repository.find_message_templates("STATEMENT_READY").await.unwrap().is_empty());
+    }
+
+    fn preference(person_id: Uuid, channel: ContactChannel, allowed: bool, quiet_hours: Option<(u32, u32)>) -> ContactPreferenceModel {
+        ContactPreferenceModel {
+            id: Uuid::new_v4(),
+            person_id,
+            channel,
+            allowed,
+            quiet_hours_start: quiet_hours.and_then(|(start, _)| NaiveTime::from_hms_opt(start, 0, 0)),
+            quiet_hours_end: quiet_hours.and_then(|(_, end)| NaiveTime::from_hms_opt(end, 0, 0)),
+            preferred_language: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn send_request(template_code: &str, person_id: Uuid, requested_at: DateTime<Utc>) -> MessageSendRequest {
+        MessageSendRequest {
+            template_code: template_code.to_string(),
+            person_id,
+            channels: vec![MessageChannel::Sms, MessageChannel::Email],
+            params: params(&[("rate", "4%")]),
+            requested_at,
+        }
+    }
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        NaiveDate::from_ymd_opt(2024, 6, day).unwrap().and_hms_opt(hour, minute, 0).unwrap().and_utc()
+    }
+
+    #[tokio::test]
+    async fn test_marketing_message_suppressed_on_opted_out_channel() {
+        let preferences = Arc::new(MockContactPreferenceRepository::default());
+        let service = service_with_preferences(Arc::default(), Arc::default(), preferences.clone()).await;
+        service
+            .save_message_template(template_request("SAVINGS_OFFER", *b"eng", "Save now at {rate}."))
+            .await
+            .unwrap();
+        let person_id = Uuid::new_v4();
+        preferences.save(preference(person_id, ContactChannel::Sms, false, None)).await.unwrap();
+
+        let dispatches = service.render_and_send(send_request("SAVINGS_OFFER", person_id, at(10, 12, 0))).await.unwrap();
+
+        assert_eq!(dispatches[0].channel, MessageChannel::Sms);
+        assert_eq!(dispatches[0].status, DispatchStatus::Suppressed);
+        assert!(dispatches[0].send_at.is_none() && dispatches[0].message.is_none());
+        // No preference stored for email
+        assert_eq!(dispatches[1].status, DispatchStatus::Send);
+        assert_eq!(dispatches[1].send_at, Some(at(10, 12, 0)));
+        assert_eq!(dispatches[1].message.as_ref().unwrap().body, "Save now at 4%.");
+    }
+
+    #[tokio::test]
+    async fn test_quiet_hours_defer_send_in_preferred_language() {
+        let preferences = Arc::new(MockContactPreferenceRepository::default());
+        let service = service_with_preferences(Arc::default(), Arc::default(), preferences.clone()).await;
+        service
+            .save_message_template(template_request("SAVINGS_OFFER", *b"eng", "Save now at {rate}."))
+            .await
+            .unwrap();
+        service
+            .save_message_template(template_request("SAVINGS_OFFER", *b"fra", "Epargnez maintenant a {rate}."))
+            .await
+            .unwrap();
+        let person_id = Uuid::new_v4();
+        let mut sms = preference(person_id, ContactChannel::Sms, true, Some((21, 7)));
+        sms.preferred_language = Some(*b"fra");
+        preferences.save(sms).await.unwrap();
+        preferences.save(preference(person_id, ContactChannel::Email, true, Some((12, 14)))).await.unwrap();
+
+        let dispatches = service.render_and_send(send_request("SAVINGS_OFFER", person_id, at(10, 22, 30))).await.unwrap();
+
+        assert_eq!(dispatches[0].status, DispatchStatus::Deferred);
+        assert_eq!(dispatches[0].send_at, Some(at(11, 7, 0)));
+        let message = dispatches[0].message.as_ref().unwrap();
+        assert_eq!(message.language_code, *b"fra");
+        assert_eq!(message.body, "Epargnez maintenant a 4%.");
+        // Outside the email window, in the default language
+        assert_eq!(dispatches[1].status, DispatchStatus::Send);
+        assert_eq!(dispatches[1].send_at, Some(at(10, 22, 30)));
+        assert_eq!(dispatches[1].message.as_ref().unwrap().language_code, *b"eng");
+    }
+
+    #[tokio::test]
+    async fn test_regulatory_message_overrides_opt_out_but_not_quiet_hours() {
+        let preferences = Arc::new(MockContactPreferenceRepository::default());
+        let service = service_with_preferences(Arc::default(), Arc::default(), preferences.clone()).await;
+        service
+            .save_message_template(MessageTemplateRequest {
+                regulatory: true,
+                ..template_request("RATE_CHANGE_NOTICE", *b"eng", "Your savings rate changes to {rate}.")
+            })
+            .await
+            .unwrap();
+        let person_id = Uuid::new_v4();
+        preferences.save(preference(person_id, ContactChannel::Sms, false, Some((21, 7)))).await.unwrap();
+        preferences.save(preference(person_id, ContactChannel::Email, false, None)).await.unwrap();
+
+        let daytime = service.render_and_send(send_request("RATE_CHANGE_NOTICE", person_id, at(10, 12, 0))).await.unwrap();
+        assert!(daytime.iter().all(|dispatch| dispatch.status == DispatchStatus::Send));
+        assert!(daytime.iter().all(|dispatch| dispatch.message.as_ref().is_some_and(|message| message.regulatory)));
+
+        let night = service.render_and_send(send_request("RATE_CHANGE_NOTICE", person_id, at(10, 23, 0))).await.unwrap();
+        assert_eq!(night[0].status, DispatchStatus::Deferred);
+        assert_eq!(night[0].send_at, Some(at(11, 7, 0)));
+        assert_eq!(night[1].status, DispatchStatus::Send);
+    }
+}
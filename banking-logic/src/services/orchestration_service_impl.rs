@@ -0,0 +1,574 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use heapless::String as HeaplessString;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        AccountStatus, CompensationAction, IncompleteOrchestration, Orchestration, OrchestrationStatus,
+        OrchestrationStep, OrchestrationStepStatus, OrchestrationType,
+    },
+    service::OrchestrationService,
+};
+use banking_db::models::{DbAccountStatus, DbBundleStatus};
+use banking_db::repository::{AccountRepository, BundleRepository, OrchestrationRepository, ProductRepository};
+use crate::mappers::OrchestrationMapper;
+
+/// Executes compensation actions. Implementations must be idempotent: a retry
+/// may run an action whose effect is already in place.
+#[async_trait]
+pub trait CompensationHandler: Send + Sync {
+    async fn compensate(&self, action: &CompensationAction, performed_by_person_id: Uuid) -> BankingResult<()>;
+}
+
+/// Compensation handler writing the recorded values back through the repositories
+pub struct RepositoryCompensationHandler {
+    account_repository: Arc<dyn AccountRepository>,
+    product_repository: Arc<dyn ProductRepository>,
+    bundle_repository: Arc<dyn BundleRepository>,
+}
+
+impl RepositoryCompensationHandler {
+    pub fn new(
+        account_repository: Arc<dyn AccountRepository>,
+        product_repository: Arc<dyn ProductRepository>,
+        bundle_repository: Arc<dyn BundleRepository>,
+    ) -> Self {
+        Self {
+            account_repository,
+            product_repository,
+            bundle_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl CompensationHandler for RepositoryCompensationHandler {
+    #[allow(deprecated)]
+    async fn compensate(&self, action: &CompensationAction, performed_by_person_id: Uuid) -> BankingResult<()> {
+        match action {
+            CompensationAction::RestoreAccountStatuses { accounts } => {
+                for snapshot in accounts {
+                    self.account_repository
+                        .update_status_legacy(
+                            snapshot.account_id,
+                            &snapshot.status.to_string(),
+                            "Compensation of failed orchestration",
+                            performed_by_person_id,
+                        )
+                        .await?;
+                }
+            }
+            CompensationAction::RestoreMandateStatuses { mandates } => {
+                for snapshot in mandates {
+                    self.account_repository
+                        .update_mandate_status(snapshot.mandate_id, &snapshot.status.to_string())
+                        .await?;
+                }
+            }
+            CompensationAction::RestoreProductOverdraftRate { product_id, overdraft_interest_rate } => {
+                let mut product = self.product_repository
+                    .find_product_by_id(*product_id)
+                    .await?
+                    .ok_or(BankingError::ProductNotFound(*product_id))?;
+                product.rules.overdraft_interest_rate = *overdraft_interest_rate;
+                product.last_updated_at = Utc::now();
+                product.updated_by_person_id = performed_by_person_id;
+                self.product_repository.update_product(product).await?;
+            }
+            CompensationAction::RestoreLoanInterestRates { accounts } => {
+                for snapshot in accounts {
+                    let mut account = self.account_repository
+                        .find_by_id(snapshot.account_id)
+                        .await?
+                        .ok_or(BankingError::AccountNotFound(snapshot.account_id))?;
+                    account.loan_interest_rate = snapshot.loan_interest_rate;
+                    account.last_updated_at = Utc::now();
+                    account.updated_by_person_id = performed_by_person_id;
+                    self.account_repository.update(account).await?;
+                }
+            }
+            CompensationAction::CloseOpenedAccount { account_id } => {
+                let opened = self.account_repository.find_by_id(*account_id).await?;
+                if opened.is_some_and(|a| a.account_status != DbAccountStatus::Closed) {
+                    self.account_repository
+                        .update_status_legacy(
+                            *account_id,
+                            &AccountStatus::Closed.to_string(),
+                            "Compensation of failed orchestration",
+                            performed_by_person_id,
+                        )
+                        .await?;
+                }
+            }
+            CompensationAction::CancelBundle { account_bundle_id } => {
+                if let Some(mut bundle) = self.bundle_repository.find_account_bundle_by_id(*account_bundle_id).await? {
+                    let now = Utc::now();
+                    let markers: Vec<Uuid> = self.bundle_repository
+                        .find_pricing_markers_by_bundle(bundle.id)
+                        .await?
+                        .into_iter()
+                        .filter(|m| m.removed_at.is_none())
+                        .map(|m| m.id)
+                        .collect();
+                    if !markers.is_empty() {
+                        self.bundle_repository.remove_pricing_markers(&markers, now).await?;
+                    }
+                    if bundle.status != DbBundleStatus::Cancelled {
+                        bundle.status = DbBundleStatus::Cancelled;
+                        self.bundle_repository.update_account_bundle(bundle).await?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Production implementation of OrchestrationService
+pub struct OrchestrationServiceImpl {
+    orchestration_repository: Arc<dyn OrchestrationRepository>,
+    compensation_handler: Arc<dyn CompensationHandler>,
+}
+
+impl OrchestrationServiceImpl {
+    pub fn new(
+        orchestration_repository: Arc<dyn OrchestrationRepository>,
+        compensation_handler: Arc<dyn CompensationHandler>,
+    ) -> Self {
+        Self {
+            orchestration_repository,
+            compensation_handler,
+        }
+    }
+
+    async fn load(&self, orchestration_id: Uuid) -> BankingResult<Orchestration> {
+        self.orchestration_repository
+            .find_orchestration_by_id(orchestration_id)
+            .await?
+            .map(OrchestrationMapper::from_model)
+            .ok_or_else(|| BankingError::NotFound(format!("Orchestration {orchestration_id} not found")))
+    }
+
+    async fn load_steps(&self, orchestration_id: Uuid) -> BankingResult<Vec<OrchestrationStep>> {
+        self.orchestration_repository
+            .find_steps(orchestration_id)
+            .await?
+            .into_iter()
+            .map(OrchestrationMapper::step_from_model)
+            .collect()
+    }
+
+    async fn save(&self, orchestration: Orchestration) -> BankingResult<Orchestration> {
+        let saved = self.orchestration_repository
+            .update_orchestration(OrchestrationMapper::to_model(orchestration))
+            .await?;
+        Ok(OrchestrationMapper::from_model(saved))
+    }
+
+    fn require_status(orchestration: &Orchestration, allowed: &[OrchestrationStatus]) -> BankingResult<()> {
+        if allowed.contains(&orchestration.status) {
+            Ok(())
+        } else {
+            Err(BankingError::ValidationError {
+                field: "status".to_string(),
+                message: format!("Orchestration {} is {:?}", orchestration.id, orchestration.status),
+            })
+        }
+    }
+
+    /// Runs the pending compensations, last completed step first. Stops at the
+    /// first failure so that earlier steps are never undone on top of a later
+    /// step that is still in effect; a retry resumes from the failed step.
+    async fn run_compensations(&self, mut orchestration: Orchestration) -> BankingResult<Orchestration> {
+        orchestration.status = OrchestrationStatus::Compensating;
+        let orchestration = self.save(orchestration).await?;
+
+        let steps = self.load_steps(orchestration.id).await?;
+        let mut failed = false;
+        for step in OrchestrationStep::pending_compensations(&steps) {
+            let Some(action) = step.compensation.as_ref() else {
+                continue;
+            };
+            match self.compensation_handler.compensate(action, orchestration.initiated_by_person_id).await {
+                Ok(()) => {
+                    self.orchestration_repository
+                        .update_step_status(
+                            step.id,
+                            OrchestrationMapper::step_status_to_db(OrchestrationStepStatus::Compensated),
+                            Some(Utc::now()),
+                            None,
+                        )
+                        .await?;
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "Compensation of step {} ({}) of orchestration {} failed: {e}",
+                        step.sequence,
+                        step.step_name,
+                        orchestration.id
+                    );
+                    self.orchestration_repository
+                        .update_step_status(
+                            step.id,
+                            OrchestrationMapper::step_status_to_db(OrchestrationStepStatus::CompensationFailed),
+                            None,
+                            Some(truncate(&e.to_string())),
+                        )
+                        .await?;
+                    failed = true;
+                    break;
+                }
+            }
+        }
+
+        let mut orchestration = orchestration;
+        if failed {
+            orchestration.status = OrchestrationStatus::CompensationFailed;
+        } else {
+            orchestration.status = OrchestrationStatus::Compensated;
+            orchestration.finished_at = Some(Utc::now());
+        }
+        self.save(orchestration).await
+    }
+}
+
+fn truncate(message: &str) -> HeaplessString<255> {
+    let mut end = message.len().min(255);
+    while !message.is_char_boundary(end) {
+        end -= 1;
+    }
+    HeaplessString::try_from(&message[..end]).unwrap_or_default()
+}
+
+#[async_trait]
+impl OrchestrationService for OrchestrationServiceImpl {
+    async fn start_orchestration(
+        &self,
+        orchestration_type: OrchestrationType,
+        subject_id: Uuid,
+        initiated_by_person_id: Uuid,
+    ) -> BankingResult<Orchestration> {
+        let orchestration = Orchestration {
+            id: Uuid::new_v4(),
+            orchestration_type,
+            subject_id,
+            status: OrchestrationStatus::Running,
+            failure_reason: None,
+            started_at: Utc::now(),
+            finished_at: None,
+            initiated_by_person_id,
+            resolved_by_person_id: None,
+            resolution_note: None,
+        };
+        let created = self.orchestration_repository
+            .create_orchestration(OrchestrationMapper::to_model(orchestration))
+            .await?;
+        Ok(OrchestrationMapper::from_model(created))
+    }
+
+    async fn record_step(
+        &self,
+        orchestration_id: Uuid,
+        step_name: &str,
+        compensation: Option<CompensationAction>,
+    ) -> BankingResult<OrchestrationStep> {
+        let orchestration = self.load(orchestration_id).await?;
+        Self::require_status(&orchestration, &[OrchestrationStatus::Running])?;
+
+        let step_name = HeaplessString::try_from(step_name).map_err(|_| BankingError::ValidationError {
+            field: "step_name".to_string(),
+            message: "Step name too long".to_string(),
+        })?;
+        let sequence = self.orchestration_repository.find_steps(orchestration_id).await?.len() as i32 + 1;
+
+        let step = OrchestrationStep {
+            id: Uuid::new_v4(),
+            orchestration_id,
+            sequence,
+            step_name,
+            compensation,
+            status: OrchestrationStepStatus::Completed,
+            completed_at: Utc::now(),
+            compensated_at: None,
+            last_error: None,
+        };
+        let created = self.orchestration_repository
+            .create_step(OrchestrationMapper::step_to_model(step)?)
+            .await?;
+        OrchestrationMapper::step_from_model(created)
+    }
+
+    async fn complete_orchestration(&self, orchestration_id: Uuid) -> BankingResult<Orchestration> {
+        let mut orchestration = self.load(orchestration_id).await?;
+        Self::require_status(&orchestration, &[OrchestrationStatus::Running])?;
+
+        orchestration.status = OrchestrationStatus::Completed;
+        orchestration.finished_at = Some(Utc::now());
+        self.save(orchestration).await
+    }
+
+    async fn fail_orchestration(&self, orchestration_id: Uuid, failure_reason: &str) -> BankingResult<Orchestration> {
+        let mut orchestration = self.load(orchestration_id).await?;
+        Self::require_status(&orchestration, &[OrchestrationStatus::Running])?;
+
+        orchestration.failure_reason = Some(truncate(failure_reason));
+        self.run_compensations(orchestration).await
+    }
+
+    async fn find_incomplete_orchestrations(&self) -> BankingResult<Vec<IncompleteOrchestration>> {
+        let models = self.orchestration_repository.find_incomplete_orchestrations().await?;
+        let mut incomplete = Vec::with_capacity(models.len());
+        for model in models {
+            let orchestration = OrchestrationMapper::from_model(model);
+            let steps = self.load_steps(orchestration.id).await?;
+            let pending_compensations = OrchestrationStep::pending_compensations(&steps)
+                .into_iter()
+                .cloned()
+                .collect();
+            incomplete.push(IncompleteOrchestration {
+                orchestration,
+                pending_compensations,
+            });
+        }
+        Ok(incomplete)
+    }
+
+    async fn retry_compensation(&self, orchestration_id: Uuid) -> BankingResult<Orchestration> {
+        let orchestration = self.load(orchestration_id).await?;
+        // Compensating covers a runner that stopped midway, e.g. on a crash
+        Self::require_status(
+            &orchestration,
+            &[OrchestrationStatus::Compensating, OrchestrationStatus::CompensationFailed],
+        )?;
+
+        self.run_compensations(orchestration).await
+    }
+
+    async fn resolve_manually(
+        &self,
+        orchestration_id: Uuid,
+        resolved_by_person_id: Uuid,
+        resolution_note: HeaplessString<255>,
+    ) -> BankingResult<Orchestration> {
+        let mut orchestration = self.load(orchestration_id).await?;
+        if orchestration.status.is_terminal() {
+            return Err(BankingError::ValidationError {
+                field: "status".to_string(),
+                message: format!("Orchestration {} is already {:?}", orchestration.id, orchestration.status),
+            });
+        }
+
+        orchestration.status = OrchestrationStatus::ManuallyResolved;
+        orchestration.resolved_by_person_id = Some(resolved_by_person_id);
+        orchestration.resolution_note = Some(resolution_note);
+        orchestration.finished_at = Some(Utc::now());
+        self.save(orchestration).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use banking_api::domain::AccountStatusSnapshot;
+    use banking_db::models::{DbOrchestrationStatus, DbOrchestrationStepStatus, OrchestrationModel, OrchestrationStepModel};
+    use chrono::DateTime;
+
+    #[derive(Default)]
+    struct MockOrchestrationRepository {
+        orchestrations: Mutex<HashMap<Uuid, OrchestrationModel>>,
+        steps: Mutex<Vec<OrchestrationStepModel>>,
+    }
+
+    #[async_trait]
+    impl OrchestrationRepository for MockOrchestrationRepository {
+        async fn create_orchestration(&self, orchestration: OrchestrationModel) -> BankingResult<OrchestrationModel> {
+            self.orchestrations.lock().unwrap().insert(orchestration.id, orchestration.clone());
+            Ok(orchestration)
+        }
+
+        async fn find_orchestration_by_id(&self, orchestration_id: Uuid) -> BankingResult<Option<OrchestrationModel>> {
+            Ok(self.orchestrations.lock().unwrap().get(&orchestration_id).cloned())
+        }
+
+        async fn update_orchestration(&self, orchestration: OrchestrationModel) -> BankingResult<OrchestrationModel> {
+            self.orchestrations.lock().unwrap().insert(orchestration.id, orchestration.clone());
+            Ok(orchestration)
+        }
+
+        async fn find_incomplete_orchestrations(&self) -> BankingResult<Vec<OrchestrationModel>> {
+            Ok(self.orchestrations.lock().unwrap()
+                .values()
+                .filter(|o| !matches!(
+                    o.status,
+                    DbOrchestrationStatus::Completed | DbOrchestrationStatus::Compensated | DbOrchestrationStatus::ManuallyResolved
+                ))
+                .cloned()
+                .collect())
+        }
+
+        async fn create_step(&self, step: OrchestrationStepModel) -> BankingResult<OrchestrationStepModel> {
+            self.steps.lock().unwrap().push(step.clone());
+            Ok(step)
+        }
+
+        async fn find_steps(&self, orchestration_id: Uuid) -> BankingResult<Vec<OrchestrationStepModel>> {
+            let mut steps: Vec<OrchestrationStepModel> = self.steps.lock().unwrap()
+                .iter()
+                .filter(|s| s.orchestration_id == orchestration_id)
+                .cloned()
+                .collect();
+            steps.sort_by_key(|s| s.sequence);
+            Ok(steps)
+        }
+
+        async fn update_step_status(
+            &self,
+            step_id: Uuid,
+            status: DbOrchestrationStepStatus,
+            compensated_at: Option<DateTime<Utc>>,
+            last_error: Option<HeaplessString<255>>,
+        ) -> BankingResult<()> {
+            let mut steps = self.steps.lock().unwrap();
+            let step = steps.iter_mut().find(|s| s.id == step_id).unwrap();
+            step.status = status;
+            step.compensated_at = compensated_at;
+            step.last_error = last_error;
+            Ok(())
+        }
+    }
+
+    /// Records the account ids of executed compensations, failing for the configured account
+    #[derive(Default)]
+    struct RecordingCompensationHandler {
+        executed: Mutex<Vec<Uuid>>,
+        failing_account: Mutex<Option<Uuid>>,
+    }
+
+    #[async_trait]
+    impl CompensationHandler for RecordingCompensationHandler {
+        async fn compensate(&self, action: &CompensationAction, _performed_by_person_id: Uuid) -> BankingResult<()> {
+            let CompensationAction::RestoreAccountStatuses { accounts } = action else {
+                panic!("Unexpected action {action:?}");
+            };
+            let account_id = accounts[0].account_id;
+            if *self.failing_account.lock().unwrap() == Some(account_id) {
+                return Err(BankingError::Internal("Account locked".to_string()));
+            }
+            self.executed.lock().unwrap().push(account_id);
+            Ok(())
+        }
+    }
+
+    fn restore(account_id: Uuid) -> Option<CompensationAction> {
+        Some(CompensationAction::RestoreAccountStatuses {
+            accounts: vec![AccountStatusSnapshot { account_id, status: AccountStatus::Active }],
+        })
+    }
+
+    /// Runs a five step orchestration that fails after step 3. Returns the
+    /// service, the handler and the account id compensated by each completed step.
+    async fn fail_after_step_3(
+        failing_step: Option<usize>,
+    ) -> (OrchestrationServiceImpl, Arc<RecordingCompensationHandler>, Uuid, Vec<Uuid>) {
+        let repository = Arc::new(MockOrchestrationRepository::default());
+        let handler = Arc::new(RecordingCompensationHandler::default());
+        let service = OrchestrationServiceImpl::new(repository, handler.clone());
+
+        let orchestration = service
+            .start_orchestration(OrchestrationType::CustomerOffboarding, Uuid::new_v4(), Uuid::new_v4())
+            .await
+            .unwrap();
+
+        let step_accounts: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        if let Some(index) = failing_step {
+            *handler.failing_account.lock().unwrap() = Some(step_accounts[index]);
+        }
+        for (index, account_id) in step_accounts.iter().enumerate() {
+            service
+                .record_step(orchestration.id, &format!("step_{}", index + 1), restore(*account_id))
+                .await
+                .unwrap();
+        }
+
+        (service, handler, orchestration.id, step_accounts)
+    }
+
+    #[tokio::test]
+    async fn test_failure_after_step_3_compensates_in_reverse_order() {
+        let (service, handler, orchestration_id, step_accounts) = fail_after_step_3(None).await;
+
+        // Step 4 of 5 fails
+        let orchestration = service.fail_orchestration(orchestration_id, "Step 4 failed").await.unwrap();
+
+        assert_eq!(
+            *handler.executed.lock().unwrap(),
+            vec![step_accounts[2], step_accounts[1], step_accounts[0]]
+        );
+        assert_eq!(orchestration.status, OrchestrationStatus::Compensated);
+        assert!(orchestration.status.is_terminal());
+        assert!(orchestration.finished_at.is_some());
+        assert!(service.find_incomplete_orchestrations().await.unwrap().is_empty());
+
+        let steps = service.load_steps(orchestration_id).await.unwrap();
+        assert_eq!(steps.len(), 3);
+        assert!(steps.iter().all(|s| s.status == OrchestrationStepStatus::Compensated));
+
+        // Compensated orchestrations cannot be compensated again
+        assert!(service.retry_compensation(orchestration_id).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_failed_compensation_is_listed_for_manual_resolution() {
+        // The compensation of step 2 fails
+        let (service, handler, orchestration_id, step_accounts) = fail_after_step_3(Some(1)).await;
+
+        let orchestration = service.fail_orchestration(orchestration_id, "Step 4 failed").await.unwrap();
+        assert_eq!(orchestration.status, OrchestrationStatus::CompensationFailed);
+        // Step 1 is not undone while step 2 is still in effect
+        assert_eq!(*handler.executed.lock().unwrap(), vec![step_accounts[2]]);
+
+        let incomplete = service.find_incomplete_orchestrations().await.unwrap();
+        assert_eq!(incomplete.len(), 1);
+        let pending: Vec<i32> = incomplete[0].pending_compensations.iter().map(|s| s.sequence).collect();
+        assert_eq!(pending, vec![2, 1]);
+        assert_eq!(incomplete[0].pending_compensations[0].status, OrchestrationStepStatus::CompensationFailed);
+        assert!(incomplete[0].pending_compensations[0].last_error.is_some());
+
+        // Retrying still fails, so the operator resolves it by hand
+        let retried = service.retry_compensation(orchestration_id).await.unwrap();
+        assert_eq!(retried.status, OrchestrationStatus::CompensationFailed);
+        assert_eq!(*handler.executed.lock().unwrap(), vec![step_accounts[2]]);
+
+        let resolved = service
+            .resolve_manually(
+                orchestration_id,
+                Uuid::new_v4(),
+                HeaplessString::try_from("Mandate restored by back office").unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resolved.status, OrchestrationStatus::ManuallyResolved);
+        assert!(service.find_incomplete_orchestrations().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_retry_resumes_from_failed_compensation() {
+        let (service, handler, orchestration_id, step_accounts) = fail_after_step_3(Some(1)).await;
+        service.fail_orchestration(orchestration_id, "Step 4 failed").await.unwrap();
+
+        *handler.failing_account.lock().unwrap() = None;
+        let retried = service.retry_compensation(orchestration_id).await.unwrap();
+
+        assert_eq!(retried.status, OrchestrationStatus::Compensated);
+        // Step 3 is not compensated a second time
+        assert_eq!(
+            *handler.executed.lock().unwrap(),
+            vec![step_accounts[2], step_accounts[1], step_accounts[0]]
+        );
+    }
+}
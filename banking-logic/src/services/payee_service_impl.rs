@@ -0,0 +1,252 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{Payee, PayeeConfirmation, PayeeVerificationMethod, PayeeVerificationStatus},
+    service::PayeeService,
+};
+use banking_db::repository::PayeeRepository;
+use crate::config::BankingConfig;
+use crate::mappers::PayeeMapper;
+
+/// Production implementation of PayeeService
+pub struct PayeeServiceImpl {
+    payee_repository: Arc<dyn PayeeRepository>,
+    config: Arc<BankingConfig>,
+}
+
+impl PayeeServiceImpl {
+    pub fn new(payee_repository: Arc<dyn PayeeRepository>, config: Arc<BankingConfig>) -> Self {
+        Self { payee_repository, config }
+    }
+
+    async fn find_payee(&self, payee_id: Uuid) -> BankingResult<Payee> {
+        self.payee_repository
+            .find_payee_by_id(payee_id)
+            .await?
+            .map(PayeeMapper::from_model)
+            .ok_or_else(|| BankingError::NotFound(format!("Payee {payee_id} not found")))
+    }
+
+    async fn save(&self, mut payee: Payee) -> BankingResult<Payee> {
+        payee.last_updated_at = Utc::now();
+        let updated = self.payee_repository.update_payee(PayeeMapper::to_model(payee)).await?;
+        Ok(PayeeMapper::from_model(updated))
+    }
+}
+
+/// Between 0.01 and 0.99
+fn penny_test_amount() -> Decimal {
+    Decimal::new(i64::from(rand::random::<u32>() % 99 + 1), 2)
+}
+
+#[async_trait]
+impl PayeeService for PayeeServiceImpl {
+    async fn add_payee(&self, mut payee: Payee) -> BankingResult<Payee> {
+        for (field, value) in [
+            ("beneficiary_name", payee.beneficiary_name.as_str()),
+            ("external_bank_code", payee.external_bank_code.as_str()),
+            ("external_account_number", payee.external_account_number.as_str()),
+        ] {
+            if value.trim().is_empty() {
+                return Err(BankingError::ValidationError {
+                    field: field.to_string(),
+                    message: format!("{field} is required"),
+                });
+            }
+        }
+        // Blocked payees count too, so a block cannot be undone by re-adding
+        let existing = self.payee_repository.find_payees_by_customer(payee.customer_id).await?;
+        if existing.iter().any(|p| {
+            p.external_bank_code == payee.external_bank_code
+                && p.external_account_number == payee.external_account_number
+        }) {
+            return Err(BankingError::ValidationError {
+                field: "external_account_number".to_string(),
+                message: "The customer already saved this account as a payee".to_string(),
+            });
+        }
+
+        let method = self.config.payees.verification_method;
+        let now = Utc::now();
+        payee.verification_status = PayeeVerificationStatus::PendingVerification;
+        payee.verification_method = Some(method);
+        payee.penny_test_amount = match method {
+            PayeeVerificationMethod::PennyTest => Some(penny_test_amount()),
+            PayeeVerificationMethod::ManualConfirmation => None,
+        };
+        payee.verified_at = None;
+        payee.verified_by_person_id = None;
+        payee.first_used_at = None;
+        payee.created_at = now;
+        payee.last_updated_at = now;
+
+        let created = PayeeMapper::from_model(self.payee_repository.create_payee(PayeeMapper::to_model(payee)).await?);
+        tracing::info!(
+            "Payee {} added for customer {}, pending {:?} verification",
+            created.id, created.customer_id, method
+        );
+        Ok(created)
+    }
+
+    async fn verify_payee(&self, payee_id: Uuid, confirmation: PayeeConfirmation) -> BankingResult<Payee> {
+        let mut payee = self.find_payee(payee_id).await?;
+        match payee.verification_status {
+            PayeeVerificationStatus::Blocked => return Err(BankingError::PayeeBlocked { payee_id }),
+            PayeeVerificationStatus::Verified => return Ok(payee),
+            PayeeVerificationStatus::Unverified | PayeeVerificationStatus::PendingVerification => {}
+        }
+
+        match confirmation {
+            PayeeConfirmation::PennyTestAmount(amount) => {
+                if payee.penny_test_amount != Some(amount) {
+                    return Err(BankingError::ValidationError {
+                        field: "penny_test_amount".to_string(),
+                        message: "The confirmed amount does not match the amount sent".to_string(),
+                    });
+                }
+            }
+            // An officer may confirm any payee, including one pending a penny test
+            PayeeConfirmation::Manual { confirmed_by_person_id } => {
+                payee.verified_by_person_id = Some(confirmed_by_person_id);
+                payee.updated_by_person_id = confirmed_by_person_id;
+            }
+        }
+        payee.verification_status = PayeeVerificationStatus::Verified;
+        payee.verified_at = Some(Utc::now());
+        self.save(payee).await
+    }
+
+    async fn block_payee(&self, payee_id: Uuid, blocked_by_person_id: Uuid) -> BankingResult<Payee> {
+        let mut payee = self.find_payee(payee_id).await?;
+        payee.verification_status = PayeeVerificationStatus::Blocked;
+        payee.updated_by_person_id = blocked_by_person_id;
+        let blocked = self.save(payee).await?;
+        tracing::warn!("Payee {} blocked by {}", payee_id, blocked_by_person_id);
+        Ok(blocked)
+    }
+
+    async fn find_payees_by_customer(&self, customer_id: Uuid) -> BankingResult<Vec<Payee>> {
+        Ok(self.payee_repository
+            .find_payees_by_customer(customer_id)
+            .await?
+            .into_iter()
+            .map(PayeeMapper::from_model)
+            .collect())
+    }
+
+    async fn authorize_external_transfer(&self, customer_id: Uuid, payee_id: Uuid, amount: Decimal) -> BankingResult<Payee> {
+        if amount <= Decimal::ZERO {
+            return Err(BankingError::ValidationError {
+                field: "amount".to_string(),
+                message: "Transfer amount must be positive".to_string(),
+            });
+        }
+        // Another customer's payee is reported as missing
+        let payee = self.find_payee(payee_id).await?;
+        if payee.customer_id != customer_id {
+            return Err(BankingError::NotFound(format!("Payee {payee_id} not found")));
+        }
+
+        let now = Utc::now();
+        payee.check_transfer(amount, now, &self.config.payees.transfer_policy())?;
+
+        if payee.first_used_at.is_some() {
+            return Ok(payee);
+        }
+        let mut payee = payee;
+        payee.first_used_at = Some(now);
+        self.save(payee).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use banking_api::LimitType;
+    use banking_db::models::PayeeModel;
+    use heapless::String as HeaplessString;
+
+    #[derive(Default)]
+    struct MockPayeeRepository {
+        payees: Mutex<HashMap<Uuid, PayeeModel>>,
+    }
+
+    #[async_trait]
+    impl PayeeRepository for MockPayeeRepository {
+        async fn create_payee(&self, payee: PayeeModel) -> BankingResult<PayeeModel> {
+            self.payees.lock().unwrap().insert(payee.id, payee.clone());
+            Ok(payee)
+        }
+        async fn update_payee(&self, payee: PayeeModel) -> BankingResult<PayeeModel> {
+            self.payees.lock().unwrap().insert(payee.id, payee.clone());
+            Ok(payee)
+        }
+        async fn find_payee_by_id(&self, payee_id: Uuid) -> BankingResult<Option<PayeeModel>> {
+            Ok(self.payees.lock().unwrap().get(&payee_id).cloned())
+        }
+        async fn find_payees_by_customer(&self, customer_id: Uuid) -> BankingResult<Vec<PayeeModel>> {
+            Ok(self.payees.lock().unwrap().values().filter(|p| p.customer_id == customer_id).cloned().collect())
+        }
+        async fn delete_payee(&self, _payee_id: Uuid) -> BankingResult<()> { todo!() }
+    }
+
+    fn new_payee(customer_id: Uuid) -> Payee {
+        Payee {
+            id: Uuid::new_v4(),
+            customer_id,
+            beneficiary_name: HeaplessString::try_from("Jane Supplier").unwrap(),
+            external_bank_code: HeaplessString::try_from("ECOCCMCX").unwrap(),
+            external_account_number: HeaplessString::try_from("CM2110005000011234567890").unwrap(),
+            verification_status: PayeeVerificationStatus::Unverified,
+            verification_method: None,
+            penny_test_amount: None,
+            verified_at: None,
+            verified_by_person_id: None,
+            first_used_at: None,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_new_payee_is_gated_until_verified_and_cooled_off() {
+        let service = PayeeServiceImpl::new(Arc::new(MockPayeeRepository::default()), Arc::new(BankingConfig::default()));
+        let customer_id = Uuid::new_v4();
+        let payee = service.add_payee(new_payee(customer_id)).await.unwrap();
+        assert_eq!(payee.verification_status, PayeeVerificationStatus::PendingVerification);
+        let penny = payee.penny_test_amount.unwrap();
+        assert!(penny > Decimal::ZERO && penny < Decimal::ONE);
+
+        // Above the 100.00 unverified limit
+        let result = service.authorize_external_transfer(customer_id, payee.id, Decimal::from(150)).await;
+        assert!(matches!(result, Err(BankingError::PayeeNotVerified { .. })));
+        let result = service.verify_payee(payee.id, PayeeConfirmation::PennyTestAmount(penny + Decimal::ONE)).await;
+        assert!(matches!(result, Err(BankingError::ValidationError { .. })));
+        let verified = service.verify_payee(payee.id, PayeeConfirmation::PennyTestAmount(penny)).await.unwrap();
+        assert_eq!(verified.verification_status, PayeeVerificationStatus::Verified);
+
+        // Verified, but still within the cooling-off period with its 500.00 limit
+        let used = service.authorize_external_transfer(customer_id, payee.id, Decimal::from(150)).await.unwrap();
+        assert!(used.first_used_at.is_some());
+        let result = service.authorize_external_transfer(customer_id, payee.id, Decimal::from(600)).await;
+        assert!(matches!(
+            result,
+            Err(BankingError::TransactionLimitExceeded { limit_type: LimitType::PayeeCoolingOff, .. })
+        ));
+
+        // Other customers cannot use the payee; nobody can once it is blocked
+        let result = service.authorize_external_transfer(Uuid::new_v4(), payee.id, Decimal::ONE).await;
+        assert!(matches!(result, Err(BankingError::NotFound(_))));
+        service.block_payee(payee.id, Uuid::new_v4()).await.unwrap();
+        let result = service.authorize_external_transfer(customer_id, payee.id, Decimal::ONE).await;
+        assert!(matches!(result, Err(BankingError::PayeeBlocked { .. })));
+    }
+}
@@ -34,6 +34,9 @@ fn map_domain_error_to_service_error(error: CountryRepositoryError) -> CountrySe
         CountryRepositoryError::InvalidCountryISO2(iso2) => {
             CountryServiceError::InvalidCountryISO2(iso2)
         }
+        CountryRepositoryError::InvalidNamePrefix(prefix) => {
+            CountryServiceError::InvalidNamePrefix(prefix)
+        }
         CountryRepositoryError::RepositoryError(e) => {
             CountryServiceError::RepositoryError(e.to_string())
         }
@@ -119,4 +122,17 @@ impl<DB: Database + Send + Sync> CountryService for CountryServiceImpl<DB> {
         }
         Ok(countries)
     }
+    async fn search_countries(
+        &self,
+        prefix: &str,
+        limit: i32,
+    ) -> Result<Vec<Country>, CountryServiceError> {
+        let models = self
+            .repositories
+            .country_repository
+            .find_by_name_prefix(prefix, limit)
+            .await
+            .map_err(map_domain_error_to_service_error)?;
+        Ok(models.into_iter().map(|model| model.to_domain()).collect())
+    }
 }
\ No newline at end of file
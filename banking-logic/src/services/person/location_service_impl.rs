@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use banking_api::domain::person::Location;
 use banking_api::service::{LocationService, LocationServiceError, LocationServiceResult};
 use banking_db::repository::LocationRepositoryError;
+use rust_decimal::Decimal;
 use sqlx::Database;
 use uuid::Uuid;
 
@@ -117,4 +118,44 @@ impl<DB: Database + Send + Sync> LocationService for LocationServiceImpl<DB> {
         }
         Ok(locations)
     }
+
+    async fn find_locations_within_bounding_box(
+        &self,
+        min_latitude: Decimal,
+        min_longitude: Decimal,
+        max_latitude: Decimal,
+        max_longitude: Decimal,
+        page: i32,
+        page_size: i32,
+    ) -> LocationServiceResult<Vec<Location>> {
+        let models = self
+            .repositories
+            .location_repository
+            .find_within_bounding_box(
+                min_latitude,
+                min_longitude,
+                max_latitude,
+                max_longitude,
+                page,
+                page_size,
+            )
+            .await
+            .map_err(map_domain_error_to_service_error)?;
+        Ok(models.into_iter().map(|model| model.to_domain()).collect())
+    }
+
+    async fn find_nearest_locations(
+        &self,
+        latitude: Decimal,
+        longitude: Decimal,
+        limit: i32,
+    ) -> LocationServiceResult<Vec<Location>> {
+        let models = self
+            .repositories
+            .location_repository
+            .find_nearest(latitude, longitude, limit)
+            .await
+            .map_err(map_domain_error_to_service_error)?;
+        Ok(models.into_iter().map(|model| model.to_domain()).collect())
+    }
 }
\ No newline at end of file
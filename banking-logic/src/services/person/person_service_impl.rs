@@ -1,14 +1,80 @@
 use async_trait::async_trait;
-use banking_api::domain::person::Person;
+use banking_api::domain::person::{DuplicateCandidate, DuplicateMatchReason, Person};
 use banking_api::service::person::person_service::{PersonService, PersonServiceError, PersonServiceResult};
+use banking_db::models::audit::AuditLogModel;
+use banking_db::models::person::PersonModel;
 use banking_db::repository::person::person_repository::PersonRepositoryError;
+use chrono::{DateTime, Utc};
 use heapless::String as HeaplessString;
 use sqlx::Database;
+use std::collections::HashSet;
 use uuid::Uuid;
 
+use crate::constants::{
+    DUPLICATE_SCORE_EXTERNAL_IDENTIFIER, DUPLICATE_SCORE_MESSAGING, DUPLICATE_SCORE_NORMALIZED_NAME,
+};
 use crate::mappers::person_mapper::{ToDomain, ToModel};
 use crate::services::repositories::Repositories;
 
+/// Lowercase, drop punctuation and collapse whitespace, so "DOE,  John" and "doe john" compare equal
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn messaging_values(person: &PersonModel) -> Vec<String> {
+    [
+        &person.messaging_info1,
+        &person.messaging_info2,
+        &person.messaging_info3,
+        &person.messaging_info4,
+        &person.messaging_info5,
+    ]
+    .into_iter()
+    .flatten()
+    .map(|value| value.trim().to_lowercase())
+    .filter(|value| !value.is_empty())
+    .collect()
+}
+
+/// Score a candidate against a person; None when nothing matches
+fn score_candidate(person: &PersonModel, candidate: &PersonModel) -> Option<DuplicateCandidate> {
+    let mut match_reasons = Vec::new();
+    if person.external_identifier.is_some() && person.external_identifier == candidate.external_identifier {
+        match_reasons.push(DuplicateMatchReason::ExternalIdentifier);
+    }
+    let name = normalize_name(&person.display_name);
+    if !name.is_empty() && name == normalize_name(&candidate.display_name) {
+        match_reasons.push(DuplicateMatchReason::NormalizedName);
+    }
+    let candidate_messaging = messaging_values(candidate);
+    if messaging_values(person).iter().any(|value| candidate_messaging.contains(value)) {
+        match_reasons.push(DuplicateMatchReason::Messaging);
+    }
+    if match_reasons.is_empty() {
+        return None;
+    }
+    let score = match_reasons
+        .iter()
+        .map(|reason| match reason {
+            DuplicateMatchReason::ExternalIdentifier => DUPLICATE_SCORE_EXTERNAL_IDENTIFIER,
+            DuplicateMatchReason::NormalizedName => DUPLICATE_SCORE_NORMALIZED_NAME,
+            DuplicateMatchReason::Messaging => DUPLICATE_SCORE_MESSAGING,
+        })
+        .sum();
+    Some(DuplicateCandidate {
+        person_id: person.id,
+        candidate_person_id: candidate.id,
+        score,
+        match_reasons,
+    })
+}
+
 pub struct PersonServiceImpl<DB: Database> {
     repositories: Repositories<DB>,
 }
@@ -68,6 +134,52 @@ impl<DB: Database> PersonServiceImpl<DB> {
            _ => PersonServiceError::Unexpected(err.to_string()),
         }
     }
+
+    async fn load_person(&self, id: Uuid) -> PersonServiceResult<PersonModel> {
+        if !self
+            .repositories
+            .person_repository
+            .exists_by_id(id)
+            .await
+            .map_err(Self::map_domain_error)?
+        {
+            return Err(PersonServiceError::PersonNotFound(id));
+        }
+        self.repositories
+            .person_repository
+            .load(id)
+            .await
+            .map_err(Self::map_domain_error)
+    }
+
+    async fn find_duplicates_of(&self, person: &PersonModel) -> PersonServiceResult<Vec<DuplicateCandidate>> {
+        let candidates = self
+            .repositories
+            .person_repository
+            .find_duplicate_candidates(
+                person.id,
+                person.external_identifier.as_deref(),
+                &normalize_name(&person.display_name),
+                &messaging_values(person),
+            )
+            .await
+            .map_err(Self::map_domain_error)?;
+        let mut scored: Vec<DuplicateCandidate> = candidates
+            .iter()
+            // Pairs already linked either way have been reviewed
+            .filter(|candidate| {
+                candidate.duplicate_of_person_id != Some(person.id)
+                    && person.duplicate_of_person_id != Some(candidate.id)
+            })
+            .filter_map(|candidate| score_candidate(person, candidate))
+            .collect();
+        scored.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then(a.candidate_person_id.cmp(&b.candidate_person_id))
+        });
+        Ok(scored)
+    }
 }
 
 #[async_trait]
@@ -129,4 +241,80 @@ impl<DB: Database + Send + Sync> PersonService for PersonServiceImpl<DB> {
         }
         Ok(persons)
     }
+
+    async fn detect_duplicates(&self, person_id: Uuid) -> PersonServiceResult<Vec<DuplicateCandidate>> {
+        let person = self.load_person(person_id).await?;
+        self.find_duplicates_of(&person).await
+    }
+
+    async fn link_duplicate(
+        &self,
+        duplicate_id: Uuid,
+        canonical_id: Uuid,
+        reviewed_by: Uuid,
+    ) -> PersonServiceResult<Person> {
+        if duplicate_id == canonical_id {
+            return Err(PersonServiceError::InvalidHierarchy(format!(
+                "Person {duplicate_id} cannot be a duplicate of itself"
+            )));
+        }
+        let canonical = self
+            .repositories
+            .person_repository
+            .find_by_id(canonical_id)
+            .await
+            .map_err(Self::map_domain_error)?
+            .ok_or(PersonServiceError::DuplicatePersonNotFound(canonical_id))?;
+        // Duplicates always point at the canonical record, never at another duplicate
+        if let Some(other) = canonical.duplicate_of_person_id {
+            return Err(PersonServiceError::InvalidHierarchy(format!(
+                "Person {canonical_id} is itself a duplicate of {other}"
+            )));
+        }
+        let mut duplicate = self.load_person(duplicate_id).await?;
+
+        let audit_log = AuditLogModel::new(Uuid::new_v4(), Utc::now(), reviewed_by);
+        self.repositories
+            .audit_log_repository
+            .create(&audit_log)
+            .await
+            .map_err(|e| PersonServiceError::RepositoryError(e.to_string()))?;
+
+        duplicate.duplicate_of_person_id = Some(canonical_id);
+        let saved = self
+            .repositories
+            .person_repository
+            .save(duplicate, audit_log.id)
+            .await
+            .map_err(Self::map_domain_error)?;
+        Ok(saved.to_domain())
+    }
+
+    async fn scan_for_duplicates(&self, since: DateTime<Utc>) -> PersonServiceResult<Vec<DuplicateCandidate>> {
+        let person_ids = self
+            .repositories
+            .person_repository
+            .find_ids_created_since(since)
+            .await
+            .map_err(Self::map_domain_error)?;
+        let mut seen_pairs = HashSet::new();
+        let mut found = Vec::new();
+        for person_id in person_ids {
+            let person = self.load_person(person_id).await?;
+            if person.duplicate_of_person_id.is_some() {
+                continue;
+            }
+            for candidate in self.find_duplicates_of(&person).await? {
+                let pair = if candidate.person_id < candidate.candidate_person_id {
+                    (candidate.person_id, candidate.candidate_person_id)
+                } else {
+                    (candidate.candidate_person_id, candidate.person_id)
+                };
+                if seen_pairs.insert(pair) {
+                    found.push(candidate);
+                }
+            }
+        }
+        Ok(found)
+    }
 }
\ No newline at end of file
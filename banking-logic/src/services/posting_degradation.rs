@@ -0,0 +1,496 @@
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use chrono::{DateTime, Duration, Utc};
+
+use banking_api::{
+    BankingResult,
+    domain::{CircuitBreakerMetrics, CircuitBreakerState, DegradedFlags, PostingDependency, Transaction},
+    service::{DegradedBackfillReport, PostingStep},
+};
+use banking_db::repository::TransactionRepository;
+use crate::config::DegradationSettings;
+use crate::mappers::TransactionMapper;
+
+struct BreakerInner {
+    state: CircuitBreakerState,
+    consecutive_failures: u32,
+    opened_at: Option<DateTime<Utc>>,
+    probe_in_flight: bool,
+    calls: u64,
+    failures: u64,
+    short_circuited: u64,
+    times_opened: u64,
+    last_state_change: Option<DateTime<Utc>>,
+}
+
+/// Skips a failing dependency for a while instead of failing every call.
+/// Opens after `failure_threshold` consecutive failures; once the open period
+/// has passed a single probe call closes it again or reopens it.
+pub struct CircuitBreaker {
+    dependency: PostingDependency,
+    failure_threshold: u32,
+    open_duration: Duration,
+    inner: Mutex<BreakerInner>,
+}
+
+impl CircuitBreaker {
+    pub fn new(dependency: PostingDependency, failure_threshold: u32, open_duration: Duration) -> Self {
+        Self {
+            dependency,
+            failure_threshold,
+            open_duration,
+            inner: Mutex::new(BreakerInner {
+                state: CircuitBreakerState::Closed,
+                consecutive_failures: 0,
+                opened_at: None,
+                probe_in_flight: false,
+                calls: 0,
+                failures: 0,
+                short_circuited: 0,
+                times_opened: 0,
+                last_state_change: None,
+            }),
+        }
+    }
+
+    /// Whether a call may go through now; a call let through must be followed
+    /// by record_success or record_failure
+    pub fn try_acquire(&self, now: DateTime<Utc>) -> bool {
+        let mut inner = self.inner.lock().unwrap();
+        let permitted = match inner.state {
+            CircuitBreakerState::Closed => true,
+            CircuitBreakerState::Open => {
+                let reopen_at = inner.opened_at.unwrap_or(now) + self.open_duration;
+                if now >= reopen_at {
+                    inner.state = CircuitBreakerState::HalfOpen;
+                    inner.last_state_change = Some(now);
+                    inner.probe_in_flight = true;
+                    true
+                } else {
+                    false
+                }
+            }
+            CircuitBreakerState::HalfOpen if !inner.probe_in_flight => {
+                inner.probe_in_flight = true;
+                true
+            }
+            CircuitBreakerState::HalfOpen => false,
+        };
+        if permitted {
+            inner.calls += 1;
+        } else {
+            inner.short_circuited += 1;
+        }
+        permitted
+    }
+
+    pub fn record_success(&self, now: DateTime<Utc>) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.consecutive_failures = 0;
+        if inner.state == CircuitBreakerState::HalfOpen {
+            inner.state = CircuitBreakerState::Closed;
+            inner.probe_in_flight = false;
+            inner.opened_at = None;
+            inner.last_state_change = Some(now);
+            tracing::info!("Circuit breaker for {:?} closed after a successful probe", self.dependency);
+        }
+    }
+
+    pub fn record_failure(&self, now: DateTime<Utc>) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.failures += 1;
+        inner.consecutive_failures += 1;
+        let open = match inner.state {
+            CircuitBreakerState::HalfOpen => true,
+            CircuitBreakerState::Closed => inner.consecutive_failures >= self.failure_threshold,
+            CircuitBreakerState::Open => false,
+        };
+        if open {
+            inner.state = CircuitBreakerState::Open;
+            inner.probe_in_flight = false;
+            inner.opened_at = Some(now);
+            inner.times_opened += 1;
+            inner.last_state_change = Some(now);
+            tracing::error!(
+                "Ops alert: circuit breaker for {:?} opened after {} consecutive failures; the step is skipped for {} seconds",
+                self.dependency, inner.consecutive_failures, self.open_duration.num_seconds()
+            );
+        }
+    }
+
+    /// Run the step if the breaker lets it through. True when it completed;
+    /// false when it was skipped or failed.
+    pub async fn run<F>(&self, step: F) -> bool
+    where
+        F: Future<Output = BankingResult<()>>,
+    {
+        if !self.try_acquire(Utc::now()) {
+            return false;
+        }
+        match step.await {
+            Ok(()) => {
+                self.record_success(Utc::now());
+                true
+            }
+            Err(e) => {
+                tracing::warn!("Non-critical posting step {:?} failed: {}", self.dependency, e);
+                self.record_failure(Utc::now());
+                false
+            }
+        }
+    }
+
+    pub fn metrics(&self) -> CircuitBreakerMetrics {
+        let inner = self.inner.lock().unwrap();
+        CircuitBreakerMetrics {
+            dependency: self.dependency,
+            state: inner.state,
+            consecutive_failures: inner.consecutive_failures,
+            calls: inner.calls,
+            failures: inner.failures,
+            short_circuited: inner.short_circuited,
+            times_opened: inner.times_opened,
+            last_state_change: inner.last_state_change,
+        }
+    }
+}
+
+struct GuardedStep {
+    step: Arc<dyn PostingStep>,
+    breaker: CircuitBreaker,
+}
+
+/// Steps run after the balance is posted, each behind its own circuit breaker
+pub struct PostingStepRunner {
+    steps: Vec<GuardedStep>,
+}
+
+impl PostingStepRunner {
+    pub fn new(steps: Vec<Arc<dyn PostingStep>>, settings: &DegradationSettings) -> Self {
+        let open_duration = Duration::seconds(settings.breaker_open_seconds);
+        Self {
+            steps: steps
+                .into_iter()
+                .map(|step| GuardedStep {
+                    breaker: CircuitBreaker::new(step.dependency(), settings.breaker_failure_threshold, open_duration),
+                    step,
+                })
+                .collect(),
+        }
+    }
+
+    /// Run every step for a posted transaction. Critical step failures are
+    /// returned; the non-critical steps that did not complete come back as flags.
+    pub async fn run_after_posting(&self, transaction: &Transaction) -> BankingResult<DegradedFlags> {
+        let mut skipped = DegradedFlags::NONE;
+        for guarded in &self.steps {
+            let dependency = guarded.step.dependency();
+            if dependency.is_critical() {
+                guarded.step.run(transaction).await?;
+            } else if !guarded.breaker.run(guarded.step.run(transaction)).await {
+                skipped.insert(dependency.degraded_flag());
+            }
+        }
+        if !skipped.is_empty() {
+            tracing::warn!(
+                "Transaction {} posted in degraded mode, skipped steps {:#06b}",
+                transaction.id, skipped.bits()
+            );
+        }
+        Ok(skipped)
+    }
+
+    /// Run the skipped backfillable steps of up to `limit` degraded transactions again
+    pub async fn backfill(
+        &self,
+        transaction_repository: &dyn TransactionRepository,
+        limit: i64,
+    ) -> BankingResult<DegradedBackfillReport> {
+        let mut report = DegradedBackfillReport::default();
+        let degraded = transaction_repository
+            .find_degraded(DegradedFlags::BACKFILLABLE.bits() as i32, limit)
+            .await?;
+
+        for model in degraded {
+            let transaction = TransactionMapper::from_model(model)?;
+            let mut flags = transaction.degraded_flags;
+            report.transactions_examined += 1;
+
+            for guarded in &self.steps {
+                let flag = guarded.step.dependency().degraded_flag();
+                if flag.is_empty() || !DegradedFlags::BACKFILLABLE.contains(flag) || !flags.contains(flag) {
+                    continue;
+                }
+                if guarded.breaker.run(guarded.step.run(&transaction)).await {
+                    flags.remove(flag);
+                    report.steps_backfilled += 1;
+                }
+            }
+
+            if flags != transaction.degraded_flags {
+                transaction_repository
+                    .update_degraded_flags(transaction.id, flags.bits() as i32)
+                    .await?;
+            }
+            if flags.intersects(DegradedFlags::BACKFILLABLE) {
+                report.transactions_still_degraded += 1;
+            } else {
+                report.transactions_completed += 1;
+            }
+        }
+
+        tracing::info!(
+            "Degraded posting backfill: {} transactions examined, {} steps backfilled, {} still degraded",
+            report.transactions_examined, report.steps_backfilled, report.transactions_still_degraded
+        );
+        Ok(report)
+    }
+
+    pub fn metrics(&self) -> Vec<CircuitBreakerMetrics> {
+        self.steps.iter().map(|guarded| guarded.breaker.metrics()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use async_trait::async_trait;
+    use chrono::NaiveDate;
+    use heapless::String as HeaplessString;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use banking_api::BankingError;
+    use banking_api::domain::{CurrencyCode, TransactionStatus, TransactionType};
+    use banking_db::models::{
+        ApprovalWorkflowModel, TransactionModel, TransactionSearchCriteriaModel,
+        workflow::WorkflowTransactionApprovalModel,
+    };
+
+    #[test]
+    fn test_breaker_opens_probes_and_closes() {
+        let breaker = CircuitBreaker::new(PostingDependency::Enrichment, 3, Duration::seconds(30));
+        let t0 = Utc::now();
+
+        // Failures below the threshold keep it closed; a success resets the count
+        for _ in 0..2 {
+            assert!(breaker.try_acquire(t0));
+            breaker.record_failure(t0);
+        }
+        assert!(breaker.try_acquire(t0));
+        breaker.record_success(t0);
+        assert_eq!(breaker.metrics().consecutive_failures, 0);
+
+        for _ in 0..3 {
+            assert!(breaker.try_acquire(t0));
+            breaker.record_failure(t0);
+        }
+        assert_eq!(breaker.metrics().state, CircuitBreakerState::Open);
+        assert!(!breaker.try_acquire(t0 + Duration::seconds(29)));
+
+        // One probe after the open period; others are skipped while it runs
+        let t1 = t0 + Duration::seconds(30);
+        assert!(breaker.try_acquire(t1));
+        assert_eq!(breaker.metrics().state, CircuitBreakerState::HalfOpen);
+        assert!(!breaker.try_acquire(t1));
+        breaker.record_failure(t1);
+        assert_eq!(breaker.metrics().state, CircuitBreakerState::Open);
+        assert!(!breaker.try_acquire(t1 + Duration::seconds(10)));
+
+        let t2 = t1 + Duration::seconds(30);
+        assert!(breaker.try_acquire(t2));
+        breaker.record_success(t2);
+        assert!(breaker.try_acquire(t2));
+
+        let metrics = breaker.metrics();
+        assert_eq!(metrics.state, CircuitBreakerState::Closed);
+        assert_eq!(metrics.times_opened, 2);
+        assert_eq!(metrics.calls, 10);
+        assert_eq!(metrics.failures, 6);
+        assert_eq!(metrics.short_circuited, 4);
+        assert_eq!(metrics.last_state_change, Some(t2));
+    }
+
+    struct TestStep {
+        dependency: PostingDependency,
+        failing: AtomicBool,
+        runs: Mutex<Vec<Uuid>>,
+    }
+
+    impl TestStep {
+        fn new(dependency: PostingDependency, failing: bool) -> Arc<Self> {
+            Arc::new(Self { dependency, failing: AtomicBool::new(failing), runs: Mutex::new(vec![]) })
+        }
+    }
+
+    #[async_trait]
+    impl PostingStep for TestStep {
+        fn dependency(&self) -> PostingDependency {
+            self.dependency
+        }
+        async fn run(&self, transaction: &Transaction) -> BankingResult<()> {
+            self.runs.lock().unwrap().push(transaction.id);
+            if self.failing.load(Ordering::SeqCst) {
+                return Err(BankingError::Internal(format!("{:?} unavailable", self.dependency)));
+            }
+            Ok(())
+        }
+    }
+
+    fn transaction(degraded_flags: DegradedFlags) -> Transaction {
+        Transaction {
+            id: Uuid::new_v4(),
+            account_id: Uuid::new_v4(),
+            transaction_code: HeaplessString::try_from("DEP").unwrap(),
+            transaction_type: TransactionType::Credit,
+            amount: Decimal::from(100),
+            currency: CurrencyCode::try_from("XAF").unwrap(),
+            description: HeaplessString::try_from("Cash deposit").unwrap(),
+            channel_id: HeaplessString::try_from("BRANCH").unwrap(),
+            terminal_id: None,
+            agent_person_id: None,
+            transaction_date: Utc::now(),
+            value_date: NaiveDate::from_ymd_opt(2024, 6, 14).unwrap(),
+            status: TransactionStatus::Posted,
+            reference_number: HeaplessString::try_from("REF-1").unwrap(),
+            external_reference: None,
+            gl_code: HeaplessString::try_from("2100").unwrap(),
+            requires_approval: false,
+            approval_status: None,
+            risk_score: None,
+            degraded_flags,
+            idempotency_key: None,
+            reversal_of_transaction_id: None,
+            fx_conversion: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_skipped_steps_are_flagged_and_critical_failures_fail() {
+        let settings = DegradationSettings { breaker_failure_threshold: 1, breaker_open_seconds: 60 };
+        let enrichment = TestStep::new(PostingDependency::Enrichment, true);
+        let runner = PostingStepRunner::new(
+            vec![enrichment.clone(), TestStep::new(PostingDependency::Categorization, false)],
+            &settings,
+        );
+        let flags = runner.run_after_posting(&transaction(DegradedFlags::NONE)).await.unwrap();
+        assert_eq!(flags, DegradedFlags::ENRICHMENT);
+        // Open now: the next posting does not even try
+        let flags = runner.run_after_posting(&transaction(DegradedFlags::NONE)).await.unwrap();
+        assert_eq!(flags, DegradedFlags::ENRICHMENT);
+        assert_eq!(enrichment.runs.lock().unwrap().len(), 1);
+
+        let runner = PostingStepRunner::new(vec![TestStep::new(PostingDependency::MandateCheck, true)], &settings);
+        assert!(runner.run_after_posting(&transaction(DegradedFlags::NONE)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_backfill_picks_up_degraded_rows() {
+        let mut skipped = DegradedFlags::ENRICHMENT;
+        skipped.insert(DegradedFlags::CATEGORIZATION);
+        skipped.insert(DegradedFlags::NOTIFICATION);
+        let degraded = transaction(skipped);
+        let clean = transaction(DegradedFlags::NONE);
+        let notification_only = transaction(DegradedFlags::NOTIFICATION);
+        let repository = MockTransactionRepository {
+            transactions: Mutex::new(
+                [&degraded, &clean, &notification_only]
+                    .into_iter()
+                    .map(|t| TransactionMapper::to_model(t.clone()))
+                    .collect(),
+            ),
+        };
+
+        let enrichment = TestStep::new(PostingDependency::Enrichment, false);
+        let categorization = TestStep::new(PostingDependency::Categorization, false);
+        let notification = TestStep::new(PostingDependency::Notification, false);
+        let runner = PostingStepRunner::new(
+            vec![enrichment.clone(), categorization.clone(), notification.clone()],
+            &DegradationSettings::default(),
+        );
+
+        let report = runner.backfill(&repository, 100).await.unwrap();
+        assert_eq!(report.transactions_examined, 1);
+        assert_eq!(report.steps_backfilled, 2);
+        assert_eq!(report.transactions_completed, 1);
+        assert_eq!(*enrichment.runs.lock().unwrap(), vec![degraded.id]);
+        assert_eq!(*categorization.runs.lock().unwrap(), vec![degraded.id]);
+        assert!(notification.runs.lock().unwrap().is_empty());
+
+        // Only the notification flag is left, which the backfill does not pick up
+        let flags = repository.flags(degraded.id);
+        assert_eq!(flags, DegradedFlags::NOTIFICATION.bits() as i32);
+        assert_eq!(runner.backfill(&repository, 100).await.unwrap().transactions_examined, 0);
+    }
+
+    struct MockTransactionRepository {
+        transactions: Mutex<Vec<TransactionModel>>,
+    }
+
+    impl MockTransactionRepository {
+        fn flags(&self, transaction_id: Uuid) -> i32 {
+            self.transactions.lock().unwrap().iter().find(|t| t.id == transaction_id).unwrap().degraded_flags
+        }
+    }
+
+    #[async_trait]
+    impl TransactionRepository for MockTransactionRepository {
+        async fn find_degraded(&self, flags_mask: i32, limit: i64) -> BankingResult<Vec<TransactionModel>> {
+            Ok(self.transactions
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|t| t.degraded_flags & flags_mask != 0)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+        async fn update_degraded_flags(&self, transaction_id: Uuid, degraded_flags: i32) -> BankingResult<()> {
+            if let Some(t) = self.transactions.lock().unwrap().iter_mut().find(|t| t.id == transaction_id) {
+                t.degraded_flags = degraded_flags;
+            }
+            Ok(())
+        }
+        async fn create(&self, _transaction: TransactionModel) -> BankingResult<TransactionModel> { todo!() }
+
+        async fn post_transaction(&self, _transaction: TransactionModel) -> BankingResult<TransactionModel> { todo!() }
+
+        async fn post_approved_transaction(&self, _transaction_id: Uuid) -> BankingResult<Option<TransactionModel>> { todo!() }
+        async fn update(&self, _transaction: TransactionModel) -> BankingResult<TransactionModel> { todo!() }
+        async fn find_by_id(&self, _transaction_id: Uuid) -> BankingResult<Option<TransactionModel>> { todo!() }
+        async fn find_by_account_id(&self, _account_id: Uuid, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_by_account_date_range(&self, _account_id: Uuid, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn search(&self, _criteria: TransactionSearchCriteriaModel) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_by_reference(&self, _reference_number: &str) -> BankingResult<Option<TransactionModel>> { todo!() }
+        async fn find_by_idempotency_key(&self, _channel_id: &str, _idempotency_key: &str) -> BankingResult<Option<TransactionModel>> { todo!() }
+        async fn find_by_external_reference(&self, _external_reference: &str) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_by_status(&self, _status: &str) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_requiring_approval(&self) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_by_terminal_id(&self, _terminal_id: Uuid, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_by_agent_person_id(&self, _agent_person_id: Uuid, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn find_by_channel(&self, _channel_id: &str, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn update_status(&self, _transaction_id: Uuid, _status: &str, _reason: &str) -> BankingResult<()> { todo!() }
+        async fn update_approval_status(&self, _transaction_id: Uuid, _approval_status: &str) -> BankingResult<()> { todo!() }
+        async fn find_last_customer_transaction(&self, _account_id: Uuid) -> BankingResult<Option<TransactionModel>> { todo!() }
+        async fn calculate_daily_volume_by_terminal(&self, _terminal_id: Uuid, _date: NaiveDate) -> BankingResult<Decimal> { todo!() }
+        async fn calculate_daily_volume_by_branch(&self, _branch_id: Uuid, _date: NaiveDate) -> BankingResult<Decimal> { todo!() }
+        async fn calculate_daily_volume_by_network(&self, _network_id: Uuid, _date: NaiveDate) -> BankingResult<Decimal> { todo!() }
+        async fn reverse_transaction(&self, _original_transaction_id: Uuid, _reversal_transaction: TransactionModel) -> BankingResult<TransactionModel> { todo!() }
+        async fn find_for_reconciliation(&self, _channel_id: &str, _date: NaiveDate) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn create_workflow(&self, _workflow: ApprovalWorkflowModel) -> BankingResult<ApprovalWorkflowModel> { todo!() }
+        async fn find_workflow_by_id(&self, _workflow_id: Uuid) -> BankingResult<Option<ApprovalWorkflowModel>> { todo!() }
+        async fn find_workflow_by_transaction(&self, _transaction_id: Uuid) -> BankingResult<Option<ApprovalWorkflowModel>> { todo!() }
+        async fn update_workflow_status(&self, _workflow_id: Uuid, _status: &str) -> BankingResult<()> { todo!() }
+        async fn find_pending_workflows(&self) -> BankingResult<Vec<ApprovalWorkflowModel>> { todo!() }
+        async fn find_expired_workflows(&self, _reference_time: DateTime<Utc>) -> BankingResult<Vec<ApprovalWorkflowModel>> { todo!() }
+        async fn create_approval(&self, _approval: WorkflowTransactionApprovalModel) -> BankingResult<WorkflowTransactionApprovalModel> { todo!() }
+        async fn find_approvals_by_workflow(&self, _workflow_id: Uuid) -> BankingResult<Vec<WorkflowTransactionApprovalModel>> { todo!() }
+        async fn find_approvals_by_approver(&self, _approver_person_id: Uuid) -> BankingResult<Vec<WorkflowTransactionApprovalModel>> { todo!() }
+        async fn count_approvals_for_workflow(&self, _workflow_id: Uuid) -> BankingResult<i64> { todo!() }
+        async fn exists(&self, _transaction_id: Uuid) -> BankingResult<bool> { todo!() }
+        async fn count_by_account(&self, _account_id: Uuid, _from_date: Option<NaiveDate>, _to_date: Option<NaiveDate>) -> BankingResult<i64> { todo!() }
+        async fn list(&self, _offset: i64, _limit: i64) -> BankingResult<Vec<TransactionModel>> { todo!() }
+        async fn count(&self) -> BankingResult<i64> { todo!() }
+    }
+}
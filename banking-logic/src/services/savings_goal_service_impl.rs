@@ -0,0 +1,427 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        proportional_deallocation, SavingsGoal, SavingsGoalDeallocation, SavingsGoalStatus, SavingsGoalsView,
+    },
+    service::{NotificationService, SavingsGoalService},
+};
+use banking_db::models::{AccountModel, DbAccountType};
+use banking_db::repository::{AccountRepository, SavingsGoalRepository};
+use crate::mappers::SavingsGoalMapper;
+
+/// Production implementation of SavingsGoalService
+pub struct SavingsGoalServiceImpl {
+    savings_goal_repository: Arc<dyn SavingsGoalRepository>,
+    account_repository: Arc<dyn AccountRepository>,
+    notification_service: Arc<dyn NotificationService>,
+}
+
+impl SavingsGoalServiceImpl {
+    pub fn new(
+        savings_goal_repository: Arc<dyn SavingsGoalRepository>,
+        account_repository: Arc<dyn AccountRepository>,
+        notification_service: Arc<dyn NotificationService>,
+    ) -> Self {
+        Self {
+            savings_goal_repository,
+            account_repository,
+            notification_service,
+        }
+    }
+
+    async fn casa_account(&self, account_id: Uuid) -> BankingResult<AccountModel> {
+        let account = self.account_repository
+            .find_by_id(account_id)
+            .await?
+            .ok_or(BankingError::AccountNotFound(account_id))?;
+        if account.account_type == DbAccountType::Loan {
+            return Err(BankingError::ValidationError {
+                field: "account_id".to_string(),
+                message: "Savings goals can only be kept on savings and current accounts".to_string(),
+            });
+        }
+        Ok(account)
+    }
+
+    async fn active_goal(&self, goal_id: Uuid) -> BankingResult<SavingsGoal> {
+        let goal = self.savings_goal_repository
+            .find_goal_by_id(goal_id)
+            .await?
+            .map(SavingsGoalMapper::from_model)
+            .ok_or_else(|| BankingError::NotFound(format!("Savings goal {goal_id} not found")))?;
+        if goal.status != SavingsGoalStatus::Active {
+            return Err(BankingError::ValidationError {
+                field: "goal_id".to_string(),
+                message: format!("Savings goal {goal_id} is closed"),
+            });
+        }
+        Ok(goal)
+    }
+
+    async fn active_goals(&self, account_id: Uuid) -> BankingResult<Vec<SavingsGoal>> {
+        Ok(self.savings_goal_repository
+            .find_goals_by_account(account_id)
+            .await?
+            .into_iter()
+            .map(SavingsGoalMapper::from_model)
+            .filter(|g| g.status == SavingsGoalStatus::Active)
+            .collect())
+    }
+
+    async fn save(&self, goal: SavingsGoal) -> BankingResult<SavingsGoal> {
+        let updated = self.savings_goal_repository
+            .update_goal(SavingsGoalMapper::to_model(goal))
+            .await?;
+        Ok(SavingsGoalMapper::from_model(updated))
+    }
+}
+
+fn positive_amount(amount: Decimal) -> BankingResult<()> {
+    if amount <= Decimal::ZERO {
+        return Err(BankingError::ValidationError {
+            field: "amount".to_string(),
+            message: "Amount must be positive".to_string(),
+        });
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl SavingsGoalService for SavingsGoalServiceImpl {
+    async fn create_goal(&self, mut goal: SavingsGoal) -> BankingResult<SavingsGoal> {
+        if goal.name.trim().is_empty() {
+            return Err(BankingError::ValidationError {
+                field: "name".to_string(),
+                message: "Goal name is required".to_string(),
+            });
+        }
+        if goal.target_amount <= Decimal::ZERO {
+            return Err(BankingError::ValidationError {
+                field: "target_amount".to_string(),
+                message: "Target amount must be positive".to_string(),
+            });
+        }
+        self.casa_account(goal.account_id).await?;
+        let existing = self.active_goals(goal.account_id).await?;
+        if existing.iter().any(|g| g.name.trim().eq_ignore_ascii_case(goal.name.trim())) {
+            return Err(BankingError::ValidationError {
+                field: "name".to_string(),
+                message: format!("The account already has a goal named '{}'", goal.name.trim()),
+            });
+        }
+
+        let now = Utc::now();
+        goal.allocated_balance = Decimal::ZERO;
+        goal.status = SavingsGoalStatus::Active;
+        goal.created_at = now;
+        goal.closed_at = None;
+        goal.last_updated_at = now;
+        let created = self.savings_goal_repository
+            .create_goal(SavingsGoalMapper::to_model(goal))
+            .await?;
+        Ok(SavingsGoalMapper::from_model(created))
+    }
+
+    async fn close_goal(&self, goal_id: Uuid, closed_by_person_id: Uuid) -> BankingResult<SavingsGoal> {
+        let mut goal = self.active_goal(goal_id).await?;
+        let now = Utc::now();
+        goal.allocated_balance = Decimal::ZERO;
+        goal.status = SavingsGoalStatus::Closed;
+        goal.closed_at = Some(now);
+        goal.last_updated_at = now;
+        goal.updated_by_person_id = closed_by_person_id;
+        self.save(goal).await
+    }
+
+    async fn allocate_to_goal(&self, goal_id: Uuid, amount: Decimal, updated_by_person_id: Uuid) -> BankingResult<SavingsGoal> {
+        positive_amount(amount)?;
+        let mut goal = self.active_goal(goal_id).await?;
+        let account = self.casa_account(goal.account_id).await?;
+
+        let allocated: Decimal = self.active_goals(goal.account_id).await?
+            .iter()
+            .map(|g| g.allocated_balance)
+            .sum();
+        let unallocated = (account.available_balance - allocated).max(Decimal::ZERO);
+        if amount > unallocated {
+            return Err(BankingError::InsufficientFunds {
+                account_id: goal.account_id,
+                requested: amount,
+                available: unallocated,
+            });
+        }
+
+        goal.allocated_balance += amount;
+        goal.last_updated_at = Utc::now();
+        goal.updated_by_person_id = updated_by_person_id;
+        self.save(goal).await
+    }
+
+    async fn deallocate_from_goal(&self, goal_id: Uuid, amount: Decimal, updated_by_person_id: Uuid) -> BankingResult<SavingsGoal> {
+        positive_amount(amount)?;
+        let mut goal = self.active_goal(goal_id).await?;
+        if amount > goal.allocated_balance {
+            return Err(BankingError::ValidationError {
+                field: "amount".to_string(),
+                message: format!("Only {} is allocated to the goal", goal.allocated_balance),
+            });
+        }
+
+        goal.allocated_balance -= amount;
+        goal.last_updated_at = Utc::now();
+        goal.updated_by_person_id = updated_by_person_id;
+        self.save(goal).await
+    }
+
+    async fn rebalance_after_withdrawal(&self, account_id: Uuid, business_date: NaiveDate) -> BankingResult<Vec<SavingsGoalDeallocation>> {
+        let goals = self.active_goals(account_id).await?;
+        if goals.is_empty() {
+            return Ok(Vec::new());
+        }
+        let account = self.account_repository
+            .find_by_id(account_id)
+            .await?
+            .ok_or(BankingError::AccountNotFound(account_id))?;
+
+        let deallocations = proportional_deallocation(&goals, account.available_balance);
+        if deallocations.is_empty() {
+            return Ok(deallocations);
+        }
+
+        let now = Utc::now();
+        for mut goal in goals {
+            let Some(deallocation) = deallocations.iter().find(|d| d.goal_id == goal.id) else {
+                continue;
+            };
+            goal.allocated_balance -= deallocation.amount;
+            goal.last_updated_at = now;
+            self.save(goal).await?;
+        }
+
+        let total: Decimal = deallocations.iter().map(|d| d.amount).sum();
+        tracing::info!(
+            "Withdrawal on account {} reduced {} savings goals by {}",
+            account_id, deallocations.len(), total
+        );
+        // The goals are already consistent; a failed message must not undo the withdrawal
+        for owner in self.account_repository.find_ownership_by_account(account_id).await? {
+            if let Err(e) = self.notification_service
+                .queue_savings_goals_reduced(owner.customer_id, account_id, total, business_date)
+                .await
+            {
+                tracing::warn!("Failed to notify customer {} of reduced savings goals: {}", owner.customer_id, e);
+            }
+        }
+
+        Ok(deallocations)
+    }
+
+    async fn find_goals_by_account(&self, account_id: Uuid) -> BankingResult<Vec<SavingsGoal>> {
+        Ok(self.savings_goal_repository
+            .find_goals_by_account(account_id)
+            .await?
+            .into_iter()
+            .map(SavingsGoalMapper::from_model)
+            .collect())
+    }
+
+    async fn get_goals_view(&self, account_id: Uuid, as_of: NaiveDate) -> BankingResult<SavingsGoalsView> {
+        let account = self.casa_account(account_id).await?;
+        let goals = self.active_goals(account_id).await?;
+        let allocated: Decimal = goals.iter().map(|g| g.allocated_balance).sum();
+
+        Ok(SavingsGoalsView {
+            account_id,
+            available_balance: account.available_balance,
+            unallocated_balance: (account.available_balance - allocated).max(Decimal::ZERO),
+            goals: goals.iter().map(|g| g.progress(as_of)).collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use banking_api::domain::{
+        ChannelDispatch, MessageSendRequest, MessageTemplate, MessageTemplateRequest, NotificationDuplicateReport,
+        NotificationQueueOutcome, NotificationRequest, QueuedNotification, RenderedMessage,
+    };
+    use banking_db::models::SavingsGoalModel;
+    use heapless::String as HeaplessString;
+    use crate::services::test_doubles::{active_account, InMemoryAccountRepository};
+
+    #[derive(Default)]
+    struct MockSavingsGoalRepository {
+        goals: Mutex<HashMap<Uuid, SavingsGoalModel>>,
+    }
+
+    #[async_trait]
+    impl SavingsGoalRepository for MockSavingsGoalRepository {
+        async fn create_goal(&self, goal: SavingsGoalModel) -> BankingResult<SavingsGoalModel> {
+            self.goals.lock().unwrap().insert(goal.id, goal.clone());
+            Ok(goal)
+        }
+        async fn update_goal(&self, goal: SavingsGoalModel) -> BankingResult<SavingsGoalModel> {
+            self.goals.lock().unwrap().insert(goal.id, goal.clone());
+            Ok(goal)
+        }
+        async fn find_goal_by_id(&self, goal_id: Uuid) -> BankingResult<Option<SavingsGoalModel>> {
+            Ok(self.goals.lock().unwrap().get(&goal_id).cloned())
+        }
+        async fn find_goals_by_account(&self, account_id: Uuid) -> BankingResult<Vec<SavingsGoalModel>> {
+            let mut goals: Vec<SavingsGoalModel> = self.goals.lock().unwrap()
+                .values()
+                .filter(|g| g.account_id == account_id)
+                .cloned()
+                .collect();
+            goals.sort_by_key(|g| g.created_at);
+            Ok(goals)
+        }
+        async fn delete_goal(&self, _goal_id: Uuid) -> BankingResult<()> { todo!() }
+    }
+
+    /// Records savings goal notices as (customer, account, amount)
+    #[derive(Default)]
+    struct MockNotificationService {
+        goal_notices: Mutex<Vec<(Uuid, Uuid, Decimal)>>,
+    }
+
+    #[async_trait]
+    impl NotificationService for MockNotificationService {
+        async fn queue_savings_goals_reduced(
+            &self,
+            customer_id: Uuid,
+            account_id: Uuid,
+            amount: Decimal,
+            _business_date: NaiveDate,
+        ) -> BankingResult<NotificationQueueOutcome> {
+            self.goal_notices.lock().unwrap().push((customer_id, account_id, amount));
+            Err(BankingError::Internal("Queue not available in tests".to_string()))
+        }
+        async fn render_and_queue(&self, _request: NotificationRequest) -> BankingResult<NotificationQueueOutcome> { todo!() }
+        async fn save_message_template(&self, _request: MessageTemplateRequest) -> BankingResult<MessageTemplate> { todo!() }
+        async fn render_message(&self, _template_code: &str, _language_preferences: &[[u8; 3]], _params: &HashMap<String, String>) -> BankingResult<RenderedMessage> { todo!() }
+        async fn render_and_send(&self, _request: MessageSendRequest) -> BankingResult<Vec<ChannelDispatch>> { todo!() }
+        async fn queue_dormancy_notice(&self, _customer_id: Uuid, _account_id: Uuid, _business_date: NaiveDate) -> BankingResult<NotificationQueueOutcome> { todo!() }
+        async fn queue_statement_ready(&self, _customer_id: Uuid, _statement_reference: &HeaplessString<50>, _business_date: NaiveDate) -> BankingResult<NotificationQueueOutcome> { todo!() }
+        async fn queue_mandate_expiry_reminder(&self, _customer_id: Uuid, _account_id: Uuid, _expiry_date: NaiveDate, _business_date: NaiveDate) -> BankingResult<NotificationQueueOutcome> { todo!() }
+        async fn queue_collection_reminder(&self, _customer_id: Uuid, _amount: Decimal, _due_date: NaiveDate, _business_date: NaiveDate) -> BankingResult<NotificationQueueOutcome> { todo!() }
+        async fn queue_inactivity_fee_warning(&self, _customer_id: Uuid, _account_id: Uuid, _fee_date: NaiveDate, _warned_from: NaiveDate) -> BankingResult<NotificationQueueOutcome> { todo!() }
+        async fn find_notifications_by_customer(&self, _customer_id: Uuid, _business_date: NaiveDate) -> BankingResult<Vec<QueuedNotification>> { todo!() }
+        async fn get_duplicate_report(&self, _business_date: NaiveDate) -> BankingResult<NotificationDuplicateReport> { todo!() }
+        async fn purge_expired_keys(&self, _as_of: NaiveDate) -> BankingResult<u64> { todo!() }
+    }
+
+    fn savings_account(available_balance: Decimal) -> AccountModel {
+        AccountModel {
+            current_balance: available_balance,
+            available_balance,
+            ..active_account(Uuid::new_v4())
+        }
+    }
+
+    fn goal(account_id: Uuid, name: &str) -> SavingsGoal {
+        SavingsGoal {
+            id: Uuid::new_v4(),
+            account_id,
+            name: HeaplessString::try_from(name).unwrap(),
+            target_amount: Decimal::from(2_000),
+            target_date: NaiveDate::from_ymd_opt(2024, 12, 31),
+            allocated_balance: Decimal::ZERO,
+            status: SavingsGoalStatus::Active,
+            created_at: Utc::now(),
+            closed_at: None,
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    struct Fixture {
+        service: SavingsGoalServiceImpl,
+        accounts: Arc<InMemoryAccountRepository>,
+        notifications: Arc<MockNotificationService>,
+        account_id: Uuid,
+    }
+
+    fn fixture(available_balance: Decimal) -> Fixture {
+        let account = savings_account(available_balance);
+        let account_id = account.id;
+        let accounts = Arc::new(InMemoryAccountRepository::with_accounts([account]));
+        accounts.add_owner(account_id, Uuid::new_v4());
+        let notifications = Arc::new(MockNotificationService::default());
+        let service = SavingsGoalServiceImpl::new(
+            Arc::new(MockSavingsGoalRepository::default()),
+            accounts.clone(),
+            notifications.clone(),
+        );
+        Fixture { service, accounts, notifications, account_id }
+    }
+
+    #[tokio::test]
+    async fn test_allocation_beyond_available_balance_is_rejected() {
+        let f = fixture(Decimal::from(1_000));
+        let person = Uuid::new_v4();
+        let school = f.service.create_goal(goal(f.account_id, "School fees")).await.unwrap();
+        let emergency = f.service.create_goal(goal(f.account_id, "Emergency")).await.unwrap();
+        f.service.allocate_to_goal(school.id, Decimal::from(700), person).await.unwrap();
+
+        let result = f.service.allocate_to_goal(emergency.id, Decimal::from(400), person).await;
+        assert!(matches!(
+            result,
+            Err(BankingError::InsufficientFunds { requested, available, .. })
+                if requested == Decimal::from(400) && available == Decimal::from(300)
+        ));
+
+        f.service.allocate_to_goal(emergency.id, Decimal::from(300), person).await.unwrap();
+        let view = f.service.get_goals_view(f.account_id, NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()).await.unwrap();
+        assert_eq!(view.unallocated_balance, Decimal::ZERO);
+        assert_eq!(view.goals.len(), 2);
+
+        // Closing a goal returns its allocation to the pool
+        f.service.close_goal(school.id, person).await.unwrap();
+        let view = f.service.get_goals_view(f.account_id, NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()).await.unwrap();
+        assert_eq!(view.unallocated_balance, Decimal::from(700));
+    }
+
+    #[tokio::test]
+    async fn test_withdrawal_deallocates_goals_proportionally() {
+        let f = fixture(Decimal::from(1_000));
+        let person = Uuid::new_v4();
+        let mut goal_ids = Vec::new();
+        for (name, amount) in [("School fees", 600), ("Emergency", 300), ("Holiday", 100)] {
+            let created = f.service.create_goal(goal(f.account_id, name)).await.unwrap();
+            f.service.allocate_to_goal(created.id, Decimal::from(amount), person).await.unwrap();
+            goal_ids.push(created.id);
+        }
+        let business_date = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+
+        // A withdrawal within the unallocated pool leaves the goals alone
+        assert!(f.service.rebalance_after_withdrawal(f.account_id, business_date).await.unwrap().is_empty());
+
+        // Withdrawing 300 leaves 700 for 1000 of goals
+        f.accounts.accounts.lock().unwrap().get_mut(&f.account_id).unwrap().available_balance = Decimal::from(700);
+        let deallocations = f.service.rebalance_after_withdrawal(f.account_id, business_date).await.unwrap();
+        assert_eq!(deallocations.iter().map(|d| d.amount).sum::<Decimal>(), Decimal::from(300));
+
+        let goals = f.service.find_goals_by_account(f.account_id).await.unwrap();
+        let allocated: Vec<Decimal> = goal_ids
+            .iter()
+            .map(|id| goals.iter().find(|g| g.id == *id).unwrap().allocated_balance)
+            .collect();
+        assert_eq!(allocated, vec![Decimal::from(420), Decimal::from(210), Decimal::from(70)]);
+
+        // The owner is told even though queueing failed; the goals stay reduced
+        let notices = f.notifications.goal_notices.lock().unwrap();
+        assert_eq!(notices.len(), 1);
+        assert_eq!(notices[0].1, f.account_id);
+        assert_eq!(notices[0].2, Decimal::from(300));
+    }
+}
@@ -0,0 +1,129 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{Segment, SegmentEvaluationReport, SegmentMembership, SegmentMembershipDelta},
+    service::SegmentService,
+};
+use banking_db::repository::SegmentRepository;
+use crate::mappers::SegmentMapper;
+
+/// Production implementation of SegmentService
+pub struct SegmentServiceImpl {
+    segment_repository: Arc<dyn SegmentRepository>,
+}
+
+impl SegmentServiceImpl {
+    pub fn new(segment_repository: Arc<dyn SegmentRepository>) -> Self {
+        Self { segment_repository }
+    }
+
+    fn validate(segment: &Segment) -> BankingResult<()> {
+        segment.validate().map_err(|message| BankingError::ValidationError {
+            field: "segment".to_string(),
+            message,
+        })
+    }
+}
+
+#[async_trait]
+impl SegmentService for SegmentServiceImpl {
+    async fn create_segment(&self, segment: Segment) -> BankingResult<Segment> {
+        Self::validate(&segment)?;
+        if self.segment_repository.find_segment_by_code(segment.code.as_str()).await?.is_some() {
+            return Err(BankingError::ValidationError {
+                field: "code".to_string(),
+                message: format!("Segment code {} already exists", segment.code),
+            });
+        }
+
+        let created = self.segment_repository
+            .create_segment(SegmentMapper::to_model(segment))
+            .await?;
+        Ok(SegmentMapper::from_model(created))
+    }
+
+    async fn update_segment(&self, segment: Segment) -> BankingResult<Segment> {
+        Self::validate(&segment)?;
+        let mut model = SegmentMapper::to_model(segment);
+        model.last_updated_at = Utc::now();
+
+        let updated = self.segment_repository.update_segment(model).await?;
+        Ok(SegmentMapper::from_model(updated))
+    }
+
+    async fn find_segment_by_id(&self, segment_id: Uuid) -> BankingResult<Option<Segment>> {
+        let segment = self.segment_repository.find_segment_by_id(segment_id).await?;
+        Ok(segment.map(SegmentMapper::from_model))
+    }
+
+    async fn find_segment_by_code(&self, code: &str) -> BankingResult<Option<Segment>> {
+        let segment = self.segment_repository.find_segment_by_code(code).await?;
+        Ok(segment.map(SegmentMapper::from_model))
+    }
+
+    async fn find_active_segments(&self) -> BankingResult<Vec<Segment>> {
+        let segments = self.segment_repository.find_active_segments().await?;
+        Ok(segments.into_iter().map(SegmentMapper::from_model).collect())
+    }
+
+    async fn deactivate_segment(&self, segment_id: Uuid, updated_by_person_id: Uuid) -> BankingResult<()> {
+        self.segment_repository.deactivate_segment(segment_id, updated_by_person_id).await
+    }
+
+    async fn delete_segment(&self, segment_id: Uuid) -> BankingResult<()> {
+        self.segment_repository.delete_segment(segment_id).await
+    }
+
+    async fn evaluate_segments(&self, processing_date: NaiveDate) -> BankingResult<SegmentEvaluationReport> {
+        let evaluated_at = Utc::now();
+        let segments = self.segment_repository.find_active_segments().await?;
+
+        let mut report = SegmentEvaluationReport {
+            evaluated_at,
+            segments_evaluated: 0,
+            total_entered: 0,
+            total_exited: 0,
+            errors: Vec::new(),
+        };
+
+        // One failing segment must not block the others
+        for segment in &segments {
+            match self.segment_repository.refresh_membership(segment, processing_date, evaluated_at).await {
+                Ok(delta) => {
+                    report.segments_evaluated += 1;
+                    report.total_entered += delta.entered_customer_ids.len() as u32;
+                    report.total_exited += delta.exited_customer_ids.len() as u32;
+                    tracing::debug!(
+                        "Segment {}: {} entered, {} exited",
+                        segment.code, delta.entered_customer_ids.len(), delta.exited_customer_ids.len()
+                    );
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to evaluate segment {}: {}", segment.code, e);
+                    report.errors.push(format!("Segment {}: {}", segment.code, e));
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    async fn get_segment_delta(&self, segment_id: Uuid, since: DateTime<Utc>) -> BankingResult<SegmentMembershipDelta> {
+        let delta = self.segment_repository.find_membership_changes_since(segment_id, since).await?;
+        Ok(SegmentMapper::delta_from_model(delta))
+    }
+
+    async fn get_segment_members(&self, segment_id: Uuid) -> BankingResult<Vec<SegmentMembership>> {
+        let members = self.segment_repository.find_current_members(segment_id).await?;
+        Ok(members.into_iter().map(SegmentMapper::membership_from_model).collect())
+    }
+
+    async fn get_segments_for_customer(&self, customer_id: Uuid) -> BankingResult<Vec<Segment>> {
+        let segments = self.segment_repository.find_segments_for_customer(customer_id).await?;
+        Ok(segments.into_iter().map(SegmentMapper::from_model).collect())
+    }
+}
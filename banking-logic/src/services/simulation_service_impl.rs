@@ -0,0 +1,198 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Months, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        AccountOpeningRequest, CurrencyCode, LoanSimulation, Quote, QuoteSimulation, QuoteStatus,
+        SavingsSimulation, SigningCondition, SimulatedFee,
+    },
+    service::{ProductService, SimulationService},
+};
+use banking_db::{models::DbQuoteStatus, repository::QuoteRepository};
+use crate::mappers::QuoteMapper;
+
+/// Configuration struct for SimulationServiceImpl to avoid too many constructor arguments
+pub struct SimulationServiceConfig {
+    pub product_service: Arc<dyn ProductService>,
+    pub quote_repository: Arc<dyn QuoteRepository>,
+    /// Withholding tax on savings interest, as a fraction
+    pub withholding_tax_rate: Decimal,
+    pub quote_validity_days: i64,
+}
+
+/// Production implementation of SimulationService
+pub struct SimulationServiceImpl {
+    product_service: Arc<dyn ProductService>,
+    quote_repository: Arc<dyn QuoteRepository>,
+    withholding_tax_rate: Decimal,
+    quote_validity_days: i64,
+}
+
+impl SimulationServiceImpl {
+    pub fn new(config: SimulationServiceConfig) -> Self {
+        Self {
+            product_service: config.product_service,
+            quote_repository: config.quote_repository,
+            withholding_tax_rate: config.withholding_tax_rate,
+            quote_validity_days: config.quote_validity_days,
+        }
+    }
+}
+
+#[async_trait]
+impl SimulationService for SimulationServiceImpl {
+    async fn simulate_savings(&self, product_id: Uuid, monthly_deposit: Decimal, months: u32) -> BankingResult<SavingsSimulation> {
+        if monthly_deposit <= Decimal::ZERO {
+            return Err(BankingError::ValidationError {
+                field: "monthly_deposit".to_string(),
+                message: "Monthly deposit must be positive".to_string(),
+            });
+        }
+        if months == 0 {
+            return Err(BankingError::ValidationError {
+                field: "months".to_string(),
+                message: "Projection period must be at least one month".to_string(),
+            });
+        }
+
+        let rules = self.product_service.get_product_rules(product_id).await?;
+        let tiers = self.product_service.get_interest_rate_tiers(product_id).await?;
+        let default_rate = self.product_service.get_interest_rate(product_id, Decimal::ZERO).await?;
+        let fee_schedule = self.product_service.get_fee_schedule(product_id).await?;
+        let fees = SimulatedFee::from_product(&rules, &fee_schedule, monthly_deposit);
+
+        Ok(SavingsSimulation::project(
+            product_id,
+            monthly_deposit,
+            months,
+            &tiers,
+            default_rate,
+            fees,
+            self.withholding_tax_rate,
+        ))
+    }
+
+    async fn simulate_loan(&self, product_id: Uuid, principal: Decimal, term_months: u32) -> BankingResult<LoanSimulation> {
+        if principal <= Decimal::ZERO {
+            return Err(BankingError::ValidationError {
+                field: "principal".to_string(),
+                message: "Loan principal must be positive".to_string(),
+            });
+        }
+        if term_months == 0 {
+            return Err(BankingError::ValidationError {
+                field: "term_months".to_string(),
+                message: "Loan term must be at least one month".to_string(),
+            });
+        }
+
+        let rules = self.product_service.get_product_rules(product_id).await?;
+        // Product rates are fractions; amortization works in percent
+        let annual_interest_rate = self.product_service.get_interest_rate(product_id, principal).await? * Decimal::from(100);
+        let fee_schedule = self.product_service.get_fee_schedule(product_id).await?;
+        let fees = SimulatedFee::from_product(&rules, &fee_schedule, principal);
+        let first_payment_date = Utc::now()
+            .date_naive()
+            .checked_add_months(Months::new(1))
+            .ok_or_else(|| BankingError::Internal("Cannot compute first payment date".to_string()))?;
+
+        Ok(LoanSimulation::simulate(
+            product_id,
+            principal,
+            term_months,
+            annual_interest_rate,
+            first_payment_date,
+            fees,
+        ))
+    }
+
+    async fn save_quote(&self, simulation: QuoteSimulation, customer_id: Option<Uuid>, created_by_person_id: Uuid) -> BankingResult<Quote> {
+        let now = Utc::now();
+        let quote = Quote {
+            id: Uuid::new_v4(),
+            quote_type: simulation.quote_type(),
+            product_id: simulation.product_id(),
+            customer_id,
+            simulation,
+            status: QuoteStatus::Active,
+            expires_at: now + Duration::days(self.quote_validity_days),
+            converted_at: None,
+            created_at: now,
+            created_by_person_id,
+        };
+
+        let created = self.quote_repository.create_quote(QuoteMapper::to_model(quote)?).await?;
+        QuoteMapper::from_model(created)
+    }
+
+    async fn find_quote_by_id(&self, quote_id: Uuid) -> BankingResult<Option<Quote>> {
+        match self.quote_repository.find_quote_by_id(quote_id).await? {
+            Some(model) => Ok(Some(QuoteMapper::from_model(model)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn convert_quote(
+        &self,
+        quote_id: Uuid,
+        customer_id: Uuid,
+        currency: CurrencyCode,
+        channel: HeaplessString<50>,
+        initiated_by: Uuid,
+    ) -> BankingResult<AccountOpeningRequest> {
+        let quote = self.find_quote_by_id(quote_id)
+            .await?
+            .ok_or_else(|| BankingError::NotFound(format!("Quote {quote_id} not found")))?;
+
+        let now = Utc::now();
+        if !quote.is_convertible(now) {
+            return Err(BankingError::ValidationError {
+                field: "quote_id".to_string(),
+                message: format!("Quote is {:?} and can no longer be converted", quote.status),
+            });
+        }
+        if quote.customer_id.is_some_and(|id| id != customer_id) {
+            return Err(BankingError::ValidationError {
+                field: "customer_id".to_string(),
+                message: "Quote was issued to a different customer".to_string(),
+            });
+        }
+
+        let converted = self.quote_repository
+            .transition_active_quote(quote_id, DbQuoteStatus::Converted, Some(now))
+            .await?;
+        if !converted {
+            return Err(BankingError::ValidationError {
+                field: "quote_id".to_string(),
+                message: "Quote has already been converted".to_string(),
+            });
+        }
+
+        let initial_deposit = match &quote.simulation {
+            QuoteSimulation::Savings(simulation) => simulation.monthly_deposit,
+            QuoteSimulation::Loan(simulation) => simulation.principal,
+        };
+
+        Ok(AccountOpeningRequest {
+            customer_id,
+            product_id: quote.product_id,
+            currency,
+            signing_condition: SigningCondition::None,
+            joint_owner_ids: Vec::new(),
+            initial_deposit: Some(initial_deposit),
+            channel,
+            initiated_by,
+            supporting_documents: Vec::new(),
+            quote_id: Some(quote_id),
+        })
+    }
+
+    async fn expire_quotes(&self, as_of: DateTime<Utc>) -> BankingResult<u64> {
+        self.quote_repository.expire_quotes(as_of).await
+    }
+}
@@ -0,0 +1,198 @@
+use std::sync::Arc;
+use chrono::NaiveDate;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{StandingOrder, StandingOrderStatus},
+    service::CalendarService,
+};
+
+/// Moves standing orders along their schedule. An occurrence due on a
+/// non-business day runs on the next business day; the schedule itself keeps
+/// counting from the unadjusted due date.
+pub struct StandingOrderScheduler {
+    calendar_service: Arc<dyn CalendarService>,
+    calendar_id: String,
+}
+
+impl StandingOrderScheduler {
+    pub fn new(calendar_service: Arc<dyn CalendarService>, calendar_id: impl Into<String>) -> Self {
+        Self {
+            calendar_service,
+            calendar_id: calendar_id.into(),
+        }
+    }
+
+    /// Schedules a new order's first occurrence on its start date
+    pub async fn schedule_first_occurrence(&self, order: &mut StandingOrder) -> BankingResult<()> {
+        order.next_due_date = order.start_date;
+        order.next_run_date = self.business_day_on_or_after(order.start_date).await?;
+        Ok(())
+    }
+
+    /// Moves the order past its pending occurrence. An order whose next
+    /// occurrence falls after its end date is completed.
+    pub async fn advance(&self, order: &mut StandingOrder) -> BankingResult<()> {
+        let next_due_date = self.next_due_date(order)?;
+        if order.end_date.is_some_and(|end_date| next_due_date > end_date) {
+            order.status = StandingOrderStatus::Completed;
+            return Ok(());
+        }
+        order.next_due_date = next_due_date;
+        order.next_run_date = self.business_day_on_or_after(next_due_date).await?;
+        Ok(())
+    }
+
+    /// Runs the pending occurrence again on the business day after `run_date`,
+    /// unless the next occurrence is due by then; it then replaces the retry.
+    pub async fn retry(&self, order: &mut StandingOrder, run_date: NaiveDate) -> BankingResult<()> {
+        let retry_date = self.calendar_service.next_business_day(run_date, &self.calendar_id).await?;
+        if retry_date < self.next_due_date(order)? {
+            order.next_run_date = retry_date;
+            Ok(())
+        } else {
+            self.advance(order).await
+        }
+    }
+
+    fn next_due_date(&self, order: &StandingOrder) -> BankingResult<NaiveDate> {
+        order
+            .frequency
+            .next_due_date(order.start_date, order.next_due_date)
+            .ok_or_else(|| BankingError::Internal(format!(
+                "Cannot compute the occurrence of standing order {} after {}",
+                order.id, order.next_due_date
+            )))
+    }
+
+    async fn business_day_on_or_after(&self, date: NaiveDate) -> BankingResult<NaiveDate> {
+        if self.calendar_service.is_business_day(date, &self.calendar_id).await? {
+            Ok(date)
+        } else {
+            self.calendar_service.next_business_day(date, &self.calendar_id).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use banking_api::domain::{
+        BankHoliday, BusinessDayCalculation, StandingOrderAmount, StandingOrderFailurePolicy,
+        StandingOrderFrequency, WeekendDays,
+    };
+    use chrono::{Datelike, Duration, Utc, Weekday};
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+
+    /// Saturday/Sunday weekend plus a fixed set of holidays
+    struct MockCalendarService {
+        holidays: Vec<NaiveDate>,
+    }
+
+    impl MockCalendarService {
+        fn is_open(&self, date: NaiveDate) -> bool {
+            !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !self.holidays.contains(&date)
+        }
+    }
+
+    #[async_trait]
+    impl CalendarService for MockCalendarService {
+        async fn is_business_day(&self, date: NaiveDate, _jurisdiction: &str) -> BankingResult<bool> {
+            Ok(self.is_open(date))
+        }
+        async fn next_business_day(&self, date: NaiveDate, _jurisdiction: &str) -> BankingResult<NaiveDate> {
+            let mut next = date + Duration::days(1);
+            while !self.is_open(next) {
+                next += Duration::days(1);
+            }
+            Ok(next)
+        }
+        async fn previous_business_day(&self, _date: NaiveDate, _jurisdiction: &str) -> BankingResult<NaiveDate> { unimplemented!() }
+        async fn add_business_days(&self, _date: NaiveDate, _days: i32, _jurisdiction: &str) -> BankingResult<NaiveDate> { unimplemented!() }
+        async fn count_business_days(&self, _from: NaiveDate, _to: NaiveDate, _jurisdiction: &str) -> BankingResult<i32> { unimplemented!() }
+        async fn add_bank_holiday(&self, _holiday: BankHoliday) -> BankingResult<()> { unimplemented!() }
+        async fn remove_bank_holiday(&self, _holiday_id: Uuid) -> BankingResult<()> { unimplemented!() }
+        async fn get_holidays(&self, _jurisdiction: &str, _year: i32) -> BankingResult<Vec<BankHoliday>> { unimplemented!() }
+        async fn calculate_business_day(&self, _date: NaiveDate, _jurisdiction: &str) -> BankingResult<BusinessDayCalculation> { unimplemented!() }
+        async fn batch_calculate_business_days(&self, _dates: Vec<NaiveDate>, _jurisdiction: &str) -> BankingResult<Vec<BusinessDayCalculation>> { unimplemented!() }
+        async fn is_weekend(&self, _date: NaiveDate, _jurisdiction: &str) -> BankingResult<bool> { unimplemented!() }
+        async fn create_weekend_days(&self, _weekend_days: WeekendDays) -> BankingResult<WeekendDays> { unimplemented!() }
+        async fn get_weekend_days_by_id(&self, _weekend_days_id: Uuid) -> BankingResult<Option<WeekendDays>> { unimplemented!() }
+        async fn update_weekend_days(&self, _weekend_days: WeekendDays) -> BankingResult<WeekendDays> { unimplemented!() }
+        async fn delete_weekend_days(&self, _weekend_days_id: Uuid) -> BankingResult<()> { unimplemented!() }
+    }
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    /// Friday 17 May 2024 is a holiday, followed by the weekend
+    fn scheduler() -> StandingOrderScheduler {
+        StandingOrderScheduler::new(Arc::new(MockCalendarService { holidays: vec![date(5, 17)] }), "CM")
+    }
+
+    fn monthly_order(start_date: NaiveDate, failure_policy: StandingOrderFailurePolicy) -> StandingOrder {
+        StandingOrder {
+            id: Uuid::new_v4(),
+            source_account_id: Uuid::new_v4(),
+            beneficiary_account_id: Some(Uuid::new_v4()),
+            external_reference: None,
+            amount: StandingOrderAmount::Fixed(Decimal::from(25_000)),
+            frequency: StandingOrderFrequency::Monthly,
+            start_date,
+            end_date: None,
+            next_due_date: start_date,
+            next_run_date: start_date,
+            status: StandingOrderStatus::Active,
+            failure_policy,
+            consecutive_failures: 0,
+            last_executed_at: None,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_monthly_order_on_holiday_runs_next_business_day() {
+        let scheduler = scheduler();
+        let mut order = monthly_order(date(4, 17), StandingOrderFailurePolicy::Skip);
+
+        // Due on the holiday, run after the weekend
+        scheduler.advance(&mut order).await.unwrap();
+        assert_eq!(order.next_due_date, date(5, 17));
+        assert_eq!(order.next_run_date, date(5, 20));
+        assert!(!order.is_due(date(5, 17)));
+        assert!(order.is_due(date(5, 20)));
+
+        // The following month is back on the 17th
+        scheduler.advance(&mut order).await.unwrap();
+        assert_eq!(order.next_due_date, date(6, 17));
+        assert_eq!(order.next_run_date, date(6, 17));
+
+        order.end_date = Some(date(7, 1));
+        scheduler.advance(&mut order).await.unwrap();
+        assert_eq!(order.status, StandingOrderStatus::Completed);
+        assert_eq!(order.next_due_date, date(6, 17));
+    }
+
+    #[tokio::test]
+    async fn test_retry_next_day_gives_way_to_next_occurrence() {
+        let scheduler = scheduler();
+        let mut order = monthly_order(date(4, 16), StandingOrderFailurePolicy::RetryNextDay);
+        scheduler.advance(&mut order).await.unwrap();
+        assert_eq!(order.next_run_date, date(5, 16));
+
+        // Unfunded on Thursday 16th, tried again on Monday 20th
+        scheduler.retry(&mut order, date(5, 16)).await.unwrap();
+        assert_eq!(order.next_due_date, date(5, 16));
+        assert_eq!(order.next_run_date, date(5, 20));
+
+        // Still unfunded on the eve of the June occurrence, which takes over
+        scheduler.retry(&mut order, date(6, 14)).await.unwrap();
+        assert_eq!(order.next_due_date, date(6, 16));
+        assert_eq!(order.next_run_date, date(6, 17));
+    }
+}
@@ -0,0 +1,674 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        is_statement_cycle_end, statement_period_start, AccountStatement, ContentHash, PaperStatementReport,
+        StatementArchiveVerification, StatementConsentRecord, StatementCycleReport, StatementDeliveryMethod,
+        StatementDeliveryPreference, StatementDispatchPlan, StatementIntegrityAlert, StatementIntegrityCheck,
+        StatementNotification, StatementNotificationType, StatementPreferenceChange, StatementPrintExtract,
+    },
+    service::StatementService,
+};
+use banking_db::repository::{AccountRepository, StatementRepository};
+use crate::mappers::StatementMapper;
+
+/// Production implementation of StatementService
+pub struct StatementServiceImpl {
+    statement_repository: Arc<dyn StatementRepository>,
+    account_repository: Arc<dyn AccountRepository>,
+}
+
+impl StatementServiceImpl {
+    pub fn new(statement_repository: Arc<dyn StatementRepository>, account_repository: Arc<dyn AccountRepository>) -> Self {
+        Self {
+            statement_repository,
+            account_repository,
+        }
+    }
+
+    async fn load_statement(&self, statement_id: Uuid) -> BankingResult<AccountStatement> {
+        let model = self.statement_repository
+            .find_statement_by_id(statement_id)
+            .await?
+            .ok_or_else(|| BankingError::NotFound(format!("Statement {statement_id} not found")))?;
+        let lines = self.statement_repository.find_statement_lines(statement_id).await?;
+        Ok(StatementMapper::statement_from_model(model, lines))
+    }
+
+    /// Serve the stored statement of the period or store a new one numbered
+    /// after the account's last. Transactions valued within the period but
+    /// posted after the stored statement was generated supersede it, as
+    /// `force` does.
+    async fn generate(
+        &self,
+        account_id: Uuid,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        force: bool,
+        generated_by_person_id: Uuid,
+    ) -> BankingResult<AccountStatement> {
+        if !self.account_repository.exists(account_id).await? {
+            return Err(BankingError::AccountNotFound(account_id));
+        }
+
+        let entries = self.statement_repository
+            .find_statement_entries(account_id, period_start, period_end)
+            .await?;
+        let current = self.statement_repository
+            .find_current_statement(account_id, period_start, period_end)
+            .await?;
+        if let Some(current) = &current {
+            let backdated = entries.iter().filter(|e| e.posted_at > current.generated_at).count();
+            if backdated == 0 && !force {
+                return self.load_statement(current.id).await;
+            }
+            if backdated > 0 {
+                tracing::info!(
+                    "Statement {} of account {} is superseded by {backdated} backdated transactions",
+                    current.id,
+                    account_id
+                );
+            }
+        }
+
+        let opening_balance = self.statement_repository.find_opening_balance(account_id, period_start).await?;
+        let charges = self.statement_repository
+            .find_statement_charges(account_id, period_start, period_end)
+            .await?;
+        let statement_number = self.statement_repository
+            .find_last_statement_number(account_id)
+            .await?
+            .unwrap_or(0)
+            + 1;
+        let statement = AccountStatement {
+            statement_number,
+            charges: StatementMapper::charges_from_model(charges),
+            supersedes_statement_id: current.map(|c| c.id),
+            ..AccountStatement::build(
+                account_id,
+                period_start,
+                period_end,
+                opening_balance,
+                entries.into_iter().map(StatementMapper::entry_from_source).collect(),
+                generated_by_person_id,
+                Utc::now(),
+            )
+        };
+
+        // A transaction posted between the reads would leave the lines short of the ledger
+        let day_after = period_end.succ_opt().ok_or_else(|| BankingError::ValidationError {
+            field: "period_end".to_string(),
+            message: format!("{period_end} is out of range"),
+        })?;
+        let ledger_balance = self.statement_repository.find_opening_balance(account_id, day_after).await?;
+        if ledger_balance != statement.totals.closing_balance {
+            return Err(BankingError::StatementOutOfBalance {
+                account_id,
+                period_end,
+                closing_balance: statement.totals.closing_balance,
+                ledger_balance,
+            });
+        }
+
+        let (model, lines) = StatementMapper::statement_to_model(statement.clone());
+        self.statement_repository.create_statement(model, lines).await?;
+        if let Some(original_id) = statement.supersedes_statement_id {
+            tracing::info!("Statement {} of account {} supersedes {}", statement.id, account_id, original_id);
+        }
+        Ok(statement)
+    }
+
+    async fn raise_integrity_alert(
+        &self,
+        statement: &AccountStatement,
+        computed_hash: ContentHash,
+        detected_during: StatementIntegrityCheck,
+    ) -> BankingResult<()> {
+        tracing::error!(
+            "Statement {} of account {} failed integrity verification during {:?}: stored {}, computed {}",
+            statement.id,
+            statement.account_id,
+            detected_during,
+            statement.content_hash.to_hex(),
+            computed_hash.to_hex()
+        );
+        let alert = StatementIntegrityAlert {
+            id: Uuid::new_v4(),
+            statement_id: statement.id,
+            account_id: statement.account_id,
+            stored_hash: statement.content_hash,
+            computed_hash,
+            detected_during,
+            detected_at: Utc::now(),
+        };
+        self.statement_repository
+            .create_integrity_alert(StatementMapper::alert_to_model(alert))
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl StatementService for StatementServiceImpl {
+    async fn change_delivery_preference(&self, change: StatementPreferenceChange) -> BankingResult<StatementDeliveryPreference> {
+        self.account_repository
+            .find_by_id(change.account_id)
+            .await?
+            .ok_or(BankingError::AccountNotFound(change.account_id))?;
+        let owners = self.account_repository.find_ownership_by_account(change.account_id).await?;
+        if !owners.iter().any(|owner| owner.customer_id == change.customer_id) {
+            return Err(BankingError::ValidationError {
+                field: "customer_id".to_string(),
+                message: format!("Customer does not own account {}", change.account_id),
+            });
+        }
+
+        let current = self.statement_repository
+            .find_preference(change.account_id)
+            .await?
+            .map(StatementMapper::preference_from_model);
+        let contact = match change.contact_messaging_id {
+            Some(messaging_id) => self.statement_repository
+                .find_customer_contact(change.customer_id, messaging_id)
+                .await?
+                .map(StatementMapper::contact_from_model),
+            None => None,
+        };
+
+        let now = Utc::now();
+        let (preference, consent) = StatementDeliveryPreference::apply_change(current.as_ref(), change, contact.as_ref(), now)?;
+
+        // Confirm on the new contact, or on the previous one when leaving e-statements
+        let notify_messaging_id = preference.contact_messaging_id
+            .or_else(|| current.as_ref().and_then(|p| p.contact_messaging_id));
+        let notification = StatementNotification::queued(
+            StatementNotificationType::PreferenceChanged,
+            consent.account_id,
+            consent.customer_id,
+            notify_messaging_id,
+            None,
+            now,
+        );
+
+        let saved = self.statement_repository
+            .save_preference_change(
+                StatementMapper::preference_to_model(preference),
+                StatementMapper::consent_to_model(consent),
+                StatementMapper::notification_to_model(notification),
+            )
+            .await?;
+        Ok(StatementMapper::preference_from_model(saved))
+    }
+
+    async fn get_delivery_preference(&self, account_id: Uuid) -> BankingResult<Option<StatementDeliveryPreference>> {
+        let preference = self.statement_repository.find_preference(account_id).await?;
+        Ok(preference.map(StatementMapper::preference_from_model))
+    }
+
+    async fn get_consent_history(&self, account_id: Uuid) -> BankingResult<Vec<StatementConsentRecord>> {
+        let records = self.statement_repository.find_consent_records(account_id).await?;
+        Ok(records.into_iter().map(StatementMapper::consent_from_model).collect())
+    }
+
+    async fn dispatch_cycle_statements(&self, cycle_date: NaiveDate) -> BankingResult<StatementCycleReport> {
+        if let Some(batch) = self.statement_repository.find_print_batch(cycle_date).await? {
+            return Ok(StatementCycleReport {
+                cycle_date,
+                accounts_processed: 0,
+                notifications_queued: 0,
+                print_entries: batch.entry_count,
+                print_batch_id: Some(batch.id),
+                already_dispatched: true,
+            });
+        }
+
+        let recipients: Vec<_> = self.statement_repository
+            .find_statement_recipients(cycle_date)
+            .await?
+            .into_iter()
+            .map(StatementMapper::recipient_from_model)
+            .collect();
+        let plan = StatementDispatchPlan::build(cycle_date, &recipients, Utc::now());
+
+        let report = StatementCycleReport {
+            cycle_date,
+            accounts_processed: recipients.len() as i64,
+            notifications_queued: plan.notifications.len() as i64,
+            print_entries: plan.extract.entries.len() as i64,
+            print_batch_id: Some(plan.extract.batch.id),
+            already_dispatched: false,
+        };
+
+        let created = self.statement_repository
+            .create_cycle_dispatch(
+                StatementMapper::batch_to_model(plan.extract.batch),
+                plan.extract.entries.into_iter().map(StatementMapper::entry_to_model).collect(),
+                plan.notifications.into_iter().map(StatementMapper::notification_to_model).collect(),
+            )
+            .await?;
+        if !created {
+            // A concurrent run dispatched the cycle first
+            return self.dispatch_cycle_statements(cycle_date).await;
+        }
+
+        tracing::info!(
+            "Statement cycle {}: {} accounts, {} e-statement notifications, {} printed",
+            cycle_date, report.accounts_processed, report.notifications_queued, report.print_entries
+        );
+        Ok(report)
+    }
+
+    async fn find_print_extract(&self, cycle_date: NaiveDate) -> BankingResult<Option<StatementPrintExtract>> {
+        let Some(batch) = self.statement_repository.find_print_batch(cycle_date).await? else {
+            return Ok(None);
+        };
+        let entries = self.statement_repository.find_print_entries(batch.id).await?;
+
+        Ok(Some(StatementPrintExtract {
+            batch: StatementMapper::batch_from_model(batch),
+            entries: entries.into_iter().map(StatementMapper::entry_from_model).collect(),
+        }))
+    }
+
+    async fn get_paper_statement_report(&self) -> BankingResult<PaperStatementReport> {
+        let accounts: Vec<_> = self.statement_repository
+            .find_paper_statement_accounts()
+            .await?
+            .into_iter()
+            .map(StatementMapper::paper_account_from_model)
+            .collect();
+        let paper_only_count = accounts
+            .iter()
+            .filter(|a| a.delivery_method == StatementDeliveryMethod::Paper)
+            .count() as i64;
+
+        Ok(PaperStatementReport {
+            generated_at: Utc::now(),
+            paper_and_electronic_count: accounts.len() as i64 - paper_only_count,
+            paper_only_count,
+            accounts,
+        })
+    }
+
+    async fn generate_statement(
+        &self,
+        account_id: Uuid,
+        cycle_date: NaiveDate,
+        force: bool,
+        generated_by_person_id: Uuid,
+    ) -> BankingResult<AccountStatement> {
+        if !is_statement_cycle_end(cycle_date) {
+            return Err(BankingError::ValidationError {
+                field: "cycle_date".to_string(),
+                message: format!("{cycle_date} is not a statement cycle end"),
+            });
+        }
+        self.generate(account_id, statement_period_start(cycle_date), cycle_date, force, generated_by_person_id).await
+    }
+
+    async fn generate_period_statement(
+        &self,
+        account_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+        generated_by_person_id: Uuid,
+    ) -> BankingResult<AccountStatement> {
+        if from > to {
+            return Err(BankingError::ValidationError {
+                field: "from".to_string(),
+                message: format!("Statement period starts on {from}, after its end {to}"),
+            });
+        }
+        self.generate(account_id, from, to, false, generated_by_person_id).await
+    }
+
+    async fn reprint_statement(&self, statement_id: Uuid) -> BankingResult<AccountStatement> {
+        let statement = self.load_statement(statement_id).await?;
+        let computed_hash = statement.compute_hash();
+        if computed_hash != statement.content_hash {
+            self.raise_integrity_alert(&statement, computed_hash, StatementIntegrityCheck::Reprint).await?;
+            return Err(BankingError::StatementIntegrityViolation {
+                statement_id,
+                stored_hash: statement.content_hash.to_hex().to_string(),
+                computed_hash: computed_hash.to_hex().to_string(),
+            });
+        }
+        Ok(statement)
+    }
+
+    async fn verify_statement_archive(&self, period_end: NaiveDate) -> BankingResult<StatementArchiveVerification> {
+        let models = self.statement_repository.find_statements_by_period(period_end).await?;
+        let statements_checked = models.len() as i64;
+        let mut tampered_statement_ids = Vec::new();
+
+        for model in models {
+            let lines = self.statement_repository.find_statement_lines(model.id).await?;
+            let statement = StatementMapper::statement_from_model(model, lines);
+            let computed_hash = statement.compute_hash();
+            if computed_hash != statement.content_hash {
+                self.raise_integrity_alert(&statement, computed_hash, StatementIntegrityCheck::ArchiveVerification).await?;
+                tampered_statement_ids.push(statement.id);
+            }
+        }
+
+        Ok(StatementArchiveVerification {
+            period_end,
+            statements_checked,
+            intact_count: statements_checked - tampered_statement_ids.len() as i64,
+            tampered_statement_ids,
+            verified_at: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use banking_db::models::{
+        AccountStatementModel, CustomerContactModel, PaperStatementAccountModel,
+        StatementChargeSummaryModel, StatementConsentRecordModel, StatementDeliveryPreferenceModel, StatementEntryModel,
+        StatementIntegrityAlertModel, StatementLineModel, StatementNotificationModel, StatementPrintBatchModel,
+        StatementPrintEntryModel, StatementRecipientModel,
+    };
+    use heapless::String as HeaplessString;
+    use rust_decimal::Decimal;
+    use crate::services::test_doubles::{active_account, InMemoryAccountRepository};
+
+    /// Stored statements in memory; every account has the same transactions,
+    /// on top of a balance of 1000 before June
+    #[derive(Default)]
+    struct MockStatementRepository {
+        entries: Mutex<Vec<StatementEntryModel>>,
+        /// Posted after the statement lines were read
+        late_entries: Mutex<Vec<StatementEntryModel>>,
+        charges: Mutex<StatementChargeSummaryModel>,
+        statements: Mutex<Vec<AccountStatementModel>>,
+        lines: Mutex<Vec<StatementLineModel>>,
+        alerts: Mutex<Vec<StatementIntegrityAlertModel>>,
+    }
+
+    #[async_trait]
+    impl StatementRepository for MockStatementRepository {
+        async fn find_opening_balance(&self, _account_id: Uuid, period_start: NaiveDate) -> BankingResult<Decimal> {
+            let entries = self.entries.lock().unwrap();
+            let late_entries = self.late_entries.lock().unwrap();
+            Ok(entries
+                .iter()
+                .chain(late_entries.iter())
+                .filter(|e| e.value_date < period_start)
+                .fold(Decimal::from(1000), |balance, e| balance + e.amount))
+        }
+        async fn find_statement_entries(&self, _account_id: Uuid, period_start: NaiveDate, period_end: NaiveDate) -> BankingResult<Vec<StatementEntryModel>> {
+            let mut entries: Vec<_> = self.entries
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|e| e.value_date >= period_start && e.value_date <= period_end)
+                .cloned()
+                .collect();
+            entries.sort_by_key(|e| e.value_date);
+            Ok(entries)
+        }
+        async fn find_statement_charges(&self, _account_id: Uuid, _period_start: NaiveDate, _period_end: NaiveDate) -> BankingResult<StatementChargeSummaryModel> {
+            Ok(self.charges.lock().unwrap().clone())
+        }
+        async fn find_last_statement_number(&self, account_id: Uuid) -> BankingResult<Option<i32>> {
+            Ok(self.statements.lock().unwrap().iter().filter(|s| s.account_id == account_id).map(|s| s.statement_number).max())
+        }
+        async fn create_statement(&self, statement: AccountStatementModel, lines: Vec<StatementLineModel>) -> BankingResult<AccountStatementModel> {
+            self.statements.lock().unwrap().push(statement.clone());
+            self.lines.lock().unwrap().extend(lines);
+            Ok(statement)
+        }
+        async fn find_statement_by_id(&self, statement_id: Uuid) -> BankingResult<Option<AccountStatementModel>> {
+            Ok(self.statements.lock().unwrap().iter().find(|s| s.id == statement_id).cloned())
+        }
+        async fn find_statement_lines(&self, statement_id: Uuid) -> BankingResult<Vec<StatementLineModel>> {
+            let mut lines: Vec<_> = self.lines.lock().unwrap().iter().filter(|l| l.statement_id == statement_id).cloned().collect();
+            lines.sort_by_key(|l| l.line_number);
+            Ok(lines)
+        }
+        async fn find_current_statement(&self, account_id: Uuid, period_start: NaiveDate, period_end: NaiveDate) -> BankingResult<Option<AccountStatementModel>> {
+            let statements = self.statements.lock().unwrap();
+            Ok(statements
+                .iter()
+                .find(|s| {
+                    s.account_id == account_id
+                        && s.period_start == period_start
+                        && s.period_end == period_end
+                        && !statements.iter().any(|other| other.supersedes_statement_id == Some(s.id))
+                })
+                .cloned())
+        }
+        async fn find_statements_by_period(&self, period_end: NaiveDate) -> BankingResult<Vec<AccountStatementModel>> {
+            Ok(self.statements.lock().unwrap().iter().filter(|s| s.period_end == period_end).cloned().collect())
+        }
+        async fn create_integrity_alert(&self, alert: StatementIntegrityAlertModel) -> BankingResult<StatementIntegrityAlertModel> {
+            self.alerts.lock().unwrap().push(alert.clone());
+            Ok(alert)
+        }
+        async fn find_preference(&self, _account_id: Uuid) -> BankingResult<Option<StatementDeliveryPreferenceModel>> { todo!() }
+        async fn save_preference_change(&self, _preference: StatementDeliveryPreferenceModel, _consent: StatementConsentRecordModel, _notification: StatementNotificationModel) -> BankingResult<StatementDeliveryPreferenceModel> { todo!() }
+        async fn find_consent_records(&self, _account_id: Uuid) -> BankingResult<Vec<StatementConsentRecordModel>> { todo!() }
+        async fn find_customer_contact(&self, _customer_id: Uuid, _messaging_id: Uuid) -> BankingResult<Option<CustomerContactModel>> { todo!() }
+        async fn find_statement_recipients(&self, _cycle_date: NaiveDate) -> BankingResult<Vec<StatementRecipientModel>> { todo!() }
+        async fn create_cycle_dispatch(&self, _batch: StatementPrintBatchModel, _entries: Vec<StatementPrintEntryModel>, _notifications: Vec<StatementNotificationModel>) -> BankingResult<bool> { todo!() }
+        async fn find_print_batch(&self, _cycle_date: NaiveDate) -> BankingResult<Option<StatementPrintBatchModel>> { todo!() }
+        async fn find_print_entries(&self, _batch_id: Uuid) -> BankingResult<Vec<StatementPrintEntryModel>> { todo!() }
+        async fn find_paper_statement_accounts(&self) -> BankingResult<Vec<PaperStatementAccountModel>> { todo!() }
+    }
+
+    fn entry(day: u32, amount: Decimal) -> StatementEntryModel {
+        let value_date = NaiveDate::from_ymd_opt(2024, 6, day).unwrap();
+        StatementEntryModel {
+            transaction_id: Uuid::new_v4(),
+            value_date,
+            description: HeaplessString::try_from("Card payment").unwrap(),
+            amount,
+            posted_at: value_date.and_hms_opt(12, 0, 0).unwrap().and_utc(),
+        }
+    }
+
+    fn june(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, day).unwrap()
+    }
+
+    fn service() -> (StatementServiceImpl, Arc<MockStatementRepository>, Arc<InMemoryAccountRepository>) {
+        let repository = Arc::new(MockStatementRepository::default());
+        *repository.entries.lock().unwrap() = vec![entry(4, Decimal::new(-1250, 2)), entry(18, Decimal::from(300))];
+        let accounts = Arc::new(InMemoryAccountRepository::default());
+        (StatementServiceImpl::new(repository.clone(), accounts.clone()), repository, accounts)
+    }
+
+    fn open_account(accounts: &InMemoryAccountRepository) -> Uuid {
+        let account = active_account(Uuid::new_v4());
+        accounts.insert_accounts([account.clone()]);
+        account.id
+    }
+
+    #[tokio::test]
+    async fn test_tampered_line_is_detected_on_reprint_and_archive_check() {
+        let (service, repository, accounts) = service();
+        let cycle_date = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+        let person_id = Uuid::new_v4();
+        let tampered = service.generate_statement(open_account(&accounts), cycle_date, false, person_id).await.unwrap();
+        let untouched = service.generate_statement(open_account(&accounts), cycle_date, false, person_id).await.unwrap();
+
+        let reprint = service.reprint_statement(tampered.id).await.unwrap();
+        assert_eq!(reprint.lines, tampered.lines);
+        assert_eq!(reprint.content_hash, tampered.content_hash);
+
+        // Edit a stored line behind the service's back
+        for line in repository.lines.lock().unwrap().iter_mut() {
+            if line.statement_id == tampered.id && line.line_number == 1 {
+                line.amount = Decimal::new(-250, 2);
+            }
+        }
+
+        let result = service.reprint_statement(tampered.id).await;
+        assert!(matches!(
+            result,
+            Err(BankingError::StatementIntegrityViolation { statement_id, .. }) if statement_id == tampered.id
+        ));
+        {
+            let alerts = repository.alerts.lock().unwrap();
+            assert_eq!(alerts.len(), 1);
+            assert_eq!(alerts[0].statement_id, tampered.id);
+            assert_eq!(alerts[0].stored_hash, tampered.content_hash);
+            assert_ne!(alerts[0].computed_hash, tampered.content_hash);
+        }
+        assert!(service.reprint_statement(untouched.id).await.is_ok());
+
+        let verification = service.verify_statement_archive(cycle_date).await.unwrap();
+        assert_eq!(verification.statements_checked, 2);
+        assert_eq!(verification.intact_count, 1);
+        assert_eq!(verification.tampered_statement_ids, vec![tampered.id]);
+        assert!(!verification.is_clean());
+        assert_eq!(repository.alerts.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_forced_regeneration_supersedes_and_keeps_original() {
+        let (service, repository, accounts) = service();
+        let account_id = open_account(&accounts);
+        let cycle_date = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+        let person_id = Uuid::new_v4();
+
+        let original = service.generate_statement(account_id, cycle_date, false, person_id).await.unwrap();
+        assert_eq!(original.totals.closing_balance, Decimal::new(128750, 2));
+
+        // Without force the stored statement is served again
+        let again = service.generate_statement(account_id, cycle_date, false, person_id).await.unwrap();
+        assert_eq!(again.id, original.id);
+
+        // A late posting changes the regenerated content
+        repository.entries.lock().unwrap().push(entry(29, Decimal::from(-50)));
+        let second = service.generate_statement(account_id, cycle_date, true, person_id).await.unwrap();
+        let third = service.generate_statement(account_id, cycle_date, true, person_id).await.unwrap();
+
+        assert_ne!(second.id, original.id);
+        assert_ne!(second.content_hash, original.content_hash);
+        assert_eq!(second.supersedes_statement_id, Some(original.id));
+        assert_eq!(third.supersedes_statement_id, Some(second.id));
+        assert_eq!(third.statement_reference, original.statement_reference);
+
+        let kept = service.reprint_statement(original.id).await.unwrap();
+        assert_eq!(kept.lines.len(), 2);
+        assert_eq!(kept.content_hash, original.content_hash);
+
+        let current = service.generate_statement(account_id, cycle_date, false, person_id).await.unwrap();
+        assert_eq!(current.id, third.id);
+        assert_eq!(service.verify_statement_archive(cycle_date).await.unwrap().statements_checked, 3);
+
+        assert!(service
+            .generate_statement(account_id, NaiveDate::from_ymd_opt(2024, 6, 15).unwrap(), false, person_id)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_period_statement_running_balance_matches_seeded_transactions() {
+        let (service, repository, accounts) = service();
+        let account_id = open_account(&accounts);
+        let person_id = Uuid::new_v4();
+        {
+            let mut entries = repository.entries.lock().unwrap();
+            entries.push(entry(10, Decimal::new(-4025, 2)));
+            entries.push(entry(25, Decimal::from(100)));
+        }
+        *repository.charges.lock().unwrap() = StatementChargeSummaryModel {
+            interest_accrued: Decimal::new(137, 2),
+            fees_charged: Decimal::new(250, 2),
+            fee_count: 1,
+        };
+
+        let statement = service.generate_period_statement(account_id, june(5), june(20), person_id).await.unwrap();
+
+        // 1000 less the 12.50 debit of June 4, before the period
+        assert_eq!(statement.totals.opening_balance, Decimal::new(98750, 2));
+        let columns: Vec<_> = statement.lines.iter().map(|l| (l.value_date, l.debit(), l.credit(), l.running_balance)).collect();
+        assert_eq!(columns, vec![
+            (june(10), Decimal::new(4025, 2), Decimal::ZERO, Decimal::new(94725, 2)),
+            (june(18), Decimal::ZERO, Decimal::from(300), Decimal::new(124725, 2)),
+        ]);
+        assert_eq!(statement.totals.total_debits, Decimal::new(4025, 2));
+        assert_eq!(statement.totals.total_credits, Decimal::from(300));
+        assert_eq!(statement.totals.closing_balance, Decimal::new(124725, 2));
+        assert_eq!(statement.charges.interest_accrued, Decimal::new(137, 2));
+        assert_eq!(statement.charges.fees_charged, Decimal::new(250, 2));
+        assert_eq!(statement.statement_number, 1);
+
+        // The stored statement is served again; other periods are numbered on
+        let again = service.generate_period_statement(account_id, june(5), june(20), person_id).await.unwrap();
+        assert_eq!(again.id, statement.id);
+        assert_eq!(again.charges, statement.charges);
+        let cycle = service.generate_statement(account_id, june(30), false, person_id).await.unwrap();
+        assert_eq!(cycle.statement_number, 2);
+        assert_eq!(cycle.totals.closing_balance, Decimal::new(134725, 2));
+
+        // A period without transactions closes where it opened
+        let quiet = service.generate_period_statement(account_id, june(19), june(24), person_id).await.unwrap();
+        assert!(quiet.lines.is_empty());
+        assert_eq!(quiet.totals.opening_balance, Decimal::new(124725, 2));
+        assert_eq!(quiet.totals.closing_balance, quiet.totals.opening_balance);
+        assert_eq!(quiet.statement_number, 3);
+
+        assert!(matches!(
+            service.generate_period_statement(account_id, june(20), june(5), person_id).await,
+            Err(BankingError::ValidationError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_backdated_transaction_supersedes_the_stored_statement() {
+        let (service, repository, accounts) = service();
+        let account_id = open_account(&accounts);
+        let person_id = Uuid::new_v4();
+        let original = service.generate_period_statement(account_id, june(1), june(15), person_id).await.unwrap();
+        assert_eq!(original.lines.len(), 1);
+
+        // Posted now with a value date inside the stored period
+        let mut backdated = entry(12, Decimal::from(-75));
+        backdated.posted_at = Utc::now();
+        repository.entries.lock().unwrap().push(backdated);
+
+        let regenerated = service.generate_period_statement(account_id, june(1), june(15), person_id).await.unwrap();
+        assert_eq!(regenerated.supersedes_statement_id, Some(original.id));
+        assert_eq!(regenerated.statement_number, 2);
+        assert_eq!(regenerated.lines.len(), 2);
+        assert_eq!(regenerated.totals.closing_balance, Decimal::new(91250, 2));
+
+        let current = service.generate_period_statement(account_id, june(1), june(15), person_id).await.unwrap();
+        assert_eq!(current.id, regenerated.id);
+        assert_eq!(service.reprint_statement(original.id).await.unwrap().lines.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_statement_short_of_the_ledger_is_not_stored() {
+        let (service, repository, accounts) = service();
+        repository.late_entries.lock().unwrap().push(entry(8, Decimal::from(20)));
+
+        let result = service.generate_period_statement(open_account(&accounts), june(1), june(15), Uuid::new_v4()).await;
+        assert!(matches!(
+            result,
+            Err(BankingError::StatementOutOfBalance { closing_balance, ledger_balance, .. })
+                if closing_balance == Decimal::new(98750, 2) && ledger_balance == Decimal::new(100750, 2)
+        ));
+        assert!(repository.statements.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_statement_for_unknown_account_is_refused() {
+        let (service, repository, _accounts) = service();
+        let account_id = Uuid::new_v4();
+
+        let result = service.generate_period_statement(account_id, june(1), june(15), Uuid::new_v4()).await;
+        assert!(matches!(result, Err(BankingError::AccountNotFound(id)) if id == account_id));
+        assert!(repository.statements.lock().unwrap().is_empty());
+    }
+}
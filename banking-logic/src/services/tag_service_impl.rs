@@ -0,0 +1,457 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{BulkTagReport, BulkTagTarget, EntityTag, Tag, TagActor, TaggableEntityKind},
+    service::TagService,
+};
+use banking_db::repository::TagRepository;
+use crate::mappers::TagMapper;
+
+const DEFAULT_BULK_CHUNK_SIZE: i64 = 500;
+
+/// Production implementation of TagService
+pub struct TagServiceImpl {
+    tag_repository: Arc<dyn TagRepository>,
+    bulk_chunk_size: i64,
+}
+
+impl TagServiceImpl {
+    pub fn new(tag_repository: Arc<dyn TagRepository>) -> Self {
+        Self {
+            tag_repository,
+            bulk_chunk_size: DEFAULT_BULK_CHUNK_SIZE,
+        }
+    }
+
+    /// Number of entities tagged per statement by bulk_assign_tag
+    pub fn with_bulk_chunk_size(mut self, bulk_chunk_size: i64) -> Self {
+        self.bulk_chunk_size = bulk_chunk_size.max(1);
+        self
+    }
+
+    async fn load_by_code(&self, tag_code: &str) -> BankingResult<Tag> {
+        self.tag_repository
+            .find_tag_by_code(tag_code)
+            .await?
+            .map(TagMapper::from_model)
+            .ok_or_else(|| BankingError::NotFound(format!("Tag {tag_code} not found")))
+    }
+
+    async fn require_entity(&self, entity_kind: TaggableEntityKind, entity_id: Uuid) -> BankingResult<()> {
+        if self.tag_repository.entity_exists(TagMapper::entity_kind_to_db(entity_kind), entity_id).await? {
+            Ok(())
+        } else {
+            Err(BankingError::NotFound(format!("{entity_kind:?} {entity_id} not found")))
+        }
+    }
+
+    fn validate(tag: &Tag) -> BankingResult<()> {
+        tag.validate().map_err(|message| BankingError::ValidationError {
+            field: "tag".to_string(),
+            message,
+        })
+    }
+}
+
+#[async_trait]
+impl TagService for TagServiceImpl {
+    async fn create_tag(&self, tag: Tag) -> BankingResult<Tag> {
+        Self::validate(&tag)?;
+        if self.tag_repository.find_tag_by_code(tag.code.as_str()).await?.is_some() {
+            return Err(BankingError::ValidationError {
+                field: "code".to_string(),
+                message: format!("Tag {} already exists", tag.code),
+            });
+        }
+
+        let created = self.tag_repository.create_tag(TagMapper::to_model(tag)).await?;
+        Ok(TagMapper::from_model(created))
+    }
+
+    async fn update_tag(&self, mut tag: Tag) -> BankingResult<Tag> {
+        Self::validate(&tag)?;
+        tag.last_updated_at = Utc::now();
+
+        let updated = self.tag_repository.update_tag(TagMapper::to_model(tag)).await?;
+        Ok(TagMapper::from_model(updated))
+    }
+
+    async fn find_tag_by_code(&self, code: &str) -> BankingResult<Option<Tag>> {
+        Ok(self.tag_repository.find_tag_by_code(code).await?.map(TagMapper::from_model))
+    }
+
+    async fn find_active_tags(&self) -> BankingResult<Vec<Tag>> {
+        let tags = self.tag_repository.find_active_tags().await?;
+        Ok(tags.into_iter().map(TagMapper::from_model).collect())
+    }
+
+    async fn deactivate_tag(&self, tag_id: Uuid, updated_by_person_id: Uuid) -> BankingResult<()> {
+        self.tag_repository
+            .find_tag_by_id(tag_id)
+            .await?
+            .ok_or_else(|| BankingError::NotFound(format!("Tag {tag_id} not found")))?;
+
+        self.tag_repository.deactivate_tag(tag_id, updated_by_person_id).await
+    }
+
+    async fn assign_tag(
+        &self,
+        tag_code: &str,
+        entity_kind: TaggableEntityKind,
+        entity_id: Uuid,
+        actor: &TagActor,
+    ) -> BankingResult<EntityTag> {
+        let tag = self.load_by_code(tag_code).await?;
+        tag.authorize(entity_kind, actor)?;
+        self.require_entity(entity_kind, entity_id).await?;
+
+        let entity_tag = EntityTag {
+            id: Uuid::new_v4(),
+            tag_id: tag.id,
+            entity_kind,
+            entity_id,
+            assigned_at: Utc::now(),
+            assigned_by_person_id: actor.person_id,
+        };
+        let assigned = self.tag_repository.assign_tag(TagMapper::entity_tag_to_model(entity_tag)).await?;
+        Ok(TagMapper::entity_tag_from_model(assigned))
+    }
+
+    async fn remove_tag(
+        &self,
+        tag_code: &str,
+        entity_kind: TaggableEntityKind,
+        entity_id: Uuid,
+        actor: &TagActor,
+    ) -> BankingResult<()> {
+        let tag = self.load_by_code(tag_code).await?;
+        if let Some(permission) = &tag.required_permission {
+            // Deactivated protected tags can still be removed, but only with the permission
+            if !actor.has_permission(permission) {
+                return Err(BankingError::UnauthorizedOperation(format!(
+                    "Tag {} requires permission {permission}",
+                    tag.code
+                )));
+            }
+        }
+
+        let removed = self.tag_repository
+            .remove_tag(tag.id, TagMapper::entity_kind_to_db(entity_kind), entity_id)
+            .await?;
+        if !removed {
+            return Err(BankingError::NotFound(format!(
+                "{entity_kind:?} {entity_id} is not tagged {}",
+                tag.code
+            )));
+        }
+        Ok(())
+    }
+
+    async fn find_tags_for_entity(&self, entity_kind: TaggableEntityKind, entity_id: Uuid) -> BankingResult<Vec<Tag>> {
+        let tags = self.tag_repository
+            .find_tags_for_entity(TagMapper::entity_kind_to_db(entity_kind), entity_id)
+            .await?;
+        Ok(tags.into_iter().map(TagMapper::from_model).collect())
+    }
+
+    async fn bulk_assign_tag(&self, tag_code: &str, target: BulkTagTarget, actor: &TagActor) -> BankingResult<BulkTagReport> {
+        let entity_kind = target.entity_kind();
+        let tag = self.load_by_code(tag_code).await?;
+        tag.authorize(entity_kind, actor)?;
+
+        let target = TagMapper::bulk_target_to_model(target);
+        let db_entity_kind = TagMapper::entity_kind_to_db(entity_kind);
+        let mut report = BulkTagReport {
+            tag_code: tag.code.clone(),
+            entity_kind,
+            matched: 0,
+            newly_tagged: 0,
+            already_tagged: 0,
+            chunks_processed: 0,
+            errors: Vec::new(),
+        };
+
+        let mut after_entity_id = None;
+        loop {
+            let page = self.tag_repository
+                .find_bulk_target_ids(&target, after_entity_id, self.bulk_chunk_size)
+                .await?;
+            let Some(last) = page.last().copied() else {
+                break;
+            };
+            let is_last_page = (page.len() as i64) < self.bulk_chunk_size;
+            let chunk_len = page.len() as u64;
+
+            // A failed chunk is reported and skipped; rerunning the bulk
+            // assignment picks it up since already tagged entities are ignored
+            match self.tag_repository
+                .assign_tag_bulk(tag.id, db_entity_kind, page, actor.person_id, Utc::now())
+                .await
+            {
+                Ok(newly_tagged) => {
+                    report.matched += chunk_len;
+                    report.newly_tagged += newly_tagged;
+                    report.already_tagged += chunk_len - newly_tagged;
+                }
+                Err(e) => {
+                    report.errors.push(format!("Chunk after {after_entity_id:?} failed: {e}"));
+                }
+            }
+            report.chunks_processed += 1;
+
+            if is_last_page {
+                break;
+            }
+            after_entity_id = Some(last);
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+    use banking_api::domain::{AccountTagSelection, TagFilter, TagMatchMode};
+    use banking_db::models::{
+        BulkTagTargetModel, DbTagMatchMode, DbTaggableEntityKind, EntityTagModel, TagModel,
+    };
+    use chrono::DateTime;
+    use heapless::String as HeaplessString;
+
+    /// Accounts only; the bulk target honours the tag filter of the selection
+    #[derive(Default)]
+    struct MockTagRepository {
+        tags: Mutex<Vec<TagModel>>,
+        account_ids: Mutex<Vec<Uuid>>,
+        entity_tags: Mutex<Vec<EntityTagModel>>,
+    }
+
+    impl MockTagRepository {
+        fn has_tag(&self, entity_id: Uuid, code: &str) -> bool {
+            let tags = self.tags.lock().unwrap();
+            self.entity_tags.lock().unwrap().iter().any(|et| {
+                et.entity_id == entity_id
+                    && tags.iter().any(|t| t.id == et.tag_id && t.code.as_str() == code)
+            })
+        }
+    }
+
+    #[async_trait]
+    impl TagRepository for MockTagRepository {
+        async fn create_tag(&self, tag: TagModel) -> BankingResult<TagModel> {
+            self.tags.lock().unwrap().push(tag.clone());
+            Ok(tag)
+        }
+
+        async fn update_tag(&self, tag: TagModel) -> BankingResult<TagModel> {
+            let mut tags = self.tags.lock().unwrap();
+            let existing = tags.iter_mut().find(|t| t.id == tag.id).unwrap();
+            *existing = tag.clone();
+            Ok(tag)
+        }
+
+        async fn find_tag_by_id(&self, tag_id: Uuid) -> BankingResult<Option<TagModel>> {
+            Ok(self.tags.lock().unwrap().iter().find(|t| t.id == tag_id).cloned())
+        }
+
+        async fn find_tag_by_code(&self, code: &str) -> BankingResult<Option<TagModel>> {
+            Ok(self.tags.lock().unwrap().iter().find(|t| t.code.as_str() == code).cloned())
+        }
+
+        async fn find_active_tags(&self) -> BankingResult<Vec<TagModel>> {
+            Ok(self.tags.lock().unwrap().iter().filter(|t| t.is_active).cloned().collect())
+        }
+
+        async fn deactivate_tag(&self, tag_id: Uuid, _updated_by_person_id: Uuid) -> BankingResult<()> {
+            let mut tags = self.tags.lock().unwrap();
+            tags.iter_mut().find(|t| t.id == tag_id).unwrap().is_active = false;
+            Ok(())
+        }
+
+        async fn entity_exists(&self, _entity_kind: DbTaggableEntityKind, entity_id: Uuid) -> BankingResult<bool> {
+            Ok(self.account_ids.lock().unwrap().contains(&entity_id))
+        }
+
+        async fn assign_tag(&self, entity_tag: EntityTagModel) -> BankingResult<EntityTagModel> {
+            let mut entity_tags = self.entity_tags.lock().unwrap();
+            if let Some(existing) = entity_tags
+                .iter()
+                .find(|et| et.tag_id == entity_tag.tag_id && et.entity_id == entity_tag.entity_id)
+            {
+                return Ok(existing.clone());
+            }
+            entity_tags.push(entity_tag.clone());
+            Ok(entity_tag)
+        }
+
+        async fn remove_tag(&self, tag_id: Uuid, _entity_kind: DbTaggableEntityKind, entity_id: Uuid) -> BankingResult<bool> {
+            let mut entity_tags = self.entity_tags.lock().unwrap();
+            let before = entity_tags.len();
+            entity_tags.retain(|et| !(et.tag_id == tag_id && et.entity_id == entity_id));
+            Ok(entity_tags.len() < before)
+        }
+
+        async fn find_tags_for_entity(&self, _entity_kind: DbTaggableEntityKind, entity_id: Uuid) -> BankingResult<Vec<TagModel>> {
+            let tag_ids: HashSet<Uuid> = self.entity_tags.lock().unwrap()
+                .iter()
+                .filter(|et| et.entity_id == entity_id)
+                .map(|et| et.tag_id)
+                .collect();
+            Ok(self.tags.lock().unwrap().iter().filter(|t| tag_ids.contains(&t.id)).cloned().collect())
+        }
+
+        async fn find_bulk_target_ids(
+            &self,
+            target: &BulkTagTargetModel,
+            after_entity_id: Option<Uuid>,
+            limit: i64,
+        ) -> BankingResult<Vec<Uuid>> {
+            let BulkTagTargetModel::Accounts(selection) = target else {
+                return Ok(Vec::new());
+            };
+            let mut ids = self.account_ids.lock().unwrap().clone();
+            ids.sort();
+            Ok(ids
+                .into_iter()
+                .filter(|id| after_entity_id.is_none_or(|after| *id > after))
+                .filter(|id| match &selection.tags {
+                    Some(filter) => {
+                        let mut matches = filter.tag_codes.iter().map(|code| self.has_tag(*id, code));
+                        match filter.match_mode {
+                            DbTagMatchMode::Any => matches.any(|m| m),
+                            DbTagMatchMode::All => matches.all(|m| m),
+                        }
+                    }
+                    None => true,
+                })
+                .take(limit as usize)
+                .collect())
+        }
+
+        async fn assign_tag_bulk(
+            &self,
+            tag_id: Uuid,
+            entity_kind: DbTaggableEntityKind,
+            entity_ids: Vec<Uuid>,
+            assigned_by_person_id: Uuid,
+            assigned_at: DateTime<Utc>,
+        ) -> BankingResult<u64> {
+            let mut newly_tagged = 0;
+            for entity_id in entity_ids {
+                let before = self.entity_tags.lock().unwrap().len();
+                self.assign_tag(EntityTagModel {
+                    id: Uuid::new_v4(),
+                    tag_id,
+                    entity_kind,
+                    entity_id,
+                    assigned_at,
+                    assigned_by_person_id,
+                })
+                .await?;
+                if self.entity_tags.lock().unwrap().len() > before {
+                    newly_tagged += 1;
+                }
+            }
+            Ok(newly_tagged)
+        }
+    }
+
+    fn tag(code: &str, required_permission: Option<&str>) -> Tag {
+        Tag {
+            id: Uuid::new_v4(),
+            code: HeaplessString::try_from(code).unwrap(),
+            description: HeaplessString::try_from(code).unwrap(),
+            applicable_entity_kinds: vec![TaggableEntityKind::Account],
+            required_permission: required_permission.map(|p| HeaplessString::try_from(p).unwrap()),
+            is_active: true,
+            created_at: Utc::now(),
+            last_updated_at: Utc::now(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    fn actor(permissions: &[&str]) -> TagActor {
+        TagActor {
+            person_id: Uuid::new_v4(),
+            permissions: permissions.iter().map(|p| HeaplessString::try_from(*p).unwrap()).collect(),
+        }
+    }
+
+    fn setup(accounts: usize) -> (Arc<MockTagRepository>, TagServiceImpl) {
+        let repository = Arc::new(MockTagRepository::default());
+        *repository.account_ids.lock().unwrap() = (0..accounts).map(|_| Uuid::new_v4()).collect();
+        let service = TagServiceImpl::new(repository.clone()).with_bulk_chunk_size(2);
+        (repository, service)
+    }
+
+    #[tokio::test]
+    async fn test_protected_tag_requires_permission_to_assign_and_remove() {
+        let (repository, service) = setup(1);
+        let account_id = repository.account_ids.lock().unwrap()[0];
+        service.create_tag(tag("StaffAccount", Some("TAG_STAFF_ACCOUNT"))).await.unwrap();
+
+        let clerk = actor(&[]);
+        let result = service.assign_tag("StaffAccount", TaggableEntityKind::Account, account_id, &clerk).await;
+        assert!(matches!(result, Err(BankingError::UnauthorizedOperation(_))));
+        assert!(repository.entity_tags.lock().unwrap().is_empty());
+
+        let officer = actor(&["TAG_STAFF_ACCOUNT"]);
+        service.assign_tag("StaffAccount", TaggableEntityKind::Account, account_id, &officer).await.unwrap();
+        // Assigning again keeps the single assignment
+        service.assign_tag("StaffAccount", TaggableEntityKind::Account, account_id, &officer).await.unwrap();
+        assert_eq!(repository.entity_tags.lock().unwrap().len(), 1);
+
+        let result = service.remove_tag("StaffAccount", TaggableEntityKind::Account, account_id, &clerk).await;
+        assert!(matches!(result, Err(BankingError::UnauthorizedOperation(_))));
+
+        service.remove_tag("StaffAccount", TaggableEntityKind::Account, account_id, &officer).await.unwrap();
+        assert!(service.find_tags_for_entity(TaggableEntityKind::Account, account_id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_assign_to_unknown_entity_fails() {
+        let (_, service) = setup(0);
+        service.create_tag(tag("VIP", None)).await.unwrap();
+
+        let result = service.assign_tag("VIP", TaggableEntityKind::Account, Uuid::new_v4(), &actor(&[])).await;
+        assert!(matches!(result, Err(BankingError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_assign_by_tag_filter_reports_chunks() {
+        let (repository, service) = setup(5);
+        let mut accounts = repository.account_ids.lock().unwrap().clone();
+        accounts.sort();
+        let admin = actor(&[]);
+        service.create_tag(tag("LegacyMigrationBatch7", None)).await.unwrap();
+        service.create_tag(tag("Reviewed", None)).await.unwrap();
+        for account_id in &accounts[..4] {
+            service.assign_tag("LegacyMigrationBatch7", TaggableEntityKind::Account, *account_id, &admin).await.unwrap();
+        }
+        service.assign_tag("Reviewed", TaggableEntityKind::Account, accounts[0], &admin).await.unwrap();
+
+        let target = BulkTagTarget::Accounts(AccountTagSelection {
+            tags: Some(TagFilter {
+                tag_codes: vec![HeaplessString::try_from("LegacyMigrationBatch7").unwrap()],
+                match_mode: TagMatchMode::Any,
+            }),
+            ..Default::default()
+        });
+        let report = service.bulk_assign_tag("Reviewed", target, &admin).await.unwrap();
+
+        assert_eq!(report.matched, 4);
+        assert_eq!(report.newly_tagged, 3);
+        assert_eq!(report.already_tagged, 1);
+        assert_eq!(report.chunks_processed, 2);
+        assert!(report.errors.is_empty());
+        assert!(accounts[..4].iter().all(|id| repository.has_tag(*id, "Reviewed")));
+        assert!(!repository.has_tag(accounts[4], "Reviewed"));
+    }
+}
@@ -0,0 +1,656 @@
+//! In-memory test doubles shared by the service tests.
+//!
+//! Every method answers from the stored rows, with an empty result when
+//! nothing matches, or with the error the PostgreSQL implementation would
+//! return (unknown account, stale version, invalid status). Nothing panics,
+//! so a test exercising a path it did not seed fails on an assertion rather
+//! than on a `todo!()`.
+
+use std::collections::{BTreeMap, HashSet};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use banking_api::{BankingError, BankingResult};
+use banking_db::models::casa::OverdraftInterestAccrual;
+use banking_db::models::account::{
+    AccountBalanceChangeRecordModel, AccountBalanceSnapshotModel, AccountFinalSettlementModel,
+    AccountInterestAccrualModel, AccountMandateModel, AccountModel, AccountOwnershipModel, AccountRelationshipModel,
+    AccountStatusChangeRecordModel, DbAccountStatus, DbAccountType, DbBalanceChangeSource, DbEntityType,
+    DbMandateStatus, DbOwnershipType, DbSettlementStatus, DbSigningCondition, LoanPenaltyAccrualModel,
+};
+use banking_db::repository::AccountRepository;
+use chrono::{DateTime, NaiveDate, Utc};
+use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+/// Active savings account in USD with the given id, for tests that only need
+/// the account to exist
+pub(crate) fn active_account(account_id: Uuid) -> AccountModel {
+    AccountModel {
+        id: account_id,
+        product_id: Uuid::new_v4(),
+        account_type: DbAccountType::Savings,
+        account_status: DbAccountStatus::Active,
+        signing_condition: DbSigningCondition::None,
+        currency: HeaplessString::try_from("USD").unwrap(),
+        open_date: NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+        domicile_agency_branch_id: Uuid::new_v4(),
+        account_number: HeaplessString::try_from("10005000010000000004262").unwrap(),
+        gl_code_suffix: None,
+        current_balance: Decimal::ZERO,
+        available_balance: Decimal::ZERO,
+        accrued_interest: Decimal::ZERO,
+        overdraft_limit: None,
+        original_principal: None,
+        outstanding_principal: None,
+        loan_interest_rate: None,
+        loan_term_months: None,
+        disbursement_date: None,
+        maturity_date: None,
+        installment_amount: None,
+        next_due_date: None,
+        penalty_rate: None,
+        collateral_id: None,
+        loan_purpose_id: None,
+        close_date: None,
+        last_activity_date: None,
+        dormancy_threshold_days: None,
+        reactivation_required: false,
+        pending_closure_reason_id: None,
+        last_disbursement_instruction_id: None,
+        status_changed_by_person_id: None,
+        status_change_reason_id: None,
+        status_change_timestamp: None,
+        most_significant_account_hold_id: None,
+        account_ownership_id: None,
+        access01_account_relationship_id: None,
+        access02_account_relationship_id: None,
+        access03_account_relationship_id: None,
+        access04_account_relationship_id: None,
+        access05_account_relationship_id: None,
+        access06_account_relationship_id: None,
+        access07_account_relationship_id: None,
+        access11_account_mandate_id: None,
+        access12_account_mandate_id: None,
+        access13_account_mandate_id: None,
+        access14_account_mandate_id: None,
+        access15_account_mandate_id: None,
+        access16_account_mandate_id: None,
+        access17_account_mandate_id: None,
+        interest01_ultimate_beneficiary_id: None,
+        interest02_ultimate_beneficiary_id: None,
+        interest03_ultimate_beneficiary_id: None,
+        interest04_ultimate_beneficiary_id: None,
+        interest05_ultimate_beneficiary_id: None,
+        interest06_ultimate_beneficiary_id: None,
+        interest07_ultimate_beneficiary_id: None,
+        created_at: Utc::now(),
+        last_updated_at: Utc::now(),
+        updated_by_person_id: Uuid::new_v4(),
+        version: 0,
+    }
+}
+
+/// AccountRepository over in-memory rows, with hooks to inject failures and
+/// latency into the calls services must survive
+#[derive(Default)]
+pub(crate) struct InMemoryAccountRepository {
+    /// Keyed by id so keyset pages come back in id order
+    pub accounts: Mutex<BTreeMap<Uuid, AccountModel>>,
+    pub ownerships: Mutex<Vec<AccountOwnershipModel>>,
+    pub relationships: Mutex<Vec<AccountRelationshipModel>>,
+    pub mandates: Mutex<Vec<AccountMandateModel>>,
+    pub settlements: Mutex<Vec<AccountFinalSettlementModel>>,
+    pub status_changes: Mutex<Vec<AccountStatusChangeRecordModel>>,
+    pub balance_changes: Mutex<Vec<AccountBalanceChangeRecordModel>>,
+    pub balance_snapshots: Mutex<Vec<AccountBalanceSnapshotModel>>,
+    pub loan_penalty_accruals: Mutex<Vec<LoanPenaltyAccrualModel>>,
+    pub overdraft_interest_accruals: Mutex<Vec<OverdraftInterestAccrual>>,
+    /// (account, date) pairs already accrued, as enforced by the unique key
+    pub accrual_markers: Mutex<HashSet<(Uuid, NaiveDate)>>,
+    /// Accruals applied by `apply_interest_accruals`, in commit order
+    pub applied_accruals: Mutex<Vec<AccountInterestAccrualModel>>,
+    /// First account of each accrual chunk, in commit order
+    pub completed_accrual_chunks: Mutex<Vec<Uuid>>,
+    /// Account numbers passed to `find_by_account_number`
+    pub queried_account_numbers: Mutex<Vec<String>>,
+    /// Creating an account of this product fails
+    pub failing_product: Mutex<Option<Uuid>>,
+    /// An accrual chunk containing one of these accounts fails once, before
+    /// or after committing
+    pub fail_accruals_before_commit: Mutex<HashSet<Uuid>>,
+    pub fail_accruals_after_commit: Mutex<HashSet<Uuid>>,
+    /// An accrual chunk containing the account is delayed by the duration
+    pub slow_accrual_account: Mutex<Option<(Uuid, Duration)>>,
+}
+
+impl InMemoryAccountRepository {
+    pub fn with_accounts(accounts: impl IntoIterator<Item = AccountModel>) -> Self {
+        let repository = Self::default();
+        repository.insert_accounts(accounts);
+        repository
+    }
+
+    pub fn insert_accounts(&self, accounts: impl IntoIterator<Item = AccountModel>) {
+        let mut stored = self.accounts.lock().unwrap();
+        for account in accounts {
+            stored.insert(account.id, account);
+        }
+    }
+
+    /// Registers `customer_id` as an owner of the account
+    pub fn add_owner(&self, account_id: Uuid, customer_id: Uuid) {
+        self.ownerships.lock().unwrap().push(AccountOwnershipModel {
+            id: Uuid::new_v4(),
+            account_id,
+            customer_id,
+            ownership_type: DbOwnershipType::Single,
+            ownership_percentage: None,
+            created_at: Utc::now(),
+        });
+    }
+
+    pub fn account(&self, account_id: Uuid) -> Option<AccountModel> {
+        self.accounts.lock().unwrap().get(&account_id).cloned()
+    }
+
+    fn filter_accounts(&self, predicate: impl Fn(&AccountModel) -> bool) -> Vec<AccountModel> {
+        self.accounts.lock().unwrap().values().filter(|a| predicate(a)).cloned().collect()
+    }
+
+    fn with_account<T>(&self, account_id: Uuid, f: impl FnOnce(&mut AccountModel) -> T) -> BankingResult<T> {
+        let mut accounts = self.accounts.lock().unwrap();
+        let account = accounts.get_mut(&account_id).ok_or(BankingError::AccountNotFound(account_id))?;
+        let value = f(account);
+        account.last_updated_at = Utc::now();
+        Ok(value)
+    }
+
+    fn parse<T: FromStr>(field: &str, value: &str) -> BankingResult<T> {
+        value.parse::<T>().map_err(|_| BankingError::ValidationError {
+            field: field.to_string(),
+            message: format!("Invalid {field}: {value}"),
+        })
+    }
+
+    fn take_injected_failure(failures: &Mutex<HashSet<Uuid>>, accruals: &[AccountInterestAccrualModel]) -> bool {
+        let mut failures = failures.lock().unwrap();
+        accruals.iter().any(|a| failures.remove(&a.account_id))
+    }
+
+    fn record_status_change(&self, account_id: Uuid, status: DbAccountStatus, reason_id: Uuid, changed_by: Uuid) -> BankingResult<()> {
+        let old_status = self.with_account(account_id, |account| {
+            let old_status = account.account_status;
+            account.account_status = status;
+            account.status_change_reason_id = Some(reason_id);
+            account.status_changed_by_person_id = Some(changed_by);
+            account.status_change_timestamp = Some(Utc::now());
+            old_status
+        })?;
+        self.status_changes.lock().unwrap().push(AccountStatusChangeRecordModel {
+            id: Uuid::new_v4(),
+            account_id,
+            old_status: Some(old_status),
+            new_status: status,
+            reason_id,
+            additional_context: None,
+            changed_by_person_id: changed_by,
+            changed_at: Utc::now(),
+            system_triggered: false,
+            created_at: Utc::now(),
+        });
+        Ok(())
+    }
+
+    fn record_balance_change(
+        &self,
+        account: &AccountModel,
+        old_balances: (Decimal, Decimal),
+        change_source: DbBalanceChangeSource,
+        transaction_id: Option<Uuid>,
+        changed_by: Uuid,
+    ) {
+        self.balance_changes.lock().unwrap().push(AccountBalanceChangeRecordModel {
+            id: Uuid::new_v4(),
+            account_id: account.id,
+            old_current_balance: old_balances.0,
+            new_current_balance: account.current_balance,
+            old_available_balance: old_balances.1,
+            new_available_balance: account.available_balance,
+            change_source,
+            transaction_id,
+            changed_at: Utc::now(),
+            changed_by_person_id: changed_by,
+        });
+    }
+}
+
+#[async_trait]
+impl AccountRepository for InMemoryAccountRepository {
+    async fn create(&self, account: AccountModel) -> BankingResult<AccountModel> {
+        if *self.failing_product.lock().unwrap() == Some(account.product_id) {
+            return Err(BankingError::Internal("Core account service unavailable".to_string()));
+        }
+        self.accounts.lock().unwrap().insert(account.id, account.clone());
+        Ok(account)
+    }
+
+    async fn update(&self, account: AccountModel) -> BankingResult<AccountModel> {
+        let mut accounts = self.accounts.lock().unwrap();
+        let stored = accounts.get_mut(&account.id).ok_or(BankingError::AccountNotFound(account.id))?;
+        if stored.version != account.version {
+            return Err(BankingError::StaleVersion {
+                entity_id: account.id,
+                expected_version: account.version,
+            });
+        }
+        *stored = AccountModel {
+            version: account.version + 1,
+            last_updated_at: Utc::now(),
+            ..account
+        };
+        Ok(stored.clone())
+    }
+
+    async fn find_by_id(&self, account_id: Uuid) -> BankingResult<Option<AccountModel>> {
+        Ok(self.account(account_id))
+    }
+
+    async fn find_by_account_number(&self, account_number: &str) -> BankingResult<Option<AccountModel>> {
+        self.queried_account_numbers.lock().unwrap().push(account_number.to_string());
+        Ok(self.filter_accounts(|a| a.account_number.as_str() == account_number).into_iter().next())
+    }
+
+    async fn find_by_customer_id(&self, customer_id: Uuid) -> BankingResult<Vec<AccountModel>> {
+        let owned: HashSet<Uuid> = self.ownerships
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|o| o.customer_id == customer_id)
+            .map(|o| o.account_id)
+            .collect();
+        Ok(self.filter_accounts(|a| owned.contains(&a.id)))
+    }
+
+    async fn find_by_product_id(&self, product_id: Uuid) -> BankingResult<Vec<AccountModel>> {
+        Ok(self.filter_accounts(|a| a.product_id == product_id))
+    }
+
+    async fn find_by_status(&self, status: &str) -> BankingResult<Vec<AccountModel>> {
+        let status: DbAccountStatus = Self::parse("account_status", status)?;
+        Ok(self.filter_accounts(|a| a.account_status == status))
+    }
+
+    async fn find_by_account_type(&self, account_type: DbAccountType) -> BankingResult<Vec<AccountModel>> {
+        Ok(self.filter_accounts(|a| a.account_type == account_type))
+    }
+
+    async fn find_dormancy_candidates(&self, reference_date: NaiveDate, threshold_days: i32, product_id: Option<Uuid>) -> BankingResult<Vec<AccountModel>> {
+        Ok(self.filter_accounts(|a| {
+            let threshold = a.dormancy_threshold_days.unwrap_or(threshold_days) as i64;
+            let last_activity = a.last_activity_date.unwrap_or(a.open_date);
+            a.account_status == DbAccountStatus::Active
+                && product_id.is_none_or(|product_id| a.product_id == product_id)
+                && (reference_date - last_activity).num_days() >= threshold
+        }))
+    }
+
+    async fn find_pending_closure(&self) -> BankingResult<Vec<AccountModel>> {
+        Ok(self.filter_accounts(|a| a.account_status == DbAccountStatus::PendingClosure))
+    }
+
+    async fn find_interest_bearing_accounts(&self) -> BankingResult<Vec<AccountModel>> {
+        self.find_interest_bearing_accounts_after(None, i64::MAX).await
+    }
+
+    async fn find_interest_bearing_accounts_after(&self, after_account_id: Option<Uuid>, limit: i64) -> BankingResult<Vec<AccountModel>> {
+        Ok(self
+            .filter_accounts(|a| {
+                a.account_status == DbAccountStatus::Active && after_account_id.is_none_or(|after| a.id > after)
+            })
+            .into_iter()
+            .take(limit.max(0) as usize)
+            .collect())
+    }
+
+    async fn update_status(&self, account_id: Uuid, status: &str, reason_id: Uuid, changed_by: Uuid) -> BankingResult<()> {
+        let status = Self::parse("account_status", status)?;
+        self.record_status_change(account_id, status, reason_id, changed_by)
+    }
+
+    async fn update_status_legacy(&self, account_id: Uuid, status: &str, _reason: &str, changed_by: Uuid) -> BankingResult<()> {
+        let status = Self::parse("account_status", status)?;
+        self.record_status_change(account_id, status, Uuid::new_v4(), changed_by)
+    }
+
+    async fn bulk_update_status(&self, account_ids: &[Uuid], status: &str, reason_id: Uuid, changed_by: Uuid) -> Vec<(Uuid, BankingResult<()>)> {
+        let mut results = Vec::with_capacity(account_ids.len());
+        for &account_id in account_ids {
+            results.push((account_id, self.update_status(account_id, status, reason_id, changed_by).await));
+        }
+        results
+    }
+
+    async fn update_balance(
+        &self,
+        account_id: Uuid,
+        current_balance: Decimal,
+        available_balance: Decimal,
+        change_source: DbBalanceChangeSource,
+        transaction_id: Option<Uuid>,
+        changed_by: Uuid,
+    ) -> BankingResult<()> {
+        let (account, old_balances) = self.with_account(account_id, |account| {
+            let old_balances = (account.current_balance, account.available_balance);
+            account.current_balance = current_balance;
+            account.available_balance = available_balance;
+            (account.clone(), old_balances)
+        })?;
+        self.record_balance_change(&account, old_balances, change_source, transaction_id, changed_by);
+        Ok(())
+    }
+
+    async fn get_balance_history(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> BankingResult<Vec<AccountBalanceChangeRecordModel>> {
+        Ok(self.balance_changes
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|c| c.account_id == account_id && from <= c.changed_at && c.changed_at <= to)
+            .cloned()
+            .collect())
+    }
+
+    async fn update_accrued_interest(&self, account_id: Uuid, accrued_interest: Decimal) -> BankingResult<()> {
+        self.with_account(account_id, |account| account.accrued_interest = accrued_interest)
+    }
+
+    async fn reset_accrued_interest(&self, account_id: Uuid) -> BankingResult<()> {
+        self.with_account(account_id, |account| account.accrued_interest = Decimal::ZERO)
+    }
+
+    async fn post_accrued_interest(
+        &self,
+        account_id: Uuid,
+        amount: Decimal,
+        change_source: DbBalanceChangeSource,
+        transaction_id: Option<Uuid>,
+        changed_by: Uuid,
+    ) -> BankingResult<()> {
+        let (account, old_balances) = self.with_account(account_id, |account| {
+            let old_balances = (account.current_balance, account.available_balance);
+            account.current_balance += amount;
+            account.available_balance += amount;
+            account.accrued_interest -= amount;
+            (account.clone(), old_balances)
+        })?;
+        self.record_balance_change(&account, old_balances, change_source, transaction_id, changed_by);
+        Ok(())
+    }
+
+    async fn apply_interest_accruals(&self, accruals: Vec<AccountInterestAccrualModel>) -> BankingResult<Vec<Uuid>> {
+        let delay = *self.slow_accrual_account.lock().unwrap();
+        if let Some((_, delay)) = delay.filter(|(slow, _)| accruals.iter().any(|a| a.account_id == *slow)) {
+            tokio::time::sleep(delay).await;
+        }
+        if Self::take_injected_failure(&self.fail_accruals_before_commit, &accruals) {
+            return Err(BankingError::Internal("Injected failure before commit".to_string()));
+        }
+
+        let mut applied = Vec::new();
+        {
+            let mut markers = self.accrual_markers.lock().unwrap();
+            let mut accounts = self.accounts.lock().unwrap();
+            let mut applied_accruals = self.applied_accruals.lock().unwrap();
+            for accrual in &accruals {
+                let Some(account) = accounts.get_mut(&accrual.account_id) else {
+                    continue;
+                };
+                if markers.insert((accrual.account_id, accrual.accrual_date)) {
+                    account.accrued_interest += accrual.daily_interest;
+                    applied_accruals.push(accrual.clone());
+                    applied.push(accrual.account_id);
+                }
+            }
+        }
+        if let Some(first) = accruals.first() {
+            self.completed_accrual_chunks.lock().unwrap().push(first.account_id);
+        }
+
+        if Self::take_injected_failure(&self.fail_accruals_after_commit, &accruals) {
+            return Err(BankingError::Internal("Injected failure after commit".to_string()));
+        }
+        Ok(applied)
+    }
+
+    async fn create_ownership(&self, ownership: AccountOwnershipModel) -> BankingResult<AccountOwnershipModel> {
+        self.ownerships.lock().unwrap().push(ownership.clone());
+        Ok(ownership)
+    }
+
+    async fn find_ownership_by_account(&self, account_id: Uuid) -> BankingResult<Vec<AccountOwnershipModel>> {
+        Ok(self.ownerships.lock().unwrap().iter().filter(|o| o.account_id == account_id).cloned().collect())
+    }
+
+    async fn find_accounts_by_owner(&self, customer_id: Uuid) -> BankingResult<Vec<AccountOwnershipModel>> {
+        Ok(self.ownerships.lock().unwrap().iter().filter(|o| o.customer_id == customer_id).cloned().collect())
+    }
+
+    async fn delete_ownership(&self, ownership_id: Uuid) -> BankingResult<()> {
+        self.ownerships.lock().unwrap().retain(|o| o.id != ownership_id);
+        Ok(())
+    }
+
+    async fn create_relationship(&self, relationship: AccountRelationshipModel) -> BankingResult<AccountRelationshipModel> {
+        self.relationships.lock().unwrap().push(relationship.clone());
+        Ok(relationship)
+    }
+
+    async fn find_relationships_by_account(&self, account_id: Uuid) -> BankingResult<Vec<AccountRelationshipModel>> {
+        Ok(self.relationships.lock().unwrap().iter().filter(|r| r.account_id == account_id).cloned().collect())
+    }
+
+    async fn find_relationships_by_entity(&self, entity_id: Uuid, entity_type: &str) -> BankingResult<Vec<AccountRelationshipModel>> {
+        let entity_type: DbEntityType = Self::parse("entity_type", entity_type)?;
+        Ok(self.relationships
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.person_id == entity_id && r.entity_type == entity_type)
+            .cloned()
+            .collect())
+    }
+
+    async fn update_relationship(&self, relationship: AccountRelationshipModel) -> BankingResult<AccountRelationshipModel> {
+        let mut relationships = self.relationships.lock().unwrap();
+        let stored = relationships
+            .iter_mut()
+            .find(|r| r.id == relationship.id)
+            .ok_or_else(|| BankingError::NotFound(format!("Account relationship {}", relationship.id)))?;
+        *stored = relationship.clone();
+        Ok(relationship)
+    }
+
+    async fn delete_relationship(&self, relationship_id: Uuid) -> BankingResult<()> {
+        self.relationships.lock().unwrap().retain(|r| r.id != relationship_id);
+        Ok(())
+    }
+
+    async fn create_mandate(&self, mandate: AccountMandateModel) -> BankingResult<AccountMandateModel> {
+        self.mandates.lock().unwrap().push(mandate.clone());
+        Ok(mandate)
+    }
+
+    async fn find_mandates_by_account(&self, account_id: Uuid) -> BankingResult<Vec<AccountMandateModel>> {
+        Ok(self.mandates.lock().unwrap().iter().filter(|m| m.account_id == account_id).cloned().collect())
+    }
+
+    async fn find_mandates_by_grantee(&self, grantee_customer_id: Uuid) -> BankingResult<Vec<AccountMandateModel>> {
+        Ok(self.mandates
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|m| m.grantee_customer_id == grantee_customer_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update_mandate_status(&self, mandate_id: Uuid, status: &str) -> BankingResult<()> {
+        let status: DbMandateStatus = Self::parse("mandate_status", status)?;
+        let mut mandates = self.mandates.lock().unwrap();
+        let mandate = mandates
+            .iter_mut()
+            .find(|m| m.id == mandate_id)
+            .ok_or_else(|| BankingError::NotFound(format!("Account mandate {mandate_id}")))?;
+        mandate.status = status;
+        Ok(())
+    }
+
+    async fn find_active_mandates(&self, account_id: Uuid) -> BankingResult<Vec<AccountMandateModel>> {
+        Ok(self.mandates
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|m| m.account_id == account_id && m.status == DbMandateStatus::Active)
+            .cloned()
+            .collect())
+    }
+
+    async fn create_final_settlement(&self, settlement: AccountFinalSettlementModel) -> BankingResult<AccountFinalSettlementModel> {
+        self.settlements.lock().unwrap().push(settlement.clone());
+        Ok(settlement)
+    }
+
+    async fn find_settlement_by_account(&self, account_id: Uuid) -> BankingResult<Option<AccountFinalSettlementModel>> {
+        Ok(self.settlements.lock().unwrap().iter().rev().find(|s| s.account_id == account_id).cloned())
+    }
+
+    async fn update_settlement_status(&self, settlement_id: Uuid, status: &str) -> BankingResult<()> {
+        let status: DbSettlementStatus = Self::parse("settlement_status", status)?;
+        let mut settlements = self.settlements.lock().unwrap();
+        let settlement = settlements
+            .iter_mut()
+            .find(|s| s.id == settlement_id)
+            .ok_or_else(|| BankingError::NotFound(format!("Final settlement {settlement_id}")))?;
+        if !settlement.status.can_advance_to(status) {
+            return Err(BankingError::ValidationError {
+                field: "settlement_status".to_string(),
+                message: format!("Settlement cannot move from {} to {status}", settlement.status),
+            });
+        }
+        settlement.status = status;
+        Ok(())
+    }
+
+    async fn get_status_history(&self, account_id: Uuid) -> BankingResult<Vec<AccountStatusChangeRecordModel>> {
+        Ok(self.status_changes.lock().unwrap().iter().filter(|c| c.account_id == account_id).cloned().collect())
+    }
+
+    async fn add_status_change(&self, status_change: AccountStatusChangeRecordModel) -> BankingResult<AccountStatusChangeRecordModel> {
+        self.status_changes.lock().unwrap().push(status_change.clone());
+        Ok(status_change)
+    }
+
+    async fn save_balance_snapshot(&self, snapshot: AccountBalanceSnapshotModel) -> BankingResult<AccountBalanceSnapshotModel> {
+        let mut snapshots = self.balance_snapshots.lock().unwrap();
+        snapshots.retain(|s| (s.account_id, s.snapshot_date) != (snapshot.account_id, snapshot.snapshot_date));
+        snapshots.push(snapshot.clone());
+        Ok(snapshot)
+    }
+
+    async fn find_latest_balance_snapshot_before(&self, account_id: Uuid, before_date: NaiveDate) -> BankingResult<Option<AccountBalanceSnapshotModel>> {
+        Ok(self.balance_snapshots
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|s| s.account_id == account_id && s.snapshot_date < before_date)
+            .max_by_key(|s| s.snapshot_date)
+            .cloned())
+    }
+
+    async fn find_balance_snapshots_by_date(&self, snapshot_date: NaiveDate) -> BankingResult<Vec<AccountBalanceSnapshotModel>> {
+        Ok(self.balance_snapshots.lock().unwrap().iter().filter(|s| s.snapshot_date == snapshot_date).cloned().collect())
+    }
+
+    async fn save_loan_penalty_accrual(&self, accrual: LoanPenaltyAccrualModel) -> BankingResult<LoanPenaltyAccrualModel> {
+        let mut accruals = self.loan_penalty_accruals.lock().unwrap();
+        accruals.retain(|a| (a.loan_account_id, a.assessment_date) != (accrual.loan_account_id, accrual.assessment_date));
+        accruals.push(accrual.clone());
+        Ok(accrual)
+    }
+
+    async fn find_latest_loan_penalty_accrual_before(&self, loan_account_id: Uuid, before_date: NaiveDate) -> BankingResult<Option<LoanPenaltyAccrualModel>> {
+        Ok(self.loan_penalty_accruals
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|a| a.loan_account_id == loan_account_id && a.assessment_date < before_date)
+            .max_by_key(|a| a.assessment_date)
+            .cloned())
+    }
+
+    async fn save_overdraft_interest_accrual(&self, accrual: OverdraftInterestAccrual) -> BankingResult<OverdraftInterestAccrual> {
+        let mut accruals = self.overdraft_interest_accruals.lock().unwrap();
+        accruals.retain(|a| (a.account_id, a.accrual_date) != (accrual.account_id, accrual.accrual_date));
+        accruals.push(accrual.clone());
+        Ok(accrual)
+    }
+
+    async fn find_latest_overdraft_interest_accrual_before(&self, account_id: Uuid, before_date: NaiveDate) -> BankingResult<Option<OverdraftInterestAccrual>> {
+        Ok(self.overdraft_interest_accruals
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|a| a.account_id == account_id && a.accrual_date < before_date)
+            .max_by_key(|a| a.accrual_date)
+            .cloned())
+    }
+
+    async fn exists(&self, account_id: Uuid) -> BankingResult<bool> {
+        Ok(self.accounts.lock().unwrap().contains_key(&account_id))
+    }
+
+    async fn find_by_ids(&self, account_ids: &[Uuid]) -> BankingResult<Vec<AccountModel>> {
+        let accounts = self.accounts.lock().unwrap();
+        Ok(account_ids.iter().filter_map(|id| accounts.get(id).cloned()).collect())
+    }
+
+    async fn exist_by_ids(&self, account_ids: &[Uuid]) -> BankingResult<Vec<(Uuid, bool)>> {
+        let accounts = self.accounts.lock().unwrap();
+        Ok(account_ids.iter().map(|id| (*id, accounts.contains_key(id))).collect())
+    }
+
+    async fn count_by_customer(&self, customer_id: Uuid) -> BankingResult<i64> {
+        Ok(self.find_by_customer_id(customer_id).await?.len() as i64)
+    }
+
+    async fn count_by_product(&self, product_id: Uuid) -> BankingResult<i64> {
+        Ok(self.find_by_product_id(product_id).await?.len() as i64)
+    }
+
+    async fn list(&self, offset: i64, limit: i64) -> BankingResult<Vec<AccountModel>> {
+        Ok(self.filter_accounts(|_| true)
+            .into_iter()
+            .skip(offset.max(0) as usize)
+            .take(limit.max(0) as usize)
+            .collect())
+    }
+
+    async fn list_after(&self, after_account_id: Option<Uuid>, limit: i64) -> BankingResult<Vec<AccountModel>> {
+        Ok(self
+            .filter_accounts(|a| after_account_id.is_none_or(|after| a.id > after))
+            .into_iter()
+            .take(limit.max(0) as usize)
+            .collect())
+    }
+
+    async fn count(&self) -> BankingResult<i64> {
+        Ok(self.accounts.lock().unwrap().len() as i64)
+    }
+
+    async fn update_last_activity_date(&self, account_id: Uuid, activity_date: NaiveDate) -> BankingResult<()> {
+        self.with_account(account_id, |account| account.last_activity_date = Some(activity_date))
+    }
+}
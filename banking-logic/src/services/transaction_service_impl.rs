@@ -8,12 +8,28 @@ use heapless::String as HeaplessString;
 
 use banking_api::{
     BankingResult, BankingError, Transaction, TransactionApprovalWorkflow,
-    service::TransactionService,
-    domain::{TransactionType, TransactionStatus, AccountStatus},
+    service::{
+        DegradedBackfillReport, InterestService, KillSwitchService, PostingStep, SavingsGoalService,
+        TransactionService, account_hold_service::AccountHoldService,
+    },
+    domain::{
+        TransactionType, TransactionStatus, TransactionSearchCriteria, AccountStatus, ReasonId, ReasonedOperation,
+        BackDatedPosting, PostingActor, CircuitBreakerMetrics, DegradedFlags, ChannelUsageWindow,
+        ApprovalRefusal, ApprovalRequirement, HoldType, customer_gl_code,
+    },
+};
+use banking_db::models::TransactionModel;
+use banking_db::models::workflow::{ApprovalWorkflowModel, WorkflowStatusModel, WorkflowTransactionApprovalModel};
+use banking_db::repository::{
+    TransactionRepository, AccountRepository, ReasonAndPurposeRepository, BackDatedPostingRepository,
+    ChannelRepository, ExchangeRateRepository,
 };
-use banking_db::repository::{TransactionRepository, AccountRepository};
 use crate::{
-    mappers::{TransactionMapper, AccountMapper},
+    config::BankingConfig,
+    constants::SYSTEM_PERSON_ID,
+    mappers::{TransactionMapper, AccountMapper, BackDatedPostingMapper, ChannelMapper, ExchangeRateMapper},
+    services::posting_degradation::PostingStepRunner,
+    validation::ReasonValidation,
 };
 use banking_api::domain::transaction::TransactionValidationResult as ValidationResult;
 use banking_db::repository::ProductRepository;
@@ -24,6 +40,16 @@ pub struct TransactionServiceImpl {
     transaction_repository: Arc<dyn TransactionRepository>,
     account_repository: Arc<dyn AccountRepository>,
     product_repository: Arc<dyn ProductRepository>,
+    reason_repository: Arc<dyn ReasonAndPurposeRepository>,
+    savings_goal_service: Arc<dyn SavingsGoalService>,
+    interest_service: Arc<dyn InterestService>,
+    back_dated_posting_repository: Arc<dyn BackDatedPostingRepository>,
+    kill_switch_service: Arc<dyn KillSwitchService>,
+    channel_repository: Arc<dyn ChannelRepository>,
+    exchange_rate_repository: Arc<dyn ExchangeRateRepository>,
+    account_hold_service: Arc<dyn AccountHoldService>,
+    posting_steps: PostingStepRunner,
+    config: Arc<BankingConfig>,
     validation_cache: ValidationCache,
 }
 
@@ -32,11 +58,31 @@ impl TransactionServiceImpl {
         transaction_repository: Arc<dyn TransactionRepository>,
         account_repository: Arc<dyn AccountRepository>,
         product_repository: Arc<dyn ProductRepository>,
+        reason_repository: Arc<dyn ReasonAndPurposeRepository>,
+        savings_goal_service: Arc<dyn SavingsGoalService>,
+        interest_service: Arc<dyn InterestService>,
+        back_dated_posting_repository: Arc<dyn BackDatedPostingRepository>,
+        kill_switch_service: Arc<dyn KillSwitchService>,
+        channel_repository: Arc<dyn ChannelRepository>,
+        exchange_rate_repository: Arc<dyn ExchangeRateRepository>,
+        account_hold_service: Arc<dyn AccountHoldService>,
+        posting_steps: Vec<Arc<dyn PostingStep>>,
+        config: Arc<BankingConfig>,
     ) -> Self {
         Self {
             transaction_repository,
             account_repository,
             product_repository,
+            reason_repository,
+            savings_goal_service,
+            interest_service,
+            back_dated_posting_repository,
+            kill_switch_service,
+            channel_repository,
+            exchange_rate_repository,
+            account_hold_service,
+            posting_steps: PostingStepRunner::new(posting_steps, &config.degradation),
+            config,
             validation_cache: ValidationCache::new(),
         }
     }
@@ -45,64 +91,76 @@ impl TransactionServiceImpl {
 #[async_trait]
 impl TransactionService for TransactionServiceImpl {
     /// Process transaction with comprehensive validation and multi-stage pipeline
-    async fn process_transaction(&self, mut transaction: Transaction) -> BankingResult<Transaction> {
-        // Set system timestamp
-        transaction.created_at = Utc::now();
-        
-        // Generate reference number if not provided
-        if transaction.reference_number.is_empty() {
-            transaction.reference_number = self.generate_reference_number().await?;
-        }
-
-        // Stage 1: Pre-validation (fail-fast checks)
-        self.pre_validate_transaction(&transaction).await?;
-
-        // Stage 2: Comprehensive validation
-        let validation_result = self.validate_transaction_limits(&transaction).await?;
-        
-        if !validation_result.is_valid() {
-            transaction.status = TransactionStatus::Failed;
-            let failed_transaction = TransactionMapper::to_model(transaction.clone());
-            self.transaction_repository.create(failed_transaction).await?;
-            
-            let reasons = validation_result
-                .get_failure_reasons()
-                .iter()
-                .map(|(field, message, code)| format!("{field}: {message} ({code})"))
-                .collect::<Vec<String>>()
-                .join(", ");
-            return Err(banking_api::BankingError::ValidationFailed(reasons));
+    async fn process_transaction(&self, transaction: Transaction) -> BankingResult<Transaction> {
+        if transaction.value_date < transaction.transaction_date.date_naive() {
+            return Err(BankingError::UnauthorizedOperation(format!(
+                "Value date {} is before the transaction date; post it as a back-dated transaction",
+                transaction.value_date
+            )));
         }
+        self.run_pipeline(transaction).await
+    }
 
-        // Stage 3: Check if approval is required
-        if self.requires_approval(&transaction).await? {
-            transaction.requires_approval = true;
-            transaction.approval_status = Some(banking_api::domain::TransactionApprovalStatus::Pending);
-            transaction.status = TransactionStatus::AwaitingApproval;
+    async fn post_back_dated_transaction(
+        &self,
+        transaction: Transaction,
+        actor: &PostingActor,
+        business_date: NaiveDate,
+    ) -> BankingResult<BackDatedPosting> {
+        let value_date = transaction.value_date;
+        let days_back = actor.authorize_back_dating(value_date, business_date, self.config.posting.max_back_date_days)?;
+        let posted = self.run_pipeline(transaction).await?;
+
+        // A transaction awaiting approval has not moved the balance yet
+        let accrual_delta = if posted.status == TransactionStatus::Posted {
+            let balance_change = match posted.transaction_type {
+                TransactionType::Credit => posted.amount,
+                TransactionType::Debit => -posted.amount,
+            };
+            self.interest_service
+                .recalculate_back_dated_accrual(posted.account_id, balance_change, value_date, business_date)
+                .await?
         } else {
-            transaction.status = TransactionStatus::Posted;
-        }
+            Decimal::ZERO
+        };
 
-        // Stage 4: Execute financial posting
-        if transaction.status == TransactionStatus::Posted {
-            self.execute_financial_posting(&mut transaction).await?;
-        }
+        let entry = BackDatedPosting {
+            id: Uuid::new_v4(),
+            transaction_id: posted.id,
+            account_id: posted.account_id,
+            value_date,
+            business_date,
+            days_back,
+            accrual_delta,
+            posted_by_person_id: actor.person_id,
+            posted_at: Utc::now(),
+        };
+        let created = self.back_dated_posting_repository
+            .create_back_dated_posting(BackDatedPostingMapper::to_model(entry))
+            .await?;
 
-        // Stage 5: Persist transaction
-        let transaction_model = TransactionMapper::to_model(transaction.clone());
-        let created_model = self.transaction_repository.create(transaction_model).await?;
+        tracing::warn!(
+            "Transaction {} back-dated {} days to {} by {}; accrual delta {}",
+            posted.id, days_back, value_date, actor.person_id, accrual_delta
+        );
+        Ok(BackDatedPostingMapper::from_model(created))
+    }
 
-        // Stage 6: Update account activity timestamp
-        if transaction.status == TransactionStatus::Posted {
-            self.update_account_activity(transaction.account_id).await?;
-        }
+    async fn find_back_dated_postings(&self, from: NaiveDate, to: NaiveDate) -> BankingResult<Vec<BackDatedPosting>> {
+        Ok(self.back_dated_posting_repository
+            .find_back_dated_postings(from, to)
+            .await?
+            .into_iter()
+            .map(BackDatedPostingMapper::from_model)
+            .collect())
+    }
 
-        tracing::info!(
-            "Transaction {} processed with status {:?} for account {}",
-            transaction.id, transaction.status, transaction.account_id
-        );
+    async fn backfill_degraded_transactions(&self, limit: i64) -> BankingResult<DegradedBackfillReport> {
+        self.posting_steps.backfill(self.transaction_repository.as_ref(), limit).await
+    }
 
-        TransactionMapper::from_model(created_model)
+    async fn get_circuit_breaker_metrics(&self) -> BankingResult<Vec<CircuitBreakerMetrics>> {
+        Ok(self.posting_steps.metrics())
     }
 
     /// Validate transaction limits across multiple tiers
@@ -131,12 +189,16 @@ impl TransactionService for TransactionServiceImpl {
     }
 
     /// Reverse a posted transaction with reason ID validation
-    async fn reverse_transaction(&self, transaction_id: Uuid, reason_id: Uuid, _additional_details: Option<&str>) -> BankingResult<()> {
-        // TODO: Validate reason_id against ReasonAndPurpose table
-        // TODO: Store additional_details if provided
-        
-        // For now, convert reason_id to string for legacy compatibility
-        let reason = format!("Reason ID: {reason_id}");
+    async fn reverse_transaction(&self, transaction_id: Uuid, reason_id: ReasonId, requested_by: Uuid) -> BankingResult<Transaction> {
+        let reason = ReasonValidation::require(
+            self.reason_repository.as_ref(),
+            reason_id,
+            ReasonedOperation::TransactionReversal,
+        )
+        .await?;
+
+        // Descriptions and status notes still carry the reason as text
+        let reason = reason.code.to_string();
         // Find original transaction
         let original_transaction = self.transaction_repository
             .find_by_id(transaction_id)
@@ -145,13 +207,10 @@ impl TransactionService for TransactionServiceImpl {
 
         let original = TransactionMapper::from_model(original_transaction)?;
 
-        // Validate transaction can be reversed
-        if original.status != TransactionStatus::Posted {
-            return Err(banking_api::BankingError::ValidationError {
-                field: "transaction_status".to_string(),
-                message: format!("Transaction {} cannot be reversed from status {:?}", transaction_id, original.status),
-            });
-        }
+        // Validate transaction can be reversed; the repository checks status again under lock
+        original
+            .check_reversible(Utc::now().date_naive(), self.config.posting.reversal_window_days)
+            .map_err(|refusal| BankingError::TransactionNotReversible { transaction_id, refusal })?;
 
         // Create reversal transaction
         let mut reversal_transaction = Transaction {
@@ -192,27 +251,30 @@ impl TransactionService for TransactionServiceImpl {
             requires_approval: false,
             approval_status: None,
             risk_score: Some(Decimal::ZERO), // System transaction
+            degraded_flags: DegradedFlags::NONE,
+            idempotency_key: None,
+            reversal_of_transaction_id: Some(transaction_id),
+            fx_conversion: None,
             created_at: Utc::now(),
         };
 
-        // Process reversal transaction
-        self.execute_financial_posting(&mut reversal_transaction).await?;
-        let reversal_model = TransactionMapper::to_model(reversal_transaction);
-        self.transaction_repository.create(reversal_model).await?;
-
-        // Mark original transaction as reversed
-        self.transaction_repository.update_status(
-            transaction_id,
-            "Reversed",
-            &format!("Reversed: {reason}"),
-        ).await?;
+        // Post the contra transaction and mark the original reversed in one database transaction
+        let reversal_model = self.transaction_repository
+            .reverse_transaction(transaction_id, TransactionMapper::to_model(reversal_transaction.clone()))
+            .await?;
+        if reversal_transaction.transaction_type == TransactionType::Debit {
+            self.savings_goal_service
+                .rebalance_after_withdrawal(reversal_transaction.account_id, reversal_transaction.value_date)
+                .await?;
+        }
+        reversal_transaction = TransactionMapper::from_model(reversal_model)?;
 
         tracing::info!(
-            "Transaction {} reversed. Reason ID: {}",
-            transaction_id, reason_id
+            "Transaction {} reversed by {} as {}. Reason ID: {}",
+            transaction_id, requested_by, reversal_transaction.id, reason_id
         );
 
-        Ok(())
+        Ok(reversal_transaction)
     }
     
     /// Legacy method - deprecated, use reverse_transaction with reason_id instead
@@ -261,65 +323,184 @@ impl TransactionService for TransactionServiceImpl {
         Ok(transactions)
     }
 
-    /// Initiate approval workflow for multi-party authorization
+    /// Find transactions matching the criteria, most recent first
+    async fn search_transactions(&self, criteria: TransactionSearchCriteria) -> BankingResult<Vec<Transaction>> {
+        if criteria.limit <= 0 || criteria.offset < 0 {
+            return Err(BankingError::ValidationError {
+                field: "limit".to_string(),
+                message: "Search requires a positive limit and a non-negative offset".to_string(),
+            });
+        }
+        if let (Some(from), Some(to)) = (criteria.from_date, criteria.to_date) {
+            if from > to {
+                return Err(BankingError::ValidationError {
+                    field: "from_date".to_string(),
+                    message: "From date must not be after to date".to_string(),
+                });
+            }
+        }
+        if let Some(tags) = &criteria.account_tags {
+            tags.validate().map_err(|message| BankingError::ValidationError {
+                field: "account_tags".to_string(),
+                message,
+            })?;
+        }
+
+        let models = self.transaction_repository
+            .search(TransactionMapper::search_criteria_to_model(criteria))
+            .await?;
+
+        let mut transactions = Vec::new();
+        for model in models {
+            transactions.push(TransactionMapper::from_model(model)?);
+        }
+
+        Ok(transactions)
+    }
+
+    /// Initiate approval workflow for multi-party authorization. The funds of
+    /// a customer debit are held until the workflow completes or times out.
     async fn initiate_approval_workflow(&self, transaction: Transaction) -> BankingResult<TransactionApprovalWorkflow> {
-        // Get account information to determine required approvers
-        let account = self.account_repository
-            .find_by_id(transaction.account_id)
-            .await?
-            .ok_or(banking_api::BankingError::AccountNotFound(transaction.account_id))?;
+        let initiated_at = Utc::now();
+        let timeout_at = initiated_at + chrono::Duration::hours(self.config.limits.approval_timeout_hours);
+        let initiated_by = transaction.agent_person_id.unwrap_or(SYSTEM_PERSON_ID);
+        let requirement = ApprovalRequirement::from_mandates(
+            initiated_by,
+            &self.active_mandates(transaction.account_id).await?,
+            timeout_at,
+        );
 
-        let account_domain = AccountMapper::from_model(account)?;
+        if let Some(hold) = requirement.hold_request(&transaction, self.approval_hold_reason_id().await?) {
+            self.account_hold_service.place_hold(hold).await?;
+        }
+
+        let workflow = self.transaction_repository
+            .create_workflow(ApprovalWorkflowModel {
+                id: Uuid::new_v4(),
+                transaction_id: Some(transaction.id),
+                account_id: Some(transaction.account_id),
+                approval_type: HeaplessString::try_from("TransactionApproval").unwrap(),
+                minimum_approvals: i32::from(requirement.required_signers_count),
+                current_approvals: 0,
+                status: WorkflowStatusModel::PendingAction,
+                initiated_by,
+                initiated_at,
+                timeout_at,
+                completed_at: None,
+                rejection_reason_id: None,
+                created_at: initiated_at,
+                last_updated_at: initiated_at,
+            })
+            .await?;
+        // The initiator's entry links the workflow to the transaction
+        self.record_approval_action(workflow.id, transaction.id, initiated_by, "Pending").await?;
 
-        // Determine required approvers based on signing condition
-        let _required_approvers = self.get_required_approvers(&account_domain, &transaction).await?;
+        tracing::info!(
+            "Approval workflow {} initiated for transaction {}; {} approval(s) required by {}",
+            workflow.id, transaction.id, requirement.required_signers_count, timeout_at
+        );
 
-        // Create workflow
-        let workflow = TransactionApprovalWorkflow {
-            id: Uuid::new_v4(),
+        Ok(TransactionApprovalWorkflow {
+            id: workflow.id,
             transaction_id: transaction.id,
             status: banking_api::domain::TransactionWorkflowStatus::Pending,
-            timeout_at: Utc::now() + chrono::Duration::hours(24), // 24-hour timeout
+            timeout_at,
             completed_at: None,
-            created_at: Utc::now(),
-        };
+            created_at: initiated_at,
+        })
+    }
 
-        // Persist workflow (this would typically involve a workflow repository)
-        tracing::info!(
-            "Approval workflow {} initiated for transaction {}",
-            workflow.id, transaction.id
-        );
+    /// Approve a transaction in the approval workflow; the last required
+    /// approval posts it
+    async fn approve_transaction(&self, transaction_id: Uuid, approver_person_id: Uuid) -> BankingResult<Transaction> {
+        let (transaction, workflow, mut requirement) = self.load_pending_approval(transaction_id).await?;
 
-        Ok(workflow)
-    }
+        let now = Utc::now();
+        let satisfied = match requirement.approve(approver_person_id, now) {
+            Ok(satisfied) => satisfied,
+            Err(refusal) => {
+                if refusal == ApprovalRefusal::Expired {
+                    self.expire_approval(&transaction, workflow.id).await?;
+                }
+                return Err(BankingError::ApprovalRefused { transaction_id, person_id: approver_person_id, refusal });
+            }
+        };
+        self.record_approval_action(workflow.id, transaction_id, approver_person_id, "Approved").await?;
+
+        if !satisfied {
+            self.transaction_repository
+                .update_approval_status(transaction_id, "PartiallyApproved")
+                .await?;
+            tracing::info!(
+                "Approval {} of {} recorded for transaction {} by {}",
+                requirement.approved_by.len(), requirement.required_signers_count, transaction_id, approver_person_id
+            );
+            return Ok(transaction);
+        }
 
-    /// Approve a transaction in the approval workflow
-    async fn approve_transaction(&self, transaction_id: Uuid, approver_person_id: Uuid) -> BankingResult<()> {
-        // Find transaction
-        let transaction = self.transaction_repository
-            .find_by_id(transaction_id)
+        // Free the held funds before the posting's funds check
+        self.release_approval_hold(&transaction, approver_person_id).await?;
+        let mut posted_model = self.transaction_repository
+            .post_approved_transaction(transaction_id)
             .await?
-            .ok_or(banking_api::BankingError::TransactionNotFound(transaction_id.to_string()))?;
-
-        if transaction.status != banking_db::TransactionStatus::AwaitingApproval {
-            return Err(banking_api::BankingError::ValidationError {
+            .ok_or_else(|| BankingError::ValidationError {
                 field: "status".to_string(),
                 message: format!("Transaction {transaction_id} is not awaiting approval"),
-            });
+            })?;
+        self.transaction_repository
+            .update_workflow_status(workflow.id, &WorkflowStatusModel::Completed.to_string())
+            .await?;
+
+        let mut posted = TransactionMapper::from_model(posted_model.clone())?;
+        if posted.transaction_type == TransactionType::Debit {
+            self.savings_goal_service
+                .rebalance_after_withdrawal(posted.account_id, posted.value_date)
+                .await?;
         }
+        self.run_posting_steps(&mut posted, &mut posted_model).await?;
 
-        // In production, this would:
-        // 1. Validate approver authorization
-        // 2. Check approval workflow requirements
-        // 3. Update approval status
-        // 4. Process transaction if all approvals received
+        tracing::info!("Transaction {} approved by {} and posted", transaction_id, approver_person_id);
+        Ok(posted)
+    }
 
-        tracing::info!(
-            "Approval recorded for transaction {} by approver {}",
-            transaction_id, approver_person_id
-        );
+    /// Reject a transaction in the approval workflow and release its held funds
+    async fn reject_transaction(
+        &self,
+        transaction_id: Uuid,
+        approver_person_id: Uuid,
+        reason_id: ReasonId,
+    ) -> BankingResult<Transaction> {
+        let reason = ReasonValidation::require(
+            self.reason_repository.as_ref(),
+            reason_id,
+            ReasonedOperation::WorkflowRejection,
+        )
+        .await?;
+        let (transaction, workflow, requirement) = self.load_pending_approval(transaction_id).await?;
+
+        if let Err(refusal) = requirement.check_decider(approver_person_id, Utc::now()) {
+            if refusal == ApprovalRefusal::Expired {
+                self.expire_approval(&transaction, workflow.id).await?;
+            }
+            return Err(BankingError::ApprovalRefused { transaction_id, person_id: approver_person_id, refusal });
+        }
+        self.record_approval_action(workflow.id, transaction_id, approver_person_id, "Rejected").await?;
 
-        Ok(())
+        self.release_approval_hold(&transaction, approver_person_id).await?;
+        self.transaction_repository
+            .update_status(transaction_id, "ApprovalRejected", &format!("Rejected: {}", reason.code))
+            .await?;
+        self.transaction_repository
+            .update_approval_status(transaction_id, "Rejected")
+            .await?;
+        self.transaction_repository
+            .update_workflow_status(workflow.id, &WorkflowStatusModel::Cancelled.to_string())
+            .await?;
+
+        tracing::info!("Transaction {} rejected by {}", transaction_id, approver_person_id);
+        self.find_transaction_by_id(transaction_id)
+            .await?
+            .ok_or_else(|| BankingError::TransactionNotFound(transaction_id.to_string()))
     }
 
     /// Validate account transactional status
@@ -338,8 +519,13 @@ impl TransactionService for TransactionServiceImpl {
     }
 
     /// Reverse pending transactions with reason ID validation
-    async fn reverse_pending_transactions(&self, _account_id: Uuid, _reason_id: Uuid, _additional_details: Option<&str>) -> BankingResult<Vec<banking_api::domain::Transaction>> {
-        // TODO: Validate reason_id against ReasonAndPurpose table
+    async fn reverse_pending_transactions(&self, _account_id: Uuid, reason_id: ReasonId, _additional_details: Option<&str>) -> BankingResult<Vec<banking_api::domain::Transaction>> {
+        ReasonValidation::require(
+            self.reason_repository.as_ref(),
+            reason_id,
+            ReasonedOperation::TransactionReversal,
+        )
+        .await?;
         // TODO: Store additional_details if provided
         todo!("Implement reverse_pending_transactions with reason_id")
     }
@@ -396,8 +582,95 @@ impl TransactionService for TransactionServiceImpl {
 
 
 impl TransactionServiceImpl {
+    /// Multi-stage pipeline shared by current and back-dated postings
+    async fn run_pipeline(&self, mut transaction: Transaction) -> BankingResult<Transaction> {
+        // A retried submission gets the original back before anything is validated or posted again
+        if let Some(key) = &transaction.idempotency_key {
+            if let Some(original) = self.transaction_repository
+                .find_by_idempotency_key(transaction.channel_id.as_str(), key.as_str())
+                .await?
+            {
+                return replayed(&transaction, original);
+            }
+        }
+
+        // Halted postings are refused before any other processing
+        self.kill_switch_service.check_posting(&transaction).await?;
+
+        // Set system timestamp
+        transaction.created_at = Utc::now();
+        
+        // Generate reference number if not provided
+        if transaction.reference_number.is_empty() {
+            transaction.reference_number = self.generate_reference_number().await?;
+        }
+
+        // Stage 1: Pre-validation (fail-fast checks), booking foreign-currency amounts in the account currency
+        self.pre_validate_transaction(&mut transaction).await?;
+
+        // Stage 2: Comprehensive validation
+        let validation_result = self.validate_transaction_limits(&transaction).await?;
+        
+        if !validation_result.is_valid() {
+            transaction.status = TransactionStatus::Failed;
+            // A refused attempt does not hold the key, so the client may retry it
+            let mut failed_transaction = TransactionMapper::to_model(transaction.clone());
+            failed_transaction.idempotency_key = None;
+            self.transaction_repository.create(failed_transaction).await?;
+            
+            let reasons = validation_result
+                .get_failure_reasons()
+                .iter()
+                .map(|(field, message, code)| format!("{field}: {message} ({code})"))
+                .collect::<Vec<String>>()
+                .join(", ");
+            return Err(banking_api::BankingError::ValidationFailed(reasons));
+        }
+
+        // Stage 3: Check if approval is required
+        if self.requires_approval(&transaction).await? {
+            transaction.requires_approval = true;
+            transaction.approval_status = Some(banking_api::domain::TransactionApprovalStatus::Pending);
+            transaction.status = TransactionStatus::AwaitingApproval;
+        } else {
+            transaction.status = TransactionStatus::Posted;
+        }
+
+        // Stage 4: Execute financial posting, which also persists the transaction
+        let created_model = if transaction.status == TransactionStatus::Posted {
+            let mut posted_model = self.execute_financial_posting(&mut transaction).await?;
+            if posted_model.id != transaction.id {
+                return replayed(&transaction, posted_model);
+            }
+            self.run_posting_steps(&mut transaction, &mut posted_model).await?;
+            posted_model
+        } else {
+            // Stage 5: Persist transaction awaiting approval and route it into an approval workflow
+            self.assign_gl_code(&mut transaction).await?;
+            let transaction_model = TransactionMapper::to_model(transaction.clone());
+            let created_model = self.transaction_repository.create(transaction_model).await?;
+            if created_model.id != transaction.id {
+                return replayed(&transaction, created_model);
+            }
+            if let Err(error) = self.initiate_approval_workflow(transaction.clone()).await {
+                self.transaction_repository
+                    .update_status(transaction.id, "Failed", &format!("Approval workflow not opened: {error}"))
+                    .await?;
+                return Err(error);
+            }
+            created_model
+        };
+
+        tracing::info!(
+            "Transaction {} processed with status {:?} for account {}",
+            transaction.id, transaction.status, transaction.account_id
+        );
+
+        TransactionMapper::from_model(created_model)
+    }
+
     /// Pre-validation checks for fast failure
-    async fn pre_validate_transaction(&self, transaction: &Transaction) -> BankingResult<()> {
+    async fn pre_validate_transaction(&self, transaction: &mut Transaction) -> BankingResult<()> {
         // Basic data validation
         if transaction.amount <= Decimal::ZERO {
             return Err(banking_api::BankingError::InvalidTransactionAmount(
@@ -405,13 +678,6 @@ impl TransactionServiceImpl {
             ));
         }
 
-        if transaction.currency.len() != 3 {
-            return Err(banking_api::BankingError::ValidationError {
-                field: "currency".to_string(),
-                message: "Currency must be a 3-character ISO code".to_string(),
-            });
-        }
-
         // Account existence check (cached)
         let account_exists = if let Some(cached) = self.validation_cache.get_account_status(transaction.account_id) {
             cached != &AccountStatus::Closed
@@ -423,9 +689,69 @@ impl TransactionServiceImpl {
             return Err(banking_api::BankingError::AccountNotFound(transaction.account_id));
         }
 
+        self.convert_to_account_currency(transaction).await?;
+        self.validate_channel_limits(transaction).await?;
+
+        Ok(())
+    }
+
+    /// Convert postings in another currency than the account's at the rate
+    /// effective at the transaction date; without a rate the posting fails
+    async fn convert_to_account_currency(&self, transaction: &mut Transaction) -> BankingResult<()> {
+        let account = self.account_repository
+            .find_by_id(transaction.account_id)
+            .await?
+            .ok_or(banking_api::BankingError::AccountNotFound(transaction.account_id))?;
+        let account = AccountMapper::from_model(account)?;
+        if transaction.currency == account.currency {
+            return Ok(());
+        }
+
+        let rate = self.exchange_rate_repository
+            .get_rate(transaction.currency.as_str(), account.currency.as_str(), transaction.transaction_date)
+            .await?
+            .map(ExchangeRateMapper::from_model);
+        let policy = self.config.posting.exchange_rate_policy();
+        transaction.convert_to(&account.currency, rate.as_ref(), &policy)?;
+
+        if let Some(rate) = rate.filter(|rate| rate.is_stale(&policy, transaction.transaction_date)) {
+            tracing::warn!(
+                "Transaction {} converted at stale {}/{} rate from {} effective since {}",
+                transaction.id, rate.from_currency, rate.to_currency, rate.source, rate.effective_at
+            );
+        }
         Ok(())
     }
 
+
+    /// Reject customer debits that would take the account's owners past the
+    /// limits of the channel, counting their debits through it from every
+    /// account they hold
+    async fn validate_channel_limits(&self, transaction: &Transaction) -> BankingResult<()> {
+        if transaction.transaction_type != TransactionType::Debit || transaction.is_system_posting() {
+            return Ok(());
+        }
+        let Some(limit) = self.channel_repository
+            .find_effective_limit(transaction.channel_id.as_str(), transaction.currency.as_str())
+            .await?
+        else {
+            return Ok(());
+        };
+        let window = ChannelUsageWindow::containing(transaction.transaction_date);
+        let usage = self.channel_repository
+            .get_owner_channel_usage(
+                transaction.account_id,
+                transaction.channel_id.as_str(),
+                transaction.currency.as_str(),
+                window.month_start,
+                window.day_start,
+                window.day_end,
+            )
+            .await?;
+        ChannelMapper::from_channel_limit_model(limit)
+            .check(transaction.amount, &ChannelMapper::from_channel_usage_model(usage))
+    }
+
     /// Validate account-level transaction rules
     async fn validate_account_level_limits(&self, transaction: &Transaction) -> BankingResult<ValidationResult> {
         let mut result = ValidationResult::success(Some(transaction.id));
@@ -475,7 +801,7 @@ impl TransactionServiceImpl {
         }
 
         // For debit transactions, check available balance
-        if transaction.transaction_type == TransactionType::Debit {
+        if transaction.transaction_type == TransactionType::Debit && !transaction.is_system_posting() {
             let available_balance = account_domain.current_balance + account_domain.overdraft_limit.unwrap_or(Decimal::ZERO);
             if transaction.amount > available_balance {
                 result.add_check(
@@ -610,6 +936,15 @@ impl TransactionServiceImpl {
 
         let account_domain = AccountMapper::from_model(account)?;
 
+        // High-value transactions need a second pair of eyes whatever the signing condition
+        let limits = &self.config.limits;
+        if limits
+            .approval_threshold(transaction.currency.as_str(), account_domain.product_id)
+            .is_some_and(|threshold| transaction.amount > threshold)
+        {
+            return Ok(true);
+        }
+
         // Check signing conditions
         match account_domain.signing_condition {
             banking_api::domain::SigningCondition::AllOwners => {
@@ -618,49 +953,175 @@ impl TransactionServiceImpl {
             }
             banking_api::domain::SigningCondition::AnyOwner => {
                 // Any owner can approve, but large amounts might require approval
-                Ok(transaction.amount > Decimal::new(10000, 2)) // $10,000 threshold
+                Ok(transaction.amount > limits.any_owner_approval_threshold)
             }
             banking_api::domain::SigningCondition::None => {
                 // Single-owner account, no approval required for normal transactions
-                Ok(transaction.amount > Decimal::new(50000, 2)) // $50,000 threshold
+                Ok(transaction.amount > limits.sole_owner_approval_threshold)
             }
         }
     }
 
-    /// Execute the financial posting (balance updates)
-    async fn execute_financial_posting(&self, transaction: &mut Transaction) -> BankingResult<()> {
+    /// Execute the financial posting: the transaction record and the balance
+    /// update are written in one database transaction
+    async fn execute_financial_posting(&self, transaction: &mut Transaction) -> BankingResult<TransactionModel> {
+        self.assign_gl_code(transaction).await?;
+
+        let posted_model = self.transaction_repository
+            .post_transaction(TransactionMapper::to_model(transaction.clone()))
+            .await?;
+
+        // Goals may not exceed the reduced balance; a replay moved no balance
+        if transaction.transaction_type == TransactionType::Debit && posted_model.id == transaction.id {
+            self.savings_goal_service
+                .rebalance_after_withdrawal(transaction.account_id, transaction.value_date)
+                .await?;
+        }
+
+        tracing::debug!(
+            "Financial posting executed: Account {} {:?} of {}",
+            transaction.account_id, transaction.transaction_type, transaction.amount
+        );
+
+        Ok(posted_model)
+    }
+
+    /// Set the GL code from the account's product if not provided
+    async fn assign_gl_code(&self, transaction: &mut Transaction) -> BankingResult<()> {
+        if !transaction.gl_code.as_str().is_empty() {
+            return Ok(());
+        }
         let account = self.account_repository
             .find_by_id(transaction.account_id)
             .await?
             .ok_or(banking_api::BankingError::AccountNotFound(transaction.account_id))?;
+        let gl_code_str = self.generate_gl_code(&account, transaction).await?;
+        transaction.set_gl_code(&gl_code_str).map_err(|e|
+            banking_api::BankingError::ValidationError {
+                field: "gl_code".to_string(),
+                message: e.to_string(),
+            }
+        )
+    }
 
-        // Calculate new balances based on transaction type
-        let new_balance = match transaction.transaction_type {
-            TransactionType::Credit => account.current_balance + transaction.amount,
-            TransactionType::Debit => account.current_balance - transaction.amount,
-        };
+    /// Critical steps fail the posting; skipped non-critical ones are flagged for backfill
+    async fn run_posting_steps(&self, transaction: &mut Transaction, posted_model: &mut TransactionModel) -> BankingResult<()> {
+        transaction.degraded_flags = self.posting_steps.run_after_posting(transaction).await?;
+        if !transaction.degraded_flags.is_empty() {
+            posted_model.degraded_flags = transaction.degraded_flags.bits() as i32;
+            self.transaction_repository
+                .update_degraded_flags(transaction.id, posted_model.degraded_flags)
+                .await?;
+        }
+        Ok(())
+    }
 
-        // Update account balance
-        self.account_repository
-            .update_balance(transaction.account_id, new_balance, new_balance)
-            .await?;
+    async fn active_mandates(&self, account_id: Uuid) -> BankingResult<Vec<banking_api::domain::AccountMandate>> {
+        Ok(self.account_repository
+            .find_active_mandates(account_id)
+            .await?
+            .into_iter()
+            .map(AccountMapper::account_mandate_from_model)
+            .collect())
+    }
 
-        // Set GL code if not provided
-        if transaction.gl_code.as_str().is_empty() {
-            let gl_code_str = self.generate_gl_code(&account, transaction).await?;
-            transaction.set_gl_code(&gl_code_str).map_err(|e|
-                banking_api::BankingError::ValidationError {
-                    field: "gl_code".to_string(),
-                    message: e.to_string(),
-                }
-            )?;
+    /// Reason carried by the holds over funds awaiting approval
+    async fn approval_hold_reason_id(&self) -> BankingResult<Uuid> {
+        let code = &self.config.limits.approval_hold_reason_code;
+        let reason = self.reason_repository
+            .find_by_code(code)
+            .await?
+            .ok_or_else(|| BankingError::Internal(format!("Approval hold reason {code} is not configured")))?;
+        ReasonValidation::check(ReasonId(reason.id), Some(&reason), ReasonedOperation::HoldPlacement)?;
+        Ok(reason.id)
+    }
+
+    /// Loads a transaction awaiting approval with its workflow. Mandates are
+    /// read again so a revoked approver can no longer decide.
+    async fn load_pending_approval(
+        &self,
+        transaction_id: Uuid,
+    ) -> BankingResult<(Transaction, ApprovalWorkflowModel, ApprovalRequirement)> {
+        let transaction = self.find_transaction_by_id(transaction_id)
+            .await?
+            .ok_or_else(|| BankingError::TransactionNotFound(transaction_id.to_string()))?;
+        if transaction.status != TransactionStatus::AwaitingApproval {
+            return Err(BankingError::ValidationError {
+                field: "status".to_string(),
+                message: format!("Transaction {transaction_id} is not awaiting approval"),
+            });
         }
+        let workflow = self.transaction_repository
+            .find_workflow_by_transaction(transaction_id)
+            .await?
+            .ok_or_else(|| BankingError::Internal(format!("No approval workflow for transaction {transaction_id}")))?;
 
-        tracing::debug!(
-            "Financial posting executed: Account {} balance updated to {}",
-            transaction.account_id, new_balance
+        let mut requirement = ApprovalRequirement::from_mandates(
+            workflow.initiated_by,
+            &self.active_mandates(transaction.account_id).await?,
+            workflow.timeout_at,
         );
+        requirement.approved_by = self.transaction_repository
+            .find_approvals_by_workflow(workflow.id)
+            .await?
+            .into_iter()
+            .filter(|approval| approval.approval_action.as_str() == "Approved")
+            .map(|approval| approval.approver_person_id)
+            .collect();
+        Ok((transaction, workflow, requirement))
+    }
+
+    async fn record_approval_action(
+        &self,
+        workflow_id: Uuid,
+        transaction_id: Uuid,
+        person_id: Uuid,
+        action: &str,
+    ) -> BankingResult<()> {
+        let now = Utc::now();
+        self.transaction_repository
+            .create_approval(WorkflowTransactionApprovalModel {
+                id: Uuid::new_v4(),
+                workflow_id,
+                transaction_id,
+                approver_person_id: person_id,
+                approval_action: HeaplessString::try_from(action).map_err(|_| BankingError::ValidationError {
+                    field: "approval_action".to_string(),
+                    message: format!("Approval action {action} too long"),
+                })?,
+                approved_at: now,
+                approval_notes: None,
+                approval_method: HeaplessString::try_from("Manual").unwrap(),
+                approval_location: None,
+                created_at: now,
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Fails a transaction whose workflow timed out. The hold expiry normally
+    /// frees the funds; a hold still active is released here.
+    async fn expire_approval(&self, transaction: &Transaction, workflow_id: Uuid) -> BankingResult<()> {
+        self.release_approval_hold(transaction, SYSTEM_PERSON_ID).await?;
+        self.transaction_repository
+            .update_status(transaction.id, "Failed", "Approval workflow timed out")
+            .await?;
+        self.transaction_repository
+            .update_workflow_status(workflow_id, &WorkflowStatusModel::TimedOut.to_string())
+            .await?;
+        tracing::warn!("Approval workflow {} of transaction {} timed out", workflow_id, transaction.id);
+        Ok(())
+    }
 
+    async fn release_approval_hold(&self, transaction: &Transaction, released_by: Uuid) -> BankingResult<()> {
+        let source_reference = transaction.id.to_string();
+        for hold in self.account_hold_service.get_active_holds(transaction.account_id).await? {
+            if hold.hold_type == HoldType::PendingAuthorization
+                && hold.source_reference.as_ref().is_some_and(|reference| reference.as_str() == source_reference)
+            {
+                self.account_hold_service.release_hold(hold.id, released_by).await?;
+            }
+        }
         Ok(())
     }
 
@@ -683,28 +1144,20 @@ impl TransactionServiceImpl {
             .await?
             .ok_or_else(|| BankingError::NotFound(format!("GL mapping not found for product {}", account.product_id)))?;
 
-        let suffix = account.gl_code_suffix.as_deref().unwrap_or("001");
-
-        Ok(format!("{}{}", gl_mapping.customer_account_code, suffix))
+        Ok(customer_gl_code(&gl_mapping.customer_account_code, account.gl_code_suffix.as_deref()))
     }
+}
 
-    /// Get required approvers for a transaction
-    async fn get_required_approvers(
-        &self,
-        _account: &banking_api::domain::Account,
-        _transaction: &Transaction,
-    ) -> BankingResult<Vec<Uuid>> {
-        // In production, this would query account ownership and mandates
-        // For now, return empty vector
-        Ok(Vec::new())
-    }
-
-    /// Update account last activity date
-    async fn update_account_activity(&self, account_id: Uuid) -> BankingResult<()> {
-        // In production, this would update the last_activity_date field
-        tracing::debug!("Updated activity timestamp for account {}", account_id);
-        Ok(())
-    }
+/// Original transaction returned for a submission that reused its idempotency key.
+/// A retry must carry the same payload; a different one is a conflict, not a replay.
+fn replayed(transaction: &Transaction, original: TransactionModel) -> BankingResult<Transaction> {
+    let original = TransactionMapper::from_model(original)?;
+    transaction.ensure_replay_of(&original)?;
+    tracing::info!(
+        "Transaction {} replays idempotency key {:?} on channel {}; returning {}",
+        transaction.id, transaction.idempotency_key, transaction.channel_id, original.id
+    );
+    Ok(original)
 }
 
 /// Validation cache for high-performance checks
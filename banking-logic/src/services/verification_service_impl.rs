@@ -0,0 +1,539 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use heapless::String as HeaplessString;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        ChallengeHandle, ChallengeRejection, ChallengeStatus, ChannelSecurityEvent, ChannelSecurityEventType,
+        Severity, VerificationChallenge, VerificationMethod, VerificationOperationKind, VerificationProof,
+        BRANCH_VERIFICATION_PERMISSION,
+    },
+    service::{ChannelSecurityService, VerificationCodeSender, VerificationService},
+};
+use banking_db::repository::VerificationRepository;
+use crate::config::BankingConfig;
+use crate::mappers::VerificationMapper;
+
+/// Production implementation of VerificationService
+pub struct VerificationServiceImpl {
+    verification_repository: Arc<dyn VerificationRepository>,
+    code_sender: Arc<dyn VerificationCodeSender>,
+    channel_security_service: Arc<dyn ChannelSecurityService>,
+    config: Arc<BankingConfig>,
+}
+
+impl VerificationServiceImpl {
+    pub fn new(
+        verification_repository: Arc<dyn VerificationRepository>,
+        code_sender: Arc<dyn VerificationCodeSender>,
+        channel_security_service: Arc<dyn ChannelSecurityService>,
+        config: Arc<BankingConfig>,
+    ) -> Self {
+        Self {
+            verification_repository,
+            code_sender,
+            channel_security_service,
+            config,
+        }
+    }
+
+    /// Wrong codes of the customer for the operation kind within the failure
+    /// window, with the time of the last one
+    async fn recent_failures(
+        &self,
+        customer_id: Uuid,
+        operation_kind: VerificationOperationKind,
+        now: DateTime<Utc>,
+    ) -> BankingResult<(u32, Option<DateTime<Utc>>)> {
+        let since = now - Duration::minutes(self.config.verification.failure_window_minutes);
+        let challenges = self.verification_repository
+            .find_challenges_created_since(customer_id, VerificationMapper::operation_kind_to_db(operation_kind), since)
+            .await?;
+        let failures = challenges.iter().map(|c| c.failed_attempts.max(0) as u32).sum();
+        let last_failed_at = challenges.iter().filter_map(|c| c.last_failed_at).max();
+        Ok((failures, last_failed_at))
+    }
+
+    /// End of the lockout the customer is in, if any
+    async fn locked_until(
+        &self,
+        customer_id: Uuid,
+        operation_kind: VerificationOperationKind,
+        now: DateTime<Utc>,
+    ) -> BankingResult<Option<DateTime<Utc>>> {
+        let (failures, last_failed_at) = self.recent_failures(customer_id, operation_kind, now).await?;
+        if failures < self.config.verification.lockout_failure_threshold {
+            return Ok(None);
+        }
+        Ok(last_failed_at
+            .map(|last| last + Duration::minutes(self.config.verification.lockout_minutes))
+            .filter(|until| now < *until))
+    }
+
+    /// Feed a failure to the channel security events, where the velocity
+    /// rules apply. A failure to record never changes the verification result.
+    async fn record_security_event(&self, challenge: &VerificationChallenge, severity: Severity, lockout: bool) {
+        let event = ChannelSecurityEvent {
+            id: Uuid::new_v4(),
+            channel_id: challenge.channel_id,
+            customer_id: Some(challenge.customer_id),
+            event_type: ChannelSecurityEventType::FailedAuth,
+            severity,
+            context: Some(serde_json::json!({
+                "source": "VerificationChallenge",
+                "challenge_id": challenge.id,
+                "operation_kind": format!("{:?}", challenge.operation_kind),
+                "failed_attempts": challenge.failed_attempts,
+                "lockout": lockout,
+            })),
+            occurred_at: challenge.last_failed_at.unwrap_or_else(Utc::now),
+            recorded_at: Utc::now(),
+        };
+        if let Err(e) = self.channel_security_service.record_security_event(event).await {
+            tracing::error!("Failed to record verification failure of challenge {}: {e}", challenge.id);
+        }
+    }
+
+    async fn reject_code(&self, mut challenge: VerificationChallenge, now: DateTime<Utc>) -> BankingError {
+        let settings = &self.config.verification;
+        challenge.failed_attempts += 1;
+        challenge.last_failed_at = Some(now);
+        if challenge.failed_attempts >= settings.max_attempts_per_challenge {
+            challenge.status = ChallengeStatus::Failed;
+        }
+        if let Err(e) = self.verification_repository.update_challenge(VerificationMapper::to_model(challenge.clone())).await {
+            return e;
+        }
+        self.record_security_event(&challenge, Severity::Medium, false).await;
+
+        // Escalate once, on the failure reaching the threshold
+        let failures = match self.recent_failures(challenge.customer_id, challenge.operation_kind, now).await {
+            Ok((failures, _)) => failures,
+            Err(e) => return e,
+        };
+        if failures == settings.lockout_failure_threshold {
+            tracing::warn!(
+                "Customer {} locked out of {:?} verification after {failures} wrong codes",
+                challenge.customer_id,
+                challenge.operation_kind
+            );
+            self.record_security_event(&challenge, Severity::High, true).await;
+        }
+        if failures >= settings.lockout_failure_threshold {
+            return BankingError::VerificationLockedOut {
+                customer_id: challenge.customer_id,
+                operation_kind: challenge.operation_kind,
+                until: now + Duration::minutes(settings.lockout_minutes),
+            };
+        }
+
+        let rejection = match challenge.status {
+            ChallengeStatus::Failed => ChallengeRejection::AttemptsExhausted,
+            _ => ChallengeRejection::WrongCode {
+                attempts_left: settings.max_attempts_per_challenge - challenge.failed_attempts,
+            },
+        };
+        BankingError::ChallengeRejected { challenge_id: challenge.id, rejection }
+    }
+}
+
+fn generate_code() -> HeaplessString<10> {
+    let mut code = HeaplessString::new();
+    // Six digits always fit
+    let _ = code.push_str(&format!("{:06}", rand::random::<u32>() % 1_000_000));
+    code
+}
+
+#[async_trait]
+impl VerificationService for VerificationServiceImpl {
+    async fn initiate_challenge(
+        &self,
+        customer_id: Uuid,
+        operation_kind: VerificationOperationKind,
+        channel_id: Uuid,
+    ) -> BankingResult<ChallengeHandle> {
+        let now = Utc::now();
+        if let Some(until) = self.locked_until(customer_id, operation_kind, now).await? {
+            return Err(BankingError::VerificationLockedOut { customer_id, operation_kind, until });
+        }
+
+        let challenge = VerificationChallenge {
+            id: Uuid::new_v4(),
+            customer_id,
+            operation_kind,
+            channel_id,
+            code: generate_code(),
+            status: ChallengeStatus::Pending,
+            failed_attempts: 0,
+            last_failed_at: None,
+            created_at: now,
+            expires_at: now + Duration::seconds(self.config.verification.challenge_ttl_seconds),
+            verified_method: None,
+            verified_by_person_id: None,
+            verified_at: None,
+            consumed_at: None,
+        };
+        let created = VerificationMapper::from_model(
+            self.verification_repository.create_challenge(VerificationMapper::to_model(challenge)).await?,
+        );
+
+        let code_sent = match self.code_sender.send_verification_code(customer_id, operation_kind, &created.code).await {
+            Ok(()) => true,
+            Err(e) => {
+                tracing::error!("Failed to send verification code of challenge {}: {e}", created.id);
+                false
+            }
+        };
+        Ok(ChallengeHandle {
+            challenge_id: created.id,
+            expires_at: created.expires_at,
+            code_sent,
+        })
+    }
+
+    async fn verify_challenge(&self, handle: &ChallengeHandle, proof: VerificationProof) -> BankingResult<VerificationChallenge> {
+        let now = Utc::now();
+        let mut challenge = self.verification_repository
+            .find_challenge_by_id(handle.challenge_id)
+            .await?
+            .map(VerificationMapper::from_model)
+            .ok_or_else(|| BankingError::NotFound(format!("Verification challenge {} not found", handle.challenge_id)))?;
+        challenge.check_verifiable(now).map_err(|rejection| BankingError::ChallengeRejected {
+            challenge_id: challenge.id,
+            rejection,
+        })?;
+        if let Some(until) = self.locked_until(challenge.customer_id, challenge.operation_kind, now).await? {
+            return Err(BankingError::VerificationLockedOut {
+                customer_id: challenge.customer_id,
+                operation_kind: challenge.operation_kind,
+                until,
+            });
+        }
+
+        match proof {
+            VerificationProof::Code(code) => {
+                if code != challenge.code {
+                    return Err(self.reject_code(challenge, now).await);
+                }
+                challenge.verified_method = Some(VerificationMethod::Otp);
+            }
+            VerificationProof::BranchAssertion(teller) => {
+                if !teller.has_permission(BRANCH_VERIFICATION_PERMISSION) {
+                    return Err(BankingError::UnauthorizedOperation(format!(
+                        "Verifying a customer in branch requires permission {BRANCH_VERIFICATION_PERMISSION}"
+                    )));
+                }
+                challenge.verified_method = Some(VerificationMethod::BranchAssertion);
+                challenge.verified_by_person_id = Some(teller.person_id);
+            }
+        }
+        challenge.status = ChallengeStatus::Verified;
+        challenge.verified_at = Some(now);
+        Ok(VerificationMapper::from_model(
+            self.verification_repository.update_challenge(VerificationMapper::to_model(challenge)).await?,
+        ))
+    }
+
+    async fn consume_verification(&self, customer_id: Uuid, operation_kind: VerificationOperationKind) -> BankingResult<VerificationChallenge> {
+        let now = Utc::now();
+        let required = || BankingError::VerificationRequired { customer_id, operation_kind };
+        let validity = Duration::minutes(self.config.verification.verified_validity_minutes);
+        let challenge = self.verification_repository
+            .find_latest_verified_challenge(customer_id, VerificationMapper::operation_kind_to_db(operation_kind))
+            .await?
+            .filter(|c| c.verified_at.is_some_and(|verified_at| now - verified_at <= validity))
+            .ok_or_else(required)?;
+
+        // A concurrent operation may have used the challenge in the meantime
+        self.verification_repository
+            .consume_challenge(challenge.id, now)
+            .await?
+            .map(VerificationMapper::from_model)
+            .ok_or_else(required)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use crate::config::VerificationSettings;
+    use banking_api::domain::{ChannelRestriction, PostingActor, SecurityEventOutcome};
+    use banking_db::models::{DbChallengeStatus, DbVerificationOperationKind, VerificationChallengeModel};
+
+    #[derive(Default)]
+    struct MockVerificationRepository {
+        challenges: Mutex<HashMap<Uuid, VerificationChallengeModel>>,
+    }
+
+    impl MockVerificationRepository {
+        /// Move a challenge back in time as if it had been created `age` ago
+        fn age(&self, challenge_id: Uuid, age: Duration) {
+            let mut challenges = self.challenges.lock().unwrap();
+            let challenge = challenges.get_mut(&challenge_id).unwrap();
+            challenge.created_at -= age;
+            challenge.expires_at -= age;
+            challenge.verified_at = challenge.verified_at.map(|at| at - age);
+        }
+    }
+
+    #[async_trait]
+    impl VerificationRepository for MockVerificationRepository {
+        async fn create_challenge(&self, challenge: VerificationChallengeModel) -> BankingResult<VerificationChallengeModel> {
+            self.challenges.lock().unwrap().insert(challenge.id, challenge.clone());
+            Ok(challenge)
+        }
+        async fn update_challenge(&self, challenge: VerificationChallengeModel) -> BankingResult<VerificationChallengeModel> {
+            self.challenges.lock().unwrap().insert(challenge.id, challenge.clone());
+            Ok(challenge)
+        }
+        async fn find_challenge_by_id(&self, challenge_id: Uuid) -> BankingResult<Option<VerificationChallengeModel>> {
+            Ok(self.challenges.lock().unwrap().get(&challenge_id).cloned())
+        }
+        async fn find_challenges_created_since(
+            &self,
+            customer_id: Uuid,
+            operation_kind: DbVerificationOperationKind,
+            since: DateTime<Utc>,
+        ) -> BankingResult<Vec<VerificationChallengeModel>> {
+            Ok(self.challenges
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|c| c.customer_id == customer_id && c.operation_kind == operation_kind && c.created_at >= since)
+                .cloned()
+                .collect())
+        }
+        async fn find_latest_verified_challenge(
+            &self,
+            customer_id: Uuid,
+            operation_kind: DbVerificationOperationKind,
+        ) -> BankingResult<Option<VerificationChallengeModel>> {
+            Ok(self.challenges
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|c| c.customer_id == customer_id && c.operation_kind == operation_kind && c.status == DbChallengeStatus::Verified)
+                .max_by_key(|c| c.verified_at)
+                .cloned())
+        }
+        async fn consume_challenge(&self, challenge_id: Uuid, consumed_at: DateTime<Utc>) -> BankingResult<Option<VerificationChallengeModel>> {
+            let mut challenges = self.challenges.lock().unwrap();
+            Ok(challenges
+                .get_mut(&challenge_id)
+                .filter(|c| c.status == DbChallengeStatus::Verified)
+                .map(|c| {
+                    c.status = DbChallengeStatus::Consumed;
+                    c.consumed_at = Some(consumed_at);
+                    c.clone()
+                }))
+        }
+    }
+
+    /// Keeps the last code sent to each customer
+    #[derive(Default)]
+    struct MockCodeSender {
+        codes: Mutex<HashMap<Uuid, HeaplessString<10>>>,
+    }
+
+    #[async_trait]
+    impl VerificationCodeSender for MockCodeSender {
+        async fn send_verification_code(
+            &self,
+            customer_id: Uuid,
+            _operation_kind: VerificationOperationKind,
+            code: &HeaplessString<10>,
+        ) -> BankingResult<()> {
+            self.codes.lock().unwrap().insert(customer_id, code.clone());
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct MockChannelSecurityService {
+        events: Mutex<Vec<ChannelSecurityEvent>>,
+    }
+
+    #[async_trait]
+    impl ChannelSecurityService for MockChannelSecurityService {
+        async fn record_security_event(&self, event: ChannelSecurityEvent) -> BankingResult<SecurityEventOutcome> {
+            self.events.lock().unwrap().push(event.clone());
+            Ok(SecurityEventOutcome {
+                event,
+                fired_rule_codes: Vec::new(),
+                compliance_alert_ids: Vec::new(),
+                restrictions: Vec::new(),
+            })
+        }
+        async fn find_recent_events_for_customer(&self, _customer_id: Uuid, _limit: i64) -> BankingResult<Vec<ChannelSecurityEvent>> { todo!() }
+        async fn restrict_channel(
+            &self,
+            _customer_id: Uuid,
+            _channel_id: Uuid,
+            _reason: HeaplessString<255>,
+            _expires_at: Option<DateTime<Utc>>,
+        ) -> BankingResult<ChannelRestriction> { todo!() }
+        async fn lift_restriction(&self, _restriction_id: Uuid, _lifted_by_person_id: Uuid) -> BankingResult<ChannelRestriction> { todo!() }
+        async fn find_active_restrictions(&self, _customer_id: Uuid, _as_of: DateTime<Utc>) -> BankingResult<Vec<ChannelRestriction>> { todo!() }
+        async fn is_channel_restricted(&self, _customer_id: Uuid, _channel_id: Uuid, _as_of: DateTime<Utc>) -> BankingResult<bool> { todo!() }
+        async fn purge_expired_events(&self, _as_of: DateTime<Utc>) -> BankingResult<u64> { todo!() }
+    }
+
+    struct Fixture {
+        service: VerificationServiceImpl,
+        repository: Arc<MockVerificationRepository>,
+        codes: Arc<MockCodeSender>,
+        security: Arc<MockChannelSecurityService>,
+    }
+
+    fn fixture() -> Fixture {
+        let repository = Arc::new(MockVerificationRepository::default());
+        let codes = Arc::new(MockCodeSender::default());
+        let security = Arc::new(MockChannelSecurityService::default());
+        let config = BankingConfig {
+            verification: VerificationSettings {
+                max_attempts_per_challenge: 3,
+                lockout_failure_threshold: 5,
+                ..VerificationSettings::default()
+            },
+            ..BankingConfig::default()
+        };
+        let service = VerificationServiceImpl::new(repository.clone(), codes.clone(), security.clone(), Arc::new(config));
+        Fixture { service, repository, codes, security }
+    }
+
+    fn code(value: &str) -> VerificationProof {
+        VerificationProof::Code(HeaplessString::try_from(value).unwrap())
+    }
+
+    fn rejection(result: BankingResult<VerificationChallenge>) -> ChallengeRejection {
+        match result {
+            Err(BankingError::ChallengeRejected { rejection, .. }) => rejection,
+            other => panic!("Expected a rejected challenge, got {other:?}"),
+        }
+    }
+
+    /// A six-digit code that differs from the one sent
+    fn wrong_code(sent: &HeaplessString<10>) -> VerificationProof {
+        code(if sent.as_str() == "000000" { "000001" } else { "000000" })
+    }
+
+    #[tokio::test]
+    async fn test_expired_challenge_and_stale_verification_are_refused() {
+        let f = fixture();
+        let (customer_id, channel_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let kind = VerificationOperationKind::ContactDetailChange;
+
+        let handle = f.service.initiate_challenge(customer_id, kind, channel_id).await.unwrap();
+        assert!(handle.code_sent);
+        f.repository.age(handle.challenge_id, Duration::minutes(6));
+        let sent = f.codes.codes.lock().unwrap()[&customer_id].clone();
+        assert_eq!(rejection(f.service.verify_challenge(&handle, VerificationProof::Code(sent)).await), ChallengeRejection::Expired);
+
+        // Verified, but longer ago than the validity window
+        let handle = f.service.initiate_challenge(customer_id, kind, channel_id).await.unwrap();
+        let sent = f.codes.codes.lock().unwrap()[&customer_id].clone();
+        f.service.verify_challenge(&handle, VerificationProof::Code(sent)).await.unwrap();
+        f.repository.age(handle.challenge_id, Duration::minutes(11));
+        assert!(matches!(
+            f.service.consume_verification(customer_id, kind).await,
+            Err(BankingError::VerificationRequired { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_challenges_are_single_use() {
+        let f = fixture();
+        let (customer_id, channel_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let kind = VerificationOperationKind::LimitIncrease;
+
+        let handle = f.service.initiate_challenge(customer_id, kind, channel_id).await.unwrap();
+        let sent = f.codes.codes.lock().unwrap()[&customer_id].clone();
+        let verified = f.service.verify_challenge(&handle, VerificationProof::Code(sent.clone())).await.unwrap();
+        assert_eq!(verified.verified_method, Some(VerificationMethod::Otp));
+        assert_eq!(
+            rejection(f.service.verify_challenge(&handle, VerificationProof::Code(sent)).await),
+            ChallengeRejection::AlreadyUsed
+        );
+
+        // Verification unlocks only its own operation kind, and only once
+        assert!(matches!(
+            f.service.consume_verification(customer_id, VerificationOperationKind::ContactDetailChange).await,
+            Err(BankingError::VerificationRequired { .. })
+        ));
+        f.service.consume_verification(customer_id, kind).await.unwrap();
+        assert!(matches!(
+            f.service.consume_verification(customer_id, kind).await,
+            Err(BankingError::VerificationRequired { .. })
+        ));
+
+        // In-person verification needs the teller permission
+        let handle = f.service.initiate_challenge(customer_id, kind, channel_id).await.unwrap();
+        let teller = |permissions: &[&str]| PostingActor {
+            person_id: Uuid::new_v4(),
+            permissions: permissions.iter().map(|p| HeaplessString::try_from(*p).unwrap()).collect(),
+        };
+        assert!(matches!(
+            f.service.verify_challenge(&handle, VerificationProof::BranchAssertion(teller(&[]))).await,
+            Err(BankingError::UnauthorizedOperation(_))
+        ));
+        let verified = f.service
+            .verify_challenge(&handle, VerificationProof::BranchAssertion(teller(&[BRANCH_VERIFICATION_PERMISSION])))
+            .await
+            .unwrap();
+        assert_eq!(verified.verified_method, Some(VerificationMethod::BranchAssertion));
+        assert!(verified.verified_by_person_id.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_wrong_codes_escalate_to_lockout() {
+        let f = fixture();
+        let (customer_id, channel_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let kind = VerificationOperationKind::LimitIncrease;
+
+        // Three wrong codes exhaust the first challenge
+        let first = f.service.initiate_challenge(customer_id, kind, channel_id).await.unwrap();
+        let sent = f.codes.codes.lock().unwrap()[&customer_id].clone();
+        assert_eq!(
+            rejection(f.service.verify_challenge(&first, wrong_code(&sent)).await),
+            ChallengeRejection::WrongCode { attempts_left: 2 }
+        );
+        f.service.verify_challenge(&first, wrong_code(&sent)).await.unwrap_err();
+        assert_eq!(
+            rejection(f.service.verify_challenge(&first, wrong_code(&sent)).await),
+            ChallengeRejection::AttemptsExhausted
+        );
+        assert_eq!(
+            rejection(f.service.verify_challenge(&first, VerificationProof::Code(sent)).await),
+            ChallengeRejection::AttemptsExhausted
+        );
+
+        // The fifth wrong code across challenges locks the customer out
+        let second = f.service.initiate_challenge(customer_id, kind, channel_id).await.unwrap();
+        let sent = f.codes.codes.lock().unwrap()[&customer_id].clone();
+        f.service.verify_challenge(&second, wrong_code(&sent)).await.unwrap_err();
+        assert!(matches!(
+            f.service.verify_challenge(&second, wrong_code(&sent)).await,
+            Err(BankingError::VerificationLockedOut { .. })
+        ));
+        assert!(matches!(
+            f.service.verify_challenge(&second, VerificationProof::Code(sent)).await,
+            Err(BankingError::VerificationLockedOut { .. })
+        ));
+        assert!(matches!(
+            f.service.initiate_challenge(customer_id, kind, channel_id).await,
+            Err(BankingError::VerificationLockedOut { .. })
+        ));
+        // Other operation kinds stay available
+        f.service.initiate_challenge(customer_id, VerificationOperationKind::ContactDetailChange, channel_id).await.unwrap();
+
+        let events = f.security.events.lock().unwrap();
+        assert_eq!(events.len(), 6);
+        assert!(events.iter().all(|e| e.event_type == ChannelSecurityEventType::FailedAuth && e.customer_id == Some(customer_id)));
+        assert_eq!(events.iter().filter(|e| matches!(e.severity, Severity::High)).count(), 1);
+    }
+}
@@ -0,0 +1,449 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        DeltaManifest, EntityDeltaManifest, WarehouseEntity, WarehouseExportMode, WarehouseRecord, WarehouseRecordOp,
+        WAREHOUSE_SCHEMA_VERSION,
+    },
+    service::{WarehouseDeltaWriter, WarehouseExportService},
+};
+use banking_db::models::WarehouseExportRunModel;
+use banking_db::repository::WarehouseExportRepository;
+use crate::config::BankingConfig;
+use crate::mappers::WarehouseMapper;
+
+/// Production implementation of WarehouseExportService
+pub struct WarehouseExportServiceImpl {
+    warehouse_export_repository: Arc<dyn WarehouseExportRepository>,
+    config: Arc<BankingConfig>,
+}
+
+impl WarehouseExportServiceImpl {
+    pub fn new(warehouse_export_repository: Arc<dyn WarehouseExportRepository>, config: Arc<BankingConfig>) -> Self {
+        Self {
+            warehouse_export_repository,
+            config,
+        }
+    }
+
+    /// Keep only the exported fields of the row, in schema order
+    fn project(entity: WarehouseEntity, row: &Value) -> Value {
+        let fields: Map<String, Value> = entity
+            .exported_fields()
+            .iter()
+            .map(|field| (field.to_string(), row.get(field).cloned().unwrap_or(Value::Null)))
+            .collect();
+        Value::Object(fields)
+    }
+
+    fn to_line(record: &WarehouseRecord, lines: &mut String) -> BankingResult<()> {
+        let line = serde_json::to_string(record)
+            .map_err(|e| BankingError::Internal(format!("Failed to serialize warehouse record: {e}")))?;
+        lines.push_str(&line);
+        lines.push('\n');
+        Ok(())
+    }
+
+    /// Write the upserts and, for incremental runs, the deletions of one entity page by page
+    async fn export_entity(
+        &self,
+        entity: WarehouseEntity,
+        from: Option<DateTime<Utc>>,
+        to: DateTime<Utc>,
+        writer: &dyn WarehouseDeltaWriter,
+    ) -> BankingResult<EntityDeltaManifest> {
+        let page_size = self.config.warehouse.page_size;
+        let db_entity = WarehouseMapper::entity_to_db(entity);
+        let mut manifest = EntityDeltaManifest {
+            entity,
+            upserted_rows: 0,
+            deleted_rows: 0,
+            min_watermark: None,
+            max_watermark: None,
+        };
+        let mut track = |changed_at: DateTime<Utc>| {
+            manifest.min_watermark = Some(manifest.min_watermark.map_or(changed_at, |min| min.min(changed_at)));
+            manifest.max_watermark = Some(manifest.max_watermark.map_or(changed_at, |max| max.max(changed_at)));
+        };
+
+        let mut upserted_rows = 0;
+        let mut after = None;
+        loop {
+            let page = self.warehouse_export_repository
+                .find_changed_rows(db_entity, from, to, after, page_size)
+                .await?;
+            let mut lines = String::new();
+            for change in &page {
+                track(change.changed_at);
+                Self::to_line(
+                    &WarehouseRecord {
+                        op: WarehouseRecordOp::Upsert,
+                        entity_id: change.entity_id,
+                        changed_at: change.changed_at,
+                        row: Some(Self::project(entity, &change.row)),
+                    },
+                    &mut lines,
+                )?;
+            }
+            if !lines.is_empty() {
+                writer.write_lines(entity, &lines).await?;
+            }
+            upserted_rows += page.len() as u64;
+            match page.last() {
+                Some(last) if page.len() as i64 == page_size => after = Some((last.changed_at, last.entity_id)),
+                _ => break,
+            }
+        }
+
+        // A snapshot holds every current row, so earlier deletions need no record
+        let mut deleted_rows = 0;
+        if let Some(from) = from {
+            let mut after = None;
+            loop {
+                let page = self.warehouse_export_repository
+                    .find_tombstones(db_entity, from, to, after, page_size)
+                    .await?;
+                let mut lines = String::new();
+                for tombstone in &page {
+                    track(tombstone.deleted_at);
+                    Self::to_line(
+                        &WarehouseRecord {
+                            op: WarehouseRecordOp::Delete,
+                            entity_id: tombstone.entity_id,
+                            changed_at: tombstone.deleted_at,
+                            row: None,
+                        },
+                        &mut lines,
+                    )?;
+                }
+                if !lines.is_empty() {
+                    writer.write_lines(entity, &lines).await?;
+                }
+                deleted_rows += page.len() as u64;
+                match page.last() {
+                    Some(last) if page.len() as i64 == page_size => after = Some((last.deleted_at, last.id)),
+                    _ => break,
+                }
+            }
+        }
+
+        manifest.upserted_rows = upserted_rows;
+        manifest.deleted_rows = deleted_rows;
+        Ok(manifest)
+    }
+
+    /// Export `(from, to]` of every entity, write the manifest and record the
+    /// run so the next one starts at `to`
+    async fn export(
+        &self,
+        mode: WarehouseExportMode,
+        from: Option<DateTime<Utc>>,
+        writer: &dyn WarehouseDeltaWriter,
+    ) -> BankingResult<DeltaManifest> {
+        let settled = Utc::now() - Duration::seconds(self.config.warehouse.settle_seconds);
+        // Never move the watermark backwards when runs follow each other within the settle time
+        let to = from.map_or(settled, |from| settled.max(from));
+
+        let mut entities = Vec::with_capacity(WarehouseEntity::ALL.len());
+        for entity in WarehouseEntity::ALL {
+            entities.push(self.export_entity(entity, from, to, writer).await?);
+        }
+
+        let manifest = DeltaManifest {
+            run_id: Uuid::new_v4(),
+            mode,
+            schema_version: WAREHOUSE_SCHEMA_VERSION,
+            from_watermark: from,
+            to_watermark: to,
+            generated_at: Utc::now(),
+            entities,
+        };
+        writer.write_manifest(&manifest).await?;
+
+        let total_rows = manifest
+            .entities
+            .iter()
+            .map(|entity| entity.upserted_rows + entity.deleted_rows)
+            .sum::<u64>();
+        self.warehouse_export_repository
+            .create_export_run(WarehouseExportRunModel {
+                id: manifest.run_id,
+                mode: WarehouseMapper::mode_to_db(mode),
+                schema_version: WAREHOUSE_SCHEMA_VERSION as i32,
+                from_watermark: from,
+                to_watermark: to,
+                total_rows: total_rows as i64,
+                completed_at: manifest.generated_at,
+            })
+            .await?;
+
+        tracing::info!(
+            run_id = %manifest.run_id,
+            mode = ?mode,
+            total_rows,
+            "Warehouse export completed"
+        );
+        Ok(manifest)
+    }
+}
+
+#[async_trait]
+impl WarehouseExportService for WarehouseExportServiceImpl {
+    async fn export_warehouse_delta(
+        &self,
+        since_watermark: DateTime<Utc>,
+        writer: &dyn WarehouseDeltaWriter,
+    ) -> BankingResult<DeltaManifest> {
+        let last_watermark = self.last_watermark().await?.ok_or_else(|| BankingError::ValidationError {
+            field: "since_watermark".to_string(),
+            message: "No export has run yet; start with a full snapshot".to_string(),
+        })?;
+        // Any other starting point would skip or repeat changes
+        if since_watermark != last_watermark {
+            return Err(BankingError::ValidationError {
+                field: "since_watermark".to_string(),
+                message: format!("Watermark {since_watermark} does not continue the last export, which ended at {last_watermark}"),
+            });
+        }
+        self.export(WarehouseExportMode::Incremental, Some(since_watermark), writer).await
+    }
+
+    async fn export_full_snapshot(&self, writer: &dyn WarehouseDeltaWriter) -> BankingResult<DeltaManifest> {
+        self.export(WarehouseExportMode::FullSnapshot, None, writer).await
+    }
+
+    async fn last_watermark(&self) -> BankingResult<Option<DateTime<Utc>>> {
+        Ok(self.warehouse_export_repository
+            .find_latest_export_run()
+            .await?
+            .map(|run| run.to_watermark))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use crate::config::WarehouseExportSettings;
+    use banking_db::models::{DbWarehouseEntity, WarehouseChangeModel, WarehouseTombstoneModel};
+    use serde_json::json;
+
+    /// Keeps the latest version of each row, as a table with an updated_at column would
+    #[derive(Default)]
+    struct MockWarehouseExportRepository {
+        rows: Mutex<HashMap<Uuid, (DbWarehouseEntity, WarehouseChangeModel)>>,
+        tombstones: Mutex<Vec<WarehouseTombstoneModel>>,
+        runs: Mutex<Vec<WarehouseExportRunModel>>,
+    }
+
+    impl MockWarehouseExportRepository {
+        fn upsert(&self, entity: DbWarehouseEntity, entity_id: Uuid, changed_at: DateTime<Utc>) {
+            let row = json!({ "id": entity_id, "current_balance": "100.00", "secret_column": "not exported" });
+            self.rows
+                .lock()
+                .unwrap()
+                .insert(entity_id, (entity, WarehouseChangeModel { entity_id, changed_at, row }));
+        }
+
+        fn delete(&self, entity: DbWarehouseEntity, entity_id: Uuid, deleted_at: DateTime<Utc>) {
+            self.rows.lock().unwrap().remove(&entity_id);
+            self.tombstones.lock().unwrap().push(WarehouseTombstoneModel {
+                id: Uuid::new_v4(),
+                entity,
+                entity_id,
+                deleted_at,
+            });
+        }
+    }
+
+    #[async_trait]
+    impl WarehouseExportRepository for MockWarehouseExportRepository {
+        async fn find_changed_rows(
+            &self,
+            entity: DbWarehouseEntity,
+            from: Option<DateTime<Utc>>,
+            to: DateTime<Utc>,
+            after: Option<(DateTime<Utc>, Uuid)>,
+            limit: i64,
+        ) -> BankingResult<Vec<WarehouseChangeModel>> {
+            let mut rows: Vec<_> = self.rows
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|(e, r)| *e == entity && from.is_none_or(|from| r.changed_at > from) && r.changed_at <= to)
+                .filter(|(_, r)| after.is_none_or(|after| (r.changed_at, r.entity_id) > after))
+                .map(|(_, r)| r.clone())
+                .collect();
+            rows.sort_by_key(|r| (r.changed_at, r.entity_id));
+            rows.truncate(limit as usize);
+            Ok(rows)
+        }
+        async fn find_tombstones(
+            &self,
+            entity: DbWarehouseEntity,
+            from: DateTime<Utc>,
+            to: DateTime<Utc>,
+            after: Option<(DateTime<Utc>, Uuid)>,
+            limit: i64,
+        ) -> BankingResult<Vec<WarehouseTombstoneModel>> {
+            let mut tombstones: Vec<_> = self.tombstones
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|t| t.entity == entity && t.deleted_at > from && t.deleted_at <= to)
+                .filter(|t| after.is_none_or(|after| (t.deleted_at, t.id) > after))
+                .cloned()
+                .collect();
+            tombstones.sort_by_key(|t| (t.deleted_at, t.id));
+            tombstones.truncate(limit as usize);
+            Ok(tombstones)
+        }
+        async fn create_tombstone(&self, tombstone: WarehouseTombstoneModel) -> BankingResult<WarehouseTombstoneModel> {
+            self.tombstones.lock().unwrap().push(tombstone.clone());
+            Ok(tombstone)
+        }
+        async fn create_export_run(&self, run: WarehouseExportRunModel) -> BankingResult<WarehouseExportRunModel> {
+            self.runs.lock().unwrap().push(run.clone());
+            Ok(run)
+        }
+        async fn find_latest_export_run(&self) -> BankingResult<Option<WarehouseExportRunModel>> {
+            Ok(self.runs.lock().unwrap().last().cloned())
+        }
+    }
+
+    /// Collects the records written per entity and the manifests
+    #[derive(Default)]
+    struct MockDeltaWriter {
+        records: Mutex<Vec<(WarehouseEntity, WarehouseRecord)>>,
+        manifests: Mutex<Vec<DeltaManifest>>,
+    }
+
+    impl MockDeltaWriter {
+        fn records(&self) -> Vec<(WarehouseEntity, WarehouseRecord)> {
+            self.records.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl WarehouseDeltaWriter for MockDeltaWriter {
+        async fn write_lines(&self, entity: WarehouseEntity, lines: &str) -> BankingResult<()> {
+            assert!(lines.ends_with('\n'));
+            let mut records = self.records.lock().unwrap();
+            for line in lines.lines() {
+                records.push((entity, serde_json::from_str(line).unwrap()));
+            }
+            Ok(())
+        }
+        async fn write_manifest(&self, manifest: &DeltaManifest) -> BankingResult<()> {
+            self.manifests.lock().unwrap().push(manifest.clone());
+            Ok(())
+        }
+    }
+
+    /// No settle time and small pages so that runs can follow each other and paging is exercised
+    fn service(repo: Arc<MockWarehouseExportRepository>) -> WarehouseExportServiceImpl {
+        let config = BankingConfig {
+            warehouse: WarehouseExportSettings {
+                page_size: 2,
+                settle_seconds: 0,
+            },
+            ..BankingConfig::default()
+        };
+        WarehouseExportServiceImpl::new(repo, Arc::new(config))
+    }
+
+    async fn next_tick() {
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    }
+
+    #[tokio::test]
+    async fn test_consecutive_runs_neither_skip_nor_repeat_changes() {
+        let repo = Arc::new(MockWarehouseExportRepository::default());
+        let service = service(repo.clone());
+        let accounts: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        for (i, account_id) in accounts.iter().enumerate() {
+            repo.upsert(DbWarehouseEntity::Account, *account_id, Utc::now() - Duration::minutes(10 - i as i64));
+        }
+
+        let snapshot_writer = MockDeltaWriter::default();
+        let snapshot = service.export_full_snapshot(&snapshot_writer).await.unwrap();
+        assert_eq!(snapshot.mode, WarehouseExportMode::FullSnapshot);
+        assert_eq!(snapshot.from_watermark, None);
+        assert_eq!(snapshot.schema_version, WAREHOUSE_SCHEMA_VERSION);
+        assert_eq!(snapshot.entities[0].upserted_rows, 3);
+        assert_eq!(snapshot_writer.records().len(), 3);
+        let (_, first) = &snapshot_writer.records()[0];
+        assert_eq!(first.row.as_ref().unwrap().get("secret_column"), None);
+        assert_eq!(first.row.as_ref().unwrap()["product_id"], Value::Null);
+
+        // Changes right after the watermark belong to the next run only
+        let changed_at = snapshot.to_watermark + Duration::milliseconds(1);
+        repo.upsert(DbWarehouseEntity::Account, accounts[0], changed_at);
+        let transaction_id = Uuid::new_v4();
+        repo.upsert(DbWarehouseEntity::Transaction, transaction_id, changed_at);
+        next_tick().await;
+
+        let first_writer = MockDeltaWriter::default();
+        let first_delta = service.export_warehouse_delta(snapshot.to_watermark, &first_writer).await.unwrap();
+        assert_eq!(first_delta.from_watermark, Some(snapshot.to_watermark));
+        let exported: Vec<_> = first_writer.records().iter().map(|(e, r)| (*e, r.entity_id)).collect();
+        assert_eq!(
+            exported,
+            vec![(WarehouseEntity::Account, accounts[0]), (WarehouseEntity::Transaction, transaction_id)]
+        );
+        assert_eq!(first_delta.entities[0].min_watermark, Some(changed_at));
+        assert_eq!(first_delta.entities[0].max_watermark, Some(changed_at));
+        assert_eq!(service.last_watermark().await.unwrap(), Some(first_delta.to_watermark));
+
+        // Restarting from an older watermark would repeat the changes above
+        let overlap = service.export_warehouse_delta(snapshot.to_watermark, &MockDeltaWriter::default()).await;
+        assert!(matches!(overlap, Err(BankingError::ValidationError { .. })));
+
+        next_tick().await;
+        let second_writer = MockDeltaWriter::default();
+        let second_delta = service.export_warehouse_delta(first_delta.to_watermark, &second_writer).await.unwrap();
+        assert!(second_writer.records().is_empty());
+        assert!(second_delta.entities.iter().all(|e| e.upserted_rows == 0 && e.deleted_rows == 0));
+        assert_eq!(second_writer.manifests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_deletions_are_captured_through_tombstones() {
+        let repo = Arc::new(MockWarehouseExportRepository::default());
+        let service = service(repo.clone());
+
+        let no_snapshot = service.export_warehouse_delta(Utc::now(), &MockDeltaWriter::default()).await;
+        assert!(matches!(no_snapshot, Err(BankingError::ValidationError { .. })));
+
+        let workflow_id = Uuid::new_v4();
+        repo.upsert(DbWarehouseEntity::Workflow, workflow_id, Utc::now() - Duration::minutes(5));
+        let snapshot = service.export_full_snapshot(&MockDeltaWriter::default()).await.unwrap();
+        assert_eq!(snapshot.entities[3].upserted_rows, 1);
+
+        let deleted_at = snapshot.to_watermark + Duration::milliseconds(1);
+        repo.delete(DbWarehouseEntity::Workflow, workflow_id, deleted_at);
+        next_tick().await;
+
+        let writer = MockDeltaWriter::default();
+        let delta = service.export_warehouse_delta(snapshot.to_watermark, &writer).await.unwrap();
+        let records = writer.records();
+        assert_eq!(records.len(), 1);
+        let (entity, record) = &records[0];
+        assert_eq!(*entity, WarehouseEntity::Workflow);
+        assert_eq!(record.op, WarehouseRecordOp::Delete);
+        assert_eq!((record.entity_id, record.changed_at, record.row.clone()), (workflow_id, deleted_at, None));
+        assert_eq!(delta.entities[3].deleted_rows, 1);
+
+        // Tombstones before a snapshot are covered by the snapshot itself
+        let reload_writer = MockDeltaWriter::default();
+        let reload = service.export_full_snapshot(&reload_writer).await.unwrap();
+        assert!(reload_writer.records().is_empty());
+        assert_eq!(reload.entities[3].deleted_rows, 0);
+    }
+}
@@ -0,0 +1,194 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use heapless::String as HeaplessString;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{
+        AccountStatus, DocumentNotification, DocumentNotificationStatus, GeneratedDocument,
+        GeneratedDocumentType, SupportedLanguages, WelcomePack, format_iban, group_iban, is_valid_iban,
+    },
+    service::{HierarchyService, ProductService, WelcomePackService},
+};
+use banking_db::repository::{AccountRepository, CustomerRepository, DocumentRepository};
+use crate::mappers::{AccountMapper, DocumentMapper};
+
+/// Configuration struct for WelcomePackServiceImpl to avoid too many constructor arguments
+pub struct WelcomePackServiceConfig {
+    pub account_repository: Arc<dyn AccountRepository>,
+    pub customer_repository: Arc<dyn CustomerRepository>,
+    pub document_repository: Arc<dyn DocumentRepository>,
+    pub product_service: Arc<dyn ProductService>,
+    pub hierarchy_service: Arc<dyn HierarchyService>,
+    pub supported_languages: SupportedLanguages,
+    /// ISO 3166 country code used to derive the IBAN of accounts numbered
+    /// with a BBAN alone; `None` if the institution does not issue IBANs
+    pub iban_country_code: Option<HeaplessString<2>>,
+}
+
+/// Production implementation of WelcomePackService
+pub struct WelcomePackServiceImpl {
+    account_repository: Arc<dyn AccountRepository>,
+    customer_repository: Arc<dyn CustomerRepository>,
+    document_repository: Arc<dyn DocumentRepository>,
+    product_service: Arc<dyn ProductService>,
+    hierarchy_service: Arc<dyn HierarchyService>,
+    supported_languages: SupportedLanguages,
+    iban_country_code: Option<HeaplessString<2>>,
+}
+
+impl WelcomePackServiceImpl {
+    pub fn new(config: WelcomePackServiceConfig) -> Self {
+        Self {
+            account_repository: config.account_repository,
+            customer_repository: config.customer_repository,
+            document_repository: config.document_repository,
+            product_service: config.product_service,
+            hierarchy_service: config.hierarchy_service,
+            supported_languages: config.supported_languages,
+            iban_country_code: config.iban_country_code,
+        }
+    }
+}
+
+#[async_trait]
+impl WelcomePackService for WelcomePackServiceImpl {
+    async fn issue_on_activation(&self, account_id: Uuid, previous_status: AccountStatus) -> BankingResult<Option<GeneratedDocument>> {
+        if !WelcomePack::is_triggered_by(previous_status, AccountStatus::Active) {
+            return Ok(None);
+        }
+
+        // Guard against a retried activation issuing a second pack
+        let existing = self.document_repository
+            .find_documents_by_account(account_id, DocumentMapper::document_type_to_db(GeneratedDocumentType::WelcomePack))
+            .await?;
+        if !existing.is_empty() {
+            tracing::debug!("Welcome pack already issued for account {}", account_id);
+            return Ok(None);
+        }
+
+        let pack = self.render_welcome_pack(account_id).await?;
+        let content = serde_json::to_string(&pack)
+            .map_err(|e| BankingError::Internal(format!("Failed to serialize welcome pack: {e}")))?;
+
+        let document = GeneratedDocument {
+            id: Uuid::new_v4(),
+            document_type: GeneratedDocumentType::WelcomePack,
+            account_id,
+            customer_id: pack.customer_id,
+            language_code: pack.language_code,
+            content,
+            created_at: pack.generated_at,
+        };
+        let stored = self.document_repository
+            .create_document(DocumentMapper::document_to_model(document))
+            .await?;
+        let document = DocumentMapper::document_from_model(stored);
+
+        let notification = DocumentNotification {
+            id: Uuid::new_v4(),
+            document_id: document.id,
+            customer_id: document.customer_id,
+            retrieval_reference: document.retrieval_reference(),
+            language_code: document.language_code,
+            status: DocumentNotificationStatus::Queued,
+            queued_at: Utc::now(),
+            sent_at: None,
+        };
+        self.document_repository
+            .queue_notification(DocumentMapper::notification_to_model(notification))
+            .await?;
+
+        tracing::info!(
+            "Welcome pack {} issued for account {} ({})",
+            document.id, account_id, String::from_utf8_lossy(&document.language_code)
+        );
+
+        Ok(Some(document))
+    }
+
+    async fn find_document_by_id(&self, document_id: Uuid) -> BankingResult<Option<GeneratedDocument>> {
+        let document = self.document_repository.find_document_by_id(document_id).await?;
+        Ok(document.map(DocumentMapper::document_from_model))
+    }
+}
+
+impl WelcomePackServiceImpl {
+    /// Assemble the localized welcome pack from account, product and branch data
+    async fn render_welcome_pack(&self, account_id: Uuid) -> BankingResult<WelcomePack> {
+        let account_model = self.account_repository
+            .find_by_id(account_id)
+            .await?
+            .ok_or(BankingError::AccountNotFound(account_id))?;
+        let account = AccountMapper::from_model(account_model)?;
+
+        // The earliest registered owner is the primary account holder
+        let mut owners = self.account_repository.find_ownership_by_account(account_id).await?;
+        owners.sort_by_key(|owner| owner.created_at);
+        let customer_id = owners
+            .first()
+            .map(|owner| owner.customer_id)
+            .ok_or_else(|| BankingError::ValidationError {
+                field: "account_ownership".to_string(),
+                message: format!("Account {account_id} has no registered owner"),
+            })?;
+        let customer = self.customer_repository
+            .find_by_id(customer_id)
+            .await?
+            .ok_or(BankingError::CustomerNotFound(customer_id))?;
+
+        let (slot, language_code) = self.supported_languages.select(customer.preferred_language_code);
+
+        let product = self.product_service
+            .find_product_by_id(account.product_id)
+            .await?
+            .ok_or_else(|| BankingError::ValidationError {
+                field: "product_id".to_string(),
+                message: format!("Product {} not found", account.product_id),
+            })?;
+        let product_name = slot.pick(&product.name_l1, &product.name_l2, &product.name_l3);
+
+        let branch = self.hierarchy_service
+            .find_branch_by_id(account.domicile_agency_branch_id)
+            .await?
+            .ok_or_else(|| BankingError::Internal(format!("Branch {} not found", account.domicile_agency_branch_id)))?;
+
+        // Accounts numbered under an IBAN scheme already hold the compact IBAN
+        let account_number = account.account_number;
+        let iban = if is_valid_iban(&account_number) {
+            Some(account_number.clone())
+        } else {
+            self.iban_country_code
+                .as_ref()
+                .and_then(|country| format_iban(country, &account_number).ok())
+        };
+        let formatted_account_number = iban.and_then(|iban| HeaplessString::try_from(group_iban(&iban).as_str()).ok());
+
+        Ok(WelcomePack {
+            account_id,
+            customer_id,
+            language_code,
+            account_number,
+            formatted_account_number,
+            currency: account.currency.into(),
+            open_date: account.open_date,
+            product_id: product.id,
+            product_name: HeaplessString::try_from(product_name)
+                .map_err(|_| BankingError::Internal("Product name too long".to_string()))?,
+            product_description: product.description,
+            minimum_balance: product.rules.minimum_balance,
+            overdraft_allowed: product.rules.overdraft_allowed,
+            overdraft_limit: product.rules.overdraft_limit,
+            maintenance_fee: product.rules.maintenance_fee,
+            closure_fee: product.rules.closure_fee,
+            branch_id: branch.id,
+            branch_name: branch.branch_name,
+            branch_code: branch.branch_code,
+            branch_contact_messaging_id: branch.messaging1_id,
+            branch_contact_messaging_type: branch.messaging1_type,
+            generated_at: Utc::now(),
+        })
+    }
+}
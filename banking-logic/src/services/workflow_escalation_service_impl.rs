@@ -0,0 +1,564 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use heapless::String as HeaplessString;
+use uuid::Uuid;
+
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{MessageChannel, MessageSendRequest, NotificationTemplate, WorkflowEscalation, WorkflowEscalationStatus},
+    service::{EscalationRunSummary, HierarchyService, NotificationService, WorkflowEscalationService},
+};
+use banking_db::models::AccountWorkflowModel;
+use banking_db::repository::{WorkflowEscalationRepository, WorkflowRepository};
+use crate::config::BankingConfig;
+use crate::mappers::WorkflowMapper;
+use crate::services::notification_service_impl::account_suffix;
+
+/// Production implementation of WorkflowEscalationService
+pub struct WorkflowEscalationServiceImpl {
+    workflow_repository: Arc<dyn WorkflowRepository>,
+    workflow_escalation_repository: Arc<dyn WorkflowEscalationRepository>,
+    hierarchy_service: Arc<dyn HierarchyService>,
+    notification_service: Arc<dyn NotificationService>,
+    config: Arc<BankingConfig>,
+}
+
+impl WorkflowEscalationServiceImpl {
+    pub fn new(
+        workflow_repository: Arc<dyn WorkflowRepository>,
+        workflow_escalation_repository: Arc<dyn WorkflowEscalationRepository>,
+        hierarchy_service: Arc<dyn HierarchyService>,
+        notification_service: Arc<dyn NotificationService>,
+        config: Arc<BankingConfig>,
+    ) -> Self {
+        Self {
+            workflow_repository,
+            workflow_escalation_repository,
+            hierarchy_service,
+            notification_service,
+            config,
+        }
+    }
+
+    async fn load(&self, escalation_id: Uuid) -> BankingResult<WorkflowEscalation> {
+        let model = self
+            .workflow_escalation_repository
+            .find_escalation_by_id(escalation_id)
+            .await?
+            .ok_or_else(|| BankingError::NotFound(format!("Workflow escalation {escalation_id} not found")))?;
+        WorkflowMapper::escalation_from_model(model)
+    }
+
+    /// The repository refused the transition because the escalation changed
+    /// since it was loaded; report the status it has now
+    async fn closed_concurrently(&self, escalation_id: Uuid) -> BankingError {
+        match self.load(escalation_id).await {
+            Ok(escalation) => BankingError::WorkflowEscalationClosed { escalation_id, status: escalation.status },
+            Err(e) => e,
+        }
+    }
+
+    /// Store the escalation and tell its supervisor. The escalation stays in
+    /// the supervisor's queue when the message cannot be sent.
+    async fn open_escalation(
+        &self,
+        escalation: WorkflowEscalation,
+        workflow: &AccountWorkflowModel,
+    ) -> BankingResult<WorkflowEscalation> {
+        let stored = self
+            .workflow_escalation_repository
+            .create_escalation(WorkflowMapper::escalation_to_model(escalation))
+            .await?;
+        let escalation = WorkflowMapper::escalation_from_model(stored)?;
+
+        let request = MessageSendRequest {
+            template_code: NotificationTemplate::WorkflowEscalation.code().to_string(),
+            person_id: escalation.escalated_to_person_id,
+            channels: vec![MessageChannel::Email, MessageChannel::InApp],
+            params: HashMap::from([
+                ("workflow_id".to_string(), workflow.id.to_string()),
+                ("workflow_type".to_string(), workflow.workflow_type.to_string()),
+                ("account_suffix".to_string(), account_suffix(workflow.account_id)),
+                ("level".to_string(), escalation.level.to_string()),
+                ("action_due_at".to_string(), escalation.action_due_at.format("%Y-%m-%d %H:%M UTC").to_string()),
+            ]),
+            requested_at: escalation.created_at,
+        };
+        if let Err(e) = self.notification_service.render_and_send(request).await {
+            tracing::warn!(
+                "Escalation {} of workflow {} opened but supervisor {} was not notified: {e}",
+                escalation.id, workflow.id, escalation.escalated_to_person_id
+            );
+        }
+        Ok(escalation)
+    }
+
+    async fn load_workflow(&self, workflow_id: Uuid) -> BankingResult<AccountWorkflowModel> {
+        self.workflow_repository
+            .find_workflow_by_id(workflow_id)
+            .await?
+            .ok_or_else(|| BankingError::NotFound(format!("Workflow {workflow_id} not found")))
+    }
+}
+
+#[async_trait]
+impl WorkflowEscalationService for WorkflowEscalationServiceImpl {
+    async fn process_escalations(&self, reference_time: DateTime<Utc>) -> BankingResult<EscalationRunSummary> {
+        let policy = self.config.workflows.escalation_policy();
+
+        // Read before timing out: the bulk update does not report which workflows it touched
+        let expired = self.workflow_repository.find_expired_workflows(reference_time).await?;
+        let workflows_timed_out = self.workflow_repository.bulk_timeout_expired_workflows(reference_time).await?;
+        let mut summary = EscalationRunSummary { workflows_timed_out, ..EscalationRunSummary::default() };
+
+        for workflow in &expired {
+            let reporting_line = self.hierarchy_service.get_reporting_line(workflow.initiated_by).await?;
+            match WorkflowEscalation::open(workflow.id, &reporting_line, &policy, reference_time) {
+                Some(escalation) => summary.escalations_opened.push(self.open_escalation(escalation, workflow).await?),
+                None => {
+                    tracing::warn!(
+                        "Workflow {} timed out but initiator {} has no supervisor to escalate to",
+                        workflow.id, workflow.initiated_by
+                    );
+                    summary.workflows_without_supervisor.push(workflow.id);
+                }
+            }
+        }
+
+        // Escalations opened above are due after `reference_time` and are not picked up here
+        let overdue = self
+            .workflow_escalation_repository
+            .find_overdue_escalations(reference_time, i16::from(policy.max_depth))
+            .await?;
+        for model in overdue {
+            let mut escalation = WorkflowMapper::escalation_from_model(model)?;
+            let workflow = self.load_workflow(escalation.workflow_id).await?;
+            let reporting_line = self.hierarchy_service.get_reporting_line(workflow.initiated_by).await?;
+            let Some(next) = escalation.escalate(&reporting_line, &policy, reference_time) else {
+                // Top of the reporting line: the escalation stays with the last supervisor
+                continue;
+            };
+            // None means the supervisor acted after the escalation was read
+            if self
+                .workflow_escalation_repository
+                .mark_escalated(escalation.id, reference_time)
+                .await?
+                .is_none()
+            {
+                continue;
+            }
+            summary.escalations_opened.push(self.open_escalation(next, &workflow).await?);
+        }
+
+        Ok(summary)
+    }
+
+    async fn find_open_escalations(&self, person_id: Uuid) -> BankingResult<Vec<WorkflowEscalation>> {
+        self.workflow_escalation_repository
+            .find_open_escalations_by_person(person_id)
+            .await?
+            .into_iter()
+            .map(WorkflowMapper::escalation_from_model)
+            .collect()
+    }
+
+    async fn find_escalations_by_workflow(&self, workflow_id: Uuid) -> BankingResult<Vec<WorkflowEscalation>> {
+        self.workflow_escalation_repository
+            .find_escalations_by_workflow(workflow_id)
+            .await?
+            .into_iter()
+            .map(WorkflowMapper::escalation_from_model)
+            .collect()
+    }
+
+    async fn acknowledge_escalation(&self, escalation_id: Uuid, person_id: Uuid) -> BankingResult<WorkflowEscalation> {
+        let mut escalation = self.load(escalation_id).await?;
+        let already_acknowledged = escalation.status == WorkflowEscalationStatus::Acknowledged;
+        let acknowledged_at = Utc::now();
+        escalation.acknowledge(person_id, acknowledged_at)?;
+        if already_acknowledged {
+            return Ok(escalation);
+        }
+
+        match self
+            .workflow_escalation_repository
+            .acknowledge_escalation(escalation_id, acknowledged_at)
+            .await?
+        {
+            Some(stored) => WorkflowMapper::escalation_from_model(stored),
+            None => Err(self.closed_concurrently(escalation_id).await),
+        }
+    }
+
+    async fn resolve_escalation(
+        &self,
+        escalation_id: Uuid,
+        person_id: Uuid,
+        resolution_notes: Option<HeaplessString<500>>,
+    ) -> BankingResult<WorkflowEscalation> {
+        let mut escalation = self.load(escalation_id).await?;
+        let resolved_at = Utc::now();
+        escalation.resolve(person_id, resolution_notes.clone(), resolved_at)?;
+
+        match self
+            .workflow_escalation_repository
+            .resolve_escalation(escalation_id, person_id, resolution_notes, resolved_at)
+            .await?
+        {
+            Some(stored) => WorkflowMapper::escalation_from_model(stored),
+            None => Err(self.closed_concurrently(escalation_id).await),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use chrono::{Duration, NaiveDate};
+    use rust_decimal::Decimal;
+    use banking_api::domain::{
+        AgencyBranch, AgentNetwork, AgentTerminal, BranchRollup, BranchStatus, ChannelDispatch, MessageTemplate,
+        MessageTemplateRequest, NetworkStatus, NotificationDuplicateReport, NotificationQueueOutcome,
+        NotificationRequest, QueuedNotification, RenderedMessage, TerminalLimits, TerminalStatus,
+        TransactionValidationResult,
+    };
+    use banking_api::service::{BranchHierarchy, NetworkHierarchy};
+    use banking_db::models::{
+        WorkflowEscalationModel, WorkflowEscalationStatusModel, WorkflowStatusModel, WorkflowStepModel,
+        WorkflowStepRecordModel, WorkflowTypeModel,
+    };
+    use banking_db::repository::workflow_repository::{
+        WorkflowBottleneckReport, WorkflowFilter, WorkflowMetricsReport, WorkflowPage, WorkflowPerformanceReport,
+        WorkflowSearchResult,
+    };
+
+    /// Workflows whose timeout passed are timed out by the bulk update
+    #[derive(Default)]
+    struct MockWorkflowRepository {
+        workflows: Mutex<Vec<AccountWorkflowModel>>,
+    }
+
+    fn is_expired(workflow: &AccountWorkflowModel, reference_time: DateTime<Utc>) -> bool {
+        workflow.timeout_at.is_some_and(|timeout_at| timeout_at < reference_time)
+            && matches!(workflow.status, WorkflowStatusModel::InProgress | WorkflowStatusModel::PendingAction)
+    }
+
+    #[async_trait]
+    impl WorkflowRepository for MockWorkflowRepository {
+        async fn find_workflow_by_id(&self, workflow_id: Uuid) -> BankingResult<Option<AccountWorkflowModel>> {
+            Ok(self.workflows.lock().unwrap().iter().find(|w| w.id == workflow_id).cloned())
+        }
+        async fn find_expired_workflows(&self, reference_time: DateTime<Utc>) -> BankingResult<Vec<AccountWorkflowModel>> {
+            Ok(self.workflows.lock().unwrap().iter().filter(|w| is_expired(w, reference_time)).cloned().collect())
+        }
+        async fn bulk_timeout_expired_workflows(&self, reference_time: DateTime<Utc>) -> BankingResult<i64> {
+            let mut timed_out = 0;
+            for workflow in self.workflows.lock().unwrap().iter_mut().filter(|w| is_expired(w, reference_time)) {
+                workflow.status = WorkflowStatusModel::TimedOut;
+                timed_out += 1;
+            }
+            Ok(timed_out)
+        }
+        async fn create_workflow(&self, _workflow: &AccountWorkflowModel) -> BankingResult<AccountWorkflowModel> { unimplemented!() }
+        async fn update_workflow(&self, _workflow: AccountWorkflowModel) -> BankingResult<AccountWorkflowModel> { unimplemented!() }
+        async fn find_workflows_by_account(&self, _account_id: Uuid) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_active_workflow(&self, _account_id: Uuid, _workflow_type: &str) -> BankingResult<Option<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_open_workflows_by_accounts(&self, _account_ids: &[Uuid]) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_by_type(&self, _workflow_type: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_by_status(&self, _status: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_by_initiator(&self, _initiated_by: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn search_workflows(&self, _filter: &WorkflowFilter, _page: WorkflowPage) -> BankingResult<WorkflowSearchResult> { unimplemented!() }
+        async fn update_workflow_status(&self, _workflow_id: Uuid, _status: &str, _notes: &str) -> BankingResult<()> { unimplemented!() }
+        async fn update_workflow_step(&self, _workflow_id: Uuid, _current_step: &str) -> BankingResult<()> { unimplemented!() }
+        async fn advance_workflow_step(&self, _workflow_id: Uuid, _step: &str, _completed_by: Uuid, _notes: &str, _supporting_documents: Vec<HeaplessString<100>>) -> BankingResult<()> { unimplemented!() }
+        async fn complete_workflow(&self, _workflow_id: Uuid, _completion_notes: &str) -> BankingResult<()> { unimplemented!() }
+        async fn fail_workflow(&self, _workflow_id: Uuid, _failure_reason: &str) -> BankingResult<()> { unimplemented!() }
+        async fn cancel_workflow(&self, _workflow_id: Uuid, _reason: &str) -> BankingResult<()> { unimplemented!() }
+        async fn add_step_record(&self, _workflow_id: Uuid, _step_record: WorkflowStepRecordModel) -> BankingResult<WorkflowStepRecordModel> { unimplemented!() }
+        async fn find_step_records_by_workflow(&self, _workflow_id: Uuid) -> BankingResult<Vec<WorkflowStepRecordModel>> { unimplemented!() }
+        async fn find_latest_step_record(&self, _workflow_id: Uuid) -> BankingResult<Option<WorkflowStepRecordModel>> { unimplemented!() }
+        async fn find_pending_workflows(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_in_progress_workflows(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_requiring_action(&self, _action_type: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_account_opening_workflows(&self, _status: Option<&str>) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_pending_kyc_workflows(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_pending_document_verification(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_account_closure_workflows(&self, _status: Option<&str>) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_pending_final_settlement(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_pending_disbursement(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_reactivation_workflows(&self, _status: Option<&str>) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_pending_mini_kyc(&self) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_compliance_workflows(&self, _status: Option<&str>) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_by_customer_risk(&self, _risk_rating: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_approval_workflows(&self, _status: Option<&str>) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_by_approver(&self, _approver_id: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn find_workflows_awaiting_approval(&self, _approver_id: &str) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn get_workflow_metrics(&self, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<WorkflowMetricsReport> { unimplemented!() }
+        async fn get_workflow_performance(&self, _workflow_type: &str, _from_date: NaiveDate, _to_date: NaiveDate) -> BankingResult<WorkflowPerformanceReport> { unimplemented!() }
+        async fn get_workflow_bottlenecks(&self) -> BankingResult<Vec<WorkflowBottleneckReport>> { unimplemented!() }
+        async fn get_average_completion_time(&self, _workflow_type: &str) -> BankingResult<Option<f64>> { unimplemented!() }
+        async fn cleanup_completed_workflows(&self, _retention_days: i32) -> BankingResult<i64> { unimplemented!() }
+        async fn cleanup_cancelled_workflows(&self, _retention_days: i32) -> BankingResult<i64> { unimplemented!() }
+        async fn find_stale_workflows(&self, _stale_threshold_hours: i32) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn bulk_update_workflow_status(&self, _workflow_ids: Vec<Uuid>, _status: &str) -> BankingResult<i64> { unimplemented!() }
+        async fn workflow_exists(&self, _workflow_id: Uuid) -> BankingResult<bool> { unimplemented!() }
+        async fn count_workflows_by_type(&self, _workflow_type: &str) -> BankingResult<i64> { unimplemented!() }
+        async fn count_workflows_by_status(&self, _status: &str) -> BankingResult<i64> { unimplemented!() }
+        async fn count_pending_workflows(&self) -> BankingResult<i64> { unimplemented!() }
+        async fn list_workflows(&self, _offset: i64, _limit: i64) -> BankingResult<Vec<AccountWorkflowModel>> { unimplemented!() }
+        async fn count_all_workflows(&self) -> BankingResult<i64> { unimplemented!() }
+    }
+
+    #[derive(Default)]
+    struct MockWorkflowEscalationRepository {
+        escalations: Mutex<Vec<WorkflowEscalationModel>>,
+    }
+
+    impl MockWorkflowEscalationRepository {
+        /// Apply `change` when the escalation has one of the `from` statuses
+        fn transition(
+            &self,
+            escalation_id: Uuid,
+            from: &[WorkflowEscalationStatusModel],
+            change: impl FnOnce(&mut WorkflowEscalationModel),
+        ) -> Option<WorkflowEscalationModel> {
+            let mut escalations = self.escalations.lock().unwrap();
+            let escalation = escalations.iter_mut().find(|e| e.id == escalation_id && from.contains(&e.status))?;
+            change(escalation);
+            Some(escalation.clone())
+        }
+    }
+
+    #[async_trait]
+    impl WorkflowEscalationRepository for MockWorkflowEscalationRepository {
+        async fn create_escalation(&self, escalation: WorkflowEscalationModel) -> BankingResult<WorkflowEscalationModel> {
+            self.escalations.lock().unwrap().push(escalation.clone());
+            Ok(escalation)
+        }
+        async fn find_escalation_by_id(&self, escalation_id: Uuid) -> BankingResult<Option<WorkflowEscalationModel>> {
+            Ok(self.escalations.lock().unwrap().iter().find(|e| e.id == escalation_id).cloned())
+        }
+        async fn find_escalations_by_workflow(&self, workflow_id: Uuid) -> BankingResult<Vec<WorkflowEscalationModel>> {
+            let mut found: Vec<_> = self.escalations.lock().unwrap().iter().filter(|e| e.workflow_id == workflow_id).cloned().collect();
+            found.sort_by_key(|e| e.level);
+            Ok(found)
+        }
+        async fn find_open_escalations_by_person(&self, person_id: Uuid) -> BankingResult<Vec<WorkflowEscalationModel>> {
+            Ok(self.escalations.lock().unwrap().iter()
+                .filter(|e| {
+                    e.escalated_to_person_id == person_id
+                        && matches!(e.status, WorkflowEscalationStatusModel::Open | WorkflowEscalationStatusModel::Acknowledged)
+                })
+                .cloned()
+                .collect())
+        }
+        async fn find_overdue_escalations(&self, reference_time: DateTime<Utc>, max_level: i16) -> BankingResult<Vec<WorkflowEscalationModel>> {
+            Ok(self.escalations.lock().unwrap().iter()
+                .filter(|e| {
+                    e.status == WorkflowEscalationStatusModel::Open && e.action_due_at <= reference_time && e.level < max_level
+                })
+                .cloned()
+                .collect())
+        }
+        async fn acknowledge_escalation(&self, escalation_id: Uuid, acknowledged_at: DateTime<Utc>) -> BankingResult<Option<WorkflowEscalationModel>> {
+            Ok(self.transition(escalation_id, &[WorkflowEscalationStatusModel::Open], |e| {
+                e.status = WorkflowEscalationStatusModel::Acknowledged;
+                e.acknowledged_at = Some(acknowledged_at);
+            }))
+        }
+        async fn resolve_escalation(
+            &self,
+            escalation_id: Uuid,
+            resolved_by_person_id: Uuid,
+            resolution_notes: Option<HeaplessString<500>>,
+            resolved_at: DateTime<Utc>,
+        ) -> BankingResult<Option<WorkflowEscalationModel>> {
+            let from = [WorkflowEscalationStatusModel::Open, WorkflowEscalationStatusModel::Acknowledged];
+            Ok(self.transition(escalation_id, &from, |e| {
+                e.status = WorkflowEscalationStatusModel::Resolved;
+                e.resolved_by_person_id = Some(resolved_by_person_id);
+                e.resolution_notes = resolution_notes;
+                e.resolved_at = Some(resolved_at);
+            }))
+        }
+        async fn mark_escalated(&self, escalation_id: Uuid, escalated_at: DateTime<Utc>) -> BankingResult<Option<WorkflowEscalationModel>> {
+            Ok(self.transition(escalation_id, &[WorkflowEscalationStatusModel::Open], |e| {
+                e.status = WorkflowEscalationStatusModel::Escalated;
+                e.last_updated_at = escalated_at;
+            }))
+        }
+    }
+
+    /// Knows the reporting line of each initiator
+    struct MockHierarchyService {
+        reporting_lines: HashMap<Uuid, Vec<Uuid>>,
+    }
+
+    #[async_trait]
+    impl HierarchyService for MockHierarchyService {
+        async fn get_reporting_line(&self, person_id: Uuid) -> BankingResult<Vec<Uuid>> {
+            Ok(self.reporting_lines.get(&person_id).cloned().unwrap_or_default())
+        }
+        async fn validate_hierarchical_limits(&self, _terminal_id: Uuid, _amount: Decimal) -> BankingResult<TransactionValidationResult> { unimplemented!() }
+        async fn get_branch_gl_prefix(&self, _branch_id: Uuid) -> BankingResult<String> { unimplemented!() }
+        async fn update_daily_volumes(&self, _terminal_id: Uuid, _amount: Decimal) -> BankingResult<()> { unimplemented!() }
+        async fn reset_daily_counters(&self) -> BankingResult<()> { unimplemented!() }
+        async fn get_terminal_limits(&self, _terminal_id: Uuid) -> BankingResult<TerminalLimits> { unimplemented!() }
+        async fn get_current_daily_volume(&self, _terminal_id: Uuid) -> BankingResult<Decimal> { unimplemented!() }
+        async fn create_agent_network(&self, _network: AgentNetwork) -> BankingResult<AgentNetwork> { unimplemented!() }
+        async fn create_agency_branch(&self, _branch: AgencyBranch) -> BankingResult<AgencyBranch> { unimplemented!() }
+        async fn create_agent_terminal(&self, _terminal: AgentTerminal) -> BankingResult<AgentTerminal> { unimplemented!() }
+        async fn find_network_by_id(&self, _network_id: Uuid) -> BankingResult<Option<AgentNetwork>> { unimplemented!() }
+        async fn find_branch_by_id(&self, _branch_id: Uuid) -> BankingResult<Option<AgencyBranch>> { unimplemented!() }
+        async fn find_terminal_by_id(&self, _terminal_id: Uuid) -> BankingResult<Option<AgentTerminal>> { unimplemented!() }
+        async fn find_branches_by_network(&self, _network_id: Uuid) -> BankingResult<Vec<AgencyBranch>> { unimplemented!() }
+        async fn find_terminals_by_branch(&self, _branch_id: Uuid) -> BankingResult<Vec<AgentTerminal>> { unimplemented!() }
+        async fn update_terminal_status(&self, _terminal_id: Uuid, _status: TerminalStatus) -> BankingResult<()> { unimplemented!() }
+        async fn update_branch_status(&self, _branch_id: Uuid, _status: BranchStatus) -> BankingResult<()> { unimplemented!() }
+        async fn update_network_status(&self, _network_id: Uuid, _status: NetworkStatus) -> BankingResult<()> { unimplemented!() }
+        async fn get_network_hierarchy(&self, _network_id: Uuid) -> BankingResult<NetworkHierarchy> { unimplemented!() }
+        async fn get_branch_rollup(&self, _branch_id: Uuid) -> BankingResult<BranchRollup> { unimplemented!() }
+    }
+
+    /// Records the messages sent to supervisors
+    #[derive(Default)]
+    struct MockNotificationService {
+        sent: Mutex<Vec<MessageSendRequest>>,
+    }
+
+    #[async_trait]
+    impl NotificationService for MockNotificationService {
+        async fn render_and_send(&self, request: MessageSendRequest) -> BankingResult<Vec<ChannelDispatch>> {
+            self.sent.lock().unwrap().push(request);
+            Ok(Vec::new())
+        }
+        async fn render_and_queue(&self, _request: NotificationRequest) -> BankingResult<NotificationQueueOutcome> { unimplemented!() }
+        async fn save_message_template(&self, _request: MessageTemplateRequest) -> BankingResult<MessageTemplate> { unimplemented!() }
+        async fn render_message(&self, _template_code: &str, _language_preferences: &[[u8; 3]], _params: &HashMap<String, String>) -> BankingResult<RenderedMessage> { unimplemented!() }
+        async fn queue_dormancy_notice(&self, _customer_id: Uuid, _account_id: Uuid, _business_date: NaiveDate) -> BankingResult<NotificationQueueOutcome> { unimplemented!() }
+        async fn queue_statement_ready(&self, _customer_id: Uuid, _statement_reference: &HeaplessString<50>, _business_date: NaiveDate) -> BankingResult<NotificationQueueOutcome> { unimplemented!() }
+        async fn queue_mandate_expiry_reminder(&self, _customer_id: Uuid, _account_id: Uuid, _expiry_date: NaiveDate, _business_date: NaiveDate) -> BankingResult<NotificationQueueOutcome> { unimplemented!() }
+        async fn queue_collection_reminder(&self, _customer_id: Uuid, _amount: Decimal, _due_date: NaiveDate, _business_date: NaiveDate) -> BankingResult<NotificationQueueOutcome> { unimplemented!() }
+        async fn queue_savings_goals_reduced(&self, _customer_id: Uuid, _account_id: Uuid, _amount: Decimal, _business_date: NaiveDate) -> BankingResult<NotificationQueueOutcome> { unimplemented!() }
+        async fn queue_inactivity_fee_warning(&self, _customer_id: Uuid, _account_id: Uuid, _fee_date: NaiveDate, _warned_from: NaiveDate) -> BankingResult<NotificationQueueOutcome> { unimplemented!() }
+        async fn find_notifications_by_customer(&self, _customer_id: Uuid, _business_date: NaiveDate) -> BankingResult<Vec<QueuedNotification>> { unimplemented!() }
+        async fn get_duplicate_report(&self, _business_date: NaiveDate) -> BankingResult<NotificationDuplicateReport> { unimplemented!() }
+        async fn purge_expired_keys(&self, _as_of: NaiveDate) -> BankingResult<u64> { unimplemented!() }
+    }
+
+    struct Fixture {
+        service: WorkflowEscalationServiceImpl,
+        escalations: Arc<MockWorkflowEscalationRepository>,
+        notifications: Arc<MockNotificationService>,
+        workflow_id: Uuid,
+        /// Supervisor, manager and regional manager of the initiator
+        reporting_line: Vec<Uuid>,
+        timed_out_at: DateTime<Utc>,
+    }
+
+    /// One workflow past its timeout; escalations are due after 24 hours
+    fn fixture() -> Fixture {
+        let initiator = Uuid::new_v4();
+        let reporting_line = vec![Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+        let timed_out_at = Utc::now();
+        let workflow = AccountWorkflowModel {
+            id: Uuid::new_v4(),
+            account_id: Uuid::new_v4(),
+            workflow_type: WorkflowTypeModel::AccountOpening,
+            current_step: WorkflowStepModel::DocumentVerification,
+            status: WorkflowStatusModel::PendingAction,
+            initiated_by: initiator,
+            initiated_at: timed_out_at - Duration::days(3),
+            completed_at: None,
+            next_action_required: None,
+            timeout_at: Some(timed_out_at - Duration::minutes(1)),
+            created_at: timed_out_at - Duration::days(3),
+            last_updated_at: timed_out_at - Duration::days(3),
+        };
+        let workflow_id = workflow.id;
+
+        let escalations = Arc::new(MockWorkflowEscalationRepository::default());
+        let notifications = Arc::new(MockNotificationService::default());
+        let service = WorkflowEscalationServiceImpl::new(
+            Arc::new(MockWorkflowRepository { workflows: Mutex::new(vec![workflow]) }),
+            escalations.clone(),
+            Arc::new(MockHierarchyService { reporting_lines: HashMap::from([(initiator, reporting_line.clone())]) }),
+            notifications.clone(),
+            Arc::new(BankingConfig::default()),
+        );
+        Fixture { service, escalations, notifications, workflow_id, reporting_line, timed_out_at }
+    }
+
+    #[tokio::test]
+    async fn test_timeout_creates_level_one_escalation_and_notifies_supervisor() {
+        let f = fixture();
+
+        let summary = f.service.process_escalations(f.timed_out_at).await.unwrap();
+
+        assert_eq!(summary.workflows_timed_out, 1);
+        assert_eq!(summary.escalations_opened.len(), 1);
+        let escalation = &summary.escalations_opened[0];
+        assert_eq!(escalation.workflow_id, f.workflow_id);
+        assert_eq!(escalation.level, 1);
+        assert_eq!(escalation.escalated_to_person_id, f.reporting_line[0]);
+
+        let sent = f.notifications.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].template_code, "WORKFLOW_ESCALATION");
+        assert_eq!(sent[0].person_id, f.reporting_line[0]);
+        assert_eq!(sent[0].params["level"], "1");
+
+        let queue = f.service.find_open_escalations(f.reporting_line[0]).await.unwrap();
+        assert_eq!(queue.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unactioned_escalation_goes_to_next_level() {
+        let f = fixture();
+        f.service.process_escalations(f.timed_out_at).await.unwrap();
+
+        let before_due = f.service.process_escalations(f.timed_out_at + Duration::hours(23)).await.unwrap();
+        assert!(before_due.escalations_opened.is_empty());
+        assert_eq!(before_due.workflows_timed_out, 0);
+
+        let summary = f.service.process_escalations(f.timed_out_at + Duration::hours(24)).await.unwrap();
+
+        assert_eq!(summary.escalations_opened.len(), 1);
+        assert_eq!(summary.escalations_opened[0].level, 2);
+        assert_eq!(summary.escalations_opened[0].escalated_to_person_id, f.reporting_line[1]);
+        assert!(f.service.find_open_escalations(f.reporting_line[0]).await.unwrap().is_empty());
+        assert_eq!(f.service.find_open_escalations(f.reporting_line[1]).await.unwrap().len(), 1);
+
+        let statuses: Vec<_> = f.service.find_escalations_by_workflow(f.workflow_id).await.unwrap()
+            .iter().map(|e| e.status).collect();
+        assert_eq!(statuses, vec![WorkflowEscalationStatus::Escalated, WorkflowEscalationStatus::Open]);
+        assert_eq!(f.notifications.sent.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_resolution_stops_further_escalation() {
+        let f = fixture();
+        let opened = f.service.process_escalations(f.timed_out_at).await.unwrap().escalations_opened.remove(0);
+
+        assert!(matches!(
+            f.service.resolve_escalation(opened.id, f.reporting_line[1], None).await,
+            Err(BankingError::WorkflowEscalationNotAssigned { .. })
+        ));
+        let notes = HeaplessString::try_from("Documents received, workflow resumed").unwrap();
+        let resolved = f.service.resolve_escalation(opened.id, f.reporting_line[0], Some(notes)).await.unwrap();
+        assert_eq!(resolved.status, WorkflowEscalationStatus::Resolved);
+
+        let summary = f.service.process_escalations(f.timed_out_at + Duration::hours(72)).await.unwrap();
+
+        assert!(summary.escalations_opened.is_empty());
+        assert_eq!(f.escalations.escalations.lock().unwrap().len(), 1);
+        assert!(f.service.find_open_escalations(f.reporting_line[0]).await.unwrap().is_empty());
+        assert!(matches!(
+            f.service.acknowledge_escalation(opened.id, f.reporting_line[0]).await,
+            Err(BankingError::WorkflowEscalationClosed { status: WorkflowEscalationStatus::Resolved, .. })
+        ));
+    }
+}
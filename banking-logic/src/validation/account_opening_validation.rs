@@ -0,0 +1,216 @@
+use banking_api::{
+    BankingResult,
+    domain::{AccountOpeningRequest, KycStatus, Product, transaction::TransactionValidationResult as ValidationResult},
+};
+use banking_db::repository::{CustomerRepository, ProductRepository};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use crate::mappers::{CustomerMapper, ProductMapper};
+
+/// Checks an account opening request before its workflow is created
+pub struct AccountOpeningValidation;
+
+impl AccountOpeningValidation {
+    /// Load the product and the customer's KYC status and check the request against them
+    pub async fn validate(
+        product_repository: &dyn ProductRepository,
+        customer_repository: &dyn CustomerRepository,
+        request: &AccountOpeningRequest,
+        today: NaiveDate,
+    ) -> BankingResult<ValidationResult> {
+        let product = product_repository
+            .find_product_by_id(request.product_id)
+            .await?
+            .map(ProductMapper::from_db);
+        let kyc_status = customer_repository
+            .get_portfolio(request.customer_id)
+            .await?
+            .map(|portfolio| CustomerMapper::kyc_status_from_db(portfolio.kyc_status));
+        Ok(Self::check(request, product.as_ref(), kyc_status.as_ref(), today))
+    }
+
+    /// Check a request against its loaded product and KYC status, reporting
+    /// every failed check rather than stopping at the first
+    pub fn check(
+        request: &AccountOpeningRequest,
+        product: Option<&Product>,
+        kyc_status: Option<&KycStatus>,
+        today: NaiveDate,
+    ) -> ValidationResult {
+        let mut result = ValidationResult::success(None);
+
+        match product {
+            None => result.add_check(
+                "product_id",
+                false,
+                format!("Product {} does not exist", request.product_id),
+                Some("PRODUCT_NOT_FOUND".to_string()),
+            ),
+            Some(product) => {
+                if !product.is_open_for_new_accounts(today) {
+                    result.add_check(
+                        "product_id",
+                        false,
+                        format!("Product {} is not open for new accounts", product.id),
+                        Some("PRODUCT_CLOSED_FOR_OPENING".to_string()),
+                    );
+                }
+                if !product.rules.supports_currency(request.currency.as_str()) {
+                    result.add_check(
+                        "currency",
+                        false,
+                        format!("Product {} is not offered in {}", product.id, request.currency.as_str()),
+                        Some("CURRENCY_NOT_SUPPORTED".to_string()),
+                    );
+                }
+                let initial_deposit = request.initial_deposit.unwrap_or(Decimal::ZERO);
+                if initial_deposit < product.rules.minimum_opening_balance {
+                    result.add_check(
+                        "initial_deposit",
+                        false,
+                        format!(
+                            "Initial deposit {} is below the product minimum {}",
+                            initial_deposit, product.rules.minimum_opening_balance
+                        ),
+                        Some("INITIAL_DEPOSIT_TOO_LOW".to_string()),
+                    );
+                }
+            }
+        }
+
+        match kyc_status {
+            Some(status) if status.allows_account_opening() => {}
+            Some(status) => result.add_check(
+                "customer_id",
+                false,
+                format!("Customer KYC status {status} does not allow account opening"),
+                Some("KYC_NOT_APPROVED".to_string()),
+            ),
+            None => result.add_check(
+                "customer_id",
+                false,
+                format!("Customer {} does not exist", request.customer_id),
+                Some("CUSTOMER_NOT_FOUND".to_string()),
+            ),
+        }
+
+        let owners = 1 + request.joint_owner_ids.len();
+        if !request.signing_condition.fits_owner_count(owners) {
+            result.add_check(
+                "signing_condition",
+                false,
+                format!("Signing condition {} does not fit an account with {owners} owner(s)", request.signing_condition),
+                Some("SIGNING_CONDITION_MISMATCH".to_string()),
+            );
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use banking_api::domain::{
+        CurrencyCode, DayCountConvention, PostingFrequency, ProductAccrualFrequency, ProductRules, ProductType,
+        SigningCondition,
+    };
+    use heapless::String as HeaplessString;
+    use uuid::Uuid;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn savings_product(minimum_opening_balance: Decimal) -> Product {
+        let rules = ProductRules {
+            minimum_balance: Decimal::ZERO,
+            maximum_balance: None,
+            daily_transaction_limit: None,
+            monthly_transaction_limit: None,
+            overdraft_allowed: false,
+            overdraft_limit: None,
+            interest_calculation_method: HeaplessString::try_from("Daily").unwrap(),
+            interest_posting_frequency: PostingFrequency::Monthly,
+            dormancy_threshold_days: 180,
+            minimum_opening_balance,
+            closure_fee: Decimal::ZERO,
+            maintenance_fee: None,
+            maintenance_fee_frequency: None,
+            default_dormancy_days: None,
+            default_overdraft_limit: None,
+            per_transaction_limit: None,
+            overdraft_interest_rate: None,
+            unauthorized_overdraft_interest_rate: None,
+            accrual_frequency: ProductAccrualFrequency::Daily,
+            day_count_convention: DayCountConvention::Actual365Fixed,
+            guarantor_required: false,
+            custody_fee_threshold: None,
+            custody_fee_rate: None,
+            inactivity_fee_threshold_months: None,
+            inactivity_fee_item_id: None,
+            inactivity_fee_warning_months: None,
+            supported_currencies: vec![HeaplessString::try_from("XAF").unwrap()],
+        };
+        Product::builder(Uuid::new_v4(), "Epargne", "Savings", "Epargne", ProductType::CASA, Uuid::new_v4())
+            .unwrap()
+            .rules(rules)
+            .valid_from(date(2024, 1, 1))
+            .build()
+            .unwrap()
+    }
+
+    fn opening_request(product: &Product) -> AccountOpeningRequest {
+        AccountOpeningRequest {
+            customer_id: Uuid::new_v4(),
+            product_id: product.id,
+            currency: CurrencyCode::try_from("XAF").unwrap(),
+            signing_condition: SigningCondition::None,
+            joint_owner_ids: Vec::new(),
+            initial_deposit: Some(Decimal::from(25_000)),
+            channel: HeaplessString::try_from("Branch").unwrap(),
+            initiated_by: Uuid::new_v4(),
+            supporting_documents: Vec::new(),
+            quote_id: None,
+        }
+    }
+
+    fn failed_fields(result: &ValidationResult) -> Vec<String> {
+        result.get_failure_reasons().into_iter().map(|(field, _, _)| field).collect()
+    }
+
+    #[test]
+    fn test_clean_request_passes() {
+        let product = savings_product(Decimal::from(10_000));
+        let request = opening_request(&product);
+        let result = AccountOpeningValidation::check(&request, Some(&product), Some(&KycStatus::Approved), date(2024, 6, 1));
+        assert!(result.is_valid());
+        assert!(result.get_failure_reasons().is_empty());
+    }
+
+    #[test]
+    fn test_every_failure_is_reported() {
+        let product = savings_product(Decimal::from(10_000));
+        let mut request = opening_request(&product);
+        request.currency = CurrencyCode::try_from("USD").unwrap();
+        request.initial_deposit = None;
+        request.signing_condition = SigningCondition::AllOwners;
+
+        let result = AccountOpeningValidation::check(&request, Some(&product), Some(&KycStatus::Approved), date(2024, 6, 1));
+        assert!(!result.is_valid());
+        assert_eq!(failed_fields(&result), vec!["currency", "initial_deposit", "signing_condition"]);
+    }
+
+    #[test]
+    fn test_unknown_product_and_pending_kyc_are_refused() {
+        let product = savings_product(Decimal::ZERO);
+        let request = opening_request(&product);
+        let result = AccountOpeningValidation::check(&request, None, Some(&KycStatus::Pending), date(2024, 6, 1));
+        assert_eq!(failed_fields(&result), vec!["product_id", "customer_id"]);
+
+        // Before the product's validity period starts
+        let result = AccountOpeningValidation::check(&request, Some(&product), Some(&KycStatus::Complete), date(2023, 12, 31));
+        assert_eq!(failed_fields(&result), vec!["product_id"]);
+    }
+}
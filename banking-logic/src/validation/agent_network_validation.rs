@@ -403,6 +403,7 @@ mod tests {
             landmark_description: None,
             operating_hours_id: Uuid::new_v4(),
             holiday_plan_id: Uuid::new_v4(),
+            calendar_id: None,
             temporary_closure_id: None,
             messaging1_id: None,
             messaging1_type: None,
@@ -468,6 +469,7 @@ mod tests {
             landmark_description: None,
             operating_hours_id: Uuid::new_v4(),
             holiday_plan_id: Uuid::new_v4(),
+            calendar_id: None,
             temporary_closure_id: None,
             messaging1_id: None,
             messaging1_type: None,
@@ -1,5 +1,9 @@
+// pub mod account_opening_validation;
 // pub mod agent_network_validation;
 // pub mod calendar_validation;
+// pub mod reason_validation;
 
+// pub use account_opening_validation::*;
 // pub use agent_network_validation::*;
-// pub use calendar_validation::*;
\ No newline at end of file
+// pub use calendar_validation::*;
+// pub use reason_validation::*;
\ No newline at end of file
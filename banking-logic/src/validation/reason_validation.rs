@@ -0,0 +1,151 @@
+use banking_api::{
+    BankingError, BankingResult,
+    domain::{ReasonId, ReasonMismatchIssue, ReasonedOperation},
+};
+use banking_db::models::ReasonAndPurpose as ReasonAndPurposeModel;
+use banking_db::repository::ReasonAndPurposeRepository;
+
+use crate::constants::REASON_REQUIREMENTS;
+
+/// Checks reasons given to operations that must carry one
+pub struct ReasonValidation;
+
+impl ReasonValidation {
+    /// Load the reason and check it exists, is active and fits the operation
+    pub async fn require(
+        reason_repository: &dyn ReasonAndPurposeRepository,
+        reason_id: ReasonId,
+        operation: ReasonedOperation,
+    ) -> BankingResult<ReasonAndPurposeModel> {
+        let reason = reason_repository.find_by_id(reason_id.as_uuid()).await?;
+        Self::check(reason_id, reason.as_ref(), operation)?;
+        // check fails on a missing reason
+        reason.ok_or_else(|| Self::mismatch(reason_id, operation, ReasonMismatchIssue::NotFound))
+    }
+
+    /// Check a loaded reason against the requirements of the operation
+    pub fn check(
+        reason_id: ReasonId,
+        reason: Option<&ReasonAndPurposeModel>,
+        operation: ReasonedOperation,
+    ) -> BankingResult<()> {
+        let reason = reason.ok_or_else(|| Self::mismatch(reason_id, operation, ReasonMismatchIssue::NotFound))?;
+        if !reason.is_active {
+            return Err(Self::mismatch(reason_id, operation, ReasonMismatchIssue::Inactive));
+        }
+
+        let requirement = REASON_REQUIREMENTS
+            .iter()
+            .find(|r| r.operation == operation)
+            .ok_or_else(|| BankingError::Internal(format!("No reason requirement configured for {operation:?}")))?;
+        if !requirement.categories.contains(&reason.category) {
+            return Err(Self::mismatch(reason_id, operation, ReasonMismatchIssue::CategoryNotAllowed(reason.category)));
+        }
+        if !requirement.contexts.contains(&reason.context) {
+            return Err(Self::mismatch(reason_id, operation, ReasonMismatchIssue::ContextNotAllowed(reason.context)));
+        }
+        Ok(())
+    }
+
+    fn mismatch(reason_id: ReasonId, operation: ReasonedOperation, issue: ReasonMismatchIssue) -> BankingError {
+        BankingError::ReasonMismatch {
+            reason_id: reason_id.as_uuid(),
+            operation,
+            issue,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use banking_api::domain::{ReasonCategory, ReasonContext};
+    use chrono::Utc;
+    use heapless::String as HeaplessString;
+    use uuid::Uuid;
+
+    fn reason(category: ReasonCategory, context: ReasonContext) -> ReasonAndPurposeModel {
+        ReasonAndPurposeModel {
+            id: Uuid::new_v4(),
+            code: HeaplessString::try_from("TEST_REASON").unwrap(),
+            category,
+            context,
+            l1_content: None,
+            l2_content: None,
+            l3_content: None,
+            l1_language_code: None,
+            l2_language_code: None,
+            l3_language_code: None,
+            requires_details: false,
+            is_active: true,
+            severity: None,
+            display_order: 0,
+            compliance_metadata: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            created_by_person_id: Uuid::new_v4(),
+            updated_by_person_id: Uuid::new_v4(),
+        }
+    }
+
+    fn issue(result: BankingResult<()>) -> ReasonMismatchIssue {
+        match result {
+            Err(BankingError::ReasonMismatch { issue, .. }) => issue,
+            other => panic!("Expected a reason mismatch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_category_and_context_must_match_the_operation() {
+        let reversal = reason(ReasonCategory::TransactionReversal, ReasonContext::Transaction);
+        let id = ReasonId(reversal.id);
+        assert!(ReasonValidation::check(id, Some(&reversal), ReasonedOperation::TransactionReversal).is_ok());
+        assert_eq!(
+            issue(ReasonValidation::check(id, Some(&reversal), ReasonedOperation::AccountStatusChange)),
+            ReasonMismatchIssue::CategoryNotAllowed(ReasonCategory::TransactionReversal)
+        );
+
+        // Right category, wrong context
+        let hold = reason(ReasonCategory::HoldReason, ReasonContext::Loan);
+        assert_eq!(
+            issue(ReasonValidation::check(ReasonId(hold.id), Some(&hold), ReasonedOperation::HoldPlacement)),
+            ReasonMismatchIssue::ContextNotAllowed(ReasonContext::Loan)
+        );
+    }
+
+    #[test]
+    fn test_missing_and_inactive_reasons_are_refused() {
+        let mut closure = reason(ReasonCategory::AccountClosure, ReasonContext::Account);
+        let id = ReasonId(closure.id);
+        assert_eq!(
+            issue(ReasonValidation::check(id, None, ReasonedOperation::AccountStatusChange)),
+            ReasonMismatchIssue::NotFound
+        );
+        closure.is_active = false;
+        assert_eq!(
+            issue(ReasonValidation::check(id, Some(&closure), ReasonedOperation::AccountStatusChange)),
+            ReasonMismatchIssue::Inactive
+        );
+    }
+
+    #[test]
+    fn test_every_reasoned_operation_has_a_requirement() {
+        for operation in [
+            ReasonedOperation::AccountStatusChange,
+            ReasonedOperation::CustomerStatusChange,
+            ReasonedOperation::HoldPlacement,
+            ReasonedOperation::HoldRelease,
+            ReasonedOperation::AccountRestriction,
+            ReasonedOperation::FeeWaiver,
+            ReasonedOperation::TransactionReversal,
+            ReasonedOperation::LegalHold,
+            ReasonedOperation::LoanRestructure,
+            ReasonedOperation::WorkflowRejection,
+            ReasonedOperation::AccountDomicileTransfer,
+            ReasonedOperation::CustomerMerge,
+            ReasonedOperation::SuspiciousActivityReport,
+        ] {
+            assert!(REASON_REQUIREMENTS.iter().any(|r| r.operation == operation), "{operation:?}");
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::person::common::{create_test_audit_log, create_test_services};
+use crate::person::mock_person_repository::create_test_person;
+use banking_api::service::audit::audit_log_service::AuditLogService;
+use banking_api::service::PersonService;
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_find_actor_history_names_the_actor() {
+    let services = create_test_services();
+    let actor = create_test_person();
+    services
+        .person_service
+        .create_person(actor.clone(), create_test_audit_log())
+        .await
+        .unwrap();
+    let first = services.audit_log_service.create_audit_log(actor.id).await.unwrap();
+    let second = services.audit_log_service.create_audit_log(actor.id).await.unwrap();
+    let unknown_actor = Uuid::new_v4();
+    services.audit_log_service.create_audit_log(unknown_actor).await.unwrap();
+
+    let from = Utc::now() - Duration::hours(1);
+    let to = Utc::now() + Duration::hours(1);
+    let history = services
+        .audit_log_service
+        .find_actor_history(actor.id, from, to, 1, 50)
+        .await
+        .unwrap();
+    assert_eq!(history.len(), 2);
+    // Entries written in the same instant are ordered by id
+    let newest_first = if second.updated_at == first.updated_at {
+        second.id.max(first.id)
+    } else {
+        second.id
+    };
+    assert_eq!(history[0].audit_log.id, newest_first);
+    assert!(history
+        .iter()
+        .all(|entry| entry.actor_display_name.as_ref().map(|name| name.as_str()) == Some("John Doe")));
+
+    let history = services
+        .audit_log_service
+        .find_actor_history(unknown_actor, from, to, 1, 50)
+        .await
+        .unwrap();
+    assert_eq!(history.len(), 1);
+    assert!(history[0].actor_display_name.is_none());
+}
@@ -1,4 +1,5 @@
 use banking_logic::services::repositories::Repositories;
+use banking_logic::services::audit::audit_log_service_impl::AuditLogServiceImpl;
 use banking_logic::services::{
     CountryServiceImpl, CountrySubdivisionServiceImpl, EntityReferenceServiceImpl,
     LocalityServiceImpl, LocationServiceImpl, PersonServiceImpl,
@@ -11,8 +12,9 @@ use crate::person::mock_locality_repository::MockLocalityRepository;
 use crate::person::mock_location_repository::MockLocationRepository;
 use crate::person::mock_entity_reference_repository::MockEntityReferenceRepository;
 use crate::person::mock_person_repository::MockPersonRepository;
-use banking_db::models::audit::AuditLogModel;
-use banking_db::repository::audit_repository::{AuditLogRepository, AuditLogRepositoryError};
+use banking_db::models::audit::{AuditChainVerification, AuditEntityType, AuditLogModel};
+use banking_db::repository::audit_repository::{AuditLogRepository, AuditLogRepositoryError, MAX_AUDIT_LOG_PAGE_SIZE};
+use chrono::{DateTime, Utc};
 use sqlx::Postgres;
 use std::sync::Mutex;
 use async_trait::async_trait;
@@ -24,6 +26,7 @@ pub struct TestServices {
     pub location_service: LocationServiceImpl<Postgres>,
     pub entity_reference_service: EntityReferenceServiceImpl<Postgres>,
     pub person_service: PersonServiceImpl<Postgres>,
+    pub audit_log_service: AuditLogServiceImpl,
     pub mock_country_subdivision_repository: Arc<MockCountrySubdivisionRepository>,
 }
 
@@ -48,20 +51,66 @@ impl AuditLogRepository<Postgres> for MockAuditLogRepository {
             .find(|a| a.id == id)
             .cloned())
     }
+
+    async fn find_by_entity(
+        &self,
+        _entity_type: AuditEntityType,
+        _entity_id: Uuid,
+        _from: DateTime<Utc>,
+        _to: DateTime<Utc>,
+        _page: i32,
+        _page_size: i32,
+    ) -> Result<Vec<AuditLogModel>, AuditLogRepositoryError> {
+        unimplemented!()
+    }
+
+    async fn find_by_actor(
+        &self,
+        person_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        page: i32,
+        page_size: i32,
+    ) -> Result<Vec<AuditLogModel>, AuditLogRepositoryError> {
+        let page_size = page_size.clamp(1, MAX_AUDIT_LOG_PAGE_SIZE) as usize;
+        let mut audit_logs: Vec<AuditLogModel> = self
+            .audit_logs
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|a| a.updated_by_person_id == person_id && a.updated_at >= from && a.updated_at < to)
+            .cloned()
+            .collect();
+        audit_logs.sort_by(|a, b| b.updated_at.cmp(&a.updated_at).then(b.id.cmp(&a.id)));
+        Ok(audit_logs
+            .into_iter()
+            .skip((page.max(1) - 1) as usize * page_size)
+            .take(page_size)
+            .collect())
+    }
+
+    async fn find_chain_segment(&self, _from_id: Uuid, _to_id: Uuid) -> Result<Vec<AuditLogModel>, AuditLogRepositoryError> {
+        unimplemented!()
+    }
+
+    async fn verify_chain(&self, _from_id: Uuid, _to_id: Uuid) -> Result<AuditChainVerification, AuditLogRepositoryError> {
+        unimplemented!()
+    }
 }
 
 pub fn create_test_services() -> TestServices {
     let mock_person_repository = Arc::new(MockPersonRepository::default());
     let mock_country_subdivision_repository =
         Arc::new(MockCountrySubdivisionRepository::default());
+    let mock_audit_log_repository = Arc::new(MockAuditLogRepository::default());
     let repositories = Repositories {
         person_repository: mock_person_repository.clone(),
-        audit_log_repository: Arc::new(MockAuditLogRepository::default()),
+        audit_log_repository: mock_audit_log_repository.clone(),
         country_repository: Arc::new(MockCountryRepository::default()),
         country_subdivision_repository: mock_country_subdivision_repository.clone(),
         locality_repository: Arc::new(MockLocalityRepository::default()),
         location_repository: Arc::new(MockLocationRepository::default()),
-        entity_reference_repository: Arc::new(MockEntityReferenceRepository::new(mock_person_repository)),
+        entity_reference_repository: Arc::new(MockEntityReferenceRepository::new(mock_person_repository.clone())),
     };
     TestServices {
         country_service: CountryServiceImpl::new(repositories.clone()),
@@ -70,6 +119,7 @@ pub fn create_test_services() -> TestServices {
         location_service: LocationServiceImpl::new(repositories.clone()),
         entity_reference_service: EntityReferenceServiceImpl::new(repositories.clone()),
         person_service: PersonServiceImpl::new(repositories),
+        audit_log_service: AuditLogServiceImpl::new(mock_audit_log_repository, mock_person_repository),
         mock_country_subdivision_repository,
     }
 }
@@ -1,5 +1,5 @@
 use crate::person::mock_country_repository::create_test_country;
-use banking_api::service::CountryService;
+use banking_api::service::{CountryService, CountryServiceError};
 use crate::person::common::create_test_services;
 
 #[tokio::test]
@@ -65,4 +65,24 @@ async fn test_get_all_countries() {
         .await
         .unwrap();
     assert!(countries.is_empty());
-}
\ No newline at end of file
+}
+#[tokio::test]
+async fn test_search_countries() {
+    let services = create_test_services();
+    let country = create_test_country();
+    services
+        .country_service
+        .create_country(country.clone())
+        .await
+        .unwrap();
+    let found = services
+        .country_service
+        .search_countries(" uNiTeD ", 10)
+        .await
+        .unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].id, country.id);
+
+    let rejected = services.country_service.search_countries(" u ", 10).await;
+    assert!(matches!(rejected, Err(CountryServiceError::InvalidNamePrefix(_))));
+}
@@ -1,7 +1,9 @@
 use async_trait::async_trait;
 use banking_api::domain::person::Country;
-use banking_db::models::person::{CountryIdxModel, CountryModel};
-use banking_db::repository::person::country_repository::{CountryRepository, CountryRepositoryError, CountryResult};
+use banking_db::models::person::{country_name_matches, fold_country_name, CountryIdxModel, CountryModel};
+use banking_db::repository::person::country_repository::{
+    normalize_country_name_prefix, CountryRepository, CountryRepositoryError, CountryResult,
+};
 use heapless::String as HeaplessString;
 use std::sync::Mutex;
 use uuid::Uuid;
@@ -109,6 +111,25 @@ impl CountryRepository<Postgres> for MockCountryRepository {
             .collect();
         Ok(result)
     }
+
+    async fn find_by_name_prefix(
+        &self,
+        prefix: &str,
+        limit: i32,
+    ) -> CountryResult<Vec<CountryModel>> {
+        let folded_prefix = normalize_country_name_prefix(prefix)?;
+        let mut countries: Vec<CountryModel> = self
+            .countries
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|c| country_name_matches(c, &folded_prefix))
+            .cloned()
+            .collect();
+        countries.sort_by_cached_key(|c| fold_country_name(&c.name_l1));
+        countries.truncate(limit.max(0) as usize);
+        Ok(countries)
+    }
 }
 
 // Helper functions for creating test data
@@ -6,6 +6,7 @@ use banking_db::models::person::{
 use banking_db::repository::person::entity_reference_repository::{EntityReferenceRepository, EntityReferenceRepositoryError, EntityReferenceResult};
 use banking_db::repository::PersonRepository;
 use heapless::String as HeaplessString;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 use sqlx::Postgres;
@@ -160,6 +161,29 @@ impl EntityReferenceRepository<Postgres> for MockEntityReferenceRepository {
         Ok(result)
     }
 
+    async fn find_by_reference_external_ids(
+        &self,
+        external_ids: &[&str],
+        _page: i32,
+        _page_size: i32,
+    ) -> EntityReferenceResult<HashMap<String, Vec<EntityReferenceIdxModel>>> {
+        let entities = self.entities.lock().unwrap();
+        let entity_ixes = self.entity_ixes.lock().unwrap();
+        let mut result: HashMap<String, Vec<EntityReferenceIdxModel>> = HashMap::new();
+        for entity in entities
+            .iter()
+            .filter(|e| external_ids.contains(&e.reference_external_id.as_str()))
+        {
+            if let Some(ix) = entity_ixes.iter().find(|ix| ix.entity_reference_id == entity.id) {
+                result
+                    .entry(entity.reference_external_id.to_string())
+                    .or_default()
+                    .push(ix.clone());
+            }
+        }
+        Ok(result)
+    }
+
     async fn exist_by_ids(
         &self,
         ids: &[Uuid],
@@ -37,6 +37,10 @@ impl LocalityRepository<Postgres> for MockLocalityRepository {
             .unwrap())
     }
 
+    async fn load_including_deactivated(&self, id: Uuid) -> LocalityResult<LocalityModel> {
+        self.load(id).await
+    }
+
     async fn find_by_id(&self, id: Uuid) -> LocalityResult<Option<LocalityIdxModel>> {
         Ok(self
             .locality_ixes
@@ -3,6 +3,7 @@ use banking_api::domain::person::{Location, LocationType};
 use banking_db::models::person::{LocationAuditModel, LocationIdxModel, LocationModel};
 use banking_db::repository::location_repository::{LocationRepository, LocationRepositoryError};
 use heapless::String as HeaplessString;
+use rust_decimal::Decimal;
 use std::sync::Mutex;
 use uuid::Uuid;
 use sqlx::Postgres;
@@ -67,6 +68,10 @@ impl LocationRepository<Postgres> for MockLocationRepository {
         }
     }
 
+    async fn load_including_deactivated(&self, id: Uuid) -> Result<LocationModel, LocationRepositoryError> {
+        self.load(id).await
+    }
+
     async fn find_by_id(
         &self,
         id: Uuid,
@@ -150,6 +155,40 @@ impl LocationRepository<Postgres> for MockLocationRepository {
             .collect();
         Ok(locations)
     }
+    async fn find_within_bounding_box(
+        &self,
+        min_latitude: Decimal,
+        min_longitude: Decimal,
+        max_latitude: Decimal,
+        max_longitude: Decimal,
+        _page: i32,
+        _page_size: i32,
+    ) -> Result<Vec<LocationModel>, LocationRepositoryError> {
+        let locations = self
+            .locations
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|l| match (l.latitude, l.longitude) {
+                (Some(lat), Some(long)) => {
+                    (min_latitude..=max_latitude).contains(&lat)
+                        && (min_longitude..=max_longitude).contains(&long)
+                }
+                _ => false,
+            })
+            .cloned()
+            .collect();
+        Ok(locations)
+    }
+
+    async fn find_nearest(
+        &self,
+        _latitude: Decimal,
+        _longitude: Decimal,
+        _limit: i32,
+    ) -> Result<Vec<LocationModel>, LocationRepositoryError> {
+        unimplemented!()
+    }
 }
 
 pub fn create_test_location(locality_id: Uuid) -> Location {
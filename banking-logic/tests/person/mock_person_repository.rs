@@ -2,7 +2,9 @@ use async_trait::async_trait;
 use banking_api::domain::person::{Person, PersonType};
 use banking_db::models::person::{PersonAuditModel, PersonIdxModel, PersonModel};
 use banking_db::repository::person::person_repository::{PersonRepository, PersonRepositoryError, PersonResult};
+use chrono::{DateTime, Utc};
 use heapless::String as HeaplessString;
+use std::collections::HashMap;
 use std::sync::Mutex;
 use uuid::Uuid;
 use sqlx::Postgres;
@@ -12,11 +14,16 @@ pub struct MockPersonRepository {
     persons: Mutex<Vec<PersonModel>>,
     person_ixes: Mutex<Vec<PersonIdxModel>>,
     person_audits: Mutex<Vec<PersonAuditModel>>,
+    created_at: Mutex<HashMap<Uuid, DateTime<Utc>>>,
 }
 
 #[async_trait]
 impl PersonRepository<Postgres> for MockPersonRepository {
     async fn save(&self, person: PersonModel, audit_log_id: Uuid) -> PersonResult<PersonModel> {
+        self.created_at.lock().unwrap().entry(person.id).or_insert_with(Utc::now);
+        // Saving an existing person replaces it, as the real repository does
+        self.persons.lock().unwrap().retain(|p| p.id != person.id);
+        self.person_ixes.lock().unwrap().retain(|p| p.person_id != person.id);
         self.persons.lock().unwrap().push(person.clone());
         // In a real scenario, we'd create a proper hash and version.
         let person_idx = PersonIdxModel {
@@ -67,6 +74,10 @@ impl PersonRepository<Postgres> for MockPersonRepository {
         }
     }
 
+    async fn load_including_deactivated(&self, id: Uuid) -> PersonResult<PersonModel> {
+        self.load(id).await
+    }
+
     async fn find_by_id(&self, id: Uuid) -> PersonResult<Option<PersonIdxModel>> {
         Ok(self
             .person_ixes
@@ -166,6 +177,34 @@ impl PersonRepository<Postgres> for MockPersonRepository {
             .collect();
         Ok(result)
     }
+    async fn find_duplicate_candidates(
+        &self,
+        person_id: Uuid,
+        _external_identifier: Option<&str>,
+        _normalized_name: &str,
+        _messaging_values: &[String],
+    ) -> PersonResult<Vec<PersonModel>> {
+        // Every other person is a candidate; the service does the scoring
+        Ok(self
+            .persons
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|p| p.id != person_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_ids_created_since(&self, since: DateTime<Utc>) -> PersonResult<Vec<Uuid>> {
+        let created_at = self.created_at.lock().unwrap();
+        let mut ids: Vec<(DateTime<Utc>, Uuid)> = created_at
+            .iter()
+            .filter(|(_, at)| **at >= since)
+            .map(|(id, at)| (*at, *id))
+            .collect();
+        ids.sort();
+        Ok(ids.into_iter().map(|(_, id)| id).collect())
+    }
 }
 
 pub fn create_test_person() -> Person {
@@ -1,3 +1,4 @@
+pub mod audit_log_tests;
 pub mod common;
 pub mod country_tests;
 pub mod mock_country_repository;
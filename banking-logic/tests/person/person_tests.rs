@@ -1,6 +1,13 @@
 use crate::person::mock_person_repository::create_test_person;
-use crate::person::common::{create_test_audit_log, create_test_services};
+use crate::person::common::{create_test_audit_log, create_test_services, TestServices};
+use banking_api::domain::person::{DuplicateMatchReason, Person};
 use banking_api::service::PersonService;
+use banking_api::service::person::person_service::PersonServiceError;
+use banking_logic::constants::{
+    DUPLICATE_SCORE_EXTERNAL_IDENTIFIER, DUPLICATE_SCORE_MESSAGING, DUPLICATE_SCORE_NORMALIZED_NAME,
+};
+use heapless::String as HeaplessString;
+use uuid::Uuid;
 
 #[tokio::test]
 async fn test_create_person() {
@@ -47,4 +54,134 @@ async fn test_get_person_by_external_identifier() {
         .await
         .unwrap();
     assert_eq!(person.id, found_person[0].id);
-}
\ No newline at end of file
+}
+
+/// Seeds an original, a true duplicate of it and a near-miss sharing only the name
+async fn seed_duplicates(services: &TestServices) -> (Person, Person, Person) {
+    let mut original = create_test_person();
+    original.messaging_info1 = Some(HeaplessString::try_from("email:john.doe@example.com").unwrap());
+
+    let mut duplicate = create_test_person();
+    duplicate.display_name = HeaplessString::try_from("JOHN  doe.").unwrap();
+    duplicate.messaging_info2 = Some(HeaplessString::try_from("EMAIL:John.Doe@example.com").unwrap());
+
+    let mut near_miss = create_test_person();
+    near_miss.external_identifier = Some(HeaplessString::try_from("JD002").unwrap());
+
+    for person in [&original, &duplicate, &near_miss] {
+        services
+            .person_service
+            .create_person(person.clone(), create_test_audit_log())
+            .await
+            .unwrap();
+    }
+    (original, duplicate, near_miss)
+}
+
+#[tokio::test]
+async fn test_detect_duplicates_orders_candidates_by_score() {
+    let services = create_test_services();
+    let (original, duplicate, near_miss) = seed_duplicates(&services).await;
+
+    let candidates = services
+        .person_service
+        .detect_duplicates(original.id)
+        .await
+        .unwrap();
+
+    assert_eq!(candidates.len(), 2);
+    assert_eq!(candidates[0].candidate_person_id, duplicate.id);
+    assert_eq!(
+        candidates[0].score,
+        DUPLICATE_SCORE_EXTERNAL_IDENTIFIER + DUPLICATE_SCORE_NORMALIZED_NAME + DUPLICATE_SCORE_MESSAGING
+    );
+    assert_eq!(
+        candidates[0].match_reasons,
+        vec![
+            DuplicateMatchReason::ExternalIdentifier,
+            DuplicateMatchReason::NormalizedName,
+            DuplicateMatchReason::Messaging,
+        ]
+    );
+    assert_eq!(candidates[1].candidate_person_id, near_miss.id);
+    assert_eq!(candidates[1].score, DUPLICATE_SCORE_NORMALIZED_NAME);
+
+    let missing = services.person_service.detect_duplicates(Uuid::new_v4()).await;
+    assert!(matches!(missing, Err(PersonServiceError::PersonNotFound(_))));
+}
+
+#[tokio::test]
+async fn test_link_duplicate_sets_the_canonical_person() {
+    let services = create_test_services();
+    let (original, duplicate, near_miss) = seed_duplicates(&services).await;
+    let reviewer = Uuid::new_v4();
+
+    let linked = services
+        .person_service
+        .link_duplicate(duplicate.id, original.id, reviewer)
+        .await
+        .unwrap();
+    assert_eq!(linked.duplicate_of_person_id, Some(original.id));
+
+    let reloaded = services
+        .person_service
+        .find_person_by_id(duplicate.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(reloaded.duplicate_of_person_id, Some(original.id));
+
+    // The reviewed pair no longer shows up as a candidate
+    let candidates = services
+        .person_service
+        .detect_duplicates(original.id)
+        .await
+        .unwrap();
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].candidate_person_id, near_miss.id);
+
+    // Duplicates cannot become canonical records
+    let chained = services
+        .person_service
+        .link_duplicate(near_miss.id, duplicate.id, reviewer)
+        .await;
+    assert!(matches!(chained, Err(PersonServiceError::InvalidHierarchy(_))));
+}
+
+#[tokio::test]
+async fn test_scan_for_duplicates_reports_each_pair_once() {
+    let services = create_test_services();
+    let since = chrono::Utc::now();
+    let (original, duplicate, near_miss) = seed_duplicates(&services).await;
+
+    let found = services
+        .person_service
+        .scan_for_duplicates(since)
+        .await
+        .unwrap();
+
+    let mut pairs: Vec<(Uuid, Uuid)> = found
+        .iter()
+        .map(|c| {
+            (
+                c.person_id.min(c.candidate_person_id),
+                c.person_id.max(c.candidate_person_id),
+            )
+        })
+        .collect();
+    pairs.sort();
+    let mut expected = vec![
+        (original.id.min(duplicate.id), original.id.max(duplicate.id)),
+        (original.id.min(near_miss.id), original.id.max(near_miss.id)),
+        (duplicate.id.min(near_miss.id), duplicate.id.max(near_miss.id)),
+    ];
+    expected.sort();
+    assert_eq!(pairs, expected);
+
+    let later = services
+        .person_service
+        .scan_for_duplicates(chrono::Utc::now())
+        .await
+        .unwrap();
+    assert!(later.is_empty());
+}